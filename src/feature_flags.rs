@@ -21,6 +21,15 @@
 //! no-std-compat = { version = "0.4.1", features = [ "alloc", "compat_hash" ] }
 //! ```
 //!
+//! Without "std", the [crate::tensor::Cpu] device still supports tensor storage,
+//! forward & backward ops, and running nn modules, so models can be run on targets like
+//! `wasm32-unknown-unknown` or embedded devices. The differences are:
+//! - The rng behind [crate::tensor::Cpu] is guarded by a spin lock instead of `std::sync::Mutex`.
+//! - Matrix multiplication is single threaded.
+//! - Fourier transforms use a simple radix 2 fft (and Bluestein's algorithm for other
+//!   lengths) instead of [rustfft](https://crates.io/crates/rustfft).
+//! - "numpy" (and therefore all file I/O) is unavailable, since it requires "std".
+//!
 //! # "intel-mkl"
 //!
 //! Enables using the `Intel MKL` libraries (assuming you installed it already) for matrix multiplication.
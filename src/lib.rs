@@ -1,5 +1,5 @@
 //! Ergonomics & safety focused deep learning in Rust. Main features include:
-//! 1. Tensor library with shapes up to 6d!
+//! 1. Tensor library with shapes up to 8d!
 //! 2. Shapes with both compile and runtime sized dimensions. (e.g. `Tensor<(usize, Const<10>)>` and `Tensor<Rank2<5, 10>>`)
 //! 3. A large library of tensor operations (including `matmul`, `conv2d`, and much more).
 //!     a. All tensor operations shape and type checked at compile time!!
@@ -113,6 +113,7 @@ pub mod optim;
 pub mod shapes;
 pub mod tensor;
 pub mod tensor_ops;
+pub mod testing;
 pub mod unique_id;
 
 /// Contains subset of all public exports.
@@ -179,76 +180,7 @@ pub(crate) mod tests {
     #[cfg(feature = "test-f64")]
     pub type TestDtype = f64;
 
-    pub trait AssertClose {
-        type Elem: std::fmt::Display + std::fmt::Debug + Copy;
-        const DEFAULT_TOLERANCE: Self::Elem;
-        fn get_far_pair(
-            &self,
-            rhs: &Self,
-            tolerance: Self::Elem,
-        ) -> Option<(Self::Elem, Self::Elem)>;
-        fn assert_close(&self, rhs: &Self, tolerance: Self::Elem)
-        where
-            Self: std::fmt::Debug,
-        {
-            if let Some((l, r)) = self.get_far_pair(rhs, tolerance) {
-                panic!("lhs != rhs | {l} != {r}\n\n{self:?}\n\n{rhs:?}");
-            }
-        }
-    }
-
-    impl AssertClose for f32 {
-        type Elem = f32;
-        const DEFAULT_TOLERANCE: Self::Elem = 1e-6;
-        fn get_far_pair(&self, rhs: &Self, tolerance: f32) -> Option<(f32, f32)> {
-            if (self - rhs).abs() > tolerance {
-                Some((*self, *rhs))
-            } else {
-                None
-            }
-        }
-    }
-
-    impl AssertClose for f64 {
-        type Elem = f64;
-        const DEFAULT_TOLERANCE: Self::Elem = 1e-6;
-        fn get_far_pair(&self, rhs: &Self, tolerance: f64) -> Option<(f64, f64)> {
-            if (self - rhs).abs() > tolerance {
-                Some((*self, *rhs))
-            } else {
-                None
-            }
-        }
-    }
-
-    impl<T: AssertClose, const M: usize> AssertClose for [T; M] {
-        type Elem = T::Elem;
-        const DEFAULT_TOLERANCE: Self::Elem = T::DEFAULT_TOLERANCE;
-        fn get_far_pair(
-            &self,
-            rhs: &Self,
-            tolerance: Self::Elem,
-        ) -> Option<(Self::Elem, Self::Elem)> {
-            for (l, r) in self.iter().zip(rhs.iter()) {
-                if let Some(pair) = l.get_far_pair(r, tolerance) {
-                    return Some(pair);
-                }
-            }
-            None
-        }
-    }
-
-    pub fn assert_close<T: AssertClose + std::fmt::Debug>(a: &T, b: &T) {
-        a.assert_close(b, T::DEFAULT_TOLERANCE);
-    }
-
-    pub fn assert_close_with_tolerance<T: AssertClose + std::fmt::Debug>(
-        a: &T,
-        b: &T,
-        tolerance: T::Elem,
-    ) {
-        a.assert_close(b, tolerance);
-    }
+    pub use crate::testing::{assert_close, assert_close_with_tolerance, AssertClose};
 }
 
 /// Used to assert things about const generics
@@ -25,7 +25,11 @@ macro_rules! activation_impls {
 }
 
 activation_impls!(ReLU, try_relu, #[doc="Unit struct that impls [Module] as calling [relu()] on `input`."]);
-activation_impls!(GeLU, try_gelu, #[doc="Unit struct that impls [Module] as calling [gelu()] on `input`."]);
+activation_impls!(Mish, try_mish, #[doc="Unit struct that impls [Module] as calling [mish()] on `input`."]);
+activation_impls!(SELU, try_selu, #[doc="Unit struct that impls [Module] as calling [selu()] on `input`."]);
+activation_impls!(HardSwish, try_hardswish, #[doc="Unit struct that impls [Module] as calling [hardswish()] on `input`."]);
+activation_impls!(HardSigmoid, try_hardsigmoid, #[doc="Unit struct that impls [Module] as calling [hardsigmoid()] on `input`."]);
+activation_impls!(HardTanh, try_hardtanh, #[doc="Unit struct that impls [Module] as calling [hardtanh()] on `input`."]);
 activation_impls!(Sin, try_sin, #[doc="Unit struct that impls [Module] as calling [sin()] on `input`."]);
 activation_impls!(Cos, try_cos, #[doc="Unit struct that impls [Module] as calling [cos()] on `input`."]);
 activation_impls!(Ln, try_ln, #[doc="Unit struct that impls [Module] as calling [ln()] on `input`."]);
@@ -36,6 +40,71 @@ activation_impls!(Square, try_square, #[doc="Unit struct that impls [Module] as
 activation_impls!(Sqrt, try_sqrt, #[doc="Unit struct that impls [Module] as calling [sqrt()] on `input`."]);
 activation_impls!(Abs, try_abs, #[doc="Unit struct that impls [Module] as calling [abs()] on `input`."]);
 
+/// Impls [Module] as calling [gelu()] on `input`, or [gelu_exact()] if `self.exact` is set.
+///
+/// Defaults to the tanh approximation:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([-1.0, 0.0, 1.0]);
+/// assert_eq!(GeLU::default().forward(t.clone()).array(), t.clone().gelu().array());
+/// assert_eq!(GeLU { exact: true }.forward(t.clone()).array(), t.gelu_exact().array());
+/// ```
+#[derive(Default, Debug, Clone, Copy)]
+pub struct GeLU {
+    pub exact: bool,
+}
+
+impl ZeroSizedModule for GeLU {}
+impl NonMutableModule for GeLU {}
+
+impl<S: Shape, E: Dtype, D: Device<E>, T: Tape<D>> Module<Tensor<S, E, D, T>> for GeLU {
+    type Output = Tensor<S, E, D, T>;
+    type Error = D::Err;
+
+    fn try_forward(&self, input: Tensor<S, E, D, T>) -> Result<Self::Output, D::Err> {
+        if self.exact {
+            input.try_gelu_exact()
+        } else {
+            input.try_gelu()
+        }
+    }
+}
+
+macro_rules! alpha_activation_impls {
+    ($struct_name:ident, $func_name:ident, #[$docstring:meta]) => {
+        #[$docstring]
+        #[derive(Debug, Clone, Copy)]
+        pub struct $struct_name {
+            pub alpha: f32,
+        }
+
+        impl Default for $struct_name {
+            /// Sets `self.alpha` to `1.0`
+            fn default() -> Self {
+                Self { alpha: 1.0 }
+            }
+        }
+
+        impl ZeroSizedModule for $struct_name {}
+        impl NonMutableModule for $struct_name {}
+
+        impl<S: Shape, E: Dtype, D: Device<E>, T: Tape<D>> Module<Tensor<S, E, D, T>>
+            for $struct_name
+        {
+            type Output = Tensor<S, E, D, T>;
+            type Error = D::Err;
+
+            fn try_forward(&self, input: Tensor<S, E, D, T>) -> Result<Self::Output, D::Err> {
+                input.$func_name(E::from_f32(self.alpha).unwrap())
+            }
+        }
+    };
+}
+
+alpha_activation_impls!(ELU, try_elu, #[doc="Impls [Module] as calling [elu()] on `input` with `self.alpha`."]);
+alpha_activation_impls!(CELU, try_celu, #[doc="Impls [Module] as calling [celu()] on `input` with `self.alpha`."]);
+
 /// Unit struct that impls [Module] as calling [softmax()] on `input`."
 #[derive(Default, Debug, Clone, Copy)]
 pub struct Softmax;
@@ -73,11 +142,87 @@ mod tests {
     fn test_nn_activations_gelu() {
         let dev: TestDevice = Default::default();
         let t = dev.tensor([-2.0, -1.0, 0.0, 1.0, 2.0]);
-        let r1 = GeLU.forward_mut(t.clone());
+        let r1 = GeLU::default().forward_mut(t.clone());
         let r2 = gelu(t);
         assert_eq!(r1.array(), r2.array());
     }
 
+    #[test]
+    fn test_nn_activations_gelu_exact() {
+        let dev: TestDevice = Default::default();
+        let t = dev.tensor([-2.0, -1.0, 0.0, 1.0, 2.0]);
+        let r1 = GeLU { exact: true }.forward_mut(t.clone());
+        let r2 = gelu_exact(t);
+        assert_eq!(r1.array(), r2.array());
+    }
+
+    #[test]
+    fn test_nn_activations_mish() {
+        let dev: TestDevice = Default::default();
+        let t = dev.tensor([-2.0, -1.0, 0.0, 1.0, 2.0]);
+        let r1 = Mish.forward_mut(t.clone());
+        let r2 = mish(t);
+        assert_eq!(r1.array(), r2.array());
+    }
+
+    #[test]
+    fn test_nn_activations_elu() {
+        let dev: TestDevice = Default::default();
+        let t = dev.tensor([-2.0, -1.0, 0.0, 1.0, 2.0]);
+        let r1 = ELU { alpha: 0.5 }.forward_mut(t.clone());
+        let r2 = elu(t.clone(), 0.5);
+        assert_eq!(r1.array(), r2.array());
+        assert_eq!(
+            ELU::default().forward(t.clone()).array(),
+            t.elu(1.0).array()
+        );
+    }
+
+    #[test]
+    fn test_nn_activations_selu() {
+        let dev: TestDevice = Default::default();
+        let t = dev.tensor([-2.0, -1.0, 0.0, 1.0, 2.0]);
+        let r1 = SELU.forward_mut(t.clone());
+        let r2 = selu(t);
+        assert_eq!(r1.array(), r2.array());
+    }
+
+    #[test]
+    fn test_nn_activations_celu() {
+        let dev: TestDevice = Default::default();
+        let t = dev.tensor([-2.0, -1.0, 0.0, 1.0, 2.0]);
+        let r1 = CELU { alpha: 2.0 }.forward_mut(t.clone());
+        let r2 = celu(t, 2.0);
+        assert_eq!(r1.array(), r2.array());
+    }
+
+    #[test]
+    fn test_nn_activations_hardswish() {
+        let dev: TestDevice = Default::default();
+        let t = dev.tensor([-4.0, -1.0, 0.0, 1.0, 4.0]);
+        let r1 = HardSwish.forward_mut(t.clone());
+        let r2 = hardswish(t);
+        assert_eq!(r1.array(), r2.array());
+    }
+
+    #[test]
+    fn test_nn_activations_hardsigmoid() {
+        let dev: TestDevice = Default::default();
+        let t = dev.tensor([-4.0, -1.0, 0.0, 1.0, 4.0]);
+        let r1 = HardSigmoid.forward_mut(t.clone());
+        let r2 = hardsigmoid(t);
+        assert_eq!(r1.array(), r2.array());
+    }
+
+    #[test]
+    fn test_nn_activations_hardtanh() {
+        let dev: TestDevice = Default::default();
+        let t = dev.tensor([-4.0, -1.0, 0.0, 1.0, 4.0]);
+        let r1 = HardTanh.forward_mut(t.clone());
+        let r2 = hardtanh(t);
+        assert_eq!(r1.array(), r2.array());
+    }
+
     #[test]
     fn test_nn_activations_sin() {
         let dev: TestDevice = Default::default();
@@ -0,0 +1,333 @@
+use crate::{gradients::*, shapes::*, tensor::*, tensor_ops::*};
+
+use super::{tensor_collection::*, BuildModule, BuildOnDevice, Module, ModuleMut, ToDevice};
+
+pub mod builder {
+    #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+    pub struct BatchNorm3D<const C: usize>;
+}
+
+impl<const C: usize, E: Dtype, D: Device<E>> BuildOnDevice<D, E> for builder::BatchNorm3D<C>
+where
+    BatchNorm3D<C, E, D>: BuildModule<D, E>,
+{
+    type Built = BatchNorm3D<C, E, D>;
+    fn try_build_on_device(device: &D) -> Result<Self::Built, D::Err> {
+        Self::Built::try_build(device)
+    }
+}
+
+/// Batch normalization for volumes (e.g. videos or medical scans) as described in
+/// [Batch Normalization: Accelerating Deep Network Training
+/// by Reducing Internal Covariate Shift](https://arxiv.org/abs/1502.03167)
+///
+/// Generics:
+///
+/// - `C` the size of the channel dimension, which is the only one not reduced over. For 4d
+///   tensors this is the 0th dimension. For 5d tensors, this is the 1st dimension.
+///
+/// # Training vs Inference
+///
+/// BatchNorm3D supports the following cases (see sections below for more details):
+/// 1. **Training**: [ModuleMut] and [OwnedTape] on the input tensor
+/// 2. **Inference**: [Module] and [NoneTape] on the input tensor.
+///
+/// *NOTE: ModuleMut/NoneTape, and Module/OwnedTape will fail to compile.*
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// type Model = BatchNorm3D<3>;
+/// let bn = dev.build_module::<Model, f32>();
+/// let _ = bn.forward(dev.zeros::<Rank4<3, 2, 2, 2>>());
+/// let _ = bn.forward(dev.zeros::<Rank5<4, 3, 2, 2, 2>>());
+/// ```
+///
+/// ### Training
+/// - Running statistics: updated with momentum
+/// - Normalization: calculated using batch stats
+///
+/// ### Inference
+/// - Running statistics: **not** updated
+/// - Normalization: calculated using running stats
+#[derive(Clone, Debug)]
+pub struct BatchNorm3D<const C: usize, E: Dtype, D: DeviceStorage> {
+    /// Scale for affine transform. Defaults to 1.0
+    pub scale: Tensor<Rank1<C>, E, D>,
+    /// Bias for affine transform. Defaults to 0.0
+    pub bias: Tensor<Rank1<C>, E, D>,
+    /// Spatial mean that is updated during training. Defaults to 0.0
+    pub running_mean: Tensor<Rank1<C>, E, D>,
+    /// Spatial variance that is updated during training. Defaults to 1.0
+    pub running_var: Tensor<Rank1<C>, E, D>,
+    /// Added to variance before taking sqrt for numerical stability. Defaults to 1e-5
+    pub epsilon: E,
+    /// Controls exponential moving average of running stats.Defaults to 0.1
+    ///
+    /// `running_stat * (1.0 - momentum) + stat * momentum`.
+    pub momentum: E,
+}
+
+impl<const C: usize, E: Dtype, D: Device<E>> BatchNorm3D<C, E, D> {
+    /// generic forward for inference
+    fn infer_fwd<S: Shape, Ax: Axes>(&self, x: Tensor<S, E, D>) -> Result<Tensor<S, E, D>, D::Err>
+    where
+        Rank1<C>: BroadcastShapeTo<S, Ax>,
+    {
+        let shape = *x.shape();
+
+        // statistics for normalizing
+        let std = (self.running_var.clone() + self.epsilon).try_sqrt()?;
+        let mean = self.running_mean.clone();
+
+        // normalize & affine
+        let x = x.try_sub(mean.try_broadcast_like(&shape)?)?;
+        let x = x.try_div(std.try_broadcast_like(&shape)?)?;
+        let x = x.try_mul(self.scale.clone().try_broadcast_like(&shape)?)?;
+        x.try_add(self.bias.clone().try_broadcast_like(&shape)?)
+    }
+
+    fn train_fwd<S, T: Tape<D>, Ax: Axes>(
+        &mut self,
+        x: Tensor<S, E, D, T>,
+    ) -> Result<Tensor<S, E, D, T>, D::Err>
+    where
+        S: Shape + HasAxes<Ax> + ReduceShapeTo<Rank1<C>, Ax>,
+    {
+        let n = E::from_usize(<S as HasAxes<Ax>>::size(x.shape())).unwrap();
+        let shape = *x.shape();
+
+        // compute statistics for updating running stats later - on tape
+        let mean_chan = x.retaped::<T>().try_mean::<Rank1<C>, _>()?;
+
+        // update statistics since we are training - off tape
+        self.running_mean = self
+            .running_mean
+            .clone()
+            .try_mul(E::ONE - self.momentum)?
+            .try_add(mean_chan.retaped::<NoneTape>().try_mul(self.momentum)?)?;
+
+        let centered = x - mean_chan.try_broadcast_like(&shape)?;
+
+        let var_chan = centered.retaped::<T>().square().mean::<Rank1<C>, _>();
+
+        // NOTE: uses unbiased variance in running estimate
+        self.running_var = self
+            .running_var
+            .clone()
+            .try_mul(E::ONE - self.momentum)?
+            .try_add(
+                var_chan
+                    .retaped::<NoneTape>()
+                    .try_mul(self.momentum * n / (n - E::ONE))?,
+            )?;
+
+        // statistics for normalizing - on tape
+        let std = (var_chan + self.epsilon).try_sqrt()?;
+
+        // record broadcast of scale & bias - on tape
+        let scale = (self.scale.retaped::<T>() / std).try_broadcast_like(&shape)?;
+        let bias = self.bias.retaped::<T>().try_broadcast_like(&shape)?;
+
+        // normalize & affine - on tape
+        centered.try_mul(scale)?.try_add(bias)
+    }
+}
+
+impl<const C: usize, Z: Dim, H: Dim, W: Dim, E: Dtype, D: Device<E>>
+    Module<Tensor<(Const<C>, Z, H, W), E, D, NoneTape>> for BatchNorm3D<C, E, D>
+{
+    type Output = Tensor<(Const<C>, Z, H, W), E, D, NoneTape>;
+    type Error = D::Err;
+
+    /// Inference 4d forward - does **not** update [Self::running_mean] and [Self::running_var]
+    fn try_forward(
+        &self,
+        x: Tensor<(Const<C>, Z, H, W), E, D, NoneTape>,
+    ) -> Result<Self::Output, D::Err> {
+        self.infer_fwd(x)
+    }
+}
+
+impl<B: Dim, const C: usize, Z: Dim, H: Dim, W: Dim, E: Dtype, D: Device<E>>
+    Module<Tensor<(B, Const<C>, Z, H, W), E, D, NoneTape>> for BatchNorm3D<C, E, D>
+{
+    type Output = Tensor<(B, Const<C>, Z, H, W), E, D, NoneTape>;
+    type Error = D::Err;
+
+    /// Inference 5d forward - does **not** update [Self::running_mean] and [Self::running_var]
+    fn try_forward(
+        &self,
+        x: Tensor<(B, Const<C>, Z, H, W), E, D, NoneTape>,
+    ) -> Result<Self::Output, D::Err> {
+        self.infer_fwd(x)
+    }
+}
+
+impl<const C: usize, Z: Dim, H: Dim, W: Dim, E: Dtype, D: Device<E>>
+    ModuleMut<Tensor<(Const<C>, Z, H, W), E, D, OwnedTape<D>>> for BatchNorm3D<C, E, D>
+{
+    type Output = Tensor<(Const<C>, Z, H, W), E, D, OwnedTape<D>>;
+    type Error = D::Err;
+
+    /// Training 4d forward - updates [Self::running_mean] and [Self::running_var]
+    fn try_forward_mut(
+        &mut self,
+        x: Tensor<(Const<C>, Z, H, W), E, D, OwnedTape<D>>,
+    ) -> Result<Self::Output, D::Err> {
+        self.train_fwd(x)
+    }
+}
+
+impl<B: Dim, const C: usize, Z: Dim, H: Dim, W: Dim, E: Dtype, D: Device<E>>
+    ModuleMut<Tensor<(B, Const<C>, Z, H, W), E, D, OwnedTape<D>>> for BatchNorm3D<C, E, D>
+{
+    type Output = Tensor<(B, Const<C>, Z, H, W), E, D, OwnedTape<D>>;
+    type Error = D::Err;
+
+    /// Training 5d forward - updates [Self::running_mean] and [Self::running_var]
+    fn try_forward_mut(
+        &mut self,
+        x: Tensor<(B, Const<C>, Z, H, W), E, D, OwnedTape<D>>,
+    ) -> Result<Self::Output, D::Err> {
+        self.train_fwd(x)
+    }
+}
+
+impl<const C: usize, E: Dtype, D: Device<E>> BuildModule<D, E> for BatchNorm3D<C, E, D> {
+    fn try_build(device: &D) -> Result<Self, D::Err> {
+        Ok(Self {
+            scale: device.try_ones()?,
+            bias: device.try_zeros()?,
+            running_mean: device.try_zeros()?,
+            running_var: device.try_ones()?,
+            epsilon: E::from_f32(1e-5).unwrap(),
+            momentum: E::from_f32(0.1).unwrap(),
+        })
+    }
+}
+
+impl<const C: usize, E: Dtype, D: Device<E>> TensorCollection<E, D> for BatchNorm3D<C, E, D> {
+    fn iter_tensors<V: ModuleVisitor<Self, E, D>>(visitor: &mut V) -> Result<(), V::Err> {
+        visitor.visit_tensor(
+            "scale",
+            |s| &s.scale,
+            |s| &mut s.scale,
+            TensorOptions::reset_to_ones(),
+        )?;
+        visitor.visit_tensor(
+            "bias",
+            |s| &s.bias,
+            |s| &mut s.bias,
+            TensorOptions::reset_to_zeros(),
+        )?;
+        visitor.visit_tensor(
+            "running_mean",
+            |s| &s.running_mean,
+            |s| &mut s.running_mean,
+            TensorOptions::detached(|t| t.try_fill_with_zeros()),
+        )?;
+        visitor.visit_tensor(
+            "running_var",
+            |s| &s.running_var,
+            |s| &mut s.running_var,
+            TensorOptions::detached(|t| t.try_fill_with_ones()),
+        )
+    }
+}
+
+impl<const C: usize, E: Dtype, D1: Device<E>, D2: Device<E>> ToDevice<D2>
+    for BatchNorm3D<C, E, D1>
+{
+    type Output = BatchNorm3D<C, E, D2>;
+    fn to_device(&self, device: &D2) -> Self::Output {
+        BatchNorm3D {
+            scale: self.scale.to_device(device),
+            bias: self.bias.to_device(device),
+            running_mean: self.running_mean.to_device(device),
+            running_var: self.running_var.to_device(device),
+            epsilon: self.epsilon,
+            momentum: self.momentum,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::builder::BatchNorm3D;
+    use crate::{
+        nn::builders::BatchNorm2D, nn::*, optim::*, shapes::*, tensor::*, tensor_ops::*, tests::*,
+    };
+
+    #[test]
+    fn test_batchnorm3d_4d_matches_batchnorm2d() {
+        let dev = TestDevice::seed_from_u64(0);
+
+        let x: Tensor<Rank4<3, 2, 3, 2>, TestDtype, _> = dev.sample_normal();
+        let mut bn3 = dev.build_module::<BatchNorm3D<3>, TestDtype>();
+        let mut bn2 = dev.build_module::<BatchNorm2D<3>, TestDtype>();
+
+        let y3 = bn3.forward_mut(x.trace());
+        let y2 = bn2.forward_mut(
+            x.trace()
+                .reshape_like(&(Const::<3>, Const::<6>, Const::<2>)),
+        );
+        assert_close(&y3.as_vec(), &y2.as_vec());
+        assert_close(&bn3.running_mean.array(), &bn2.running_mean.array());
+        assert_close(&bn3.running_var.array(), &bn2.running_var.array());
+
+        let g3 = y3.exp().mean().backward();
+        let g2 = y2.exp().mean().backward();
+        assert_close(&g3.get(&bn3.scale).array(), &g2.get(&bn2.scale).array());
+        assert_close(&g3.get(&bn3.bias).array(), &g2.get(&bn2.bias).array());
+        assert_close(&g3.get(&x).as_vec(), &g2.get(&x).as_vec());
+
+        // inference uses the running statistics, and doesn't update them
+        let x2: Tensor<Rank4<3, 2, 3, 2>, TestDtype, _> = dev.sample_normal();
+        let y3 = bn3.forward(x2.clone());
+        let y2 = bn2.forward(x2.reshape_like(&(Const::<3>, Const::<6>, Const::<2>)));
+        assert_close(&y3.as_vec(), &y2.as_vec());
+        assert_close(&bn3.running_mean.array(), &bn2.running_mean.array());
+    }
+
+    #[test]
+    fn test_batchnorm3d_5d_matches_batchnorm2d() {
+        let dev = TestDevice::seed_from_u64(2);
+
+        let x: Tensor<Rank5<2, 2, 3, 2, 2>, TestDtype, _> = dev.sample_normal();
+        let mut bn3 = dev.build_module::<BatchNorm3D<2>, TestDtype>();
+        let mut bn2 = dev.build_module::<BatchNorm2D<2>, TestDtype>();
+
+        for _ in 0..2 {
+            let y3 = bn3.forward_mut(x.trace());
+            let y2 = bn2.forward_mut(
+                x.trace()
+                    .reshape_like(&(Const::<2>, Const::<2>, Const::<6>, Const::<2>)),
+            );
+            assert_close(&y3.as_vec(), &y2.as_vec());
+            let g3 = y3.square().mean().backward();
+            let g2 = y2.square().mean().backward();
+            assert_close(&g3.get(&x).as_vec(), &g2.get(&x).as_vec());
+        }
+        assert_close(&bn3.running_mean.array(), &bn2.running_mean.array());
+        assert_close(&bn3.running_var.array(), &bn2.running_var.array());
+
+        let y3 = bn3.forward(x.clone());
+        let y2 = bn2.forward(x.reshape_like(&(Const::<2>, Const::<2>, Const::<6>, Const::<2>)));
+        assert_close(&y3.as_vec(), &y2.as_vec());
+    }
+
+    #[test]
+    fn test_batchnorm3d_update() {
+        let dev: TestDevice = Default::default();
+
+        let x1: Tensor<Rank5<2, 3, 2, 4, 5>, TestDtype, _> = dev.sample_normal();
+        let mut bn = dev.build_module::<BatchNorm3D<3>, TestDtype>();
+        let y = bn.forward_mut(x1.trace());
+        let g = y.square().mean().backward();
+
+        let mut opt = Sgd::new(&bn, Default::default());
+        opt.update(&mut bn, g).expect("");
+    }
+}
@@ -0,0 +1,261 @@
+use crate::{gradients::Tape, shapes::*, tensor::*, tensor_ops::*};
+
+use super::{tensor_collection::*, BuildModule, BuildOnDevice, Module, ModuleMut, ToDevice};
+
+use std::vec::Vec;
+
+/// Runs two copies of a recurrent module `M` over a sequence, one in the original
+/// order and one over the time reversed sequence, and concatenates their outputs along
+/// the last axis. The output of the reversed copy is flipped back, so both halves
+/// are aligned with the input steps.
+///
+/// The time axis is the first axis of unbatched `(S, I)` inputs and the second axis of
+/// batched `(B, S, I)` inputs. The concatenated axis is a [usize] dim of twice the
+/// output size of `M`.
+///
+/// **Pytorch equivalent**: `torch.nn.RNN(..., bidirectional=True, batch_first=True)`
+///
+/// # Generics
+/// - `M`: The recurrent module to run in both directions.
+///
+/// # Examples
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// type Model = Bidirectional<RNN<3, 5>>;
+/// let model = dev.build_module::<Model, f32>();
+/// let y: Tensor<(Const<4>, Const<7>, usize), f32, _> = model.forward(dev.zeros::<Rank3<4, 7, 3>>());
+/// assert_eq!(y.shape(), &(Const, Const, 10));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Bidirectional<M> {
+    /// Runs over the sequence in order
+    pub fwd: M,
+
+    /// Runs over the reversed sequence
+    pub bwd: M,
+}
+
+impl<D: DeviceStorage, E: Dtype, M: BuildOnDevice<D, E>> BuildOnDevice<D, E> for Bidirectional<M> {
+    type Built = Bidirectional<M::Built>;
+}
+
+impl<D: DeviceStorage, E: Dtype, M: BuildModule<D, E>> BuildModule<D, E> for Bidirectional<M> {
+    fn try_build(device: &D) -> Result<Self, <D>::Err> {
+        Ok(Self {
+            fwd: BuildModule::try_build(device)?,
+            bwd: BuildModule::try_build(device)?,
+        })
+    }
+}
+
+impl<E: Dtype, D: DeviceStorage, M: TensorCollection<E, D>> TensorCollection<E, D>
+    for Bidirectional<M>
+{
+    fn iter_tensors<V: ModuleVisitor<Self, E, D>>(visitor: &mut V) -> Result<(), V::Err> {
+        visitor.visit_module("fwd", |s| &s.fwd, |s| &mut s.fwd)?;
+        visitor.visit_module("bwd", |s| &s.bwd, |s| &mut s.bwd)
+    }
+}
+
+impl<M: ToDevice<D>, D> ToDevice<D> for Bidirectional<M> {
+    type Output = Bidirectional<M::Output>;
+    fn to_device(&self, device: &D) -> Self::Output {
+        Bidirectional {
+            fwd: self.fwd.to_device(device),
+            bwd: self.bwd.to_device(device),
+        }
+    }
+}
+
+/// Calls `fwd` on `x` and `bwd` on `x` reversed along `TimeAx`, and concatenates the
+/// results along `CatAx` after reversing the output of `bwd` back.
+#[allow(clippy::type_complexity)]
+fn try_bidirectional<TimeAx: Axes, CatAx: Default, S, O, E: Dtype, D, T>(
+    x: Tensor<S, E, D, T>,
+    fwd: impl FnOnce(Tensor<S, E, D, T>) -> Result<Tensor<O, E, D, T>, D::Err>,
+    bwd: impl FnOnce(Tensor<S, E, D, T>) -> Result<Tensor<O, E, D, T>, D::Err>,
+) -> Result<<Vec<Tensor<O, E, D, T>> as TryConcatAlong<CatAx>>::Output, D::Err>
+where
+    D: DeviceStorage,
+    T: Tape<D>,
+    S: Shape + HasAxes<TimeAx>,
+    O: Shape + HasAxes<TimeAx>,
+    Tensor<S, E, D, T>: TryFlip + HasShape<Shape = S> + HasErr<Err = D::Err>,
+    Tensor<O, E, D, T>: TryFlip + HasShape<Shape = O> + HasErr<Err = D::Err>,
+    Vec<Tensor<O, E, D, T>>: TryConcatAlong<CatAx, Err = D::Err>,
+{
+    // the reversed copy gets a fresh tape, so its ops are merged after the ones that produced `x`
+    let x_rev = x.retaped::<T>().try_flip::<TimeAx>()?;
+    let y_fwd = fwd(x)?;
+    let y_bwd = bwd(x_rev)?.try_flip::<TimeAx>()?;
+    alloc::vec![y_fwd, y_bwd].try_concat_along(Default::default())
+}
+
+impl<S: Dim, I: Dim, O: Dim, E: Dtype, D: DeviceStorage, T: Tape<D>, M>
+    Module<Tensor<(S, I), E, D, T>> for Bidirectional<M>
+where
+    M: Module<Tensor<(S, I), E, D, T>, Output = Tensor<(S, O), E, D, T>, Error = D::Err>,
+    Tensor<(S, I), E, D, T>: TryFlip + HasShape<Shape = (S, I)> + HasErr<Err = D::Err>,
+    Tensor<(S, O), E, D, T>: TryFlip + HasShape<Shape = (S, O)> + HasErr<Err = D::Err>,
+    Vec<Tensor<(S, O), E, D, T>>:
+        TryConcatAlong<Axis<1>, Output = Tensor<(S, usize), E, D, T>, Err = D::Err>,
+{
+    type Output = Tensor<(S, usize), E, D, T>;
+    type Error = D::Err;
+
+    fn try_forward(&self, x: Tensor<(S, I), E, D, T>) -> Result<Self::Output, D::Err> {
+        try_bidirectional::<Axis<0>, Axis<1>, _, _, _, _, _>(
+            x,
+            |x| self.fwd.try_forward(x),
+            |x| self.bwd.try_forward(x),
+        )
+    }
+}
+
+impl<S: Dim, I: Dim, O: Dim, E: Dtype, D: DeviceStorage, T: Tape<D>, M>
+    ModuleMut<Tensor<(S, I), E, D, T>> for Bidirectional<M>
+where
+    M: ModuleMut<Tensor<(S, I), E, D, T>, Output = Tensor<(S, O), E, D, T>, Error = D::Err>,
+    Tensor<(S, I), E, D, T>: TryFlip + HasShape<Shape = (S, I)> + HasErr<Err = D::Err>,
+    Tensor<(S, O), E, D, T>: TryFlip + HasShape<Shape = (S, O)> + HasErr<Err = D::Err>,
+    Vec<Tensor<(S, O), E, D, T>>:
+        TryConcatAlong<Axis<1>, Output = Tensor<(S, usize), E, D, T>, Err = D::Err>,
+{
+    type Output = Tensor<(S, usize), E, D, T>;
+    type Error = D::Err;
+
+    fn try_forward_mut(&mut self, x: Tensor<(S, I), E, D, T>) -> Result<Self::Output, D::Err> {
+        try_bidirectional::<Axis<0>, Axis<1>, _, _, _, _, _>(
+            x,
+            |x| self.fwd.try_forward_mut(x),
+            |x| self.bwd.try_forward_mut(x),
+        )
+    }
+}
+
+impl<B: Dim, S: Dim, I: Dim, O: Dim, E: Dtype, D: DeviceStorage, T: Tape<D>, M>
+    Module<Tensor<(B, S, I), E, D, T>> for Bidirectional<M>
+where
+    M: Module<Tensor<(B, S, I), E, D, T>, Output = Tensor<(B, S, O), E, D, T>, Error = D::Err>,
+    Tensor<(B, S, I), E, D, T>: TryFlip + HasShape<Shape = (B, S, I)> + HasErr<Err = D::Err>,
+    Tensor<(B, S, O), E, D, T>: TryFlip + HasShape<Shape = (B, S, O)> + HasErr<Err = D::Err>,
+    Vec<Tensor<(B, S, O), E, D, T>>:
+        TryConcatAlong<Axis<2>, Output = Tensor<(B, S, usize), E, D, T>, Err = D::Err>,
+{
+    type Output = Tensor<(B, S, usize), E, D, T>;
+    type Error = D::Err;
+
+    fn try_forward(&self, x: Tensor<(B, S, I), E, D, T>) -> Result<Self::Output, D::Err> {
+        try_bidirectional::<Axis<1>, Axis<2>, _, _, _, _, _>(
+            x,
+            |x| self.fwd.try_forward(x),
+            |x| self.bwd.try_forward(x),
+        )
+    }
+}
+
+impl<B: Dim, S: Dim, I: Dim, O: Dim, E: Dtype, D: DeviceStorage, T: Tape<D>, M>
+    ModuleMut<Tensor<(B, S, I), E, D, T>> for Bidirectional<M>
+where
+    M: ModuleMut<Tensor<(B, S, I), E, D, T>, Output = Tensor<(B, S, O), E, D, T>, Error = D::Err>,
+    Tensor<(B, S, I), E, D, T>: TryFlip + HasShape<Shape = (B, S, I)> + HasErr<Err = D::Err>,
+    Tensor<(B, S, O), E, D, T>: TryFlip + HasShape<Shape = (B, S, O)> + HasErr<Err = D::Err>,
+    Vec<Tensor<(B, S, O), E, D, T>>:
+        TryConcatAlong<Axis<2>, Output = Tensor<(B, S, usize), E, D, T>, Err = D::Err>,
+{
+    type Output = Tensor<(B, S, usize), E, D, T>;
+    type Error = D::Err;
+
+    fn try_forward_mut(&mut self, x: Tensor<(B, S, I), E, D, T>) -> Result<Self::Output, D::Err> {
+        try_bidirectional::<Axis<1>, Axis<2>, _, _, _, _, _>(
+            x,
+            |x| self.fwd.try_forward_mut(x),
+            |x| self.bwd.try_forward_mut(x),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        nn::{builders::*, DeviceBuildExt},
+        tests::*,
+    };
+
+    #[test]
+    fn test_bidirectional_reset() {
+        let dev: TestDevice = Default::default();
+        let model = dev.build_module::<Bidirectional<RNN<2, 3>>, TestDtype>();
+        assert_ne!(model.fwd.first.weight_ih.array(), [[0.0; 2]; 3]);
+        assert_ne!(model.bwd.first.weight_ih.array(), [[0.0; 2]; 3]);
+        assert_ne!(
+            model.fwd.first.weight_ih.array(),
+            model.bwd.first.weight_ih.array()
+        );
+    }
+
+    #[test]
+    fn test_bidirectional_matches_manual() {
+        let dev: TestDevice = Default::default();
+        let model = dev.build_module::<Bidirectional<RNN<3, 4, 2>>, TestDtype>();
+        let x: Tensor<Rank3<2, 5, 3>, TestDtype, _> = dev.sample_normal();
+
+        let y = model.forward(x.trace());
+        assert_eq!(y.shape(), &(Const, Const, 8));
+        let y_vec = y.as_vec();
+        let g = y.exp().mean().backward();
+
+        // run each direction separately, reversing the time axis of the input by hand
+        let scale: TestDtype = 1.0 / 80.0;
+        let y_fwd = model.fwd.forward(x.trace());
+        let y_fwd_vec = y_fwd.as_vec();
+        let g_fwd = (y_fwd.exp().sum() * scale).backward();
+        let x_rev = x.clone().flip::<Axis<1>>();
+        let y_bwd = model.bwd.forward(x_rev.trace()).flip::<Axis<1>>();
+        let y_bwd_vec = y_bwd.as_vec();
+        let g_bwd = (y_bwd.exp().sum() * scale).backward();
+
+        for i in 0..10 {
+            assert_close(
+                &y_vec[i * 8..i * 8 + 4].to_vec(),
+                &y_fwd_vec[i * 4..(i + 1) * 4].to_vec(),
+            );
+            assert_close(
+                &y_vec[i * 8 + 4..(i + 1) * 8].to_vec(),
+                &y_bwd_vec[i * 4..(i + 1) * 4].to_vec(),
+            );
+        }
+
+        assert_close(
+            &g.get(&model.fwd.first.weight_ih).array(),
+            &g_fwd.get(&model.fwd.first.weight_ih).array(),
+        );
+        assert_close(
+            &g.get(&model.bwd.rest[0].weight_hh).array(),
+            &g_bwd.get(&model.bwd.rest[0].weight_hh).array(),
+        );
+        let g_x = g.get(&x).array();
+        let g_x_fwd = g_fwd.get(&x).array();
+        let g_x_rev = g_bwd.get(&x_rev).array();
+        for b in 0..2 {
+            for t in 0..5 {
+                for i in 0..3 {
+                    assert_close(&g_x[b][t][i], &(g_x_fwd[b][t][i] + g_x_rev[b][4 - t][i]));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_bidirectional_unbatched_matches_batched() {
+        let dev: TestDevice = Default::default();
+        let model = dev.build_module::<Bidirectional<RNN<3, 4>>, TestDtype>();
+        let x: Tensor<Rank2<5, 3>, TestDtype, _> = dev.sample_normal();
+        let y = model.forward(x.clone());
+        assert_eq!(y.shape(), &(Const, 8));
+        let y_batched = model.forward(x.broadcast::<Rank3<1, 5, 3>, _>());
+        assert_close(&y.as_vec(), &y_batched.as_vec());
+    }
+}
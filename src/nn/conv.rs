@@ -13,17 +13,29 @@ pub mod builder {
         const KERNEL_SIZE: usize,
         const STRIDE: usize = 1,
         const PADDING: usize = 0,
+        const GROUPS: usize = 1,
+        const DILATION: usize = 1,
     >;
 }
 
-impl<const I: usize, const O: usize, const K: usize, const S: usize, const P: usize, E, D>
-    BuildOnDevice<D, E> for builder::Conv2D<I, O, K, S, P>
+impl<
+        const I: usize,
+        const O: usize,
+        const K: usize,
+        const S: usize,
+        const P: usize,
+        const G: usize,
+        const L: usize,
+        E,
+        D,
+    > BuildOnDevice<D, E> for builder::Conv2D<I, O, K, S, P, G, L>
 where
     E: Dtype,
     D: Device<E>,
-    Conv2D<I, O, K, S, P, E, D>: BuildModule<D, E>,
+    Const<{ I / G }>: Sized,
+    Conv2D<I, O, K, S, P, G, L, E, D>: BuildModule<D, E>,
 {
-    type Built = Conv2D<I, O, K, S, P, E, D>;
+    type Built = Conv2D<I, O, K, S, P, G, L, E, D>;
     fn try_build_on_device(device: &D) -> Result<Self::Built, <D>::Err> {
         Self::Built::try_build(device)
     }
@@ -45,6 +57,16 @@ where
 /// - `KERNEL_SIZE`: The size of the kernel applied to both width and height of the images.
 /// - `STRIDE`: How far to move the kernel each step. Defaults to `1`
 /// - `PADDING`: How much zero padding to add around the images. Defaults to `0`.
+/// - `GROUPS`: The number of groups the input & output channels are split into. Each group of
+///   `OUT_CHAN / GROUPS` output channels only sees `IN_CHAN / GROUPS` input channels.
+///   Both `IN_CHAN` and `OUT_CHAN` must be divisible by it. Defaults to `1`.
+/// - `DILATION`: The spacing between kernel elements. Defaults to `1`.
+///
+/// Depthwise convolutions use `GROUPS = IN_CHAN`:
+/// ```ignore
+/// # use dfdx::prelude::*;
+/// type DepthwiseConv = Conv2D<8, 8, 3, 1, 1, 8>;
+/// ```
 #[derive(Debug, Clone)]
 pub struct Conv2D<
     const IN_CHAN: usize,
@@ -52,17 +74,31 @@ pub struct Conv2D<
     const KERNEL_SIZE: usize,
     const STRIDE: usize,
     const PADDING: usize,
+    const GROUPS: usize,
+    const DILATION: usize,
     E: Dtype,
     D: DeviceStorage,
-> {
-    pub weight: Tensor<Rank4<OUT_CHAN, IN_CHAN, KERNEL_SIZE, KERNEL_SIZE>, E, D>,
+> where
+    Const<{ IN_CHAN / GROUPS }>: Sized,
+{
+    pub weight: Tensor<Rank4<OUT_CHAN, { IN_CHAN / GROUPS }, KERNEL_SIZE, KERNEL_SIZE>, E, D>,
 }
 
-impl<const I: usize, const O: usize, const K: usize, const S: usize, const P: usize, E, D>
-    TensorCollection<E, D> for Conv2D<I, O, K, S, P, E, D>
+impl<
+        const I: usize,
+        const O: usize,
+        const K: usize,
+        const S: usize,
+        const P: usize,
+        const G: usize,
+        const L: usize,
+        E,
+        D,
+    > TensorCollection<E, D> for Conv2D<I, O, K, S, P, G, L, E, D>
 where
     E: Dtype + Float + SampleUniform,
     D: Device<E>,
+    Const<{ I / G }>: Sized,
 {
     fn iter_tensors<V: ModuleVisitor<Self, E, D>>(visitor: &mut V) -> Result<(), V::Err> {
         visitor.visit_tensor(
@@ -70,21 +106,31 @@ where
             |s| &s.weight,
             |s| &mut s.weight,
             TensorOptions::reset_with(|t| {
-                let b = E::ONE / E::from_usize(I * K * K).unwrap().sqrt();
+                let b = E::ONE / E::from_usize(I / G * K * K).unwrap().sqrt();
                 t.try_fill_with_distr(rand_distr::Uniform::new(-b, b))
             }),
         )
     }
 }
 
-impl<const I: usize, const O: usize, const K: usize, const S: usize, const P: usize, E, D>
-    BuildModule<D, E> for Conv2D<I, O, K, S, P, E, D>
+impl<
+        const I: usize,
+        const O: usize,
+        const K: usize,
+        const S: usize,
+        const P: usize,
+        const G: usize,
+        const L: usize,
+        E,
+        D,
+    > BuildModule<D, E> for Conv2D<I, O, K, S, P, G, L, E, D>
 where
     E: Dtype + Float + SampleUniform,
     D: Device<E>,
+    Const<{ I / G }>: Sized,
 {
     fn try_build(device: &D) -> Result<Self, <D>::Err> {
-        let k = E::from_usize(I * K * K).unwrap();
+        let k = E::from_usize(I / G * K * K).unwrap();
         let bound = E::ONE / k.sqrt();
         Ok(Self {
             weight: device.try_sample(rand_distr::Uniform::new(-bound, bound))?,
@@ -92,14 +138,25 @@ where
     }
 }
 
-impl<const I: usize, const O: usize, const K: usize, const S: usize, const P: usize, E, D1, D2>
-    ToDevice<D2> for Conv2D<I, O, K, S, P, E, D1>
+impl<
+        const I: usize,
+        const O: usize,
+        const K: usize,
+        const S: usize,
+        const P: usize,
+        const G: usize,
+        const L: usize,
+        E,
+        D1,
+        D2,
+    > ToDevice<D2> for Conv2D<I, O, K, S, P, G, L, E, D1>
 where
     E: Dtype,
     D1: Device<E>,
     D2: Device<E>,
+    Const<{ I / G }>: Sized,
 {
-    type Output = Conv2D<I, O, K, S, P, E, D2>;
+    type Output = Conv2D<I, O, K, S, P, G, L, E, D2>;
 
     fn to_device(&self, device: &D2) -> Self::Output {
         Conv2D {
@@ -108,13 +165,23 @@ where
     }
 }
 
-#[cfg(feature = "nightly")]
-impl<const C: usize, const O: usize, const K: usize, const S: usize, const P: usize, E, D, Img>
-    super::Module<Img> for Conv2D<C, O, K, S, P, E, D>
+impl<
+        const C: usize,
+        const O: usize,
+        const K: usize,
+        const S: usize,
+        const P: usize,
+        const G: usize,
+        const L: usize,
+        E,
+        D,
+        Img,
+    > super::Module<Img> for Conv2D<C, O, K, S, P, G, L, E, D>
 where
     E: Dtype,
     D: Device<E>,
-    Img: TryConv2DTo<Tensor<Rank4<O, C, K, K>, E, D>, S, P> + HasErr<Err = D::Err>,
+    Const<{ C / G }>: Sized,
+    Img: TryConv2DTo<Tensor<Rank4<O, { C / G }, K, K>, E, D>, S, P, L> + HasErr<Err = D::Err>,
 {
     type Output = Img::Output;
     type Error = D::Err;
@@ -124,15 +191,24 @@ where
     }
 }
 
-impl<const I: usize, const O: usize, const K: usize, const S: usize, const P: usize, E, D>
-    NonMutableModule for Conv2D<I, O, K, S, P, E, D>
+impl<
+        const I: usize,
+        const O: usize,
+        const K: usize,
+        const S: usize,
+        const P: usize,
+        const G: usize,
+        const L: usize,
+        E,
+        D,
+    > NonMutableModule for Conv2D<I, O, K, S, P, G, L, E, D>
 where
     E: Dtype,
     D: DeviceStorage,
+    Const<{ I / G }>: Sized,
 {
 }
 
-#[cfg(feature = "nightly")]
 #[cfg(test)]
 mod tests {
     use crate::{
@@ -198,6 +274,31 @@ mod tests {
             .forward_mut(dev.zeros::<Rank3<1, 10, 10>>());
     }
 
+    #[rustfmt::skip]
+    #[test]
+    fn test_forward_grouped_sizes() {
+        let dev: TestDevice = Default::default();
+        let x = dev.zeros::<Rank4<5, 4, 10, 10>>();
+        let m = dev.build_module::<Conv2D<4, 6, 3, 1, 0, 2>, TestDtype>();
+        let _: Tensor<Rank4<6, 2, 3, 3>, _, _> = m.weight.clone();
+        let _: Tensor<Rank4<5, 6, 8, 8>, _, _, _> = m.forward(x.clone());
+        let m = dev.build_module::<Conv2D<4, 4, 3, 1, 1, 4>, TestDtype>();
+        let _: Tensor<Rank4<4, 1, 3, 3>, _, _> = m.weight.clone();
+        let _: Tensor<Rank4<5, 4, 10, 10>, _, _, _> = m.forward(x.clone());
+        let _: Tensor<Rank3<8, 8, 8>, _, _, _> = dev.build_module::<Conv2D<4, 8, 3, 1, 0, 4>, TestDtype>().forward(dev.zeros::<Rank3<4, 10, 10>>());
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn test_forward_dilated_sizes() {
+        let dev: TestDevice = Default::default();
+        let x = dev.zeros::<Rank4<5, 3, 10, 10>>();
+        let _: Tensor<Rank4<5, 2, 6, 6>, _, _, _> = dev.build_module::<Conv2D<3, 2, 3, 1, 0, 1, 2>, TestDtype>().forward(x.clone());
+        let _: Tensor<Rank4<5, 2, 4, 4>, _, _, _> = dev.build_module::<Conv2D<3, 2, 3, 1, 0, 1, 3>, TestDtype>().forward(x.clone());
+        let _: Tensor<Rank4<5, 2, 10, 10>, _, _, _> = dev.build_module::<Conv2D<3, 2, 3, 1, 2, 1, 2>, TestDtype>().forward(x.clone());
+        let _: Tensor<Rank4<5, 2, 4, 4>, _, _, _> = dev.build_module::<Conv2D<3, 2, 3, 2, 1, 1, 2>, TestDtype>().forward(x.clone());
+    }
+
     #[test]
     fn test_conv_with_optimizer() {
         let dev: TestDevice = Default::default();
@@ -0,0 +1,184 @@
+use num_traits::Float;
+use rand_distr::uniform::SampleUniform;
+
+use crate::{shapes::*, tensor::*, tensor_ops::*};
+
+use super::{tensor_collection::*, BuildModule, BuildOnDevice, NonMutableModule, ToDevice};
+
+pub mod builder {
+    #[derive(Debug)]
+    pub struct Conv1D<
+        const IN_CHAN: usize,
+        const OUT_CHAN: usize,
+        const KERNEL_SIZE: usize,
+        const STRIDE: usize = 1,
+        const PADDING: usize = 0,
+    >;
+}
+
+impl<const I: usize, const O: usize, const K: usize, const S: usize, const P: usize, E, D>
+    BuildOnDevice<D, E> for builder::Conv1D<I, O, K, S, P>
+where
+    E: Dtype,
+    D: Device<E>,
+    Conv1D<I, O, K, S, P, E, D>: BuildModule<D, E>,
+{
+    type Built = Conv1D<I, O, K, S, P, E, D>;
+    fn try_build_on_device(device: &D) -> Result<Self::Built, <D>::Err> {
+        Self::Built::try_build(device)
+    }
+}
+
+/// **Requires Nightly** Performs *unbiased* 1d convolutions on 2d and 3d sequences
+/// of shape `(IN_CHAN, Length)` or `(Batch, IN_CHAN, Length)`.
+///
+/// **Pytorch Equivalent**: `torch.nn.Conv1d(..., bias=False)`
+///
+/// Generics:
+/// - `IN_CHAN`: The number of input channels in a sequence.
+/// - `OUT_CHAN`: The number of channels in the output of the layer.
+/// - `KERNEL_SIZE`: The size of the kernel applied along the sequence.
+/// - `STRIDE`: How far to move the kernel each step. Defaults to `1`
+/// - `PADDING`: How much zero padding to add to both ends of the sequence. Defaults to `0`.
+#[derive(Debug, Clone)]
+pub struct Conv1D<
+    const IN_CHAN: usize,
+    const OUT_CHAN: usize,
+    const KERNEL_SIZE: usize,
+    const STRIDE: usize,
+    const PADDING: usize,
+    E: Dtype,
+    D: DeviceStorage,
+> {
+    pub weight: Tensor<Rank3<OUT_CHAN, IN_CHAN, KERNEL_SIZE>, E, D>,
+}
+
+impl<const I: usize, const O: usize, const K: usize, const S: usize, const P: usize, E, D>
+    TensorCollection<E, D> for Conv1D<I, O, K, S, P, E, D>
+where
+    E: Dtype + Float + SampleUniform,
+    D: Device<E>,
+{
+    fn iter_tensors<V: ModuleVisitor<Self, E, D>>(visitor: &mut V) -> Result<(), V::Err> {
+        visitor.visit_tensor(
+            "weight",
+            |s| &s.weight,
+            |s| &mut s.weight,
+            TensorOptions::reset_with(|t| {
+                let b = E::ONE / E::from_usize(I * K).unwrap().sqrt();
+                t.try_fill_with_distr(rand_distr::Uniform::new(-b, b))
+            }),
+        )
+    }
+}
+
+impl<const I: usize, const O: usize, const K: usize, const S: usize, const P: usize, E, D>
+    BuildModule<D, E> for Conv1D<I, O, K, S, P, E, D>
+where
+    E: Dtype + Float + SampleUniform,
+    D: Device<E>,
+{
+    fn try_build(device: &D) -> Result<Self, <D>::Err> {
+        let k = E::from_usize(I * K).unwrap();
+        let bound = E::ONE / k.sqrt();
+        Ok(Self {
+            weight: device.try_sample(rand_distr::Uniform::new(-bound, bound))?,
+        })
+    }
+}
+
+impl<const I: usize, const O: usize, const K: usize, const S: usize, const P: usize, E, D1, D2>
+    ToDevice<D2> for Conv1D<I, O, K, S, P, E, D1>
+where
+    E: Dtype,
+    D1: Device<E>,
+    D2: Device<E>,
+{
+    type Output = Conv1D<I, O, K, S, P, E, D2>;
+
+    fn to_device(&self, device: &D2) -> Self::Output {
+        Conv1D {
+            weight: self.weight.to_device(device),
+        }
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<const C: usize, const O: usize, const K: usize, const S: usize, const P: usize, E, D, Img>
+    super::Module<Img> for Conv1D<C, O, K, S, P, E, D>
+where
+    E: Dtype,
+    D: Device<E>,
+    Img: TryConv1DTo<Tensor<Rank3<O, C, K>, E, D>, S, P> + HasErr<Err = D::Err>,
+{
+    type Output = Img::Output;
+    type Error = D::Err;
+
+    fn try_forward(&self, x: Img) -> Result<Self::Output, D::Err> {
+        x.try_conv1d_to(self.weight.clone())
+    }
+}
+
+impl<const I: usize, const O: usize, const K: usize, const S: usize, const P: usize, E, D>
+    NonMutableModule for Conv1D<I, O, K, S, P, E, D>
+where
+    E: Dtype,
+    D: DeviceStorage,
+{
+}
+
+#[cfg(feature = "nightly")]
+#[cfg(test)]
+mod tests {
+    use crate::{
+        nn::{DeviceBuildExt, Module},
+        optim::*,
+        tensor::{AsArray, SampleTensor, ZerosTensor},
+        tests::*,
+    };
+
+    use super::{builder::Conv1D, *};
+
+    #[rustfmt::skip]
+    #[test]
+    fn test_forward_2d_sizes() {
+        let dev: TestDevice = Default::default();
+        let x = dev.zeros::<Rank2<3, 10>>();
+        let _: Tensor<Rank2<2, 8>, _, _, _> = dev.build_module::<Conv1D<3, 2, 3>, TestDtype>().forward(x.clone());
+        let _: Tensor<Rank2<4, 9>, _, _, _> = dev.build_module::<Conv1D<3, 4, 2>, TestDtype>().forward(x.clone());
+        let _: Tensor<Rank2<2, 4>, _, _, _> = dev.build_module::<Conv1D<3, 2, 3, 2>, TestDtype>().forward(x.clone());
+        let _: Tensor<Rank2<2, 10>, _, _, _> = dev.build_module::<Conv1D<3, 2, 3, 1, 1>, TestDtype>().forward(x.clone());
+        let _: Tensor<Rank2<2, 6>, _, _, _> = dev.build_module::<Conv1D<3, 2, 3, 2, 2>, TestDtype>().forward(x.clone());
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn test_forward_3d_sizes() {
+        let dev: TestDevice = Default::default();
+        let x = dev.zeros::<Rank3<5, 3, 10>>();
+        let _: Tensor<Rank3<5, 2, 8>, _, _, _> = dev.build_module::<Conv1D<3, 2, 3>, TestDtype>().forward(x.clone());
+        let _: Tensor<Rank3<5, 4, 9>, _, _, _> = dev.build_module::<Conv1D<3, 4, 2>, TestDtype>().forward(x.clone());
+        let _: Tensor<Rank3<5, 2, 4>, _, _, _> = dev.build_module::<Conv1D<3, 2, 3, 2>, TestDtype>().forward(x.clone());
+        let _: Tensor<Rank3<5, 2, 10>, _, _, _> = dev.build_module::<Conv1D<3, 2, 3, 1, 1>, TestDtype>().forward(x.clone());
+        let _: Tensor<Rank3<5, 2, 6>, _, _, _> = dev.build_module::<Conv1D<3, 2, 3, 2, 2>, TestDtype>().forward(x.clone());
+    }
+
+    #[test]
+    fn test_conv1d_with_optimizer() {
+        let dev: TestDevice = Default::default();
+
+        let mut m = dev.build_module::<Conv1D<2, 4, 3>, TestDtype>();
+
+        let weight_init = m.weight.clone();
+
+        let mut opt = Sgd::new(&m, Default::default());
+        let out = m.forward(dev.sample_normal::<Rank3<8, 2, 28>>().traced());
+        let g = out.square().mean().backward();
+
+        assert_ne!(g.get(&m.weight).array(), [[[0.0; 3]; 2]; 4]);
+
+        opt.update(&mut m, g).expect("unused params");
+
+        assert_ne!(weight_init.array(), m.weight.array());
+    }
+}
@@ -0,0 +1,182 @@
+use num_traits::Float;
+use rand_distr::uniform::SampleUniform;
+
+use crate::{shapes::*, tensor::*, tensor_ops::*};
+
+use super::{tensor_collection::*, BuildModule, BuildOnDevice, NonMutableModule, ToDevice};
+
+pub mod builder {
+    #[derive(Debug)]
+    pub struct Conv3D<
+        const IN_CHAN: usize,
+        const OUT_CHAN: usize,
+        const KERNEL_SIZE: usize,
+        const STRIDE: usize = 1,
+        const PADDING: usize = 0,
+    >;
+}
+
+impl<const I: usize, const O: usize, const K: usize, const S: usize, const P: usize, E, D>
+    BuildOnDevice<D, E> for builder::Conv3D<I, O, K, S, P>
+where
+    E: Dtype,
+    D: Device<E>,
+    Conv3D<I, O, K, S, P, E, D>: BuildModule<D, E>,
+{
+    type Built = Conv3D<I, O, K, S, P, E, D>;
+    fn try_build_on_device(device: &D) -> Result<Self::Built, <D>::Err> {
+        Self::Built::try_build(device)
+    }
+}
+
+/// **Requires Nightly** Performs *unbiased* 3d convolutions on 4d and 5d volumes
+/// of shape `(IN_CHAN, Depth, Height, Width)` or `(Batch, IN_CHAN, Depth, Height, Width)`.
+///
+/// **Pytorch Equivalent**: `torch.nn.Conv3d(..., bias=False)`
+///
+/// Generics:
+/// - `IN_CHAN`: The number of input channels in a volume.
+/// - `OUT_CHAN`: The number of channels in the output of the layer.
+/// - `KERNEL_SIZE`: The size of the kernel applied to the depth, height and width of the volumes.
+/// - `STRIDE`: How far to move the kernel each step. Defaults to `1`
+/// - `PADDING`: How much zero padding to add around the volumes. Defaults to `0`.
+#[derive(Debug, Clone)]
+pub struct Conv3D<
+    const IN_CHAN: usize,
+    const OUT_CHAN: usize,
+    const KERNEL_SIZE: usize,
+    const STRIDE: usize,
+    const PADDING: usize,
+    E: Dtype,
+    D: DeviceStorage,
+> {
+    pub weight: Tensor<Rank5<OUT_CHAN, IN_CHAN, KERNEL_SIZE, KERNEL_SIZE, KERNEL_SIZE>, E, D>,
+}
+
+impl<const I: usize, const O: usize, const K: usize, const S: usize, const P: usize, E, D>
+    TensorCollection<E, D> for Conv3D<I, O, K, S, P, E, D>
+where
+    E: Dtype + Float + SampleUniform,
+    D: Device<E>,
+{
+    fn iter_tensors<V: ModuleVisitor<Self, E, D>>(visitor: &mut V) -> Result<(), V::Err> {
+        visitor.visit_tensor(
+            "weight",
+            |s| &s.weight,
+            |s| &mut s.weight,
+            TensorOptions::reset_with(|t| {
+                let b = E::ONE / E::from_usize(I * K * K * K).unwrap().sqrt();
+                t.try_fill_with_distr(rand_distr::Uniform::new(-b, b))
+            }),
+        )
+    }
+}
+
+impl<const I: usize, const O: usize, const K: usize, const S: usize, const P: usize, E, D>
+    BuildModule<D, E> for Conv3D<I, O, K, S, P, E, D>
+where
+    E: Dtype + Float + SampleUniform,
+    D: Device<E>,
+{
+    fn try_build(device: &D) -> Result<Self, <D>::Err> {
+        let k = E::from_usize(I * K * K * K).unwrap();
+        let bound = E::ONE / k.sqrt();
+        Ok(Self {
+            weight: device.try_sample(rand_distr::Uniform::new(-bound, bound))?,
+        })
+    }
+}
+
+impl<const I: usize, const O: usize, const K: usize, const S: usize, const P: usize, E, D1, D2>
+    ToDevice<D2> for Conv3D<I, O, K, S, P, E, D1>
+where
+    E: Dtype,
+    D1: Device<E>,
+    D2: Device<E>,
+{
+    type Output = Conv3D<I, O, K, S, P, E, D2>;
+
+    fn to_device(&self, device: &D2) -> Self::Output {
+        Conv3D {
+            weight: self.weight.to_device(device),
+        }
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<const C: usize, const O: usize, const K: usize, const S: usize, const P: usize, E, D, Img>
+    super::Module<Img> for Conv3D<C, O, K, S, P, E, D>
+where
+    E: Dtype,
+    D: Device<E>,
+    Img: TryConv3DTo<Tensor<Rank5<O, C, K, K, K>, E, D>, S, P> + HasErr<Err = D::Err>,
+{
+    type Output = Img::Output;
+    type Error = D::Err;
+
+    fn try_forward(&self, x: Img) -> Result<Self::Output, D::Err> {
+        x.try_conv3d_to(self.weight.clone())
+    }
+}
+
+impl<const I: usize, const O: usize, const K: usize, const S: usize, const P: usize, E, D>
+    NonMutableModule for Conv3D<I, O, K, S, P, E, D>
+where
+    E: Dtype,
+    D: DeviceStorage,
+{
+}
+
+#[cfg(feature = "nightly")]
+#[cfg(test)]
+mod tests {
+    use crate::{
+        nn::{DeviceBuildExt, Module},
+        optim::*,
+        tensor::{AsVec, SampleTensor, ZerosTensor},
+        tests::*,
+    };
+
+    use super::{builder::Conv3D, *};
+
+    #[rustfmt::skip]
+    #[test]
+    fn test_forward_4d_sizes() {
+        let dev: TestDevice = Default::default();
+        let x = dev.zeros::<Rank4<3, 6, 10, 10>>();
+        let _: Tensor<Rank4<2, 4, 8, 8>, _, _, _> = dev.build_module::<Conv3D<3, 2, 3>, TestDtype>().forward(x.clone());
+        let _: Tensor<Rank4<4, 5, 9, 9>, _, _, _> = dev.build_module::<Conv3D<3, 4, 2>, TestDtype>().forward(x.clone());
+        let _: Tensor<Rank4<2, 2, 4, 4>, _, _, _> = dev.build_module::<Conv3D<3, 2, 3, 2>, TestDtype>().forward(x.clone());
+        let _: Tensor<Rank4<2, 6, 10, 10>, _, _, _> = dev.build_module::<Conv3D<3, 2, 3, 1, 1>, TestDtype>().forward(x.clone());
+    }
+
+    #[rustfmt::skip]
+    #[test]
+    fn test_forward_5d_sizes() {
+        let dev: TestDevice = Default::default();
+        let x = dev.zeros::<Rank5<5, 3, 6, 10, 10>>();
+        let _: Tensor<Rank5<5, 2, 4, 8, 8>, _, _, _> = dev.build_module::<Conv3D<3, 2, 3>, TestDtype>().forward(x.clone());
+        let _: Tensor<Rank5<5, 4, 5, 9, 9>, _, _, _> = dev.build_module::<Conv3D<3, 4, 2>, TestDtype>().forward(x.clone());
+        let _: Tensor<Rank5<5, 2, 2, 4, 4>, _, _, _> = dev.build_module::<Conv3D<3, 2, 3, 2>, TestDtype>().forward(x.clone());
+        let _: Tensor<Rank5<5, 2, 6, 10, 10>, _, _, _> = dev.build_module::<Conv3D<3, 2, 3, 1, 1>, TestDtype>().forward(x.clone());
+    }
+
+    #[test]
+    fn test_conv3d_with_optimizer() {
+        let dev: TestDevice = Default::default();
+
+        let mut m = dev.build_module::<Conv3D<2, 4, 3>, TestDtype>();
+
+        let weight_init = m.weight.clone();
+
+        let mut opt = Sgd::new(&m, Default::default());
+        let out = m.forward(dev.sample_normal::<Rank5<4, 2, 5, 6, 6>>().traced());
+        let g = out.square().mean().backward();
+
+        assert_ne!(g.get(&m.weight).as_vec(), std::vec![0.0; 4 * 2 * 3 * 3 * 3]);
+
+        opt.update(&mut m, g).expect("unused params");
+
+        assert_ne!(weight_init.as_vec(), m.weight.as_vec());
+    }
+}
@@ -38,7 +38,7 @@ use super::{Module, ModuleMut, ZeroSizedModule};
 /// let mut dropout: DropoutOneIn<2> = Default::default();
 /// let x: Tensor<Rank2<2, 5>, f32, _> = dev.ones();
 /// let r = dropout.forward_mut(x.trace());
-/// assert_eq!(r.array(), [[2.0, 2.0, 2.0, 0.0, 0.0], [2.0, 2.0, 0.0, 0.0, 2.0]]);
+/// assert_eq!(r.array(), [[0.0, 2.0, 2.0, 2.0, 0.0], [2.0, 0.0, 0.0, 2.0, 2.0]]);
 /// ```
 #[derive(Clone, Debug, Default)]
 pub struct DropoutOneIn<const N: usize>;
@@ -108,7 +108,7 @@ impl<const N: usize, S: Shape, E: Dtype, D: Device<E>> ModuleMut<Tensor<S, E, D,
 /// let mut dropout = Dropout { p: 0.5 };
 /// let x: Tensor<Rank2<2, 5>, f32, _> = dev.ones();
 /// let r = dropout.forward_mut(x.trace());
-/// assert_eq!(r.array(), [[2.0, 2.0, 2.0, 0.0, 0.0], [2.0, 2.0, 0.0, 0.0, 2.0]]);
+/// assert_eq!(r.array(), [[0.0, 2.0, 2.0, 2.0, 0.0], [2.0, 0.0, 0.0, 2.0, 2.0]]);
 /// ```
 #[derive(Clone, Debug)]
 pub struct Dropout {
@@ -147,11 +147,117 @@ impl<S: Shape, E: Dtype, D: Device<E>> ModuleMut<Tensor<S, E, D, OwnedTape<D>>>
     }
 }
 
+/// Does nothing as a [Module], and drops entire channels with probability `p` as [ModuleMut].
+///
+/// The input is either a `(C, H, W)` image or a `(B, C, H, W)` batch of images. Each channel of each
+/// image is zeroed with probability `p`, and the remaining channels are scaled by `1 / (1 - p)`.
+/// This is more effective than [Dropout] after convolutions, since neighboring pixels of a feature
+/// map are strongly correlated.
+///
+/// Described in paper: [Efficient Object Localization Using Convolutional Networks](https://arxiv.org/abs/1411.4280)
+///
+/// Like [Dropout], [Module] requires a [NoneTape] and [ModuleMut] requires an [OwnedTape].
+///
+/// The mask of shape `(B, C)` is sampled on the device with [bernoulli()], and then broadcast
+/// over the spatial dimensions.
+///
+/// **Pytorch equivalent**: `torch.nn.Dropout2d(p)`
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let mut dropout = Dropout2D { p: 0.5 };
+/// let x: Tensor<Rank4<2, 8, 3, 3>, f32, _> = dev.ones();
+/// let r = dropout.forward_mut(x.trace()).array();
+/// for channel in r.iter().flatten() {
+///     let v = channel[0][0];
+///     assert!(v == 0.0 || v == 2.0);
+///     assert_eq!(channel, &[[v; 3]; 3]);
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Dropout2D {
+    pub p: f32,
+}
+
+impl Default for Dropout2D {
+    /// Sets `self.p` to `0.5`
+    fn default() -> Self {
+        Self { p: 0.5 }
+    }
+}
+
+impl ZeroSizedModule for Dropout2D {}
+
+impl Dropout2D {
+    /// Multiplies `input` with a mask of shape `M` that's broadcast to the shape of `input`.
+    fn try_drop_channels<S: Shape, M, Ax: Axes, E: Dtype, D: Device<E>>(
+        &self,
+        input: Tensor<S, E, D, OwnedTape<D>>,
+        mask_shape: M,
+    ) -> Result<Tensor<S, E, D, OwnedTape<D>>, D::Err>
+    where
+        M: Shape + BroadcastShapeTo<S, Ax>,
+    {
+        let p = E::from_f32(self.p).unwrap();
+        let dev = input.device.clone();
+        let keep = dev.try_ones_like(&mask_shape)?.try_mul(E::ONE - p)?;
+        let scale = dev.try_ones_like(&mask_shape)?.try_div(E::ONE - p)?;
+        let mask = keep
+            .try_bernoulli()?
+            .try_choose(scale, dev.try_zeros_like(&mask_shape)?)?;
+        let shape = *input.shape();
+        input.try_mul(mask.try_broadcast_like(&shape)?)
+    }
+}
+
+impl<S: Shape, E: Dtype, D: Device<E>> Module<Tensor<S, E, D, NoneTape>> for Dropout2D {
+    type Output = Tensor<S, E, D, NoneTape>;
+    type Error = D::Err;
+
+    /// Does nothing.
+    fn try_forward(&self, input: Tensor<S, E, D, NoneTape>) -> Result<Self::Output, D::Err> {
+        Ok(input)
+    }
+}
+
+impl<C: Dim, H: Dim, W: Dim, E: Dtype, D: Device<E>>
+    ModuleMut<Tensor<(C, H, W), E, D, OwnedTape<D>>> for Dropout2D
+{
+    type Output = Tensor<(C, H, W), E, D, OwnedTape<D>>;
+    type Error = D::Err;
+
+    /// Zeros each of the `C` channels with probability `self.p`
+    fn try_forward_mut(
+        &mut self,
+        input: Tensor<(C, H, W), E, D, OwnedTape<D>>,
+    ) -> Result<Self::Output, D::Err> {
+        let (c, _, _) = *input.shape();
+        self.try_drop_channels(input, (c,))
+    }
+}
+
+impl<B: Dim, C: Dim, H: Dim, W: Dim, E: Dtype, D: Device<E>>
+    ModuleMut<Tensor<(B, C, H, W), E, D, OwnedTape<D>>> for Dropout2D
+{
+    type Output = Tensor<(B, C, H, W), E, D, OwnedTape<D>>;
+    type Error = D::Err;
+
+    /// Zeros each of the `B * C` channels with probability `self.p`
+    fn try_forward_mut(
+        &mut self,
+        input: Tensor<(B, C, H, W), E, D, OwnedTape<D>>,
+    ) -> Result<Self::Output, D::Err> {
+        let (b, c, _, _) = *input.shape();
+        self.try_drop_channels(input, (b, c))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
-        shapes::Rank1,
-        tensor::{AsArray, OnesTensor},
+        tensor::{AsArray, OnesTensor, SampleTensor},
         tests::*,
     };
 
@@ -187,4 +293,56 @@ mod tests {
         let r = dropout.forward_mut(t.trace());
         assert_ne!(t.array(), r.array());
     }
+
+    #[test]
+    fn test_dropout2d_drops_whole_channels() {
+        let dev: TestDevice = Default::default();
+        let mut dropout = Dropout2D { p: 0.25 };
+        let x: Tensor<Rank4<4, 16, 2, 3>, TestDtype, _> = dev.sample_uniform();
+        let r = dropout.forward_mut(x.trace());
+        let r_array = r.array();
+        let g = r.sum().backward().get(&x).array();
+
+        let x = x.array();
+        let mut num_dropped = 0;
+        for b in 0..4 {
+            for c in 0..16 {
+                if r_array[b][c] == [[0.0; 3]; 2] {
+                    num_dropped += 1;
+                    assert_eq!(g[b][c], [[0.0; 3]; 2]);
+                } else {
+                    let scaled = x[b][c].map(|row| row.map(|v| v / 0.75));
+                    assert_close(&r_array[b][c], &scaled);
+                    assert_close(&g[b][c], &[[1.0 / 0.75; 3]; 2]);
+                }
+            }
+        }
+        // 16 channels are expected to be dropped
+        assert!((4..32).contains(&num_dropped), "{num_dropped}");
+    }
+
+    #[test]
+    fn test_dropout2d_3d_input() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank3<64, 2, 2>, TestDtype, _> = dev.ones();
+
+        let r = Dropout2D { p: 0.5 }.forward_mut(x.trace()).array();
+        for channel in r.iter() {
+            assert!(channel == &[[0.0; 2]; 2] || channel == &[[2.0; 2]; 2]);
+        }
+        assert!(r.iter().any(|ch| ch[0][0] == 0.0) && r.iter().any(|ch| ch[0][0] == 2.0));
+
+        let r = Dropout2D { p: 0.0 }.forward_mut(x.trace());
+        assert_eq!(r.array(), x.array());
+        let r = Dropout2D { p: 1.0 }.forward_mut(x.trace());
+        assert_eq!(r.array(), [[[0.0; 2]; 2]; 64]);
+    }
+
+    #[test]
+    fn test_dropout2d_no_tape() {
+        let dev: TestDevice = Default::default();
+        let dropout = Dropout2D { p: 0.5 };
+        let t: Tensor<Rank4<2, 3, 2, 2>, TestDtype, _> = dev.sample_normal();
+        assert_eq!(dropout.forward(t.clone()).array(), t.array());
+    }
 }
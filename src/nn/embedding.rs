@@ -46,10 +46,29 @@ where
 /// let inputs: Tensor<Rank2<10, 5>, usize, _> = dev.zeros();
 /// let _: Tensor<(Const<10>, Const<5>, Const<2>), f32, _> = model.forward(inputs);
 /// ```
+///
+/// Setting [Self::padding_idx] makes that id always map to a vector of zeros that doesn't
+/// receive any gradient, and setting [Self::max_norm] scales down looked up vectors with a
+/// larger l2 norm. See [Tensor::embedding()] for details.
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let mut model = dev.build_module::<Embedding<7, 2>, f32>();
+/// model.padding_idx = Some(0);
+/// model.max_norm = Some(1.0);
+/// let y = model.forward(dev.tensor([0, 3]));
+/// assert_eq!(y.array()[0], [0.0; 2]);
+/// ```
 #[derive(Debug, Clone)]
 pub struct Embedding<const VOCAB: usize, const DIM: usize, E: Dtype, D: DeviceStorage> {
     /// Transposed weight matrix, shape (I, O)
     pub weight: Tensor<Rank2<VOCAB, DIM>, E, D>,
+
+    /// The id that is looked up as zeros and never updated, `None` by default
+    pub padding_idx: Option<usize>,
+
+    /// The maximum l2 norm of looked up vectors, `None` by default
+    pub max_norm: Option<E>,
 }
 
 impl<const V: usize, const M: usize, E: Dtype, D: DeviceStorage> NonMutableModule
@@ -63,7 +82,11 @@ impl<const V: usize, const M: usize, E: Dtype + Float + SampleUniform, D: Device
     fn try_build(device: &D) -> Result<Self, D::Err> {
         let bound = E::ONE / E::from_usize(V).unwrap().sqrt();
         let weight = device.try_sample(Uniform::new(-bound, bound))?;
-        Ok(Self { weight })
+        Ok(Self {
+            weight,
+            padding_idx: None,
+            max_norm: None,
+        })
     }
 }
 
@@ -91,7 +114,10 @@ impl<const V: usize, const M: usize, const S: usize, E: Dtype, D: Device<E>, T:
 
     fn try_forward(&self, input: Tensor<Rank1<S>, usize, D, T>) -> Result<Self::Output, D::Err> {
         let (input, tape) = input.split_tape();
-        self.weight.clone().put_tape(tape).try_gather(input)
+        self.weight
+            .clone()
+            .put_tape(tape)
+            .try_embedding(input, self.padding_idx, self.max_norm)
     }
 }
 
@@ -113,7 +139,10 @@ impl<
         input: Tensor<Rank2<BATCH, SEQ>, usize, D, T>,
     ) -> Result<Self::Output, D::Err> {
         let (input, tape) = input.split_tape();
-        self.weight.clone().put_tape(tape).try_gather(input)
+        self.weight
+            .clone()
+            .put_tape(tape)
+            .try_embedding(input, self.padding_idx, self.max_norm)
     }
 }
 
@@ -124,6 +153,8 @@ impl<const VOCAB: usize, const DIM: usize, E: Dtype, D1: Device<E>, D2: Device<E
     fn to_device(&self, device: &D2) -> Self::Output {
         Embedding {
             weight: self.weight.to_device(device),
+            padding_idx: self.padding_idx,
+            max_norm: self.max_norm,
         }
     }
 }
@@ -154,6 +185,8 @@ mod tests {
 
         let model = Embedding {
             weight: dev.tensor(W),
+            padding_idx: None,
+            max_norm: None,
         };
 
         let x = dev.tensor([0, 0, 1]);
@@ -195,6 +228,8 @@ mod tests {
 
         let model = Embedding {
             weight: dev.tensor(W),
+            padding_idx: None,
+            max_norm: None,
         };
 
         let x = dev.tensor([[0, 0], [0, 1]]);
@@ -234,4 +269,28 @@ mod tests {
             ],
         );
     }
+
+    #[test]
+    fn test_embedding_padding_and_max_norm() {
+        let dev: TestDevice = Default::default();
+
+        let model = Embedding {
+            weight: dev.tensor([[3.0, 4.0], [0.3, 0.4], [-1.0, 1.0]]),
+            padding_idx: Some(2),
+            max_norm: Some(1.0),
+        };
+
+        let x = dev.tensor([[0, 2], [1, 0]]);
+        let y = model.forward(x.trace());
+        assert_close(
+            &y.array(),
+            &[[[0.6, 0.8], [0.0, 0.0]], [[0.3, 0.4], [0.6, 0.8]]],
+        );
+
+        let g = y.sum().backward();
+        assert_eq!(
+            g.get(&model.weight).array(),
+            [[2.0, 2.0], [1.0, 1.0], [0.0, 0.0]]
+        );
+    }
 }
@@ -0,0 +1,171 @@
+use num_traits::Float;
+use rand_distr::{uniform::SampleUniform, Uniform};
+
+use crate::{gradients::Tape, shapes::*, tensor::*, tensor_ops::*};
+
+use super::{tensor_collection::*, BuildModule, BuildOnDevice, Module, NonMutableModule, ToDevice};
+
+pub mod builder {
+    #[derive(Debug)]
+    pub struct EmbeddingBag<const VOCAB: usize, const DIM: usize>;
+}
+
+impl<const V: usize, const M: usize, E: Dtype, D: Device<E>> BuildOnDevice<D, E>
+    for builder::EmbeddingBag<V, M>
+where
+    EmbeddingBag<V, M, E, D>: BuildModule<D, E>,
+{
+    type Built = EmbeddingBag<V, M, E, D>;
+    fn try_build_on_device(device: &D) -> Result<Self::Built, D::Err> {
+        Self::Built::try_build(device)
+    }
+}
+
+/// An [super::modules::Embedding] that reduces bags of ids into a single vector each,
+/// without materializing the individual looked up vectors.
+/// Initializes [Self::weight] from a Uniform distribution
+/// between [-1 / sqrt(VOCAB), 1 / sqrt(VOCAB)].
+///
+/// The input is a tuple of a flat list of ids, and the offsets into it that each bag starts at.
+/// Bag `b` consists of `ids[offsets[b]..offsets[b + 1]]`, and the last bag runs until the end
+/// of the ids. See [Tensor::embedding_bag()] for details.
+///
+/// # Generics
+/// - `VOCAB` The size of the vocabulary, input ids must be less than VOCAB;
+/// - `DIM` The "output" size of the reduced vectors.
+///
+/// # Examples
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// type Model = EmbeddingBag<7, 2>;
+/// let mut model = dev.build_module::<Model, f32>();
+/// model.mode = EmbeddingBagMode::Sum;
+/// // 3 bags of sizes 2, 1 & 3
+/// let ids: Tensor<Rank1<6>, usize, _> = dev.tensor([0, 4, 1, 6, 6, 2]);
+/// let offsets: Tensor<Rank1<3>, usize, _> = dev.tensor([0, 2, 3]);
+/// let _: Tensor<Rank2<3, 2>, f32, _> = model.forward((ids, offsets));
+/// ```
+#[derive(Debug, Clone)]
+pub struct EmbeddingBag<const VOCAB: usize, const DIM: usize, E: Dtype, D: DeviceStorage> {
+    /// Embedding table, shape (VOCAB, DIM)
+    pub weight: Tensor<Rank2<VOCAB, DIM>, E, D>,
+
+    /// How each bag is reduced, [EmbeddingBagMode::Mean] by default
+    pub mode: EmbeddingBagMode,
+}
+
+impl<const V: usize, const M: usize, E: Dtype, D: DeviceStorage> NonMutableModule
+    for EmbeddingBag<V, M, E, D>
+{
+}
+
+impl<const V: usize, const M: usize, E: Dtype + Float + SampleUniform, D: Device<E>>
+    BuildModule<D, E> for EmbeddingBag<V, M, E, D>
+{
+    fn try_build(device: &D) -> Result<Self, D::Err> {
+        let bound = E::ONE / E::from_usize(V).unwrap().sqrt();
+        let weight = device.try_sample(Uniform::new(-bound, bound))?;
+        Ok(Self {
+            weight,
+            mode: Default::default(),
+        })
+    }
+}
+
+impl<const C: usize, const M: usize, E: Dtype + Float + SampleUniform, D: SampleTensor<E>>
+    TensorCollection<E, D> for EmbeddingBag<C, M, E, D>
+{
+    fn iter_tensors<V: ModuleVisitor<Self, E, D>>(visitor: &mut V) -> Result<(), V::Err> {
+        visitor.visit_tensor(
+            "weight",
+            |s| &s.weight,
+            |s| &mut s.weight,
+            TensorOptions::reset_with(|t| {
+                let b: E = E::ONE / E::from_usize(C).unwrap().sqrt();
+                t.try_fill_with_distr(Uniform::new(-b, b))
+            }),
+        )
+    }
+}
+
+impl<const V: usize, const M: usize, N: Dim, B: Dim, E: Dtype, D: Device<E>, T: Tape<D>>
+    Module<(Tensor<(N,), usize, D, T>, Tensor<(B,), usize, D>)> for EmbeddingBag<V, M, E, D>
+{
+    type Output = Tensor<(B, Const<M>), E, D, T>;
+    type Error = D::Err;
+
+    fn try_forward(
+        &self,
+        (ids, offsets): (Tensor<(N,), usize, D, T>, Tensor<(B,), usize, D>),
+    ) -> Result<Self::Output, D::Err> {
+        let (ids, tape) = ids.split_tape();
+        self.weight
+            .clone()
+            .put_tape(tape)
+            .try_embedding_bag(ids, offsets, self.mode)
+    }
+}
+
+impl<const VOCAB: usize, const DIM: usize, E: Dtype, D1: Device<E>, D2: Device<E>> ToDevice<D2>
+    for EmbeddingBag<VOCAB, DIM, E, D1>
+{
+    type Output = EmbeddingBag<VOCAB, DIM, E, D2>;
+    fn to_device(&self, device: &D2) -> Self::Output {
+        EmbeddingBag {
+            weight: self.weight.to_device(device),
+            mode: self.mode,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{nn::DeviceBuildExt, tests::*};
+
+    const W: [[TestDtype; 3]; 3] = [[1.0, -2.0, 0.5], [0.0, 3.0, 1.0], [2.0, 1.0, -1.0]];
+
+    #[test]
+    fn test_embedding_bag_initialize() {
+        let dev: TestDevice = Default::default();
+        let m = dev.build_module::<builder::EmbeddingBag<2000, 1>, TestDtype>();
+        assert_eq!(m.mode, EmbeddingBagMode::Mean);
+        let bound = 1.0 / (2000.0.sqrt());
+        for v in m.weight.as_vec() {
+            assert!(-bound <= v && v <= bound && v != 0.0);
+        }
+    }
+
+    #[test]
+    fn test_embedding_bag_forward() {
+        let dev: TestDevice = Default::default();
+
+        let mut model = EmbeddingBag {
+            weight: dev.tensor(W),
+            mode: EmbeddingBagMode::Mean,
+        };
+
+        let ids = dev.tensor([0, 2, 1, 1, 2]);
+        let offsets = dev.tensor([0, 2]);
+        let y = model.forward((ids.trace(), offsets.clone()));
+        assert_close(
+            &y.array(),
+            &[[1.5, -0.5, -0.25], [2.0 / 3.0, 7.0 / 3.0, 1.0 / 3.0]],
+        );
+        let g = y.sum().backward();
+        assert_close(
+            &g.get(&model.weight).array(),
+            &[[0.5; 3], [2.0 / 3.0; 3], [5.0 / 6.0; 3]],
+        );
+
+        model.mode = EmbeddingBagMode::Max;
+        let y = model.forward((ids.trace(), offsets));
+        assert_eq!(y.array(), [[2.0, 1.0, 0.5], [2.0, 3.0, 1.0]]);
+        let g = y.sum().backward();
+        assert_eq!(
+            g.get(&model.weight).array(),
+            [[0.0, 0.0, 1.0], [0.0, 1.0, 1.0], [2.0, 1.0, 0.0]]
+        );
+    }
+}
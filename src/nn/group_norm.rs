@@ -0,0 +1,222 @@
+use crate::{gradients::Tape, shapes::*, tensor::*, tensor_ops::*};
+
+use super::{tensor_collection::*, BuildModule, BuildOnDevice, Module, NonMutableModule, ToDevice};
+
+pub mod builder {
+    #[derive(Debug)]
+    pub struct GroupNorm<const GROUPS: usize, const C: usize>;
+}
+
+impl<const G: usize, const C: usize, E: Dtype, D: Device<E>> BuildOnDevice<D, E>
+    for builder::GroupNorm<G, C>
+where
+    GroupNorm<G, C, E, D>: BuildModule<D, E>,
+{
+    type Built = GroupNorm<G, C, E, D>;
+    fn try_build_on_device(device: &D) -> Result<Self::Built, D::Err> {
+        Self::Built::try_build(device)
+    }
+}
+
+/// Implements group normalization as described in [Group Normalization](https://arxiv.org/abs/1803.08494).
+///
+/// The `C` channels of an image are split into `GROUPS` groups of consecutive channels, and each group
+/// is normalized to 0 mean and unit std dev using [Tensor::group_norm()]. This is followed by an
+/// element-wise affine transform of each channel using learnable parameters [Self::gamma] and [Self::beta].
+///
+/// Unlike [super::modules::BatchNorm2D], the statistics don't depend on the other images in the batch,
+/// so training and inference behave the same.
+///
+/// [Self::epsilon] is added to the variance to ensure big enough numbers. It defaults to `1e-5`.
+///
+/// # Generics
+/// - `GROUPS` The number of groups, which must divide `C`.
+/// - `C` The number of channels.
+///
+/// # Examples
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// type Model = GroupNorm<2, 6>;
+/// let model = dev.build_module::<Model, f32>();
+/// let _: Tensor<Rank3<6, 4, 4>, f32, _> = model.forward(dev.zeros::<Rank3<6, 4, 4>>());
+/// let _: Tensor<Rank4<8, 6, 4, 4>, f32, _> = model.forward(dev.zeros::<Rank4<8, 6, 4, 4>>());
+/// ```
+#[derive(Debug, Clone)]
+pub struct GroupNorm<const GROUPS: usize, const C: usize, E: Dtype, D: DeviceStorage> {
+    pub gamma: Tensor<Rank1<C>, E, D>,
+    pub beta: Tensor<Rank1<C>, E, D>,
+    pub epsilon: E,
+}
+
+impl<const G: usize, const C: usize, E: Dtype, D: DeviceStorage> NonMutableModule
+    for GroupNorm<G, C, E, D>
+{
+}
+
+impl<const G: usize, const C: usize, E: Dtype, D: Device<E>> BuildModule<D, E>
+    for GroupNorm<G, C, E, D>
+{
+    /// Fills [Self::gamma] with 1s and [Self::beta] with 0s and sets [Self::epsilon] to `1e-5`.
+    fn try_build(device: &D) -> Result<Self, D::Err> {
+        Ok(Self {
+            gamma: device.try_ones()?,
+            beta: device.try_zeros()?,
+            epsilon: E::from_f32(1e-5).unwrap(),
+        })
+    }
+}
+
+impl<const G: usize, const C: usize, E: Dtype, D: Device<E>> TensorCollection<E, D>
+    for GroupNorm<G, C, E, D>
+{
+    fn iter_tensors<V: ModuleVisitor<Self, E, D>>(visitor: &mut V) -> Result<(), V::Err> {
+        visitor.visit_tensor(
+            "gamma",
+            |s| &s.gamma,
+            |s| &mut s.gamma,
+            TensorOptions::reset_to_ones(),
+        )?;
+        visitor.visit_tensor(
+            "beta",
+            |s| &s.beta,
+            |s| &mut s.beta,
+            TensorOptions::reset_to_zeros(),
+        )
+    }
+}
+
+impl<const G: usize, const C: usize, E: Dtype, D1: Device<E>, D2: Device<E>> ToDevice<D2>
+    for GroupNorm<G, C, E, D1>
+{
+    type Output = GroupNorm<G, C, E, D2>;
+
+    fn to_device(&self, device: &D2) -> Self::Output {
+        GroupNorm {
+            gamma: self.gamma.to_device(device),
+            beta: self.beta.to_device(device),
+            epsilon: self.epsilon,
+        }
+    }
+}
+
+impl<const G: usize, const C: usize, E: Dtype, D: Device<E>> GroupNorm<G, C, E, D> {
+    fn affine<S: Shape, Ax: Axes, T: Tape<D>>(
+        &self,
+        x: Tensor<S, E, D, T>,
+    ) -> Result<Tensor<S, E, D, T>, D::Err>
+    where
+        Rank1<C>: BroadcastShapeTo<S, Ax>,
+    {
+        let shape = *x.shape();
+        x.try_mul(self.gamma.retaped::<T>().try_broadcast_like(&shape)?)?
+            .try_add(self.beta.retaped::<T>().try_broadcast_like(&shape)?)
+    }
+}
+
+impl<const G: usize, const C: usize, H: Dim, W: Dim, E: Dtype, D: Device<E>, T: Tape<D>>
+    Module<Tensor<(Const<C>, H, W), E, D, T>> for GroupNorm<G, C, E, D>
+{
+    type Output = Tensor<(Const<C>, H, W), E, D, T>;
+    type Error = D::Err;
+
+    fn try_forward(&self, x: Tensor<(Const<C>, H, W), E, D, T>) -> Result<Self::Output, D::Err> {
+        assert_eq!(C % G, 0, "{C} channels can't be split into {G} groups");
+        let x = x.try_normalize_groups(G, self.epsilon)?;
+        self.affine(x)
+    }
+}
+
+impl<
+        const G: usize,
+        const C: usize,
+        B: Dim,
+        H: Dim,
+        W: Dim,
+        E: Dtype,
+        D: Device<E>,
+        T: Tape<D>,
+    > Module<Tensor<(B, Const<C>, H, W), E, D, T>> for GroupNorm<G, C, E, D>
+{
+    type Output = Tensor<(B, Const<C>, H, W), E, D, T>;
+    type Error = D::Err;
+
+    fn try_forward(&self, x: Tensor<(B, Const<C>, H, W), E, D, T>) -> Result<Self::Output, D::Err> {
+        let x = x.try_group_norm(G, self.epsilon)?;
+        self.affine(x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::{DeviceBuildExt, ResetParams};
+    use crate::tests::*;
+
+    #[test]
+    fn test_group_norm_reset() {
+        let dev: TestDevice = Default::default();
+
+        let mut m = dev.build_module::<builder::GroupNorm<2, 4>, TestDtype>();
+        assert_eq!(m.gamma.array(), [1.0; 4]);
+        assert_eq!(m.beta.array(), [0.0; 4]);
+
+        m.gamma = dev.sample_normal();
+        m.beta = dev.sample_normal();
+        m.reset_params();
+
+        assert_eq!(m.gamma.array(), [1.0; 4]);
+        assert_eq!(m.beta.array(), [0.0; 4]);
+    }
+
+    #[test]
+    fn test_group_norm_3d_forward() {
+        let dev: TestDevice = Default::default();
+        let m = GroupNorm::<2, 4, TestDtype, TestDevice> {
+            gamma: dev.tensor([1.0, 2.0, 0.5, -1.0]),
+            beta: dev.tensor([0.0, 1.0, 0.0, 0.5]),
+            epsilon: 0.0,
+        };
+        let x = dev.tensor([[[1.0, 3.0]], [[1.0, 3.0]], [[3.0, 5.0]], [[5.0, 3.0]]]);
+        let r = m.forward(x.trace());
+        assert_eq!(
+            r.array(),
+            [[[-1.0, 1.0]], [[-1.0, 3.0]], [[-0.5, 0.5]], [[-0.5, 1.5]]]
+        );
+        let g = r.sum().backward();
+        assert_eq!(g.get(&m.gamma).array(), [0.0; 4]);
+        assert_eq!(g.get(&m.beta).array(), [2.0; 4]);
+    }
+
+    #[test]
+    fn test_group_norm_4d_forward() {
+        let dev: TestDevice = Default::default();
+        let mut m = dev.build_module::<builder::GroupNorm<3, 6>, TestDtype>();
+        m.gamma = dev.sample_normal();
+        m.beta = dev.sample_normal();
+        let x: Tensor<Rank4<2, 6, 2, 3>, TestDtype, _> = dev.sample_normal();
+        let w: Tensor<Rank4<2, 6, 2, 3>, TestDtype, _> = dev.sample_normal();
+
+        // each image of the batch is normalized on its own
+        let r = m.forward(x.trace());
+        let r_array = r.array();
+        let g = (r * w.clone()).sum().backward();
+        for (i, r_i) in r_array.iter().enumerate() {
+            let x_i = x.clone().select(dev.tensor(i));
+            assert_close(r_i, &m.forward(x_i).array());
+        }
+
+        // groups are normalized with the statistics of their 2 channels
+        let r2 = x
+            .trace()
+            .reshape_like(&(Const::<2>, Const::<3>, Const::<12>))
+            .normalize::<Axis<2>>(1e-5)
+            .reshape_like(x.shape());
+        let r2 = m.affine(r2).unwrap();
+        assert_close(&r_array, &r2.array());
+        let g2 = (r2 * w).sum().backward();
+        assert_close(&g.get(&m.gamma).array(), &g2.get(&m.gamma).array());
+        assert_close(&g.get(&m.beta).array(), &g2.get(&m.beta).array());
+        assert_close_with_tolerance(&g.get(&x).array(), &g2.get(&x).array(), 1e-5);
+    }
+}
@@ -3,14 +3,18 @@ use crate::{gradients::Tape, shapes::*, tensor::*, tensor_ops::*};
 use super::{tensor_collection::*, BuildModule, BuildOnDevice, Module, NonMutableModule, ToDevice};
 
 pub mod builder {
+    use core::marker::PhantomData;
+
     #[derive(Debug)]
-    pub struct LayerNorm1D<const M: usize>;
+    pub struct LayerNorm<S>(PhantomData<S>);
+
+    pub type LayerNorm1D<const M: usize> = LayerNorm<crate::shapes::Rank1<M>>;
 }
-impl<const M: usize, E: Dtype, D: Device<E>> BuildOnDevice<D, E> for builder::LayerNorm1D<M>
+impl<S: ConstShape, E: Dtype, D: Device<E>> BuildOnDevice<D, E> for builder::LayerNorm<S>
 where
-    LayerNorm1D<M, E, D>: BuildModule<D, E>,
+    LayerNorm<S, E, D>: BuildModule<D, E>,
 {
-    type Built = LayerNorm1D<M, E, D>;
+    type Built = LayerNorm<S, E, D>;
     fn try_build_on_device(device: &D) -> Result<Self::Built, D::Err> {
         Self::Built::try_build(device)
     }
@@ -18,33 +22,51 @@ where
 
 /// Implements layer normalization as described in [Layer Normalization](https://arxiv.org/abs/1607.06450).
 ///
-/// This calls [normalize()] on the last axis of the input to normalize to 0 mean and unit std dev, and then does an element-wise
-/// affine transform using learnable parameters [Self::gamma] and [Self::beta].
+/// This calls [Tensor::layer_norm()] on the trailing axes of the input that match `S`, to normalize them to 0 mean
+/// and unit std dev, and then does an element-wise affine transform using learnable parameters [Self::gamma] and
+/// [Self::beta] of shape `S`.
 ///
-/// [Self::epsilon] is passed to [normalize()] and added to the variance to ensure big enough numbers. It defaults to `1e-5`.
+/// [Self::epsilon] is added to the variance to ensure big enough numbers. It defaults to `1e-5`.
 ///
 /// # Generics
-/// - `M` The size of the affine transform tensors.
+/// - `S` The normalized shape, which is also the shape of the affine transform tensors. Shapes of up to
+///   3 dimensions are supported, with up to 2 leading batch dimensions.
 ///
 /// # Examples
 /// ```rust
 /// # use dfdx::prelude::*;
 /// # let dev: Cpu = Default::default();
-/// type Model = LayerNorm1D<5>;
+/// // normalizes (H, W, C) feature maps, e.g. in a ConvNeXt block
+/// type Model = LayerNorm<Rank3<4, 4, 8>>;
 /// let model = dev.build_module::<Model, f32>();
-/// let _: Tensor<Rank1<5>, f32, _> = model.forward(dev.zeros::<Rank1<5>>());
+/// let _: Tensor<Rank3<4, 4, 8>, f32, _> = model.forward(dev.zeros::<Rank3<4, 4, 8>>());
+/// let _: Tensor<Rank4<2, 4, 4, 8>, f32, _> = model.forward(dev.zeros::<Rank4<2, 4, 4, 8>>());
 /// ```
-
 #[derive(Debug, Clone)]
-pub struct LayerNorm1D<const M: usize, E: Dtype, D: DeviceStorage> {
-    pub gamma: Tensor<Rank1<M>, E, D>,
-    pub beta: Tensor<Rank1<M>, E, D>,
+pub struct LayerNorm<S: ConstShape, E: Dtype, D: DeviceStorage> {
+    pub gamma: Tensor<S, E, D>,
+    pub beta: Tensor<S, E, D>,
     pub epsilon: E,
 }
 
-impl<const M: usize, E: Dtype, D: DeviceStorage> NonMutableModule for LayerNorm1D<M, E, D> {}
+/// [LayerNorm] over the last axis of the input.
+///
+/// # Generics
+/// - `M` The size of the affine transform tensors.
+///
+/// # Examples
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// type Model = LayerNorm1D<5>;
+/// let model = dev.build_module::<Model, f32>();
+/// let _: Tensor<Rank1<5>, f32, _> = model.forward(dev.zeros::<Rank1<5>>());
+/// ```
+pub type LayerNorm1D<const M: usize, E, D> = LayerNorm<Rank1<M>, E, D>;
+
+impl<S: ConstShape, E: Dtype, D: DeviceStorage> NonMutableModule for LayerNorm<S, E, D> {}
 
-impl<const M: usize, E: Dtype, D: Device<E>> BuildModule<D, E> for LayerNorm1D<M, E, D> {
+impl<S: ConstShape, E: Dtype, D: Device<E>> BuildModule<D, E> for LayerNorm<S, E, D> {
     /// Fills [Self::gamma] with 1s and [Self::beta] with 0s and sets [Self::epsilon] to `1e-5`.
     fn try_build(device: &D) -> Result<Self, D::Err> {
         Ok(Self {
@@ -55,7 +77,7 @@ impl<const M: usize, E: Dtype, D: Device<E>> BuildModule<D, E> for LayerNorm1D<M
     }
 }
 
-impl<const M: usize, E: Dtype, D: Device<E>> TensorCollection<E, D> for LayerNorm1D<M, E, D> {
+impl<S: ConstShape, E: Dtype, D: Device<E>> TensorCollection<E, D> for LayerNorm<S, E, D> {
     fn iter_tensors<V: ModuleVisitor<Self, E, D>>(visitor: &mut V) -> Result<(), V::Err> {
         visitor.visit_tensor(
             "gamma",
@@ -72,13 +94,11 @@ impl<const M: usize, E: Dtype, D: Device<E>> TensorCollection<E, D> for LayerNor
     }
 }
 
-impl<const M: usize, E: Dtype, D1: Device<E>, D2: Device<E>> ToDevice<D2>
-    for LayerNorm1D<M, E, D1>
-{
-    type Output = LayerNorm1D<M, E, D2>;
+impl<S: ConstShape, E: Dtype, D1: Device<E>, D2: Device<E>> ToDevice<D2> for LayerNorm<S, E, D1> {
+    type Output = LayerNorm<S, E, D2>;
 
     fn to_device(&self, device: &D2) -> Self::Output {
-        LayerNorm1D {
+        LayerNorm {
             gamma: self.gamma.to_device(device),
             beta: self.beta.to_device(device),
             epsilon: self.epsilon,
@@ -86,50 +106,55 @@ impl<const M: usize, E: Dtype, D1: Device<E>, D2: Device<E>> ToDevice<D2>
     }
 }
 
-impl<const M: usize, E: Dtype, D: Device<E>, T: Tape<D>> Module<Tensor<Rank1<M>, E, D, T>>
-    for LayerNorm1D<M, E, D>
-{
-    type Output = Tensor<Rank1<M>, E, D, T>;
-    type Error = D::Err;
-
-    fn try_forward(&self, x: Tensor<Rank1<M>, E, D, T>) -> Result<Self::Output, D::Err> {
-        x.try_normalize(self.epsilon)?
-            .try_mul(self.gamma.clone())?
-            .try_add(self.beta.clone())
-    }
-}
-
-impl<B: Dim, const M: usize, E: Dtype, D: Device<E>, T: Tape<D>>
-    Module<Tensor<(B, Const<M>), E, D, T>> for LayerNorm1D<M, E, D>
-{
-    type Output = Tensor<(B, Const<M>), E, D, T>;
-    type Error = D::Err;
-
-    fn try_forward(&self, x: Tensor<(B, Const<M>), E, D, T>) -> Result<Self::Output, D::Err> {
-        let shape = *x.shape();
-        x.try_normalize::<Axis<1>>(self.epsilon)?
-            .try_mul(self.gamma.retaped::<T>().try_broadcast_like(&shape)?)?
-            .try_add(self.beta.retaped::<T>().try_broadcast_like(&shape)?)
-    }
+macro_rules! layer_norm_impls {
+    ([$($M:ident),+], [$($B:ident),+], $Ax:ty) => {
+        impl<$($B: Dim, )+ $(const $M: usize, )+ E: Dtype, D: Device<E>, T: Tape<D>>
+            Module<Tensor<($($B, )+ $(Const<$M>, )+), E, D, T>> for LayerNorm<($(Const<$M>, )+), E, D>
+        {
+            type Output = Tensor<($($B, )+ $(Const<$M>, )+), E, D, T>;
+            type Error = D::Err;
+
+            fn try_forward(
+                &self,
+                x: Tensor<($($B, )+ $(Const<$M>, )+), E, D, T>,
+            ) -> Result<Self::Output, D::Err> {
+                let shape = *x.shape();
+                x.try_layer_norm::<$Ax>(self.epsilon)?
+                    .try_mul(self.gamma.retaped::<T>().try_broadcast_like(&shape)?)?
+                    .try_add(self.beta.retaped::<T>().try_broadcast_like(&shape)?)
+            }
+        }
+    };
+    ([$($M:ident),+], $Ax:ty) => {
+        impl<$(const $M: usize, )+ E: Dtype, D: Device<E>, T: Tape<D>>
+            Module<Tensor<($(Const<$M>, )+), E, D, T>> for LayerNorm<($(Const<$M>, )+), E, D>
+        {
+            type Output = Tensor<($(Const<$M>, )+), E, D, T>;
+            type Error = D::Err;
+
+            fn try_forward(&self, x: Tensor<($(Const<$M>, )+), E, D, T>) -> Result<Self::Output, D::Err> {
+                x.try_layer_norm::<$Ax>(self.epsilon)?
+                    .try_mul(self.gamma.retaped::<T>())?
+                    .try_add(self.beta.retaped::<T>())
+            }
+        }
+    };
 }
 
-impl<B: Dim, S: Dim, const M: usize, E: Dtype, D: Device<E>, T: Tape<D>>
-    Module<Tensor<(B, S, Const<M>), E, D, T>> for LayerNorm1D<M, E, D>
-{
-    type Output = Tensor<(B, S, Const<M>), E, D, T>;
-    type Error = D::Err;
-
-    fn try_forward(&self, x: Tensor<(B, S, Const<M>), E, D, T>) -> Result<Self::Output, D::Err> {
-        let shape = *x.shape();
-        x.try_normalize::<Axis<2>>(self.epsilon)?
-            .try_mul(self.gamma.retaped::<T>().try_broadcast_like(&shape)?)?
-            .try_add(self.beta.retaped::<T>().try_broadcast_like(&shape)?)
-    }
-}
+layer_norm_impls!([M], Axis<0>);
+layer_norm_impls!([M], [B], Axis<1>);
+layer_norm_impls!([M], [B, S], Axis<2>);
+layer_norm_impls!([M, N], Axes2<0, 1>);
+layer_norm_impls!([M, N], [B], Axes2<1, 2>);
+layer_norm_impls!([M, N], [B, S], Axes2<2, 3>);
+layer_norm_impls!([M, N, O], Axes3<0, 1, 2>);
+layer_norm_impls!([M, N, O], [B], Axes3<1, 2, 3>);
+layer_norm_impls!([M, N, O], [B, S], Axes3<2, 3, 4>);
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::gradients::OwnedTape;
     use crate::nn::{DeviceBuildExt, ModuleMut, ResetParams};
     use crate::tests::*;
 
@@ -192,4 +217,34 @@ mod tests {
         );
         assert_close(&g.get(&m.beta).array(), &[0.2; 5]);
     }
+
+    #[test]
+    fn test_layer_norm_3d_shape() {
+        let dev: TestDevice = Default::default();
+        let mut m = dev.build_module::<builder::LayerNorm<Rank3<2, 3, 4>>, TestDtype>();
+        m.gamma = dev.sample_normal();
+        m.beta = dev.sample_normal();
+        let x: Tensor<Rank4<3, 2, 3, 4>, TestDtype, _> = dev.sample_normal();
+        let w: Tensor<Rank4<3, 2, 3, 4>, TestDtype, _> = dev.sample_normal();
+
+        let r = m.forward(x.trace());
+        let r_array = r.array();
+        let g = (r * w.clone()).sum().backward();
+
+        // every (H, W, C) map is normalized on its own
+        for (i, r_i) in r_array.iter().enumerate() {
+            let x_i = x.clone().select(dev.tensor(i));
+            assert_close(r_i, &m.forward(x_i).array());
+        }
+
+        let shape = *x.shape();
+        let r2 = x.trace().normalize::<Axes3<1, 2, 3>>(1e-5)
+            * m.gamma.retaped::<OwnedTape<_>>().broadcast_like(&shape)
+            + m.beta.retaped::<OwnedTape<_>>().broadcast_like(&shape);
+        assert_close(&r_array, &r2.array());
+        let g2 = (r2 * w).sum().backward();
+        assert_close(&g.get(&m.gamma).array(), &g2.get(&m.gamma).array());
+        assert_close(&g.get(&m.beta).array(), &g2.get(&m.beta).array());
+        assert_close_with_tolerance(&g.get(&x).array(), &g2.get(&x).array(), 1e-5);
+    }
 }
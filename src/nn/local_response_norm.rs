@@ -0,0 +1,139 @@
+use crate::{gradients::Tape, shapes::*, tensor::*, tensor_ops::*};
+
+use super::{Module, NonMutableModule, ZeroSizedModule};
+
+/// Implements local response normalization across channels, as described in
+/// [ImageNet Classification with Deep Convolutional Neural Networks](https://papers.nips.cc/paper/2012/hash/c399862d3b9d6b76c8436e924a68c45b-Abstract.html).
+///
+/// Every element is divided by a power of the sum of squares of the `SIZE` channels around it,
+/// using [TryLocalResponseNorm::local_response_norm()]:
+///
+/// `y[c] = x[c] / (k + alpha / SIZE * sum(x[c'] ^ 2))^beta`
+///
+/// This has no learnable parameters. [Self::alpha], [Self::beta] and [Self::k] default
+/// to `1e-4`, `0.75` and `1.0`, which are the defaults of pytorch. AlexNet uses `SIZE = 5`,
+/// `alpha = 1e-4`, `beta = 0.75` and `k = 2.0`.
+///
+/// # Generics
+/// - `SIZE` The number of channels to sum over.
+///
+/// # Examples
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let lrn = LocalResponseNorm::<5> { k: 2.0, ..Default::default() };
+/// let _: Tensor<Rank3<6, 4, 4>, f32, _> = lrn.forward(dev.zeros::<Rank3<6, 4, 4>>());
+/// let _: Tensor<Rank4<8, 6, 4, 4>, f32, _> = lrn.forward(dev.zeros::<Rank4<8, 6, 4, 4>>());
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct LocalResponseNorm<const SIZE: usize> {
+    pub alpha: f32,
+    pub beta: f32,
+    pub k: f32,
+}
+
+impl<const SIZE: usize> Default for LocalResponseNorm<SIZE> {
+    /// Sets `self.alpha` to `1e-4`, `self.beta` to `0.75` and `self.k` to `1.0`
+    fn default() -> Self {
+        Self {
+            alpha: 1e-4,
+            beta: 0.75,
+            k: 1.0,
+        }
+    }
+}
+
+impl<const SIZE: usize> ZeroSizedModule for LocalResponseNorm<SIZE> {}
+impl<const SIZE: usize> NonMutableModule for LocalResponseNorm<SIZE> {}
+
+impl<const SIZE: usize> LocalResponseNorm<SIZE> {
+    fn try_norm<S: Shape + HasAxes<Ax>, Ax: Axes<Array = [isize; 1]>, E: Dtype, D, T>(
+        &self,
+        x: Tensor<S, E, D, T>,
+    ) -> Result<Tensor<S, E, D, T>, D::Err>
+    where
+        D: Device<E>,
+        T: Tape<D>,
+    {
+        x.try_local_response_norm::<Ax>(
+            SIZE,
+            E::from_f32(self.alpha).unwrap(),
+            E::from_f32(self.beta).unwrap(),
+            E::from_f32(self.k).unwrap(),
+        )
+    }
+}
+
+impl<const SIZE: usize, C: Dim, H: Dim, W: Dim, E: Dtype, D: Device<E>, T: Tape<D>>
+    Module<Tensor<(C, H, W), E, D, T>> for LocalResponseNorm<SIZE>
+{
+    type Output = Tensor<(C, H, W), E, D, T>;
+    type Error = D::Err;
+
+    fn try_forward(&self, x: Tensor<(C, H, W), E, D, T>) -> Result<Self::Output, D::Err> {
+        self.try_norm::<_, Axis<0>, _, _, _>(x)
+    }
+}
+
+impl<const SIZE: usize, B: Dim, C: Dim, H: Dim, W: Dim, E: Dtype, D: Device<E>, T: Tape<D>>
+    Module<Tensor<(B, C, H, W), E, D, T>> for LocalResponseNorm<SIZE>
+{
+    type Output = Tensor<(B, C, H, W), E, D, T>;
+    type Error = D::Err;
+
+    fn try_forward(&self, x: Tensor<(B, C, H, W), E, D, T>) -> Result<Self::Output, D::Err> {
+        self.try_norm::<_, Axis<1>, _, _, _>(x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::*;
+
+    #[test]
+    fn test_local_response_norm_3d_vs_4d() {
+        let dev: TestDevice = Default::default();
+        let m = LocalResponseNorm::<3> {
+            alpha: 0.5,
+            beta: 0.75,
+            k: 2.0,
+        };
+        let x: Tensor<Rank4<2, 5, 2, 3>, TestDtype, _> = dev.sample_normal();
+        let r = m.forward(x.trace());
+        let r_array = r.array();
+        for (i, r_i) in r_array.iter().enumerate() {
+            let x_i = x.clone().select(dev.tensor(i));
+            assert_close(r_i, &m.forward(x_i).array());
+        }
+        assert_close(
+            &r_array,
+            &x.clone()
+                .local_response_norm::<Axis<1>>(3, 0.5, 0.75, 2.0)
+                .array(),
+        );
+    }
+
+    #[test]
+    fn test_local_response_norm_single_channel() {
+        let dev: TestDevice = Default::default();
+        let m = LocalResponseNorm::<1> {
+            alpha: 3.0,
+            beta: 0.5,
+            k: 1.0,
+        };
+        // with one channel, y = x / sqrt(1 + 3 * x^2)
+        let x: Tensor<Rank3<1, 1, 3>, TestDtype, _> = dev.tensor([[[0.0, 1.0, -2.0]]]);
+        let r = m.forward(x.trace());
+        assert_close(
+            &r.array(),
+            &[[[0.0, 0.5, -2.0 / 13f64.sqrt() as TestDtype]]],
+        );
+        // dy/dx = (1 + 3 * x^2)^-1.5
+        let g = r.sum().backward();
+        assert_close(
+            &g.get(&x).array(),
+            &[[[1.0, 0.125, 1.0 / 13f64.powf(1.5) as TestDtype]]],
+        );
+    }
+}
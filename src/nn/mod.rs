@@ -20,8 +20,10 @@
 //! two functions:
 //!
 //! - [modules::BatchNorm2D]
+//! - [modules::BatchNorm3D]
 //! - [modules::DropoutOneIn]
 //! - [modules::Dropout]
+//! - [modules::Dropout2D]
 //!
 //! # Initializing
 //!
@@ -111,24 +113,41 @@ pub mod tensor_collection;
 mod activations;
 mod add_into;
 mod batchnorm2d;
+mod batchnorm3d;
 mod bias2d;
+mod bidirectional;
+#[cfg(feature = "nightly")]
 mod conv;
+mod conv1d;
+mod conv3d;
+mod convtrans;
 mod dropout;
 mod embedding;
+mod embedding_bag;
 mod flatten;
 mod generalized_residual;
+mod group_norm;
 mod impl_module_for_tuples;
 mod layer_norm;
 mod linear;
+mod local_response_norm;
 mod module;
 #[cfg(feature = "numpy")]
 mod npz;
+mod padding;
+mod pixel_shuffle;
+mod pool1d;
 mod pool2d;
+mod pool3d;
+mod pool_adaptive;
 mod pool_global;
+mod prelu;
 mod repeated;
 mod residual;
+mod rnn;
 mod split_into;
 mod transformer;
+mod upscale;
 
 pub use module::*;
 
@@ -144,24 +163,47 @@ pub mod modules {
     pub use super::activations::*;
     pub use super::add_into::AddInto;
     pub use super::batchnorm2d::BatchNorm2D;
+    pub use super::batchnorm3d::BatchNorm3D;
     pub use super::bias2d::Bias2D;
+    pub use super::bidirectional::Bidirectional;
     #[cfg(feature = "nightly")]
     pub use super::conv::Conv2D;
-    pub use super::dropout::{Dropout, DropoutOneIn};
+    #[cfg(feature = "nightly")]
+    pub use super::conv1d::Conv1D;
+    #[cfg(feature = "nightly")]
+    pub use super::conv3d::Conv3D;
+    #[cfg(feature = "nightly")]
+    pub use super::convtrans::ConvTrans2D;
+    pub use super::dropout::{Dropout, Dropout2D, DropoutOneIn};
     pub use super::embedding::Embedding;
+    pub use super::embedding_bag::EmbeddingBag;
     #[cfg(feature = "nightly")]
     pub use super::flatten::Flatten2D;
     pub use super::generalized_residual::GeneralizedResidual;
-    pub use super::layer_norm::LayerNorm1D;
+    pub use super::group_norm::GroupNorm;
+    pub use super::layer_norm::{LayerNorm, LayerNorm1D};
     pub use super::linear::Linear;
+    pub use super::local_response_norm::LocalResponseNorm;
+    #[cfg(feature = "nightly")]
+    pub use super::padding::{ReflectionPad2D, ZeroPad2D};
+    #[cfg(feature = "nightly")]
+    pub use super::pixel_shuffle::{PixelShuffle, PixelUnshuffle};
+    #[cfg(feature = "nightly")]
+    pub use super::pool1d::{AvgPool1D, MaxPool1D, MinPool1D};
+    #[cfg(feature = "nightly")]
+    pub use super::pool2d::{AvgPool2D, LpPool2D, MaxPool2D, MinPool2D};
     #[cfg(feature = "nightly")]
-    pub use super::pool2d::{AvgPool2D, MaxPool2D, MinPool2D};
+    pub use super::pool3d::{AvgPool3D, MaxPool3D, MinPool3D};
+    pub use super::pool_adaptive::{AdaptiveAvgPool2D, AdaptiveMaxPool2D};
     pub use super::pool_global::{AvgPoolGlobal, MaxPoolGlobal, MinPoolGlobal};
+    pub use super::prelu::PReLU;
     pub use super::repeated::Repeated;
     pub use super::residual::Residual;
+    pub use super::rnn::{RNNCell, RNN};
     pub use super::split_into::SplitInto;
     #[cfg(feature = "nightly")]
     pub use super::transformer::*;
+    pub use super::upscale::Upscale2D;
 }
 
 pub mod builders {
@@ -170,22 +212,45 @@ pub mod builders {
     pub use super::activations::*;
     pub use super::add_into::AddInto;
     pub use super::batchnorm2d::builder::BatchNorm2D;
+    pub use super::batchnorm3d::builder::BatchNorm3D;
     pub use super::bias2d::builder::Bias2D;
+    pub use super::bidirectional::Bidirectional;
     #[cfg(feature = "nightly")]
     pub use super::conv::builder::Conv2D;
-    pub use super::dropout::{Dropout, DropoutOneIn};
+    #[cfg(feature = "nightly")]
+    pub use super::conv1d::builder::Conv1D;
+    #[cfg(feature = "nightly")]
+    pub use super::conv3d::builder::Conv3D;
+    #[cfg(feature = "nightly")]
+    pub use super::convtrans::builder::ConvTrans2D;
+    pub use super::dropout::{Dropout, Dropout2D, DropoutOneIn};
     pub use super::embedding::builder::Embedding;
+    pub use super::embedding_bag::builder::EmbeddingBag;
     #[cfg(feature = "nightly")]
     pub use super::flatten::Flatten2D;
     pub use super::generalized_residual::GeneralizedResidual;
-    pub use super::layer_norm::builder::LayerNorm1D;
+    pub use super::group_norm::builder::GroupNorm;
+    pub use super::layer_norm::builder::{LayerNorm, LayerNorm1D};
     pub use super::linear::builder::Linear;
+    pub use super::local_response_norm::LocalResponseNorm;
+    #[cfg(feature = "nightly")]
+    pub use super::padding::{ReflectionPad2D, ZeroPad2D};
+    #[cfg(feature = "nightly")]
+    pub use super::pixel_shuffle::{PixelShuffle, PixelUnshuffle};
+    #[cfg(feature = "nightly")]
+    pub use super::pool1d::{AvgPool1D, MaxPool1D, MinPool1D};
+    #[cfg(feature = "nightly")]
+    pub use super::pool2d::{AvgPool2D, LpPool2D, MaxPool2D, MinPool2D};
     #[cfg(feature = "nightly")]
-    pub use super::pool2d::{AvgPool2D, MaxPool2D, MinPool2D};
+    pub use super::pool3d::{AvgPool3D, MaxPool3D, MinPool3D};
+    pub use super::pool_adaptive::{AdaptiveAvgPool2D, AdaptiveMaxPool2D};
     pub use super::pool_global::{AvgPoolGlobal, MaxPoolGlobal, MinPoolGlobal};
+    pub use super::prelu::builder::PReLU;
     pub use super::repeated::Repeated;
     pub use super::residual::Residual;
+    pub use super::rnn::builder::{RNNCell, RNN};
     pub use super::split_into::SplitInto;
     #[cfg(feature = "nightly")]
     pub use super::transformer::builder::*;
+    pub use super::upscale::Upscale2D;
 }
@@ -0,0 +1,115 @@
+#[cfg(feature = "nightly")]
+use crate::{
+    shapes::{Dim, Dtype},
+    tensor::{DeviceStorage, Tensor},
+    tensor_ops::{GenericPad2D, PadAlgebra, PadMode},
+};
+
+#[allow(unused)]
+use super::{Module, NonMutableModule, ZeroSizedModule};
+
+/// **Requires Nightly** Pads all 4 sides of images (3d) and batches of images (4d)
+/// with `P` zeros.
+///
+/// **Pytorch equivalent**: `torch.nn.ZeroPad2d(P)`
+///
+/// Examples:
+/// ```ignore
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let m: ZeroPad2D<2> = Default::default();
+/// let _: Tensor<Rank3<3, 8, 9>, f32, _> = m.forward(dev.zeros::<Rank3<3, 4, 5>>());
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ZeroPad2D<const P: usize>;
+
+/// **Requires Nightly** Pads all 4 sides of images (3d) and batches of images (4d)
+/// with `P` pixels, by mirroring the image at its border. `P` must be smaller than
+/// the height & width of the image.
+///
+/// **Pytorch equivalent**: `torch.nn.ReflectionPad2d(P)`
+///
+/// Examples:
+/// ```ignore
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let m: ReflectionPad2D<2> = Default::default();
+/// let _: Tensor<Rank3<3, 8, 9>, f32, _> = m.forward(dev.zeros::<Rank3<3, 4, 5>>());
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ReflectionPad2D<const P: usize>;
+
+macro_rules! impl_padding {
+    ($PadTy:ident, $Mode:expr) => {
+        impl<const P: usize> ZeroSizedModule for $PadTy<P> {}
+        impl<const P: usize> NonMutableModule for $PadTy<P> {}
+
+        #[cfg(feature = "nightly")]
+        impl<const P: usize, C: Dim, H: PadAlgebra<P>, W: PadAlgebra<P>, E: Dtype, D, T>
+            Module<Tensor<(C, H, W), E, D, T>> for $PadTy<P>
+        where
+            D: DeviceStorage,
+            Tensor<(C, H, W), E, D, T>: GenericPad2D<Dtype = E, Err = D::Err>,
+        {
+            type Output =
+                <Tensor<(C, H, W), E, D, T> as GenericPad2D>::Output<H::Padded, W::Padded>;
+            type Error = D::Err;
+
+            fn try_forward(&self, x: Tensor<(C, H, W), E, D, T>) -> Result<Self::Output, D::Err> {
+                x.generic_pad2d_like(P, P, Default::default(), Default::default(), $Mode)
+            }
+        }
+
+        #[cfg(feature = "nightly")]
+        impl<
+                const P: usize,
+                B: Dim,
+                C: Dim,
+                H: PadAlgebra<P>,
+                W: PadAlgebra<P>,
+                E: Dtype,
+                D,
+                T,
+            > Module<Tensor<(B, C, H, W), E, D, T>> for $PadTy<P>
+        where
+            D: DeviceStorage,
+            Tensor<(B, C, H, W), E, D, T>: GenericPad2D<Dtype = E, Err = D::Err>,
+        {
+            type Output =
+                <Tensor<(B, C, H, W), E, D, T> as GenericPad2D>::Output<H::Padded, W::Padded>;
+            type Error = D::Err;
+
+            fn try_forward(
+                &self,
+                x: Tensor<(B, C, H, W), E, D, T>,
+            ) -> Result<Self::Output, D::Err> {
+                x.generic_pad2d_like(P, P, Default::default(), Default::default(), $Mode)
+            }
+        }
+    };
+}
+
+impl_padding!(ZeroPad2D, PadMode::Constant(E::default()));
+impl_padding!(ReflectionPad2D, PadMode::Reflect);
+
+#[cfg(feature = "nightly")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{shapes::*, tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_padding_modules() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank4<2, 3, 4, 5>, TestDtype, _> = dev.sample_normal();
+        let r: Tensor<Rank4<2, 3, 6, 7>, _, _> = ZeroPad2D::<1>.forward(x.clone());
+        assert_eq!(
+            r.as_vec(),
+            x.clone()
+                .pad2d([1, 1, 1, 1], PadMode::Constant(0.0))
+                .as_vec()
+        );
+        let r: Tensor<Rank4<2, 3, 10, 11>, _, _> = ReflectionPad2D::<3>.forward(x.clone());
+        assert_eq!(r.as_vec(), x.pad2d([3, 3, 3, 3], PadMode::Reflect).as_vec());
+    }
+}
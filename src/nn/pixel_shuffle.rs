@@ -0,0 +1,60 @@
+#[cfg(feature = "nightly")]
+use crate::tensor_ops::{ConstPixelShuffle, ConstPixelUnshuffle};
+
+#[allow(unused)]
+use super::{Module, NonMutableModule, ZeroSizedModule};
+
+/// **Requires Nightly** Rearranges images (3d) and batches of images (4d) from shape
+/// `(C * R * R, H, W)` to `(C, H * R, W * R)`.
+///
+/// **Pytorch equivalent**: `torch.nn.PixelShuffle(R)`
+#[derive(Debug, Default, Clone, Copy)]
+pub struct PixelShuffle<const R: usize>;
+
+/// **Requires Nightly** Rearranges images (3d) and batches of images (4d) from shape
+/// `(C, H * R, W * R)` to `(C * R * R, H, W)`.
+///
+/// **Pytorch equivalent**: `torch.nn.PixelUnshuffle(R)`
+#[derive(Debug, Default, Clone, Copy)]
+pub struct PixelUnshuffle<const R: usize>;
+
+impl<const R: usize> ZeroSizedModule for PixelShuffle<R> {}
+impl<const R: usize> NonMutableModule for PixelShuffle<R> {}
+impl<const R: usize> ZeroSizedModule for PixelUnshuffle<R> {}
+impl<const R: usize> NonMutableModule for PixelUnshuffle<R> {}
+
+#[cfg(feature = "nightly")]
+impl<const R: usize, Img: ConstPixelShuffle<R>> Module<Img> for PixelShuffle<R> {
+    type Output = Img::Output;
+    type Error = Img::Err;
+
+    fn try_forward(&self, x: Img) -> Result<Self::Output, Img::Err> {
+        x.try_shuffle()
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<const R: usize, Img: ConstPixelUnshuffle<R>> Module<Img> for PixelUnshuffle<R> {
+    type Output = Img::Output;
+    type Error = Img::Err;
+
+    fn try_forward(&self, x: Img) -> Result<Self::Output, Img::Err> {
+        x.try_unshuffle()
+    }
+}
+
+#[cfg(feature = "nightly")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{shapes::*, tensor::*, tests::*};
+
+    #[test]
+    fn test_pixel_shuffle_modules() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank4<3, 16, 2, 5>, TestDtype, _> = dev.sample_normal();
+        let r: Tensor<Rank4<3, 4, 4, 10>, _, _> = PixelShuffle::<2>.forward(x.clone());
+        let r: Tensor<Rank4<3, 16, 2, 5>, _, _> = PixelUnshuffle::<2>.forward(r);
+        assert_eq!(r.array(), x.array());
+    }
+}
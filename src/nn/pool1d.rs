@@ -0,0 +1,118 @@
+#[cfg(feature = "nightly")]
+use crate::tensor_ops::{ConstAvgPool1D, ConstMaxPool1D, ConstMinPool1D};
+
+#[allow(unused)]
+use super::{BuildModule, Module, NonMutableModule, ZeroSizedModule};
+
+/// Average pool with 1d kernel that operates on sequences (2d) and batches of sequences (3d).
+/// Each patch reduces to the average of the values in the patch.
+///
+/// Generics:
+/// - `KERNEL_SIZE`: The size of the kernel applied along the length of the sequences.
+/// - `STRIDE`: How far to move the kernel each step. Defaults to `1`
+/// - `PADDING`: How much zero padding to add to both ends of the sequences. Defaults to `0`.
+#[derive(Debug, Default, Clone)]
+pub struct AvgPool1D<const KERNEL_SIZE: usize, const STRIDE: usize = 1, const PADDING: usize = 0>;
+
+/// Max pool with 1d kernel that operates on sequences (2d) and batches of sequences (3d).
+/// Each patch reduces to the maximum value in that patch.
+///
+/// Generics:
+/// - `KERNEL_SIZE`: The size of the kernel applied along the length of the sequences.
+/// - `STRIDE`: How far to move the kernel each step. Defaults to `1`
+/// - `PADDING`: How much zero padding to add to both ends of the sequences. Defaults to `0`.
+#[derive(Debug, Default, Clone)]
+pub struct MaxPool1D<const KERNEL_SIZE: usize, const STRIDE: usize = 1, const PADDING: usize = 0>;
+
+/// Minimum pool with 1d kernel that operates on sequences (2d) and batches of sequences (3d).
+/// Each patch reduces to the minimum of the values in the patch.
+///
+/// Generics:
+/// - `KERNEL_SIZE`: The size of the kernel applied along the length of the sequences.
+/// - `STRIDE`: How far to move the kernel each step. Defaults to `1`
+/// - `PADDING`: How much zero padding to add to both ends of the sequences. Defaults to `0`.
+#[derive(Debug, Default, Clone)]
+pub struct MinPool1D<const KERNEL_SIZE: usize, const STRIDE: usize = 1, const PADDING: usize = 0>;
+
+macro_rules! impl_pools {
+    ($PoolTy:tt, $Trait:ident) => {
+        impl<const K: usize, const S: usize, const P: usize> ZeroSizedModule for $PoolTy<K, S, P> {}
+        impl<const K: usize, const S: usize, const P: usize> NonMutableModule for $PoolTy<K, S, P> {}
+
+        #[cfg(feature = "nightly")]
+        impl<const K: usize, const S: usize, const P: usize, Seq: $Trait<K, S, P>> Module<Seq>
+            for $PoolTy<K, S, P>
+        {
+            type Output = Seq::Output;
+            type Error = Seq::Err;
+
+            fn try_forward(&self, x: Seq) -> Result<Self::Output, Seq::Err> {
+                x.try_pool1d()
+            }
+        }
+    };
+}
+
+impl_pools!(AvgPool1D, ConstAvgPool1D);
+impl_pools!(MaxPool1D, ConstMaxPool1D);
+impl_pools!(MinPool1D, ConstMinPool1D);
+
+#[cfg(feature = "nightly")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{shapes::*, tensor::*, tests::*};
+
+    #[test]
+    fn test_max_forward_sizes() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank2<3, 10>, TestDtype, _> = dev.zeros();
+        let _: Tensor<Rank2<3, 8>, _, _> = MaxPool1D::<3>::default().forward(x.clone());
+        let _: Tensor<Rank2<3, 9>, _, _> = MaxPool1D::<2>::default().forward(x.clone());
+        let _: Tensor<Rank2<3, 4>, _, _> = MaxPool1D::<3, 2>::default().forward(x.clone());
+        let _: Tensor<Rank2<3, 10>, _, _> = MaxPool1D::<3, 1, 1>::default().forward(x.clone());
+        let _: Tensor<Rank2<3, 6>, _, _> = MaxPool1D::<3, 2, 2>::default().forward(x);
+
+        let x: Tensor<Rank3<5, 3, 10>, TestDtype, _> = dev.zeros();
+        let _: Tensor<Rank3<5, 3, 7>, _, _> = MaxPool1D::<4>::default().forward(x.clone());
+        let _: Tensor<Rank3<5, 3, 3>, _, _> = MaxPool1D::<3, 3>::default().forward(x.clone());
+        let _: Tensor<Rank3<5, 3, 12>, _, _> = MaxPool1D::<3, 1, 2>::default().forward(x);
+    }
+
+    #[test]
+    fn test_min_forward_sizes() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank2<3, 10>, TestDtype, _> = dev.zeros();
+        let _: Tensor<Rank2<3, 8>, _, _> = MinPool1D::<3>::default().forward(x.clone());
+        let _: Tensor<Rank2<3, 4>, _, _> = MinPool1D::<3, 2>::default().forward(x.clone());
+        let _: Tensor<Rank2<3, 12>, _, _> = MinPool1D::<3, 1, 2>::default().forward(x);
+
+        let x: Tensor<Rank3<5, 3, 10>, TestDtype, _> = dev.zeros();
+        let _: Tensor<Rank3<5, 3, 9>, _, _> = MinPool1D::<2>::default().forward(x.clone());
+        let _: Tensor<Rank3<5, 3, 6>, _, _> = MinPool1D::<3, 2, 2>::default().forward(x);
+    }
+
+    #[test]
+    fn test_avg_forward_sizes() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank2<3, 10>, TestDtype, _> = dev.zeros();
+        let _: Tensor<Rank2<3, 7>, _, _> = AvgPool1D::<4>::default().forward(x.clone());
+        let _: Tensor<Rank2<3, 3>, _, _> = AvgPool1D::<3, 3>::default().forward(x.clone());
+        let _: Tensor<Rank2<3, 10>, _, _> = AvgPool1D::<3, 1, 1>::default().forward(x);
+
+        let x: Tensor<Rank3<5, 3, 10>, TestDtype, _> = dev.zeros();
+        let _: Tensor<Rank3<5, 3, 8>, _, _> = AvgPool1D::<3>::default().forward(x.clone());
+        let _: Tensor<Rank3<5, 3, 4>, _, _> = AvgPool1D::<3, 2>::default().forward(x);
+    }
+
+    #[test]
+    fn test_tuple_pool_sizes() {
+        type A = MaxPool1D<3>;
+        type B = AvgPool1D<1, 1, 1>;
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank2<1, 10>, TestDtype, _> = dev.zeros();
+
+        let _: Tensor<Rank2<1, 6>, _, _> = <(A, A)>::default().forward(x.clone());
+        let _: Tensor<Rank2<1, 8>, _, _> = <(A, A, B)>::default().forward(x);
+    }
+}
@@ -1,5 +1,10 @@
 #[cfg(feature = "nightly")]
-use crate::tensor_ops::{ConstAvgPool2D, ConstMaxPool2D, ConstMinPool2D};
+use crate::{
+    shapes::HasDtype,
+    tensor_ops::{ConstAvgPool2D, ConstLpPool2D, ConstMaxPool2D, ConstMinPool2D},
+};
+#[cfg(feature = "nightly")]
+use num_traits::FromPrimitive;
 
 #[allow(unused)]
 use super::{BuildModule, Module, NonMutableModule, ZeroSizedModule};
@@ -34,6 +39,23 @@ pub struct MaxPool2D<const KERNEL_SIZE: usize, const STRIDE: usize = 1, const PA
 #[derive(Debug, Default, Clone)]
 pub struct MinPool2D<const KERNEL_SIZE: usize, const STRIDE: usize = 1, const PADDING: usize = 0>;
 
+/// Power-average pool with 2d kernel that operates on images (3d) and batches of images (4d).
+/// Each patch reduces to the `NORM`-norm of the values in the patch, `(sum |x|^NORM)^(1/NORM)`.
+///
+/// Generics:
+/// - `NORM`: The power of the norm. `1` is sum pooling of absolute values, and larger values
+///   approach max pooling of absolute values.
+/// - `KERNEL_SIZE`: The size of the kernel applied to both width and height of the images.
+/// - `STRIDE`: How far to move the kernel each step. Defaults to `1`
+/// - `PADDING`: How much zero padding to add around the images. Defaults to `0`.
+#[derive(Debug, Default, Clone)]
+pub struct LpPool2D<
+    const NORM: usize,
+    const KERNEL_SIZE: usize,
+    const STRIDE: usize = 1,
+    const PADDING: usize = 0,
+>;
+
 macro_rules! impl_pools {
     ($PoolTy:tt, $Trait:ident) => {
         impl<const K: usize, const S: usize, const P: usize> ZeroSizedModule for $PoolTy<K, S, P> {}
@@ -57,6 +79,32 @@ impl_pools!(AvgPool2D, ConstAvgPool2D);
 impl_pools!(MaxPool2D, ConstMaxPool2D);
 impl_pools!(MinPool2D, ConstMinPool2D);
 
+impl<const N: usize, const K: usize, const S: usize, const P: usize> ZeroSizedModule
+    for LpPool2D<N, K, S, P>
+{
+}
+impl<const N: usize, const K: usize, const S: usize, const P: usize> NonMutableModule
+    for LpPool2D<N, K, S, P>
+{
+}
+
+#[cfg(feature = "nightly")]
+impl<
+        const N: usize,
+        const K: usize,
+        const S: usize,
+        const P: usize,
+        Img: ConstLpPool2D<K, S, P>,
+    > Module<Img> for LpPool2D<N, K, S, P>
+{
+    type Output = Img::Output;
+    type Error = Img::Err;
+
+    fn try_forward(&self, x: Img) -> Result<Self::Output, Img::Err> {
+        x.try_pool2d(<Img as HasDtype>::Dtype::from_usize(N).unwrap())
+    }
+}
+
 #[cfg(feature = "nightly")]
 #[cfg(test)]
 mod tests {
@@ -185,4 +233,27 @@ mod tests {
         let _: Tensor<Rank3<1, 6, 6>, _, _> = <(A, A)>::default().forward(x.clone());
         let _: Tensor<Rank3<1, 8, 8>, _, _> = <(A, A, B)>::default().forward(x.clone());
     }
+
+    #[test]
+    fn test_lp_forward_sizes() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank3<3, 10, 10>, TestDtype, _> = dev.zeros();
+        let _: Tensor<Rank3<3, 8, 8>, _, _> = LpPool2D::<2, 3>::default().forward(x.clone());
+        let _: Tensor<Rank3<3, 4, 4>, _, _> = LpPool2D::<1, 3, 2>::default().forward(x.clone());
+        let _: Tensor<Rank3<3, 10, 10>, _, _> = LpPool2D::<3, 3, 1, 1>::default().forward(x);
+
+        let x: Tensor<Rank4<5, 3, 10, 10>, TestDtype, _> = dev.zeros();
+        let _: Tensor<Rank4<5, 3, 9, 9>, _, _> = LpPool2D::<2, 2>::default().forward(x.clone());
+        let _: Tensor<Rank4<5, 3, 6, 6>, _, _> = LpPool2D::<2, 3, 2, 2>::default().forward(x);
+    }
+
+    #[test]
+    fn test_lp_forward_values() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank3<1, 2, 2>, TestDtype, _> = dev.tensor([[[3.0, -4.0], [0.0, 0.0]]]);
+        let r = LpPool2D::<2, 2>::default().forward(x.clone());
+        assert_close(&r.array(), &[[[5.0]]]);
+        let r = LpPool2D::<1, 2>::default().forward(x);
+        assert_close(&r.array(), &[[[7.0]]]);
+    }
 }
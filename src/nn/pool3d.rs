@@ -0,0 +1,111 @@
+#[cfg(feature = "nightly")]
+use crate::tensor_ops::{ConstAvgPool3D, ConstMaxPool3D, ConstMinPool3D};
+
+#[allow(unused)]
+use super::{BuildModule, Module, NonMutableModule, ZeroSizedModule};
+
+/// Average pool with 3d kernel that operates on volumes (4d) and batches of volumes (5d).
+/// Each patch reduces to the average of the values in the patch.
+///
+/// Generics:
+/// - `KERNEL_SIZE`: The size of the kernel applied to the depth, height and width of the volumes.
+/// - `STRIDE`: How far to move the kernel each step. Defaults to `1`
+/// - `PADDING`: How much zero padding to add around the volumes. Defaults to `0`.
+#[derive(Debug, Default, Clone)]
+pub struct AvgPool3D<const KERNEL_SIZE: usize, const STRIDE: usize = 1, const PADDING: usize = 0>;
+
+/// Max pool with 3d kernel that operates on volumes (4d) and batches of volumes (5d).
+/// Each patch reduces to the maximum value in that patch.
+///
+/// Generics:
+/// - `KERNEL_SIZE`: The size of the kernel applied to the depth, height and width of the volumes.
+/// - `STRIDE`: How far to move the kernel each step. Defaults to `1`
+/// - `PADDING`: How much zero padding to add around the volumes. Defaults to `0`.
+#[derive(Debug, Default, Clone)]
+pub struct MaxPool3D<const KERNEL_SIZE: usize, const STRIDE: usize = 1, const PADDING: usize = 0>;
+
+/// Minimum pool with 3d kernel that operates on volumes (4d) and batches of volumes (5d).
+/// Each patch reduces to the minimum of the values in the patch.
+///
+/// Generics:
+/// - `KERNEL_SIZE`: The size of the kernel applied to the depth, height and width of the volumes.
+/// - `STRIDE`: How far to move the kernel each step. Defaults to `1`
+/// - `PADDING`: How much zero padding to add around the volumes. Defaults to `0`.
+#[derive(Debug, Default, Clone)]
+pub struct MinPool3D<const KERNEL_SIZE: usize, const STRIDE: usize = 1, const PADDING: usize = 0>;
+
+macro_rules! impl_pools {
+    ($PoolTy:tt, $Trait:ident) => {
+        impl<const K: usize, const S: usize, const P: usize> ZeroSizedModule for $PoolTy<K, S, P> {}
+        impl<const K: usize, const S: usize, const P: usize> NonMutableModule for $PoolTy<K, S, P> {}
+
+        #[cfg(feature = "nightly")]
+        impl<const K: usize, const S: usize, const P: usize, Vol: $Trait<K, S, P>> Module<Vol>
+            for $PoolTy<K, S, P>
+        {
+            type Output = Vol::Output;
+            type Error = Vol::Err;
+
+            fn try_forward(&self, x: Vol) -> Result<Self::Output, Vol::Err> {
+                x.try_pool3d()
+            }
+        }
+    };
+}
+
+impl_pools!(AvgPool3D, ConstAvgPool3D);
+impl_pools!(MaxPool3D, ConstMaxPool3D);
+impl_pools!(MinPool3D, ConstMinPool3D);
+
+#[cfg(feature = "nightly")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{shapes::*, tensor::*, tests::*};
+
+    #[test]
+    fn test_max_forward_sizes() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank4<3, 6, 8, 10>, TestDtype, _> = dev.zeros();
+        let _: Tensor<Rank4<3, 4, 6, 8>, _, _> = MaxPool3D::<3>::default().forward(x.clone());
+        let _: Tensor<Rank4<3, 2, 3, 4>, _, _> = MaxPool3D::<3, 2>::default().forward(x.clone());
+        let _: Tensor<Rank4<3, 6, 8, 10>, _, _> = MaxPool3D::<3, 1, 1>::default().forward(x);
+
+        let x: Tensor<Rank5<5, 3, 6, 8, 10>, TestDtype, _> = dev.zeros();
+        let _: Tensor<Rank5<5, 3, 5, 7, 9>, _, _> = MaxPool3D::<2>::default().forward(x.clone());
+        let _: Tensor<Rank5<5, 3, 4, 5, 6>, _, _> = MaxPool3D::<3, 2, 2>::default().forward(x);
+    }
+
+    #[test]
+    fn test_min_forward_sizes() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank4<3, 6, 8, 10>, TestDtype, _> = dev.zeros();
+        let _: Tensor<Rank4<3, 3, 5, 7>, _, _> = MinPool3D::<4>::default().forward(x.clone());
+        let _: Tensor<Rank4<3, 2, 2, 3>, _, _> = MinPool3D::<3, 3>::default().forward(x);
+
+        let x: Tensor<Rank5<5, 3, 6, 8, 10>, TestDtype, _> = dev.zeros();
+        let _: Tensor<Rank5<5, 3, 8, 10, 12>, _, _> = MinPool3D::<3, 1, 2>::default().forward(x);
+    }
+
+    #[test]
+    fn test_avg_forward_sizes() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank4<3, 6, 8, 10>, TestDtype, _> = dev.zeros();
+        let _: Tensor<Rank4<3, 4, 6, 8>, _, _> = AvgPool3D::<3>::default().forward(x.clone());
+        let _: Tensor<Rank4<3, 3, 4, 5>, _, _> = AvgPool3D::<2, 2>::default().forward(x);
+
+        let x: Tensor<Rank5<5, 3, 6, 8, 10>, TestDtype, _> = dev.zeros();
+        let _: Tensor<Rank5<5, 3, 6, 8, 10>, _, _> = AvgPool3D::<3, 1, 1>::default().forward(x);
+    }
+
+    #[test]
+    fn test_tuple_pool_sizes() {
+        type A = MaxPool3D<3>;
+        type B = AvgPool3D<1, 1, 1>;
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank4<1, 6, 8, 10>, TestDtype, _> = dev.zeros();
+
+        let _: Tensor<Rank4<1, 2, 4, 6>, _, _> = <(A, A)>::default().forward(x.clone());
+        let _: Tensor<Rank4<1, 4, 6, 8>, _, _> = <(A, A, B)>::default().forward(x);
+    }
+}
@@ -0,0 +1,95 @@
+use crate::tensor_ops::{ConstAdaptiveAvgPool2D, ConstAdaptiveMaxPool2D};
+
+use super::{Module, NonMutableModule, ZeroSizedModule};
+
+/// Average pool that operates on images (3d) and batches of images (4d), picking
+/// the kernel size & stride based on the input so the output height & width are
+/// always `H` & `W`. Output pixel `i` averages over input pixels
+/// `floor(i * H_in / H)..ceil((i + 1) * H_in / H)` (and likewise for width).
+///
+/// **Pytorch equivalent**: `torch.nn.AdaptiveAvgPool2d((H, W))`
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let m: AdaptiveAvgPool2D<2, 3> = Default::default();
+/// let _: Tensor<Rank3<5, 2, 3>, f32, _> = m.forward(dev.zeros::<Rank3<5, 16, 8>>());
+/// let _: Tensor<Rank4<10, 5, 2, 3>, f32, _> = m.forward(dev.zeros::<Rank4<10, 5, 7, 9>>());
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct AdaptiveAvgPool2D<const H: usize, const W: usize>;
+
+/// Max pool that operates on images (3d) and batches of images (4d), picking
+/// the kernel size & stride based on the input so the output height & width are
+/// always `H` & `W`. Output pixel `i` is the max of input pixels
+/// `floor(i * H_in / H)..ceil((i + 1) * H_in / H)` (and likewise for width).
+///
+/// **Pytorch equivalent**: `torch.nn.AdaptiveMaxPool2d((H, W))`
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let m: AdaptiveMaxPool2D<2, 3> = Default::default();
+/// let _: Tensor<Rank3<5, 2, 3>, f32, _> = m.forward(dev.zeros::<Rank3<5, 16, 8>>());
+/// let _: Tensor<Rank4<10, 5, 2, 3>, f32, _> = m.forward(dev.zeros::<Rank4<10, 5, 7, 9>>());
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct AdaptiveMaxPool2D<const H: usize, const W: usize>;
+
+macro_rules! impl_pools {
+    ($PoolTy:tt, $Trait:ident) => {
+        impl<const H: usize, const W: usize> ZeroSizedModule for $PoolTy<H, W> {}
+        impl<const H: usize, const W: usize> NonMutableModule for $PoolTy<H, W> {}
+
+        impl<const H: usize, const W: usize, Img: $Trait<H, W>> Module<Img> for $PoolTy<H, W> {
+            type Output = Img::Output;
+            type Error = Img::Err;
+
+            fn try_forward(&self, x: Img) -> Result<Self::Output, Img::Err> {
+                x.try_adaptive_pool2d()
+            }
+        }
+    };
+}
+
+impl_pools!(AdaptiveAvgPool2D, ConstAdaptiveAvgPool2D);
+impl_pools!(AdaptiveMaxPool2D, ConstAdaptiveMaxPool2D);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{shapes::*, tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_adaptive_forward_sizes() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank3<3, 10, 7>, TestDtype, _> = dev.zeros();
+        let _: Tensor<Rank3<3, 1, 1>, _, _> = AdaptiveAvgPool2D::<1, 1>.forward(x.clone());
+        let _: Tensor<Rank3<3, 4, 2>, _, _> = AdaptiveMaxPool2D::<4, 2>.forward(x.clone());
+        let _: Tensor<Rank3<3, 12, 14>, _, _> = AdaptiveAvgPool2D::<12, 14>.forward(x);
+
+        let x: Tensor<(Const<5>, Const<3>, usize, usize), TestDtype, _> =
+            dev.zeros_like(&(Const, Const, 13, 6));
+        let _: Tensor<Rank4<5, 3, 3, 3>, _, _> = AdaptiveMaxPool2D::<3, 3>.forward(x);
+    }
+
+    #[test]
+    fn test_adaptive_avg_pool_matches_avg() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank4<2, 3, 4, 6>, TestDtype, _> = dev.sample_normal();
+        let r = AdaptiveAvgPool2D::<1, 1>.forward(x.clone());
+        let m = x.mean::<Rank2<2, 3>, _>();
+        assert_close(&r.as_vec(), &m.as_vec());
+    }
+
+    #[test]
+    fn test_adaptive_max_pool_matches_max() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank3<3, 5, 7>, TestDtype, _> = dev.sample_normal();
+        let r = AdaptiveMaxPool2D::<1, 1>.forward(x.clone());
+        let m = x.max::<Rank1<3>, _>();
+        assert_close(&r.as_vec(), &m.as_vec());
+    }
+}
@@ -0,0 +1,175 @@
+use crate::{gradients::Tape, shapes::*, tensor::*, tensor_ops::*};
+
+use super::{tensor_collection::*, BuildModule, BuildOnDevice, Module, NonMutableModule, ToDevice};
+
+pub mod builder {
+    #[derive(Debug)]
+    pub struct PReLU<const CHAN: usize>;
+}
+
+impl<const C: usize, E: Dtype, D: Device<E>> BuildOnDevice<D, E> for builder::PReLU<C>
+where
+    PReLU<C, E, D>: BuildModule<D, E>,
+{
+    type Built = PReLU<C, E, D>;
+    fn try_build_on_device(device: &D) -> Result<Self::Built, <D>::Err> {
+        Self::Built::try_build(device)
+    }
+}
+
+/// Calls [prelu()] with a learnable slope [Self::alpha] per channel, initialized to `0.25`.
+///
+/// Like pytorch, the channels are the first axis of 1d inputs, and the second axis otherwise.
+///
+/// Example:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// type Model = PReLU<3>;
+/// let model = dev.build_module::<Model, f32>();
+///
+/// // 2d input
+/// let x: Tensor<Rank2<10, 3>, f32, _> = dev.sample_normal();
+/// model.forward(x);
+///
+/// // 4d input
+/// let x: Tensor<Rank4<10, 3, 4, 4>, f32, _> = dev.sample_normal();
+/// model.forward(x);
+/// ```
+#[derive(Clone, Debug)]
+pub struct PReLU<const C: usize, E: Dtype, D: DeviceStorage> {
+    pub alpha: Tensor<Rank1<C>, E, D>,
+}
+
+impl<const C: usize, E: Dtype, D: Device<E>> BuildModule<D, E> for PReLU<C, E, D> {
+    fn try_build(device: &D) -> Result<Self, <D>::Err> {
+        let mut alpha = device.try_zeros()?;
+        alpha.copy_from(&[E::from_f32(0.25).unwrap(); C]);
+        Ok(Self { alpha })
+    }
+}
+
+impl<const C: usize, E: Dtype, D: DeviceStorage> NonMutableModule for PReLU<C, E, D> {}
+
+impl<const C: usize, E: Dtype, D1: Device<E>, D2: Device<E>> ToDevice<D2> for PReLU<C, E, D1> {
+    type Output = PReLU<C, E, D2>;
+
+    fn to_device(&self, device: &D2) -> Self::Output {
+        PReLU {
+            alpha: self.alpha.to_device(device),
+        }
+    }
+}
+
+impl<const C: usize, E: Dtype, D: Device<E>> TensorCollection<E, D> for PReLU<C, E, D> {
+    fn iter_tensors<V: ModuleVisitor<Self, E, D>>(visitor: &mut V) -> Result<(), V::Err> {
+        visitor.visit_tensor(
+            "alpha",
+            |s| &s.alpha,
+            |s| &mut s.alpha,
+            TensorOptions::reset_with(|t| {
+                t.copy_from(&[E::from_f32(0.25).unwrap(); C]);
+                Ok(())
+            }),
+        )
+    }
+}
+
+impl<const C: usize, E: Dtype, D: Device<E>, T: Tape<D>> Module<Tensor<Rank1<C>, E, D, T>>
+    for PReLU<C, E, D>
+{
+    type Output = Tensor<Rank1<C>, E, D, T>;
+    type Error = D::Err;
+
+    fn try_forward(&self, input: Tensor<Rank1<C>, E, D, T>) -> Result<Self::Output, D::Err> {
+        input.try_prelu(self.alpha.retaped::<T>())
+    }
+}
+
+impl<B: Dim, const C: usize, E: Dtype, D: Device<E>, T: Tape<D>>
+    Module<Tensor<(B, Const<C>), E, D, T>> for PReLU<C, E, D>
+{
+    type Output = Tensor<(B, Const<C>), E, D, T>;
+    type Error = D::Err;
+
+    fn try_forward(&self, input: Tensor<(B, Const<C>), E, D, T>) -> Result<Self::Output, D::Err> {
+        let s = *input.shape();
+        input.try_prelu(self.alpha.retaped::<T>().try_broadcast_like(&s)?)
+    }
+}
+
+impl<B: Dim, const C: usize, L: Dim, E: Dtype, D: Device<E>, T: Tape<D>>
+    Module<Tensor<(B, Const<C>, L), E, D, T>> for PReLU<C, E, D>
+{
+    type Output = Tensor<(B, Const<C>, L), E, D, T>;
+    type Error = D::Err;
+
+    fn try_forward(
+        &self,
+        input: Tensor<(B, Const<C>, L), E, D, T>,
+    ) -> Result<Self::Output, D::Err> {
+        let s = *input.shape();
+        input.try_prelu(self.alpha.retaped::<T>().try_broadcast_like(&s)?)
+    }
+}
+
+impl<B: Dim, const C: usize, H: Dim, W: Dim, E: Dtype, D: Device<E>, T: Tape<D>>
+    Module<Tensor<(B, Const<C>, H, W), E, D, T>> for PReLU<C, E, D>
+{
+    type Output = Tensor<(B, Const<C>, H, W), E, D, T>;
+    type Error = D::Err;
+
+    fn try_forward(
+        &self,
+        input: Tensor<(B, Const<C>, H, W), E, D, T>,
+    ) -> Result<Self::Output, D::Err> {
+        let s = *input.shape();
+        input.try_prelu(self.alpha.retaped::<T>().try_broadcast_like(&s)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{nn::*, optim::*, tests::*};
+
+    #[test]
+    fn test_prelu_init() {
+        let dev: TestDevice = Default::default();
+        let mut m = dev.build_module::<builder::PReLU<3>, TestDtype>();
+        assert_eq!(m.alpha.array(), [0.25; 3]);
+        m.alpha.copy_from(&[0.0; 3]);
+        m.reset_params();
+        assert_eq!(m.alpha.array(), [0.25; 3]);
+    }
+
+    #[test]
+    fn test_prelu_forward_4d() {
+        let dev: TestDevice = Default::default();
+        let mut m = dev.build_module::<builder::PReLU<2>, TestDtype>();
+        m.alpha = dev.tensor([0.5, 0.0]);
+        let x: Tensor<Rank4<2, 2, 1, 2>, TestDtype, _> = dev.tensor([
+            [[[-1.0, 2.0]], [[-3.0, 4.0]]],
+            [[[5.0, -6.0]], [[-7.0, 8.0]]],
+        ]);
+        let r = m.forward(x.trace());
+        assert_close(
+            &r.array(),
+            &[[[[-0.5, 2.0]], [[0.0, 4.0]]], [[[5.0, -3.0]], [[0.0, 8.0]]]],
+        );
+        let g = r.sum().backward();
+        assert_close(&g.get(&m.alpha).array(), &[-7.0, -10.0]);
+    }
+
+    #[test]
+    fn test_prelu_alpha_is_optimized() {
+        let dev: TestDevice = Default::default();
+        let mut m = dev.build_module::<builder::PReLU<2>, TestDtype>();
+        let mut sgd = Sgd::new(&m, Default::default());
+        let x: Tensor<Rank2<3, 2>, TestDtype, _> =
+            dev.tensor([[-1.0, 2.0], [-3.0, -4.0], [1.0, -1.0]]);
+        let g = m.forward(x.trace()).sum().backward();
+        sgd.update(&mut m, g).expect("");
+        assert_ne!(m.alpha.array(), [0.25; 2]);
+    }
+}
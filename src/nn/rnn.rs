@@ -0,0 +1,619 @@
+use crate::{gradients::Tape, shapes::*, tensor::*, tensor_ops::*};
+
+use super::{
+    activations::Tanh, tensor_collection::*, BuildModule, BuildOnDevice, Module, NonMutableModule,
+    ToDevice,
+};
+
+use num_traits::Float;
+use rand_distr::{uniform::SampleUniform, Uniform};
+use std::vec::Vec;
+
+pub mod builder {
+    use super::Tanh;
+    use core::marker::PhantomData;
+
+    #[derive(Debug, Copy, Clone)]
+    pub struct RNNCell<const I: usize, const H: usize, A = Tanh>(PhantomData<A>);
+
+    #[derive(Debug, Copy, Clone)]
+    pub struct RNN<const I: usize, const H: usize, const L: usize = 1, A = Tanh>(PhantomData<A>);
+}
+
+impl<const I: usize, const H: usize, A, E: Dtype, D: Device<E>> BuildOnDevice<D, E>
+    for builder::RNNCell<I, H, A>
+where
+    RNNCell<I, H, A, E, D>: BuildModule<D, E>,
+{
+    type Built = RNNCell<I, H, A, E, D>;
+    fn try_build_on_device(device: &D) -> Result<Self::Built, D::Err> {
+        Self::Built::try_build(device)
+    }
+}
+
+impl<const I: usize, const H: usize, const L: usize, A, E: Dtype, D: Device<E>> BuildOnDevice<D, E>
+    for builder::RNN<I, H, L, A>
+where
+    RNN<I, H, L, A, E, D>: BuildModule<D, E>,
+{
+    type Built = RNN<I, H, L, A, E, D>;
+    fn try_build_on_device(device: &D) -> Result<Self::Built, D::Err> {
+        Self::Built::try_build(device)
+    }
+}
+
+/// A single step of an Elman recurrent network, computing the next hidden state
+/// `h' = A(x * weight_ih^T + bias_ih + h * weight_hh^T + bias_hh)`.
+///
+/// This is the building block of [RNN], and can be used directly to write custom
+/// recurrent loops. The hidden state should be the tensor that carries the tape
+/// through the loop.
+///
+/// Initializes all parameters from a Uniform distribution between [-1 / sqrt(H), 1 / sqrt(H)].
+///
+/// # Generics
+/// - `I` The size of the input at each step.
+/// - `H` The size of the hidden state.
+/// - `A` The nonlinearity, usually [Tanh] or [ReLU](super::modules::ReLU). Defaults to [Tanh].
+///
+/// # Examples
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// type Model = RNNCell<3, 5>;
+/// let model = dev.build_module::<Model, f32>();
+/// let x: Tensor<Rank2<4, 3>, f32, _> = dev.zeros();
+/// let h: Tensor<Rank2<4, 5>, f32, _> = dev.zeros();
+/// let _: Tensor<Rank2<4, 5>, f32, _> = model.forward((x, h));
+/// ```
+#[derive(Debug, Clone)]
+pub struct RNNCell<const I: usize, const H: usize, A, E: Dtype, D: DeviceStorage> {
+    /// Input to hidden weight matrix, shape (H, I)
+    pub weight_ih: Tensor<Rank2<H, I>, E, D>,
+
+    /// Hidden to hidden weight matrix, shape (H, H)
+    pub weight_hh: Tensor<Rank2<H, H>, E, D>,
+
+    /// Input to hidden bias, shape (H, )
+    pub bias_ih: Tensor<Rank1<H>, E, D>,
+
+    /// Hidden to hidden bias, shape (H, )
+    pub bias_hh: Tensor<Rank1<H>, E, D>,
+
+    /// The nonlinearity applied to the new hidden state
+    pub nonlinearity: A,
+}
+
+impl<const I: usize, const H: usize, A, E: Dtype, D: DeviceStorage> NonMutableModule
+    for RNNCell<I, H, A, E, D>
+{
+}
+
+impl<const I: usize, const H: usize, A, E, D> BuildModule<D, E> for RNNCell<I, H, A, E, D>
+where
+    A: Default,
+    E: Dtype + Float + SampleUniform,
+    D: Device<E>,
+{
+    fn try_build(device: &D) -> Result<Self, D::Err> {
+        let b: E = E::ONE / E::from_usize(H).unwrap().sqrt();
+        Ok(Self {
+            weight_ih: device.try_sample(Uniform::new(-b, b))?,
+            weight_hh: device.try_sample(Uniform::new(-b, b))?,
+            bias_ih: device.try_sample(Uniform::new(-b, b))?,
+            bias_hh: device.try_sample(Uniform::new(-b, b))?,
+            nonlinearity: Default::default(),
+        })
+    }
+}
+
+impl<const I: usize, const H: usize, A, E, D> TensorCollection<E, D> for RNNCell<I, H, A, E, D>
+where
+    E: Dtype + Float + SampleUniform,
+    D: SampleTensor<E>,
+{
+    fn iter_tensors<V: ModuleVisitor<Self, E, D>>(visitor: &mut V) -> Result<(), V::Err> {
+        visitor.visit_tensor(
+            "weight_ih",
+            |s| &s.weight_ih,
+            |s| &mut s.weight_ih,
+            TensorOptions::reset_with(|t| {
+                let b: E = E::ONE / E::from_usize(H).unwrap().sqrt();
+                t.try_fill_with_distr(Uniform::new(-b, b))
+            }),
+        )?;
+        visitor.visit_tensor(
+            "weight_hh",
+            |s| &s.weight_hh,
+            |s| &mut s.weight_hh,
+            TensorOptions::reset_with(|t| {
+                let b: E = E::ONE / E::from_usize(H).unwrap().sqrt();
+                t.try_fill_with_distr(Uniform::new(-b, b))
+            }),
+        )?;
+        visitor.visit_tensor(
+            "bias_ih",
+            |s| &s.bias_ih,
+            |s| &mut s.bias_ih,
+            TensorOptions::reset_with(|t| {
+                let b: E = E::ONE / E::from_usize(H).unwrap().sqrt();
+                t.try_fill_with_distr(Uniform::new(-b, b))
+            }),
+        )?;
+        visitor.visit_tensor(
+            "bias_hh",
+            |s| &s.bias_hh,
+            |s| &mut s.bias_hh,
+            TensorOptions::reset_with(|t| {
+                let b: E = E::ONE / E::from_usize(H).unwrap().sqrt();
+                t.try_fill_with_distr(Uniform::new(-b, b))
+            }),
+        )
+    }
+}
+
+impl<const I: usize, const H: usize, A: Clone, E: Dtype, D1: Device<E>, D2: Device<E>> ToDevice<D2>
+    for RNNCell<I, H, A, E, D1>
+{
+    type Output = RNNCell<I, H, A, E, D2>;
+    fn to_device(&self, device: &D2) -> Self::Output {
+        RNNCell {
+            weight_ih: self.weight_ih.to_device(device),
+            weight_hh: self.weight_hh.to_device(device),
+            bias_ih: self.bias_ih.to_device(device),
+            bias_hh: self.bias_hh.to_device(device),
+            nonlinearity: self.nonlinearity.clone(),
+        }
+    }
+}
+
+impl<const I: usize, const H: usize, A, E: Dtype, D: Device<E>, T: Tape<D>>
+    Module<(Tensor<Rank1<I>, E, D, T>, Tensor<Rank1<H>, E, D, T>)> for RNNCell<I, H, A, E, D>
+where
+    A: Module<Tensor<Rank1<H>, E, D, T>, Output = Tensor<Rank1<H>, E, D, T>, Error = D::Err>,
+{
+    type Output = Tensor<Rank1<H>, E, D, T>;
+    type Error = D::Err;
+
+    fn try_forward(
+        &self,
+        (x, h): (Tensor<Rank1<I>, E, D, T>, Tensor<Rank1<H>, E, D, T>),
+    ) -> Result<Self::Output, D::Err> {
+        let h = h
+            .try_matmul(self.weight_hh.retaped::<T>().try_permute()?)?
+            .try_add(self.bias_hh.clone())?;
+        let x = x
+            .try_matmul(self.weight_ih.retaped::<T>().try_permute()?)?
+            .try_add(self.bias_ih.clone())?;
+        self.nonlinearity.try_forward(h.try_add(x)?)
+    }
+}
+
+impl<const I: usize, const H: usize, B: Dim, A, E: Dtype, D: Device<E>, T: Tape<D>>
+    Module<(
+        Tensor<(B, Const<I>), E, D, T>,
+        Tensor<(B, Const<H>), E, D, T>,
+    )> for RNNCell<I, H, A, E, D>
+where
+    A: Module<
+        Tensor<(B, Const<H>), E, D, T>,
+        Output = Tensor<(B, Const<H>), E, D, T>,
+        Error = D::Err,
+    >,
+{
+    type Output = Tensor<(B, Const<H>), E, D, T>;
+    type Error = D::Err;
+
+    fn try_forward(
+        &self,
+        (x, h): (
+            Tensor<(B, Const<I>), E, D, T>,
+            Tensor<(B, Const<H>), E, D, T>,
+        ),
+    ) -> Result<Self::Output, D::Err> {
+        let shape = *h.shape();
+        let h = h
+            .try_matmul(self.weight_hh.retaped::<T>().try_permute()?)?
+            .try_add(self.bias_hh.retaped::<T>().try_broadcast_like(&shape)?)?;
+        let x = x
+            .try_matmul(self.weight_ih.retaped::<T>().try_permute()?)?
+            .try_add(self.bias_ih.retaped::<T>().try_broadcast_like(&shape)?)?;
+        self.nonlinearity.try_forward(h.try_add(x)?)
+    }
+}
+
+impl<const I: usize, const H: usize, A, E: Dtype, D> RNNCell<I, H, A, E, D>
+where
+    D: Device<E> + TryStack<E> + TensorFromVec<usize>,
+{
+    /// Runs the cell over a time major `(S, B, I)` sequence starting from a zero
+    /// hidden state, and returns the hidden state of every step as `(S, B, H)`.
+    #[allow(clippy::type_complexity)]
+    fn try_forward_seq<S: Dim, B: Dim, T: Tape<D>>(
+        &self,
+        x: Tensor<(S, B, Const<I>), E, D, T>,
+    ) -> Result<Tensor<(S, B, Const<H>), E, D, T>, D::Err>
+    where
+        A: Module<
+            Tensor<(B, Const<H>), E, D, T>,
+            Output = Tensor<(B, Const<H>), E, D, T>,
+            Error = D::Err,
+        >,
+    {
+        let (seq, batch, _) = *x.shape();
+        assert!(seq.size() > 0, "RNN input sequence must not be empty");
+        let dev = x.device.clone();
+
+        // the input projection doesn't depend on the hidden state, so it is done for all steps at once
+        let shape = (seq, batch, Const::<H>);
+        let bias = self
+            .bias_ih
+            .retaped::<T>()
+            .try_add(self.bias_hh.clone())?
+            .try_broadcast_like(&shape)?;
+        let (xw, mut tape) = x
+            .try_matmul(self.weight_ih.retaped::<T>().try_permute()?)?
+            .try_add(bias)?
+            .split_tape();
+
+        // each step records onto its own tape, which is appended after the steps it depends on
+        let mut outs: Vec<Tensor<(B, Const<H>), E, D>> = Vec::with_capacity(seq.size());
+        for t in 0..seq.size() {
+            let idx = dev.try_tensor_from_vec(alloc::vec![t], ())?;
+            let x_t = xw.retaped::<T>().try_select(idx)?;
+            let pre = match outs.last() {
+                Some(h) => h
+                    .retaped::<T>()
+                    .try_matmul(self.weight_hh.retaped::<T>().try_permute()?)?
+                    .try_add(x_t)?,
+                None => x_t,
+            };
+            let (h, step_tape) = self.nonlinearity.try_forward(pre)?.split_tape();
+            tape = tape.merge(step_tape);
+            outs.push(h);
+        }
+
+        let (out, stack_tape) = dev
+            .try_stack(
+                outs.into_iter()
+                    .map(|h| h.retaped::<T>())
+                    .collect::<Vec<_>>(),
+            )?
+            .split_tape();
+        out.put_tape(tape.merge(stack_tape))
+            .try_reshape_like(&shape)
+    }
+}
+
+/// A multi-layer Elman recurrent network. Each layer is an [RNNCell] that is run over the
+/// whole sequence starting from a zero hidden state, and the hidden states of one layer
+/// are the inputs of the next.
+///
+/// The output is the hidden state of the last layer at every step, so the final hidden state
+/// is the last element of the sequence axis.
+///
+/// **Pytorch equivalent**: `torch.nn.RNN(I, H, num_layers=L, nonlinearity="tanh", batch_first=True)`
+///
+/// # Generics
+/// - `I` The size of the input at each step.
+/// - `H` The size of the hidden state of every layer.
+/// - `L` The number of stacked layers, must be at least 1. Defaults to 1.
+/// - `A` The nonlinearity, usually [Tanh] or [ReLU](super::modules::ReLU). Defaults to [Tanh].
+///
+/// # Examples
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// type Model = RNN<3, 5, 2, ReLU>;
+/// let model = dev.build_module::<Model, f32>();
+/// // single sequence forward
+/// let _: Tensor<Rank2<7, 5>, f32, _> = model.forward(dev.zeros::<Rank2<7, 3>>());
+/// // batched forward
+/// let _: Tensor<Rank3<4, 7, 5>, f32, _> = model.forward(dev.zeros::<Rank3<4, 7, 3>>());
+/// ```
+#[derive(Debug, Clone)]
+pub struct RNN<const I: usize, const H: usize, const L: usize, A, E: Dtype, D: DeviceStorage> {
+    /// The first layer, which receives the input sequence
+    pub first: RNNCell<I, H, A, E, D>,
+
+    /// The remaining `L - 1` layers
+    pub rest: Vec<RNNCell<H, H, A, E, D>>,
+}
+
+impl<const I: usize, const H: usize, const L: usize, A, E: Dtype, D: DeviceStorage> NonMutableModule
+    for RNN<I, H, L, A, E, D>
+{
+}
+
+impl<const I: usize, const H: usize, const L: usize, A, E, D> BuildModule<D, E>
+    for RNN<I, H, L, A, E, D>
+where
+    A: Default,
+    E: Dtype + Float + SampleUniform,
+    D: Device<E>,
+{
+    fn try_build(device: &D) -> Result<Self, D::Err> {
+        assert!(L > 0, "RNN must have at least one layer");
+        let first = BuildModule::try_build(device)?;
+        let mut rest = Vec::with_capacity(L - 1);
+        for _ in 1..L {
+            rest.push(BuildModule::try_build(device)?);
+        }
+        Ok(Self { first, rest })
+    }
+}
+
+impl<const I: usize, const H: usize, const L: usize, A, E, D> TensorCollection<E, D>
+    for RNN<I, H, L, A, E, D>
+where
+    E: Dtype + Float + SampleUniform,
+    D: SampleTensor<E>,
+{
+    fn iter_tensors<V: ModuleVisitor<Self, E, D>>(visitor: &mut V) -> Result<(), V::Err> {
+        visitor.visit_module("l0", |s| &s.first, |s| &mut s.first)?;
+        for i in 1..L {
+            visitor.visit_module(
+                &alloc::format!("l{i}"),
+                |s| &s.rest[i - 1],
+                |s| &mut s.rest[i - 1],
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl<const I: usize, const H: usize, const L: usize, A: Clone, E, D1, D2> ToDevice<D2>
+    for RNN<I, H, L, A, E, D1>
+where
+    E: Dtype,
+    D1: Device<E>,
+    D2: Device<E>,
+{
+    type Output = RNN<I, H, L, A, E, D2>;
+    fn to_device(&self, device: &D2) -> Self::Output {
+        RNN {
+            first: self.first.to_device(device),
+            rest: self.rest.iter().map(|l| l.to_device(device)).collect(),
+        }
+    }
+}
+
+impl<const I: usize, const H: usize, const L: usize, A, E: Dtype, D> RNN<I, H, L, A, E, D>
+where
+    D: Device<E> + TryStack<E> + TensorFromVec<usize>,
+{
+    #[allow(clippy::type_complexity)]
+    fn try_forward_seq<S: Dim, B: Dim, T: Tape<D>>(
+        &self,
+        x: Tensor<(S, B, Const<I>), E, D, T>,
+    ) -> Result<Tensor<(S, B, Const<H>), E, D, T>, D::Err>
+    where
+        A: Module<
+            Tensor<(B, Const<H>), E, D, T>,
+            Output = Tensor<(B, Const<H>), E, D, T>,
+            Error = D::Err,
+        >,
+    {
+        let mut x = self.first.try_forward_seq(x)?;
+        for layer in self.rest.iter() {
+            x = layer.try_forward_seq(x)?;
+        }
+        Ok(x)
+    }
+}
+
+impl<const I: usize, const H: usize, const L: usize, S: Dim, A, E: Dtype, D, T: Tape<D>>
+    Module<Tensor<(S, Const<I>), E, D, T>> for RNN<I, H, L, A, E, D>
+where
+    D: Device<E> + TryStack<E> + TensorFromVec<usize>,
+    A: Module<Tensor<Rank2<1, H>, E, D, T>, Output = Tensor<Rank2<1, H>, E, D, T>, Error = D::Err>,
+{
+    type Output = Tensor<(S, Const<H>), E, D, T>;
+    type Error = D::Err;
+
+    fn try_forward(&self, x: Tensor<(S, Const<I>), E, D, T>) -> Result<Self::Output, D::Err> {
+        let seq = x.shape().0;
+        let x = x.try_reshape_like(&(seq, Const::<1>, Const::<I>))?;
+        self.try_forward_seq(x)?
+            .try_reshape_like(&(seq, Const::<H>))
+    }
+}
+
+impl<
+        const I: usize,
+        const H: usize,
+        const L: usize,
+        B: Dim,
+        S: Dim,
+        A,
+        E: Dtype,
+        D,
+        T: Tape<D>,
+    > Module<Tensor<(B, S, Const<I>), E, D, T>> for RNN<I, H, L, A, E, D>
+where
+    D: Device<E> + TryStack<E> + TensorFromVec<usize>,
+    A: Module<
+        Tensor<(B, Const<H>), E, D, T>,
+        Output = Tensor<(B, Const<H>), E, D, T>,
+        Error = D::Err,
+    >,
+{
+    type Output = Tensor<(B, S, Const<H>), E, D, T>;
+    type Error = D::Err;
+
+    fn try_forward(&self, x: Tensor<(B, S, Const<I>), E, D, T>) -> Result<Self::Output, D::Err> {
+        let x = x.try_permute::<_, Axes3<1, 0, 2>>()?;
+        self.try_forward_seq(x)?.try_permute::<_, Axes3<1, 0, 2>>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        gradients::{Merge, OwnedTape},
+        nn::{builders, modules::ReLU, DeviceBuildExt},
+        tests::*,
+    };
+
+    fn cell<const I: usize, const H: usize, A: Default>(
+        dev: &TestDevice,
+        w_ih: [[TestDtype; I]; H],
+        w_hh: [[TestDtype; H]; H],
+        b_ih: [TestDtype; H],
+        b_hh: [TestDtype; H],
+    ) -> RNNCell<I, H, A, TestDtype, TestDevice> {
+        RNNCell {
+            weight_ih: dev.tensor(w_ih),
+            weight_hh: dev.tensor(w_hh),
+            bias_ih: dev.tensor(b_ih),
+            bias_hh: dev.tensor(b_hh),
+            nonlinearity: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_rnn_ondevice() {
+        let dev: TestDevice = Default::default();
+        let _: RNN<2, 3, 1, Tanh, TestDtype, _> = BuildModule::build(&dev);
+        let _ = dev.build_module::<builders::RNN<2, 3>, TestDtype>();
+        let _ = dev.build_module::<builders::RNNCell<2, 3, ReLU>, TestDtype>();
+        let m = dev.build_module::<builders::RNN<2, 3, 4>, TestDtype>();
+        assert_eq!(m.rest.len(), 3);
+    }
+
+    #[test]
+    fn test_rnn_initialize() {
+        let dev: TestDevice = Default::default();
+        let m = dev.build_module::<builders::RNN<2, 100, 2>, TestDtype>();
+        let bound: TestDtype = 0.1;
+        for l in [&m.first.weight_ih.as_vec(), &m.rest[0].weight_ih.as_vec()] {
+            for &v in l.iter() {
+                assert!(-bound <= v && v <= bound && v != 0.0);
+            }
+        }
+        for v in m.rest[0].weight_hh.as_vec() {
+            assert!(-bound <= v && v <= bound && v != 0.0);
+        }
+        for v in m.first.bias_hh.as_vec() {
+            assert!(-bound <= v && v <= bound && v != 0.0);
+        }
+    }
+
+    #[test]
+    fn test_rnn_forward_tanh() {
+        let dev: TestDevice = Default::default();
+        let model: RNN<2, 3, 1, Tanh, TestDtype, TestDevice> = RNN {
+            first: cell(
+                &dev,
+                [[0.1, -0.2], [0.3, 0.4], [-0.5, 0.6]],
+                [[0.2, -0.1, 0.0], [0.1, 0.3, -0.2], [-0.3, 0.2, 0.1]],
+                [0.1, -0.1, 0.2],
+                [0.0, 0.05, -0.05],
+            ),
+            rest: Vec::new(),
+        };
+        let x = dev.tensor([[1.0, -1.0], [0.5, 2.0], [-1.5, 0.0]]);
+        let y = model.forward(x);
+        assert_close(
+            &y.array(),
+            &[
+                [0.379949, -0.148885, -0.7397831],
+                [-0.1577922, 0.7783954, 0.7075497],
+                [-0.1580616, -0.4001022, 0.8254775],
+            ],
+        );
+    }
+
+    #[test]
+    fn test_rnn_forward_relu() {
+        let dev: TestDevice = Default::default();
+        let model: RNN<2, 3, 1, ReLU, TestDtype, TestDevice> = RNN {
+            first: cell(
+                &dev,
+                [[0.1, -0.2], [0.3, 0.4], [-0.5, 0.6]],
+                [[0.2, -0.1, 0.0], [0.1, 0.3, -0.2], [-0.3, 0.2, 0.1]],
+                [0.1, -0.1, 0.2],
+                [0.0, 0.05, -0.05],
+            ),
+            rest: Vec::new(),
+        };
+        let x = dev.tensor([[1.0, -1.0], [0.5, 2.0], [-1.5, 0.0]]);
+        let y = model.forward(x);
+        assert_close(
+            &y.array(),
+            &[[0.4, 0.0, 0.0], [0.0, 0.94, 0.98], [0.0, 0.0, 1.186]],
+        );
+    }
+
+    #[test]
+    fn test_rnn_matches_unrolled_cells() {
+        let dev: TestDevice = Default::default();
+        let model = dev.build_module::<builders::RNN<3, 4, 2>, TestDtype>();
+        let x: Tensor<Rank3<2, 5, 3>, TestDtype, _> = dev.sample_normal();
+
+        let y = model.forward(x.trace());
+        let y_vec = y.as_vec();
+        let g = y.exp().mean().backward();
+
+        // unroll both layers over time by hand with the cells
+        let mut tape: OwnedTape<TestDevice> = Default::default();
+        let mut h1: Tensor<Rank2<2, 4>, TestDtype, _> = dev.zeros();
+        let mut h2: Tensor<Rank2<2, 4>, TestDtype, _> = dev.zeros();
+        let mut outs = Vec::new();
+        for t in 0..5 {
+            let x_t: Tensor<Rank2<2, 3>, _, _, _> =
+                x.retaped::<OwnedTape<_>>().select(dev.tensor([t; 2]));
+            let (h, step) = model
+                .first
+                .forward((x_t, h1.retaped::<OwnedTape<_>>()))
+                .split_tape();
+            tape = tape.merge(step);
+            h1 = h;
+            let (h, step) = model.rest[0]
+                .forward((h1.retaped::<OwnedTape<_>>(), h2.retaped::<OwnedTape<_>>()))
+                .split_tape();
+            tape = tape.merge(step);
+            h2 = h;
+            outs.push(h2.retaped::<OwnedTape<_>>());
+        }
+        let (y2, stack_tape) = dev.stack(outs).split_tape();
+        let y2 = y2
+            .put_tape(tape.merge(stack_tape))
+            .permute::<_, Axes3<1, 0, 2>>();
+        assert_close(&y_vec, &y2.as_vec());
+
+        let g2 = y2.exp().mean().backward();
+        assert_close(&g.get(&x).array(), &g2.get(&x).array());
+        for layer in [&model.first.weight_hh, &model.rest[0].weight_hh] {
+            assert_close(&g.get(layer).array(), &g2.get(layer).array());
+        }
+        assert_close(
+            &g.get(&model.first.weight_ih).array(),
+            &g2.get(&model.first.weight_ih).array(),
+        );
+        assert_close(
+            &g.get(&model.rest[0].weight_ih).array(),
+            &g2.get(&model.rest[0].weight_ih).array(),
+        );
+        for bias in [
+            &model.first.bias_ih,
+            &model.first.bias_hh,
+            &model.rest[0].bias_ih,
+            &model.rest[0].bias_hh,
+        ] {
+            assert_close(&g.get(bias).array(), &g2.get(bias).array());
+        }
+    }
+
+    #[test]
+    fn test_rnn_batched_matches_unbatched() {
+        let dev: TestDevice = Default::default();
+        let model = dev.build_module::<builders::RNN<3, 4, 2, ReLU>, TestDtype>();
+        let x: Tensor<Rank3<2, 5, 3>, TestDtype, _> = dev.sample_normal();
+        let y = model.forward(x.clone());
+        let y0 = model.forward(x.clone().select(dev.tensor(0)));
+        let y1 = model.forward(x.select(dev.tensor(1)));
+        assert_close(&y.array(), &[y0.array(), y1.array()]);
+    }
+}
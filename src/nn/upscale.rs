@@ -0,0 +1,73 @@
+use crate::{
+    shapes::Const,
+    tensor_ops::{GenericUpscale2D, NearestNeighbor, UpscaleMethod},
+};
+
+use super::{Module, NonMutableModule, ZeroSizedModule};
+
+/// Upscales images (3d) and batches of images (4d) to a height & width of `OH` & `OW`
+/// using the [UpscaleMethod] `M`.
+///
+/// **Pytorch equivalent**: `torch.nn.Upsample(size=(OH, OW), mode="nearest")`, or
+/// `torch.nn.Upsample(size=(OH, OW), mode="bilinear", align_corners=...)` with
+/// [Bilinear](crate::tensor_ops::Bilinear).
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let m: Upscale2D<4, 6> = Default::default();
+/// let _: Tensor<Rank3<5, 4, 6>, f32, _> = m.forward(dev.zeros::<Rank3<5, 2, 3>>());
+/// let _: Tensor<Rank4<10, 5, 4, 6>, f32, _> = m.forward(dev.zeros::<Rank4<10, 5, 2, 2>>());
+/// ```
+///
+/// Bilinear interpolation is picked with the method:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let m: Upscale2D<4, 6, Bilinear> = Upscale2D(Bilinear { align_corners: true });
+/// let _: Tensor<Rank3<5, 4, 6>, f32, _> = m.forward(dev.zeros::<Rank3<5, 2, 3>>());
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Upscale2D<const OH: usize, const OW: usize, M: UpscaleMethod = NearestNeighbor>(pub M);
+
+impl<const OH: usize, const OW: usize, M: UpscaleMethod> ZeroSizedModule for Upscale2D<OH, OW, M> {}
+impl<const OH: usize, const OW: usize, M: UpscaleMethod> NonMutableModule for Upscale2D<OH, OW, M> {}
+
+impl<const OH: usize, const OW: usize, M: UpscaleMethod, Img: GenericUpscale2D<M>> Module<Img>
+    for Upscale2D<OH, OW, M>
+{
+    type Output = Img::Output<Const<OH>, Const<OW>>;
+    type Error = Img::Err;
+
+    fn try_forward(&self, x: Img) -> Result<Self::Output, Img::Err> {
+        x.generic_upscale2d_like(self.0, Const, Const)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{shapes::*, tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_upscale2d_nearest_matches_op() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank4<2, 3, 2, 3>, TestDtype, _> = dev.sample_normal();
+        let r: Tensor<Rank4<2, 3, 5, 7>, _, _> = Upscale2D::<5, 7>::default().forward(x.clone());
+        let e = x.upsample2d_nearest::<5, 7>();
+        assert_eq!(r.array(), e.array());
+    }
+
+    #[test]
+    fn test_upscale2d_bilinear_matches_op() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank3<3, 2, 3>, TestDtype, _> = dev.sample_normal();
+        let m = Upscale2D::<5, 7, Bilinear>(Bilinear {
+            align_corners: true,
+        });
+        let r: Tensor<Rank3<3, 5, 7>, _, _> = m.forward(x.clone());
+        let e = x.upsample2d_bilinear::<5, 7>(true);
+        assert_eq!(r.array(), e.array());
+    }
+}
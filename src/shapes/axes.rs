@@ -78,6 +78,36 @@ impl<const I: isize, const J: isize, const K: isize, const L: isize, const M: is
     }
 }
 
+/// A set of 7 axes
+#[rustfmt::skip]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Axes7<const I: isize, const J: isize, const K: isize, const L: isize, const M: isize, const N: isize, const O: isize>;
+#[rustfmt::skip]
+impl<const I: isize, const J: isize, const K: isize, const L: isize, const M: isize, const N: isize, const O: isize> Axes
+    for Axes7<I, J, K, L, M, N, O>
+{
+    type Array = [isize; 7];
+    #[inline(always)]
+    fn as_array() -> Self::Array {
+        [I, J, K, L, M, N, O]
+    }
+}
+
+/// A set of 8 axes
+#[rustfmt::skip]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Axes8<const I: isize, const J: isize, const K: isize, const L: isize, const M: isize, const N: isize, const O: isize, const P: isize>;
+#[rustfmt::skip]
+impl<const I: isize, const J: isize, const K: isize, const L: isize, const M: isize, const N: isize, const O: isize, const P: isize> Axes
+    for Axes8<I, J, K, L, M, N, O, P>
+{
+    type Array = [isize; 8];
+    #[inline(always)]
+    fn as_array() -> Self::Array {
+        [I, J, K, L, M, N, O, P]
+    }
+}
+
 /// Represents something that has the axes `Ax`
 pub trait HasAxes<Ax> {
     /// Returns the number of elements in dimensions along `Ax`
@@ -130,6 +160,21 @@ impl_has_axis!((D1, D2, D3, D4, D5, D6), 6, 2);
 impl_has_axis!((D1, D2, D3, D4, D5, D6), 6, 3);
 impl_has_axis!((D1, D2, D3, D4, D5, D6), 6, 4);
 impl_has_axis!((D1, D2, D3, D4, D5, D6), 6, 5);
+impl_has_axis!((D1, D2, D3, D4, D5, D6, D7), 7, 0);
+impl_has_axis!((D1, D2, D3, D4, D5, D6, D7), 7, 1);
+impl_has_axis!((D1, D2, D3, D4, D5, D6, D7), 7, 2);
+impl_has_axis!((D1, D2, D3, D4, D5, D6, D7), 7, 3);
+impl_has_axis!((D1, D2, D3, D4, D5, D6, D7), 7, 4);
+impl_has_axis!((D1, D2, D3, D4, D5, D6, D7), 7, 5);
+impl_has_axis!((D1, D2, D3, D4, D5, D6, D7), 7, 6);
+impl_has_axis!((D1, D2, D3, D4, D5, D6, D7, D8), 8, 0);
+impl_has_axis!((D1, D2, D3, D4, D5, D6, D7, D8), 8, 1);
+impl_has_axis!((D1, D2, D3, D4, D5, D6, D7, D8), 8, 2);
+impl_has_axis!((D1, D2, D3, D4, D5, D6, D7, D8), 8, 3);
+impl_has_axis!((D1, D2, D3, D4, D5, D6, D7, D8), 8, 4);
+impl_has_axis!((D1, D2, D3, D4, D5, D6, D7, D8), 8, 5);
+impl_has_axis!((D1, D2, D3, D4, D5, D6, D7, D8), 8, 6);
+impl_has_axis!((D1, D2, D3, D4, D5, D6, D7, D8), 8, 7);
 
 impl<const I: isize, const J: isize, S> HasAxes<Axes2<I, J>> for S
 where
@@ -213,3 +258,68 @@ where
             * <Self as HasAxes<Axis<N>>>::size(self)
     }
 }
+
+impl<
+        const I: isize,
+        const J: isize,
+        const K: isize,
+        const L: isize,
+        const M: isize,
+        const N: isize,
+        const O: isize,
+        S,
+    > HasAxes<Axes7<I, J, K, L, M, N, O>> for S
+where
+    Self: HasAxes<Axis<I>>
+        + HasAxes<Axis<J>>
+        + HasAxes<Axis<K>>
+        + HasAxes<Axis<L>>
+        + HasAxes<Axis<M>>
+        + HasAxes<Axis<N>>
+        + HasAxes<Axis<O>>,
+{
+    #[inline(always)]
+    fn size(&self) -> usize {
+        <Self as HasAxes<Axis<I>>>::size(self)
+            * <Self as HasAxes<Axis<J>>>::size(self)
+            * <Self as HasAxes<Axis<K>>>::size(self)
+            * <Self as HasAxes<Axis<L>>>::size(self)
+            * <Self as HasAxes<Axis<M>>>::size(self)
+            * <Self as HasAxes<Axis<N>>>::size(self)
+            * <Self as HasAxes<Axis<O>>>::size(self)
+    }
+}
+
+impl<
+        const I: isize,
+        const J: isize,
+        const K: isize,
+        const L: isize,
+        const M: isize,
+        const N: isize,
+        const O: isize,
+        const P: isize,
+        S,
+    > HasAxes<Axes8<I, J, K, L, M, N, O, P>> for S
+where
+    Self: HasAxes<Axis<I>>
+        + HasAxes<Axis<J>>
+        + HasAxes<Axis<K>>
+        + HasAxes<Axis<L>>
+        + HasAxes<Axis<M>>
+        + HasAxes<Axis<N>>
+        + HasAxes<Axis<O>>
+        + HasAxes<Axis<P>>,
+{
+    #[inline(always)]
+    fn size(&self) -> usize {
+        <Self as HasAxes<Axis<I>>>::size(self)
+            * <Self as HasAxes<Axis<J>>>::size(self)
+            * <Self as HasAxes<Axis<K>>>::size(self)
+            * <Self as HasAxes<Axis<L>>>::size(self)
+            * <Self as HasAxes<Axis<M>>>::size(self)
+            * <Self as HasAxes<Axis<N>>>::size(self)
+            * <Self as HasAxes<Axis<O>>>::size(self)
+            * <Self as HasAxes<Axis<P>>>::size(self)
+    }
+}
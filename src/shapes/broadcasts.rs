@@ -48,6 +48,8 @@ broadcast_to!(0, (), 3, (M, N, O), Axes3<0, 1, 2>);
 broadcast_to!(0, (), 4, (M, N, O, P), Axes4<0, 1, 2, 3>);
 broadcast_to!(0, (), 5, (M, N, O, P, Q), Axes5<0, 1, 2, 3, 4>);
 broadcast_to!(0, (), 6, (M, N, O, P, Q, R), Axes6<0, 1, 2, 3, 4, 5>);
+broadcast_to!(0, (), 7, (M, N, O, P, Q, R, S), Axes7<0, 1, 2, 3, 4, 5, 6>);
+broadcast_to!(0, (), 8, (M, N, O, P, Q, R, S, T), Axes8<0, 1, 2, 3, 4, 5, 6, 7>);
 
 broadcast_to!(1, (M), 2, (M, N), Axis<1>);
 broadcast_to!(1, (N), 2, (M, N), Axis<0>);
@@ -58,6 +60,32 @@ broadcast_to!(1, (M), 4, (M, N, O, P), Axes3<1, 2, 3>);
 broadcast_to!(1, (N), 4, (M, N, O, P), Axes3<0, 2, 3>);
 broadcast_to!(1, (O), 4, (M, N, O, P), Axes3<0, 1, 3>);
 broadcast_to!(1, (P), 4, (M, N, O, P), Axes3<0, 1, 2>);
+broadcast_to!(1, (M), 5, (M, N, O, P, Q), Axes4<1, 2, 3, 4>);
+broadcast_to!(1, (N), 5, (M, N, O, P, Q), Axes4<0, 2, 3, 4>);
+broadcast_to!(1, (O), 5, (M, N, O, P, Q), Axes4<0, 1, 3, 4>);
+broadcast_to!(1, (P), 5, (M, N, O, P, Q), Axes4<0, 1, 2, 4>);
+broadcast_to!(1, (Q), 5, (M, N, O, P, Q), Axes4<0, 1, 2, 3>);
+broadcast_to!(1, (M), 6, (M, N, O, P, Q, R), Axes5<1, 2, 3, 4, 5>);
+broadcast_to!(1, (N), 6, (M, N, O, P, Q, R), Axes5<0, 2, 3, 4, 5>);
+broadcast_to!(1, (O), 6, (M, N, O, P, Q, R), Axes5<0, 1, 3, 4, 5>);
+broadcast_to!(1, (P), 6, (M, N, O, P, Q, R), Axes5<0, 1, 2, 4, 5>);
+broadcast_to!(1, (Q), 6, (M, N, O, P, Q, R), Axes5<0, 1, 2, 3, 5>);
+broadcast_to!(1, (R), 6, (M, N, O, P, Q, R), Axes5<0, 1, 2, 3, 4>);
+broadcast_to!(1, (M), 7, (M, N, O, P, Q, R, S), Axes6<1, 2, 3, 4, 5, 6>);
+broadcast_to!(1, (N), 7, (M, N, O, P, Q, R, S), Axes6<0, 2, 3, 4, 5, 6>);
+broadcast_to!(1, (O), 7, (M, N, O, P, Q, R, S), Axes6<0, 1, 3, 4, 5, 6>);
+broadcast_to!(1, (P), 7, (M, N, O, P, Q, R, S), Axes6<0, 1, 2, 4, 5, 6>);
+broadcast_to!(1, (Q), 7, (M, N, O, P, Q, R, S), Axes6<0, 1, 2, 3, 5, 6>);
+broadcast_to!(1, (R), 7, (M, N, O, P, Q, R, S), Axes6<0, 1, 2, 3, 4, 6>);
+broadcast_to!(1, (S), 7, (M, N, O, P, Q, R, S), Axes6<0, 1, 2, 3, 4, 5>);
+broadcast_to!(1, (M), 8, (M, N, O, P, Q, R, S, T), Axes7<1, 2, 3, 4, 5, 6, 7>);
+broadcast_to!(1, (N), 8, (M, N, O, P, Q, R, S, T), Axes7<0, 2, 3, 4, 5, 6, 7>);
+broadcast_to!(1, (O), 8, (M, N, O, P, Q, R, S, T), Axes7<0, 1, 3, 4, 5, 6, 7>);
+broadcast_to!(1, (P), 8, (M, N, O, P, Q, R, S, T), Axes7<0, 1, 2, 4, 5, 6, 7>);
+broadcast_to!(1, (Q), 8, (M, N, O, P, Q, R, S, T), Axes7<0, 1, 2, 3, 5, 6, 7>);
+broadcast_to!(1, (R), 8, (M, N, O, P, Q, R, S, T), Axes7<0, 1, 2, 3, 4, 6, 7>);
+broadcast_to!(1, (S), 8, (M, N, O, P, Q, R, S, T), Axes7<0, 1, 2, 3, 4, 5, 7>);
+broadcast_to!(1, (T), 8, (M, N, O, P, Q, R, S, T), Axes7<0, 1, 2, 3, 4, 5, 6>);
 
 broadcast_to!(2, (M, N), 3, (M, N, O), Axis<2>);
 broadcast_to!(2, (M, O), 3, (M, N, O), Axis<1>);
@@ -68,11 +96,562 @@ broadcast_to!(2, (N, O), 4, (M, N, O, P), Axes2<0, 3>);
 broadcast_to!(2, (M, P), 4, (M, N, O, P), Axes2<1, 2>);
 broadcast_to!(2, (N, P), 4, (M, N, O, P), Axes2<0, 2>);
 broadcast_to!(2, (O, P), 4, (M, N, O, P), Axes2<0, 1>);
+broadcast_to!(2, (M, N), 5, (M, N, O, P, Q), Axes3<2, 3, 4>);
+broadcast_to!(2, (M, O), 5, (M, N, O, P, Q), Axes3<1, 3, 4>);
+broadcast_to!(2, (N, O), 5, (M, N, O, P, Q), Axes3<0, 3, 4>);
+broadcast_to!(2, (M, P), 5, (M, N, O, P, Q), Axes3<1, 2, 4>);
+broadcast_to!(2, (N, P), 5, (M, N, O, P, Q), Axes3<0, 2, 4>);
+broadcast_to!(2, (O, P), 5, (M, N, O, P, Q), Axes3<0, 1, 4>);
+broadcast_to!(2, (M, Q), 5, (M, N, O, P, Q), Axes3<1, 2, 3>);
+broadcast_to!(2, (N, Q), 5, (M, N, O, P, Q), Axes3<0, 2, 3>);
+broadcast_to!(2, (O, Q), 5, (M, N, O, P, Q), Axes3<0, 1, 3>);
+broadcast_to!(2, (P, Q), 5, (M, N, O, P, Q), Axes3<0, 1, 2>);
+broadcast_to!(2, (M, N), 6, (M, N, O, P, Q, R), Axes4<2, 3, 4, 5>);
+broadcast_to!(2, (M, O), 6, (M, N, O, P, Q, R), Axes4<1, 3, 4, 5>);
+broadcast_to!(2, (N, O), 6, (M, N, O, P, Q, R), Axes4<0, 3, 4, 5>);
+broadcast_to!(2, (M, P), 6, (M, N, O, P, Q, R), Axes4<1, 2, 4, 5>);
+broadcast_to!(2, (N, P), 6, (M, N, O, P, Q, R), Axes4<0, 2, 4, 5>);
+broadcast_to!(2, (O, P), 6, (M, N, O, P, Q, R), Axes4<0, 1, 4, 5>);
+broadcast_to!(2, (M, Q), 6, (M, N, O, P, Q, R), Axes4<1, 2, 3, 5>);
+broadcast_to!(2, (N, Q), 6, (M, N, O, P, Q, R), Axes4<0, 2, 3, 5>);
+broadcast_to!(2, (O, Q), 6, (M, N, O, P, Q, R), Axes4<0, 1, 3, 5>);
+broadcast_to!(2, (P, Q), 6, (M, N, O, P, Q, R), Axes4<0, 1, 2, 5>);
+broadcast_to!(2, (M, R), 6, (M, N, O, P, Q, R), Axes4<1, 2, 3, 4>);
+broadcast_to!(2, (N, R), 6, (M, N, O, P, Q, R), Axes4<0, 2, 3, 4>);
+broadcast_to!(2, (O, R), 6, (M, N, O, P, Q, R), Axes4<0, 1, 3, 4>);
+broadcast_to!(2, (P, R), 6, (M, N, O, P, Q, R), Axes4<0, 1, 2, 4>);
+broadcast_to!(2, (Q, R), 6, (M, N, O, P, Q, R), Axes4<0, 1, 2, 3>);
+broadcast_to!(2, (M, N), 7, (M, N, O, P, Q, R, S), Axes5<2, 3, 4, 5, 6>);
+broadcast_to!(2, (M, O), 7, (M, N, O, P, Q, R, S), Axes5<1, 3, 4, 5, 6>);
+broadcast_to!(2, (N, O), 7, (M, N, O, P, Q, R, S), Axes5<0, 3, 4, 5, 6>);
+broadcast_to!(2, (M, P), 7, (M, N, O, P, Q, R, S), Axes5<1, 2, 4, 5, 6>);
+broadcast_to!(2, (N, P), 7, (M, N, O, P, Q, R, S), Axes5<0, 2, 4, 5, 6>);
+broadcast_to!(2, (O, P), 7, (M, N, O, P, Q, R, S), Axes5<0, 1, 4, 5, 6>);
+broadcast_to!(2, (M, Q), 7, (M, N, O, P, Q, R, S), Axes5<1, 2, 3, 5, 6>);
+broadcast_to!(2, (N, Q), 7, (M, N, O, P, Q, R, S), Axes5<0, 2, 3, 5, 6>);
+broadcast_to!(2, (O, Q), 7, (M, N, O, P, Q, R, S), Axes5<0, 1, 3, 5, 6>);
+broadcast_to!(2, (P, Q), 7, (M, N, O, P, Q, R, S), Axes5<0, 1, 2, 5, 6>);
+broadcast_to!(2, (M, R), 7, (M, N, O, P, Q, R, S), Axes5<1, 2, 3, 4, 6>);
+broadcast_to!(2, (N, R), 7, (M, N, O, P, Q, R, S), Axes5<0, 2, 3, 4, 6>);
+broadcast_to!(2, (O, R), 7, (M, N, O, P, Q, R, S), Axes5<0, 1, 3, 4, 6>);
+broadcast_to!(2, (P, R), 7, (M, N, O, P, Q, R, S), Axes5<0, 1, 2, 4, 6>);
+broadcast_to!(2, (Q, R), 7, (M, N, O, P, Q, R, S), Axes5<0, 1, 2, 3, 6>);
+broadcast_to!(2, (M, S), 7, (M, N, O, P, Q, R, S), Axes5<1, 2, 3, 4, 5>);
+broadcast_to!(2, (N, S), 7, (M, N, O, P, Q, R, S), Axes5<0, 2, 3, 4, 5>);
+broadcast_to!(2, (O, S), 7, (M, N, O, P, Q, R, S), Axes5<0, 1, 3, 4, 5>);
+broadcast_to!(2, (P, S), 7, (M, N, O, P, Q, R, S), Axes5<0, 1, 2, 4, 5>);
+broadcast_to!(2, (Q, S), 7, (M, N, O, P, Q, R, S), Axes5<0, 1, 2, 3, 5>);
+broadcast_to!(2, (R, S), 7, (M, N, O, P, Q, R, S), Axes5<0, 1, 2, 3, 4>);
+broadcast_to!(2, (M, N), 8, (M, N, O, P, Q, R, S, T), Axes6<2, 3, 4, 5, 6, 7>);
+broadcast_to!(2, (M, O), 8, (M, N, O, P, Q, R, S, T), Axes6<1, 3, 4, 5, 6, 7>);
+broadcast_to!(2, (N, O), 8, (M, N, O, P, Q, R, S, T), Axes6<0, 3, 4, 5, 6, 7>);
+broadcast_to!(2, (M, P), 8, (M, N, O, P, Q, R, S, T), Axes6<1, 2, 4, 5, 6, 7>);
+broadcast_to!(2, (N, P), 8, (M, N, O, P, Q, R, S, T), Axes6<0, 2, 4, 5, 6, 7>);
+broadcast_to!(2, (O, P), 8, (M, N, O, P, Q, R, S, T), Axes6<0, 1, 4, 5, 6, 7>);
+broadcast_to!(2, (M, Q), 8, (M, N, O, P, Q, R, S, T), Axes6<1, 2, 3, 5, 6, 7>);
+broadcast_to!(2, (N, Q), 8, (M, N, O, P, Q, R, S, T), Axes6<0, 2, 3, 5, 6, 7>);
+broadcast_to!(2, (O, Q), 8, (M, N, O, P, Q, R, S, T), Axes6<0, 1, 3, 5, 6, 7>);
+broadcast_to!(2, (P, Q), 8, (M, N, O, P, Q, R, S, T), Axes6<0, 1, 2, 5, 6, 7>);
+broadcast_to!(2, (M, R), 8, (M, N, O, P, Q, R, S, T), Axes6<1, 2, 3, 4, 6, 7>);
+broadcast_to!(2, (N, R), 8, (M, N, O, P, Q, R, S, T), Axes6<0, 2, 3, 4, 6, 7>);
+broadcast_to!(2, (O, R), 8, (M, N, O, P, Q, R, S, T), Axes6<0, 1, 3, 4, 6, 7>);
+broadcast_to!(2, (P, R), 8, (M, N, O, P, Q, R, S, T), Axes6<0, 1, 2, 4, 6, 7>);
+broadcast_to!(2, (Q, R), 8, (M, N, O, P, Q, R, S, T), Axes6<0, 1, 2, 3, 6, 7>);
+broadcast_to!(2, (M, S), 8, (M, N, O, P, Q, R, S, T), Axes6<1, 2, 3, 4, 5, 7>);
+broadcast_to!(2, (N, S), 8, (M, N, O, P, Q, R, S, T), Axes6<0, 2, 3, 4, 5, 7>);
+broadcast_to!(2, (O, S), 8, (M, N, O, P, Q, R, S, T), Axes6<0, 1, 3, 4, 5, 7>);
+broadcast_to!(2, (P, S), 8, (M, N, O, P, Q, R, S, T), Axes6<0, 1, 2, 4, 5, 7>);
+broadcast_to!(2, (Q, S), 8, (M, N, O, P, Q, R, S, T), Axes6<0, 1, 2, 3, 5, 7>);
+broadcast_to!(2, (R, S), 8, (M, N, O, P, Q, R, S, T), Axes6<0, 1, 2, 3, 4, 7>);
+broadcast_to!(2, (M, T), 8, (M, N, O, P, Q, R, S, T), Axes6<1, 2, 3, 4, 5, 6>);
+broadcast_to!(2, (N, T), 8, (M, N, O, P, Q, R, S, T), Axes6<0, 2, 3, 4, 5, 6>);
+broadcast_to!(2, (O, T), 8, (M, N, O, P, Q, R, S, T), Axes6<0, 1, 3, 4, 5, 6>);
+broadcast_to!(2, (P, T), 8, (M, N, O, P, Q, R, S, T), Axes6<0, 1, 2, 4, 5, 6>);
+broadcast_to!(2, (Q, T), 8, (M, N, O, P, Q, R, S, T), Axes6<0, 1, 2, 3, 5, 6>);
+broadcast_to!(2, (R, T), 8, (M, N, O, P, Q, R, S, T), Axes6<0, 1, 2, 3, 4, 6>);
+broadcast_to!(2, (S, T), 8, (M, N, O, P, Q, R, S, T), Axes6<0, 1, 2, 3, 4, 5>);
 
 broadcast_to!(3, (M, N, O), 4, (M, N, O, P), Axis<3>);
 broadcast_to!(3, (M, N, P), 4, (M, N, O, P), Axis<2>);
 broadcast_to!(3, (M, O, P), 4, (M, N, O, P), Axis<1>);
 broadcast_to!(3, (N, O, P), 4, (M, N, O, P), Axis<0>);
+broadcast_to!(3, (M, N, O), 5, (M, N, O, P, Q), Axes2<3, 4>);
+broadcast_to!(3, (M, N, P), 5, (M, N, O, P, Q), Axes2<2, 4>);
+broadcast_to!(3, (M, O, P), 5, (M, N, O, P, Q), Axes2<1, 4>);
+broadcast_to!(3, (N, O, P), 5, (M, N, O, P, Q), Axes2<0, 4>);
+broadcast_to!(3, (M, N, Q), 5, (M, N, O, P, Q), Axes2<2, 3>);
+broadcast_to!(3, (M, O, Q), 5, (M, N, O, P, Q), Axes2<1, 3>);
+broadcast_to!(3, (N, O, Q), 5, (M, N, O, P, Q), Axes2<0, 3>);
+broadcast_to!(3, (M, P, Q), 5, (M, N, O, P, Q), Axes2<1, 2>);
+broadcast_to!(3, (N, P, Q), 5, (M, N, O, P, Q), Axes2<0, 2>);
+broadcast_to!(3, (O, P, Q), 5, (M, N, O, P, Q), Axes2<0, 1>);
+broadcast_to!(3, (M, N, O), 6, (M, N, O, P, Q, R), Axes3<3, 4, 5>);
+broadcast_to!(3, (M, N, P), 6, (M, N, O, P, Q, R), Axes3<2, 4, 5>);
+broadcast_to!(3, (M, O, P), 6, (M, N, O, P, Q, R), Axes3<1, 4, 5>);
+broadcast_to!(3, (N, O, P), 6, (M, N, O, P, Q, R), Axes3<0, 4, 5>);
+broadcast_to!(3, (M, N, Q), 6, (M, N, O, P, Q, R), Axes3<2, 3, 5>);
+broadcast_to!(3, (M, O, Q), 6, (M, N, O, P, Q, R), Axes3<1, 3, 5>);
+broadcast_to!(3, (N, O, Q), 6, (M, N, O, P, Q, R), Axes3<0, 3, 5>);
+broadcast_to!(3, (M, P, Q), 6, (M, N, O, P, Q, R), Axes3<1, 2, 5>);
+broadcast_to!(3, (N, P, Q), 6, (M, N, O, P, Q, R), Axes3<0, 2, 5>);
+broadcast_to!(3, (O, P, Q), 6, (M, N, O, P, Q, R), Axes3<0, 1, 5>);
+broadcast_to!(3, (M, N, R), 6, (M, N, O, P, Q, R), Axes3<2, 3, 4>);
+broadcast_to!(3, (M, O, R), 6, (M, N, O, P, Q, R), Axes3<1, 3, 4>);
+broadcast_to!(3, (N, O, R), 6, (M, N, O, P, Q, R), Axes3<0, 3, 4>);
+broadcast_to!(3, (M, P, R), 6, (M, N, O, P, Q, R), Axes3<1, 2, 4>);
+broadcast_to!(3, (N, P, R), 6, (M, N, O, P, Q, R), Axes3<0, 2, 4>);
+broadcast_to!(3, (O, P, R), 6, (M, N, O, P, Q, R), Axes3<0, 1, 4>);
+broadcast_to!(3, (M, Q, R), 6, (M, N, O, P, Q, R), Axes3<1, 2, 3>);
+broadcast_to!(3, (N, Q, R), 6, (M, N, O, P, Q, R), Axes3<0, 2, 3>);
+broadcast_to!(3, (O, Q, R), 6, (M, N, O, P, Q, R), Axes3<0, 1, 3>);
+broadcast_to!(3, (P, Q, R), 6, (M, N, O, P, Q, R), Axes3<0, 1, 2>);
+broadcast_to!(3, (M, N, O), 7, (M, N, O, P, Q, R, S), Axes4<3, 4, 5, 6>);
+broadcast_to!(3, (M, N, P), 7, (M, N, O, P, Q, R, S), Axes4<2, 4, 5, 6>);
+broadcast_to!(3, (M, O, P), 7, (M, N, O, P, Q, R, S), Axes4<1, 4, 5, 6>);
+broadcast_to!(3, (N, O, P), 7, (M, N, O, P, Q, R, S), Axes4<0, 4, 5, 6>);
+broadcast_to!(3, (M, N, Q), 7, (M, N, O, P, Q, R, S), Axes4<2, 3, 5, 6>);
+broadcast_to!(3, (M, O, Q), 7, (M, N, O, P, Q, R, S), Axes4<1, 3, 5, 6>);
+broadcast_to!(3, (N, O, Q), 7, (M, N, O, P, Q, R, S), Axes4<0, 3, 5, 6>);
+broadcast_to!(3, (M, P, Q), 7, (M, N, O, P, Q, R, S), Axes4<1, 2, 5, 6>);
+broadcast_to!(3, (N, P, Q), 7, (M, N, O, P, Q, R, S), Axes4<0, 2, 5, 6>);
+broadcast_to!(3, (O, P, Q), 7, (M, N, O, P, Q, R, S), Axes4<0, 1, 5, 6>);
+broadcast_to!(3, (M, N, R), 7, (M, N, O, P, Q, R, S), Axes4<2, 3, 4, 6>);
+broadcast_to!(3, (M, O, R), 7, (M, N, O, P, Q, R, S), Axes4<1, 3, 4, 6>);
+broadcast_to!(3, (N, O, R), 7, (M, N, O, P, Q, R, S), Axes4<0, 3, 4, 6>);
+broadcast_to!(3, (M, P, R), 7, (M, N, O, P, Q, R, S), Axes4<1, 2, 4, 6>);
+broadcast_to!(3, (N, P, R), 7, (M, N, O, P, Q, R, S), Axes4<0, 2, 4, 6>);
+broadcast_to!(3, (O, P, R), 7, (M, N, O, P, Q, R, S), Axes4<0, 1, 4, 6>);
+broadcast_to!(3, (M, Q, R), 7, (M, N, O, P, Q, R, S), Axes4<1, 2, 3, 6>);
+broadcast_to!(3, (N, Q, R), 7, (M, N, O, P, Q, R, S), Axes4<0, 2, 3, 6>);
+broadcast_to!(3, (O, Q, R), 7, (M, N, O, P, Q, R, S), Axes4<0, 1, 3, 6>);
+broadcast_to!(3, (P, Q, R), 7, (M, N, O, P, Q, R, S), Axes4<0, 1, 2, 6>);
+broadcast_to!(3, (M, N, S), 7, (M, N, O, P, Q, R, S), Axes4<2, 3, 4, 5>);
+broadcast_to!(3, (M, O, S), 7, (M, N, O, P, Q, R, S), Axes4<1, 3, 4, 5>);
+broadcast_to!(3, (N, O, S), 7, (M, N, O, P, Q, R, S), Axes4<0, 3, 4, 5>);
+broadcast_to!(3, (M, P, S), 7, (M, N, O, P, Q, R, S), Axes4<1, 2, 4, 5>);
+broadcast_to!(3, (N, P, S), 7, (M, N, O, P, Q, R, S), Axes4<0, 2, 4, 5>);
+broadcast_to!(3, (O, P, S), 7, (M, N, O, P, Q, R, S), Axes4<0, 1, 4, 5>);
+broadcast_to!(3, (M, Q, S), 7, (M, N, O, P, Q, R, S), Axes4<1, 2, 3, 5>);
+broadcast_to!(3, (N, Q, S), 7, (M, N, O, P, Q, R, S), Axes4<0, 2, 3, 5>);
+broadcast_to!(3, (O, Q, S), 7, (M, N, O, P, Q, R, S), Axes4<0, 1, 3, 5>);
+broadcast_to!(3, (P, Q, S), 7, (M, N, O, P, Q, R, S), Axes4<0, 1, 2, 5>);
+broadcast_to!(3, (M, R, S), 7, (M, N, O, P, Q, R, S), Axes4<1, 2, 3, 4>);
+broadcast_to!(3, (N, R, S), 7, (M, N, O, P, Q, R, S), Axes4<0, 2, 3, 4>);
+broadcast_to!(3, (O, R, S), 7, (M, N, O, P, Q, R, S), Axes4<0, 1, 3, 4>);
+broadcast_to!(3, (P, R, S), 7, (M, N, O, P, Q, R, S), Axes4<0, 1, 2, 4>);
+broadcast_to!(3, (Q, R, S), 7, (M, N, O, P, Q, R, S), Axes4<0, 1, 2, 3>);
+broadcast_to!(3, (M, N, O), 8, (M, N, O, P, Q, R, S, T), Axes5<3, 4, 5, 6, 7>);
+broadcast_to!(3, (M, N, P), 8, (M, N, O, P, Q, R, S, T), Axes5<2, 4, 5, 6, 7>);
+broadcast_to!(3, (M, O, P), 8, (M, N, O, P, Q, R, S, T), Axes5<1, 4, 5, 6, 7>);
+broadcast_to!(3, (N, O, P), 8, (M, N, O, P, Q, R, S, T), Axes5<0, 4, 5, 6, 7>);
+broadcast_to!(3, (M, N, Q), 8, (M, N, O, P, Q, R, S, T), Axes5<2, 3, 5, 6, 7>);
+broadcast_to!(3, (M, O, Q), 8, (M, N, O, P, Q, R, S, T), Axes5<1, 3, 5, 6, 7>);
+broadcast_to!(3, (N, O, Q), 8, (M, N, O, P, Q, R, S, T), Axes5<0, 3, 5, 6, 7>);
+broadcast_to!(3, (M, P, Q), 8, (M, N, O, P, Q, R, S, T), Axes5<1, 2, 5, 6, 7>);
+broadcast_to!(3, (N, P, Q), 8, (M, N, O, P, Q, R, S, T), Axes5<0, 2, 5, 6, 7>);
+broadcast_to!(3, (O, P, Q), 8, (M, N, O, P, Q, R, S, T), Axes5<0, 1, 5, 6, 7>);
+broadcast_to!(3, (M, N, R), 8, (M, N, O, P, Q, R, S, T), Axes5<2, 3, 4, 6, 7>);
+broadcast_to!(3, (M, O, R), 8, (M, N, O, P, Q, R, S, T), Axes5<1, 3, 4, 6, 7>);
+broadcast_to!(3, (N, O, R), 8, (M, N, O, P, Q, R, S, T), Axes5<0, 3, 4, 6, 7>);
+broadcast_to!(3, (M, P, R), 8, (M, N, O, P, Q, R, S, T), Axes5<1, 2, 4, 6, 7>);
+broadcast_to!(3, (N, P, R), 8, (M, N, O, P, Q, R, S, T), Axes5<0, 2, 4, 6, 7>);
+broadcast_to!(3, (O, P, R), 8, (M, N, O, P, Q, R, S, T), Axes5<0, 1, 4, 6, 7>);
+broadcast_to!(3, (M, Q, R), 8, (M, N, O, P, Q, R, S, T), Axes5<1, 2, 3, 6, 7>);
+broadcast_to!(3, (N, Q, R), 8, (M, N, O, P, Q, R, S, T), Axes5<0, 2, 3, 6, 7>);
+broadcast_to!(3, (O, Q, R), 8, (M, N, O, P, Q, R, S, T), Axes5<0, 1, 3, 6, 7>);
+broadcast_to!(3, (P, Q, R), 8, (M, N, O, P, Q, R, S, T), Axes5<0, 1, 2, 6, 7>);
+broadcast_to!(3, (M, N, S), 8, (M, N, O, P, Q, R, S, T), Axes5<2, 3, 4, 5, 7>);
+broadcast_to!(3, (M, O, S), 8, (M, N, O, P, Q, R, S, T), Axes5<1, 3, 4, 5, 7>);
+broadcast_to!(3, (N, O, S), 8, (M, N, O, P, Q, R, S, T), Axes5<0, 3, 4, 5, 7>);
+broadcast_to!(3, (M, P, S), 8, (M, N, O, P, Q, R, S, T), Axes5<1, 2, 4, 5, 7>);
+broadcast_to!(3, (N, P, S), 8, (M, N, O, P, Q, R, S, T), Axes5<0, 2, 4, 5, 7>);
+broadcast_to!(3, (O, P, S), 8, (M, N, O, P, Q, R, S, T), Axes5<0, 1, 4, 5, 7>);
+broadcast_to!(3, (M, Q, S), 8, (M, N, O, P, Q, R, S, T), Axes5<1, 2, 3, 5, 7>);
+broadcast_to!(3, (N, Q, S), 8, (M, N, O, P, Q, R, S, T), Axes5<0, 2, 3, 5, 7>);
+broadcast_to!(3, (O, Q, S), 8, (M, N, O, P, Q, R, S, T), Axes5<0, 1, 3, 5, 7>);
+broadcast_to!(3, (P, Q, S), 8, (M, N, O, P, Q, R, S, T), Axes5<0, 1, 2, 5, 7>);
+broadcast_to!(3, (M, R, S), 8, (M, N, O, P, Q, R, S, T), Axes5<1, 2, 3, 4, 7>);
+broadcast_to!(3, (N, R, S), 8, (M, N, O, P, Q, R, S, T), Axes5<0, 2, 3, 4, 7>);
+broadcast_to!(3, (O, R, S), 8, (M, N, O, P, Q, R, S, T), Axes5<0, 1, 3, 4, 7>);
+broadcast_to!(3, (P, R, S), 8, (M, N, O, P, Q, R, S, T), Axes5<0, 1, 2, 4, 7>);
+broadcast_to!(3, (Q, R, S), 8, (M, N, O, P, Q, R, S, T), Axes5<0, 1, 2, 3, 7>);
+broadcast_to!(3, (M, N, T), 8, (M, N, O, P, Q, R, S, T), Axes5<2, 3, 4, 5, 6>);
+broadcast_to!(3, (M, O, T), 8, (M, N, O, P, Q, R, S, T), Axes5<1, 3, 4, 5, 6>);
+broadcast_to!(3, (N, O, T), 8, (M, N, O, P, Q, R, S, T), Axes5<0, 3, 4, 5, 6>);
+broadcast_to!(3, (M, P, T), 8, (M, N, O, P, Q, R, S, T), Axes5<1, 2, 4, 5, 6>);
+broadcast_to!(3, (N, P, T), 8, (M, N, O, P, Q, R, S, T), Axes5<0, 2, 4, 5, 6>);
+broadcast_to!(3, (O, P, T), 8, (M, N, O, P, Q, R, S, T), Axes5<0, 1, 4, 5, 6>);
+broadcast_to!(3, (M, Q, T), 8, (M, N, O, P, Q, R, S, T), Axes5<1, 2, 3, 5, 6>);
+broadcast_to!(3, (N, Q, T), 8, (M, N, O, P, Q, R, S, T), Axes5<0, 2, 3, 5, 6>);
+broadcast_to!(3, (O, Q, T), 8, (M, N, O, P, Q, R, S, T), Axes5<0, 1, 3, 5, 6>);
+broadcast_to!(3, (P, Q, T), 8, (M, N, O, P, Q, R, S, T), Axes5<0, 1, 2, 5, 6>);
+broadcast_to!(3, (M, R, T), 8, (M, N, O, P, Q, R, S, T), Axes5<1, 2, 3, 4, 6>);
+broadcast_to!(3, (N, R, T), 8, (M, N, O, P, Q, R, S, T), Axes5<0, 2, 3, 4, 6>);
+broadcast_to!(3, (O, R, T), 8, (M, N, O, P, Q, R, S, T), Axes5<0, 1, 3, 4, 6>);
+broadcast_to!(3, (P, R, T), 8, (M, N, O, P, Q, R, S, T), Axes5<0, 1, 2, 4, 6>);
+broadcast_to!(3, (Q, R, T), 8, (M, N, O, P, Q, R, S, T), Axes5<0, 1, 2, 3, 6>);
+broadcast_to!(3, (M, S, T), 8, (M, N, O, P, Q, R, S, T), Axes5<1, 2, 3, 4, 5>);
+broadcast_to!(3, (N, S, T), 8, (M, N, O, P, Q, R, S, T), Axes5<0, 2, 3, 4, 5>);
+broadcast_to!(3, (O, S, T), 8, (M, N, O, P, Q, R, S, T), Axes5<0, 1, 3, 4, 5>);
+broadcast_to!(3, (P, S, T), 8, (M, N, O, P, Q, R, S, T), Axes5<0, 1, 2, 4, 5>);
+broadcast_to!(3, (Q, S, T), 8, (M, N, O, P, Q, R, S, T), Axes5<0, 1, 2, 3, 5>);
+broadcast_to!(3, (R, S, T), 8, (M, N, O, P, Q, R, S, T), Axes5<0, 1, 2, 3, 4>);
+
+broadcast_to!(4, (M, N, O, P), 5, (M, N, O, P, Q), Axis<4>);
+broadcast_to!(4, (M, N, O, Q), 5, (M, N, O, P, Q), Axis<3>);
+broadcast_to!(4, (M, N, P, Q), 5, (M, N, O, P, Q), Axis<2>);
+broadcast_to!(4, (M, O, P, Q), 5, (M, N, O, P, Q), Axis<1>);
+broadcast_to!(4, (N, O, P, Q), 5, (M, N, O, P, Q), Axis<0>);
+broadcast_to!(4, (M, N, O, P), 6, (M, N, O, P, Q, R), Axes2<4, 5>);
+broadcast_to!(4, (M, N, O, Q), 6, (M, N, O, P, Q, R), Axes2<3, 5>);
+broadcast_to!(4, (M, N, P, Q), 6, (M, N, O, P, Q, R), Axes2<2, 5>);
+broadcast_to!(4, (M, O, P, Q), 6, (M, N, O, P, Q, R), Axes2<1, 5>);
+broadcast_to!(4, (N, O, P, Q), 6, (M, N, O, P, Q, R), Axes2<0, 5>);
+broadcast_to!(4, (M, N, O, R), 6, (M, N, O, P, Q, R), Axes2<3, 4>);
+broadcast_to!(4, (M, N, P, R), 6, (M, N, O, P, Q, R), Axes2<2, 4>);
+broadcast_to!(4, (M, O, P, R), 6, (M, N, O, P, Q, R), Axes2<1, 4>);
+broadcast_to!(4, (N, O, P, R), 6, (M, N, O, P, Q, R), Axes2<0, 4>);
+broadcast_to!(4, (M, N, Q, R), 6, (M, N, O, P, Q, R), Axes2<2, 3>);
+broadcast_to!(4, (M, O, Q, R), 6, (M, N, O, P, Q, R), Axes2<1, 3>);
+broadcast_to!(4, (N, O, Q, R), 6, (M, N, O, P, Q, R), Axes2<0, 3>);
+broadcast_to!(4, (M, P, Q, R), 6, (M, N, O, P, Q, R), Axes2<1, 2>);
+broadcast_to!(4, (N, P, Q, R), 6, (M, N, O, P, Q, R), Axes2<0, 2>);
+broadcast_to!(4, (O, P, Q, R), 6, (M, N, O, P, Q, R), Axes2<0, 1>);
+broadcast_to!(4, (M, N, O, P), 7, (M, N, O, P, Q, R, S), Axes3<4, 5, 6>);
+broadcast_to!(4, (M, N, O, Q), 7, (M, N, O, P, Q, R, S), Axes3<3, 5, 6>);
+broadcast_to!(4, (M, N, P, Q), 7, (M, N, O, P, Q, R, S), Axes3<2, 5, 6>);
+broadcast_to!(4, (M, O, P, Q), 7, (M, N, O, P, Q, R, S), Axes3<1, 5, 6>);
+broadcast_to!(4, (N, O, P, Q), 7, (M, N, O, P, Q, R, S), Axes3<0, 5, 6>);
+broadcast_to!(4, (M, N, O, R), 7, (M, N, O, P, Q, R, S), Axes3<3, 4, 6>);
+broadcast_to!(4, (M, N, P, R), 7, (M, N, O, P, Q, R, S), Axes3<2, 4, 6>);
+broadcast_to!(4, (M, O, P, R), 7, (M, N, O, P, Q, R, S), Axes3<1, 4, 6>);
+broadcast_to!(4, (N, O, P, R), 7, (M, N, O, P, Q, R, S), Axes3<0, 4, 6>);
+broadcast_to!(4, (M, N, Q, R), 7, (M, N, O, P, Q, R, S), Axes3<2, 3, 6>);
+broadcast_to!(4, (M, O, Q, R), 7, (M, N, O, P, Q, R, S), Axes3<1, 3, 6>);
+broadcast_to!(4, (N, O, Q, R), 7, (M, N, O, P, Q, R, S), Axes3<0, 3, 6>);
+broadcast_to!(4, (M, P, Q, R), 7, (M, N, O, P, Q, R, S), Axes3<1, 2, 6>);
+broadcast_to!(4, (N, P, Q, R), 7, (M, N, O, P, Q, R, S), Axes3<0, 2, 6>);
+broadcast_to!(4, (O, P, Q, R), 7, (M, N, O, P, Q, R, S), Axes3<0, 1, 6>);
+broadcast_to!(4, (M, N, O, S), 7, (M, N, O, P, Q, R, S), Axes3<3, 4, 5>);
+broadcast_to!(4, (M, N, P, S), 7, (M, N, O, P, Q, R, S), Axes3<2, 4, 5>);
+broadcast_to!(4, (M, O, P, S), 7, (M, N, O, P, Q, R, S), Axes3<1, 4, 5>);
+broadcast_to!(4, (N, O, P, S), 7, (M, N, O, P, Q, R, S), Axes3<0, 4, 5>);
+broadcast_to!(4, (M, N, Q, S), 7, (M, N, O, P, Q, R, S), Axes3<2, 3, 5>);
+broadcast_to!(4, (M, O, Q, S), 7, (M, N, O, P, Q, R, S), Axes3<1, 3, 5>);
+broadcast_to!(4, (N, O, Q, S), 7, (M, N, O, P, Q, R, S), Axes3<0, 3, 5>);
+broadcast_to!(4, (M, P, Q, S), 7, (M, N, O, P, Q, R, S), Axes3<1, 2, 5>);
+broadcast_to!(4, (N, P, Q, S), 7, (M, N, O, P, Q, R, S), Axes3<0, 2, 5>);
+broadcast_to!(4, (O, P, Q, S), 7, (M, N, O, P, Q, R, S), Axes3<0, 1, 5>);
+broadcast_to!(4, (M, N, R, S), 7, (M, N, O, P, Q, R, S), Axes3<2, 3, 4>);
+broadcast_to!(4, (M, O, R, S), 7, (M, N, O, P, Q, R, S), Axes3<1, 3, 4>);
+broadcast_to!(4, (N, O, R, S), 7, (M, N, O, P, Q, R, S), Axes3<0, 3, 4>);
+broadcast_to!(4, (M, P, R, S), 7, (M, N, O, P, Q, R, S), Axes3<1, 2, 4>);
+broadcast_to!(4, (N, P, R, S), 7, (M, N, O, P, Q, R, S), Axes3<0, 2, 4>);
+broadcast_to!(4, (O, P, R, S), 7, (M, N, O, P, Q, R, S), Axes3<0, 1, 4>);
+broadcast_to!(4, (M, Q, R, S), 7, (M, N, O, P, Q, R, S), Axes3<1, 2, 3>);
+broadcast_to!(4, (N, Q, R, S), 7, (M, N, O, P, Q, R, S), Axes3<0, 2, 3>);
+broadcast_to!(4, (O, Q, R, S), 7, (M, N, O, P, Q, R, S), Axes3<0, 1, 3>);
+broadcast_to!(4, (P, Q, R, S), 7, (M, N, O, P, Q, R, S), Axes3<0, 1, 2>);
+broadcast_to!(4, (M, N, O, P), 8, (M, N, O, P, Q, R, S, T), Axes4<4, 5, 6, 7>);
+broadcast_to!(4, (M, N, O, Q), 8, (M, N, O, P, Q, R, S, T), Axes4<3, 5, 6, 7>);
+broadcast_to!(4, (M, N, P, Q), 8, (M, N, O, P, Q, R, S, T), Axes4<2, 5, 6, 7>);
+broadcast_to!(4, (M, O, P, Q), 8, (M, N, O, P, Q, R, S, T), Axes4<1, 5, 6, 7>);
+broadcast_to!(4, (N, O, P, Q), 8, (M, N, O, P, Q, R, S, T), Axes4<0, 5, 6, 7>);
+broadcast_to!(4, (M, N, O, R), 8, (M, N, O, P, Q, R, S, T), Axes4<3, 4, 6, 7>);
+broadcast_to!(4, (M, N, P, R), 8, (M, N, O, P, Q, R, S, T), Axes4<2, 4, 6, 7>);
+broadcast_to!(4, (M, O, P, R), 8, (M, N, O, P, Q, R, S, T), Axes4<1, 4, 6, 7>);
+broadcast_to!(4, (N, O, P, R), 8, (M, N, O, P, Q, R, S, T), Axes4<0, 4, 6, 7>);
+broadcast_to!(4, (M, N, Q, R), 8, (M, N, O, P, Q, R, S, T), Axes4<2, 3, 6, 7>);
+broadcast_to!(4, (M, O, Q, R), 8, (M, N, O, P, Q, R, S, T), Axes4<1, 3, 6, 7>);
+broadcast_to!(4, (N, O, Q, R), 8, (M, N, O, P, Q, R, S, T), Axes4<0, 3, 6, 7>);
+broadcast_to!(4, (M, P, Q, R), 8, (M, N, O, P, Q, R, S, T), Axes4<1, 2, 6, 7>);
+broadcast_to!(4, (N, P, Q, R), 8, (M, N, O, P, Q, R, S, T), Axes4<0, 2, 6, 7>);
+broadcast_to!(4, (O, P, Q, R), 8, (M, N, O, P, Q, R, S, T), Axes4<0, 1, 6, 7>);
+broadcast_to!(4, (M, N, O, S), 8, (M, N, O, P, Q, R, S, T), Axes4<3, 4, 5, 7>);
+broadcast_to!(4, (M, N, P, S), 8, (M, N, O, P, Q, R, S, T), Axes4<2, 4, 5, 7>);
+broadcast_to!(4, (M, O, P, S), 8, (M, N, O, P, Q, R, S, T), Axes4<1, 4, 5, 7>);
+broadcast_to!(4, (N, O, P, S), 8, (M, N, O, P, Q, R, S, T), Axes4<0, 4, 5, 7>);
+broadcast_to!(4, (M, N, Q, S), 8, (M, N, O, P, Q, R, S, T), Axes4<2, 3, 5, 7>);
+broadcast_to!(4, (M, O, Q, S), 8, (M, N, O, P, Q, R, S, T), Axes4<1, 3, 5, 7>);
+broadcast_to!(4, (N, O, Q, S), 8, (M, N, O, P, Q, R, S, T), Axes4<0, 3, 5, 7>);
+broadcast_to!(4, (M, P, Q, S), 8, (M, N, O, P, Q, R, S, T), Axes4<1, 2, 5, 7>);
+broadcast_to!(4, (N, P, Q, S), 8, (M, N, O, P, Q, R, S, T), Axes4<0, 2, 5, 7>);
+broadcast_to!(4, (O, P, Q, S), 8, (M, N, O, P, Q, R, S, T), Axes4<0, 1, 5, 7>);
+broadcast_to!(4, (M, N, R, S), 8, (M, N, O, P, Q, R, S, T), Axes4<2, 3, 4, 7>);
+broadcast_to!(4, (M, O, R, S), 8, (M, N, O, P, Q, R, S, T), Axes4<1, 3, 4, 7>);
+broadcast_to!(4, (N, O, R, S), 8, (M, N, O, P, Q, R, S, T), Axes4<0, 3, 4, 7>);
+broadcast_to!(4, (M, P, R, S), 8, (M, N, O, P, Q, R, S, T), Axes4<1, 2, 4, 7>);
+broadcast_to!(4, (N, P, R, S), 8, (M, N, O, P, Q, R, S, T), Axes4<0, 2, 4, 7>);
+broadcast_to!(4, (O, P, R, S), 8, (M, N, O, P, Q, R, S, T), Axes4<0, 1, 4, 7>);
+broadcast_to!(4, (M, Q, R, S), 8, (M, N, O, P, Q, R, S, T), Axes4<1, 2, 3, 7>);
+broadcast_to!(4, (N, Q, R, S), 8, (M, N, O, P, Q, R, S, T), Axes4<0, 2, 3, 7>);
+broadcast_to!(4, (O, Q, R, S), 8, (M, N, O, P, Q, R, S, T), Axes4<0, 1, 3, 7>);
+broadcast_to!(4, (P, Q, R, S), 8, (M, N, O, P, Q, R, S, T), Axes4<0, 1, 2, 7>);
+broadcast_to!(4, (M, N, O, T), 8, (M, N, O, P, Q, R, S, T), Axes4<3, 4, 5, 6>);
+broadcast_to!(4, (M, N, P, T), 8, (M, N, O, P, Q, R, S, T), Axes4<2, 4, 5, 6>);
+broadcast_to!(4, (M, O, P, T), 8, (M, N, O, P, Q, R, S, T), Axes4<1, 4, 5, 6>);
+broadcast_to!(4, (N, O, P, T), 8, (M, N, O, P, Q, R, S, T), Axes4<0, 4, 5, 6>);
+broadcast_to!(4, (M, N, Q, T), 8, (M, N, O, P, Q, R, S, T), Axes4<2, 3, 5, 6>);
+broadcast_to!(4, (M, O, Q, T), 8, (M, N, O, P, Q, R, S, T), Axes4<1, 3, 5, 6>);
+broadcast_to!(4, (N, O, Q, T), 8, (M, N, O, P, Q, R, S, T), Axes4<0, 3, 5, 6>);
+broadcast_to!(4, (M, P, Q, T), 8, (M, N, O, P, Q, R, S, T), Axes4<1, 2, 5, 6>);
+broadcast_to!(4, (N, P, Q, T), 8, (M, N, O, P, Q, R, S, T), Axes4<0, 2, 5, 6>);
+broadcast_to!(4, (O, P, Q, T), 8, (M, N, O, P, Q, R, S, T), Axes4<0, 1, 5, 6>);
+broadcast_to!(4, (M, N, R, T), 8, (M, N, O, P, Q, R, S, T), Axes4<2, 3, 4, 6>);
+broadcast_to!(4, (M, O, R, T), 8, (M, N, O, P, Q, R, S, T), Axes4<1, 3, 4, 6>);
+broadcast_to!(4, (N, O, R, T), 8, (M, N, O, P, Q, R, S, T), Axes4<0, 3, 4, 6>);
+broadcast_to!(4, (M, P, R, T), 8, (M, N, O, P, Q, R, S, T), Axes4<1, 2, 4, 6>);
+broadcast_to!(4, (N, P, R, T), 8, (M, N, O, P, Q, R, S, T), Axes4<0, 2, 4, 6>);
+broadcast_to!(4, (O, P, R, T), 8, (M, N, O, P, Q, R, S, T), Axes4<0, 1, 4, 6>);
+broadcast_to!(4, (M, Q, R, T), 8, (M, N, O, P, Q, R, S, T), Axes4<1, 2, 3, 6>);
+broadcast_to!(4, (N, Q, R, T), 8, (M, N, O, P, Q, R, S, T), Axes4<0, 2, 3, 6>);
+broadcast_to!(4, (O, Q, R, T), 8, (M, N, O, P, Q, R, S, T), Axes4<0, 1, 3, 6>);
+broadcast_to!(4, (P, Q, R, T), 8, (M, N, O, P, Q, R, S, T), Axes4<0, 1, 2, 6>);
+broadcast_to!(4, (M, N, S, T), 8, (M, N, O, P, Q, R, S, T), Axes4<2, 3, 4, 5>);
+broadcast_to!(4, (M, O, S, T), 8, (M, N, O, P, Q, R, S, T), Axes4<1, 3, 4, 5>);
+broadcast_to!(4, (N, O, S, T), 8, (M, N, O, P, Q, R, S, T), Axes4<0, 3, 4, 5>);
+broadcast_to!(4, (M, P, S, T), 8, (M, N, O, P, Q, R, S, T), Axes4<1, 2, 4, 5>);
+broadcast_to!(4, (N, P, S, T), 8, (M, N, O, P, Q, R, S, T), Axes4<0, 2, 4, 5>);
+broadcast_to!(4, (O, P, S, T), 8, (M, N, O, P, Q, R, S, T), Axes4<0, 1, 4, 5>);
+broadcast_to!(4, (M, Q, S, T), 8, (M, N, O, P, Q, R, S, T), Axes4<1, 2, 3, 5>);
+broadcast_to!(4, (N, Q, S, T), 8, (M, N, O, P, Q, R, S, T), Axes4<0, 2, 3, 5>);
+broadcast_to!(4, (O, Q, S, T), 8, (M, N, O, P, Q, R, S, T), Axes4<0, 1, 3, 5>);
+broadcast_to!(4, (P, Q, S, T), 8, (M, N, O, P, Q, R, S, T), Axes4<0, 1, 2, 5>);
+broadcast_to!(4, (M, R, S, T), 8, (M, N, O, P, Q, R, S, T), Axes4<1, 2, 3, 4>);
+broadcast_to!(4, (N, R, S, T), 8, (M, N, O, P, Q, R, S, T), Axes4<0, 2, 3, 4>);
+broadcast_to!(4, (O, R, S, T), 8, (M, N, O, P, Q, R, S, T), Axes4<0, 1, 3, 4>);
+broadcast_to!(4, (P, R, S, T), 8, (M, N, O, P, Q, R, S, T), Axes4<0, 1, 2, 4>);
+broadcast_to!(4, (Q, R, S, T), 8, (M, N, O, P, Q, R, S, T), Axes4<0, 1, 2, 3>);
+
+broadcast_to!(5, (M, N, O, P, Q), 6, (M, N, O, P, Q, R), Axis<5>);
+broadcast_to!(5, (M, N, O, P, R), 6, (M, N, O, P, Q, R), Axis<4>);
+broadcast_to!(5, (M, N, O, Q, R), 6, (M, N, O, P, Q, R), Axis<3>);
+broadcast_to!(5, (M, N, P, Q, R), 6, (M, N, O, P, Q, R), Axis<2>);
+broadcast_to!(5, (M, O, P, Q, R), 6, (M, N, O, P, Q, R), Axis<1>);
+broadcast_to!(5, (N, O, P, Q, R), 6, (M, N, O, P, Q, R), Axis<0>);
+broadcast_to!(5, (M, N, O, P, Q), 7, (M, N, O, P, Q, R, S), Axes2<5, 6>);
+broadcast_to!(5, (M, N, O, P, R), 7, (M, N, O, P, Q, R, S), Axes2<4, 6>);
+broadcast_to!(5, (M, N, O, Q, R), 7, (M, N, O, P, Q, R, S), Axes2<3, 6>);
+broadcast_to!(5, (M, N, P, Q, R), 7, (M, N, O, P, Q, R, S), Axes2<2, 6>);
+broadcast_to!(5, (M, O, P, Q, R), 7, (M, N, O, P, Q, R, S), Axes2<1, 6>);
+broadcast_to!(5, (N, O, P, Q, R), 7, (M, N, O, P, Q, R, S), Axes2<0, 6>);
+broadcast_to!(5, (M, N, O, P, S), 7, (M, N, O, P, Q, R, S), Axes2<4, 5>);
+broadcast_to!(5, (M, N, O, Q, S), 7, (M, N, O, P, Q, R, S), Axes2<3, 5>);
+broadcast_to!(5, (M, N, P, Q, S), 7, (M, N, O, P, Q, R, S), Axes2<2, 5>);
+broadcast_to!(5, (M, O, P, Q, S), 7, (M, N, O, P, Q, R, S), Axes2<1, 5>);
+broadcast_to!(5, (N, O, P, Q, S), 7, (M, N, O, P, Q, R, S), Axes2<0, 5>);
+broadcast_to!(5, (M, N, O, R, S), 7, (M, N, O, P, Q, R, S), Axes2<3, 4>);
+broadcast_to!(5, (M, N, P, R, S), 7, (M, N, O, P, Q, R, S), Axes2<2, 4>);
+broadcast_to!(5, (M, O, P, R, S), 7, (M, N, O, P, Q, R, S), Axes2<1, 4>);
+broadcast_to!(5, (N, O, P, R, S), 7, (M, N, O, P, Q, R, S), Axes2<0, 4>);
+broadcast_to!(5, (M, N, Q, R, S), 7, (M, N, O, P, Q, R, S), Axes2<2, 3>);
+broadcast_to!(5, (M, O, Q, R, S), 7, (M, N, O, P, Q, R, S), Axes2<1, 3>);
+broadcast_to!(5, (N, O, Q, R, S), 7, (M, N, O, P, Q, R, S), Axes2<0, 3>);
+broadcast_to!(5, (M, P, Q, R, S), 7, (M, N, O, P, Q, R, S), Axes2<1, 2>);
+broadcast_to!(5, (N, P, Q, R, S), 7, (M, N, O, P, Q, R, S), Axes2<0, 2>);
+broadcast_to!(5, (O, P, Q, R, S), 7, (M, N, O, P, Q, R, S), Axes2<0, 1>);
+broadcast_to!(5, (M, N, O, P, Q), 8, (M, N, O, P, Q, R, S, T), Axes3<5, 6, 7>);
+broadcast_to!(5, (M, N, O, P, R), 8, (M, N, O, P, Q, R, S, T), Axes3<4, 6, 7>);
+broadcast_to!(5, (M, N, O, Q, R), 8, (M, N, O, P, Q, R, S, T), Axes3<3, 6, 7>);
+broadcast_to!(5, (M, N, P, Q, R), 8, (M, N, O, P, Q, R, S, T), Axes3<2, 6, 7>);
+broadcast_to!(5, (M, O, P, Q, R), 8, (M, N, O, P, Q, R, S, T), Axes3<1, 6, 7>);
+broadcast_to!(5, (N, O, P, Q, R), 8, (M, N, O, P, Q, R, S, T), Axes3<0, 6, 7>);
+broadcast_to!(5, (M, N, O, P, S), 8, (M, N, O, P, Q, R, S, T), Axes3<4, 5, 7>);
+broadcast_to!(5, (M, N, O, Q, S), 8, (M, N, O, P, Q, R, S, T), Axes3<3, 5, 7>);
+broadcast_to!(5, (M, N, P, Q, S), 8, (M, N, O, P, Q, R, S, T), Axes3<2, 5, 7>);
+broadcast_to!(5, (M, O, P, Q, S), 8, (M, N, O, P, Q, R, S, T), Axes3<1, 5, 7>);
+broadcast_to!(5, (N, O, P, Q, S), 8, (M, N, O, P, Q, R, S, T), Axes3<0, 5, 7>);
+broadcast_to!(5, (M, N, O, R, S), 8, (M, N, O, P, Q, R, S, T), Axes3<3, 4, 7>);
+broadcast_to!(5, (M, N, P, R, S), 8, (M, N, O, P, Q, R, S, T), Axes3<2, 4, 7>);
+broadcast_to!(5, (M, O, P, R, S), 8, (M, N, O, P, Q, R, S, T), Axes3<1, 4, 7>);
+broadcast_to!(5, (N, O, P, R, S), 8, (M, N, O, P, Q, R, S, T), Axes3<0, 4, 7>);
+broadcast_to!(5, (M, N, Q, R, S), 8, (M, N, O, P, Q, R, S, T), Axes3<2, 3, 7>);
+broadcast_to!(5, (M, O, Q, R, S), 8, (M, N, O, P, Q, R, S, T), Axes3<1, 3, 7>);
+broadcast_to!(5, (N, O, Q, R, S), 8, (M, N, O, P, Q, R, S, T), Axes3<0, 3, 7>);
+broadcast_to!(5, (M, P, Q, R, S), 8, (M, N, O, P, Q, R, S, T), Axes3<1, 2, 7>);
+broadcast_to!(5, (N, P, Q, R, S), 8, (M, N, O, P, Q, R, S, T), Axes3<0, 2, 7>);
+broadcast_to!(5, (O, P, Q, R, S), 8, (M, N, O, P, Q, R, S, T), Axes3<0, 1, 7>);
+broadcast_to!(5, (M, N, O, P, T), 8, (M, N, O, P, Q, R, S, T), Axes3<4, 5, 6>);
+broadcast_to!(5, (M, N, O, Q, T), 8, (M, N, O, P, Q, R, S, T), Axes3<3, 5, 6>);
+broadcast_to!(5, (M, N, P, Q, T), 8, (M, N, O, P, Q, R, S, T), Axes3<2, 5, 6>);
+broadcast_to!(5, (M, O, P, Q, T), 8, (M, N, O, P, Q, R, S, T), Axes3<1, 5, 6>);
+broadcast_to!(5, (N, O, P, Q, T), 8, (M, N, O, P, Q, R, S, T), Axes3<0, 5, 6>);
+broadcast_to!(5, (M, N, O, R, T), 8, (M, N, O, P, Q, R, S, T), Axes3<3, 4, 6>);
+broadcast_to!(5, (M, N, P, R, T), 8, (M, N, O, P, Q, R, S, T), Axes3<2, 4, 6>);
+broadcast_to!(5, (M, O, P, R, T), 8, (M, N, O, P, Q, R, S, T), Axes3<1, 4, 6>);
+broadcast_to!(5, (N, O, P, R, T), 8, (M, N, O, P, Q, R, S, T), Axes3<0, 4, 6>);
+broadcast_to!(5, (M, N, Q, R, T), 8, (M, N, O, P, Q, R, S, T), Axes3<2, 3, 6>);
+broadcast_to!(5, (M, O, Q, R, T), 8, (M, N, O, P, Q, R, S, T), Axes3<1, 3, 6>);
+broadcast_to!(5, (N, O, Q, R, T), 8, (M, N, O, P, Q, R, S, T), Axes3<0, 3, 6>);
+broadcast_to!(5, (M, P, Q, R, T), 8, (M, N, O, P, Q, R, S, T), Axes3<1, 2, 6>);
+broadcast_to!(5, (N, P, Q, R, T), 8, (M, N, O, P, Q, R, S, T), Axes3<0, 2, 6>);
+broadcast_to!(5, (O, P, Q, R, T), 8, (M, N, O, P, Q, R, S, T), Axes3<0, 1, 6>);
+broadcast_to!(5, (M, N, O, S, T), 8, (M, N, O, P, Q, R, S, T), Axes3<3, 4, 5>);
+broadcast_to!(5, (M, N, P, S, T), 8, (M, N, O, P, Q, R, S, T), Axes3<2, 4, 5>);
+broadcast_to!(5, (M, O, P, S, T), 8, (M, N, O, P, Q, R, S, T), Axes3<1, 4, 5>);
+broadcast_to!(5, (N, O, P, S, T), 8, (M, N, O, P, Q, R, S, T), Axes3<0, 4, 5>);
+broadcast_to!(5, (M, N, Q, S, T), 8, (M, N, O, P, Q, R, S, T), Axes3<2, 3, 5>);
+broadcast_to!(5, (M, O, Q, S, T), 8, (M, N, O, P, Q, R, S, T), Axes3<1, 3, 5>);
+broadcast_to!(5, (N, O, Q, S, T), 8, (M, N, O, P, Q, R, S, T), Axes3<0, 3, 5>);
+broadcast_to!(5, (M, P, Q, S, T), 8, (M, N, O, P, Q, R, S, T), Axes3<1, 2, 5>);
+broadcast_to!(5, (N, P, Q, S, T), 8, (M, N, O, P, Q, R, S, T), Axes3<0, 2, 5>);
+broadcast_to!(5, (O, P, Q, S, T), 8, (M, N, O, P, Q, R, S, T), Axes3<0, 1, 5>);
+broadcast_to!(5, (M, N, R, S, T), 8, (M, N, O, P, Q, R, S, T), Axes3<2, 3, 4>);
+broadcast_to!(5, (M, O, R, S, T), 8, (M, N, O, P, Q, R, S, T), Axes3<1, 3, 4>);
+broadcast_to!(5, (N, O, R, S, T), 8, (M, N, O, P, Q, R, S, T), Axes3<0, 3, 4>);
+broadcast_to!(5, (M, P, R, S, T), 8, (M, N, O, P, Q, R, S, T), Axes3<1, 2, 4>);
+broadcast_to!(5, (N, P, R, S, T), 8, (M, N, O, P, Q, R, S, T), Axes3<0, 2, 4>);
+broadcast_to!(5, (O, P, R, S, T), 8, (M, N, O, P, Q, R, S, T), Axes3<0, 1, 4>);
+broadcast_to!(5, (M, Q, R, S, T), 8, (M, N, O, P, Q, R, S, T), Axes3<1, 2, 3>);
+broadcast_to!(5, (N, Q, R, S, T), 8, (M, N, O, P, Q, R, S, T), Axes3<0, 2, 3>);
+broadcast_to!(5, (O, Q, R, S, T), 8, (M, N, O, P, Q, R, S, T), Axes3<0, 1, 3>);
+broadcast_to!(5, (P, Q, R, S, T), 8, (M, N, O, P, Q, R, S, T), Axes3<0, 1, 2>);
+
+broadcast_to!(6, (M, N, O, P, Q, R), 7, (M, N, O, P, Q, R, S), Axis<6>);
+broadcast_to!(6, (M, N, O, P, Q, S), 7, (M, N, O, P, Q, R, S), Axis<5>);
+broadcast_to!(6, (M, N, O, P, R, S), 7, (M, N, O, P, Q, R, S), Axis<4>);
+broadcast_to!(6, (M, N, O, Q, R, S), 7, (M, N, O, P, Q, R, S), Axis<3>);
+broadcast_to!(6, (M, N, P, Q, R, S), 7, (M, N, O, P, Q, R, S), Axis<2>);
+broadcast_to!(6, (M, O, P, Q, R, S), 7, (M, N, O, P, Q, R, S), Axis<1>);
+broadcast_to!(6, (N, O, P, Q, R, S), 7, (M, N, O, P, Q, R, S), Axis<0>);
+broadcast_to!(6, (M, N, O, P, Q, R), 8, (M, N, O, P, Q, R, S, T), Axes2<6, 7>);
+broadcast_to!(6, (M, N, O, P, Q, S), 8, (M, N, O, P, Q, R, S, T), Axes2<5, 7>);
+broadcast_to!(6, (M, N, O, P, R, S), 8, (M, N, O, P, Q, R, S, T), Axes2<4, 7>);
+broadcast_to!(6, (M, N, O, Q, R, S), 8, (M, N, O, P, Q, R, S, T), Axes2<3, 7>);
+broadcast_to!(6, (M, N, P, Q, R, S), 8, (M, N, O, P, Q, R, S, T), Axes2<2, 7>);
+broadcast_to!(6, (M, O, P, Q, R, S), 8, (M, N, O, P, Q, R, S, T), Axes2<1, 7>);
+broadcast_to!(6, (N, O, P, Q, R, S), 8, (M, N, O, P, Q, R, S, T), Axes2<0, 7>);
+broadcast_to!(6, (M, N, O, P, Q, T), 8, (M, N, O, P, Q, R, S, T), Axes2<5, 6>);
+broadcast_to!(6, (M, N, O, P, R, T), 8, (M, N, O, P, Q, R, S, T), Axes2<4, 6>);
+broadcast_to!(6, (M, N, O, Q, R, T), 8, (M, N, O, P, Q, R, S, T), Axes2<3, 6>);
+broadcast_to!(6, (M, N, P, Q, R, T), 8, (M, N, O, P, Q, R, S, T), Axes2<2, 6>);
+broadcast_to!(6, (M, O, P, Q, R, T), 8, (M, N, O, P, Q, R, S, T), Axes2<1, 6>);
+broadcast_to!(6, (N, O, P, Q, R, T), 8, (M, N, O, P, Q, R, S, T), Axes2<0, 6>);
+broadcast_to!(6, (M, N, O, P, S, T), 8, (M, N, O, P, Q, R, S, T), Axes2<4, 5>);
+broadcast_to!(6, (M, N, O, Q, S, T), 8, (M, N, O, P, Q, R, S, T), Axes2<3, 5>);
+broadcast_to!(6, (M, N, P, Q, S, T), 8, (M, N, O, P, Q, R, S, T), Axes2<2, 5>);
+broadcast_to!(6, (M, O, P, Q, S, T), 8, (M, N, O, P, Q, R, S, T), Axes2<1, 5>);
+broadcast_to!(6, (N, O, P, Q, S, T), 8, (M, N, O, P, Q, R, S, T), Axes2<0, 5>);
+broadcast_to!(6, (M, N, O, R, S, T), 8, (M, N, O, P, Q, R, S, T), Axes2<3, 4>);
+broadcast_to!(6, (M, N, P, R, S, T), 8, (M, N, O, P, Q, R, S, T), Axes2<2, 4>);
+broadcast_to!(6, (M, O, P, R, S, T), 8, (M, N, O, P, Q, R, S, T), Axes2<1, 4>);
+broadcast_to!(6, (N, O, P, R, S, T), 8, (M, N, O, P, Q, R, S, T), Axes2<0, 4>);
+broadcast_to!(6, (M, N, Q, R, S, T), 8, (M, N, O, P, Q, R, S, T), Axes2<2, 3>);
+broadcast_to!(6, (M, O, Q, R, S, T), 8, (M, N, O, P, Q, R, S, T), Axes2<1, 3>);
+broadcast_to!(6, (N, O, Q, R, S, T), 8, (M, N, O, P, Q, R, S, T), Axes2<0, 3>);
+broadcast_to!(6, (M, P, Q, R, S, T), 8, (M, N, O, P, Q, R, S, T), Axes2<1, 2>);
+broadcast_to!(6, (N, P, Q, R, S, T), 8, (M, N, O, P, Q, R, S, T), Axes2<0, 2>);
+broadcast_to!(6, (O, P, Q, R, S, T), 8, (M, N, O, P, Q, R, S, T), Axes2<0, 1>);
+
+broadcast_to!(
+    7,
+    (M, N, O, P, Q, R, S),
+    8,
+    (M, N, O, P, Q, R, S, T),
+    Axis<7>
+);
+broadcast_to!(
+    7,
+    (M, N, O, P, Q, R, T),
+    8,
+    (M, N, O, P, Q, R, S, T),
+    Axis<6>
+);
+broadcast_to!(
+    7,
+    (M, N, O, P, Q, S, T),
+    8,
+    (M, N, O, P, Q, R, S, T),
+    Axis<5>
+);
+broadcast_to!(
+    7,
+    (M, N, O, P, R, S, T),
+    8,
+    (M, N, O, P, Q, R, S, T),
+    Axis<4>
+);
+broadcast_to!(
+    7,
+    (M, N, O, Q, R, S, T),
+    8,
+    (M, N, O, P, Q, R, S, T),
+    Axis<3>
+);
+broadcast_to!(
+    7,
+    (M, N, P, Q, R, S, T),
+    8,
+    (M, N, O, P, Q, R, S, T),
+    Axis<2>
+);
+broadcast_to!(
+    7,
+    (M, O, P, Q, R, S, T),
+    8,
+    (M, N, O, P, Q, R, S, T),
+    Axis<1>
+);
+broadcast_to!(
+    7,
+    (N, O, P, Q, R, S, T),
+    8,
+    (M, N, O, P, Q, R, S, T),
+    Axis<0>
+);
+
+/// The dimension that two dimensions broadcast to, like numpy does: the sizes
+/// must be equal, or one of them must be 1 and is stretched to the other.
+///
+/// The size of a [Const] dim can't be picked at runtime, so two [Const] dims must be
+/// the same, and broadcasting a [Const] dim with a `usize` dim gives a `usize` dim.
+pub trait BroadcastDim<Rhs: Dim>: Dim {
+    type Output: Dim;
+
+    /// The size of the broadcast dimension. This doesn't check that the sizes
+    /// are compatible; stretching a dim that isn't 1 to a different size fails later.
+    fn broadcast_dim(&self, rhs: &Rhs) -> Self::Output;
+}
+
+impl<const N: usize> BroadcastDim<Const<N>> for Const<N> {
+    type Output = Self;
+    fn broadcast_dim(&self, _: &Const<N>) -> Self::Output {
+        Const
+    }
+}
+
+impl<const N: usize> BroadcastDim<usize> for Const<N> {
+    type Output = usize;
+    fn broadcast_dim(&self, rhs: &usize) -> Self::Output {
+        if *rhs == 1 {
+            N
+        } else {
+            *rhs
+        }
+    }
+}
+
+impl<const N: usize> BroadcastDim<Const<N>> for usize {
+    type Output = usize;
+    fn broadcast_dim(&self, _: &Const<N>) -> Self::Output {
+        if *self == 1 {
+            N
+        } else {
+            *self
+        }
+    }
+}
+
+impl BroadcastDim<usize> for usize {
+    type Output = usize;
+    fn broadcast_dim(&self, rhs: &usize) -> Self::Output {
+        if *self == 1 {
+            *rhs
+        } else {
+            *self
+        }
+    }
+}
 
 /// Internal implementation for broadcasting strides
 pub trait BroadcastStridesTo<S: Shape, Ax>: Shape + BroadcastShapeTo<S, Ax> {
@@ -0,0 +1,134 @@
+use super::{axes::*, shape::*};
+
+/// Marker for shapes that have an `N`th axis counted from the end, where `1` is the
+/// last axis. See [FromEnd].
+pub trait AxisFromEnd<const N: usize>: Shape {
+    type Axis: Axes<Array = [isize; 1]>;
+}
+
+/// The `N`th axis of `S` counted from the end, where `1` is the last axis.
+///
+/// This lets code that is generic over the rank of a tensor reference axes
+/// without knowing how many dimensions it has:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let a: Tensor<Rank3<2, 3, 4>, f32, _> = dev.zeros();
+/// let _: Tensor<Rank2<2, 4>, f32, _> = a.clone().sum::<_, FromEnd<Rank3<2, 3, 4>, 2>>();
+/// let _ = a.softmax::<FromEnd<Rank3<2, 3, 4>, 1>>();
+/// ```
+///
+/// Functions over any rank are bounded by [super::ReduceShape] (or [super::ReduceShapeTo]
+/// and [super::BroadcastShapeTo]) on the resulting axis:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// fn softmax_last<S: AxisFromEnd<1>, D: Device<f32>>(t: Tensor<S, f32, D>) -> Tensor<S, f32, D>
+/// where
+///     S: ReduceShape<FromEnd<S, 1>>,
+/// {
+///     t.softmax::<FromEnd<S, 1>>()
+/// }
+/// # let dev: Cpu = Default::default();
+/// let _ = softmax_last(dev.zeros::<Rank2<2, 3>>());
+/// let _ = softmax_last(dev.zeros::<Rank4<2, 3, 4, 5>>());
+/// ```
+pub type FromEnd<S, const N: usize> = <S as AxisFromEnd<N>>::Axis;
+
+/// Marker for shapes that can swap their `I`th & `J`th axes counted from the end,
+/// where `1` is the last axis and `I < J`. See [SwapFromEnd].
+pub trait SwapAxesFromEnd<const I: usize, const J: usize>: Shape {
+    type Axes: Axes;
+}
+
+/// The permutation of `S` that swaps its `I`th & `J`th axes counted from the end,
+/// where `1` is the last axis and `I < J`. Used with [crate::tensor_ops::PermuteTo].
+///
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let a: Tensor<Rank4<2, 3, 4, 5>, f32, _> = dev.zeros();
+/// let _: Tensor<Rank4<2, 3, 5, 4>, f32, _> = a.permute::<_, SwapFromEnd<Rank4<2, 3, 4, 5>, 1, 2>>();
+/// ```
+pub type SwapFromEnd<S, const I: usize, const J: usize> = <S as SwapAxesFromEnd<I, J>>::Axes;
+
+/// The axis at position `k` after swapping axes `a` & `b`.
+const fn swapped(k: isize, a: isize, b: isize) -> isize {
+    if k == a {
+        b
+    } else if k == b {
+        a
+    } else {
+        k
+    }
+}
+
+macro_rules! from_end {
+    ($Vars:tt, $Num:tt, $AxesTy:tt, $Idx:tt) => {
+        from_end!(@axis $Vars, $Num, $Idx);
+        from_end!(@pairs $Vars, $Num, $AxesTy, $Idx, $Idx);
+    };
+    (@axis $Vars:tt, $Num:tt, [$($N:tt),*]) => {
+        $(from_end!(@axis_impl $Vars, $Num, $N);)*
+    };
+    (@axis_impl ($($Vars:tt),*), $Num:tt, $N:tt) => {
+        impl<$($Vars: Dim, )*> AxisFromEnd<{ $N + 1 }> for ($($Vars, )*) {
+            type Axis = Axis<{ $Num - 1 - $N }>;
+        }
+        impl AxisFromEnd<{ $N + 1 }> for [usize; $Num] {
+            type Axis = Axis<{ $Num - 1 - $N }>;
+        }
+    };
+    (@pairs $Vars:tt, $Num:tt, $AxesTy:tt, $Idx:tt, [$I:tt]) => {};
+    (@pairs $Vars:tt, $Num:tt, $AxesTy:tt, $Idx:tt, [$I:tt, $($J:tt),+]) => {
+        $(from_end!(@swap_impl $Vars, $Num, $AxesTy, $Idx, $I, $J);)+
+        from_end!(@pairs $Vars, $Num, $AxesTy, $Idx, [$($J),+]);
+    };
+    (@swap_impl ($($Vars:tt),*), $Num:tt, $AxesTy:tt, [$($Idx:tt),*], $I:tt, $J:tt) => {
+        impl<$($Vars: Dim, )*> SwapAxesFromEnd<{ $I + 1 }, { $J + 1 }> for ($($Vars, )*) {
+            type Axes = $AxesTy<$({ swapped($Idx, $Num - 1 - $I, $Num - 1 - $J) }, )*>;
+        }
+        impl SwapAxesFromEnd<{ $I + 1 }, { $J + 1 }> for [usize; $Num] {
+            type Axes = $AxesTy<$({ swapped($Idx, $Num - 1 - $I, $Num - 1 - $J) }, )*>;
+        }
+    };
+}
+
+from_end!((D1), 1, Axis, [0]);
+from_end!((D1, D2), 2, Axes2, [0, 1]);
+from_end!((D1, D2, D3), 3, Axes3, [0, 1, 2]);
+from_end!((D1, D2, D3, D4), 4, Axes4, [0, 1, 2, 3]);
+from_end!((D1, D2, D3, D4, D5), 5, Axes5, [0, 1, 2, 3, 4]);
+from_end!((D1, D2, D3, D4, D5, D6), 6, Axes6, [0, 1, 2, 3, 4, 5]);
+from_end!(
+    (D1, D2, D3, D4, D5, D6, D7),
+    7,
+    Axes7,
+    [0, 1, 2, 3, 4, 5, 6]
+);
+from_end!(
+    (D1, D2, D3, D4, D5, D6, D7, D8),
+    8,
+    Axes8,
+    [0, 1, 2, 3, 4, 5, 6, 7]
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_end() {
+        type S = (usize, Const<2>, usize);
+        assert_eq!(<FromEnd<S, 1> as Axes>::as_array(), [2]);
+        assert_eq!(<FromEnd<S, 3> as Axes>::as_array(), [0]);
+        assert_eq!(<FromEnd<[usize; 8], 2> as Axes>::as_array(), [6]);
+    }
+
+    #[test]
+    fn test_swap_from_end() {
+        type S = (usize, usize, usize, usize);
+        assert_eq!(<SwapFromEnd<S, 1, 2> as Axes>::as_array(), [0, 1, 3, 2]);
+        assert_eq!(<SwapFromEnd<S, 1, 4> as Axes>::as_array(), [3, 1, 2, 0]);
+        assert_eq!(<SwapFromEnd<S, 2, 3> as Axes>::as_array(), [0, 2, 1, 3]);
+    }
+}
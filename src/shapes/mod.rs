@@ -1,23 +1,23 @@
 /// Shape related traits/structes like [Shape], [Dtype], [Dim], [Axes]
 mod axes;
 mod broadcasts;
+mod from_end;
 mod permutes;
 mod replace_dim;
 mod same_numel;
 mod shape;
 
-pub(crate) use axes::Axes;
-pub(crate) use broadcasts::{
-    BroadcastShapeTo, BroadcastStridesTo, ReduceShape, ReduceShapeTo, ReduceStridesTo,
-};
+pub(crate) use broadcasts::{BroadcastStridesTo, ReduceStridesTo};
 pub(crate) use permutes::{PermuteShapeTo, PermuteStridesTo};
-pub(crate) use replace_dim::{RemoveDimTo, ReplaceDimTo};
+pub(crate) use replace_dim::{GatherNdShapeTo, RemoveDimTo, ReplaceDimTo};
 
 #[allow(unused_imports)]
 pub(crate) use same_numel::HasSameNumelAs;
 
-pub use axes::{Axes2, Axes3, Axes4, Axes5, Axes6, Axis, HasAxes};
+pub use axes::{Axes, Axes2, Axes3, Axes4, Axes5, Axes6, Axes7, Axes8, Axis, HasAxes};
+pub use broadcasts::{BroadcastDim, BroadcastShapeTo, ReduceShape, ReduceShapeTo};
+pub use from_end::{AxisFromEnd, FromEnd, SwapAxesFromEnd, SwapFromEnd};
 pub use shape::{Array, Const, ConstDim, Dim};
 pub use shape::{ConstShape, HasShape, Shape};
 pub use shape::{Dtype, HasDtype, HasUnitType, Unit};
-pub use shape::{Rank0, Rank1, Rank2, Rank3, Rank4, Rank5, Rank6};
+pub use shape::{Rank0, Rank1, Rank2, Rank3, Rank4, Rank5, Rank6, Rank7, Rank8};
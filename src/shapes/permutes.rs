@@ -18,6 +18,13 @@ where
 {
     #[inline(always)]
     fn permuted(&self) -> Dst {
+        // ranks 7 & 8 don't check this at compile time, see [DimAt]
+        for i in 0..Src::NUM_DIMS {
+            assert!(
+                Ax::as_array().into_iter().any(|a| a == i as isize),
+                "Ax is not a permutation, axis {i} is missing"
+            );
+        }
         let src_dims = self.concrete();
         let mut dst_dims: Dst::Concrete = Default::default();
         for (i_dst, i_src) in Ax::as_array().into_iter().enumerate() {
@@ -170,6 +177,64 @@ permutations!([0, 1, 2, 3]);
 permutations!([0, 1, 2, 3, 4]);
 permutations!([0, 1, 2, 3, 4, 5]);
 
+/// The [Dim] at axis `I` of a shape.
+///
+/// Expanding every permutation of rank 7 & 8 shapes would be 5040 & 40320 impls,
+/// so those ranks have a single impl of [PermuteShapeTo] that uses this to find
+/// the permuted shape. This means that whether `Ax` is actually a permutation
+/// is only checked at runtime for them.
+pub trait DimAt<const I: isize> {
+    type Dim: Dim;
+}
+
+macro_rules! impl_dim_at {
+    (($($Vars:tt),*), $Axis:tt, $Dim:tt) => {
+        impl<$($Vars: Dim, )*> DimAt<$Axis> for ($($Vars, )*) {
+            type Dim = $Dim;
+        }
+    };
+}
+
+macro_rules! impl_projected_permute {
+    ($Src:tt, [$($Vars:tt),*], $AxesTy:tt, [$($Ax:tt),*]) => {
+        impl<$($Vars: Dim, )* $(const $Ax: isize, )*>
+            PermuteShapeTo<($(<$Src as DimAt<$Ax>>::Dim, )*), $AxesTy<$($Ax, )*>> for $Src
+        where
+            $Src: $(DimAt<$Ax> + )*
+        {
+        }
+    };
+}
+
+impl_dim_at!((D1, D2, D3, D4, D5, D6, D7), 0, D1);
+impl_dim_at!((D1, D2, D3, D4, D5, D6, D7), 1, D2);
+impl_dim_at!((D1, D2, D3, D4, D5, D6, D7), 2, D3);
+impl_dim_at!((D1, D2, D3, D4, D5, D6, D7), 3, D4);
+impl_dim_at!((D1, D2, D3, D4, D5, D6, D7), 4, D5);
+impl_dim_at!((D1, D2, D3, D4, D5, D6, D7), 5, D6);
+impl_dim_at!((D1, D2, D3, D4, D5, D6, D7), 6, D7);
+impl_projected_permute!(
+    (D1, D2, D3, D4, D5, D6, D7),
+    [D1, D2, D3, D4, D5, D6, D7],
+    Axes7,
+    [I, J, K, L, M, N, O]
+);
+
+impl_dim_at!((D1, D2, D3, D4, D5, D6, D7, D8), 0, D1);
+impl_dim_at!((D1, D2, D3, D4, D5, D6, D7, D8), 1, D2);
+impl_dim_at!((D1, D2, D3, D4, D5, D6, D7, D8), 2, D3);
+impl_dim_at!((D1, D2, D3, D4, D5, D6, D7, D8), 3, D4);
+impl_dim_at!((D1, D2, D3, D4, D5, D6, D7, D8), 4, D5);
+impl_dim_at!((D1, D2, D3, D4, D5, D6, D7, D8), 5, D6);
+impl_dim_at!((D1, D2, D3, D4, D5, D6, D7, D8), 6, D7);
+impl_dim_at!((D1, D2, D3, D4, D5, D6, D7, D8), 7, D8);
+impl_projected_permute!(
+    (D1, D2, D3, D4, D5, D6, D7, D8),
+    [D1, D2, D3, D4, D5, D6, D7, D8],
+    Axes8,
+    [I, J, K, L, M, N, O, P]
+);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -193,4 +258,18 @@ mod tests {
         assert_eq!(src.strides(), [6, 3, 1]);
         assert_eq!(dst_strides, [3, 1, 6]);
     }
+
+    #[test]
+    fn test_permute_8d() {
+        let src = (1, Const::<2>, 3, 4, 5, 6, 7, Const::<8>);
+        let dst = PermuteStridesTo::<_, Axes8<7, 6, 5, 4, 3, 2, 1, 0>>::permuted(&src);
+        assert_eq!(dst, (Const::<8>, 7, 6, 5, 4, 3, Const::<2>, 1));
+    }
+
+    #[test]
+    #[should_panic = "is not a permutation"]
+    fn test_permute_7d_duplicate_axes() {
+        let src = (1, 2, 3, 4, 5, 6, 7);
+        let _ = PermuteStridesTo::<_, Axes7<0, 0, 2, 3, 4, 5, 6>>::permuted(&src);
+    }
 }
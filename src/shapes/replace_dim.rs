@@ -1,6 +1,6 @@
 use super::{
     axes::{Axes, Axis},
-    shape::{Dim, Shape},
+    shape::{Const, Dim, Shape},
 };
 
 /// Marker for shapes that can be indexed and have a dimension removed
@@ -140,3 +140,192 @@ impl<Batch: Dim, Seq: Dim, S1: Dim, S2: Dim> ReplaceDimTo<(Batch, Seq, S2), (Bat
 {
     type Ax = Axis<0>;
 }
+
+/// Marker for shapes that can be indexed along their first `K` axes at once by `Idx`,
+/// where the last dimension of `Idx` has size `K` and holds the indices. The leading
+/// dimensions of `Idx` replace the first `K` dimensions of `Self`.
+pub trait GatherNdShapeTo<Dst: Shape, Idx: Shape>: Shape {
+    /// The number of leading axes that are indexed.
+    const K: usize;
+
+    #[inline]
+    fn gather_nd(&self, idx: Idx) -> Dst {
+        // (M, N, O) * (B, 2) -> (B, O)
+        let batch = Idx::NUM_DIMS - 1;
+        let src_dims = self.concrete();
+        let idx_dims = idx.concrete();
+        let mut dst_dims: Dst::Concrete = Default::default();
+        for i in 0..Dst::NUM_DIMS {
+            dst_dims[i] = if i < batch {
+                idx_dims[i]
+            } else {
+                src_dims[i - batch + Self::K]
+            };
+        }
+        Dst::from_concrete(&dst_dims).unwrap()
+    }
+}
+
+macro_rules! gather_nd {
+    // the batch dims of the index are each prefix of `[$Batch]`
+    ($Vars:tt, $K:tt, $Rest:tt, [$($Batch:tt),*]) => {
+        gather_nd!(@prefixes $Vars, $K, $Rest, (), [$($Batch),*]);
+    };
+    (@prefixes $Vars:tt, $K:tt, $Rest:tt, ($($Done:tt),*), []) => {
+        gather_nd!(@impl $Vars, $K, ($($Done),*), $Rest);
+    };
+    (@prefixes $Vars:tt, $K:tt, $Rest:tt, ($($Done:tt),*), [$Next:tt $(, $Todo:tt)*]) => {
+        gather_nd!(@impl $Vars, $K, ($($Done),*), $Rest);
+        gather_nd!(@prefixes $Vars, $K, $Rest, ($($Done, )* $Next), [$($Todo),*]);
+    };
+    (@impl ($($Vars:tt),*), $K:tt, ($($Batch:tt),*), ($($Rest:tt),*)) => {
+impl<$($Vars: Dim, )* $($Batch: Dim, )*>
+    GatherNdShapeTo<($($Batch, )* $($Rest, )*), ($($Batch, )* Const<$K>, )> for ($($Vars, )*)
+{
+    const K: usize = $K;
+}
+    };
+}
+
+// the index and the output have at most 8 dims
+gather_nd!((D1), 1, (), [B1, B2, B3, B4, B5, B6, B7]);
+gather_nd!((D1, D2), 1, (D2), [B1, B2, B3, B4, B5, B6, B7]);
+gather_nd!((D1, D2), 2, (), [B1, B2, B3, B4, B5, B6, B7]);
+gather_nd!((D1, D2, D3), 1, (D2, D3), [B1, B2, B3, B4, B5, B6]);
+gather_nd!((D1, D2, D3), 2, (D3), [B1, B2, B3, B4, B5, B6, B7]);
+gather_nd!((D1, D2, D3), 3, (), [B1, B2, B3, B4, B5, B6, B7]);
+gather_nd!((D1, D2, D3, D4), 1, (D2, D3, D4), [B1, B2, B3, B4, B5]);
+gather_nd!((D1, D2, D3, D4), 2, (D3, D4), [B1, B2, B3, B4, B5, B6]);
+gather_nd!((D1, D2, D3, D4), 3, (D4), [B1, B2, B3, B4, B5, B6, B7]);
+gather_nd!((D1, D2, D3, D4), 4, (), [B1, B2, B3, B4, B5, B6, B7]);
+gather_nd!((D1, D2, D3, D4, D5), 1, (D2, D3, D4, D5), [B1, B2, B3, B4]);
+gather_nd!((D1, D2, D3, D4, D5), 2, (D3, D4, D5), [B1, B2, B3, B4, B5]);
+gather_nd!((D1, D2, D3, D4, D5), 3, (D4, D5), [B1, B2, B3, B4, B5, B6]);
+gather_nd!((D1, D2, D3, D4, D5), 4, (D5), [B1, B2, B3, B4, B5, B6, B7]);
+gather_nd!((D1, D2, D3, D4, D5), 5, (), [B1, B2, B3, B4, B5, B6, B7]);
+gather_nd!(
+    (D1, D2, D3, D4, D5, D6),
+    1,
+    (D2, D3, D4, D5, D6),
+    [B1, B2, B3]
+);
+gather_nd!(
+    (D1, D2, D3, D4, D5, D6),
+    2,
+    (D3, D4, D5, D6),
+    [B1, B2, B3, B4]
+);
+gather_nd!(
+    (D1, D2, D3, D4, D5, D6),
+    3,
+    (D4, D5, D6),
+    [B1, B2, B3, B4, B5]
+);
+gather_nd!(
+    (D1, D2, D3, D4, D5, D6),
+    4,
+    (D5, D6),
+    [B1, B2, B3, B4, B5, B6]
+);
+gather_nd!(
+    (D1, D2, D3, D4, D5, D6),
+    5,
+    (D6),
+    [B1, B2, B3, B4, B5, B6, B7]
+);
+gather_nd!(
+    (D1, D2, D3, D4, D5, D6),
+    6,
+    (),
+    [B1, B2, B3, B4, B5, B6, B7]
+);
+gather_nd!(
+    (D1, D2, D3, D4, D5, D6, D7),
+    1,
+    (D2, D3, D4, D5, D6, D7),
+    [B1, B2]
+);
+gather_nd!(
+    (D1, D2, D3, D4, D5, D6, D7),
+    2,
+    (D3, D4, D5, D6, D7),
+    [B1, B2, B3]
+);
+gather_nd!(
+    (D1, D2, D3, D4, D5, D6, D7),
+    3,
+    (D4, D5, D6, D7),
+    [B1, B2, B3, B4]
+);
+gather_nd!(
+    (D1, D2, D3, D4, D5, D6, D7),
+    4,
+    (D5, D6, D7),
+    [B1, B2, B3, B4, B5]
+);
+gather_nd!(
+    (D1, D2, D3, D4, D5, D6, D7),
+    5,
+    (D6, D7),
+    [B1, B2, B3, B4, B5, B6]
+);
+gather_nd!(
+    (D1, D2, D3, D4, D5, D6, D7),
+    6,
+    (D7),
+    [B1, B2, B3, B4, B5, B6, B7]
+);
+gather_nd!(
+    (D1, D2, D3, D4, D5, D6, D7),
+    7,
+    (),
+    [B1, B2, B3, B4, B5, B6, B7]
+);
+gather_nd!(
+    (D1, D2, D3, D4, D5, D6, D7, D8),
+    1,
+    (D2, D3, D4, D5, D6, D7, D8),
+    [B1]
+);
+gather_nd!(
+    (D1, D2, D3, D4, D5, D6, D7, D8),
+    2,
+    (D3, D4, D5, D6, D7, D8),
+    [B1, B2]
+);
+gather_nd!(
+    (D1, D2, D3, D4, D5, D6, D7, D8),
+    3,
+    (D4, D5, D6, D7, D8),
+    [B1, B2, B3]
+);
+gather_nd!(
+    (D1, D2, D3, D4, D5, D6, D7, D8),
+    4,
+    (D5, D6, D7, D8),
+    [B1, B2, B3, B4]
+);
+gather_nd!(
+    (D1, D2, D3, D4, D5, D6, D7, D8),
+    5,
+    (D6, D7, D8),
+    [B1, B2, B3, B4, B5]
+);
+gather_nd!(
+    (D1, D2, D3, D4, D5, D6, D7, D8),
+    6,
+    (D7, D8),
+    [B1, B2, B3, B4, B5, B6]
+);
+gather_nd!(
+    (D1, D2, D3, D4, D5, D6, D7, D8),
+    7,
+    (D8),
+    [B1, B2, B3, B4, B5, B6, B7]
+);
+gather_nd!(
+    (D1, D2, D3, D4, D5, D6, D7, D8),
+    8,
+    (),
+    [B1, B2, B3, B4, B5, B6, B7]
+);
@@ -70,6 +70,9 @@ pub trait Dtype:
 impl Dtype for f32 {}
 impl Dtype for f64 {}
 impl Dtype for usize {}
+impl Dtype for i32 {}
+impl Dtype for i64 {}
+impl Dtype for u8 {}
 
 /// Represents something that has a [Dtype].
 pub trait HasDtype {
@@ -231,6 +234,14 @@ pub type Rank5<const M: usize, const N: usize, const O: usize, const P: usize, c
 /// Compile time known shape with 6 dimensions
 pub type Rank6<const M: usize, const N: usize, const O: usize, const P: usize, const Q: usize, const R: usize> =
     (Const<M>, Const<N>, Const<O>, Const<P>, Const<Q>, Const<R>);
+#[rustfmt::skip]
+/// Compile time known shape with 7 dimensions
+pub type Rank7<const M: usize, const N: usize, const O: usize, const P: usize, const Q: usize, const R: usize, const S: usize> =
+    (Const<M>, Const<N>, Const<O>, Const<P>, Const<Q>, Const<R>, Const<S>);
+#[rustfmt::skip]
+/// Compile time known shape with 8 dimensions
+pub type Rank8<const M: usize, const N: usize, const O: usize, const P: usize, const Q: usize, const R: usize, const S: usize, const T: usize> =
+    (Const<M>, Const<N>, Const<O>, Const<P>, Const<Q>, Const<R>, Const<S>, Const<T>);
 
 macro_rules! shape {
     (($($D:tt $Idx:tt),*), rank=$Num:expr, all=$All:tt) => {
@@ -293,3 +304,5 @@ shape!((D1 0, D2 1, D3 2), rank=3, all=Axes3);
 shape!((D1 0, D2 1, D3 2, D4 3), rank=4, all=Axes4);
 shape!((D1 0, D2 1, D3 2, D4 3, D5 4), rank=5, all=Axes5);
 shape!((D1 0, D2 1, D3 2, D4 3, D5 4, D6 5), rank=6, all=Axes6);
+shape!((D1 0, D2 1, D3 2, D4 3, D5 4, D6 5, D7 6), rank=7, all=Axes7);
+shape!((D1 0, D2 1, D3 2, D4 3, D5 4, D6 5, D7 6, D8 7), rank=8, all=Axes8);
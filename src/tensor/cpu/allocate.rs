@@ -22,7 +22,7 @@ impl<S: Shape, E: Default + Clone> StridedArray<S, E> {
         let strides: S::Concrete = shape.strides();
 
         #[cfg(feature = "fast_alloc")]
-        let data = std::vec![elem; numel];
+        let data = alloc::vec![elem; numel];
 
         #[cfg(not(feature = "fast_alloc"))]
         let data = {
@@ -47,7 +47,7 @@ impl<S: Shape, E: Default + Clone> StridedArray<S, E> {
         let strides = other.strides;
 
         #[cfg(feature = "fast_alloc")]
-        let data = std::vec![Default::default(); numel];
+        let data = alloc::vec![Default::default(); numel];
 
         #[cfg(not(feature = "fast_alloc"))]
         let data = {
@@ -108,7 +108,7 @@ impl<E: Unit> SampleTensor<E> for Cpu {
     ) -> Result<Tensor<S::Shape, E, Self>, Self::Err> {
         let mut storage = StridedArray::new(*src.shape())?;
         {
-            let mut rng = self.rng.lock().unwrap();
+            let mut rng = self.lock_rng();
             for v in storage.buf_iter_mut() {
                 *v = rng.sample(&distr);
             }
@@ -121,7 +121,7 @@ impl<E: Unit> SampleTensor<E> for Cpu {
         distr: D,
     ) -> Result<(), Self::Err> {
         {
-            let mut rng = self.rng.lock().unwrap();
+            let mut rng = self.lock_rng();
             for v in storage.buf_iter_mut() {
                 *v = rng.sample(&distr);
             }
@@ -1,10 +1,13 @@
 use crate::shapes::{Dtype, HasDtype, HasShape, HasUnitType, Shape, Unit};
 use crate::tensor::storage_traits::*;
+use core::ops::DerefMut;
 use rand::{rngs::StdRng, Rng, SeedableRng};
-use std::{
-    sync::{Arc, Mutex},
-    vec::Vec,
-};
+use std::{sync::Arc, vec::Vec};
+
+#[cfg(not(feature = "std"))]
+use spin::Mutex;
+#[cfg(feature = "std")]
+use std::sync::Mutex;
 
 /// A device that stores data on the heap.
 ///
@@ -31,6 +34,19 @@ impl Cpu {
             rng: Arc::new(Mutex::new(StdRng::seed_from_u64(seed))),
         }
     }
+
+    /// Locks the rng. Without the "std" feature this is a spin lock, since
+    /// there may be no OS to park the thread on (e.g. wasm or embedded).
+    pub(crate) fn lock_rng(&self) -> impl DerefMut<Target = StdRng> + '_ {
+        #[cfg(feature = "std")]
+        {
+            self.rng.lock().unwrap()
+        }
+        #[cfg(not(feature = "std"))]
+        {
+            self.rng.lock()
+        }
+    }
 }
 
 /// The storage for the cpu device
@@ -47,6 +63,8 @@ pub enum CpuError {
     OutOfMemory,
     /// Not enough elements were provided when creating a tensor
     WrongNumElements,
+    /// The shapes of the tensors can't be broadcast together
+    ShapeMismatch,
 }
 
 impl std::fmt::Display for CpuError {
@@ -54,6 +72,7 @@ impl std::fmt::Display for CpuError {
         match self {
             Self::OutOfMemory => f.write_str("CpuError::OutOfMemory"),
             Self::WrongNumElements => f.write_str("CpuError::WrongNumElements"),
+            Self::ShapeMismatch => f.write_str("CpuError::ShapeMismatch"),
         }
     }
 }
@@ -92,6 +111,6 @@ impl DeviceStorage for Cpu {
     }
 
     fn random_u64(&self) -> u64 {
-        self.rng.lock().unwrap().gen()
+        self.lock_rng().gen()
     }
 }
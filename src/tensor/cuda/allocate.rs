@@ -35,6 +35,34 @@ impl Cuda {
             device: self.clone(),
         })
     }
+
+    /// Copies `storage` to the host, keeping its strides. Used by ops that are
+    /// computed on the host.
+    pub(crate) fn storage_to_cpu<S: Shape, E: Unit>(
+        &self,
+        storage: &CudaArray<S, E>,
+    ) -> Result<StridedArray<S, E>, CudaError> {
+        let mut data = std::vec![Default::default(); storage.data.len()];
+        self.dev.sync_copy_from(storage.data.as_ref(), &mut data)?;
+        Ok(StridedArray {
+            data: Arc::new(data),
+            shape: storage.shape,
+            strides: storage.strides,
+        })
+    }
+
+    /// Copies `storage` from the host to the device, keeping its strides.
+    pub(crate) fn storage_from_cpu<S: Shape, E: Unit>(
+        &self,
+        storage: StridedArray<S, E>,
+    ) -> Result<CudaArray<S, E>, CudaError> {
+        let data = self.dev.take_async(storage.data.as_ref().clone())?;
+        Ok(CudaArray {
+            data: Arc::new(data),
+            shape: storage.shape,
+            strides: storage.strides,
+        })
+    }
 }
 
 impl<E: Unit> ZerosTensor<E> for Cuda {
@@ -77,7 +105,7 @@ impl<E: Unit> OneFillStorage<E> for Cuda {
         storage: &mut Self::Storage<S, E>,
     ) -> Result<(), Self::Err> {
         self.dev.copy_into_async(
-            std::vec![E::ONE; storage.data.len()],
+            alloc::vec![E::ONE; storage.data.len()],
             Arc::make_mut(&mut storage.data),
         )?;
         Ok(())
@@ -100,9 +128,9 @@ where
         storage: &mut Self::Storage<S, E>,
         distr: D,
     ) -> Result<(), Self::Err> {
-        let mut host_vec = std::vec![Default::default(); storage.data.len()];
+        let mut host_vec = alloc::vec![Default::default(); storage.data.len()];
         {
-            let mut rng = self.cpu.rng.lock().unwrap();
+            let mut rng = self.cpu.lock_rng();
             host_vec.fill_with(|| rng.sample(&distr));
         }
         self.dev
@@ -0,0 +1,86 @@
+//! Bindings to the parts of cuFFT that dfdx uses. cudarc doesn't wrap cuFFT.
+
+use core::ffi::{c_int, c_void};
+use cudarc::driver::{sys::CUstream, CudaDevice};
+use std::{collections::BTreeMap, sync::Arc};
+
+/// A `cufftResult` other than `CUFFT_SUCCESS`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CufftError(pub c_int);
+
+pub(crate) mod sys {
+    use super::*;
+
+    #[allow(non_camel_case_types)]
+    pub(crate) type cufftHandle = c_int;
+
+    pub(crate) const CUFFT_INVALID_SIZE: c_int = 4;
+    pub(crate) const CUFFT_C2C: c_int = 0x29;
+    pub(crate) const CUFFT_Z2Z: c_int = 0x69;
+    pub(crate) const CUFFT_FORWARD: c_int = -1;
+    pub(crate) const CUFFT_INVERSE: c_int = 1;
+
+    #[link(name = "cufft")]
+    extern "C" {
+        pub(crate) fn cufftPlan1d(
+            plan: *mut cufftHandle,
+            nx: c_int,
+            kind: c_int,
+            batch: c_int,
+        ) -> c_int;
+        pub(crate) fn cufftSetStream(plan: cufftHandle, stream: CUstream) -> c_int;
+        pub(crate) fn cufftExecC2C(
+            plan: cufftHandle,
+            idata: *mut c_void,
+            odata: *mut c_void,
+            direction: c_int,
+        ) -> c_int;
+        pub(crate) fn cufftExecZ2Z(
+            plan: cufftHandle,
+            idata: *mut c_void,
+            odata: *mut c_void,
+            direction: c_int,
+        ) -> c_int;
+        pub(crate) fn cufftDestroy(plan: cufftHandle) -> c_int;
+    }
+}
+
+pub(crate) fn result(status: c_int) -> Result<(), CufftError> {
+    match status {
+        0 => Ok(()),
+        e => Err(CufftError(e)),
+    }
+}
+
+/// cuFFT plans keyed by `(n, batch, kind)`.
+pub(crate) type CufftPlans = BTreeMap<(usize, usize, c_int), CufftPlan>;
+
+/// A batch of `batch` complex to complex transforms of length `n`, stored one after
+/// the other. The plan keeps the device alive so it can be destroyed on drop.
+#[derive(Debug)]
+pub(crate) struct CufftPlan {
+    pub(crate) handle: sys::cufftHandle,
+    #[allow(unused)]
+    device: Arc<CudaDevice>,
+}
+
+impl CufftPlan {
+    pub(crate) fn new(
+        device: Arc<CudaDevice>,
+        n: usize,
+        kind: c_int,
+        batch: usize,
+    ) -> Result<Self, CufftError> {
+        let n = c_int::try_from(n).map_err(|_| CufftError(sys::CUFFT_INVALID_SIZE))?;
+        let batch = c_int::try_from(batch).map_err(|_| CufftError(sys::CUFFT_INVALID_SIZE))?;
+        let mut handle = 0;
+        result(unsafe { sys::cufftPlan1d(&mut handle, n, kind, batch) })?;
+        Ok(Self { handle, device })
+    }
+}
+
+impl Drop for CufftPlan {
+    fn drop(&mut self) {
+        unsafe { sys::cufftDestroy(self.handle) };
+    }
+}
@@ -2,11 +2,13 @@ use crate::shapes::{Dtype, HasDtype, HasShape, HasUnitType, Shape, Unit};
 use crate::tensor::cpu::{Cpu, CpuError};
 use crate::tensor::storage_traits::{DeviceStorage, HasErr};
 
+use super::cufft::{CufftError, CufftPlans};
+
 use cudarc::{
     cublas::{result::CublasError, CudaBlas},
     driver::{result::DriverError, BuildError, CudaDevice, CudaDeviceBuilder, CudaSlice},
 };
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
 #[derive(Debug)]
 pub enum CudaError {
@@ -14,6 +16,9 @@ pub enum CudaError {
     Blas(CublasError),
     Driver(DriverError),
     Cpu(CpuError),
+    Fft(CufftError),
+    /// The op can't be computed this way on cuda
+    Unsupported(&'static str),
 }
 
 impl From<CpuError> for CudaError {
@@ -34,6 +39,12 @@ impl From<CublasError> for CudaError {
     }
 }
 
+impl From<CufftError> for CudaError {
+    fn from(value: CufftError) -> Self {
+        Self::Fft(value)
+    }
+}
+
 impl From<DriverError> for CudaError {
     fn from(value: DriverError) -> Self {
         Self::Driver(value)
@@ -45,6 +56,9 @@ pub struct Cuda {
     pub(crate) cpu: Cpu,
     pub(crate) dev: Arc<CudaDevice>,
     pub(crate) blas: Arc<CudaBlas>,
+    /// Building a cuFFT plan allocates its workspace, so plans are kept for the
+    /// lifetime of the device.
+    pub(crate) fft_plans: Arc<Mutex<CufftPlans>>,
 }
 
 impl Default for Cuda {
@@ -68,7 +82,12 @@ impl Cuda {
         let cpu = Cpu::seed_from_u64(seed);
         let dev = CudaDeviceBuilder::new(ordinal).build()?;
         let blas = Arc::new(CudaBlas::new(dev.clone())?);
-        Ok(Self { cpu, dev, blas })
+        Ok(Self {
+            cpu,
+            dev,
+            blas,
+            fft_plans: Default::default(),
+        })
     }
 
     /// Block until kernels finish processing. Useful for benchmarking.
@@ -0,0 +1,76 @@
+//! Running the cpu kernels of an op on host copies of cuda storage.
+
+use crate::{
+    shapes::{Shape, Unit},
+    tensor::cpu::{Cpu, CpuError, StridedArray},
+};
+
+use super::{Cuda, CudaArray, CudaError};
+
+/// Device storage that [Cuda::on_host] copies to the host.
+pub(crate) trait ToHost {
+    type Host;
+    fn to_host(self, dev: &Cuda) -> Result<Self::Host, CudaError>;
+}
+
+/// Device storage that [Cuda::on_host] creates from what the cpu returned.
+pub(crate) trait FromHost: Sized {
+    type Host;
+    fn from_host(dev: &Cuda, host: Self::Host) -> Result<Self, CudaError>;
+}
+
+impl<S: Shape, E: Unit> ToHost for &CudaArray<S, E> {
+    type Host = StridedArray<S, E>;
+    fn to_host(self, dev: &Cuda) -> Result<Self::Host, CudaError> {
+        dev.storage_to_cpu(self)
+    }
+}
+
+impl<S: Shape, E: Unit> FromHost for CudaArray<S, E> {
+    type Host = StridedArray<S, E>;
+    fn from_host(dev: &Cuda, host: Self::Host) -> Result<Self, CudaError> {
+        dev.storage_from_cpu(host)
+    }
+}
+
+macro_rules! tuple_impls {
+    ($($T:ident),+) => {
+        impl<$($T: ToHost),+> ToHost for ($($T,)+) {
+            type Host = ($($T::Host,)+);
+            #[allow(non_snake_case)]
+            fn to_host(self, dev: &Cuda) -> Result<Self::Host, CudaError> {
+                let ($($T,)+) = self;
+                Ok(($($T.to_host(dev)?,)+))
+            }
+        }
+
+        impl<$($T: FromHost),+> FromHost for ($($T,)+) {
+            type Host = ($($T::Host,)+);
+            #[allow(non_snake_case)]
+            fn from_host(dev: &Cuda, host: Self::Host) -> Result<Self, CudaError> {
+                let ($($T,)+) = host;
+                Ok(($($T::from_host(dev, $T)?,)+))
+            }
+        }
+    };
+}
+
+tuple_impls!(A, B);
+tuple_impls!(A, B, C);
+
+impl Cuda {
+    /// Copies `inp` to the host, runs `f` with the cpu on it, and copies the result back
+    /// to the device. Backward passes return their updated gradients from `f`.
+    ///
+    /// This is how the linear algebra ops (det, svd & solve) run on cuda, as cudarc has
+    /// no cuSOLVER bindings to factorize matrices on the device. It syncs the device on
+    /// every call, so it's only meant as a fallback until those bindings exist.
+    pub(crate) fn on_host<I: ToHost, O: FromHost>(
+        &self,
+        inp: I,
+        f: impl FnOnce(&Cpu, I::Host) -> Result<O::Host, CpuError>,
+    ) -> Result<O, CudaError> {
+        let inp = inp.to_host(self)?;
+        O::from_host(self, f(&self.cpu, inp)?)
+    }
+}
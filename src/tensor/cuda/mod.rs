@@ -1,6 +1,9 @@
 mod allocate;
+pub(crate) mod cufft;
 mod device;
+mod host;
 
 pub(crate) use device::CudaArray;
 
+pub use cufft::CufftError;
 pub use device::{Cuda, CudaError};
@@ -0,0 +1,267 @@
+use super::{storage_traits::DeviceStorage, AsVec, Tensor};
+use crate::shapes::{HasShape, Shape, Unit};
+use std::{string::String, vec::Vec};
+
+/// Options that control how a [Tensor] is printed. Used with [Tensor::display_with].
+///
+/// The defaults are the same as pytorch's: 4 digits of precision, and tensors
+/// with more than 1000 elements are summarized using 3 items on each edge.
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([[1.0f32, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+/// let opts = DisplayOptions {
+///     precision: 1,
+///     ..Default::default()
+/// };
+/// assert_eq!(
+///     std::format!("{}", t.display_with(opts)),
+///     "Tensor(shape=[2, 3], dtype=f32, device=Cpu)\n[[1.0, 2.0, 3.0],\n [4.0, 5.0, 6.0]]"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DisplayOptions {
+    /// Number of digits printed after the decimal point of floating point elements.
+    pub precision: usize,
+    /// Number of items printed at the beginning and end of each axis when summarizing.
+    pub edge_items: usize,
+    /// Tensors with more elements than this are summarized with `...`.
+    pub threshold: usize,
+}
+
+impl Default for DisplayOptions {
+    fn default() -> Self {
+        Self {
+            precision: 4,
+            edge_items: 3,
+            threshold: 1000,
+        }
+    }
+}
+
+/// Implements [std::fmt::Display] for a [Tensor] with specific [DisplayOptions].
+/// Created by [Tensor::display_with].
+#[derive(Debug)]
+pub struct TensorDisplay<'a, S: Shape, E: Unit, D: DeviceStorage, T> {
+    tensor: &'a Tensor<S, E, D, T>,
+    options: DisplayOptions,
+}
+
+impl<S: Shape, E: Unit, D: DeviceStorage, T> Tensor<S, E, D, T> {
+    /// Display the tensor using `options` instead of the defaults. See [DisplayOptions].
+    pub fn display_with(&self, options: DisplayOptions) -> TensorDisplay<'_, S, E, D, T> {
+        TensorDisplay {
+            tensor: self,
+            options,
+        }
+    }
+}
+
+/// Prints the shape, dtype, and device of the tensor, followed by its data.
+/// Large tensors are summarized, see [DisplayOptions].
+///
+/// The precision of the formatter is respected, so `{:.2}` prints 2 decimal places.
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([1.0f32, -2.0, 3.0]);
+/// assert_eq!(
+///     std::format!("{t:.2}"),
+///     "Tensor(shape=[3], dtype=f32, device=Cpu)\n[ 1.00, -2.00,  3.00]"
+/// );
+/// ```
+impl<S: Shape, E: Unit + std::fmt::Display, D: DeviceStorage, T> std::fmt::Display
+    for Tensor<S, E, D, T>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut options = DisplayOptions::default();
+        if let Some(precision) = f.precision() {
+            options.precision = precision;
+        }
+        self.display_with(options).fmt(f)
+    }
+}
+
+impl<'a, S: Shape, E: Unit + std::fmt::Display, D: DeviceStorage, T> std::fmt::Display
+    for TensorDisplay<'a, S, E, D, T>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let shape = self.tensor.shape().concrete();
+        let dims: Vec<usize> = (0..S::NUM_DIMS).map(|i| shape[i]).collect();
+        let device = std::any::type_name::<D>().rsplit("::").next().unwrap();
+        write!(
+            f,
+            "Tensor(shape={dims:?}, dtype={}, device={device})",
+            std::any::type_name::<E>()
+        )?;
+        f.write_str("\n")?;
+
+        let data = self.tensor.as_vec();
+        let summarize = data.len() > self.options.threshold;
+        let edge = self.options.edge_items;
+
+        // for each axis, the indices that are printed. `None` is where `...` goes.
+        let shown: Vec<Vec<Option<usize>>> = dims
+            .iter()
+            .map(|&d| {
+                if summarize && d > 2 * edge {
+                    (0..edge)
+                        .map(Some)
+                        .chain(std::iter::once(None))
+                        .chain((d - edge..d).map(Some))
+                        .collect()
+                } else {
+                    (0..d).map(Some).collect()
+                }
+            })
+            .collect();
+
+        let mut strides = alloc::vec![1; dims.len()];
+        for i in (0..dims.len().saturating_sub(1)).rev() {
+            strides[i] = strides[i + 1] * dims[i + 1];
+        }
+
+        let precision = self.options.precision;
+        let is_bool = std::any::TypeId::of::<E>() == std::any::TypeId::of::<bool>();
+        let fmt_elem = |x: &E| {
+            if is_bool {
+                alloc::format!("{x}")
+            } else {
+                alloc::format!("{x:.precision$}")
+            }
+        };
+
+        let mut printer = Printer {
+            shown: &shown,
+            strides: &strides,
+            data: &data,
+            fmt_elem: &fmt_elem,
+            width: 0,
+        };
+        printer.width = printer.max_width(0, 0);
+        printer.write(f, 0, 0)
+    }
+}
+
+struct Printer<'a, E, F> {
+    shown: &'a [Vec<Option<usize>>],
+    strides: &'a [usize],
+    data: &'a [E],
+    fmt_elem: &'a F,
+    width: usize,
+}
+
+impl<'a, E, F: Fn(&E) -> String> Printer<'a, E, F> {
+    fn max_width(&self, axis: usize, offset: usize) -> usize {
+        if axis == self.shown.len() {
+            return (self.fmt_elem)(&self.data[offset]).chars().count();
+        }
+        self.shown[axis]
+            .iter()
+            .flatten()
+            .map(|i| self.max_width(axis + 1, offset + i * self.strides[axis]))
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn write(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        axis: usize,
+        offset: usize,
+    ) -> std::fmt::Result {
+        if axis == self.shown.len() {
+            let width = self.width;
+            return write!(f, "{:>width$}", (self.fmt_elem)(&self.data[offset]));
+        }
+
+        let last_axis = axis + 1 == self.shown.len();
+        f.write_str("[")?;
+        for (n, i) in self.shown[axis].iter().enumerate() {
+            if n > 0 {
+                if last_axis {
+                    f.write_str(", ")?;
+                } else {
+                    f.write_str(",")?;
+                    for _ in axis + 1..self.shown.len() {
+                        f.write_str("\n")?;
+                    }
+                    for _ in 0..=axis {
+                        f.write_str(" ")?;
+                    }
+                }
+            }
+            match i {
+                Some(i) => self.write(f, axis + 1, offset + i * self.strides[axis])?,
+                None => f.write_str("...")?,
+            }
+        }
+        f.write_str("]")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{shapes::*, tensor::*, tests::TestDevice};
+
+    fn body<T: std::fmt::Display>(t: T) -> String {
+        let s = alloc::format!("{t}");
+        let (_, body) = s.split_once('\n').unwrap();
+        body.into()
+    }
+
+    #[test]
+    fn test_display_header() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, f32, _> = dev.zeros_like(&(2, 3));
+        let s = alloc::format!("{t}");
+        assert!(s.starts_with("Tensor(shape=[2, 3], dtype=f32, device="));
+    }
+
+    #[test]
+    fn test_display_0d() {
+        let dev: TestDevice = Default::default();
+        assert_eq!(body(dev.tensor(1.5f32)), "1.5000");
+    }
+
+    #[test]
+    fn test_display_3d() {
+        let dev: TestDevice = Default::default();
+        let t = dev.tensor([[[1.0f32, -2.0], [3.0, 4.0]], [[5.0, 6.0], [7.0, 80.0]]]);
+        assert_eq!(
+            body(t.display_with(DisplayOptions {
+                precision: 1,
+                ..Default::default()
+            })),
+            "[[[ 1.0, -2.0],\n  [ 3.0,  4.0]],\n\n [[ 5.0,  6.0],\n  [ 7.0, 80.0]]]"
+        );
+    }
+
+    #[test]
+    fn test_display_non_floats() {
+        let dev: TestDevice = Default::default();
+        assert_eq!(body(dev.tensor([true, false])), "[ true, false]");
+        assert_eq!(body(dev.tensor([1usize, 20, 3])), "[ 1, 20,  3]");
+    }
+
+    #[test]
+    fn test_display_summarized() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank2<4, 5>, usize, _> =
+            dev.tensor_from_vec((0..20).collect(), (Const, Const));
+        let opts = DisplayOptions {
+            precision: 0,
+            edge_items: 1,
+            threshold: 10,
+        };
+        assert_eq!(
+            body(t.display_with(opts)),
+            "[[ 0, ...,  4],\n ...,\n [15, ..., 19]]"
+        );
+    }
+}
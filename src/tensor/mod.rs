@@ -51,6 +51,7 @@
 //! # let dev: Cpu = Default::default();
 //! let _: Tensor<Rank1<5>, f32, _> = dev.sample_uniform();
 //! let _: Tensor<Rank2<3, 5>, f32, _> = dev.sample_normal();
+//! let _: Tensor<Rank1<8>, usize, _> = dev.sample_uniform_int(0..10);
 //! // or pass in actual distributions
 //! let _: Tensor<Rank1<3>, f32, _> = dev.sample(rand_distr::Standard);
 //! let _: Tensor<Rank2<4, 3>, f32, _> = dev.sample(rand_distr::StandardNormal);
@@ -87,6 +88,19 @@
 //! let t: [[f32; 3]; 2] = t.array();
 //! ```
 //!
+//! # Printing tensors
+//!
+//! Tensors implement [std::fmt::Display], which prints the shape, dtype, device, and
+//! a summarized view of the data. Use [Tensor::display_with] and [DisplayOptions] to
+//! control the precision and how much of the data is printed.
+//!
+//! ```rust
+//! # use dfdx::prelude::*;
+//! # let dev: Cpu = Default::default();
+//! let t: Tensor<Rank2<100, 100>, f32, _> = dev.zeros();
+//! std::println!("{t:.2}");
+//! ```
+//!
 //! # Tracking gradients
 //!
 //! Use the [Tensor::trace] or [Tensor::traced] methods to add [crate::gradients::OwnedTape] to the [Tensor].
@@ -113,6 +127,7 @@
 pub(crate) mod cpu;
 #[cfg(feature = "cuda")]
 pub(crate) mod cuda;
+mod display;
 #[cfg(feature = "numpy")]
 pub(crate) mod numpy;
 pub(crate) mod storage_traits;
@@ -123,8 +138,9 @@ pub(crate) use storage_traits::{OneFillStorage, ZeroFillStorage};
 pub use cpu::{Cpu, CpuError};
 
 #[cfg(feature = "cuda")]
-pub use cuda::{Cuda, CudaError};
+pub use cuda::{Cuda, CudaError, CufftError};
 
+pub use display::{DisplayOptions, TensorDisplay};
 pub use storage_traits::{AsArray, AsVec, CopySlice, TensorFrom, TensorFromVec};
 pub use storage_traits::{DeviceStorage, HasErr};
 pub use storage_traits::{OnesTensor, SampleTensor, ZerosTensor};
@@ -133,6 +149,7 @@ pub use storage_traits::{OnesTensor, SampleTensor, ZerosTensor};
 pub use tensor_impls::OnCuda;
 pub use tensor_impls::{OnCpu, OnDevice, PutTape, SplitTape, Tensor, ToDevice};
 pub use tensor_impls::{Tensor0D, Tensor1D, Tensor2D, Tensor3D, Tensor4D, Tensor5D, Tensor6D};
+pub use tensor_impls::{Tensor7D, Tensor8D};
 
 #[cfg(test)]
 mod tests {
@@ -220,6 +237,20 @@ mod tests {
         assert_eq!(t.array(), [[1.0, 2.0], [3.0, 4.0]]);
     }
 
+    #[test]
+    fn test_integer_tensors() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<Rank2<2, 2>, i32, _> = dev.tensor([[-1, 2], [3, -4]]);
+        assert_eq!(a.clone().array(), [[-1, 2], [3, -4]]);
+        let mut b: Tensor<Rank1<3>, i64, _> = dev.zeros();
+        b.copy_from(&[i64::MIN, 0, i64::MAX]);
+        assert_eq!(b.array(), [i64::MIN, 0, i64::MAX]);
+        let c: Tensor<Rank1<3>, u8, _> = dev.ones();
+        assert_eq!(c.as_vec(), [1, 1, 1]);
+        let d = dev.tensor_from_vec(std::vec![255u8, 0], (2,));
+        assert_eq!(d.as_vec(), [255, 0]);
+    }
+
     #[test]
     fn fuzz_test_rand() {
         let dev: TestDevice = Default::default();
@@ -234,4 +265,18 @@ mod tests {
         let dev: TestDevice = Default::default();
         let _: Tensor<Rank1<1000>, f32, _> = dev.sample_normal();
     }
+
+    #[test]
+    fn test_sample_uniform_int() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank1<1000>, usize, _> = dev.sample_uniform_int(3..7);
+        let counts = t.bincount().as_vec();
+        assert_eq!(counts.len(), 7);
+        assert_eq!(&counts[..3], [0, 0, 0]);
+        assert!(counts[3..].iter().all(|&c| c > 150));
+
+        let t = dev.sample_uniform_int_like(&(5, Const::<4>), -2isize..0);
+        assert_eq!(t.shape(), &(5, Const::<4>));
+        assert!(t.as_vec().iter().all(|&x| x == -2 || x == -1));
+    }
 }
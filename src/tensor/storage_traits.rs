@@ -1,6 +1,6 @@
-use rand::distributions::Distribution;
+use rand::distributions::{uniform::SampleUniform, Distribution, Uniform};
 use rand_distr::{Standard, StandardNormal};
-use std::vec::Vec;
+use std::{ops::Range, vec::Vec};
 
 use crate::{shapes::*, unique_id::unique_id};
 
@@ -224,6 +224,27 @@ pub trait SampleTensor<E: Unit>: DeviceStorage {
         self.sample_like::<S, _>(src, StandardNormal)
     }
 
+    /// Samples a const tensor of integers uniformly from `range`, which excludes
+    /// `range.end`. Panics if `range` is empty.
+    fn sample_uniform_int<S: ConstShape>(&self, range: Range<E>) -> Tensor<S, E, Self>
+    where
+        E: SampleUniform,
+    {
+        self.sample::<S, _>(Uniform::new(range.start, range.end))
+    }
+    /// Samples a tensor of integers with a given shape uniformly from `range`, which
+    /// excludes `range.end`. Panics if `range` is empty.
+    fn sample_uniform_int_like<S: HasShape>(
+        &self,
+        src: &S,
+        range: Range<E>,
+    ) -> Tensor<S::Shape, E, Self>
+    where
+        E: SampleUniform,
+    {
+        self.sample_like::<S, _>(src, Uniform::new(range.start, range.end))
+    }
+
     /// Samples a const tensor from a given distribution.
     fn sample<S: ConstShape, D: Distribution<E>>(&self, distr: D) -> Tensor<S, E, Self> {
         self.try_sample_like::<S, D>(&Default::default(), distr)
@@ -315,7 +336,7 @@ pub trait TensorFrom<Src, S: Shape, E: Unit>: DeviceStorage {
 
 impl<E: Unit, D: DeviceStorage + TensorFromVec<E>> TensorFrom<E, Rank0, E> for D {
     fn try_tensor(&self, src: E) -> Result<Tensor<Rank0, E, Self>, Self::Err> {
-        self.try_tensor_from_vec(std::vec![src], ())
+        self.try_tensor_from_vec(alloc::vec![src], ())
     }
 }
 
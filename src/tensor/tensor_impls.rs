@@ -270,3 +270,24 @@ pub type Tensor6D<
     const R: usize,
     Tape = NoneTape,
 > = Tensor<Rank6<M, N, O, P, Q, R>, f32, Cpu, Tape>;
+pub type Tensor7D<
+    const M: usize,
+    const N: usize,
+    const O: usize,
+    const P: usize,
+    const Q: usize,
+    const R: usize,
+    const S: usize,
+    Tape = NoneTape,
+> = Tensor<Rank7<M, N, O, P, Q, R, S>, f32, Cpu, Tape>;
+pub type Tensor8D<
+    const M: usize,
+    const N: usize,
+    const O: usize,
+    const P: usize,
+    const Q: usize,
+    const R: usize,
+    const S: usize,
+    const T: usize,
+    Tape = NoneTape,
+> = Tensor<Rank8<M, N, O, P, Q, R, S, T>, f32, Cpu, Tape>;
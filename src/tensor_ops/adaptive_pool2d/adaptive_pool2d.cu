@@ -0,0 +1,248 @@
+#include "cuda_utils.cuh"
+
+struct AdaptivePool2dOp {
+    size_t batch;
+    size_t chan;
+    size_t h_in;
+    size_t h_out;
+    size_t w_in;
+    size_t w_out;
+};
+
+// output index i pools over input indices [start, end)
+__device__ size_t window_start(const size_t i, const size_t inp, const size_t out) {
+    return (i * inp) / out;
+}
+
+__device__ size_t window_end(const size_t i, const size_t inp, const size_t out) {
+    return ((i + 1) * inp + out - 1) / out;
+}
+
+template<typename T>
+__device__ void adaptive_avg_pool2d_fwd(
+    const AdaptivePool2dOp op,
+    const size_t *inp_strides,
+    const size_t *out_strides,
+    const T *inp, // 4d (Batch, Channels, Height, Width)
+    T *out // 4d (Batch, Channels, HeightOut, WidthOut)
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    const size_t numel = op.batch * op.chan * op.h_out * op.w_out;
+    if (i >= numel) {
+        return;
+    }
+
+    unsigned int idx = i;
+    const size_t ow = idx % op.w_out;
+    idx /= op.w_out;
+    const size_t oh = idx % op.h_out;
+    idx /= op.h_out;
+    const size_t c = idx % op.chan;
+    idx /= op.chan;
+    const size_t b = idx % op.batch;
+    idx /= op.batch;
+
+    const size_t y0 = window_start(oh, op.h_in, op.h_out);
+    const size_t y1 = window_end(oh, op.h_in, op.h_out);
+    const size_t x0 = window_start(ow, op.w_in, op.w_out);
+    const size_t x1 = window_end(ow, op.w_in, op.w_out);
+
+    T tmp = 0.0;
+    for (size_t y = y0; y < y1; y++) {
+        for (size_t x = x0; x < x1; x++) {
+            auto inp_i = b * inp_strides[0] + c * inp_strides[1] + y * inp_strides[2] + x * inp_strides[3];
+            tmp += inp[inp_i];
+        }
+    }
+
+    tmp /= static_cast<T>((y1 - y0) * (x1 - x0));
+    out[i] = tmp;
+}
+
+template<typename T>
+__device__ void adaptive_avg_pool2d_bwd(
+    const AdaptivePool2dOp op,
+    const size_t *inp_strides,
+    const size_t *out_strides,
+    const T *inp, // 4d (Batch, Channels, Height, Width)
+    T *grad_inp,
+    const T *out, // 4d (Batch, Channels, HeightOut, WidthOut)
+    const T *grad_out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    const size_t numel = op.batch * op.chan * op.h_in * op.w_in;
+    if (i >= numel) {
+        return;
+    }
+
+    unsigned int idx = i;
+    const size_t x = idx % op.w_in;
+    idx /= op.w_in;
+    const size_t y = idx % op.h_in;
+    idx /= op.h_in;
+    const size_t c = idx % op.chan;
+    idx /= op.chan;
+    const size_t b = idx % op.batch;
+    idx /= op.batch;
+
+    // candidate outputs whose window may contain (y, x)
+    const size_t oh0 = window_start(y, op.h_out, op.h_in);
+    const size_t oh1 = window_end(y, op.h_out, op.h_in);
+    const size_t ow0 = window_start(x, op.w_out, op.w_in);
+    const size_t ow1 = window_end(x, op.w_out, op.w_in);
+
+    T tmp = 0.0;
+    for (size_t oh = oh0; oh < oh1; oh++) {
+        const size_t y0 = window_start(oh, op.h_in, op.h_out);
+        const size_t y1 = window_end(oh, op.h_in, op.h_out);
+        if (y < y0 || y >= y1) { continue; }
+        for (size_t ow = ow0; ow < ow1; ow++) {
+            const size_t x0 = window_start(ow, op.w_in, op.w_out);
+            const size_t x1 = window_end(ow, op.w_in, op.w_out);
+            if (x < x0 || x >= x1) { continue; }
+
+            auto out_i = b * out_strides[0] + c * out_strides[1] + oh * out_strides[2] + ow * out_strides[3];
+            tmp += grad_out[out_i] / static_cast<T>((y1 - y0) * (x1 - x0));
+        }
+    }
+
+    grad_inp[i] += tmp;
+}
+
+template<typename T>
+__device__ void adaptive_max_pool2d_fwd(
+    const AdaptivePool2dOp op,
+    const size_t *inp_strides,
+    const size_t *out_strides,
+    const T *inp, // 4d (Batch, Channels, Height, Width)
+    T *out // 4d (Batch, Channels, HeightOut, WidthOut)
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    const size_t numel = op.batch * op.chan * op.h_out * op.w_out;
+    if (i >= numel) {
+        return;
+    }
+
+    unsigned int idx = i;
+    const size_t ow = idx % op.w_out;
+    idx /= op.w_out;
+    const size_t oh = idx % op.h_out;
+    idx /= op.h_out;
+    const size_t c = idx % op.chan;
+    idx /= op.chan;
+    const size_t b = idx % op.batch;
+    idx /= op.batch;
+
+    const size_t y0 = window_start(oh, op.h_in, op.h_out);
+    const size_t y1 = window_end(oh, op.h_in, op.h_out);
+    const size_t x0 = window_start(ow, op.w_in, op.w_out);
+    const size_t x1 = window_end(ow, op.w_in, op.w_out);
+
+    T tmp = -INFINITY;
+    for (size_t y = y0; y < y1; y++) {
+        for (size_t x = x0; x < x1; x++) {
+            auto inp_i = b * inp_strides[0] + c * inp_strides[1] + y * inp_strides[2] + x * inp_strides[3];
+            tmp = maxg(tmp, inp[inp_i]);
+        }
+    }
+
+    out[i] = tmp;
+}
+
+template<typename T>
+__device__ void adaptive_max_pool2d_bwd(
+    const AdaptivePool2dOp op,
+    const size_t *inp_strides,
+    const size_t *out_strides,
+    const T *inp, // 4d (Batch, Channels, Height, Width)
+    T *grad_inp,
+    const T *out, // 4d (Batch, Channels, HeightOut, WidthOut)
+    const T *grad_out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    const size_t numel = op.batch * op.chan * op.h_in * op.w_in;
+    if (i >= numel) {
+        return;
+    }
+
+    unsigned int idx = i;
+    const size_t x = idx % op.w_in;
+    idx /= op.w_in;
+    const size_t y = idx % op.h_in;
+    idx /= op.h_in;
+    const size_t c = idx % op.chan;
+    idx /= op.chan;
+    const size_t b = idx % op.batch;
+    idx /= op.batch;
+
+    const T inp_v = inp[i];
+
+    // candidate outputs whose window may contain (y, x)
+    const size_t oh0 = window_start(y, op.h_out, op.h_in);
+    const size_t oh1 = window_end(y, op.h_out, op.h_in);
+    const size_t ow0 = window_start(x, op.w_out, op.w_in);
+    const size_t ow1 = window_end(x, op.w_out, op.w_in);
+
+    T tmp = 0.0;
+    for (size_t oh = oh0; oh < oh1; oh++) {
+        const size_t y0 = window_start(oh, op.h_in, op.h_out);
+        const size_t y1 = window_end(oh, op.h_in, op.h_out);
+        if (y < y0 || y >= y1) { continue; }
+        for (size_t ow = ow0; ow < ow1; ow++) {
+            const size_t x0 = window_start(ow, op.w_in, op.w_out);
+            const size_t x1 = window_end(ow, op.w_in, op.w_out);
+            if (x < x0 || x >= x1) { continue; }
+
+            auto out_i = b * out_strides[0] + c * out_strides[1] + oh * out_strides[2] + ow * out_strides[3];
+            if (out[out_i] == inp_v) {
+                tmp += grad_out[out_i];
+            }
+        }
+    }
+
+    grad_inp[i] += tmp;
+}
+
+#define POOL_OP(TYPENAME, fwd, bwd, fwd_FN, bwd_FN) \
+extern "C" __global__ void fwd( \
+    const AdaptivePool2dOp op, \
+    const size_t *inp_strides, \
+    const size_t *out_strides, \
+    const TYPENAME *inp, \
+    TYPENAME *out \
+) { \
+    fwd_FN(op, inp_strides, out_strides, inp, out); \
+} \
+extern "C" __global__ void bwd( \
+    const AdaptivePool2dOp op, \
+    const size_t *inp_strides, \
+    const size_t *out_strides, \
+    const TYPENAME *inp, \
+    TYPENAME *grad_inp, \
+    const TYPENAME *out, \
+    const TYPENAME *grad_out \
+) { \
+    bwd_FN(op, inp_strides, out_strides, inp, grad_inp, out, grad_out); \
+}
+
+POOL_OP(
+    float,
+    adaptive_avg_pool2d_fwd_f32, adaptive_avg_pool2d_bwd_f32,
+    adaptive_avg_pool2d_fwd, adaptive_avg_pool2d_bwd
+);
+POOL_OP(
+    float,
+    adaptive_max_pool2d_fwd_f32, adaptive_max_pool2d_bwd_f32,
+    adaptive_max_pool2d_fwd, adaptive_max_pool2d_bwd
+);
+
+POOL_OP(
+    double,
+    adaptive_avg_pool2d_fwd_f64, adaptive_avg_pool2d_bwd_f64,
+    adaptive_avg_pool2d_fwd, adaptive_avg_pool2d_bwd
+);
+POOL_OP(
+    double,
+    adaptive_max_pool2d_fwd_f64, adaptive_max_pool2d_bwd_f64,
+    adaptive_max_pool2d_fwd, adaptive_max_pool2d_bwd
+);
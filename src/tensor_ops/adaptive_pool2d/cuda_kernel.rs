@@ -0,0 +1,94 @@
+use crate::{shapes::*, tensor::cuda::Cuda};
+
+use std::sync::Arc;
+
+use cudarc::driver::{AsKernelParam, LaunchAsync, LaunchConfig};
+
+const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/adaptive_pool2d.ptx"));
+
+unsafe impl AsKernelParam for super::AdaptivePool2DOp {}
+
+fn make_4d<S: Shape>(strides: S::Concrete) -> [usize; 4] {
+    match S::NUM_DIMS {
+        3 => [0, strides[0], strides[1], strides[2]],
+        4 => [strides[0], strides[1], strides[2], strides[3]],
+        _ => panic!("Only implemented for 3d & 4d arrays"),
+    }
+}
+
+macro_rules! pool_impl {
+    ($Trait:tt<$TypeName:ty>, $Fwd:tt, $Bwd:tt) => {
+        impl super::$Trait<$TypeName> for Cuda {
+            fn forward<I: Shape, O: Shape>(
+                &self,
+                op: super::AdaptivePool2DOp,
+                inp: &Self::Storage<I, $TypeName>,
+                out: &mut Self::Storage<O, $TypeName>,
+            ) -> Result<(), Self::Err> {
+                if !self.dev.has_func($Fwd, $Fwd) {
+                    self.dev.load_ptx(PTX_SRC.into(), $Fwd, &[$Fwd, $Bwd])?;
+                }
+
+                let inp_strides = self.dev.take_async(make_4d::<I>(inp.strides).into())?;
+                let out_strides = self.dev.take_async(make_4d::<O>(out.strides).into())?;
+                let fwd_fn = self.dev.get_func($Fwd, $Fwd).unwrap();
+                let cfg = LaunchConfig::for_num_elems(out.shape().num_elements() as u32);
+                let params = (
+                    op,                           // const AdaptivePool2dOp op,
+                    &inp_strides,                 // const size_t *inp_strides,
+                    &out_strides,                 // const size_t *out_strides,
+                    inp.data.as_ref(),            // const float *inp,
+                    Arc::make_mut(&mut out.data), // float *out
+                );
+                unsafe { fwd_fn.launch_async(cfg, params) }?;
+                Ok(())
+            }
+            fn backward<I: Shape, O: Shape>(
+                &self,
+                op: super::AdaptivePool2DOp,
+                inp: &Self::Storage<I, $TypeName>,
+                grad_inp: &mut Self::Storage<I, $TypeName>,
+                out: &Self::Storage<O, $TypeName>,
+                grad_out: &Self::Storage<O, $TypeName>,
+            ) -> Result<(), Self::Err> {
+                let inp_strides = self.dev.take_async(make_4d::<I>(inp.strides).into())?;
+                let out_strides = self.dev.take_async(make_4d::<O>(out.strides).into())?;
+                let bwd_fn = self.dev.get_func($Fwd, $Bwd).unwrap();
+                let cfg = LaunchConfig::for_num_elems(grad_inp.shape().num_elements() as u32);
+                let params = (
+                    op,                                // const AdaptivePool2dOp op,
+                    &inp_strides,                      // const size_t *inp_strides,
+                    &out_strides,                      // const size_t *out_strides,
+                    inp.data.as_ref(),                 // const float *inp,
+                    Arc::make_mut(&mut grad_inp.data), // float *grad_inp,
+                    out.data.as_ref(),                 // const float *out,
+                    grad_out.data.as_ref(),            // const float *grad_out
+                );
+                unsafe { bwd_fn.launch_async(cfg, params) }?;
+                Ok(())
+            }
+        }
+    };
+}
+
+pool_impl!(
+    AdaptiveAvgPool2DKernel<f32>,
+    "adaptive_avg_pool2d_fwd_f32",
+    "adaptive_avg_pool2d_bwd_f32"
+);
+pool_impl!(
+    AdaptiveMaxPool2DKernel<f32>,
+    "adaptive_max_pool2d_fwd_f32",
+    "adaptive_max_pool2d_bwd_f32"
+);
+
+pool_impl!(
+    AdaptiveAvgPool2DKernel<f64>,
+    "adaptive_avg_pool2d_fwd_f64",
+    "adaptive_avg_pool2d_bwd_f64"
+);
+pool_impl!(
+    AdaptiveMaxPool2DKernel<f64>,
+    "adaptive_max_pool2d_fwd_f64",
+    "adaptive_max_pool2d_bwd_f64"
+);
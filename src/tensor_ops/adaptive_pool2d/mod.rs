@@ -0,0 +1,257 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{
+    gradients::Tape,
+    shapes::*,
+    tensor::{DeviceStorage, HasErr, PutTape, SplitTape, Tensor, ZerosTensor},
+};
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct AdaptivePool2DOp {
+    pub batch: usize,
+    pub chan: usize,
+    pub h_in: usize,
+    pub h_out: usize,
+    pub w_in: usize,
+    pub w_out: usize,
+}
+
+impl AdaptivePool2DOp {
+    fn new([b, c, h_in, w_in]: [usize; 4], [h_out, w_out]: [usize; 2]) -> Self {
+        assert!(h_in > 0 && w_in > 0, "Can't adaptively pool an empty image");
+        Self {
+            batch: b,
+            chan: c,
+            h_in,
+            h_out,
+            w_in,
+            w_out,
+        }
+    }
+}
+
+/// The range of input indices that output index `i` pools over. Output index `i` starts
+/// at `floor(i * inp / out)` and ends (exclusive) at `ceil((i + 1) * inp / out)`, so
+/// neighbouring windows may overlap by one element when `inp` isn't divisible by `out`.
+#[inline(always)]
+pub(super) fn pool_window(i: usize, inp: usize, out: usize) -> core::ops::Range<usize> {
+    let start = (i * inp) / out;
+    let end = ((i + 1) * inp + out - 1) / out;
+    start..end
+}
+
+macro_rules! adaptive_pool2d {
+    (Kernel=$Kernel:ident, ConstTrait=$ConstTrait:ident, TryTrait=$TryTrait:ident, Meth=$Meth:ident, TryMeth=$TryMeth:ident) => {
+        pub trait $Kernel<E: Unit>: DeviceStorage {
+            fn forward<I: Shape, O: Shape>(
+                &self,
+                op: AdaptivePool2DOp,
+                inp: &Self::Storage<I, E>,
+                out: &mut Self::Storage<O, E>,
+            ) -> Result<(), Self::Err>;
+
+            fn backward<I: Shape, O: Shape>(
+                &self,
+                op: AdaptivePool2DOp,
+                inp: &Self::Storage<I, E>,
+                grad_inp: &mut Self::Storage<I, E>,
+                out: &Self::Storage<O, E>,
+                grad_out: &Self::Storage<O, E>,
+            ) -> Result<(), Self::Err>;
+        }
+
+        pub trait $ConstTrait<const H: usize, const W: usize>: HasErr {
+            type Output;
+            fn try_adaptive_pool2d(self) -> Result<Self::Output, Self::Err>;
+        }
+
+        pub trait $TryTrait {
+            fn $Meth<const H: usize, const W: usize>(self) -> Self::Output
+            where
+                Self: $ConstTrait<H, W>,
+            {
+                self.try_adaptive_pool2d().unwrap()
+            }
+            fn $TryMeth<const H: usize, const W: usize>(self) -> Result<Self::Output, Self::Err>
+            where
+                Self: $ConstTrait<H, W>,
+            {
+                self.try_adaptive_pool2d()
+            }
+        }
+        impl<T> $TryTrait for T {}
+
+        impl<
+                C: Dim,
+                Hi: Dim,
+                Wi: Dim,
+                E: Dtype,
+                D: $Kernel<E> + ZerosTensor<E>,
+                T: 'static + Tape<D>,
+                const H: usize,
+                const W: usize,
+            > $ConstTrait<H, W> for Tensor<(C, Hi, Wi), E, D, T>
+        {
+            type Output = Tensor<(C, Const<H>, Const<W>), E, D, T>;
+
+            fn try_adaptive_pool2d(self) -> Result<Self::Output, Self::Err> {
+                let &(chan, h_in, w_in) = self.shape();
+                let op = AdaptivePool2DOp::new([1, chan.size(), h_in.size(), w_in.size()], [H, W]);
+                let (inp, mut tape) = self.split_tape();
+                let mut out = inp.device.try_zeros_like(&(chan, Const, Const))?;
+                inp.device.forward(op, &inp.storage, &mut out.storage)?;
+                let phantom_out = out.clone();
+                tape.try_alloc_grad(&inp)?;
+                tape.try_alloc_grad(&out)?;
+                tape.add_backward_op(move |grads| {
+                    let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+                    inp.device
+                        .backward(op, &inp.storage, grad_inp, &phantom_out.storage, grad_out)
+                });
+                Ok(out.put_tape(tape))
+            }
+        }
+
+        impl<
+                B: Dim,
+                C: Dim,
+                Hi: Dim,
+                Wi: Dim,
+                E: Dtype,
+                D: $Kernel<E> + ZerosTensor<E>,
+                T: 'static + Tape<D>,
+                const H: usize,
+                const W: usize,
+            > $ConstTrait<H, W> for Tensor<(B, C, Hi, Wi), E, D, T>
+        {
+            type Output = Tensor<(B, C, Const<H>, Const<W>), E, D, T>;
+
+            fn try_adaptive_pool2d(self) -> Result<Self::Output, Self::Err> {
+                let &(batch, chan, h_in, w_in) = self.shape();
+                let op = AdaptivePool2DOp::new(
+                    [batch.size(), chan.size(), h_in.size(), w_in.size()],
+                    [H, W],
+                );
+                let (inp, mut tape) = self.split_tape();
+                let mut out = inp.device.try_zeros_like(&(batch, chan, Const, Const))?;
+                inp.device.forward(op, &inp.storage, &mut out.storage)?;
+                let phantom_out = out.clone();
+                tape.try_alloc_grad(&inp)?;
+                tape.try_alloc_grad(&out)?;
+                tape.add_backward_op(move |grads| {
+                    let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+                    inp.device
+                        .backward(op, &inp.storage, grad_inp, &phantom_out.storage, grad_out)
+                });
+                Ok(out.put_tape(tape))
+            }
+        }
+    };
+}
+
+adaptive_pool2d!(
+    Kernel = AdaptiveAvgPool2DKernel,
+    ConstTrait = ConstAdaptiveAvgPool2D,
+    TryTrait = TryAdaptiveAvgPool2D,
+    Meth = adaptive_avg_pool2d,
+    TryMeth = try_adaptive_avg_pool2d
+);
+
+adaptive_pool2d!(
+    Kernel = AdaptiveMaxPool2DKernel,
+    ConstTrait = ConstAdaptiveMaxPool2D,
+    TryTrait = TryAdaptiveMaxPool2D,
+    Meth = adaptive_max_pool2d,
+    TryMeth = try_adaptive_max_pool2d
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_pool_window() {
+        assert_eq!(pool_window(0, 5, 3), 0..2);
+        assert_eq!(pool_window(1, 5, 3), 1..4);
+        assert_eq!(pool_window(2, 5, 3), 3..5);
+        assert_eq!(pool_window(0, 2, 4), 0..1);
+        assert_eq!(pool_window(1, 2, 4), 0..1);
+        assert_eq!(pool_window(2, 2, 4), 1..2);
+        assert_eq!(pool_window(3, 2, 4), 1..2);
+    }
+
+    #[test]
+    fn test_adaptive_avg_pool2d_3d_divisible() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([[
+            [1.0, 2.0, 3.0, 4.0],
+            [5.0, 6.0, 7.0, 8.0],
+            [-1.0, -2.0, -3.0, -4.0],
+            [0.5, 0.5, 1.5, 1.5],
+        ]]);
+        let r = x.trace().adaptive_avg_pool2d::<2, 2>();
+        assert_close(&r.array(), &[[[3.5, 5.5], [-0.5, -1.0]]]);
+        let g = r.sum().backward();
+        assert_close(&g.get(&x).array(), &[[[0.25; 4]; 4]]);
+    }
+
+    #[test]
+    fn test_adaptive_avg_pool2d_3d_overlapping() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> =
+            dev.tensor([[[1.0, 2.0, 3.0, 4.0, 5.0], [6.0, 7.0, 8.0, 9.0, 10.0]]]);
+        let r = x.trace().adaptive_avg_pool2d::<1, 3>();
+        assert_close(&r.array(), &[[[4.0, 5.5, 7.0]]]);
+        let g = r.sum().backward();
+        let (a, b) = (1.0 / 4.0, 1.0 / 6.0);
+        assert_close(
+            &g.get(&x).array(),
+            &[[[a, a + b, b, b + a, a], [a, a + b, b, b + a, a]]],
+        );
+    }
+
+    #[test]
+    fn test_adaptive_max_pool2d_4d() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([
+            [[[0.1, -0.5, 0.3], [0.9, 0.2, -0.1], [0.0, 0.4, 0.3]]],
+            [[[1.0, 1.0, -1.0], [-2.0, 0.5, 0.5], [0.2, 0.7, -0.3]]],
+        ]);
+        let r = x.trace().adaptive_max_pool2d::<2, 2>();
+        assert_close(
+            &r.array(),
+            &[[[[0.9, 0.3], [0.9, 0.4]]], [[[1.0, 1.0], [0.7, 0.7]]]],
+        );
+        let g = r.sum().backward();
+        assert_close(
+            &g.get(&x).array(),
+            &[
+                [[[0.0, 0.0, 1.0], [2.0, 0.0, 0.0], [0.0, 1.0, 0.0]]],
+                [[[1.0, 2.0, 0.0], [0.0, 0.0, 0.0], [0.0, 2.0, 0.0]]],
+            ],
+        );
+    }
+
+    #[test]
+    fn test_adaptive_max_pool2d_upsamples() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([[[1.0, 2.0], [3.0, 4.0]]]);
+        let r = x.trace().adaptive_max_pool2d::<4, 4>();
+        assert_close(
+            &r.array(),
+            &[[
+                [1.0, 1.0, 2.0, 2.0],
+                [1.0, 1.0, 2.0, 2.0],
+                [3.0, 3.0, 4.0, 4.0],
+                [3.0, 3.0, 4.0, 4.0],
+            ]],
+        );
+        let g = r.sum().backward();
+        assert_close(&g.get(&x).array(), &[[[4.0, 4.0], [4.0, 4.0]]]);
+    }
+}
@@ -10,4 +10,25 @@ BINARY_OP(float, badd_fwd_f32, badd_bwd_f32, BinaryAddOp,
 BINARY_OP(double, badd_fwd_f64, badd_bwd_f64, BinaryAddOp,
     x + y,
     1.0,
-    1.0)
\ No newline at end of file
+    1.0)
+
+// integers don't have gradients
+BINARY_OP(size_t, badd_fwd_usize, badd_bwd_usize, BinaryAddOp,
+    wrapping_add(x, y),
+    0,
+    0)
+
+BINARY_OP(int, badd_fwd_i32, badd_bwd_i32, BinaryAddOp,
+    wrapping_add(x, y),
+    0,
+    0)
+
+BINARY_OP(long long, badd_fwd_i64, badd_bwd_i64, BinaryAddOp,
+    wrapping_add(x, y),
+    0,
+    0)
+
+BINARY_OP(unsigned char, badd_fwd_u8, badd_bwd_u8, BinaryAddOp,
+    wrapping_add(x, y),
+    0,
+    0)
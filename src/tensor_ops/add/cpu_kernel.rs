@@ -1,5 +1,7 @@
-use crate::tensor_ops::cpu_kernels::{BinaryDerivative, UnaryDerivative};
-use num_traits::Float;
+use super::ScalarAddKernelOp;
+use crate::tensor_ops::cpu_kernels::{
+    int_binary_derivative, int_scalar_derivative, BinaryDerivative, Float, UnaryDerivative,
+};
 
 impl<F: Float> BinaryDerivative<F> for super::BinaryAddKernelOp {
     #[inline(always)]
@@ -24,3 +26,6 @@ impl<F: Float> UnaryDerivative<F> for super::ScalarAddKernelOp<F> {
         F::one()
     }
 }
+
+int_binary_derivative!(super::BinaryAddKernelOp, |x, y| x.wrapping_add(y));
+int_scalar_derivative!(ScalarAddKernelOp, |x, scalar| x.wrapping_add(scalar));
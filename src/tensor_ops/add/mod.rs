@@ -39,6 +39,22 @@ pub struct ScalarAddKernelOp<E> {
 /// let r = a + 1.0;
 /// assert_eq!(r.array(), [[2.0, 3.0, 4.0], [0.0, -1.0, -2.0]]);
 /// ```
+///
+/// Integer tensors support add, sub, mul, div, [maximum](crate::tensor_ops::maximum) and
+/// [minimum](crate::tensor_ops::minimum) too, so index tensors can be adjusted without leaving
+/// the device. No gradients flow through integer ops.
+///
+/// Integer add, sub and mul wrap around on overflow, like rust's `wrapping_*` methods, and
+/// [div()](crate::tensor_ops::div) saturates instead of panicking when dividing by zero.
+/// Both devices give the same results.
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+/// let idx = dev.tensor([0, 1]);
+/// let r: Tensor<Rank1<2>, f32, _> = t.select(idx + 1);
+/// assert_eq!(r.array(), [2.0, 6.0]);
+/// ```
 pub fn add<S: Shape, E: Dtype, D: Device<E>, T: Tape<D> + Merge<RhsTape>, RhsTape: Tape<D>>(
     lhs: Tensor<S, E, D, T>,
     rhs: Tensor<S, E, D, RhsTape>,
@@ -206,4 +222,25 @@ mod tests {
         let g = r.exp().sum().backward();
         assert_close(&g.get(&x).array(), &[[1.6487212; 2]; 3]);
     }
+
+    #[test]
+    fn test_add_usize() {
+        let dev: TestDevice = Default::default();
+        let a = dev.tensor([[0usize, 1, 2], [3, 4, 5]]);
+        let b = dev.tensor([[1usize, 1, 1], [2, 0, 7]]);
+        assert_eq!((a.clone() + b).array(), [[1, 2, 3], [5, 4, 12]]);
+        assert_eq!((a + 3).array(), [[3, 4, 5], [6, 7, 8]]);
+    }
+
+    #[test]
+    fn test_add_int_wraps() {
+        let dev: TestDevice = Default::default();
+        let a = dev.tensor([usize::MAX, 1]);
+        assert_eq!((a + 2).array(), [1, 3]);
+        let a = dev.tensor([250u8, 4]);
+        let b = dev.tensor([10u8, 251]);
+        assert_eq!((a + b).array(), [4, 255]);
+        let a = dev.tensor([i64::MAX, -5]);
+        assert_eq!((a + 1).array(), [i64::MIN, -4]);
+    }
 }
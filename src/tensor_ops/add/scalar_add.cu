@@ -1,3 +1,4 @@
+#include "cuda_utils.cuh"
 #include "unary_op_macros.cuh"
 
 template<typename F>
@@ -12,4 +13,20 @@ UNARY_OP(float, sadd_fwd_f32, sadd_bwd_f32, ScalarAddKernelOp<float>,
 UNARY_OP(double, sadd_fwd_f64, sadd_bwd_f64, ScalarAddKernelOp<double>,
     x + op.scalar,
     1.0);
-    
\ No newline at end of file
+
+// integers don't have gradients
+UNARY_OP(size_t, sadd_fwd_usize, sadd_bwd_usize, ScalarAddKernelOp<size_t>,
+    wrapping_add(x, op.scalar),
+    0);
+
+UNARY_OP(int, sadd_fwd_i32, sadd_bwd_i32, ScalarAddKernelOp<int>,
+    wrapping_add(x, op.scalar),
+    0);
+
+UNARY_OP(long long, sadd_fwd_i64, sadd_bwd_i64, ScalarAddKernelOp<long long>,
+    wrapping_add(x, op.scalar),
+    0);
+
+UNARY_OP(unsigned char, sadd_fwd_u8, sadd_bwd_u8, ScalarAddKernelOp<unsigned char>,
+    wrapping_add(x, op.scalar),
+    0);
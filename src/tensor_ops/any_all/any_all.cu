@@ -0,0 +1,29 @@
+#include "cuda_utils.cuh"
+
+// Each thread reduces the `chunk_len` elements of one output element. The reduced
+// axes are the last dims, so they are the logical indices
+// [i * chunk_len, (i + 1) * chunk_len).
+#define BOOL_REDUCE_OP(NAME, INIT, OP) \
+extern "C" __global__ void NAME( \
+    const size_t numel, \
+    const size_t chunk_len, \
+    const size_t num_dims, \
+    const size_t *dims, \
+    const size_t *strides, \
+    const bool *inp, \
+    bool *out \
+) { \
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x; \
+    if (i >= numel) { \
+        return; \
+    } \
+\
+    bool tmp = INIT; \
+    for (unsigned int j = i * chunk_len; j < (i + 1) * chunk_len; j++) { \
+        tmp = tmp OP inp[get_strided_index(j, num_dims, dims, strides)]; \
+    } \
+    out[i] = tmp; \
+}
+
+BOOL_REDUCE_OP(any_to, false, ||);
+BOOL_REDUCE_OP(all_to, true, &&);
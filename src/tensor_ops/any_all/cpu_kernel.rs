@@ -0,0 +1,46 @@
+use crate::{
+    shapes::{Axes, HasAxes, ReduceShapeTo, Shape},
+    tensor::cpu::{Cpu, StridedArray},
+    tensor_ops::utilities::reduction_utils::index_for_reductions,
+};
+
+impl Cpu {
+    fn reduce_bool<Src: Shape + ReduceShapeTo<Dst, Ax>, Dst: Shape, Ax: Axes>(
+        &self,
+        dst: Dst,
+        inp: &StridedArray<Src, bool>,
+        init: bool,
+        f: impl Fn(bool, bool) -> bool,
+    ) -> Result<StridedArray<Dst, bool>, <Self as crate::tensor::HasErr>::Err> {
+        let mut out: StridedArray<Dst, bool> = StridedArray::new(dst)?;
+        let num_elems_reduced = <Src as HasAxes<Ax>>::size(&inp.shape);
+        let inp_buf = inp.data.as_ref();
+        let mut idx = index_for_reductions::<Src, Ax>(inp.shape, inp.strides);
+        for o in out.buf_iter_mut() {
+            let mut tmp = init;
+            for _ in 0..num_elems_reduced {
+                tmp = f(tmp, inp_buf[idx.next().unwrap()]);
+            }
+            *o = tmp;
+        }
+        Ok(out)
+    }
+}
+
+impl super::AnyAllKernel for Cpu {
+    fn any<Src: Shape + ReduceShapeTo<Dst, Ax>, Dst: Shape, Ax: Axes>(
+        &self,
+        dst: Dst,
+        inp: &Self::Storage<Src, bool>,
+    ) -> Result<Self::Storage<Dst, bool>, Self::Err> {
+        self.reduce_bool(dst, inp, false, |a, b| a || b)
+    }
+
+    fn all<Src: Shape + ReduceShapeTo<Dst, Ax>, Dst: Shape, Ax: Axes>(
+        &self,
+        dst: Dst,
+        inp: &Self::Storage<Src, bool>,
+    ) -> Result<Self::Storage<Dst, bool>, Self::Err> {
+        self.reduce_bool(dst, inp, true, |a, b| a && b)
+    }
+}
@@ -0,0 +1,81 @@
+use crate::{
+    shapes::*,
+    tensor::cuda::{Cuda, CudaArray},
+};
+use cudarc::driver::{CudaSlice, LaunchAsync, LaunchConfig};
+use std::{sync::Arc, vec::Vec};
+
+const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/any_all.ptx"));
+const MODULE_NAME: &str = "any_all";
+const ALL_FN_NAMES: [&str; 2] = ["any_to", "all_to"];
+
+impl Cuda {
+    fn reduce_bool<Src: Shape + ReduceShapeTo<Dst, Ax>, Dst: Shape, Ax: Axes>(
+        &self,
+        fn_name: &str,
+        dst: Dst,
+        inp: &CudaArray<Src, bool>,
+    ) -> Result<CudaArray<Dst, bool>, <Self as crate::tensor::HasErr>::Err> {
+        if !self.dev.has_func(MODULE_NAME, fn_name) {
+            self.dev
+                .load_ptx(PTX_SRC.into(), MODULE_NAME, &ALL_FN_NAMES)?;
+        }
+
+        // move the reduced axes to the end, so each output element reduces a
+        // contiguous chunk of logical indices
+        let mut axes: Vec<(bool, (usize, usize))> = inp
+            .shape
+            .concrete()
+            .into_iter()
+            .zip(inp.strides.into_iter())
+            .map(|x| (false, x))
+            .collect();
+        for i in Ax::as_array().into_iter() {
+            axes[i as usize].0 = true;
+        }
+        axes.sort_by_key(|x| x.0);
+        let (dims, strides): (Vec<usize>, Vec<usize>) = axes.into_iter().map(|(_, x)| x).unzip();
+
+        let numel = dst.num_elements();
+        let chunk_len = <Src as HasAxes<Ax>>::size(&inp.shape);
+        let dims: CudaSlice<usize> = self.dev.take_async(dims)?;
+        let strides: CudaSlice<usize> = self.dev.take_async(strides)?;
+        let mut storage = unsafe { self.dev.alloc_async::<bool>(numel) }?;
+
+        let fwd_fn = self.dev.get_func(MODULE_NAME, fn_name).unwrap();
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            numel,             // const size_t numel,
+            chunk_len,         // const size_t chunk_len,
+            Src::NUM_DIMS,     // const size_t num_dims,
+            &dims,             // const size_t *dims,
+            &strides,          // const size_t *strides,
+            inp.data.as_ref(), // const bool *inp,
+            &mut storage,      // bool *out
+        );
+        unsafe { fwd_fn.launch_async(cfg, params) }?;
+        Ok(CudaArray {
+            data: Arc::new(storage),
+            shape: dst,
+            strides: dst.strides(),
+        })
+    }
+}
+
+impl super::AnyAllKernel for Cuda {
+    fn any<Src: Shape + ReduceShapeTo<Dst, Ax>, Dst: Shape, Ax: Axes>(
+        &self,
+        dst: Dst,
+        inp: &Self::Storage<Src, bool>,
+    ) -> Result<Self::Storage<Dst, bool>, Self::Err> {
+        self.reduce_bool(ALL_FN_NAMES[0], dst, inp)
+    }
+
+    fn all<Src: Shape + ReduceShapeTo<Dst, Ax>, Dst: Shape, Ax: Axes>(
+        &self,
+        dst: Dst,
+        inp: &Self::Storage<Src, bool>,
+    ) -> Result<Self::Storage<Dst, bool>, Self::Err> {
+        self.reduce_bool(ALL_FN_NAMES[1], dst, inp)
+    }
+}
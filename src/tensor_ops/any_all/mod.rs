@@ -0,0 +1,171 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{shapes::*, tensor::*};
+
+pub trait AnyAllKernel: DeviceStorage {
+    fn any<Src: Shape + ReduceShapeTo<Dst, Ax>, Dst: Shape, Ax: Axes>(
+        &self,
+        dst: Dst,
+        inp: &Self::Storage<Src, bool>,
+    ) -> Result<Self::Storage<Dst, bool>, Self::Err>;
+
+    fn all<Src: Shape + ReduceShapeTo<Dst, Ax>, Dst: Shape, Ax: Axes>(
+        &self,
+        dst: Dst,
+        inp: &Self::Storage<Src, bool>,
+    ) -> Result<Self::Storage<Dst, bool>, Self::Err>;
+}
+
+/// Reduction along multiple axes of a boolean tensor using logical 'or'.
+pub trait AnyTo: HasErr + HasShape {
+    /// Whether any value along `Ax` is `true`. Reducing no elements gives `false`.
+    ///
+    /// **Pytorch equivalent**: `t.any(Ax)`
+    ///
+    /// Example:
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t = dev.tensor([[1.0, -2.0, 3.0], [-1.0, -2.0, -3.0]]);
+    /// let r = t.scalar_gt(0.0).any::<Rank1<2>, _>(); // or `any::<_, Axis<1>>()`
+    /// assert_eq!(r.array(), [true, false]);
+    /// ```
+    fn any<Dst: Shape, Ax: Axes>(self) -> Self::WithShape<Dst>
+    where
+        Self::Shape: ReduceShapeTo<Dst, Ax>,
+    {
+        self.try_any().unwrap()
+    }
+    /// Fallible version of [AnyTo::any]
+    fn try_any<Dst: Shape, Ax: Axes>(self) -> Result<Self::WithShape<Dst>, Self::Err>
+    where
+        Self::Shape: ReduceShapeTo<Dst, Ax>;
+}
+
+/// Reduction along multiple axes of a boolean tensor using logical 'and'.
+pub trait AllTo: HasErr + HasShape {
+    /// Whether all values along `Ax` are `true`. Reducing no elements gives `true`.
+    ///
+    /// **Pytorch equivalent**: `t.all(Ax)`
+    ///
+    /// Example:
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t = dev.tensor([[1.0, -2.0, 3.0], [1.0, 2.0, 3.0]]);
+    /// let r = t.scalar_gt(0.0).all::<Rank1<2>, _>(); // or `all::<_, Axis<1>>()`
+    /// assert_eq!(r.array(), [false, true]);
+    /// ```
+    fn all<Dst: Shape, Ax: Axes>(self) -> Self::WithShape<Dst>
+    where
+        Self::Shape: ReduceShapeTo<Dst, Ax>,
+    {
+        self.try_all().unwrap()
+    }
+    /// Fallible version of [AllTo::all]
+    fn try_all<Dst: Shape, Ax: Axes>(self) -> Result<Self::WithShape<Dst>, Self::Err>
+    where
+        Self::Shape: ReduceShapeTo<Dst, Ax>;
+}
+
+impl<S: Shape, D: AnyAllKernel> AnyTo for Tensor<S, bool, D> {
+    fn try_any<Dst: Shape, Ax: Axes>(self) -> Result<Self::WithShape<Dst>, Self::Err>
+    where
+        Self::Shape: ReduceShapeTo<Dst, Ax>,
+    {
+        let dst: Dst = self.shape().reduced();
+        let storage = self.device.any(dst, &self.storage)?;
+        Ok(self.device.upgrade(storage))
+    }
+}
+
+impl<S: Shape, D: AnyAllKernel> AllTo for Tensor<S, bool, D> {
+    fn try_all<Dst: Shape, Ax: Axes>(self) -> Result<Self::WithShape<Dst>, Self::Err>
+    where
+        Self::Shape: ReduceShapeTo<Dst, Ax>,
+    {
+        let dst: Dst = self.shape().reduced();
+        let storage = self.device.all(dst, &self.storage)?;
+        Ok(self.device.upgrade(storage))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_any_all_1d() {
+        let dev: TestDevice = Default::default();
+        let t = dev.tensor([false, true, false]);
+        assert!(t.clone().any::<Rank0, _>().array());
+        assert!(!t.all::<Rank0, _>().array());
+        let t = dev.tensor([true; 4]);
+        assert!(t.clone().any::<Rank0, _>().array());
+        assert!(t.all::<Rank0, _>().array());
+        let t = dev.tensor([false; 4]);
+        assert!(!t.clone().any::<Rank0, _>().array());
+        assert!(!t.all::<Rank0, _>().array());
+    }
+
+    #[test]
+    fn test_any_all_axes() {
+        let dev: TestDevice = Default::default();
+        let t = dev.tensor([
+            [[true, true], [false, true], [true, true]],
+            [[false, false], [false, true], [false, false]],
+        ]);
+        assert_eq!(
+            t.clone().any::<_, Axis<0>>().array(),
+            [[true, true], [false, true], [true, true]]
+        );
+        assert_eq!(
+            t.clone().all::<_, Axis<0>>().array(),
+            [[false, false], [false, true], [false, false]]
+        );
+        assert_eq!(
+            t.clone().any::<_, Axis<1>>().array(),
+            [[true, true], [false, true]]
+        );
+        assert_eq!(
+            t.clone().all::<_, Axis<1>>().array(),
+            [[false, true], [false, false]]
+        );
+        assert_eq!(
+            t.clone().any::<_, Axis<2>>().array(),
+            [[true, true, true], [false, true, false]]
+        );
+        assert_eq!(
+            t.clone().all::<_, Axis<2>>().array(),
+            [[true, false, true], [false, false, false]]
+        );
+        assert_eq!(
+            t.clone().any::<_, Axes2<0, 2>>().array(),
+            [true, true, true]
+        );
+        assert_eq!(t.all::<_, Axes2<0, 2>>().array(), [false, false, false]);
+    }
+
+    #[test]
+    fn test_any_all_broadcasted() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank2<4, 3>, TestDtype, _> = dev.tensor([-1.0, 1.0, 1.0]).broadcast();
+        let t = t.scalar_gt(0.0);
+        assert_eq!(t.clone().any::<_, Axis<0>>().array(), [false, true, true]);
+        assert_eq!(t.clone().all::<_, Axis<0>>().array(), [false, true, true]);
+        assert_eq!(t.clone().any::<_, Axis<1>>().array(), [true; 4]);
+        assert_eq!(t.all::<_, Axis<1>>().array(), [false; 4]);
+    }
+
+    #[test]
+    fn test_any_all_empty() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<(Const<2>, usize), bool, _> = dev.zeros_like(&(Const, 0));
+        assert_eq!(t.clone().any::<Rank1<2>, _>().array(), [false; 2]);
+        assert_eq!(t.all::<Rank1<2>, _>().array(), [true; 2]);
+    }
+}
@@ -0,0 +1,37 @@
+#include "cuda_utils.cuh"
+
+template<typename T>
+__device__ void bernoulli(
+    const size_t numel,
+    const size_t num_dims,
+    const size_t *dims,
+    const size_t *strides,
+    const unsigned long long seed,
+    const T *probs,
+    bool *out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    const double p = probs[get_strided_index(i, num_dims, dims, strides)];
+    unsigned long long state = rng_init(seed, i);
+    out[i] = rng_next_f64(&state) < p;
+}
+
+#define BERNOULLI(TYPENAME, FN) \
+extern "C" __global__ void FN( \
+    const size_t numel, \
+    const size_t num_dims, \
+    const size_t *dims, \
+    const size_t *strides, \
+    const unsigned long long seed, \
+    const TYPENAME *probs, \
+    bool *out \
+) { \
+    bernoulli(numel, num_dims, dims, strides, seed, probs, out); \
+}
+
+BERNOULLI(float, bernoulli_f32);
+BERNOULLI(double, bernoulli_f64);
@@ -0,0 +1,23 @@
+use crate::{
+    shapes::{Dtype, Shape},
+    tensor::cpu::{Cpu, LendingIterator, StridedArray},
+    tensor_ops::utilities::rng::StreamRng,
+};
+
+use num_traits::Float;
+
+impl<E: Dtype + Float> super::BernoulliKernel<E> for Cpu {
+    fn bernoulli<S: Shape>(
+        &self,
+        seed: u64,
+        probs: &Self::Storage<S, E>,
+    ) -> Result<Self::Storage<S, bool>, Self::Err> {
+        let mut out: StridedArray<S, bool> = StridedArray::new(probs.shape)?;
+        let mut probs_iter = probs.iter();
+        for (i, o) in out.buf_iter_mut().enumerate() {
+            let p = probs_iter.next().unwrap().to_f64().unwrap();
+            *o = StreamRng::new(seed, i as u64).next_f64() < p;
+        }
+        Ok(out)
+    }
+}
@@ -0,0 +1,64 @@
+use crate::{
+    shapes::*,
+    tensor::cuda::{Cuda, CudaArray},
+};
+use cudarc::driver::{AsKernelParam, LaunchAsync, LaunchConfig};
+use std::sync::Arc;
+
+const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/bernoulli.ptx"));
+const MOD: &str = "bernoulli";
+const FNS: &[&str] = &["bernoulli_f32", "bernoulli_f64"];
+
+trait HasCudaKernel<E> {
+    const FN: &'static str;
+}
+
+impl HasCudaKernel<f32> for Cuda {
+    const FN: &'static str = "bernoulli_f32";
+}
+
+impl HasCudaKernel<f64> for Cuda {
+    const FN: &'static str = "bernoulli_f64";
+}
+
+impl<E: Dtype + AsKernelParam> super::BernoulliKernel<E> for Cuda
+where
+    Self: HasCudaKernel<E>,
+{
+    fn bernoulli<S: Shape>(
+        &self,
+        seed: u64,
+        probs: &Self::Storage<S, E>,
+    ) -> Result<Self::Storage<S, bool>, Self::Err> {
+        if !self.dev.has_func(MOD, Self::FN) {
+            self.dev.load_ptx(PTX_SRC.into(), MOD, FNS)?;
+        }
+
+        let shape = probs.shape;
+        let numel = shape.num_elements();
+        let mut storage = self.dev.alloc_zeros_async::<bool>(numel)?;
+
+        if numel > 0 {
+            let dims = self.dev.take_async(shape.concrete().into())?;
+            let strides = self.dev.take_async(probs.strides.into())?;
+            let fwd_fn = self.dev.get_func(MOD, Self::FN).unwrap();
+            let cfg = LaunchConfig::for_num_elems(numel as u32);
+            let params = (
+                numel,               // const size_t numel,
+                S::NUM_DIMS,         // const size_t num_dims,
+                &dims,               // const size_t *dims,
+                &strides,            // const size_t *strides,
+                seed,                // const unsigned long long seed,
+                probs.data.as_ref(), // const T *probs,
+                &mut storage,        // bool *out
+            );
+            unsafe { fwd_fn.launch_async(cfg, params) }?;
+        }
+
+        Ok(CudaArray {
+            data: Arc::new(storage),
+            shape,
+            strides: shape.strides(),
+        })
+    }
+}
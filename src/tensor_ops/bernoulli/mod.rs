@@ -0,0 +1,99 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{shapes::*, tensor::*};
+
+pub trait BernoulliKernel<E: Dtype>: DeviceStorage {
+    /// Samples `true` with the probability given by each element of `probs`.
+    fn bernoulli<S: Shape>(
+        &self,
+        seed: u64,
+        probs: &Self::Storage<S, E>,
+    ) -> Result<Self::Storage<S, bool>, Self::Err>;
+}
+
+/// Samples a boolean tensor where each element is `true` with the probability given
+/// by the corresponding element of `probs`. Sampling happens on the device, and the
+/// result does not track gradients.
+///
+/// Probabilities `<= 0` are always `false` and probabilities `>= 1` are always `true`.
+///
+/// **Pytorch equivalent**: `torch.bernoulli(probs).bool()`
+///
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let probs = dev.tensor([0.0, 1.0, 0.5]);
+/// let r = bernoulli(&probs);
+/// let r = r.array();
+/// assert!(!r[0] && r[1]);
+/// ```
+///
+/// Use [crate::tensor_ops::ChooseFrom::choose] to turn the mask into 0/1 floats:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let probs: Tensor<Rank1<3>, f32, _> = dev.tensor([1.0, 0.0, 1.0]);
+/// let r: Tensor<Rank1<3>, f32, _> = probs.bernoulli().choose(dev.ones(), dev.zeros());
+/// assert_eq!(r.array(), [1.0, 0.0, 1.0]);
+/// ```
+pub fn bernoulli<S: Shape, E: Dtype, D: BernoulliKernel<E>, T>(
+    probs: &Tensor<S, E, D, T>,
+) -> Tensor<S, bool, D> {
+    probs.bernoulli()
+}
+
+impl<S: Shape, E: Dtype, D: BernoulliKernel<E>, T> Tensor<S, E, D, T> {
+    /// See [bernoulli]
+    pub fn bernoulli(&self) -> Tensor<S, bool, D> {
+        self.try_bernoulli().unwrap()
+    }
+
+    /// See [bernoulli]
+    pub fn try_bernoulli(&self) -> Result<Tensor<S, bool, D>, D::Err> {
+        let seed = self.device.random_u64();
+        let storage = self.device.bernoulli(seed, &self.storage)?;
+        Ok(self.device.upgrade(storage))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{shapes::*, tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_bernoulli_frequencies() {
+        let dev: TestDevice = Default::default();
+        let probs: Tensor<Rank2<2, 1000>, TestDtype, _> = dev.tensor([[0.25; 1000], [0.75; 1000]]);
+        let r = probs.bernoulli().array();
+        let low = r[0].iter().filter(|&&x| x).count();
+        let high = r[1].iter().filter(|&&x| x).count();
+        assert!((200..300).contains(&low), "{low}");
+        assert!((700..800).contains(&high), "{high}");
+    }
+
+    #[test]
+    fn test_bernoulli_extremes() {
+        let dev: TestDevice = Default::default();
+        let probs: Tensor<_, TestDtype, _> = dev.tensor([-1.0, 0.0, 1.0, 2.0]);
+        assert_eq!(probs.bernoulli().array(), [false, false, true, true]);
+    }
+
+    #[test]
+    fn test_bernoulli_broadcasted() {
+        let dev: TestDevice = Default::default();
+        let probs: Tensor<_, TestDtype, _> = dev.tensor([0.5, 0.5]);
+        let r = probs.broadcast::<Rank2<100, 2>, _>().bernoulli().array();
+        // each element is sampled separately even though the storage is shared
+        assert!(r.iter().any(|row| row[0] != r[0][0]));
+    }
+
+    #[test]
+    fn test_bernoulli_new_samples_each_call() {
+        let dev: TestDevice = Default::default();
+        let probs: Tensor<Rank1<64>, TestDtype, _> = dev.ones() * 0.5;
+        assert_ne!(probs.bernoulli().array(), probs.bernoulli().array());
+    }
+}
@@ -0,0 +1,107 @@
+#include "cuda_utils.cuh"
+
+enum BitwiseOp {
+    BITWISE_AND,
+    BITWISE_OR,
+    BITWISE_XOR,
+    BITWISE_SHL,
+    BITWISE_SHR,
+};
+
+// Shifting by at least the number of bits in T produces 0
+template<typename T>
+__device__ T bitwise(const BitwiseOp op, const T x, const T y) {
+    const size_t bits = sizeof(T) * 8;
+    switch (op) {
+        case BITWISE_AND: return x & y;
+        case BITWISE_OR: return x | y;
+        case BITWISE_XOR: return x ^ y;
+        case BITWISE_SHL: return y < bits ? x << y : 0;
+        case BITWISE_SHR: return y < bits ? x >> y : 0;
+    }
+    return 0;
+}
+
+template<typename T>
+__device__ void bitwise_not(
+    const size_t numel,
+    const T *inp,
+    T *out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+    out[i] = ~inp[i];
+}
+
+template<typename T>
+__device__ void bitwise_binary(
+    const BitwiseOp op,
+    const size_t numel,
+    const size_t num_dims,
+    const size_t *dims,
+    const T *lhs,
+    const size_t *lhs_strides,
+    const T *rhs,
+    const size_t *rhs_strides,
+    T *out
+) {
+    unsigned int out_i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (out_i >= numel) {
+        return;
+    }
+
+    unsigned int lhs_i = get_strided_index(out_i, num_dims, dims, lhs_strides);
+    unsigned int rhs_i = get_strided_index(out_i, num_dims, dims, rhs_strides);
+
+    out[out_i] = bitwise(op, lhs[lhs_i], rhs[rhs_i]);
+}
+
+template<typename T>
+__device__ void bitwise_scalar(
+    const BitwiseOp op,
+    const size_t numel,
+    const T *lhs,
+    const T rhs,
+    T *out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+    out[i] = bitwise(op, lhs[i], rhs);
+}
+
+#define BITWISE_OP(TYPENAME, NOT, BINARY, SCALAR) \
+extern "C" __global__ void NOT( \
+    const size_t numel, \
+    const TYPENAME *inp, \
+    TYPENAME *out \
+) { \
+    bitwise_not(numel, inp, out); \
+} \
+extern "C" __global__ void BINARY( \
+    const BitwiseOp op, \
+    const size_t numel, \
+    const size_t num_dims, \
+    const size_t *dims, \
+    const TYPENAME *lhs, \
+    const size_t *lhs_strides, \
+    const TYPENAME *rhs, \
+    const size_t *rhs_strides, \
+    TYPENAME *out \
+) { \
+    bitwise_binary(op, numel, num_dims, dims, lhs, lhs_strides, rhs, rhs_strides, out); \
+} \
+extern "C" __global__ void SCALAR( \
+    const BitwiseOp op, \
+    const size_t numel, \
+    const TYPENAME *lhs, \
+    const TYPENAME rhs, \
+    TYPENAME *out \
+) { \
+    bitwise_scalar(op, numel, lhs, rhs, out); \
+}
+
+BITWISE_OP(size_t, bitwise_not_usize, bitwise_binary_usize, bitwise_scalar_usize);
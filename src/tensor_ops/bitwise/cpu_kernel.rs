@@ -0,0 +1,54 @@
+use crate::{
+    shapes::Shape,
+    tensor::cpu::{Cpu, StridedArray},
+};
+
+use super::{BitwiseKernel, BitwiseOp};
+
+fn apply(op: BitwiseOp, x: usize, y: usize) -> usize {
+    match op {
+        BitwiseOp::And => x & y,
+        BitwiseOp::Or => x | y,
+        BitwiseOp::Xor => x ^ y,
+        BitwiseOp::Shl if y < usize::BITS as usize => x << y,
+        BitwiseOp::Shr if y < usize::BITS as usize => x >> y,
+        BitwiseOp::Shl | BitwiseOp::Shr => 0,
+    }
+}
+
+impl BitwiseKernel<usize> for Cpu {
+    fn not<S: Shape>(
+        &self,
+        inp: &Self::Storage<S, usize>,
+    ) -> Result<Self::Storage<S, usize>, Self::Err> {
+        let mut out: StridedArray<S, usize> = inp.clone();
+        for x in out.buf_iter_mut() {
+            *x = !*x;
+        }
+        Ok(out)
+    }
+
+    fn binary<S: Shape>(
+        &self,
+        op: BitwiseOp,
+        lhs: &Self::Storage<S, usize>,
+        rhs: &Self::Storage<S, usize>,
+    ) -> Result<Self::Storage<S, usize>, Self::Err> {
+        self.eval_binary(|l, r| apply(op, l, r), lhs, rhs)
+    }
+
+    fn scalar<S: Shape>(
+        &self,
+        op: BitwiseOp,
+        lhs: &Self::Storage<S, usize>,
+        rhs: usize,
+    ) -> Result<Self::Storage<S, usize>, Self::Err> {
+        let mut out: StridedArray<S, usize> = lhs.clone();
+        // NOTE: we can iterate over buf here because we know inp & out
+        // have exact same strides due to clone.
+        for x in out.buf_iter_mut() {
+            *x = apply(op, *x, rhs);
+        }
+        Ok(out)
+    }
+}
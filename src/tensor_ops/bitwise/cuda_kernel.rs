@@ -0,0 +1,126 @@
+use super::{BitwiseKernel, BitwiseOp};
+use crate::{
+    shapes::*,
+    tensor::cuda::{Cuda, CudaArray},
+};
+use cudarc::driver::{AsKernelParam, CudaSlice, LaunchAsync, LaunchConfig};
+use std::sync::Arc;
+
+const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/bitwise.ptx"));
+
+unsafe impl AsKernelParam for BitwiseOp {}
+
+trait HasCudaKernel<E> {
+    const MOD: &'static str;
+    const FNS: &'static [&'static str];
+}
+
+impl HasCudaKernel<usize> for Cuda {
+    const MOD: &'static str = "bitwise_usize";
+    const FNS: &'static [&'static str] = &[
+        "bitwise_not_usize",
+        "bitwise_binary_usize",
+        "bitwise_scalar_usize",
+    ];
+}
+
+impl<E: Unit + AsKernelParam> BitwiseKernel<E> for Cuda
+where
+    Self: HasCudaKernel<E>,
+{
+    fn not<S: Shape>(&self, inp: &Self::Storage<S, E>) -> Result<Self::Storage<S, E>, Self::Err> {
+        if !self.dev.has_func(Self::MOD, Self::FNS[0]) {
+            self.dev.load_ptx(PTX_SRC.into(), Self::MOD, Self::FNS)?;
+        }
+
+        let numel = inp.data.len();
+        let mut storage = unsafe { self.dev.alloc_async::<E>(numel) }?;
+
+        let fwd_fn = self.dev.get_func(Self::MOD, Self::FNS[0]).unwrap();
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            numel,             // const size_t numel,
+            inp.data.as_ref(), // const T *inp,
+            &mut storage,      // T *out
+        );
+        unsafe { fwd_fn.launch_async(cfg, params) }?;
+
+        Ok(CudaArray {
+            data: Arc::new(storage),
+            shape: inp.shape,
+            strides: inp.strides,
+        })
+    }
+
+    fn binary<S: Shape>(
+        &self,
+        op: BitwiseOp,
+        lhs: &Self::Storage<S, E>,
+        rhs: &Self::Storage<S, E>,
+    ) -> Result<Self::Storage<S, E>, Self::Err> {
+        if !self.dev.has_func(Self::MOD, Self::FNS[0]) {
+            self.dev.load_ptx(PTX_SRC.into(), Self::MOD, Self::FNS)?;
+        }
+
+        let shape = lhs.shape;
+        let strides = lhs.shape.strides();
+        let numel = shape.num_elements();
+
+        let mut storage = unsafe { self.dev.alloc_async::<E>(numel) }?;
+
+        let dims: CudaSlice<usize> = self.dev.take_async(shape.concrete().into())?;
+        let lhs_strides: CudaSlice<usize> = self.dev.take_async(lhs.strides.into())?;
+        let rhs_strides: CudaSlice<usize> = self.dev.take_async(rhs.strides.into())?;
+
+        let fwd_fn = self.dev.get_func(Self::MOD, Self::FNS[1]).unwrap();
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            op,                // const BitwiseOp op,
+            numel,             // const size_t numel,
+            S::NUM_DIMS,       // const size_t num_dims,
+            &dims,             // const size_t *dims,
+            lhs.data.as_ref(), // const T *lhs,
+            &lhs_strides,      // const size_t *lhs_strides,
+            rhs.data.as_ref(), // const T *rhs,
+            &rhs_strides,      // const size_t *rhs_strides,
+            &mut storage,      // T *out,
+        );
+        unsafe { fwd_fn.launch_async(cfg, params) }?;
+        Ok(CudaArray {
+            data: Arc::new(storage),
+            shape,
+            strides,
+        })
+    }
+
+    fn scalar<S: Shape>(
+        &self,
+        op: BitwiseOp,
+        lhs: &Self::Storage<S, E>,
+        rhs: E,
+    ) -> Result<Self::Storage<S, E>, Self::Err> {
+        if !self.dev.has_func(Self::MOD, Self::FNS[0]) {
+            self.dev.load_ptx(PTX_SRC.into(), Self::MOD, Self::FNS)?;
+        }
+
+        let numel = lhs.data.len();
+        let mut storage = unsafe { self.dev.alloc_async::<E>(numel) }?;
+
+        let fwd_fn = self.dev.get_func(Self::MOD, Self::FNS[2]).unwrap();
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            op,                // const BitwiseOp op,
+            numel,             // const size_t numel,
+            lhs.data.as_ref(), // const T *lhs,
+            rhs,               // const T rhs,
+            &mut storage,      // T *out
+        );
+        unsafe { fwd_fn.launch_async(cfg, params) }?;
+
+        Ok(CudaArray {
+            data: Arc::new(storage),
+            shape: lhs.shape,
+            strides: lhs.strides,
+        })
+    }
+}
@@ -0,0 +1,208 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{
+    shapes::*,
+    tensor::{DeviceStorage, Tensor},
+};
+
+use std::ops::{BitAnd, BitOr, BitXor, Not, Shl, Shr};
+
+/// A bitwise operation on two integers. Shifting by at least the number of bits in
+/// the integer produces 0.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BitwiseOp {
+    And,
+    Or,
+    Xor,
+    Shl,
+    Shr,
+}
+
+pub trait BitwiseKernel<E: Unit>: DeviceStorage {
+    fn not<S: Shape>(&self, inp: &Self::Storage<S, E>) -> Result<Self::Storage<S, E>, Self::Err>;
+
+    fn binary<S: Shape>(
+        &self,
+        op: BitwiseOp,
+        lhs: &Self::Storage<S, E>,
+        rhs: &Self::Storage<S, E>,
+    ) -> Result<Self::Storage<S, E>, Self::Err>;
+
+    fn scalar<S: Shape>(
+        &self,
+        op: BitwiseOp,
+        lhs: &Self::Storage<S, E>,
+        rhs: E,
+    ) -> Result<Self::Storage<S, E>, Self::Err>;
+}
+
+impl<S: Shape, D: BitwiseKernel<usize>> Not for Tensor<S, usize, D> {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        !&self
+    }
+}
+
+impl<S: Shape, D: BitwiseKernel<usize>> Not for &Tensor<S, usize, D> {
+    type Output = Tensor<S, usize, D>;
+
+    fn not(self) -> Self::Output {
+        self.device.upgrade(self.device.not(&self.storage).unwrap())
+    }
+}
+
+macro_rules! bitwise_op_impl {
+    ($op:ident, $op_method:ident, $BitwiseOp:expr) => {
+        impl<S: Shape, D: BitwiseKernel<usize>> $op for Tensor<S, usize, D> {
+            type Output = Self;
+
+            fn $op_method(self, rhs: Self) -> Self {
+                (&self).$op_method(&rhs)
+            }
+        }
+
+        impl<S: Shape, D: BitwiseKernel<usize>> $op for &Tensor<S, usize, D> {
+            type Output = Tensor<S, usize, D>;
+
+            fn $op_method(self, rhs: Self) -> Self::Output {
+                assert_eq!(self.shape(), rhs.shape());
+                self.device.upgrade(
+                    self.device
+                        .binary($BitwiseOp, &self.storage, &rhs.storage)
+                        .unwrap(),
+                )
+            }
+        }
+
+        impl<S: Shape, D: BitwiseKernel<usize>> $op<usize> for Tensor<S, usize, D> {
+            type Output = Self;
+
+            fn $op_method(self, rhs: usize) -> Self {
+                (&self).$op_method(rhs)
+            }
+        }
+
+        impl<S: Shape, D: BitwiseKernel<usize>> $op<usize> for &Tensor<S, usize, D> {
+            type Output = Tensor<S, usize, D>;
+
+            fn $op_method(self, rhs: usize) -> Self::Output {
+                self.device
+                    .upgrade(self.device.scalar($BitwiseOp, &self.storage, rhs).unwrap())
+            }
+        }
+    };
+}
+
+bitwise_op_impl!(BitAnd, bitand, BitwiseOp::And);
+bitwise_op_impl!(BitOr, bitor, BitwiseOp::Or);
+bitwise_op_impl!(BitXor, bitxor, BitwiseOp::Xor);
+bitwise_op_impl!(Shl, shl, BitwiseOp::Shl);
+bitwise_op_impl!(Shr, shr, BitwiseOp::Shr);
+
+/// Element wise and scalar bitwise 'and', 'or', 'xor' and 'not' of integer tensors.
+/// See [crate::tensor_ops::bool_and] and friends for boolean tensors.
+///
+/// Example:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let a = dev.tensor([0b1100usize, 0b1010]);
+/// let b = dev.tensor([0b1010usize, 0b0110]);
+///
+/// assert_eq!((&a & &b).array(), [0b1000, 0b0010]);
+/// assert_eq!((&a | &b).array(), [0b1110, 0b1110]);
+/// assert_eq!((&a ^ &b).array(), [0b0110, 0b1100]);
+/// assert_eq!((&a & 0b0100).array(), [0b0100, 0b0000]);
+/// assert_eq!((!a).array(), [!0b1100, !0b1010]);
+/// ```
+pub fn bitwise_and<S: Shape, D: BitwiseKernel<usize>>(
+    lhs: &Tensor<S, usize, D>,
+    rhs: &Tensor<S, usize, D>,
+) -> Tensor<S, usize, D> {
+    lhs & rhs
+}
+
+/// Element wise and scalar left shift of integer tensors. Shifting by at least the number
+/// of bits in the integer produces 0.
+///
+/// Example:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let a = dev.tensor([1usize, 3, 5]);
+/// assert_eq!((&a << 2).array(), [4, 12, 20]);
+/// assert_eq!((&a << &dev.tensor([0, 1, 64])).array(), [1, 6, 0]);
+/// assert_eq!((a >> 1).array(), [0, 1, 2]);
+/// ```
+pub fn shl<S: Shape, D: BitwiseKernel<usize>>(
+    lhs: &Tensor<S, usize, D>,
+    rhs: &Tensor<S, usize, D>,
+) -> Tensor<S, usize, D> {
+    lhs << rhs
+}
+
+/// Element wise and scalar right shift of integer tensors. See [shl].
+pub fn shr<S: Shape, D: BitwiseKernel<usize>>(
+    lhs: &Tensor<S, usize, D>,
+    rhs: &Tensor<S, usize, D>,
+) -> Tensor<S, usize, D> {
+    lhs >> rhs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_bitwise_binary() {
+        let dev: TestDevice = Default::default();
+        let a = dev.tensor([[0b0011usize, 0b0101], [0b1111, 0]]);
+        let b = dev.tensor([[0b0110usize, 0b0101], [0b1001, 0b1]]);
+        assert_eq!((&a & &b).array(), [[0b0010, 0b0101], [0b1001, 0]]);
+        assert_eq!((&a | &b).array(), [[0b0111, 0b0101], [0b1111, 0b1]]);
+        assert_eq!((a ^ b).array(), [[0b0101, 0], [0b0110, 0b1]]);
+    }
+
+    #[test]
+    fn test_bitwise_not() {
+        let dev: TestDevice = Default::default();
+        let a = dev.tensor([0usize, 1, usize::MAX]);
+        assert_eq!((!&a).array(), [usize::MAX, usize::MAX - 1, 0]);
+        assert_eq!((!!a).array(), [0, 1, usize::MAX]);
+    }
+
+    #[test]
+    fn test_bitwise_scalar() {
+        let dev: TestDevice = Default::default();
+        let a = dev.tensor([0b0011usize, 0b0101, 0b1000]);
+        assert_eq!((&a & 0b0110).array(), [0b0010, 0b0100, 0]);
+        assert_eq!((&a | 0b0110).array(), [0b0111, 0b0111, 0b1110]);
+        assert_eq!((a ^ 0b0110).array(), [0b0101, 0b0011, 0b1110]);
+    }
+
+    #[test]
+    fn test_shifts() {
+        let dev: TestDevice = Default::default();
+        let a = dev.tensor([1usize, 6, 255, usize::MAX]);
+        let b = dev.tensor([3usize, 1, 4, 100]);
+        assert_eq!((&a << &b).array(), [8, 12, 4080, 0]);
+        assert_eq!((&a >> &b).array(), [0, 3, 15, 0]);
+        assert_eq!((&a << 1).array(), [2, 12, 510, usize::MAX - 1]);
+        assert_eq!((a >> 64).array(), [0; 4]);
+    }
+
+    #[test]
+    fn test_bitwise_broadcasted() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<Rank2<2, 3>, usize, _> = dev.tensor([1usize, 2, 3]).broadcast();
+        let b = dev.tensor([[1usize, 1, 1], [2, 2, 2]]);
+        assert_eq!((&a << &b).array(), [[2, 4, 6], [4, 8, 12]]);
+        assert_eq!((a | 4).array(), [[5, 6, 7]; 2]);
+    }
+}
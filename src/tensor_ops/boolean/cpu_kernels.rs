@@ -9,7 +9,7 @@ use crate::{
 use super::BooleanKernel;
 
 impl Cpu {
-    fn eval_binary<S: Shape, E: Unit, O: Fn(E, E) -> E>(
+    pub(crate) fn eval_binary<S: Shape, E: Unit, O: Fn(E, E) -> E>(
         &self,
         op: O,
         lhs: &StridedArray<S, E>,
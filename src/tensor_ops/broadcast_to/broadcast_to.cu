@@ -21,3 +21,15 @@ extern "C" __global__ void sum_f64(
     }
     out[i] += inp[i];
 }
+
+extern "C" __global__ void sum_usize(
+    const size_t numel,
+    const size_t *inp,
+    size_t *out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+    out[i] += inp[i];
+}
@@ -1,6 +1,6 @@
 use crate::{
     shapes::*,
-    tensor::cpu::{Cpu, StridedArray},
+    tensor::cpu::{Cpu, CpuError, StridedArray},
 };
 
 impl<E: Dtype> super::BroadcastKernel<E> for Cpu {
@@ -27,6 +27,28 @@ impl<E: Dtype> super::BroadcastKernel<E> for Cpu {
     where
         Src: BroadcastShapeTo<Dst, Ax>,
     {
+        self.stretch_backward(grad_inp, grad_out)
+    }
+
+    fn stretch<Src: Shape, Dst: Shape>(
+        &self,
+        dst: Dst,
+        inp: &Self::Storage<Src, E>,
+    ) -> Result<Self::Storage<Dst, E>, Self::Err> {
+        let strides = super::stretched_strides(&inp.shape, inp.strides, &dst)
+            .ok_or(CpuError::ShapeMismatch)?;
+        Ok(StridedArray {
+            data: inp.data.clone(),
+            shape: dst,
+            strides,
+        })
+    }
+
+    fn stretch_backward<Src: Shape, Dst: Shape>(
+        &self,
+        grad_inp: &mut Self::Storage<Src, E>,
+        grad_out: &Self::Storage<Dst, E>,
+    ) -> Result<(), Self::Err> {
         debug_assert_eq!(grad_out.data.len(), grad_inp.data.len());
         for (i, o) in grad_inp.buf_iter_mut().zip(grad_out.buf_iter()) {
             *i += *o;
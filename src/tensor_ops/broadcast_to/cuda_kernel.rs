@@ -1,4 +1,5 @@
 use crate::shapes::*;
+use crate::tensor::cpu::CpuError;
 use crate::tensor::cuda::{Cuda, CudaArray};
 
 use cudarc::driver::{LaunchAsync, LaunchConfig};
@@ -21,6 +22,11 @@ impl HasCudaKernel<f64> for Cuda {
     const FNS: &'static [&'static str] = &["sum_f64"];
 }
 
+impl HasCudaKernel<usize> for Cuda {
+    const MOD: &'static str = "broadcast_usize";
+    const FNS: &'static [&'static str] = &["sum_usize"];
+}
+
 impl<E: Dtype> super::BroadcastKernel<E> for Cuda
 where
     Self: HasCudaKernel<E>,
@@ -47,6 +53,28 @@ where
     where
         Src: BroadcastShapeTo<Dst, Ax>,
     {
+        self.stretch_backward(grad_inp, grad_out)
+    }
+
+    fn stretch<Src: Shape, Dst: Shape>(
+        &self,
+        dst: Dst,
+        inp: &Self::Storage<Src, E>,
+    ) -> Result<Self::Storage<Dst, E>, Self::Err> {
+        let strides = super::stretched_strides(&inp.shape, inp.strides, &dst)
+            .ok_or(CpuError::ShapeMismatch)?;
+        Ok(CudaArray {
+            data: inp.data.clone(),
+            shape: dst,
+            strides,
+        })
+    }
+
+    fn stretch_backward<Src: Shape, Dst: Shape>(
+        &self,
+        grad_inp: &mut Self::Storage<Src, E>,
+        grad_out: &Self::Storage<Dst, E>,
+    ) -> Result<(), Self::Err> {
         if !self.dev.has_func(Self::MOD, Self::FNS[0]) {
             self.dev.load_ptx(PTX_SRC.into(), Self::MOD, Self::FNS)?;
         }
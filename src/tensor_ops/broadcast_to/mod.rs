@@ -20,6 +20,18 @@ pub trait BroadcastKernel<E: Dtype>: DeviceStorage {
     ) -> Result<(), Self::Err>
     where
         Src: BroadcastShapeTo<Dst, Ax>;
+
+    /// Views `inp` as `dst`, lining up the trailing dims and stretching dims of size 1.
+    fn stretch<Src: Shape, Dst: Shape>(
+        &self,
+        dst: Dst,
+        inp: &Self::Storage<Src, E>,
+    ) -> Result<Self::Storage<Dst, E>, Self::Err>;
+    fn stretch_backward<Src: Shape, Dst: Shape>(
+        &self,
+        grad_inp: &mut Self::Storage<Src, E>,
+        grad_out: &Self::Storage<Dst, E>,
+    ) -> Result<(), Self::Err>;
 }
 
 /// Broadcast self into a new shape.
@@ -87,6 +99,180 @@ impl<S: Shape, E: Dtype, D: BroadcastKernel<E>, T: Tape<D>> BroadcastTo for Tens
     }
 }
 
+/// Broadcast a pair of tensors to a common shape, like numpy does for binary ops.
+///
+/// Binary ops like [add()](crate::tensor_ops::add) and comparisons like
+/// [eq()](crate::tensor_ops::eq) require both sides to have the same shape. They
+/// don't broadcast implicitly, because that would stop rust from inferring the shape of
+/// the other side (e.g. `a + dev.ones()`). Use this to broadcast both sides explicitly
+/// without naming the shape or the axes:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let a = dev.tensor([[1.0, 2.0, 3.0], [-1.0, -2.0, -3.0]]);
+/// let b = dev.tensor([1.0, 2.0, 3.0]);
+///
+/// let (a, b) = (a, b).broadcast_pair();
+/// assert_eq!((a.clone() + b.clone()).array(), [[2.0, 4.0, 6.0], [0.0, 0.0, 0.0]]);
+///
+/// // the lhs can be the smaller one too
+/// let (b, a) = (dev.tensor([1.0, 2.0, 3.0]), a).broadcast_pair();
+/// assert_eq!(b.eq(&a).array(), [[true; 3], [false; 3]]);
+/// ```
+///
+/// The shapes are lined up by their trailing dimensions, and the missing leading
+/// dimensions are added to the smaller shape. Dimensions of size 1 are stretched to
+/// the size of the other side, as long as their size is only known at runtime:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let a = dev.tensor_from_vec(vec![1.0, 2.0, 3.0], (3, 1));
+/// let b = dev.tensor_from_vec(vec![10.0, 20.0], (1, 2));
+/// let (a, b) = (a, b).broadcast_pair();
+/// assert_eq!((a + b).as_vec(), [11.0, 21.0, 12.0, 22.0, 13.0, 23.0]);
+/// ```
+/// See [BroadcastDim] for the resulting type of each dimension. The gradients of a
+/// broadcast tensor are summed back over the new and stretched dimensions.
+pub trait TryBroadcastPair: Sized {
+    type Output;
+    type Err: std::fmt::Debug;
+
+    /// Broadcast both tensors to a common shape. Panics if two lined up dimensions
+    /// differ and neither of them is 1.
+    fn broadcast_pair(self) -> Self::Output {
+        self.try_broadcast_pair().unwrap()
+    }
+
+    /// Fallible version of [TryBroadcastPair::broadcast_pair]. Returns an error
+    /// instead of panicking if the shapes can't be broadcast together.
+    fn try_broadcast_pair(self) -> Result<Self::Output, Self::Err>;
+}
+
+/// The strides of `src` viewed as `dst`, lining up the trailing dims like numpy does.
+/// New leading dims, and dims of size 1 that are stretched, get a stride of 0.
+/// Returns `None` if a dim that isn't 1 would have to be stretched.
+fn stretched_strides<Src: Shape, Dst: Shape>(
+    src: &Src,
+    strides: Src::Concrete,
+    dst: &Dst,
+) -> Option<Dst::Concrete> {
+    let src_dims = src.concrete();
+    let dst_dims = dst.concrete();
+    let offset = Dst::NUM_DIMS.checked_sub(Src::NUM_DIMS)?;
+    let mut new_strides: Dst::Concrete = Default::default();
+    for i in 0..Src::NUM_DIMS {
+        if src_dims[i] == dst_dims[i + offset] {
+            new_strides[i + offset] = strides[i];
+        } else if src_dims[i] != 1 {
+            return None;
+        }
+    }
+    Some(new_strides)
+}
+
+fn try_stretch_like<Src: Shape, Dst: Shape, E: Dtype, D: BroadcastKernel<E>, T: Tape<D>>(
+    t: Tensor<Src, E, D, T>,
+    dst: &Dst,
+) -> Result<Tensor<Dst, E, D, T>, D::Err> {
+    let (inp, mut tape) = t.split_tape();
+    let out = inp.device.upgrade(inp.device.stretch(*dst, &inp.storage)?);
+    let phantom_out = out.clone();
+    tape.try_alloc_grad(&inp)?;
+    tape.try_alloc_grad(&out)?;
+    tape.add_backward_op(move |grads| {
+        let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+        inp.device.stretch_backward(grad_inp, grad_out)
+    });
+    Ok(out.put_tape(tape))
+}
+
+macro_rules! broadcast_pair {
+    (@impl ($($Lhs:ident),* $(,)?), ($($Rhs:ident),* $(,)?), ($($New:ident),*), ($($A:ident),*), ($($B:ident),*)) => {
+        impl<E: Dtype, D: BroadcastKernel<E>, L: Tape<D>, R: Tape<D>, $($New: Dim, )* $($A: BroadcastDim<$B>, $B: Dim, )*>
+            TryBroadcastPair for (Tensor<($($Lhs, )*), E, D, L>, Tensor<($($Rhs, )*), E, D, R>)
+        {
+            type Output = (
+                Tensor<($($New, )* $(<$A as BroadcastDim<$B>>::Output, )*), E, D, L>,
+                Tensor<($($New, )* $(<$A as BroadcastDim<$B>>::Output, )*), E, D, R>,
+            );
+            type Err = D::Err;
+            #[allow(non_snake_case)]
+            fn try_broadcast_pair(self) -> Result<Self::Output, Self::Err> {
+                let (lhs, rhs) = self;
+                let ($($Lhs, )*) = *lhs.shape();
+                let ($($Rhs, )*) = *rhs.shape();
+                let shape = ($($New, )* $($A.broadcast_dim(&$B), )*);
+                Ok((try_stretch_like(lhs, &shape)?, try_stretch_like(rhs, &shape)?))
+            }
+        }
+    };
+    ((), ($($A:ident),*), ($($B:ident),*)) => {
+        broadcast_pair!(@impl ($($A),*), ($($B),*), (), ($($A),*), ($($B),*));
+    };
+    (($($New:ident),+), ($($A:ident),*), ($($B:ident),*)) => {
+        broadcast_pair!(@impl ($($New, )+ $($A),*), ($($B),*), ($($New),+), ($($A),*), ($($B),*));
+        broadcast_pair!(@impl ($($B),*), ($($New, )+ $($A),*), ($($New),+), ($($A),*), ($($B),*));
+    };
+}
+
+broadcast_pair!((), (), ());
+broadcast_pair!((A1), (), ());
+broadcast_pair!((), (A1), (B1));
+broadcast_pair!((A1, A2), (), ());
+broadcast_pair!((A1), (A2), (B1));
+broadcast_pair!((), (A1, A2), (B1, B2));
+broadcast_pair!((A1, A2, A3), (), ());
+broadcast_pair!((A1, A2), (A3), (B1));
+broadcast_pair!((A1), (A2, A3), (B1, B2));
+broadcast_pair!((), (A1, A2, A3), (B1, B2, B3));
+broadcast_pair!((A1, A2, A3, A4), (), ());
+broadcast_pair!((A1, A2, A3), (A4), (B1));
+broadcast_pair!((A1, A2), (A3, A4), (B1, B2));
+broadcast_pair!((A1), (A2, A3, A4), (B1, B2, B3));
+broadcast_pair!((), (A1, A2, A3, A4), (B1, B2, B3, B4));
+broadcast_pair!((A1, A2, A3, A4, A5), (), ());
+broadcast_pair!((A1, A2, A3, A4), (A5), (B1));
+broadcast_pair!((A1, A2, A3), (A4, A5), (B1, B2));
+broadcast_pair!((A1, A2), (A3, A4, A5), (B1, B2, B3));
+broadcast_pair!((A1), (A2, A3, A4, A5), (B1, B2, B3, B4));
+broadcast_pair!((), (A1, A2, A3, A4, A5), (B1, B2, B3, B4, B5));
+broadcast_pair!((A1, A2, A3, A4, A5, A6), (), ());
+broadcast_pair!((A1, A2, A3, A4, A5), (A6), (B1));
+broadcast_pair!((A1, A2, A3, A4), (A5, A6), (B1, B2));
+broadcast_pair!((A1, A2, A3), (A4, A5, A6), (B1, B2, B3));
+broadcast_pair!((A1, A2), (A3, A4, A5, A6), (B1, B2, B3, B4));
+broadcast_pair!((A1), (A2, A3, A4, A5, A6), (B1, B2, B3, B4, B5));
+broadcast_pair!((), (A1, A2, A3, A4, A5, A6), (B1, B2, B3, B4, B5, B6));
+broadcast_pair!((A1, A2, A3, A4, A5, A6, A7), (), ());
+broadcast_pair!((A1, A2, A3, A4, A5, A6), (A7), (B1));
+broadcast_pair!((A1, A2, A3, A4, A5), (A6, A7), (B1, B2));
+broadcast_pair!((A1, A2, A3, A4), (A5, A6, A7), (B1, B2, B3));
+broadcast_pair!((A1, A2, A3), (A4, A5, A6, A7), (B1, B2, B3, B4));
+broadcast_pair!((A1, A2), (A3, A4, A5, A6, A7), (B1, B2, B3, B4, B5));
+broadcast_pair!((A1), (A2, A3, A4, A5, A6, A7), (B1, B2, B3, B4, B5, B6));
+broadcast_pair!(
+    (),
+    (A1, A2, A3, A4, A5, A6, A7),
+    (B1, B2, B3, B4, B5, B6, B7)
+);
+broadcast_pair!((A1, A2, A3, A4, A5, A6, A7, A8), (), ());
+broadcast_pair!((A1, A2, A3, A4, A5, A6, A7), (A8), (B1));
+broadcast_pair!((A1, A2, A3, A4, A5, A6), (A7, A8), (B1, B2));
+broadcast_pair!((A1, A2, A3, A4, A5), (A6, A7, A8), (B1, B2, B3));
+broadcast_pair!((A1, A2, A3, A4), (A5, A6, A7, A8), (B1, B2, B3, B4));
+broadcast_pair!((A1, A2, A3), (A4, A5, A6, A7, A8), (B1, B2, B3, B4, B5));
+broadcast_pair!((A1, A2), (A3, A4, A5, A6, A7, A8), (B1, B2, B3, B4, B5, B6));
+broadcast_pair!(
+    (A1),
+    (A2, A3, A4, A5, A6, A7, A8),
+    (B1, B2, B3, B4, B5, B6, B7)
+);
+broadcast_pair!(
+    (),
+    (A1, A2, A3, A4, A5, A6, A7, A8),
+    (B1, B2, B3, B4, B5, B6, B7, B8)
+);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -173,4 +359,168 @@ mod tests {
             .backward();
         assert_close(&g.get(&a).array(), &a.array().map(|x| x.exp() / 3.0));
     }
+
+    #[test]
+    fn test_broadcast_pair_rhs() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<_, TestDtype, _> = dev.tensor([[1.0, 2.0, 3.0], [-1.0, -2.0, -3.0]]);
+        let b: Tensor<_, TestDtype, _> = dev.tensor([0.5, 1.0, 1.5]);
+        let (a_up, b_up) = (a.trace(), b.trace()).broadcast_pair();
+        let r = a_up * b_up;
+        assert_eq!(r.array(), [[0.5, 2.0, 4.5], [-0.5, -2.0, -4.5]]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&a).array(), [[0.5, 1.0, 1.5]; 2]);
+        assert_eq!(g.get(&b).array(), [0.0; 3]);
+    }
+
+    #[test]
+    fn test_broadcast_pair_lhs() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<_, TestDtype, _> = dev.tensor([[1.0, 2.0], [4.0, 8.0]]);
+        let b: Tensor<_, TestDtype, _> =
+            dev.tensor([[[1.0, 2.0], [3.0, 4.0]], [[5.0, 6.0], [7.0, 8.0]]]);
+        let (a_up, b_up) = (a.trace(), b.trace()).broadcast_pair();
+        let r = a_up / b_up;
+        assert_close(
+            &r.array(),
+            &[
+                [[1.0, 1.0], [4.0 / 3.0, 2.0]],
+                [[0.2, 1.0 / 3.0], [4.0 / 7.0, 1.0]],
+            ],
+        );
+        let g = r.sum().backward();
+        assert_close(
+            &g.get(&a).array(),
+            &[[1.2, 0.5 + 1.0 / 6.0], [1.0 / 3.0 + 1.0 / 7.0, 0.375]],
+        );
+        assert_close(
+            &g.get(&b).array(),
+            &[
+                [[-1.0, -0.5], [-4.0 / 9.0, -0.5]],
+                [[-0.04, -1.0 / 18.0], [-4.0 / 49.0, -0.125]],
+            ],
+        );
+    }
+
+    #[test]
+    fn test_broadcast_pair_0d() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<Rank3<2, 3, 4>, TestDtype, _> = dev.zeros();
+        let b: Tensor<_, TestDtype, _> = dev.tensor(2.0);
+        let (b_up, a_up) = (b.trace(), a.trace()).broadcast_pair();
+        let r = b_up - a_up;
+        assert_eq!(r.array(), [[[2.0; 4]; 3]; 2]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&a).array(), [[[-1.0; 4]; 3]; 2]);
+        assert_eq!(g.get(&b).array(), 24.0);
+    }
+
+    #[test]
+    fn test_broadcast_pair_cmp() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<_, TestDtype, _> = dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let b: Tensor<_, TestDtype, _> = dev.tensor([4.0, 2.0, 7.0]);
+        let (a, b) = (a, b).broadcast_pair();
+        assert_eq!(
+            a.eq(&b).array(),
+            [[false, true, false], [true, false, false]]
+        );
+        assert_eq!(
+            a.lt(&b).array(),
+            [[true, false, true], [false, false, true]]
+        );
+    }
+
+    #[test]
+    fn test_broadcast_pair_stretch() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<_, TestDtype, _> = dev.tensor_from_vec(std::vec![1.0, 2.0, 3.0], (3, 1));
+        let b: Tensor<_, TestDtype, _> = dev.tensor_from_vec(std::vec![0.5, -1.0], (1, 2));
+        let (a_up, b_up) = (a.trace(), b.trace()).broadcast_pair();
+        assert_eq!(a_up.shape(), &(3, 2));
+        let r = a_up * b_up;
+        assert_eq!(r.as_vec(), [0.5, -1.0, 1.0, -2.0, 1.5, -3.0]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&a).as_vec(), [-0.5; 3]);
+        assert_eq!(g.get(&b).as_vec(), [6.0, 6.0]);
+    }
+
+    #[test]
+    fn test_broadcast_pair_stretch_const() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<Rank3<2, 1, 3>, TestDtype, _> =
+            dev.tensor([[[1.0, 2.0, 3.0]], [[4.0, 5.0, 6.0]]]);
+        let b: Tensor<_, TestDtype, _> = dev.tensor_from_vec(std::vec![1.0, -1.0], (2, 1));
+        let (a_up, b_up) = (a.trace(), b.trace()).broadcast_pair();
+        let _: &(Const<2>, usize, usize) = a_up.shape();
+        assert_eq!(a_up.shape(), &(Const, 2, 3));
+        let r = a_up - b_up;
+        assert_eq!(
+            r.as_vec(),
+            [0.0, 1.0, 2.0, 2.0, 3.0, 4.0, 3.0, 4.0, 5.0, 5.0, 6.0, 7.0]
+        );
+        let g = r.sum().backward();
+        assert_eq!(g.get(&a).array(), [[[2.0; 3]]; 2]);
+        assert_eq!(g.get(&b).as_vec(), [-6.0, -6.0]);
+    }
+
+    #[test]
+    fn test_broadcast_pair_8d() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<Rank8<2, 1, 1, 1, 1, 1, 1, 3>, TestDtype, _> = dev.sample_normal();
+        let b: Tensor<Rank1<3>, TestDtype, _> = dev.sample_normal();
+        let (a_up, b_up) = (a.trace(), b.trace()).broadcast_pair();
+        let r = a_up * b_up;
+        let a_vec = a.as_vec();
+        let b_vec = b.as_vec();
+        let expected: std::vec::Vec<TestDtype> = a_vec
+            .iter()
+            .enumerate()
+            .map(|(i, x)| x * b_vec[i % 3])
+            .collect();
+        assert_eq!(r.as_vec(), expected);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&a).as_vec(), [b_vec.clone(), b_vec].concat());
+        let b_grad: [TestDtype; 3] = std::array::from_fn(|j| a_vec[j] + a_vec[3 + j]);
+        g.get(&b).array().assert_close(&b_grad, 1e-6);
+
+        let c: Tensor<_, TestDtype, _> = dev.ones_like(&(2, 1, 1, 1, 4, 1, 1, 1));
+        let d: Tensor<_, TestDtype, _> = dev.ones_like(&(1, 3, 1, 1, 1, 1, 5));
+        let (c, d) = (c, d).broadcast_pair();
+        assert_eq!(c.shape(), &(2, 1, 3, 1, 4, 1, 1, 5));
+        assert_eq!(d.shape(), c.shape());
+        assert_eq!((c + d).as_vec(), [2.0; 120]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_broadcast_pair_wrong_stretch() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<_, TestDtype, _> = dev.zeros_like(&(3, 2));
+        let b: Tensor<_, TestDtype, _> = dev.zeros_like(&(2, 1, 3));
+        let _ = (a, b).broadcast_pair();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_broadcast_pair_wrong_dims() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<_, TestDtype, _> = dev.zeros_like(&(2, 3));
+        let b: Tensor<_, TestDtype, _> = dev.zeros_like(&(2,));
+        let _ = (a, b).broadcast_pair();
+    }
+
+    #[test]
+    fn test_try_broadcast_pair_wrong_dims() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<_, TestDtype, _> = dev.zeros_like(&(2, 3));
+        let b: Tensor<_, TestDtype, _> = dev.zeros_like(&(2,));
+        assert!((a.clone(), b.clone()).try_broadcast_pair().is_err());
+        assert!((b, a).try_broadcast_pair().is_err());
+
+        let c: Tensor<Rank2<2, 3>, TestDtype, _> = dev.zeros();
+        let d: Tensor<_, TestDtype, _> = dev.zeros_like(&(4, 1));
+        assert!((c.clone(), d.clone()).try_broadcast_pair().is_err());
+        assert!((d, c).try_broadcast_pair().is_err());
+    }
 }
@@ -0,0 +1,98 @@
+use crate::{
+    shapes::*,
+    tensor::cpu::{Cpu, LendingIterator, StridedArray},
+    tensor_ops::utilities::linalg::{cholesky_factor, lower_triangular_inverse, MatrixShape},
+};
+
+use num_traits::Float;
+use std::{sync::Arc, vec::Vec};
+
+impl<E: Dtype + Float> super::CholeskyKernel<E> for Cpu {
+    fn forward<S: MatrixShape>(
+        &self,
+        inp: &Self::Storage<S, E>,
+    ) -> Result<Self::Storage<S, E>, Self::Err> {
+        let n = inp.shape.rows();
+        let mut out: StridedArray<S, E> = StridedArray::new(inp.shape)?;
+        if inp.shape.num_elements() == 0 {
+            return Ok(out);
+        }
+        {
+            let mut inp_iter = inp.iter();
+            let mut out_iter = out.iter_mut();
+            while let Some(o) = out_iter.next() {
+                *o = *inp_iter.next().unwrap();
+            }
+        }
+        for a in Arc::make_mut(&mut out.data).chunks_exact_mut(n * n) {
+            cholesky_factor(a, n);
+        }
+        Ok(out)
+    }
+
+    fn backward<S: MatrixShape>(
+        &self,
+        out: &Self::Storage<S, E>,
+        grad_inp: &mut Self::Storage<S, E>,
+        grad_out: &Self::Storage<S, E>,
+    ) -> Result<(), Self::Err> {
+        if grad_inp.shape.num_elements() == 0 {
+            return Ok(());
+        }
+        let n = out.shape.rows();
+        let half = E::from_f64(0.5).unwrap();
+
+        let mut go_buf: Vec<E> = Vec::with_capacity(grad_out.shape.num_elements());
+        let mut grad_out_iter = grad_out.iter();
+        while let Some(go) = grad_out_iter.next() {
+            go_buf.push(*go);
+        }
+
+        let mut grad_inp_iter = grad_inp.iter_mut();
+        let mut phi = alloc::vec![E::zero(); n * n];
+        let mut tmp = alloc::vec![E::zero(); n * n];
+        for (l, gl) in out.data.chunks_exact(n * n).zip(go_buf.chunks_exact(n * n)) {
+            // phi = Φ(L^T gL)
+            for i in 0..n {
+                for j in 0..n {
+                    let mut v = E::zero();
+                    if j <= i {
+                        for k in i..n {
+                            v += l[k * n + i] * gl[k * n + j];
+                        }
+                    }
+                    phi[i * n + j] = if i == j { v * half } else { v };
+                }
+            }
+
+            // tmp = phi L^-1, then phi = L^-T tmp
+            let l_inv = lower_triangular_inverse(l, n);
+            for i in 0..n {
+                for j in 0..n {
+                    let mut v = E::zero();
+                    for k in j..n {
+                        v += phi[i * n + k] * l_inv[k * n + j];
+                    }
+                    tmp[i * n + j] = v;
+                }
+            }
+            for i in 0..n {
+                for j in 0..n {
+                    let mut v = E::zero();
+                    for k in i..n {
+                        v += l_inv[k * n + i] * tmp[k * n + j];
+                    }
+                    phi[i * n + j] = v;
+                }
+            }
+
+            for i in 0..n {
+                for j in 0..n {
+                    let g = grad_inp_iter.next().unwrap();
+                    *g += (phi[i * n + j] + phi[j * n + i]) * half;
+                }
+            }
+        }
+        Ok(())
+    }
+}
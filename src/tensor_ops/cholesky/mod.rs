@@ -0,0 +1,120 @@
+mod cpu_kernel;
+
+use crate::{gradients::Tape, shapes::*, tensor::*};
+
+use super::utilities::linalg::MatrixShape;
+
+pub trait CholeskyKernel<E: Dtype>: DeviceStorage {
+    /// Returns the lower triangular factor of each matrix.
+    fn forward<S: MatrixShape>(
+        &self,
+        inp: &Self::Storage<S, E>,
+    ) -> Result<Self::Storage<S, E>, Self::Err>;
+
+    /// Adds the symmetrized cholesky gradient `L^-T Φ(L^T grad_out) L^-1` to `grad_inp`,
+    /// where `Φ` takes the lower triangle and halves the diagonal.
+    fn backward<S: MatrixShape>(
+        &self,
+        out: &Self::Storage<S, E>,
+        grad_inp: &mut Self::Storage<S, E>,
+        grad_out: &Self::Storage<S, E>,
+    ) -> Result<(), Self::Err>;
+}
+
+impl<S: MatrixShape, E: Dtype, D: CholeskyKernel<E>, T: Tape<D>> Tensor<S, E, D, T> {
+    /// Cholesky decomposition `A = L L^T` of a symmetric positive definite matrix, or a
+    /// batch of them in the last two axes. Returns the lower triangular `L`.
+    ///
+    /// Only the lower triangle of the input is read, and the gradient is symmetric.
+    /// Matrices that aren't positive definite result in NaNs.
+    ///
+    /// **Pytorch equivalent**: `torch.linalg.cholesky(t)`
+    ///
+    /// **Cuda**: not supported yet. It needs bindings to cuSOLVER's `potrf`.
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t = dev.tensor([[4.0, 2.0], [2.0, 10.0]]);
+    /// let l = t.cholesky();
+    /// assert_eq!(l.array(), [[2.0, 0.0], [1.0, 3.0]]);
+    /// ```
+    pub fn cholesky(self) -> Self {
+        self.try_cholesky().unwrap()
+    }
+
+    /// Fallible version of [Tensor::cholesky]
+    pub fn try_cholesky(self) -> Result<Self, D::Err> {
+        let shape = *self.shape();
+        assert_eq!(shape.rows(), shape.cols(), "matrices must be square");
+        let (inp, mut tape) = self.split_tape();
+        let out = inp.device.upgrade(inp.device.forward(&inp.storage)?);
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&inp)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+            inp.device
+                .backward(&phantom_out.storage, grad_inp, grad_out)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+// cholesky isn't implemented on cuda yet
+#[cfg(all(test, not(feature = "test-cuda")))]
+mod tests {
+    use crate::{shapes::*, tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_cholesky_3x3() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([
+            [4.0, 12.0, -16.0],
+            [12.0, 37.0, -43.0],
+            [-16.0, -43.0, 98.0],
+        ]);
+        let r = t.trace().cholesky();
+        assert_close(
+            &r.array(),
+            &[[2.0, 0.0, 0.0], [6.0, 1.0, 0.0], [-8.0, 5.0, 3.0]],
+        );
+        // only the lower triangle is read
+        let r2 = t.clone().permute::<_, Axes2<1, 0>>().cholesky();
+        assert_close(&r2.array(), &r.array());
+    }
+
+    #[test]
+    fn test_cholesky_backward_is_logdet_gradient() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([[4.0, 2.0], [2.0, 3.0]]);
+        // log(det(A)) = 2 * sum(log(diag(L))), whose gradient is inv(A)
+        let eye: Tensor<Rank2<2, 2>, TestDtype, _> = dev.tensor([[1.0, 0.0], [0.0, 1.0]]);
+        let r = t.trace().cholesky();
+        let diag = (r * eye).sum::<Rank1<2>, Axis<1>>();
+        let g = (diag.ln().sum() * 2.0).backward();
+        assert_close(&g.get(&t).array(), &[[0.375, -0.25], [-0.25, 0.5]]);
+    }
+
+    #[test]
+    fn test_cholesky_batched_backward() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank3<2, 3, 3>, TestDtype, _> = dev.sample_normal();
+        let eye: Tensor<Rank2<3, 3>, TestDtype, _> =
+            dev.tensor([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]);
+        let a = x.clone().matmul(x.permute::<_, Axes3<0, 2, 1>>())
+            + eye.clone().broadcast::<Rank3<2, 3, 3>, Axis<0>>();
+        let a = dev.tensor(a.array());
+
+        let l = a.clone().cholesky();
+        let l_t = l.clone().permute::<_, Axes3<0, 2, 1>>();
+        assert_close_with_tolerance(&l.matmul(l_t).array(), &a.array(), 1e-5);
+
+        let r = a.trace().cholesky();
+        let diag = (r * eye.broadcast::<Rank3<2, 3, 3>, Axis<0>>()).sum::<Rank2<2, 3>, Axis<2>>();
+        let g = (diag.ln().sum() * 2.0).backward();
+        let (_, logabsdet) = a.trace().slogdet();
+        let g2 = logabsdet.sum().backward();
+        assert_close_with_tolerance(&g.get(&a).array(), &g2.get(&a).array(), 1e-4);
+    }
+}
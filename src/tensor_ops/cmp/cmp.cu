@@ -53,3 +53,21 @@ CMP_OP(double, gt_fwd_f64, scalar_gt_fwd_f64, >)
 CMP_OP(double, ge_fwd_f64, scalar_ge_fwd_f64, >=)
 CMP_OP(double, lt_fwd_f64, scalar_lt_fwd_f64, <)
 CMP_OP(double, le_fwd_f64, scalar_le_fwd_f64, <=)
+CMP_OP(int, eq_fwd_i32, scalar_eq_fwd_i32, ==)
+CMP_OP(int, ne_fwd_i32, scalar_ne_fwd_i32, !=)
+CMP_OP(int, gt_fwd_i32, scalar_gt_fwd_i32, >)
+CMP_OP(int, ge_fwd_i32, scalar_ge_fwd_i32, >=)
+CMP_OP(int, lt_fwd_i32, scalar_lt_fwd_i32, <)
+CMP_OP(int, le_fwd_i32, scalar_le_fwd_i32, <=)
+CMP_OP(long long, eq_fwd_i64, scalar_eq_fwd_i64, ==)
+CMP_OP(long long, ne_fwd_i64, scalar_ne_fwd_i64, !=)
+CMP_OP(long long, gt_fwd_i64, scalar_gt_fwd_i64, >)
+CMP_OP(long long, ge_fwd_i64, scalar_ge_fwd_i64, >=)
+CMP_OP(long long, lt_fwd_i64, scalar_lt_fwd_i64, <)
+CMP_OP(long long, le_fwd_i64, scalar_le_fwd_i64, <=)
+CMP_OP(unsigned char, eq_fwd_u8, scalar_eq_fwd_u8, ==)
+CMP_OP(unsigned char, ne_fwd_u8, scalar_ne_fwd_u8, !=)
+CMP_OP(unsigned char, gt_fwd_u8, scalar_gt_fwd_u8, >)
+CMP_OP(unsigned char, ge_fwd_u8, scalar_ge_fwd_u8, >=)
+CMP_OP(unsigned char, lt_fwd_u8, scalar_lt_fwd_u8, <)
+CMP_OP(unsigned char, le_fwd_u8, scalar_le_fwd_u8, <=)
@@ -149,3 +149,24 @@ cmps!(GtKernelOp, f64, "gt_fwd_f64", "scalar_gt_fwd_f64");
 cmps!(GeKernelOp, f64, "ge_fwd_f64", "scalar_ge_fwd_f64");
 cmps!(LtKernelOp, f64, "lt_fwd_f64", "scalar_lt_fwd_f64");
 cmps!(LeKernelOp, f64, "le_fwd_f64", "scalar_le_fwd_f64");
+
+cmps!(EqKernelOp, i32, "eq_fwd_i32", "scalar_eq_fwd_i32");
+cmps!(NeKernelOp, i32, "ne_fwd_i32", "scalar_ne_fwd_i32");
+cmps!(GtKernelOp, i32, "gt_fwd_i32", "scalar_gt_fwd_i32");
+cmps!(GeKernelOp, i32, "ge_fwd_i32", "scalar_ge_fwd_i32");
+cmps!(LtKernelOp, i32, "lt_fwd_i32", "scalar_lt_fwd_i32");
+cmps!(LeKernelOp, i32, "le_fwd_i32", "scalar_le_fwd_i32");
+
+cmps!(EqKernelOp, i64, "eq_fwd_i64", "scalar_eq_fwd_i64");
+cmps!(NeKernelOp, i64, "ne_fwd_i64", "scalar_ne_fwd_i64");
+cmps!(GtKernelOp, i64, "gt_fwd_i64", "scalar_gt_fwd_i64");
+cmps!(GeKernelOp, i64, "ge_fwd_i64", "scalar_ge_fwd_i64");
+cmps!(LtKernelOp, i64, "lt_fwd_i64", "scalar_lt_fwd_i64");
+cmps!(LeKernelOp, i64, "le_fwd_i64", "scalar_le_fwd_i64");
+
+cmps!(EqKernelOp, u8, "eq_fwd_u8", "scalar_eq_fwd_u8");
+cmps!(NeKernelOp, u8, "ne_fwd_u8", "scalar_ne_fwd_u8");
+cmps!(GtKernelOp, u8, "gt_fwd_u8", "scalar_gt_fwd_u8");
+cmps!(GeKernelOp, u8, "ge_fwd_u8", "scalar_ge_fwd_u8");
+cmps!(LtKernelOp, u8, "lt_fwd_u8", "scalar_lt_fwd_u8");
+cmps!(LeKernelOp, u8, "le_fwd_u8", "scalar_le_fwd_u8");
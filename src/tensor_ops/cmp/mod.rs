@@ -283,10 +283,8 @@ mod tests {
         );
     }
 
-    // TODO Remove this attribute once Cuda supports integers
-    #[cfg(not(feature = "cuda"))]
     #[test]
-    fn test_eq_not_dtype() {
+    fn test_eq_int() {
         test_cmp(
             [[1, 2, 3], [0, 123, 5]],
             [[0, 2, -3], [-4, 123, 6]],
@@ -314,10 +312,8 @@ mod tests {
         );
     }
 
-    // TODO Remove this attribute once Cuda supports integers
-    #[cfg(not(feature = "cuda"))]
     #[test]
-    fn test_ne_not_dtype() {
+    fn test_ne_int() {
         test_cmp(
             [[1, 2, 3], [0, 123, 5]],
             [[0, 2, -3], [-4, 123, 6]],
@@ -345,10 +341,8 @@ mod tests {
         );
     }
 
-    // TODO Remove this attribute once Cuda supports integers
-    #[cfg(not(feature = "cuda"))]
     #[test]
-    fn test_gt_not_dtype() {
+    fn test_gt_int() {
         test_cmp(
             [[1, 2, 3], [0, 123, 5]],
             [[0, 2, -3], [-4, 123, 6]],
@@ -376,10 +370,8 @@ mod tests {
         );
     }
 
-    // TODO Remove this attribute once Cuda supports integers
-    #[cfg(not(feature = "cuda"))]
     #[test]
-    fn test_ge_not_dtype() {
+    fn test_ge_int() {
         test_cmp(
             [[1, 2, 3], [0, 123, 5]],
             [[0, 2, -3], [-4, 123, 6]],
@@ -407,10 +399,8 @@ mod tests {
         );
     }
 
-    // TODO Remove this attribute once Cuda supports integers
-    #[cfg(not(feature = "cuda"))]
     #[test]
-    fn test_lt_not_dtype() {
+    fn test_lt_int() {
         test_cmp(
             [[1, 2, 3], [0, 123, 5]],
             [[0, 2, -3], [-4, 123, 6]],
@@ -438,10 +428,8 @@ mod tests {
         );
     }
 
-    // TODO Remove this attribute once Cuda supports integers
-    #[cfg(not(feature = "cuda"))]
     #[test]
-    fn test_le_not_dtype() {
+    fn test_le_int() {
         test_cmp(
             [[1, 2, 3], [0, 123, 5]],
             [[0, 2, -3], [-4, 123, 6]],
@@ -450,6 +438,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_cmp_i64_u8() {
+        test_cmp::<i64, 1, 3, _>(
+            [[i64::MIN, 0, i64::MAX]],
+            [[0, 0, 0]],
+            |a, b| a.lt(b).array(),
+            [[true, false, false]],
+        );
+        test_scalar_cmp::<u8, 1, 3, _>(
+            [[0, 128, 255]],
+            |a| a.scalar_ge(128).array(),
+            [[false, true, true]],
+        );
+    }
+
     #[test]
     fn test_scalar_le() {
         test_scalar_cmp::<TestDtype, 2, 2, _>(
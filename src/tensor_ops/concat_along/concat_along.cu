@@ -0,0 +1,95 @@
+#include "cuda_utils.cuh"
+
+// Converts the index of an element of an input (in a contiguous layout) into
+// the index of the element of the output (in a contiguous layout), where the input
+// starts at `offset` along `axis`.
+__device__ unsigned int get_concat_index(
+    const unsigned int inp_i,
+    const size_t num_dims,
+    const size_t *inp_dims,
+    const size_t *out_dims,
+    const size_t axis,
+    const size_t offset
+) {
+    size_t inner = 1;
+    for (size_t d = axis + 1; d < num_dims; d++) {
+        inner *= inp_dims[d];
+    }
+    size_t i_inner = inp_i % inner;
+    size_t i_axis = (inp_i / inner) % inp_dims[axis];
+    size_t i_outer = inp_i / (inner * inp_dims[axis]);
+    return (i_outer * out_dims[axis] + offset + i_axis) * inner + i_inner;
+}
+
+template<typename T>
+__device__ void concat_along_fwd(
+    const size_t numel,
+    const size_t num_dims,
+    const size_t *inp_dims,
+    const size_t *out_dims,
+    const size_t axis,
+    const size_t offset,
+    const T *inp,
+    const size_t *inp_strides,
+    T *out
+) {
+    unsigned int inp_i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (inp_i >= numel) {
+        return;
+    }
+
+    unsigned int out_i = get_concat_index(inp_i, num_dims, inp_dims, out_dims, axis, offset);
+    out[out_i] = inp[get_strided_index(inp_i, num_dims, inp_dims, inp_strides)];
+}
+
+template<typename T>
+__device__ void concat_along_bwd(
+    const size_t numel,
+    const size_t num_dims,
+    const size_t *inp_dims,
+    const size_t *out_dims,
+    const size_t axis,
+    const size_t offset,
+    T *grad_inp,
+    const size_t *inp_strides,
+    const T *grad_out
+) {
+    unsigned int inp_i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (inp_i >= numel) {
+        return;
+    }
+
+    unsigned int out_i = get_concat_index(inp_i, num_dims, inp_dims, out_dims, axis, offset);
+    atomicAdd(grad_inp + get_strided_index(inp_i, num_dims, inp_dims, inp_strides), grad_out[out_i]);
+}
+
+#define CONCAT_ALONG_OP(TYPENAME, FWD, BWD) \
+extern "C" __global__ void FWD( \
+    const size_t numel, \
+    const size_t num_dims, \
+    const size_t *inp_dims, \
+    const size_t *out_dims, \
+    const size_t axis, \
+    const size_t offset, \
+    const TYPENAME *inp, \
+    const size_t *inp_strides, \
+    TYPENAME *out \
+) { \
+    concat_along_fwd(numel, num_dims, inp_dims, out_dims, axis, offset, inp, inp_strides, out); \
+} \
+extern "C" __global__ void BWD( \
+    const size_t numel, \
+    const size_t num_dims, \
+    const size_t *inp_dims, \
+    const size_t *out_dims, \
+    const size_t axis, \
+    const size_t offset, \
+    TYPENAME *grad_inp, \
+    const size_t *inp_strides, \
+    const TYPENAME *grad_out \
+) { \
+    concat_along_bwd(numel, num_dims, inp_dims, out_dims, axis, offset, grad_inp, inp_strides, grad_out); \
+}
+
+CONCAT_ALONG_OP(float, concat_along_fwd_f32, concat_along_bwd_f32);
+CONCAT_ALONG_OP(double, concat_along_fwd_f64, concat_along_bwd_f64);
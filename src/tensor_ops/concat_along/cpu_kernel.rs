@@ -0,0 +1,63 @@
+use crate::{
+    shapes::*,
+    tensor::cpu::{Cpu, LendingIterator, StridedArray},
+};
+
+use std::vec::Vec;
+
+/// Converts an index into an input to the index into the output, where the input
+/// starts at `offset` along `axis`.
+fn out_index<S: Shape, Dst: Shape>(
+    axis: usize,
+    offset: usize,
+    i_inp: S::Concrete,
+) -> Dst::Concrete {
+    let mut i_out: Dst::Concrete = Default::default();
+    for j in 0..S::NUM_DIMS {
+        i_out[j] = i_inp[j];
+    }
+    i_out[axis] += offset;
+    i_out
+}
+
+impl<E: Dtype> super::ConcatAlongKernel<E> for Cpu {
+    fn forward<S: Shape, Dst: Shape>(
+        &self,
+        axis: usize,
+        dst: Dst,
+        inp: Vec<&Self::Storage<S, E>>,
+    ) -> Result<Self::Storage<Dst, E>, Self::Err> {
+        let mut out: Self::Storage<Dst, E> = StridedArray::new(dst)?;
+        let mut offset = 0;
+        for item in inp {
+            if item.shape.num_elements() > 0 {
+                let mut item_iter = item.iter_with_index();
+                while let Some((x, i_inp)) = item_iter.next() {
+                    out[out_index::<S, Dst>(axis, offset, i_inp)] = *x;
+                }
+            }
+            offset += item.shape.concrete()[axis];
+        }
+        Ok(out)
+    }
+
+    fn backward<S: Shape, Dst: Shape>(
+        &self,
+        axis: usize,
+        mut grad_inp: Vec<&mut Self::Storage<S, E>>,
+        grad_out: &Self::Storage<Dst, E>,
+    ) -> Result<(), Self::Err> {
+        let mut offset = 0;
+        for item in grad_inp.drain(..) {
+            let size = item.shape.concrete()[axis];
+            if item.shape.num_elements() > 0 {
+                let mut item_iter = item.iter_mut_with_index();
+                while let Some((g, i_inp)) = item_iter.next() {
+                    *g += grad_out[out_index::<S, Dst>(axis, offset, i_inp)];
+                }
+            }
+            offset += size;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,103 @@
+use crate::{
+    shapes::*,
+    tensor::cuda::{Cuda, CudaArray},
+};
+use cudarc::driver::{AsKernelParam, CudaSlice, LaunchAsync, LaunchConfig};
+use std::{sync::Arc, vec::Vec};
+
+const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/concat_along.ptx"));
+
+trait HasCudaKernel<E> {
+    const MOD: &'static str;
+    const FNS: &'static [&'static str];
+}
+
+impl HasCudaKernel<f32> for Cuda {
+    const MOD: &'static str = "concat_along_f32";
+    const FNS: &'static [&'static str] = &["concat_along_fwd_f32", "concat_along_bwd_f32"];
+}
+
+impl HasCudaKernel<f64> for Cuda {
+    const MOD: &'static str = "concat_along_f64";
+    const FNS: &'static [&'static str] = &["concat_along_fwd_f64", "concat_along_bwd_f64"];
+}
+
+impl<E: Dtype + AsKernelParam> super::ConcatAlongKernel<E> for Cuda
+where
+    Self: HasCudaKernel<E>,
+{
+    fn forward<S: Shape, Dst: Shape>(
+        &self,
+        axis: usize,
+        dst: Dst,
+        inp: Vec<&Self::Storage<S, E>>,
+    ) -> Result<Self::Storage<Dst, E>, Self::Err> {
+        if !self.dev.has_func(Self::MOD, Self::FNS[0]) {
+            self.dev.load_ptx(PTX_SRC.into(), Self::MOD, Self::FNS)?;
+        }
+
+        let mut storage = unsafe { self.dev.alloc_async::<E>(dst.num_elements()) }?;
+        let out_dims: CudaSlice<usize> = self.dev.take_async(dst.concrete().into())?;
+
+        let mut offset = 0;
+        for item in inp {
+            let numel = item.shape.num_elements();
+            let inp_dims: CudaSlice<usize> = self.dev.take_async(item.shape.concrete().into())?;
+            let inp_strides: CudaSlice<usize> = self.dev.take_async(item.strides.into())?;
+            let fwd_fn = self.dev.get_func(Self::MOD, Self::FNS[0]).unwrap();
+            let cfg = LaunchConfig::for_num_elems(numel as u32);
+            let params = (
+                numel,              // const size_t numel,
+                S::NUM_DIMS,        // const size_t num_dims,
+                &inp_dims,          // const size_t *inp_dims,
+                &out_dims,          // const size_t *out_dims,
+                axis,               // const size_t axis,
+                offset,             // const size_t offset,
+                item.data.as_ref(), // const float *inp,
+                &inp_strides,       // const size_t *inp_strides,
+                &mut storage,       // float *out
+            );
+            unsafe { fwd_fn.launch_async(cfg, params) }?;
+            offset += item.shape.concrete()[axis];
+        }
+
+        Ok(CudaArray {
+            data: Arc::new(storage),
+            shape: dst,
+            strides: dst.strides(),
+        })
+    }
+
+    fn backward<S: Shape, Dst: Shape>(
+        &self,
+        axis: usize,
+        mut grad_inp: Vec<&mut Self::Storage<S, E>>,
+        grad_out: &Self::Storage<Dst, E>,
+    ) -> Result<(), Self::Err> {
+        let out_dims: CudaSlice<usize> = self.dev.take_async(grad_out.shape.concrete().into())?;
+
+        let mut offset = 0;
+        for item in grad_inp.drain(..) {
+            let numel = item.shape.num_elements();
+            let size = item.shape.concrete()[axis];
+            let inp_dims: CudaSlice<usize> = self.dev.take_async(item.shape.concrete().into())?;
+            let inp_strides: CudaSlice<usize> = self.dev.take_async(item.strides.into())?;
+            let bwd_fn = self.dev.get_func(Self::MOD, Self::FNS[1]).unwrap();
+            let cfg = LaunchConfig::for_num_elems(numel as u32);
+            let params = (
+                numel,                         // const size_t numel,
+                S::NUM_DIMS,                   // const size_t num_dims,
+                &inp_dims,                     // const size_t *inp_dims,
+                &out_dims,                     // const size_t *out_dims,
+                axis,                          // const size_t axis,
+                offset,                        // const size_t offset,
+                Arc::make_mut(&mut item.data), // float *grad_inp,
+                &inp_strides,                  // const size_t *inp_strides,
+                grad_out.data.as_ref(),        // const float *grad_out
+            );
+            unsafe { bwd_fn.launch_async(cfg, params) }?;
+            offset += size;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,218 @@
+use crate::{
+    gradients::{Merge, Tape},
+    shapes::*,
+    tensor::*,
+};
+
+use std::vec::Vec;
+
+mod cpu_kernel;
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+/// Concatenate a vec of tensors together along an existing axis.
+pub trait TryConcatAlong<Ax>: Sized {
+    type Output;
+    type Err: std::fmt::Debug;
+
+    /// Concatenate a vec of tensors along axis `Ax`. All of the tensors must have
+    /// the same shape, except along `Ax`. The size of `Ax` in the output is a [usize]
+    /// dim holding the sum of the sizes of the inputs.
+    ///
+    /// **Pytorch equivalent** `torch.cat(tensors, dim=Ax)`.
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let a: Tensor<Rank2<2, 3>, f32, _> = dev.zeros();
+    /// let b: Tensor<Rank2<2, 3>, f32, _> = dev.ones();
+    /// let r: Tensor<(usize, Const<3>), f32, _> = vec![a, b].concat_along(Axis::<0>);
+    /// assert_eq!(r.shape(), &(4, Const));
+    ///
+    /// // inputs with different sizes along the axis need a runtime dim
+    /// let a = dev.tensor_from_vec(vec![1.0, 2.0, 3.0, 4.0], (Const::<2>, 2));
+    /// let b = dev.tensor_from_vec(vec![5.0, 6.0], (Const::<2>, 1));
+    /// let r = vec![a, b].concat_along(Axis::<1>);
+    /// assert_eq!(r.as_vec(), [1.0, 2.0, 5.0, 3.0, 4.0, 6.0]);
+    /// ```
+    fn concat_along(self, ax: Ax) -> Self::Output {
+        self.try_concat_along(ax).unwrap()
+    }
+
+    /// Fallible version of [TryConcatAlong::concat_along]
+    fn try_concat_along(self, ax: Ax) -> Result<Self::Output, Self::Err>;
+}
+
+/// Marker for shapes that can be concatenated along axis `Ax`, which
+/// makes that axis a [usize] dim.
+pub trait ConcatShape<Ax>: Shape {
+    type Catted: Shape;
+    fn catted(&self, size: usize) -> Self::Catted;
+}
+
+macro_rules! concat_shape {
+    (($($D:tt),*), $Ax:literal, $Catted:ty) => {
+        impl<$($D: Dim, )*> ConcatShape<Axis<$Ax>> for ($($D, )*) {
+            type Catted = $Catted;
+            fn catted(&self, size: usize) -> Self::Catted {
+                let mut dims = self.concrete();
+                dims[$Ax] = size;
+                Self::Catted::from_concrete(&dims).unwrap()
+            }
+        }
+    };
+}
+
+concat_shape!((D0), 0, (usize,));
+concat_shape!((D0, D1), 0, (usize, D1));
+concat_shape!((D0, D1), 1, (D0, usize));
+concat_shape!((D0, D1, D2), 0, (usize, D1, D2));
+concat_shape!((D0, D1, D2), 1, (D0, usize, D2));
+concat_shape!((D0, D1, D2), 2, (D0, D1, usize));
+concat_shape!((D0, D1, D2, D3), 0, (usize, D1, D2, D3));
+concat_shape!((D0, D1, D2, D3), 1, (D0, usize, D2, D3));
+concat_shape!((D0, D1, D2, D3), 2, (D0, D1, usize, D3));
+concat_shape!((D0, D1, D2, D3), 3, (D0, D1, D2, usize));
+concat_shape!((D0, D1, D2, D3, D4), 0, (usize, D1, D2, D3, D4));
+concat_shape!((D0, D1, D2, D3, D4), 1, (D0, usize, D2, D3, D4));
+concat_shape!((D0, D1, D2, D3, D4), 2, (D0, D1, usize, D3, D4));
+concat_shape!((D0, D1, D2, D3, D4), 3, (D0, D1, D2, usize, D4));
+concat_shape!((D0, D1, D2, D3, D4), 4, (D0, D1, D2, D3, usize));
+
+pub trait ConcatAlongKernel<E: Dtype>: DeviceStorage {
+    fn forward<S: Shape, Dst: Shape>(
+        &self,
+        axis: usize,
+        dst: Dst,
+        inp: Vec<&Self::Storage<S, E>>,
+    ) -> Result<Self::Storage<Dst, E>, Self::Err>;
+    fn backward<S: Shape, Dst: Shape>(
+        &self,
+        axis: usize,
+        grad_inp: Vec<&mut Self::Storage<S, E>>,
+        grad_out: &Self::Storage<Dst, E>,
+    ) -> Result<(), Self::Err>;
+}
+
+impl<const I: isize, S: Shape, E: Dtype, D: ConcatAlongKernel<E>, T> TryConcatAlong<Axis<I>>
+    for Vec<Tensor<S, E, D, T>>
+where
+    S: ConcatShape<Axis<I>>,
+    T: Tape<D> + Merge<T>,
+{
+    type Output = Tensor<S::Catted, E, D, T>;
+    type Err = D::Err;
+
+    fn try_concat_along(self, _: Axis<I>) -> Result<Self::Output, Self::Err> {
+        assert!(!self.is_empty());
+        let axis = I as usize;
+
+        // need to split tape for ease of implementation
+        let mut tensors = Vec::with_capacity(self.len());
+        let mut tape: T = Default::default();
+        for item in self.into_iter() {
+            let (item, rhs) = item.split_tape();
+            tape = tape.merge(rhs);
+            tensors.push(item);
+        }
+
+        // check that all the shapes are equal except along the axis
+        let device = tensors[0].device.clone();
+        let dims = tensors[0].shape().concrete();
+        let mut size = 0;
+        for t in tensors.iter() {
+            let t_dims = t.shape().concrete();
+            for i in 0..S::NUM_DIMS {
+                if i != axis {
+                    assert_eq!(t_dims[i], dims[i], "dimension {i} not the same");
+                }
+            }
+            size += t_dims[axis];
+        }
+        let dst = tensors[0].shape().catted(size);
+
+        // we map to storage refs so kernels don't have to know about tensors
+        let storages: Vec<&D::Storage<S, E>> = tensors.iter().map(|t| &t.storage).collect();
+        let out = device.upgrade(device.forward(axis, dst, storages)?);
+
+        let phantom_out = out.clone();
+        for inp in tensors.iter() {
+            tape.try_alloc_grad(inp)?;
+        }
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_inp, grad_out) = grads.many_and_ref(&tensors, &phantom_out);
+            device.backward(axis, grad_inp, grad_out)?;
+            Ok(())
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_concat_along_axis_0() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<Rank2<2, 3>, TestDtype, _> = dev.sample_normal();
+        let b: Tensor<Rank2<2, 3>, TestDtype, _> = dev.sample_normal();
+        let c: Tensor<Rank2<2, 3>, TestDtype, _> = dev.sample_normal();
+        let r = std::vec![a.trace(), b.trace(), c.trace()].concat_along(Axis::<0>);
+        assert_eq!(r.shape(), &(6, Const));
+        let r_vec = r.as_vec();
+        assert_eq!(&r_vec[..6], &a.as_vec());
+        assert_eq!(&r_vec[6..12], &b.as_vec());
+        assert_eq!(&r_vec[12..], &c.as_vec());
+
+        let g = r.exp().mean().backward();
+        assert_close(&g.get(&a).array(), &(a.exp() / 18.0).array());
+        assert_close(&g.get(&b).array(), &(b.exp() / 18.0).array());
+        assert_close(&g.get(&c).array(), &(c.exp() / 18.0).array());
+    }
+
+    #[test]
+    fn test_concat_along_last_axis() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<(Const<2>, usize), TestDtype, _> =
+            dev.tensor_from_vec(std::vec![1.0, 2.0, 3.0, 4.0], (Const, 2));
+        let b: Tensor<(Const<2>, usize), TestDtype, _> =
+            dev.tensor_from_vec(std::vec![5.0, 6.0], (Const, 1));
+        let r = std::vec![a.trace(), b.trace()].concat_along(Axis::<1>);
+        assert_eq!(r.shape(), &(Const, 3));
+        assert_eq!(r.as_vec(), [1.0, 2.0, 5.0, 3.0, 4.0, 6.0]);
+        let g = (r * dev.tensor_from_vec(std::vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0], (Const, 3)))
+            .sum()
+            .backward();
+        assert_eq!(g.get(&a).as_vec(), [1.0, 2.0, 4.0, 5.0]);
+        assert_eq!(g.get(&b).as_vec(), [3.0, 6.0]);
+    }
+
+    #[test]
+    fn test_concat_along_broadcasted() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<Rank1<2>, TestDtype, _> = dev.tensor([1.0, 2.0]);
+        let b: Tensor<Rank3<2, 1, 2>, TestDtype, _> = dev.tensor([[[3.0, 4.0]], [[5.0, 6.0]]]);
+        let r = std::vec![
+            a.trace().broadcast::<Rank3<2, 1, 2>, Axes2<0, 1>>(),
+            b.trace()
+        ]
+        .concat_along(Axis::<1>);
+        assert_eq!(r.shape(), &(Const, 2, Const));
+        assert_eq!(r.as_vec(), [1.0, 2.0, 3.0, 4.0, 1.0, 2.0, 5.0, 6.0]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&a).array(), [2.0, 2.0]);
+        assert_eq!(g.get(&b).array(), [[[1.0; 2]]; 2]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_concat_along_different_dims() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<(usize, usize), TestDtype, _> = dev.zeros_like(&(2, 3));
+        let b: Tensor<(usize, usize), TestDtype, _> = dev.zeros_like(&(3, 3));
+        let _ = std::vec![a, b].concat_along(Axis::<1>);
+    }
+}
@@ -0,0 +1,196 @@
+struct Conv1DOp {
+    size_t stride;
+    size_t padding;
+    size_t kernel;
+    size_t batch;
+    size_t chan_in;
+    size_t chan_out;
+    size_t l_in;
+    size_t l_out;
+};
+
+template<typename T>
+__device__ void unfold_input_into_patches(
+    const Conv1DOp op,
+    const T *image, // 3d (Batch, Channels, Length)
+    const size_t *strides, // 3d image strides
+    T *patches // 4d (Batch, Channels, KernelSize, LengthOut)
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    const auto patches_numel = op.batch * op.chan_in * op.kernel * op.l_out;
+    if (i >= patches_numel) {
+        return;
+    }
+
+    // patches shape is (B, C, K, l_out)
+    unsigned int idx = i;
+    const size_t ol = idx % op.l_out;
+    idx /= op.l_out;
+    const size_t k = idx % op.kernel;
+    idx /= op.kernel;
+    const size_t c = idx % op.chan_in;
+    idx /= op.chan_in;
+    const size_t b = idx % op.batch;
+    idx /= op.batch;
+
+    const size_t l_plus_p = ol * op.stride + k;
+    if (l_plus_p < op.padding) {
+        return;
+    }
+    const size_t l = l_plus_p - op.padding;
+    if (l >= op.l_in) {
+        return;
+    }
+
+    const size_t i_image = b * strides[0] + c * strides[1] + l * strides[2];
+    patches[i] = image[i_image];
+}
+
+template<typename T>
+__device__ void unfold_output_into_patches(
+    const Conv1DOp op,
+    const T *image_out, // 3d (Batch, ChanOut, LengthOut)
+    T *patches // 4d (Batch, ChanOut, KernelSize, LengthIn)
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    const auto patches_numel = op.batch * op.chan_out * op.kernel * op.l_in;
+    if (i >= patches_numel) {
+        return;
+    }
+
+    unsigned int idx = i;
+    const size_t l = idx % op.l_in;
+    idx /= op.l_in;
+    const size_t k = idx % op.kernel;
+    idx /= op.kernel;
+    const size_t o = idx % op.chan_out;
+    idx /= op.chan_out;
+    const size_t b = idx % op.batch;
+    idx /= op.batch;
+
+    size_t ol = l + op.padding;
+    if (ol < k) {
+        return;
+    }
+    ol -= k;
+    if (ol % op.stride != 0) {
+        return;
+    }
+    ol /= op.stride;
+    if (ol >= op.l_out) {
+        return;
+    }
+
+    size_t image_i = b * (op.chan_out * op.l_out) + o * op.l_out + ol;
+    patches[i] = image_out[image_i];
+}
+
+template<typename T>
+__device__ void transpose_and_broadcast_filters(
+    const Conv1DOp op,
+    const T *filters, // 3d (ChanOut, ChanIn, KernelSize)
+    const size_t *strides, // 3d filters strides
+    T *filters_tr // 4d (Batch, ChanIn, ChanOut, KernelSize)
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    auto numel = op.chan_in * op.chan_out * op.kernel;
+    if (i >= numel) {
+        return;
+    }
+
+    unsigned int idx = i;
+    const size_t k = idx % op.kernel;
+    idx /= op.kernel;
+    const size_t c = idx % op.chan_in;
+    idx /= op.chan_in;
+    const size_t o = idx % op.chan_out;
+    idx /= op.chan_out;
+
+    auto i_tr = c * (op.chan_out * op.kernel) + o * op.kernel + k;
+    auto i_no = o * strides[0] + c * strides[1] + k * strides[2];
+
+    const T f = filters[i_no];
+    for (auto b = 0; b < op.batch; b++) {
+        filters_tr[b * numel + i_tr] = f;
+    }
+}
+
+template<typename T>
+__device__ void sum_transposed_filters(
+    const Conv1DOp op,
+    const T *filters_tr, // 4d (Batch, ChanIn, ChanOut, KernelSize)
+    T *filters, // 3d (ChanOut, ChanIn, KernelSize)
+    const size_t *strides // 3d filter strides
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    auto numel = op.chan_out * op.chan_in * op.kernel;
+    if (i >= numel) {
+        return;
+    }
+
+    unsigned int idx = i;
+    const size_t k = idx % op.kernel;
+    idx /= op.kernel;
+    const size_t c = idx % op.chan_in;
+    idx /= op.chan_in;
+    const size_t o = idx % op.chan_out;
+    idx /= op.chan_out;
+
+    auto i_tr = c * (op.chan_out * op.kernel) + o * op.kernel + k;
+    auto i_no = o * strides[0] + c * strides[1] + k * strides[2];
+
+    T tmp = 0.0;
+    for (auto b = 0; b < op.batch; b++) {
+        tmp += filters_tr[b * numel + i_tr];
+    }
+
+    filters[i_no] += tmp;
+}
+
+#define CONV_OP(TYPENAME, UNFOLD_INPUT, UNFOLD_OUTPUT, TR_FILTERS, SUM_TR_FILTERS) \
+extern "C" __global__ void UNFOLD_INPUT( \
+    const Conv1DOp op, \
+    const TYPENAME *image, \
+    const size_t *strides, \
+    TYPENAME *patches \
+) { \
+    unfold_input_into_patches(op, image, strides, patches); \
+} \
+extern "C" __global__ void UNFOLD_OUTPUT( \
+    const Conv1DOp op, \
+    const TYPENAME *image_out, \
+    TYPENAME *patches \
+) { \
+    unfold_output_into_patches(op, image_out, patches); \
+} \
+extern "C" __global__ void TR_FILTERS( \
+    const Conv1DOp op, \
+    const TYPENAME *filters, \
+    const size_t *strides, \
+    TYPENAME *filters_tr \
+) { \
+    transpose_and_broadcast_filters(op, filters, strides, filters_tr); \
+} \
+extern "C" __global__ void SUM_TR_FILTERS( \
+    const Conv1DOp op, \
+    const TYPENAME *filters_tr, \
+    TYPENAME *filters, \
+    const size_t *strides \
+) { \
+    sum_transposed_filters(op, filters_tr, filters, strides); \
+}
+
+CONV_OP(
+    float,
+    unfold_input_into_patches_f32,
+    unfold_output_into_patches_f32,
+    transpose_and_broadcast_filters_f32,
+    sum_transposed_filters_f32
+);
+CONV_OP(
+    double,
+    unfold_input_into_patches_f64,
+    unfold_output_into_patches_f64,
+    transpose_and_broadcast_filters_f64,
+    sum_transposed_filters_f64
+);
@@ -0,0 +1,218 @@
+use crate::shapes::{Dtype, Shape};
+use crate::tensor::cpu::*;
+use crate::tensor_ops::matmul::cpu_kernel::MatMulImpl;
+
+use super::{Conv1DKernel, Conv1DOp};
+
+use std::sync::Arc;
+
+impl Conv1DOp {
+    #[inline(always)]
+    fn unfold_idx(&self, [k, l]: [usize; 2]) -> Option<usize> {
+        let mut ol = l + self.padding;
+        if ol < k {
+            return None;
+        }
+        ol -= k;
+        if ol % self.stride != 0 {
+            return None;
+        }
+        ol /= self.stride;
+        if ol >= self.l_out {
+            return None;
+        }
+        Some(ol)
+    }
+}
+
+impl Cpu {
+    #[inline]
+    fn conv1d_forward<E: Dtype, P: Shape<Concrete = [usize; 3]>>(
+        &self,
+        op: &Conv1DOp,
+        img: &[E],
+        filters: &[E],
+        out: &mut [E],
+        inp_patches_buf: &mut StridedArray<P, E>,
+    ) -> Result<(), CpuError>
+    where
+        Self: MatMulImpl<E>,
+    {
+        {
+            let buf = Arc::make_mut(&mut inp_patches_buf.data);
+            let mut i = 0;
+            for c in 0..op.chan_in {
+                for k in 0..op.kernel {
+                    for ol in 0..op.l_out {
+                        let l = (ol * op.stride + k).wrapping_sub(op.padding);
+                        if l < op.l_in {
+                            buf[i] = img[c * op.l_in + l];
+                        }
+                        i += 1;
+                    }
+                }
+            }
+        }
+
+        // (O, C * K) * (C * K, OL) = (O, OL)
+        let m = op.chan_out;
+        let k = op.chan_in * op.kernel;
+        let n = op.l_out;
+        Self::matmul(
+            View::new(filters, (m, k)),
+            View::new(inp_patches_buf.view().data, (k, n)),
+            &mut ViewMut::new(out, (m, n)),
+        );
+        Ok(())
+    }
+
+    #[inline]
+    #[allow(clippy::too_many_arguments)]
+    fn conv1d_backward<E: Dtype, P: Shape<Concrete = [usize; 3]>>(
+        &self,
+        op: &Conv1DOp,
+        img: &[E],
+        grad_img: &mut [E],
+        filters_tr: &[E],
+        grad_filters_tr: &mut [E],
+        grad_out: &[E],
+        out_patches_buf: &mut StridedArray<P, E>,
+    ) -> Result<(), CpuError>
+    where
+        Self: MatMulImpl<E>,
+    {
+        {
+            let mut i = 0;
+            let buf = Arc::make_mut(&mut out_patches_buf.data);
+            for o in 0..op.chan_out {
+                for k in 0..op.kernel {
+                    for l in 0..op.l_in {
+                        if let Some(ol) = op.unfold_idx([k, l]) {
+                            buf[i] = grad_out[o * op.l_out + ol];
+                        }
+                        i += 1;
+                    }
+                }
+            }
+        }
+
+        {
+            // img_g += filters^T * unfold(grad_out)
+            // (C, L) += (C, O * K) * (O * K, L)
+            let m = op.chan_in;
+            let k = op.chan_out * op.kernel;
+            let n = op.l_in;
+            Self::matmul(
+                View::new(filters_tr, (m, k)),
+                View::new(out_patches_buf.view().data, (k, n)),
+                &mut ViewMut::new(grad_img, (m, n)),
+            );
+        }
+
+        {
+            // weight_g^T += img * patches^T
+            // (C, O * K) += (C, L) * (L, O * K)
+            let m = op.chan_in;
+            let k = op.l_in;
+            let n = op.chan_out * op.kernel;
+            Self::matmul(
+                View::new(img, (m, k)),
+                View::new(out_patches_buf.view().data, (n, k)).tr(),
+                &mut ViewMut::new(grad_filters_tr, (m, n)),
+            );
+        }
+        Ok(())
+    }
+}
+
+impl<E: Dtype> Conv1DKernel<E> for Cpu
+where
+    Self: MatMulImpl<E>,
+{
+    fn forward<L: Shape, R: Shape, O: Shape>(
+        &self,
+        op: Conv1DOp,
+        lhs: &Self::Storage<L, E>,
+        rhs: &Self::Storage<R, E>,
+        out: &mut Self::Storage<O, E>,
+    ) -> Result<(), Self::Err> {
+        let mut patches: StridedArray<_, E> = StridedArray::new(op.inp_patches_shape())?;
+        let [lstride, ostride] = match L::NUM_DIMS {
+            2 => [0; 2],
+            3 => [lhs.strides[0], out.strides[0]],
+            _ => unreachable!(),
+        };
+        let lhs = lhs.data.as_ref();
+        let rhs = rhs.data.as_ref();
+        let out = Arc::make_mut(&mut out.data);
+        for i_batch in 0..op.batch {
+            self.conv1d_forward(
+                &op,
+                &lhs[i_batch * lstride..],
+                rhs,
+                &mut out[i_batch * ostride..],
+                &mut patches,
+            )?;
+        }
+        Ok(())
+    }
+
+    fn backward<L: Shape, R: Shape, O: Shape>(
+        &self,
+        op: Conv1DOp,
+        lhs: &Self::Storage<L, E>,
+        grad_lhs: &mut Self::Storage<L, E>,
+        rhs: &Self::Storage<R, E>,
+        grad_rhs: &mut Self::Storage<R, E>,
+        grad_out: &Self::Storage<O, E>,
+    ) -> Result<(), Self::Err> {
+        let mut patches: StridedArray<_, E> = StridedArray::new(op.out_patches_shape())?;
+        let mut f102: StridedArray<_, E> = StridedArray::new(op.filters_tr_shape())?;
+        let mut grad_f102: StridedArray<_, E> = StridedArray::new(op.filters_tr_shape())?;
+
+        {
+            // transpose filters in f102
+            let buf = rhs.data.as_ref();
+            let mut f_iter = f102.iter_mut_with_index();
+            while let Some((f, [c, o, k])) = f_iter.next() {
+                let idx = o * rhs.strides[0] + c * rhs.strides[1] + k * rhs.strides[2];
+                *f = buf[idx];
+            }
+        }
+
+        let [lstride, ostride] = match L::NUM_DIMS {
+            2 => [0; 2],
+            3 => [lhs.strides[0], grad_out.strides[0]],
+            _ => unreachable!(),
+        };
+        let lhs = lhs.data.as_ref();
+        let grad_lhs = Arc::make_mut(&mut grad_lhs.data);
+        let f = f102.data.as_ref();
+        let grad_f = Arc::make_mut(&mut grad_f102.data);
+        let grad_out = grad_out.data.as_ref();
+
+        for i_batch in 0..op.batch {
+            self.conv1d_backward(
+                &op,
+                &lhs[i_batch * lstride..],
+                &mut grad_lhs[i_batch * lstride..],
+                f,
+                grad_f,
+                &grad_out[i_batch * ostride..],
+                &mut patches,
+            )?;
+        }
+
+        {
+            // untranspose filters
+            let buf = Arc::make_mut(&mut grad_rhs.data);
+            let mut f_iter = grad_f102.iter_with_index();
+            while let Some((f, [c, o, k])) = f_iter.next() {
+                let idx = o * rhs.strides[0] + c * rhs.strides[1] + k * rhs.strides[2];
+                buf[idx] += *f;
+            }
+        }
+
+        Ok(())
+    }
+}
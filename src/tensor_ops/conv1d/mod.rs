@@ -0,0 +1,361 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{
+    gradients::Tape,
+    shapes::*,
+    tensor::{DeviceStorage, HasErr, PutTape, SplitTape, Tensor, ZerosTensor},
+};
+
+use super::conv2d::ConvAlgebra;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub(super) struct Conv1DOp {
+    pub stride: usize,
+    pub padding: usize,
+    pub kernel: usize,
+    pub batch: usize,
+    pub chan_in: usize,
+    pub chan_out: usize,
+    pub l_in: usize,
+    pub l_out: usize,
+}
+
+impl Conv1DOp {
+    fn new(s: usize, p: usize, k: usize, [b, c, l_in]: [usize; 3], o: usize) -> Self {
+        Self {
+            stride: s,
+            padding: p,
+            kernel: k,
+            batch: b,
+            chan_in: c,
+            chan_out: o,
+            l_in,
+            l_out: (l_in + 2 * p - k) / s + 1,
+        }
+    }
+
+    pub(super) fn inp_patches_shape(&self) -> (usize, usize, usize) {
+        (self.chan_in, self.kernel, self.l_out)
+    }
+
+    pub(super) fn out_patches_shape(&self) -> (usize, usize, usize) {
+        (self.chan_out, self.kernel, self.l_in)
+    }
+
+    pub(super) fn filters_tr_shape(&self) -> (usize, usize, usize) {
+        (self.chan_in, self.chan_out, self.kernel)
+    }
+}
+
+pub(super) trait Conv1DKernel<E: Dtype>: DeviceStorage {
+    fn forward<L: Shape, R: Shape, O: Shape>(
+        &self,
+        op: Conv1DOp,
+        lhs: &Self::Storage<L, E>,
+        rhs: &Self::Storage<R, E>,
+        out: &mut Self::Storage<O, E>,
+    ) -> Result<(), Self::Err>;
+
+    fn backward<L: Shape, R: Shape, O: Shape>(
+        &self,
+        op: Conv1DOp,
+        lhs: &Self::Storage<L, E>,
+        grad_lhs: &mut Self::Storage<L, E>,
+        rhs: &Self::Storage<R, E>,
+        grad_rhs: &mut Self::Storage<R, E>,
+        grad_out: &Self::Storage<O, E>,
+    ) -> Result<(), Self::Err>;
+}
+
+pub trait TryConv1DTo<F, const S: usize, const P: usize>: HasErr {
+    type Output;
+    fn conv1d_to(self, filters: F) -> Self::Output {
+        self.try_conv1d_to(filters).unwrap()
+    }
+    fn try_conv1d_to(self, filters: F) -> Result<Self::Output, Self::Err>;
+}
+
+/// **Requires Nightly** 1d convolution with stride `S` and padding `P` over
+/// `(Chan, Length)` or `(Batch, Chan, Length)` inputs. Filters have shape `(ChanOut, ChanIn, K)`.
+///
+/// ```ignore
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let x: Tensor<Rank2<2, 10>, f32, _> = dev.zeros();
+/// let w: Tensor<Rank3<4, 2, 3>, f32, _> = dev.zeros();
+/// let y: Tensor<Rank2<4, 8>, f32, _> = x.conv1d::<1, 0>(w);
+/// ```
+pub trait TryConv1D<F> {
+    fn conv1d<const S: usize, const P: usize>(self, filters: F) -> Self::Output
+    where
+        Self: TryConv1DTo<F, S, P>,
+    {
+        self.conv1d_to(filters)
+    }
+    fn try_conv1d<const S: usize, const P: usize>(
+        self,
+        filters: F,
+    ) -> Result<Self::Output, Self::Err>
+    where
+        Self: TryConv1DTo<F, S, P>,
+    {
+        self.try_conv1d_to(filters)
+    }
+}
+
+impl<T, F> TryConv1D<F> for T {}
+
+impl<
+        const C: usize,
+        const L: usize,
+        const O: usize,
+        const K: usize,
+        const S: usize,
+        const P: usize,
+        E: Dtype,
+        D: Conv1DKernel<E> + ZerosTensor<E>,
+        T: 'static + Tape<D>,
+    > TryConv1DTo<Tensor<Rank3<O, C, K>, E, D>, S, P> for Tensor<Rank2<C, L>, E, D, T>
+where
+    Const<L>: ConvAlgebra<K, S, P>,
+{
+    type Output = Tensor<(Const<O>, <Const<L> as ConvAlgebra<K, S, P>>::Convolved), E, D, T>;
+
+    fn try_conv1d_to(
+        self,
+        filters: Tensor<Rank3<O, C, K>, E, D>,
+    ) -> Result<Self::Output, Self::Err> {
+        let op = Conv1DOp::new(S, P, K, [1, C, L], O);
+        let (lhs, ltape) = self.split_tape();
+        let (rhs, rtape) = filters.split_tape();
+        let mut tape = ltape.merge(rtape);
+        let mut out = lhs.device.try_zeros()?;
+        lhs.device
+            .forward(op, &lhs.storage, &rhs.storage, &mut out.storage)?;
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&lhs)?;
+        tape.try_alloc_grad(&rhs)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_lhs, grad_rhs, grad_out) = grads.muts_and_ref(&lhs, &rhs, &phantom_out);
+            lhs.device
+                .backward(op, &lhs.storage, grad_lhs, &rhs.storage, grad_rhs, grad_out)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+impl<
+        B: Dim,
+        const C: usize,
+        const L: usize,
+        const O: usize,
+        const K: usize,
+        const S: usize,
+        const P: usize,
+        E: Dtype,
+        D: Conv1DKernel<E> + ZerosTensor<E>,
+        T: 'static + Tape<D>,
+    > TryConv1DTo<Tensor<Rank3<O, C, K>, E, D>, S, P> for Tensor<(B, Const<C>, Const<L>), E, D, T>
+where
+    Const<L>: ConvAlgebra<K, S, P>,
+{
+    type Output = Tensor<(B, Const<O>, <Const<L> as ConvAlgebra<K, S, P>>::Convolved), E, D, T>;
+
+    fn try_conv1d_to(
+        self,
+        filters: Tensor<Rank3<O, C, K>, E, D>,
+    ) -> Result<Self::Output, Self::Err> {
+        let batch = self.shape().0;
+        let op = Conv1DOp::new(S, P, K, [batch.size(), C, L], O);
+        let (lhs, ltape) = self.split_tape();
+        let (rhs, rtape) = filters.split_tape();
+        let mut out: Tensor<(B, Const<O>, <Const<L> as ConvAlgebra<K, S, P>>::Convolved), E, D> =
+            lhs.device
+                .try_zeros_like(&(batch, Const::<O>, Default::default()))?;
+        let mut tape = ltape.merge(rtape);
+        lhs.device
+            .forward(op, &lhs.storage, &rhs.storage, &mut out.storage)?;
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&lhs)?;
+        tape.try_alloc_grad(&rhs)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_lhs, grad_rhs, grad_out) = grads.muts_and_ref(&lhs, &rhs, &phantom_out);
+            lhs.device
+                .backward(op, &lhs.storage, grad_lhs, &rhs.storage, grad_rhs, grad_out)?;
+            Ok(())
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_conv1d_default_stride_and_padding() {
+        let dev: TestDevice = Default::default();
+        let weight: Tensor<_, TestDtype, _> = dev.tensor([
+            [[0.1993, 0.1334], [0.5465, -0.9140]],
+            [[0.0050, -0.0647], [-1.5058, 0.5380]],
+            [[0.3207, 2.3891], [0.2030, -0.1447]],
+        ]);
+        let x: Tensor<_, TestDtype, _> = dev.tensor([
+            [1.2882, 1.4494, 0.0663, -0.7645],
+            [-1.0922, 0.0313, -1.0221, -1.4368],
+        ]);
+        let result = x.trace().conv1d::<1, 0>(weight.clone());
+        result.array().assert_close(
+            &[
+                [-0.17540728, 1.24901469, 0.66588684],
+                [1.57413898, -0.59406395, 0.81587443],
+                [3.64964157, 0.77747168, -1.80478588],
+            ],
+            1e-5,
+        );
+        let g = result.exp().mean().backward();
+        g.get(&x).array().assert_close(
+            &[
+                [1.39175246, 10.34245266, 0.67555262, 0.05626208],
+                [0.11091827, -0.24661603, -0.61252394, -0.06512733],
+            ],
+            1e-5,
+        );
+        g.get(&weight).array().assert_close(
+            &[
+                [[0.69598925, -0.00449850], [-0.31072984, -0.70378052]],
+                [[0.79641149, 0.58928923], [-0.84060428, -0.40689236]],
+                [[5.85667244, 6.19596615], [-4.67855652, -0.13962162]],
+            ],
+            1e-5,
+        );
+    }
+
+    #[test]
+    fn test_conv1d_stride_2_padding_1() {
+        let dev: TestDevice = Default::default();
+        let weight: Tensor<_, TestDtype, _> =
+            dev.tensor([[[0.0313, -1.0221, -1.4368]], [[0.1993, 0.1334, 0.5465]]]);
+        let x: Tensor<_, TestDtype, _> = dev.tensor([[1.2882, 1.4494, 0.0663, -0.7645, -1.0922]]);
+        let result = x.trace().conv1d::<2, 1>(weight.clone());
+        assert_close(
+            &result.array(),
+            &[
+                [-3.39916714, 1.07603459, 1.09240877],
+                [0.96394298, -0.12008941, -0.29806433],
+            ],
+        );
+        let g = result.exp().mean().backward();
+        assert_close(
+            &g.get(&x).array(),
+            &[[
+                0.05260625,
+                0.27558192,
+                -0.47992351,
+                -0.58137706,
+                -0.49138673,
+            ]],
+        );
+        assert_close(
+            &g.get(&weight).array(),
+            &[
+                [[0.32863522, -0.50314160, -0.36564779]],
+                [[0.11965578, 0.43763125, 0.52039300]],
+            ],
+        );
+    }
+
+    #[test]
+    fn test_batched_conv1d() {
+        let dev: TestDevice = Default::default();
+        let weight: Tensor<_, TestDtype, _> = dev.tensor([
+            [[0.0050, -0.0647], [-1.5058, 0.5380]],
+            [[0.3207, 2.3891], [0.2030, -0.1447]],
+        ]);
+        let x: Tensor<_, TestDtype, _> = dev.tensor([
+            [[1.2882, 1.4494, 0.0663], [-0.7645, -1.0922, 0.0313]],
+            [[-1.0221, -1.4368, 0.1993], [0.1334, 0.5465, -0.9140]],
+        ]);
+        let result = x.trace().conv1d::<1, 1>(weight.clone());
+
+        #[rustfmt::skip]
+        result.array().assert_close(
+            &[
+                [[-0.49464754, 0.47624532, 1.66443155, -0.04680004], [3.18826177, 3.87873512, 0.39697420, 0.02761631]],
+                [[0.13789907, 0.18099374, -1.33473041, 1.37729770], [-2.46120209, -3.81244470, 0.25856117, -0.12162649]],
+            ],
+            1e-5,
+        );
+        let g = result.exp().mean().backward();
+
+        #[rustfmt::skip]
+        g.get(&x).array().assert_close(
+            &[
+                [[4.58783228, 7.24645490, 0.22162581], [0.26330985, -0.86154094, 0.08741209]],
+                [[0.00891741, 0.02449323, 0.21130023], [-0.07467977, 0.03175463, -0.36470505]],
+            ],
+            1e-5,
+        );
+        g.get(&weight).array().assert_close(
+            &[
+                [[0.56130967, 0.03916807], [-0.64314093, -0.09323953]],
+                [[3.92613992, 6.34806890], [-2.41652593, -4.52950033]],
+            ],
+            1e-5,
+        );
+    }
+
+    #[test]
+    fn test_batched_conv1d_runtime_batch_stride_2() {
+        let dev: TestDevice = Default::default();
+        let weight: Tensor<_, TestDtype, _> =
+            dev.tensor([[[0.4352, -1.1208, 0.2873]], [[-0.6931, 0.0542, 0.9815]]]);
+        #[rustfmt::skip]
+        let x: Tensor<(usize, Const<1>, Const<5>), TestDtype, _> = dev.tensor_from_vec(
+            std::vec![
+                0.3311, -0.8724, 1.2057, 0.0468, -0.5513,
+                -1.4102, 0.6635, 0.2219, -0.3378, 0.9046,
+                0.7783, 0.1529, -0.9967, 1.5126, -0.2641,
+            ],
+            (3, Const, Const),
+        );
+        let result: Tensor<(usize, Const<2>, Const<3>), _, _, _> =
+            x.trace().conv1d::<2, 1>(weight.clone());
+        assert_eq!(result.shape(), &(3, Const, Const));
+
+        #[rustfmt::skip]
+        result.as_vec().assert_close(
+            &std::vec![
+                -0.62173740, -1.71757140, 0.63826440, -0.83831498, 0.71594358, -0.06231754,
+                1.77117571, -0.05700026, -1.16088624, 0.57479241, -0.77939557, 0.28315850,
+                -0.82839047, 1.61821342, 0.95428680, 0.19225521, 1.32462077, -1.06269728,
+            ],
+            1e-5,
+        );
+        let g = result.exp().mean().backward();
+
+        #[rustfmt::skip]
+        g.get(&x).as_vec().assert_close(
+            &std::vec![
+                -0.03213574, -0.04229570, -0.00501588, 0.12402918, -0.11505357,
+                -0.36063849, 0.19587477, -0.05743556, -0.00344860, -0.01550575,
+                -0.02354567, 0.05020191, -0.30275340, 0.33505554, -0.16065479,
+            ],
+            1e-5,
+        );
+        g.get(&weight).array().assert_close(
+            &[
+                [[0.28622920, -0.76770510, 0.60095350]],
+                [[-0.04374627, -0.11151360, 0.36757070]],
+            ],
+            1e-5,
+        );
+    }
+}
@@ -1,7 +1,9 @@
 struct Conv2DOp {
     size_t stride;
     size_t padding;
+    size_t dilation;
     size_t kernel;
+    size_t groups;
     size_t batch;
     size_t chan_in;
     size_t chan_out;
@@ -39,7 +41,7 @@ __device__ void unfold_input_into_patches(
     const size_t b = idx % op.batch;
     idx /= op.batch;
 
-    const size_t y_plus_p = oh * op.stride + k1;
+    const size_t y_plus_p = oh * op.stride + k1 * op.dilation;
     if (y_plus_p < op.padding) {
         return;
     }
@@ -48,7 +50,7 @@ __device__ void unfold_input_into_patches(
         return;
     }
 
-    const size_t x_plus_p = ow * op.stride + k2;
+    const size_t x_plus_p = ow * op.stride + k2 * op.dilation;
     if (x_plus_p < op.padding) {
         return;
     }
@@ -88,10 +90,10 @@ __device__ void unfold_output_into_patches(
     idx /= op.batch;
 
     size_t oh = y + op.padding;
-    if (oh < k1) {
+    if (oh < k1 * op.dilation) {
         return;
     }
-    oh -= k1;
+    oh -= k1 * op.dilation;
     if (oh % op.stride != 0) {
         return;
     }
@@ -101,10 +103,10 @@ __device__ void unfold_output_into_patches(
     }
     
     size_t ow = x + op.padding;
-    if (ow < k2) {
+    if (ow < k2 * op.dilation) {
         return;
     }
-    ow -= k2;
+    ow -= k2 * op.dilation;
     if (ow % op.stride != 0) {
         return;
     }
@@ -120,27 +122,34 @@ __device__ void unfold_output_into_patches(
 template<typename T>
 __device__ void transpose_and_broadcast_filters(
     const Conv2DOp op,
-    const T *filters, // 4d (ChanOut, ChanIn, KernelSize, KernelSize)
+    const T *filters, // 4d (ChanOut, ChanIn / Groups, KernelSize, KernelSize)
     const size_t *strides, // 4d filters strides
-    T *filters_tr // 5d (Batch, ChanIn, ChanOut, KernelSize, KernelSize)
+    T *filters_tr // 5d (Batch, ChanIn, ChanOut / Groups, KernelSize, KernelSize)
 ) {
     unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
-    auto numel = op.chan_in * op.chan_out * op.kernel * op.kernel;
+    auto numel = (op.chan_in / op.groups) * op.chan_out * op.kernel * op.kernel;
     if (i >= numel) {
         return;
     }
 
+    const size_t chan_in_g = op.chan_in / op.groups;
+    const size_t chan_out_g = op.chan_out / op.groups;
+
     unsigned int idx = i;
     const size_t k2 = idx % op.kernel;
     idx /= op.kernel;
     const size_t k1 = idx % op.kernel;
     idx /= op.kernel;
-    const size_t c = idx % op.chan_in;
-    idx /= op.chan_in;
+    const size_t c = idx % chan_in_g;
+    idx /= chan_in_g;
     const size_t o = idx % op.chan_out;
     idx /= op.chan_out;
 
-    auto i_tr = c * (op.chan_out * op.kernel * op.kernel) + o * (op.kernel * op.kernel) + k1 * (op.kernel) + k2;
+    const size_t g = o / chan_out_g;
+    const size_t o_g = o % chan_out_g;
+    const size_t c_tr = g * chan_in_g + c;
+
+    auto i_tr = c_tr * (chan_out_g * op.kernel * op.kernel) + o_g * (op.kernel * op.kernel) + k1 * (op.kernel) + k2;
     auto i_no = o * strides[0] + c * strides[1] + k1 * strides[2] + k2 * strides[3];
 
     const T f = filters[i_no];
@@ -152,27 +161,34 @@ __device__ void transpose_and_broadcast_filters(
 template<typename T>
 __device__ void sum_transposed_filters(
     const Conv2DOp op,
-    const T *filters_tr, // 5d (Batch, ChanIn, ChanOut, KernelSize, KernelSize)
-    T *filters, // 4d (ChanOut, ChanIn, KernelSize, KernelSize)
+    const T *filters_tr, // 5d (Batch, ChanIn, ChanOut / Groups, KernelSize, KernelSize)
+    T *filters, // 4d (ChanOut, ChanIn / Groups, KernelSize, KernelSize)
     const size_t *strides // 4d filter strides
 ) {
     unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
-    auto numel = op.chan_out * op.chan_in * op.kernel * op.kernel;
+    auto numel = op.chan_out * (op.chan_in / op.groups) * op.kernel * op.kernel;
     if (i >= numel) {
         return;
     }
 
+    const size_t chan_in_g = op.chan_in / op.groups;
+    const size_t chan_out_g = op.chan_out / op.groups;
+
     unsigned int idx = i;
     const size_t k2 = idx % op.kernel;
     idx /= op.kernel;
     const size_t k1 = idx % op.kernel;
     idx /= op.kernel;
-    const size_t c = idx % op.chan_in;
-    idx /= op.chan_in;
+    const size_t c = idx % chan_in_g;
+    idx /= chan_in_g;
     const size_t o = idx % op.chan_out;
     idx /= op.chan_out;
 
-    auto i_tr = c * (op.chan_out * op.kernel * op.kernel) + o * (op.kernel * op.kernel) + k1 * (op.kernel) + k2;
+    const size_t g = o / chan_out_g;
+    const size_t o_g = o % chan_out_g;
+    const size_t c_tr = g * chan_in_g + c;
+
+    auto i_tr = c_tr * (chan_out_g * op.kernel * op.kernel) + o_g * (op.kernel * op.kernel) + k1 * (op.kernel) + k2;
     auto i_no = o * strides[0] + c * strides[1] + k1 * strides[2] + k2 * strides[3];
 
     T tmp = 0.0;
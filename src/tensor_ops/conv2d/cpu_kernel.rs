@@ -1,19 +1,21 @@
 use crate::shapes::{Dtype, Shape};
 use crate::tensor::cpu::*;
+use crate::tensor_ops::fft::cpu_kernel::{dft2, mul, Complex};
 use crate::tensor_ops::matmul::cpu_kernel::MatMulImpl;
 
-use super::{Conv2DKernel, Conv2DOp};
+use super::{Conv2DAlgorithm, Conv2DKernel, Conv2DOp};
 
-use std::sync::Arc;
+use num_traits::{Float, Signed};
+use std::{sync::Arc, vec::Vec};
 
 impl Conv2DOp {
     #[inline(always)]
     fn unfold_idx(&self, [k1, k2, y, x]: [usize; 4]) -> Option<[usize; 2]> {
         let mut oh = y + self.padding;
-        if oh < k1 {
+        if oh < k1 * self.dilation {
             return None;
         }
-        oh -= k1;
+        oh -= k1 * self.dilation;
         if oh % self.stride != 0 {
             return None;
         }
@@ -23,10 +25,10 @@ impl Conv2DOp {
         }
 
         let mut ow = x + self.padding;
-        if ow < k2 {
+        if ow < k2 * self.dilation {
             return None;
         }
-        ow -= k2;
+        ow -= k2 * self.dilation;
         if ow % self.stride != 0 {
             return None;
         }
@@ -39,7 +41,158 @@ impl Conv2DOp {
     }
 }
 
+/// The spectrum of a row major `(h, w)` grid of `values`, with value `[y, x]` placed
+/// at `[y * step + offset, x * step + offset]` in the fft grid.
+fn spectrum<E: Dtype + Float + Signed>(
+    (nh, nw): (usize, usize),
+    (h, w): (usize, usize),
+    step: usize,
+    offset: usize,
+    values: &[E],
+) -> Vec<Complex<E>> {
+    let mut z = std::vec![(E::zero(), E::zero()); nh * nw];
+    for y in 0..h {
+        for x in 0..w {
+            z[(y * step + offset) * nw + x * step + offset].0 = values[y * w + x];
+        }
+    }
+    dft2(&mut z, nh, nw, false);
+    z
+}
+
+/// `acc += a * b`, or `acc += a * conj(b)` when `conj` is set
+fn mul_add<E: Float>(acc: &mut [Complex<E>], a: &[Complex<E>], b: &[Complex<E>], conj: bool) {
+    for ((acc, &a), &b) in acc.iter_mut().zip(a.iter()).zip(b.iter()) {
+        let b = if conj { (b.0, -b.1) } else { b };
+        let v = mul(a, b);
+        acc.0 = acc.0 + v.0;
+        acc.1 = acc.1 + v.1;
+    }
+}
+
 impl Cpu {
+    /// Spectra of the dilated filters, indexed by `o * (ChanIn / Groups) + c`.
+    fn conv2d_filter_spectra<E: Dtype + Float + Signed>(
+        &self,
+        op: &Conv2DOp,
+        filters: &[E],
+    ) -> Vec<Vec<Complex<E>>> {
+        let k2 = op.kernel * op.kernel;
+        (0..op.chan_out * (op.chan_in / op.groups))
+            .map(|i| {
+                let f = &filters[i * k2..(i + 1) * k2];
+                spectrum(op.fft_shape(), (op.kernel, op.kernel), op.dilation, 0, f)
+            })
+            .collect()
+    }
+
+    /// Spectra of each padded channel of the image.
+    fn conv2d_img_spectra<E: Dtype + Float + Signed>(
+        &self,
+        op: &Conv2DOp,
+        img: &[E],
+    ) -> Vec<Vec<Complex<E>>> {
+        let hw = op.h_in * op.w_in;
+        (0..op.chan_in)
+            .map(|c| {
+                let v = &img[c * hw..(c + 1) * hw];
+                spectrum(op.fft_shape(), (op.h_in, op.w_in), 1, op.padding, v)
+            })
+            .collect()
+    }
+
+    /// Cross correlates the image with the filters in the frequency domain:
+    /// `out[o] = ifft(sum_c fft(img[c]) * conj(fft(filters[o, c])))`, keeping every
+    /// `stride`th value.
+    fn conv2d_fft_forward<E: Dtype + Float + Signed>(
+        &self,
+        op: &Conv2DOp,
+        img: &[E],
+        filter_spectra: &[Vec<Complex<E>>],
+        out: &mut [E],
+    ) {
+        let (nh, nw) = op.fft_shape();
+        let scale = E::one() / E::from(nh * nw).unwrap();
+        let chan_in_g = op.chan_in / op.groups;
+        let chan_out_g = op.chan_out / op.groups;
+        let img_spectra = self.conv2d_img_spectra(op, img);
+        let mut acc = std::vec![(E::zero(), E::zero()); nh * nw];
+        for o in 0..op.chan_out {
+            let g = o / chan_out_g;
+            acc.fill((E::zero(), E::zero()));
+            for c in 0..chan_in_g {
+                let f = &filter_spectra[o * chan_in_g + c];
+                mul_add(&mut acc, &img_spectra[g * chan_in_g + c], f, true);
+            }
+            dft2(&mut acc, nh, nw, true);
+            for oh in 0..op.h_out {
+                for ow in 0..op.w_out {
+                    let v = acc[oh * op.stride * nw + ow * op.stride].0 * scale;
+                    let o_i = o * (op.h_out * op.w_out) + oh * op.w_out + ow;
+                    out[o_i] += v;
+                }
+            }
+        }
+    }
+
+    /// The gradient of the image is the convolution of `grad_out` (spread out by
+    /// `stride`) with the filters. The cross correlation of the image with `grad_out`
+    /// is accumulated into `grad_filter_spectra`, and only transformed back once every
+    /// image in the batch has been added.
+    #[allow(clippy::too_many_arguments)]
+    fn conv2d_fft_backward<E: Dtype + Float + Signed>(
+        &self,
+        op: &Conv2DOp,
+        img: &[E],
+        grad_img: &mut [E],
+        filter_spectra: &[Vec<Complex<E>>],
+        grad_filter_spectra: &mut [Vec<Complex<E>>],
+        grad_out: &[E],
+    ) {
+        let (nh, nw) = op.fft_shape();
+        let scale = E::one() / E::from(nh * nw).unwrap();
+        let chan_in_g = op.chan_in / op.groups;
+        let chan_out_g = op.chan_out / op.groups;
+        let img_spectra = self.conv2d_img_spectra(op, img);
+        let ohw = op.h_out * op.w_out;
+        let grad_out_spectra: Vec<_> = (0..op.chan_out)
+            .map(|o| {
+                let v = &grad_out[o * ohw..(o + 1) * ohw];
+                spectrum((nh, nw), (op.h_out, op.w_out), op.stride, 0, v)
+            })
+            .collect();
+
+        let mut acc = std::vec![(E::zero(), E::zero()); nh * nw];
+        for c in 0..op.chan_in {
+            let (g, c_g) = (c / chan_in_g, c % chan_in_g);
+            acc.fill((E::zero(), E::zero()));
+            for o in g * chan_out_g..(g + 1) * chan_out_g {
+                let f = &filter_spectra[o * chan_in_g + c_g];
+                mul_add(&mut acc, &grad_out_spectra[o], f, false);
+            }
+            dft2(&mut acc, nh, nw, true);
+            for y in 0..op.h_in {
+                for x in 0..op.w_in {
+                    let v = acc[(y + op.padding) * nw + x + op.padding].0 * scale;
+                    let i = c * (op.h_in * op.w_in) + y * op.w_in + x;
+                    grad_img[i] += v;
+                }
+            }
+        }
+
+        for o in 0..op.chan_out {
+            let g = o / chan_out_g;
+            for c in 0..chan_in_g {
+                mul_add(
+                    &mut grad_filter_spectra[o * chan_in_g + c],
+                    &img_spectra[g * chan_in_g + c],
+                    &grad_out_spectra[o],
+                    true,
+                );
+            }
+        }
+    }
+
     #[inline]
     fn conv2d_forward<E: Dtype, P: Shape<Concrete = [usize; 5]>>(
         &self,
@@ -60,8 +213,10 @@ impl Cpu {
                     for k2 in 0..op.kernel {
                         for oh in 0..op.h_out {
                             for ow in 0..op.w_out {
-                                let y = (oh * op.stride + k1).wrapping_sub(op.padding);
-                                let x = (ow * op.stride + k2).wrapping_sub(op.padding);
+                                let y =
+                                    (oh * op.stride + k1 * op.dilation).wrapping_sub(op.padding);
+                                let x =
+                                    (ow * op.stride + k2 * op.dilation).wrapping_sub(op.padding);
                                 if y < op.h_in && x < op.w_in {
                                     buf[i] = img[c * (op.w_in * op.h_in) + y * op.w_in + x];
                                 }
@@ -73,15 +228,19 @@ impl Cpu {
             }
         }
 
-        // (O, C * K * K) * (C * K * K, OH * OW) = (O, OH * OW)
-        let m = op.chan_out;
-        let k = op.chan_in * op.kernel * op.kernel;
+        // for each group g:
+        // (O / G, C / G * K * K) * (C / G * K * K, OH * OW) = (O / G, OH * OW)
+        let m = op.chan_out / op.groups;
+        let k = (op.chan_in / op.groups) * op.kernel * op.kernel;
         let n = op.w_out * op.h_out;
-        Self::matmul(
-            View::new(filters, (m, k)),
-            View::new(inp_patches_buf.view().data, (k, n)),
-            &mut ViewMut::new(out, (m, n)),
-        );
+        let patches = inp_patches_buf.view().data;
+        for g in 0..op.groups {
+            Self::matmul(
+                View::new(&filters[g * m * k..], (m, k)),
+                View::new(&patches[g * k * n..], (k, n)),
+                &mut ViewMut::new(&mut out[g * m * n..], (m, n)),
+            );
+        }
         Ok(())
     }
 
@@ -120,46 +279,58 @@ impl Cpu {
             }
         }
 
+        let patches = out_patches_buf.view().data;
+
         {
             // img_g += filters^T * unfold(grad_out)
-            // (C, H * W) += (C, O * K * K) * (O * K * K, H * W)
-            let m = op.chan_in;
-            let k = op.chan_out * op.kernel * op.kernel;
+            // for each group g:
+            // (C / G, H * W) += (C / G, O / G * K * K) * (O / G * K * K, H * W)
+            let m = op.chan_in / op.groups;
+            let k = (op.chan_out / op.groups) * op.kernel * op.kernel;
             let n = op.h_in * op.w_in;
-            Self::matmul(
-                View::new(filters_tr, (m, k)),
-                View::new(out_patches_buf.view().data, (k, n)),
-                &mut ViewMut::new(grad_img, (m, n)),
-            );
+            for g in 0..op.groups {
+                Self::matmul(
+                    View::new(&filters_tr[g * m * k..], (m, k)),
+                    View::new(&patches[g * k * n..], (k, n)),
+                    &mut ViewMut::new(&mut grad_img[g * m * n..], (m, n)),
+                );
+            }
         }
 
         {
             // weight_g^T += img * patches^T
-            // (C, O * K * K) += (C, H * W) * (H * W, O * K * K)
-            let m = op.chan_in;
+            // for each group g:
+            // (C / G, O / G * K * K) += (C / G, H * W) * (H * W, O / G * K * K)
+            let m = op.chan_in / op.groups;
             let k = op.h_in * op.w_in;
-            let n = op.chan_out * op.kernel * op.kernel;
-            Self::matmul(
-                View::new(img, (m, k)),
-                View::new(out_patches_buf.view().data, (n, k)).tr(),
-                &mut ViewMut::new(grad_filters_tr, (m, n)),
-            );
+            let n = (op.chan_out / op.groups) * op.kernel * op.kernel;
+            for g in 0..op.groups {
+                Self::matmul(
+                    View::new(&img[g * m * k..], (m, k)),
+                    View::new(&patches[g * n * k..], (n, k)).tr(),
+                    &mut ViewMut::new(&mut grad_filters_tr[g * m * n..], (m, n)),
+                );
+            }
         }
         Ok(())
     }
 }
 
-impl<E: Dtype> Conv2DKernel<E> for Cpu
+impl<E: Dtype + Float + Signed> Conv2DKernel<E> for Cpu
 where
     Self: MatMulImpl<E>,
 {
     fn forward<L: Shape, R: Shape, O: Shape>(
         &self,
         op: Conv2DOp,
+        algorithm: Conv2DAlgorithm,
         lhs: &Self::Storage<L, E>,
         rhs: &Self::Storage<R, E>,
         out: &mut Self::Storage<O, E>,
     ) -> Result<(), Self::Err> {
+        if op.use_fft(algorithm) {
+            return self.fft_forward(op, lhs, rhs, out);
+        }
         let mut patches: StridedArray<_, E> = StridedArray::new(op.inp_patches_shape())?;
         let [lstride, ostride] = match L::NUM_DIMS {
             3 => [0; 2],
@@ -184,22 +355,29 @@ where
     fn backward<L: Shape, R: Shape, O: Shape>(
         &self,
         op: Conv2DOp,
+        algorithm: Conv2DAlgorithm,
         lhs: &Self::Storage<L, E>,
         grad_lhs: &mut Self::Storage<L, E>,
         rhs: &Self::Storage<R, E>,
         grad_rhs: &mut Self::Storage<R, E>,
         grad_out: &Self::Storage<O, E>,
     ) -> Result<(), Self::Err> {
+        if op.use_fft(algorithm) {
+            return self.fft_backward(op, lhs, grad_lhs, rhs, grad_rhs, grad_out);
+        }
         let mut patches: StridedArray<_, E> = StridedArray::new(op.out_patches_shape())?;
         let mut f1023: StridedArray<_, E> = StridedArray::new(op.filters_tr_shape())?;
         let mut grad_f1023: StridedArray<_, E> = StridedArray::new(op.filters_tr_shape())?;
+        let chan_in_g = op.chan_in / op.groups;
+        let chan_out_g = op.chan_out / op.groups;
 
         {
-            // transpose filters in f1023
+            // transpose filters in f1023 within each group
             let buf = rhs.data.as_ref();
             let mut f_iter = f1023.iter_mut_with_index();
             while let Some((f, [c, o, k1, k2])) = f_iter.next() {
-                let idx = o * rhs.strides[0]
+                let (g, c) = (c / chan_in_g, c % chan_in_g);
+                let idx = (g * chan_out_g + o) * rhs.strides[0]
                     + c * rhs.strides[1]
                     + k1 * rhs.strides[2]
                     + k2 * rhs.strides[3];
@@ -235,7 +413,8 @@ where
             let buf = Arc::make_mut(&mut grad_rhs.data);
             let mut f_iter = grad_f1023.iter_with_index();
             while let Some((f, [c, o, k1, k2])) = f_iter.next() {
-                let idx = o * rhs.strides[0]
+                let (g, c) = (c / chan_in_g, c % chan_in_g);
+                let idx = (g * chan_out_g + o) * rhs.strides[0]
                     + c * rhs.strides[1]
                     + k1 * rhs.strides[2]
                     + k2 * rhs.strides[3];
@@ -246,3 +425,85 @@ where
         Ok(())
     }
 }
+
+impl Cpu {
+    fn fft_forward<E: Dtype + Float + Signed, L: Shape, R: Shape, O: Shape>(
+        &self,
+        op: Conv2DOp,
+        lhs: &StridedArray<L, E>,
+        rhs: &StridedArray<R, E>,
+        out: &mut StridedArray<O, E>,
+    ) -> Result<(), CpuError> {
+        let [lstride, ostride] = match L::NUM_DIMS {
+            3 => [0; 2],
+            4 => [lhs.strides[0], out.strides[0]],
+            _ => unreachable!(),
+        };
+        let filter_spectra = self.conv2d_filter_spectra(&op, rhs.data.as_ref());
+        let lhs = lhs.data.as_ref();
+        let out = Arc::make_mut(&mut out.data);
+        for i_batch in 0..op.batch {
+            self.conv2d_fft_forward(
+                &op,
+                &lhs[i_batch * lstride..],
+                &filter_spectra,
+                &mut out[i_batch * ostride..],
+            );
+        }
+        Ok(())
+    }
+
+    fn fft_backward<E: Dtype + Float + Signed, L: Shape, R: Shape, O: Shape>(
+        &self,
+        op: Conv2DOp,
+        lhs: &StridedArray<L, E>,
+        grad_lhs: &mut StridedArray<L, E>,
+        rhs: &StridedArray<R, E>,
+        grad_rhs: &mut StridedArray<R, E>,
+        grad_out: &StridedArray<O, E>,
+    ) -> Result<(), CpuError> {
+        let [lstride, ostride] = match L::NUM_DIMS {
+            3 => [0; 2],
+            4 => [lhs.strides[0], grad_out.strides[0]],
+            _ => unreachable!(),
+        };
+        let (nh, nw) = op.fft_shape();
+        let filter_spectra = self.conv2d_filter_spectra(&op, rhs.data.as_ref());
+        let mut grad_filter_spectra =
+            std::vec![std::vec![(E::zero(), E::zero()); nh * nw]; filter_spectra.len()];
+
+        let lhs = lhs.data.as_ref();
+        let grad_lhs = Arc::make_mut(&mut grad_lhs.data);
+        let grad_out = grad_out.data.as_ref();
+        for i_batch in 0..op.batch {
+            self.conv2d_fft_backward(
+                &op,
+                &lhs[i_batch * lstride..],
+                &mut grad_lhs[i_batch * lstride..],
+                &filter_spectra,
+                &mut grad_filter_spectra,
+                &grad_out[i_batch * ostride..],
+            );
+        }
+
+        // the filter gradients are at the dilated kernel offsets of the cross correlation
+        let scale = E::one() / E::from(nh * nw).unwrap();
+        let chan_in_g = op.chan_in / op.groups;
+        let buf = Arc::make_mut(&mut grad_rhs.data);
+        for (i, spectrum) in grad_filter_spectra.iter_mut().enumerate() {
+            let (o, c) = (i / chan_in_g, i % chan_in_g);
+            dft2(spectrum, nh, nw, true);
+            for k1 in 0..op.kernel {
+                for k2 in 0..op.kernel {
+                    let idx = o * rhs.strides[0]
+                        + c * rhs.strides[1]
+                        + k1 * rhs.strides[2]
+                        + k2 * rhs.strides[3];
+                    let v = spectrum[k1 * op.dilation * nw + k2 * op.dilation].0 * scale;
+                    buf[idx] += v;
+                }
+            }
+        }
+        Ok(())
+    }
+}
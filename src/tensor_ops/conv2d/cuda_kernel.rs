@@ -2,7 +2,10 @@ use cudarc::cublas::{CudaBlas, Gemm};
 use cudarc::driver::{AsKernelParam, LaunchAsync, LaunchConfig, ValidAsZeroBits};
 
 use crate::tensor_ops::matmul::cuda_kernel::sgemm_batch;
-use crate::{shapes::*, tensor::cuda::Cuda};
+use crate::{
+    shapes::*,
+    tensor::cuda::{Cuda, CudaError},
+};
 
 use std::sync::Arc;
 
@@ -43,6 +46,17 @@ fn make_4d<S: Shape>(strides: S::Concrete) -> [usize; 4] {
     }
 }
 
+/// Only im2col is implemented, so [super::Conv2DAlgorithm::Auto] always picks it, and
+/// asking for [super::Conv2DAlgorithm::Fft] is an error.
+fn check_algorithm(algorithm: super::Conv2DAlgorithm) -> Result<(), CudaError> {
+    match algorithm {
+        super::Conv2DAlgorithm::Fft => Err(CudaError::Unsupported(
+            "Conv2DAlgorithm::Fft isn't implemented on cuda",
+        )),
+        _ => Ok(()),
+    }
+}
+
 impl<E: Dtype + ValidAsZeroBits> super::Conv2DKernel<E> for Cuda
 where
     Self: HasCudaKernel<E>,
@@ -51,10 +65,12 @@ where
     fn forward<L: Shape, R: Shape, O: Shape>(
         &self,
         op: super::Conv2DOp,
+        algorithm: super::Conv2DAlgorithm,
         lhs: &Self::Storage<L, E>,
         rhs: &Self::Storage<R, E>,
         out: &mut Self::Storage<O, E>,
     ) -> Result<(), Self::Err> {
+        check_algorithm(algorithm)?;
         if !self.dev.has_func(Self::MOD, Self::FNS[0]) {
             self.dev.load_ptx(PTX_SRC.into(), Self::MOD, Self::FNS)?;
         }
@@ -67,23 +83,27 @@ where
         let params = (op, lhs.data.as_ref(), &img_strides, &mut patches);
         unsafe { unfold_fn.launch_async(cfg, params) }?;
 
-        // (O, C * K * K) * (B, C * K * K, OH * OW) = (B, O, OH * OW)
-        let m = op.chan_out;
-        let k = op.chan_in * op.kernel * op.kernel;
+        // for each group g:
+        // (O / G, C / G * K * K) * (B, C / G * K * K, OH * OW) = (B, O / G, OH * OW)
+        let m = op.chan_out / op.groups;
+        let k = (op.chan_in / op.groups) * op.kernel * op.kernel;
         let n = op.h_out * op.w_out;
-        unsafe {
-            sgemm_batch(
-                self.blas.as_ref(),
-                (op.batch, m, k, n),
-                rhs.data.as_ref(),
-                [0, k, 1],
-                &patches,
-                [k * n, n, 1],
-                Default::default(),
-                Arc::make_mut(&mut out.data),
-                [m * n, n, 1],
-            )
-            .unwrap();
+        let out = Arc::make_mut(&mut out.data);
+        for g in 0..op.groups {
+            unsafe {
+                sgemm_batch(
+                    self.blas.as_ref(),
+                    (op.batch, m, k, n),
+                    &rhs.data.try_slice(g * m * k..).unwrap(),
+                    [0, k, 1],
+                    &patches.try_slice(g * k * n..).unwrap(),
+                    [op.groups * k * n, n, 1],
+                    Default::default(),
+                    &mut out.try_slice_mut(g * m * n..).unwrap(),
+                    [op.groups * m * n, n, 1],
+                )
+                .unwrap();
+            }
         }
 
         Ok(())
@@ -92,12 +112,14 @@ where
     fn backward<L: Shape, R: Shape, O: Shape>(
         &self,
         op: super::Conv2DOp,
+        algorithm: super::Conv2DAlgorithm,
         lhs: &Self::Storage<L, E>,
         grad_lhs: &mut Self::Storage<L, E>,
         rhs: &Self::Storage<R, E>,
         grad_rhs: &mut Self::Storage<R, E>,
         grad_out: &Self::Storage<O, E>,
     ) -> Result<(), Self::Err> {
+        check_algorithm(algorithm)?;
         let patches_numel = op.batch * op.chan_out * op.kernel * op.kernel * op.h_in * op.w_in;
         let mut patches = self.dev.alloc_zeros_async::<E>(patches_numel)?;
 
@@ -109,14 +131,14 @@ where
             unsafe { unfold_fn.launch_async(cfg, params) }?;
         }
 
-        let filters_numel = op.batch * op.chan_in * op.chan_out * op.kernel * op.kernel;
+        let filters_numel = op.batch * rhs.shape.num_elements();
         let mut f_b1023 = self.dev.alloc_zeros_async::<E>(filters_numel)?;
         let mut grad_f_b1023 = self.dev.alloc_zeros_async::<E>(filters_numel)?;
         let f_strides = self.dev.take_async(rhs.strides.into())?;
 
         {
             // prepare filters for backward operations by
-            // swapping dims 0 and 1 and adding a batch dimension
+            // swapping dims 0 and 1 within each group and adding a batch dimension
             let tr_fn = self.dev.get_func(Self::MOD, Self::FNS[2]).unwrap();
             let cfg = LaunchConfig::for_num_elems(rhs.shape.num_elements() as u32);
             let params = (op, rhs.data.as_ref(), &f_strides, &mut f_b1023);
@@ -125,45 +147,52 @@ where
 
         {
             // img_g += filters * patches
-            // (B, C, H * W) += (B, C, O * K * K) * (B, O * K * K, H * W)
-            let m = op.chan_in;
-            let k = op.chan_out * op.kernel * op.kernel;
+            // for each group g:
+            // (B, C / G, H * W) += (B, C / G, O / G * K * K) * (B, O / G * K * K, H * W)
+            let m = op.chan_in / op.groups;
+            let k = (op.chan_out / op.groups) * op.kernel * op.kernel;
             let n = op.h_in * op.w_in;
-            unsafe {
-                sgemm_batch(
-                    self.blas.as_ref(),
-                    (op.batch, m, k, n),
-                    &f_b1023,
-                    [m * k, k, 1],
-                    &patches,
-                    [k * n, n, 1],
-                    <E>::ONE,
-                    Arc::make_mut(&mut grad_lhs.data),
-                    [m * n, n, 1],
-                )
-                .unwrap();
+            let grad_lhs = Arc::make_mut(&mut grad_lhs.data);
+            for g in 0..op.groups {
+                unsafe {
+                    sgemm_batch(
+                        self.blas.as_ref(),
+                        (op.batch, m, k, n),
+                        &f_b1023.try_slice(g * m * k..).unwrap(),
+                        [op.groups * m * k, k, 1],
+                        &patches.try_slice(g * k * n..).unwrap(),
+                        [op.groups * k * n, n, 1],
+                        <E>::ONE,
+                        &mut grad_lhs.try_slice_mut(g * m * n..).unwrap(),
+                        [op.groups * m * n, n, 1],
+                    )
+                    .unwrap();
+                }
             }
         }
 
         {
             // weight_g += img * patches^T
-            // (B, C, O * K * K) += (B, C, H * W) * (B, H * W, O * K * K)
-            let m = op.chan_in;
+            // for each group g:
+            // (B, C / G, O / G * K * K) += (B, C / G, H * W) * (B, H * W, O / G * K * K)
+            let m = op.chan_in / op.groups;
             let k = op.h_in * op.w_in;
-            let n = op.chan_out * op.kernel * op.kernel;
-            unsafe {
-                sgemm_batch(
-                    self.blas.as_ref(),
-                    (op.batch, m, k, n),
-                    lhs.data.as_ref(),
-                    [m * k, k, 1],
-                    &patches,
-                    [k * n, 1, k],
-                    <E>::ONE,
-                    &mut grad_f_b1023,
-                    [m * n, n, 1],
-                )
-                .unwrap();
+            let n = (op.chan_out / op.groups) * op.kernel * op.kernel;
+            for g in 0..op.groups {
+                unsafe {
+                    sgemm_batch(
+                        self.blas.as_ref(),
+                        (op.batch, m, k, n),
+                        &lhs.data.try_slice(g * m * k..).unwrap(),
+                        [op.groups * m * k, k, 1],
+                        &patches.try_slice(g * k * n..).unwrap(),
+                        [op.groups * k * n, 1, k],
+                        <E>::ONE,
+                        &mut grad_f_b1023.try_slice_mut(g * m * n..).unwrap(),
+                        [op.groups * m * n, n, 1],
+                    )
+                    .unwrap();
+                }
             }
 
             // sum all the gradients collected in our broadcasted grad_f
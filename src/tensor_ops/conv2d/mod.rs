@@ -9,12 +9,34 @@ use crate::{
     tensor::{DeviceStorage, HasErr, PutTape, SplitTape, Tensor, ZerosTensor},
 };
 
+/// How [TryConv2D] computes a convolution.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Conv2DAlgorithm {
+    /// Picks [Conv2DAlgorithm::Fft] when it needs fewer operations than
+    /// [Conv2DAlgorithm::Im2Col], which is the case for large kernels.
+    Auto,
+    /// Unfolds the image into patches, and multiplies them with the filters.
+    Im2Col,
+    /// Multiplies the fourier transforms of the image and the filters. Only the
+    /// cpu implements this: on cuda, [Conv2DAlgorithm::Auto] always picks
+    /// [Conv2DAlgorithm::Im2Col], and asking for this explicitly is an error.
+    Fft,
+}
+
+impl Default for Conv2DAlgorithm {
+    fn default() -> Self {
+        Self::Auto
+    }
+}
+
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub(super) struct Conv2DOp {
     pub stride: usize,
     pub padding: usize,
+    pub dilation: usize,
     pub kernel: usize,
+    pub groups: usize,
     pub batch: usize,
     pub chan_in: usize,
     pub chan_out: usize,
@@ -25,18 +47,74 @@ pub(super) struct Conv2DOp {
 }
 
 impl Conv2DOp {
-    fn new(s: usize, p: usize, k: usize, [b, c, h_in, w_in]: [usize; 4], o: usize) -> Self {
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        s: usize,
+        p: usize,
+        l: usize,
+        k: usize,
+        g: usize,
+        [b, c, h_in, w_in]: [usize; 4],
+        o: usize,
+    ) -> Self {
+        assert_eq!(c % g, 0, "in channels must be divisible by groups");
+        assert_eq!(o % g, 0, "out channels must be divisible by groups");
         Self {
             stride: s,
             padding: p,
+            dilation: l,
             kernel: k,
+            groups: g,
             batch: b,
             chan_in: c,
             chan_out: o,
             h_in,
-            h_out: (h_in + 2 * p - k) / s + 1,
+            h_out: (h_in + 2 * p - l * (k - 1) - 1) / s + 1,
             w_in,
-            w_out: (w_in + 2 * p - k) / s + 1,
+            w_out: (w_in + 2 * p - l * (k - 1) - 1) / s + 1,
+        }
+    }
+
+    /// The size of the grid the fourier transforms are computed over. It fits the
+    /// padded image, so the circular convolution never wraps around.
+    pub(super) fn fft_shape(&self) -> (usize, usize) {
+        (
+            (self.h_in + 2 * self.padding).next_power_of_two(),
+            (self.w_in + 2 * self.padding).next_power_of_two(),
+        )
+    }
+
+    /// Whether to compute the convolution with fourier transforms, see [Conv2DAlgorithm].
+    pub(super) fn use_fft(&self, algorithm: Conv2DAlgorithm) -> bool {
+        match algorithm {
+            Conv2DAlgorithm::Im2Col => false,
+            Conv2DAlgorithm::Fft => true,
+            Conv2DAlgorithm::Auto => {
+                let (nh, nw) = self.fft_shape();
+                let grid = nh * nw;
+                let log_grid = grid.trailing_zeros() as usize;
+                let chan_in_g = self.chan_in / self.groups;
+
+                // multiply-adds of the matmul with the unfolded patches
+                let im2col = self.batch
+                    * self.chan_out
+                    * chan_in_g
+                    * self.kernel
+                    * self.kernel
+                    * self.h_out
+                    * self.w_out;
+
+                // transforms of every image channel, output channel & filter, plus the
+                // complex products between them
+                let transforms =
+                    self.batch * (self.chan_in + self.chan_out) + self.chan_out * chan_in_g;
+                let products = self.batch * self.chan_out * chan_in_g;
+                let fft = transforms * grid * log_grid + 4 * products * grid;
+
+                // the transforms are far less optimized than gemm, so they need to
+                // win by a wide margin
+                4 * fft < im2col
+            }
         }
     }
 
@@ -50,15 +128,23 @@ impl Conv2DOp {
         (self.chan_out, self.kernel, self.kernel, self.h_in, self.w_in)
     }
 
+    /// Filters transposed within each group, i.e. `(ChanIn, ChanOut / Groups, K, K)`.
     pub(super) fn filters_tr_shape(&self) -> (usize, usize, usize, usize) {
-        (self.chan_in, self.chan_out, self.kernel, self.kernel)
+        (
+            self.chan_in,
+            self.chan_out / self.groups,
+            self.kernel,
+            self.kernel,
+        )
     }
 }
 
+/// `algorithm` is kept out of [Conv2DOp], which is passed to the cuda kernels as is.
 pub(super) trait Conv2DKernel<E: Dtype>: DeviceStorage {
     fn forward<L: Shape, R: Shape, O: Shape>(
         &self,
         op: Conv2DOp,
+        algorithm: Conv2DAlgorithm,
         lhs: &Self::Storage<L, E>,
         rhs: &Self::Storage<R, E>,
         out: &mut Self::Storage<O, E>,
@@ -67,6 +153,7 @@ pub(super) trait Conv2DKernel<E: Dtype>: DeviceStorage {
     fn backward<L: Shape, R: Shape, O: Shape>(
         &self,
         op: Conv2DOp,
+        algorithm: Conv2DAlgorithm,
         lhs: &Self::Storage<L, E>,
         grad_lhs: &mut Self::Storage<L, E>,
         rhs: &Self::Storage<R, E>,
@@ -75,26 +162,63 @@ pub(super) trait Conv2DKernel<E: Dtype>: DeviceStorage {
     ) -> Result<(), Self::Err>;
 }
 
-pub trait ConvAlgebra<const K: usize, const S: usize, const P: usize>: ConstDim {
+/// The output size of a convolution with kernel size `K`, stride `S`, padding `P`
+/// and dilation `L`.
+pub trait ConvAlgebra<const K: usize, const S: usize, const P: usize, const L: usize = 1>:
+    ConstDim
+{
     type Convolved: ConstDim;
 }
 
-impl<const D: usize, const K: usize, const S: usize, const P: usize> ConvAlgebra<K, S, P>
-    for Const<D>
+impl<const D: usize, const K: usize, const S: usize, const P: usize, const L: usize>
+    ConvAlgebra<K, S, P, L> for Const<D>
 where
-    Const<{ (D + 2 * P - K) / S + 1 }>: Sized,
+    Const<{ (D + 2 * P - L * (K - 1) - 1) / S + 1 }>: Sized,
 {
-    type Convolved = Const<{ (D + 2 * P - K) / S + 1 }>;
+    type Convolved = Const<{ (D + 2 * P - L * (K - 1) - 1) / S + 1 }>;
 }
 
-pub trait TryConv2DTo<F, const S: usize, const P: usize>: HasErr {
+pub trait TryConv2DTo<F, const S: usize, const P: usize, const L: usize = 1>: HasErr {
     type Output;
     fn conv2d_to(self, filters: F) -> Self::Output {
         self.try_conv2d_to(filters).unwrap()
     }
-    fn try_conv2d_to(self, filters: F) -> Result<Self::Output, Self::Err>;
+    fn try_conv2d_to(self, filters: F) -> Result<Self::Output, Self::Err> {
+        self.try_conv2d_to_with(filters, Default::default())
+    }
+    fn try_conv2d_to_with(
+        self,
+        filters: F,
+        algorithm: Conv2DAlgorithm,
+    ) -> Result<Self::Output, Self::Err>;
 }
 
+/// **Requires Nightly** 2d convolution with stride `S` and padding `P` over
+/// `(Chan, Height, Width)` or `(Batch, Chan, Height, Width)` images.
+///
+/// Filters have shape `(ChanOut, ChanIn / Groups, K, K)`, and the number of groups
+/// is inferred from the filters: input channels are split into `Chan / (ChanIn / Groups)`
+/// groups, and each group of `ChanOut / Groups` output channels only sees its own group
+/// of input channels.
+///
+/// The algorithm is picked from the sizes of the convolution, so large kernels are
+/// computed with fourier transforms on the cpu. See [Conv2DAlgorithm] and
+/// [TryConv2D::conv2d_with].
+///
+/// ```ignore
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let x: Tensor<Rank3<4, 10, 10>, f32, _> = dev.zeros();
+/// // regular convolution
+/// let w: Tensor<Rank4<6, 4, 3, 3>, f32, _> = dev.zeros();
+/// let _: Tensor<Rank3<6, 8, 8>, f32, _> = x.clone().conv2d::<1, 0>(w);
+/// // 2 groups
+/// let w: Tensor<Rank4<6, 2, 3, 3>, f32, _> = dev.zeros();
+/// let _: Tensor<Rank3<6, 8, 8>, f32, _> = x.clone().conv2d::<1, 0>(w);
+/// // depthwise
+/// let w: Tensor<Rank4<4, 1, 3, 3>, f32, _> = dev.zeros();
+/// let _: Tensor<Rank3<4, 8, 8>, f32, _> = x.conv2d::<1, 0>(w);
+/// ```
 pub trait TryConv2D<F> {
     fn conv2d<const S: usize, const P: usize>(self, filters: F) -> Self::Output
     where
@@ -111,6 +235,65 @@ pub trait TryConv2D<F> {
     {
         self.try_conv2d_to(filters)
     }
+    /// Same as [TryConv2D::conv2d], but always computed with `algorithm` instead of
+    /// [Conv2DAlgorithm::Auto]. Only the cpu has an fft implementation, so on cuda
+    /// [Conv2DAlgorithm::Fft] returns an error (and [TryConv2D::conv2d_with] panics).
+    ///
+    /// ```ignore
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let x: Tensor<Rank3<4, 32, 32>, f32, _> = dev.zeros();
+    /// let w: Tensor<Rank4<4, 4, 15, 15>, f32, _> = dev.zeros();
+    /// let _: Tensor<Rank3<4, 32, 32>, f32, _> = x.conv2d_with::<1, 7>(w, Conv2DAlgorithm::Fft);
+    /// ```
+    fn conv2d_with<const S: usize, const P: usize>(
+        self,
+        filters: F,
+        algorithm: Conv2DAlgorithm,
+    ) -> Self::Output
+    where
+        Self: TryConv2DTo<F, S, P>,
+    {
+        self.try_conv2d_to_with(filters, algorithm).unwrap()
+    }
+    fn try_conv2d_with<const S: usize, const P: usize>(
+        self,
+        filters: F,
+        algorithm: Conv2DAlgorithm,
+    ) -> Result<Self::Output, Self::Err>
+    where
+        Self: TryConv2DTo<F, S, P>,
+    {
+        self.try_conv2d_to_with(filters, algorithm)
+    }
+    /// Same as [TryConv2D::conv2d], but with the kernel dilated by `L`, i.e.
+    /// with `L - 1` zeros inserted between each kernel element.
+    ///
+    /// ```ignore
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let x: Tensor<Rank3<2, 10, 10>, f32, _> = dev.zeros();
+    /// let w: Tensor<Rank4<4, 2, 3, 3>, f32, _> = dev.zeros();
+    /// let _: Tensor<Rank3<4, 6, 6>, f32, _> = x.dilated_conv2d::<1, 0, 2>(w);
+    /// ```
+    fn dilated_conv2d<const S: usize, const P: usize, const L: usize>(
+        self,
+        filters: F,
+    ) -> Self::Output
+    where
+        Self: TryConv2DTo<F, S, P, L>,
+    {
+        self.conv2d_to(filters)
+    }
+    fn try_dilated_conv2d<const S: usize, const P: usize, const L: usize>(
+        self,
+        filters: F,
+    ) -> Result<Self::Output, Self::Err>
+    where
+        Self: TryConv2DTo<F, S, P, L>,
+    {
+        self.try_conv2d_to(filters)
+    }
 }
 
 impl<T, F> TryConv2D<F> for T {}
@@ -120,47 +303,57 @@ impl<
         const H: usize,
         const W: usize,
         const O: usize,
+        const CG: usize,
         const K: usize,
         const S: usize,
         const P: usize,
+        const L: usize,
         E: Dtype,
         D: Conv2DKernel<E> + ZerosTensor<E>,
         T: 'static + Tape<D>,
-    > TryConv2DTo<Tensor<Rank4<O, C, K, K>, E, D>, S, P> for Tensor<Rank3<C, H, W>, E, D, T>
+    > TryConv2DTo<Tensor<Rank4<O, CG, K, K>, E, D>, S, P, L> for Tensor<Rank3<C, H, W>, E, D, T>
 where
-    Const<H>: ConvAlgebra<K, S, P>,
-    Const<W>: ConvAlgebra<K, S, P>,
+    Const<H>: ConvAlgebra<K, S, P, L>,
+    Const<W>: ConvAlgebra<K, S, P, L>,
 {
     type Output = Tensor<
         (
             Const<O>,
-            <Const<H> as ConvAlgebra<K, S, P>>::Convolved,
-            <Const<W> as ConvAlgebra<K, S, P>>::Convolved,
+            <Const<H> as ConvAlgebra<K, S, P, L>>::Convolved,
+            <Const<W> as ConvAlgebra<K, S, P, L>>::Convolved,
         ),
         E,
         D,
         T,
     >;
 
-    fn try_conv2d_to(
+    fn try_conv2d_to_with(
         self,
-        filters: Tensor<Rank4<O, C, K, K>, E, D>,
+        filters: Tensor<Rank4<O, CG, K, K>, E, D>,
+        algorithm: Conv2DAlgorithm,
     ) -> Result<Self::Output, Self::Err> {
-        let op = Conv2DOp::new(S, P, K, [1, C, H, W], O);
+        let op = Conv2DOp::new(S, P, L, K, C / CG, [1, C, H, W], O);
         let (lhs, ltape) = self.split_tape();
         let (rhs, rtape) = filters.split_tape();
         let mut tape = ltape.merge(rtape);
         let mut out = lhs.device.try_zeros()?;
         lhs.device
-            .forward(op, &lhs.storage, &rhs.storage, &mut out.storage)?;
+            .forward(op, algorithm, &lhs.storage, &rhs.storage, &mut out.storage)?;
         let phantom_out = out.clone();
         tape.try_alloc_grad(&lhs)?;
         tape.try_alloc_grad(&rhs)?;
         tape.try_alloc_grad(&out)?;
         tape.add_backward_op(move |grads| {
             let (grad_lhs, grad_rhs, grad_out) = grads.muts_and_ref(&lhs, &rhs, &phantom_out);
-            lhs.device
-                .backward(op, &lhs.storage, grad_lhs, &rhs.storage, grad_rhs, grad_out)
+            lhs.device.backward(
+                op,
+                algorithm,
+                &lhs.storage,
+                grad_lhs,
+                &rhs.storage,
+                grad_rhs,
+                grad_out,
+            )
         });
         Ok(out.put_tape(tape))
     }
@@ -172,35 +365,38 @@ impl<
         const H: usize,
         const W: usize,
         const O: usize,
+        const CG: usize,
         const K: usize,
         const S: usize,
         const P: usize,
+        const L: usize,
         E: Dtype,
         D: Conv2DKernel<E> + ZerosTensor<E>,
         T: 'static + Tape<D>,
-    > TryConv2DTo<Tensor<Rank4<O, C, K, K>, E, D>, S, P>
+    > TryConv2DTo<Tensor<Rank4<O, CG, K, K>, E, D>, S, P, L>
     for Tensor<(B, Const<C>, Const<H>, Const<W>), E, D, T>
 where
-    Const<H>: ConvAlgebra<K, S, P>,
-    Const<W>: ConvAlgebra<K, S, P>,
+    Const<H>: ConvAlgebra<K, S, P, L>,
+    Const<W>: ConvAlgebra<K, S, P, L>,
 {
     type Output = Tensor<
         (
             B,
             Const<O>,
-            <Const<H> as ConvAlgebra<K, S, P>>::Convolved,
-            <Const<W> as ConvAlgebra<K, S, P>>::Convolved,
+            <Const<H> as ConvAlgebra<K, S, P, L>>::Convolved,
+            <Const<W> as ConvAlgebra<K, S, P, L>>::Convolved,
         ),
         E,
         D,
         T,
     >;
-    fn try_conv2d_to(
+    fn try_conv2d_to_with(
         self,
-        filters: Tensor<Rank4<O, C, K, K>, E, D>,
+        filters: Tensor<Rank4<O, CG, K, K>, E, D>,
+        algorithm: Conv2DAlgorithm,
     ) -> Result<Self::Output, Self::Err> {
         let batch = self.shape().0;
-        let op = Conv2DOp::new(S, P, K, [batch.size(), C, H, W], O);
+        let op = Conv2DOp::new(S, P, L, K, C / CG, [batch.size(), C, H, W], O);
         let (lhs, ltape) = self.split_tape();
         let (rhs, rtape) = filters.split_tape();
         let mut out =
@@ -208,15 +404,22 @@ where
                 .try_zeros_like(&(batch, Const, Default::default(), Default::default()))?;
         let mut tape = ltape.merge(rtape);
         lhs.device
-            .forward(op, &lhs.storage, &rhs.storage, &mut out.storage)?;
+            .forward(op, algorithm, &lhs.storage, &rhs.storage, &mut out.storage)?;
         let phantom_out = out.clone();
         tape.try_alloc_grad(&lhs)?;
         tape.try_alloc_grad(&rhs)?;
         tape.try_alloc_grad(&out)?;
         tape.add_backward_op(move |grads| {
             let (grad_lhs, grad_rhs, grad_out) = grads.muts_and_ref(&lhs, &rhs, &phantom_out);
-            lhs.device
-                .backward(op, &lhs.storage, grad_lhs, &rhs.storage, grad_rhs, grad_out)?;
+            lhs.device.backward(
+                op,
+                algorithm,
+                &lhs.storage,
+                grad_lhs,
+                &rhs.storage,
+                grad_rhs,
+                grad_out,
+            )?;
             Ok(())
         });
         Ok(out.put_tape(tape))
@@ -467,4 +670,282 @@ mod tests {
             [[-0.19717735, -0.19717735, -0.19717735],[-0.19717735, 1.3412137, 2.9476144],[-0.19717735, 4.247249, -2.1779637]],
         ]);
     }
+
+    #[test]
+    fn test_conv2d_depthwise() {
+        let dev: TestDevice = Default::default();
+        #[rustfmt::skip]
+        let weight: Tensor<_, TestDtype, _> = dev.tensor([
+            [[[0.203, -0.1447], [1.2328, 0.1988]]],
+            [[[0.909, -0.3655], [0.2182, 1.0243]]],
+            [[[0.6962, 0.1285], [-1.0823, 0.4452]]],
+            [[[0.0769, 0.7205], [0.2162, 1.0882]]],
+        ]);
+        #[rustfmt::skip]
+        let x: Tensor<_, TestDtype, _> = dev.tensor([
+            [[1.2882, 1.4494, 0.0663], [-0.7645, -1.0922, 0.0313], [-1.0221, -1.4368, 0.1993]],
+            [[0.1334, 0.5465, -0.914], [0.005, -0.0647, -1.5058], [0.538, 0.3207, 2.3891]],
+        ]);
+        let result = x.trace().conv2d::<1, 0>(weight.clone());
+
+        #[rustfmt::skip]
+        result.array().assert_close(
+            &[
+                [[-1.10782854, -1.05560713], [-1.54283288, -1.95791191]],
+                [[-0.64433626, 1.0870145], [-1.99046786, -1.11361672]],
+                [[0.12888239, -0.33733305], [-0.44433471, 0.47799427]],
+                [[0.33468617, -2.26911085], [0.41906949, 1.57924963]],
+            ],
+            1e-5,
+        );
+
+        let g = result.exp().mean().backward();
+
+        #[rustfmt::skip]
+        g.get(&x).array().assert_close(
+            &[
+                [[0.03401755, 0.15790708, -0.07088808], [0.04308219, 0.12035875, 0.18538762], [0.01833465, 0.02675709, 0.02277561]],
+                [[0.05621466, 0.10361785, 0.01038809], [-0.02285429, 0.24693925, 0.2583045], [-0.02283023, 0.07771403, 0.37482717]],
+            ],
+            1e-5,
+        );
+
+        #[rustfmt::skip]
+        g.get(&weight).array().assert_close(
+            &[
+                [[[0.03826417, 0.0170441], [-0.06586675, -0.03930341]]],
+                [[[0.28195508, 0.0511629], [-0.26572756, -0.03821674]]],
+                [[[0.02753919, -0.15629491], [0.05135892, 0.1819144]]],
+                [[[-0.00395876, -0.42089155], [0.14838547, 0.73948685]]],
+            ],
+            1e-5,
+        );
+    }
+
+    #[test]
+    fn test_batched_conv2d_2_groups() {
+        let dev: TestDevice = Default::default();
+        #[rustfmt::skip]
+        let weight: Tensor<_, TestDtype, _> = dev.tensor([
+            [[[-0.9087, -0.0585], [-0.0724, -0.1409]], [[0.3502, -0.2767], [-0.0533, -0.5418]]],
+            [[[0.0357, -0.4401], [-0.4271, 0.5594]], [[0.144, -0.0352], [0.01, -0.3961]]],
+        ]);
+        #[rustfmt::skip]
+        let x: Tensor<_, TestDtype, _> = dev.tensor([
+            [[[2.3382, -0.6629], [0.3949, 0.1465]], [[0.8351, -1.4021], [-0.4148, -0.7515]], [[-1.0746, -0.8438], [-0.5125, -0.2868]], [[-0.9067, 0.422], [-0.5475, -3.1978]]],
+            [[[1.1907, -0.3919], [-0.7434, 0.2684]], [[0.2299, 0.0528], [-0.8545, 0.1916]], [[-1.5374, 1.4435], [-1.2655, -0.2066]], [[0.0191, 0.2189], [-0.2466, 0.4829]]],
+        ]);
+        let result = x.trace().conv2d::<1, 1>(weight.clone());
+
+        #[rustfmt::skip]
+        result.array().assert_close(
+            &[
+                [[[-0.78190956, 0.63926388, 0.12272589], [-0.19875964, -1.02549067, 0.14081016], [0.09167351, -0.30473879, -0.39629985]], [[-0.24198737, -0.18928126, 0.36460698], [0.43501955, 1.50720037, 0.12115862], [0.24482325, 0.14164699, -0.47072196]]],
+                [[[-0.29232945, -0.07184868, 0.02555932], [0.43444388, -1.03542115, 0.34496565], [0.27992905, 0.30756456, -0.17679676]], [[-0.86758707, 1.37760215, -0.61432985], [0.06569498, -0.46394409, 0.17612241], [0.56562687, -0.00676217, 0.06216198]]],
+            ],
+            1e-5,
+        );
+
+        let g = result.exp().mean().backward();
+
+        #[rustfmt::skip]
+        g.get(&x).array().assert_close(
+            &[
+                [[[-0.01598622, -0.03933198], [-0.02432172, -0.02189966]], [[-0.01250371, -0.02175263], [-0.01411973, -0.00622386]], [[-0.01203004, -0.05828929], [-0.0440206, 0.04328578]], [[0.00813761, -0.00860395], [-0.01238564, -0.04798217]]],
+                [[[-0.01626539, -0.04192248], [-0.04323889, -0.02759074]], [[-0.02102712, -0.0045196], [-0.02070264, -0.00973692]], [[-0.05295061, 0.04869481], [-0.01140376, -0.0154652]], [[-0.00204825, -0.03932443], [-0.0093236, -0.00330191]]],
+            ],
+            1e-5,
+        );
+
+        #[rustfmt::skip]
+        g.get(&weight).array().assert_close(
+            &[
+                [[[-0.01254514, 0.09168753], [0.13369466, -0.00940628]], [[-0.08254313, -0.03675465], [-0.02170102, -0.10875523]]],
+                [[[-0.20263778, -0.26726775], [-0.30830817, -0.00087027]], [[-0.15840446, -0.10211538], [-0.1558008, -0.40908999]]],
+            ],
+            1e-5,
+        );
+    }
+
+    #[test]
+    fn test_conv2d_dilation_2_padding_1() {
+        let dev: TestDevice = Default::default();
+        #[rustfmt::skip]
+        let weight: Tensor<_, TestDtype, _> = dev.tensor([
+            [[[-0.4766, -1.0465], [0.9512, -1.2042]], [[-0.1416, -0.2626], [0.8279, -0.9927]]],
+            [[[0.5359, -0.3657], [-0.0776, -0.3355]], [[0.3202, -0.5688], [-0.0394, 0.1767]]],
+            [[[0.9202, -1.2026], [0.7625, 0.4741]], [[-0.2418, 0.1525], [-0.233, 0.8234]]],
+        ]);
+        #[rustfmt::skip]
+        let x: Tensor<Rank3<2, 5, 5>, TestDtype, _> = dev.tensor([
+            [[0.0947, 1.25, -0.9314, 0.9924, -0.2592], [-0.2615, 1.8997, 0.1575, -0.0429, 0.7295], [1.1269, -0.0308, 0.588, -0.9737, -0.3668], [-0.4381, -1.3323, -1.5085, -1.6269, -0.2387], [-0.1724, -0.3203, 0.0691, -1.3356, -0.0795]],
+            [[-0.5126, 0.7416, -1.1432, 0.3871, 0.6544], [1.0389, -0.2286, 0.4723, -0.8817, 0.1305], [-0.6652, 0.9128, -0.0443, 1.3376, -0.4917], [0.2358, -1.4095, 0.7781, 0.0642, -0.9563], [0.8412, 0.3093, -0.2739, -0.6128, 1.1754]],
+        ]);
+        let result = x.trace().dilated_conv2d::<1, 1, 2>(weight.clone());
+
+        #[rustfmt::skip]
+        result.array().assert_close(
+            &[
+                [[-2.06068752, -0.04714720, 2.54466047, -0.46718008, -0.77076591], [-2.37191636, 1.15945977, -1.26985544, 2.15763050, -0.34657560], [1.07556062, 0.51128686, -1.13542516, -0.49356789, -1.34906124], [-0.12880593, -0.32546413, 2.72120628, -0.99311087, -1.50309858], [1.76438665, 1.54972537, 2.52025131, 1.10969607, 0.76628982]],
+                [[-0.67774297, 0.00997390, -0.27981332, -0.25251852, 0.03806802], [-0.70732192, 0.61113998, 0.85368819, -1.15032855, 0.67863426], [-0.36676461, 0.53457400, 2.17814087, -0.10786374, -0.18159249], [-0.34582312, 0.10972869, 0.22350895, 0.95453699, 0.03428057], [1.28894571, -0.05019946, -0.60686110, 0.07197850, -0.85129887]],
+                [[0.71241853, 0.02210512, 0.75545438, 0.46335750, 0.17272485], [-0.65315876, 2.41340029, 0.24008490, -0.28923236, -0.23450135], [-4.11166645, -1.07270703, 0.31459545, -3.05877973, -1.08175137], [0.27906547, -0.03628936, -0.32818471, 1.96455963, -2.09504302], [1.38727523, 1.47262629, 1.08113508, -1.43504141, -1.51259694]],
+            ],
+            1e-5,
+        );
+
+        let g = result.exp().mean().backward();
+
+        #[rustfmt::skip]
+        g.get(&x).array().assert_close(
+            &[
+                [[0.12997705, 0.01854320, -0.27613008, -0.01645920, -0.13425571], [0.02724366, 0.22430238, -0.02402978, -0.23861652, -0.01713922], [0.16732622, -0.10207986, 0.20091385, -0.21423448, -0.26776604], [0.05322591, -0.24349295, -0.17391254, -0.26143711, -0.06490487], [0.01780900, 0.18986297, 0.06402518, -0.24206880, 0.02751546]],
+                [[-0.03417112, 0.00238933, -0.01981581, -0.01167723, -0.03116664], [0.00985917, 0.17269398, -0.01300747, -0.20592128, 0.00213341], [-0.00018980, -0.02787859, 0.15615877, -0.03754756, -0.11202745], [-0.00244438, -0.11280787, -0.02491721, -0.01165623, -0.02373629], [0.00438983, 0.16942182, -0.01577390, -0.18878656, 0.07951406]],
+            ],
+            1e-5,
+        );
+
+        #[rustfmt::skip]
+        g.get(&weight).array().assert_close(
+            &[
+                [[[-0.43732179, -0.65230282], [0.32328016, -0.38562410]], [[-0.12750146, 0.21812674], [0.02890503, -0.35819498]]],
+                [[[0.25980026, -0.07918498], [-0.20663818, -0.26373420]], [[0.03505790, -0.16027829], [-0.09924369, 0.07212309]]],
+                [[[0.05096776, -0.37577717], [0.18040911, 0.07306447]], [[-0.11508241, -0.21487533], [-0.10339223, 0.10709408]]],
+            ],
+            1e-5,
+        );
+    }
+
+    #[test]
+    fn test_batched_conv2d_stride_2_dilation_2() {
+        let dev: TestDevice = Default::default();
+        #[rustfmt::skip]
+        let weight: Tensor<_, TestDtype, _> = dev.tensor([
+            [[[-0.1176, 0.6071, -0.4507], [0.0824, 0.3298, -0.0056], [-0.2873, 0.1753, -0.2703]]],
+            [[[-0.2977, -0.5114, 0.6523], [-0.2747, 0.5774, 0.1903], [-0.1305, 0.3338, 0.1361]]],
+        ]);
+        #[rustfmt::skip]
+        let x: Tensor<_, TestDtype, _> = dev.tensor([
+            [[[0.0409, 0.4649, -0.4609, 0.3526, 0.9262], [0.4113, 1.5621, -0.8851, 0.0674, -0.7052], [-0.7836, -0.1839, 0.2213, 0.419, 0.5081], [2.2337, 0.8626, -1.5945, 0.2044, -0.623], [-0.5157, 1.3062, -0.2229, -1.9513, 0.3106]]],
+            [[[-0.2915, -1.1744, -0.9211, -0.6253, -0.0234], [-0.4267, 0.0715, 1.8362, -0.8003, -0.8074], [-0.2492, 1.0956, -0.7062, 1.4343, -1.3081], [-1.0323, -0.0544, -0.8634, -0.6184, 0.4512], [0.7242, 0.1139, -0.2746, 1.3442, 0.4026]]],
+        ]);
+        let result = x.trace().dilated_conv2d::<2, 1, 2>(weight.clone());
+
+        #[rustfmt::skip]
+        result.array().assert_close(
+            &[
+                [[[0.6107676, -0.0610481], [1.20131457, -0.00429506]], [[1.23053748, -0.43453269], [-0.21793036, -0.61844119]]],
+                [[[0.18567958, -0.35082374], [0.38962478, -0.70270141]], [[-0.21333595, -0.68105699], [-0.70769287, 0.04586739]]],
+            ],
+            1e-5,
+        );
+
+        let g = result.exp().mean().backward();
+
+        #[rustfmt::skip]
+        g.get(&x).array().assert_close(
+            &[
+                [[[0.0, 0.0, 0.0, 0.0, 0.0], [0.0, 0.23831862, 0.0, 0.04252851, 0.0], [0.0, 0.0, 0.0, 0.0, 0.0], [0.0, 0.16284344, 0.0, 0.07018776, 0.0], [0.0, 0.0, 0.0, 0.0, 0.0]]],
+                [[[0.0, 0.0, 0.0, 0.0, 0.0], [0.0, 0.06606119, 0.0, 0.00579374, 0.0], [0.0, 0.0, 0.0, 0.0, 0.0], [0.0, 0.04606733, 0.0, 0.05813768, 0.0], [0.0, 0.0, 0.0, 0.0, 0.0]]],
+            ],
+            1e-5,
+        );
+
+        #[rustfmt::skip]
+        g.get(&weight).array().assert_close(
+            &[
+                [[[0.09942595, 0.31059422, -0.05984463], [0.14699342, 0.32173698, -0.06705923], [0.04832573, 0.0800094, -0.02300644]]],
+                [[[0.05728061, 0.03061857, -0.02126061], [0.09097194, 0.32332191, -0.03476223], [0.03319129, 0.170512, 0.01250496]]],
+            ],
+            1e-5,
+        );
+    }
+
+    /// Runs the kernels with both algorithms on the same random inputs
+    #[cfg(not(feature = "test-cuda"))]
+    fn assert_fft_matches_im2col([s, p, l, k, g]: [usize; 5], [b, c, h, w]: [usize; 4], o: usize) {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.sample_normal_like(&(b, c, h, w));
+        let f: Tensor<_, TestDtype, _> = dev.sample_normal_like(&(o, c / g, k, k));
+        let op = Conv2DOp::new(s, p, l, k, g, [b, c, h, w], o);
+        let out_shape = (b, o, op.h_out, op.w_out);
+        let grad_out: Tensor<_, TestDtype, _> = dev.sample_normal_like(&out_shape);
+
+        let mut results = std::vec::Vec::new();
+        for algorithm in [Conv2DAlgorithm::Im2Col, Conv2DAlgorithm::Fft] {
+            let mut out: Tensor<_, TestDtype, _> = dev.zeros_like(&out_shape);
+            Conv2DKernel::forward(
+                &dev,
+                op,
+                algorithm,
+                &x.storage,
+                &f.storage,
+                &mut out.storage,
+            )
+            .unwrap();
+            let mut grad_x: Tensor<_, TestDtype, _> = dev.zeros_like(x.shape());
+            let mut grad_f: Tensor<_, TestDtype, _> = dev.zeros_like(f.shape());
+            Conv2DKernel::backward(
+                &dev,
+                op,
+                algorithm,
+                &x.storage,
+                &mut grad_x.storage,
+                &f.storage,
+                &mut grad_f.storage,
+                &grad_out.storage,
+            )
+            .unwrap();
+            results.push([out.as_vec(), grad_x.as_vec(), grad_f.as_vec()]);
+        }
+        for (a, b) in results[0].iter().zip(results[1].iter()) {
+            assert_close_with_tolerance(a, b, 1e-4);
+        }
+    }
+
+    #[cfg(not(feature = "test-cuda"))]
+    #[test]
+    fn test_conv2d_fft_matches_im2col() {
+        assert_fft_matches_im2col([1, 0, 1, 3, 1], [1, 2, 5, 5], 3);
+        assert_fft_matches_im2col([2, 1, 1, 3, 1], [2, 3, 6, 7], 2);
+        assert_fft_matches_im2col([1, 2, 2, 3, 1], [2, 2, 7, 6], 3);
+        assert_fft_matches_im2col([3, 4, 2, 2, 2], [2, 4, 9, 8], 4);
+        assert_fft_matches_im2col([1, 3, 1, 7, 3], [1, 3, 8, 8], 3);
+    }
+
+    #[test]
+    fn test_conv2d_auto_algorithm() {
+        let op = |k, p| Conv2DOp::new(1, p, 1, k, 1, [8, 64, 32, 32], 64);
+        assert!(!op(3, 1).use_fft(Conv2DAlgorithm::Auto));
+        assert!(op(15, 7).use_fft(Conv2DAlgorithm::Auto));
+    }
+
+    #[cfg(not(feature = "test-cuda"))]
+    #[test]
+    fn test_conv2d_with_fft() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank4<2, 3, 12, 12>, TestDtype, _> = dev.sample_normal();
+        let w: Tensor<Rank4<4, 3, 9, 9>, TestDtype, _> = dev.sample_normal();
+        let a = x
+            .trace()
+            .conv2d_with::<1, 4>(w.clone(), Conv2DAlgorithm::Im2Col);
+        let b = x
+            .trace()
+            .conv2d_with::<1, 4>(w.clone(), Conv2DAlgorithm::Fft);
+        assert_close_with_tolerance(&a.array(), &b.array(), 1e-3);
+
+        let ga = a.square().mean().backward();
+        let gb = b.square().mean().backward();
+        assert_close_with_tolerance(&ga.get(&x).array(), &gb.get(&x).array(), 1e-3);
+        assert_close_with_tolerance(&ga.get(&w).array(), &gb.get(&w).array(), 1e-3);
+    }
+
+    #[cfg(feature = "test-cuda")]
+    #[test]
+    fn test_conv2d_with_fft_is_unsupported_on_cuda() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank3<2, 8, 8>, TestDtype, _> = dev.sample_normal();
+        let w: Tensor<Rank4<3, 2, 5, 5>, TestDtype, _> = dev.sample_normal();
+        let r = x.try_conv2d_with::<1, 2>(w, Conv2DAlgorithm::Fft);
+        assert!(matches!(r, Err(CudaError::Unsupported(_))));
+    }
 }
@@ -0,0 +1,263 @@
+struct Conv3DOp {
+    size_t stride;
+    size_t padding;
+    size_t kernel;
+    size_t batch;
+    size_t chan_in;
+    size_t chan_out;
+    size_t d_in;
+    size_t d_out;
+    size_t h_in;
+    size_t h_out;
+    size_t w_in;
+    size_t w_out;
+};
+
+template<typename T>
+__device__ void unfold_input_into_patches(
+    const Conv3DOp op,
+    const T *image, // 5d (Batch, Channels, Depth, Height, Width)
+    const size_t *strides, // 5d image strides
+    T *patches // 8d (Batch, Channels, KernelSize, KernelSize, KernelSize, DepthOut, HeightOut, WidthOut)
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    const auto patches_numel = op.batch * op.chan_in * op.kernel * op.kernel * op.kernel * op.d_out * op.h_out * op.w_out;
+    if (i >= patches_numel) {
+        return;
+    }
+
+    // patches shape is (B, C, K, K, K, d_out, h_out, w_out)
+    unsigned int idx = i;
+    const size_t ow = idx % op.w_out;
+    idx /= op.w_out;
+    const size_t oh = idx % op.h_out;
+    idx /= op.h_out;
+    const size_t od = idx % op.d_out;
+    idx /= op.d_out;
+    const size_t k2 = idx % op.kernel;
+    idx /= op.kernel;
+    const size_t k1 = idx % op.kernel;
+    idx /= op.kernel;
+    const size_t k0 = idx % op.kernel;
+    idx /= op.kernel;
+    const size_t c = idx % op.chan_in;
+    idx /= op.chan_in;
+    const size_t b = idx % op.batch;
+    idx /= op.batch;
+
+    const size_t z_plus_p = od * op.stride + k0;
+    if (z_plus_p < op.padding) {
+        return;
+    }
+    const size_t z = z_plus_p - op.padding;
+    if (z >= op.d_in) {
+        return;
+    }
+
+    const size_t y_plus_p = oh * op.stride + k1;
+    if (y_plus_p < op.padding) {
+        return;
+    }
+    const size_t y = y_plus_p - op.padding;
+    if (y >= op.h_in) {
+        return;
+    }
+
+    const size_t x_plus_p = ow * op.stride + k2;
+    if (x_plus_p < op.padding) {
+        return;
+    }
+    const size_t x = x_plus_p - op.padding;
+    if (x >= op.w_in) {
+        return;
+    }
+
+    const size_t i_image = b * strides[0] + c * strides[1] + z * strides[2] + y * strides[3] + x * strides[4];
+    patches[i] = image[i_image];
+}
+
+__device__ bool unfold_dim(const Conv3DOp op, const size_t k, const size_t i, const size_t out_size, size_t *o) {
+    size_t tmp = i + op.padding;
+    if (tmp < k) {
+        return false;
+    }
+    tmp -= k;
+    if (tmp % op.stride != 0) {
+        return false;
+    }
+    tmp /= op.stride;
+    if (tmp >= out_size) {
+        return false;
+    }
+    *o = tmp;
+    return true;
+}
+
+template<typename T>
+__device__ void unfold_output_into_patches(
+    const Conv3DOp op,
+    const T *image_out, // 5d (Batch, ChanOut, DepthOut, HeightOut, WidthOut)
+    T *patches // 8d (Batch, ChanOut, KernelSize, KernelSize, KernelSize, DepthIn, HeightIn, WidthIn)
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    const auto patches_numel = op.batch * op.chan_out * op.kernel * op.kernel * op.kernel * op.d_in * op.h_in * op.w_in;
+    if (i >= patches_numel) {
+        return;
+    }
+
+    unsigned int idx = i;
+    const size_t x = idx % op.w_in;
+    idx /= op.w_in;
+    const size_t y = idx % op.h_in;
+    idx /= op.h_in;
+    const size_t z = idx % op.d_in;
+    idx /= op.d_in;
+    const size_t k2 = idx % op.kernel;
+    idx /= op.kernel;
+    const size_t k1 = idx % op.kernel;
+    idx /= op.kernel;
+    const size_t k0 = idx % op.kernel;
+    idx /= op.kernel;
+    const size_t o = idx % op.chan_out;
+    idx /= op.chan_out;
+    const size_t b = idx % op.batch;
+    idx /= op.batch;
+
+    size_t od, oh, ow;
+    if (!unfold_dim(op, k0, z, op.d_out, &od)) {
+        return;
+    }
+    if (!unfold_dim(op, k1, y, op.h_out, &oh)) {
+        return;
+    }
+    if (!unfold_dim(op, k2, x, op.w_out, &ow)) {
+        return;
+    }
+
+    size_t image_i = b * (op.chan_out * op.d_out * op.h_out * op.w_out)
+        + o * (op.d_out * op.h_out * op.w_out)
+        + od * (op.h_out * op.w_out)
+        + oh * op.w_out
+        + ow;
+    patches[i] = image_out[image_i];
+}
+
+template<typename T>
+__device__ void transpose_and_broadcast_filters(
+    const Conv3DOp op,
+    const T *filters, // 5d (ChanOut, ChanIn, KernelSize, KernelSize, KernelSize)
+    const size_t *strides, // 5d filters strides
+    T *filters_tr // 6d (Batch, ChanIn, ChanOut, KernelSize, KernelSize, KernelSize)
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    auto numel = op.chan_in * op.chan_out * op.kernel * op.kernel * op.kernel;
+    if (i >= numel) {
+        return;
+    }
+
+    unsigned int idx = i;
+    const size_t k2 = idx % op.kernel;
+    idx /= op.kernel;
+    const size_t k1 = idx % op.kernel;
+    idx /= op.kernel;
+    const size_t k0 = idx % op.kernel;
+    idx /= op.kernel;
+    const size_t c = idx % op.chan_in;
+    idx /= op.chan_in;
+    const size_t o = idx % op.chan_out;
+    idx /= op.chan_out;
+
+    const size_t kkk = op.kernel * op.kernel * op.kernel;
+    auto i_tr = c * (op.chan_out * kkk) + o * kkk + k0 * (op.kernel * op.kernel) + k1 * op.kernel + k2;
+    auto i_no = o * strides[0] + c * strides[1] + k0 * strides[2] + k1 * strides[3] + k2 * strides[4];
+
+    const T f = filters[i_no];
+    for (auto b = 0; b < op.batch; b++) {
+        filters_tr[b * numel + i_tr] = f;
+    }
+}
+
+template<typename T>
+__device__ void sum_transposed_filters(
+    const Conv3DOp op,
+    const T *filters_tr, // 6d (Batch, ChanIn, ChanOut, KernelSize, KernelSize, KernelSize)
+    T *filters, // 5d (ChanOut, ChanIn, KernelSize, KernelSize, KernelSize)
+    const size_t *strides // 5d filter strides
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    auto numel = op.chan_out * op.chan_in * op.kernel * op.kernel * op.kernel;
+    if (i >= numel) {
+        return;
+    }
+
+    unsigned int idx = i;
+    const size_t k2 = idx % op.kernel;
+    idx /= op.kernel;
+    const size_t k1 = idx % op.kernel;
+    idx /= op.kernel;
+    const size_t k0 = idx % op.kernel;
+    idx /= op.kernel;
+    const size_t c = idx % op.chan_in;
+    idx /= op.chan_in;
+    const size_t o = idx % op.chan_out;
+    idx /= op.chan_out;
+
+    const size_t kkk = op.kernel * op.kernel * op.kernel;
+    auto i_tr = c * (op.chan_out * kkk) + o * kkk + k0 * (op.kernel * op.kernel) + k1 * op.kernel + k2;
+    auto i_no = o * strides[0] + c * strides[1] + k0 * strides[2] + k1 * strides[3] + k2 * strides[4];
+
+    T tmp = 0.0;
+    for (auto b = 0; b < op.batch; b++) {
+        tmp += filters_tr[b * numel + i_tr];
+    }
+
+    filters[i_no] += tmp;
+}
+
+#define CONV_OP(TYPENAME, UNFOLD_INPUT, UNFOLD_OUTPUT, TR_FILTERS, SUM_TR_FILTERS) \
+extern "C" __global__ void UNFOLD_INPUT( \
+    const Conv3DOp op, \
+    const TYPENAME *image, \
+    const size_t *strides, \
+    TYPENAME *patches \
+) { \
+    unfold_input_into_patches(op, image, strides, patches); \
+} \
+extern "C" __global__ void UNFOLD_OUTPUT( \
+    const Conv3DOp op, \
+    const TYPENAME *image_out, \
+    TYPENAME *patches \
+) { \
+    unfold_output_into_patches(op, image_out, patches); \
+} \
+extern "C" __global__ void TR_FILTERS( \
+    const Conv3DOp op, \
+    const TYPENAME *filters, \
+    const size_t *strides, \
+    TYPENAME *filters_tr \
+) { \
+    transpose_and_broadcast_filters(op, filters, strides, filters_tr); \
+} \
+extern "C" __global__ void SUM_TR_FILTERS( \
+    const Conv3DOp op, \
+    const TYPENAME *filters_tr, \
+    TYPENAME *filters, \
+    const size_t *strides \
+) { \
+    sum_transposed_filters(op, filters_tr, filters, strides); \
+}
+
+CONV_OP(
+    float,
+    unfold_input_into_patches_f32,
+    unfold_output_into_patches_f32,
+    transpose_and_broadcast_filters_f32,
+    sum_transposed_filters_f32
+);
+CONV_OP(
+    double,
+    unfold_input_into_patches_f64,
+    unfold_output_into_patches_f64,
+    transpose_and_broadcast_filters_f64,
+    sum_transposed_filters_f64
+);
@@ -0,0 +1,262 @@
+use crate::shapes::{Dtype, Shape};
+use crate::tensor::cpu::*;
+use crate::tensor_ops::matmul::cpu_kernel::MatMulImpl;
+
+use super::{Conv3DKernel, Conv3DOp};
+
+use std::sync::Arc;
+
+impl Conv3DOp {
+    /// The output index that input index `i` contributes to at kernel offset `k`.
+    #[inline(always)]
+    fn unfold_dim(&self, k: usize, i: usize, out_size: usize) -> Option<usize> {
+        let mut o = i + self.padding;
+        if o < k {
+            return None;
+        }
+        o -= k;
+        if o % self.stride != 0 {
+            return None;
+        }
+        o /= self.stride;
+        if o >= out_size {
+            return None;
+        }
+        Some(o)
+    }
+
+    #[inline(always)]
+    fn unfold_idx(&self, [k0, k1, k2, z, y, x]: [usize; 6]) -> Option<[usize; 3]> {
+        Some([
+            self.unfold_dim(k0, z, self.d_out)?,
+            self.unfold_dim(k1, y, self.h_out)?,
+            self.unfold_dim(k2, x, self.w_out)?,
+        ])
+    }
+}
+
+impl Cpu {
+    #[inline]
+    fn conv3d_forward<E: Dtype, P: Shape<Concrete = [usize; 7]>>(
+        &self,
+        op: &Conv3DOp,
+        img: &[E],
+        filters: &[E],
+        out: &mut [E],
+        inp_patches_buf: &mut StridedArray<P, E>,
+    ) -> Result<(), CpuError>
+    where
+        Self: MatMulImpl<E>,
+    {
+        {
+            let buf = Arc::make_mut(&mut inp_patches_buf.data);
+            let mut i = 0;
+            for c in 0..op.chan_in {
+                for k0 in 0..op.kernel {
+                    for k1 in 0..op.kernel {
+                        for k2 in 0..op.kernel {
+                            for od in 0..op.d_out {
+                                for oh in 0..op.h_out {
+                                    for ow in 0..op.w_out {
+                                        let z = (od * op.stride + k0).wrapping_sub(op.padding);
+                                        let y = (oh * op.stride + k1).wrapping_sub(op.padding);
+                                        let x = (ow * op.stride + k2).wrapping_sub(op.padding);
+                                        if z < op.d_in && y < op.h_in && x < op.w_in {
+                                            buf[i] = img[c * (op.d_in * op.h_in * op.w_in)
+                                                + z * (op.h_in * op.w_in)
+                                                + y * op.w_in
+                                                + x];
+                                        }
+                                        i += 1;
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        // (O, C * K * K * K) * (C * K * K * K, OD * OH * OW) = (O, OD * OH * OW)
+        let m = op.chan_out;
+        let k = op.chan_in * op.kernel * op.kernel * op.kernel;
+        let n = op.d_out * op.h_out * op.w_out;
+        Self::matmul(
+            View::new(filters, (m, k)),
+            View::new(inp_patches_buf.view().data, (k, n)),
+            &mut ViewMut::new(out, (m, n)),
+        );
+        Ok(())
+    }
+
+    #[inline]
+    #[allow(clippy::too_many_arguments)]
+    fn conv3d_backward<E: Dtype, P: Shape<Concrete = [usize; 7]>>(
+        &self,
+        op: &Conv3DOp,
+        img: &[E],
+        grad_img: &mut [E],
+        filters_tr: &[E],
+        grad_filters_tr: &mut [E],
+        grad_out: &[E],
+        out_patches_buf: &mut StridedArray<P, E>,
+    ) -> Result<(), CpuError>
+    where
+        Self: MatMulImpl<E>,
+    {
+        {
+            let mut i = 0;
+            let buf = Arc::make_mut(&mut out_patches_buf.data);
+            for o in 0..op.chan_out {
+                for k0 in 0..op.kernel {
+                    for k1 in 0..op.kernel {
+                        for k2 in 0..op.kernel {
+                            for z in 0..op.d_in {
+                                for y in 0..op.h_in {
+                                    for x in 0..op.w_in {
+                                        if let Some([od, oh, ow]) =
+                                            op.unfold_idx([k0, k1, k2, z, y, x])
+                                        {
+                                            buf[i] = grad_out[o * (op.d_out * op.h_out * op.w_out)
+                                                + od * (op.h_out * op.w_out)
+                                                + oh * op.w_out
+                                                + ow];
+                                        }
+                                        i += 1;
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        {
+            // img_g += filters^T * unfold(grad_out)
+            // (C, D * H * W) += (C, O * K * K * K) * (O * K * K * K, D * H * W)
+            let m = op.chan_in;
+            let k = op.chan_out * op.kernel * op.kernel * op.kernel;
+            let n = op.d_in * op.h_in * op.w_in;
+            Self::matmul(
+                View::new(filters_tr, (m, k)),
+                View::new(out_patches_buf.view().data, (k, n)),
+                &mut ViewMut::new(grad_img, (m, n)),
+            );
+        }
+
+        {
+            // weight_g^T += img * patches^T
+            // (C, O * K * K * K) += (C, D * H * W) * (D * H * W, O * K * K * K)
+            let m = op.chan_in;
+            let k = op.d_in * op.h_in * op.w_in;
+            let n = op.chan_out * op.kernel * op.kernel * op.kernel;
+            Self::matmul(
+                View::new(img, (m, k)),
+                View::new(out_patches_buf.view().data, (n, k)).tr(),
+                &mut ViewMut::new(grad_filters_tr, (m, n)),
+            );
+        }
+        Ok(())
+    }
+}
+
+impl<E: Dtype> Conv3DKernel<E> for Cpu
+where
+    Self: MatMulImpl<E>,
+{
+    fn forward<L: Shape, R: Shape, O: Shape>(
+        &self,
+        op: Conv3DOp,
+        lhs: &Self::Storage<L, E>,
+        rhs: &Self::Storage<R, E>,
+        out: &mut Self::Storage<O, E>,
+    ) -> Result<(), Self::Err> {
+        let mut patches: StridedArray<_, E> = StridedArray::new(op.inp_patches_shape())?;
+        let [lstride, ostride] = match L::NUM_DIMS {
+            4 => [0; 2],
+            5 => [lhs.strides[0], out.strides[0]],
+            _ => unreachable!(),
+        };
+        let lhs = lhs.data.as_ref();
+        let rhs = rhs.data.as_ref();
+        let out = Arc::make_mut(&mut out.data);
+        for i_batch in 0..op.batch {
+            self.conv3d_forward(
+                &op,
+                &lhs[i_batch * lstride..],
+                rhs,
+                &mut out[i_batch * ostride..],
+                &mut patches,
+            )?;
+        }
+        Ok(())
+    }
+
+    fn backward<L: Shape, R: Shape, O: Shape>(
+        &self,
+        op: Conv3DOp,
+        lhs: &Self::Storage<L, E>,
+        grad_lhs: &mut Self::Storage<L, E>,
+        rhs: &Self::Storage<R, E>,
+        grad_rhs: &mut Self::Storage<R, E>,
+        grad_out: &Self::Storage<O, E>,
+    ) -> Result<(), Self::Err> {
+        let mut patches: StridedArray<_, E> = StridedArray::new(op.out_patches_shape())?;
+        let mut f10234: StridedArray<_, E> = StridedArray::new(op.filters_tr_shape())?;
+        let mut grad_f10234: StridedArray<_, E> = StridedArray::new(op.filters_tr_shape())?;
+
+        {
+            // transpose filters in f10234
+            let buf = rhs.data.as_ref();
+            let mut f_iter = f10234.iter_mut_with_index();
+            while let Some((f, [c, o, k0, k1, k2])) = f_iter.next() {
+                let idx = o * rhs.strides[0]
+                    + c * rhs.strides[1]
+                    + k0 * rhs.strides[2]
+                    + k1 * rhs.strides[3]
+                    + k2 * rhs.strides[4];
+                *f = buf[idx];
+            }
+        }
+
+        let [lstride, ostride] = match L::NUM_DIMS {
+            4 => [0; 2],
+            5 => [lhs.strides[0], grad_out.strides[0]],
+            _ => unreachable!(),
+        };
+        let lhs = lhs.data.as_ref();
+        let grad_lhs = Arc::make_mut(&mut grad_lhs.data);
+        let f = f10234.data.as_ref();
+        let grad_f = Arc::make_mut(&mut grad_f10234.data);
+        let grad_out = grad_out.data.as_ref();
+
+        for i_batch in 0..op.batch {
+            self.conv3d_backward(
+                &op,
+                &lhs[i_batch * lstride..],
+                &mut grad_lhs[i_batch * lstride..],
+                f,
+                grad_f,
+                &grad_out[i_batch * ostride..],
+                &mut patches,
+            )?;
+        }
+
+        {
+            // untranspose filters
+            let buf = Arc::make_mut(&mut grad_rhs.data);
+            let mut f_iter = grad_f10234.iter_with_index();
+            while let Some((f, [c, o, k0, k1, k2])) = f_iter.next() {
+                let idx = o * rhs.strides[0]
+                    + c * rhs.strides[1]
+                    + k0 * rhs.strides[2]
+                    + k1 * rhs.strides[3]
+                    + k2 * rhs.strides[4];
+                buf[idx] += *f;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,341 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{
+    gradients::Tape,
+    shapes::*,
+    tensor::{DeviceStorage, HasErr, PutTape, SplitTape, Tensor, ZerosTensor},
+};
+
+use super::conv2d::ConvAlgebra;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub(super) struct Conv3DOp {
+    pub stride: usize,
+    pub padding: usize,
+    pub kernel: usize,
+    pub batch: usize,
+    pub chan_in: usize,
+    pub chan_out: usize,
+    pub d_in: usize,
+    pub d_out: usize,
+    pub h_in: usize,
+    pub h_out: usize,
+    pub w_in: usize,
+    pub w_out: usize,
+}
+
+impl Conv3DOp {
+    fn new(s: usize, p: usize, k: usize, [b, c, d_in, h_in, w_in]: [usize; 5], o: usize) -> Self {
+        Self {
+            stride: s,
+            padding: p,
+            kernel: k,
+            batch: b,
+            chan_in: c,
+            chan_out: o,
+            d_in,
+            d_out: (d_in + 2 * p - k) / s + 1,
+            h_in,
+            h_out: (h_in + 2 * p - k) / s + 1,
+            w_in,
+            w_out: (w_in + 2 * p - k) / s + 1,
+        }
+    }
+
+    #[rustfmt::skip]
+    pub(super) fn inp_patches_shape(&self) -> (usize, usize, usize, usize, usize, usize, usize) {
+        (self.chan_in, self.kernel, self.kernel, self.kernel, self.d_out, self.h_out, self.w_out)
+    }
+
+    #[rustfmt::skip]
+    pub(super) fn out_patches_shape(&self) -> (usize, usize, usize, usize, usize, usize, usize) {
+        (self.chan_out, self.kernel, self.kernel, self.kernel, self.d_in, self.h_in, self.w_in)
+    }
+
+    #[rustfmt::skip]
+    pub(super) fn filters_tr_shape(&self) -> (usize, usize, usize, usize, usize) {
+        (self.chan_in, self.chan_out, self.kernel, self.kernel, self.kernel)
+    }
+}
+
+pub(super) trait Conv3DKernel<E: Dtype>: DeviceStorage {
+    fn forward<L: Shape, R: Shape, O: Shape>(
+        &self,
+        op: Conv3DOp,
+        lhs: &Self::Storage<L, E>,
+        rhs: &Self::Storage<R, E>,
+        out: &mut Self::Storage<O, E>,
+    ) -> Result<(), Self::Err>;
+
+    fn backward<L: Shape, R: Shape, O: Shape>(
+        &self,
+        op: Conv3DOp,
+        lhs: &Self::Storage<L, E>,
+        grad_lhs: &mut Self::Storage<L, E>,
+        rhs: &Self::Storage<R, E>,
+        grad_rhs: &mut Self::Storage<R, E>,
+        grad_out: &Self::Storage<O, E>,
+    ) -> Result<(), Self::Err>;
+}
+
+pub trait TryConv3DTo<F, const S: usize, const P: usize>: HasErr {
+    type Output;
+    fn conv3d_to(self, filters: F) -> Self::Output {
+        self.try_conv3d_to(filters).unwrap()
+    }
+    fn try_conv3d_to(self, filters: F) -> Result<Self::Output, Self::Err>;
+}
+
+/// **Requires Nightly** 3d convolution with stride `S` and padding `P` over
+/// `(Chan, Depth, Height, Width)` or `(Batch, Chan, Depth, Height, Width)` inputs.
+/// Filters have shape `(ChanOut, ChanIn, K, K, K)`.
+///
+/// ```ignore
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let x: Tensor<Rank4<2, 6, 8, 8>, f32, _> = dev.zeros();
+/// let w: Tensor<Rank5<4, 2, 3, 3, 3>, f32, _> = dev.zeros();
+/// let y: Tensor<Rank4<4, 4, 6, 6>, f32, _> = x.conv3d::<1, 0>(w);
+/// ```
+pub trait TryConv3D<F> {
+    fn conv3d<const S: usize, const P: usize>(self, filters: F) -> Self::Output
+    where
+        Self: TryConv3DTo<F, S, P>,
+    {
+        self.conv3d_to(filters)
+    }
+    fn try_conv3d<const S: usize, const P: usize>(
+        self,
+        filters: F,
+    ) -> Result<Self::Output, Self::Err>
+    where
+        Self: TryConv3DTo<F, S, P>,
+    {
+        self.try_conv3d_to(filters)
+    }
+}
+
+impl<T, F> TryConv3D<F> for T {}
+
+impl<
+        const C: usize,
+        const Z: usize,
+        const H: usize,
+        const W: usize,
+        const O: usize,
+        const K: usize,
+        const S: usize,
+        const P: usize,
+        E: Dtype,
+        D: Conv3DKernel<E> + ZerosTensor<E>,
+        T: 'static + Tape<D>,
+    > TryConv3DTo<Tensor<Rank5<O, C, K, K, K>, E, D>, S, P> for Tensor<Rank4<C, Z, H, W>, E, D, T>
+where
+    Const<Z>: ConvAlgebra<K, S, P>,
+    Const<H>: ConvAlgebra<K, S, P>,
+    Const<W>: ConvAlgebra<K, S, P>,
+{
+    type Output = Tensor<
+        (
+            Const<O>,
+            <Const<Z> as ConvAlgebra<K, S, P>>::Convolved,
+            <Const<H> as ConvAlgebra<K, S, P>>::Convolved,
+            <Const<W> as ConvAlgebra<K, S, P>>::Convolved,
+        ),
+        E,
+        D,
+        T,
+    >;
+
+    fn try_conv3d_to(
+        self,
+        filters: Tensor<Rank5<O, C, K, K, K>, E, D>,
+    ) -> Result<Self::Output, Self::Err> {
+        let op = Conv3DOp::new(S, P, K, [1, C, Z, H, W], O);
+        let (lhs, ltape) = self.split_tape();
+        let (rhs, rtape) = filters.split_tape();
+        let mut tape = ltape.merge(rtape);
+        let mut out = lhs.device.try_zeros()?;
+        lhs.device
+            .forward(op, &lhs.storage, &rhs.storage, &mut out.storage)?;
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&lhs)?;
+        tape.try_alloc_grad(&rhs)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_lhs, grad_rhs, grad_out) = grads.muts_and_ref(&lhs, &rhs, &phantom_out);
+            lhs.device
+                .backward(op, &lhs.storage, grad_lhs, &rhs.storage, grad_rhs, grad_out)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+impl<
+        B: Dim,
+        const C: usize,
+        const Z: usize,
+        const H: usize,
+        const W: usize,
+        const O: usize,
+        const K: usize,
+        const S: usize,
+        const P: usize,
+        E: Dtype,
+        D: Conv3DKernel<E> + ZerosTensor<E>,
+        T: 'static + Tape<D>,
+    > TryConv3DTo<Tensor<Rank5<O, C, K, K, K>, E, D>, S, P>
+    for Tensor<(B, Const<C>, Const<Z>, Const<H>, Const<W>), E, D, T>
+where
+    Const<Z>: ConvAlgebra<K, S, P>,
+    Const<H>: ConvAlgebra<K, S, P>,
+    Const<W>: ConvAlgebra<K, S, P>,
+{
+    type Output = Tensor<
+        (
+            B,
+            Const<O>,
+            <Const<Z> as ConvAlgebra<K, S, P>>::Convolved,
+            <Const<H> as ConvAlgebra<K, S, P>>::Convolved,
+            <Const<W> as ConvAlgebra<K, S, P>>::Convolved,
+        ),
+        E,
+        D,
+        T,
+    >;
+    fn try_conv3d_to(
+        self,
+        filters: Tensor<Rank5<O, C, K, K, K>, E, D>,
+    ) -> Result<Self::Output, Self::Err> {
+        let batch = self.shape().0;
+        let op = Conv3DOp::new(S, P, K, [batch.size(), C, Z, H, W], O);
+        let (lhs, ltape) = self.split_tape();
+        let (rhs, rtape) = filters.split_tape();
+        let mut out = lhs.device.try_zeros_like(&(
+            batch,
+            Const,
+            Default::default(),
+            Default::default(),
+            Default::default(),
+        ))?;
+        let mut tape = ltape.merge(rtape);
+        lhs.device
+            .forward(op, &lhs.storage, &rhs.storage, &mut out.storage)?;
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&lhs)?;
+        tape.try_alloc_grad(&rhs)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_lhs, grad_rhs, grad_out) = grads.muts_and_ref(&lhs, &rhs, &phantom_out);
+            lhs.device
+                .backward(op, &lhs.storage, grad_lhs, &rhs.storage, grad_rhs, grad_out)?;
+            Ok(())
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    #[rustfmt::skip]
+    fn test_conv3d_default_stride_and_padding() {
+        let dev: TestDevice = Default::default();
+        let weight: Tensor<Rank5<2, 1, 2, 2, 2>, TestDtype, _> = dev.tensor_from_vec(
+            std::vec![
+                -1.6269, -0.2387, -0.1724, -0.3203, 0.0691, -1.3356, -0.0795, 0.2381,
+                0.751, -0.8462, -0.3999, -2.0152, -0.5036, -2.1967, -1.4194, 1.1015,
+            ],
+            Default::default(),
+        );
+        let x: Tensor<Rank4<1, 2, 3, 3>, TestDtype, _> = dev.tensor_from_vec(
+            std::vec![
+                0.0947, 1.25, -0.9314, 0.9924, -0.2592, -0.2615, 1.8997, 0.1575,
+                -0.0429, 0.7295, 1.1269, -0.0308, 0.588, -0.9737, -0.3668, -0.4381,
+                -1.3323, -1.5085,
+            ],
+            Default::default(),
+        );
+        let result = x.trace().conv3d::<1, 0>(weight.clone());
+        result.as_vec().assert_close(
+            &std::vec![
+                -2.27377359, -1.57377595, -0.87190721, 0.64005981, -5.6111264, 2.83572066, 0.88466409, 1.57564868,
+            ],
+            1e-5,
+        );
+        let g = result.exp().mean().backward();
+        g.get(&x).as_vec().assert_close(
+            &std::vec![
+                -0.02058739, 1.55427447, -1.8088734, 0.13994378, -1.06202708, -4.86924166, -0.13008946, -0.90939159,
+                -1.29358832, 0.00065879, -1.08923351, -4.71430854, -0.15053547, -4.04510915, 0.7087739, -0.43390902,
+                -0.53054999, 0.72201172,
+            ],
+            1e-5,
+        );
+        g.get(&weight).as_vec().assert_close(
+            &std::vec![
+                0.02402516, -0.08359174, 0.14268727, -0.01204765, -0.16152436, -0.12415329, -0.35641462, -0.44929471,
+                2.80681438, -2.22010803, 0.11861251, -0.53543619, 1.99069406, -0.58154085, -3.01170393, -2.0967219,
+            ],
+            1e-5,
+        );
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn test_batched_conv3d_stride_2_padding_1() {
+        let dev: TestDevice = Default::default();
+        let weight: Tensor<Rank5<1, 2, 2, 2, 2>, TestDtype, _> = dev.tensor_from_vec(
+            std::vec![
+                -1.8694, -1.0937, -0.9532, -2.0929, 1.9023, -2.4083, -0.2833, -0.5252,
+                1.6559, -1.9854, 1.0718, -0.7314, -0.1552, -0.671, 0.6405, -1.1376,
+            ],
+            Default::default(),
+        );
+        let x: Tensor<Rank5<2, 2, 2, 2, 3>, TestDtype, _> = dev.tensor_from_vec(
+            std::vec![
+                0.0947, 1.25, -0.9314, 0.9924, -0.2592, -0.2615, 1.8997, 0.1575,
+                -0.0429, 0.7295, 1.1269, -0.0308, 0.588, -0.9737, -0.3668, -0.4381,
+                -1.3323, -1.5085, -1.6269, -0.2387, -0.1724, -0.3203, 0.0691, -1.3356,
+                -0.0795, 0.2381, 0.751, -0.8462, -0.3999, -2.0152, -0.5036, -2.1967,
+                -1.4194, 1.1015, -2.2016, 0.7986, 0.3279, -0.3123, 0.4593, 0.5275,
+                1.0454, -0.2304, -0.5922, -0.6046, -0.9864, -0.0449, -0.7858, 1.0686,
+            ],
+            Default::default(),
+        );
+        let result: Tensor<Rank5<2, 1, 2, 2, 2>, _, _, _> = x.trace().conv3d::<2, 1>(weight.clone());
+        result.as_vec().assert_close(
+            &std::vec![
+                -0.71864524, -0.07133689, -2.09603182, 1.35567075, -2.78596747, -0.19008889, -0.16193053, 0.69318203,
+                -0.33126564, -1.18440676, 1.68395096, 4.08482871, 1.48711952, 5.13799938, -1.11556609, -0.18056244,
+            ],
+            1e-4,
+        );
+        let g = result.exp().mean().backward();
+        g.get(&x).as_vec().assert_close(
+            &std::vec![
+                -0.0159993, -0.01648714, -0.03056494, -0.01850528, 0.46123192, -0.58391674, -0.00806674, -0.04926171,
+                -0.10816181, -0.05813701, -0.23368314, -0.13671726, -0.034655, 0.03727502, -0.06620463, -0.00515594,
+                -0.03762981, -0.16269075, -0.00281906, 0.055391, -0.03779901, -0.10553645, 0.20699471, -0.24818365,
+                -0.02356882, -0.00541683, -0.01004207, -0.81081395, 7.06606444, -8.94559375, -0.5787304, -10.15006515,
+                -22.28605891, -0.02240237, -0.09753601, -0.05706384, -0.05105082, 0.01224666, -0.02175145, -0.2259088,
+                -0.57648804, -2.4924193, -0.20224732, 11.41296667, -7.78824764, -0.04066716, 0.08639664, -0.10358831,
+            ],
+            1e-4,
+        );
+        g.get(&weight).as_vec().assert_close(
+            &std::vec![
+                0.02599885, 0.09915649, -23.38322429, -15.24850513, -1.54826814, -7.82610176, 0.07729853, -0.04052775,
+                -0.03236134, -0.12914722, -6.45036525, -10.68252832, 3.56009277, -1.04733879, -0.06263751, 0.02006267,
+            ],
+            1e-4,
+        );
+    }
+}
@@ -0,0 +1,368 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{
+    gradients::Tape,
+    shapes::*,
+    tensor::{DeviceStorage, HasErr, PutTape, SplitTape, Tensor, ZerosTensor},
+};
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub(super) struct ConvTrans2DOp {
+    pub stride: usize,
+    pub padding: usize,
+    pub kernel: usize,
+    pub batch: usize,
+    pub chan_in: usize,
+    pub chan_out: usize,
+    pub h_in: usize,
+    pub h_out: usize,
+    pub w_in: usize,
+    pub w_out: usize,
+}
+
+impl ConvTrans2DOp {
+    fn new(s: usize, p: usize, k: usize, [b, c, h_in, w_in]: [usize; 4], o: usize) -> Self {
+        Self {
+            stride: s,
+            padding: p,
+            kernel: k,
+            batch: b,
+            chan_in: c,
+            chan_out: o,
+            h_in,
+            h_out: (h_in - 1) * s + k - 2 * p,
+            w_in,
+            w_out: (w_in - 1) * s + k - 2 * p,
+        }
+    }
+
+    #[rustfmt::skip]
+    pub(super) fn inp_patches_shape(&self) -> (usize, usize, usize, usize, usize) {
+        (self.chan_in, self.kernel, self.kernel, self.h_out, self.w_out)
+    }
+
+    #[rustfmt::skip]
+    pub(super) fn out_patches_shape(&self) -> (usize, usize, usize, usize, usize) {
+        (self.chan_out, self.kernel, self.kernel, self.h_in, self.w_in)
+    }
+
+    pub(super) fn filters_tr_shape(&self) -> (usize, usize, usize, usize) {
+        (self.chan_in, self.chan_out, self.kernel, self.kernel)
+    }
+}
+
+pub(super) trait ConvTrans2DKernel<E: Dtype>: DeviceStorage {
+    fn forward<L: Shape, R: Shape, O: Shape>(
+        &self,
+        op: ConvTrans2DOp,
+        lhs: &Self::Storage<L, E>,
+        rhs: &Self::Storage<R, E>,
+        out: &mut Self::Storage<O, E>,
+    ) -> Result<(), Self::Err>;
+
+    fn backward<L: Shape, R: Shape, O: Shape>(
+        &self,
+        op: ConvTrans2DOp,
+        lhs: &Self::Storage<L, E>,
+        grad_lhs: &mut Self::Storage<L, E>,
+        rhs: &Self::Storage<R, E>,
+        grad_rhs: &mut Self::Storage<R, E>,
+        grad_out: &Self::Storage<O, E>,
+    ) -> Result<(), Self::Err>;
+}
+
+pub trait ConvTransAlgebra<const K: usize, const S: usize, const P: usize>: ConstDim {
+    type Convolved: ConstDim;
+}
+
+impl<const D: usize, const K: usize, const S: usize, const P: usize> ConvTransAlgebra<K, S, P>
+    for Const<D>
+where
+    Const<{ (D - 1) * S + K - 2 * P }>: Sized,
+{
+    type Convolved = Const<{ (D - 1) * S + K - 2 * P }>;
+}
+
+pub trait TryConvTrans2DTo<F, const S: usize, const P: usize>: HasErr {
+    type Output;
+    fn convtrans2d_to(self, filters: F) -> Self::Output {
+        self.try_convtrans2d_to(filters).unwrap()
+    }
+    fn try_convtrans2d_to(self, filters: F) -> Result<Self::Output, Self::Err>;
+}
+
+/// **Requires Nightly** Transposed 2d convolution with stride `S` and padding `P`,
+/// which is the gradient of conv2d with respect to its input. Each spatial dimension
+/// `D` becomes `(D - 1) * S + K - 2 * P`.
+///
+/// Filters have shape `(ChanOut, ChanIn, K, K)`, the same layout as conv2d filters.
+/// Note that pytorch's `ConvTranspose2d` stores its weight as `(ChanIn, ChanOut, K, K)`.
+///
+/// ```ignore
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let x: Tensor<Rank3<2, 3, 3>, f32, _> = dev.zeros();
+/// let w: Tensor<Rank4<4, 2, 3, 3>, f32, _> = dev.zeros();
+/// let y: Tensor<Rank3<4, 5, 5>, f32, _> = x.convtrans2d::<2, 1>(w);
+/// ```
+pub trait TryConvTrans2D<F> {
+    fn convtrans2d<const S: usize, const P: usize>(self, filters: F) -> Self::Output
+    where
+        Self: TryConvTrans2DTo<F, S, P>,
+    {
+        self.convtrans2d_to(filters)
+    }
+    fn try_convtrans2d<const S: usize, const P: usize>(
+        self,
+        filters: F,
+    ) -> Result<Self::Output, Self::Err>
+    where
+        Self: TryConvTrans2DTo<F, S, P>,
+    {
+        self.try_convtrans2d_to(filters)
+    }
+}
+
+impl<T, F> TryConvTrans2D<F> for T {}
+
+impl<
+        const C: usize,
+        const H: usize,
+        const W: usize,
+        const O: usize,
+        const K: usize,
+        const S: usize,
+        const P: usize,
+        E: Dtype,
+        D: ConvTrans2DKernel<E> + ZerosTensor<E>,
+        T: 'static + Tape<D>,
+    > TryConvTrans2DTo<Tensor<Rank4<O, C, K, K>, E, D>, S, P> for Tensor<Rank3<C, H, W>, E, D, T>
+where
+    Const<H>: ConvTransAlgebra<K, S, P>,
+    Const<W>: ConvTransAlgebra<K, S, P>,
+{
+    type Output = Tensor<
+        (
+            Const<O>,
+            <Const<H> as ConvTransAlgebra<K, S, P>>::Convolved,
+            <Const<W> as ConvTransAlgebra<K, S, P>>::Convolved,
+        ),
+        E,
+        D,
+        T,
+    >;
+
+    fn try_convtrans2d_to(
+        self,
+        filters: Tensor<Rank4<O, C, K, K>, E, D>,
+    ) -> Result<Self::Output, Self::Err> {
+        let op = ConvTrans2DOp::new(S, P, K, [1, C, H, W], O);
+        let (lhs, ltape) = self.split_tape();
+        let (rhs, rtape) = filters.split_tape();
+        let mut tape = ltape.merge(rtape);
+        let mut out = lhs.device.try_zeros()?;
+        lhs.device
+            .forward(op, &lhs.storage, &rhs.storage, &mut out.storage)?;
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&lhs)?;
+        tape.try_alloc_grad(&rhs)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_lhs, grad_rhs, grad_out) = grads.muts_and_ref(&lhs, &rhs, &phantom_out);
+            lhs.device
+                .backward(op, &lhs.storage, grad_lhs, &rhs.storage, grad_rhs, grad_out)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+impl<
+        B: Dim,
+        const C: usize,
+        const H: usize,
+        const W: usize,
+        const O: usize,
+        const K: usize,
+        const S: usize,
+        const P: usize,
+        E: Dtype,
+        D: ConvTrans2DKernel<E> + ZerosTensor<E>,
+        T: 'static + Tape<D>,
+    > TryConvTrans2DTo<Tensor<Rank4<O, C, K, K>, E, D>, S, P>
+    for Tensor<(B, Const<C>, Const<H>, Const<W>), E, D, T>
+where
+    Const<H>: ConvTransAlgebra<K, S, P>,
+    Const<W>: ConvTransAlgebra<K, S, P>,
+{
+    type Output = Tensor<
+        (
+            B,
+            Const<O>,
+            <Const<H> as ConvTransAlgebra<K, S, P>>::Convolved,
+            <Const<W> as ConvTransAlgebra<K, S, P>>::Convolved,
+        ),
+        E,
+        D,
+        T,
+    >;
+    fn try_convtrans2d_to(
+        self,
+        filters: Tensor<Rank4<O, C, K, K>, E, D>,
+    ) -> Result<Self::Output, Self::Err> {
+        let batch = self.shape().0;
+        let op = ConvTrans2DOp::new(S, P, K, [batch.size(), C, H, W], O);
+        let (lhs, ltape) = self.split_tape();
+        let (rhs, rtape) = filters.split_tape();
+        let mut out =
+            lhs.device
+                .try_zeros_like(&(batch, Const, Default::default(), Default::default()))?;
+        let mut tape = ltape.merge(rtape);
+        lhs.device
+            .forward(op, &lhs.storage, &rhs.storage, &mut out.storage)?;
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&lhs)?;
+        tape.try_alloc_grad(&rhs)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_lhs, grad_rhs, grad_out) = grads.muts_and_ref(&lhs, &rhs, &phantom_out);
+            lhs.device
+                .backward(op, &lhs.storage, grad_lhs, &rhs.storage, grad_rhs, grad_out)?;
+            Ok(())
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_convtrans2d_default_stride_and_padding() {
+        let dev: TestDevice = Default::default();
+        let weight: Tensor<_, TestDtype, _> = dev.tensor([
+            [[[0.1792, -0.8311], [-1.3090, 0.1939]]],
+            [[[0.9932, -0.6470], [-0.3337, 1.6457]]],
+        ]);
+        let x: Tensor<_, TestDtype, _> =
+            dev.tensor([[[0.9417, -1.3966, -0.6797], [0.3705, -1.0163, -0.0721]]]);
+        let result = x.trace().convtrans2d::<1, 0>(weight.clone());
+        #[rustfmt::skip]
+        assert_close(
+            &result.array(),
+            &[
+                [[0.16875264, -1.03291759, 1.03891202, 0.56489867], [-1.16629170, 1.52070152, 1.45065317, -0.07187152], [-0.48498450, 1.40217665, -0.10268167, -0.01398019]],
+                [[0.93529644, -1.99638302, 0.22852216, 0.43976590], [0.05373531, 0.76669845, -1.48563235, -1.07193359], [-0.12363585, 0.94887116, -1.64846514, -0.11865497]],
+            ],
+        );
+        let g = result.exp().mean().backward();
+        assert_close(
+            &g.get(&x).array(),
+            &[[
+                [0.25120616, -0.35296374, -0.23448345],
+                [-0.00641187, -0.26767075, 0.01673499],
+            ]],
+        );
+        assert_close(
+            &g.get(&weight).array(),
+            &[
+                [[[-0.25605948, -0.31312022], [-0.54014373, -0.07350542]]],
+                [[[-0.01906590, -0.08914842], [-0.18657940, 0.09065558]]],
+            ],
+        );
+    }
+
+    #[test]
+    fn test_convtrans2d_stride_2_padding_1() {
+        let dev: TestDevice = Default::default();
+        #[rustfmt::skip]
+        let weight: Tensor<_, TestDtype, _> = dev.tensor([[
+            [[-1.3090, 0.1939, 0.9932], [-0.6470, -0.3337, 1.6457], [-0.5589, -0.5142, 2.4041]],
+            [[-1.5311, 0.7965, -2.0036], [-0.5970, 1.5037, 1.2214], [-0.9011, -0.4537, 0.0802]],
+        ]]);
+        let x: Tensor<_, TestDtype, _> = dev.tensor([
+            [[0.9417, -1.3966], [-0.6797, 0.3705]],
+            [[-1.0163, -0.0721], [0.1792, -0.8311]],
+        ]);
+        let result = x.trace().convtrans2d::<2, 1>(weight.clone());
+        result.array().assert_close(
+            &[[
+                [-1.84245560, 1.25509077, 0.35762865],
+                [-0.01218786, 2.78135231, 0.16071229],
+                [0.49627893, -0.64325421, -1.37336092],
+            ]],
+            1e-5,
+        );
+        let g = result.exp().mean().backward();
+        g.get(&x).array().assert_close(
+            &[
+                [[4.89074592, -1.37465642], [1.83771534, -2.36946734]],
+                [[0.59659797, -1.66905372], [-3.16011181, -2.63453502]],
+            ],
+            1e-5,
+        );
+        #[rustfmt::skip]
+        g.get(&weight).array().assert_close(
+            &[[
+                [[0.66446432, -0.02626340, -1.21899162], [-0.52275163, -0.31894421, 0.32737722], [-2.50469869, -0.07886675, 1.68886922]],
+                [[-1.49051631, -0.08877452, 0.32138193], [-0.07663856, -0.02002608, -0.38568399], [-0.12930601, -0.12096212, -1.82265880]],
+            ]],
+            1e-5,
+        );
+    }
+
+    #[test]
+    fn test_batched_convtrans2d() {
+        let dev: TestDevice = Default::default();
+        #[rustfmt::skip]
+        let weight: Tensor<_, TestDtype, _> = dev.tensor([
+            [[[2.4041, -1.5311], [0.7965, -2.0036]], [[-0.5970, 1.5037], [1.2214, -0.9011]]],
+            [[[-0.4537, 0.0802], [-1.2581, 0.5522]], [[2.2276, -1.3552], [-1.9815, 0.2882]]],
+        ]);
+        #[rustfmt::skip]
+        let x: Tensor<_, TestDtype, _> = dev.tensor([
+            [[[0.9417, -1.3966], [-0.6797, 0.3705]], [[-1.0163, -0.0721], [0.1792, -0.8311]]],
+            [[[-1.3090, 0.1939], [0.9932, -0.6470]], [[-0.3337, 1.6457], [-0.5589, -0.5142]]],
+        ]);
+        let result = x.trace().convtrans2d::<2, 0>(weight.clone());
+
+        #[rustfmt::skip]
+        result.array().assert_close(
+            &[
+                [
+                    [[2.87067207, -2.97004718, -3.31452236, 2.02991749], [-0.49124477, -0.97100219, -1.20045484, 2.86319707], [-1.74104917, 1.31015171, 1.38688575, -1.81699762], [-0.32250617, 1.20036980, -0.72000229, 0.00657041]],
+                    [[-2.69115917, 1.45281410, 0.47302746, -0.01429740], [0.82904568, 0.22710908, 1.89992861, -0.79198174], [0.70756581, -0.29736378, -2.01945421, 1.15602082], [0.50004577, -0.32368490, 1.18069860, -0.03493292]],
+                ],
+                [
+                    [[-2.94774800, 1.50242521, -0.51632791, 2.17775880], [-1.45019968, 2.92340947, 2.16449933, -1.87143831], [2.72141542, -2.36110645, -1.24847530, 0.21741916], [0.10844334, -1.48635073, -1.14337938, 1.75967482]],
+                    [[-0.14945682, 0.34724844, 3.57798889, -2.21470186], [2.30807945, -0.81900214, -3.50490014, 0.58136232], [-1.69562048, 0.83707592, -0.85188802, 0.64495444], [-0.14208457, 0.38737006, 1.83287800, -0.50546584]],
+                ],
+            ],
+            1e-4,
+        );
+        let g = result.exp().mean().backward();
+
+        #[rustfmt::skip]
+        g.get(&x).array().assert_close(
+            &[
+                [[[0.62816123, -0.86308736], [-0.21667857, 0.06835635]], [[-0.31053076, -0.23231600], [0.05962015, -0.19753863]]],
+                [[[-0.88321794, -0.32398351], [0.57282485, -0.31522353]], [[-0.46172810, 1.61680402], [-0.18424894, -0.26555851]]],
+            ],
+            1e-4,
+        );
+
+        #[rustfmt::skip]
+        g.get(&weight).array().assert_close(
+            &[
+                [[[0.51394864, -0.28005749], [0.03322518, -0.84142454]], [[-0.45177293, 0.19160819], [0.19611071, -0.17122757]]],
+                [[[0.03458442, 0.03985819], [-0.36614043, 0.01965207]], [[0.91215417, -0.14823148], [-0.19104905, -0.00497382]]],
+            ],
+            1e-4,
+        );
+    }
+}
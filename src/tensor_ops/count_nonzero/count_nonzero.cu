@@ -0,0 +1,49 @@
+#include "cuda_utils.cuh"
+
+// Each thread counts the `chunk_len` elements of one output element. The reduced
+// axes are the last dims, so they are the logical indices
+// [i * chunk_len, (i + 1) * chunk_len).
+template<typename T>
+__device__ void count_nonzero(
+    const size_t numel,
+    const size_t chunk_len,
+    const size_t num_dims,
+    const size_t *dims,
+    const size_t *strides,
+    const T *inp,
+    size_t *out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    size_t count = 0;
+    for (unsigned int j = i * chunk_len; j < (i + 1) * chunk_len; j++) {
+        if (inp[get_strided_index(j, num_dims, dims, strides)] != (T)0) {
+            count++;
+        }
+    }
+    out[i] = count;
+}
+
+#define COUNT_NONZERO(TYPENAME, FN) \
+extern "C" __global__ void FN( \
+    const size_t numel, \
+    const size_t chunk_len, \
+    const size_t num_dims, \
+    const size_t *dims, \
+    const size_t *strides, \
+    const TYPENAME *inp, \
+    size_t *out \
+) { \
+    count_nonzero(numel, chunk_len, num_dims, dims, strides, inp, out); \
+}
+
+COUNT_NONZERO(float, count_nonzero_f32);
+COUNT_NONZERO(double, count_nonzero_f64);
+COUNT_NONZERO(int, count_nonzero_i32);
+COUNT_NONZERO(long long, count_nonzero_i64);
+COUNT_NONZERO(unsigned char, count_nonzero_u8);
+COUNT_NONZERO(size_t, count_nonzero_usize);
+COUNT_NONZERO(bool, count_nonzero_bool);
@@ -0,0 +1,28 @@
+use crate::{
+    shapes::{Axes, HasAxes, ReduceShapeTo, Shape, Unit},
+    tensor::cpu::{Cpu, StridedArray},
+    tensor_ops::utilities::reduction_utils::index_for_reductions,
+};
+
+impl<E: Unit> super::CountNonzeroKernel<E> for Cpu {
+    fn forward<Src: Shape + ReduceShapeTo<Dst, Ax>, Dst: Shape, Ax: Axes>(
+        &self,
+        dst: Dst,
+        inp: &Self::Storage<Src, E>,
+    ) -> Result<Self::Storage<Dst, usize>, Self::Err> {
+        let mut out: StridedArray<Dst, usize> = StridedArray::new(dst)?;
+        let num_elems_reduced = <Src as HasAxes<Ax>>::size(&inp.shape);
+        let inp_buf = inp.data.as_ref();
+        let mut idx = index_for_reductions::<Src, Ax>(inp.shape, inp.strides);
+        for o in out.buf_iter_mut() {
+            let mut count = 0;
+            for _ in 0..num_elems_reduced {
+                if inp_buf[idx.next().unwrap()] != E::default() {
+                    count += 1;
+                }
+            }
+            *o = count;
+        }
+        Ok(out)
+    }
+}
@@ -0,0 +1,84 @@
+use crate::{
+    shapes::*,
+    tensor::cuda::{Cuda, CudaArray},
+};
+use cudarc::driver::{CudaSlice, LaunchAsync, LaunchConfig};
+use std::{sync::Arc, vec::Vec};
+
+const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/count_nonzero.ptx"));
+
+trait HasCudaKernel<E> {
+    const MOD: &'static str;
+    const FNS: &'static [&'static str];
+}
+
+macro_rules! has_cuda_kernel {
+    ($TypeName:ty, $Mod:tt, $Fwd:tt) => {
+        impl HasCudaKernel<$TypeName> for Cuda {
+            const MOD: &'static str = $Mod;
+            const FNS: &'static [&'static str] = &[$Fwd];
+        }
+    };
+}
+
+has_cuda_kernel!(f32, "count_nonzero_f32", "count_nonzero_f32");
+has_cuda_kernel!(f64, "count_nonzero_f64", "count_nonzero_f64");
+has_cuda_kernel!(i32, "count_nonzero_i32", "count_nonzero_i32");
+has_cuda_kernel!(i64, "count_nonzero_i64", "count_nonzero_i64");
+has_cuda_kernel!(u8, "count_nonzero_u8", "count_nonzero_u8");
+has_cuda_kernel!(usize, "count_nonzero_usize", "count_nonzero_usize");
+has_cuda_kernel!(bool, "count_nonzero_bool", "count_nonzero_bool");
+
+impl<E: Unit> super::CountNonzeroKernel<E> for Cuda
+where
+    Self: HasCudaKernel<E>,
+{
+    fn forward<Src: Shape + ReduceShapeTo<Dst, Ax>, Dst: Shape, Ax: Axes>(
+        &self,
+        dst: Dst,
+        inp: &Self::Storage<Src, E>,
+    ) -> Result<Self::Storage<Dst, usize>, Self::Err> {
+        if !self.dev.has_func(Self::MOD, Self::FNS[0]) {
+            self.dev.load_ptx(PTX_SRC.into(), Self::MOD, Self::FNS)?;
+        }
+
+        // move the reduced axes to the end, so each output element reduces a
+        // contiguous chunk of logical indices
+        let mut axes: Vec<(bool, (usize, usize))> = inp
+            .shape
+            .concrete()
+            .into_iter()
+            .zip(inp.strides.into_iter())
+            .map(|x| (false, x))
+            .collect();
+        for i in Ax::as_array().into_iter() {
+            axes[i as usize].0 = true;
+        }
+        axes.sort_by_key(|x| x.0);
+        let (dims, strides): (Vec<usize>, Vec<usize>) = axes.into_iter().map(|(_, x)| x).unzip();
+
+        let numel = dst.num_elements();
+        let chunk_len = <Src as HasAxes<Ax>>::size(&inp.shape);
+        let dims: CudaSlice<usize> = self.dev.take_async(dims)?;
+        let strides: CudaSlice<usize> = self.dev.take_async(strides)?;
+        let mut storage = unsafe { self.dev.alloc_async::<usize>(numel) }?;
+
+        let fwd_fn = self.dev.get_func(Self::MOD, Self::FNS[0]).unwrap();
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            numel,             // const size_t numel,
+            chunk_len,         // const size_t chunk_len,
+            Src::NUM_DIMS,     // const size_t num_dims,
+            &dims,             // const size_t *dims,
+            &strides,          // const size_t *strides,
+            inp.data.as_ref(), // const T *inp,
+            &mut storage,      // size_t *out
+        );
+        unsafe { fwd_fn.launch_async(cfg, params) }?;
+        Ok(CudaArray {
+            data: Arc::new(storage),
+            shape: dst,
+            strides: dst.strides(),
+        })
+    }
+}
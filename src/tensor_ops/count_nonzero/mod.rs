@@ -0,0 +1,110 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{shapes::*, tensor::*};
+
+pub trait CountNonzeroKernel<E: Unit>: DeviceStorage {
+    fn forward<Src: Shape + ReduceShapeTo<Dst, Ax>, Dst: Shape, Ax: Axes>(
+        &self,
+        dst: Dst,
+        inp: &Self::Storage<Src, E>,
+    ) -> Result<Self::Storage<Dst, usize>, Self::Err>;
+}
+
+/// Reduction along multiple axes that counts the elements that aren't zero.
+pub trait CountNonzeroTo<D: DeviceStorage>: HasErr + HasShape {
+    /// The number of elements along `Ax` that aren't equal to zero (or `false`). NaNs
+    /// count as nonzero.
+    ///
+    /// The counts are a [usize] tensor, which can be turned into floats with
+    /// [to_dtype()](crate::tensor_ops::to_dtype), e.g. for the denominator of a masked mean.
+    ///
+    /// **Pytorch equivalent**: `t.count_nonzero(Ax)`
+    ///
+    /// Example:
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t = dev.tensor([[1.0, 0.0, 3.0], [0.0, -0.0, 0.0]]);
+    /// let r = t.count_nonzero::<Rank1<2>, _>(); // or `count_nonzero::<_, Axis<1>>()`
+    /// assert_eq!(r.array(), [2, 0]);
+    /// let r = t.count_nonzero::<Rank0, _>();
+    /// assert_eq!(r.array(), 2);
+    /// ```
+    fn count_nonzero<Dst: Shape, Ax: Axes>(&self) -> Tensor<Dst, usize, D>
+    where
+        Self::Shape: ReduceShapeTo<Dst, Ax>,
+    {
+        self.try_count_nonzero().unwrap()
+    }
+    /// Fallible version of [CountNonzeroTo::count_nonzero]
+    fn try_count_nonzero<Dst: Shape, Ax: Axes>(&self) -> Result<Tensor<Dst, usize, D>, Self::Err>
+    where
+        Self::Shape: ReduceShapeTo<Dst, Ax>;
+}
+
+impl<S: Shape, E: Unit, D: CountNonzeroKernel<E>, T> CountNonzeroTo<D> for Tensor<S, E, D, T> {
+    fn try_count_nonzero<Dst: Shape, Ax: Axes>(&self) -> Result<Tensor<Dst, usize, D>, Self::Err>
+    where
+        Self::Shape: ReduceShapeTo<Dst, Ax>,
+    {
+        let dst: Dst = self.shape().reduced();
+        let storage = self.device.forward(dst, &self.storage)?;
+        Ok(self.device.upgrade(storage))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_count_nonzero_1d() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([0.0, 1.0, -0.0, TestDtype::NAN, -2.0]);
+        assert_eq!(t.count_nonzero::<Rank0, _>().array(), 3);
+        let t: Tensor<Rank1<0>, TestDtype, _> = dev.zeros();
+        assert_eq!(t.count_nonzero::<Rank0, _>().array(), 0);
+    }
+
+    #[test]
+    fn test_count_nonzero_axes() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([
+            [[1.0, 0.0], [0.0, 0.0], [2.0, 3.0]],
+            [[0.0, 4.0], [5.0, 0.0], [0.0, 0.0]],
+        ]);
+        assert_eq!(
+            t.count_nonzero::<_, Axis<0>>().array(),
+            [[1, 1], [1, 0], [1, 1]]
+        );
+        assert_eq!(t.count_nonzero::<_, Axis<1>>().array(), [[2, 1], [1, 1]]);
+        assert_eq!(
+            t.count_nonzero::<_, Axis<2>>().array(),
+            [[1, 0, 2], [1, 1, 0]]
+        );
+        assert_eq!(t.count_nonzero::<_, Axes2<0, 2>>().array(), [2, 1, 2]);
+        assert_eq!(t.count_nonzero::<Rank0, _>().array(), 5);
+    }
+
+    #[test]
+    fn test_count_nonzero_broadcasted_and_traced() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([0.0, 1.0, 2.0]);
+        let b = t.trace().broadcast::<Rank2<4, 3>, _>();
+        assert_eq!(b.count_nonzero::<_, Axis<0>>().array(), [0, 4, 4]);
+        assert_eq!(b.count_nonzero::<Rank0, _>().array(), 8);
+    }
+
+    #[test]
+    fn test_count_nonzero_ints_and_bools() {
+        let dev: TestDevice = Default::default();
+        let t = dev.tensor([[0i32, 5, -1], [0, 0, 7]]);
+        assert_eq!(t.count_nonzero::<_, Axis<1>>().array(), [2, 1]);
+        let t = dev.tensor([[true, false], [true, true]]);
+        assert_eq!(t.count_nonzero::<_, Axis<0>>().array(), [2, 1]);
+    }
+}
@@ -0,0 +1,244 @@
+use crate::{
+    gradients::{Merge, Tape},
+    shapes::*,
+    tensor::{HasErr, Tensor},
+};
+
+use super::{BroadcastTo, Device, MeanTo, PermuteTo, SumTo, TryDiv, TryMatMul, TrySub};
+
+/// Covariance matrix of the `D` variables in the last axis of `t`, with the `N`
+/// observations in the second to last axis, so `(N, D)` becomes `(D, D)`. A leading
+/// batch axis is also supported, in which case `(B, N, D)` becomes `(B, D, D)`.
+///
+/// Divides by `N - 1`, i.e. the unbiased estimate.
+///
+/// **Pytorch equivalent**: `torch.cov(t.T)`
+///
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([[1.0, 2.0], [2.0, 0.0], [3.0, 1.0]]);
+/// let r: Tensor<Rank2<2, 2>, f32, _> = cov(t);
+/// assert_eq!(r.array(), [[1.0, -0.5], [-0.5, 1.0]]);
+/// ```
+pub fn cov<T: TryCov>(t: T) -> T::Output {
+    t.cov()
+}
+
+/// Correlation matrix (Pearson correlation coefficients) of the `D` variables in the last
+/// axis of `t`. See [cov] for the supported shapes.
+///
+/// Variables with zero variance have `NaN` correlations.
+///
+/// **Pytorch equivalent**: `torch.corrcoef(t.T)`
+///
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([[0.0, 4.0, 1.0], [2.0, 0.0, 1.0], [0.0, 4.0, -1.0], [2.0, 0.0, -1.0]]);
+/// let r: Tensor<Rank2<3, 3>, f32, _> = corrcoef(t);
+/// assert_eq!(r.array(), [[1.0, -1.0, 0.0], [-1.0, 1.0, 0.0], [0.0, 0.0, 1.0]]);
+/// ```
+pub fn corrcoef<T: TryCov>(t: T) -> T::Output {
+    t.corrcoef()
+}
+
+/// Covariance & correlation matrices. See [cov] and [corrcoef].
+pub trait TryCov: HasErr {
+    type Output;
+
+    /// See [cov]
+    fn cov(self) -> Self::Output {
+        self.try_cov().unwrap()
+    }
+    /// Fallible version of [TryCov::cov]
+    fn try_cov(self) -> Result<Self::Output, Self::Err>;
+
+    /// See [corrcoef]
+    fn corrcoef(self) -> Self::Output {
+        self.try_corrcoef().unwrap()
+    }
+    /// Fallible version of [TryCov::corrcoef]
+    fn try_corrcoef(self) -> Result<Self::Output, Self::Err>;
+}
+
+impl<const N: usize, V: Dim, E: Dtype, D: Device<E>, T: Tape<D> + Merge<T>> TryCov
+    for Tensor<(Const<N>, V), E, D, T>
+{
+    type Output = Tensor<(V, V), E, D, T>;
+
+    fn try_cov(self) -> Result<Self::Output, Self::Err> {
+        let xc = center::<_, Axis<0>, _, _, _>(self)?;
+        let rhs = xc.retaped::<T>();
+        xc.try_permute::<_, Axes2<1, 0>>()?
+            .try_matmul(rhs)?
+            .try_div(E::from_usize(N - 1).unwrap())
+    }
+
+    fn try_corrcoef(self) -> Result<Self::Output, Self::Err> {
+        let z = unit_columns::<_, Axis<0>, _, _, _>(self)?;
+        let rhs = z.retaped::<T>();
+        z.try_permute::<_, Axes2<1, 0>>()?.try_matmul(rhs)
+    }
+}
+
+impl<const B: usize, const N: usize, V: Dim, E: Dtype, D: Device<E>, T: Tape<D> + Merge<T>> TryCov
+    for Tensor<(Const<B>, Const<N>, V), E, D, T>
+{
+    type Output = Tensor<(Const<B>, V, V), E, D, T>;
+
+    fn try_cov(self) -> Result<Self::Output, Self::Err> {
+        let xc = center::<_, Axis<1>, _, _, _>(self)?;
+        let rhs = xc.retaped::<T>();
+        xc.try_permute::<_, Axes3<0, 2, 1>>()?
+            .try_matmul(rhs)?
+            .try_div(E::from_usize(N - 1).unwrap())
+    }
+
+    fn try_corrcoef(self) -> Result<Self::Output, Self::Err> {
+        let z = unit_columns::<_, Axis<1>, _, _, _>(self)?;
+        let rhs = z.retaped::<T>();
+        z.try_permute::<_, Axes3<0, 2, 1>>()?.try_matmul(rhs)
+    }
+}
+
+/// Subtracts the mean along `Ax`
+fn center<S: Shape + ReduceShape<Ax>, Ax: Axes, E: Dtype, D: Device<E>, T: Tape<D> + Merge<T>>(
+    t: Tensor<S, E, D, T>,
+) -> Result<Tensor<S, E, D, T>, D::Err> {
+    let mean = t
+        .retaped::<T>()
+        .try_mean::<_, Ax>()?
+        .try_broadcast_like(t.shape())?;
+    t.try_sub(mean)
+}
+
+/// Centers along `Ax` and scales to unit length along `Ax`, so the dot products of
+/// the result are the correlation coefficients.
+fn unit_columns<
+    S: Shape + ReduceShape<Ax>,
+    Ax: Axes,
+    E: Dtype,
+    D: Device<E>,
+    T: Tape<D> + Merge<T>,
+>(
+    t: Tensor<S, E, D, T>,
+) -> Result<Tensor<S, E, D, T>, D::Err> {
+    let xc = center::<_, Ax, _, _, _>(t)?;
+    let norm = xc
+        .retaped::<T>()
+        .try_square()?
+        .try_sum::<_, Ax>()?
+        .try_sqrt()?
+        .try_broadcast_like(xc.shape())?;
+    xc.try_div(norm)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_cov_2d() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([
+            [1.0, 2.0, 0.0],
+            [2.0, 0.0, 1.0],
+            [3.0, 1.0, 5.0],
+            [6.0, 1.0, 2.0],
+        ]);
+        let r = t.trace().cov();
+        assert_close(
+            &r.array(),
+            &[
+                [4.6666665, -0.33333334, 1.6666666],
+                [-0.33333334, 0.6666667, -0.33333334],
+                [1.6666666, -0.33333334, 4.6666665],
+            ],
+        );
+        let g = r.sum().backward();
+        assert_close(
+            &g.get(&t).array(),
+            &[
+                [-2.0, -2.0, -2.0],
+                [-2.0, -2.0, -2.0],
+                [2.0, 2.0, 2.0],
+                [2.0, 2.0, 2.0],
+            ],
+        );
+    }
+
+    #[test]
+    fn test_cov_matches_var() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank2<5, 3>, TestDtype, _> = dev.sample_normal();
+        let r = t.clone().cov();
+        let var = t.var_along::<Rank1<3>, _>(1).array();
+        let r = r.array();
+        for i in 0..3 {
+            assert!((r[i][i] - var[i]).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_corrcoef_2d() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([
+            [1.0, 2.0, 0.0],
+            [2.0, 0.0, 1.0],
+            [3.0, 1.0, 5.0],
+            [6.0, 1.0, 2.0],
+        ]);
+        let r = t.trace().corrcoef();
+        assert_close(
+            &r.array(),
+            &[
+                [1.0, -0.18898223, 0.35714287],
+                [-0.18898223, 1.0, -0.18898223],
+                [0.35714287, -0.18898223, 1.0],
+            ],
+        );
+        // the correlation doesn't change with scale or offset
+        let r2 = (t.clone() * 3.0 + 1.0).corrcoef();
+        assert_close(&r.array(), &r2.array());
+
+        let g = r.sum().backward();
+        let g2 = g.get(&t).array();
+        // each column's gradient is orthogonal to the column & to the ones vector
+        let t = t.array();
+        for j in 0..3 {
+            let dot: TestDtype = (0..4).map(|i| g2[i][j] * t[i][j]).sum();
+            let sum: TestDtype = (0..4).map(|i| g2[i][j]).sum();
+            assert!(dot.abs() < 1e-5);
+            assert!(sum.abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_cov_corrcoef_batched() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank3<2, 6, 3>, TestDtype, _> = dev.sample_normal();
+        let w: Tensor<Rank3<2, 3, 3>, TestDtype, _> = dev.sample_normal();
+        let r = t.trace().cov();
+        let r_arr = r.array();
+        let g = (r * w.clone()).sum().backward().get(&t).array();
+        let r_c = t.trace().corrcoef();
+        let r_c_arr = r_c.array();
+        let g_c = (r_c * w.clone()).sum().backward().get(&t).array();
+        for b in 0..2 {
+            let tb = t.clone().select(dev.tensor(b));
+            let wb = w.clone().select(dev.tensor(b));
+
+            let rb = tb.trace().cov();
+            assert_close(&r_arr[b], &rb.array());
+            let gb = (rb * wb.clone()).sum().backward();
+            assert_close(&g[b], &gb.get(&tb).array());
+
+            let rb = tb.trace().corrcoef();
+            assert_close(&r_c_arr[b], &rb.array());
+            let gb = (rb * wb).sum().backward();
+            assert_close(&g_c[b], &gb.get(&tb).array());
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use crate::shapes::{Dtype, Shape};
+use crate::tensor::cpu::{Cpu, CpuError, LendingIterator, StridedArray};
+
+use super::{CumProdKernel, CumProdOp};
+
+use std::sync::Arc;
+
+/// Copies `src` into a new contiguous array
+fn contiguous<S: Shape, E: Dtype>(
+    src: &StridedArray<S, E>,
+) -> Result<StridedArray<S, E>, CpuError> {
+    let mut dst = StridedArray::new(src.shape)?;
+    let mut dst_iter = dst.iter_mut_with_index();
+    while let Some((d, i)) = dst_iter.next() {
+        *d = src[i];
+    }
+    Ok(dst)
+}
+
+impl<E: Dtype> CumProdKernel<E> for Cpu {
+    fn forward<S: Shape>(
+        &self,
+        op: CumProdOp,
+        inp: &Self::Storage<S, E>,
+    ) -> Result<Self::Storage<S, E>, Self::Err> {
+        if inp.shape.num_elements() == 0 {
+            return StridedArray::new(inp.shape);
+        }
+        let mut out = contiguous(inp)?;
+
+        // out is contiguous, so the previous element along the axis is `stride` behind
+        let size = inp.shape.concrete()[op.axis];
+        let stride = out.strides[op.axis];
+        let data = Arc::make_mut(&mut out.data);
+        for i in 0..data.len() {
+            if (i / stride) % size > 0 {
+                data[i] = data[i] * data[i - stride];
+            }
+        }
+        Ok(out)
+    }
+
+    fn backward<S: Shape>(
+        &self,
+        op: CumProdOp,
+        inp: &Self::Storage<S, E>,
+        grad_inp: &mut Self::Storage<S, E>,
+        out: &Self::Storage<S, E>,
+        grad_out: &Self::Storage<S, E>,
+    ) -> Result<(), Self::Err> {
+        if inp.shape.num_elements() == 0 {
+            return Ok(());
+        }
+        let inp = contiguous(inp)?;
+        let out = contiguous(out)?;
+        let mut tmp = contiguous(grad_out)?;
+
+        // The gradient of x[i] is out[i - 1] * s[i], where s is accumulated in reverse:
+        // s[i] = grad_out[i] + x[i + 1] * s[i + 1]
+        let size = inp.shape.concrete()[op.axis];
+        let stride = tmp.strides[op.axis];
+        let data = Arc::make_mut(&mut tmp.data);
+        for i in (0..data.len()).rev() {
+            let pos = (i / stride) % size;
+            if pos + 1 < size {
+                data[i] = data[i] + inp.data[i + stride] * data[i + stride];
+            }
+        }
+        for (i, d) in data.iter_mut().enumerate() {
+            if (i / stride) % size > 0 {
+                *d *= out.data[i - stride];
+            }
+        }
+
+        let mut tmp_iter = tmp.iter_with_index();
+        while let Some((t, i)) = tmp_iter.next() {
+            grad_inp[i] += *t;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,122 @@
+#include "cuda_utils.cuh"
+
+struct CumProdOp {
+    size_t axis;
+};
+
+// Computes the strided offsets of the first element of the `line`th line along op.axis
+__device__ void get_line_offsets(
+    const CumProdOp op,
+    size_t line,
+    const size_t num_dims,
+    const size_t *dims,
+    const size_t *inp_strides,
+    const size_t *out_strides,
+    size_t *inp_i,
+    size_t *out_i
+) {
+    *inp_i = 0;
+    *out_i = 0;
+    for (int d = num_dims - 1; d >= 0; d--) {
+        if (d == op.axis) {
+            continue;
+        }
+        const size_t i_dim = line % dims[d];
+        *inp_i += i_dim * inp_strides[d];
+        *out_i += i_dim * out_strides[d];
+        line /= dims[d];
+    }
+}
+
+template<typename T>
+__device__ void cumprod_fwd(
+    const CumProdOp op,
+    const size_t num_lines,
+    const size_t num_dims,
+    const size_t *dims,
+    const size_t *inp_strides,
+    const size_t *out_strides,
+    const T *inp,
+    T *out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= num_lines) {
+        return;
+    }
+
+    size_t inp_i, out_i;
+    get_line_offsets(op, i, num_dims, dims, inp_strides, out_strides, &inp_i, &out_i);
+
+    T acc = 1.0;
+    for (size_t k = 0; k < dims[op.axis]; k++) {
+        acc *= inp[inp_i + k * inp_strides[op.axis]];
+        out[out_i + k * out_strides[op.axis]] = acc;
+    }
+}
+
+// The gradient of x[k] is out[k - 1] * s[k], where s is accumulated in reverse:
+// s[k] = grad_out[k] + x[k + 1] * s[k + 1]
+template<typename T>
+__device__ void cumprod_bwd(
+    const CumProdOp op,
+    const size_t num_lines,
+    const size_t num_dims,
+    const size_t *dims,
+    const size_t *inp_strides,
+    const size_t *out_strides,
+    const T *inp,
+    T *grad_inp,
+    const T *out,
+    const T *grad_out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= num_lines) {
+        return;
+    }
+
+    size_t inp_i, out_i;
+    get_line_offsets(op, i, num_dims, dims, inp_strides, out_strides, &inp_i, &out_i);
+
+    const size_t inp_stride = inp_strides[op.axis];
+    const size_t out_stride = out_strides[op.axis];
+    T s = 0.0;
+    T x_next = 0.0;
+    for (size_t k = dims[op.axis]; k > 0; k--) {
+        const size_t j = k - 1;
+        s = grad_out[out_i + j * out_stride] + x_next * s;
+        const T prefix = j > 0 ? out[out_i + (j - 1) * out_stride] : 1.0;
+        atomicAdd(grad_inp + inp_i + j * inp_stride, prefix * s);
+        x_next = inp[inp_i + j * inp_stride];
+    }
+}
+
+#define CUMPROD_OP(TYPENAME, FWD, BWD) \
+extern "C" __global__ void FWD( \
+    const CumProdOp op, \
+    const size_t num_lines, \
+    const size_t num_dims, \
+    const size_t *dims, \
+    const size_t *inp_strides, \
+    const size_t *out_strides, \
+    const TYPENAME *inp, \
+    TYPENAME *out \
+) { \
+    cumprod_fwd(op, num_lines, num_dims, dims, inp_strides, out_strides, inp, out); \
+} \
+extern "C" __global__ void BWD( \
+    const CumProdOp op, \
+    const size_t num_lines, \
+    const size_t num_dims, \
+    const size_t *dims, \
+    const size_t *inp_strides, \
+    const size_t *out_strides, \
+    const TYPENAME *inp, \
+    TYPENAME *grad_inp, \
+    const TYPENAME *out, \
+    const TYPENAME *grad_out \
+) { \
+    cumprod_bwd(op, num_lines, num_dims, dims, inp_strides, out_strides, inp, grad_inp, out, grad_out); \
+}
+
+CUMPROD_OP(float, cumprod_fwd_f32, cumprod_bwd_f32);
+CUMPROD_OP(double, cumprod_fwd_f64, cumprod_bwd_f64);
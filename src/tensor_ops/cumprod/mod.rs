@@ -0,0 +1,150 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{gradients::Tape, shapes::*, tensor::*};
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct CumProdOp {
+    /// The axis to accumulate along
+    pub axis: usize,
+}
+
+pub trait CumProdKernel<E: Dtype>: DeviceStorage {
+    fn forward<S: Shape>(
+        &self,
+        op: CumProdOp,
+        inp: &Self::Storage<S, E>,
+    ) -> Result<Self::Storage<S, E>, Self::Err>;
+    fn backward<S: Shape>(
+        &self,
+        op: CumProdOp,
+        inp: &Self::Storage<S, E>,
+        grad_inp: &mut Self::Storage<S, E>,
+        out: &Self::Storage<S, E>,
+        grad_out: &Self::Storage<S, E>,
+    ) -> Result<(), Self::Err>;
+}
+
+/// Cumulative product along `Ax`. The `i`th element of the output along `Ax` is the
+/// product of the first `i + 1` elements of the input.
+///
+/// The backward pass never divides by the input, so gradients are correct even when
+/// the input contains zeros.
+///
+/// **Pytorch equivalent**: `t.cumprod(dim=Ax)`
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+/// let r = t.clone().cumprod::<Axis<1>>();
+/// assert_eq!(r.array(), [[1.0, 2.0, 6.0], [4.0, 20.0, 120.0]]);
+/// let r = t.cumprod::<Axis<0>>();
+/// assert_eq!(r.array(), [[1.0, 2.0, 3.0], [4.0, 10.0, 18.0]]);
+/// ```
+pub trait TryCumProd: HasErr + HasShape {
+    fn cumprod<Ax: Axes<Array = [isize; 1]>>(self) -> Self
+    where
+        Self::Shape: HasAxes<Ax>,
+    {
+        self.try_cumprod::<Ax>().unwrap()
+    }
+    fn try_cumprod<Ax: Axes<Array = [isize; 1]>>(self) -> Result<Self, Self::Err>
+    where
+        Self::Shape: HasAxes<Ax>;
+}
+
+impl<S: Shape, E: Dtype, D: CumProdKernel<E>, T: Tape<D>> TryCumProd for Tensor<S, E, D, T> {
+    fn try_cumprod<Ax: Axes<Array = [isize; 1]>>(self) -> Result<Self, Self::Err>
+    where
+        Self::Shape: HasAxes<Ax>,
+    {
+        if S::NUM_DIMS == 0 {
+            return Ok(self);
+        }
+        let op = CumProdOp {
+            axis: Ax::as_array()[0] as usize,
+        };
+        let (inp, mut tape) = self.split_tape();
+        let out = inp.device.upgrade(inp.device.forward(op, &inp.storage)?);
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&inp)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+            inp.device
+                .backward(op, &inp.storage, grad_inp, &phantom_out.storage, grad_out)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_cumprod_1d() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([2.0, -1.0, 3.0, 0.5]);
+        let r = t.trace().cumprod::<Axis<0>>();
+        assert_eq!(r.array(), [2.0, -2.0, -6.0, -3.0]);
+        let g = r.sum().backward();
+        // d/dx0 = 1 + x1 + x1x2 + x1x2x3, etc.
+        assert_eq!(g.get(&t).array(), [-4.5, 11.0, -3.0, -6.0]);
+    }
+
+    #[test]
+    fn test_cumprod_with_zeros() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([2.0, 0.0, 3.0, 0.0]);
+        let r = t.trace().cumprod::<Axis<0>>();
+        assert_eq!(r.array(), [2.0, 0.0, 0.0, 0.0]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&t).array(), [1.0, 8.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_cumprod_3d_middle_axis() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank3<2, 3, 2>, TestDtype, _> = dev.sample_normal();
+        let r = t.trace().cumprod::<Axis<1>>();
+        let t_arr = t.array();
+        let r_arr = r.array();
+        for i in 0..2 {
+            for k in 0..2 {
+                let mut acc = 1.0;
+                for j in 0..3 {
+                    acc *= t_arr[i][j][k];
+                    assert_close(&r_arr[i][j][k], &acc);
+                }
+            }
+        }
+        let g = r.sum().backward();
+        let g_arr = g.get(&t).array();
+        for i in 0..2 {
+            for k in 0..2 {
+                let [a, b, c] = [t_arr[i][0][k], t_arr[i][1][k], t_arr[i][2][k]];
+                assert_close(&g_arr[i][0][k], &(1.0 + b + b * c));
+                assert_close(&g_arr[i][1][k], &(a + a * c));
+                assert_close(&g_arr[i][2][k], &(a * b));
+            }
+        }
+    }
+
+    #[test]
+    fn test_cumprod_broadcasted() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([2.0, 3.0]);
+        let r = t.trace().broadcast::<Rank2<3, 2>, _>().cumprod::<Axis<0>>();
+        assert_eq!(r.array(), [[2.0, 3.0], [4.0, 9.0], [8.0, 27.0]]);
+        let g = r.sum().backward();
+        // d/dx (x + x^2 + x^3) = 1 + 2x + 3x^2
+        assert_eq!(g.get(&t).array(), [17.0, 34.0]);
+    }
+}
@@ -0,0 +1,66 @@
+use crate::shapes::{Dtype, Shape};
+use crate::tensor::cpu::{Cpu, LendingIterator, StridedArray};
+
+use super::{CumSumKernel, CumSumOp};
+
+use std::sync::Arc;
+
+impl<E: Dtype> CumSumKernel<E> for Cpu {
+    fn forward<S: Shape>(
+        &self,
+        op: CumSumOp,
+        inp: &Self::Storage<S, E>,
+    ) -> Result<Self::Storage<S, E>, Self::Err> {
+        let mut out = StridedArray::new(inp.shape)?;
+        if out.data.is_empty() {
+            return Ok(out);
+        }
+        let mut out_iter = out.iter_mut_with_index();
+        while let Some((o, i)) = out_iter.next() {
+            *o = inp[i];
+        }
+
+        // out is contiguous, so the previous element along the axis is `stride` behind
+        let size = inp.shape.concrete()[op.axis];
+        let stride = out.strides[op.axis];
+        let data = Arc::make_mut(&mut out.data);
+        for i in 0..data.len() {
+            if (i / stride) % size > 0 {
+                data[i] = data[i] + data[i - stride];
+            }
+        }
+        Ok(out)
+    }
+
+    fn backward<S: Shape>(
+        &self,
+        op: CumSumOp,
+        grad_inp: &mut Self::Storage<S, E>,
+        grad_out: &Self::Storage<S, E>,
+    ) -> Result<(), Self::Err> {
+        let mut tmp = StridedArray::new(grad_out.shape)?;
+        if tmp.data.is_empty() {
+            return Ok(());
+        }
+        let mut tmp_iter = tmp.iter_mut_with_index();
+        while let Some((t, i)) = tmp_iter.next() {
+            *t = grad_out[i];
+        }
+
+        // reversed cumsum
+        let size = grad_out.shape.concrete()[op.axis];
+        let stride = tmp.strides[op.axis];
+        let data = Arc::make_mut(&mut tmp.data);
+        for i in (0..data.len()).rev() {
+            if (i / stride) % size + 1 < size {
+                data[i] = data[i] + data[i + stride];
+            }
+        }
+
+        let mut tmp_iter = tmp.iter_with_index();
+        while let Some((t, i)) = tmp_iter.next() {
+            grad_inp[i] += *t;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,110 @@
+#include "cuda_utils.cuh"
+
+struct CumSumOp {
+    size_t axis;
+};
+
+// Computes the strided offsets of the first element of the `line`th line along op.axis
+__device__ void get_line_offsets(
+    const CumSumOp op,
+    size_t line,
+    const size_t num_dims,
+    const size_t *dims,
+    const size_t *inp_strides,
+    const size_t *out_strides,
+    size_t *inp_i,
+    size_t *out_i
+) {
+    *inp_i = 0;
+    *out_i = 0;
+    for (int d = num_dims - 1; d >= 0; d--) {
+        if (d == op.axis) {
+            continue;
+        }
+        const size_t i_dim = line % dims[d];
+        *inp_i += i_dim * inp_strides[d];
+        *out_i += i_dim * out_strides[d];
+        line /= dims[d];
+    }
+}
+
+template<typename T>
+__device__ void cumsum_fwd(
+    const CumSumOp op,
+    const size_t num_lines,
+    const size_t num_dims,
+    const size_t *dims,
+    const size_t *inp_strides,
+    const size_t *out_strides,
+    const T *inp,
+    T *out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= num_lines) {
+        return;
+    }
+
+    size_t inp_i, out_i;
+    get_line_offsets(op, i, num_dims, dims, inp_strides, out_strides, &inp_i, &out_i);
+
+    T acc = 0.0;
+    for (size_t k = 0; k < dims[op.axis]; k++) {
+        acc += inp[inp_i + k * inp_strides[op.axis]];
+        out[out_i + k * out_strides[op.axis]] = acc;
+    }
+}
+
+template<typename T>
+__device__ void cumsum_bwd(
+    const CumSumOp op,
+    const size_t num_lines,
+    const size_t num_dims,
+    const size_t *dims,
+    const size_t *inp_strides,
+    const size_t *out_strides,
+    T *grad_inp,
+    const T *grad_out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= num_lines) {
+        return;
+    }
+
+    size_t inp_i, out_i;
+    get_line_offsets(op, i, num_dims, dims, inp_strides, out_strides, &inp_i, &out_i);
+
+    T acc = 0.0;
+    for (size_t k = dims[op.axis]; k > 0; k--) {
+        acc += grad_out[out_i + (k - 1) * out_strides[op.axis]];
+        atomicAdd(grad_inp + inp_i + (k - 1) * inp_strides[op.axis], acc);
+    }
+}
+
+#define CUMSUM_OP(TYPENAME, FWD, BWD) \
+extern "C" __global__ void FWD( \
+    const CumSumOp op, \
+    const size_t num_lines, \
+    const size_t num_dims, \
+    const size_t *dims, \
+    const size_t *inp_strides, \
+    const size_t *out_strides, \
+    const TYPENAME *inp, \
+    TYPENAME *out \
+) { \
+    cumsum_fwd(op, num_lines, num_dims, dims, inp_strides, out_strides, inp, out); \
+} \
+extern "C" __global__ void BWD( \
+    const CumSumOp op, \
+    const size_t num_lines, \
+    const size_t num_dims, \
+    const size_t *dims, \
+    const size_t *inp_strides, \
+    const size_t *out_strides, \
+    TYPENAME *grad_inp, \
+    const TYPENAME *grad_out \
+) { \
+    cumsum_bwd(op, num_lines, num_dims, dims, inp_strides, out_strides, grad_inp, grad_out); \
+}
+
+CUMSUM_OP(float, cumsum_fwd_f32, cumsum_bwd_f32);
+CUMSUM_OP(double, cumsum_fwd_f64, cumsum_bwd_f64);
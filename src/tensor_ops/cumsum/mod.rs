@@ -0,0 +1,126 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{gradients::Tape, shapes::*, tensor::*};
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct CumSumOp {
+    /// The axis to accumulate along
+    pub axis: usize,
+}
+
+pub trait CumSumKernel<E: Dtype>: DeviceStorage {
+    fn forward<S: Shape>(
+        &self,
+        op: CumSumOp,
+        inp: &Self::Storage<S, E>,
+    ) -> Result<Self::Storage<S, E>, Self::Err>;
+    fn backward<S: Shape>(
+        &self,
+        op: CumSumOp,
+        grad_inp: &mut Self::Storage<S, E>,
+        grad_out: &Self::Storage<S, E>,
+    ) -> Result<(), Self::Err>;
+}
+
+/// Cumulative sum along `Ax`. The `i`th element of the output along `Ax` is the sum
+/// of the first `i + 1` elements of the input.
+///
+/// The gradient is the reversed cumulative sum of the output's gradient.
+///
+/// **Pytorch equivalent**: `t.cumsum(dim=Ax)`
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+/// let r = t.clone().cumsum::<Axis<1>>();
+/// assert_eq!(r.array(), [[1.0, 3.0, 6.0], [4.0, 9.0, 15.0]]);
+/// let r = t.cumsum::<Axis<0>>();
+/// assert_eq!(r.array(), [[1.0, 2.0, 3.0], [5.0, 7.0, 9.0]]);
+/// ```
+pub trait TryCumSum: HasErr + HasShape {
+    fn cumsum<Ax: Axes<Array = [isize; 1]>>(self) -> Self
+    where
+        Self::Shape: HasAxes<Ax>,
+    {
+        self.try_cumsum::<Ax>().unwrap()
+    }
+    fn try_cumsum<Ax: Axes<Array = [isize; 1]>>(self) -> Result<Self, Self::Err>
+    where
+        Self::Shape: HasAxes<Ax>;
+}
+
+impl<S: Shape, E: Dtype, D: CumSumKernel<E>, T: Tape<D>> TryCumSum for Tensor<S, E, D, T> {
+    fn try_cumsum<Ax: Axes<Array = [isize; 1]>>(self) -> Result<Self, Self::Err>
+    where
+        Self::Shape: HasAxes<Ax>,
+    {
+        if S::NUM_DIMS == 0 {
+            return Ok(self);
+        }
+        let op = CumSumOp {
+            axis: Ax::as_array()[0] as usize,
+        };
+        let (inp, mut tape) = self.split_tape();
+        let out = inp.device.upgrade(inp.device.forward(op, &inp.storage)?);
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&inp)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+            inp.device.backward(op, grad_inp, grad_out)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_cumsum_1d() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([1.0, -2.0, 3.0, 0.5]);
+        let r = t.trace().cumsum::<Axis<0>>();
+        assert_eq!(r.array(), [1.0, -1.0, 2.0, 2.5]);
+        let g = (r * dev.tensor([1.0, 2.0, 3.0, 4.0])).sum().backward();
+        assert_eq!(g.get(&t).array(), [10.0, 9.0, 7.0, 4.0]);
+    }
+
+    #[test]
+    fn test_cumsum_3d_middle_axis() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank3<2, 3, 2>, TestDtype, _> = dev.sample_normal();
+        let r = t.trace().cumsum::<Axis<1>>();
+        let t_arr = t.array();
+        let r_arr = r.array();
+        for i in 0..2 {
+            for k in 0..2 {
+                let mut acc = 0.0;
+                for j in 0..3 {
+                    acc += t_arr[i][j][k];
+                    assert_close(&r_arr[i][j][k], &acc);
+                }
+            }
+        }
+        let g = r.sum().backward();
+        assert_eq!(g.get(&t).array(), [[[3.0; 2], [2.0; 2], [1.0; 2]]; 2]);
+    }
+
+    #[test]
+    fn test_cumsum_broadcasted() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([1.0, 2.0]);
+        let r = t.trace().broadcast::<Rank2<3, 2>, _>().cumsum::<Axis<0>>();
+        assert_eq!(r.array(), [[1.0, 2.0], [2.0, 4.0], [3.0, 6.0]]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&t).array(), [6.0, 6.0]);
+    }
+}
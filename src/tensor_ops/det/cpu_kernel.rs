@@ -0,0 +1,92 @@
+use crate::{
+    shapes::*,
+    tensor::cpu::{Cpu, LendingIterator, StridedArray},
+    tensor_ops::utilities::linalg::{lu_factor, lu_solve, MatrixShape},
+};
+
+use num_traits::Float;
+use std::{sync::Arc, vec::Vec};
+
+impl<E: Dtype + Float> super::SlogDetKernel<E> for Cpu {
+    fn forward<S: MatrixShape>(
+        &self,
+        inp: &Self::Storage<S, E>,
+    ) -> Result<
+        (
+            Self::Storage<S::Batch, E>,
+            Self::Storage<S::Batch, E>,
+            Self::Storage<S, E>,
+        ),
+        Self::Err,
+    > {
+        let n = inp.shape.rows();
+        let batch = inp.shape.batch();
+        let mut sign: StridedArray<S::Batch, E> = StridedArray::new(batch)?;
+        let mut logabsdet: StridedArray<S::Batch, E> = StridedArray::new(batch)?;
+        let mut inv: StridedArray<S, E> = StridedArray::new(inp.shape)?;
+        if inp.shape.num_elements() == 0 {
+            // the determinant of an empty matrix is 1
+            Arc::make_mut(&mut sign.data).fill(E::one());
+            return Ok((sign, logabsdet, inv));
+        }
+
+        let mut buf: Vec<E> = Vec::with_capacity(inp.shape.num_elements());
+        let mut inp_iter = inp.iter();
+        while let Some(x) = inp_iter.next() {
+            buf.push(*x);
+        }
+
+        let sign_buf = Arc::make_mut(&mut sign.data);
+        let logabsdet_buf = Arc::make_mut(&mut logabsdet.data);
+        let inv_buf = Arc::make_mut(&mut inv.data);
+        for (b, a) in buf.chunks_exact_mut(n * n).enumerate() {
+            let (perm, mut s) = lu_factor(a, n);
+            let mut l = E::zero();
+            for i in 0..n {
+                let d = a[i * n + i];
+                if d < E::zero() {
+                    s = -s;
+                }
+                l += d.abs().ln();
+            }
+            sign_buf[b] = if l == E::neg_infinity() { E::zero() } else { s };
+            logabsdet_buf[b] = l;
+
+            let inv_b = &mut inv_buf[b * n * n..(b + 1) * n * n];
+            for i in 0..n {
+                inv_b[i * n + i] = E::one();
+            }
+            lu_solve(a, &perm, n, inv_b, n);
+        }
+        Ok((sign, logabsdet, inv))
+    }
+
+    fn backward<S: MatrixShape>(
+        &self,
+        inv: &Self::Storage<S, E>,
+        grad_inp: &mut Self::Storage<S, E>,
+        grad_out: &Self::Storage<S::Batch, E>,
+    ) -> Result<(), Self::Err> {
+        if grad_inp.shape.num_elements() == 0 {
+            return Ok(());
+        }
+        let n = inv.shape.rows();
+        let inv_buf = inv.data.as_ref();
+        let mut grad_out_iter = grad_out.iter();
+        let mut grad_inp_iter = grad_inp.iter_mut();
+        let mut b = 0;
+        while let Some(go) = grad_out_iter.next() {
+            let inv_b = &inv_buf[b * n * n..(b + 1) * n * n];
+            for i in 0..n {
+                for j in 0..n {
+                    let g = grad_inp_iter.next().unwrap();
+                    if *go != E::zero() {
+                        *g += *go * inv_b[j * n + i];
+                    }
+                }
+            }
+            b += 1;
+        }
+        Ok(())
+    }
+}
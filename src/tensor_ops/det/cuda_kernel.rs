@@ -0,0 +1,35 @@
+use crate::{shapes::*, tensor::cuda::Cuda, tensor_ops::utilities::linalg::MatrixShape};
+
+use num_traits::Float;
+
+impl<E: Dtype + Float> super::SlogDetKernel<E> for Cuda {
+    fn forward<S: MatrixShape>(
+        &self,
+        inp: &Self::Storage<S, E>,
+    ) -> Result<
+        (
+            Self::Storage<S::Batch, E>,
+            Self::Storage<S::Batch, E>,
+            Self::Storage<S, E>,
+        ),
+        Self::Err,
+    > {
+        self.on_host(inp, |cpu, inp| cpu.forward(&inp))
+    }
+
+    fn backward<S: MatrixShape>(
+        &self,
+        inv: &Self::Storage<S, E>,
+        grad_inp: &mut Self::Storage<S, E>,
+        grad_out: &Self::Storage<S::Batch, E>,
+    ) -> Result<(), Self::Err> {
+        *grad_inp = self.on_host(
+            (inv, &*grad_inp, grad_out),
+            |cpu, (inv, mut grad_inp, grad_out)| {
+                cpu.backward(&inv, &mut grad_inp, &grad_out)?;
+                Ok(grad_inp)
+            },
+        )?;
+        Ok(())
+    }
+}
@@ -0,0 +1,185 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{gradients::Tape, shapes::*, tensor::*};
+
+use super::{utilities::linalg::MatrixShape, Device, TryMul};
+
+pub trait SlogDetKernel<E: Dtype>: DeviceStorage {
+    /// Returns the sign and log absolute value of the determinant of each matrix,
+    /// and the inverse of each matrix.
+    #[allow(clippy::type_complexity)]
+    fn forward<S: MatrixShape>(
+        &self,
+        inp: &Self::Storage<S, E>,
+    ) -> Result<
+        (
+            Self::Storage<S::Batch, E>,
+            Self::Storage<S::Batch, E>,
+            Self::Storage<S, E>,
+        ),
+        Self::Err,
+    >;
+
+    /// Adds `grad_out * inv^T` to `grad_inp` for each matrix. Matrices with a zero
+    /// `grad_out` are skipped, so singular matrices don't produce NaNs unless they are used.
+    fn backward<S: MatrixShape>(
+        &self,
+        inv: &Self::Storage<S, E>,
+        grad_inp: &mut Self::Storage<S, E>,
+        grad_out: &Self::Storage<S::Batch, E>,
+    ) -> Result<(), Self::Err>;
+}
+
+impl<S: MatrixShape, E: Dtype, D: SlogDetKernel<E>, T: Tape<D>> Tensor<S, E, D, T> {
+    /// Sign and natural log of the absolute value of the determinant of a square
+    /// matrix, or a batch of square matrices in the last two axes. Gradients only flow
+    /// through the log determinant, and are `inv(A)^T`.
+    ///
+    /// Singular matrices have a sign of `0` and a log determinant of `-inf`.
+    ///
+    /// **Pytorch equivalent**: `torch.linalg.slogdet(t)`
+    ///
+    /// **Cuda**: currently syncs to the host. The LU factorization runs on the cpu, and
+    /// each call and backward pass copies the matrices to the host and back.
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t = dev.tensor([[1.0, 2.0], [3.0, 4.0]]);
+    /// let (sign, logabsdet) = t.slogdet();
+    /// assert_eq!(sign.array(), -1.0);
+    /// assert!((logabsdet.array() - 2.0f32.ln()).abs() < 1e-6);
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn slogdet(self) -> (Tensor<S::Batch, E, D>, Tensor<S::Batch, E, D, T>) {
+        self.try_slogdet().unwrap()
+    }
+
+    /// Fallible version of [Tensor::slogdet]
+    #[allow(clippy::type_complexity)]
+    pub fn try_slogdet(
+        self,
+    ) -> Result<(Tensor<S::Batch, E, D>, Tensor<S::Batch, E, D, T>), D::Err> {
+        let shape = *self.shape();
+        assert_eq!(shape.rows(), shape.cols(), "matrices must be square");
+        let (inp, mut tape) = self.split_tape();
+        let (sign, logabsdet, inv) = inp.device.forward(&inp.storage)?;
+        let sign = inp.device.upgrade(sign);
+        let out = inp.device.upgrade(logabsdet);
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&inp)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+            inp.device.backward(&inv, grad_inp, grad_out)
+        });
+        Ok((sign, out.put_tape(tape)))
+    }
+}
+
+impl<S: MatrixShape, E: Dtype, D: SlogDetKernel<E> + Device<E>, T: Tape<D>> Tensor<S, E, D, T> {
+    /// Determinant of a square matrix, or a batch of square matrices in the last two axes.
+    /// Computed as `sign * exp(logabsdet)` from [Tensor::slogdet], so gradients are
+    /// `det(A) * inv(A)^T`, which is not finite for singular matrices that receive a
+    /// non-zero gradient.
+    ///
+    /// **Pytorch equivalent**: `torch.linalg.det(t)`
+    ///
+    /// **Cuda**: currently syncs to the host, like [Tensor::slogdet].
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t = dev.tensor([[[2.0, 0.0], [0.0, 3.0]], [[1.0, 2.0], [2.0, 4.0]]]);
+    /// let r = t.det();
+    /// assert_eq!(r.array(), [6.0, 0.0]);
+    /// ```
+    pub fn det(self) -> Tensor<S::Batch, E, D, T> {
+        self.try_det().unwrap()
+    }
+
+    /// Fallible version of [Tensor::det]
+    pub fn try_det(self) -> Result<Tensor<S::Batch, E, D, T>, D::Err> {
+        let (sign, logabsdet) = self.try_slogdet()?;
+        logabsdet.try_exp()?.try_mul(sign)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{shapes::*, tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_det_2d() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([[1.0, 2.0], [3.0, 4.0]]);
+        let r = t.trace().det();
+        assert_close(&r.array(), &-2.0);
+        let g = r.backward();
+        assert_close(&g.get(&t).array(), &[[4.0, -3.0], [-2.0, 1.0]]);
+    }
+
+    #[test]
+    fn test_slogdet_2d() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([[1.0, 2.0], [3.0, 4.0]]);
+        let (sign, r) = t.trace().slogdet();
+        assert_eq!(sign.array(), -1.0);
+        assert_close(&r.array(), &(2.0 as TestDtype).ln());
+        let g = r.backward();
+        assert_close(&g.get(&t).array(), &[[-2.0, 1.5], [1.0, -0.5]]);
+    }
+
+    #[test]
+    fn test_det_3x3_permuted() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> =
+            dev.tensor([[2.0, -1.0, 0.0], [-1.0, 2.0, -1.0], [0.0, -1.0, 3.0]]);
+        let r = t.trace().det();
+        assert_close_with_tolerance(&r.array(), &7.0, 1e-5);
+        let r2 = t.trace().permute::<_, Axes2<1, 0>>().det();
+        assert_close_with_tolerance(&r2.array(), &7.0, 1e-5);
+
+        // the gradient of det is the cofactor matrix
+        let cofactors = [[5.0, 3.0, 1.0], [3.0, 6.0, 2.0], [1.0, 2.0, 3.0]];
+        let g = r.backward();
+        assert_close_with_tolerance(&g.get(&t).array(), &cofactors, 1e-5);
+        let g2 = r2.backward();
+        assert_close_with_tolerance(&g2.get(&t).array(), &cofactors, 1e-5);
+    }
+
+    #[test]
+    fn test_det_batched() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([
+            [[[2.0, 0.0], [0.0, 3.0]], [[0.0, 1.0], [1.0, 0.0]]],
+            [[[1.0, 2.0], [3.0, 4.0]], [[1.0, 1.0], [1.0, 1.0]]],
+        ]);
+        let r = t.trace().det();
+        assert_close(&r.array(), &[[6.0, -1.0], [-2.0, 0.0]]);
+        let (sign, logabsdet) = t.trace().slogdet();
+        assert_eq!(sign.array(), [[1.0, -1.0], [-1.0, 0.0]]);
+        assert_eq!(logabsdet.array()[1][1], TestDtype::NEG_INFINITY);
+
+        let g = r.select(dev.tensor([0, 1])).sum().backward();
+        assert_close(
+            &g.get(&t).array(),
+            &[
+                [[[3.0, 0.0], [0.0, 2.0]], [[0.0; 2]; 2]],
+                [[[0.0; 2]; 2], [[0.0; 2]; 2]],
+            ],
+        );
+    }
+
+    #[test]
+    fn test_slogdet_matches_det() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank3<4, 3, 3>, TestDtype, _> = dev.sample_normal();
+        let (sign, logabsdet) = t.clone().slogdet();
+        let det = t.det();
+        assert_close(&(logabsdet.exp() * sign).array(), &det.array());
+    }
+}
@@ -0,0 +1,53 @@
+use crate::shapes::{Dtype, Shape};
+use crate::tensor::cpu::{Cpu, LendingIterator};
+
+use super::{DiagonalKernel, DiagonalOp};
+
+impl DiagonalOp {
+    /// Index of `mat` that the element of `vec` at `idx` lies at
+    #[inline(always)]
+    fn mat_index<M: Shape, V: Shape>(&self, idx: V::Concrete) -> M::Concrete {
+        let mut mat_idx: M::Concrete = Default::default();
+        for d in 0..V::NUM_DIMS - 1 {
+            mat_idx[d] = idx[d];
+        }
+        let k = idx[V::NUM_DIMS - 1];
+        mat_idx[M::NUM_DIMS - 2] = self.row + k;
+        mat_idx[M::NUM_DIMS - 1] = self.col + k;
+        mat_idx
+    }
+}
+
+impl<E: Dtype> DiagonalKernel<E> for Cpu {
+    fn forward<M: Shape, V: Shape>(
+        &self,
+        op: DiagonalOp,
+        mat: &Self::Storage<M, E>,
+        vec: &mut Self::Storage<V, E>,
+    ) -> Result<(), Self::Err> {
+        if vec.shape.num_elements() == 0 {
+            return Ok(());
+        }
+        let mut vec_iter = vec.iter_mut_with_index();
+        while let Some((v, i)) = vec_iter.next() {
+            *v += mat[op.mat_index::<M, V>(i)];
+        }
+        Ok(())
+    }
+
+    fn backward<M: Shape, V: Shape>(
+        &self,
+        op: DiagonalOp,
+        mat: &mut Self::Storage<M, E>,
+        vec: &Self::Storage<V, E>,
+    ) -> Result<(), Self::Err> {
+        if vec.shape.num_elements() == 0 {
+            return Ok(());
+        }
+        let mut vec_iter = vec.iter_with_index();
+        while let Some((v, i)) = vec_iter.next() {
+            mat[op.mat_index::<M, V>(i)] += *v;
+        }
+        Ok(())
+    }
+}
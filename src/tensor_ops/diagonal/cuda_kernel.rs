@@ -0,0 +1,95 @@
+use crate::{shapes::*, tensor::cuda::Cuda};
+use cudarc::driver::{AsKernelParam, LaunchAsync, LaunchConfig};
+use std::sync::Arc;
+
+const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/diagonal.ptx"));
+
+unsafe impl AsKernelParam for super::DiagonalOp {}
+
+trait HasCudaKernel<E> {
+    const MOD: &'static str;
+    const FNS: &'static [&'static str];
+}
+
+impl HasCudaKernel<f32> for Cuda {
+    const MOD: &'static str = "diagonal_f32";
+    const FNS: &'static [&'static str] = &["diagonal_fwd_f32", "diagonal_bwd_f32"];
+}
+
+impl HasCudaKernel<f64> for Cuda {
+    const MOD: &'static str = "diagonal_f64";
+    const FNS: &'static [&'static str] = &["diagonal_fwd_f64", "diagonal_bwd_f64"];
+}
+
+impl<E: Dtype> super::DiagonalKernel<E> for Cuda
+where
+    Self: HasCudaKernel<E>,
+{
+    fn forward<M: Shape, V: Shape>(
+        &self,
+        op: super::DiagonalOp,
+        mat: &Self::Storage<M, E>,
+        vec: &mut Self::Storage<V, E>,
+    ) -> Result<(), Self::Err> {
+        if !self.dev.has_func(Self::MOD, Self::FNS[0]) {
+            self.dev.load_ptx(PTX_SRC.into(), Self::MOD, Self::FNS)?;
+        }
+
+        let numel = vec.shape.num_elements();
+        if numel == 0 {
+            return Ok(());
+        }
+        let dims = self.dev.take_async(vec.shape.concrete().into())?;
+        let vec_strides = self.dev.take_async(vec.strides.into())?;
+        let mat_strides = self.dev.take_async(mat.strides.into())?;
+
+        let fwd_fn = self.dev.get_func(Self::MOD, Self::FNS[0]).unwrap();
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            op,                           // const DiagonalOp op,
+            numel,                        // const size_t numel,
+            V::NUM_DIMS,                  // const size_t num_dims,
+            &dims,                        // const size_t *dims,
+            &vec_strides,                 // const size_t *vec_strides,
+            &mat_strides,                 // const size_t *mat_strides,
+            mat.data.as_ref(),            // const T *mat,
+            Arc::make_mut(&mut vec.data), // T *vec
+        );
+        unsafe { fwd_fn.launch_async(cfg, params) }?;
+        Ok(())
+    }
+
+    fn backward<M: Shape, V: Shape>(
+        &self,
+        op: super::DiagonalOp,
+        mat: &mut Self::Storage<M, E>,
+        vec: &Self::Storage<V, E>,
+    ) -> Result<(), Self::Err> {
+        if !self.dev.has_func(Self::MOD, Self::FNS[1]) {
+            self.dev.load_ptx(PTX_SRC.into(), Self::MOD, Self::FNS)?;
+        }
+
+        let numel = vec.shape.num_elements();
+        if numel == 0 {
+            return Ok(());
+        }
+        let dims = self.dev.take_async(vec.shape.concrete().into())?;
+        let vec_strides = self.dev.take_async(vec.strides.into())?;
+        let mat_strides = self.dev.take_async(mat.strides.into())?;
+
+        let bwd_fn = self.dev.get_func(Self::MOD, Self::FNS[1]).unwrap();
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            op,                           // const DiagonalOp op,
+            numel,                        // const size_t numel,
+            V::NUM_DIMS,                  // const size_t num_dims,
+            &dims,                        // const size_t *dims,
+            &vec_strides,                 // const size_t *vec_strides,
+            &mat_strides,                 // const size_t *mat_strides,
+            Arc::make_mut(&mut mat.data), // T *mat,
+            vec.data.as_ref(),            // const T *vec
+        );
+        unsafe { bwd_fn.launch_async(cfg, params) }?;
+        Ok(())
+    }
+}
@@ -0,0 +1,101 @@
+#include "cuda_utils.cuh"
+
+struct DiagonalOp {
+    size_t row;
+    size_t col;
+};
+
+// Computes the strided indices into `vec` and `mat` for the i'th element
+// of the contiguous `vec`.
+__device__ void get_diagonal_indices(
+    const DiagonalOp op,
+    unsigned int i,
+    const size_t num_dims,
+    const size_t *dims,
+    const size_t *vec_strides,
+    const size_t *mat_strides,
+    unsigned int *vec_i,
+    unsigned int *mat_i
+) {
+    const size_t k = i % dims[num_dims - 1];
+    i /= dims[num_dims - 1];
+    *vec_i = k * vec_strides[num_dims - 1];
+    *mat_i = (op.row + k) * mat_strides[num_dims - 1] + (op.col + k) * mat_strides[num_dims];
+    for (int d = num_dims - 2; d >= 0; d--) {
+        const size_t i_dim = i % dims[d];
+        *vec_i += i_dim * vec_strides[d];
+        *mat_i += i_dim * mat_strides[d];
+        i /= dims[d];
+    }
+}
+
+template<typename T>
+__device__ void diagonal_fwd(
+    const DiagonalOp op,
+    const size_t numel,
+    const size_t num_dims,
+    const size_t *dims,
+    const size_t *vec_strides,
+    const size_t *mat_strides,
+    const T *mat,
+    T *vec
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    unsigned int vec_i, mat_i;
+    get_diagonal_indices(op, i, num_dims, dims, vec_strides, mat_strides, &vec_i, &mat_i);
+    atomicAdd(vec + vec_i, mat[mat_i]);
+}
+
+template<typename T>
+__device__ void diagonal_bwd(
+    const DiagonalOp op,
+    const size_t numel,
+    const size_t num_dims,
+    const size_t *dims,
+    const size_t *vec_strides,
+    const size_t *mat_strides,
+    T *mat,
+    const T *vec
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    unsigned int vec_i, mat_i;
+    get_diagonal_indices(op, i, num_dims, dims, vec_strides, mat_strides, &vec_i, &mat_i);
+    atomicAdd(mat + mat_i, vec[vec_i]);
+}
+
+#define DIAGONAL_OP(TYPENAME, FWD, BWD) \
+extern "C" __global__ void FWD( \
+    const DiagonalOp op, \
+    const size_t numel, \
+    const size_t num_dims, \
+    const size_t *dims, \
+    const size_t *vec_strides, \
+    const size_t *mat_strides, \
+    const TYPENAME *mat, \
+    TYPENAME *vec \
+) { \
+    diagonal_fwd(op, numel, num_dims, dims, vec_strides, mat_strides, mat, vec); \
+} \
+extern "C" __global__ void BWD( \
+    const DiagonalOp op, \
+    const size_t numel, \
+    const size_t num_dims, \
+    const size_t *dims, \
+    const size_t *vec_strides, \
+    const size_t *mat_strides, \
+    TYPENAME *mat, \
+    const TYPENAME *vec \
+) { \
+    diagonal_bwd(op, numel, num_dims, dims, vec_strides, mat_strides, mat, vec); \
+}
+
+DIAGONAL_OP(float, diagonal_fwd_f32, diagonal_bwd_f32);
+DIAGONAL_OP(double, diagonal_fwd_f64, diagonal_bwd_f64);
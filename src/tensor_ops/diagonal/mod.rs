@@ -0,0 +1,240 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{gradients::Tape, shapes::*, tensor::*};
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct DiagonalOp {
+    /// Row of the last two dims that the diagonal starts at
+    pub row: usize,
+    /// Column of the last two dims that the diagonal starts at
+    pub col: usize,
+}
+
+impl DiagonalOp {
+    fn new(offset: isize) -> Self {
+        Self {
+            row: (-offset).max(0) as usize,
+            col: offset.max(0) as usize,
+        }
+    }
+
+    /// The number of elements on the diagonal of a `rows x cols` matrix
+    fn len(&self, rows: usize, cols: usize) -> usize {
+        rows.saturating_sub(self.row)
+            .min(cols.saturating_sub(self.col))
+    }
+}
+
+pub trait DiagonalKernel<E: Dtype>: DeviceStorage {
+    /// Adds the diagonal of the last two dims of `mat` into `vec`
+    fn forward<M: Shape, V: Shape>(
+        &self,
+        op: DiagonalOp,
+        mat: &Self::Storage<M, E>,
+        vec: &mut Self::Storage<V, E>,
+    ) -> Result<(), Self::Err>;
+
+    /// Adds `vec` into the diagonal of the last two dims of `mat`
+    fn backward<M: Shape, V: Shape>(
+        &self,
+        op: DiagonalOp,
+        mat: &mut Self::Storage<M, E>,
+        vec: &Self::Storage<V, E>,
+    ) -> Result<(), Self::Err>;
+}
+
+/// Extracts the `offset`th diagonal of the last two dims of a tensor.
+///
+/// `offset` of `0` is the main diagonal, positive values are above the main diagonal,
+/// and negative values below it. The length of the output's last dimension is the number
+/// of elements on the diagonal, which is only known at runtime.
+///
+/// **Pytorch equivalent**: `t.diagonal(offset, dim1=-2, dim2=-1)`
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+/// let r = t.clone().diagonal(0);
+/// assert_eq!(r.as_vec(), [1.0, 5.0]);
+/// let r = t.clone().diagonal(1);
+/// assert_eq!(r.as_vec(), [2.0, 6.0]);
+/// let r = t.diagonal(-1);
+/// assert_eq!(r.as_vec(), [4.0]);
+/// ```
+pub trait TryDiagonal: HasErr {
+    type Output;
+    fn diagonal(self, offset: isize) -> Self::Output {
+        self.try_diagonal(offset).unwrap()
+    }
+    fn try_diagonal(self, offset: isize) -> Result<Self::Output, Self::Err>;
+}
+
+/// Builds a diagonal matrix out of the last dimension of a tensor. All elements off the
+/// diagonal are zero.
+///
+/// **Pytorch equivalent**: `torch.diag_embed(t)`
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([1.0, 2.0, 3.0]);
+/// let r = t.diag();
+/// assert_eq!(r.array(), [[1.0, 0.0, 0.0], [0.0, 2.0, 0.0], [0.0, 0.0, 3.0]]);
+/// ```
+pub trait TryDiag: HasErr {
+    type Output;
+    fn diag(self) -> Self::Output {
+        self.try_diag().unwrap()
+    }
+    fn try_diag(self) -> Result<Self::Output, Self::Err>;
+}
+
+macro_rules! diagonal_impls {
+    ([$($Vars:tt),*], [$($Idx:tt),*], $RowIdx:tt, $ColIdx:tt) => {
+impl<$($Vars: Dim, )* R: Dim, C: Dim, E: Dtype, D: DiagonalKernel<E> + ZerosTensor<E>, T: Tape<D>>
+    TryDiagonal for Tensor<($($Vars, )* R, C), E, D, T>
+{
+    type Output = Tensor<($($Vars, )* usize,), E, D, T>;
+    fn try_diagonal(self, offset: isize) -> Result<Self::Output, Self::Err> {
+        let op = DiagonalOp::new(offset);
+        let shape = *self.shape();
+        let len = op.len(shape.$RowIdx.size(), shape.$ColIdx.size());
+        let (inp, mut tape) = self.split_tape();
+        let mut out = inp.device.try_zeros_like(&($(shape.$Idx, )* len,))?;
+        inp.device.forward(op, &inp.storage, &mut out.storage)?;
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&inp)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+            inp.device.backward(op, grad_inp, grad_out)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+impl<$($Vars: Dim, )* N: Dim, E: Dtype, D: DiagonalKernel<E> + ZerosTensor<E>, T: Tape<D>>
+    TryDiag for Tensor<($($Vars, )* N,), E, D, T>
+{
+    type Output = Tensor<($($Vars, )* N, N), E, D, T>;
+    fn try_diag(self) -> Result<Self::Output, Self::Err> {
+        let op = DiagonalOp::new(0);
+        let shape = *self.shape();
+        let n = shape.$RowIdx;
+        let (inp, mut tape) = self.split_tape();
+        let mut out = inp.device.try_zeros_like(&($(shape.$Idx, )* n, n))?;
+        inp.device.backward(op, &mut out.storage, &inp.storage)?;
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&inp)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+            inp.device.forward(op, grad_out, grad_inp)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+    };
+}
+
+diagonal_impls!([], [], 0, 1);
+diagonal_impls!([B], [0], 1, 2);
+diagonal_impls!([B, S], [0, 1], 2, 3);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_diagonal_offsets() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        assert_eq!(t.clone().diagonal(0).as_vec(), [1.0, 5.0]);
+        assert_eq!(t.clone().diagonal(1).as_vec(), [2.0, 6.0]);
+        assert_eq!(t.clone().diagonal(2).as_vec(), [3.0]);
+        assert_eq!(t.clone().diagonal(-1).as_vec(), [4.0]);
+        assert_eq!(t.clone().diagonal(3).shape(), &(0,));
+        assert_eq!(t.clone().diagonal(-2).shape(), &(0,));
+    }
+
+    #[test]
+    fn test_diagonal_backward() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank3<2, 3, 4>, TestDtype, _> = dev.sample_normal();
+        let r = t.trace().diagonal(1);
+        assert_eq!(r.shape(), &(Const::<2>, 3));
+        let t_arr = t.array();
+        let r_vec = r.as_vec();
+        for b in 0..2 {
+            for k in 0..3 {
+                assert_eq!(r_vec[b * 3 + k], t_arr[b][k][k + 1]);
+            }
+        }
+        let g = r.exp().sum().backward();
+        let g_arr = g.get(&t).array();
+        for b in 0..2 {
+            for i in 0..3 {
+                for j in 0..4 {
+                    let expected = if j == i + 1 {
+                        t_arr[b][i][j].exp()
+                    } else {
+                        0.0
+                    };
+                    assert_eq!(g_arr[b][i][j], expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_diagonal_broadcasted() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([1.0, 2.0, 3.0]);
+        let r = t.trace().broadcast::<Rank2<3, 3>, Axis<0>>().diagonal(0);
+        assert_eq!(r.as_vec(), [1.0, 2.0, 3.0]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&t).array(), [1.0; 3]);
+    }
+
+    #[test]
+    fn test_diag_1d() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([1.0, 2.0, 3.0]);
+        let r = t.trace().diag();
+        assert_eq!(
+            r.array(),
+            [[1.0, 0.0, 0.0], [0.0, 2.0, 0.0], [0.0, 0.0, 3.0]]
+        );
+        let g = (r * dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]))
+            .sum()
+            .backward();
+        assert_eq!(g.get(&t).array(), [1.0, 5.0, 9.0]);
+    }
+
+    #[test]
+    fn test_diag_2d() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank2<2, 3>, TestDtype, _> = dev.sample_normal();
+        let r = t.trace().diag();
+        let t_arr = t.array();
+        let r_arr = r.array();
+        for b in 0..2 {
+            for i in 0..3 {
+                for (j, &r) in r_arr[b][i].iter().enumerate() {
+                    let expected = if i == j { t_arr[b][i] } else { 0.0 };
+                    assert_eq!(r, expected);
+                }
+            }
+        }
+        let g = r.diagonal(0).exp().sum().backward();
+        assert_eq!(g.get(&t).array(), t.exp().array());
+    }
+}
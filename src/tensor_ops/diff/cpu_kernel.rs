@@ -0,0 +1,73 @@
+use crate::shapes::{Dtype, Shape};
+use crate::tensor::cpu::{Cpu, LendingIterator, StridedArray};
+
+use super::{DiffKernel, DiffOp};
+
+/// The coefficients of the `n`th order differences, i.e. the `j`th output element along
+/// the axis is `sum_k coefs[k] * inp[j + k]`
+fn coefs<E: Dtype>(n: usize) -> std::vec::Vec<E> {
+    // signed binomial coefficients: (-1)^(n - k) * (n choose k)
+    let mut coefs = alloc::vec![E::default(); n + 1];
+    let mut c = E::ONE;
+    for k in 0..=n {
+        coefs[n - k] = if k % 2 == 0 { c } else { E::default() - c };
+        c = c * E::from_usize(n - k).unwrap() / E::from_usize(k + 1).unwrap();
+    }
+    coefs
+}
+
+/// Converts an index into the output into an index into the input
+fn inp_index<S: Shape, Dst: Shape>(i_out: Dst::Concrete) -> S::Concrete {
+    let mut i_inp: S::Concrete = Default::default();
+    for j in 0..S::NUM_DIMS {
+        i_inp[j] = i_out[j];
+    }
+    i_inp
+}
+
+impl<E: Dtype> DiffKernel<E> for Cpu {
+    fn forward<S: Shape, Dst: Shape>(
+        &self,
+        op: DiffOp,
+        inp: &Self::Storage<S, E>,
+        dst: Dst,
+    ) -> Result<Self::Storage<Dst, E>, Self::Err> {
+        let coefs = coefs::<E>(op.n);
+        let mut out: Self::Storage<Dst, E> = StridedArray::new(dst)?;
+        if dst.num_elements() == 0 {
+            return Ok(out);
+        }
+        let mut out_iter = out.iter_mut_with_index();
+        while let Some((o, i_out)) = out_iter.next() {
+            let mut i_inp = inp_index::<S, Dst>(i_out);
+            let mut tmp = E::default();
+            for &c in coefs.iter() {
+                tmp += c * inp[i_inp];
+                i_inp[op.axis] += 1;
+            }
+            *o = tmp;
+        }
+        Ok(out)
+    }
+
+    fn backward<S: Shape, Dst: Shape>(
+        &self,
+        op: DiffOp,
+        grad_inp: &mut Self::Storage<S, E>,
+        grad_out: &Self::Storage<Dst, E>,
+    ) -> Result<(), Self::Err> {
+        if grad_out.shape.num_elements() == 0 {
+            return Ok(());
+        }
+        let coefs = coefs::<E>(op.n);
+        let mut grad_out_iter = grad_out.iter_with_index();
+        while let Some((g, i_out)) = grad_out_iter.next() {
+            let mut i_inp = inp_index::<S, Dst>(i_out);
+            for &c in coefs.iter() {
+                grad_inp[i_inp] += c * *g;
+                i_inp[op.axis] += 1;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,94 @@
+use crate::{
+    shapes::*,
+    tensor::cuda::{Cuda, CudaArray},
+};
+use cudarc::driver::{AsKernelParam, LaunchAsync, LaunchConfig};
+use std::sync::Arc;
+
+const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/diff.ptx"));
+
+unsafe impl AsKernelParam for super::DiffOp {}
+
+trait HasCudaKernel<E> {
+    const MOD: &'static str;
+    const FNS: &'static [&'static str];
+}
+
+impl HasCudaKernel<f32> for Cuda {
+    const MOD: &'static str = "diff_f32";
+    const FNS: &'static [&'static str] = &["diff_fwd_f32", "diff_bwd_f32"];
+}
+
+impl HasCudaKernel<f64> for Cuda {
+    const MOD: &'static str = "diff_f64";
+    const FNS: &'static [&'static str] = &["diff_fwd_f64", "diff_bwd_f64"];
+}
+
+impl<E: Dtype> super::DiffKernel<E> for Cuda
+where
+    Self: HasCudaKernel<E>,
+{
+    fn forward<S: Shape, Dst: Shape>(
+        &self,
+        op: super::DiffOp,
+        inp: &Self::Storage<S, E>,
+        dst: Dst,
+    ) -> Result<Self::Storage<Dst, E>, Self::Err> {
+        if !self.dev.has_func(Self::MOD, Self::FNS[0]) {
+            self.dev.load_ptx(PTX_SRC.into(), Self::MOD, Self::FNS)?;
+        }
+
+        let numel = dst.num_elements();
+        let mut storage = unsafe { self.dev.alloc_async::<E>(numel) }?;
+
+        let out_dims = self.dev.take_async(dst.concrete().into())?;
+        let inp_strides = self.dev.take_async(inp.strides.into())?;
+
+        let fwd_fn = self.dev.get_func(Self::MOD, Self::FNS[0]).unwrap();
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            op,                // const DiffOp op,
+            numel,             // const size_t numel,
+            S::NUM_DIMS,       // const size_t num_dims,
+            &out_dims,         // const size_t *out_dims,
+            &inp_strides,      // const size_t *inp_strides,
+            inp.data.as_ref(), // const T *inp,
+            &mut storage,      // T *out
+        );
+        unsafe { fwd_fn.launch_async(cfg, params) }?;
+
+        Ok(CudaArray {
+            data: Arc::new(storage),
+            shape: dst,
+            strides: dst.strides(),
+        })
+    }
+
+    fn backward<S: Shape, Dst: Shape>(
+        &self,
+        op: super::DiffOp,
+        grad_inp: &mut Self::Storage<S, E>,
+        grad_out: &Self::Storage<Dst, E>,
+    ) -> Result<(), Self::Err> {
+        let numel = grad_out.shape.num_elements();
+
+        let out_dims = self.dev.take_async(grad_out.shape.concrete().into())?;
+        let inp_strides = self.dev.take_async(grad_inp.strides.into())?;
+        let out_strides = self.dev.take_async(grad_out.strides.into())?;
+
+        let bwd_fn = self.dev.get_func(Self::MOD, Self::FNS[1]).unwrap();
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            op,                                // const DiffOp op,
+            numel,                             // const size_t numel,
+            S::NUM_DIMS,                       // const size_t num_dims,
+            &out_dims,                         // const size_t *out_dims,
+            &inp_strides,                      // const size_t *inp_strides,
+            &out_strides,                      // const size_t *out_strides,
+            Arc::make_mut(&mut grad_inp.data), // T *grad_inp,
+            grad_out.data.as_ref(),            // const T *grad_out
+        );
+        unsafe { bwd_fn.launch_async(cfg, params) }?;
+        Ok(())
+    }
+}
@@ -0,0 +1,91 @@
+#include "cuda_utils.cuh"
+
+struct DiffOp {
+    size_t axis;
+    size_t n;
+};
+
+// The `k`th coefficient of the `n`th order differences, (-1)^(n - k) * (n choose k)
+template<typename T>
+__device__ T diff_coef(size_t n, size_t k) {
+    T c = 1.0;
+    for (size_t j = 0; j < k; j++) {
+        c = c * (n - j) / (j + 1);
+    }
+    return (n - k) % 2 == 0 ? c : -c;
+}
+
+template<typename T>
+__device__ void diff_fwd(
+    const DiffOp op,
+    const size_t numel,
+    const size_t num_dims,
+    const size_t *out_dims,
+    const size_t *inp_strides,
+    const T *inp,
+    T *out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    // the output index is the index of the first input element along the axis
+    unsigned int inp_i = get_strided_index(i, num_dims, out_dims, inp_strides);
+    T tmp = 0.0;
+    for (size_t k = 0; k <= op.n; k++) {
+        tmp += diff_coef<T>(op.n, k) * inp[inp_i + k * inp_strides[op.axis]];
+    }
+    out[i] = tmp;
+}
+
+template<typename T>
+__device__ void diff_bwd(
+    const DiffOp op,
+    const size_t numel,
+    const size_t num_dims,
+    const size_t *out_dims,
+    const size_t *inp_strides,
+    const size_t *out_strides,
+    T *grad_inp,
+    const T *grad_out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    unsigned int inp_i = get_strided_index(i, num_dims, out_dims, inp_strides);
+    T g = grad_out[get_strided_index(i, num_dims, out_dims, out_strides)];
+    for (size_t k = 0; k <= op.n; k++) {
+        atomicAdd(grad_inp + inp_i + k * inp_strides[op.axis], diff_coef<T>(op.n, k) * g);
+    }
+}
+
+#define DIFF_OP(TYPENAME, FWD, BWD) \
+extern "C" __global__ void FWD( \
+    const DiffOp op, \
+    const size_t numel, \
+    const size_t num_dims, \
+    const size_t *out_dims, \
+    const size_t *inp_strides, \
+    const TYPENAME *inp, \
+    TYPENAME *out \
+) { \
+    diff_fwd(op, numel, num_dims, out_dims, inp_strides, inp, out); \
+} \
+extern "C" __global__ void BWD( \
+    const DiffOp op, \
+    const size_t numel, \
+    const size_t num_dims, \
+    const size_t *out_dims, \
+    const size_t *inp_strides, \
+    const size_t *out_strides, \
+    TYPENAME *grad_inp, \
+    const TYPENAME *grad_out \
+) { \
+    diff_bwd(op, numel, num_dims, out_dims, inp_strides, out_strides, grad_inp, grad_out); \
+}
+
+DIFF_OP(float, diff_fwd_f32, diff_bwd_f32);
+DIFF_OP(double, diff_fwd_f64, diff_bwd_f64);
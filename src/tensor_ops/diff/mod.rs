@@ -0,0 +1,174 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use super::concat_along::ConcatShape;
+use crate::{gradients::Tape, shapes::*, tensor::*};
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct DiffOp {
+    /// The axis to take differences along
+    pub axis: usize,
+    /// The order of the differences
+    pub n: usize,
+}
+
+pub trait DiffKernel<E: Dtype>: DeviceStorage {
+    fn forward<S: Shape, Dst: Shape>(
+        &self,
+        op: DiffOp,
+        inp: &Self::Storage<S, E>,
+        dst: Dst,
+    ) -> Result<Self::Storage<Dst, E>, Self::Err>;
+    fn backward<S: Shape, Dst: Shape>(
+        &self,
+        op: DiffOp,
+        grad_inp: &mut Self::Storage<S, E>,
+        grad_out: &Self::Storage<Dst, E>,
+    ) -> Result<(), Self::Err>;
+}
+
+/// The `n`th order finite differences along `Ax`. The first order differences are
+/// `t[i + 1] - t[i]`, and higher orders apply this `n` times, which shrinks `Ax` by `n`.
+/// `Ax` becomes a [usize] dim in the output, and is empty if `n` is at least the size
+/// of the axis.
+///
+/// **Pytorch equivalent**: `torch.diff(t, n=n, dim=Ax)`
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([[1.0, 2.0, 4.0, 7.0], [0.0, 3.0, 3.0, 0.0]]);
+/// let r: Tensor<(Const<2>, usize), f32, _> = t.clone().diff::<Axis<1>>(1);
+/// assert_eq!(r.as_vec(), [1.0, 2.0, 3.0, 3.0, 0.0, -3.0]);
+/// let r = t.diff::<Axis<1>>(2);
+/// assert_eq!(r.as_vec(), [1.0, 1.0, -3.0, -3.0]);
+/// ```
+///
+/// A total variation penalty for images:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let img: Tensor<Rank3<3, 8, 8>, f32, _> = dev.sample_normal();
+/// let dy = img.clone().diff::<Axis<1>>(1).abs().sum::<Rank0, _>();
+/// let dx = img.diff::<Axis<2>>(1).abs().sum::<Rank0, _>();
+/// let tv = dy + dx;
+/// ```
+pub trait TryDiff: HasErr + HasShape {
+    /// See [TryDiff]
+    #[allow(clippy::type_complexity)]
+    fn diff<Ax>(self, n: usize) -> Self::WithShape<<Self::Shape as ConcatShape<Ax>>::Catted>
+    where
+        Self::Shape: ConcatShape<Ax>,
+    {
+        self.try_diff::<Ax>(n).unwrap()
+    }
+
+    /// Fallible version of [TryDiff::diff]
+    #[allow(clippy::type_complexity)]
+    fn try_diff<Ax>(
+        self,
+        n: usize,
+    ) -> Result<Self::WithShape<<Self::Shape as ConcatShape<Ax>>::Catted>, Self::Err>
+    where
+        Self::Shape: ConcatShape<Ax>;
+}
+
+impl<S: Shape, E: Dtype, D: DiffKernel<E>, T: Tape<D>> TryDiff for Tensor<S, E, D, T> {
+    fn try_diff<Ax>(self, n: usize) -> Result<Tensor<S::Catted, E, D, T>, Self::Err>
+    where
+        S: ConcatShape<Ax>,
+    {
+        let shape = *self.shape();
+
+        // the axis is the only dimension that changes
+        let dims = shape.concrete();
+        let catted = shape.catted(usize::MAX).concrete();
+        let axis = (0..S::NUM_DIMS).find(|&i| catted[i] == usize::MAX).unwrap();
+
+        let op = DiffOp { axis, n };
+        let dst = shape.catted(dims[axis].saturating_sub(n));
+        let (inp, mut tape) = self.split_tape();
+        let out = inp
+            .device
+            .upgrade(inp.device.forward(op, &inp.storage, dst)?);
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&inp)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+            inp.device.backward(op, grad_inp, grad_out)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_diff_1d() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([1.0, 2.0, 4.0, 7.0, 11.0]);
+        let r = t.trace().diff::<Axis<0>>(1);
+        assert_eq!(r.as_vec(), std::vec![1.0, 2.0, 3.0, 4.0]);
+        let g = r.square().sum().backward();
+        assert_eq!(g.get(&t).array(), [-2.0, -2.0, -2.0, -2.0, 8.0]);
+    }
+
+    #[test]
+    fn test_diff_higher_order() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([1.0, 2.0, 4.0, 7.0, 11.0]);
+        let r = t.trace().diff::<Axis<0>>(3);
+        assert_eq!(r.as_vec(), std::vec![0.0, 0.0]);
+        let w: Tensor<(usize,), TestDtype, _> = dev.tensor((std::vec![1.0, 2.0], (2,)));
+        let g = (r * w).sum().backward();
+        assert_eq!(g.get(&t).array(), [-1.0, 1.0, 3.0, -5.0, 2.0]);
+
+        let r = t.trace().diff::<Axis<0>>(0);
+        assert_eq!(r.as_vec(), std::vec![1.0, 2.0, 4.0, 7.0, 11.0]);
+
+        let r = t.diff::<Axis<0>>(5);
+        assert_eq!(r.shape(), &(0,));
+    }
+
+    #[test]
+    fn test_diff_matches_slices() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank3<2, 5, 3>, TestDtype, _> = dev.sample_normal();
+        let w: Tensor<(Const<2>, usize, Const<3>), TestDtype, _> =
+            dev.sample_normal_like(&(Const, 4, Const));
+        let r = t.trace().diff::<Axis<1>>(1);
+        let a = t.trace().slice((.., 1.., ..));
+        let b = t.trace().slice((.., ..4, ..));
+        let r2 = a - b;
+        assert_close(&r.as_vec(), &r2.as_vec());
+
+        let g = (r * w.clone()).sum().backward();
+        let g2 = (r2 * w).sum().backward();
+        assert_close(&g.get(&t).array(), &g2.get(&t).array());
+    }
+
+    #[test]
+    fn test_diff_permuted() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([[1.0, 0.0], [3.0, 1.0], [2.0, 5.0]]);
+        let r = t.trace().permute::<_, Axes2<1, 0>>().diff::<Axis<1>>(1);
+        assert_eq!(r.as_vec(), std::vec![2.0, -1.0, 1.0, 4.0]);
+        let g = r.exp().sum().backward();
+        assert_close(
+            &g.get(&t).array(),
+            &[
+                [-7.389056, -2.7182817],
+                [7.021176, -51.879868],
+                [0.36787945, 54.59815],
+            ],
+        );
+    }
+}
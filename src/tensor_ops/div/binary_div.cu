@@ -11,4 +11,24 @@ BINARY_OP(double, bdiv_fwd_f64, bdiv_bwd_f64, BinaryDivOp,
     x / y,
     1.0 / y,
     -x / (y * y))
-   
\ No newline at end of file
+
+// integers don't have gradients
+BINARY_OP(size_t, bdiv_fwd_usize, bdiv_bwd_usize, BinaryDivOp,
+    int_div(x, y),
+    0,
+    0)
+
+BINARY_OP(int, bdiv_fwd_i32, bdiv_bwd_i32, BinaryDivOp,
+    int_div(x, y),
+    0,
+    0)
+
+BINARY_OP(long long, bdiv_fwd_i64, bdiv_bwd_i64, BinaryDivOp,
+    int_div(x, y),
+    0,
+    0)
+
+BINARY_OP(unsigned char, bdiv_fwd_u8, bdiv_bwd_u8, BinaryDivOp,
+    int_div(x, y),
+    0,
+    0)
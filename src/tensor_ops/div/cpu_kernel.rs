@@ -1,5 +1,7 @@
-use crate::tensor_ops::cpu_kernels::{BinaryDerivative, UnaryDerivative};
-use num_traits::Float;
+use super::ScalarDivKernelOp;
+use crate::tensor_ops::cpu_kernels::{
+    int_binary_derivative, int_div, int_scalar_derivative, BinaryDerivative, Float, UnaryDerivative,
+};
 
 impl<F: Float> UnaryDerivative<F> for super::ScalarDivKernelOp<F> {
     fn f(&self, &x: &F) -> F {
@@ -24,3 +26,6 @@ impl<F: Float> BinaryDerivative<F> for super::BinaryDivKernelOp {
         -x / y.powi(2)
     }
 }
+
+int_binary_derivative!(super::BinaryDivKernelOp, |x, y| int_div(x, y));
+int_scalar_derivative!(ScalarDivKernelOp, |x, scalar| int_div(x, scalar));
@@ -36,6 +36,17 @@ pub struct BinaryDivKernelOp;
 /// let r = a / 2.0;
 /// assert_eq!(r.array(), [[0.5, 1.0, 1.5], [-0.5, -1.0, -1.5]]);
 /// ```
+///
+/// Integer division rounds towards zero. Dividing by zero doesn't panic, since that
+/// can't be done on cuda: it saturates to the largest value of the dtype, or the
+/// smallest for negative numerators. `MIN / -1` wraps around to `MIN`.
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let a = dev.tensor([7i32, -7, 0, i32::MIN]);
+/// let b = dev.tensor([2i32, 0, 0, -1]);
+/// assert_eq!((a / b).array(), [3, i32::MIN, i32::MAX, i32::MIN]);
+/// ```
 pub fn div<S: Shape, E: Dtype, D: Device<E>, T: Tape<D> + Merge<RhsTape>, RhsTape: Tape<D>>(
     lhs: Tensor<S, E, D, T>,
     rhs: Tensor<S, E, D, RhsTape>,
@@ -48,9 +59,10 @@ pub trait TryDiv<Rhs = Self>: HasErr {
     fn try_div(self, rhs: Rhs) -> Result<Self, Self::Err>;
 }
 
-impl<S: Shape, E: Dtype, D: Device<E>, LhsTape: Tape<D>, RhsTape: Tape<D>>
-    TryDiv<Tensor<S, E, D, RhsTape>> for Tensor<S, E, D, LhsTape>
+impl<S: Shape, E: Dtype, D, LhsTape: Tape<D>, RhsTape: Tape<D>> TryDiv<Tensor<S, E, D, RhsTape>>
+    for Tensor<S, E, D, LhsTape>
 where
+    D: BinaryKernel<BinaryDivKernelOp, E>,
     LhsTape: Merge<RhsTape>,
 {
     /// See [div]
@@ -59,14 +71,16 @@ where
     }
 }
 
-impl<S: Shape, E: Dtype, D: Device<E>, T: Tape<D>> TryDiv<E> for Tensor<S, E, D, T> {
+impl<S: Shape, E: Dtype, D: UnaryKernel<ScalarDivKernelOp<E>, E>, T: Tape<D>> TryDiv<E>
+    for Tensor<S, E, D, T>
+{
     /// See [div]
     fn try_div(self, rhs: E) -> Result<Self, Self::Err> {
         try_unary_op(ScalarDivKernelOp { scalar: rhs }, self)
     }
 }
 
-impl<S: Shape, E: Dtype, D: Device<E>, LhsTape: Tape<D>, Rhs> std::ops::Div<Rhs>
+impl<S: Shape, E: Dtype, D: DeviceStorage, LhsTape: Tape<D>, Rhs> std::ops::Div<Rhs>
     for Tensor<S, E, D, LhsTape>
 where
     Self: TryDiv<Rhs>,
@@ -173,4 +187,39 @@ mod tests {
         let g = r.exp().sum().backward();
         assert_close(&g.get(&x).array(), &[[0.8243606; 2]; 3]);
     }
+
+    #[test]
+    fn test_div_usize() {
+        let dev: TestDevice = Default::default();
+        let a = dev.tensor([[0usize, 1, 7], [8, 9, 10]]);
+        let b = dev.tensor([[1usize, 2, 2], [3, 9, 11]]);
+        assert_eq!((a.clone() / b).array(), [[0, 0, 3], [2, 1, 0]]);
+        assert_eq!((a / 4).array(), [[0, 0, 1], [2, 2, 2]]);
+    }
+
+    #[test]
+    fn test_div_int_by_zero_saturates() {
+        let dev: TestDevice = Default::default();
+        let a = dev.tensor([0usize, 1, 9]);
+        let b = dev.tensor([0usize, 0, 2]);
+        assert_eq!((a.clone() / b).array(), [usize::MAX, usize::MAX, 4]);
+        assert_eq!((a / 0).array(), [usize::MAX; 3]);
+
+        let a = dev.tensor([0u8, 200]);
+        assert_eq!((a / 0).array(), [255, 255]);
+
+        let a = dev.tensor([-7i32, 7, 0, -7, i32::MIN]);
+        let b = dev.tensor([0i32, 0, 0, 2, -1]);
+        assert_eq!(
+            (a.clone() / b).array(),
+            [i32::MIN, i32::MAX, i32::MAX, -3, i32::MIN]
+        );
+        assert_eq!(
+            (a / 0).array(),
+            [i32::MIN, i32::MAX, i32::MAX, i32::MIN, i32::MIN]
+        );
+
+        let a = dev.tensor([i64::MIN, -1, 1]);
+        assert_eq!((a / -1).array(), [i64::MIN, 1, -1]);
+    }
 }
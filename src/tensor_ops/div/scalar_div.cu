@@ -1,3 +1,4 @@
+#include "cuda_utils.cuh"
 #include "unary_op_macros.cuh"
 
 template<typename T>
@@ -12,3 +13,20 @@ UNARY_OP(float, sdiv_fwd_f32, sdiv_bwd_f32, ScalarDivKernelOp<float>,
 UNARY_OP(double, sdiv_fwd_f64, sdiv_bwd_f64, ScalarDivKernelOp<double>,
     x / op.scalar,
     1.0 / op.scalar);
+
+// integers don't have gradients
+UNARY_OP(size_t, sdiv_fwd_usize, sdiv_bwd_usize, ScalarDivKernelOp<size_t>,
+    int_div(x, op.scalar),
+    0);
+
+UNARY_OP(int, sdiv_fwd_i32, sdiv_bwd_i32, ScalarDivKernelOp<int>,
+    int_div(x, op.scalar),
+    0);
+
+UNARY_OP(long long, sdiv_fwd_i64, sdiv_bwd_i64, ScalarDivKernelOp<long long>,
+    int_div(x, op.scalar),
+    0);
+
+UNARY_OP(unsigned char, sdiv_fwd_u8, sdiv_bwd_u8, ScalarDivKernelOp<unsigned char>,
+    int_div(x, op.scalar),
+    0);
@@ -1,22 +1,25 @@
+#include "cuda_utils.cuh"
+
 #define DROPOUT(TYPENAME, FWD, BWD) \
 extern "C" __global__ void FWD( \
     const TYPENAME prob, \
     const size_t numel, \
+    const unsigned long long seed, \
     const TYPENAME *inp, \
-    const TYPENAME *noise, \
     TYPENAME *out \
 ) { \
     unsigned int i = blockIdx.x * blockDim.x + threadIdx.x; \
     if (i >= numel) { \
         return; \
     } \
-    auto scalar = (noise[i] < prob) ? 0.0 : (1.0 / (1.0 - prob)); \
+    unsigned long long state = rng_init(seed, i); \
+    auto scalar = (rng_next_f64(&state) < prob) ? 0.0 : (1.0 / (1.0 - prob)); \
     out[i] = inp[i] * scalar; \
 } \
 extern "C" __global__ void BWD( \
     const TYPENAME prob, \
     const size_t numel, \
-    const TYPENAME *noise, \
+    const unsigned long long seed, \
     TYPENAME *grad_inp, \
     const TYPENAME *grad_out \
 ) { \
@@ -24,7 +27,8 @@ extern "C" __global__ void BWD( \
     if (i >= numel) { \
         return; \
     } \
-    grad_inp[i] += (noise[i] < prob) ? 0.0 : (grad_out[i] / (1.0 - prob)); \
+    unsigned long long state = rng_init(seed, i); \
+    grad_inp[i] += (rng_next_f64(&state) < prob) ? 0.0 : (grad_out[i] / (1.0 - prob)); \
 }
 
 DROPOUT(float, dropout_fwd_f32, dropout_bwd_f32);
@@ -41,15 +41,15 @@ pub trait DropoutKernel<E: Dtype>: DeviceStorage {
 /// # let dev: Cpu = Default::default();
 /// let t = dev.tensor([1.0f32, 2.0, 3.0, 4.0]);
 /// let r = t.dropout(0.5);
-/// assert_eq!(r.array(), [2.0, 4.0, 6.0, 0.0]);
+/// assert_eq!(r.array(), [0.0, 4.0, 6.0, 8.0]);
 /// ```
 ///
 /// ### Implementation details:
 ///
 /// To reduce memory usage, this function first samples a u64 seed from `rng`,
-/// and then instantiates two identical [rand::rngs::StdRng] with that seed. These rngs
-/// are used in both the forward pass and backward pass to generate identical
-/// random numbers, so the masking is the same for both.
+/// and then derives a separate random stream for each element from that seed. The
+/// streams are regenerated in the backward pass, so the masking is the same for both,
+/// and on cuda the mask is generated on the device.
 pub fn dropout<S: Shape, E: Dtype, D: DropoutKernel<E>, T: Tape<D>>(
     t: Tensor<S, E, D, T>,
     prob: E,
@@ -110,9 +110,9 @@ mod tests {
         let dev: TestDevice = Default::default();
         let t: Tensor<_, f32, _> = dev.tensor([0.0, 2.0, -3.0, -4.0, 0.0]);
         let r = t.trace().dropout(0.5);
-        assert_eq!(r.array(), [0.0, 4.0, -6.0, 0.0, 0.0]);
+        assert_eq!(r.array(), [0.0, 4.0, -6.0, -8.0, 0.0]);
         let g = r.mean().backward();
-        assert_eq!(g.get(&t).array(), [0.4, 0.4, 0.4, 0.0, 0.0]);
+        assert_eq!(g.get(&t).array(), [0.0, 0.4, 0.4, 0.4, 0.0]);
     }
 
     #[test]
@@ -120,12 +120,12 @@ mod tests {
         let dev: TestDevice = Default::default();
         let t: Tensor<_, f32, _> = dev.tensor([[0.05, 0.1, -0.2], [0.3, -0.4, 0.5]]);
         let r = t.trace().dropout(0.6);
-        assert_close(&r.array(), &[[0.125, 0.25, -0.5], [0.0, 0.0, 1.25]]);
+        assert_close(&r.array(), &[[0.0, 0.25, -0.5], [0.75, 0.0, 1.25]]);
         // NOTE: .exp() so we ensure result grad is used properly
         let g = r.exp().mean().backward();
         assert_close(
             &g.get(&t).array(),
-            &[[0.47214523, 0.5350107, 0.2527211], [0.0, 0.0, 1.4543099]],
+            &[[0.0, 0.5350107, 0.2527211], [0.8820835, 0.0, 1.4543099]],
         );
     }
 }
@@ -0,0 +1,60 @@
+use crate::{shapes::*, tensor::cpu::Cpu};
+
+use super::Contraction;
+
+use std::{sync::Arc, vec::Vec};
+
+impl<E: Dtype> super::EinsumKernel<E> for Cpu {
+    fn contract<X: Shape, Y: Shape, Z: Shape>(
+        &self,
+        op: &Contraction,
+        x: &Self::Storage<X, E>,
+        y: Option<&Self::Storage<Y, E>>,
+        z: &mut Self::Storage<Z, E>,
+    ) -> Result<(), Self::Err> {
+        if op.sizes.contains(&0) {
+            return Ok(());
+        }
+
+        let x_strides = op.label_strides(&op.x_labels, x.strides.into());
+        let y_strides = match y {
+            Some(y) => op.label_strides(&op.y_labels, y.strides.into()),
+            None => alloc::vec![0; op.sizes.len()],
+        };
+        let z_strides = op.label_strides(&op.z_labels, z.strides.into());
+
+        let x_buf = x.data.as_ref();
+        let y_buf = y.map(|y| y.data.as_ref());
+        let z_buf = Arc::make_mut(&mut z.data);
+
+        let num_labels = op.sizes.len();
+        let mut idx: Vec<usize> = alloc::vec![0; num_labels];
+        let (mut i_x, mut i_y, mut i_z) = (0, 0, 0);
+        loop {
+            z_buf[i_z] += match y_buf {
+                Some(y_buf) => x_buf[i_x] * y_buf[i_y],
+                None => x_buf[i_x],
+            };
+
+            // advance the index of the last label, carrying into earlier labels
+            let mut l = num_labels;
+            loop {
+                if l == 0 {
+                    return Ok(());
+                }
+                l -= 1;
+                idx[l] += 1;
+                i_x += x_strides[l];
+                i_y += y_strides[l];
+                i_z += z_strides[l];
+                if idx[l] < op.sizes[l] {
+                    break;
+                }
+                i_x -= op.sizes[l] * x_strides[l];
+                i_y -= op.sizes[l] * y_strides[l];
+                i_z -= op.sizes[l] * z_strides[l];
+                idx[l] = 0;
+            }
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use crate::{shapes::*, tensor::cuda::Cuda};
+
+use super::Contraction;
+
+use cudarc::driver::{AsKernelParam, CudaSlice, LaunchAsync, LaunchConfig};
+
+use std::sync::Arc;
+
+const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/einsum.ptx"));
+
+trait HasCudaKernel<E> {
+    const MOD: &'static str;
+    const FNS: &'static [&'static str];
+}
+
+impl HasCudaKernel<f32> for Cuda {
+    const MOD: &'static str = "einsum_f32";
+    const FNS: &'static [&'static str] = &["einsum_contract_f32"];
+}
+
+impl HasCudaKernel<f64> for Cuda {
+    const MOD: &'static str = "einsum_f64";
+    const FNS: &'static [&'static str] = &["einsum_contract_f64"];
+}
+
+impl<E: Dtype + AsKernelParam> super::EinsumKernel<E> for Cuda
+where
+    Self: HasCudaKernel<E>,
+{
+    fn contract<X: Shape, Y: Shape, Z: Shape>(
+        &self,
+        op: &Contraction,
+        x: &Self::Storage<X, E>,
+        y: Option<&Self::Storage<Y, E>>,
+        z: &mut Self::Storage<Z, E>,
+    ) -> Result<(), Self::Err> {
+        if op.sizes.contains(&0) {
+            return Ok(());
+        }
+
+        if !self.dev.has_func(Self::MOD, Self::FNS[0]) {
+            self.dev.load_ptx(PTX_SRC.into(), Self::MOD, Self::FNS)?;
+        }
+
+        // the labels of z come first, so each thread computes a single element of z
+        let num_outer = op.z_labels.iter().max().map_or(0, |l| l + 1);
+        let numel: usize = op.sizes[..num_outer].iter().product();
+
+        let x_strides = op.label_strides(&op.x_labels, x.strides.into());
+        let y_strides = match y {
+            Some(y) => op.label_strides(&op.y_labels, y.strides.into()),
+            None => std::vec![0; op.sizes.len()],
+        };
+        let z_strides = op.label_strides(&op.z_labels, z.strides.into());
+
+        let sizes: CudaSlice<usize> = self.dev.take_async(op.sizes.clone())?;
+        let x_strides: CudaSlice<usize> = self.dev.take_async(x_strides)?;
+        let y_strides: CudaSlice<usize> = self.dev.take_async(y_strides)?;
+        let z_strides: CudaSlice<usize> = self.dev.take_async(z_strides)?;
+
+        let contract_fn = self.dev.get_func(Self::MOD, Self::FNS[0]).unwrap();
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            numel,                                          // const size_t numel,
+            op.sizes.len(),                                 // const size_t num_labels,
+            num_outer,                                      // const size_t num_outer,
+            &sizes,                                         // const size_t *sizes,
+            x.data.as_ref(),                                // const T *x,
+            &x_strides,                                     // const size_t *x_strides,
+            y.map_or(x.data.as_ref(), |y| y.data.as_ref()), // const T *y,
+            &y_strides,                                     // const size_t *y_strides,
+            y.is_some() as usize,                           // const size_t has_y,
+            Arc::make_mut(&mut z.data),                     // T *z,
+            &z_strides,                                     // const size_t *z_strides
+        );
+        unsafe { contract_fn.launch_async(cfg, params) }?;
+        Ok(())
+    }
+}
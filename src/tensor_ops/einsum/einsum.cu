@@ -0,0 +1,77 @@
+#include "cuda_utils.cuh"
+
+// Each thread handles a single assignment of the first `num_outer` labels (the labels of z),
+// and sums over all assignments of the remaining labels.
+template<typename T>
+__device__ void einsum_contract(
+    const size_t numel,
+    const size_t num_labels,
+    const size_t num_outer,
+    const size_t *sizes,
+    const T *x,
+    const size_t *x_strides,
+    const T *y,
+    const size_t *y_strides,
+    const size_t has_y,
+    T *z,
+    const size_t *z_strides
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    size_t i_x = 0;
+    size_t i_y = 0;
+    size_t i_z = 0;
+    unsigned int idx = i;
+    for (size_t d = 0; d < num_outer; d++) {
+        size_t l = num_outer - 1 - d;
+        size_t v = idx % sizes[l];
+        idx /= sizes[l];
+        i_x += v * x_strides[l];
+        i_y += v * y_strides[l];
+        i_z += v * z_strides[l];
+    }
+
+    size_t num_inner = 1;
+    for (size_t l = num_outer; l < num_labels; l++) {
+        num_inner *= sizes[l];
+    }
+
+    T sum = 0.0;
+    for (size_t j = 0; j < num_inner; j++) {
+        size_t j_x = i_x;
+        size_t j_y = i_y;
+        size_t jdx = j;
+        for (size_t d = num_outer; d < num_labels; d++) {
+            size_t l = num_labels - 1 - (d - num_outer);
+            size_t v = jdx % sizes[l];
+            jdx /= sizes[l];
+            j_x += v * x_strides[l];
+            j_y += v * y_strides[l];
+        }
+        sum += has_y ? x[j_x] * y[j_y] : x[j_x];
+    }
+    atomicAdd(z + i_z, sum);
+}
+
+#define EINSUM_OP(TYPENAME, FN) \
+extern "C" __global__ void FN( \
+    const size_t numel, \
+    const size_t num_labels, \
+    const size_t num_outer, \
+    const size_t *sizes, \
+    const TYPENAME *x, \
+    const size_t *x_strides, \
+    const TYPENAME *y, \
+    const size_t *y_strides, \
+    const size_t has_y, \
+    TYPENAME *z, \
+    const size_t *z_strides \
+) { \
+    einsum_contract(numel, num_labels, num_outer, sizes, x, x_strides, y, y_strides, has_y, z, z_strides); \
+}
+
+EINSUM_OP(float, einsum_contract_f32);
+EINSUM_OP(double, einsum_contract_f64);
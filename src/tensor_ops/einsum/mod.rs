@@ -0,0 +1,395 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{
+    gradients::{Merge, Tape},
+    shapes::*,
+    tensor::*,
+};
+
+use std::vec::Vec;
+
+/// Adds `x[..] * y[..]` (or just `x[..]` without `y`) into `z[..]` for every assignment
+/// of values to the labels.
+///
+/// Each operand maps each of its dims to a label, and the labels of `z` come before
+/// all other labels.
+#[derive(Debug, Clone)]
+pub(super) struct Contraction {
+    pub sizes: Vec<usize>,
+    pub x_labels: Vec<usize>,
+    pub y_labels: Vec<usize>,
+    pub z_labels: Vec<usize>,
+}
+
+impl Contraction {
+    /// `x`, `y` and `z` contain the label of each dim of each operand, and `sizes`
+    /// the size of each label.
+    fn new(sizes: &[usize], x: &[usize], y: Option<&[usize]>, z: &[usize]) -> Self {
+        // put the labels of z first
+        let mut order: Vec<usize> = Vec::with_capacity(sizes.len());
+        for &l in z.iter().chain(x.iter()).chain(y.unwrap_or(&[]).iter()) {
+            if !order.contains(&l) {
+                order.push(l);
+            }
+        }
+        let position = |l: &usize| order.iter().position(|o| o == l).unwrap();
+        Self {
+            sizes: order.iter().map(|&l| sizes[l]).collect(),
+            x_labels: x.iter().map(position).collect(),
+            y_labels: y.unwrap_or(&[]).iter().map(position).collect(),
+            z_labels: z.iter().map(position).collect(),
+        }
+    }
+
+    /// The stride of each label when indexing into an operand, which is the sum of the strides
+    /// of all dims with that label.
+    pub(super) fn label_strides(&self, labels: &[usize], strides: Vec<usize>) -> Vec<usize> {
+        let mut label_strides = alloc::vec![0; self.sizes.len()];
+        for (&l, s) in labels.iter().zip(strides) {
+            label_strides[l] += s;
+        }
+        label_strides
+    }
+}
+
+pub(super) trait EinsumKernel<E: Dtype>: DeviceStorage {
+    fn contract<X: Shape, Y: Shape, Z: Shape>(
+        &self,
+        op: &Contraction,
+        x: &Self::Storage<X, E>,
+        y: Option<&Self::Storage<Y, E>>,
+        z: &mut Self::Storage<Z, E>,
+    ) -> Result<(), Self::Err>;
+}
+
+/// Parses a spec like `"ij,jk->ik"` into the subscripts of each input, and the output subscripts.
+fn parse_spec(spec: &str, num_inputs: usize) -> (Vec<Vec<char>>, Vec<char>) {
+    let spec: std::string::String = spec.chars().filter(|c| !c.is_whitespace()).collect();
+    let (inputs, output) = spec
+        .split_once("->")
+        .expect("einsum spec must contain an explicit output, e.g. \"ij,jk->ik\"");
+    let inputs: Vec<Vec<char>> = inputs.split(',').map(|s| s.chars().collect()).collect();
+    let output: Vec<char> = output.chars().collect();
+    assert_eq!(
+        inputs.len(),
+        num_inputs,
+        "einsum spec has the wrong number of inputs"
+    );
+    for &c in inputs.iter().flatten().chain(output.iter()) {
+        assert!(c.is_ascii_alphabetic(), "invalid einsum subscript {c:?}");
+    }
+    (inputs, output)
+}
+
+/// Assigns a label to each subscript, returning the labels of each input and the output,
+/// and the size of each label.
+#[allow(clippy::type_complexity)]
+fn label(spec: &str, dims: &[Vec<usize>]) -> (Vec<Vec<usize>>, Vec<usize>, Vec<usize>) {
+    let (inputs, output) = parse_spec(spec, dims.len());
+    let mut chars: Vec<char> = Vec::new();
+    let mut sizes: Vec<usize> = Vec::new();
+    let mut labels = Vec::with_capacity(inputs.len());
+    for (subscripts, dims) in inputs.iter().zip(dims.iter()) {
+        assert_eq!(
+            subscripts.len(),
+            dims.len(),
+            "einsum subscripts {subscripts:?} don't match the number of dims"
+        );
+        let mut input_labels = Vec::with_capacity(dims.len());
+        for (c, &size) in subscripts.iter().zip(dims.iter()) {
+            match chars.iter().position(|o| o == c) {
+                Some(l) => {
+                    assert_eq!(sizes[l], size, "einsum subscript {c:?} has different sizes");
+                    input_labels.push(l);
+                }
+                None => {
+                    chars.push(*c);
+                    sizes.push(size);
+                    input_labels.push(chars.len() - 1);
+                }
+            }
+        }
+        labels.push(input_labels);
+    }
+    let mut output_labels = Vec::with_capacity(output.len());
+    for (i, c) in output.iter().enumerate() {
+        assert!(
+            !output[..i].contains(c),
+            "einsum output subscript {c:?} is repeated"
+        );
+        let l = chars
+            .iter()
+            .position(|o| o == c)
+            .unwrap_or_else(|| panic!("einsum output subscript {c:?} isn't in any input"));
+        output_labels.push(l);
+    }
+    (labels, output_labels, sizes)
+}
+
+/// The output shape of an einsum, panicking if it doesn't match `Dst`.
+fn output_shape<Dst: Shape>(labels: &[usize], sizes: &[usize]) -> Dst {
+    assert_eq!(
+        labels.len(),
+        Dst::NUM_DIMS,
+        "einsum output doesn't match the number of output dims"
+    );
+    let mut dims: Dst::Concrete = Default::default();
+    for (i, &l) in labels.iter().enumerate() {
+        dims[i] = sizes[l];
+    }
+    Dst::from_concrete(&dims).expect("einsum output doesn't match the output shape")
+}
+
+/// Einstein summation over one tensor or a tuple of two tensors, using a spec like
+/// `"ij,jk->ik"`. See [einsum].
+pub trait TryEinsum: Sized {
+    type Output<Dst: Shape>;
+    type Err: std::fmt::Debug;
+
+    /// Einstein summation. See [einsum]
+    fn einsum<Dst: Shape>(self, spec: &str) -> Self::Output<Dst> {
+        self.try_einsum(spec).unwrap()
+    }
+
+    /// Fallible version of [TryEinsum::einsum]
+    fn try_einsum<Dst: Shape>(self, spec: &str) -> Result<Self::Output<Dst>, Self::Err>;
+}
+
+/// Einstein summation over one tensor or a tuple of two tensors.
+///
+/// The spec lists the subscripts of each input separated by `,`, and the subscripts
+/// of the output after `->`. Each subscript is a single ascii letter naming a dimension.
+/// Dimensions with the same subscript are multiplied together elementwise, and
+/// subscripts that are not part of the output are summed over. A subscript repeated in
+/// a single input takes the diagonal. The spec is parsed and validated at runtime, and the
+/// output shape `Dst` must match the sizes of the output subscripts.
+///
+/// **Pytorch equivalent** `torch.einsum(spec, a, b)`.
+///
+/// **NOTE** This is a direct loop over all of the subscripts, so [super::TryMatMul] is
+/// much faster for formulas that are just matrix multiplications.
+///
+/// Matrix multiplication:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let a = dev.tensor([[1.0, 2.0], [3.0, 4.0]]);
+/// let b = dev.tensor([[1.0, 0.0, 1.0], [0.0, 1.0, 1.0]]);
+/// let c: Tensor<Rank2<2, 3>, f32, _> = einsum("ij,jk->ik", (a, b));
+/// assert_eq!(c.array(), [[1.0, 2.0, 3.0], [3.0, 4.0, 7.0]]);
+/// ```
+///
+/// Attention scores, and a trace of a single tensor:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let q: Tensor<Rank3<2, 4, 8>, f32, _> = dev.zeros();
+/// let k: Tensor<Rank3<2, 5, 8>, f32, _> = dev.zeros();
+/// let _: Tensor<Rank3<2, 4, 5>, f32, _> = einsum("bqd,bkd->bqk", (q, k));
+///
+/// let t = dev.tensor([[1.0, 2.0], [3.0, 4.0]]);
+/// let tr: Tensor<Rank0, f32, _> = einsum("ii->", t);
+/// assert_eq!(tr.array(), 5.0);
+/// ```
+pub fn einsum<Dst: Shape, Args: TryEinsum>(spec: &str, args: Args) -> Args::Output<Dst> {
+    args.einsum(spec)
+}
+
+impl<S: Shape, E: Dtype, D: EinsumKernel<E> + ZerosTensor<E>, T: Tape<D>> TryEinsum
+    for Tensor<S, E, D, T>
+{
+    type Output<Dst: Shape> = Tensor<Dst, E, D, T>;
+    type Err = D::Err;
+
+    fn try_einsum<Dst: Shape>(self, spec: &str) -> Result<Self::Output<Dst>, Self::Err> {
+        let (mut labels, out_labels, sizes) = label(spec, &[self.shape().concrete().into()]);
+        let inp_labels = labels.pop().unwrap();
+        let dst: Dst = output_shape(&out_labels, &sizes);
+
+        let (inp, mut tape) = self.split_tape();
+        let mut out = inp.device.try_zeros_like(&dst)?;
+        let op = Contraction::new(&sizes, &inp_labels, None, &out_labels);
+        inp.device
+            .contract::<S, S, Dst>(&op, &inp.storage, None, &mut out.storage)?;
+
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&inp)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+            let op = Contraction::new(&sizes, &out_labels, None, &inp_labels);
+            inp.device
+                .contract::<Dst, Dst, S>(&op, grad_out, None, grad_inp)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+impl<L: Shape, R: Shape, E: Dtype, D, LTape, RTape> TryEinsum
+    for (Tensor<L, E, D, LTape>, Tensor<R, E, D, RTape>)
+where
+    D: EinsumKernel<E> + ZerosTensor<E>,
+    LTape: Tape<D> + Merge<RTape>,
+    RTape: Tape<D>,
+{
+    type Output<Dst: Shape> = Tensor<Dst, E, D, LTape>;
+    type Err = D::Err;
+
+    fn try_einsum<Dst: Shape>(self, spec: &str) -> Result<Self::Output<Dst>, Self::Err> {
+        let (lhs, rhs) = self;
+        let dims = [lhs.shape().concrete().into(), rhs.shape().concrete().into()];
+        let (mut labels, out_labels, sizes) = label(spec, &dims);
+        let rhs_labels = labels.pop().unwrap();
+        let lhs_labels = labels.pop().unwrap();
+        let dst: Dst = output_shape(&out_labels, &sizes);
+
+        let (lhs, ltape) = lhs.split_tape();
+        let (rhs, rtape) = rhs.split_tape();
+        let mut tape = ltape.merge(rtape);
+        let mut out = lhs.device.try_zeros_like(&dst)?;
+        let op = Contraction::new(&sizes, &lhs_labels, Some(&rhs_labels), &out_labels);
+        lhs.device
+            .contract(&op, &lhs.storage, Some(&rhs.storage), &mut out.storage)?;
+
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&lhs)?;
+        tape.try_alloc_grad(&rhs)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_lhs, grad_rhs, grad_out) = grads.muts_and_ref(&lhs, &rhs, &phantom_out);
+            let op = Contraction::new(&sizes, &out_labels, Some(&rhs_labels), &lhs_labels);
+            lhs.device
+                .contract(&op, grad_out, Some(&rhs.storage), grad_lhs)?;
+            let op = Contraction::new(&sizes, &out_labels, Some(&lhs_labels), &rhs_labels);
+            lhs.device
+                .contract(&op, grad_out, Some(&lhs.storage), grad_rhs)?;
+            Ok(())
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_einsum_matmul() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<Rank2<3, 4>, TestDtype, _> = dev.sample_normal();
+        let b: Tensor<Rank2<4, 2>, TestDtype, _> = dev.sample_normal();
+        let r: Tensor<Rank2<3, 2>, _, _, _> = einsum("ij,jk->ik", (a.trace(), b.trace()));
+        let r2 = a.trace().matmul(b.trace());
+        assert_close(&r.array(), &r2.array());
+        let g = r.square().mean().backward();
+        let g2 = r2.square().mean().backward();
+        assert_close(&g.get(&a).array(), &g2.get(&a).array());
+        assert_close(&g.get(&b).array(), &g2.get(&b).array());
+    }
+
+    #[test]
+    fn test_einsum_batched_transposed() {
+        let dev: TestDevice = Default::default();
+        let q: Tensor<Rank3<2, 3, 4>, TestDtype, _> = dev.sample_normal();
+        let k: Tensor<Rank3<2, 5, 4>, TestDtype, _> = dev.sample_normal();
+        let r: Tensor<Rank3<2, 3, 5>, _, _, _> =
+            (q.trace(), k.trace()).einsum("b q d, b k d -> b q k");
+        let r2 = q.trace().matmul(k.trace().permute::<_, Axes3<0, 2, 1>>());
+        assert_close(&r.array(), &r2.array());
+        let g = r.square().mean().backward();
+        let g2 = r2.square().mean().backward();
+        assert_close(&g.get(&q).array(), &g2.get(&q).array());
+        assert_close(&g.get(&k).array(), &g2.get(&k).array());
+    }
+
+    #[test]
+    fn test_einsum_outer_and_sum() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<_, TestDtype, _> = dev.tensor([1.0, 2.0]);
+        let b: Tensor<_, TestDtype, _> = dev.tensor([3.0, 4.0, 5.0]);
+        let r: Tensor<Rank2<2, 3>, _, _, _> = einsum("i,j->ij", (a.clone(), b.clone()));
+        assert_eq!(r.array(), [[3.0, 4.0, 5.0], [6.0, 8.0, 10.0]]);
+
+        // `j` is only in `b`, so it is summed before multiplying
+        let r: Tensor<Rank1<2>, _, _, _> = einsum("i,j->i", (a.trace(), b.trace()));
+        assert_eq!(r.array(), [12.0, 24.0]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&a).array(), [12.0, 12.0]);
+        assert_eq!(g.get(&b).array(), [3.0, 3.0, 3.0]);
+    }
+
+    #[test]
+    fn test_einsum_unary() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let r: Tensor<Rank2<3, 2>, _, _, _> = t.trace().einsum("ij->ji");
+        assert_eq!(r.array(), [[1.0, 4.0], [2.0, 5.0], [3.0, 6.0]]);
+        let g = (r * dev.tensor([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]))
+            .sum()
+            .backward();
+        assert_eq!(g.get(&t).array(), [[1.0, 3.0, 5.0], [2.0, 4.0, 6.0]]);
+
+        let r: Tensor<Rank1<2>, _, _, _> = t.trace().einsum("ij->i");
+        assert_eq!(r.array(), [6.0, 15.0]);
+    }
+
+    #[test]
+    fn test_einsum_diagonal() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([[1.0, 2.0], [3.0, 4.0]]);
+        let r: Tensor<Rank1<2>, _, _, _> = t.trace().einsum("ii->i");
+        assert_eq!(r.array(), [1.0, 4.0]);
+        let g = (r * dev.tensor([2.0, 3.0])).sum().backward();
+        assert_eq!(g.get(&t).array(), [[2.0, 0.0], [0.0, 3.0]]);
+
+        let r: Tensor<Rank0, _, _, _> = t.trace().einsum("ii->");
+        assert_eq!(r.array(), 5.0);
+        let g = r.backward();
+        assert_eq!(g.get(&t).array(), [[1.0, 0.0], [0.0, 1.0]]);
+    }
+
+    #[test]
+    fn test_einsum_broadcasted_input() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<_, TestDtype, _> = dev.tensor([1.0, 2.0]);
+        let b: Tensor<_, TestDtype, _> = dev.tensor([[1.0, 2.0], [3.0, 4.0]]);
+        let r: Tensor<Rank1<3>, _, _, _> = einsum(
+            "ki,ij->k",
+            (a.trace().broadcast::<Rank2<3, 2>, _>(), b.trace()),
+        );
+        assert_eq!(r.array(), [17.0; 3]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&a).array(), [9.0, 21.0]);
+        assert_eq!(g.get(&b).array(), [[3.0, 3.0], [6.0, 6.0]]);
+    }
+
+    #[test]
+    fn test_einsum_usize_output() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<Rank2<2, 3>, TestDtype, _> = dev.ones();
+        let r: Tensor<(usize,), _, _, _> = a.einsum("ij->j");
+        assert_eq!(r.shape(), &(3,));
+        assert_eq!(r.as_vec(), [2.0; 3]);
+    }
+
+    #[test]
+    #[should_panic = "different sizes"]
+    fn test_einsum_mismatched_sizes() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<Rank2<2, 3>, TestDtype, _> = dev.zeros();
+        let b: Tensor<Rank2<2, 3>, TestDtype, _> = dev.zeros();
+        let _: Tensor<Rank2<2, 3>, _, _, _> = einsum("ij,jk->ik", (a, b));
+    }
+
+    #[test]
+    #[should_panic = "output shape"]
+    fn test_einsum_wrong_output_shape() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<Rank2<2, 3>, TestDtype, _> = dev.zeros();
+        let _: Tensor<Rank1<2>, _, _, _> = a.einsum("ij->j");
+    }
+}
@@ -0,0 +1,61 @@
+use crate::tensor_ops::cpu_kernels::UnaryDerivative;
+use num_traits::Float;
+
+impl<F: Float> UnaryDerivative<F> for super::EluKernelOp<F> {
+    #[inline(always)]
+    fn f(&self, &x: &F) -> F {
+        if x > F::zero() {
+            x
+        } else {
+            self.alpha * x.exp_m1()
+        }
+    }
+    #[inline(always)]
+    fn df(&self, &x: &F) -> F {
+        if x > F::zero() {
+            F::one()
+        } else {
+            self.alpha * x.exp()
+        }
+    }
+}
+
+impl<F: Float> UnaryDerivative<F> for super::SeluKernelOp {
+    #[inline(always)]
+    fn f(&self, &x: &F) -> F {
+        let scale = F::from(super::SELU_SCALE).unwrap();
+        if x > F::zero() {
+            scale * x
+        } else {
+            scale * F::from(super::SELU_ALPHA).unwrap() * x.exp_m1()
+        }
+    }
+    #[inline(always)]
+    fn df(&self, &x: &F) -> F {
+        let scale = F::from(super::SELU_SCALE).unwrap();
+        if x > F::zero() {
+            scale
+        } else {
+            scale * F::from(super::SELU_ALPHA).unwrap() * x.exp()
+        }
+    }
+}
+
+impl<F: Float> UnaryDerivative<F> for super::CeluKernelOp<F> {
+    #[inline(always)]
+    fn f(&self, &x: &F) -> F {
+        if x > F::zero() {
+            x
+        } else {
+            self.alpha * (x / self.alpha).exp_m1()
+        }
+    }
+    #[inline(always)]
+    fn df(&self, &x: &F) -> F {
+        if x > F::zero() {
+            F::one()
+        } else {
+            (x / self.alpha).exp()
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use super::{CeluKernelOp as Celu, EluKernelOp as Elu, SeluKernelOp as Selu};
+use crate::tensor_ops::cuda_kernels::cuda_unary;
+
+unsafe impl cudarc::driver::AsKernelParam for Elu<f32> {}
+unsafe impl cudarc::driver::AsKernelParam for Elu<f64> {}
+unsafe impl cudarc::driver::AsKernelParam for Selu {}
+unsafe impl cudarc::driver::AsKernelParam for Celu<f32> {}
+unsafe impl cudarc::driver::AsKernelParam for Celu<f64> {}
+
+const PTX: &str = include_str!(concat!(env!("OUT_DIR"), "/elu.ptx"));
+
+cuda_unary!(Elu<f32>, f32, PTX, "elu_fwd_f32", "elu_bwd_f32");
+cuda_unary!(Elu<f64>, f64, PTX, "elu_fwd_f64", "elu_bwd_f64");
+cuda_unary!(Selu, f32, PTX, "selu_fwd_f32", "selu_bwd_f32");
+cuda_unary!(Selu, f64, PTX, "selu_fwd_f64", "selu_bwd_f64");
+cuda_unary!(Celu<f32>, f32, PTX, "celu_fwd_f32", "celu_bwd_f32");
+cuda_unary!(Celu<f64>, f64, PTX, "celu_fwd_f64", "celu_bwd_f64");
@@ -0,0 +1,41 @@
+#include "unary_op_macros.cuh"
+#include "cuda_utils.cuh"
+
+template<typename F>
+struct EluKernelOp {
+    F alpha;
+};
+
+struct SeluKernelOp {};
+
+template<typename F>
+struct CeluKernelOp {
+    F alpha;
+};
+
+#define SELU_ALPHA 1.6732632423543772848170429916717
+#define SELU_SCALE 1.0507009873554804934193349852946
+
+UNARY_OP(float, elu_fwd_f32, elu_bwd_f32, EluKernelOp<float>,
+    x > 0.0 ? x : op.alpha * expm1g(x),
+    x > 0.0 ? 1.0 : op.alpha * expg(x))
+
+UNARY_OP(double, elu_fwd_f64, elu_bwd_f64, EluKernelOp<double>,
+    x > 0.0 ? x : op.alpha * expm1g(x),
+    x > 0.0 ? 1.0 : op.alpha * expg(x))
+
+UNARY_OP(float, selu_fwd_f32, selu_bwd_f32, SeluKernelOp,
+    SELU_SCALE * (x > 0.0 ? x : SELU_ALPHA * expm1g(x)),
+    SELU_SCALE * (x > 0.0 ? 1.0 : SELU_ALPHA * expg(x)))
+
+UNARY_OP(double, selu_fwd_f64, selu_bwd_f64, SeluKernelOp,
+    SELU_SCALE * (x > 0.0 ? x : SELU_ALPHA * expm1g(x)),
+    SELU_SCALE * (x > 0.0 ? 1.0 : SELU_ALPHA * expg(x)))
+
+UNARY_OP(float, celu_fwd_f32, celu_bwd_f32, CeluKernelOp<float>,
+    x > 0.0 ? x : op.alpha * expm1g(x / op.alpha),
+    x > 0.0 ? 1.0 : expg(x / op.alpha))
+
+UNARY_OP(double, celu_fwd_f64, celu_bwd_f64, CeluKernelOp<double>,
+    x > 0.0 ? x : op.alpha * expm1g(x / op.alpha),
+    x > 0.0 ? 1.0 : expg(x / op.alpha))
@@ -0,0 +1,172 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use super::ops::{try_unary_op, UnaryKernel};
+use crate::{gradients::Tape, shapes::*, tensor::Tensor};
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct EluKernelOp<E> {
+    pub alpha: E,
+}
+
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SeluKernelOp;
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct CeluKernelOp<E> {
+    pub alpha: E,
+}
+
+const SELU_ALPHA: f64 = 1.673_263_242_354_377_3;
+const SELU_SCALE: f64 = 1.050_700_987_355_480_5;
+
+/// [Exponential Linear Unit](https://arxiv.org/abs/1511.07289).
+/// `t` if `t > 0`, otherwise `alpha * (e^t - 1)`.
+///
+/// It's derivative is `1` if `t > 0`, otherwise `alpha * e^t`.
+///
+/// **Pytorch equivalent**: `torch.nn.functional.elu(t, alpha)`
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([-1.0, 0.0, 1.0, 2.0]);
+/// let r = t.elu(1.0);
+/// ```
+pub fn elu<S: Shape, E: Dtype, D: UnaryKernel<EluKernelOp<E>, E>, T: Tape<D>>(
+    t: Tensor<S, E, D, T>,
+    alpha: E,
+) -> Tensor<S, E, D, T> {
+    t.elu(alpha)
+}
+
+/// [Scaled Exponential Linear Unit](https://arxiv.org/abs/1706.02515).
+/// `scale * elu(t, alpha)`, with the fixed `alpha ≈ 1.6733` and `scale ≈ 1.0507` from the paper.
+///
+/// It's derivative is `scale` if `t > 0`, otherwise `scale * alpha * e^t`.
+///
+/// **Pytorch equivalent**: `torch.nn.functional.selu(t)`
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([-1.0, 0.0, 1.0, 2.0]);
+/// let r = t.selu();
+/// ```
+pub fn selu<S: Shape, E: Dtype, D: UnaryKernel<SeluKernelOp, E>, T: Tape<D>>(
+    t: Tensor<S, E, D, T>,
+) -> Tensor<S, E, D, T> {
+    t.selu()
+}
+
+/// [Continuously Differentiable Exponential Linear Unit](https://arxiv.org/abs/1704.07483).
+/// `t` if `t > 0`, otherwise `alpha * (e^(t / alpha) - 1)`.
+///
+/// It's derivative is `1` if `t > 0`, otherwise `e^(t / alpha)`.
+///
+/// **Pytorch equivalent**: `torch.nn.functional.celu(t, alpha)`
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([-1.0, 0.0, 1.0, 2.0]);
+/// let r = t.celu(2.0);
+/// ```
+pub fn celu<S: Shape, E: Dtype, D: UnaryKernel<CeluKernelOp<E>, E>, T: Tape<D>>(
+    t: Tensor<S, E, D, T>,
+    alpha: E,
+) -> Tensor<S, E, D, T> {
+    t.celu(alpha)
+}
+
+impl<S: Shape, E: Dtype, D: UnaryKernel<EluKernelOp<E>, E>, T: Tape<D>> Tensor<S, E, D, T> {
+    /// See [elu]
+    pub fn elu(self, alpha: E) -> Self {
+        self.try_elu(alpha).unwrap()
+    }
+    /// See [elu]
+    pub fn try_elu(self, alpha: E) -> Result<Self, D::Err> {
+        try_unary_op(EluKernelOp { alpha }, self)
+    }
+}
+
+impl<S: Shape, E: Dtype, D: UnaryKernel<SeluKernelOp, E>, T: Tape<D>> Tensor<S, E, D, T> {
+    /// See [selu]
+    pub fn selu(self) -> Self {
+        self.try_selu().unwrap()
+    }
+    /// See [selu]
+    pub fn try_selu(self) -> Result<Self, D::Err> {
+        try_unary_op(SeluKernelOp, self)
+    }
+}
+
+impl<S: Shape, E: Dtype, D: UnaryKernel<CeluKernelOp<E>, E>, T: Tape<D>> Tensor<S, E, D, T> {
+    /// See [celu]
+    pub fn celu(self, alpha: E) -> Self {
+        self.try_celu(alpha).unwrap()
+    }
+    /// See [celu]
+    pub fn try_celu(self, alpha: E) -> Result<Self, D::Err> {
+        try_unary_op(CeluKernelOp { alpha }, self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_elu() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([-2.0, -0.5, 0.0, 0.5, 2.0]);
+        let r = x.trace().elu(0.5);
+        assert_close(&r.array(), &[-0.43233237, -0.19673467, 0.0, 0.5, 2.0]);
+        let g = r.mean().backward();
+        assert_close(
+            &g.get(&x).array(),
+            &[0.013533528, 0.060653067, 0.1, 0.2, 0.2],
+        );
+    }
+
+    #[test]
+    fn test_selu() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([-2.0, -0.5, 0.0, 0.5, 2.0]);
+        let r = x.trace().selu();
+        assert_close(
+            &r.array(),
+            &[-1.5201665, -0.6917582, 0.0, 0.5253505, 2.101402],
+        );
+        let g = r.mean().backward();
+        assert_close(
+            &g.get(&x).array(),
+            &[0.047586575, 0.21326824, 0.35161987, 0.2101402, 0.2101402],
+        );
+    }
+
+    #[test]
+    fn test_celu() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([-2.0, -0.5, 0.0, 0.5, 2.0]);
+        let r = x.trace().celu(2.0);
+        assert_close(&r.array(), &[-1.2642411, -0.44239843, 0.0, 0.5, 2.0]);
+        let g = r.mean().backward();
+        assert_close(&g.get(&x).array(), &[0.07357589, 0.15576015, 0.2, 0.2, 0.2]);
+    }
+
+    #[test]
+    fn test_celu_one_is_elu() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([-3.0, -1.0, 0.25, 4.0]);
+        assert_close(&x.clone().celu(1.0).array(), &x.elu(1.0).array());
+    }
+}
@@ -0,0 +1,75 @@
+use crate::shapes::{Dim, Dtype, ReplaceDimTo, Shape};
+use crate::tensor::cpu::{Cpu, LendingIterator, StridedArray};
+
+use super::{EmbeddingKernel, EmbeddingOp};
+
+use num_traits::Float;
+use std::sync::Arc;
+
+impl<E: Dtype + Float> EmbeddingKernel<E> for Cpu {
+    fn forward<V: Dim, M: Dim, Dst: Shape, Idx: Shape>(
+        &self,
+        op: EmbeddingOp<E>,
+        weight: &Self::Storage<(V, M), E>,
+        ids: &Self::Storage<Idx, usize>,
+    ) -> Result<Self::Storage<Dst, E>, Self::Err>
+    where
+        (V, M): ReplaceDimTo<Dst, Idx>,
+    {
+        let (vocab, m) = (weight.shape.0.size(), weight.shape.1.size());
+        let [s0, s1] = [weight.strides[0], weight.strides[1]];
+        let mut out: StridedArray<Dst, E> = StridedArray::new(weight.shape.replace(ids.shape))?;
+        let buf = Arc::make_mut(&mut out.data);
+        let mut ids_iter = ids.iter();
+        let mut i = 0;
+        while let Some(&row) = ids_iter.next() {
+            assert!(
+                row < vocab,
+                "index {row} is out of bounds for vocab of size {vocab}"
+            );
+            if row != op.padding_idx {
+                let mut scale = E::one();
+                if op.max_norm > E::zero() {
+                    let norm = (0..m)
+                        .map(|j| weight.data[row * s0 + j * s1].powi(2))
+                        .fold(E::zero(), |a, b| a + b)
+                        .sqrt();
+                    if norm > op.max_norm {
+                        scale = op.max_norm / (norm + E::from_f32(1e-7).unwrap());
+                    }
+                }
+                for j in 0..m {
+                    buf[i * m + j] = weight.data[row * s0 + j * s1] * scale;
+                }
+            }
+            i += 1;
+        }
+        Ok(out)
+    }
+
+    fn backward<V: Dim, M: Dim, Dst: Shape, Idx: Shape>(
+        &self,
+        op: EmbeddingOp<E>,
+        grad_weight: &mut Self::Storage<(V, M), E>,
+        ids: &Self::Storage<Idx, usize>,
+        grad_out: &Self::Storage<Dst, E>,
+    ) -> Result<(), Self::Err>
+    where
+        (V, M): ReplaceDimTo<Dst, Idx>,
+    {
+        let m = grad_weight.shape.1.size();
+        let [s0, s1] = [grad_weight.strides[0], grad_weight.strides[1]];
+        let grad_weight = Arc::make_mut(&mut grad_weight.data);
+        let mut ids_iter = ids.iter();
+        let mut grad_out_iter = grad_out.iter();
+        while let Some(&row) = ids_iter.next() {
+            for j in 0..m {
+                let g = *grad_out_iter.next().unwrap();
+                if row != op.padding_idx {
+                    grad_weight[row * s0 + j * s1] += g;
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,111 @@
+use crate::{
+    shapes::*,
+    tensor::cuda::{Cuda, CudaArray},
+    tensor_ops::cuda_kernels::packed_info,
+};
+use cudarc::driver::{AsKernelParam, LaunchAsync, LaunchConfig};
+use std::sync::Arc;
+
+const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/embedding.ptx"));
+
+unsafe impl AsKernelParam for super::EmbeddingOp<f32> {}
+unsafe impl AsKernelParam for super::EmbeddingOp<f64> {}
+
+trait HasCudaKernel<E> {
+    const MOD: &'static str;
+    const FNS: &'static [&'static str];
+}
+
+impl HasCudaKernel<f32> for Cuda {
+    const MOD: &'static str = "embedding_f32";
+    const FNS: &'static [&'static str] = &["embedding_fwd_f32", "embedding_bwd_f32"];
+}
+
+impl HasCudaKernel<f64> for Cuda {
+    const MOD: &'static str = "embedding_f64";
+    const FNS: &'static [&'static str] = &["embedding_fwd_f64", "embedding_bwd_f64"];
+}
+
+impl<E: Dtype> super::EmbeddingKernel<E> for Cuda
+where
+    Self: HasCudaKernel<E>,
+    super::EmbeddingOp<E>: AsKernelParam,
+{
+    fn forward<V: Dim, M: Dim, Dst: Shape, Idx: Shape>(
+        &self,
+        op: super::EmbeddingOp<E>,
+        weight: &Self::Storage<(V, M), E>,
+        ids: &Self::Storage<Idx, usize>,
+    ) -> Result<Self::Storage<Dst, E>, Self::Err>
+    where
+        (V, M): ReplaceDimTo<Dst, Idx>,
+    {
+        if !self.dev.has_func(Self::MOD, Self::FNS[0]) {
+            self.dev.load_ptx(PTX_SRC.into(), Self::MOD, Self::FNS)?;
+        }
+
+        let shape = weight.shape.replace(ids.shape);
+        let numel = shape.num_elements();
+        let mut storage = unsafe { self.dev.alloc_async::<E>(numel) }?;
+
+        let weight_strides = self.dev.take_async(weight.strides.into())?;
+        let idx_info = self.dev.take_async(packed_info(ids))?;
+
+        let fwd_fn = self.dev.get_func(Self::MOD, Self::FNS[0]).unwrap();
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            op,                    // const EmbeddingOp<T> op,
+            numel,                 // const size_t numel,
+            weight.shape.0.size(), // const size_t vocab,
+            weight.shape.1.size(), // const size_t m,
+            &weight_strides,       // const size_t *weight_strides,
+            weight.data.as_ref(),  // const T *weight,
+            Idx::NUM_DIMS,         // const size_t idx_num_dims,
+            &idx_info,             // const size_t *idx_info,
+            ids.data.as_ref(),     // const size_t *idx,
+            &mut storage,          // T *out
+        );
+        unsafe { fwd_fn.launch_async(cfg, params) }?;
+
+        Ok(CudaArray {
+            data: Arc::new(storage),
+            shape,
+            strides: shape.strides(),
+        })
+    }
+
+    fn backward<V: Dim, M: Dim, Dst: Shape, Idx: Shape>(
+        &self,
+        op: super::EmbeddingOp<E>,
+        grad_weight: &mut Self::Storage<(V, M), E>,
+        ids: &Self::Storage<Idx, usize>,
+        grad_out: &Self::Storage<Dst, E>,
+    ) -> Result<(), Self::Err>
+    where
+        (V, M): ReplaceDimTo<Dst, Idx>,
+    {
+        let numel = grad_out.shape.num_elements();
+
+        let weight_strides = self.dev.take_async(grad_weight.strides.into())?;
+        let idx_info = self.dev.take_async(packed_info(ids))?;
+        let out_info = self.dev.take_async(packed_info(grad_out))?;
+
+        let bwd_fn = self.dev.get_func(Self::MOD, Self::FNS[1]).unwrap();
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            op,                                   // const EmbeddingOp<T> op,
+            numel,                                // const size_t numel,
+            grad_weight.shape.1.size(),           // const size_t m,
+            &weight_strides,                      // const size_t *weight_strides,
+            Arc::make_mut(&mut grad_weight.data), // T *grad_weight,
+            Idx::NUM_DIMS,                        // const size_t idx_num_dims,
+            &idx_info,                            // const size_t *idx_info,
+            ids.data.as_ref(),                    // const size_t *idx,
+            Dst::NUM_DIMS,                        // const size_t out_num_dims,
+            &out_info,                            // const size_t *out_info,
+            grad_out.data.as_ref(),               // const T *grad_out
+        );
+        unsafe { bwd_fn.launch_async(cfg, params) }?;
+        Ok(())
+    }
+}
@@ -0,0 +1,111 @@
+#include "cuda_utils.cuh"
+
+template<typename T>
+struct EmbeddingOp {
+    size_t padding_idx;
+    T max_norm;
+};
+
+template<typename T>
+__device__ void embedding_fwd(
+    const EmbeddingOp<T> op,
+    const size_t numel,
+    const size_t vocab,
+    const size_t m,
+    const size_t *weight_strides,
+    const T *weight,
+    const size_t idx_num_dims,
+    const size_t *idx_info,
+    const size_t *idx,
+    T *out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    // the output is contiguous, with one row of size `m` per id
+    size_t row = idx[get_strided_index(i / m, idx_num_dims, idx_info, idx_info + idx_num_dims)];
+    assert(row < vocab);
+    if (row == op.padding_idx) {
+        out[i] = 0.0;
+        return;
+    }
+
+    const T *w = weight + row * weight_strides[0];
+    T scale = 1.0;
+    if (op.max_norm > 0.0) {
+        T norm = 0.0;
+        for (size_t j = 0; j < m; j++) {
+            T x = w[j * weight_strides[1]];
+            norm += x * x;
+        }
+        norm = sqrtg(norm);
+        if (norm > op.max_norm) {
+            scale = op.max_norm / (norm + 1e-7);
+        }
+    }
+    out[i] = w[(i % m) * weight_strides[1]] * scale;
+}
+
+template<typename T>
+__device__ void embedding_bwd(
+    const EmbeddingOp<T> op,
+    const size_t numel,
+    const size_t m,
+    const size_t *weight_strides,
+    T *grad_weight,
+    const size_t idx_num_dims,
+    const size_t *idx_info,
+    const size_t *idx,
+    const size_t out_num_dims,
+    const size_t *out_info,
+    const T *grad_out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    // one thread per looked up element, so only the rows that were looked up are touched
+    size_t row = idx[get_strided_index(i / m, idx_num_dims, idx_info, idx_info + idx_num_dims)];
+    if (row == op.padding_idx) {
+        return;
+    }
+    T g = grad_out[get_strided_index(i, out_num_dims, out_info, out_info + out_num_dims)];
+    atomicAdd(grad_weight + row * weight_strides[0] + (i % m) * weight_strides[1], g);
+}
+
+#define EMBEDDING_OP(TYPENAME, FWD, BWD) \
+extern "C" __global__ void FWD( \
+    const EmbeddingOp<TYPENAME> op, \
+    const size_t numel, \
+    const size_t vocab, \
+    const size_t m, \
+    const size_t *weight_strides, \
+    const TYPENAME *weight, \
+    const size_t idx_num_dims, \
+    const size_t *idx_info, \
+    const size_t *idx, \
+    TYPENAME *out \
+) { \
+    embedding_fwd(op, numel, vocab, m, weight_strides, weight, idx_num_dims, idx_info, idx, out); \
+} \
+extern "C" __global__ void BWD( \
+    const EmbeddingOp<TYPENAME> op, \
+    const size_t numel, \
+    const size_t m, \
+    const size_t *weight_strides, \
+    TYPENAME *grad_weight, \
+    const size_t idx_num_dims, \
+    const size_t *idx_info, \
+    const size_t *idx, \
+    const size_t out_num_dims, \
+    const size_t *out_info, \
+    const TYPENAME *grad_out \
+) { \
+    embedding_bwd(op, numel, m, weight_strides, grad_weight, idx_num_dims, idx_info, idx, out_num_dims, out_info, grad_out); \
+}
+
+EMBEDDING_OP(float, embedding_fwd_f32, embedding_bwd_f32);
+EMBEDDING_OP(double, embedding_fwd_f64, embedding_bwd_f64);
@@ -0,0 +1,196 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{gradients::Tape, shapes::*, tensor::*};
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct EmbeddingOp<E> {
+    /// The row that is always looked up as zeros, or `usize::MAX` for none
+    pub padding_idx: usize,
+    /// Looked up rows with a larger l2 norm are scaled down to this norm, or `0` for none
+    pub max_norm: E,
+}
+
+pub trait EmbeddingKernel<E: Dtype>: DeviceStorage {
+    fn forward<V: Dim, M: Dim, Dst: Shape, Idx: Shape>(
+        &self,
+        op: EmbeddingOp<E>,
+        weight: &Self::Storage<(V, M), E>,
+        ids: &Self::Storage<Idx, usize>,
+    ) -> Result<Self::Storage<Dst, E>, Self::Err>
+    where
+        (V, M): ReplaceDimTo<Dst, Idx>;
+
+    /// Only adds to the rows of `grad_weight` that were looked up, so the cost
+    /// doesn't depend on the size of the vocabulary.
+    fn backward<V: Dim, M: Dim, Dst: Shape, Idx: Shape>(
+        &self,
+        op: EmbeddingOp<E>,
+        grad_weight: &mut Self::Storage<(V, M), E>,
+        ids: &Self::Storage<Idx, usize>,
+        grad_out: &Self::Storage<Dst, E>,
+    ) -> Result<(), Self::Err>
+    where
+        (V, M): ReplaceDimTo<Dst, Idx>;
+}
+
+impl<V: Dim, M: Dim, E: Dtype, D: EmbeddingKernel<E>, T: Tape<D>> Tensor<(V, M), E, D, T> {
+    /// Looks up the rows of an embedding table `(V, M)` for every id in `ids`, which
+    /// results in a tensor of shape `(..ids, M)`. This is like [crate::tensor_ops::GatherTo::gather()]
+    /// along the first axis, with two extra options:
+    ///
+    /// - `padding_idx`: this id is always looked up as a vector of zeros, and its row of
+    ///   the table doesn't receive any gradient.
+    /// - `max_norm`: looked up rows with an l2 norm larger than this are scaled down to have
+    ///   norm `max_norm`. The scaling is not differentiated through, so the gradient is the
+    ///   same as if the table had been renormalized beforehand.
+    ///
+    /// **Pytorch equivalent**: `torch.nn.functional.embedding(ids, weight, padding_idx, max_norm)`
+    ///
+    /// Examples:
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let weight = dev.tensor([[1.0, 2.0], [3.0, 4.0], [0.5, 0.5]]);
+    /// let ids = dev.tensor([1, 0, 2]);
+    /// let r = weight.clone().embedding(ids.clone(), Some(0), None);
+    /// assert_eq!(r.array(), [[3.0, 4.0], [0.0, 0.0], [0.5, 0.5]]);
+    /// let r = weight.embedding(ids, None, Some(1.0));
+    /// assert!(r.square().sum::<Rank1<3>, _>().array().iter().all(|&n| n <= 1.0));
+    /// ```
+    pub fn embedding<Dst: Shape, Idx: Shape>(
+        self,
+        ids: Tensor<Idx, usize, D>,
+        padding_idx: Option<usize>,
+        max_norm: Option<E>,
+    ) -> Tensor<Dst, E, D, T>
+    where
+        (V, M): ReplaceDimTo<Dst, Idx>,
+    {
+        self.try_embedding(ids, padding_idx, max_norm).unwrap()
+    }
+
+    /// Fallible version of [Tensor::embedding]
+    pub fn try_embedding<Dst: Shape, Idx: Shape>(
+        self,
+        ids: Tensor<Idx, usize, D>,
+        padding_idx: Option<usize>,
+        max_norm: Option<E>,
+    ) -> Result<Tensor<Dst, E, D, T>, D::Err>
+    where
+        (V, M): ReplaceDimTo<Dst, Idx>,
+    {
+        if let Some(max_norm) = max_norm {
+            assert!(max_norm > E::default(), "max_norm must be positive");
+        }
+        let op = EmbeddingOp {
+            padding_idx: padding_idx.unwrap_or(usize::MAX),
+            max_norm: max_norm.unwrap_or_default(),
+        };
+        let (weight, mut tape) = self.split_tape();
+        let out =
+            weight
+                .device
+                .upgrade(weight.device.forward(op, &weight.storage, &ids.storage)?);
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&weight)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_weight, grad_out) = grads.mut_and_ref(&weight, &phantom_out);
+            weight
+                .device
+                .backward(op, grad_weight, &ids.storage, grad_out)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{shapes::*, tensor::*, tensor_ops::*, tests::*};
+
+    const W: [[TestDtype; 3]; 4] = [
+        [1.0, -2.0, 2.0],
+        [0.5, 0.0, -0.5],
+        [3.0, 0.0, 4.0],
+        [-1.0, 1.0, 0.0],
+    ];
+
+    #[test]
+    fn test_embedding_matches_gather() {
+        let dev: TestDevice = Default::default();
+        let w: Tensor<_, TestDtype, _> = dev.tensor(W);
+        let ids = dev.tensor([[3, 0, 0], [2, 1, 3]]);
+        let r = w.trace().embedding(ids.clone(), None, None);
+        let r2 = w.trace().gather(ids);
+        assert_eq!(r.array(), r2.array());
+        let g = r.exp().sum().backward();
+        let g2 = r2.exp().sum().backward();
+        assert_close(&g.get(&w).array(), &g2.get(&w).array());
+    }
+
+    #[test]
+    fn test_embedding_padding_idx() {
+        let dev: TestDevice = Default::default();
+        let w: Tensor<_, TestDtype, _> = dev.tensor(W);
+        let r = w.trace().embedding(dev.tensor([1, 2, 1, 0]), Some(1), None);
+        assert_eq!(
+            r.array(),
+            [[0.0; 3], [3.0, 0.0, 4.0], [0.0; 3], [1.0, -2.0, 2.0]]
+        );
+        let g = r.sum().backward();
+        assert_eq!(g.get(&w).array(), [[1.0; 3], [0.0; 3], [1.0; 3], [0.0; 3]]);
+    }
+
+    #[test]
+    fn test_embedding_max_norm() {
+        let dev: TestDevice = Default::default();
+        let w: Tensor<_, TestDtype, _> = dev.tensor(W);
+        let r = w
+            .trace()
+            .embedding(dev.tensor([0, 1, 2, 2]), None, Some(2.0));
+        assert_close(
+            &r.array(),
+            &[
+                [0.6666667, -1.3333334, 1.3333334],
+                [0.5, 0.0, -0.5],
+                [1.2, 0.0, 1.6],
+                [1.2, 0.0, 1.6],
+            ],
+        );
+        // the renormalization is not differentiated through
+        let g = (r * dev.tensor([[1.0, 2.0, 3.0]; 4])).sum().backward();
+        assert_eq!(
+            g.get(&w).array(),
+            [[1.0, 2.0, 3.0], [1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [0.0; 3]]
+        );
+    }
+
+    #[test]
+    fn test_embedding_permuted_table() {
+        let dev: TestDevice = Default::default();
+        let w: Tensor<Rank2<3, 4>, TestDtype, _> = dev.sample_normal();
+        let ids = dev.tensor([[2, 0], [3, 3]]);
+        let r = w
+            .trace()
+            .permute::<Rank2<4, 3>, _>()
+            .embedding(ids.clone(), Some(0), None);
+        let r2 = w.trace().permute::<Rank2<4, 3>, _>().gather(ids);
+        let r2 = r2.array();
+        let r = r.array();
+        assert_eq!(r[0][0], r2[0][0]);
+        assert_eq!(r[0][1], [0.0; 3]);
+        assert_eq!(r[1], r2[1]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_embedding_out_of_bounds() {
+        let dev: TestDevice = Default::default();
+        let w: Tensor<_, TestDtype, _> = dev.tensor(W);
+        let _ = w.embedding(dev.tensor([4]), None, None);
+    }
+}
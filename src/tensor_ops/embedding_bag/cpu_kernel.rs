@@ -0,0 +1,138 @@
+use crate::shapes::{Dim, Dtype};
+use crate::tensor::cpu::{Cpu, StridedArray};
+
+use super::{EmbeddingBagKernel, EmbeddingBagMode};
+
+use num_traits::Float;
+use std::{sync::Arc, vec::Vec};
+
+/// The `[start, end)` range of `ids` that make up every bag.
+fn bag_ranges<N: Dim, B: Dim>(
+    ids: &StridedArray<(N,), usize>,
+    offsets: &StridedArray<(B,), usize>,
+) -> Vec<(usize, usize)> {
+    let n = ids.shape.0.size();
+    let num_bags = offsets.shape.0.size();
+    let offset = |b: usize| offsets.data[b * offsets.strides[0]];
+    (0..num_bags)
+        .map(|b| {
+            let start = offset(b);
+            let end = if b + 1 < num_bags { offset(b + 1) } else { n };
+            assert!(
+                start <= end && end <= n,
+                "offsets must be non decreasing and at most the number of ids, found {start} then {end} with {n} ids"
+            );
+            (start, end)
+        })
+        .collect()
+}
+
+/// The first row of the bag that attains the maximum in column `j`.
+fn argmax<E: Dtype + Float>(
+    weight: &[E],
+    [s0, s1]: [usize; 2],
+    rows: impl Iterator<Item = usize>,
+    j: usize,
+) -> Option<usize> {
+    let mut best: Option<(usize, E)> = None;
+    for row in rows {
+        let x = weight[row * s0 + j * s1];
+        let better = match best {
+            None => true,
+            Some((_, b)) => x > b,
+        };
+        if better {
+            best = Some((row, x));
+        }
+    }
+    best.map(|(row, _)| row)
+}
+
+impl<E: Dtype + Float> EmbeddingBagKernel<E> for Cpu {
+    fn forward<V: Dim, M: Dim, N: Dim, B: Dim>(
+        &self,
+        mode: EmbeddingBagMode,
+        weight: &Self::Storage<(V, M), E>,
+        ids: &Self::Storage<(N,), usize>,
+        offsets: &Self::Storage<(B,), usize>,
+    ) -> Result<Self::Storage<(B, M), E>, Self::Err> {
+        let (vocab, m) = (weight.shape.0.size(), weight.shape.1.size());
+        let strides = [weight.strides[0], weight.strides[1]];
+        let id = |i: usize| {
+            let row = ids.data[i * ids.strides[0]];
+            assert!(
+                row < vocab,
+                "index {row} is out of bounds for vocab of size {vocab}"
+            );
+            row
+        };
+
+        let mut out: StridedArray<(B, M), E> =
+            StridedArray::new((offsets.shape.0, weight.shape.1))?;
+        let buf = Arc::make_mut(&mut out.data);
+        for (b, (start, end)) in bag_ranges(ids, offsets).into_iter().enumerate() {
+            let rows: Vec<usize> = (start..end).map(id).collect();
+            if rows.is_empty() {
+                continue;
+            }
+            for j in 0..m {
+                let col = |row: usize| weight.data[row * strides[0] + j * strides[1]];
+                buf[b * m + j] = match mode {
+                    EmbeddingBagMode::Sum => {
+                        rows.iter().map(|&r| col(r)).fold(E::zero(), |a, x| a + x)
+                    }
+                    EmbeddingBagMode::Mean => {
+                        rows.iter().map(|&r| col(r)).fold(E::zero(), |a, x| a + x)
+                            / E::from_usize(rows.len()).unwrap()
+                    }
+                    EmbeddingBagMode::Max => {
+                        col(argmax(&weight.data, strides, rows.iter().copied(), j).unwrap())
+                    }
+                };
+            }
+        }
+        Ok(out)
+    }
+
+    fn backward<V: Dim, M: Dim, N: Dim, B: Dim>(
+        &self,
+        mode: EmbeddingBagMode,
+        weight: &Self::Storage<(V, M), E>,
+        grad_weight: &mut Self::Storage<(V, M), E>,
+        ids: &Self::Storage<(N,), usize>,
+        offsets: &Self::Storage<(B,), usize>,
+        grad_out: &Self::Storage<(B, M), E>,
+    ) -> Result<(), Self::Err> {
+        let m = grad_weight.shape.1.size();
+        let strides = [weight.strides[0], weight.strides[1]];
+        let [g0, g1] = [grad_weight.strides[0], grad_weight.strides[1]];
+        let [o0, o1] = [grad_out.strides[0], grad_out.strides[1]];
+        let id = |i: usize| ids.data[i * ids.strides[0]];
+        let grad_weight = Arc::make_mut(&mut grad_weight.data);
+        for (b, (start, end)) in bag_ranges(ids, offsets).into_iter().enumerate() {
+            if start == end {
+                continue;
+            }
+            for j in 0..m {
+                let g = grad_out.data[b * o0 + j * o1];
+                match mode {
+                    EmbeddingBagMode::Sum | EmbeddingBagMode::Mean => {
+                        let g = if mode == EmbeddingBagMode::Mean {
+                            g / E::from_usize(end - start).unwrap()
+                        } else {
+                            g
+                        };
+                        for i in start..end {
+                            grad_weight[id(i) * g0 + j * g1] += g;
+                        }
+                    }
+                    EmbeddingBagMode::Max => {
+                        let row = argmax(&weight.data, strides, (start..end).map(id), j).unwrap();
+                        grad_weight[row * g0 + j * g1] += g;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,117 @@
+use crate::{
+    shapes::*,
+    tensor::cuda::{Cuda, CudaArray},
+};
+use cudarc::driver::{AsKernelParam, LaunchAsync, LaunchConfig};
+use std::sync::Arc;
+
+use super::EmbeddingBagMode;
+
+const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/embedding_bag.ptx"));
+
+unsafe impl AsKernelParam for EmbeddingBagMode {}
+
+trait HasCudaKernel<E> {
+    const MOD: &'static str;
+    const FNS: &'static [&'static str];
+}
+
+impl HasCudaKernel<f32> for Cuda {
+    const MOD: &'static str = "embedding_bag_f32";
+    const FNS: &'static [&'static str] = &["embedding_bag_fwd_f32", "embedding_bag_bwd_f32"];
+}
+
+impl HasCudaKernel<f64> for Cuda {
+    const MOD: &'static str = "embedding_bag_f64";
+    const FNS: &'static [&'static str] = &["embedding_bag_fwd_f64", "embedding_bag_bwd_f64"];
+}
+
+impl<E: Dtype> super::EmbeddingBagKernel<E> for Cuda
+where
+    Self: HasCudaKernel<E>,
+{
+    fn forward<V: Dim, M: Dim, N: Dim, B: Dim>(
+        &self,
+        mode: EmbeddingBagMode,
+        weight: &Self::Storage<(V, M), E>,
+        ids: &Self::Storage<(N,), usize>,
+        offsets: &Self::Storage<(B,), usize>,
+    ) -> Result<Self::Storage<(B, M), E>, Self::Err> {
+        if !self.dev.has_func(Self::MOD, Self::FNS[0]) {
+            self.dev.load_ptx(PTX_SRC.into(), Self::MOD, Self::FNS)?;
+        }
+
+        let shape = (offsets.shape.0, weight.shape.1);
+        let numel = shape.num_elements();
+        let mut storage = unsafe { self.dev.alloc_async::<E>(numel) }?;
+
+        let strides = [
+            weight.strides[0],
+            weight.strides[1],
+            ids.strides[0],
+            offsets.strides[0],
+        ];
+        let strides = self.dev.take_async(strides.into())?;
+
+        let fwd_fn = self.dev.get_func(Self::MOD, Self::FNS[0]).unwrap();
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            mode,                   // const EmbeddingBagMode mode,
+            numel,                  // const size_t numel,
+            ids.shape.0.size(),     // const size_t n,
+            offsets.shape.0.size(), // const size_t num_bags,
+            weight.shape.0.size(),  // const size_t vocab,
+            weight.shape.1.size(),  // const size_t m,
+            &strides,               // const size_t *strides,
+            weight.data.as_ref(),   // const T *weight,
+            ids.data.as_ref(),      // const size_t *ids,
+            offsets.data.as_ref(),  // const size_t *offsets,
+            &mut storage,           // T *out
+        );
+        unsafe { fwd_fn.launch_async(cfg, params) }?;
+
+        Ok(CudaArray {
+            data: Arc::new(storage),
+            shape,
+            strides: shape.strides(),
+        })
+    }
+
+    fn backward<V: Dim, M: Dim, N: Dim, B: Dim>(
+        &self,
+        mode: EmbeddingBagMode,
+        weight: &Self::Storage<(V, M), E>,
+        grad_weight: &mut Self::Storage<(V, M), E>,
+        ids: &Self::Storage<(N,), usize>,
+        offsets: &Self::Storage<(B,), usize>,
+        grad_out: &Self::Storage<(B, M), E>,
+    ) -> Result<(), Self::Err> {
+        let numel = grad_out.shape.num_elements();
+
+        let strides = [
+            weight.strides[0],
+            weight.strides[1],
+            ids.strides[0],
+            offsets.strides[0],
+        ];
+        let strides = self.dev.take_async(strides.into())?;
+
+        let bwd_fn = self.dev.get_func(Self::MOD, Self::FNS[1]).unwrap();
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            mode,                                 // const EmbeddingBagMode mode,
+            numel,                                // const size_t numel,
+            ids.shape.0.size(),                   // const size_t n,
+            offsets.shape.0.size(),               // const size_t num_bags,
+            weight.shape.1.size(),                // const size_t m,
+            &strides,                             // const size_t *strides,
+            weight.data.as_ref(),                 // const T *weight,
+            Arc::make_mut(&mut grad_weight.data), // T *grad_weight,
+            ids.data.as_ref(),                    // const size_t *ids,
+            offsets.data.as_ref(),                // const size_t *offsets,
+            grad_out.data.as_ref(),               // const T *grad_out
+        );
+        unsafe { bwd_fn.launch_async(cfg, params) }?;
+        Ok(())
+    }
+}
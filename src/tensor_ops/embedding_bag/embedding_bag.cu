@@ -0,0 +1,180 @@
+#include "cuda_utils.cuh"
+
+enum EmbeddingBagMode {
+    EMBEDDING_BAG_SUM,
+    EMBEDDING_BAG_MEAN,
+    EMBEDDING_BAG_MAX,
+};
+
+// the [start, end) range of ids that make up bag `b`
+__device__ __forceinline__ void bag_range(
+    const size_t b,
+    const size_t n,
+    const size_t num_bags,
+    const size_t offsets_stride,
+    const size_t *offsets,
+    size_t *start,
+    size_t *end
+) {
+    *start = offsets[b * offsets_stride];
+    *end = b + 1 < num_bags ? offsets[(b + 1) * offsets_stride] : n;
+    assert(*start <= *end && *end <= n);
+}
+
+// the first row of the bag that attains the maximum in column `j`
+template<typename T>
+__device__ size_t bag_argmax(
+    const size_t start,
+    const size_t end,
+    const size_t j,
+    const size_t *weight_strides,
+    const T *weight,
+    const size_t ids_stride,
+    const size_t *ids
+) {
+    size_t best_row = ids[start * ids_stride];
+    T best = weight[best_row * weight_strides[0] + j * weight_strides[1]];
+    for (size_t i = start + 1; i < end; i++) {
+        size_t row = ids[i * ids_stride];
+        T x = weight[row * weight_strides[0] + j * weight_strides[1]];
+        if (x > best) {
+            best = x;
+            best_row = row;
+        }
+    }
+    return best_row;
+}
+
+template<typename T>
+__device__ void embedding_bag_fwd(
+    const EmbeddingBagMode mode,
+    const size_t numel,
+    const size_t n,
+    const size_t num_bags,
+    const size_t vocab,
+    const size_t m,
+    const size_t *strides,
+    const T *weight,
+    const size_t *ids,
+    const size_t *offsets,
+    T *out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    // the 2 weight strides, then the ids stride and the offsets stride
+    const size_t *weight_strides = strides;
+    const size_t ids_stride = strides[2];
+    const size_t offsets_stride = strides[3];
+
+    // one thread per element of the contiguous (num_bags, m) output
+    size_t b = i / m;
+    size_t j = i % m;
+    size_t start, end;
+    bag_range(b, n, num_bags, offsets_stride, offsets, &start, &end);
+    if (start == end) {
+        out[i] = 0.0;
+        return;
+    }
+
+    for (size_t k = start; k < end; k++) {
+        assert(ids[k * ids_stride] < vocab);
+    }
+
+    if (mode == EMBEDDING_BAG_MAX) {
+        size_t row = bag_argmax(start, end, j, weight_strides, weight, ids_stride, ids);
+        out[i] = weight[row * weight_strides[0] + j * weight_strides[1]];
+        return;
+    }
+
+    T sum = 0.0;
+    for (size_t k = start; k < end; k++) {
+        sum += weight[ids[k * ids_stride] * weight_strides[0] + j * weight_strides[1]];
+    }
+    out[i] = mode == EMBEDDING_BAG_MEAN ? sum / (T)(end - start) : sum;
+}
+
+// `grad_weight` has the same strides as `weight`, and `grad_out` is contiguous
+template<typename T>
+__device__ void embedding_bag_bwd(
+    const EmbeddingBagMode mode,
+    const size_t numel,
+    const size_t n,
+    const size_t num_bags,
+    const size_t m,
+    const size_t *strides,
+    const T *weight,
+    T *grad_weight,
+    const size_t *ids,
+    const size_t *offsets,
+    const T *grad_out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    const size_t *weight_strides = strides;
+    const size_t ids_stride = strides[2];
+    const size_t offsets_stride = strides[3];
+
+    size_t b = i / m;
+    size_t j = i % m;
+    size_t start, end;
+    bag_range(b, n, num_bags, offsets_stride, offsets, &start, &end);
+    if (start == end) {
+        return;
+    }
+
+    T g = grad_out[i];
+    if (mode == EMBEDDING_BAG_MAX) {
+        size_t row = bag_argmax(start, end, j, weight_strides, weight, ids_stride, ids);
+        atomicAdd(grad_weight + row * weight_strides[0] + j * weight_strides[1], g);
+        return;
+    }
+
+    if (mode == EMBEDDING_BAG_MEAN) {
+        g /= (T)(end - start);
+    }
+    for (size_t k = start; k < end; k++) {
+        size_t row = ids[k * ids_stride];
+        atomicAdd(grad_weight + row * weight_strides[0] + j * weight_strides[1], g);
+    }
+}
+
+#define EMBEDDING_BAG_OP(TYPENAME, FWD, BWD) \
+extern "C" __global__ void FWD( \
+    const EmbeddingBagMode mode, \
+    const size_t numel, \
+    const size_t n, \
+    const size_t num_bags, \
+    const size_t vocab, \
+    const size_t m, \
+    const size_t *strides, \
+    const TYPENAME *weight, \
+    const size_t *ids, \
+    const size_t *offsets, \
+    TYPENAME *out \
+) { \
+    embedding_bag_fwd(mode, numel, n, num_bags, vocab, m, strides, weight, ids, offsets, out); \
+} \
+extern "C" __global__ void BWD( \
+    const EmbeddingBagMode mode, \
+    const size_t numel, \
+    const size_t n, \
+    const size_t num_bags, \
+    const size_t m, \
+    const size_t *strides, \
+    const TYPENAME *weight, \
+    TYPENAME *grad_weight, \
+    const size_t *ids, \
+    const size_t *offsets, \
+    const TYPENAME *grad_out \
+) { \
+    embedding_bag_bwd(mode, numel, n, num_bags, m, strides, weight, grad_weight, ids, offsets, grad_out); \
+}
+
+EMBEDDING_BAG_OP(float, embedding_bag_fwd_f32, embedding_bag_bwd_f32);
+EMBEDDING_BAG_OP(double, embedding_bag_fwd_f64, embedding_bag_bwd_f64);
@@ -0,0 +1,213 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{gradients::Tape, shapes::*, tensor::*};
+
+/// How [Tensor::embedding_bag()] reduces the looked up rows of a bag.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum EmbeddingBagMode {
+    Sum,
+    #[default]
+    Mean,
+    /// Elementwise maximum. The gradient only flows to the first row that attained it.
+    Max,
+}
+
+pub trait EmbeddingBagKernel<E: Dtype>: DeviceStorage {
+    fn forward<V: Dim, M: Dim, N: Dim, B: Dim>(
+        &self,
+        mode: EmbeddingBagMode,
+        weight: &Self::Storage<(V, M), E>,
+        ids: &Self::Storage<(N,), usize>,
+        offsets: &Self::Storage<(B,), usize>,
+    ) -> Result<Self::Storage<(B, M), E>, Self::Err>;
+
+    /// Only adds to the rows of `grad_weight` that were looked up. `weight` is
+    /// needed to find the rows that attained the maximum for [EmbeddingBagMode::Max].
+    fn backward<V: Dim, M: Dim, N: Dim, B: Dim>(
+        &self,
+        mode: EmbeddingBagMode,
+        weight: &Self::Storage<(V, M), E>,
+        grad_weight: &mut Self::Storage<(V, M), E>,
+        ids: &Self::Storage<(N,), usize>,
+        offsets: &Self::Storage<(B,), usize>,
+        grad_out: &Self::Storage<(B, M), E>,
+    ) -> Result<(), Self::Err>;
+}
+
+impl<V: Dim, M: Dim, E: Dtype, D: EmbeddingBagKernel<E>, T: Tape<D>> Tensor<(V, M), E, D, T> {
+    /// Looks up the rows of an embedding table `(V, M)` for a flat list of `ids`, and
+    /// reduces them per bag with `mode`. Bag `b` consists of `ids[offsets[b]..offsets[b + 1]]`,
+    /// and the last bag runs until the end of `ids`. So `offsets` must be non decreasing
+    /// and start at 0. Empty bags result in a row of zeros.
+    ///
+    /// This is equivalent to [Tensor::embedding()] followed by a reduction of every bag,
+    /// but without materializing the looked up rows.
+    ///
+    /// **Pytorch equivalent**: `torch.nn.functional.embedding_bag(ids, weight, offsets, mode=mode)`
+    ///
+    /// Examples:
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let weight = dev.tensor([[1.0, 2.0], [3.0, -4.0], [0.0, 5.0]]);
+    /// let ids = dev.tensor([0, 2, 1, 1, 2, 0]);
+    /// let offsets = dev.tensor([0, 2, 3]);
+    /// let r = weight.clone().embedding_bag(ids.clone(), offsets.clone(), EmbeddingBagMode::Sum);
+    /// assert_eq!(r.array(), [[1.0, 7.0], [3.0, -4.0], [4.0, 3.0]]);
+    /// let r = weight.embedding_bag(ids, offsets, EmbeddingBagMode::Max);
+    /// assert_eq!(r.array(), [[1.0, 5.0], [3.0, -4.0], [3.0, 5.0]]);
+    /// ```
+    pub fn embedding_bag<N: Dim, B: Dim>(
+        self,
+        ids: Tensor<(N,), usize, D>,
+        offsets: Tensor<(B,), usize, D>,
+        mode: EmbeddingBagMode,
+    ) -> Tensor<(B, M), E, D, T> {
+        self.try_embedding_bag(ids, offsets, mode).unwrap()
+    }
+
+    /// Fallible version of [Tensor::embedding_bag]
+    #[allow(clippy::type_complexity)]
+    pub fn try_embedding_bag<N: Dim, B: Dim>(
+        self,
+        ids: Tensor<(N,), usize, D>,
+        offsets: Tensor<(B,), usize, D>,
+        mode: EmbeddingBagMode,
+    ) -> Result<Tensor<(B, M), E, D, T>, D::Err> {
+        let (weight, mut tape) = self.split_tape();
+        let out = weight.device.upgrade(weight.device.forward(
+            mode,
+            &weight.storage,
+            &ids.storage,
+            &offsets.storage,
+        )?);
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&weight)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_weight, grad_out) = grads.mut_and_ref(&weight, &phantom_out);
+            weight.device.backward(
+                mode,
+                &weight.storage,
+                grad_weight,
+                &ids.storage,
+                &offsets.storage,
+                grad_out,
+            )
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EmbeddingBagMode;
+    use crate::{shapes::*, tensor::*, tensor_ops::*, tests::*};
+
+    const W: [[TestDtype; 3]; 4] = [
+        [1.0, -2.0, 2.0],
+        [0.5, 0.0, -0.5],
+        [3.0, 0.0, 4.0],
+        [-1.0, 1.0, 0.0],
+    ];
+
+    #[test]
+    fn test_embedding_bag_matches_embedding() {
+        let dev: TestDevice = Default::default();
+        let w: Tensor<_, TestDtype, _> = dev.tensor(W);
+        let ids = dev.tensor([[3, 0, 0], [2, 1, 3]]);
+        let flat_ids = dev.tensor([3, 0, 0, 2, 1, 3]);
+        let offsets = dev.tensor([0, 3]);
+
+        let r = w
+            .trace()
+            .embedding_bag(flat_ids.clone(), offsets.clone(), EmbeddingBagMode::Sum);
+        let r2 = w
+            .trace()
+            .embedding::<Rank3<2, 3, 3>, _>(ids.clone(), None, None)
+            .sum::<Rank2<2, 3>, Axis<1>>();
+        assert_close(&r.array(), &r2.array());
+        let g = r.exp().sum().backward();
+        let g2 = r2.exp().sum().backward();
+        assert_close(&g.get(&w).array(), &g2.get(&w).array());
+
+        let r = w
+            .trace()
+            .embedding_bag(flat_ids, offsets, EmbeddingBagMode::Mean);
+        let r2 = w
+            .trace()
+            .embedding::<Rank3<2, 3, 3>, _>(ids, None, None)
+            .mean::<Rank2<2, 3>, Axis<1>>();
+        assert_close(&r.array(), &r2.array());
+        let g = r.exp().sum().backward();
+        let g2 = r2.exp().sum().backward();
+        assert_close(&g.get(&w).array(), &g2.get(&w).array());
+    }
+
+    #[test]
+    fn test_embedding_bag_uneven_bags() {
+        let dev: TestDevice = Default::default();
+        let w: Tensor<_, TestDtype, _> = dev.tensor(W);
+        let ids = dev.tensor([2, 0, 1, 3, 1]);
+        let offsets = dev.tensor([0, 1, 1, 4]);
+        let r = w
+            .trace()
+            .embedding_bag(ids, offsets, EmbeddingBagMode::Mean);
+        assert_close(
+            &r.array(),
+            &[
+                [3.0, 0.0, 4.0],
+                [0.0; 3],
+                [1.0 / 6.0, -1.0 / 3.0, 0.5],
+                [0.5, 0.0, -0.5],
+            ],
+        );
+        let g = r.sum().backward();
+        assert_close(
+            &g.get(&w).array(),
+            &[[1.0 / 3.0; 3], [4.0 / 3.0; 3], [1.0; 3], [1.0 / 3.0; 3]],
+        );
+    }
+
+    #[test]
+    fn test_embedding_bag_max() {
+        let dev: TestDevice = Default::default();
+        let w: Tensor<_, TestDtype, _> = dev.tensor(W);
+        let ids = dev.tensor([0, 1, 3, 2, 3, 3]);
+        let offsets = dev.tensor([0, 3, 4]);
+        let r = w.trace().embedding_bag(ids, offsets, EmbeddingBagMode::Max);
+        assert_eq!(
+            r.array(),
+            [[1.0, 1.0, 2.0], [3.0, 0.0, 4.0], [-1.0, 1.0, 0.0]]
+        );
+        // ties go to the first row that attained the maximum
+        let g = (r * dev.tensor([[1.0, 2.0, 3.0]; 3])).sum().backward();
+        assert_eq!(
+            g.get(&w).array(),
+            [[1.0, 0.0, 3.0], [0.0; 3], [1.0, 2.0, 3.0], [1.0, 4.0, 3.0]]
+        );
+    }
+
+    #[test]
+    fn test_embedding_bag_runtime_bags() {
+        let dev: TestDevice = Default::default();
+        let w: Tensor<_, TestDtype, _> = dev.tensor(W);
+        let ids: Tensor<(usize,), usize, _> = dev.tensor_from_vec(std::vec![1, 2, 3], (3,));
+        let offsets: Tensor<(usize,), usize, _> = dev.tensor_from_vec(std::vec![0, 2], (2,));
+        let r = w.trace().embedding_bag(ids, offsets, EmbeddingBagMode::Sum);
+        assert_eq!(r.shape(), &(2, Const::<3>));
+        assert_close(&r.as_vec(), &std::vec![3.5, 0.0, 3.5, -1.0, 1.0, 0.0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_embedding_bag_out_of_bounds() {
+        let dev: TestDevice = Default::default();
+        let w: Tensor<_, TestDtype, _> = dev.tensor(W);
+        let _ = w.embedding_bag(dev.tensor([0, 4]), dev.tensor([0]), EmbeddingBagMode::Sum);
+    }
+}
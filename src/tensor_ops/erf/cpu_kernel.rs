@@ -0,0 +1,25 @@
+use crate::tensor_ops::{cpu_kernels::UnaryDerivative, special};
+use num_traits::{Float, FloatConst};
+
+impl<F: Float + FloatConst> UnaryDerivative<F> for super::ErfKernelOp {
+    #[inline(always)]
+    fn f(&self, x: &F) -> F {
+        F::from(special::erf(x.to_f64().unwrap())).unwrap()
+    }
+    #[inline(always)]
+    fn df(&self, &x: &F) -> F {
+        F::FRAC_2_SQRT_PI() * (-x * x).exp()
+    }
+}
+
+impl<F: Float + FloatConst> UnaryDerivative<F> for super::ErfInvKernelOp {
+    #[inline(always)]
+    fn f(&self, x: &F) -> F {
+        F::from(special::erfinv(x.to_f64().unwrap())).unwrap()
+    }
+    #[inline(always)]
+    fn df(&self, x: &F) -> F {
+        let y = self.f(x);
+        (y * y).exp() / F::FRAC_2_SQRT_PI()
+    }
+}
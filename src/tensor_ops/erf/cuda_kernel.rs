@@ -0,0 +1,12 @@
+use super::{ErfInvKernelOp, ErfKernelOp};
+use crate::tensor_ops::cuda_kernels::cuda_unary;
+
+unsafe impl cudarc::driver::AsKernelParam for ErfKernelOp {}
+unsafe impl cudarc::driver::AsKernelParam for ErfInvKernelOp {}
+
+const PTX: &str = include_str!(concat!(env!("OUT_DIR"), "/erf.ptx"));
+
+cuda_unary!(ErfKernelOp, f32, PTX, "erf_fwd_f32", "erf_bwd_f32");
+cuda_unary!(ErfKernelOp, f64, PTX, "erf_fwd_f64", "erf_bwd_f64");
+cuda_unary!(ErfInvKernelOp, f32, PTX, "erfinv_fwd_f32", "erfinv_bwd_f32");
+cuda_unary!(ErfInvKernelOp, f64, PTX, "erfinv_fwd_f64", "erfinv_bwd_f64");
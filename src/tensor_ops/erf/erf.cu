@@ -0,0 +1,22 @@
+#include "unary_op_macros.cuh"
+#define _USE_MATH_DEFINES
+#include <math.h>
+
+struct ErfKernelOp {};
+struct ErfInvKernelOp {};
+
+UNARY_OP(float, erf_fwd_f32, erf_bwd_f32, ErfKernelOp,
+        erff(x),
+        M_2_SQRTPI * expf(-x * x))
+
+UNARY_OP(double, erf_fwd_f64, erf_bwd_f64, ErfKernelOp,
+        erf(x),
+        M_2_SQRTPI * exp(-x * x))
+
+UNARY_OP(float, erfinv_fwd_f32, erfinv_bwd_f32, ErfInvKernelOp,
+        erfinvf(x),
+        expf(erfinvf(x) * erfinvf(x)) / M_2_SQRTPI)
+
+UNARY_OP(double, erfinv_fwd_f64, erfinv_bwd_f64, ErfInvKernelOp,
+        erfinv(x),
+        exp(erfinv(x) * erfinv(x)) / M_2_SQRTPI)
@@ -0,0 +1,150 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use super::ops::{try_unary_op, UnaryKernel};
+use crate::{gradients::Tape, shapes::*, tensor::Tensor};
+
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct ErfKernelOp;
+
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct ErfInvKernelOp;
+
+/// [Error function](https://en.wikipedia.org/wiki/Error_function).
+///
+/// It's derivative is `2 / sqrt(pi) * exp(-t^2)`
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([-1.0, 0.0, 1.0, 2.0]);
+/// let r = t.erf();
+/// ```
+///
+/// The cdf of a standard normal distribution is `0.5 * (1 + erf(x / sqrt(2)))`:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let x = dev.tensor([-1.0, 0.0, 1.0]);
+/// let cdf = ((x / std::f32::consts::SQRT_2).erf() + 1.0) * 0.5;
+/// ```
+pub fn erf<S: Shape, E: Dtype, D: UnaryKernel<ErfKernelOp, E>, T: Tape<D>>(
+    t: Tensor<S, E, D, T>,
+) -> Tensor<S, E, D, T> {
+    t.erf()
+}
+
+/// Inverse of the [error function](https://en.wikipedia.org/wiki/Error_function#Inverse_functions),
+/// defined on `[-1, 1]`. Returns `-inf`/`inf` at `-1`/`1`, and `nan` outside of that range.
+///
+/// It's derivative is `sqrt(pi) / 2 * exp(erfinv(t)^2)`
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([-0.5, 0.0, 0.5, 0.9]);
+/// let r = t.erfinv();
+/// ```
+///
+/// Quantiles of a standard normal distribution are `sqrt(2) * erfinv(2p - 1)`:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let p = dev.tensor([0.025, 0.5, 0.975]);
+/// let q = ((p * 2.0 - 1.0).erfinv() * std::f32::consts::SQRT_2).array();
+/// assert!((q[2] - 1.959964).abs() < 1e-5);
+/// ```
+pub fn erfinv<S: Shape, E: Dtype, D: UnaryKernel<ErfInvKernelOp, E>, T: Tape<D>>(
+    t: Tensor<S, E, D, T>,
+) -> Tensor<S, E, D, T> {
+    t.erfinv()
+}
+
+impl<S: Shape, E: Dtype, D: UnaryKernel<ErfKernelOp, E>, T: Tape<D>> Tensor<S, E, D, T> {
+    /// See [erf]
+    pub fn erf(self) -> Self {
+        self.try_erf().unwrap()
+    }
+    /// See [erf]
+    pub fn try_erf(self) -> Result<Self, D::Err> {
+        try_unary_op(ErfKernelOp, self)
+    }
+}
+
+impl<S: Shape, E: Dtype, D: UnaryKernel<ErfInvKernelOp, E>, T: Tape<D>> Tensor<S, E, D, T> {
+    /// See [erfinv]
+    pub fn erfinv(self) -> Self {
+        self.try_erfinv().unwrap()
+    }
+    /// See [erfinv]
+    pub fn try_erfinv(self) -> Result<Self, D::Err> {
+        try_unary_op(ErfInvKernelOp, self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::*;
+    use crate::{tensor::*, tensor_ops::*};
+
+    #[test]
+    fn test_erf() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([-2.0, -0.5, 0.0, 1.0, 3.5]);
+        let r = x.trace().erf();
+        assert_close(
+            &r.array(),
+            &[-0.9953223, -0.5204999, 0.0, 0.8427008, 0.9999993],
+        );
+        let g = r.mean().backward();
+        assert_close(
+            &g.get(&x).array(),
+            &[
+                0.0041333973,
+                0.17575652,
+                0.22567584,
+                0.0830215,
+                1.0798854e-6,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_erfinv() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([-0.9, -0.5, 0.0, 0.25, 0.75]);
+        let r = x.trace().erfinv();
+        assert_close(
+            &r.array(),
+            &[-1.1630871, -0.47693628, 0.0, 0.22531206, 0.8134199],
+        );
+        let g = r.mean().backward();
+        assert_close(
+            &g.get(&x).array(),
+            &[0.68560857, 0.22251697, 0.1772454, 0.18647565, 0.34349996],
+        );
+    }
+
+    #[test]
+    fn test_erfinv_out_of_domain() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([-1.0, 1.0, 1.5]);
+        let r = x.erfinv().array();
+        assert_eq!(r[0], TestDtype::NEG_INFINITY);
+        assert_eq!(r[1], TestDtype::INFINITY);
+        assert!(r[2].is_nan());
+    }
+
+    #[test]
+    fn test_erf_erfinv_roundtrip() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([-0.99, -0.3, 0.1, 0.6, 0.95]);
+        assert_close(&x.clone().erfinv().erf().array(), &x.array());
+    }
+}
@@ -0,0 +1,13 @@
+use crate::tensor_ops::cpu_kernels::UnaryDerivative;
+use num_traits::Float;
+
+impl<F: Float> UnaryDerivative<F> for super::Expm1KernelOp {
+    #[inline(always)]
+    fn f(&self, x: &F) -> F {
+        x.exp_m1()
+    }
+    #[inline(always)]
+    fn df(&self, x: &F) -> F {
+        x.exp()
+    }
+}
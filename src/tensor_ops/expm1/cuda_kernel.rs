@@ -0,0 +1,20 @@
+use crate::tensor_ops::cuda_kernels::cuda_unary;
+
+unsafe impl cudarc::driver::AsKernelParam for super::Expm1KernelOp {}
+
+const PTX: &str = include_str!(concat!(env!("OUT_DIR"), "/expm1.ptx"));
+
+cuda_unary!(
+    super::Expm1KernelOp,
+    f32,
+    PTX,
+    "expm1_fwd_f32",
+    "expm1_bwd_f32"
+);
+cuda_unary!(
+    super::Expm1KernelOp,
+    f64,
+    PTX,
+    "expm1_fwd_f64",
+    "expm1_bwd_f64"
+);
@@ -0,0 +1,62 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use super::ops::{try_unary_op, UnaryKernel};
+use crate::{gradients::Tape, shapes::*, tensor::Tensor};
+
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct Expm1KernelOp;
+
+/// `e^t - 1`, computed without losing precision when `t` is close to 0.
+///
+/// It's derivative is `e^t`.
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([-1.0, 0.0, 1e-7, 2.0]);
+/// let r = t.expm1();
+/// ```
+pub fn expm1<S: Shape, E: Dtype, D: UnaryKernel<Expm1KernelOp, E>, T: Tape<D>>(
+    t: Tensor<S, E, D, T>,
+) -> Tensor<S, E, D, T> {
+    t.expm1()
+}
+
+impl<S: Shape, E: Dtype, D: UnaryKernel<Expm1KernelOp, E>, T: Tape<D>> Tensor<S, E, D, T> {
+    /// See [expm1]
+    pub fn expm1(self) -> Self {
+        self.try_expm1().unwrap()
+    }
+    /// See [expm1]
+    pub fn try_expm1(self) -> Result<Self, D::Err> {
+        try_unary_op(Expm1KernelOp, self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_expm1() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([-0.5, -1e-7, 0.0, 1e-7, 2.0]);
+        let r = x.trace().expm1();
+        let r_array = r.array();
+        assert_close(
+            &r_array,
+            &[-0.39346934, -9.9999995e-8, 0.0, 1.0000001e-7, 6.389056],
+        );
+        assert!((r_array[3] - 1.0000001e-7).abs() < 1e-13);
+        let g = r.mean().backward();
+        assert_close(
+            &g.get(&x).array(),
+            &[0.12130613, 0.19999998, 0.2, 0.20000002, 1.4778112],
+        );
+    }
+}
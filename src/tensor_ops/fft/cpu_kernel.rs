@@ -0,0 +1,280 @@
+use crate::shapes::{Dtype, Shape};
+use crate::tensor::{
+    cpu::{Cpu, LendingIterator, StridedArray},
+    AsVec,
+};
+
+use super::{hermitian_weight, FftKernel, FftOp};
+
+use num_traits::{Float, Signed};
+use std::{sync::Arc, vec::Vec};
+
+pub(crate) type Complex<E> = (E, E);
+
+#[cfg(any(feature = "nightly", not(feature = "std")))]
+pub(crate) fn mul<E: Float>(a: Complex<E>, b: Complex<E>) -> Complex<E> {
+    (a.0 * b.0 - a.1 * b.1, a.0 * b.1 + a.1 * b.0)
+}
+
+/// A planned, unnormalized discrete fourier transform of length `n`, computed
+/// with rustfft.
+#[cfg(feature = "std")]
+pub(crate) struct Dft<E> {
+    fft: Arc<dyn rustfft::Fft<E>>,
+}
+
+#[cfg(feature = "std")]
+impl<E: Dtype + Float + Signed> Dft<E> {
+    pub(crate) fn new(n: usize, inverse: bool) -> Self {
+        let direction = if inverse {
+            rustfft::FftDirection::Inverse
+        } else {
+            rustfft::FftDirection::Forward
+        };
+        Self {
+            fft: rustfft::FftPlanner::new().plan_fft(n, direction),
+        }
+    }
+
+    /// Transforms every chunk of `n` values of `x` in place.
+    pub(crate) fn process(&self, x: &mut [Complex<E>]) {
+        if x.is_empty() {
+            return;
+        }
+        let mut buf: Vec<rustfft::num_complex::Complex<E>> = x
+            .iter()
+            .map(|&(re, im)| rustfft::num_complex::Complex::new(re, im))
+            .collect();
+        self.fft.process(&mut buf);
+        for (x, v) in x.iter_mut().zip(buf) {
+            *x = (v.re, v.im);
+        }
+    }
+}
+
+/// Without std there is no rustfft, so transforms fall back to a radix 2 fft
+/// for powers of two and [Bluestein's algorithm](https://en.wikipedia.org/wiki/Chirp_Z-transform#Bluestein's_algorithm)
+/// for other lengths.
+#[cfg(not(feature = "std"))]
+pub(crate) struct Dft<E> {
+    n: usize,
+    inverse: bool,
+    marker: core::marker::PhantomData<E>,
+}
+
+#[cfg(not(feature = "std"))]
+impl<E: Dtype + Float + Signed> Dft<E> {
+    pub(crate) fn new(n: usize, inverse: bool) -> Self {
+        Self {
+            n,
+            inverse,
+            marker: core::marker::PhantomData,
+        }
+    }
+
+    /// Transforms every chunk of `n` values of `x` in place.
+    pub(crate) fn process(&self, x: &mut [Complex<E>]) {
+        if x.is_empty() {
+            return;
+        }
+        for chunk in x.chunks_exact_mut(self.n) {
+            let v = if self.n.is_power_of_two() {
+                no_std_fft::radix2(chunk, self.inverse)
+            } else {
+                no_std_fft::bluestein(chunk, self.inverse)
+            };
+            chunk.copy_from_slice(&v);
+        }
+    }
+}
+
+#[cfg(not(feature = "std"))]
+mod no_std_fft {
+    use super::{mul, Complex};
+    use num_traits::Float;
+    use std::vec::Vec;
+
+    /// `exp(-/+ 2 pi i k / n)`, computed in f64 so large transforms stay accurate. Quarter
+    /// turns are exact.
+    fn twiddle<E: Float>(k: usize, n: usize, inverse: bool) -> Complex<E> {
+        let k = k % n;
+        let sign = if inverse { 1.0 } else { -1.0 };
+        let quarter = 4 * k / n;
+        if quarter * n == 4 * k {
+            let (re, im) = [(1.0, 0.0), (0.0, sign), (-1.0, 0.0), (0.0, -sign)][quarter];
+            return (E::from(re).unwrap(), E::from(im).unwrap());
+        }
+        let theta = sign * 2.0 * std::f64::consts::PI * (k as f64) / (n as f64);
+        (E::from(theta.cos()).unwrap(), E::from(theta.sin()).unwrap())
+    }
+
+    /// Radix 2 fft, `x.len()` must be a power of two.
+    pub(super) fn radix2<E: Float>(x: &[Complex<E>], inverse: bool) -> Vec<Complex<E>> {
+        let n = x.len();
+        if n <= 1 {
+            return x.to_vec();
+        }
+        let even: Vec<_> = x.iter().step_by(2).copied().collect();
+        let odd: Vec<_> = x.iter().skip(1).step_by(2).copied().collect();
+        let even = radix2(&even, inverse);
+        let odd = radix2(&odd, inverse);
+        let half = n / 2;
+        let mut out = alloc::vec![(E::zero(), E::zero()); n];
+        for k in 0..half {
+            let (e, o) = (even[k], mul(twiddle(k, n, inverse), odd[k]));
+            out[k] = (e.0 + o.0, e.1 + o.1);
+            out[k + half] = (e.0 - o.0, e.1 - o.1);
+        }
+        out
+    }
+
+    /// Writes a transform of any length `n` as a convolution with the chirp
+    /// `exp(-/+ pi i t^2 / n)`. The convolution is computed with radix 2 ffts of a power
+    /// of two length `>= 2n - 1`.
+    pub(super) fn bluestein<E: Float>(x: &[Complex<E>], inverse: bool) -> Vec<Complex<E>> {
+        let n = x.len();
+        let m = (2 * n - 1).next_power_of_two();
+        // t^2 mod 2n keeps the angles small, so the chirp stays accurate for large n
+        let chirp: Vec<Complex<E>> = (0..n)
+            .map(|t| twiddle((t * t) % (2 * n), 2 * n, inverse))
+            .collect();
+        let conj = |(re, im): Complex<E>| (re, -im);
+
+        let mut a = alloc::vec![(E::zero(), E::zero()); m];
+        for ((a_t, &x_t), &c_t) in a.iter_mut().zip(x.iter()).zip(chirp.iter()) {
+            *a_t = mul(x_t, c_t);
+        }
+        let mut b = alloc::vec![(E::zero(), E::zero()); m];
+        b[0] = conj(chirp[0]);
+        for t in 1..n {
+            b[t] = conj(chirp[t]);
+            b[m - t] = conj(chirp[t]);
+        }
+
+        let a = radix2(&a, false);
+        let b = radix2(&b, false);
+        let ab: Vec<_> = a.into_iter().zip(b).map(|(a, b)| mul(a, b)).collect();
+        let conv = radix2(&ab, true);
+
+        let scale = E::one() / E::from(m).unwrap();
+        conv.into_iter()
+            .zip(chirp)
+            .map(|(v, c)| {
+                let v = mul(v, c);
+                (v.0 * scale, v.1 * scale)
+            })
+            .collect()
+    }
+}
+
+/// Unnormalized 2d transform of a row major `(h, w)` grid, in place.
+#[cfg(feature = "nightly")]
+pub(crate) fn dft2<E: Dtype + Float + Signed>(
+    x: &mut [Complex<E>],
+    h: usize,
+    w: usize,
+    inverse: bool,
+) {
+    Dft::new(w, inverse).process(x);
+    let mut cols: Vec<Complex<E>> = (0..w)
+        .flat_map(|j| (0..h).map(move |i| (i, j)))
+        .map(|(i, j)| x[i * w + j])
+        .collect();
+    Dft::new(h, inverse).process(&mut cols);
+    for (c, col) in cols.chunks_exact(h).enumerate() {
+        for (i, &v) in col.iter().enumerate() {
+            x[i * w + c] = v;
+        }
+    }
+}
+
+/// Applies `op` to every row of `inp`, which is in logical order, returning the output
+/// in logical order.
+fn transform<E: Dtype + Float + Signed>(op: &FftOp, inp: &[E]) -> Vec<E> {
+    let n = op.n;
+    let row_in = op.in_axis_len * if op.in_complex { 2 } else { 1 };
+    let row_out = op.out_len * if op.out_complex { 2 } else { 1 };
+    let num_rows = inp.len().checked_div(row_in).unwrap_or(0);
+
+    let mut z = alloc::vec![(E::zero(), E::zero()); num_rows * n];
+    for (row, z_row) in inp.chunks_exact(row_in).zip(z.chunks_exact_mut(n)) {
+        for (t, z_t) in z_row.iter_mut().enumerate().take(op.in_len) {
+            let w = if op.hermitian_in {
+                E::from(hermitian_weight(t, n)).unwrap()
+            } else {
+                E::one()
+            };
+            *z_t = if op.in_complex {
+                (row[2 * t] * w, row[2 * t + 1] * w)
+            } else {
+                (row[t] * w, E::zero())
+            };
+        }
+    }
+
+    Dft::new(n, op.inverse).process(&mut z);
+
+    let scale = if op.normalize {
+        E::one() / E::from(n).unwrap()
+    } else {
+        E::one()
+    };
+    let mut out = alloc::vec![E::zero(); num_rows * row_out];
+    for (r, out_row) in out.chunks_exact_mut(row_out).enumerate() {
+        for k in 0..op.out_len {
+            let v = if k < n {
+                z[r * n + k]
+            } else {
+                (E::zero(), E::zero())
+            };
+            let w = if op.hermitian_out {
+                scale * E::from(hermitian_weight(k, n)).unwrap()
+            } else {
+                scale
+            };
+            if op.out_complex {
+                out_row[2 * k] = v.0 * w;
+                out_row[2 * k + 1] = v.1 * w;
+            } else {
+                out_row[k] = v.0 * w;
+            }
+        }
+    }
+    out
+}
+
+impl<E: Dtype + Float + Signed> FftKernel<E> for Cpu {
+    fn forward<S: Shape, Dst: Shape>(
+        &self,
+        op: FftOp,
+        inp: &Self::Storage<S, E>,
+        dst: Dst,
+    ) -> Result<Self::Storage<Dst, E>, Self::Err> {
+        let mut out: Self::Storage<Dst, E> = StridedArray::new(dst)?;
+        if dst.num_elements() == 0 {
+            return Ok(out);
+        }
+        // the output is contiguous, so its buffer is in logical order
+        let values = transform(&op, &inp.as_vec());
+        Arc::make_mut(&mut out.data).copy_from_slice(&values);
+        Ok(out)
+    }
+
+    fn backward<S: Shape, Dst: Shape>(
+        &self,
+        op: FftOp,
+        grad_inp: &mut Self::Storage<S, E>,
+        grad_out: &Self::Storage<Dst, E>,
+    ) -> Result<(), Self::Err> {
+        if grad_inp.shape.num_elements() == 0 {
+            return Ok(());
+        }
+        let values = transform(&op, &grad_out.as_vec());
+        let mut values = values.iter();
+        let mut grad_inp_iter = grad_inp.iter_mut();
+        while let Some(g) = grad_inp_iter.next() {
+            *g += *values.next().unwrap();
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,169 @@
+use crate::{
+    shapes::*,
+    tensor::cuda::{
+        cufft::{result, sys, CufftPlan},
+        Cuda, CudaArray, CudaError,
+    },
+};
+
+use core::ffi::{c_int, c_void};
+use cudarc::driver::{
+    AsKernelParam, CudaSlice, DevicePtrMut, LaunchAsync, LaunchConfig, ValidAsZeroBits,
+};
+use std::{collections::btree_map::Entry, sync::Arc};
+
+const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/fft.ptx"));
+
+unsafe impl AsKernelParam for super::FftOp {}
+
+trait HasCudaKernel<E> {
+    const MOD: &'static str;
+    const FNS: &'static [&'static str];
+    /// The cuFFT complex to complex transform for this dtype
+    const KIND: c_int;
+    const EXEC: unsafe extern "C" fn(sys::cufftHandle, *mut c_void, *mut c_void, c_int) -> c_int;
+}
+
+impl HasCudaKernel<f32> for Cuda {
+    const MOD: &'static str = "fft_f32";
+    const FNS: &'static [&'static str] = &["fft_load_f32", "fft_store_f32", "fft_accum_f32"];
+    const KIND: c_int = sys::CUFFT_C2C;
+    const EXEC: unsafe extern "C" fn(sys::cufftHandle, *mut c_void, *mut c_void, c_int) -> c_int =
+        sys::cufftExecC2C;
+}
+
+impl HasCudaKernel<f64> for Cuda {
+    const MOD: &'static str = "fft_f64";
+    const FNS: &'static [&'static str] = &["fft_load_f64", "fft_store_f64", "fft_accum_f64"];
+    const KIND: c_int = sys::CUFFT_Z2Z;
+    const EXEC: unsafe extern "C" fn(sys::cufftHandle, *mut c_void, *mut c_void, c_int) -> c_int =
+        sys::cufftExecZ2Z;
+}
+
+impl Cuda {
+    /// Loads every row of `inp` into a buffer of `op.n` interleaved complex values per row,
+    /// weighted and zero padded as `op` describes, and transforms the rows in place
+    /// with cuFFT.
+    fn fft_rows<S: Shape, E: Dtype>(
+        &self,
+        op: super::FftOp,
+        inp: &CudaArray<S, E>,
+    ) -> Result<CudaSlice<E>, CudaError>
+    where
+        Self: HasCudaKernel<E>,
+    {
+        if !self.dev.has_func(Self::MOD, Self::FNS[0]) {
+            self.dev.load_ptx(PTX_SRC.into(), Self::MOD, Self::FNS)?;
+        }
+
+        let row_in = op.in_axis_len * if op.in_complex { 2 } else { 1 };
+        let num_rows = inp.shape.num_elements().checked_div(row_in).unwrap_or(0);
+        let numel = num_rows * op.n;
+        let mut z = unsafe { self.dev.alloc_async::<E>(2 * numel) }?;
+        if numel == 0 {
+            return Ok(z);
+        }
+
+        let dims = self.dev.take_async(inp.shape.concrete().into())?;
+        let strides = self.dev.take_async(inp.strides.into())?;
+        let load_fn = self.dev.get_func(Self::MOD, Self::FNS[0]).unwrap();
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            op,                // const FftOp op,
+            numel,             // const size_t numel,
+            S::NUM_DIMS,       // const size_t num_dims,
+            &dims,             // const size_t *dims,
+            &strides,          // const size_t *strides,
+            inp.data.as_ref(), // const T *inp,
+            &mut z,            // T *z
+        );
+        unsafe { load_fn.launch_async(cfg, params) }?;
+
+        let direction = if op.inverse {
+            sys::CUFFT_INVERSE
+        } else {
+            sys::CUFFT_FORWARD
+        };
+        // The lock is held until the stream has joined the device's stream, so two
+        // transforms never share a plan's workspace at the same time.
+        let mut plans = self.fft_plans.lock().unwrap();
+        let plan = match plans.entry((op.n, num_rows, Self::KIND)) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(CufftPlan::new(
+                self.dev.clone(),
+                op.n,
+                Self::KIND,
+                num_rows,
+            )?),
+        };
+        let stream = self.dev.auto_joining_stream()?;
+        unsafe {
+            result(sys::cufftSetStream(plan.handle, stream.stream))?;
+            let ptr = *z.device_ptr_mut() as *mut c_void;
+            result(Self::EXEC(plan.handle, ptr, ptr, direction))?;
+        }
+        drop(stream);
+        drop(plans);
+        Ok(z)
+    }
+}
+
+impl<E: Dtype + ValidAsZeroBits> super::FftKernel<E> for Cuda
+where
+    Self: HasCudaKernel<E>,
+{
+    fn forward<S: Shape, Dst: Shape>(
+        &self,
+        op: super::FftOp,
+        inp: &Self::Storage<S, E>,
+        dst: Dst,
+    ) -> Result<Self::Storage<Dst, E>, Self::Err> {
+        let numel = dst.num_elements();
+        let mut storage = self.dev.alloc_zeros_async::<E>(numel)?;
+        if numel > 0 {
+            let z = self.fft_rows(op, inp)?;
+            let store_fn = self.dev.get_func(Self::MOD, Self::FNS[1]).unwrap();
+            let cfg = LaunchConfig::for_num_elems(numel as u32);
+            let params = (
+                op,           // const FftOp op,
+                numel,        // const size_t numel,
+                &z,           // const T *z,
+                &mut storage, // T *out
+            );
+            unsafe { store_fn.launch_async(cfg, params) }?;
+        }
+        Ok(CudaArray {
+            data: Arc::new(storage),
+            shape: dst,
+            strides: dst.strides(),
+        })
+    }
+
+    fn backward<S: Shape, Dst: Shape>(
+        &self,
+        op: super::FftOp,
+        grad_inp: &mut Self::Storage<S, E>,
+        grad_out: &Self::Storage<Dst, E>,
+    ) -> Result<(), Self::Err> {
+        let numel = grad_inp.shape.num_elements();
+        if numel == 0 {
+            return Ok(());
+        }
+        let z = self.fft_rows(op, grad_out)?;
+        let dims = self.dev.take_async(grad_inp.shape.concrete().into())?;
+        let strides = self.dev.take_async(grad_inp.strides.into())?;
+        let accum_fn = self.dev.get_func(Self::MOD, Self::FNS[2]).unwrap();
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            op,                                // const FftOp op,
+            numel,                             // const size_t numel,
+            S::NUM_DIMS,                       // const size_t num_dims,
+            &dims,                             // const size_t *dims,
+            &strides,                          // const size_t *strides,
+            &z,                                // const T *z,
+            Arc::make_mut(&mut grad_inp.data), // T *grad_inp
+        );
+        unsafe { accum_fn.launch_async(cfg, params) }?;
+        Ok(())
+    }
+}
@@ -0,0 +1,147 @@
+#include "cuda_utils.cuh"
+
+struct FftOp {
+    size_t n;
+    size_t in_axis_len;
+    size_t in_len;
+    size_t out_len;
+    bool in_complex;
+    bool out_complex;
+    bool inverse;
+    bool normalize;
+    bool hermitian_in;
+    bool hermitian_out;
+};
+
+// How many times bin k of a one sided spectrum appears in the full spectrum of a
+// real signal of length n.
+__device__ size_t hermitian_weight(size_t k, size_t n) {
+    if (k == 0 || 2 * k == n) {
+        return 1;
+    } else if (2 * k < n) {
+        return 2;
+    } else {
+        return 0;
+    }
+}
+
+// Loads n complex values for every row of the input into z, weighting them and
+// padding each row with zeros after the first in_len values.
+template<typename T>
+__device__ void fft_load(
+    const FftOp op,
+    const size_t numel,
+    const size_t num_dims,
+    const size_t *dims,
+    const size_t *strides,
+    const T *inp,
+    T *z
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    size_t row = i / op.n;
+    size_t t = i % op.n;
+    T re = 0.0;
+    T im = 0.0;
+    if (t < op.in_len) {
+        T w = op.hermitian_in ? (T)hermitian_weight(t, op.n) : 1.0;
+        size_t idx = row * op.in_axis_len + t;
+        if (op.in_complex) {
+            re = inp[get_strided_index(2 * idx, num_dims, dims, strides)] * w;
+            im = inp[get_strided_index(2 * idx + 1, num_dims, dims, strides)] * w;
+        } else {
+            re = inp[get_strided_index(idx, num_dims, dims, strides)] * w;
+        }
+    }
+    z[2 * i] = re;
+    z[2 * i + 1] = im;
+}
+
+// The i'th output value (in logical order), taken from the transformed rows in z.
+template<typename T>
+__device__ T fft_value(const FftOp op, const size_t i, const T *z) {
+    size_t row_out = op.out_complex ? 2 * op.out_len : op.out_len;
+    size_t row = i / row_out;
+    size_t j = i % row_out;
+    size_t k = op.out_complex ? j / 2 : j;
+    size_t part = op.out_complex ? j % 2 : 0;
+    if (k >= op.n) {
+        return 0.0;
+    }
+    T w = op.normalize ? 1.0 / (T)op.n : 1.0;
+    if (op.hermitian_out) {
+        w *= (T)hermitian_weight(k, op.n);
+    }
+    return z[2 * (row * op.n + k) + part] * w;
+}
+
+template<typename T>
+__device__ void fft_store(
+    const FftOp op,
+    const size_t numel,
+    const T *z,
+    T *out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    out[i] = fft_value(op, i, z);
+}
+
+template<typename T>
+__device__ void fft_accum(
+    const FftOp op,
+    const size_t numel,
+    const size_t num_dims,
+    const size_t *dims,
+    const size_t *strides,
+    const T *z,
+    T *grad_inp
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    atomicAdd(grad_inp + get_strided_index(i, num_dims, dims, strides), fft_value(op, i, z));
+}
+
+#define FFT_OP(TYPENAME, LOAD, STORE, ACCUM) \
+extern "C" __global__ void LOAD( \
+    const FftOp op, \
+    const size_t numel, \
+    const size_t num_dims, \
+    const size_t *dims, \
+    const size_t *strides, \
+    const TYPENAME *inp, \
+    TYPENAME *z \
+) { \
+    fft_load(op, numel, num_dims, dims, strides, inp, z); \
+} \
+extern "C" __global__ void STORE( \
+    const FftOp op, \
+    const size_t numel, \
+    const TYPENAME *z, \
+    TYPENAME *out \
+) { \
+    fft_store(op, numel, z, out); \
+} \
+extern "C" __global__ void ACCUM( \
+    const FftOp op, \
+    const size_t numel, \
+    const size_t num_dims, \
+    const size_t *dims, \
+    const size_t *strides, \
+    const TYPENAME *z, \
+    TYPENAME *grad_inp \
+) { \
+    fft_accum(op, numel, num_dims, dims, strides, z, grad_inp); \
+}
+
+FFT_OP(float, fft_load_f32, fft_store_f32, fft_accum_f32);
+FFT_OP(double, fft_load_f64, fft_store_f64, fft_accum_f64);
@@ -0,0 +1,507 @@
+pub(super) mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{gradients::Tape, shapes::*, tensor::*};
+
+/// A discrete fourier transform along the last axis (or the second to last axis for
+/// complex values, whose last axis holds the real & imaginary parts).
+///
+/// Computes `out[k] = out_weight(k) * sum_t in_weight(t) * inp[t] * exp(-/+ 2 pi i k t / n)`
+/// for `t < in_len` and `k < out_len`, taking the real part if the output is real.
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct FftOp {
+    /// The size of the transform
+    pub n: usize,
+    /// The size of the input's transformed axis
+    pub in_axis_len: usize,
+    /// The number of input elements along the axis that are used, at most `n`
+    pub in_len: usize,
+    /// The size of the output's transformed axis
+    pub out_len: usize,
+    pub in_complex: bool,
+    pub out_complex: bool,
+    /// Use `exp(+2 pi i k t / n)` instead of `exp(-2 pi i k t / n)`
+    pub inverse: bool,
+    /// Divide by `n`
+    pub normalize: bool,
+    /// Weight the inputs by how often they appear in a hermitian spectrum, see [hermitian_weight]
+    pub hermitian_in: bool,
+    /// Weight the outputs by how often they appear in a hermitian spectrum, see [hermitian_weight]
+    pub hermitian_out: bool,
+}
+
+/// How many times bin `k` of a one sided spectrum appears in the full spectrum of a
+/// real signal of length `n`. Bins past `n / 2` don't appear at all.
+pub(crate) fn hermitian_weight(k: usize, n: usize) -> usize {
+    if k == 0 || 2 * k == n {
+        1
+    } else if 2 * k < n {
+        2
+    } else {
+        0
+    }
+}
+
+/// Transforms take `O(n log(n))` for any length `n`. The cpu uses rustfft, and cuda
+/// uses cuFFT.
+pub trait FftKernel<E: Dtype>: DeviceStorage {
+    fn forward<S: Shape, Dst: Shape>(
+        &self,
+        op: FftOp,
+        inp: &Self::Storage<S, E>,
+        dst: Dst,
+    ) -> Result<Self::Storage<Dst, E>, Self::Err>;
+
+    /// Adds the transform `op` of `grad_out` into `grad_inp`. `op` is the adjoint of the
+    /// forward transform.
+    fn backward<S: Shape, Dst: Shape>(
+        &self,
+        op: FftOp,
+        grad_inp: &mut Self::Storage<S, E>,
+        grad_out: &Self::Storage<Dst, E>,
+    ) -> Result<(), Self::Err>;
+}
+
+/// Real shapes whose last axis can be replaced with a one sided complex spectrum,
+/// i.e. `(.., N)` to `(.., M, 2)`
+pub trait RealFftShape: Shape {
+    type Complex: Shape;
+    fn complex(&self, m: usize) -> Self::Complex;
+}
+
+/// Complex shapes `(.., M, 2)` whose last two axes can be replaced with a real signal `(.., N)`
+pub trait ComplexFftShape: Shape {
+    type Real: Shape;
+    fn real(&self, n: usize) -> Self::Real;
+}
+
+macro_rules! fft_shapes {
+    (($($D:tt),*), $Last:tt) => {
+        impl<$($D: Dim, )* $Last: Dim> RealFftShape for ($($D, )* $Last,) {
+            type Complex = ($($D, )* usize, Const<2>);
+            #[allow(non_snake_case)]
+            fn complex(&self, m: usize) -> Self::Complex {
+                let ($($D, )* _,) = *self;
+                ($($D, )* m, Const)
+            }
+        }
+        impl<$($D: Dim, )* $Last: Dim> ComplexFftShape for ($($D, )* $Last, Const<2>) {
+            type Real = ($($D, )* usize,);
+            #[allow(non_snake_case)]
+            fn real(&self, n: usize) -> Self::Real {
+                let ($($D, )* _, _) = *self;
+                ($($D, )* n,)
+            }
+        }
+    };
+}
+
+fft_shapes!((), D0);
+fft_shapes!((D0), D1);
+fft_shapes!((D0, D1), D2);
+fft_shapes!((D0, D1, D2), D3);
+
+/// The fourier transform of complex values along the second to last axis. The last
+/// axis must have size 2, and holds the real & imaginary parts.
+///
+/// **Pytorch equivalent**: `torch.view_as_real(torch.fft.fft(torch.view_as_complex(t), dim=-1))`
+///
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([[1.0, 0.0], [2.0, 0.0], [3.0, 0.0], [4.0, 0.0]]);
+/// let r = fft(t);
+/// assert_eq!(r.array(), [[10.0, 0.0], [-2.0, 2.0], [-2.0, 0.0], [-2.0, -2.0]]);
+/// ```
+pub fn fft<S: Shape, E: Dtype, D: FftKernel<E>, T: Tape<D>>(
+    t: Tensor<S, E, D, T>,
+) -> Tensor<S, E, D, T> {
+    t.fft()
+}
+
+/// The inverse of [fft()], including the division by the size of the axis.
+///
+/// **Pytorch equivalent**: `torch.view_as_real(torch.fft.ifft(torch.view_as_complex(t), dim=-1))`
+pub fn ifft<S: Shape, E: Dtype, D: FftKernel<E>, T: Tape<D>>(
+    t: Tensor<S, E, D, T>,
+) -> Tensor<S, E, D, T> {
+    t.ifft()
+}
+
+/// The fourier transform of real values along the last axis. Only the `N / 2 + 1`
+/// non negative frequencies are returned, as a new [usize] axis followed by an axis
+/// of size 2 for the real & imaginary parts.
+///
+/// **Pytorch equivalent**: `torch.view_as_real(torch.fft.rfft(t, dim=-1))`
+///
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([1.0, 2.0, 3.0, 4.0]);
+/// let r: Tensor<(usize, Const<2>), f32, _> = rfft(t);
+/// assert_eq!(r.as_vec(), [10.0, 0.0, -2.0, 2.0, -2.0, 0.0]);
+/// ```
+pub fn rfft<S: RealFftShape, E: Dtype, D: FftKernel<E>, T: Tape<D>>(
+    t: Tensor<S, E, D, T>,
+) -> Tensor<S::Complex, E, D, T> {
+    t.rfft()
+}
+
+/// The inverse of [rfft()], producing a real signal of length `n`. The input is the
+/// one sided spectrum, which should have `n / 2 + 1` frequencies. Missing frequencies
+/// are treated as zeros and extra ones are ignored, as are the imaginary parts of the
+/// zero (and for even `n` the `n / 2`) frequency.
+///
+/// **Pytorch equivalent**: `torch.fft.irfft(torch.view_as_complex(t), n=n, dim=-1)`
+///
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([[10.0, 0.0], [-2.0, 2.0], [-2.0, 0.0]]);
+/// let r: Tensor<(usize,), f32, _> = irfft(t, 4);
+/// assert_eq!(r.as_vec(), [1.0, 2.0, 3.0, 4.0]);
+/// ```
+pub fn irfft<S: ComplexFftShape, E: Dtype, D: FftKernel<E>, T: Tape<D>>(
+    t: Tensor<S, E, D, T>,
+    n: usize,
+) -> Tensor<S::Real, E, D, T> {
+    t.irfft(n)
+}
+
+impl<S: Shape, E: Dtype, D: FftKernel<E>, T: Tape<D>> Tensor<S, E, D, T> {
+    /// See [fft()]
+    pub fn fft(self) -> Self {
+        self.try_fft().unwrap()
+    }
+
+    /// See [fft()]
+    pub fn try_fft(self) -> Result<Self, D::Err> {
+        let (fwd, bwd) = self.complex_ops(false);
+        let dst = *self.shape();
+        try_fft_op(self, fwd, bwd, dst)
+    }
+
+    /// See [ifft()]
+    pub fn ifft(self) -> Self {
+        self.try_ifft().unwrap()
+    }
+
+    /// See [ifft()]
+    pub fn try_ifft(self) -> Result<Self, D::Err> {
+        let (fwd, bwd) = self.complex_ops(true);
+        let dst = *self.shape();
+        try_fft_op(self, fwd, bwd, dst)
+    }
+
+    /// See [rfft()]
+    pub fn rfft(self) -> Tensor<S::Complex, E, D, T>
+    where
+        S: RealFftShape,
+    {
+        self.try_rfft().unwrap()
+    }
+
+    /// See [rfft()]
+    pub fn try_rfft(self) -> Result<Tensor<S::Complex, E, D, T>, D::Err>
+    where
+        S: RealFftShape,
+    {
+        let n = self.shape().concrete()[S::NUM_DIMS - 1];
+        assert!(n > 0, "fft of an empty axis");
+        let m = n / 2 + 1;
+        let fwd = FftOp {
+            n,
+            in_axis_len: n,
+            in_len: n,
+            out_len: m,
+            in_complex: false,
+            out_complex: true,
+            inverse: false,
+            normalize: false,
+            hermitian_in: false,
+            hermitian_out: false,
+        };
+        let bwd = FftOp {
+            n,
+            in_axis_len: m,
+            in_len: m,
+            out_len: n,
+            in_complex: true,
+            out_complex: false,
+            inverse: true,
+            normalize: false,
+            hermitian_in: false,
+            hermitian_out: false,
+        };
+        let dst = self.shape().complex(m);
+        try_fft_op(self, fwd, bwd, dst)
+    }
+
+    /// See [irfft()]
+    pub fn irfft(self, n: usize) -> Tensor<S::Real, E, D, T>
+    where
+        S: ComplexFftShape,
+    {
+        self.try_irfft(n).unwrap()
+    }
+
+    /// See [irfft()]
+    pub fn try_irfft(self, n: usize) -> Result<Tensor<S::Real, E, D, T>, D::Err>
+    where
+        S: ComplexFftShape,
+    {
+        assert!(n > 0, "fft of an empty axis");
+        let m = self.shape().concrete()[S::NUM_DIMS - 2];
+        let fwd = FftOp {
+            n,
+            in_axis_len: m,
+            in_len: m.min(n / 2 + 1),
+            out_len: n,
+            in_complex: true,
+            out_complex: false,
+            inverse: true,
+            normalize: true,
+            hermitian_in: true,
+            hermitian_out: false,
+        };
+        let bwd = FftOp {
+            n,
+            in_axis_len: n,
+            in_len: n,
+            out_len: m,
+            in_complex: false,
+            out_complex: true,
+            inverse: false,
+            normalize: true,
+            hermitian_in: false,
+            hermitian_out: true,
+        };
+        let dst = self.shape().real(n);
+        try_fft_op(self, fwd, bwd, dst)
+    }
+
+    /// The forward & backward ops of a complex to complex transform
+    fn complex_ops(&self, inverse: bool) -> (FftOp, FftOp) {
+        assert!(S::NUM_DIMS >= 2, "complex values need at least 2 dims");
+        let dims = self.shape().concrete();
+        assert_eq!(
+            dims[S::NUM_DIMS - 1],
+            2,
+            "the last axis must hold real & imaginary parts"
+        );
+        let n = dims[S::NUM_DIMS - 2];
+        assert!(n > 0, "fft of an empty axis");
+        let fwd = FftOp {
+            n,
+            in_axis_len: n,
+            in_len: n,
+            out_len: n,
+            in_complex: true,
+            out_complex: true,
+            inverse,
+            normalize: inverse,
+            hermitian_in: false,
+            hermitian_out: false,
+        };
+        // the adjoint of the transform is the transform in the opposite direction
+        let bwd = FftOp {
+            inverse: !inverse,
+            ..fwd
+        };
+        (fwd, bwd)
+    }
+}
+
+fn try_fft_op<S: Shape, Dst: Shape, E: Dtype, D: FftKernel<E>, T: Tape<D>>(
+    t: Tensor<S, E, D, T>,
+    fwd: FftOp,
+    bwd: FftOp,
+    dst: Dst,
+) -> Result<Tensor<Dst, E, D, T>, D::Err> {
+    let (inp, mut tape) = t.split_tape();
+    let out = inp
+        .device
+        .upgrade(inp.device.forward(fwd, &inp.storage, dst)?);
+    let phantom_out = out.clone();
+    tape.try_alloc_grad(&inp)?;
+    tape.try_alloc_grad(&out)?;
+    tape.add_backward_op(move |grads| {
+        let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+        inp.device.backward(bwd, grad_inp, grad_out)
+    });
+    Ok(out.put_tape(tape))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{gradients::OwnedTape, tensor_ops::*, tests::*};
+
+    type Traced<S> = Tensor<S, TestDtype, TestDevice, OwnedTape<TestDevice>>;
+
+    /// The transforms are linear, so the gradient of each input element is the loss
+    /// of a one hot input.
+    fn assert_grads<S: Shape, Dst: Shape>(
+        dev: &TestDevice,
+        x: Tensor<S, TestDtype, TestDevice>,
+        f: impl Fn(Traced<S>) -> Traced<Dst>,
+    ) {
+        let y = f(x.trace());
+        let w: Tensor<Dst, TestDtype, _> = dev.sample_normal_like(y.shape());
+        let g = (y * w.clone()).sum::<Rank0, _>().backward();
+
+        let n = x.shape().num_elements();
+        let mut expected = std::vec::Vec::with_capacity(n);
+        for i in 0..n {
+            let mut e = std::vec![0.0; n];
+            e[i] = 1.0;
+            let e = dev.tensor_from_vec(e, *x.shape());
+            expected.push((f(e.trace()) * w.clone()).sum::<Rank0, _>().array());
+        }
+        assert_close_with_tolerance(&g.get(&x).as_vec(), &expected, 1e-4);
+    }
+
+    #[test]
+    fn test_fft_known_values() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> =
+            dev.tensor([[1.0, 0.0], [2.0, 0.0], [3.0, 0.0], [4.0, 0.0]]);
+        let r = t.trace().fft();
+        assert_close(
+            &r.array(),
+            &[[10.0, 0.0], [-2.0, 2.0], [-2.0, 0.0], [-2.0, -2.0]],
+        );
+        // only the real parts of the output are summed
+        let g = r.select(dev.tensor([0, 0, 0, 0])).sum().backward();
+        assert_close(
+            &g.get(&t).array(),
+            &[[4.0, 0.0], [0.0, 0.0], [0.0, 0.0], [0.0, 0.0]],
+        );
+    }
+
+    #[test]
+    fn test_fft_odd_length() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([[1.0, 1.0], [0.0, 0.0], [0.0, -1.0]]);
+        let r = t.fft();
+        // 1 + i + (-i) * exp(-4 pi i k / 3)
+        assert_close_with_tolerance(
+            &r.array(),
+            &[[1.0, 0.0], [1.8660254, 1.5], [0.1339746, 1.5]],
+            1e-5,
+        );
+    }
+
+    #[test]
+    fn test_fft_matches_direct_dft() {
+        let dev: TestDevice = Default::default();
+        // powers of two, even & odd lengths and a prime
+        for n in [1, 8, 12, 15, 17] {
+            let x: std::vec::Vec<f64> = (0..2 * n).map(|i| ((i * 7) % 11) as f64 - 5.0).collect();
+            let mut expected = std::vec![0.0; 2 * n];
+            for k in 0..n {
+                for t in 0..n {
+                    let theta = -2.0 * core::f64::consts::PI * ((k * t) % n) as f64 / n as f64;
+                    let (re, im) = (x[2 * t], x[2 * t + 1]);
+                    expected[2 * k] += re * theta.cos() - im * theta.sin();
+                    expected[2 * k + 1] += re * theta.sin() + im * theta.cos();
+                }
+            }
+            let t =
+                dev.tensor_from_vec(x.iter().map(|&v| v as TestDtype).collect(), (n, Const::<2>));
+            let expected: std::vec::Vec<TestDtype> =
+                expected.iter().map(|&v| v as TestDtype).collect();
+            assert_close_with_tolerance(&t.fft().as_vec(), &expected, 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_ifft_inverts_fft() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank3<3, 6, 2>, TestDtype, _> = dev.sample_normal();
+        let r = t.clone().fft().ifft();
+        assert_close_with_tolerance(&r.array(), &t.array(), 1e-5);
+
+        let t: Tensor<Rank2<5, 2>, TestDtype, _> = dev.sample_normal();
+        let r = t.clone().ifft().fft();
+        assert_close_with_tolerance(&r.array(), &t.array(), 1e-5);
+    }
+
+    #[test]
+    fn test_fft_grads() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank3<2, 8, 2>, TestDtype, _> = dev.sample_normal();
+        assert_grads(&dev, x.clone(), |t| t.fft());
+        assert_grads(&dev, x, |t| t.ifft());
+        let x: Tensor<Rank2<6, 2>, TestDtype, _> = dev.sample_normal();
+        assert_grads(&dev, x.clone(), |t| t.fft());
+        assert_grads(&dev, x, |t| t.ifft());
+    }
+
+    #[test]
+    fn test_fft_of_permuted() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank3<3, 2, 5>, TestDtype, _> = dev.sample_normal();
+        let r = t.clone().permute::<_, Axes3<2, 0, 1>>().fft();
+        let r2 = dev.tensor(t.permute::<_, Axes3<2, 0, 1>>().array()).fft();
+        assert_close(&r.array(), &r2.array());
+    }
+
+    #[test]
+    fn test_rfft_matches_fft() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank2<3, 6>, TestDtype, _> = dev.sample_normal();
+        let r = t.clone().rfft();
+        assert_eq!(r.shape(), &(Const, 4, Const));
+
+        let mut complex = std::vec::Vec::new();
+        for x in t.as_vec() {
+            complex.push(x);
+            complex.push(0.0);
+        }
+        let full = dev
+            .tensor_from_vec(complex, (Const::<3>, Const::<6>, Const::<2>))
+            .fft();
+        let expected = full.slice((.., ..4, ..));
+        assert_close_with_tolerance(&r.as_vec(), &expected.as_vec(), 1e-5);
+    }
+
+    #[test]
+    fn test_irfft_inverts_rfft() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank2<2, 8>, TestDtype, _> = dev.sample_normal();
+        let r = t.clone().rfft().irfft(8);
+        assert_eq!(r.shape(), &(Const, 8));
+        assert_close_with_tolerance(&r.as_vec(), &t.as_vec(), 1e-5);
+
+        let t: Tensor<Rank1<7>, TestDtype, _> = dev.sample_normal();
+        let r = t.clone().rfft().irfft(7);
+        assert_close_with_tolerance(&r.as_vec(), &t.as_vec(), 1e-5);
+    }
+
+    #[test]
+    fn test_irfft_ignores_hermitian_imaginary_parts() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([[4.0, 3.0], [0.0, 0.0], [4.0, -1.0]]);
+        let r = t.irfft(4);
+        assert_close(&r.as_vec(), &std::vec![2.0, 0.0, 2.0, 0.0]);
+    }
+
+    #[test]
+    fn test_rfft_irfft_grads() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank2<2, 8>, TestDtype, _> = dev.sample_normal();
+        assert_grads(&dev, x, |t| t.rfft());
+        let x: Tensor<Rank1<5>, TestDtype, _> = dev.sample_normal();
+        assert_grads(&dev, x, |t| t.rfft());
+
+        let x: Tensor<Rank3<2, 5, 2>, TestDtype, _> = dev.sample_normal();
+        assert_grads(&dev, x.clone(), |t| t.irfft(8));
+        assert_grads(&dev, x.clone(), |t| t.irfft(9));
+        // fewer output samples than frequencies, so some of them are ignored
+        assert_grads(&dev, x, |t| t.irfft(4));
+    }
+}
@@ -0,0 +1,47 @@
+use crate::shapes::{Dtype, Shape};
+use crate::tensor::cpu::{Cpu, LendingIterator, StridedArray};
+
+use super::{FlipKernel, FlipOp};
+
+impl FlipOp {
+    #[inline(always)]
+    fn flip_index<S: Shape>(&self, dims: &S::Concrete, idx: &mut S::Concrete) {
+        for d in 0..S::NUM_DIMS {
+            if self.axes & (1 << d) != 0 {
+                idx[d] = dims[d] - 1 - idx[d];
+            }
+        }
+    }
+}
+
+impl<E: Dtype> FlipKernel<E> for Cpu {
+    fn forward<S: Shape>(
+        &self,
+        op: FlipOp,
+        inp: &Self::Storage<S, E>,
+    ) -> Result<Self::Storage<S, E>, Self::Err> {
+        let dims = inp.shape.concrete();
+        let mut out = StridedArray::new(inp.shape)?;
+        let mut out_iter = out.iter_mut_with_index();
+        while let Some((o, mut i)) = out_iter.next() {
+            op.flip_index::<S>(&dims, &mut i);
+            *o = inp[i];
+        }
+        Ok(out)
+    }
+
+    fn backward<S: Shape>(
+        &self,
+        op: FlipOp,
+        grad_inp: &mut Self::Storage<S, E>,
+        grad_out: &Self::Storage<S, E>,
+    ) -> Result<(), Self::Err> {
+        let dims = grad_out.shape.concrete();
+        let mut out_iter = grad_out.iter_with_index();
+        while let Some((o, mut i)) = out_iter.next() {
+            op.flip_index::<S>(&dims, &mut i);
+            grad_inp[i] += *o;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,89 @@
+#include "cuda_utils.cuh"
+
+struct FlipOp {
+    size_t axes;
+};
+
+// Computes the index into a strided input for the i'th element of the contiguous
+// output, reversing each axis whose bit is set in op.axes.
+__device__ unsigned int get_flipped_index(
+    const FlipOp op,
+    unsigned int i,
+    const size_t num_dims,
+    const size_t *dims,
+    const size_t *strides
+) {
+    unsigned int inp_i = 0;
+    for (int d = num_dims - 1; d >= 0; d--) {
+        size_t i_dim = i % dims[d];
+        if (op.axes & (1 << d)) {
+            i_dim = dims[d] - 1 - i_dim;
+        }
+        inp_i += i_dim * strides[d];
+        i /= dims[d];
+    }
+    return inp_i;
+}
+
+template<typename T>
+__device__ void flip_fwd(
+    const FlipOp op,
+    const size_t numel,
+    const size_t num_dims,
+    const size_t *dims,
+    const size_t *inp_strides,
+    const T *inp,
+    T *out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    out[i] = inp[get_flipped_index(op, i, num_dims, dims, inp_strides)];
+}
+
+template<typename T>
+__device__ void flip_bwd(
+    const FlipOp op,
+    const size_t numel,
+    const size_t num_dims,
+    const size_t *dims,
+    const size_t *inp_strides,
+    T *grad_inp,
+    const T *grad_out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    atomicAdd(grad_inp + get_flipped_index(op, i, num_dims, dims, inp_strides), grad_out[i]);
+}
+
+#define FLIP_OP(TYPENAME, FWD, BWD) \
+extern "C" __global__ void FWD( \
+    const FlipOp op, \
+    const size_t numel, \
+    const size_t num_dims, \
+    const size_t *dims, \
+    const size_t *inp_strides, \
+    const TYPENAME *inp, \
+    TYPENAME *out \
+) { \
+    flip_fwd(op, numel, num_dims, dims, inp_strides, inp, out); \
+} \
+extern "C" __global__ void BWD( \
+    const FlipOp op, \
+    const size_t numel, \
+    const size_t num_dims, \
+    const size_t *dims, \
+    const size_t *inp_strides, \
+    TYPENAME *grad_inp, \
+    const TYPENAME *grad_out \
+) { \
+    flip_bwd(op, numel, num_dims, dims, inp_strides, grad_inp, grad_out); \
+}
+
+FLIP_OP(float, flip_fwd_f32, flip_bwd_f32);
+FLIP_OP(double, flip_fwd_f64, flip_bwd_f64);
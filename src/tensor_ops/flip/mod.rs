@@ -0,0 +1,144 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{gradients::Tape, shapes::*, tensor::*};
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct FlipOp {
+    /// Bit `i` is set if axis `i` is reversed
+    pub axes: usize,
+}
+
+impl FlipOp {
+    fn new<Ax: Axes>() -> Self {
+        let mut axes = 0;
+        for ax in Ax::as_array() {
+            axes |= 1 << ax;
+        }
+        Self { axes }
+    }
+}
+
+pub trait FlipKernel<E: Dtype>: DeviceStorage {
+    fn forward<S: Shape>(
+        &self,
+        op: FlipOp,
+        inp: &Self::Storage<S, E>,
+    ) -> Result<Self::Storage<S, E>, Self::Err>;
+    fn backward<S: Shape>(
+        &self,
+        op: FlipOp,
+        grad_inp: &mut Self::Storage<S, E>,
+        grad_out: &Self::Storage<S, E>,
+    ) -> Result<(), Self::Err>;
+}
+
+/// Reverses the order of elements along one or more axes.
+///
+/// **Pytorch equivalent**: `t.flip(dims=Ax)`
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+/// let r = t.clone().flip::<Axis<1>>();
+/// assert_eq!(r.array(), [[3.0, 2.0, 1.0], [6.0, 5.0, 4.0]]);
+/// let r = t.flip::<Axes2<0, 1>>();
+/// assert_eq!(r.array(), [[6.0, 5.0, 4.0], [3.0, 2.0, 1.0]]);
+/// ```
+pub trait TryFlip: HasErr + HasShape {
+    fn flip<Ax: Axes>(self) -> Self
+    where
+        Self::Shape: HasAxes<Ax>,
+    {
+        self.try_flip::<Ax>().unwrap()
+    }
+    fn try_flip<Ax: Axes>(self) -> Result<Self, Self::Err>
+    where
+        Self::Shape: HasAxes<Ax>;
+}
+
+impl<S: Shape, E: Dtype, D: FlipKernel<E>, T: Tape<D>> TryFlip for Tensor<S, E, D, T> {
+    fn try_flip<Ax: Axes>(self) -> Result<Self, Self::Err>
+    where
+        Self::Shape: HasAxes<Ax>,
+    {
+        if S::NUM_DIMS == 0 {
+            return Ok(self);
+        }
+        let op = FlipOp::new::<Ax>();
+        let (inp, mut tape) = self.split_tape();
+        let out = inp.device.upgrade(inp.device.forward(op, &inp.storage)?);
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&inp)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+            inp.device.backward(op, grad_inp, grad_out)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_flip_1d() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([1.0, 2.0, 3.0, 4.0]);
+        let r = t.trace().flip::<Axis<0>>();
+        assert_eq!(r.array(), [4.0, 3.0, 2.0, 1.0]);
+        let g = (r * dev.tensor([1.0, 2.0, 3.0, 4.0])).sum().backward();
+        assert_eq!(g.get(&t).array(), [4.0, 3.0, 2.0, 1.0]);
+    }
+
+    #[test]
+    fn test_flip_3d() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank3<2, 3, 4>, TestDtype, _> = dev.sample_normal();
+        let t_arr = t.array();
+
+        let r = t.clone().flip::<Axis<1>>().array();
+        for i in 0..2 {
+            for j in 0..3 {
+                assert_eq!(r[i][j], t_arr[i][2 - j]);
+            }
+        }
+
+        let r = t.trace().flip::<Axes2<0, 2>>();
+        let r_arr = r.array();
+        for i in 0..2 {
+            for j in 0..3 {
+                for k in 0..4 {
+                    assert_eq!(r_arr[i][j][k], t_arr[1 - i][j][3 - k]);
+                }
+            }
+        }
+
+        let w: Tensor<Rank3<2, 3, 4>, TestDtype, _> = dev.sample_normal();
+        let g = (r * w.clone()).sum().backward();
+        assert_eq!(g.get(&t).array(), w.flip::<Axes2<0, 2>>().array());
+    }
+
+    #[test]
+    fn test_flip_broadcasted() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([1.0, 2.0, 3.0]);
+        let r = t
+            .trace()
+            .broadcast::<Rank2<2, 3>, _>()
+            .flip::<Axes2<0, 1>>();
+        assert_eq!(r.array(), [[3.0, 2.0, 1.0]; 2]);
+        let g = (r * dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]))
+            .sum()
+            .backward();
+        assert_eq!(g.get(&t).array(), [9.0, 7.0, 5.0]);
+    }
+}
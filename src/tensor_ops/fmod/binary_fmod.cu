@@ -0,0 +1,25 @@
+#include "binary_op_macros.cuh"
+
+struct BinaryFmodKernelOp {};
+
+struct BinaryRemainderKernelOp {};
+
+BINARY_OP(float, bfmod_fwd_f32, bfmod_bwd_f32, BinaryFmodKernelOp,
+    fmodg(x, y),
+    1.0,
+    0.0)
+
+BINARY_OP(double, bfmod_fwd_f64, bfmod_bwd_f64, BinaryFmodKernelOp,
+    fmodg(x, y),
+    1.0,
+    0.0)
+
+BINARY_OP(float, bremainder_fwd_f32, bremainder_bwd_f32, BinaryRemainderKernelOp,
+    remainderg(x, y),
+    1.0,
+    0.0)
+
+BINARY_OP(double, bremainder_fwd_f64, bremainder_bwd_f64, BinaryRemainderKernelOp,
+    remainderg(x, y),
+    1.0,
+    0.0)
@@ -0,0 +1,60 @@
+use crate::tensor_ops::cpu_kernels::{BinaryDerivative, UnaryDerivative};
+
+/// `x % y` with the sign of `y`, like python's `%`.
+#[inline(always)]
+fn remainder<F: num_traits::Float>(x: F, y: F) -> F {
+    let r = x % y;
+    if r != F::zero() && (r < F::zero()) != (y < F::zero()) {
+        r + y
+    } else {
+        r
+    }
+}
+
+impl<F: num_traits::Float> UnaryDerivative<F> for super::ScalarFmodKernelOp<F> {
+    fn f(&self, &x: &F) -> F {
+        x % self.scalar
+    }
+    fn df(&self, _: &F) -> F {
+        F::one()
+    }
+}
+
+impl<F: num_traits::Float> BinaryDerivative<F> for super::BinaryFmodKernelOp {
+    #[inline(always)]
+    fn f(&self, &x: &F, &y: &F) -> F {
+        x % y
+    }
+    #[inline(always)]
+    fn dfdx(&self, _: &F, _: &F) -> F {
+        F::one()
+    }
+    #[inline(always)]
+    fn dfdy(&self, _: &F, _: &F) -> F {
+        F::zero()
+    }
+}
+
+impl<F: num_traits::Float> UnaryDerivative<F> for super::ScalarRemainderKernelOp<F> {
+    fn f(&self, &x: &F) -> F {
+        remainder(x, self.scalar)
+    }
+    fn df(&self, _: &F) -> F {
+        F::one()
+    }
+}
+
+impl<F: num_traits::Float> BinaryDerivative<F> for super::BinaryRemainderKernelOp {
+    #[inline(always)]
+    fn f(&self, &x: &F, &y: &F) -> F {
+        remainder(x, y)
+    }
+    #[inline(always)]
+    fn dfdx(&self, _: &F, _: &F) -> F {
+        F::one()
+    }
+    #[inline(always)]
+    fn dfdy(&self, _: &F, _: &F) -> F {
+        F::zero()
+    }
+}
@@ -0,0 +1,72 @@
+use super::{
+    BinaryFmodKernelOp as BinaryFmod, BinaryRemainderKernelOp as BinaryRemainder,
+    ScalarFmodKernelOp as ScalarFmod, ScalarRemainderKernelOp as ScalarRemainder,
+};
+use crate::tensor_ops::cuda_kernels::{cuda_binary, cuda_unary};
+
+unsafe impl cudarc::driver::AsKernelParam for ScalarFmod<f32> {}
+unsafe impl cudarc::driver::AsKernelParam for ScalarFmod<f64> {}
+unsafe impl cudarc::driver::AsKernelParam for BinaryFmod {}
+unsafe impl cudarc::driver::AsKernelParam for ScalarRemainder<f32> {}
+unsafe impl cudarc::driver::AsKernelParam for ScalarRemainder<f64> {}
+unsafe impl cudarc::driver::AsKernelParam for BinaryRemainder {}
+
+const SCALAR_PTX: &str = include_str!(concat!(env!("OUT_DIR"), "/scalar_fmod.ptx"));
+const BINARY_PTX: &str = include_str!(concat!(env!("OUT_DIR"), "/binary_fmod.ptx"));
+
+cuda_unary!(
+    ScalarFmod<f32>,
+    f32,
+    SCALAR_PTX,
+    "sfmod_fwd_f32",
+    "sfmod_bwd_f32"
+);
+cuda_unary!(
+    ScalarFmod<f64>,
+    f64,
+    SCALAR_PTX,
+    "sfmod_fwd_f64",
+    "sfmod_bwd_f64"
+);
+cuda_binary!(
+    BinaryFmod,
+    f32,
+    BINARY_PTX,
+    "bfmod_fwd_f32",
+    "bfmod_bwd_f32"
+);
+cuda_binary!(
+    BinaryFmod,
+    f64,
+    BINARY_PTX,
+    "bfmod_fwd_f64",
+    "bfmod_bwd_f64"
+);
+cuda_unary!(
+    ScalarRemainder<f32>,
+    f32,
+    SCALAR_PTX,
+    "sremainder_fwd_f32",
+    "sremainder_bwd_f32"
+);
+cuda_unary!(
+    ScalarRemainder<f64>,
+    f64,
+    SCALAR_PTX,
+    "sremainder_fwd_f64",
+    "sremainder_bwd_f64"
+);
+cuda_binary!(
+    BinaryRemainder,
+    f32,
+    BINARY_PTX,
+    "bremainder_fwd_f32",
+    "bremainder_bwd_f32"
+);
+cuda_binary!(
+    BinaryRemainder,
+    f64,
+    BINARY_PTX,
+    "bremainder_fwd_f64",
+    "bremainder_bwd_f64"
+);
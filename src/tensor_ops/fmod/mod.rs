@@ -0,0 +1,196 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use super::ops::{try_binary_op, try_unary_op, BinaryKernel, UnaryKernel};
+use crate::{gradients::*, shapes::*, tensor::*};
+
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct BinaryFmodKernelOp;
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct ScalarFmodKernelOp<E> {
+    scalar: E,
+}
+
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct BinaryRemainderKernelOp;
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct ScalarRemainderKernelOp<E> {
+    scalar: E,
+}
+
+/// Element wise and scalar `x - trunc(x / y) * y`, where the result has the sign of `x`.
+///
+/// The gradient wrt `x` is 1, and the divisor `y` receives a zero gradient.
+///
+/// **Pytorch equivalent**: `torch.fmod(x, y)`
+///
+/// Example:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let a = dev.tensor([-3.0, -1.5, 1.5, 3.0]);
+/// let r = fmod(a.clone(), 2.0);
+/// assert_eq!(r.array(), [-1.0, -1.5, 1.5, 1.0]);
+///
+/// let b = dev.tensor([2.0, -2.0, 1.0, -2.0]);
+/// let r = a.fmod(b);
+/// assert_eq!(r.array(), [-1.0, -1.5, 0.5, 1.0]);
+/// ```
+pub fn fmod<Lhs: TryFmod<Rhs>, Rhs>(lhs: Lhs, rhs: Rhs) -> Lhs {
+    lhs.fmod(rhs)
+}
+
+/// Element wise and scalar `x - floor(x / y) * y`, where the result has the sign of `y`.
+/// This matches python's `%` operator.
+///
+/// The gradient wrt `x` is 1, and the divisor `y` receives a zero gradient.
+///
+/// **Pytorch equivalent**: `torch.remainder(x, y)`
+///
+/// Example:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let a = dev.tensor([-3.0, -1.5, 1.5, 3.0]);
+/// let r = remainder(a.clone(), 2.0);
+/// assert_eq!(r.array(), [1.0, 0.5, 1.5, 1.0]);
+///
+/// let b = dev.tensor([2.0, -2.0, 1.0, -2.0]);
+/// let r = a.remainder(b);
+/// assert_eq!(r.array(), [1.0, -1.5, 0.5, -1.0]);
+/// ```
+///
+/// Wrapping angles into `[0, 2π)`:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let theta = dev.tensor([-1.0, 7.0]);
+/// let r = theta.remainder(2.0 * std::f32::consts::PI);
+/// assert_eq!(r.array(), [2.0 * std::f32::consts::PI - 1.0, 7.0 - 2.0 * std::f32::consts::PI]);
+/// ```
+pub fn remainder<Lhs: TryRemainder<Rhs>, Rhs>(lhs: Lhs, rhs: Rhs) -> Lhs {
+    lhs.remainder(rhs)
+}
+
+/// Element wise and scalar fmod. See [fmod].
+pub trait TryFmod<Rhs>: HasErr {
+    /// See [fmod]
+    fn fmod(self, rhs: Rhs) -> Self {
+        self.try_fmod(rhs).unwrap()
+    }
+
+    /// Fallible version of [TryFmod::fmod]
+    fn try_fmod(self, rhs: Rhs) -> Result<Self, Self::Err>;
+}
+
+/// Element wise and scalar remainder. See [remainder].
+pub trait TryRemainder<Rhs>: HasErr {
+    /// See [remainder]
+    fn remainder(self, rhs: Rhs) -> Self {
+        self.try_remainder(rhs).unwrap()
+    }
+
+    /// Fallible version of [TryRemainder::remainder]
+    fn try_remainder(self, rhs: Rhs) -> Result<Self, Self::Err>;
+}
+
+impl<S: Shape, E: Dtype, D, LTape, RTape> TryFmod<Tensor<S, E, D, RTape>> for Tensor<S, E, D, LTape>
+where
+    D: BinaryKernel<BinaryFmodKernelOp, E>,
+    LTape: Tape<D> + Merge<RTape>,
+    RTape: Tape<D>,
+{
+    fn try_fmod(self, rhs: Tensor<S, E, D, RTape>) -> Result<Self, Self::Err> {
+        try_binary_op(BinaryFmodKernelOp, self, rhs)
+    }
+}
+
+impl<S: Shape, E: Dtype, D: UnaryKernel<ScalarFmodKernelOp<E>, E>, T: Tape<D>> TryFmod<E>
+    for Tensor<S, E, D, T>
+{
+    fn try_fmod(self, rhs: E) -> Result<Self, Self::Err> {
+        try_unary_op(ScalarFmodKernelOp { scalar: rhs }, self)
+    }
+}
+
+impl<S: Shape, E: Dtype, D, LTape, RTape> TryRemainder<Tensor<S, E, D, RTape>>
+    for Tensor<S, E, D, LTape>
+where
+    D: BinaryKernel<BinaryRemainderKernelOp, E>,
+    LTape: Tape<D> + Merge<RTape>,
+    RTape: Tape<D>,
+{
+    fn try_remainder(self, rhs: Tensor<S, E, D, RTape>) -> Result<Self, Self::Err> {
+        try_binary_op(BinaryRemainderKernelOp, self, rhs)
+    }
+}
+
+impl<S: Shape, E: Dtype, D: UnaryKernel<ScalarRemainderKernelOp<E>, E>, T: Tape<D>> TryRemainder<E>
+    for Tensor<S, E, D, T>
+{
+    fn try_remainder(self, rhs: E) -> Result<Self, Self::Err> {
+        try_unary_op(ScalarRemainderKernelOp { scalar: rhs }, self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_fmod_scalar() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<_, TestDtype, _> = dev.tensor([[-5.5, -2.0, 0.0], [0.5, 2.5, 7.0]]);
+        let r = a.trace().fmod(2.0);
+        let r_array = r.array();
+        assert_close(&r_array, &[[-1.5, -0.0, 0.0], [0.5, 0.5, 1.0]]);
+        let g = r.exp().sum().backward();
+        assert_close(&g.get(&a).array(), &r_array.map(|x| x.map(TestDtype::exp)));
+    }
+
+    #[test]
+    fn test_remainder_scalar() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<_, TestDtype, _> = dev.tensor([[-5.5, -2.0, 0.0], [0.5, 2.5, 7.0]]);
+        let r = a.trace().remainder(2.0);
+        assert_close(&r.array(), &[[0.5, 0.0, 0.0], [0.5, 0.5, 1.0]]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&a).array(), [[1.0; 3]; 2]);
+
+        let r = a.remainder(-2.0);
+        assert_close(&r.array(), &[[-1.5, 0.0, 0.0], [-1.5, -1.5, -1.0]]);
+    }
+
+    #[test]
+    fn test_fmod_tensor() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<_, TestDtype, _> = dev.tensor([-7.0, -7.0, 7.0, 7.0, 1.25]);
+        let b: Tensor<_, TestDtype, _> = dev.tensor([3.0, -3.0, 3.0, -3.0, 0.5]);
+        let r = a.trace().fmod(b.trace());
+        assert_eq!(r.array(), [-1.0, -1.0, 1.0, 1.0, 0.25]);
+        let g = (r * dev.tensor([1.0, 2.0, 3.0, 4.0, 5.0])).sum().backward();
+        assert_eq!(g.get(&a).array(), [1.0, 2.0, 3.0, 4.0, 5.0]);
+        assert_eq!(g.get(&b).array(), [0.0; 5]);
+    }
+
+    #[test]
+    fn test_remainder_tensor() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<_, TestDtype, _> = dev.tensor([-7.0, -7.0, 7.0, 7.0, 6.0]);
+        let b: Tensor<_, TestDtype, _> = dev.tensor([3.0, -3.0, 3.0, -3.0, -3.0]);
+        let r = a.trace().remainder(b.trace());
+        assert_eq!(r.array(), [2.0, -1.0, 1.0, -2.0, 0.0]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&a).array(), [1.0; 5]);
+        assert_eq!(g.get(&b).array(), [0.0; 5]);
+    }
+}
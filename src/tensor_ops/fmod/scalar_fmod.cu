@@ -0,0 +1,27 @@
+#include "unary_op_macros.cuh"
+
+template<typename F>
+struct ScalarFmodKernelOp {
+    F scalar;
+};
+
+template<typename F>
+struct ScalarRemainderKernelOp {
+    F scalar;
+};
+
+UNARY_OP(float, sfmod_fwd_f32, sfmod_bwd_f32, ScalarFmodKernelOp<float>,
+    fmodg(x, op.scalar),
+    1.0);
+
+UNARY_OP(double, sfmod_fwd_f64, sfmod_bwd_f64, ScalarFmodKernelOp<double>,
+    fmodg(x, op.scalar),
+    1.0);
+
+UNARY_OP(float, sremainder_fwd_f32, sremainder_bwd_f32, ScalarRemainderKernelOp<float>,
+    remainderg(x, op.scalar),
+    1.0);
+
+UNARY_OP(double, sremainder_fwd_f64, sremainder_bwd_f64, ScalarRemainderKernelOp<double>,
+    remainderg(x, op.scalar),
+    1.0);
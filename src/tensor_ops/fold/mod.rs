@@ -0,0 +1,209 @@
+use crate::{gradients::Tape, shapes::*, tensor::*};
+
+use super::{
+    add::BinaryAddKernelOp,
+    ops::BinaryKernel,
+    unfold::{UnfoldKernel, UnfoldOp},
+};
+
+/// Sum sliding local blocks (patches) back into `(Chan, Height, Width)` or
+/// `(Batch, Chan, Height, Width)` images. This is the inverse of [super::TryUnfold],
+/// and is also known as `col2im`.
+pub trait TryFold: HasErr {
+    type Output;
+
+    /// Folds a `(Chan * K * K, L)` tensor of `K x K` patches into an image of size
+    /// `(height, width)`, where the patches were extracted with stride `stride` and
+    /// zero padding `padding`. Overlapping values are summed, and padded values are
+    /// dropped. The patches are ordered the same way that [super::TryUnfold::unfold]
+    /// produces them.
+    ///
+    /// **Pytorch equivalent** `torch.nn.functional.fold(t, (height, width), kernel, padding=padding, stride=stride)`.
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let patches: Tensor<Rank2<4, 4>, f32, _> = dev.ones();
+    /// let img: Tensor<(usize, usize, usize), f32, _> = patches.fold((3, 3), 2, 1, 0);
+    /// assert_eq!(img.shape(), &(1, 3, 3));
+    /// assert_eq!(img.as_vec(), [1.0, 2.0, 1.0, 2.0, 4.0, 2.0, 1.0, 2.0, 1.0]);
+    /// ```
+    fn fold(
+        self,
+        output_size: (usize, usize),
+        kernel: usize,
+        stride: usize,
+        padding: usize,
+    ) -> Self::Output {
+        self.try_fold(output_size, kernel, stride, padding).unwrap()
+    }
+
+    /// Fallible version of [TryFold::fold]
+    fn try_fold(
+        self,
+        output_size: (usize, usize),
+        kernel: usize,
+        stride: usize,
+        padding: usize,
+    ) -> Result<Self::Output, Self::Err>;
+}
+
+/// Builds the [UnfoldOp] that unfolds an image of `output_size` into patches
+/// of shape `(rows, cols)`.
+fn fold_op(
+    batch: usize,
+    (rows, cols): (usize, usize),
+    (h, w): (usize, usize),
+    kernel: usize,
+    stride: usize,
+    padding: usize,
+) -> UnfoldOp {
+    assert!(kernel > 0, "kernel size must be positive");
+    assert_eq!(
+        rows % (kernel * kernel),
+        0,
+        "patch rows must be divisible by kernel * kernel"
+    );
+    let chan = rows / (kernel * kernel);
+    let op = UnfoldOp::new(kernel, stride, padding, [batch, chan, h, w]);
+    assert_eq!(
+        op.h_out * op.w_out,
+        cols,
+        "number of patches doesn't match the output size"
+    );
+    op
+}
+
+impl<R: Dim, L: Dim, E: Dtype, D, T: Tape<D>> TryFold for Tensor<(R, L), E, D, T>
+where
+    D: UnfoldKernel<E> + BinaryKernel<BinaryAddKernelOp, E> + ZerosTensor<E>,
+{
+    type Output = Tensor<(usize, usize, usize), E, D, T>;
+
+    fn try_fold(
+        self,
+        (h, w): (usize, usize),
+        kernel: usize,
+        stride: usize,
+        padding: usize,
+    ) -> Result<Self::Output, Self::Err> {
+        let (rows, cols) = *self.shape();
+        let shape = (rows.size(), cols.size());
+        let op = fold_op(1, shape, (h, w), kernel, stride, padding);
+        let (inp, mut tape) = self.split_tape();
+        let mut out = inp.device.try_zeros_like(&(op.chan, h, w))?;
+        UnfoldKernel::backward(&inp.device, op, &mut out.storage, &inp.storage)?;
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&inp)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+            let mut grad = inp.device.try_zeros_like(inp.shape())?.storage;
+            UnfoldKernel::forward(&inp.device, op, grad_out, &mut grad)?;
+            *grad_inp = BinaryKernel::forward(&inp.device, BinaryAddKernelOp, grad_inp, &grad)?;
+            Ok(())
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+impl<B: Dim, R: Dim, L: Dim, E: Dtype, D, T: Tape<D>> TryFold for Tensor<(B, R, L), E, D, T>
+where
+    D: UnfoldKernel<E> + BinaryKernel<BinaryAddKernelOp, E> + ZerosTensor<E>,
+{
+    type Output = Tensor<(B, usize, usize, usize), E, D, T>;
+
+    fn try_fold(
+        self,
+        (h, w): (usize, usize),
+        kernel: usize,
+        stride: usize,
+        padding: usize,
+    ) -> Result<Self::Output, Self::Err> {
+        let (batch, rows, cols) = *self.shape();
+        let shape = (rows.size(), cols.size());
+        let op = fold_op(batch.size(), shape, (h, w), kernel, stride, padding);
+        let (inp, mut tape) = self.split_tape();
+        let mut out = inp.device.try_zeros_like(&(batch, op.chan, h, w))?;
+        UnfoldKernel::backward(&inp.device, op, &mut out.storage, &inp.storage)?;
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&inp)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+            let mut grad = inp.device.try_zeros_like(inp.shape())?.storage;
+            UnfoldKernel::forward(&inp.device, op, grad_out, &mut grad)?;
+            *grad_inp = BinaryKernel::forward(&inp.device, BinaryAddKernelOp, grad_inp, &grad)?;
+            Ok(())
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_fold_inverts_non_overlapping_unfold() {
+        let dev: TestDevice = Default::default();
+        let img: Tensor<Rank3<2, 4, 6>, TestDtype, _> = dev.sample_normal();
+        let r = img.clone().unfold(2, 2, 0).fold((4, 6), 2, 2, 0);
+        assert_eq!(r.shape(), &(2, 4, 6));
+        assert_eq!(r.as_vec(), img.as_vec());
+    }
+
+    #[test]
+    fn test_fold_overlapping() {
+        let dev: TestDevice = Default::default();
+        let patches: Tensor<_, TestDtype, _> = dev.tensor([
+            [1.0, 2.0, 3.0, 4.0],
+            [1.0, 2.0, 3.0, 4.0],
+            [1.0, 2.0, 3.0, 4.0],
+            [1.0, 2.0, 3.0, 4.0],
+        ]);
+        let r = patches.trace().fold((3, 3), 2, 1, 0);
+        assert_eq!(r.as_vec(), [1.0, 3.0, 2.0, 4.0, 10.0, 6.0, 3.0, 7.0, 4.0]);
+        let w = dev.tensor_from_vec(
+            std::vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0],
+            (1, 3, 3),
+        );
+        let g = (r * w.clone()).sum().backward();
+        assert_eq!(g.get(&patches).as_vec(), w.unfold(2, 1, 0).as_vec());
+    }
+
+    #[test]
+    fn test_fold_stride_and_padding() {
+        let dev: TestDevice = Default::default();
+        let patches: Tensor<Rank2<9, 4>, TestDtype, _> = dev.ones();
+        let r = patches.trace().fold((3, 3), 3, 2, 1);
+        let r_vec = r.as_vec();
+        assert_eq!(r_vec, [1.0, 2.0, 1.0, 2.0, 4.0, 2.0, 1.0, 2.0, 1.0]);
+        let g = r.exp().sum().backward();
+        let expected = dev.tensor_from_vec(r_vec, (1, 3, 3)).exp().unfold(3, 2, 1);
+        assert_close(&g.get(&patches).as_vec(), &expected.as_vec());
+    }
+
+    #[test]
+    fn test_fold_4d() {
+        let dev: TestDevice = Default::default();
+        let patches: Tensor<Rank3<2, 8, 6>, TestDtype, _> = dev.sample_normal();
+        let r = patches.trace().fold((3, 4), 2, 1, 0);
+        assert_eq!(r.shape(), &(Const, 2, 3, 4));
+        let r0 = patches.clone().select(dev.tensor(1)).fold((3, 4), 2, 1, 0);
+        let r_vec = r.as_vec();
+        assert_eq!(r_vec[24..], r0.as_vec());
+        let g = r.square().sum().backward();
+        let expected = (dev.tensor_from_vec(r_vec, (2, 2, 3, 4)) * 2.0).unfold(2, 1, 0);
+        assert_close(&g.get(&patches).as_vec(), &expected.as_vec());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_fold_wrong_num_patches() {
+        let dev: TestDevice = Default::default();
+        let patches: Tensor<Rank2<4, 3>, TestDtype, _> = dev.ones();
+        let _ = patches.fold((3, 3), 2, 1, 0);
+    }
+}
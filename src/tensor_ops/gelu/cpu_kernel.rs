@@ -1,4 +1,4 @@
-use crate::tensor_ops::cpu_kernels::UnaryDerivative;
+use crate::tensor_ops::{cpu_kernels::UnaryDerivative, special};
 use num_traits::{Float, FloatConst};
 
 impl<F: Float + FloatConst> UnaryDerivative<F> for super::GeLUKernelOp {
@@ -30,3 +30,23 @@ impl<F: Float + FloatConst> UnaryDerivative<F> for super::GeLUKernelOp {
         left_derivative + right_derivative
     }
 }
+
+impl<F: Float + FloatConst> UnaryDerivative<F> for super::GeLUExactKernelOp {
+    #[inline(always)]
+    fn f(&self, &x: &F) -> F {
+        x * std_normal_cdf(x)
+    }
+
+    #[inline(always)]
+    fn df(&self, &x: &F) -> F {
+        let half = F::from(0.5).unwrap();
+        let pdf = (-half * x * x).exp() * F::FRAC_2_SQRT_PI() * F::FRAC_1_SQRT_2() * half;
+        std_normal_cdf(x) + x * pdf
+    }
+}
+
+#[inline(always)]
+fn std_normal_cdf<F: Float + FloatConst>(x: F) -> F {
+    let erf = F::from(special::erf((x * F::FRAC_1_SQRT_2()).to_f64().unwrap())).unwrap();
+    F::from(0.5).unwrap() * (F::one() + erf)
+}
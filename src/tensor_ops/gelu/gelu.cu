@@ -4,6 +4,7 @@
 #include <math.h>
 
 struct GeLUKernelOp {};
+struct GeLUExactKernelOp {};
 
 template<typename T>
 __device__ T gelu_fwd(T x) {
@@ -43,3 +44,25 @@ UNARY_OP(double, gelu_fwd_f64, gelu_bwd_f64, GeLUKernelOp,
     gelu_fwd(x),
     gelu_bwd(x)
 )
+
+template<typename T>
+__device__ T gelu_exact_fwd(T x) {
+    return 0.5 * x * (1.0 + erfg(x * M_SQRT1_2));
+}
+
+template<typename T>
+__device__ T gelu_exact_bwd(T x) {
+    T cdf = 0.5 * (1.0 + erfg(x * M_SQRT1_2));
+    T pdf = expg(-0.5 * x * x) * M_2_SQRTPI * M_SQRT1_2 * 0.5;
+    return cdf + x * pdf;
+}
+
+UNARY_OP(float, gelu_exact_fwd_f32, gelu_exact_bwd_f32, GeLUExactKernelOp,
+    gelu_exact_fwd(x),
+    gelu_exact_bwd(x)
+)
+
+UNARY_OP(double, gelu_exact_fwd_f64, gelu_exact_bwd_f64, GeLUExactKernelOp,
+    gelu_exact_fwd(x),
+    gelu_exact_bwd(x)
+)
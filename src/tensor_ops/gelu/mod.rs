@@ -10,8 +10,14 @@ use crate::{gradients::Tape, shapes::*, tensor::Tensor};
 #[derive(Debug, Default, Copy, Clone)]
 pub struct GeLUKernelOp;
 
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct GeLUExactKernelOp;
+
 /// [Gaussian Linear Unit (GeLU)](https://paperswithcode.com/method/gelu). `0.5 * x * (1 + tanh(sqrt(2 / pi) * (x + 0.044715 * x^3)))`
 ///
+/// This is the tanh approximation, see [gelu_exact] for the erf formulation.
+///
 /// Examples:
 /// ```rust
 /// # use dfdx::prelude::*;
@@ -25,6 +31,25 @@ pub fn gelu<S: Shape, E: Dtype, D: UnaryKernel<GeLUKernelOp, E>, T: Tape<D>>(
     t.gelu()
 }
 
+/// [Gaussian Linear Unit (GeLU)](https://paperswithcode.com/method/gelu) using the erf formulation
+/// `0.5 * x * (1 + erf(x / sqrt(2)))`, i.e. `x` times the standard normal cdf.
+///
+/// This matches `torch.nn.functional.gelu(x)` (with `approximate="none"`), so use this when porting
+/// weights trained with exact gelu. [gelu] is the tanh approximation.
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([-1.0, 0.0, 1.0, 2.0]);
+/// let r = t.gelu_exact();
+/// ```
+pub fn gelu_exact<S: Shape, E: Dtype, D: UnaryKernel<GeLUExactKernelOp, E>, T: Tape<D>>(
+    t: Tensor<S, E, D, T>,
+) -> Tensor<S, E, D, T> {
+    t.gelu_exact()
+}
+
 impl<S: Shape, E: Dtype, D: UnaryKernel<GeLUKernelOp, E>, T: Tape<D>> Tensor<S, E, D, T> {
     /// See [gelu]
     pub fn gelu(self) -> Self {
@@ -36,6 +61,17 @@ impl<S: Shape, E: Dtype, D: UnaryKernel<GeLUKernelOp, E>, T: Tape<D>> Tensor<S,
     }
 }
 
+impl<S: Shape, E: Dtype, D: UnaryKernel<GeLUExactKernelOp, E>, T: Tape<D>> Tensor<S, E, D, T> {
+    /// See [gelu_exact]
+    pub fn gelu_exact(self) -> Self {
+        self.try_gelu_exact().unwrap()
+    }
+    /// See [gelu_exact]
+    pub fn try_gelu_exact(self) -> Result<Self, D::Err> {
+        try_unary_op(GeLUExactKernelOp, self)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{tensor::*, tensor_ops::*, tests::*};
@@ -57,4 +93,20 @@ mod tests {
             &[-0.016455507, -0.014156329, 0.1, 0.5023068, 1.5338063],
         );
     }
+
+    #[test]
+    fn test_gelu_exact() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([-3.0, -1.0, 0.0, 1.0, 2.0]);
+        let r = x.trace().gelu_exact();
+        assert_close(
+            &r.array(),
+            &[-0.004049694, -0.15865526, 0.0, 0.8413447, 1.9544997],
+        );
+        let g = r.mean().backward();
+        assert_close(
+            &g.get(&x).array(),
+            &[-0.0023891295, -0.016663095, 0.1, 0.2166631, 0.21704637],
+        );
+    }
 }
@@ -0,0 +1,62 @@
+use crate::shapes::{Dtype, Shape};
+use crate::tensor::cpu::{Cpu, LendingIterator, StridedArray};
+
+use super::{GluKernel, GluOp};
+
+use num_traits::Float;
+
+/// Converts an index into the output into the indices of `a` and `b` in the input
+fn inp_indices<S: Shape, Dst: Shape>(
+    axis: usize,
+    half: usize,
+    i_out: Dst::Concrete,
+) -> (S::Concrete, S::Concrete) {
+    let mut i_a: S::Concrete = Default::default();
+    for j in 0..S::NUM_DIMS {
+        i_a[j] = i_out[j];
+    }
+    let mut i_b = i_a;
+    i_b[axis] += half;
+    (i_a, i_b)
+}
+
+fn sigmoid<E: Float>(x: E) -> E {
+    E::one() / (E::one() + x.neg().exp())
+}
+
+impl<E: Dtype + Float> GluKernel<E> for Cpu {
+    fn forward<S: Shape, Dst: Shape>(
+        &self,
+        op: GluOp,
+        inp: &Self::Storage<S, E>,
+        dst: Dst,
+    ) -> Result<Self::Storage<Dst, E>, Self::Err> {
+        let half = dst.concrete()[op.axis];
+        let mut out: Self::Storage<Dst, E> = StridedArray::new(dst)?;
+        let mut out_iter = out.iter_mut_with_index();
+        while let Some((o, i_out)) = out_iter.next() {
+            let (i_a, i_b) = inp_indices::<S, Dst>(op.axis, half, i_out);
+            *o = inp[i_a] * sigmoid(inp[i_b]);
+        }
+        Ok(out)
+    }
+
+    fn backward<S: Shape, Dst: Shape>(
+        &self,
+        op: GluOp,
+        inp: &Self::Storage<S, E>,
+        grad_inp: &mut Self::Storage<S, E>,
+        grad_out: &Self::Storage<Dst, E>,
+    ) -> Result<(), Self::Err> {
+        let half = grad_out.shape.concrete()[op.axis];
+        let mut grad_out_iter = grad_out.iter_with_index();
+        while let Some((g, i_out)) = grad_out_iter.next() {
+            let (i_a, i_b) = inp_indices::<S, Dst>(op.axis, half, i_out);
+            let a = inp[i_a];
+            let sig_b = sigmoid(inp[i_b]);
+            grad_inp[i_a] += *g * sig_b;
+            grad_inp[i_b] += *g * a * sig_b * (E::one() - sig_b);
+        }
+        Ok(())
+    }
+}
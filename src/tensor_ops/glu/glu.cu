@@ -0,0 +1,86 @@
+#include "cuda_utils.cuh"
+
+struct GluOp {
+    size_t axis;
+};
+
+template<typename T>
+__device__ T sigmoid(T x) {
+    return 1.0 / (1.0 + expg(-x));
+}
+
+template<typename T>
+__device__ void glu_fwd(
+    const GluOp op,
+    const size_t numel,
+    const size_t num_dims,
+    const size_t *out_dims,
+    const size_t *inp_strides,
+    const T *inp,
+    T *out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    // `a` has the same index as the output, and `b` is half the axis further along
+    unsigned int a_i = get_strided_index(i, num_dims, out_dims, inp_strides);
+    unsigned int b_i = a_i + out_dims[op.axis] * inp_strides[op.axis];
+    out[i] = inp[a_i] * sigmoid(inp[b_i]);
+}
+
+template<typename T>
+__device__ void glu_bwd(
+    const GluOp op,
+    const size_t numel,
+    const size_t num_dims,
+    const size_t *out_dims,
+    const size_t *inp_strides,
+    const size_t *out_strides,
+    const T *inp,
+    T *grad_inp,
+    const T *grad_out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    unsigned int a_i = get_strided_index(i, num_dims, out_dims, inp_strides);
+    unsigned int b_i = a_i + out_dims[op.axis] * inp_strides[op.axis];
+    T g = grad_out[get_strided_index(i, num_dims, out_dims, out_strides)];
+    T a = inp[a_i];
+    T sig_b = sigmoid(inp[b_i]);
+    atomicAdd(grad_inp + a_i, g * sig_b);
+    atomicAdd(grad_inp + b_i, g * a * sig_b * (1.0 - sig_b));
+}
+
+#define GLU_OP(TYPENAME, FWD, BWD) \
+extern "C" __global__ void FWD( \
+    const GluOp op, \
+    const size_t numel, \
+    const size_t num_dims, \
+    const size_t *out_dims, \
+    const size_t *inp_strides, \
+    const TYPENAME *inp, \
+    TYPENAME *out \
+) { \
+    glu_fwd(op, numel, num_dims, out_dims, inp_strides, inp, out); \
+} \
+extern "C" __global__ void BWD( \
+    const GluOp op, \
+    const size_t numel, \
+    const size_t num_dims, \
+    const size_t *out_dims, \
+    const size_t *inp_strides, \
+    const size_t *out_strides, \
+    const TYPENAME *inp, \
+    TYPENAME *grad_inp, \
+    const TYPENAME *grad_out \
+) { \
+    glu_bwd(op, numel, num_dims, out_dims, inp_strides, out_strides, inp, grad_inp, grad_out); \
+}
+
+GLU_OP(float, glu_fwd_f32, glu_bwd_f32);
+GLU_OP(double, glu_fwd_f64, glu_bwd_f64);
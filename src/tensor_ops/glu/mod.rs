@@ -0,0 +1,161 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use super::concat_along::ConcatShape;
+use crate::{gradients::Tape, shapes::*, tensor::*};
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct GluOp {
+    /// The axis to split in half
+    pub axis: usize,
+}
+
+pub trait GluKernel<E: Dtype>: DeviceStorage {
+    fn forward<S: Shape, Dst: Shape>(
+        &self,
+        op: GluOp,
+        inp: &Self::Storage<S, E>,
+        dst: Dst,
+    ) -> Result<Self::Storage<Dst, E>, Self::Err>;
+    fn backward<S: Shape, Dst: Shape>(
+        &self,
+        op: GluOp,
+        inp: &Self::Storage<S, E>,
+        grad_inp: &mut Self::Storage<S, E>,
+        grad_out: &Self::Storage<Dst, E>,
+    ) -> Result<(), Self::Err>;
+}
+
+/// [Gated Linear Unit](https://arxiv.org/abs/1612.08083). Splits `t` in half along `Ax`
+/// into `a` and `b`, and computes `a * sigmoid(b)` in a single kernel.
+///
+/// The size of `Ax` must be even, and it becomes a [usize] dim holding half of the
+/// size in the output.
+///
+/// **Pytorch equivalent**: `torch.nn.functional.glu(t, dim=Ax)`
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([[1.0, 2.0, 0.0, 0.0], [3.0, 4.0, 100.0, -100.0]]);
+/// let r: Tensor<(Const<2>, usize), f32, _> = t.glu::<Axis<1>>();
+/// assert_eq!(r.as_vec(), [0.5, 1.0, 3.0, 0.0]);
+/// ```
+pub trait TryGlu: HasErr + HasShape {
+    /// See [TryGlu]
+    #[allow(clippy::type_complexity)]
+    fn glu<Ax>(self) -> Self::WithShape<<Self::Shape as ConcatShape<Ax>>::Catted>
+    where
+        Self::Shape: ConcatShape<Ax>,
+    {
+        self.try_glu::<Ax>().unwrap()
+    }
+
+    /// Fallible version of [TryGlu::glu]
+    #[allow(clippy::type_complexity)]
+    fn try_glu<Ax>(
+        self,
+    ) -> Result<Self::WithShape<<Self::Shape as ConcatShape<Ax>>::Catted>, Self::Err>
+    where
+        Self::Shape: ConcatShape<Ax>;
+}
+
+impl<S: Shape, E: Dtype, D: GluKernel<E>, T: Tape<D>> TryGlu for Tensor<S, E, D, T> {
+    fn try_glu<Ax>(self) -> Result<Tensor<S::Catted, E, D, T>, Self::Err>
+    where
+        S: ConcatShape<Ax>,
+    {
+        let shape = *self.shape();
+
+        // the axis is the only dimension that changes
+        let dims = shape.concrete();
+        let catted = shape.catted(usize::MAX).concrete();
+        let axis = (0..S::NUM_DIMS).find(|&i| catted[i] == usize::MAX).unwrap();
+        assert_eq!(dims[axis] % 2, 0, "size of the axis must be even");
+
+        let op = GluOp { axis };
+        let dst = shape.catted(dims[axis] / 2);
+        let (inp, mut tape) = self.split_tape();
+        let out = inp
+            .device
+            .upgrade(inp.device.forward(op, &inp.storage, dst)?);
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&inp)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+            inp.device.backward(op, &inp.storage, grad_inp, grad_out)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_glu_1d() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([1.0, -2.0, 3.0, 0.5, 0.0, -1.0]);
+        let r = t.trace().glu::<Axis<0>>();
+        assert_eq!(r.shape(), &(3,));
+        assert_close(&r.as_vec(), &std::vec![0.62245935, -1.0, 0.8068243]);
+        let g = r.sum().backward();
+        assert_close(
+            &g.get(&t).array(),
+            &[0.62245935, 0.5, 0.26894143, 0.23500371, -0.5, 0.5898358],
+        );
+    }
+
+    #[test]
+    fn test_glu_matches_split() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank3<2, 4, 3>, TestDtype, _> = dev.sample_normal();
+        let w: Tensor<(Const<2>, usize, Const<3>), TestDtype, _> =
+            dev.sample_normal_like(&(Const, 2, Const));
+        let r = t.trace().glu::<Axis<1>>();
+        let [a, b] = t.trace().chunk::<Axis<1>, 2>();
+        let r2 = a * b.sigmoid();
+        assert_close(&r.as_vec(), &r2.as_vec());
+
+        let g = (r * w.clone()).sum().backward();
+        let g2 = (r2 * w).sum().backward();
+        assert_close(&g.get(&t).array(), &g2.get(&t).array());
+    }
+
+    #[test]
+    fn test_glu_last_axis_of_permuted() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank2<4, 2>, TestDtype, _> =
+            dev.tensor([[1.0, 2.0], [-1.0, 0.5], [0.0, 3.0], [2.0, -4.0]]);
+        let r = t.trace().permute::<_, Axes2<1, 0>>().glu::<Axis<1>>();
+        assert_close(
+            &r.as_vec(),
+            &std::vec![0.5, -0.8807971, 1.9051483, 0.008993105],
+        );
+        let g = r.sum().backward();
+        assert_close(
+            &g.get(&t).array(),
+            &[
+                [0.5, 0.95257413],
+                [0.8807971, 0.01798621],
+                [0.25, 0.09035332],
+                [-0.10499358, 0.008831353],
+            ],
+        );
+    }
+
+    #[test]
+    #[should_panic = "size of the axis must be even"]
+    fn test_glu_odd_axis() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank2<2, 3>, TestDtype, _> = dev.zeros();
+        let _ = t.glu::<Axis<1>>();
+    }
+}
@@ -0,0 +1,164 @@
+use crate::shapes::*;
+use crate::tensor::cpu::Cpu;
+
+use std::sync::Arc;
+
+use num_traits::Float;
+
+use super::{GridSampleKernel, GridSampleOp};
+
+fn make_4d<S: Shape>(strides: S::Concrete) -> [usize; 4] {
+    match S::NUM_DIMS {
+        3 => [0, strides[0], strides[1], strides[2]],
+        4 => [strides[0], strides[1], strides[2], strides[3]],
+        _ => panic!("Only implemented for 3d & 4d arrays"),
+    }
+}
+
+/// Maps a normalized grid coordinate in `[-1, 1]` to a pixel coordinate, along
+/// with the derivative of that mapping.
+fn unnormalize<F: Float>(g: F, size: usize, align_corners: bool) -> (F, F) {
+    let two = F::from(2.0).unwrap();
+    if align_corners {
+        let scale = F::from(size - 1).unwrap() / two;
+        ((g + F::one()) * scale, scale)
+    } else {
+        let scale = F::from(size).unwrap() / two;
+        ((g + F::one()) * scale - F::from(0.5).unwrap(), scale)
+    }
+}
+
+/// A pixel that a grid location reads from, with its bilinear weight & the derivatives
+/// of that weight with respect to the x & y pixel coordinates.
+struct Corner<F> {
+    idx: Option<[usize; 2]>,
+    weight: F,
+    dw_dx: F,
+    dw_dy: F,
+}
+
+fn corners<F: Float>(x: F, y: F, h: usize, w: usize) -> [Corner<F>; 4] {
+    let (x0, y0) = (x.floor(), y.floor());
+    let (lx, ly) = (x - x0, y - y0);
+    let one = F::one();
+    let at = |dy: F, dx: F| {
+        let (y, x) = (y0 + dy, x0 + dx);
+        if y >= F::zero() && x >= F::zero() && y < F::from(h).unwrap() && x < F::from(w).unwrap() {
+            Some([y.to_usize().unwrap(), x.to_usize().unwrap()])
+        } else {
+            None
+        }
+    };
+    [
+        Corner {
+            idx: at(F::zero(), F::zero()),
+            weight: (one - ly) * (one - lx),
+            dw_dx: -(one - ly),
+            dw_dy: -(one - lx),
+        },
+        Corner {
+            idx: at(F::zero(), one),
+            weight: (one - ly) * lx,
+            dw_dx: one - ly,
+            dw_dy: -lx,
+        },
+        Corner {
+            idx: at(one, F::zero()),
+            weight: ly * (one - lx),
+            dw_dx: -ly,
+            dw_dy: one - lx,
+        },
+        Corner {
+            idx: at(one, one),
+            weight: ly * lx,
+            dw_dx: ly,
+            dw_dy: lx,
+        },
+    ]
+}
+
+impl<F: Float + Dtype> GridSampleKernel<F> for Cpu {
+    fn forward<I: Shape, G: Shape, O: Shape>(
+        &self,
+        op: GridSampleOp,
+        inp: &Self::Storage<I, F>,
+        grid: &Self::Storage<G, F>,
+        out: &mut Self::Storage<O, F>,
+    ) -> Result<(), Self::Err> {
+        let istr = make_4d::<I>(inp.strides);
+        let gstr = make_4d::<G>(grid.strides);
+        let ostr = make_4d::<O>(out.strides);
+
+        let inp_buf = inp.data.as_ref();
+        let grid_buf = grid.data.as_ref();
+        let out_buf = Arc::make_mut(&mut out.data);
+        for b in 0..op.batch {
+            for oh in 0..op.h_out {
+                for ow in 0..op.w_out {
+                    let g_i = b * gstr[0] + oh * gstr[1] + ow * gstr[2];
+                    let (x, _) = unnormalize(grid_buf[g_i], op.w_in, op.align_corners);
+                    let (y, _) = unnormalize(grid_buf[g_i + gstr[3]], op.h_in, op.align_corners);
+                    let corners = corners(x, y, op.h_in, op.w_in);
+                    for c in 0..op.chan {
+                        let mut tmp = F::zero();
+                        for corner in corners.iter() {
+                            if let Some([y, x]) = corner.idx {
+                                tmp += corner.weight
+                                    * inp_buf
+                                        [b * istr[0] + c * istr[1] + y * istr[2] + x * istr[3]];
+                            }
+                        }
+                        out_buf[b * ostr[0] + c * ostr[1] + oh * ostr[2] + ow * ostr[3]] = tmp;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn backward<I: Shape, G: Shape, O: Shape>(
+        &self,
+        op: GridSampleOp,
+        inp: &Self::Storage<I, F>,
+        grad_inp: &mut Self::Storage<I, F>,
+        grid: &Self::Storage<G, F>,
+        grad_grid: &mut Self::Storage<G, F>,
+        grad_out: &Self::Storage<O, F>,
+    ) -> Result<(), Self::Err> {
+        let istr = make_4d::<I>(inp.strides);
+        let gstr = make_4d::<G>(grid.strides);
+        let ostr = make_4d::<O>(grad_out.strides);
+
+        let inp_buf = inp.data.as_ref();
+        let ginp_buf = Arc::make_mut(&mut grad_inp.data);
+        let grid_buf = grid.data.as_ref();
+        let ggrid_buf = Arc::make_mut(&mut grad_grid.data);
+        let gout_buf = grad_out.data.as_ref();
+        for b in 0..op.batch {
+            for oh in 0..op.h_out {
+                for ow in 0..op.w_out {
+                    let g_i = b * gstr[0] + oh * gstr[1] + ow * gstr[2];
+                    let (x, dx) = unnormalize(grid_buf[g_i], op.w_in, op.align_corners);
+                    let (y, dy) = unnormalize(grid_buf[g_i + gstr[3]], op.h_in, op.align_corners);
+                    let corners = corners(x, y, op.h_in, op.w_in);
+                    let mut grad_x = F::zero();
+                    let mut grad_y = F::zero();
+                    for c in 0..op.chan {
+                        let go = gout_buf[b * ostr[0] + c * ostr[1] + oh * ostr[2] + ow * ostr[3]];
+                        for corner in corners.iter() {
+                            if let Some([y, x]) = corner.idx {
+                                let i = b * istr[0] + c * istr[1] + y * istr[2] + x * istr[3];
+                                ginp_buf[i] += go * corner.weight;
+                                grad_x += go * corner.dw_dx * inp_buf[i];
+                                grad_y += go * corner.dw_dy * inp_buf[i];
+                            }
+                        }
+                    }
+                    ggrid_buf[g_i] += grad_x * dx;
+                    ggrid_buf[g_i + gstr[3]] += grad_y * dy;
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,86 @@
+use crate::{shapes::*, tensor::cuda::Cuda};
+
+use std::sync::Arc;
+
+use cudarc::driver::{AsKernelParam, LaunchAsync, LaunchConfig};
+
+use super::{GridSampleKernel, GridSampleOp};
+
+const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/grid_sample.ptx"));
+
+unsafe impl AsKernelParam for GridSampleOp {}
+
+fn make_4d<S: Shape>(strides: S::Concrete) -> [usize; 4] {
+    match S::NUM_DIMS {
+        3 => [0, strides[0], strides[1], strides[2]],
+        4 => [strides[0], strides[1], strides[2], strides[3]],
+        _ => panic!("Only implemented for 3d & 4d arrays"),
+    }
+}
+
+macro_rules! grid_sample_impl {
+    ($TypeName:ty, $Fwd:tt, $Bwd:tt) => {
+        impl GridSampleKernel<$TypeName> for Cuda {
+            fn forward<I: Shape, G: Shape, O: Shape>(
+                &self,
+                op: GridSampleOp,
+                inp: &Self::Storage<I, $TypeName>,
+                grid: &Self::Storage<G, $TypeName>,
+                out: &mut Self::Storage<O, $TypeName>,
+            ) -> Result<(), Self::Err> {
+                if !self.dev.has_func($Fwd, $Fwd) {
+                    self.dev.load_ptx(PTX_SRC.into(), $Fwd, &[$Fwd, $Bwd])?;
+                }
+
+                let inp_strides = self.dev.take_async(make_4d::<I>(inp.strides).into())?;
+                let grid_strides = self.dev.take_async(make_4d::<G>(grid.strides).into())?;
+                let out_strides = self.dev.take_async(make_4d::<O>(out.strides).into())?;
+                let fwd_fn = self.dev.get_func($Fwd, $Fwd).unwrap();
+                let cfg = LaunchConfig::for_num_elems(out.shape().num_elements() as u32);
+                let params = (
+                    op,                           // const GridSampleOp op,
+                    &inp_strides,                 // const size_t *inp_strides,
+                    &grid_strides,                // const size_t *grid_strides,
+                    &out_strides,                 // const size_t *out_strides,
+                    inp.data.as_ref(),            // const float *inp,
+                    grid.data.as_ref(),           // const float *grid,
+                    Arc::make_mut(&mut out.data), // float *out
+                );
+                unsafe { fwd_fn.launch_async(cfg, params) }?;
+                Ok(())
+            }
+
+            fn backward<I: Shape, G: Shape, O: Shape>(
+                &self,
+                op: GridSampleOp,
+                inp: &Self::Storage<I, $TypeName>,
+                grad_inp: &mut Self::Storage<I, $TypeName>,
+                grid: &Self::Storage<G, $TypeName>,
+                grad_grid: &mut Self::Storage<G, $TypeName>,
+                grad_out: &Self::Storage<O, $TypeName>,
+            ) -> Result<(), Self::Err> {
+                let inp_strides = self.dev.take_async(make_4d::<I>(inp.strides).into())?;
+                let grid_strides = self.dev.take_async(make_4d::<G>(grid.strides).into())?;
+                let out_strides = self.dev.take_async(make_4d::<O>(grad_out.strides).into())?;
+                let bwd_fn = self.dev.get_func($Fwd, $Bwd).unwrap();
+                let cfg = LaunchConfig::for_num_elems((op.batch * op.h_out * op.w_out) as u32);
+                let params = (
+                    op,                                 // const GridSampleOp op,
+                    &inp_strides,                       // const size_t *inp_strides,
+                    &grid_strides,                      // const size_t *grid_strides,
+                    &out_strides,                       // const size_t *out_strides,
+                    inp.data.as_ref(),                  // const float *inp,
+                    Arc::make_mut(&mut grad_inp.data),  // float *grad_inp,
+                    grid.data.as_ref(),                 // const float *grid,
+                    Arc::make_mut(&mut grad_grid.data), // float *grad_grid,
+                    grad_out.data.as_ref(),             // const float *grad_out
+                );
+                unsafe { bwd_fn.launch_async(cfg, params) }?;
+                Ok(())
+            }
+        }
+    };
+}
+
+grid_sample_impl!(f32, "grid_sample_fwd_f32", "grid_sample_bwd_f32");
+grid_sample_impl!(f64, "grid_sample_fwd_f64", "grid_sample_bwd_f64");
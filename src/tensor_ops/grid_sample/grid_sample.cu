@@ -0,0 +1,164 @@
+#include "cuda_utils.cuh"
+
+struct GridSampleOp {
+    size_t batch;
+    size_t chan;
+    size_t h_in;
+    size_t h_out;
+    size_t w_in;
+    size_t w_out;
+    bool align_corners;
+};
+
+// Maps a normalized grid coordinate in [-1, 1] to a pixel coordinate.
+template<typename T>
+__device__ T unnormalize(T g, size_t size, bool align_corners, T *scale) {
+    if (align_corners) {
+        *scale = static_cast<T>(size - 1) / 2.0;
+        return (g + 1.0) * *scale;
+    } else {
+        *scale = static_cast<T>(size) / 2.0;
+        return (g + 1.0) * *scale - 0.5;
+    }
+}
+
+__device__ bool in_bounds(long y, long x, size_t h, size_t w) {
+    return y >= 0 && x >= 0 && y < static_cast<long>(h) && x < static_cast<long>(w);
+}
+
+template<typename T>
+__device__ void grid_sample_fwd(
+    const GridSampleOp op,
+    const size_t *inp_strides,
+    const size_t *grid_strides,
+    const size_t *out_strides,
+    const T *inp, // 4d (Batch, Channels, Height, Width)
+    const T *grid, // 4d (Batch, HeightOut, WidthOut, 2)
+    T *out // 4d (Batch, Channels, HeightOut, WidthOut)
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    const size_t numel = op.batch * op.chan * op.h_out * op.w_out;
+    if (i >= numel) {
+        return;
+    }
+
+    unsigned int idx = i;
+    const size_t ow = idx % op.w_out;
+    idx /= op.w_out;
+    const size_t oh = idx % op.h_out;
+    idx /= op.h_out;
+    const size_t c = idx % op.chan;
+    idx /= op.chan;
+    const size_t b = idx % op.batch;
+    idx /= op.batch;
+
+    const T *g = grid + b * grid_strides[0] + oh * grid_strides[1] + ow * grid_strides[2];
+    T scale;
+    const T x = unnormalize(g[0], op.w_in, op.align_corners, &scale);
+    const T y = unnormalize(g[grid_strides[3]], op.h_in, op.align_corners, &scale);
+    const T fx0 = floor(x);
+    const T fy0 = floor(y);
+    const T lx = x - fx0;
+    const T ly = y - fy0;
+    const long x0 = static_cast<long>(fx0);
+    const long y0 = static_cast<long>(fy0);
+
+    const T *base = inp + b * inp_strides[0] + c * inp_strides[1];
+    T tmp = 0.0;
+    for (long dy = 0; dy < 2; dy++) {
+        for (long dx = 0; dx < 2; dx++) {
+            if (!in_bounds(y0 + dy, x0 + dx, op.h_in, op.w_in)) { continue; }
+            const T wy = dy ? ly : 1.0 - ly;
+            const T wx = dx ? lx : 1.0 - lx;
+            tmp += wy * wx * base[(y0 + dy) * inp_strides[2] + (x0 + dx) * inp_strides[3]];
+        }
+    }
+    out[i] = tmp;
+}
+
+template<typename T>
+__device__ void grid_sample_bwd(
+    const GridSampleOp op,
+    const size_t *inp_strides,
+    const size_t *grid_strides,
+    const size_t *out_strides,
+    const T *inp, // 4d (Batch, Channels, Height, Width)
+    T *grad_inp,
+    const T *grid, // 4d (Batch, HeightOut, WidthOut, 2)
+    T *grad_grid,
+    const T *grad_out // 4d (Batch, Channels, HeightOut, WidthOut)
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    const size_t numel = op.batch * op.h_out * op.w_out;
+    if (i >= numel) {
+        return;
+    }
+
+    unsigned int idx = i;
+    const size_t ow = idx % op.w_out;
+    idx /= op.w_out;
+    const size_t oh = idx % op.h_out;
+    idx /= op.h_out;
+    const size_t b = idx % op.batch;
+    idx /= op.batch;
+
+    const size_t g_i = b * grid_strides[0] + oh * grid_strides[1] + ow * grid_strides[2];
+    T scale_x, scale_y;
+    const T x = unnormalize(grid[g_i], op.w_in, op.align_corners, &scale_x);
+    const T y = unnormalize(grid[g_i + grid_strides[3]], op.h_in, op.align_corners, &scale_y);
+    const T fx0 = floor(x);
+    const T fy0 = floor(y);
+    const T lx = x - fx0;
+    const T ly = y - fy0;
+    const long x0 = static_cast<long>(fx0);
+    const long y0 = static_cast<long>(fy0);
+
+    T grad_x = 0.0;
+    T grad_y = 0.0;
+    for (size_t c = 0; c < op.chan; c++) {
+        const T go = grad_out[b * out_strides[0] + c * out_strides[1] + oh * out_strides[2] + ow * out_strides[3]];
+        const size_t base = b * inp_strides[0] + c * inp_strides[1];
+        for (long dy = 0; dy < 2; dy++) {
+            for (long dx = 0; dx < 2; dx++) {
+                if (!in_bounds(y0 + dy, x0 + dx, op.h_in, op.w_in)) { continue; }
+                const T wy = dy ? ly : 1.0 - ly;
+                const T wx = dx ? lx : 1.0 - lx;
+                const size_t inp_i = base + (y0 + dy) * inp_strides[2] + (x0 + dx) * inp_strides[3];
+                atomicAdd(grad_inp + inp_i, go * wy * wx);
+                grad_x += go * (dx ? wy : -wy) * inp[inp_i];
+                grad_y += go * (dy ? wx : -wx) * inp[inp_i];
+            }
+        }
+    }
+    grad_grid[g_i] += grad_x * scale_x;
+    grad_grid[g_i + grid_strides[3]] += grad_y * scale_y;
+}
+
+#define GRID_SAMPLE_OP(TYPENAME, fwd, bwd) \
+extern "C" __global__ void fwd( \
+    const GridSampleOp op, \
+    const size_t *inp_strides, \
+    const size_t *grid_strides, \
+    const size_t *out_strides, \
+    const TYPENAME *inp, \
+    const TYPENAME *grid, \
+    TYPENAME *out \
+) { \
+    grid_sample_fwd(op, inp_strides, grid_strides, out_strides, inp, grid, out); \
+} \
+extern "C" __global__ void bwd( \
+    const GridSampleOp op, \
+    const size_t *inp_strides, \
+    const size_t *grid_strides, \
+    const size_t *out_strides, \
+    const TYPENAME *inp, \
+    TYPENAME *grad_inp, \
+    const TYPENAME *grid, \
+    TYPENAME *grad_grid, \
+    const TYPENAME *grad_out \
+) { \
+    grid_sample_bwd(op, inp_strides, grid_strides, out_strides, inp, grad_inp, grid, grad_grid, grad_out); \
+}
+
+GRID_SAMPLE_OP(float, grid_sample_fwd_f32, grid_sample_bwd_f32);
+GRID_SAMPLE_OP(double, grid_sample_fwd_f64, grid_sample_bwd_f64);
@@ -0,0 +1,262 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{
+    gradients::{Merge, Tape},
+    shapes::*,
+    tensor::{DeviceStorage, HasErr, PutTape, SplitTape, Tensor, ZerosTensor},
+};
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct GridSampleOp {
+    pub batch: usize,
+    pub chan: usize,
+    pub h_in: usize,
+    pub h_out: usize,
+    pub w_in: usize,
+    pub w_out: usize,
+    pub align_corners: bool,
+}
+
+pub trait GridSampleKernel<E: Dtype>: DeviceStorage {
+    fn forward<I: Shape, G: Shape, O: Shape>(
+        &self,
+        op: GridSampleOp,
+        inp: &Self::Storage<I, E>,
+        grid: &Self::Storage<G, E>,
+        out: &mut Self::Storage<O, E>,
+    ) -> Result<(), Self::Err>;
+
+    #[allow(clippy::too_many_arguments)]
+    fn backward<I: Shape, G: Shape, O: Shape>(
+        &self,
+        op: GridSampleOp,
+        inp: &Self::Storage<I, E>,
+        grad_inp: &mut Self::Storage<I, E>,
+        grid: &Self::Storage<G, E>,
+        grad_grid: &mut Self::Storage<G, E>,
+        grad_out: &Self::Storage<O, E>,
+    ) -> Result<(), Self::Err>;
+}
+
+/// Bilinearly samples images (3d) and batches of images (4d) at the locations in `grid`.
+///
+/// `grid` has shape `(OH, OW, 2)` (or `(B, OH, OW, 2)` for batches), and holds the `(x, y)`
+/// location to sample each output pixel from. Locations are normalized so that `-1` is the
+/// left/top of the image and `1` is the right/bottom. With `align_corners` these are the
+/// centers of the corner pixels, otherwise they are the outer edges of the corner pixels.
+/// Samples that fall outside the image read zeros.
+///
+/// Gradients flow into both the image and the grid.
+///
+/// **Pytorch equivalent**: `torch.nn.functional.grid_sample(inp, grid, mode="bilinear",
+/// padding_mode="zeros", align_corners=align_corners)`
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let img = dev.tensor([[[1.0, 2.0], [3.0, 4.0]]]);
+/// let grid = dev.tensor([[[-1.0, -1.0], [0.0, 0.0], [1.0, -1.0]]]);
+/// let r: Tensor<Rank3<1, 1, 3>, f32, _> = img.grid_sample(grid, true);
+/// assert_eq!(r.array(), [[[1.0, 2.5, 2.0]]]);
+/// ```
+pub trait TryGridSample<Grid>: HasErr {
+    type Output;
+
+    fn grid_sample(self, grid: Grid, align_corners: bool) -> Self::Output {
+        self.try_grid_sample(grid, align_corners).unwrap()
+    }
+
+    fn try_grid_sample(self, grid: Grid, align_corners: bool) -> Result<Self::Output, Self::Err>;
+}
+
+impl<
+        C: Dim,
+        H: Dim,
+        W: Dim,
+        OH: Dim,
+        OW: Dim,
+        E: Dtype,
+        D: GridSampleKernel<E> + ZerosTensor<E>,
+        T: 'static + Tape<D> + Merge<R>,
+        R: 'static + Tape<D>,
+    > TryGridSample<Tensor<(OH, OW, Const<2>), E, D, R>> for Tensor<(C, H, W), E, D, T>
+{
+    type Output = Tensor<(C, OH, OW), E, D, T>;
+
+    fn try_grid_sample(
+        self,
+        grid: Tensor<(OH, OW, Const<2>), E, D, R>,
+        align_corners: bool,
+    ) -> Result<Self::Output, Self::Err> {
+        let &(chan, h, w) = self.shape();
+        let &(oh, ow, _) = grid.shape();
+        let op = GridSampleOp {
+            batch: 1,
+            chan: chan.size(),
+            h_in: h.size(),
+            h_out: oh.size(),
+            w_in: w.size(),
+            w_out: ow.size(),
+            align_corners,
+        };
+        try_grid_sample_op(op, self, grid, (chan, oh, ow))
+    }
+}
+
+impl<
+        B: Dim,
+        C: Dim,
+        H: Dim,
+        W: Dim,
+        OH: Dim,
+        OW: Dim,
+        E: Dtype,
+        D: GridSampleKernel<E> + ZerosTensor<E>,
+        T: 'static + Tape<D> + Merge<R>,
+        R: 'static + Tape<D>,
+    > TryGridSample<Tensor<(B, OH, OW, Const<2>), E, D, R>> for Tensor<(B, C, H, W), E, D, T>
+{
+    type Output = Tensor<(B, C, OH, OW), E, D, T>;
+
+    fn try_grid_sample(
+        self,
+        grid: Tensor<(B, OH, OW, Const<2>), E, D, R>,
+        align_corners: bool,
+    ) -> Result<Self::Output, Self::Err> {
+        let &(batch, chan, h, w) = self.shape();
+        let &(grid_batch, oh, ow, _) = grid.shape();
+        assert_eq!(batch.size(), grid_batch.size());
+        let op = GridSampleOp {
+            batch: batch.size(),
+            chan: chan.size(),
+            h_in: h.size(),
+            h_out: oh.size(),
+            w_in: w.size(),
+            w_out: ow.size(),
+            align_corners,
+        };
+        try_grid_sample_op(op, self, grid, (batch, chan, oh, ow))
+    }
+}
+
+fn try_grid_sample_op<
+    I: Shape,
+    G: Shape,
+    O: Shape,
+    E: Dtype,
+    D: GridSampleKernel<E> + ZerosTensor<E>,
+    T: 'static + Tape<D> + Merge<R>,
+    R: 'static + Tape<D>,
+>(
+    op: GridSampleOp,
+    inp: Tensor<I, E, D, T>,
+    grid: Tensor<G, E, D, R>,
+    out_shape: O,
+) -> Result<Tensor<O, E, D, T>, D::Err> {
+    let (inp, ltape) = inp.split_tape();
+    let (grid, rtape) = grid.split_tape();
+    let mut tape = ltape.merge(rtape);
+    let mut out = inp.device.try_zeros_like(&out_shape)?;
+    inp.device
+        .forward(op, &inp.storage, &grid.storage, &mut out.storage)?;
+    let phantom_out = out.clone();
+    tape.try_alloc_grad(&inp)?;
+    tape.try_alloc_grad(&grid)?;
+    tape.try_alloc_grad(&out)?;
+    tape.add_backward_op(move |grads| {
+        let (grad_inp, grad_grid, grad_out) = grads.muts_and_ref(&inp, &grid, &phantom_out);
+        inp.device.backward(
+            op,
+            &inp.storage,
+            grad_inp,
+            &grid.storage,
+            grad_grid,
+            grad_out,
+        )
+    });
+    Ok(out.put_tape(tape))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tensor::*, tensor_ops::*, tests::*};
+
+    const IMG: [[[TestDtype; 3]; 2]; 2] = [
+        [[0.1, 0.2, 0.3], [0.4, 0.5, 0.6]],
+        [[-0.5, 0.25, 1.0], [0.0, -1.0, 0.75]],
+    ];
+    const GRID: [[[TestDtype; 2]; 2]; 2] = [[[-0.9, -0.6], [0.3, 0.2]], [[0.8, 0.7], [1.2, -1.1]]];
+
+    #[test]
+    fn test_grid_sample_3d() {
+        let dev: TestDevice = Default::default();
+        let img = dev.tensor(IMG);
+        let grid = dev.tensor(GRID);
+        let r = img.trace().grid_sample(grid.trace(), false);
+        assert_close(
+            &r.array(),
+            &[
+                [[0.0585, 0.455], [0.384, 0.024]],
+                [[-0.2925, 0.0275], [0.48, 0.08]],
+            ],
+        );
+        let g = r.exp().sum().backward();
+        assert_close_with_tolerance(
+            &g.get(&img).array(),
+            &[
+                [[0.62024, 0.26007, 0.29473], [0.0, 0.60683, 1.43611]],
+                [[0.43664, 0.1696, 0.22543], [0.0, 0.39573, 1.35807]],
+            ],
+            1e-4,
+        );
+        assert_close_with_tolerance(
+            &g.get(&grid).array(),
+            &[
+                [[-0.36068, -0.17366], [2.47207, -0.34945]],
+                [[-2.51153, -1.67435], [-0.83434, 0.27811]],
+            ],
+            1e-4,
+        );
+    }
+
+    #[test]
+    fn test_grid_sample_4d_align_corners() {
+        let dev: TestDevice = Default::default();
+        let img = dev.tensor([IMG, IMG]);
+        let grid = dev.tensor([GRID, GRID]);
+        let r = img.trace().grid_sample(grid.trace(), true);
+        let expected = [
+            [[0.17, 0.41], [0.535, 0.228]],
+            [[-0.36, -0.095], [0.4675, 0.76]],
+        ];
+        assert_close(&r.array(), &[expected, expected]);
+        let g = r.exp().sum().backward();
+        let grad_img = [
+            [[0.85342, 0.56796, 1.34034], [0.21335, 0.94684, 1.43229]],
+            [[0.50233, 0.35832, 1.92573], [0.12558, 0.66721, 1.24897]],
+        ];
+        let grad_grid = [
+            [[0.3976, 0.29117], [1.37834, -0.20593]],
+            [[2.72434, -0.10298], [-2.38935, 1.00604]],
+        ];
+        assert_close_with_tolerance(&g.get(&img).array(), &[grad_img, grad_img], 1e-4);
+        assert_close_with_tolerance(&g.get(&grid).array(), &[grad_grid, grad_grid], 1e-4);
+    }
+
+    #[test]
+    fn test_grid_sample_identity() {
+        let dev: TestDevice = Default::default();
+        let img: Tensor<Rank3<2, 3, 4>, TestDtype, _> = dev.sample_normal();
+        let ys = [-1.0, 0.0, 1.0];
+        let xs = [-1.0, -1.0 / 3.0, 1.0 / 3.0, 1.0];
+        let grid = dev.tensor(ys.map(|y| xs.map(|x| [x, y])));
+        let r = img.clone().grid_sample(grid, true);
+        assert_close(&r.array(), &img.array());
+    }
+}
@@ -0,0 +1,90 @@
+use crate::shapes::{Dtype, Shape};
+use crate::tensor::cpu::{Cpu, LendingIterator, StridedArray};
+
+use super::GroupNormKernel;
+
+use num_traits::Float;
+use std::{sync::Arc, vec::Vec};
+
+/// The mean and reciprocal standard deviation of a group.
+fn group_stats<E: Dtype + Float>(group: &[E], epsilon: E) -> (E, E) {
+    let n = E::from_usize(group.len()).unwrap();
+    let mean = group.iter().fold(E::zero(), |a, &x| a + x) / n;
+    let var = group
+        .iter()
+        .fold(E::zero(), |a, &x| a + (x - mean) * (x - mean))
+        / n;
+    (mean, (var + epsilon).sqrt().recip())
+}
+
+impl<E: Dtype + Float> GroupNormKernel<E> for Cpu {
+    fn forward<S: Shape>(
+        &self,
+        groups: usize,
+        epsilon: E,
+        inp: &Self::Storage<S, E>,
+    ) -> Result<Self::Storage<S, E>, Self::Err> {
+        let mut out: StridedArray<S, E> = StridedArray::new(inp.shape)?;
+        let buf = Arc::make_mut(&mut out.data);
+        let mut inp_iter = inp.iter();
+        let mut i = 0;
+        while let Some(x) = inp_iter.next() {
+            buf[i] = *x;
+            i += 1;
+        }
+
+        let group_len = buf.len() / groups;
+        for group in buf.chunks_mut(group_len) {
+            let (mean, rstd) = group_stats(group, epsilon);
+            for x in group.iter_mut() {
+                *x = (*x - mean) * rstd;
+            }
+        }
+        Ok(out)
+    }
+
+    fn backward<S: Shape>(
+        &self,
+        groups: usize,
+        epsilon: E,
+        inp: &Self::Storage<S, E>,
+        grad_inp: &mut Self::Storage<S, E>,
+        out: &Self::Storage<S, E>,
+        grad_out: &Self::Storage<S, E>,
+    ) -> Result<(), Self::Err> {
+        let numel = inp.shape.num_elements();
+        let group_len = numel / groups;
+        let n = E::from_usize(group_len).unwrap();
+
+        let mut x = Vec::with_capacity(numel);
+        let mut inp_iter = inp.iter();
+        while let Some(v) = inp_iter.next() {
+            x.push(*v);
+        }
+        // out & grad_out are both contiguous
+        let (y, dy) = (out.data.as_slice(), grad_out.data.as_slice());
+
+        let mut dx = Vec::with_capacity(numel);
+        for g in 0..groups {
+            let r = g * group_len..(g + 1) * group_len;
+            let (_, rstd) = group_stats(&x[r.clone()], epsilon);
+            let mean_dy = dy[r.clone()].iter().fold(E::zero(), |a, &d| a + d) / n;
+            let mean_dy_y = dy[r.clone()]
+                .iter()
+                .zip(y[r.clone()].iter())
+                .fold(E::zero(), |a, (&d, &y)| a + d * y)
+                / n;
+            for (&d, &y) in dy[r.clone()].iter().zip(y[r].iter()) {
+                dx.push(rstd * (d - mean_dy - y * mean_dy_y));
+            }
+        }
+
+        let mut grad_inp_iter = grad_inp.iter_mut();
+        let mut i = 0;
+        while let Some(g) = grad_inp_iter.next() {
+            *g += dx[i];
+            i += 1;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,106 @@
+use crate::{
+    shapes::*,
+    tensor::cuda::{Cuda, CudaArray},
+};
+use cudarc::driver::{AsKernelParam, LaunchAsync, LaunchConfig};
+use std::sync::Arc;
+
+const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/group_norm.ptx"));
+
+/// Has to match `BLOCK_SIZE` in group_norm.cu
+const BLOCK_SIZE: u32 = 256;
+
+trait HasCudaKernel<E> {
+    const MOD: &'static str;
+    const FNS: &'static [&'static str];
+}
+
+impl HasCudaKernel<f32> for Cuda {
+    const MOD: &'static str = "group_norm_f32";
+    const FNS: &'static [&'static str] = &["group_norm_fwd_f32", "group_norm_bwd_f32"];
+}
+
+impl HasCudaKernel<f64> for Cuda {
+    const MOD: &'static str = "group_norm_f64";
+    const FNS: &'static [&'static str] = &["group_norm_fwd_f64", "group_norm_bwd_f64"];
+}
+
+/// One block per group, so that the statistics of a group can be reduced in shared memory.
+fn launch_cfg(groups: usize) -> LaunchConfig {
+    LaunchConfig {
+        grid_dim: (groups as u32, 1, 1),
+        block_dim: (BLOCK_SIZE, 1, 1),
+        shared_mem_bytes: 0,
+    }
+}
+
+impl<E: Dtype + AsKernelParam> super::GroupNormKernel<E> for Cuda
+where
+    Self: HasCudaKernel<E>,
+{
+    fn forward<S: Shape>(
+        &self,
+        groups: usize,
+        epsilon: E,
+        inp: &Self::Storage<S, E>,
+    ) -> Result<Self::Storage<S, E>, Self::Err> {
+        if !self.dev.has_func(Self::MOD, Self::FNS[0]) {
+            self.dev.load_ptx(PTX_SRC.into(), Self::MOD, Self::FNS)?;
+        }
+
+        let shape = inp.shape;
+        let numel = shape.num_elements();
+        let mut storage = unsafe { self.dev.alloc_async::<E>(numel) }?;
+
+        let dims = self.dev.take_async(shape.concrete().into())?;
+        let inp_strides = self.dev.take_async(inp.strides.into())?;
+
+        let fwd_fn = self.dev.get_func(Self::MOD, Self::FNS[0]).unwrap();
+        let params = (
+            numel / groups,    // const size_t group_len,
+            epsilon,           // const T epsilon,
+            S::NUM_DIMS,       // const size_t num_dims,
+            &dims,             // const size_t *dims,
+            &inp_strides,      // const size_t *inp_strides,
+            inp.data.as_ref(), // const T *inp,
+            &mut storage,      // T *out
+        );
+        unsafe { fwd_fn.launch_async(launch_cfg(groups), params) }?;
+
+        Ok(CudaArray {
+            data: Arc::new(storage),
+            shape,
+            strides: shape.strides(),
+        })
+    }
+
+    fn backward<S: Shape>(
+        &self,
+        groups: usize,
+        epsilon: E,
+        inp: &Self::Storage<S, E>,
+        grad_inp: &mut Self::Storage<S, E>,
+        out: &Self::Storage<S, E>,
+        grad_out: &Self::Storage<S, E>,
+    ) -> Result<(), Self::Err> {
+        let numel = inp.shape.num_elements();
+
+        let dims = self.dev.take_async(inp.shape.concrete().into())?;
+        let inp_strides = self.dev.take_async(inp.strides.into())?;
+
+        let bwd_fn = self.dev.get_func(Self::MOD, Self::FNS[1]).unwrap();
+        let params = (
+            numel / groups,                    // const size_t group_len,
+            epsilon,                           // const T epsilon,
+            S::NUM_DIMS,                       // const size_t num_dims,
+            &dims,                             // const size_t *dims,
+            &inp_strides,                      // const size_t *inp_strides,
+            inp.data.as_ref(),                 // const T *inp,
+            Arc::make_mut(&mut grad_inp.data), // T *grad_inp,
+            out.data.as_ref(),                 // const T *out,
+            grad_out.data.as_ref(),            // const T *grad_out
+        );
+        unsafe { bwd_fn.launch_async(launch_cfg(groups), params) }?;
+        Ok(())
+    }
+}
@@ -0,0 +1,119 @@
+#include "cuda_utils.cuh"
+
+// must be a power of two, as it's used for the tree reductions below
+#define BLOCK_SIZE 256
+
+// The mean and reciprocal standard deviation of the group starting at logical index `start`.
+template<typename T>
+__device__ void group_stats(
+    const size_t start,
+    const size_t group_len,
+    const T epsilon,
+    const size_t num_dims,
+    const size_t *dims,
+    const size_t *inp_strides,
+    const T *inp,
+    T *buf,
+    T *mean,
+    T *rstd
+) {
+    T sum = 0.0;
+    for (size_t k = threadIdx.x; k < group_len; k += blockDim.x) {
+        sum += inp[get_strided_index(start + k, num_dims, dims, inp_strides)];
+    }
+    *mean = block_sum(sum, buf) / group_len;
+
+    T sum_sq = 0.0;
+    for (size_t k = threadIdx.x; k < group_len; k += blockDim.x) {
+        T x = inp[get_strided_index(start + k, num_dims, dims, inp_strides)] - *mean;
+        sum_sq += x * x;
+    }
+    T var = block_sum(sum_sq, buf) / group_len;
+    *rstd = 1.0 / sqrtg(var + epsilon);
+}
+
+// One block per group. `out` is contiguous.
+template<typename T>
+__device__ void group_norm_fwd(
+    const size_t group_len,
+    const T epsilon,
+    const size_t num_dims,
+    const size_t *dims,
+    const size_t *inp_strides,
+    const T *inp,
+    T *out
+) {
+    __shared__ T buf[BLOCK_SIZE];
+    size_t start = blockIdx.x * group_len;
+    T mean, rstd;
+    group_stats(start, group_len, epsilon, num_dims, dims, inp_strides, inp, buf, &mean, &rstd);
+
+    for (size_t k = threadIdx.x; k < group_len; k += blockDim.x) {
+        size_t i = start + k;
+        out[i] = (inp[get_strided_index(i, num_dims, dims, inp_strides)] - mean) * rstd;
+    }
+}
+
+// One block per group. `out` & `grad_out` are contiguous, and `grad_inp` has the strides of `inp`.
+template<typename T>
+__device__ void group_norm_bwd(
+    const size_t group_len,
+    const T epsilon,
+    const size_t num_dims,
+    const size_t *dims,
+    const size_t *inp_strides,
+    const T *inp,
+    T *grad_inp,
+    const T *out,
+    const T *grad_out
+) {
+    __shared__ T buf[BLOCK_SIZE];
+    size_t start = blockIdx.x * group_len;
+    T mean, rstd;
+    group_stats(start, group_len, epsilon, num_dims, dims, inp_strides, inp, buf, &mean, &rstd);
+
+    T sum_dy = 0.0;
+    T sum_dy_y = 0.0;
+    for (size_t k = threadIdx.x; k < group_len; k += blockDim.x) {
+        T dy = grad_out[start + k];
+        sum_dy += dy;
+        sum_dy_y += dy * out[start + k];
+    }
+    T mean_dy = block_sum(sum_dy, buf) / group_len;
+    T mean_dy_y = block_sum(sum_dy_y, buf) / group_len;
+
+    for (size_t k = threadIdx.x; k < group_len; k += blockDim.x) {
+        size_t i = start + k;
+        T dx = rstd * (grad_out[i] - mean_dy - out[i] * mean_dy_y);
+        atomicAdd(grad_inp + get_strided_index(i, num_dims, dims, inp_strides), dx);
+    }
+}
+
+#define GROUP_NORM_OP(TYPENAME, FWD, BWD) \
+extern "C" __global__ void FWD( \
+    const size_t group_len, \
+    const TYPENAME epsilon, \
+    const size_t num_dims, \
+    const size_t *dims, \
+    const size_t *inp_strides, \
+    const TYPENAME *inp, \
+    TYPENAME *out \
+) { \
+    group_norm_fwd(group_len, epsilon, num_dims, dims, inp_strides, inp, out); \
+} \
+extern "C" __global__ void BWD( \
+    const size_t group_len, \
+    const TYPENAME epsilon, \
+    const size_t num_dims, \
+    const size_t *dims, \
+    const size_t *inp_strides, \
+    const TYPENAME *inp, \
+    TYPENAME *grad_inp, \
+    const TYPENAME *out, \
+    const TYPENAME *grad_out \
+) { \
+    group_norm_bwd(group_len, epsilon, num_dims, dims, inp_strides, inp, grad_inp, out, grad_out); \
+}
+
+GROUP_NORM_OP(float, group_norm_fwd_f32, group_norm_bwd_f32);
+GROUP_NORM_OP(double, group_norm_fwd_f64, group_norm_bwd_f64);
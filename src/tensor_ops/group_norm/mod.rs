@@ -0,0 +1,215 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{gradients::Tape, shapes::*, tensor::*};
+
+pub trait GroupNormKernel<E: Dtype>: DeviceStorage {
+    /// Normalizes each of the `groups` equally sized chunks of `inp` (in row major order)
+    /// to zero mean and unit variance.
+    fn forward<S: Shape>(
+        &self,
+        groups: usize,
+        epsilon: E,
+        inp: &Self::Storage<S, E>,
+    ) -> Result<Self::Storage<S, E>, Self::Err>;
+
+    /// Recomputes the statistics of each group from `inp`, so nothing but `out`
+    /// has to be kept around from the forward pass.
+    fn backward<S: Shape>(
+        &self,
+        groups: usize,
+        epsilon: E,
+        inp: &Self::Storage<S, E>,
+        grad_inp: &mut Self::Storage<S, E>,
+        out: &Self::Storage<S, E>,
+        grad_out: &Self::Storage<S, E>,
+    ) -> Result<(), Self::Err>;
+}
+
+impl<S: Shape, E: Dtype, D: GroupNormKernel<E>, T: Tape<D>> Tensor<S, E, D, T> {
+    /// Group normalization of a `(N, C, ...)` tensor without the affine transform. The `C`
+    /// channels of every sample are split into `num_groups` groups of consecutive channels,
+    /// and each group is normalized to zero mean and unit (biased) variance.
+    ///
+    /// The statistics of each group are computed in a single fused kernel.
+    ///
+    /// **Pytorch equivalent**: `torch.nn.functional.group_norm(t, num_groups, eps=epsilon)`
+    ///
+    /// Examples:
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// // a single sample with 4 channels of length 2, split into 2 groups
+    /// let t = dev.tensor([[[1.0, 3.0], [1.0, 3.0], [3.0, 5.0], [5.0, 3.0]]]);
+    /// let r = t.group_norm(2, 0.0);
+    /// assert_eq!(r.array(), [[[-1.0, 1.0], [-1.0, 1.0], [-1.0, 1.0], [1.0, -1.0]]]);
+    /// ```
+    pub fn group_norm(self, num_groups: usize, epsilon: E) -> Self {
+        self.try_group_norm(num_groups, epsilon).unwrap()
+    }
+
+    /// Fallible version of [Tensor::group_norm]
+    pub fn try_group_norm(self, num_groups: usize, epsilon: E) -> Result<Self, D::Err> {
+        assert!(
+            S::NUM_DIMS >= 2,
+            "group_norm expects a (N, C, ...) tensor with at least 2 dimensions"
+        );
+        let [n, c] = {
+            let dims = self.shape().concrete();
+            [dims[0], dims[1]]
+        };
+        assert!(
+            num_groups > 0 && c % num_groups == 0,
+            "{c} channels can't be split into {num_groups} groups"
+        );
+        self.try_normalize_groups(n * num_groups, epsilon)
+    }
+
+    /// Layer normalization over the trailing axes `Ax` without the affine transform. Every
+    /// sub-tensor spanned by `Ax` is normalized to zero mean and unit (biased) variance, which is
+    /// the same as [Tensor::normalize] but uses the fused kernel of [Tensor::group_norm].
+    ///
+    /// **Pytorch equivalent**: `torch.nn.functional.layer_norm(t, normalized_shape, eps=epsilon)`,
+    /// where `normalized_shape` are the dimensions of `Ax`.
+    ///
+    /// Examples:
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t: Tensor<Rank3<2, 2, 2>, f32, _> =
+    ///     dev.tensor([[[1.0, 1.0], [3.0, 3.0]], [[0.0, 4.0], [4.0, 0.0]]]);
+    /// let r = t.layer_norm::<Axes2<1, 2>>(0.0);
+    /// assert_eq!(r.array(), [[[-1.0, -1.0], [1.0, 1.0]], [[-1.0, 1.0], [1.0, -1.0]]]);
+    /// ```
+    pub fn layer_norm<Ax: Axes>(self, epsilon: E) -> Self
+    where
+        S: ReduceShape<Ax>,
+    {
+        self.try_layer_norm::<Ax>(epsilon).unwrap()
+    }
+
+    /// Fallible version of [Tensor::layer_norm]
+    pub fn try_layer_norm<Ax: Axes>(self, epsilon: E) -> Result<Self, D::Err>
+    where
+        S: ReduceShape<Ax>,
+    {
+        let start = S::NUM_DIMS - Ax::as_array().into_iter().count();
+        assert!(
+            Ax::as_array()
+                .into_iter()
+                .enumerate()
+                .all(|(i, ax)| ax as usize == start + i),
+            "layer_norm can only normalize over the trailing axes"
+        );
+        let inner: usize = self.shape().concrete().into_iter().skip(start).product();
+        let groups = self.shape().num_elements() / inner;
+        self.try_normalize_groups(groups, epsilon)
+    }
+
+    /// Normalizes each of the `groups` equally sized chunks of the tensor, in row major order.
+    pub(crate) fn try_normalize_groups(self, groups: usize, epsilon: E) -> Result<Self, D::Err> {
+        assert_eq!(self.shape().num_elements() % groups, 0);
+        let (inp, mut tape) = self.split_tape();
+        let out = inp
+            .device
+            .upgrade(inp.device.forward(groups, epsilon, &inp.storage)?);
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&inp)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+            inp.device.backward(
+                groups,
+                epsilon,
+                &inp.storage,
+                grad_inp,
+                &phantom_out.storage,
+                grad_out,
+            )
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{shapes::*, tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_group_norm_matches_normalize() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank4<2, 4, 3, 2>, TestDtype, _> = dev.sample_normal();
+        let r = x.trace().group_norm(2, 1e-5);
+        let r2 = x
+            .trace()
+            .reshape_like(&(Const::<2>, Const::<2>, Const::<12>))
+            .normalize::<Axis<2>>(1e-5);
+        assert_close(&r.as_vec(), &r2.as_vec());
+
+        let w: Tensor<Rank4<2, 4, 3, 2>, TestDtype, _> = dev.sample_normal();
+        let g = (r * w.clone()).sum().backward();
+        let w2 = w.reshape_like(&(Const::<2>, Const::<2>, Const::<12>));
+        let g2 = (r2 * w2).sum().backward();
+        assert_close(&g.get(&x).as_vec(), &g2.get(&x).as_vec());
+    }
+
+    #[test]
+    fn test_group_norm_permuted_input() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank3<3, 4, 2>, TestDtype, _> = dev.sample_normal();
+        let r = x.trace().permute::<Rank3<2, 4, 3>, _>().group_norm(4, 1e-5);
+        let r2 = x
+            .trace()
+            .permute::<Rank3<2, 4, 3>, _>()
+            .normalize::<Axis<2>>(1e-5);
+        assert_close(&r.array(), &r2.array());
+        let g = r.exp().sum().backward();
+        let g2 = r2.exp().sum().backward();
+        assert_close_with_tolerance(&g.get(&x).array(), &g2.get(&x).array(), 1e-5);
+    }
+
+    #[test]
+    fn test_group_norm_single_group() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank2<2, 3>, TestDtype, _> = dev.tensor([[1.0, 2.0, 3.0], [0.0, 0.0, 0.0]]);
+        let r = x.trace().group_norm(1, 1e-5);
+        assert_close(&r.array(), &[[-1.2247357, 0.0, 1.2247357], [0.0, 0.0, 0.0]]);
+        // the gradient of the sum of a normalized group is always 0
+        let g = r.sum().backward();
+        assert_close(&g.get(&x).array(), &[[0.0; 3]; 2]);
+    }
+
+    #[test]
+    fn test_layer_norm_matches_normalize() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank4<2, 3, 2, 4>, TestDtype, _> = dev.sample_normal();
+        let r = x.trace().layer_norm::<Axes3<1, 2, 3>>(1e-5);
+        let r2 = x.trace().normalize::<Axes3<1, 2, 3>>(1e-5);
+        assert_close(&r.array(), &r2.array());
+        let g = r.exp().sum().backward();
+        let g2 = r2.exp().sum().backward();
+        assert_close_with_tolerance(&g.get(&x).array(), &g2.get(&x).array(), 1e-5);
+
+        let r = x.trace().layer_norm::<Axes2<2, 3>>(1e-5);
+        let r2 = x.trace().normalize::<Axes2<2, 3>>(1e-5);
+        assert_close(&r.array(), &r2.array());
+    }
+
+    #[test]
+    #[should_panic = "trailing axes"]
+    fn test_layer_norm_leading_axes() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank3<2, 3, 2>, TestDtype, _> = dev.zeros();
+        let _ = x.layer_norm::<Axes2<0, 1>>(1e-5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_group_norm_uneven_groups() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank3<2, 3, 2>, TestDtype, _> = dev.zeros();
+        let _ = x.group_norm(2, 1e-5);
+    }
+}
@@ -0,0 +1,54 @@
+use crate::tensor_ops::cpu_kernels::UnaryDerivative;
+use num_traits::Float;
+
+impl<F: Float> UnaryDerivative<F> for super::HardSwishKernelOp {
+    #[inline(always)]
+    fn f(&self, &x: &F) -> F {
+        let three = F::from(3.0).unwrap();
+        let six = F::from(6.0).unwrap();
+        x * (x + three).max(F::zero()).min(six) / six
+    }
+    #[inline(always)]
+    fn df(&self, &x: &F) -> F {
+        let three = F::from(3.0).unwrap();
+        if x < -three {
+            F::zero()
+        } else if x <= three {
+            (x + x + three) / F::from(6.0).unwrap()
+        } else {
+            F::one()
+        }
+    }
+}
+
+impl<F: Float> UnaryDerivative<F> for super::HardSigmoidKernelOp {
+    #[inline(always)]
+    fn f(&self, &x: &F) -> F {
+        let six = F::from(6.0).unwrap();
+        (x + F::from(3.0).unwrap()).max(F::zero()).min(six) / six
+    }
+    #[inline(always)]
+    fn df(&self, &x: &F) -> F {
+        let three = F::from(3.0).unwrap();
+        if -three < x && x < three {
+            F::from(6.0).unwrap().recip()
+        } else {
+            F::zero()
+        }
+    }
+}
+
+impl<F: Float> UnaryDerivative<F> for super::HardTanhKernelOp {
+    #[inline(always)]
+    fn f(&self, &x: &F) -> F {
+        x.max(-F::one()).min(F::one())
+    }
+    #[inline(always)]
+    fn df(&self, &x: &F) -> F {
+        if -F::one() < x && x < F::one() {
+            F::one()
+        } else {
+            F::zero()
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use super::{HardSigmoidKernelOp, HardSwishKernelOp, HardTanhKernelOp};
+use crate::tensor_ops::cuda_kernels::cuda_unary;
+
+unsafe impl cudarc::driver::AsKernelParam for HardSwishKernelOp {}
+unsafe impl cudarc::driver::AsKernelParam for HardSigmoidKernelOp {}
+unsafe impl cudarc::driver::AsKernelParam for HardTanhKernelOp {}
+
+const PTX: &str = include_str!(concat!(env!("OUT_DIR"), "/hard_activations.ptx"));
+
+cuda_unary!(
+    HardSwishKernelOp,
+    f32,
+    PTX,
+    "hardswish_fwd_f32",
+    "hardswish_bwd_f32"
+);
+cuda_unary!(
+    HardSwishKernelOp,
+    f64,
+    PTX,
+    "hardswish_fwd_f64",
+    "hardswish_bwd_f64"
+);
+cuda_unary!(
+    HardSigmoidKernelOp,
+    f32,
+    PTX,
+    "hardsigmoid_fwd_f32",
+    "hardsigmoid_bwd_f32"
+);
+cuda_unary!(
+    HardSigmoidKernelOp,
+    f64,
+    PTX,
+    "hardsigmoid_fwd_f64",
+    "hardsigmoid_bwd_f64"
+);
+cuda_unary!(
+    HardTanhKernelOp,
+    f32,
+    PTX,
+    "hardtanh_fwd_f32",
+    "hardtanh_bwd_f32"
+);
+cuda_unary!(
+    HardTanhKernelOp,
+    f64,
+    PTX,
+    "hardtanh_fwd_f64",
+    "hardtanh_bwd_f64"
+);
@@ -0,0 +1,46 @@
+#include "unary_op_macros.cuh"
+#include "cuda_utils.cuh"
+
+struct HardSwishKernelOp {};
+struct HardSigmoidKernelOp {};
+struct HardTanhKernelOp {};
+
+template<typename T>
+__device__ T hardsigmoid_fwd(T x) {
+    return ming(maxg(x + (T)3.0, (T)0.0), (T)6.0) / 6.0;
+}
+
+template<typename T>
+__device__ T hardswish_bwd(T x) {
+    if (x < -3.0) {
+        return 0.0;
+    } else if (x <= 3.0) {
+        return (x + x + 3.0) / 6.0;
+    } else {
+        return 1.0;
+    }
+}
+
+UNARY_OP(float, hardswish_fwd_f32, hardswish_bwd_f32, HardSwishKernelOp,
+    x * hardsigmoid_fwd(x),
+    hardswish_bwd(x))
+
+UNARY_OP(double, hardswish_fwd_f64, hardswish_bwd_f64, HardSwishKernelOp,
+    x * hardsigmoid_fwd(x),
+    hardswish_bwd(x))
+
+UNARY_OP(float, hardsigmoid_fwd_f32, hardsigmoid_bwd_f32, HardSigmoidKernelOp,
+    hardsigmoid_fwd(x),
+    (x > -3.0 && x < 3.0) ? 1.0 / 6.0 : 0.0)
+
+UNARY_OP(double, hardsigmoid_fwd_f64, hardsigmoid_bwd_f64, HardSigmoidKernelOp,
+    hardsigmoid_fwd(x),
+    (x > -3.0 && x < 3.0) ? 1.0 / 6.0 : 0.0)
+
+UNARY_OP(float, hardtanh_fwd_f32, hardtanh_bwd_f32, HardTanhKernelOp,
+    ming(maxg(x, -1.0f), 1.0f),
+    (x > -1.0 && x < 1.0) ? 1.0 : 0.0)
+
+UNARY_OP(double, hardtanh_fwd_f64, hardtanh_bwd_f64, HardTanhKernelOp,
+    ming(maxg(x, -1.0), 1.0),
+    (x > -1.0 && x < 1.0) ? 1.0 : 0.0)
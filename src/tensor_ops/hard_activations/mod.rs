@@ -0,0 +1,163 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use super::ops::{try_unary_op, UnaryKernel};
+use crate::{gradients::Tape, shapes::*, tensor::Tensor};
+
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct HardSwishKernelOp;
+
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct HardSigmoidKernelOp;
+
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct HardTanhKernelOp;
+
+/// [Hard Swish](https://arxiv.org/abs/1905.02244) `t * relu6(t + 3) / 6`.
+///
+/// It's derivative is `0` below `-3`, `(2t + 3) / 6` on `[-3, 3]`, and `1` above `3`.
+///
+/// **Pytorch equivalent**: `torch.nn.functional.hardswish(t)`
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([-4.0, -1.5, 0.0, 4.0]);
+/// let r = t.hardswish();
+/// assert_eq!(r.array(), [-0.0, -0.375, 0.0, 4.0]);
+/// ```
+pub fn hardswish<S: Shape, E: Dtype, D: UnaryKernel<HardSwishKernelOp, E>, T: Tape<D>>(
+    t: Tensor<S, E, D, T>,
+) -> Tensor<S, E, D, T> {
+    t.hardswish()
+}
+
+/// [Hard Sigmoid](https://arxiv.org/abs/1905.02244) `relu6(t + 3) / 6`, a piecewise linear
+/// approximation of [sigmoid()](crate::tensor_ops::sigmoid).
+///
+/// It's derivative is `1 / 6` on `(-3, 3)`, and `0` elsewhere.
+///
+/// **Pytorch equivalent**: `torch.nn.functional.hardsigmoid(t)`
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([-4.0, -1.5, 0.0, 4.0]);
+/// let r = t.hardsigmoid();
+/// assert_eq!(r.array(), [0.0, 0.25, 0.5, 1.0]);
+/// ```
+pub fn hardsigmoid<S: Shape, E: Dtype, D: UnaryKernel<HardSigmoidKernelOp, E>, T: Tape<D>>(
+    t: Tensor<S, E, D, T>,
+) -> Tensor<S, E, D, T> {
+    t.hardsigmoid()
+}
+
+/// Hard Tanh, which clamps `t` to `[-1, 1]`. Use [clamp()](crate::tensor_ops::clamp)
+/// for other ranges.
+///
+/// It's derivative is `1` on `(-1, 1)`, and `0` elsewhere.
+///
+/// **Pytorch equivalent**: `torch.nn.functional.hardtanh(t)`
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([-4.0, -0.5, 0.0, 4.0]);
+/// let r = t.hardtanh();
+/// assert_eq!(r.array(), [-1.0, -0.5, 0.0, 1.0]);
+/// ```
+pub fn hardtanh<S: Shape, E: Dtype, D: UnaryKernel<HardTanhKernelOp, E>, T: Tape<D>>(
+    t: Tensor<S, E, D, T>,
+) -> Tensor<S, E, D, T> {
+    t.hardtanh()
+}
+
+impl<S: Shape, E: Dtype, D: UnaryKernel<HardSwishKernelOp, E>, T: Tape<D>> Tensor<S, E, D, T> {
+    /// See [hardswish]
+    pub fn hardswish(self) -> Self {
+        self.try_hardswish().unwrap()
+    }
+    /// See [hardswish]
+    pub fn try_hardswish(self) -> Result<Self, D::Err> {
+        try_unary_op(HardSwishKernelOp, self)
+    }
+}
+
+impl<S: Shape, E: Dtype, D: UnaryKernel<HardSigmoidKernelOp, E>, T: Tape<D>> Tensor<S, E, D, T> {
+    /// See [hardsigmoid]
+    pub fn hardsigmoid(self) -> Self {
+        self.try_hardsigmoid().unwrap()
+    }
+    /// See [hardsigmoid]
+    pub fn try_hardsigmoid(self) -> Result<Self, D::Err> {
+        try_unary_op(HardSigmoidKernelOp, self)
+    }
+}
+
+impl<S: Shape, E: Dtype, D: UnaryKernel<HardTanhKernelOp, E>, T: Tape<D>> Tensor<S, E, D, T> {
+    /// See [hardtanh]
+    pub fn hardtanh(self) -> Self {
+        self.try_hardtanh().unwrap()
+    }
+    /// See [hardtanh]
+    pub fn try_hardtanh(self) -> Result<Self, D::Err> {
+        try_unary_op(HardTanhKernelOp, self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_hardswish() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([-4.0, -3.0, -1.5, 0.0, 1.0, 3.0, 4.0]);
+        let r = x.trace().hardswish();
+        assert_close(&r.array(), &[0.0, 0.0, -0.375, 0.0, 0.6666667, 3.0, 4.0]);
+        let g = r.mean().backward();
+        assert_close(
+            &g.get(&x).array(),
+            &[
+                0.0,
+                -0.071428575,
+                0.0,
+                0.071428575,
+                0.11904762,
+                0.21428572,
+                0.14285715,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_hardsigmoid() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([-4.0, -3.0, -1.5, 0.0, 1.0, 3.0, 4.0]);
+        let r = x.trace().hardsigmoid();
+        assert_close(&r.array(), &[0.0, 0.0, 0.25, 0.5, 0.6666667, 1.0, 1.0]);
+        let g = r.mean().backward();
+        assert_close(
+            &g.get(&x).array(),
+            &[0.0, 0.0, 0.023809524, 0.023809524, 0.023809524, 0.0, 0.0],
+        );
+    }
+
+    #[test]
+    fn test_hardtanh() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([-2.0, -1.0, -0.5, 0.0, 0.5, 1.0, 2.0]);
+        let r = x.trace().hardtanh();
+        assert_eq!(r.array(), [-1.0, -1.0, -0.5, 0.0, 0.5, 1.0, 1.0]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&x).array(), [0.0, 0.0, 1.0, 1.0, 1.0, 0.0, 0.0]);
+    }
+}
@@ -0,0 +1,51 @@
+use crate::{
+    shapes::{Dtype, Shape},
+    tensor::cpu::{Cpu, LendingIterator, StridedArray},
+};
+
+use num_traits::Float;
+use std::sync::Arc;
+
+impl<E: Dtype + Float> super::HistogramKernel<E> for Cpu {
+    fn histogram<S: Shape>(
+        &self,
+        bins: usize,
+        min: E,
+        max: E,
+        inp: &Self::Storage<S, E>,
+    ) -> Result<Self::Storage<(usize,), E>, Self::Err> {
+        let mut out = StridedArray::new((bins,))?;
+        if inp.shape.num_elements() == 0 {
+            return Ok(out);
+        }
+        let scale = E::from_usize(bins).unwrap() / (max - min);
+        let out_buf = Arc::make_mut(&mut out.data);
+        let mut inp_iter = inp.iter();
+        while let Some(&x) = inp_iter.next() {
+            if min <= x && x <= max {
+                let b = ((x - min) * scale).to_usize().unwrap().min(bins - 1);
+                out_buf[b] += E::ONE;
+            }
+        }
+        Ok(out)
+    }
+}
+
+impl super::BincountKernel for Cpu {
+    fn bincount<S: Shape>(
+        &self,
+        inp: &Self::Storage<S, usize>,
+    ) -> Result<Self::Storage<(usize,), usize>, Self::Err> {
+        let len = inp.data.iter().max().map_or(0, |m| m + 1);
+        let mut out = StridedArray::new((len,))?;
+        if inp.shape.num_elements() == 0 {
+            return Ok(out);
+        }
+        let out_buf = Arc::make_mut(&mut out.data);
+        let mut inp_iter = inp.iter();
+        while let Some(&x) = inp_iter.next() {
+            out_buf[x] += 1;
+        }
+        Ok(out)
+    }
+}
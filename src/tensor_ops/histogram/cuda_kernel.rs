@@ -0,0 +1,122 @@
+use crate::{
+    shapes::*,
+    tensor::cuda::{Cuda, CudaArray},
+};
+use cudarc::driver::{AsKernelParam, LaunchAsync, LaunchConfig};
+use std::sync::Arc;
+
+const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/histogram.ptx"));
+const MOD: &str = "histogram";
+const FNS: &[&str] = &["histogram_f32", "histogram_f64", "bincount_len", "bincount"];
+
+trait HasCudaKernel<E> {
+    const FN: &'static str;
+}
+
+impl HasCudaKernel<f32> for Cuda {
+    const FN: &'static str = "histogram_f32";
+}
+
+impl HasCudaKernel<f64> for Cuda {
+    const FN: &'static str = "histogram_f64";
+}
+
+impl<E: Dtype + AsKernelParam> super::HistogramKernel<E> for Cuda
+where
+    Self: HasCudaKernel<E>,
+{
+    fn histogram<S: Shape>(
+        &self,
+        bins: usize,
+        min: E,
+        max: E,
+        inp: &Self::Storage<S, E>,
+    ) -> Result<Self::Storage<(usize,), E>, Self::Err> {
+        if !self.dev.has_func(MOD, Self::FN) {
+            self.dev.load_ptx(PTX_SRC.into(), MOD, FNS)?;
+        }
+
+        let shape = (bins,);
+        let mut storage = self.dev.alloc_zeros_async::<E>(bins)?;
+
+        let numel = inp.shape.num_elements();
+        if numel > 0 {
+            let dims = self.dev.take_async(inp.shape.concrete().into())?;
+            let strides = self.dev.take_async(inp.strides.into())?;
+            let fwd_fn = self.dev.get_func(MOD, Self::FN).unwrap();
+            let cfg = LaunchConfig::for_num_elems(numel as u32);
+            let params = (
+                numel,             // const size_t numel,
+                S::NUM_DIMS,       // const size_t num_dims,
+                &dims,             // const size_t *dims,
+                &strides,          // const size_t *strides,
+                bins,              // const size_t bins,
+                min,               // const T min,
+                max,               // const T max,
+                inp.data.as_ref(), // const T *inp,
+                &mut storage,      // T *out
+            );
+            unsafe { fwd_fn.launch_async(cfg, params) }?;
+        }
+
+        Ok(CudaArray {
+            data: Arc::new(storage),
+            shape,
+            strides: shape.strides(),
+        })
+    }
+}
+
+impl super::BincountKernel for Cuda {
+    fn bincount<S: Shape>(
+        &self,
+        inp: &Self::Storage<S, usize>,
+    ) -> Result<Self::Storage<(usize,), usize>, Self::Err> {
+        if !self.dev.has_func(MOD, FNS[2]) {
+            self.dev.load_ptx(PTX_SRC.into(), MOD, FNS)?;
+        }
+
+        // the length of the output depends on the largest value, so it has to be
+        // computed up front and copied back to the host
+        let mut len = [0usize];
+        if !inp.data.is_empty() {
+            let physical_numel = inp.data.len();
+            let mut len_buf = self.dev.alloc_zeros_async::<usize>(1)?;
+            let len_fn = self.dev.get_func(MOD, FNS[2]).unwrap();
+            let cfg = LaunchConfig::for_num_elems(physical_numel as u32);
+            let params = (
+                physical_numel,    // const size_t numel,
+                inp.data.as_ref(), // const size_t *inp,
+                &mut len_buf,      // size_t *len
+            );
+            unsafe { len_fn.launch_async(cfg, params) }?;
+            self.dev.sync_copy_from(&len_buf, &mut len)?;
+        }
+
+        let shape = (len[0],);
+        let mut storage = self.dev.alloc_zeros_async::<usize>(len[0])?;
+
+        let numel = inp.shape.num_elements();
+        if numel > 0 {
+            let dims = self.dev.take_async(inp.shape.concrete().into())?;
+            let strides = self.dev.take_async(inp.strides.into())?;
+            let fwd_fn = self.dev.get_func(MOD, FNS[3]).unwrap();
+            let cfg = LaunchConfig::for_num_elems(numel as u32);
+            let params = (
+                numel,             // const size_t numel,
+                S::NUM_DIMS,       // const size_t num_dims,
+                &dims,             // const size_t *dims,
+                &strides,          // const size_t *strides,
+                inp.data.as_ref(), // const size_t *inp,
+                &mut storage,      // size_t *out
+            );
+            unsafe { fwd_fn.launch_async(cfg, params) }?;
+        }
+
+        Ok(CudaArray {
+            data: Arc::new(storage),
+            shape,
+            strides: shape.strides(),
+        })
+    }
+}
@@ -0,0 +1,80 @@
+#include "cuda_utils.cuh"
+
+template<typename T>
+__device__ void histogram(
+    const size_t numel,
+    const size_t num_dims,
+    const size_t *dims,
+    const size_t *strides,
+    const size_t bins,
+    const T min,
+    const T max,
+    const T *inp,
+    T *out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    const T x = inp[get_strided_index(i, num_dims, dims, strides)];
+    if (!(min <= x && x <= max)) {
+        return;
+    }
+    size_t b = static_cast<size_t>((x - min) * static_cast<T>(bins) / (max - min));
+    if (b >= bins) {
+        b = bins - 1;
+    }
+    atomicAdd(out + b, static_cast<T>(1.0));
+}
+
+#define HISTOGRAM(TYPENAME, FN) \
+extern "C" __global__ void FN( \
+    const size_t numel, \
+    const size_t num_dims, \
+    const size_t *dims, \
+    const size_t *strides, \
+    const size_t bins, \
+    const TYPENAME min, \
+    const TYPENAME max, \
+    const TYPENAME *inp, \
+    TYPENAME *out \
+) { \
+    histogram(numel, num_dims, dims, strides, bins, min, max, inp, out); \
+}
+
+HISTOGRAM(float, histogram_f32);
+HISTOGRAM(double, histogram_f64);
+
+extern "C" __global__ void bincount_len(
+    const size_t numel,
+    const size_t *inp,
+    size_t *len
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    atomicMax(
+        reinterpret_cast<unsigned long long *>(len),
+        static_cast<unsigned long long>(inp[i] + 1)
+    );
+}
+
+extern "C" __global__ void bincount(
+    const size_t numel,
+    const size_t num_dims,
+    const size_t *dims,
+    const size_t *strides,
+    const size_t *inp,
+    size_t *out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    const size_t x = inp[get_strided_index(i, num_dims, dims, strides)];
+    atomicAdd(reinterpret_cast<unsigned long long *>(out + x), 1ull);
+}
@@ -0,0 +1,146 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{shapes::*, tensor::*};
+
+pub trait HistogramKernel<E: Dtype>: DeviceStorage {
+    /// Counts the values of `inp` that fall into each of `bins` equal width bins
+    /// spanning `[min, max]`.
+    fn histogram<S: Shape>(
+        &self,
+        bins: usize,
+        min: E,
+        max: E,
+        inp: &Self::Storage<S, E>,
+    ) -> Result<Self::Storage<(usize,), E>, Self::Err>;
+}
+
+pub trait BincountKernel: DeviceStorage {
+    /// Counts the occurrences of each value of `inp`. The output has `max(inp) + 1`
+    /// elements.
+    fn bincount<S: Shape>(
+        &self,
+        inp: &Self::Storage<S, usize>,
+    ) -> Result<Self::Storage<(usize,), usize>, Self::Err>;
+}
+
+impl<S: Shape, E: Dtype, D: HistogramKernel<E>, T> Tensor<S, E, D, T> {
+    /// Computes a histogram of all elements using `bins` equal width bins that span
+    /// `range`, which is inclusive on both ends. Elements outside of `range` and NaNs
+    /// are ignored. The result does not track gradients.
+    ///
+    /// **Pytorch equivalent**: `torch.histc(t, bins, min, max)`
+    ///
+    /// Panics if `bins` is `0` or the range is empty.
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t = dev.tensor([[0.0, 0.5, 1.0], [2.0, 3.0, 4.5]]);
+    /// let r = t.histogram(4, (0.0, 4.0));
+    /// assert_eq!(r.as_vec(), [2.0, 1.0, 1.0, 1.0]);
+    /// ```
+    pub fn histogram(self, bins: usize, range: (E, E)) -> Tensor<(usize,), E, D> {
+        self.try_histogram(bins, range).unwrap()
+    }
+
+    /// See [Tensor::histogram]
+    pub fn try_histogram(
+        self,
+        bins: usize,
+        range: (E, E),
+    ) -> Result<Tensor<(usize,), E, D>, D::Err> {
+        let (min, max) = range;
+        assert!(bins > 0, "histogram requires at least 1 bin");
+        assert!(min < max, "histogram range must be non empty");
+        let storage = self.device.histogram(bins, min, max, &self.storage)?;
+        Ok(self.device.upgrade(storage))
+    }
+}
+
+impl<S: Shape, D: BincountKernel, T> Tensor<S, usize, D, T> {
+    /// Counts how many times each value occurs. The output has `max + 1` elements,
+    /// where `max` is the largest value, or is empty if there are no elements.
+    ///
+    /// **Pytorch equivalent**: `torch.bincount(t.flatten())`
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t: Tensor<_, usize, _> = dev.tensor([[1, 3, 1], [0, 1, 3]]);
+    /// let r = t.bincount();
+    /// assert_eq!(r.as_vec(), [1, 3, 0, 2]);
+    /// ```
+    pub fn bincount(self) -> Tensor<(usize,), usize, D> {
+        self.try_bincount().unwrap()
+    }
+
+    /// See [Tensor::bincount]
+    pub fn try_bincount(self) -> Result<Tensor<(usize,), usize, D>, D::Err> {
+        let storage = self.device.bincount(&self.storage)?;
+        Ok(self.device.upgrade(storage))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{shapes::*, tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_histogram_bounds() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> =
+            dev.tensor([-1.0, 0.0, 0.99, 1.0, 1.5, 2.0, 2.01, TestDtype::NAN]);
+        let r = t.histogram(2, (0.0, 2.0));
+        assert_eq!(r.shape(), &(2,));
+        assert_eq!(r.as_vec(), [2.0, 3.0]);
+    }
+
+    #[test]
+    fn test_histogram_sums_to_numel() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank3<4, 5, 6>, TestDtype, _> = dev.sample_uniform();
+        let r = t.clone().histogram(7, (0.0, 1.0));
+        assert_eq!(r.as_vec().iter().sum::<TestDtype>(), 120.0);
+
+        let t_vec = t.as_vec();
+        for (b, &count) in r.as_vec().iter().enumerate() {
+            let lo = b as TestDtype / 7.0;
+            let hi = (b + 1) as TestDtype / 7.0;
+            let expected = t_vec.iter().filter(|&&x| lo <= x && x < hi).count();
+            assert_eq!(count, expected as TestDtype);
+        }
+    }
+
+    #[test]
+    fn test_histogram_broadcasted() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([0.25, 0.75]);
+        let r = t.broadcast::<Rank2<3, 2>, _>().histogram(2, (0.0, 1.0));
+        assert_eq!(r.as_vec(), [3.0, 3.0]);
+    }
+
+    #[test]
+    fn test_bincount() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, usize, _> = dev.tensor([4, 0, 4, 2, 4]);
+        assert_eq!(t.bincount().as_vec(), [1, 0, 1, 0, 3]);
+    }
+
+    #[test]
+    fn test_bincount_broadcasted() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, usize, _> = dev.tensor([1, 2]);
+        let r = t.broadcast::<Rank2<3, 2>, _>().bincount();
+        assert_eq!(r.as_vec(), [0, 3, 3]);
+    }
+
+    #[test]
+    fn test_bincount_empty() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<(usize,), usize, _> = dev.zeros_like(&(0,));
+        assert_eq!(t.bincount().shape(), &(0,));
+    }
+}
@@ -0,0 +1,89 @@
+use crate::{
+    shapes::{Dim, Dtype, Shape},
+    tensor::cpu::{Cpu, LendingIterator, StridedArray},
+};
+
+use std::vec::Vec;
+
+/// Converts an index into `src` to the index into `inp` it is added to.
+fn inp_index<S: Shape, Src: Shape, I: Dim>(
+    axis: usize,
+    idx: &StridedArray<(I,), usize>,
+    i_src: Src::Concrete,
+) -> S::Concrete {
+    let mut i_inp: S::Concrete = Default::default();
+    for j in 0..S::NUM_DIMS {
+        i_inp[j] = i_src[j];
+    }
+    i_inp[axis] = idx[[i_src[axis]]];
+    i_inp
+}
+
+impl<E: Dtype> super::IndexAddKernel<E> for Cpu {
+    fn forward<S: Shape, Src: Shape, I: Dim>(
+        &self,
+        op: super::IndexAddOp,
+        inp: &Self::Storage<S, E>,
+        idx: &Self::Storage<(I,), usize>,
+        src: &Self::Storage<Src, E>,
+    ) -> Result<Self::Storage<S, E>, Self::Err> {
+        let mut out: Self::Storage<S, E> = StridedArray::new(inp.shape)?;
+        if inp.shape.num_elements() == 0 {
+            return Ok(out);
+        }
+        let mut inp_iter = inp.iter();
+        let mut out_iter = out.iter_mut();
+        while let Some((o, i)) = out_iter.next().zip(inp_iter.next()) {
+            *o = *i;
+        }
+        if src.shape.num_elements() == 0 {
+            return Ok(out);
+        }
+        let mut src_iter = src.iter_with_index();
+        while let Some((x, i_src)) = src_iter.next() {
+            let i_out = inp_index::<S, Src, I>(op.axis, idx, i_src);
+            if op.copy {
+                out[i_out] = *x;
+            } else {
+                out[i_out] += *x;
+            }
+        }
+        Ok(out)
+    }
+
+    fn backward<S: Shape, Src: Shape, I: Dim>(
+        &self,
+        op: super::IndexAddOp,
+        grad_inp: &mut Self::Storage<S, E>,
+        idx: &Self::Storage<(I,), usize>,
+        grad_src: &mut Self::Storage<Src, E>,
+        grad_out: &Self::Storage<S, E>,
+    ) -> Result<(), Self::Err> {
+        if grad_inp.shape.num_elements() == 0 {
+            return Ok(());
+        }
+
+        // slices of the input that are overwritten by `src` don't receive gradients
+        let mut copied: Vec<bool> = alloc::vec![false; grad_inp.shape.concrete()[op.axis]];
+        if op.copy {
+            for &i in idx.data.iter() {
+                copied[i] = true;
+            }
+        }
+        let mut inp_iter = grad_inp.iter_mut_with_index();
+        while let Some((g, i_inp)) = inp_iter.next() {
+            if !copied[i_inp[op.axis]] {
+                *g += grad_out[i_inp];
+            }
+        }
+
+        if grad_src.shape.num_elements() == 0 {
+            return Ok(());
+        }
+        let mut src_iter = grad_src.iter_mut_with_index();
+        while let Some((g, i_src)) = src_iter.next() {
+            *g += grad_out[inp_index::<S, Src, I>(op.axis, idx, i_src)];
+        }
+        Ok(())
+    }
+}
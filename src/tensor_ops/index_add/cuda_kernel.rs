@@ -0,0 +1,162 @@
+use crate::{
+    shapes::*,
+    tensor::cuda::{Cuda, CudaArray},
+};
+use cudarc::driver::{AsKernelParam, CudaSlice, LaunchAsync, LaunchConfig};
+use std::sync::Arc;
+
+const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/index_add.ptx"));
+
+unsafe impl AsKernelParam for super::IndexAddOp {}
+
+trait HasCudaKernel<E> {
+    const MOD: &'static str;
+    const FNS: &'static [&'static str];
+}
+
+impl HasCudaKernel<f32> for Cuda {
+    const MOD: &'static str = "index_add_f32";
+    const FNS: &'static [&'static str] = &[
+        "index_add_copy_f32",
+        "index_add_fwd_f32",
+        "index_add_bwd_inp_f32",
+        "index_add_bwd_src_f32",
+        "index_add_copied",
+    ];
+}
+
+impl HasCudaKernel<f64> for Cuda {
+    const MOD: &'static str = "index_add_f64";
+    const FNS: &'static [&'static str] = &[
+        "index_add_copy_f64",
+        "index_add_fwd_f64",
+        "index_add_bwd_inp_f64",
+        "index_add_bwd_src_f64",
+        "index_add_copied",
+    ];
+}
+
+impl<E: Dtype + AsKernelParam> super::IndexAddKernel<E> for Cuda
+where
+    Self: HasCudaKernel<E>,
+{
+    fn forward<S: Shape, Src: Shape, I: Dim>(
+        &self,
+        op: super::IndexAddOp,
+        inp: &Self::Storage<S, E>,
+        idx: &Self::Storage<(I,), usize>,
+        src: &Self::Storage<Src, E>,
+    ) -> Result<Self::Storage<S, E>, Self::Err> {
+        if !self.dev.has_func(Self::MOD, Self::FNS[0]) {
+            self.dev.load_ptx(PTX_SRC.into(), Self::MOD, Self::FNS)?;
+        }
+
+        let shape = inp.shape;
+        let strides = shape.strides();
+        let numel = shape.num_elements();
+
+        let mut storage = unsafe { self.dev.alloc_async::<E>(numel) }?;
+
+        let dims: CudaSlice<usize> = self.dev.take_async(shape.concrete().into())?;
+        let inp_strides: CudaSlice<usize> = self.dev.take_async(inp.strides.into())?;
+        let copy_fn = self.dev.get_func(Self::MOD, Self::FNS[0]).unwrap();
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            numel,             // const size_t numel,
+            S::NUM_DIMS,       // const size_t num_dims,
+            &dims,             // const size_t *dims,
+            inp.data.as_ref(), // const float *inp,
+            &inp_strides,      // const size_t *inp_strides,
+            &mut storage,      // float *out
+        );
+        unsafe { copy_fn.launch_async(cfg, params) }?;
+
+        let src_numel = src.shape.num_elements();
+        let src_dims: CudaSlice<usize> = self.dev.take_async(src.shape.concrete().into())?;
+        let src_strides: CudaSlice<usize> = self.dev.take_async(src.strides.into())?;
+        let fwd_fn = self.dev.get_func(Self::MOD, Self::FNS[1]).unwrap();
+        let cfg = LaunchConfig::for_num_elems(src_numel as u32);
+        let params = (
+            op,                // const IndexAddOp op,
+            src_numel,         // const size_t numel,
+            Src::NUM_DIMS,     // const size_t num_dims,
+            &dims,             // const size_t *dims,
+            &src_dims,         // const size_t *src_dims,
+            idx.data.as_ref(), // const size_t *idx,
+            idx.strides[0],    // const size_t idx_stride,
+            src.data.as_ref(), // const float *src,
+            &src_strides,      // const size_t *src_strides,
+            &mut storage,      // float *out
+        );
+        unsafe { fwd_fn.launch_async(cfg, params) }?;
+
+        Ok(CudaArray {
+            data: Arc::new(storage),
+            shape,
+            strides,
+        })
+    }
+
+    fn backward<S: Shape, Src: Shape, I: Dim>(
+        &self,
+        op: super::IndexAddOp,
+        grad_inp: &mut Self::Storage<S, E>,
+        idx: &Self::Storage<(I,), usize>,
+        grad_src: &mut Self::Storage<Src, E>,
+        grad_out: &Self::Storage<S, E>,
+    ) -> Result<(), Self::Err> {
+        let dims = grad_inp.shape.concrete();
+
+        // slices of the input that are overwritten by `src` don't receive gradients
+        let mut copied = self.dev.alloc_zeros_async::<bool>(dims[op.axis])?;
+        if op.copy {
+            let idx_numel = idx.shape.num_elements();
+            let copied_fn = self.dev.get_func(Self::MOD, Self::FNS[4]).unwrap();
+            let cfg = LaunchConfig::for_num_elems(idx_numel as u32);
+            let params = (
+                idx_numel,         // const size_t numel,
+                idx.data.as_ref(), // const size_t *idx,
+                idx.strides[0],    // const size_t idx_stride,
+                &mut copied,       // bool *copied
+            );
+            unsafe { copied_fn.launch_async(cfg, params) }?;
+        }
+
+        let numel = grad_inp.shape.num_elements();
+        let dims: CudaSlice<usize> = self.dev.take_async(dims.into())?;
+        let inp_strides: CudaSlice<usize> = self.dev.take_async(grad_inp.strides.into())?;
+        let bwd_inp_fn = self.dev.get_func(Self::MOD, Self::FNS[2]).unwrap();
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            op,                                // const IndexAddOp op,
+            numel,                             // const size_t numel,
+            S::NUM_DIMS,                       // const size_t num_dims,
+            &dims,                             // const size_t *dims,
+            &copied,                           // const bool *copied,
+            Arc::make_mut(&mut grad_inp.data), // float *grad_inp,
+            &inp_strides,                      // const size_t *inp_strides,
+            grad_out.data.as_ref(),            // const float *grad_out
+        );
+        unsafe { bwd_inp_fn.launch_async(cfg, params) }?;
+
+        let src_numel = grad_src.shape.num_elements();
+        let src_dims: CudaSlice<usize> = self.dev.take_async(grad_src.shape.concrete().into())?;
+        let src_strides: CudaSlice<usize> = self.dev.take_async(grad_src.strides.into())?;
+        let bwd_src_fn = self.dev.get_func(Self::MOD, Self::FNS[3]).unwrap();
+        let cfg = LaunchConfig::for_num_elems(src_numel as u32);
+        let params = (
+            op,                                // const IndexAddOp op,
+            src_numel,                         // const size_t numel,
+            Src::NUM_DIMS,                     // const size_t num_dims,
+            &dims,                             // const size_t *dims,
+            &src_dims,                         // const size_t *src_dims,
+            idx.data.as_ref(),                 // const size_t *idx,
+            idx.strides[0],                    // const size_t idx_stride,
+            Arc::make_mut(&mut grad_src.data), // float *grad_src,
+            &src_strides,                      // const size_t *src_strides,
+            grad_out.data.as_ref(),            // const float *grad_out
+        );
+        unsafe { bwd_src_fn.launch_async(cfg, params) }?;
+        Ok(())
+    }
+}
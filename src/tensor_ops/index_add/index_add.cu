@@ -0,0 +1,191 @@
+#include "cuda_utils.cuh"
+
+struct IndexAddOp {
+    size_t axis;
+    bool copy;
+};
+
+// Converts the index of an element of `src` (in a contiguous layout) into the
+// index of the element of `out` (in a contiguous layout) it is added to.
+__device__ unsigned int get_out_index(
+    const IndexAddOp op,
+    const unsigned int src_i,
+    const size_t num_dims,
+    const size_t *dims,
+    const size_t *src_dims,
+    const size_t *idx,
+    const size_t idx_stride
+) {
+    size_t inner = 1;
+    for (size_t d = op.axis + 1; d < num_dims; d++) {
+        inner *= src_dims[d];
+    }
+    size_t i_inner = src_i % inner;
+    size_t i_axis = (src_i / inner) % src_dims[op.axis];
+    size_t i_outer = src_i / (inner * src_dims[op.axis]);
+    return (i_outer * dims[op.axis] + idx[i_axis * idx_stride]) * inner + i_inner;
+}
+
+template<typename T>
+__device__ void index_add_copy(
+    const size_t numel,
+    const size_t num_dims,
+    const size_t *dims,
+    const T *inp,
+    const size_t *inp_strides,
+    T *out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    out[i] = inp[get_strided_index(i, num_dims, dims, inp_strides)];
+}
+
+template<typename T>
+__device__ void index_add_fwd(
+    const IndexAddOp op,
+    const size_t numel,
+    const size_t num_dims,
+    const size_t *dims,
+    const size_t *src_dims,
+    const size_t *idx,
+    const size_t idx_stride,
+    const T *src,
+    const size_t *src_strides,
+    T *out
+) {
+    unsigned int src_i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (src_i >= numel) {
+        return;
+    }
+
+    unsigned int out_i = get_out_index(op, src_i, num_dims, dims, src_dims, idx, idx_stride);
+    T x = src[get_strided_index(src_i, num_dims, src_dims, src_strides)];
+    if (op.copy) {
+        out[out_i] = x;
+    } else {
+        atomicAdd(out + out_i, x);
+    }
+}
+
+template<typename T>
+__device__ void index_add_bwd_inp(
+    const IndexAddOp op,
+    const size_t numel,
+    const size_t num_dims,
+    const size_t *dims,
+    const bool *copied,
+    T *grad_inp,
+    const size_t *inp_strides,
+    const T *grad_out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    size_t inner = 1;
+    for (size_t d = op.axis + 1; d < num_dims; d++) {
+        inner *= dims[d];
+    }
+    if (copied[(i / inner) % dims[op.axis]]) {
+        return;
+    }
+
+    unsigned int inp_i = get_strided_index(i, num_dims, dims, inp_strides);
+    atomicAdd(grad_inp + inp_i, grad_out[i]);
+}
+
+template<typename T>
+__device__ void index_add_bwd_src(
+    const IndexAddOp op,
+    const size_t numel,
+    const size_t num_dims,
+    const size_t *dims,
+    const size_t *src_dims,
+    const size_t *idx,
+    const size_t idx_stride,
+    T *grad_src,
+    const size_t *src_strides,
+    const T *grad_out
+) {
+    unsigned int src_i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (src_i >= numel) {
+        return;
+    }
+
+    unsigned int out_i = get_out_index(op, src_i, num_dims, dims, src_dims, idx, idx_stride);
+    unsigned int grad_src_i = get_strided_index(src_i, num_dims, src_dims, src_strides);
+    atomicAdd(grad_src + grad_src_i, grad_out[out_i]);
+}
+
+extern "C" __global__ void index_add_copied(
+    const size_t numel,
+    const size_t *idx,
+    const size_t idx_stride,
+    bool *copied
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    copied[idx[i * idx_stride]] = true;
+}
+
+#define INDEX_ADD_OP(TYPENAME, COPY, FWD, BWD_INP, BWD_SRC) \
+extern "C" __global__ void COPY( \
+    const size_t numel, \
+    const size_t num_dims, \
+    const size_t *dims, \
+    const TYPENAME *inp, \
+    const size_t *inp_strides, \
+    TYPENAME *out \
+) { \
+    index_add_copy(numel, num_dims, dims, inp, inp_strides, out); \
+} \
+extern "C" __global__ void FWD( \
+    const IndexAddOp op, \
+    const size_t numel, \
+    const size_t num_dims, \
+    const size_t *dims, \
+    const size_t *src_dims, \
+    const size_t *idx, \
+    const size_t idx_stride, \
+    const TYPENAME *src, \
+    const size_t *src_strides, \
+    TYPENAME *out \
+) { \
+    index_add_fwd(op, numel, num_dims, dims, src_dims, idx, idx_stride, src, src_strides, out); \
+} \
+extern "C" __global__ void BWD_INP( \
+    const IndexAddOp op, \
+    const size_t numel, \
+    const size_t num_dims, \
+    const size_t *dims, \
+    const bool *copied, \
+    TYPENAME *grad_inp, \
+    const size_t *inp_strides, \
+    const TYPENAME *grad_out \
+) { \
+    index_add_bwd_inp(op, numel, num_dims, dims, copied, grad_inp, inp_strides, grad_out); \
+} \
+extern "C" __global__ void BWD_SRC( \
+    const IndexAddOp op, \
+    const size_t numel, \
+    const size_t num_dims, \
+    const size_t *dims, \
+    const size_t *src_dims, \
+    const size_t *idx, \
+    const size_t idx_stride, \
+    TYPENAME *grad_src, \
+    const size_t *src_strides, \
+    const TYPENAME *grad_out \
+) { \
+    index_add_bwd_src(op, numel, num_dims, dims, src_dims, idx, idx_stride, grad_src, src_strides, grad_out); \
+}
+
+INDEX_ADD_OP(float, index_add_copy_f32, index_add_fwd_f32, index_add_bwd_inp_f32, index_add_bwd_src_f32);
+INDEX_ADD_OP(double, index_add_copy_f64, index_add_fwd_f64, index_add_bwd_inp_f64, index_add_bwd_src_f64);
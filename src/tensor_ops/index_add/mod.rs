@@ -0,0 +1,254 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{
+    gradients::{Merge, Tape},
+    shapes::*,
+    tensor::*,
+};
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct IndexAddOp {
+    /// The axis that `idx` indexes into
+    pub axis: usize,
+    /// Whether values from `src` replace the values of `inp` instead of being added to them
+    pub copy: bool,
+}
+
+pub trait IndexAddKernel<E: Dtype>: DeviceStorage {
+    /// Adds (or copies) slice `i` of `src` along `op.axis` into slice `idx[i]` of `inp`.
+    fn forward<S: Shape, Src: Shape, I: Dim>(
+        &self,
+        op: IndexAddOp,
+        inp: &Self::Storage<S, E>,
+        idx: &Self::Storage<(I,), usize>,
+        src: &Self::Storage<Src, E>,
+    ) -> Result<Self::Storage<S, E>, Self::Err>;
+
+    fn backward<S: Shape, Src: Shape, I: Dim>(
+        &self,
+        op: IndexAddOp,
+        grad_inp: &mut Self::Storage<S, E>,
+        idx: &Self::Storage<(I,), usize>,
+        grad_src: &mut Self::Storage<Src, E>,
+        grad_out: &Self::Storage<S, E>,
+    ) -> Result<(), Self::Err>;
+}
+
+impl<S: Shape, E: Dtype, D: IndexAddKernel<E>, LTape: Tape<D>> Tensor<S, E, D, LTape> {
+    /// Adds slices of `src` along `Ax` into the slices of `self` given by `idx`.
+    /// Slice `i` of `src` is added to slice `idx[i]` of `self`, and slices with
+    /// repeated indices are summed. `src` must have the same shape as `self`, except
+    /// along `Ax` where it has the same size as `idx`.
+    ///
+    /// **Pytorch equivalent**: `t.index_add(Ax, idx, src)`
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t: Tensor<Rank2<3, 2>, f32, _> = dev.zeros();
+    /// let idx = dev.tensor([2, 0, 2]);
+    /// let src = dev.tensor([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+    /// let r = t.index_add::<Axis<0>, _, _, _>(idx, src);
+    /// assert_eq!(r.array(), [[3.0, 4.0], [0.0, 0.0], [6.0, 8.0]]);
+    /// ```
+    pub fn index_add<Ax: Axes<Array = [isize; 1]>, I: Dim, Src: Shape, RTape: Tape<D>>(
+        self,
+        idx: Tensor<(I,), usize, D>,
+        src: Tensor<Src, E, D, RTape>,
+    ) -> Self
+    where
+        S: HasAxes<Ax>,
+        LTape: Merge<RTape>,
+    {
+        self.try_index_add::<Ax, I, Src, RTape>(idx, src).unwrap()
+    }
+
+    /// See [Tensor::index_add]
+    pub fn try_index_add<Ax: Axes<Array = [isize; 1]>, I: Dim, Src: Shape, RTape: Tape<D>>(
+        self,
+        idx: Tensor<(I,), usize, D>,
+        src: Tensor<Src, E, D, RTape>,
+    ) -> Result<Self, D::Err>
+    where
+        S: HasAxes<Ax>,
+        LTape: Merge<RTape>,
+    {
+        let op = IndexAddOp {
+            axis: Ax::as_array()[0] as usize,
+            copy: false,
+        };
+        try_index_add_op(op, self, idx, src)
+    }
+
+    /// Copies slices of `src` along `Ax` into the slices of `self` given by `idx`.
+    /// Slice `i` of `src` replaces slice `idx[i]` of `self`. `src` must have the same
+    /// shape as `self`, except along `Ax` where it has the same size as `idx`.
+    ///
+    /// The indices should be unique; which slice is copied for a repeated index
+    /// is unspecified.
+    ///
+    /// **Pytorch equivalent**: `t.index_copy(Ax, idx, src)`
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t = dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    /// let idx = dev.tensor([2, 0]);
+    /// let src = dev.tensor([[-1.0, -2.0], [-3.0, -4.0]]);
+    /// let r = t.index_copy::<Axis<1>, _, _, _>(idx, src);
+    /// assert_eq!(r.array(), [[-2.0, 2.0, -1.0], [-4.0, 5.0, -3.0]]);
+    /// ```
+    pub fn index_copy<Ax: Axes<Array = [isize; 1]>, I: Dim, Src: Shape, RTape: Tape<D>>(
+        self,
+        idx: Tensor<(I,), usize, D>,
+        src: Tensor<Src, E, D, RTape>,
+    ) -> Self
+    where
+        S: HasAxes<Ax>,
+        LTape: Merge<RTape>,
+    {
+        self.try_index_copy::<Ax, I, Src, RTape>(idx, src).unwrap()
+    }
+
+    /// See [Tensor::index_copy]
+    pub fn try_index_copy<Ax: Axes<Array = [isize; 1]>, I: Dim, Src: Shape, RTape: Tape<D>>(
+        self,
+        idx: Tensor<(I,), usize, D>,
+        src: Tensor<Src, E, D, RTape>,
+    ) -> Result<Self, D::Err>
+    where
+        S: HasAxes<Ax>,
+        LTape: Merge<RTape>,
+    {
+        let op = IndexAddOp {
+            axis: Ax::as_array()[0] as usize,
+            copy: true,
+        };
+        try_index_add_op(op, self, idx, src)
+    }
+}
+
+fn try_index_add_op<
+    S: Shape,
+    Src: Shape,
+    I: Dim,
+    E: Dtype,
+    D: IndexAddKernel<E>,
+    LTape: Tape<D> + Merge<RTape>,
+    RTape: Tape<D>,
+>(
+    op: IndexAddOp,
+    inp: Tensor<S, E, D, LTape>,
+    idx: Tensor<(I,), usize, D>,
+    src: Tensor<Src, E, D, RTape>,
+) -> Result<Tensor<S, E, D, LTape>, D::Err> {
+    assert_eq!(S::NUM_DIMS, Src::NUM_DIMS);
+    let inp_dims = inp.shape().concrete();
+    let src_dims = src.shape().concrete();
+    for i in 0..S::NUM_DIMS {
+        if i == op.axis {
+            assert_eq!(
+                src_dims[i],
+                idx.shape().0.size(),
+                "dimension {i} not the same"
+            );
+        } else {
+            assert_eq!(src_dims[i], inp_dims[i], "dimension {i} not the same");
+        }
+    }
+
+    let (inp, ltape) = inp.split_tape();
+    let (src, rtape) = src.split_tape();
+    let mut tape = ltape.merge(rtape);
+    let storage = inp
+        .device
+        .forward(op, &inp.storage, &idx.storage, &src.storage)?;
+    let out = inp.device.upgrade(storage);
+    let phantom_out = out.clone();
+    tape.try_alloc_grad(&inp)?;
+    tape.try_alloc_grad(&src)?;
+    tape.try_alloc_grad(&out)?;
+    tape.add_backward_op(move |grads| {
+        let (grad_inp, grad_src, grad_out) = grads.muts_and_ref(&inp, &src, &phantom_out);
+        inp.device
+            .backward(op, grad_inp, &idx.storage, grad_src, grad_out)
+    });
+    Ok(out.put_tape(tape))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{shapes::*, tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_index_add_axis_0() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+        let src: Tensor<_, TestDtype, _> = dev.tensor([[0.5, -0.5], [1.0, -1.0]]);
+        let r = t
+            .trace()
+            .index_add::<Axis<0>, _, _, _>(dev.tensor([2, 2]), src.trace());
+        assert_eq!(r.array(), [[1.0, 2.0], [3.0, 4.0], [6.5, 4.5]]);
+        let g = (r * dev.tensor([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]))
+            .sum()
+            .backward();
+        assert_eq!(g.get(&t).array(), [[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+        assert_eq!(g.get(&src).array(), [[5.0, 6.0], [5.0, 6.0]]);
+    }
+
+    #[test]
+    fn test_index_add_axis_1() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank3<2, 3, 2>, TestDtype, _> = dev.zeros();
+        let src: Tensor<Rank3<2, 1, 2>, TestDtype, _> = dev.tensor([[[1.0, 2.0]], [[3.0, 4.0]]]);
+        let r = t
+            .trace()
+            .index_add::<Axis<1>, _, _, _>(dev.tensor([1]), src.trace());
+        assert_eq!(
+            r.array(),
+            [
+                [[0.0, 0.0], [1.0, 2.0], [0.0, 0.0]],
+                [[0.0, 0.0], [3.0, 4.0], [0.0, 0.0]]
+            ]
+        );
+        let g = r.exp().sum().backward();
+        assert_close(
+            &g.get(&src).array(),
+            &[[[1.0, 2.0]], [[3.0, 4.0]]].map(|a| a.map(|b| b.map(TestDtype::exp))),
+        );
+    }
+
+    #[test]
+    fn test_index_copy() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let src: Tensor<_, TestDtype, _> = dev.tensor([[-1.0, -2.0], [-3.0, -4.0]]);
+        let r = t
+            .trace()
+            .index_copy::<Axis<1>, _, _, _>(dev.tensor([2, 0]), src.trace());
+        assert_eq!(r.array(), [[-2.0, 2.0, -1.0], [-4.0, 5.0, -3.0]]);
+        let g = (r * dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]))
+            .sum()
+            .backward();
+        assert_eq!(g.get(&t).array(), [[0.0, 2.0, 0.0], [0.0, 5.0, 0.0]]);
+        assert_eq!(g.get(&src).array(), [[3.0, 1.0], [6.0, 4.0]]);
+    }
+
+    #[test]
+    fn test_index_add_usize_dim() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([1.0, 2.0, 3.0, 4.0]);
+        let src: Tensor<(usize,), TestDtype, _> =
+            dev.tensor_from_vec(std::vec![10.0, 20.0, 30.0], (3,));
+        let idx: Tensor<(usize,), usize, _> = dev.tensor_from_vec(std::vec![3, 0, 3], (3,));
+        let r = t.trace().index_add::<Axis<0>, _, _, _>(idx, src.trace());
+        assert_eq!(r.array(), [21.0, 2.0, 3.0, 44.0]);
+        let g = (r * dev.tensor([1.0, 2.0, 3.0, 4.0])).sum().backward();
+        assert_eq!(g.get(&t).array(), [1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(g.get(&src).as_vec(), [4.0, 1.0, 4.0]);
+    }
+}
@@ -0,0 +1,45 @@
+use crate::{
+    shapes::{Shape, Unit},
+    tensor::cpu::{Cpu, LendingIterator, StridedArray},
+};
+
+use super::{ClassifyKernel, IsFiniteKernelOp, IsInfKernelOp, IsNanKernelOp};
+
+use num_traits::Float;
+
+trait ClassifyOpCpuKernel<E: Unit> {
+    fn func(x: E) -> bool;
+}
+
+impl<Op: ClassifyOpCpuKernel<E>, E: Unit> ClassifyKernel<Op, E> for Cpu {
+    fn forward<S: Shape>(
+        &self,
+        inp: &Self::Storage<S, E>,
+    ) -> Result<Self::Storage<S, bool>, Self::Err> {
+        let mut out: Self::Storage<S, bool> = StridedArray::new(inp.shape)?;
+        let mut inp_iter = inp.iter();
+        let mut out_iter = out.iter_mut();
+        while let Some((o, x)) = out_iter.next().zip(inp_iter.next()) {
+            *o = Op::func(*x);
+        }
+        Ok(out)
+    }
+}
+
+impl<E: Unit + Float> ClassifyOpCpuKernel<E> for IsNanKernelOp {
+    fn func(x: E) -> bool {
+        x.is_nan()
+    }
+}
+
+impl<E: Unit + Float> ClassifyOpCpuKernel<E> for IsInfKernelOp {
+    fn func(x: E) -> bool {
+        x.is_infinite()
+    }
+}
+
+impl<E: Unit + Float> ClassifyOpCpuKernel<E> for IsFiniteKernelOp {
+    fn func(x: E) -> bool {
+        x.is_finite()
+    }
+}
@@ -0,0 +1,78 @@
+use crate::{
+    shapes::{Shape, Unit},
+    tensor::cuda::Cuda,
+    tensor::cuda::CudaArray,
+};
+use cudarc::driver::{CudaSlice, LaunchAsync, LaunchConfig};
+use std::sync::Arc;
+
+use super::{ClassifyKernel, IsFiniteKernelOp, IsInfKernelOp, IsNanKernelOp};
+
+const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/isfinite.ptx"));
+
+trait ClassifyOpCudaKernel<E: Unit> {
+    /// Compiled by build.rs
+    const PTX_SRC: &'static str;
+
+    /// Unique name for the kernel
+    const MODULE_NAME: &'static str;
+
+    /// Name of function in the .cu file
+    const FWD_FN_NAME: &'static str;
+}
+
+impl<E: Unit, Op: ClassifyOpCudaKernel<E>> ClassifyKernel<Op, E> for Cuda {
+    fn forward<S: Shape>(
+        &self,
+        inp: &Self::Storage<S, E>,
+    ) -> Result<Self::Storage<S, bool>, Self::Err> {
+        if !self.dev.has_func(Op::MODULE_NAME, Op::FWD_FN_NAME) {
+            self.dev
+                .load_ptx(Op::PTX_SRC.into(), Op::MODULE_NAME, &[Op::FWD_FN_NAME])?;
+        }
+
+        let shape = inp.shape;
+        let strides = inp.shape.strides();
+        let numel = shape.num_elements();
+
+        let mut storage = self.dev.alloc_zeros_async::<bool>(numel)?;
+
+        let dims: CudaSlice<usize> = self.dev.take_async(shape.concrete().into())?;
+        let inp_strides: CudaSlice<usize> = self.dev.take_async(inp.strides.into())?;
+
+        let fwd_fn = self.dev.get_func(Op::MODULE_NAME, Op::FWD_FN_NAME).unwrap();
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            numel,             // const size_t numel,
+            S::NUM_DIMS,       // const size_t num_dims,
+            &dims,             // const size_t *dims,
+            inp.data.as_ref(), // const float *inp,
+            &inp_strides,      // const size_t *inp_strides,
+            &mut storage,      // bool *out
+        );
+        unsafe { fwd_fn.launch_async(cfg, params) }?;
+        Ok(CudaArray {
+            data: Arc::new(storage),
+            shape,
+            strides,
+        })
+    }
+}
+
+macro_rules! classify {
+    ($Op:ty, $TypeName:ty, $Fwd:tt) => {
+        impl ClassifyOpCudaKernel<$TypeName> for $Op {
+            const PTX_SRC: &'static str = PTX_SRC;
+            const MODULE_NAME: &'static str = $Fwd;
+            const FWD_FN_NAME: &'static str = $Fwd;
+        }
+    };
+}
+
+classify!(IsNanKernelOp, f32, "isnan_fwd_f32");
+classify!(IsInfKernelOp, f32, "isinf_fwd_f32");
+classify!(IsFiniteKernelOp, f32, "isfinite_fwd_f32");
+
+classify!(IsNanKernelOp, f64, "isnan_fwd_f64");
+classify!(IsInfKernelOp, f64, "isinf_fwd_f64");
+classify!(IsFiniteKernelOp, f64, "isfinite_fwd_f64");
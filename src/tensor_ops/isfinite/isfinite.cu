@@ -0,0 +1,25 @@
+#include "cuda_utils.cuh"
+
+#define CLASSIFY_OP(TYPENAME, FWD, FUNC) \
+extern "C" __global__ void FWD( \
+    const size_t numel, \
+    const size_t num_dims, \
+    const size_t *dims, \
+    const TYPENAME *inp, \
+    const size_t *inp_strides, \
+    bool *out \
+) { \
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x; \
+    if (i >= numel) { \
+        return; \
+    } \
+    unsigned int inp_i = get_strided_index(i, num_dims, dims, inp_strides); \
+    out[i] = FUNC(inp[inp_i]); \
+}
+
+CLASSIFY_OP(float, isnan_fwd_f32, isnan)
+CLASSIFY_OP(float, isinf_fwd_f32, isinf)
+CLASSIFY_OP(float, isfinite_fwd_f32, isfinite)
+CLASSIFY_OP(double, isnan_fwd_f64, isnan)
+CLASSIFY_OP(double, isinf_fwd_f64, isinf)
+CLASSIFY_OP(double, isfinite_fwd_f64, isfinite)
@@ -0,0 +1,146 @@
+use crate::{
+    gradients::{NoneTape, Tape},
+    shapes::{Shape, Unit},
+    tensor::{DeviceStorage, Tensor},
+};
+
+mod cpu_kernels;
+#[cfg(feature = "cuda")]
+mod cuda_kernels;
+
+pub trait ClassifyKernel<Op, E: Unit>: DeviceStorage {
+    fn forward<S: Shape>(
+        &self,
+        inp: &Self::Storage<S, E>,
+    ) -> Result<Self::Storage<S, bool>, Self::Err>;
+}
+
+fn try_classify_op<Op, S: Shape, E: Unit, D: ClassifyKernel<Op, E>, T: Tape<D>>(
+    inp: &Tensor<S, E, D, T>,
+) -> Result<Tensor<S, bool, D, NoneTape>, D::Err> {
+    let storage = inp.device.forward(&inp.storage)?;
+    let out = inp.device.upgrade(storage);
+    Ok(out)
+}
+
+pub enum IsNanKernelOp {}
+pub enum IsInfKernelOp {}
+pub enum IsFiniteKernelOp {}
+
+/// Element-wise check for NaN values.
+///
+/// **Pytorch equivalent**: `torch.isnan(t)`
+///
+/// Examples:
+/// ```
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let a = dev.tensor([1.0, f32::NAN, f32::INFINITY, f32::NEG_INFINITY]);
+/// let r = a.isnan();
+/// assert_eq!(r.array(), [false, true, false, false]);
+/// ```
+pub fn isnan<S: Shape, E: Unit, D: ClassifyKernel<IsNanKernelOp, E>, T: Tape<D>>(
+    t: &Tensor<S, E, D, T>,
+) -> Tensor<S, bool, D, NoneTape> {
+    t.isnan()
+}
+
+/// Element-wise check for positive or negative infinity.
+///
+/// **Pytorch equivalent**: `torch.isinf(t)`
+///
+/// Examples:
+/// ```
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let a = dev.tensor([1.0, f32::NAN, f32::INFINITY, f32::NEG_INFINITY]);
+/// let r = a.isinf();
+/// assert_eq!(r.array(), [false, false, true, true]);
+/// ```
+pub fn isinf<S: Shape, E: Unit, D: ClassifyKernel<IsInfKernelOp, E>, T: Tape<D>>(
+    t: &Tensor<S, E, D, T>,
+) -> Tensor<S, bool, D, NoneTape> {
+    t.isinf()
+}
+
+/// Element-wise check for values that are neither NaN nor infinite.
+///
+/// **Pytorch equivalent**: `torch.isfinite(t)`
+///
+/// Examples:
+/// ```
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let a = dev.tensor([1.0, f32::NAN, f32::INFINITY, f32::NEG_INFINITY]);
+/// let r = a.isfinite();
+/// assert_eq!(r.array(), [true, false, false, false]);
+/// ```
+pub fn isfinite<S: Shape, E: Unit, D: ClassifyKernel<IsFiniteKernelOp, E>, T: Tape<D>>(
+    t: &Tensor<S, E, D, T>,
+) -> Tensor<S, bool, D, NoneTape> {
+    t.isfinite()
+}
+
+// Macro to reduce boilerplate of implementing classification methods on Tensor.
+macro_rules! impl_classify_kernel_op {
+    ($kernel_op:ty, $try_op:ident, $op:ident, $doc:expr) => {
+        impl<S: Shape, E: Unit, D: ClassifyKernel<$kernel_op, E>, T: Tape<D>> Tensor<S, E, D, T> {
+            #[doc = $doc]
+            pub fn $try_op(&self) -> Result<Tensor<S, bool, D, NoneTape>, D::Err> {
+                try_classify_op(self)
+            }
+
+            #[doc = $doc]
+            pub fn $op(&self) -> Tensor<S, bool, D, NoneTape> {
+                self.$try_op().unwrap()
+            }
+        }
+    };
+}
+
+impl_classify_kernel_op!(IsNanKernelOp, try_isnan, isnan, "See [isnan]");
+impl_classify_kernel_op!(IsInfKernelOp, try_isinf, isinf, "See [isinf]");
+impl_classify_kernel_op!(IsFiniteKernelOp, try_isfinite, isfinite, "See [isfinite]");
+
+#[cfg(test)]
+mod tests {
+    use crate::{shapes::*, tensor::*, tensor_ops::*, tests::*};
+
+    const NAN: TestDtype = TestDtype::NAN;
+    const INF: TestDtype = TestDtype::INFINITY;
+
+    #[test]
+    fn test_isnan_isinf_isfinite() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<_, TestDtype, _> = dev.tensor([[1.0, NAN, -INF], [INF, -0.0, -NAN]]);
+        assert_eq!(
+            a.isnan().array(),
+            [[false, true, false], [false, false, true]]
+        );
+        assert_eq!(
+            a.isinf().array(),
+            [[false, false, true], [true, false, false]]
+        );
+        assert_eq!(
+            a.isfinite().array(),
+            [[true, false, false], [false, true, false]]
+        );
+    }
+
+    #[test]
+    fn test_isfinite_of_overflow() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<_, TestDtype, _> = dev.tensor([1.0, 1000.0, -1000.0]);
+        let r = a.trace().exp();
+        assert_eq!(r.isfinite().array(), [true, false, true]);
+        assert_eq!((r * 0.0).isnan().array(), [false, true, false]);
+    }
+
+    #[test]
+    fn test_isfinite_broadcasted() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<_, TestDtype, _> = dev.tensor([NAN, 2.0]);
+        let b: Tensor<Rank2<3, 2>, _, _> = a.broadcast();
+        assert_eq!(b.isnan().array(), [[true, false]; 3]);
+    }
+}
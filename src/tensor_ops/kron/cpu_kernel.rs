@@ -0,0 +1,72 @@
+use crate::{
+    shapes::*,
+    tensor::cpu::{Cpu, LendingIterator, StridedArray},
+};
+
+/// Converts an index into the output to the indices into `lhs` and `rhs`, where
+/// `(p, q)` are the sizes of the last two dims of `rhs`.
+#[inline]
+fn kron_indices<L: Shape, R: Shape, O: Shape>(
+    i_out: O::Concrete,
+    [p, q]: [usize; 2],
+) -> (L::Concrete, R::Concrete) {
+    let mut i_lhs: L::Concrete = Default::default();
+    let mut i_rhs: R::Concrete = Default::default();
+    for j in 0..O::NUM_DIMS - 2 {
+        i_lhs[j] = i_out[j];
+        i_rhs[j] = i_out[j];
+    }
+    let (r, c) = (i_out[O::NUM_DIMS - 2], i_out[O::NUM_DIMS - 1]);
+    i_lhs[O::NUM_DIMS - 2] = r / p;
+    i_lhs[O::NUM_DIMS - 1] = c / q;
+    i_rhs[O::NUM_DIMS - 2] = r % p;
+    i_rhs[O::NUM_DIMS - 1] = c % q;
+    (i_lhs, i_rhs)
+}
+
+fn last_two<S: Shape>(shape: &S) -> [usize; 2] {
+    let dims = shape.concrete();
+    [dims[S::NUM_DIMS - 2], dims[S::NUM_DIMS - 1]]
+}
+
+impl<E: Dtype> super::KronKernel<E> for Cpu {
+    fn forward<L: Shape, R: Shape, O: Shape>(
+        &self,
+        dst: O,
+        lhs: &Self::Storage<L, E>,
+        rhs: &Self::Storage<R, E>,
+    ) -> Result<Self::Storage<O, E>, Self::Err> {
+        let mut out: Self::Storage<O, E> = StridedArray::new(dst)?;
+        if dst.num_elements() == 0 {
+            return Ok(out);
+        }
+        let pq = last_two(&rhs.shape);
+        let mut out_iter = out.iter_mut_with_index();
+        while let Some((o, i_out)) = out_iter.next() {
+            let (i_lhs, i_rhs) = kron_indices::<L, R, O>(i_out, pq);
+            *o = lhs[i_lhs] * rhs[i_rhs];
+        }
+        Ok(out)
+    }
+
+    fn backward<L: Shape, R: Shape, O: Shape>(
+        &self,
+        lhs: &Self::Storage<L, E>,
+        grad_lhs: &mut Self::Storage<L, E>,
+        rhs: &Self::Storage<R, E>,
+        grad_rhs: &mut Self::Storage<R, E>,
+        grad_out: &Self::Storage<O, E>,
+    ) -> Result<(), Self::Err> {
+        if grad_out.shape.num_elements() == 0 {
+            return Ok(());
+        }
+        let pq = last_two(&rhs.shape);
+        let mut out_iter = grad_out.iter_with_index();
+        while let Some((go, i_out)) = out_iter.next() {
+            let (i_lhs, i_rhs) = kron_indices::<L, R, O>(i_out, pq);
+            grad_lhs[i_lhs] += *go * rhs[i_rhs];
+            grad_rhs[i_rhs] += *go * lhs[i_lhs];
+        }
+        Ok(())
+    }
+}
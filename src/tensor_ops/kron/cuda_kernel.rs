@@ -0,0 +1,101 @@
+use crate::{
+    shapes::*,
+    tensor::cuda::{Cuda, CudaArray},
+};
+
+use cudarc::driver::{AsKernelParam, CudaSlice, LaunchAsync, LaunchConfig};
+
+use std::sync::Arc;
+
+const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/kron.ptx"));
+
+trait HasCudaKernel<E> {
+    const MOD: &'static str;
+    const FNS: &'static [&'static str];
+}
+
+impl HasCudaKernel<f32> for Cuda {
+    const MOD: &'static str = "kron_f32";
+    const FNS: &'static [&'static str] = &["kron_fwd_f32", "kron_bwd_f32"];
+}
+
+impl HasCudaKernel<f64> for Cuda {
+    const MOD: &'static str = "kron_f64";
+    const FNS: &'static [&'static str] = &["kron_fwd_f64", "kron_bwd_f64"];
+}
+
+impl<E: Dtype + AsKernelParam> super::KronKernel<E> for Cuda
+where
+    Self: HasCudaKernel<E>,
+{
+    fn forward<L: Shape, R: Shape, O: Shape>(
+        &self,
+        dst: O,
+        lhs: &Self::Storage<L, E>,
+        rhs: &Self::Storage<R, E>,
+    ) -> Result<Self::Storage<O, E>, Self::Err> {
+        if !self.dev.has_func(Self::MOD, Self::FNS[0]) {
+            self.dev.load_ptx(PTX_SRC.into(), Self::MOD, Self::FNS)?;
+        }
+
+        let numel = dst.num_elements();
+        let mut storage = unsafe { self.dev.alloc_async::<E>(numel) }?;
+        let out_dims: CudaSlice<usize> = self.dev.take_async(dst.concrete().into())?;
+        let rhs_dims: CudaSlice<usize> = self.dev.take_async(rhs.shape.concrete().into())?;
+        let lhs_strides: CudaSlice<usize> = self.dev.take_async(lhs.strides.into())?;
+        let rhs_strides: CudaSlice<usize> = self.dev.take_async(rhs.strides.into())?;
+
+        let fwd_fn = self.dev.get_func(Self::MOD, Self::FNS[0]).unwrap();
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            numel,             // const size_t numel,
+            O::NUM_DIMS,       // const size_t num_dims,
+            &out_dims,         // const size_t *out_dims,
+            &rhs_dims,         // const size_t *rhs_dims,
+            lhs.data.as_ref(), // const T *lhs,
+            &lhs_strides,      // const size_t *lhs_strides,
+            rhs.data.as_ref(), // const T *rhs,
+            &rhs_strides,      // const size_t *rhs_strides,
+            &mut storage,      // T *out
+        );
+        unsafe { fwd_fn.launch_async(cfg, params) }?;
+        Ok(CudaArray {
+            data: Arc::new(storage),
+            shape: dst,
+            strides: dst.strides(),
+        })
+    }
+
+    fn backward<L: Shape, R: Shape, O: Shape>(
+        &self,
+        lhs: &Self::Storage<L, E>,
+        grad_lhs: &mut Self::Storage<L, E>,
+        rhs: &Self::Storage<R, E>,
+        grad_rhs: &mut Self::Storage<R, E>,
+        grad_out: &Self::Storage<O, E>,
+    ) -> Result<(), Self::Err> {
+        let numel = grad_out.shape.num_elements();
+        let out_dims: CudaSlice<usize> = self.dev.take_async(grad_out.shape.concrete().into())?;
+        let rhs_dims: CudaSlice<usize> = self.dev.take_async(rhs.shape.concrete().into())?;
+        let lhs_strides: CudaSlice<usize> = self.dev.take_async(lhs.strides.into())?;
+        let rhs_strides: CudaSlice<usize> = self.dev.take_async(rhs.strides.into())?;
+
+        let bwd_fn = self.dev.get_func(Self::MOD, Self::FNS[1]).unwrap();
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            numel,                             // const size_t numel,
+            O::NUM_DIMS,                       // const size_t num_dims,
+            &out_dims,                         // const size_t *out_dims,
+            &rhs_dims,                         // const size_t *rhs_dims,
+            lhs.data.as_ref(),                 // const T *lhs,
+            Arc::make_mut(&mut grad_lhs.data), // T *grad_lhs,
+            &lhs_strides,                      // const size_t *lhs_strides,
+            rhs.data.as_ref(),                 // const T *rhs,
+            Arc::make_mut(&mut grad_rhs.data), // T *grad_rhs,
+            &rhs_strides,                      // const size_t *rhs_strides,
+            grad_out.data.as_ref(),            // const T *grad_out
+        );
+        unsafe { bwd_fn.launch_async(cfg, params) }?;
+        Ok(())
+    }
+}
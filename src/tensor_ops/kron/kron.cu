@@ -0,0 +1,109 @@
+#include "cuda_utils.cuh"
+
+// Converts the index of an element of the (contiguous) output into the strided
+// indices of the elements of lhs and rhs that are multiplied together.
+__device__ void get_kron_indices(
+    const unsigned int out_i,
+    const size_t num_dims,
+    const size_t *out_dims,
+    const size_t *rhs_dims,
+    const size_t *lhs_strides,
+    const size_t *rhs_strides,
+    size_t *lhs_i,
+    size_t *rhs_i
+) {
+    const size_t p = rhs_dims[num_dims - 2];
+    const size_t q = rhs_dims[num_dims - 1];
+    const size_t c = out_i % out_dims[num_dims - 1];
+    const size_t r = (out_i / out_dims[num_dims - 1]) % out_dims[num_dims - 2];
+    unsigned int batch_i = out_i / (out_dims[num_dims - 1] * out_dims[num_dims - 2]);
+
+    *lhs_i = (r / p) * lhs_strides[num_dims - 2] + (c / q) * lhs_strides[num_dims - 1];
+    *rhs_i = (r % p) * rhs_strides[num_dims - 2] + (c % q) * rhs_strides[num_dims - 1];
+    for (size_t d = num_dims - 2; d-- > 0;) {
+        const size_t b = batch_i % out_dims[d];
+        batch_i /= out_dims[d];
+        *lhs_i += b * lhs_strides[d];
+        *rhs_i += b * rhs_strides[d];
+    }
+}
+
+template<typename T>
+__device__ void kron_fwd(
+    const size_t numel,
+    const size_t num_dims,
+    const size_t *out_dims,
+    const size_t *rhs_dims,
+    const T *lhs,
+    const size_t *lhs_strides,
+    const T *rhs,
+    const size_t *rhs_strides,
+    T *out
+) {
+    unsigned int out_i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (out_i >= numel) {
+        return;
+    }
+
+    size_t lhs_i, rhs_i;
+    get_kron_indices(out_i, num_dims, out_dims, rhs_dims, lhs_strides, rhs_strides, &lhs_i, &rhs_i);
+    out[out_i] = lhs[lhs_i] * rhs[rhs_i];
+}
+
+template<typename T>
+__device__ void kron_bwd(
+    const size_t numel,
+    const size_t num_dims,
+    const size_t *out_dims,
+    const size_t *rhs_dims,
+    const T *lhs,
+    T *grad_lhs,
+    const size_t *lhs_strides,
+    const T *rhs,
+    T *grad_rhs,
+    const size_t *rhs_strides,
+    const T *grad_out
+) {
+    unsigned int out_i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (out_i >= numel) {
+        return;
+    }
+
+    size_t lhs_i, rhs_i;
+    get_kron_indices(out_i, num_dims, out_dims, rhs_dims, lhs_strides, rhs_strides, &lhs_i, &rhs_i);
+    atomicAdd(grad_lhs + lhs_i, grad_out[out_i] * rhs[rhs_i]);
+    atomicAdd(grad_rhs + rhs_i, grad_out[out_i] * lhs[lhs_i]);
+}
+
+#define KRON_OP(TYPENAME, FWD, BWD) \
+extern "C" __global__ void FWD( \
+    const size_t numel, \
+    const size_t num_dims, \
+    const size_t *out_dims, \
+    const size_t *rhs_dims, \
+    const TYPENAME *lhs, \
+    const size_t *lhs_strides, \
+    const TYPENAME *rhs, \
+    const size_t *rhs_strides, \
+    TYPENAME *out \
+) { \
+    kron_fwd(numel, num_dims, out_dims, rhs_dims, lhs, lhs_strides, rhs, rhs_strides, out); \
+} \
+extern "C" __global__ void BWD( \
+    const size_t numel, \
+    const size_t num_dims, \
+    const size_t *out_dims, \
+    const size_t *rhs_dims, \
+    const TYPENAME *lhs, \
+    TYPENAME *grad_lhs, \
+    const size_t *lhs_strides, \
+    const TYPENAME *rhs, \
+    TYPENAME *grad_rhs, \
+    const size_t *rhs_strides, \
+    const TYPENAME *grad_out \
+) { \
+    kron_bwd(numel, num_dims, out_dims, rhs_dims, lhs, grad_lhs, lhs_strides, rhs, grad_rhs, rhs_strides, grad_out); \
+}
+
+KRON_OP(float, kron_fwd_f32, kron_bwd_f32);
+KRON_OP(double, kron_fwd_f64, kron_bwd_f64);
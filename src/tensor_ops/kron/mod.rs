@@ -0,0 +1,185 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{
+    gradients::{Merge, Tape},
+    shapes::*,
+    tensor::*,
+};
+
+pub trait KronKernel<E: Dtype>: DeviceStorage {
+    fn forward<L: Shape, R: Shape, O: Shape>(
+        &self,
+        dst: O,
+        lhs: &Self::Storage<L, E>,
+        rhs: &Self::Storage<R, E>,
+    ) -> Result<Self::Storage<O, E>, Self::Err>;
+
+    fn backward<L: Shape, R: Shape, O: Shape>(
+        &self,
+        lhs: &Self::Storage<L, E>,
+        grad_lhs: &mut Self::Storage<L, E>,
+        rhs: &Self::Storage<R, E>,
+        grad_rhs: &mut Self::Storage<R, E>,
+        grad_out: &Self::Storage<O, E>,
+    ) -> Result<(), Self::Err>;
+}
+
+/// Kronecker product of the matrices in the last two axes of `lhs` and `rhs`,
+/// broadcasted over any leading batch axes, which must match.
+///
+/// For `lhs` of shape `(M, N)` and `rhs` of shape `(P, Q)` the result has shape
+/// `(M * P, N * Q)`, where `r[i * P + k][j * Q + l] = lhs[i][j] * rhs[k][l]`.
+///
+/// **Pytorch equivalent** `torch.kron(lhs, rhs)` for matrices.
+///
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let a = dev.tensor([[1.0, 2.0], [3.0, 4.0]]);
+/// let b = dev.tensor([[0.0, 1.0], [1.0, 0.0]]);
+/// let r: Tensor<(usize, usize), f32, _> = kron(a, b);
+/// assert_eq!(r.shape(), &(4, 4));
+/// assert_eq!(
+///     r.as_vec(),
+///     [
+///         0.0, 1.0, 0.0, 2.0,
+///         1.0, 0.0, 2.0, 0.0,
+///         0.0, 3.0, 0.0, 4.0,
+///         3.0, 0.0, 4.0, 0.0,
+///     ]
+/// );
+/// ```
+pub fn kron<Lhs: TryKron<Rhs>, Rhs>(lhs: Lhs, rhs: Rhs) -> Lhs::Output {
+    lhs.kron(rhs)
+}
+
+/// Batched Kronecker products. See [kron].
+pub trait TryKron<Rhs>: HasErr {
+    type Output;
+
+    /// See [kron]
+    fn kron(self, rhs: Rhs) -> Self::Output {
+        self.try_kron(rhs).unwrap()
+    }
+
+    /// Fallible version of [TryKron::kron]
+    fn try_kron(self, rhs: Rhs) -> Result<Self::Output, Self::Err>;
+}
+
+macro_rules! kron {
+    ($($B:ident),*) => {
+        impl<$($B: Dim, )* M: Dim, N: Dim, P: Dim, Q: Dim, E: Dtype, D: KronKernel<E>, LTape, RTape>
+            TryKron<Tensor<($($B, )* P, Q), E, D, RTape>> for Tensor<($($B, )* M, N), E, D, LTape>
+        where
+            LTape: Tape<D> + Merge<RTape>,
+            RTape: Tape<D>,
+        {
+            type Output = Tensor<($($B, )* usize, usize), E, D, LTape>;
+
+            #[allow(non_snake_case)]
+            fn try_kron(
+                self,
+                rhs: Tensor<($($B, )* P, Q), E, D, RTape>,
+            ) -> Result<Self::Output, Self::Err> {
+                let ($($B, )* m, n) = *self.shape();
+                let (.., p, q) = *rhs.shape();
+                let dst = ($($B, )* m.size() * p.size(), n.size() * q.size());
+                let (lhs, ltape) = self.split_tape();
+                let (rhs, rtape) = rhs.split_tape();
+                let mut tape = ltape.merge(rtape);
+                let out = lhs.device.upgrade(lhs.device.forward(dst, &lhs.storage, &rhs.storage)?);
+                let phantom_out = out.clone();
+                tape.try_alloc_grad(&lhs)?;
+                tape.try_alloc_grad(&rhs)?;
+                tape.try_alloc_grad(&out)?;
+                tape.add_backward_op(move |grads| {
+                    let (grad_lhs, grad_rhs, grad_out) = grads.muts_and_ref(&lhs, &rhs, &phantom_out);
+                    lhs.device
+                        .backward(&lhs.storage, grad_lhs, &rhs.storage, grad_rhs, grad_out)
+                });
+                Ok(out.put_tape(tape))
+            }
+        }
+    };
+}
+
+kron!();
+kron!(B0);
+kron!(B0, B1);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_kron_2d() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<_, TestDtype, _> = dev.tensor([[1.0, 2.0, 3.0]]);
+        let b: Tensor<_, TestDtype, _> = dev.tensor([[1.0], [-1.0]]);
+        let r = a.trace().kron(b.trace());
+        assert_eq!(r.shape(), &(2, 3));
+        assert_eq!(r.as_vec(), [1.0, 2.0, 3.0, -1.0, -2.0, -3.0]);
+        let g = (r * dev.tensor_from_vec(std::vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0], (2, 3)))
+            .sum()
+            .backward();
+        assert_eq!(g.get(&a).array(), [[-3.0, -3.0, -3.0]]);
+        assert_eq!(g.get(&b).array(), [[14.0], [32.0]]);
+    }
+
+    #[test]
+    fn test_kron_batched() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<Rank3<2, 2, 3>, TestDtype, _> = dev.sample_normal();
+        let b: Tensor<Rank3<2, 4, 2>, TestDtype, _> = dev.sample_normal();
+        let r = kron(a.trace(), b.trace());
+        assert_eq!(r.shape(), &(Const, 8, 6));
+        let r_vec = r.as_vec();
+        let (a_array, b_array) = (a.array(), b.array());
+        for z in 0..2 {
+            for i in 0..2 {
+                for j in 0..3 {
+                    for k in 0..4 {
+                        for l in 0..2 {
+                            assert_eq!(
+                                r_vec[z * 48 + (i * 4 + k) * 6 + j * 2 + l],
+                                a_array[z][i][j] * b_array[z][k][l]
+                            );
+                        }
+                    }
+                }
+            }
+        }
+
+        let g = r.sum().backward();
+        let a_expected = b
+            .clone()
+            .sum::<Rank1<2>, Axes2<1, 2>>()
+            .broadcast::<Rank3<2, 2, 3>, Axes2<1, 2>>();
+        let b_expected = a
+            .clone()
+            .sum::<Rank1<2>, Axes2<1, 2>>()
+            .broadcast::<Rank3<2, 4, 2>, Axes2<1, 2>>();
+        assert_close(&g.get(&a).array(), &a_expected.array());
+        assert_close(&g.get(&b).array(), &b_expected.array());
+    }
+
+    #[test]
+    fn test_kron_broadcasted_input() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<_, TestDtype, _> = dev.tensor([1.0, 2.0]);
+        let b: Tensor<_, TestDtype, _> = dev.tensor([[1.0, -1.0]]);
+        let r = a
+            .trace()
+            .broadcast::<Rank2<2, 2>, Axis<0>>()
+            .kron(b.trace());
+        assert_eq!(r.shape(), &(2, 4));
+        assert_eq!(r.as_vec(), [1.0, -1.0, 2.0, -2.0, 1.0, -1.0, 2.0, -2.0]);
+        let g = r.square().sum().backward();
+        assert_eq!(g.get(&a).array(), [8.0, 16.0]);
+        assert_eq!(g.get(&b).array(), [[20.0, -20.0]]);
+    }
+}
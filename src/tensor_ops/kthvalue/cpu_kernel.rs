@@ -0,0 +1,55 @@
+use crate::{
+    shapes::{Axes, Dtype, HasAxes, ReduceShapeTo, Shape},
+    tensor::cpu::{Cpu, StridedArray},
+    tensor_ops::{sort::nans_last, utilities::reduction_utils::index_for_reductions},
+};
+
+use std::vec::Vec;
+
+impl<E: Dtype> super::KthValueKernel<E> for Cpu {
+    fn forward<Src, Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        &self,
+        dst: Dst,
+        k: usize,
+        inp: &Self::Storage<Src, E>,
+    ) -> Result<(Self::Storage<Dst, E>, Self::Storage<Dst, usize>), Self::Err>
+    where
+        Src: Shape + ReduceShapeTo<Dst, Ax>,
+    {
+        let mut values: StridedArray<Dst, E> = StridedArray::new(dst)?;
+        let mut indices: StridedArray<Dst, usize> = StridedArray::new(dst)?;
+        let num_elems_reduced = <Src as HasAxes<Ax>>::size(&inp.shape);
+        let inp_buf = inp.data.as_ref();
+        let mut idx = index_for_reductions::<Src, Ax>(inp.shape, inp.strides);
+        let mut line: Vec<(E, usize)> = Vec::with_capacity(num_elems_reduced);
+        for (v, i) in values.buf_iter_mut().zip(indices.buf_iter_mut()) {
+            line.clear();
+            line.extend((0..num_elems_reduced).map(|j| (inp_buf[idx.next().unwrap()], j)));
+            let (_, kth, _) =
+                line.select_nth_unstable_by(k, |a, b| nans_last(&a.0, &b.0).then(a.1.cmp(&b.1)));
+            (*v, *i) = *kth;
+        }
+        Ok((values, indices))
+    }
+
+    fn backward<Src, Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        &self,
+        grad_inp: &mut Self::Storage<Src, E>,
+        indices: &Self::Storage<Dst, usize>,
+        grad_out: &Self::Storage<Dst, E>,
+    ) -> Result<(), Self::Err>
+    where
+        Src: Shape + ReduceShapeTo<Dst, Ax>,
+    {
+        let num_elems_reduced = <Src as HasAxes<Ax>>::size(&grad_inp.shape);
+        let mut inp_idx = index_for_reductions::<Src, Ax>(grad_inp.shape, grad_inp.strides);
+        let grad_inp_buf = std::sync::Arc::make_mut(&mut grad_inp.data);
+        let mut line: Vec<usize> = Vec::with_capacity(num_elems_reduced);
+        for (&i, &go) in indices.buf_iter().zip(grad_out.buf_iter()) {
+            line.clear();
+            line.extend((0..num_elems_reduced).map(|_| inp_idx.next().unwrap()));
+            grad_inp_buf[line[i]] += go;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,110 @@
+use crate::{
+    shapes::*,
+    tensor::cuda::{Cuda, CudaArray},
+};
+use cudarc::driver::{LaunchAsync, LaunchConfig};
+use std::sync::Arc;
+
+const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/kthvalue.ptx"));
+
+trait HasCudaKernel<E> {
+    const MOD: &'static str;
+    const FNS: &'static [&'static str];
+}
+
+impl HasCudaKernel<f32> for Cuda {
+    const MOD: &'static str = "kthvalue_f32";
+    const FNS: &'static [&'static str] = &["kthvalue_fwd_f32", "kthvalue_bwd_f32"];
+}
+
+impl HasCudaKernel<f64> for Cuda {
+    const MOD: &'static str = "kthvalue_f64";
+    const FNS: &'static [&'static str] = &["kthvalue_fwd_f64", "kthvalue_bwd_f64"];
+}
+
+impl<E: Dtype> super::KthValueKernel<E> for Cuda
+where
+    Self: HasCudaKernel<E>,
+{
+    fn forward<Src, Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        &self,
+        dst: Dst,
+        k: usize,
+        inp: &Self::Storage<Src, E>,
+    ) -> Result<(Self::Storage<Dst, E>, Self::Storage<Dst, usize>), Self::Err>
+    where
+        Src: Shape + ReduceShapeTo<Dst, Ax>,
+    {
+        if !self.dev.has_func(Self::MOD, Self::FNS[0]) {
+            self.dev.load_ptx(PTX_SRC.into(), Self::MOD, Self::FNS)?;
+        }
+
+        let strides = dst.strides();
+        let mut values = unsafe { self.dev.alloc_async::<E>(dst.num_elements()) }?;
+        let mut indices = unsafe { self.dev.alloc_async::<usize>(dst.num_elements()) }?;
+
+        let numel = inp.shape.num_elements();
+        let dims = self.dev.take_async(inp.shape.concrete().into())?;
+        let inp_strides = self.dev.take_async(inp.strides.into())?;
+        let out_strides = self.dev.take_async(strides.into())?;
+
+        let fwd_fn = self.dev.get_func(Self::MOD, Self::FNS[0]).unwrap();
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            numel,                      // const size_t numel,
+            Src::NUM_DIMS,              // const size_t num_dims,
+            Ax::as_array()[0] as usize, // const size_t axis,
+            k,                          // const size_t k,
+            &dims,                      // const size_t *dims,
+            &inp_strides,               // const size_t *inp_strides,
+            &out_strides,               // const size_t *out_strides,
+            inp.data.as_ref(),          // const T *inp,
+            &mut values,                // T *out,
+            &mut indices,               // size_t *indices
+        );
+        unsafe { fwd_fn.launch_async(cfg, params) }?;
+
+        Ok((
+            CudaArray {
+                data: Arc::new(values),
+                shape: dst,
+                strides,
+            },
+            CudaArray {
+                data: Arc::new(indices),
+                shape: dst,
+                strides,
+            },
+        ))
+    }
+
+    fn backward<Src, Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        &self,
+        grad_inp: &mut Self::Storage<Src, E>,
+        indices: &Self::Storage<Dst, usize>,
+        grad_out: &Self::Storage<Dst, E>,
+    ) -> Result<(), Self::Err>
+    where
+        Src: Shape + ReduceShapeTo<Dst, Ax>,
+    {
+        let bwd_fn = self.dev.get_func(Self::MOD, Self::FNS[1]).unwrap();
+
+        let numel = grad_out.shape.num_elements();
+        let dims = self.dev.take_async(grad_inp.shape.concrete().into())?;
+        let inp_strides = self.dev.take_async(grad_inp.strides.into())?;
+
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            numel,                             // const size_t numel,
+            Src::NUM_DIMS,                     // const size_t num_dims,
+            Ax::as_array()[0] as usize,        // const size_t axis,
+            &dims,                             // const size_t *dims,
+            &inp_strides,                      // const size_t *inp_strides,
+            Arc::make_mut(&mut grad_inp.data), // T *grad_inp,
+            indices.data.as_ref(),             // const size_t *indices,
+            grad_out.data.as_ref(),            // const T *grad_out
+        );
+        unsafe { bwd_fn.launch_async(cfg, params) }?;
+        Ok(())
+    }
+}
@@ -0,0 +1,125 @@
+#include "cuda_utils.cuh"
+
+// Strict ordering that places NaNs after all other values
+template<typename T>
+__device__ bool nans_last_lt(T a, T b) {
+    if (isnan(a)) {
+        return false;
+    }
+    if (isnan(b)) {
+        return true;
+    }
+    return a < b;
+}
+
+// Each thread computes the rank of one input element within its line along `axis`,
+// breaking ties by position. The element with rank `k` writes the output.
+template<typename T>
+__device__ void kthvalue_fwd(
+    const size_t numel,
+    const size_t num_dims,
+    const size_t axis,
+    const size_t k,
+    const size_t *dims,
+    const size_t *inp_strides,
+    const size_t *out_strides,
+    const T *inp,
+    T *out,
+    size_t *indices
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    size_t pos = 0;
+    size_t inp_i = 0;
+    size_t out_i = 0;
+    unsigned int idx = i;
+    for (int d = num_dims - 1; d >= 0; d--) {
+        const size_t i_dim = idx % dims[d];
+        idx /= dims[d];
+        if (d == axis) {
+            pos = i_dim;
+        } else {
+            inp_i += i_dim * inp_strides[d];
+            out_i += i_dim * out_strides[d < axis ? d : d - 1];
+        }
+    }
+
+    const size_t stride = inp_strides[axis];
+    const T x = inp[inp_i + pos * stride];
+    size_t rank = 0;
+    for (size_t j = 0; j < dims[axis]; j++) {
+        const T y = inp[inp_i + j * stride];
+        if (nans_last_lt(y, x) || (j < pos && !nans_last_lt(x, y))) {
+            rank++;
+        }
+    }
+
+    if (rank == k) {
+        out[out_i] = x;
+        indices[out_i] = pos;
+    }
+}
+
+template<typename T>
+__device__ void kthvalue_bwd(
+    const size_t numel,
+    const size_t num_dims,
+    const size_t axis,
+    const size_t *dims,
+    const size_t *inp_strides,
+    T *grad_inp,
+    const size_t *indices,
+    const T *grad_out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    // grad_out & indices are contiguous
+    size_t inp_i = indices[i] * inp_strides[axis];
+    unsigned int idx = i;
+    for (int d = num_dims - 1; d >= 0; d--) {
+        if (d == axis) {
+            continue;
+        }
+        inp_i += (idx % dims[d]) * inp_strides[d];
+        idx /= dims[d];
+    }
+
+    atomicAdd(grad_inp + inp_i, grad_out[i]);
+}
+
+#define KTHVALUE_OP(TYPENAME, FWD, BWD) \
+extern "C" __global__ void FWD( \
+    const size_t numel, \
+    const size_t num_dims, \
+    const size_t axis, \
+    const size_t k, \
+    const size_t *dims, \
+    const size_t *inp_strides, \
+    const size_t *out_strides, \
+    const TYPENAME *inp, \
+    TYPENAME *out, \
+    size_t *indices \
+) { \
+    kthvalue_fwd(numel, num_dims, axis, k, dims, inp_strides, out_strides, inp, out, indices); \
+} \
+extern "C" __global__ void BWD( \
+    const size_t numel, \
+    const size_t num_dims, \
+    const size_t axis, \
+    const size_t *dims, \
+    const size_t *inp_strides, \
+    TYPENAME *grad_inp, \
+    const size_t *indices, \
+    const TYPENAME *grad_out \
+) { \
+    kthvalue_bwd(numel, num_dims, axis, dims, inp_strides, grad_inp, indices, grad_out); \
+}
+
+KTHVALUE_OP(float, kthvalue_fwd_f32, kthvalue_bwd_f32);
+KTHVALUE_OP(double, kthvalue_fwd_f64, kthvalue_bwd_f64);
@@ -0,0 +1,182 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{gradients::Tape, shapes::*, tensor::*};
+
+pub trait KthValueKernel<E: Dtype>: DeviceStorage {
+    /// Finds the `k`th smallest (0 based) value along `Ax` with NaNs placed last, and the
+    /// position along `Ax` it was found at. Ties are broken by position.
+    #[allow(clippy::type_complexity)]
+    fn forward<Src, Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        &self,
+        dst: Dst,
+        k: usize,
+        inp: &Self::Storage<Src, E>,
+    ) -> Result<(Self::Storage<Dst, E>, Self::Storage<Dst, usize>), Self::Err>
+    where
+        Src: Shape + ReduceShapeTo<Dst, Ax>;
+    fn backward<Src, Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        &self,
+        grad_inp: &mut Self::Storage<Src, E>,
+        indices: &Self::Storage<Dst, usize>,
+        grad_out: &Self::Storage<Dst, E>,
+    ) -> Result<(), Self::Err>
+    where
+        Src: Shape + ReduceShapeTo<Dst, Ax>;
+}
+
+/// Reduces a single axis to its `k`th smallest value.
+pub trait KthValueTo<D: DeviceStorage>: HasErr + HasShape {
+    /// Returns the `k`th smallest value along `Ax`, and the position along `Ax` that
+    /// it was found at. `k` starts at `1`, so `k = 1` is the minimum.
+    ///
+    /// NaNs are treated as larger than all other values, and if multiple values are equal
+    /// the one that appears first along `Ax` is considered smaller. The gradient only
+    /// flows to the selected element.
+    ///
+    /// **Pytorch equivalent**: `t.kthvalue(k, dim=Ax)`
+    ///
+    /// Panics if `k` is `0` or larger than the size of `Ax`.
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t = dev.tensor([[3.0, 1.0, 2.0], [-1.0, 5.0, 0.0]]);
+    /// let (values, indices) = t.kthvalue::<Rank1<2>, _>(2);
+    /// assert_eq!(values.array(), [2.0, 0.0]);
+    /// assert_eq!(indices.array(), [2, 2]);
+    /// ```
+    fn kthvalue<Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        self,
+        k: usize,
+    ) -> (Self::WithShape<Dst>, Tensor<Dst, usize, D>)
+    where
+        Self::Shape: ReduceShapeTo<Dst, Ax>,
+    {
+        self.try_kthvalue(k).unwrap()
+    }
+    /// Fallible version of [KthValueTo::kthvalue]
+    #[allow(clippy::type_complexity)]
+    fn try_kthvalue<Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        self,
+        k: usize,
+    ) -> Result<(Self::WithShape<Dst>, Tensor<Dst, usize, D>), Self::Err>
+    where
+        Self::Shape: ReduceShapeTo<Dst, Ax>;
+}
+
+impl<S: Shape, E: Dtype, D: KthValueKernel<E>, T: Tape<D>> KthValueTo<D> for Tensor<S, E, D, T> {
+    fn try_kthvalue<Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        self,
+        k: usize,
+    ) -> Result<(Self::WithShape<Dst>, Tensor<Dst, usize, D>), Self::Err>
+    where
+        Self::Shape: ReduceShapeTo<Dst, Ax>,
+    {
+        let size = <S as HasAxes<Ax>>::size(self.shape());
+        assert!(
+            1 <= k && k <= size,
+            "kthvalue expects 1 <= k <= {size}, but k was {k}"
+        );
+        let dst: Dst = self.shape().reduced();
+        let (inp, mut tape) = self.split_tape();
+        let (values, indices) = inp.device.forward(dst, k - 1, &inp.storage)?;
+        let out = inp.device.upgrade(values);
+        let indices = inp.device.upgrade(indices);
+        let phantom_out = out.clone();
+        let phantom_indices = indices.clone();
+        tape.try_alloc_grad(&inp)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+            inp.device
+                .backward(grad_inp, &phantom_indices.storage, grad_out)
+        });
+        Ok((out.put_tape(tape), indices))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_kthvalue_1d() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([3.0, -1.0, 2.0, 0.5, 2.0]);
+        let expected = [(-1.0, 1), (0.5, 3), (2.0, 2), (2.0, 4), (3.0, 0)];
+        for (k, &(v, i)) in expected.iter().enumerate() {
+            let (values, indices) = t.clone().kthvalue::<Rank0, _>(k + 1);
+            assert_eq!(values.array(), v);
+            assert_eq!(indices.array(), i);
+        }
+    }
+
+    #[test]
+    fn test_kthvalue_backward() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([[3.0, 1.0, 2.0], [-1.0, 5.0, 0.0]]);
+        let (values, indices) = t.trace().kthvalue::<Rank1<2>, _>(3);
+        assert_eq!(values.array(), [3.0, 5.0]);
+        assert_eq!(indices.array(), [0, 1]);
+        let g = values.exp().sum().backward();
+        assert_close(
+            &g.get(&t).array(),
+            &[
+                [TestDtype::exp(3.0), 0.0, 0.0],
+                [0.0, TestDtype::exp(5.0), 0.0],
+            ],
+        );
+    }
+
+    #[test]
+    fn test_kthvalue_axis_0() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank3<5, 2, 3>, TestDtype, _> = dev.sample_normal();
+        let (values, indices) = t.trace().kthvalue::<Rank2<2, 3>, _>(2);
+        let sorted = t.clone().sort::<Axis<0>>().array();
+        let t_arr = t.array();
+        let (v_arr, i_arr) = (values.array(), indices.array());
+        for j in 0..2 {
+            for k in 0..3 {
+                assert_eq!(v_arr[j][k], sorted[1][j][k]);
+                assert_eq!(t_arr[i_arr[j][k]][j][k], v_arr[j][k]);
+            }
+        }
+        let g = values.sum().backward();
+        let g_arr = g.get(&t).array();
+        for (i, g_i) in g_arr.iter().enumerate() {
+            for j in 0..2 {
+                for k in 0..3 {
+                    let expected = if i_arr[j][k] == i { 1.0 } else { 0.0 };
+                    assert_eq!(g_i[j][k], expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_kthvalue_broadcasted() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([2.0, 1.0, 3.0]);
+        let (values, indices) = t
+            .trace()
+            .broadcast::<Rank2<2, 3>, _>()
+            .kthvalue::<Rank1<2>, _>(1);
+        assert_eq!(values.array(), [1.0; 2]);
+        assert_eq!(indices.array(), [1; 2]);
+        let g = values.sum().backward();
+        assert_eq!(g.get(&t).array(), [0.0, 2.0, 0.0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_kthvalue_k_too_large() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank1<3>, TestDtype, _> = dev.zeros();
+        let _ = t.kthvalue::<Rank0, _>(4);
+    }
+}
@@ -0,0 +1,72 @@
+use crate::{
+    shapes::{Dtype, Shape},
+    tensor::cpu::{Cpu, LendingIterator, StridedArray},
+    tensor_ops::cpu_kernels::BinaryDerivative,
+};
+
+use num_traits::Float;
+
+impl<F: Float> BinaryDerivative<F> for super::LerpKernelOp<F> {
+    #[inline(always)]
+    fn f(&self, &x: &F, &y: &F) -> F {
+        x + self.weight * (y - x)
+    }
+
+    #[inline(always)]
+    fn dfdx(&self, _: &F, _: &F) -> F {
+        F::one() - self.weight
+    }
+
+    #[inline(always)]
+    fn dfdy(&self, _: &F, _: &F) -> F {
+        self.weight
+    }
+}
+
+impl<E: Dtype + Float> super::LerpKernel<E> for Cpu {
+    fn forward<S: Shape>(
+        &self,
+        start: &Self::Storage<S, E>,
+        end: &Self::Storage<S, E>,
+        weight: &Self::Storage<S, E>,
+    ) -> Result<Self::Storage<S, E>, Self::Err> {
+        let mut out: StridedArray<S, E> = StridedArray::new(start.shape)?;
+        let mut start_iter = start.iter();
+        let mut end_iter = end.iter();
+        let mut weight_iter = weight.iter();
+        for o in out.buf_iter_mut() {
+            let s = *start_iter.next().unwrap();
+            let e = *end_iter.next().unwrap();
+            let w = *weight_iter.next().unwrap();
+            *o = s + w * (e - s);
+        }
+        Ok(out)
+    }
+
+    fn backward<S: Shape>(
+        &self,
+        start: &Self::Storage<S, E>,
+        grad_start: &mut Self::Storage<S, E>,
+        end: &Self::Storage<S, E>,
+        grad_end: &mut Self::Storage<S, E>,
+        weight: &Self::Storage<S, E>,
+        grad_weight: &mut Self::Storage<S, E>,
+        grad_out: &Self::Storage<S, E>,
+    ) -> Result<(), Self::Err> {
+        let mut start_iter = start.iter();
+        let mut end_iter = end.iter();
+        let mut weight_iter = weight.iter();
+        let mut grad_start_iter = grad_start.iter_mut();
+        let mut grad_end_iter = grad_end.iter_mut();
+        let mut grad_weight_iter = grad_weight.iter_mut();
+        for &go in grad_out.buf_iter() {
+            let s = *start_iter.next().unwrap();
+            let e = *end_iter.next().unwrap();
+            let w = *weight_iter.next().unwrap();
+            *grad_start_iter.next().unwrap() += (E::one() - w) * go;
+            *grad_end_iter.next().unwrap() += w * go;
+            *grad_weight_iter.next().unwrap() += (e - s) * go;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,117 @@
+use super::LerpKernelOp as Lerp;
+use crate::{
+    shapes::*,
+    tensor::cuda::{Cuda, CudaArray},
+    tensor_ops::cuda_kernels::{cuda_binary, packed_info},
+};
+use cudarc::driver::{AsKernelParam, CudaSlice, LaunchAsync, LaunchConfig};
+use std::{sync::Arc, vec::Vec};
+
+unsafe impl AsKernelParam for Lerp<f32> {}
+unsafe impl AsKernelParam for Lerp<f64> {}
+
+const PTX: &str = include_str!(concat!(env!("OUT_DIR"), "/lerp.ptx"));
+
+cuda_binary!(Lerp<f32>, f32, PTX, "lerp_fwd_f32", "lerp_bwd_f32");
+cuda_binary!(Lerp<f64>, f64, PTX, "lerp_fwd_f64", "lerp_bwd_f64");
+
+trait HasCudaKernel<E> {
+    const MOD: &'static str;
+    const FNS: &'static [&'static str];
+}
+
+impl HasCudaKernel<f32> for Cuda {
+    const MOD: &'static str = "lerp_tensor_f32";
+    const FNS: &'static [&'static str] = &["lerp_tensor_fwd_f32", "lerp_tensor_bwd_f32"];
+}
+
+impl HasCudaKernel<f64> for Cuda {
+    const MOD: &'static str = "lerp_tensor_f64";
+    const FNS: &'static [&'static str] = &["lerp_tensor_fwd_f64", "lerp_tensor_bwd_f64"];
+}
+
+/// The shared dims, then the strides of `start`, `end` and `weight`.
+fn info<S: Shape, E>(
+    start: &CudaArray<S, E>,
+    end: &CudaArray<S, E>,
+    weight: &CudaArray<S, E>,
+) -> Vec<usize> {
+    let mut info = packed_info(start);
+    info.extend(end.strides);
+    info.extend(weight.strides);
+    info
+}
+
+impl<E: Dtype + AsKernelParam> super::LerpKernel<E> for Cuda
+where
+    Self: HasCudaKernel<E>,
+{
+    fn forward<S: Shape>(
+        &self,
+        start: &Self::Storage<S, E>,
+        end: &Self::Storage<S, E>,
+        weight: &Self::Storage<S, E>,
+    ) -> Result<Self::Storage<S, E>, Self::Err> {
+        if !self.dev.has_func(Self::MOD, Self::FNS[0]) {
+            self.dev.load_ptx(PTX.into(), Self::MOD, Self::FNS)?;
+        }
+
+        let shape = start.shape;
+        let strides = shape.strides();
+        let numel = shape.num_elements();
+
+        let mut storage = unsafe { self.dev.alloc_async::<E>(numel) }?;
+
+        let info: CudaSlice<usize> = self.dev.take_async(info(start, end, weight))?;
+
+        let fwd_fn = self.dev.get_func(Self::MOD, Self::FNS[0]).unwrap();
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            numel,                // const size_t numel,
+            S::NUM_DIMS,          // const size_t num_dims,
+            &info,                // const size_t *info,
+            start.data.as_ref(),  // const T *start,
+            end.data.as_ref(),    // const T *end,
+            weight.data.as_ref(), // const T *weight,
+            &mut storage,         // T *out
+        );
+        unsafe { fwd_fn.launch_async(cfg, params) }?;
+        Ok(CudaArray {
+            data: Arc::new(storage),
+            shape,
+            strides,
+        })
+    }
+
+    fn backward<S: Shape>(
+        &self,
+        start: &Self::Storage<S, E>,
+        grad_start: &mut Self::Storage<S, E>,
+        end: &Self::Storage<S, E>,
+        grad_end: &mut Self::Storage<S, E>,
+        weight: &Self::Storage<S, E>,
+        grad_weight: &mut Self::Storage<S, E>,
+        grad_out: &Self::Storage<S, E>,
+    ) -> Result<(), Self::Err> {
+        let bwd_fn = self.dev.get_func(Self::MOD, Self::FNS[1]).unwrap();
+        let numel = start.shape.num_elements();
+
+        let info: CudaSlice<usize> = self.dev.take_async(info(start, end, weight))?;
+
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            numel,                                // const size_t numel,
+            S::NUM_DIMS,                          // const size_t num_dims,
+            &info,                                // const size_t *info,
+            start.data.as_ref(),                  // const T *start,
+            Arc::make_mut(&mut grad_start.data),  // T *grad_start,
+            end.data.as_ref(),                    // const T *end,
+            Arc::make_mut(&mut grad_end.data),    // T *grad_end,
+            weight.data.as_ref(),                 // const T *weight,
+            Arc::make_mut(&mut grad_weight.data), // T *grad_weight,
+            grad_out.data.as_ref(),               // const T *grad_out
+        );
+        unsafe { bwd_fn.launch_async(cfg, params) }?;
+        Ok(())
+    }
+}
@@ -0,0 +1,111 @@
+#include "binary_op_macros.cuh"
+
+template<typename T>
+struct LerpOp {
+    T weight;
+};
+
+BINARY_OP(float, lerp_fwd_f32, lerp_bwd_f32, LerpOp<float>,
+    x + op.weight * (y - x),
+    1.0 - op.weight,
+    op.weight
+)
+
+BINARY_OP(double, lerp_fwd_f64, lerp_bwd_f64, LerpOp<double>,
+    x + op.weight * (y - x),
+    1.0 - op.weight,
+    op.weight
+)
+
+template<typename T>
+__device__ void lerp_tensor_fwd(
+    const size_t numel,
+    const size_t num_dims,
+    const size_t *info,
+    const T *start,
+    const T *end,
+    const T *weight,
+    T *out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    // dims, then the strides of start, end and weight
+    const size_t *dims = info;
+    const size_t *start_strides = info + num_dims;
+    const size_t *end_strides = info + 2 * num_dims;
+    const size_t *weight_strides = info + 3 * num_dims;
+
+    const T s = start[get_strided_index(i, num_dims, dims, start_strides)];
+    const T e = end[get_strided_index(i, num_dims, dims, end_strides)];
+    const T w = weight[get_strided_index(i, num_dims, dims, weight_strides)];
+    out[i] = s + w * (e - s);
+}
+
+template<typename T>
+__device__ void lerp_tensor_bwd(
+    const size_t numel,
+    const size_t num_dims,
+    const size_t *info,
+    const T *start,
+    T *grad_start,
+    const T *end,
+    T *grad_end,
+    const T *weight,
+    T *grad_weight,
+    const T *grad_out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    // dims, then the strides of start, end and weight
+    const size_t *dims = info;
+    const size_t *start_strides = info + num_dims;
+    const size_t *end_strides = info + 2 * num_dims;
+    const size_t *weight_strides = info + 3 * num_dims;
+
+    const unsigned int start_i = get_strided_index(i, num_dims, dims, start_strides);
+    const unsigned int end_i = get_strided_index(i, num_dims, dims, end_strides);
+    const unsigned int weight_i = get_strided_index(i, num_dims, dims, weight_strides);
+    const T s = start[start_i];
+    const T e = end[end_i];
+    const T w = weight[weight_i];
+    const T go = grad_out[i];
+    atomicAdd(grad_start + start_i, (1.0 - w) * go);
+    atomicAdd(grad_end + end_i, w * go);
+    atomicAdd(grad_weight + weight_i, (e - s) * go);
+}
+
+#define LERP_TENSOR(TYPENAME, FWD, BWD) \
+extern "C" __global__ void FWD( \
+    const size_t numel, \
+    const size_t num_dims, \
+    const size_t *info, \
+    const TYPENAME *start, \
+    const TYPENAME *end, \
+    const TYPENAME *weight, \
+    TYPENAME *out \
+) { \
+    lerp_tensor_fwd(numel, num_dims, info, start, end, weight, out); \
+} \
+extern "C" __global__ void BWD( \
+    const size_t numel, \
+    const size_t num_dims, \
+    const size_t *info, \
+    const TYPENAME *start, \
+    TYPENAME *grad_start, \
+    const TYPENAME *end, \
+    TYPENAME *grad_end, \
+    const TYPENAME *weight, \
+    TYPENAME *grad_weight, \
+    const TYPENAME *grad_out \
+) { \
+    lerp_tensor_bwd(numel, num_dims, info, start, grad_start, end, grad_end, weight, grad_weight, grad_out); \
+}
+
+LERP_TENSOR(float, lerp_tensor_fwd_f32, lerp_tensor_bwd_f32);
+LERP_TENSOR(double, lerp_tensor_fwd_f64, lerp_tensor_bwd_f64);
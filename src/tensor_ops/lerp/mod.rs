@@ -0,0 +1,180 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use super::ops::{try_binary_op, BinaryKernel};
+use crate::{gradients::*, shapes::*, tensor::*};
+
+/// Linear interpolation with a scalar weight.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct LerpKernelOp<E> {
+    pub weight: E,
+}
+
+pub trait LerpKernel<E: Dtype>: DeviceStorage {
+    /// Computes `start + weight * (end - start)` with a weight per element.
+    fn forward<S: Shape>(
+        &self,
+        start: &Self::Storage<S, E>,
+        end: &Self::Storage<S, E>,
+        weight: &Self::Storage<S, E>,
+    ) -> Result<Self::Storage<S, E>, Self::Err>;
+
+    #[allow(clippy::too_many_arguments)]
+    fn backward<S: Shape>(
+        &self,
+        start: &Self::Storage<S, E>,
+        grad_start: &mut Self::Storage<S, E>,
+        end: &Self::Storage<S, E>,
+        grad_end: &mut Self::Storage<S, E>,
+        weight: &Self::Storage<S, E>,
+        grad_weight: &mut Self::Storage<S, E>,
+        grad_out: &Self::Storage<S, E>,
+    ) -> Result<(), Self::Err>;
+}
+
+/// Linear interpolation `start + weight * (end - start)`, where `weight` is either a
+/// scalar or a tensor of the same shape. Gradients flow into all tensor inputs.
+///
+/// **Pytorch equivalent**: `torch.lerp(start, end, weight)`
+///
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let start = dev.tensor([0.0, 1.0, 2.0]);
+/// let end = dev.tensor([10.0, 3.0, 2.0]);
+/// let r = lerp(start.clone(), end.clone(), 0.5);
+/// assert_eq!(r.array(), [5.0, 2.0, 2.0]);
+///
+/// let weight = dev.tensor([0.0, 0.25, 1.0]);
+/// let r = start.lerp(end, weight);
+/// assert_eq!(r.array(), [0.0, 1.5, 2.0]);
+/// ```
+///
+/// An exponential moving average of parameters:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let ema = dev.tensor([1.0, 2.0]);
+/// let params = dev.tensor([3.0, 2.0]);
+/// let ema = ema.lerp(params, 0.1);
+/// assert_eq!(ema.array(), [1.2, 2.0]);
+/// ```
+pub fn lerp<Start: TryLerp<End, W>, End, W>(start: Start, end: End, weight: W) -> Start {
+    start.lerp(end, weight)
+}
+
+/// Linear interpolation between tensors. See [lerp].
+pub trait TryLerp<End, W>: HasErr {
+    /// See [lerp]
+    fn lerp(self, end: End, weight: W) -> Self {
+        self.try_lerp(end, weight).unwrap()
+    }
+
+    /// Fallible version of [TryLerp::lerp]
+    fn try_lerp(self, end: End, weight: W) -> Result<Self, Self::Err>;
+}
+
+impl<S: Shape, E: Dtype, D, T, R> TryLerp<Tensor<S, E, D, R>, E> for Tensor<S, E, D, T>
+where
+    D: BinaryKernel<LerpKernelOp<E>, E>,
+    T: Tape<D> + Merge<R>,
+    R: Tape<D>,
+{
+    fn try_lerp(self, end: Tensor<S, E, D, R>, weight: E) -> Result<Self, Self::Err> {
+        try_binary_op(LerpKernelOp { weight }, self, end)
+    }
+}
+
+impl<S: Shape, E: Dtype, D: LerpKernel<E>, T, R, W> TryLerp<Tensor<S, E, D, R>, Tensor<S, E, D, W>>
+    for Tensor<S, E, D, T>
+where
+    T: Tape<D> + Merge<R> + Merge<W>,
+    R: Tape<D>,
+    W: Tape<D>,
+{
+    fn try_lerp(
+        self,
+        end: Tensor<S, E, D, R>,
+        weight: Tensor<S, E, D, W>,
+    ) -> Result<Self, Self::Err> {
+        assert_eq!(self.shape(), end.shape());
+        assert_eq!(self.shape(), weight.shape());
+        let (start, tape) = self.split_tape();
+        let (end, end_tape) = end.split_tape();
+        let (weight, weight_tape) = weight.split_tape();
+        let mut tape = tape.merge(end_tape).merge(weight_tape);
+        let storage = start
+            .device
+            .forward(&start.storage, &end.storage, &weight.storage)?;
+        let out = start.device.upgrade(storage);
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&start)?;
+        tape.try_alloc_grad(&end)?;
+        tape.try_alloc_grad(&weight)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let inps = alloc::vec![start.clone(), end.clone(), weight.clone()];
+            let (mut grad_inps, grad_out) = grads.many_and_ref(&inps, &phantom_out);
+            let grad_weight = grad_inps.pop().unwrap();
+            let grad_end = grad_inps.pop().unwrap();
+            let grad_start = grad_inps.pop().unwrap();
+            start.device.backward(
+                &start.storage,
+                grad_start,
+                &end.storage,
+                grad_end,
+                &weight.storage,
+                grad_weight,
+                grad_out,
+            )
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_lerp_scalar_weight() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<_, TestDtype, _> = dev.tensor([[1.0, -2.0], [0.0, 4.0]]);
+        let b: Tensor<_, TestDtype, _> = dev.tensor([[3.0, 2.0], [-1.0, 4.0]]);
+        let r = a.trace().lerp(b.trace(), 0.25);
+        assert_eq!(r.array(), [[1.5, -1.0], [-0.25, 4.0]]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&a).array(), [[0.75; 2]; 2]);
+        assert_eq!(g.get(&b).array(), [[0.25; 2]; 2]);
+    }
+
+    #[test]
+    fn test_lerp_tensor_weight() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<_, TestDtype, _> = dev.tensor([1.0, -2.0, 0.0]);
+        let b: Tensor<_, TestDtype, _> = dev.tensor([3.0, 2.0, -1.0]);
+        let w: Tensor<_, TestDtype, _> = dev.tensor([0.5, 0.0, 2.0]);
+        let r = a.trace().lerp(b.trace(), w.trace());
+        assert_eq!(r.array(), [2.0, -2.0, -2.0]);
+        let g = (r * dev.tensor([1.0, 2.0, 3.0])).sum().backward();
+        assert_eq!(g.get(&a).array(), [0.5, 2.0, -3.0]);
+        assert_eq!(g.get(&b).array(), [0.5, 0.0, 6.0]);
+        assert_eq!(g.get(&w).array(), [2.0, 8.0, -3.0]);
+    }
+
+    #[test]
+    fn test_lerp_broadcasted_weight() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<Rank2<2, 3>, TestDtype, _> = dev.zeros();
+        let b: Tensor<Rank2<2, 3>, TestDtype, _> = dev.ones();
+        let w: Tensor<_, TestDtype, _> = dev.tensor([0.0, 0.5]);
+        let r = a.trace().lerp(b, w.trace().broadcast::<Rank2<2, 3>, _>());
+        assert_eq!(r.array(), [[0.0; 3], [0.5; 3]]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&w).array(), [3.0, 3.0]);
+    }
+}
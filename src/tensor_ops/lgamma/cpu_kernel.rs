@@ -0,0 +1,24 @@
+use crate::tensor_ops::{cpu_kernels::UnaryDerivative, special};
+use num_traits::Float;
+
+impl<F: Float> UnaryDerivative<F> for super::LgammaKernelOp {
+    #[inline(always)]
+    fn f(&self, x: &F) -> F {
+        F::from(special::lgamma(x.to_f64().unwrap())).unwrap()
+    }
+    #[inline(always)]
+    fn df(&self, x: &F) -> F {
+        F::from(special::digamma(x.to_f64().unwrap())).unwrap()
+    }
+}
+
+impl<F: Float> UnaryDerivative<F> for super::DigammaKernelOp {
+    #[inline(always)]
+    fn f(&self, x: &F) -> F {
+        F::from(special::digamma(x.to_f64().unwrap())).unwrap()
+    }
+    #[inline(always)]
+    fn df(&self, x: &F) -> F {
+        F::from(special::trigamma(x.to_f64().unwrap())).unwrap()
+    }
+}
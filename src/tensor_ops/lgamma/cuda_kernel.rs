@@ -0,0 +1,24 @@
+use super::{DigammaKernelOp, LgammaKernelOp};
+use crate::tensor_ops::cuda_kernels::cuda_unary;
+
+unsafe impl cudarc::driver::AsKernelParam for LgammaKernelOp {}
+unsafe impl cudarc::driver::AsKernelParam for DigammaKernelOp {}
+
+const PTX: &str = include_str!(concat!(env!("OUT_DIR"), "/lgamma.ptx"));
+
+cuda_unary!(LgammaKernelOp, f32, PTX, "lgamma_fwd_f32", "lgamma_bwd_f32");
+cuda_unary!(LgammaKernelOp, f64, PTX, "lgamma_fwd_f64", "lgamma_bwd_f64");
+cuda_unary!(
+    DigammaKernelOp,
+    f32,
+    PTX,
+    "digamma_fwd_f32",
+    "digamma_bwd_f32"
+);
+cuda_unary!(
+    DigammaKernelOp,
+    f64,
+    PTX,
+    "digamma_fwd_f64",
+    "digamma_bwd_f64"
+);
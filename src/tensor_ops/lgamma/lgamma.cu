@@ -0,0 +1,68 @@
+#include "unary_op_macros.cuh"
+#include "cuda_utils.cuh"
+#define _USE_MATH_DEFINES
+#include <math.h>
+
+struct LgammaKernelOp {};
+struct DigammaKernelOp {};
+
+// Mirrors `special::digamma`: recurrence up to 10, then the asymptotic series.
+template<typename T>
+__device__ T digammag(T x) {
+    if (x <= 0.0 && x == floor(x)) {
+        return NAN;
+    }
+    T acc = 0.0;
+    if (x < 0.0) {
+        acc = -M_PI / tan(M_PI * x);
+        x = 1.0 - x;
+    }
+    while (x < 10.0) {
+        acc -= 1.0 / x;
+        x += 1.0;
+    }
+    T inv_sq = 1.0 / (x * x);
+    T series = inv_sq * (1.0 / 12.0 - inv_sq * (1.0 / 120.0 - inv_sq * (1.0 / 252.0 - inv_sq * (1.0 / 240.0 - inv_sq / 132.0))));
+    return acc + logg(x) - 0.5 / x - series;
+}
+
+// Mirrors `special::trigamma`: recurrence up to 10, then the asymptotic series.
+template<typename T>
+__device__ T trigammag(T x) {
+    if (x <= 0.0 && x == floor(x)) {
+        return NAN;
+    }
+    T acc = 0.0;
+    T sign = 1.0;
+    if (x < 0.0) {
+        T s = sin(M_PI * x);
+        acc = M_PI * M_PI / (s * s);
+        sign = -1.0;
+        x = 1.0 - x;
+    }
+    T rec = 0.0;
+    while (x < 10.0) {
+        rec += 1.0 / (x * x);
+        x += 1.0;
+    }
+    T inv = 1.0 / x;
+    T inv_sq = inv * inv;
+    T series = inv + 0.5 * inv_sq + inv * inv_sq * (1.0 / 6.0 - inv_sq * (1.0 / 30.0 - inv_sq * (1.0 / 42.0 - inv_sq * (1.0 / 30.0 - inv_sq * (5.0 / 66.0)))));
+    return acc + sign * (rec + series);
+}
+
+UNARY_OP(float, lgamma_fwd_f32, lgamma_bwd_f32, LgammaKernelOp,
+        lgammaf(x),
+        digammag(x))
+
+UNARY_OP(double, lgamma_fwd_f64, lgamma_bwd_f64, LgammaKernelOp,
+        lgamma(x),
+        digammag(x))
+
+UNARY_OP(float, digamma_fwd_f32, digamma_bwd_f32, DigammaKernelOp,
+        digammag(x),
+        trigammag(x))
+
+UNARY_OP(double, digamma_fwd_f64, digamma_bwd_f64, DigammaKernelOp,
+        digammag(x),
+        trigammag(x))
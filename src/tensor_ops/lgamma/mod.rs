@@ -0,0 +1,130 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use super::ops::{try_unary_op, UnaryKernel};
+use crate::{gradients::Tape, shapes::*, tensor::Tensor};
+
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct LgammaKernelOp;
+
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct DigammaKernelOp;
+
+/// Natural log of the absolute value of the [gamma function](https://en.wikipedia.org/wiki/Gamma_function).
+/// Returns `inf` at the poles `0, -1, -2, ...`.
+///
+/// It's derivative is [digamma].
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([0.5, 1.0, 2.0, 5.0]);
+/// let r = t.lgamma();
+/// ```
+///
+/// The log pdf of a beta distribution:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let x = dev.tensor([0.2, 0.5, 0.9]);
+/// let (a, b) = (2.0, 3.0);
+/// let ln_beta = dev.tensor([a, b, a + b]).lgamma().array();
+/// let ln_beta = ln_beta[0] + ln_beta[1] - ln_beta[2];
+/// let log_pdf = x.clone().ln() * (a - 1.0) + (-x + 1.0).ln() * (b - 1.0) - ln_beta;
+/// ```
+pub fn lgamma<S: Shape, E: Dtype, D: UnaryKernel<LgammaKernelOp, E>, T: Tape<D>>(
+    t: Tensor<S, E, D, T>,
+) -> Tensor<S, E, D, T> {
+    t.lgamma()
+}
+
+/// [Digamma function](https://en.wikipedia.org/wiki/Digamma_function), the derivative of
+/// [lgamma]. Returns `nan` at the poles `0, -1, -2, ...`.
+///
+/// It's derivative is the [trigamma function](https://en.wikipedia.org/wiki/Trigamma_function).
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([0.5, 1.0, 2.0, 5.0]);
+/// let r = t.digamma();
+/// ```
+pub fn digamma<S: Shape, E: Dtype, D: UnaryKernel<DigammaKernelOp, E>, T: Tape<D>>(
+    t: Tensor<S, E, D, T>,
+) -> Tensor<S, E, D, T> {
+    t.digamma()
+}
+
+impl<S: Shape, E: Dtype, D: UnaryKernel<LgammaKernelOp, E>, T: Tape<D>> Tensor<S, E, D, T> {
+    /// See [lgamma]
+    pub fn lgamma(self) -> Self {
+        self.try_lgamma().unwrap()
+    }
+    /// See [lgamma]
+    pub fn try_lgamma(self) -> Result<Self, D::Err> {
+        try_unary_op(LgammaKernelOp, self)
+    }
+}
+
+impl<S: Shape, E: Dtype, D: UnaryKernel<DigammaKernelOp, E>, T: Tape<D>> Tensor<S, E, D, T> {
+    /// See [digamma]
+    pub fn digamma(self) -> Self {
+        self.try_digamma().unwrap()
+    }
+    /// See [digamma]
+    pub fn try_digamma(self) -> Result<Self, D::Err> {
+        try_unary_op(DigammaKernelOp, self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::*;
+    use crate::{tensor::*, tensor_ops::*};
+
+    #[test]
+    fn test_lgamma() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([-2.5, 0.25, 1.0, 2.5, 7.0]);
+        let r = x.trace().lgamma();
+        assert_close(
+            &r.array(),
+            &[-0.05624372, 1.2880225, 0.0, 0.28468287, 6.579251],
+        );
+        let g = r.mean().backward();
+        assert_close(
+            &g.get(&x).array(),
+            &[0.22063133, -0.8454907, -0.11544313, 0.14063133, 0.37455687],
+        );
+    }
+
+    #[test]
+    fn test_digamma() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([-2.5, 0.25, 1.0, 2.5, 7.0]);
+        let r = x.trace().digamma();
+        assert_close(
+            &r.array(),
+            &[1.1031566, -4.2274535, -0.5772157, 0.70315665, 1.8727843],
+        );
+        let g = r.mean().backward();
+        assert_close(
+            &g.get(&x).array(),
+            &[1.9078493, 3.4394658, 0.3289868, 0.09807155, 0.030709036],
+        );
+    }
+
+    #[test]
+    fn test_lgamma_poles() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([0.0, -1.0, -4.0]);
+        assert_eq!(x.clone().lgamma().array(), [TestDtype::INFINITY; 3]);
+        assert!(x.digamma().array().iter().all(|x| x.is_nan()));
+    }
+}
@@ -0,0 +1,83 @@
+use crate::shapes::{Dtype, Shape};
+use crate::tensor::cpu::{Cpu, LendingIterator, StridedArray};
+
+use super::{LocalResponseNormKernel, LocalResponseNormOp};
+
+use num_traits::Float;
+use std::{sync::Arc, vec::Vec};
+
+impl<E: Dtype + Float> LocalResponseNormOp<E> {
+    /// The range of channels in the window around channel `c`
+    fn window(&self, c: usize) -> core::ops::RangeInclusive<usize> {
+        c.saturating_sub(self.size / 2)..=(c + (self.size - 1) / 2).min(self.chan - 1)
+    }
+
+    /// `k + alpha / size * sum(x[c'] ^ 2)` for the element at logical index `i` of the
+    /// contiguous `x`
+    fn scale(&self, x: &[E], i: usize) -> E {
+        let c = (i / self.inner) % self.chan;
+        let base = i - c * self.inner;
+        let sum_sq = self
+            .window(c)
+            .map(|c| x[base + c * self.inner].powi(2))
+            .fold(E::zero(), |a, b| a + b);
+        self.k + self.alpha / E::from_usize(self.size).unwrap() * sum_sq
+    }
+}
+
+fn to_contiguous<S: Shape, E: Dtype>(t: &StridedArray<S, E>) -> Vec<E> {
+    let mut data = Vec::with_capacity(t.shape.num_elements());
+    let mut iter = t.iter();
+    while let Some(x) = iter.next() {
+        data.push(*x);
+    }
+    data
+}
+
+impl<E: Dtype + Float> LocalResponseNormKernel<E> for Cpu {
+    fn forward<S: Shape>(
+        &self,
+        op: LocalResponseNormOp<E>,
+        inp: &Self::Storage<S, E>,
+    ) -> Result<Self::Storage<S, E>, Self::Err> {
+        let x = to_contiguous(inp);
+        let mut out: StridedArray<S, E> = StridedArray::new(inp.shape)?;
+        let buf = Arc::make_mut(&mut out.data);
+        for (i, y) in buf.iter_mut().enumerate() {
+            *y = x[i] * op.scale(&x, i).powf(-op.beta);
+        }
+        Ok(out)
+    }
+
+    fn backward<S: Shape>(
+        &self,
+        op: LocalResponseNormOp<E>,
+        inp: &Self::Storage<S, E>,
+        grad_inp: &mut Self::Storage<S, E>,
+        out: &Self::Storage<S, E>,
+        grad_out: &Self::Storage<S, E>,
+    ) -> Result<(), Self::Err> {
+        let x = to_contiguous(inp);
+        let scale: Vec<E> = (0..x.len()).map(|i| op.scale(&x, i)).collect();
+        // out & grad_out are both contiguous
+        let (y, dy) = (out.data.as_slice(), grad_out.data.as_slice());
+        let coef = E::from_f32(2.0).unwrap() * op.alpha * op.beta / E::from_usize(op.size).unwrap();
+
+        let mut grad_inp_iter = grad_inp.iter_mut();
+        let mut i = 0;
+        while let Some(g) = grad_inp_iter.next() {
+            // channel c' has channel c in its window iff c' is in [c - (size - 1) / 2, c + size / 2]
+            let c = (i / op.inner) % op.chan;
+            let base = i - c * op.inner;
+            let lo = c.saturating_sub((op.size - 1) / 2);
+            let hi = (c + op.size / 2).min(op.chan - 1);
+            let cross = (lo..=hi)
+                .map(|c| base + c * op.inner)
+                .map(|j| dy[j] * y[j] / scale[j])
+                .fold(E::zero(), |a, b| a + b);
+            *g += dy[i] * scale[i].powf(-op.beta) - coef * x[i] * cross;
+            i += 1;
+        }
+        Ok(())
+    }
+}
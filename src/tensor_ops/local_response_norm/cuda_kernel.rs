@@ -0,0 +1,102 @@
+use crate::{
+    shapes::*,
+    tensor::cuda::{Cuda, CudaArray},
+};
+use cudarc::driver::{AsKernelParam, LaunchAsync, LaunchConfig};
+use std::sync::Arc;
+
+use super::LocalResponseNormOp;
+
+const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/local_response_norm.ptx"));
+
+unsafe impl AsKernelParam for LocalResponseNormOp<f32> {}
+unsafe impl AsKernelParam for LocalResponseNormOp<f64> {}
+
+trait HasCudaKernel<E> {
+    const MOD: &'static str;
+    const FNS: &'static [&'static str];
+}
+
+impl HasCudaKernel<f32> for Cuda {
+    const MOD: &'static str = "local_response_norm_f32";
+    const FNS: &'static [&'static str] =
+        &["local_response_norm_fwd_f32", "local_response_norm_bwd_f32"];
+}
+
+impl HasCudaKernel<f64> for Cuda {
+    const MOD: &'static str = "local_response_norm_f64";
+    const FNS: &'static [&'static str] =
+        &["local_response_norm_fwd_f64", "local_response_norm_bwd_f64"];
+}
+
+impl<E: Dtype> super::LocalResponseNormKernel<E> for Cuda
+where
+    Self: HasCudaKernel<E>,
+    LocalResponseNormOp<E>: AsKernelParam,
+{
+    fn forward<S: Shape>(
+        &self,
+        op: LocalResponseNormOp<E>,
+        inp: &Self::Storage<S, E>,
+    ) -> Result<Self::Storage<S, E>, Self::Err> {
+        if !self.dev.has_func(Self::MOD, Self::FNS[0]) {
+            self.dev.load_ptx(PTX_SRC.into(), Self::MOD, Self::FNS)?;
+        }
+
+        let shape = inp.shape;
+        let numel = shape.num_elements();
+        let mut storage = unsafe { self.dev.alloc_async::<E>(numel) }?;
+
+        let dims = self.dev.take_async(shape.concrete().into())?;
+        let inp_strides = self.dev.take_async(inp.strides.into())?;
+
+        let fwd_fn = self.dev.get_func(Self::MOD, Self::FNS[0]).unwrap();
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            op,                // const LocalResponseNormOp<T> op,
+            numel,             // const size_t numel,
+            S::NUM_DIMS,       // const size_t num_dims,
+            &dims,             // const size_t *dims,
+            &inp_strides,      // const size_t *inp_strides,
+            inp.data.as_ref(), // const T *inp,
+            &mut storage,      // T *out
+        );
+        unsafe { fwd_fn.launch_async(cfg, params) }?;
+
+        Ok(CudaArray {
+            data: Arc::new(storage),
+            shape,
+            strides: shape.strides(),
+        })
+    }
+
+    fn backward<S: Shape>(
+        &self,
+        op: LocalResponseNormOp<E>,
+        inp: &Self::Storage<S, E>,
+        grad_inp: &mut Self::Storage<S, E>,
+        out: &Self::Storage<S, E>,
+        grad_out: &Self::Storage<S, E>,
+    ) -> Result<(), Self::Err> {
+        let numel = inp.shape.num_elements();
+
+        let dims = self.dev.take_async(inp.shape.concrete().into())?;
+        let inp_strides = self.dev.take_async(inp.strides.into())?;
+
+        let bwd_fn = self.dev.get_func(Self::MOD, Self::FNS[1]).unwrap();
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            op,                                // const LocalResponseNormOp<T> op,
+            numel,                             // const size_t numel,
+            S::NUM_DIMS,                       // const size_t num_dims,
+            &dims,                             // const size_t *dims,
+            &inp_strides,                      // const size_t *inp_strides,
+            inp.data.as_ref(),                 // const T *inp,
+            Arc::make_mut(&mut grad_inp.data), // T *grad_inp,
+            out.data.as_ref(),                 // const T *out,
+            grad_out.data.as_ref(),            // const T *grad_out
+        );
+        unsafe { bwd_fn.launch_async(cfg, params) }?;
+        Ok(())
+    }
+}
@@ -0,0 +1,118 @@
+#include "cuda_utils.cuh"
+
+template<typename T>
+struct LocalResponseNormOp {
+    size_t size;
+    T alpha;
+    T beta;
+    T k;
+    size_t chan;
+    size_t inner;
+};
+
+// `k + alpha / size * sum(x[c'] ^ 2)` over the window of channels around the element at
+// logical index `i`
+template<typename T>
+__device__ T lrn_scale(
+    const LocalResponseNormOp<T> op,
+    const size_t i,
+    const size_t num_dims,
+    const size_t *dims,
+    const size_t *inp_strides,
+    const T *inp
+) {
+    size_t c = (i / op.inner) % op.chan;
+    size_t base = i - c * op.inner;
+    size_t lo = c >= op.size / 2 ? c - op.size / 2 : 0;
+    size_t hi = min(c + (op.size - 1) / 2, op.chan - 1);
+    T sum_sq = 0.0;
+    for (size_t cc = lo; cc <= hi; cc++) {
+        T x = inp[get_strided_index(base + cc * op.inner, num_dims, dims, inp_strides)];
+        sum_sq += x * x;
+    }
+    return op.k + op.alpha / op.size * sum_sq;
+}
+
+template<typename T>
+__device__ void local_response_norm_fwd(
+    const LocalResponseNormOp<T> op,
+    const size_t numel,
+    const size_t num_dims,
+    const size_t *dims,
+    const size_t *inp_strides,
+    const T *inp,
+    T *out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    T x = inp[get_strided_index(i, num_dims, dims, inp_strides)];
+    out[i] = x * powg(lrn_scale(op, i, num_dims, dims, inp_strides, inp), -op.beta);
+}
+
+// `out` & `grad_out` are contiguous, and `grad_inp` has the strides of `inp`
+template<typename T>
+__device__ void local_response_norm_bwd(
+    const LocalResponseNormOp<T> op,
+    const size_t numel,
+    const size_t num_dims,
+    const size_t *dims,
+    const size_t *inp_strides,
+    const T *inp,
+    T *grad_inp,
+    const T *out,
+    const T *grad_out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    // channel c' has channel c in its window iff c' is in [c - (size - 1) / 2, c + size / 2]
+    size_t c = (i / op.inner) % op.chan;
+    size_t base = i - c * op.inner;
+    size_t lo = c >= (op.size - 1) / 2 ? c - (op.size - 1) / 2 : 0;
+    size_t hi = min(c + op.size / 2, op.chan - 1);
+    T cross = 0.0;
+    for (size_t cc = lo; cc <= hi; cc++) {
+        size_t j = base + cc * op.inner;
+        cross += grad_out[j] * out[j] / lrn_scale(op, j, num_dims, dims, inp_strides, inp);
+    }
+
+    size_t inp_i = get_strided_index(i, num_dims, dims, inp_strides);
+    T scale = lrn_scale(op, i, num_dims, dims, inp_strides, inp);
+    T coef = 2.0 * op.alpha * op.beta / op.size;
+    T dx = grad_out[i] * powg(scale, -op.beta) - coef * inp[inp_i] * cross;
+    atomicAdd(grad_inp + inp_i, dx);
+}
+
+#define LRN_OP(TYPENAME, FWD, BWD) \
+extern "C" __global__ void FWD( \
+    const LocalResponseNormOp<TYPENAME> op, \
+    const size_t numel, \
+    const size_t num_dims, \
+    const size_t *dims, \
+    const size_t *inp_strides, \
+    const TYPENAME *inp, \
+    TYPENAME *out \
+) { \
+    local_response_norm_fwd(op, numel, num_dims, dims, inp_strides, inp, out); \
+} \
+extern "C" __global__ void BWD( \
+    const LocalResponseNormOp<TYPENAME> op, \
+    const size_t numel, \
+    const size_t num_dims, \
+    const size_t *dims, \
+    const size_t *inp_strides, \
+    const TYPENAME *inp, \
+    TYPENAME *grad_inp, \
+    const TYPENAME *out, \
+    const TYPENAME *grad_out \
+) { \
+    local_response_norm_bwd(op, numel, num_dims, dims, inp_strides, inp, grad_inp, out, grad_out); \
+}
+
+LRN_OP(float, local_response_norm_fwd_f32, local_response_norm_bwd_f32);
+LRN_OP(double, local_response_norm_fwd_f64, local_response_norm_bwd_f64);
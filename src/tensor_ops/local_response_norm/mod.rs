@@ -0,0 +1,185 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{gradients::Tape, shapes::*, tensor::*};
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct LocalResponseNormOp<E> {
+    /// The number of channels in the window around each channel
+    pub size: usize,
+    pub alpha: E,
+    pub beta: E,
+    pub k: E,
+    /// The number of channels
+    pub chan: usize,
+    /// The number of elements between two consecutive channels, i.e. the product of all
+    /// dimensions after the channel axis
+    pub inner: usize,
+}
+
+pub trait LocalResponseNormKernel<E: Dtype>: DeviceStorage {
+    fn forward<S: Shape>(
+        &self,
+        op: LocalResponseNormOp<E>,
+        inp: &Self::Storage<S, E>,
+    ) -> Result<Self::Storage<S, E>, Self::Err>;
+
+    fn backward<S: Shape>(
+        &self,
+        op: LocalResponseNormOp<E>,
+        inp: &Self::Storage<S, E>,
+        grad_inp: &mut Self::Storage<S, E>,
+        out: &Self::Storage<S, E>,
+        grad_out: &Self::Storage<S, E>,
+    ) -> Result<(), Self::Err>;
+}
+
+/// Local response normalization across the channels in `Ax`, as used in AlexNet. Every
+/// element is divided by a power of the sum of squares of the `size` channels around it:
+///
+/// `y[c] = x[c] / (k + alpha / size * sum(x[c'] ^ 2))^beta`
+///
+/// where `c'` ranges over `c - size / 2..=c + (size - 1) / 2`, ignoring channels that are
+/// out of bounds.
+///
+/// **Pytorch equivalent**: `torch.nn.functional.local_response_norm(t, size, alpha, beta, k)`,
+/// which always normalizes across `Axis<1>`.
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t: Tensor<Rank2<3, 2>, f32, _> = dev.tensor([[1.0, 2.0], [1.0, 0.0], [1.0, 2.0]]);
+/// let r = t.local_response_norm::<Axis<0>>(2, 2.0, 1.0, 0.0);
+/// assert_eq!(r.array(), [[1.0, 0.5], [0.5, 0.0], [0.5, 0.5]]);
+/// ```
+pub trait TryLocalResponseNorm<E>: HasErr + HasShape {
+    fn local_response_norm<Ax: Axes<Array = [isize; 1]>>(
+        self,
+        size: usize,
+        alpha: E,
+        beta: E,
+        k: E,
+    ) -> Self
+    where
+        Self::Shape: HasAxes<Ax>,
+    {
+        self.try_local_response_norm::<Ax>(size, alpha, beta, k)
+            .unwrap()
+    }
+    fn try_local_response_norm<Ax: Axes<Array = [isize; 1]>>(
+        self,
+        size: usize,
+        alpha: E,
+        beta: E,
+        k: E,
+    ) -> Result<Self, Self::Err>
+    where
+        Self::Shape: HasAxes<Ax>;
+}
+
+impl<S: Shape, E: Dtype, D: LocalResponseNormKernel<E>, T: Tape<D>> TryLocalResponseNorm<E>
+    for Tensor<S, E, D, T>
+{
+    fn try_local_response_norm<Ax: Axes<Array = [isize; 1]>>(
+        self,
+        size: usize,
+        alpha: E,
+        beta: E,
+        k: E,
+    ) -> Result<Self, Self::Err>
+    where
+        Self::Shape: HasAxes<Ax>,
+    {
+        assert!(size > 0, "size must be positive");
+        let axis = Ax::as_array()[0] as usize;
+        let dims = self.shape().concrete();
+        let op = LocalResponseNormOp {
+            size,
+            alpha,
+            beta,
+            k,
+            chan: dims[axis],
+            inner: dims.into_iter().skip(axis + 1).product(),
+        };
+        let (inp, mut tape) = self.split_tape();
+        let out = inp.device.upgrade(inp.device.forward(op, &inp.storage)?);
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&inp)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+            inp.device
+                .backward(op, &inp.storage, grad_inp, &phantom_out.storage, grad_out)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{gradients::OwnedTape, shapes::*, tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_local_response_norm_matches_composed() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank3<2, 4, 3>, TestDtype, _> = dev.sample_normal();
+        let r = x.trace().local_response_norm::<Axis<1>>(3, 0.5, 0.75, 2.0);
+
+        // sum of squares over the window [c - 1, c + 1] using shifted copies
+        let sq = x.trace().square();
+        let zeros: Tensor<_, TestDtype, _> = dev.zeros_like(&(Const::<2>, 1, Const::<3>));
+        let prev = std::vec![
+            zeros.retaped::<OwnedTape<_>>(),
+            sq.retaped().slice((.., ..3, ..))
+        ]
+        .concat_along(Axis::<1>)
+        .reshape_like(x.shape());
+        let next = std::vec![
+            sq.retaped::<OwnedTape<_>>().slice((.., 1.., ..)),
+            zeros.retaped()
+        ]
+        .concat_along(Axis::<1>)
+        .reshape_like(x.shape());
+        let div = ((sq + prev + next) * (0.5 / 3.0) + 2.0).powf(-0.75);
+        let r2 = x.trace() * div;
+
+        assert_close(&r.array(), &r2.array());
+        let w: Tensor<Rank3<2, 4, 3>, TestDtype, _> = dev.sample_normal();
+        let g = (r * w.clone()).sum().backward();
+        let g2 = (r2 * w).sum().backward();
+        assert_close(&g.get(&x).array(), &g2.get(&x).array());
+    }
+
+    #[test]
+    fn test_local_response_norm_even_size() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank2<2, 4>, TestDtype, _> =
+            dev.tensor([[1.0, -1.0, 2.0, 0.5], [0.0, 3.0, -2.0, 1.0]]);
+        // with size 2, the window of channel c is [c - 1, c]
+        let r = x
+            .trace()
+            .permute::<Rank2<4, 2>, _>()
+            .local_response_norm::<Axis<0>>(2, 1.0, 1.0, 1.0);
+        assert_close(
+            &r.array(),
+            &[
+                [2.0 / 3.0, 0.0],
+                [-0.5, 6.0 / 11.0],
+                [4.0 / 7.0, -4.0 / 15.0],
+                [0.16, 2.0 / 7.0],
+            ],
+        );
+        let g = r.sum().backward();
+        assert_close(
+            &g.get(&x).array(),
+            &[
+                [0.4722222, 0.41326531, -0.14321633, 0.2944],
+                [1.0, -0.00903581, 0.22548753, 0.20408163],
+            ],
+        );
+    }
+}
@@ -0,0 +1,20 @@
+use crate::tensor_ops::cuda_kernels::cuda_unary;
+
+unsafe impl cudarc::driver::AsKernelParam for super::Log1pKernelOp {}
+
+const PTX: &str = include_str!(concat!(env!("OUT_DIR"), "/log1p.ptx"));
+
+cuda_unary!(
+    super::Log1pKernelOp,
+    f32,
+    PTX,
+    "log1p_fwd_f32",
+    "log1p_bwd_f32"
+);
+cuda_unary!(
+    super::Log1pKernelOp,
+    f64,
+    PTX,
+    "log1p_fwd_f64",
+    "log1p_bwd_f64"
+);
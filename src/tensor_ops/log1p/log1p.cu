@@ -0,0 +1,11 @@
+#include "unary_op_macros.cuh"
+
+struct Log1pKernelOp {};
+
+UNARY_OP(float, log1p_fwd_f32, log1p_bwd_f32, Log1pKernelOp,
+        log1pf(x),
+        1.0 / (1.0 + x))
+
+UNARY_OP(double, log1p_fwd_f64, log1p_bwd_f64, Log1pKernelOp,
+        log1p(x),
+        1.0 / (1.0 + x))
@@ -0,0 +1,77 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use super::ops::{try_unary_op, UnaryKernel};
+use crate::{gradients::Tape, shapes::*, tensor::Tensor};
+
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct Log1pKernelOp;
+
+/// `ln(1 + t)`, computed without losing precision when `t` is close to 0.
+///
+/// It's derivative is `1 / (1 + t)`.
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([-0.5, 0.0, 1e-7, 2.0]);
+/// let r = t.log1p();
+/// ```
+pub fn log1p<S: Shape, E: Dtype, D: UnaryKernel<Log1pKernelOp, E>, T: Tape<D>>(
+    t: Tensor<S, E, D, T>,
+) -> Tensor<S, E, D, T> {
+    t.log1p()
+}
+
+impl<S: Shape, E: Dtype, D: UnaryKernel<Log1pKernelOp, E>, T: Tape<D>> Tensor<S, E, D, T> {
+    /// See [log1p]
+    pub fn log1p(self) -> Self {
+        self.try_log1p().unwrap()
+    }
+    /// See [log1p]
+    pub fn try_log1p(self) -> Result<Self, D::Err> {
+        try_unary_op(Log1pKernelOp, self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_log1p() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([-0.5, -1e-7, 0.0, 1e-7, 2.0]);
+        let r = x.trace().log1p();
+        let r_array = r.array();
+        assert_close(
+            &r_array,
+            &[
+                -(core::f64::consts::LN_2 as TestDtype),
+                -1e-7,
+                0.0,
+                9.9999995e-8,
+                1.0986123,
+            ],
+        );
+        assert!((r_array[3] - 9.9999995e-8).abs() < 1e-13);
+        let g = r.mean().backward();
+        assert_close(
+            &g.get(&x).array(),
+            &[0.4, 0.20000002, 0.2, 0.19999998, 0.06666667],
+        );
+    }
+
+    #[test]
+    fn test_log1p_domain() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([-1.0, -2.0]);
+        let r = x.log1p().array();
+        assert_eq!(r[0], TestDtype::NEG_INFINITY);
+        assert!(r[1].is_nan());
+    }
+}
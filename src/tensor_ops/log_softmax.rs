@@ -1,7 +1,8 @@
-use super::{BroadcastTo, Device, LogSumExpTo, TrySub};
+use super::Device;
 use crate::{gradients::Tape, shapes::*, tensor::Tensor};
 
-/// `log(softmax(t))` in numerically stable way across `Ax`. Does `t - logsumexp(t)` under the hood.
+/// `log(softmax(t))` in numerically stable way across `Ax`, i.e. `t - logsumexp(t)` computed
+/// in a single fused kernel.
 ///
 /// **Pytorch equivalent**: `t.log_softmax(Ax)`
 ///
@@ -42,15 +43,13 @@ impl<S: Shape, E: Dtype, D: Device<E>, T: Tape<D>> Tensor<S, E, D, T> {
     where
         S: ReduceShape<Ax>,
     {
-        let logsumexp = self.retaped::<T>().try_logsumexp::<S::Reduced, Ax>()?;
-        let logsumexp = logsumexp.try_broadcast_like(self.shape())?;
-        self.try_sub(logsumexp)
+        self.try_softmax_kernel::<Ax>(true)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{shapes::Axis, tensor::*, tensor_ops::*, tests::*};
+    use crate::{gradients::NoneTape, shapes::*, tensor::*, tensor_ops::*, tests::*};
 
     #[test]
     fn test_log_softmax_1d() {
@@ -95,4 +94,23 @@ mod tests {
             ],
         );
     }
+
+    #[test]
+    fn test_log_softmax_3d_axes_0_2() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank3<2, 3, 4>, TestDtype, _> = dev.sample_normal();
+        let r = t.trace().log_softmax::<Axes2<0, 2>>();
+        assert_close(
+            &r.retaped::<NoneTape>()
+                .exp()
+                .sum::<_, Axes2<0, 2>>()
+                .array(),
+            &[1.0; 3],
+        );
+        let r2 = t.trace().softmax::<Axes2<0, 2>>().ln();
+        assert_close(&r.array(), &r2.array());
+        let g = r.mean().backward();
+        let g2 = r2.mean().backward();
+        assert_close(&g.get(&t).array(), &g2.get(&t).array());
+    }
 }
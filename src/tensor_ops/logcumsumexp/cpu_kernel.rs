@@ -0,0 +1,95 @@
+use crate::shapes::{Dtype, Shape};
+use crate::tensor::cpu::{Cpu, CpuError, LendingIterator, StridedArray};
+
+use super::{LogCumSumExpKernel, LogCumSumExpOp};
+
+use num_traits::Float;
+use std::sync::Arc;
+
+/// Copies `src` into a new contiguous array
+fn contiguous<S: Shape, E: Dtype>(
+    src: &StridedArray<S, E>,
+) -> Result<StridedArray<S, E>, CpuError> {
+    let mut dst = StridedArray::new(src.shape)?;
+    let mut dst_iter = dst.iter_mut_with_index();
+    while let Some((d, i)) = dst_iter.next() {
+        *d = src[i];
+    }
+    Ok(dst)
+}
+
+/// `exp(a - b)`, which is `1` when `a` and `b` are both infinite
+fn exp_diff<E: Float>(a: E, b: E) -> E {
+    if a == b {
+        E::one()
+    } else {
+        (a - b).exp()
+    }
+}
+
+impl<E: Dtype + Float> LogCumSumExpKernel<E> for Cpu {
+    fn forward<S: Shape>(
+        &self,
+        op: LogCumSumExpOp,
+        inp: &Self::Storage<S, E>,
+    ) -> Result<Self::Storage<S, E>, Self::Err> {
+        if inp.shape.num_elements() == 0 {
+            return StridedArray::new(inp.shape);
+        }
+        let mut out = contiguous(inp)?;
+
+        // out is contiguous, so the previous element along the axis is `stride` behind
+        let size = inp.shape.concrete()[op.axis];
+        let stride = out.strides[op.axis];
+        let data = Arc::make_mut(&mut out.data);
+        for i in 0..data.len() {
+            if (i / stride) % size > 0 {
+                let (a, b) = (data[i - stride], data[i]);
+                let max = a.max(b);
+                data[i] = if max == E::neg_infinity() {
+                    max
+                } else {
+                    max + (exp_diff(a, max) + exp_diff(b, max)).ln()
+                };
+            }
+        }
+        Ok(out)
+    }
+
+    fn backward<S: Shape>(
+        &self,
+        op: LogCumSumExpOp,
+        inp: &Self::Storage<S, E>,
+        grad_inp: &mut Self::Storage<S, E>,
+        out: &Self::Storage<S, E>,
+        grad_out: &Self::Storage<S, E>,
+    ) -> Result<(), Self::Err> {
+        if inp.shape.num_elements() == 0 {
+            return Ok(());
+        }
+        let inp = contiguous(inp)?;
+        let out = contiguous(out)?;
+        let mut tmp = contiguous(grad_out)?;
+
+        // The gradient of x[i] is exp(x[i] - out[i]) * r[i], where r is accumulated in
+        // reverse: r[i] = grad_out[i] + exp(out[i] - out[i + 1]) * r[i + 1]. Since out is
+        // non decreasing along the axis, none of the exponentials can overflow.
+        let size = inp.shape.concrete()[op.axis];
+        let stride = tmp.strides[op.axis];
+        let data = Arc::make_mut(&mut tmp.data);
+        for i in (0..data.len()).rev() {
+            if (i / stride) % size + 1 < size {
+                data[i] = data[i] + exp_diff(out.data[i], out.data[i + stride]) * data[i + stride];
+            }
+        }
+        for (i, d) in data.iter_mut().enumerate() {
+            *d *= exp_diff(inp.data[i], out.data[i]);
+        }
+
+        let mut tmp_iter = tmp.iter_with_index();
+        while let Some((t, i)) = tmp_iter.next() {
+            grad_inp[i] += *t;
+        }
+        Ok(())
+    }
+}
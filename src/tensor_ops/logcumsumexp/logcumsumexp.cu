@@ -0,0 +1,133 @@
+#include "cuda_utils.cuh"
+
+struct LogCumSumExpOp {
+    size_t axis;
+};
+
+// Computes the strided offsets of the first element of the `line`th line along op.axis
+__device__ void get_line_offsets(
+    const LogCumSumExpOp op,
+    size_t line,
+    const size_t num_dims,
+    const size_t *dims,
+    const size_t *inp_strides,
+    const size_t *out_strides,
+    size_t *inp_i,
+    size_t *out_i
+) {
+    *inp_i = 0;
+    *out_i = 0;
+    for (int d = num_dims - 1; d >= 0; d--) {
+        if (d == op.axis) {
+            continue;
+        }
+        const size_t i_dim = line % dims[d];
+        *inp_i += i_dim * inp_strides[d];
+        *out_i += i_dim * out_strides[d];
+        line /= dims[d];
+    }
+}
+
+// exp(a - b), which is 1 when a and b are both infinite
+template<typename T>
+__device__ T exp_diff(T a, T b) {
+    return a == b ? 1.0 : expg(a - b);
+}
+
+template<typename T>
+__device__ void logcumsumexp_fwd(
+    const LogCumSumExpOp op,
+    const size_t num_lines,
+    const size_t num_dims,
+    const size_t *dims,
+    const size_t *inp_strides,
+    const size_t *out_strides,
+    const T *inp,
+    T *out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= num_lines) {
+        return;
+    }
+
+    size_t inp_i, out_i;
+    get_line_offsets(op, i, num_dims, dims, inp_strides, out_strides, &inp_i, &out_i);
+
+    T acc = inp[inp_i];
+    out[out_i] = acc;
+    for (size_t k = 1; k < dims[op.axis]; k++) {
+        T x = inp[inp_i + k * inp_strides[op.axis]];
+        T m = maxg(acc, x);
+        if (!(isinf(m) && m < 0)) {
+            acc = m + logg(exp_diff(acc, m) + exp_diff(x, m));
+        }
+        out[out_i + k * out_strides[op.axis]] = acc;
+    }
+}
+
+// The gradient of x[k] is exp(x[k] - out[k]) * r[k], where r is accumulated in reverse:
+// r[k] = grad_out[k] + exp(out[k] - out[k + 1]) * r[k + 1]
+template<typename T>
+__device__ void logcumsumexp_bwd(
+    const LogCumSumExpOp op,
+    const size_t num_lines,
+    const size_t num_dims,
+    const size_t *dims,
+    const size_t *inp_strides,
+    const size_t *out_strides,
+    const T *inp,
+    T *grad_inp,
+    const T *out,
+    const T *grad_out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= num_lines) {
+        return;
+    }
+
+    size_t inp_i, out_i;
+    get_line_offsets(op, i, num_dims, dims, inp_strides, out_strides, &inp_i, &out_i);
+
+    const size_t inp_stride = inp_strides[op.axis];
+    const size_t out_stride = out_strides[op.axis];
+    T r = 0.0;
+    T out_next = 0.0;
+    for (size_t k = dims[op.axis]; k > 0; k--) {
+        const size_t j = k - 1;
+        const T o = out[out_i + j * out_stride];
+        r = grad_out[out_i + j * out_stride] + (j + 1 < dims[op.axis] ? exp_diff(o, out_next) * r : 0.0);
+        atomicAdd(grad_inp + inp_i + j * inp_stride, exp_diff(inp[inp_i + j * inp_stride], o) * r);
+        out_next = o;
+    }
+}
+
+#define LOGCUMSUMEXP_OP(TYPENAME, FWD, BWD) \
+extern "C" __global__ void FWD( \
+    const LogCumSumExpOp op, \
+    const size_t num_lines, \
+    const size_t num_dims, \
+    const size_t *dims, \
+    const size_t *inp_strides, \
+    const size_t *out_strides, \
+    const TYPENAME *inp, \
+    TYPENAME *out \
+) { \
+    logcumsumexp_fwd(op, num_lines, num_dims, dims, inp_strides, out_strides, inp, out); \
+} \
+extern "C" __global__ void BWD( \
+    const LogCumSumExpOp op, \
+    const size_t num_lines, \
+    const size_t num_dims, \
+    const size_t *dims, \
+    const size_t *inp_strides, \
+    const size_t *out_strides, \
+    const TYPENAME *inp, \
+    TYPENAME *grad_inp, \
+    const TYPENAME *out, \
+    const TYPENAME *grad_out \
+) { \
+    logcumsumexp_bwd(op, num_lines, num_dims, dims, inp_strides, out_strides, inp, grad_inp, out, grad_out); \
+}
+
+LOGCUMSUMEXP_OP(float, logcumsumexp_fwd_f32, logcumsumexp_bwd_f32);
+LOGCUMSUMEXP_OP(double, logcumsumexp_fwd_f64, logcumsumexp_bwd_f64);
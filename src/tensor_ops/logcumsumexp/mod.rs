@@ -0,0 +1,147 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{gradients::Tape, shapes::*, tensor::*};
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct LogCumSumExpOp {
+    /// The axis to accumulate along
+    pub axis: usize,
+}
+
+pub trait LogCumSumExpKernel<E: Dtype>: DeviceStorage {
+    fn forward<S: Shape>(
+        &self,
+        op: LogCumSumExpOp,
+        inp: &Self::Storage<S, E>,
+    ) -> Result<Self::Storage<S, E>, Self::Err>;
+    fn backward<S: Shape>(
+        &self,
+        op: LogCumSumExpOp,
+        inp: &Self::Storage<S, E>,
+        grad_inp: &mut Self::Storage<S, E>,
+        out: &Self::Storage<S, E>,
+        grad_out: &Self::Storage<S, E>,
+    ) -> Result<(), Self::Err>;
+}
+
+/// Cumulative [super::LogSumExpTo::logsumexp] along `Ax`. The `i`th element of the output
+/// along `Ax` is `log(sum(exp(x)))` of the first `i + 1` elements of the input.
+///
+/// This is computed in a numerically stable way, and never overflows for large inputs.
+///
+/// **Pytorch equivalent**: `t.logcumsumexp(dim=Ax)`
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([0.0, 0.0, 1000.0, f32::NEG_INFINITY]);
+/// let r = t.logcumsumexp::<Axis<0>>();
+/// assert_eq!(r.array(), [0.0, 2.0f32.ln(), 1000.0, 1000.0]);
+/// ```
+pub trait TryLogCumSumExp: HasErr + HasShape {
+    fn logcumsumexp<Ax: Axes<Array = [isize; 1]>>(self) -> Self
+    where
+        Self::Shape: HasAxes<Ax>,
+    {
+        self.try_logcumsumexp::<Ax>().unwrap()
+    }
+    fn try_logcumsumexp<Ax: Axes<Array = [isize; 1]>>(self) -> Result<Self, Self::Err>
+    where
+        Self::Shape: HasAxes<Ax>;
+}
+
+impl<S: Shape, E: Dtype, D: LogCumSumExpKernel<E>, T: Tape<D>> TryLogCumSumExp
+    for Tensor<S, E, D, T>
+{
+    fn try_logcumsumexp<Ax: Axes<Array = [isize; 1]>>(self) -> Result<Self, Self::Err>
+    where
+        Self::Shape: HasAxes<Ax>,
+    {
+        if S::NUM_DIMS == 0 {
+            return Ok(self);
+        }
+        let op = LogCumSumExpOp {
+            axis: Ax::as_array()[0] as usize,
+        };
+        let (inp, mut tape) = self.split_tape();
+        let out = inp.device.upgrade(inp.device.forward(op, &inp.storage)?);
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&inp)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+            inp.device
+                .backward(op, &inp.storage, grad_inp, &phantom_out.storage, grad_out)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_logcumsumexp_1d() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([1.0, -2.0, 3.0, 0.5]);
+        let r = t.trace().logcumsumexp::<Axis<0>>();
+        let r2 = t.trace().exp().cumsum::<Axis<0>>().ln();
+        assert_close(&r.array(), &r2.array());
+        let w = dev.tensor([1.0, -2.0, 3.0, 4.0]);
+        let g = (r * w.clone()).sum().backward();
+        let g2 = (r2 * w).sum().backward();
+        assert_close(&g.get(&t).array(), &g2.get(&t).array());
+    }
+
+    #[test]
+    fn test_logcumsumexp_large_values() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([1000.0, 1000.0, -1000.0]);
+        let r = t.trace().logcumsumexp::<Axis<0>>();
+        let ln2 = (2.0 as TestDtype).ln();
+        assert_close(&r.array(), &[1000.0, 1000.0 + ln2, 1000.0 + ln2]);
+        let g = r.sum().backward();
+        assert_close_with_tolerance(&g.get(&t).array(), &[2.0, 1.0, 0.0], 1e-4);
+    }
+
+    #[test]
+    fn test_logcumsumexp_3d_middle_axis() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank3<2, 3, 2>, TestDtype, _> = dev.sample_normal();
+        let r = t.trace().logcumsumexp::<Axis<1>>();
+        let r2 = t.trace().exp().cumsum::<Axis<1>>().ln();
+        assert_close(&r.array(), &r2.array());
+        let g = r.exp().mean().backward();
+        let g2 = r2.exp().mean().backward();
+        assert_close(&g.get(&t).array(), &g2.get(&t).array());
+    }
+
+    #[test]
+    fn test_logcumsumexp_broadcasted() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([1.0, 2.0]);
+        let r = t
+            .trace()
+            .broadcast::<Rank2<3, 2>, _>()
+            .logcumsumexp::<Axis<0>>();
+        let ln = |n: TestDtype| n.ln();
+        assert_close(
+            &r.array(),
+            &[
+                [1.0, 2.0],
+                [1.0 + ln(2.0), 2.0 + ln(2.0)],
+                [1.0 + ln(3.0), 2.0 + ln(3.0)],
+            ],
+        );
+        let g = r.sum().backward();
+        // each element of the i'th row gets 1 / (i + 1) from each of the 3 - i rows after it
+        assert_close(&g.get(&t).array(), &[3.0, 3.0]);
+    }
+}
@@ -0,0 +1,92 @@
+use crate::{
+    shapes::{Dtype, Shape},
+    tensor::cpu::{Cpu, LendingIterator, StridedArray},
+};
+
+use std::{sync::Arc, vec::Vec};
+
+impl<E: Dtype> super::MaskedFillKernel<E> for Cpu {
+    fn forward<S: Shape>(
+        &self,
+        mask: &Self::Storage<S, bool>,
+        inp: &Self::Storage<S, E>,
+        value: E,
+    ) -> Result<Self::Storage<S, E>, Self::Err> {
+        let mut out: Self::Storage<S, E> = StridedArray::new(inp.shape)?;
+        if inp.shape.num_elements() == 0 {
+            return Ok(out);
+        }
+        let mut mask_iter = mask.iter();
+        let mut inp_iter = inp.iter();
+        let mut out_iter = out.iter_mut();
+        while let Some((o, (m, i))) = out_iter.next().zip(mask_iter.next().zip(inp_iter.next())) {
+            *o = if *m { value } else { *i };
+        }
+        Ok(out)
+    }
+
+    fn backward<S: Shape>(
+        &self,
+        mask: &Self::Storage<S, bool>,
+        grad_inp: &mut Self::Storage<S, E>,
+        grad_out: &Self::Storage<S, E>,
+    ) -> Result<(), Self::Err> {
+        if grad_inp.shape.num_elements() == 0 {
+            return Ok(());
+        }
+        let mut mask_iter = mask.iter();
+        let mut inp_iter = grad_inp.iter_mut();
+        let mut out_iter = grad_out.iter();
+        while let Some((i, (m, o))) = inp_iter.next().zip(mask_iter.next().zip(out_iter.next())) {
+            if !*m {
+                *i += *o;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<E: Dtype> super::MaskedSelectKernel<E> for Cpu {
+    fn forward<S: Shape>(
+        &self,
+        mask: &Self::Storage<S, bool>,
+        inp: &Self::Storage<S, E>,
+    ) -> Result<Self::Storage<(usize,), E>, Self::Err> {
+        let mut data = Vec::new();
+        if inp.shape.num_elements() > 0 {
+            let mut mask_iter = mask.iter();
+            let mut inp_iter = inp.iter();
+            while let Some((m, i)) = mask_iter.next().zip(inp_iter.next()) {
+                if *m {
+                    data.push(*i);
+                }
+            }
+        }
+        let shape = (data.len(),);
+        Ok(StridedArray {
+            data: Arc::new(data),
+            shape,
+            strides: shape.strides(),
+        })
+    }
+
+    fn backward<S: Shape>(
+        &self,
+        mask: &Self::Storage<S, bool>,
+        grad_inp: &mut Self::Storage<S, E>,
+        grad_out: &Self::Storage<(usize,), E>,
+    ) -> Result<(), Self::Err> {
+        if grad_inp.shape.num_elements() == 0 {
+            return Ok(());
+        }
+        let mut grad_out_iter = grad_out.buf_iter();
+        let mut mask_iter = mask.iter();
+        let mut inp_iter = grad_inp.iter_mut();
+        while let Some((i, m)) = inp_iter.next().zip(mask_iter.next()) {
+            if *m {
+                *i += *grad_out_iter.next().unwrap();
+            }
+        }
+        Ok(())
+    }
+}
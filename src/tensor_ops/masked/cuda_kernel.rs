@@ -0,0 +1,215 @@
+use crate::{
+    shapes::*,
+    tensor::{
+        cpu::NdIndex,
+        cuda::{Cuda, CudaArray, CudaError},
+    },
+};
+use cudarc::driver::{AsKernelParam, CudaSlice, LaunchAsync, LaunchConfig};
+use std::{sync::Arc, vec::Vec};
+
+const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/masked.ptx"));
+
+trait HasCudaKernel<E> {
+    const MOD: &'static str;
+    const FNS: &'static [&'static str];
+}
+
+impl HasCudaKernel<f32> for Cuda {
+    const MOD: &'static str = "masked_f32";
+    const FNS: &'static [&'static str] = &[
+        "masked_fill_fwd_f32",
+        "masked_fill_bwd_f32",
+        "masked_select_fwd_f32",
+        "masked_select_bwd_f32",
+    ];
+}
+
+impl HasCudaKernel<f64> for Cuda {
+    const MOD: &'static str = "masked_f64";
+    const FNS: &'static [&'static str] = &[
+        "masked_fill_fwd_f64",
+        "masked_fill_bwd_f64",
+        "masked_select_fwd_f64",
+        "masked_select_bwd_f64",
+    ];
+}
+
+impl Cuda {
+    /// Computes the index into the output of masked select for each element of `mask`,
+    /// with `usize::MAX` for elements that aren't selected. Also returns the number of
+    /// selected elements.
+    fn masked_positions<S: Shape>(
+        &self,
+        mask: &CudaArray<S, bool>,
+    ) -> Result<(CudaSlice<usize>, usize), CudaError> {
+        let mut mask_buf = std::vec![false; mask.data.len()];
+        self.dev.sync_copy_from(mask.data.as_ref(), &mut mask_buf)?;
+
+        let numel = mask.shape.num_elements();
+        let mut positions = Vec::with_capacity(numel);
+        let mut len = 0;
+        if numel > 0 {
+            let mut index = NdIndex::new(mask.shape, mask.strides);
+            while let Some(i) = index.next() {
+                if mask_buf[i] {
+                    positions.push(len);
+                    len += 1;
+                } else {
+                    positions.push(usize::MAX);
+                }
+            }
+        }
+        Ok((self.dev.take_async(positions)?, len))
+    }
+}
+
+impl<E: Dtype + AsKernelParam> super::MaskedFillKernel<E> for Cuda
+where
+    Self: HasCudaKernel<E>,
+{
+    fn forward<S: Shape>(
+        &self,
+        mask: &Self::Storage<S, bool>,
+        inp: &Self::Storage<S, E>,
+        value: E,
+    ) -> Result<Self::Storage<S, E>, Self::Err> {
+        if !self.dev.has_func(Self::MOD, Self::FNS[0]) {
+            self.dev.load_ptx(PTX_SRC.into(), Self::MOD, Self::FNS)?;
+        }
+
+        let shape = inp.shape;
+        let strides = shape.strides();
+        let numel = shape.num_elements();
+
+        let mut storage = unsafe { self.dev.alloc_async::<E>(numel) }?;
+
+        let dims: CudaSlice<usize> = self.dev.take_async(shape.concrete().into())?;
+        let mask_strides: CudaSlice<usize> = self.dev.take_async(mask.strides.into())?;
+        let inp_strides: CudaSlice<usize> = self.dev.take_async(inp.strides.into())?;
+
+        let fwd_fn = self.dev.get_func(Self::MOD, Self::FNS[0]).unwrap();
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            numel,              // const size_t numel,
+            S::NUM_DIMS,        // const size_t num_dims,
+            &dims,              // const size_t *dims,
+            mask.data.as_ref(), // const bool *mask,
+            &mask_strides,      // const size_t *mask_strides,
+            inp.data.as_ref(),  // const float *inp,
+            &inp_strides,       // const size_t *inp_strides,
+            value,              // const float value,
+            &mut storage,       // float *out,
+        );
+        unsafe { fwd_fn.launch_async(cfg, params) }?;
+        Ok(CudaArray {
+            data: Arc::new(storage),
+            shape,
+            strides,
+        })
+    }
+
+    fn backward<S: Shape>(
+        &self,
+        mask: &Self::Storage<S, bool>,
+        grad_inp: &mut Self::Storage<S, E>,
+        grad_out: &Self::Storage<S, E>,
+    ) -> Result<(), Self::Err> {
+        let bwd_fn = self.dev.get_func(Self::MOD, Self::FNS[1]).unwrap();
+        let numel = mask.shape.num_elements();
+
+        let dims: CudaSlice<usize> = self.dev.take_async(mask.shape.concrete().into())?;
+        let mask_strides: CudaSlice<usize> = self.dev.take_async(mask.strides.into())?;
+        let inp_strides: CudaSlice<usize> = self.dev.take_async(grad_inp.strides.into())?;
+
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            numel,                             // const size_t numel,
+            S::NUM_DIMS,                       // const size_t num_dims,
+            &dims,                             // const size_t *dims,
+            mask.data.as_ref(),                // const bool *mask,
+            &mask_strides,                     // const size_t *mask_strides,
+            Arc::make_mut(&mut grad_inp.data), // float *grad_inp,
+            &inp_strides,                      // const size_t *inp_strides,
+            grad_out.data.as_ref(),            // const float *grad_out,
+        );
+        unsafe { bwd_fn.launch_async(cfg, params) }?;
+        Ok(())
+    }
+}
+
+impl<E: Dtype + AsKernelParam> super::MaskedSelectKernel<E> for Cuda
+where
+    Self: HasCudaKernel<E>,
+{
+    fn forward<S: Shape>(
+        &self,
+        mask: &Self::Storage<S, bool>,
+        inp: &Self::Storage<S, E>,
+    ) -> Result<Self::Storage<(usize,), E>, Self::Err> {
+        if !self.dev.has_func(Self::MOD, Self::FNS[0]) {
+            self.dev.load_ptx(PTX_SRC.into(), Self::MOD, Self::FNS)?;
+        }
+
+        // the length of the output depends on the values of the mask, so the
+        // positions are computed on the host
+        let (positions, len) = self.masked_positions(mask)?;
+        let shape = (len,);
+        let mut storage = unsafe { self.dev.alloc_async::<E>(len) }?;
+
+        let numel = inp.shape.num_elements();
+        if len > 0 {
+            let dims: CudaSlice<usize> = self.dev.take_async(inp.shape.concrete().into())?;
+            let inp_strides: CudaSlice<usize> = self.dev.take_async(inp.strides.into())?;
+
+            let fwd_fn = self.dev.get_func(Self::MOD, Self::FNS[2]).unwrap();
+            let cfg = LaunchConfig::for_num_elems(numel as u32);
+            let params = (
+                numel,             // const size_t numel,
+                S::NUM_DIMS,       // const size_t num_dims,
+                &dims,             // const size_t *dims,
+                &positions,        // const size_t *positions,
+                inp.data.as_ref(), // const float *inp,
+                &inp_strides,      // const size_t *inp_strides,
+                &mut storage,      // float *out,
+            );
+            unsafe { fwd_fn.launch_async(cfg, params) }?;
+        }
+        Ok(CudaArray {
+            data: Arc::new(storage),
+            shape,
+            strides: shape.strides(),
+        })
+    }
+
+    fn backward<S: Shape>(
+        &self,
+        mask: &Self::Storage<S, bool>,
+        grad_inp: &mut Self::Storage<S, E>,
+        grad_out: &Self::Storage<(usize,), E>,
+    ) -> Result<(), Self::Err> {
+        let (positions, len) = self.masked_positions(mask)?;
+        if len == 0 {
+            return Ok(());
+        }
+
+        let bwd_fn = self.dev.get_func(Self::MOD, Self::FNS[3]).unwrap();
+        let numel = grad_inp.shape.num_elements();
+
+        let dims: CudaSlice<usize> = self.dev.take_async(grad_inp.shape.concrete().into())?;
+        let inp_strides: CudaSlice<usize> = self.dev.take_async(grad_inp.strides.into())?;
+
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            numel,                             // const size_t numel,
+            S::NUM_DIMS,                       // const size_t num_dims,
+            &dims,                             // const size_t *dims,
+            &positions,                        // const size_t *positions,
+            Arc::make_mut(&mut grad_inp.data), // float *grad_inp,
+            &inp_strides,                      // const size_t *inp_strides,
+            grad_out.data.as_ref(),            // const float *grad_out,
+        );
+        unsafe { bwd_fn.launch_async(cfg, params) }?;
+        Ok(())
+    }
+}
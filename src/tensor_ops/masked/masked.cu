@@ -0,0 +1,140 @@
+#include "cuda_utils.cuh"
+
+template<typename T>
+__device__ void masked_fill_fwd(
+    const size_t numel,
+    const size_t num_dims,
+    const size_t *dims,
+    const bool *mask,
+    const size_t *mask_strides,
+    const T *inp,
+    const size_t *inp_strides,
+    const T value,
+    T *out
+) {
+    unsigned int out_i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (out_i >= numel) {
+        return;
+    }
+
+    unsigned int mask_i = get_strided_index(out_i, num_dims, dims, mask_strides);
+    unsigned int inp_i = get_strided_index(out_i, num_dims, dims, inp_strides);
+
+    out[out_i] = mask[mask_i] ? value : inp[inp_i];
+}
+
+template<typename T>
+__device__ void masked_fill_bwd(
+    const size_t numel,
+    const size_t num_dims,
+    const size_t *dims,
+    const bool *mask,
+    const size_t *mask_strides,
+    T *grad_inp,
+    const size_t *inp_strides,
+    const T *grad_out
+) {
+    unsigned int out_i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (out_i >= numel) {
+        return;
+    }
+
+    unsigned int mask_i = get_strided_index(out_i, num_dims, dims, mask_strides);
+    unsigned int inp_i = get_strided_index(out_i, num_dims, dims, inp_strides);
+
+    if (!mask[mask_i]) {
+        atomicAdd(grad_inp + inp_i, grad_out[out_i]);
+    }
+}
+
+// `positions` holds the index into the output for each element of the input,
+// or `SIZE_MAX` for elements that aren't selected.
+template<typename T>
+__device__ void masked_select_fwd(
+    const size_t numel,
+    const size_t num_dims,
+    const size_t *dims,
+    const size_t *positions,
+    const T *inp,
+    const size_t *inp_strides,
+    T *out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel || positions[i] == SIZE_MAX) {
+        return;
+    }
+
+    unsigned int inp_i = get_strided_index(i, num_dims, dims, inp_strides);
+    out[positions[i]] = inp[inp_i];
+}
+
+template<typename T>
+__device__ void masked_select_bwd(
+    const size_t numel,
+    const size_t num_dims,
+    const size_t *dims,
+    const size_t *positions,
+    T *grad_inp,
+    const size_t *inp_strides,
+    const T *grad_out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel || positions[i] == SIZE_MAX) {
+        return;
+    }
+
+    unsigned int inp_i = get_strided_index(i, num_dims, dims, inp_strides);
+    atomicAdd(grad_inp + inp_i, grad_out[positions[i]]);
+}
+
+#define MASKED_OP(TYPENAME, FILL_FWD, FILL_BWD, SELECT_FWD, SELECT_BWD) \
+extern "C" __global__ void FILL_FWD( \
+    const size_t numel, \
+    const size_t num_dims, \
+    const size_t *dims, \
+    const bool *mask, \
+    const size_t *mask_strides, \
+    const TYPENAME *inp, \
+    const size_t *inp_strides, \
+    const TYPENAME value, \
+    TYPENAME *out \
+) { \
+    masked_fill_fwd(numel, num_dims, dims, mask, mask_strides, inp, inp_strides, value, out); \
+} \
+extern "C" __global__ void FILL_BWD( \
+    const size_t numel, \
+    const size_t num_dims, \
+    const size_t *dims, \
+    const bool *mask, \
+    const size_t *mask_strides, \
+    TYPENAME *grad_inp, \
+    const size_t *inp_strides, \
+    const TYPENAME *grad_out \
+) { \
+    masked_fill_bwd(numel, num_dims, dims, mask, mask_strides, grad_inp, inp_strides, grad_out); \
+} \
+extern "C" __global__ void SELECT_FWD( \
+    const size_t numel, \
+    const size_t num_dims, \
+    const size_t *dims, \
+    const size_t *positions, \
+    const TYPENAME *inp, \
+    const size_t *inp_strides, \
+    TYPENAME *out \
+) { \
+    masked_select_fwd(numel, num_dims, dims, positions, inp, inp_strides, out); \
+} \
+extern "C" __global__ void SELECT_BWD( \
+    const size_t numel, \
+    const size_t num_dims, \
+    const size_t *dims, \
+    const size_t *positions, \
+    TYPENAME *grad_inp, \
+    const size_t *inp_strides, \
+    const TYPENAME *grad_out \
+) { \
+    masked_select_bwd(numel, num_dims, dims, positions, grad_inp, inp_strides, grad_out); \
+}
+
+MASKED_OP(float, masked_fill_fwd_f32, masked_fill_bwd_f32, masked_select_fwd_f32, masked_select_bwd_f32);
+MASKED_OP(double, masked_fill_fwd_f64, masked_fill_bwd_f64, masked_select_fwd_f64, masked_select_bwd_f64);
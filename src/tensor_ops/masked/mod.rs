@@ -0,0 +1,184 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{gradients::Tape, shapes::*, tensor::*};
+
+pub trait MaskedFillKernel<E: Dtype>: DeviceStorage {
+    fn forward<S: Shape>(
+        &self,
+        mask: &Self::Storage<S, bool>,
+        inp: &Self::Storage<S, E>,
+        value: E,
+    ) -> Result<Self::Storage<S, E>, Self::Err>;
+
+    fn backward<S: Shape>(
+        &self,
+        mask: &Self::Storage<S, bool>,
+        grad_inp: &mut Self::Storage<S, E>,
+        grad_out: &Self::Storage<S, E>,
+    ) -> Result<(), Self::Err>;
+}
+
+pub trait MaskedSelectKernel<E: Dtype>: DeviceStorage {
+    /// Collects the elements of `inp` where `mask` is true, in row major order.
+    fn forward<S: Shape>(
+        &self,
+        mask: &Self::Storage<S, bool>,
+        inp: &Self::Storage<S, E>,
+    ) -> Result<Self::Storage<(usize,), E>, Self::Err>;
+
+    fn backward<S: Shape>(
+        &self,
+        mask: &Self::Storage<S, bool>,
+        grad_inp: &mut Self::Storage<S, E>,
+        grad_out: &Self::Storage<(usize,), E>,
+    ) -> Result<(), Self::Err>;
+}
+
+impl<S: Shape, E: Dtype, D: MaskedFillKernel<E>, T: Tape<D>> Tensor<S, E, D, T> {
+    /// Replaces the elements where `mask` is true with `value`. Gradients only flow
+    /// through the elements that are kept.
+    ///
+    /// **Pytorch equivalent**: `t.masked_fill(mask, value)`
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t = dev.tensor([[1.0, 2.0], [3.0, 4.0]]);
+    /// let mask = dev.tensor([[false, true], [true, false]]);
+    /// let r = t.masked_fill(mask, f32::NEG_INFINITY);
+    /// assert_eq!(r.array(), [[1.0, f32::NEG_INFINITY], [f32::NEG_INFINITY, 4.0]]);
+    /// ```
+    pub fn masked_fill(self, mask: Tensor<S, bool, D>, value: E) -> Self {
+        self.try_masked_fill(mask, value).unwrap()
+    }
+
+    /// See [Tensor::masked_fill]
+    pub fn try_masked_fill(self, mask: Tensor<S, bool, D>, value: E) -> Result<Self, D::Err> {
+        assert_eq!(self.shape(), mask.shape());
+        let (inp, mut tape) = self.split_tape();
+        let storage = inp.device.forward(&mask.storage, &inp.storage, value)?;
+        let out = inp.device.upgrade(storage);
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&inp)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+            inp.device.backward(&mask.storage, grad_inp, grad_out)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+impl<S: Shape, E: Dtype, D: MaskedSelectKernel<E>, T: Tape<D>> Tensor<S, E, D, T> {
+    /// Selects the elements where `mask` is true into a 1d tensor, in row major
+    /// order. The length of the result is the number of true values in `mask`.
+    ///
+    /// **Pytorch equivalent**: `t.masked_select(mask)`
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t = dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    /// let mask = dev.tensor([[false, true, true], [true, false, false]]);
+    /// let r: Tensor<(usize,), f32, _> = t.masked_select(mask);
+    /// assert_eq!(r.as_vec(), [2.0, 3.0, 4.0]);
+    /// ```
+    pub fn masked_select(self, mask: Tensor<S, bool, D>) -> Tensor<(usize,), E, D, T> {
+        self.try_masked_select(mask).unwrap()
+    }
+
+    /// See [Tensor::masked_select]
+    pub fn try_masked_select(
+        self,
+        mask: Tensor<S, bool, D>,
+    ) -> Result<Tensor<(usize,), E, D, T>, D::Err> {
+        assert_eq!(self.shape(), mask.shape());
+        let (inp, mut tape) = self.split_tape();
+        let storage = inp.device.forward(&mask.storage, &inp.storage)?;
+        let out = inp.device.upgrade(storage);
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&inp)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+            inp.device.backward(&mask.storage, grad_inp, grad_out)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{shapes::*, tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_masked_fill() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([[1.0, -2.0, 3.0], [0.5, 2.0, -1.0]]);
+        let mask = dev.tensor([[true, false, false], [false, true, true]]);
+        let r = t.trace().masked_fill(mask, 0.25);
+        assert_eq!(r.array(), [[0.25, -2.0, 3.0], [0.5, 0.25, 0.25]]);
+        let g = r.exp().sum().backward();
+        assert_close(
+            &g.get(&t).array(),
+            &[
+                [0.0, (-2.0 as TestDtype).exp(), (3.0 as TestDtype).exp()],
+                [(0.5 as TestDtype).exp(), 0.0, 0.0],
+            ],
+        );
+    }
+
+    #[test]
+    fn test_masked_fill_neg_infinity() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+        let mask = dev.tensor([[true, false]; 3]);
+        let r = t.trace().masked_fill(mask, TestDtype::NEG_INFINITY);
+        let r = r.softmax::<Axis<1>>();
+        assert_eq!(r.array(), [[0.0, 1.0]; 3]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&t).array(), [[0.0; 2]; 3]);
+    }
+
+    #[test]
+    fn test_masked_select() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let mask = dev.tensor([[true, false, true], [false, false, true]]);
+        let r = t.trace().masked_select(mask);
+        assert_eq!(r.shape(), &(3,));
+        assert_eq!(r.as_vec(), [1.0, 3.0, 6.0]);
+        let g = (r * dev.tensor_from_vec(std::vec![1.0, 2.0, 3.0], (3,)))
+            .sum()
+            .backward();
+        assert_eq!(g.get(&t).array(), [[1.0, 0.0, 2.0], [0.0, 0.0, 3.0]]);
+    }
+
+    #[test]
+    fn test_masked_select_none() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([1.0, 2.0, 3.0]);
+        let r = t.masked_select(dev.zeros());
+        assert_eq!(r.shape(), &(0,));
+    }
+
+    #[test]
+    fn test_masked_select_permuted() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let mask = dev.tensor([[true, true], [false, true], [true, false]]);
+        let r = t.trace().permute::<_, Axes2<1, 0>>().masked_select(mask);
+        assert_eq!(r.as_vec(), [1.0, 4.0, 5.0, 3.0]);
+        let g = r.exp().sum().backward();
+        assert_close(
+            &g.get(&t).array(),
+            &[
+                [TestDtype::exp(1.0), 0.0, TestDtype::exp(3.0)],
+                [TestDtype::exp(4.0), TestDtype::exp(5.0), 0.0],
+            ],
+        );
+    }
+}
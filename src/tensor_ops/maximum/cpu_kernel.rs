@@ -1,6 +1,6 @@
-use crate::tensor_ops::cpu_kernels::BinaryDerivative;
+use crate::tensor_ops::cpu_kernels::{int_binary_derivative, BinaryDerivative, Float};
 
-impl<F: num_traits::Float> BinaryDerivative<F> for super::MaximumKernelOp {
+impl<F: Float> BinaryDerivative<F> for super::MaximumKernelOp {
     #[inline(always)]
     fn f(&self, &x: &F, &y: &F) -> F {
         x.max(y)
@@ -26,3 +26,5 @@ impl<F: num_traits::Float> BinaryDerivative<F> for super::MaximumKernelOp {
         }
     }
 }
+
+int_binary_derivative!(super::MaximumKernelOp, |x, y| x.max(y));
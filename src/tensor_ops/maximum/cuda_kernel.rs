@@ -7,3 +7,7 @@ const PTX: &str = include_str!(concat!(env!("OUT_DIR"), "/maximum.ptx"));
 
 cuda_binary!(Max, f32, PTX, "maximum_fwd_f32", "maximum_bwd_f32");
 cuda_binary!(Max, f64, PTX, "maximum_fwd_f64", "maximum_bwd_f64");
+cuda_binary!(Max, usize, PTX, "maximum_fwd_usize", "maximum_bwd_usize");
+cuda_binary!(Max, i32, PTX, "maximum_fwd_i32", "maximum_bwd_i32");
+cuda_binary!(Max, i64, PTX, "maximum_fwd_i64", "maximum_bwd_i64");
+cuda_binary!(Max, u8, PTX, "maximum_fwd_u8", "maximum_bwd_u8");
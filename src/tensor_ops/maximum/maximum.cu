@@ -28,3 +28,24 @@ BINARY_OP(double, maximum_fwd_f64, maximum_bwd_f64, MaximumKernalOp,
     op_dfdx(x, y),
     op_dfdy(x, y)
 )
+
+// integers don't have gradients
+BINARY_OP(size_t, maximum_fwd_usize, maximum_bwd_usize, MaximumKernalOp,
+    (x > y) ? x : y,
+    0,
+    0)
+
+BINARY_OP(int, maximum_fwd_i32, maximum_bwd_i32, MaximumKernalOp,
+    (x > y) ? x : y,
+    0,
+    0)
+
+BINARY_OP(long long, maximum_fwd_i64, maximum_bwd_i64, MaximumKernalOp,
+    (x > y) ? x : y,
+    0,
+    0)
+
+BINARY_OP(unsigned char, maximum_fwd_u8, maximum_bwd_u8, MaximumKernalOp,
+    (x > y) ? x : y,
+    0,
+    0)
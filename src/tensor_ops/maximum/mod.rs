@@ -3,7 +3,10 @@ mod cpu_kernel;
 #[cfg(feature = "cuda")]
 mod cuda_kernel;
 
-use super::{ops::try_binary_op, Device};
+use super::{
+    ops::{try_binary_op, BinaryKernel},
+    Device,
+};
 use crate::{gradients::*, shapes::*, tensor::Tensor};
 
 #[repr(C)]
@@ -12,6 +15,9 @@ pub struct MaximumKernelOp;
 
 /// Element wise maximum.
 ///
+/// Where both values are equal the gradient is split evenly between them, otherwise
+/// it only flows into the larger value.
+///
 /// **Pytorch equivalent**: `torch.maximum(a, b)`
 ///
 /// Example:
@@ -22,6 +28,18 @@ pub struct MaximumKernelOp;
 /// let b = dev.tensor([[1.0, 0.5, 1.0], [-2.0, 2.0, -3.5]]);
 /// let r = a.maximum(b);
 /// assert_eq!(r.array(), [[1.0, 2.0, 3.0], [-1.0, 2.0, -3.0]]);
+/// ```
+///
+/// Clamping a tensor between two other tensors:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([-2.0, 0.5, 3.0]);
+/// let lo = dev.tensor([-1.0, 0.0, 0.0]);
+/// let hi = dev.tensor([1.0, 1.0, 2.0]);
+/// let r = t.maximum(lo).minimum(hi);
+/// assert_eq!(r.array(), [-1.0, 0.5, 2.0]);
+/// ```
 pub fn maximum<S: Shape, E: Dtype, D: Device<E>, LTape: Tape<D> + Merge<RTape>, RTape: Tape<D>>(
     lhs: Tensor<S, E, D, LTape>,
     rhs: Tensor<S, E, D, RTape>,
@@ -29,7 +47,9 @@ pub fn maximum<S: Shape, E: Dtype, D: Device<E>, LTape: Tape<D> + Merge<RTape>,
     lhs.maximum(rhs)
 }
 
-impl<S: Shape, E: Dtype, D: Device<E>, LTape: Tape<D>> Tensor<S, E, D, LTape> {
+impl<S: Shape, E: Dtype, D: BinaryKernel<MaximumKernelOp, E>, LTape: Tape<D>>
+    Tensor<S, E, D, LTape>
+{
     /// See [maximum]
     pub fn maximum<RTape: Tape<D>>(self, rhs: Tensor<S, E, D, RTape>) -> Self
     where
@@ -64,4 +84,15 @@ mod tests {
         assert_eq!(g.get(&a).array(), [[0.0, 0.5, 1.0], [0.5, 1.0, 0.0]]);
         assert_eq!(g.get(&b).array(), [[1.0, 0.5, 0.0], [0.5, 0.0, 1.0]]);
     }
+
+    #[test]
+    fn test_maximum_usize() {
+        let dev: TestDevice = Default::default();
+        let a = dev.tensor([[0usize, 5, 2], [3, 3, 9]]);
+        let b = dev.tensor([[1usize, 4, 2], [0, 6, 8]]);
+        assert_eq!(a.maximum(b).array(), [[1, 5, 2], [3, 6, 9]]);
+        let a = dev.tensor([-3i32, 4, 0]);
+        let b = dev.tensor([-5i32, 7, 0]);
+        assert_eq!(a.maximum(b).array(), [-3, 7, 0]);
+    }
 }
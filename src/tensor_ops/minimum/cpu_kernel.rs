@@ -1,6 +1,4 @@
-use crate::tensor_ops::cpu_kernels::BinaryDerivative;
-
-use num_traits::Float;
+use crate::tensor_ops::cpu_kernels::{int_binary_derivative, BinaryDerivative, Float};
 
 impl<F: Float> BinaryDerivative<F> for super::MinimumKernelOp {
     #[inline(always)]
@@ -29,3 +27,5 @@ impl<F: Float> BinaryDerivative<F> for super::MinimumKernelOp {
         }
     }
 }
+
+int_binary_derivative!(super::MinimumKernelOp, |x, y| x.min(y));
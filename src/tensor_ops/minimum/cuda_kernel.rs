@@ -7,3 +7,7 @@ const PTX: &str = include_str!(concat!(env!("OUT_DIR"), "/minimum.ptx"));
 
 cuda_binary!(Min, f32, PTX, "minimum_fwd_f32", "minimum_bwd_f32");
 cuda_binary!(Min, f64, PTX, "minimum_fwd_f64", "minimum_bwd_f64");
+cuda_binary!(Min, usize, PTX, "minimum_fwd_usize", "minimum_bwd_usize");
+cuda_binary!(Min, i32, PTX, "minimum_fwd_i32", "minimum_bwd_i32");
+cuda_binary!(Min, i64, PTX, "minimum_fwd_i64", "minimum_bwd_i64");
+cuda_binary!(Min, u8, PTX, "minimum_fwd_u8", "minimum_bwd_u8");
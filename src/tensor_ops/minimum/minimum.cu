@@ -28,3 +28,24 @@ BINARY_OP(double, minimum_fwd_f64, minimum_bwd_f64, MinimumKernelOp,
     op_dfdx(x, y),
     op_dfdy(x, y)
 )
+
+// integers don't have gradients
+BINARY_OP(size_t, minimum_fwd_usize, minimum_bwd_usize, MinimumKernelOp,
+    (x < y) ? x : y,
+    0,
+    0)
+
+BINARY_OP(int, minimum_fwd_i32, minimum_bwd_i32, MinimumKernelOp,
+    (x < y) ? x : y,
+    0,
+    0)
+
+BINARY_OP(long long, minimum_fwd_i64, minimum_bwd_i64, MinimumKernelOp,
+    (x < y) ? x : y,
+    0,
+    0)
+
+BINARY_OP(unsigned char, minimum_fwd_u8, minimum_bwd_u8, MinimumKernelOp,
+    (x < y) ? x : y,
+    0,
+    0)
@@ -3,7 +3,10 @@ mod cpu_kernel;
 #[cfg(feature = "cuda")]
 mod cuda_kernel;
 
-use super::{ops::try_binary_op, Device};
+use super::{
+    ops::{try_binary_op, BinaryKernel},
+    Device,
+};
 use crate::{gradients::*, shapes::*, tensor::Tensor};
 
 #[repr(C)]
@@ -12,6 +15,9 @@ pub struct MinimumKernelOp;
 
 /// Element wise minimum.
 ///
+/// Where both values are equal the gradient is split evenly between them, otherwise
+/// it only flows into the smaller value.
+///
 /// **Pytorch equivalent**: `torch.minimum(a, b)`
 ///
 /// Example:
@@ -22,6 +28,7 @@ pub struct MinimumKernelOp;
 /// let b = dev.tensor([[1.0, 0.5, 1.0], [-2.0, 2.0, -3.5]]);
 /// let r = a.minimum(b);
 /// assert_eq!(r.array(), [[1.0, 0.5, 1.0], [-2.0, -2.0, -3.5]]);
+/// ```
 pub fn minimum<S: Shape, E: Dtype, D: Device<E>, LTape: Tape<D> + Merge<RTape>, RTape: Tape<D>>(
     lhs: Tensor<S, E, D, LTape>,
     rhs: Tensor<S, E, D, RTape>,
@@ -29,7 +36,9 @@ pub fn minimum<S: Shape, E: Dtype, D: Device<E>, LTape: Tape<D> + Merge<RTape>,
     lhs.minimum(rhs)
 }
 
-impl<S: Shape, E: Dtype, D: Device<E>, LTape: Tape<D>> Tensor<S, E, D, LTape> {
+impl<S: Shape, E: Dtype, D: BinaryKernel<MinimumKernelOp, E>, LTape: Tape<D>>
+    Tensor<S, E, D, LTape>
+{
     /// See [minimum]
     pub fn minimum<RTape: Tape<D>>(self, rhs: Tensor<S, E, D, RTape>) -> Self
     where
@@ -63,4 +72,15 @@ mod tests {
         assert_eq!(g.get(&a).array(), [[1.0, 0.5, 0.0], [0.5, 0.0, 1.0]]);
         assert_eq!(g.get(&b).array(), [[0.0, 0.5, 1.0], [0.5, 1.0, 0.0]]);
     }
+
+    #[test]
+    fn test_minimum_usize() {
+        let dev: TestDevice = Default::default();
+        let a = dev.tensor([[0usize, 5, 2], [3, 3, 9]]);
+        let b = dev.tensor([[1usize, 4, 2], [0, 6, 8]]);
+        assert_eq!(a.minimum(b).array(), [[0, 4, 2], [0, 3, 8]]);
+        let a = dev.tensor([-3i64, 4, 0]);
+        let b = dev.tensor([-5i64, 7, 0]);
+        assert_eq!(a.minimum(b).array(), [-5, 4, 0]);
+    }
 }
@@ -0,0 +1,21 @@
+use crate::tensor_ops::cpu_kernels::UnaryDerivative;
+use num_traits::Float;
+
+/// `ln(1 + e^x)` that doesn't overflow for large `x`.
+#[inline(always)]
+fn softplus<F: Float>(x: F) -> F {
+    x.max(F::zero()) + (-x.abs()).exp().ln_1p()
+}
+
+impl<F: Float> UnaryDerivative<F> for super::MishKernelOp {
+    #[inline(always)]
+    fn f(&self, &x: &F) -> F {
+        x * softplus(x).tanh()
+    }
+    #[inline(always)]
+    fn df(&self, &x: &F) -> F {
+        let tanh_sp = softplus(x).tanh();
+        let sigmoid = (F::one() + (-x).exp()).recip();
+        tanh_sp + x * (F::one() - tanh_sp * tanh_sp) * sigmoid
+    }
+}
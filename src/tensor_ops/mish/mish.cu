@@ -0,0 +1,29 @@
+#include "unary_op_macros.cuh"
+#include "cuda_utils.cuh"
+
+struct MishKernelOp {};
+
+template<typename T>
+__device__ T softplus(T x) {
+    return maxg(x, (T)0.0) + log1pg(expg(-absg(x)));
+}
+
+template<typename T>
+__device__ T mish_fwd(T x) {
+    return x * tanhg(softplus(x));
+}
+
+template<typename T>
+__device__ T mish_bwd(T x) {
+    T tanh_sp = tanhg(softplus(x));
+    T sigmoid = 1.0 / (1.0 + expg(-x));
+    return tanh_sp + x * (1.0 - tanh_sp * tanh_sp) * sigmoid;
+}
+
+UNARY_OP(float, mish_fwd_f32, mish_bwd_f32, MishKernelOp,
+    mish_fwd(x),
+    mish_bwd(x))
+
+UNARY_OP(double, mish_fwd_f64, mish_bwd_f64, MishKernelOp,
+    mish_fwd(x),
+    mish_bwd(x))
@@ -0,0 +1,70 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use super::ops::{try_unary_op, UnaryKernel};
+use crate::{gradients::Tape, shapes::*, tensor::Tensor};
+
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct MishKernelOp;
+
+/// [Mish](https://arxiv.org/abs/1908.08681) `t * tanh(softplus(t))`, fused into a single op.
+///
+/// It's derivative is `tanh(softplus(t)) + t * sech^2(softplus(t)) * sigmoid(t)`
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([-1.0, 0.0, 1.0, 2.0]);
+/// let r = t.mish();
+/// ```
+pub fn mish<S: Shape, E: Dtype, D: UnaryKernel<MishKernelOp, E>, T: Tape<D>>(
+    t: Tensor<S, E, D, T>,
+) -> Tensor<S, E, D, T> {
+    t.mish()
+}
+
+impl<S: Shape, E: Dtype, D: UnaryKernel<MishKernelOp, E>, T: Tape<D>> Tensor<S, E, D, T> {
+    /// See [mish]
+    pub fn mish(self) -> Self {
+        self.try_mish().unwrap()
+    }
+    /// See [mish]
+    pub fn try_mish(self) -> Result<Self, D::Err> {
+        try_unary_op(MishKernelOp, self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_mish() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([-3.0, -1.0, 0.0, 1.0, 3.0]);
+        let r = x.trace().mish();
+        assert_close(
+            &r.array(),
+            &[-0.14564747, -0.30340147, 0.0, 0.8650984, 2.986535],
+        );
+        let g = r.mean().backward();
+        assert_close(
+            &g.get(&x).array(),
+            &[-0.018678622, 0.011843351, 0.12, 0.20980725, 0.20422138],
+        );
+    }
+
+    #[test]
+    fn test_mish_large_inputs() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([-1000.0, 1000.0]);
+        let r = x.trace().mish();
+        assert_eq!(r.array(), [-0.0, 1000.0]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&x).array(), [0.0, 1.0]);
+    }
+}
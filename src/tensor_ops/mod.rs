@@ -52,9 +52,11 @@
 //! - [MaxTo]
 //! - [MeanTo]
 //! - [MinTo]
+//! - [NormTo]
 //! - [SumTo]
 //! - [VarTo]
 //! - [StddevTo]
+//! - [WeightedMeanTo]
 //! - [LogSumExpTo]
 //!
 //! # Broadcasts
@@ -85,6 +87,17 @@
 //! let _ = big + small.broadcast();
 //! ```
 //!
+//! Binary ops don't broadcast implicitly. [TryBroadcastPair] broadcasts whichever side has
+//! the smaller shape, numpy style:
+//! ```rust
+//! # use dfdx::prelude::*;
+//! # let dev: Cpu = Default::default();
+//! let big: Tensor<Rank2<2, 5>, f32, _> = dev.zeros();
+//! let small: Tensor<Rank1<5>, f32, _> = dev.zeros();
+//! let (small, big) = (small, big).broadcast_pair();
+//! let _ = small - big;
+//! ```
+//!
 //! # Permutes
 //!
 //! Permuting has an identical interface to broadcasts/reductions:
@@ -143,65 +156,167 @@ mod utilities;
 pub use utilities::*;
 
 mod abs;
+mod adaptive_pool2d;
 mod add;
+mod any_all;
 mod bce;
+mod bernoulli;
+mod bitwise;
 mod boolean;
 mod broadcast_to;
+mod cholesky;
 mod choose;
 mod clamp;
 mod cmp;
+mod concat_along;
 mod cos;
+mod count_nonzero;
+mod cov;
+mod cumprod;
+mod cumsum;
+mod det;
+mod diagonal;
+mod diff;
 mod div;
 mod dropout;
+mod einsum;
+mod elu;
+mod embedding;
+mod embedding_bag;
+mod erf;
 mod exp;
+mod expm1;
+mod fft;
+mod flip;
+mod fmod;
+mod fold;
 mod gelu;
+mod glu;
+mod grid_sample;
+mod group_norm;
+mod hard_activations;
+mod histogram;
 mod huber_error;
+mod index_add;
+mod isfinite;
+mod kron;
+mod kthvalue;
+mod lerp;
+mod lgamma;
 mod ln;
+mod local_response_norm;
+mod log1p;
 mod log_softmax;
+mod logcumsumexp;
 mod logsumexp_to;
+mod masked;
 mod matmul;
 mod max_to;
 mod maximum;
 mod mean_to;
 mod min_to;
 mod minimum;
+mod mish;
+mod mode;
 mod mul;
+mod multinomial;
 mod nans_to;
 mod negate;
+mod nonzero;
+mod norm_to;
 mod normalize;
+mod one_hot;
+mod outer;
+mod pad2d;
 mod permute_to;
+mod pixel_shuffle;
 mod pow;
+mod prelu;
+mod randperm;
 mod relu;
+mod repeat;
+mod repeat_interleave;
 mod reshape_to;
+mod roll;
+mod rounding;
+mod rsqrt;
+mod scatter_add;
+mod searchsorted;
 mod select_and_gather;
 mod sigmoid;
+mod sign;
 mod sin;
+mod slice;
 mod softmax;
+mod softplus;
+mod solve;
+mod sort;
+mod split;
 mod sqrt;
 mod square;
 mod stack;
 mod stddev_to;
+mod stft;
 mod sub;
 mod sum_to;
+mod svd;
 mod tanh;
+mod to_dtype;
+mod trapz;
+mod triangle;
+mod unfold;
+mod upscale2d;
 mod var_to;
+mod weighted_mean;
 
 pub use abs::abs;
+pub(crate) use adaptive_pool2d::{ConstAdaptiveAvgPool2D, ConstAdaptiveMaxPool2D};
+pub use adaptive_pool2d::{TryAdaptiveAvgPool2D, TryAdaptiveMaxPool2D};
 pub use add::{add, TryAdd};
+pub use any_all::{AllTo, AnyTo};
 pub use bce::bce_with_logits;
+pub use bernoulli::bernoulli;
+pub use bitwise::{bitwise_and, shl, shr};
 pub use boolean::{bool_and, bool_not, bool_or, bool_xor};
-pub use broadcast_to::BroadcastTo;
+pub use broadcast_to::{BroadcastTo, TryBroadcastPair};
 pub use choose::ChooseFrom;
 pub use clamp::clamp;
 pub use cmp::{eq, ge, gt, le, lt, ne};
+pub use concat_along::TryConcatAlong;
 pub use cos::cos;
+pub use count_nonzero::CountNonzeroTo;
+pub use cov::{corrcoef, cov, TryCov};
+pub use cumprod::TryCumProd;
+pub use cumsum::TryCumSum;
+pub use diagonal::{TryDiag, TryDiagonal};
+pub use diff::TryDiff;
 pub use div::{div, TryDiv};
 pub use dropout::dropout;
+pub use einsum::{einsum, TryEinsum};
+pub use elu::{celu, elu, selu};
+pub use embedding_bag::EmbeddingBagMode;
+pub use erf::{erf, erfinv};
 pub use exp::exp;
-pub use gelu::gelu;
+pub use expm1::expm1;
+pub use fft::{fft, ifft, irfft, rfft, ComplexFftShape, RealFftShape};
+pub use flip::TryFlip;
+pub use fmod::{fmod, remainder, TryFmod, TryRemainder};
+pub use fold::TryFold;
+pub use gelu::{gelu, gelu_exact};
+pub use glu::TryGlu;
+pub use grid_sample::TryGridSample;
+pub use hard_activations::{hardsigmoid, hardswish, hardtanh};
 pub use huber_error::huber_error;
+pub use isfinite::{isfinite, isinf, isnan};
+pub use kron::{kron, TryKron};
+pub use kthvalue::KthValueTo;
+pub use lerp::{lerp, TryLerp};
+pub use lgamma::{digamma, lgamma};
 pub use ln::ln;
+pub use local_response_norm::TryLocalResponseNorm;
+pub use log1p::log1p;
 pub use log_softmax::log_softmax;
+pub use logcumsumexp::TryLogCumSumExp;
 pub use logsumexp_to::LogSumExpTo;
 pub use matmul::{matmul, TryMatMul};
 pub use max_to::MaxTo;
@@ -209,37 +324,107 @@ pub use maximum::maximum;
 pub use mean_to::MeanTo;
 pub use min_to::MinTo;
 pub use minimum::minimum;
+pub use mish::mish;
+pub use mode::ModeTo;
 pub use mul::{mul, TryMul};
+pub use multinomial::TryMultinomial;
 pub use nans_to::nans_to;
 pub use negate::negate;
+pub use nonzero::TryNonzero;
+pub use norm_to::NormTo;
 pub use normalize::normalize;
+pub use one_hot::TryOneHot;
+pub use outer::{outer, TryOuter};
+#[cfg(feature = "nightly")]
+pub(crate) use pad2d::PadAlgebra;
+pub use pad2d::{GenericPad2D, PadMode, TryPad2D};
 pub use permute_to::PermuteTo;
+pub use pixel_shuffle::TryPixelShuffle;
+#[cfg(feature = "nightly")]
+pub(crate) use pixel_shuffle::{ConstPixelShuffle, ConstPixelUnshuffle};
 pub use pow::{powf, powi};
+pub use prelu::{prelu, TryPReLU};
+pub use randperm::RandpermTensor;
 pub use relu::relu;
+pub use repeat::TryRepeat;
+pub use repeat_interleave::TryRepeatInterleave;
 pub use reshape_to::ReshapeTo;
-pub use select_and_gather::{GatherTo, SelectTo};
+pub use roll::TryRoll;
+pub use rounding::{ceil, floor, frac, quantize_ste, round, trunc};
+pub use rsqrt::rsqrt;
+pub use scatter_add::{TryScatterAdd, TrySelectScatterAdd};
+pub use searchsorted::{searchsorted, TrySearchSorted};
+pub use select_and_gather::{GatherNdTo, GatherTo, SelectTo};
 pub use sigmoid::sigmoid;
+pub use sign::sign;
 pub use sin::sin;
+pub use slice::TrySlice;
 pub use softmax::softmax;
+pub use softplus::softplus;
+pub use solve::{solve, triangular_solve, TrySolve};
+pub use sort::{TryArgSort, TrySort};
+pub use split::TrySplit;
 pub use sqrt::sqrt;
 pub use square::square;
 pub use stack::TryStack;
 pub use stddev_to::StddevTo;
+pub use stft::{hann_window, mel_filterbank, TryStft};
 pub use sub::{sub, TrySub};
 pub use sum_to::SumTo;
 pub use tanh::tanh;
+pub use to_dtype::to_dtype;
+pub use trapz::{trapz, TryTrapz};
+pub use triangle::{tril, triu};
+pub use unfold::TryUnfold;
+pub use upscale2d::{Bilinear, GenericUpscale2D, NearestNeighbor, TryUpscale2D, UpscaleMethod};
 pub use var_to::VarTo;
+pub use weighted_mean::WeightedMeanTo;
 
 #[cfg(feature = "nightly")]
-mod conv2d;
+mod conv1d;
+#[cfg(feature = "nightly")]
+pub use conv1d::TryConv1D;
+#[cfg(feature = "nightly")]
+pub(crate) use conv1d::TryConv1DTo;
+
 #[cfg(feature = "nightly")]
-pub use conv2d::TryConv2D;
+mod conv2d;
 #[cfg(feature = "nightly")]
 pub(crate) use conv2d::TryConv2DTo;
+#[cfg(feature = "nightly")]
+pub use conv2d::{Conv2DAlgorithm, TryConv2D};
+
+#[cfg(feature = "nightly")]
+mod conv3d;
+#[cfg(feature = "nightly")]
+pub use conv3d::TryConv3D;
+#[cfg(feature = "nightly")]
+pub(crate) use conv3d::TryConv3DTo;
+
+#[cfg(feature = "nightly")]
+mod convtrans2d;
+#[cfg(feature = "nightly")]
+pub use convtrans2d::TryConvTrans2D;
+#[cfg(feature = "nightly")]
+pub(crate) use convtrans2d::TryConvTrans2DTo;
+
+#[cfg(feature = "nightly")]
+mod pool1d;
+#[cfg(feature = "nightly")]
+pub(crate) use pool1d::{ConstAvgPool1D, ConstMaxPool1D, ConstMinPool1D};
+#[cfg(feature = "nightly")]
+pub use pool1d::{TryAvgPool1D, TryMaxPool1D, TryMinPool1D};
 
 #[cfg(feature = "nightly")]
 mod pool2d;
 #[cfg(feature = "nightly")]
-pub(crate) use pool2d::{ConstAvgPool2D, ConstMaxPool2D, ConstMinPool2D};
+pub(crate) use pool2d::{ConstAvgPool2D, ConstLpPool2D, ConstMaxPool2D, ConstMinPool2D};
+#[cfg(feature = "nightly")]
+pub use pool2d::{TryAvgPool2D, TryLpPool2D, TryMaxPool2D, TryMinPool2D};
+
+#[cfg(feature = "nightly")]
+mod pool3d;
+#[cfg(feature = "nightly")]
+pub(crate) use pool3d::{ConstAvgPool3D, ConstMaxPool3D, ConstMinPool3D};
 #[cfg(feature = "nightly")]
-pub use pool2d::{TryAvgPool2D, TryMaxPool2D, TryMinPool2D};
+pub use pool3d::{TryAvgPool3D, TryMaxPool3D, TryMinPool3D};
@@ -0,0 +1,43 @@
+use crate::{
+    shapes::{Axes, Dtype, HasAxes, ReduceShapeTo, Shape},
+    tensor::cpu::{Cpu, StridedArray},
+    tensor_ops::{sort::nans_last, utilities::reduction_utils::index_for_reductions},
+};
+
+use std::{cmp::Ordering, vec::Vec};
+
+impl<E: Dtype> super::ModeKernel<E> for Cpu {
+    fn forward<Src, Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        &self,
+        dst: Dst,
+        inp: &Self::Storage<Src, E>,
+    ) -> Result<(Self::Storage<Dst, E>, Self::Storage<Dst, usize>), Self::Err>
+    where
+        Src: Shape + ReduceShapeTo<Dst, Ax>,
+    {
+        let mut values: StridedArray<Dst, E> = StridedArray::new(dst)?;
+        let mut counts: StridedArray<Dst, usize> = StridedArray::new(dst)?;
+        let num_elems_reduced = <Src as HasAxes<Ax>>::size(&inp.shape);
+        let inp_buf = inp.data.as_ref();
+        let mut idx = index_for_reductions::<Src, Ax>(inp.shape, inp.strides);
+        let mut line: Vec<E> = Vec::with_capacity(num_elems_reduced);
+        for (v, c) in values.buf_iter_mut().zip(counts.buf_iter_mut()) {
+            line.clear();
+            line.extend((0..num_elems_reduced).map(|_| inp_buf[idx.next().unwrap()]));
+            line.sort_by(nans_last);
+
+            // runs of equal values are now adjacent, and the first longest run is
+            // the smallest mode
+            let mut start = 0;
+            for i in 1..=line.len() {
+                if i == line.len() || nans_last(&line[start], &line[i]) != Ordering::Equal {
+                    if i - start > *c {
+                        (*v, *c) = (line[start], i - start);
+                    }
+                    start = i;
+                }
+            }
+        }
+        Ok((values, counts))
+    }
+}
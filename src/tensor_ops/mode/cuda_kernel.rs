@@ -0,0 +1,76 @@
+use crate::{
+    shapes::*,
+    tensor::cuda::{Cuda, CudaArray},
+};
+use cudarc::driver::{LaunchAsync, LaunchConfig};
+use std::sync::Arc;
+
+const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/mode.ptx"));
+
+trait HasCudaKernel<E> {
+    const MOD: &'static str;
+    const FNS: &'static [&'static str];
+}
+
+impl HasCudaKernel<f32> for Cuda {
+    const MOD: &'static str = "mode_f32";
+    const FNS: &'static [&'static str] = &["mode_fwd_f32"];
+}
+
+impl HasCudaKernel<f64> for Cuda {
+    const MOD: &'static str = "mode_f64";
+    const FNS: &'static [&'static str] = &["mode_fwd_f64"];
+}
+
+impl<E: Dtype> super::ModeKernel<E> for Cuda
+where
+    Self: HasCudaKernel<E>,
+{
+    fn forward<Src, Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        &self,
+        dst: Dst,
+        inp: &Self::Storage<Src, E>,
+    ) -> Result<(Self::Storage<Dst, E>, Self::Storage<Dst, usize>), Self::Err>
+    where
+        Src: Shape + ReduceShapeTo<Dst, Ax>,
+    {
+        if !self.dev.has_func(Self::MOD, Self::FNS[0]) {
+            self.dev.load_ptx(PTX_SRC.into(), Self::MOD, Self::FNS)?;
+        }
+
+        let numel = dst.num_elements();
+        let strides = dst.strides();
+        let mut values = unsafe { self.dev.alloc_async::<E>(numel) }?;
+        let mut counts = unsafe { self.dev.alloc_async::<usize>(numel) }?;
+
+        let dims = self.dev.take_async(inp.shape.concrete().into())?;
+        let inp_strides = self.dev.take_async(inp.strides.into())?;
+
+        let fwd_fn = self.dev.get_func(Self::MOD, Self::FNS[0]).unwrap();
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            numel,                      // const size_t numel,
+            Src::NUM_DIMS,              // const size_t num_dims,
+            Ax::as_array()[0] as usize, // const size_t axis,
+            &dims,                      // const size_t *dims,
+            &inp_strides,               // const size_t *inp_strides,
+            inp.data.as_ref(),          // const T *inp,
+            &mut values,                // T *out,
+            &mut counts,                // size_t *counts
+        );
+        unsafe { fwd_fn.launch_async(cfg, params) }?;
+
+        Ok((
+            CudaArray {
+                data: Arc::new(values),
+                shape: dst,
+                strides,
+            },
+            CudaArray {
+                data: Arc::new(counts),
+                shape: dst,
+                strides,
+            },
+        ))
+    }
+}
@@ -0,0 +1,126 @@
+#![allow(clippy::type_complexity)]
+
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{shapes::*, tensor::*};
+
+pub trait ModeKernel<E: Dtype>: DeviceStorage {
+    /// Finds the most frequent value along `Ax`, and how many times it occurs. Ties
+    /// are broken by picking the smallest value. All NaNs count as the same value.
+    fn forward<Src, Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        &self,
+        dst: Dst,
+        inp: &Self::Storage<Src, E>,
+    ) -> Result<(Self::Storage<Dst, E>, Self::Storage<Dst, usize>), Self::Err>
+    where
+        Src: Shape + ReduceShapeTo<Dst, Ax>;
+}
+
+/// Reduces a single axis to its most frequent value.
+pub trait ModeTo<D: DeviceStorage>: HasErr + HasShape + HasDtype {
+    /// Returns the most frequent value along `Ax`, and the number of times it occurs.
+    /// If multiple values occur equally often, the smallest of them is returned.
+    ///
+    /// This is not differentiable, so the result does not track gradients.
+    ///
+    /// **Pytorch equivalent**: `t.mode(dim=Ax).values`, with the counts in place of indices.
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t = dev.tensor([[1.0, 3.0, 3.0, 2.0], [4.0, 2.0, 4.0, 2.0]]);
+    /// let (values, counts) = t.mode::<Rank1<2>, _>();
+    /// assert_eq!(values.array(), [3.0, 2.0]);
+    /// assert_eq!(counts.array(), [2, 2]);
+    /// ```
+    fn mode<Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        self,
+    ) -> (Tensor<Dst, Self::Dtype, D>, Tensor<Dst, usize, D>)
+    where
+        Self::Shape: ReduceShapeTo<Dst, Ax>,
+    {
+        self.try_mode().unwrap()
+    }
+    /// Fallible version of [ModeTo::mode]
+    fn try_mode<Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        self,
+    ) -> Result<(Tensor<Dst, Self::Dtype, D>, Tensor<Dst, usize, D>), Self::Err>
+    where
+        Self::Shape: ReduceShapeTo<Dst, Ax>;
+}
+
+impl<S: Shape, E: Dtype, D: ModeKernel<E>, T> ModeTo<D> for Tensor<S, E, D, T> {
+    fn try_mode<Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        self,
+    ) -> Result<(Tensor<Dst, E, D>, Tensor<Dst, usize, D>), Self::Err>
+    where
+        Self::Shape: ReduceShapeTo<Dst, Ax>,
+    {
+        let dst: Dst = self.shape().reduced();
+        let (values, counts) = self.device.forward(dst, &self.storage)?;
+        Ok((self.device.upgrade(values), self.device.upgrade(counts)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_mode_1d() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([2.0, 5.0, 2.0, 5.0, 1.0, 5.0]);
+        let (values, counts) = t.mode::<Rank0, _>();
+        assert_eq!(values.array(), 5.0);
+        assert_eq!(counts.array(), 3);
+    }
+
+    #[test]
+    fn test_mode_ties_pick_smallest() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([[3.0, 1.0, 2.0], [7.0, 7.0, -1.0]]);
+        let (values, counts) = t.mode::<Rank1<2>, _>();
+        assert_eq!(values.array(), [1.0, 7.0]);
+        assert_eq!(counts.array(), [1, 2]);
+    }
+
+    #[test]
+    fn test_mode_nans() {
+        let dev: TestDevice = Default::default();
+        let nan = TestDtype::NAN;
+        let t: Tensor<_, TestDtype, _> = dev.tensor([nan, 1.0, nan, 1.0, nan]);
+        let (values, counts) = t.clone().mode::<Rank0, _>();
+        assert!(values.array().is_nan());
+        assert_eq!(counts.array(), 3);
+        let t: Tensor<_, TestDtype, _> = dev.tensor([nan, 1.0, nan, 1.0]);
+        let (values, counts) = t.mode::<Rank0, _>();
+        assert_eq!(values.array(), 1.0);
+        assert_eq!(counts.array(), 2);
+    }
+
+    #[test]
+    fn test_mode_axis_0() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([
+            [[0.0, 1.0], [2.0, 3.0]],
+            [[0.0, 2.0], [3.0, 3.0]],
+            [[1.0, 2.0], [2.0, 4.0]],
+        ]);
+        let (values, counts) = t.mode::<Rank2<2, 2>, _>();
+        assert_eq!(values.array(), [[0.0, 2.0], [2.0, 3.0]]);
+        assert_eq!(counts.array(), [[2, 2], [2, 2]]);
+    }
+
+    #[test]
+    fn test_mode_broadcasted() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([4.0, 1.0]);
+        let (values, counts) = t.broadcast::<Rank2<3, 2>, _>().mode::<Rank1<2>, Axis<0>>();
+        assert_eq!(values.array(), [4.0, 1.0]);
+        assert_eq!(counts.array(), [3, 3]);
+    }
+}
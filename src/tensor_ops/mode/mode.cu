@@ -0,0 +1,85 @@
+#include "cuda_utils.cuh"
+
+// Equality that considers all NaNs equal to each other
+template<typename T>
+__device__ bool nans_eq(T a, T b) {
+    return a == b || (isnan(a) && isnan(b));
+}
+
+// Strict ordering that places NaNs after all other values
+template<typename T>
+__device__ bool nans_last_lt(T a, T b) {
+    if (isnan(a)) {
+        return false;
+    }
+    if (isnan(b)) {
+        return true;
+    }
+    return a < b;
+}
+
+// Each thread counts the occurrences of every value in one line along `axis`
+template<typename T>
+__device__ void mode_fwd(
+    const size_t numel,
+    const size_t num_dims,
+    const size_t axis,
+    const size_t *dims,
+    const size_t *inp_strides,
+    const T *inp,
+    T *out,
+    size_t *counts
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    // out & counts are contiguous
+    size_t inp_i = 0;
+    unsigned int idx = i;
+    for (int d = num_dims - 1; d >= 0; d--) {
+        if (d == axis) {
+            continue;
+        }
+        inp_i += (idx % dims[d]) * inp_strides[d];
+        idx /= dims[d];
+    }
+
+    const size_t stride = inp_strides[axis];
+    T best = inp[inp_i];
+    size_t best_count = 0;
+    for (size_t j = 0; j < dims[axis]; j++) {
+        const T x = inp[inp_i + j * stride];
+        size_t count = 0;
+        for (size_t l = 0; l < dims[axis]; l++) {
+            if (nans_eq(x, inp[inp_i + l * stride])) {
+                count++;
+            }
+        }
+        if (count > best_count || (count == best_count && nans_last_lt(x, best))) {
+            best = x;
+            best_count = count;
+        }
+    }
+
+    out[i] = best;
+    counts[i] = best_count;
+}
+
+#define MODE_OP(TYPENAME, FWD) \
+extern "C" __global__ void FWD( \
+    const size_t numel, \
+    const size_t num_dims, \
+    const size_t axis, \
+    const size_t *dims, \
+    const size_t *inp_strides, \
+    const TYPENAME *inp, \
+    TYPENAME *out, \
+    size_t *counts \
+) { \
+    mode_fwd(numel, num_dims, axis, dims, inp_strides, inp, out, counts); \
+}
+
+MODE_OP(float, mode_fwd_f32);
+MODE_OP(double, mode_fwd_f64);
@@ -11,4 +11,24 @@ BINARY_OP(double, bmul_fwd_f64, bmul_bwd_f64, BinaryMulKernalOp,
     x * y,
     y,
     x)
-   
\ No newline at end of file
+
+// integers don't have gradients
+BINARY_OP(size_t, bmul_fwd_usize, bmul_bwd_usize, BinaryMulKernalOp,
+    wrapping_mul(x, y),
+    0,
+    0)
+
+BINARY_OP(int, bmul_fwd_i32, bmul_bwd_i32, BinaryMulKernalOp,
+    wrapping_mul(x, y),
+    0,
+    0)
+
+BINARY_OP(long long, bmul_fwd_i64, bmul_bwd_i64, BinaryMulKernalOp,
+    wrapping_mul(x, y),
+    0,
+    0)
+
+BINARY_OP(unsigned char, bmul_fwd_u8, bmul_bwd_u8, BinaryMulKernalOp,
+    wrapping_mul(x, y),
+    0,
+    0)
@@ -1,6 +1,7 @@
-use crate::tensor_ops::cpu_kernels::{BinaryDerivative, UnaryDerivative};
-
-use num_traits::Float;
+use super::ScalarMulKernelOp;
+use crate::tensor_ops::cpu_kernels::{
+    int_binary_derivative, int_scalar_derivative, BinaryDerivative, Float, UnaryDerivative,
+};
 
 impl<F: Float> UnaryDerivative<F> for super::ScalarMulKernelOp<F> {
     fn f(&self, &x: &F) -> F {
@@ -25,3 +26,6 @@ impl<F: Float> BinaryDerivative<F> for super::BinaryMulKernelOp {
         x
     }
 }
+
+int_binary_derivative!(super::BinaryMulKernelOp, |x, y| x.wrapping_mul(y));
+int_scalar_derivative!(ScalarMulKernelOp, |x, scalar| x.wrapping_mul(scalar));
@@ -3,6 +3,10 @@ use crate::tensor_ops::cuda_kernels::{cuda_binary, cuda_unary};
 
 unsafe impl cudarc::driver::AsKernelParam for Scalar<f32> {}
 unsafe impl cudarc::driver::AsKernelParam for Scalar<f64> {}
+unsafe impl cudarc::driver::AsKernelParam for Scalar<usize> {}
+unsafe impl cudarc::driver::AsKernelParam for Scalar<i32> {}
+unsafe impl cudarc::driver::AsKernelParam for Scalar<i64> {}
+unsafe impl cudarc::driver::AsKernelParam for Scalar<u8> {}
 unsafe impl cudarc::driver::AsKernelParam for Binary {}
 
 const SCALAR_PTX: &str = include_str!(concat!(env!("OUT_DIR"), "/scalar_mul.ptx"));
@@ -10,5 +14,25 @@ const BINARY_PTX: &str = include_str!(concat!(env!("OUT_DIR"), "/binary_mul.ptx"
 
 cuda_unary!(Scalar<f32>, f32, SCALAR_PTX, "smul_fwd_f32", "smul_bwd_f32");
 cuda_unary!(Scalar<f64>, f64, SCALAR_PTX, "smul_fwd_f64", "smul_bwd_f64");
+cuda_unary!(
+    Scalar<usize>,
+    usize,
+    SCALAR_PTX,
+    "smul_fwd_usize",
+    "smul_bwd_usize"
+);
+cuda_unary!(Scalar<i32>, i32, SCALAR_PTX, "smul_fwd_i32", "smul_bwd_i32");
+cuda_unary!(Scalar<i64>, i64, SCALAR_PTX, "smul_fwd_i64", "smul_bwd_i64");
+cuda_unary!(Scalar<u8>, u8, SCALAR_PTX, "smul_fwd_u8", "smul_bwd_u8");
 cuda_binary!(Binary, f32, BINARY_PTX, "bmul_fwd_f32", "bmul_bwd_f32");
 cuda_binary!(Binary, f64, BINARY_PTX, "bmul_fwd_f64", "bmul_bwd_f64");
+cuda_binary!(
+    Binary,
+    usize,
+    BINARY_PTX,
+    "bmul_fwd_usize",
+    "bmul_bwd_usize"
+);
+cuda_binary!(Binary, i32, BINARY_PTX, "bmul_fwd_i32", "bmul_bwd_i32");
+cuda_binary!(Binary, i64, BINARY_PTX, "bmul_fwd_i64", "bmul_bwd_i64");
+cuda_binary!(Binary, u8, BINARY_PTX, "bmul_fwd_u8", "bmul_bwd_u8");
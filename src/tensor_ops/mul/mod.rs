@@ -45,9 +45,10 @@ pub trait TryMul<Rhs = Self>: HasErr {
     fn try_mul(self, rhs: Rhs) -> Result<Self, Self::Err>;
 }
 
-impl<S: Shape, E: Dtype, D: Device<E>, LhsTape: Tape<D>, RhsTape: Tape<D>>
-    TryMul<Tensor<S, E, D, RhsTape>> for Tensor<S, E, D, LhsTape>
+impl<S: Shape, E: Dtype, D, LhsTape: Tape<D>, RhsTape: Tape<D>> TryMul<Tensor<S, E, D, RhsTape>>
+    for Tensor<S, E, D, LhsTape>
 where
+    D: BinaryKernel<BinaryMulKernelOp, E>,
     LhsTape: Merge<RhsTape>,
 {
     fn try_mul(self, rhs: Tensor<S, E, D, RhsTape>) -> Result<Self, Self::Err> {
@@ -55,13 +56,15 @@ where
     }
 }
 
-impl<S: Shape, E: Dtype, D: Device<E>, T: Tape<D>> TryMul<E> for Tensor<S, E, D, T> {
+impl<S: Shape, E: Dtype, D: UnaryKernel<ScalarMulKernelOp<E>, E>, T: Tape<D>> TryMul<E>
+    for Tensor<S, E, D, T>
+{
     fn try_mul(self, rhs: E) -> Result<Self, Self::Err> {
         try_unary_op(ScalarMulKernelOp { scalar: rhs }, self)
     }
 }
 
-impl<S: Shape, E: Dtype, D: Device<E>, LhsTape: Tape<D>, Rhs> std::ops::Mul<Rhs>
+impl<S: Shape, E: Dtype, D: DeviceStorage, LhsTape: Tape<D>, Rhs> std::ops::Mul<Rhs>
     for Tensor<S, E, D, LhsTape>
 where
     Self: TryMul<Rhs>,
@@ -163,4 +166,23 @@ mod tests {
         let g = r.exp().sum().backward();
         assert_close(&g.get(&x).array(), &[[0.8243606; 2]; 3]);
     }
+
+    #[test]
+    fn test_mul_usize() {
+        let dev: TestDevice = Default::default();
+        let a = dev.tensor([[0usize, 1, 2], [3, 4, 5]]);
+        let b = dev.tensor([[7usize, 3, 2], [1, 0, 5]]);
+        assert_eq!((a.clone() * b).array(), [[0, 3, 4], [3, 0, 25]]);
+        assert_eq!((a * 4).array(), [[0, 4, 8], [12, 16, 20]]);
+    }
+
+    #[test]
+    fn test_mul_int_wraps() {
+        let dev: TestDevice = Default::default();
+        let a = dev.tensor([16u8, 3, 255]);
+        assert_eq!((a * 17).array(), [16, 51, 239]);
+        let a = dev.tensor([i32::MAX, -3, i32::MIN]);
+        let b = dev.tensor([2i32, -4, -1]);
+        assert_eq!((a * b).array(), [-2, 12, i32::MIN]);
+    }
 }
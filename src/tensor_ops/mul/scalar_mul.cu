@@ -1,3 +1,4 @@
+#include "cuda_utils.cuh"
 #include "unary_op_macros.cuh"
 
 template<typename F>
@@ -12,4 +13,20 @@ UNARY_OP(float, smul_fwd_f32, smul_bwd_f32, ScalarMulKernelOp<float>,
 UNARY_OP(double, smul_fwd_f64, smul_bwd_f64, ScalarMulKernelOp<double>,
     x * op.scalar,
     op.scalar);
-    
\ No newline at end of file
+
+// integers don't have gradients
+UNARY_OP(size_t, smul_fwd_usize, smul_bwd_usize, ScalarMulKernelOp<size_t>,
+    wrapping_mul(x, op.scalar),
+    0);
+
+UNARY_OP(int, smul_fwd_i32, smul_bwd_i32, ScalarMulKernelOp<int>,
+    wrapping_mul(x, op.scalar),
+    0);
+
+UNARY_OP(long long, smul_fwd_i64, smul_bwd_i64, ScalarMulKernelOp<long long>,
+    wrapping_mul(x, op.scalar),
+    0);
+
+UNARY_OP(unsigned char, smul_fwd_u8, smul_bwd_u8, ScalarMulKernelOp<unsigned char>,
+    wrapping_mul(x, op.scalar),
+    0);
@@ -0,0 +1,76 @@
+use crate::{
+    shapes::{Dtype, Shape},
+    tensor::{
+        cpu::{Cpu, StridedArray},
+        AsVec,
+    },
+    tensor_ops::utilities::rng::StreamRng,
+};
+
+use num_traits::Float;
+use std::{sync::Arc, vec::Vec};
+
+/// Samples an index from `weights` given a uniform value `u` in `[0, 1)`, skipping
+/// zero weights. Returns `weights.len()` if there is nothing to sample.
+fn sample_index(weights: &[f64], u: f64) -> usize {
+    let total: f64 = weights.iter().filter(|&&w| w > 0.0).sum();
+    if total <= 0.0 {
+        return weights.len();
+    }
+    let target = u * total;
+    let mut acc = 0.0;
+    let mut last = weights.len();
+    for (i, &w) in weights.iter().enumerate() {
+        if w > 0.0 {
+            acc += w;
+            last = i;
+            if target < acc {
+                return i;
+            }
+        }
+    }
+    last
+}
+
+impl<E: Dtype + Float> super::MultinomialKernel<E> for Cpu {
+    fn multinomial<S: Shape, Dst: Shape>(
+        &self,
+        seed: u64,
+        probs: &Self::Storage<S, E>,
+        dst: Dst,
+        replacement: bool,
+    ) -> Result<Self::Storage<Dst, usize>, Self::Err> {
+        let mut out: StridedArray<Dst, usize> = StridedArray::new(dst)?;
+        let num_samples = dst.concrete()[Dst::NUM_DIMS - 1];
+        if num_samples == 0 {
+            return Ok(out);
+        }
+        let n = probs.shape.concrete()[S::NUM_DIMS - 1];
+        let weights: Vec<f64> = probs
+            .as_vec()
+            .into_iter()
+            .map(|w| w.to_f64().unwrap())
+            .collect();
+
+        let out_buf = Arc::make_mut(&mut out.data);
+        for (row, samples) in out_buf.chunks_mut(num_samples).enumerate() {
+            let row_weights = &weights[row * n..(row + 1) * n];
+            if replacement {
+                for (j, s) in samples.iter_mut().enumerate() {
+                    let mut rng = StreamRng::new(seed, (row * num_samples + j) as u64);
+                    *s = sample_index(row_weights, rng.next_f64());
+                }
+            } else {
+                let mut rng = StreamRng::new(seed, row as u64);
+                let mut row_weights = row_weights.to_vec();
+                for s in samples.iter_mut() {
+                    *s = sample_index(&row_weights, rng.next_f64());
+                    if *s < n {
+                        row_weights[*s] = 0.0;
+                    }
+                }
+            }
+        }
+        Ok(out)
+    }
+}
@@ -0,0 +1,82 @@
+use crate::{
+    shapes::*,
+    tensor::cuda::{Cuda, CudaArray},
+};
+use cudarc::driver::{AsKernelParam, LaunchAsync, LaunchConfig};
+use std::sync::Arc;
+
+const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/multinomial.ptx"));
+
+trait HasCudaKernel<E> {
+    const MOD: &'static str;
+    const FNS: &'static [&'static str];
+}
+
+impl HasCudaKernel<f32> for Cuda {
+    const MOD: &'static str = "multinomial_f32";
+    const FNS: &'static [&'static str] = &[
+        "multinomial_replacement_f32",
+        "multinomial_no_replacement_f32",
+    ];
+}
+
+impl HasCudaKernel<f64> for Cuda {
+    const MOD: &'static str = "multinomial_f64";
+    const FNS: &'static [&'static str] = &[
+        "multinomial_replacement_f64",
+        "multinomial_no_replacement_f64",
+    ];
+}
+
+impl<E: Dtype + AsKernelParam> super::MultinomialKernel<E> for Cuda
+where
+    Self: HasCudaKernel<E>,
+{
+    fn multinomial<S: Shape, Dst: Shape>(
+        &self,
+        seed: u64,
+        probs: &Self::Storage<S, E>,
+        dst: Dst,
+        replacement: bool,
+    ) -> Result<Self::Storage<Dst, usize>, Self::Err> {
+        if !self.dev.has_func(Self::MOD, Self::FNS[0]) {
+            self.dev.load_ptx(PTX_SRC.into(), Self::MOD, Self::FNS)?;
+        }
+
+        let numel = dst.num_elements();
+        let mut storage = self.dev.alloc_zeros_async::<usize>(numel)?;
+
+        if numel > 0 {
+            let num_samples = dst.concrete()[Dst::NUM_DIMS - 1];
+            let dims = self.dev.take_async(probs.shape.concrete().into())?;
+            let strides = self.dev.take_async(probs.strides.into())?;
+            let (fwd_fn, num_threads) = if replacement {
+                (self.dev.get_func(Self::MOD, Self::FNS[0]).unwrap(), numel)
+            } else {
+                let num_rows = numel / num_samples;
+                (
+                    self.dev.get_func(Self::MOD, Self::FNS[1]).unwrap(),
+                    num_rows,
+                )
+            };
+            let cfg = LaunchConfig::for_num_elems(num_threads as u32);
+            let params = (
+                num_threads,         // const size_t numel,
+                S::NUM_DIMS,         // const size_t num_dims,
+                &dims,               // const size_t *dims,
+                &strides,            // const size_t *strides,
+                num_samples,         // const size_t num_samples,
+                seed,                // const unsigned long long seed,
+                probs.data.as_ref(), // const T *probs,
+                &mut storage,        // size_t *out
+            );
+            unsafe { fwd_fn.launch_async(cfg, params) }?;
+        }
+
+        Ok(CudaArray {
+            data: Arc::new(storage),
+            shape: dst,
+            strides: dst.strides(),
+        })
+    }
+}
@@ -0,0 +1,163 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{shapes::*, tensor::*};
+
+pub trait MultinomialKernel<E: Dtype>: DeviceStorage {
+    /// Samples indices into the last axis of `probs` for each of its rows, where the
+    /// last axis of `dst` is the number of samples. Rows whose weights can't be sampled
+    /// produce the length of the last axis.
+    fn multinomial<S: Shape, Dst: Shape>(
+        &self,
+        seed: u64,
+        probs: &Self::Storage<S, E>,
+        dst: Dst,
+        replacement: bool,
+    ) -> Result<Self::Storage<Dst, usize>, Self::Err>;
+}
+
+/// Sampling indices from categorical distributions.
+pub trait TryMultinomial: HasErr {
+    type Output;
+
+    /// Samples `num_samples` indices from the last axis, where each row holds the
+    /// (unnormalized) non-negative weights of a categorical distribution. Sampling
+    /// happens on the device. The result does not track gradients.
+    ///
+    /// Without `replacement`, each index is sampled at most once per row, which
+    /// panics if `num_samples` is larger than the number of categories.
+    ///
+    /// Samples from rows without any positive weights (or without enough of them when
+    /// sampling without `replacement`) are the number of categories, which is out of bounds.
+    ///
+    /// **Pytorch equivalent**: `torch.multinomial(probs, num_samples, replacement)`
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let probs = dev.tensor([0.0, 0.25, 0.0, 0.75]);
+    /// let r = probs.multinomial(5, true);
+    /// assert!(r.as_vec().iter().all(|&i| i == 1 || i == 3));
+    /// ```
+    ///
+    /// Sampling each row of a batch without replacement:
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let probs = dev.tensor([[1.0, 1.0, 0.0], [0.0, 3.0, 1.0]]);
+    /// let r = probs.multinomial(2, false);
+    /// assert_eq!(r.shape(), &(Const::<2>, 2));
+    /// let r = r.as_vec();
+    /// assert!(r[..2] == [0, 1] || r[..2] == [1, 0]);
+    /// assert!(r[2..] == [1, 2] || r[2..] == [2, 1]);
+    /// ```
+    fn multinomial(&self, num_samples: usize, replacement: bool) -> Self::Output {
+        self.try_multinomial(num_samples, replacement).unwrap()
+    }
+
+    /// Fallible version of [TryMultinomial::multinomial]
+    fn try_multinomial(
+        &self,
+        num_samples: usize,
+        replacement: bool,
+    ) -> Result<Self::Output, Self::Err>;
+}
+
+impl<N: Dim, E: Dtype, D: MultinomialKernel<E>, T> TryMultinomial for Tensor<(N,), E, D, T> {
+    type Output = Tensor<(usize,), usize, D>;
+
+    fn try_multinomial(
+        &self,
+        num_samples: usize,
+        replacement: bool,
+    ) -> Result<Self::Output, Self::Err> {
+        let (n,) = *self.shape();
+        assert!(
+            replacement || num_samples <= n.size(),
+            "cannot sample more than the number of categories without replacement"
+        );
+        let seed = self.device.random_u64();
+        let storage = self
+            .device
+            .multinomial(seed, &self.storage, (num_samples,), replacement)?;
+        Ok(self.device.upgrade(storage))
+    }
+}
+
+impl<B: Dim, N: Dim, E: Dtype, D: MultinomialKernel<E>, T> TryMultinomial
+    for Tensor<(B, N), E, D, T>
+{
+    type Output = Tensor<(B, usize), usize, D>;
+
+    fn try_multinomial(
+        &self,
+        num_samples: usize,
+        replacement: bool,
+    ) -> Result<Self::Output, Self::Err> {
+        let (b, n) = *self.shape();
+        assert!(
+            replacement || num_samples <= n.size(),
+            "cannot sample more than the number of categories without replacement"
+        );
+        let seed = self.device.random_u64();
+        let storage =
+            self.device
+                .multinomial(seed, &self.storage, (b, num_samples), replacement)?;
+        Ok(self.device.upgrade(storage))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{shapes::*, tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_multinomial_with_replacement_frequencies() {
+        let dev: TestDevice = Default::default();
+        let probs: Tensor<_, TestDtype, _> = dev.tensor([1.0, 0.0, 2.0, 1.0]);
+        let r = probs.multinomial(4000, true);
+        let counts = r.bincount().as_vec();
+        assert_eq!(counts[1], 0);
+        for (i, expected) in [(0, 1000.0), (2, 2000.0), (3, 1000.0)] {
+            assert!((counts[i] as f64 - expected).abs() < 150.0, "{counts:?}");
+        }
+    }
+
+    #[test]
+    fn test_multinomial_without_replacement() {
+        let dev: TestDevice = Default::default();
+        let probs: Tensor<Rank2<8, 5>, TestDtype, _> = dev.sample_uniform();
+        let r = probs.multinomial(5, false).as_vec();
+        for row in r.chunks(5) {
+            let mut row = row.to_vec();
+            row.sort_unstable();
+            assert_eq!(row, [0, 1, 2, 3, 4]);
+        }
+    }
+
+    #[test]
+    fn test_multinomial_without_replacement_prefers_heavy() {
+        let dev: TestDevice = Default::default();
+        let probs: Tensor<_, TestDtype, _> = dev.tensor([[1e-6, 1.0, 1e-6], [1.0, 1e-6, 1e-6]]);
+        let r = probs.multinomial(1, false);
+        assert_eq!(r.as_vec(), [1, 0]);
+    }
+
+    #[test]
+    fn test_multinomial_invalid_rows() {
+        let dev: TestDevice = Default::default();
+        let probs: Tensor<_, TestDtype, _> = dev.tensor([[0.0, 0.0], [0.0, 1.0]]);
+        assert_eq!(probs.multinomial(2, true).as_vec(), [2, 2, 1, 1]);
+        assert_eq!(probs.multinomial(2, false).as_vec(), [2, 2, 1, 2]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_multinomial_too_many_samples() {
+        let dev: TestDevice = Default::default();
+        let probs: Tensor<_, TestDtype, _> = dev.tensor([1.0, 1.0]);
+        probs.multinomial(3, false);
+    }
+}
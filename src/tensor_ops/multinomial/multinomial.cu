@@ -0,0 +1,131 @@
+#include "cuda_utils.cuh"
+
+// Samples an index from the row of probs starting at the logical index `row_start`,
+// skipping zero weights. Returns n if there is nothing to sample. When `taken` is
+// not null, the weights at its first `num_taken` indices are treated as 0.
+template<typename T>
+__device__ size_t sample_index(
+    const size_t num_dims,
+    const size_t *dims,
+    const size_t *strides,
+    const T *probs,
+    const size_t row_start,
+    const size_t n,
+    const size_t *taken,
+    const size_t num_taken,
+    const double u
+) {
+    double total = 0.0;
+    for (size_t c = 0; c < n; c++) {
+        double w = probs[get_strided_index(row_start + c, num_dims, dims, strides)];
+        bool is_taken = false;
+        for (size_t t = 0; t < num_taken; t++) {
+            is_taken |= taken[t] == c;
+        }
+        if (w > 0.0 && !is_taken) {
+            total += w;
+        }
+    }
+    if (total <= 0.0) {
+        return n;
+    }
+
+    const double target = u * total;
+    double acc = 0.0;
+    size_t last = n;
+    for (size_t c = 0; c < n; c++) {
+        double w = probs[get_strided_index(row_start + c, num_dims, dims, strides)];
+        bool is_taken = false;
+        for (size_t t = 0; t < num_taken; t++) {
+            is_taken |= taken[t] == c;
+        }
+        if (w > 0.0 && !is_taken) {
+            acc += w;
+            last = c;
+            if (target < acc) {
+                return c;
+            }
+        }
+    }
+    return last;
+}
+
+// One thread per sample, each with its own rng stream.
+template<typename T>
+__device__ void multinomial_replacement(
+    const size_t numel,
+    const size_t num_dims,
+    const size_t *dims,
+    const size_t *strides,
+    const size_t num_samples,
+    const unsigned long long seed,
+    const T *probs,
+    size_t *out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    const size_t n = dims[num_dims - 1];
+    const size_t row = i / num_samples;
+    unsigned long long state = rng_init(seed, i);
+    const double u = rng_next_f64(&state);
+    out[i] = sample_index(num_dims, dims, strides, probs, row * n, n, out, 0, u);
+}
+
+// One thread per row, each with its own rng stream. Previous samples of the
+// row are excluded from the following ones.
+template<typename T>
+__device__ void multinomial_no_replacement(
+    const size_t num_rows,
+    const size_t num_dims,
+    const size_t *dims,
+    const size_t *strides,
+    const size_t num_samples,
+    const unsigned long long seed,
+    const T *probs,
+    size_t *out
+) {
+    unsigned int row = blockIdx.x * blockDim.x + threadIdx.x;
+    if (row >= num_rows) {
+        return;
+    }
+
+    const size_t n = dims[num_dims - 1];
+    size_t *samples = out + row * num_samples;
+    unsigned long long state = rng_init(seed, row);
+    for (size_t j = 0; j < num_samples; j++) {
+        const double u = rng_next_f64(&state);
+        samples[j] = sample_index(num_dims, dims, strides, probs, row * n, n, samples, j, u);
+    }
+}
+
+#define MULTINOMIAL(TYPENAME, REPLACEMENT, NO_REPLACEMENT) \
+extern "C" __global__ void REPLACEMENT( \
+    const size_t numel, \
+    const size_t num_dims, \
+    const size_t *dims, \
+    const size_t *strides, \
+    const size_t num_samples, \
+    const unsigned long long seed, \
+    const TYPENAME *probs, \
+    size_t *out \
+) { \
+    multinomial_replacement(numel, num_dims, dims, strides, num_samples, seed, probs, out); \
+} \
+extern "C" __global__ void NO_REPLACEMENT( \
+    const size_t num_rows, \
+    const size_t num_dims, \
+    const size_t *dims, \
+    const size_t *strides, \
+    const size_t num_samples, \
+    const unsigned long long seed, \
+    const TYPENAME *probs, \
+    size_t *out \
+) { \
+    multinomial_no_replacement(num_rows, num_dims, dims, strides, num_samples, seed, probs, out); \
+}
+
+MULTINOMIAL(float, multinomial_replacement_f32, multinomial_no_replacement_f32);
+MULTINOMIAL(double, multinomial_replacement_f64, multinomial_no_replacement_f64);
@@ -0,0 +1,32 @@
+use crate::{
+    shapes::{Shape, Unit},
+    tensor::cpu::{Cpu, LendingIterator, StridedArray},
+};
+
+use std::{sync::Arc, vec::Vec};
+
+impl<E: Unit> super::NonzeroKernel<E> for Cpu {
+    fn nonzero<S: Shape, Dst: Shape>(
+        &self,
+        inp: &Self::Storage<S, E>,
+        dst: fn(usize) -> Dst,
+    ) -> Result<Self::Storage<Dst, usize>, Self::Err> {
+        let mut coords = Vec::new();
+        if inp.shape.num_elements() > 0 {
+            let mut inp_iter = inp.iter_with_index();
+            while let Some((x, idx)) = inp_iter.next() {
+                if *x != E::default() {
+                    let idx: Vec<usize> = idx.into();
+                    coords.extend_from_slice(&idx);
+                }
+            }
+        }
+        let shape = dst(coords.len() / S::NUM_DIMS.max(1));
+        debug_assert_eq!(shape.num_elements(), coords.len());
+        Ok(StridedArray {
+            data: Arc::new(coords),
+            shape,
+            strides: shape.strides(),
+        })
+    }
+}
@@ -0,0 +1,18 @@
+use crate::{
+    shapes::{Shape, Unit},
+    tensor::cuda::Cuda,
+};
+
+impl<E: Unit> super::NonzeroKernel<E> for Cuda {
+    fn nonzero<S: Shape, Dst: Shape>(
+        &self,
+        inp: &Self::Storage<S, E>,
+        dst: fn(usize) -> Dst,
+    ) -> Result<Self::Storage<Dst, usize>, Self::Err> {
+        // the shape of the output depends on the values of the input, so the
+        // coordinates are computed on the host
+        let inp = self.storage_to_cpu(inp)?;
+        let out = self.cpu.nonzero(&inp, dst)?;
+        self.storage_from_cpu(out)
+    }
+}
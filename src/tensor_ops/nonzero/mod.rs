@@ -0,0 +1,134 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{shapes::*, tensor::*};
+
+pub trait NonzeroKernel<E: Unit>: DeviceStorage {
+    /// Collects the coordinates of the non-zero elements of `inp` in row major order.
+    /// `dst` builds the output shape from the number of non-zero elements, and must
+    /// have `S::NUM_DIMS` elements per non-zero element.
+    fn nonzero<S: Shape, Dst: Shape>(
+        &self,
+        inp: &Self::Storage<S, E>,
+        dst: fn(usize) -> Dst,
+    ) -> Result<Self::Storage<Dst, usize>, Self::Err>;
+}
+
+/// Indices of the non-zero (or `true`) elements of a tensor.
+pub trait TryNonzero: HasErr {
+    type Output;
+
+    /// Returns the indices of all non-zero elements in row major order. The result
+    /// does not track gradients.
+    ///
+    /// For 1d tensors this is a `(usize,)` tensor of indices, which can be used
+    /// directly with [super::GatherTo::gather]. For higher ranks each row of the
+    /// `(usize, Const<N>)` result holds the coordinates of one element.
+    ///
+    /// **Pytorch equivalent**: `t.nonzero()`
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t = dev.tensor([0.0, 2.0, 0.0, 3.0]);
+    /// let idx = t.nonzero();
+    /// assert_eq!(idx.as_vec(), [1, 3]);
+    /// let r: Tensor<(usize,), f32, _> = t.gather(idx);
+    /// assert_eq!(r.as_vec(), [2.0, 3.0]);
+    /// ```
+    ///
+    /// Masks and higher ranks:
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t = dev.tensor([[1.0, -2.0], [3.0, 0.5]]);
+    /// let idx = t.scalar_gt(0.75).nonzero();
+    /// assert_eq!(idx.shape(), &(2, Const::<2>));
+    /// assert_eq!(idx.as_vec(), [0, 0, 1, 0]);
+    /// ```
+    fn nonzero(&self) -> Self::Output {
+        self.try_nonzero().unwrap()
+    }
+
+    /// Fallible version of [TryNonzero::nonzero]
+    fn try_nonzero(&self) -> Result<Self::Output, Self::Err>;
+}
+
+impl<D0: Dim, E: Unit, D: NonzeroKernel<E>, T> TryNonzero for Tensor<(D0,), E, D, T> {
+    type Output = Tensor<(usize,), usize, D>;
+
+    fn try_nonzero(&self) -> Result<Self::Output, Self::Err> {
+        let storage = self.device.nonzero(&self.storage, |n| (n,))?;
+        Ok(self.device.upgrade(storage))
+    }
+}
+
+macro_rules! nonzero {
+    (($($Dims:ident),*), $N:expr) => {
+        impl<$($Dims: Dim, )* E: Unit, D: NonzeroKernel<E>, T> TryNonzero
+            for Tensor<($($Dims, )*), E, D, T>
+        {
+            type Output = Tensor<(usize, Const<$N>), usize, D>;
+
+            fn try_nonzero(&self) -> Result<Self::Output, Self::Err> {
+                let storage = self.device.nonzero(&self.storage, |n| (n, Const))?;
+                Ok(self.device.upgrade(storage))
+            }
+        }
+    };
+}
+
+nonzero!((D0, D1), 2);
+nonzero!((D0, D1, D2), 3);
+nonzero!((D0, D1, D2, D3), 4);
+nonzero!((D0, D1, D2, D3, D4), 5);
+nonzero!((D0, D1, D2, D3, D4, D5), 6);
+
+#[cfg(test)]
+mod tests {
+    use crate::{shapes::*, tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_nonzero_1d() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([0.0, -1.0, 0.0, TestDtype::NAN, -0.0]);
+        let idx = t.nonzero();
+        assert_eq!(idx.as_vec(), [1, 3]);
+    }
+
+    #[test]
+    fn test_nonzero_none() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank2<2, 3>, TestDtype, _> = dev.zeros();
+        let idx = t.nonzero();
+        assert_eq!(idx.shape(), &(0, Const::<2>));
+    }
+
+    #[test]
+    fn test_nonzero_permuted() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([[1.0, 0.0, 2.0], [0.0, 3.0, 4.0]]);
+        let idx = t.permute::<Rank2<3, 2>, _>().nonzero();
+        assert_eq!(idx.shape(), &(4, Const::<2>));
+        assert_eq!(idx.as_vec(), [0, 0, 1, 1, 2, 0, 2, 1]);
+    }
+
+    #[test]
+    fn test_nonzero_3d_usize() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank3<2, 2, 2>, usize, _> = dev.tensor([[[0, 3], [0, 0]], [[1, 0], [0, 2]]]);
+        assert_eq!(t.nonzero().as_vec(), [0, 0, 1, 1, 0, 0, 1, 1, 1]);
+    }
+
+    #[test]
+    fn test_nonzero_gather() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([3.0, 0.0, -1.0, 0.0]);
+        let r: Tensor<(usize,), TestDtype, _, _> = t.trace().gather(t.nonzero());
+        assert_eq!(r.as_vec(), [3.0, -1.0]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&t).array(), [1.0, 0.0, 1.0, 0.0]);
+    }
+}
@@ -0,0 +1,83 @@
+use crate::{
+    shapes::{Axes, Dtype, HasAxes, ReduceShapeTo, Shape},
+    tensor::cpu::{Cpu, StridedArray},
+    tensor_ops::utilities::reduction_utils::index_for_reductions,
+};
+
+use num_traits::Float;
+
+impl<E: Dtype + Float> super::NormKernel<E> for Cpu {
+    fn forward<Src, Dst: Shape, Ax: Axes>(
+        &self,
+        dst: Dst,
+        inp: &Self::Storage<Src, E>,
+        p: E,
+    ) -> Result<Self::Storage<Dst, E>, Self::Err>
+    where
+        Src: Shape + ReduceShapeTo<Dst, Ax>,
+    {
+        let mut out: StridedArray<Dst, E> = StridedArray::new(dst)?;
+        let num_elems_reduced = <Src as HasAxes<Ax>>::size(&inp.shape);
+        let inp_buf = inp.data.as_ref();
+        let mut idx = index_for_reductions::<Src, Ax>(inp.shape, inp.strides);
+        for o in out.buf_iter_mut() {
+            let mut tmp = E::zero();
+            if p.is_infinite() {
+                for _ in 0..num_elems_reduced {
+                    tmp = tmp.max(inp_buf[idx.next().unwrap()].abs());
+                }
+            } else {
+                for _ in 0..num_elems_reduced {
+                    tmp += inp_buf[idx.next().unwrap()].abs().powf(p);
+                }
+                tmp = if p == E::one() {
+                    tmp
+                } else if p == E::from_f64(2.0).unwrap() {
+                    tmp.sqrt()
+                } else {
+                    tmp.powf(p.recip())
+                };
+            }
+            *o = tmp;
+        }
+        Ok(out)
+    }
+
+    fn backward<Src, Dst: Shape, Ax: Axes>(
+        &self,
+        inp: &Self::Storage<Src, E>,
+        grad_inp: &mut Self::Storage<Src, E>,
+        out: &Self::Storage<Dst, E>,
+        grad_out: &Self::Storage<Dst, E>,
+        p: E,
+    ) -> Result<(), Self::Err>
+    where
+        Src: Shape + ReduceShapeTo<Dst, Ax>,
+    {
+        let num_elems_reduced = <Src as HasAxes<Ax>>::size(&grad_inp.shape);
+
+        let grad_inp_buf = std::sync::Arc::make_mut(&mut grad_inp.data);
+        let inp_buf = inp.data.as_ref();
+        let mut inp_idx = index_for_reductions::<Src, Ax>(grad_inp.shape, grad_inp.strides);
+
+        for (&o, &go) in out.buf_iter().zip(grad_out.buf_iter()) {
+            for _ in 0..num_elems_reduced {
+                let inp_i = inp_idx.next().unwrap();
+                let x = inp_buf[inp_i];
+                let d = if x == E::zero() || o == E::zero() {
+                    E::zero()
+                } else if p.is_infinite() {
+                    if x.abs() == o {
+                        x.signum()
+                    } else {
+                        E::zero()
+                    }
+                } else {
+                    x.signum() * (x.abs() / o).powf(p - E::one())
+                };
+                grad_inp_buf[inp_i] += go * d;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,152 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{gradients::Tape, shapes::*, tensor::*};
+
+pub trait NormKernel<E: Dtype>: DeviceStorage {
+    fn forward<Src, Dst: Shape, Ax: Axes>(
+        &self,
+        dst: Dst,
+        inp: &Self::Storage<Src, E>,
+        p: E,
+    ) -> Result<Self::Storage<Dst, E>, Self::Err>
+    where
+        Src: Shape + ReduceShapeTo<Dst, Ax>;
+    fn backward<Src, Dst: Shape, Ax: Axes>(
+        &self,
+        inp: &Self::Storage<Src, E>,
+        grad_inp: &mut Self::Storage<Src, E>,
+        out: &Self::Storage<Dst, E>,
+        grad_out: &Self::Storage<Dst, E>,
+        p: E,
+    ) -> Result<(), Self::Err>
+    where
+        Src: Shape + ReduceShapeTo<Dst, Ax>;
+}
+
+/// Reduction along multiple axes using a p-norm, `(sum |x|^p)^(1/p)`.
+pub trait NormTo<E: Dtype>: HasErr + HasShape {
+    /// p-norm reduction for any `p > 0`, where `p = E::INFINITY` gives the max norm `max |x|`.
+    /// **Pytorch equivalent**: `torch.linalg.vector_norm(t, ord=p, dim=Ax)`
+    ///
+    /// Gradients use the subgradient `0` where the norm is `0`. For the max norm, every
+    /// element with the maximum absolute value receives the full gradient, like [super::MaxTo].
+    ///
+    /// Example reducing a single axis with the L2 norm:
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t: Tensor<Rank2<2, 2>, f32, _> = dev.tensor([[3.0, -4.0], [0.0, 0.0]]);
+    /// let r = t.norm::<_, Axis<1>>(2.0);
+    /// assert_eq!(r.array(), [5.0, 0.0]);
+    /// ```
+    ///
+    /// L1 and max norms of all elements:
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// # let t: Tensor<Rank2<2, 2>, f32, _> = dev.tensor([[3.0, -4.0], [0.0, 0.0]]);
+    /// assert_eq!(t.clone().norm::<Rank0, _>(1.0).array(), 7.0);
+    /// assert_eq!(t.norm::<Rank0, _>(f32::INFINITY).array(), 4.0);
+    /// ```
+    fn norm<Dst: Shape, Ax: Axes>(self, p: E) -> Self::WithShape<Dst>
+    where
+        Self::Shape: ReduceShapeTo<Dst, Ax>,
+    {
+        self.try_norm(p).unwrap()
+    }
+    /// Fallible version of [NormTo::norm]
+    fn try_norm<Dst: Shape, Ax: Axes>(self, p: E) -> Result<Self::WithShape<Dst>, Self::Err>
+    where
+        Self::Shape: ReduceShapeTo<Dst, Ax>;
+}
+
+impl<S: Shape, E: Dtype, D: NormKernel<E>, T: Tape<D>> NormTo<E> for Tensor<S, E, D, T> {
+    fn try_norm<Dst: Shape, Ax: Axes>(self, p: E) -> Result<Self::WithShape<Dst>, Self::Err>
+    where
+        Self::Shape: ReduceShapeTo<Dst, Ax>,
+    {
+        assert!(p > E::default(), "p must be positive");
+        let dst: Dst = self.shape().reduced();
+        let (inp, mut tape) = self.split_tape();
+        let out = inp
+            .device
+            .upgrade(inp.device.forward(dst, &inp.storage, p)?);
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&inp)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+            inp.device
+                .backward(&inp.storage, grad_inp, &phantom_out.storage, grad_out, p)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor_ops::*;
+    use crate::tests::*;
+
+    #[test]
+    fn test_l2_norm_axis_1() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([[3.0, -4.0], [0.0, 0.0], [-1.0, 0.0]]);
+        let r = t.trace().norm::<_, Axis<1>>(2.0);
+        assert_eq!(r.array(), [5.0, 0.0, 1.0]);
+        let g = r.sum().backward();
+        assert_close(&g.get(&t).array(), &[[0.6, -0.8], [0.0, 0.0], [-1.0, 0.0]]);
+    }
+
+    #[test]
+    fn test_l1_norm_axis_0() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([[1.0, -2.0, 0.0], [-3.0, 4.0, 0.0]]);
+        let r = t.trace().norm::<_, Axis<0>>(1.0);
+        assert_eq!(r.array(), [4.0, 6.0, 0.0]);
+        let g = (r * dev.tensor([1.0, 2.0, 3.0])).sum().backward();
+        assert_eq!(g.get(&t).array(), [[1.0, -2.0, 0.0], [-1.0, 2.0, 0.0]]);
+    }
+
+    #[test]
+    fn test_inf_norm() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([[1.0, -3.0, 3.0], [-0.5, 0.0, 0.25]]);
+        let r = t.trace().norm::<_, Axis<1>>(TestDtype::INFINITY);
+        assert_eq!(r.array(), [3.0, 0.5]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&t).array(), [[0.0, -1.0, 1.0], [-1.0, 0.0, 0.0]]);
+    }
+
+    #[test]
+    fn test_p_norm_matches_composition() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank3<2, 3, 4>, TestDtype, _> = dev.sample_normal();
+        let r = t.trace().norm::<Rank1<3>, _>(3.0);
+        let r2 = t
+            .trace()
+            .abs()
+            .powf(3.0)
+            .sum::<Rank1<3>, _>()
+            .powf(1.0 / 3.0);
+        assert_close(&r.array(), &r2.array());
+        let g = r.exp().sum().backward();
+        let g2 = r2.exp().sum().backward();
+        assert_close_with_tolerance(&g.get(&t).array(), &g2.get(&t).array(), 1e-5);
+    }
+
+    #[test]
+    fn test_norm_broadcasted() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([3.0, 4.0]);
+        let b = t.trace().broadcast::<Rank2<4, 2>, _>();
+        let r = b.norm::<Rank0, _>(2.0);
+        assert_close(&r.array(), &10.0);
+        let g = r.backward();
+        assert_close(&g.get(&t).array(), &[1.2, 1.6]);
+    }
+}
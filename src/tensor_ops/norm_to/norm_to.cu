@@ -0,0 +1,189 @@
+#include "cuda_utils.cuh"
+
+// atomicMax is not implemented for floats, but the ordering of non-negative
+// floats is the same as the ordering of their bits as signed integers
+__device__ __forceinline__ void atomicMaxAbs(float * addr, float value) {
+    atomicMax((int *)addr, __float_as_int(value));
+}
+
+__device__ __forceinline__ void atomicMaxAbs(double * addr, double value) {
+    atomicMax((long long int *)addr, __double_as_longlong(value));
+}
+
+// Efficiently computes the sum (or the max if is_max) of each chunk in "data" of size
+// chunk_len, and accumulates them into out[i / chunk_len]
+template<typename T>
+__device__ void chunk_reduce(
+    const size_t numel,
+    const size_t chunk_len,
+    const bool is_max,
+    const T data,
+    T* out
+) {
+    __shared__ T buf[1024];
+    // assumes that threads where i >= numel have already exited
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    unsigned int block_i = threadIdx.x;
+    buf[block_i] = data;
+
+    unsigned int chunk_i = i % chunk_len;
+    unsigned int chunk_start = max((int)(block_i - chunk_i), 0);
+    unsigned int chunk_end = min((unsigned int)(block_i + chunk_len - chunk_i), blockDim.x);
+
+    chunk_i = block_i - chunk_start;
+
+    size_t max_chunk_len = min(chunk_end - chunk_start, blockDim.x);
+    size_t incr = next_power_of_two(max_chunk_len) >> 1;
+
+    __syncthreads();
+
+    // Uses sequential addressing as discussed in
+    // https://developer.download.nvidia.com/assets/cuda/files/reduction.pdf
+    for (; incr > 0; incr >>= 1) {
+        unsigned int block_i_2 = block_i + incr;
+
+        if (block_i_2 < chunk_end && chunk_i < incr) {
+            // This is sound because __syncthreads and the conditions above
+            // ensure that no data races occur
+            if (is_max) {
+                buf[block_i] = maxg(buf[block_i], buf[block_i_2]);
+            } else {
+                buf[block_i] += buf[block_i_2];
+            }
+        }
+
+        __syncthreads();
+    }
+
+    if (block_i == chunk_start) {
+        if (is_max) {
+            atomicMaxAbs(out + i / chunk_len, buf[block_i]);
+        } else {
+            atomicAdd(out + i / chunk_len, buf[block_i]);
+        }
+    }
+}
+
+// strides and dims specify how to index inp to put all reduced elements next to
+// each other, and chunk_len is len(inp) / len(out). Accumulates sum(|x|^p) into out,
+// or max(|x|) if p is infinite. out is expected to be zeroed.
+template<typename T>
+__device__ void norm_to_fwd(
+    const size_t numel,
+    const size_t num_dims,
+    const T elems_per_thread,
+    const size_t chunk_len,
+    const T p,
+    const T *inp,
+    const size_t *dims,
+    const size_t *strides,
+    T *out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+
+    if (i >= numel) {
+        return;
+    }
+
+    unsigned int inp_i = get_strided_index(i, num_dims, dims, strides);
+    T x = absg(inp[inp_i]);
+    if (isinf(p)) {
+        chunk_reduce(numel, chunk_len, true, x, out);
+    } else {
+        chunk_reduce(numel, chunk_len, false, powg(x, p) * elems_per_thread, out);
+    }
+}
+
+// Takes the p-th root of the sums computed by norm_to_fwd.
+template<typename T>
+__device__ void norm_to_root(
+    const size_t numel,
+    const T p,
+    T *out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+
+    if (i >= numel || isinf(p) || p == 1.0) {
+        return;
+    }
+
+    out[i] = p == 2.0 ? sqrtg(out[i]) : powg(out[i], 1.0 / p);
+}
+
+// Accepts pre-broadcasted strides for both input & output.
+// So both inp & out are expected to be broadcasted to the same size.
+template<typename T>
+__device__ void norm_to_bwd(
+    const size_t numel,
+    const size_t num_dims,
+    const T elems_per_thread,
+    const T p,
+    const size_t *dims,
+    const T *inp,
+    T *grad_inp,
+    const size_t *inp_strides,
+    const T *out,
+    const T *grad_out,
+    const size_t *out_strides
+) {
+    unsigned int inp_i = blockIdx.x * blockDim.x + threadIdx.x;
+
+    if (inp_i >= numel) {
+        return;
+    }
+
+    unsigned int i = get_unstrided_index(inp_i, num_dims, dims, inp_strides);
+    unsigned int out_i = get_strided_index(i, num_dims, dims, out_strides);
+
+    T x = inp[inp_i];
+    T o = out[out_i];
+    T d = 0.0;
+    if (x != 0.0 && o != 0.0) {
+        if (isinf(p)) {
+            d = absg(x) == o ? copysigng(1.0, x) : 0.0;
+        } else {
+            d = copysigng(powg(absg(x) / o, p - 1.0), x);
+        }
+    }
+    grad_inp[inp_i] += grad_out[out_i] * d * elems_per_thread;
+}
+
+#define NORM(TYPENAME, FWD, ROOT, BWD) \
+extern "C" __global__ void FWD( \
+    const size_t numel, \
+    const size_t num_dims, \
+    const TYPENAME elems_per_thread, \
+    const size_t chunk_len, \
+    const TYPENAME p, \
+    const TYPENAME *inp, \
+    const size_t *dims, \
+    const size_t *strides, \
+    TYPENAME *out \
+) { \
+    norm_to_fwd(numel, num_dims, elems_per_thread, chunk_len, p, inp, dims, strides, out); \
+} \
+extern "C" __global__ void ROOT( \
+    const size_t numel, \
+    const TYPENAME p, \
+    TYPENAME *out \
+) { \
+    norm_to_root(numel, p, out); \
+} \
+extern "C" __global__ void BWD( \
+    const size_t numel, \
+    const size_t num_dims, \
+    const TYPENAME elems_per_thread, \
+    const TYPENAME p, \
+    const size_t *dims, \
+    const TYPENAME *inp, \
+    TYPENAME *grad_inp, \
+    const size_t *inp_strides, \
+    const TYPENAME *out, \
+    const TYPENAME *grad_out, \
+    const size_t *out_strides \
+) { \
+    norm_to_bwd(numel, num_dims, elems_per_thread, p, dims, inp, grad_inp, inp_strides, out, grad_out, out_strides); \
+}
+
+NORM(float, norm_to_fwd_f32, norm_to_root_f32, norm_to_bwd_f32);
+NORM(double, norm_to_fwd_f64, norm_to_root_f64, norm_to_bwd_f64);
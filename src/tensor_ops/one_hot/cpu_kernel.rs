@@ -0,0 +1,29 @@
+use crate::{
+    shapes::{Dtype, Shape},
+    tensor::cpu::{Cpu, LendingIterator, StridedArray},
+};
+
+impl<E: Dtype> super::OneHotKernel<E> for Cpu {
+    fn forward<Src: Shape, Dst: Shape>(
+        &self,
+        dst: Dst,
+        inp: &Self::Storage<Src, usize>,
+    ) -> Result<Self::Storage<Dst, E>, Self::Err> {
+        let mut out = StridedArray::new(dst)?;
+        if inp.shape.num_elements() == 0 {
+            return Ok(out);
+        }
+        let n = dst.concrete()[Dst::NUM_DIMS - 1];
+        let mut inp_iter = inp.iter_with_index();
+        while let Some((&x, i)) = inp_iter.next() {
+            assert!(x < n, "one_hot index {x} is out of range for {n} classes");
+            let mut out_i: Dst::Concrete = Default::default();
+            for d in 0..Src::NUM_DIMS {
+                out_i[d] = i[d];
+            }
+            out_i[Dst::NUM_DIMS - 1] = x;
+            out[out_i] = E::ONE;
+        }
+        Ok(out)
+    }
+}
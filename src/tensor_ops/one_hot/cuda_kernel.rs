@@ -0,0 +1,64 @@
+use crate::{
+    shapes::*,
+    tensor::cuda::{Cuda, CudaArray},
+};
+use cudarc::driver::{LaunchAsync, LaunchConfig};
+use std::sync::Arc;
+
+const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/one_hot.ptx"));
+
+trait HasCudaKernel<E> {
+    const MOD: &'static str;
+    const FNS: &'static [&'static str];
+}
+
+impl HasCudaKernel<f32> for Cuda {
+    const MOD: &'static str = "one_hot_f32";
+    const FNS: &'static [&'static str] = &["one_hot_fwd_f32"];
+}
+
+impl HasCudaKernel<f64> for Cuda {
+    const MOD: &'static str = "one_hot_f64";
+    const FNS: &'static [&'static str] = &["one_hot_fwd_f64"];
+}
+
+impl<E: Dtype> super::OneHotKernel<E> for Cuda
+where
+    Self: HasCudaKernel<E>,
+{
+    fn forward<Src: Shape, Dst: Shape>(
+        &self,
+        dst: Dst,
+        inp: &Self::Storage<Src, usize>,
+    ) -> Result<Self::Storage<Dst, E>, Self::Err> {
+        if !self.dev.has_func(Self::MOD, Self::FNS[0]) {
+            self.dev.load_ptx(PTX_SRC.into(), Self::MOD, Self::FNS)?;
+        }
+
+        let mut storage = self.dev.alloc_zeros_async::<E>(dst.num_elements())?;
+
+        let numel = inp.shape.num_elements();
+        if numel > 0 {
+            let dims = self.dev.take_async(inp.shape.concrete().into())?;
+            let inp_strides = self.dev.take_async(inp.strides.into())?;
+            let fwd_fn = self.dev.get_func(Self::MOD, Self::FNS[0]).unwrap();
+            let cfg = LaunchConfig::for_num_elems(numel as u32);
+            let params = (
+                numel,                             // const size_t numel,
+                Src::NUM_DIMS,                     // const size_t num_dims,
+                &dims,                             // const size_t *dims,
+                &inp_strides,                      // const size_t *inp_strides,
+                dst.concrete()[Dst::NUM_DIMS - 1], // const size_t n,
+                inp.data.as_ref(),                 // const size_t *inp,
+                &mut storage,                      // T *out
+            );
+            unsafe { fwd_fn.launch_async(cfg, params) }?;
+        }
+
+        Ok(CudaArray {
+            data: Arc::new(storage),
+            shape: dst,
+            strides: dst.strides(),
+        })
+    }
+}
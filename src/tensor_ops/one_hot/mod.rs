@@ -0,0 +1,113 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{shapes::*, tensor::*};
+
+pub trait OneHotKernel<E: Dtype>: DeviceStorage {
+    /// Sets `out[..., inp[...]]` to `1` and everything else to `0`, where `Dst` is
+    /// `Src` with the number of classes appended.
+    fn forward<Src: Shape, Dst: Shape>(
+        &self,
+        dst: Dst,
+        inp: &Self::Storage<Src, usize>,
+    ) -> Result<Self::Storage<Dst, E>, Self::Err>;
+}
+
+/// Converts a tensor of class indices into a one-hot encoded float tensor, with a new
+/// trailing dimension of size `N`. The result does not track gradients.
+///
+/// **Pytorch equivalent**: `torch.nn.functional.one_hot(t, N).float()`
+///
+/// On [crate::tensor::Cpu], panics if any index is `>= N`. On cuda, out of range indices
+/// produce all zeros.
+///
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t: Tensor<Rank1<3>, usize, _> = dev.tensor([2, 0, 1]);
+/// let r: Tensor<Rank2<3, 4>, f32, _> = t.one_hot::<4>();
+/// assert_eq!(
+///     r.array(),
+///     [[0.0, 0.0, 1.0, 0.0], [1.0, 0.0, 0.0, 0.0], [0.0, 1.0, 0.0, 0.0]]
+/// );
+/// ```
+pub trait TryOneHot<E: Dtype>: HasErr {
+    type Output<const N: usize>;
+    fn one_hot<const N: usize>(self) -> Self::Output<N> {
+        self.try_one_hot::<N>().unwrap()
+    }
+    fn try_one_hot<const N: usize>(self) -> Result<Self::Output<N>, Self::Err>;
+}
+
+macro_rules! one_hot_impl {
+    ([$($Vars:tt),*], [$($Idx:tt),*]) => {
+impl<$($Vars: Dim, )* E: Dtype, D: OneHotKernel<E>, T> TryOneHot<E>
+    for Tensor<($($Vars, )*), usize, D, T>
+{
+    type Output<const N: usize> = Tensor<($($Vars, )* Const<N>,), E, D>;
+    fn try_one_hot<const N: usize>(self) -> Result<Self::Output<N>, Self::Err> {
+        let dst = ($(self.shape().$Idx, )* Const::<N>,);
+        let storage = self.device.forward(dst, &self.storage)?;
+        Ok(self.device.upgrade(storage))
+    }
+}
+    };
+}
+
+one_hot_impl!([], []);
+one_hot_impl!([A], [0]);
+one_hot_impl!([A, B], [0, 1]);
+one_hot_impl!([A, B, C], [0, 1, 2]);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_one_hot_0d() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank0, usize, _> = dev.tensor(1);
+        let r: Tensor<Rank1<3>, TestDtype, _> = t.one_hot::<3>();
+        assert_eq!(r.array(), [0.0, 1.0, 0.0]);
+    }
+
+    #[test]
+    fn test_one_hot_2d() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank2<2, 3>, usize, _> = dev.tensor([[0, 4, 2], [3, 3, 1]]);
+        let r: Tensor<Rank3<2, 3, 5>, TestDtype, _> = t.clone().one_hot::<5>();
+        let r_arr = r.array();
+        let t_arr = t.array();
+        for i in 0..2 {
+            for j in 0..3 {
+                for (k, &r) in r_arr[i][j].iter().enumerate() {
+                    let expected = if t_arr[i][j] == k { 1.0 } else { 0.0 };
+                    assert_eq!(r, expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_one_hot_broadcasted() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank1<2>, usize, _> = dev.tensor([1, 0]);
+        let r: Tensor<Rank3<3, 2, 2>, TestDtype, _> =
+            t.broadcast::<Rank2<3, 2>, _>().one_hot::<2>();
+        assert_eq!(r.array(), [[[0.0, 1.0], [1.0, 0.0]]; 3]);
+    }
+
+    #[test]
+    fn test_one_hot_cross_entropy() {
+        let dev: TestDevice = Default::default();
+        let logits: Tensor<Rank2<2, 3>, TestDtype, _> = dev.sample_normal();
+        let labels: Tensor<Rank1<2>, usize, _> = dev.tensor([2, 0]);
+        let targets = labels.clone().one_hot::<3>();
+        let loss = crate::losses::cross_entropy_with_logits_loss(logits.clone(), targets);
+        let logp = logits.log_softmax::<Axis<1>>().array();
+        assert_close(&loss.array(), &(-(logp[0][2] + logp[1][0]) / 2.0));
+    }
+}
@@ -0,0 +1,39 @@
+#include "cuda_utils.cuh"
+
+template<typename T>
+__device__ void one_hot_fwd(
+    const size_t numel,
+    const size_t num_dims,
+    const size_t *dims,
+    const size_t *inp_strides,
+    const size_t n,
+    const size_t *inp,
+    T *out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    // out is contiguous with n elements for every element of inp
+    const size_t x = inp[get_strided_index(i, num_dims, dims, inp_strides)];
+    if (x < n) {
+        out[i * n + x] = 1.0;
+    }
+}
+
+#define ONE_HOT(TYPENAME, FWD) \
+extern "C" __global__ void FWD( \
+    const size_t numel, \
+    const size_t num_dims, \
+    const size_t *dims, \
+    const size_t *inp_strides, \
+    const size_t n, \
+    const size_t *inp, \
+    TYPENAME *out \
+) { \
+    one_hot_fwd(numel, num_dims, dims, inp_strides, n, inp, out); \
+}
+
+ONE_HOT(float, one_hot_fwd_f32);
+ONE_HOT(double, one_hot_fwd_f64);
@@ -0,0 +1,124 @@
+use crate::{
+    gradients::{Merge, Tape},
+    shapes::*,
+    tensor::{HasErr, Tensor},
+};
+
+use super::{BroadcastTo, Device, TryMul};
+
+/// Outer product of the vectors in the last axis of `lhs` and `rhs`, broadcasted over
+/// any leading batch axes, which must match.
+///
+/// **Pytorch equivalent** `torch.einsum("...m,...n->...mn", lhs, rhs)`, or
+/// `torch.outer(lhs, rhs)` for single vectors.
+///
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let a = dev.tensor([[1.0, 2.0], [3.0, 4.0]]);
+/// let b = dev.tensor([[1.0, 0.0, -1.0], [2.0, 1.0, 0.0]]);
+/// let r: Tensor<Rank3<2, 2, 3>, f32, _> = outer(a, b);
+/// assert_eq!(
+///     r.array(),
+///     [[[1.0, 0.0, -1.0], [2.0, 0.0, -2.0]], [[6.0, 3.0, 0.0], [8.0, 4.0, 0.0]]]
+/// );
+/// ```
+pub fn outer<Lhs: TryOuter<Rhs>, Rhs>(lhs: Lhs, rhs: Rhs) -> Lhs::Output {
+    lhs.outer(rhs)
+}
+
+/// Batched outer products. See [outer].
+pub trait TryOuter<Rhs>: HasErr {
+    type Output;
+
+    /// See [outer]
+    fn outer(self, rhs: Rhs) -> Self::Output {
+        self.try_outer(rhs).unwrap()
+    }
+
+    /// Fallible version of [TryOuter::outer]
+    fn try_outer(self, rhs: Rhs) -> Result<Self::Output, Self::Err>;
+}
+
+macro_rules! outer {
+    (($($B:tt),*), $L:literal, $R:literal) => {
+        impl<$($B: Dim, )* M: Dim, N: Dim, E: Dtype, D: Device<E>, LTape, RTape>
+            TryOuter<Tensor<($($B, )* N,), E, D, RTape>> for Tensor<($($B, )* M,), E, D, LTape>
+        where
+            LTape: Tape<D> + Merge<RTape>,
+            RTape: Tape<D>,
+        {
+            type Output = Tensor<($($B, )* M, N), E, D, LTape>;
+
+            #[allow(non_snake_case)]
+            fn try_outer(
+                self,
+                rhs: Tensor<($($B, )* N,), E, D, RTape>,
+            ) -> Result<Self::Output, Self::Err> {
+                let ($($B, )* m,) = *self.shape();
+                let n = rhs.shape().concrete()[$L];
+                let n: N = Dim::from_size(n).unwrap();
+                let dst = ($($B, )* m, n);
+                let lhs = self.try_broadcast_like::<_, Axis<$R>>(&dst)?;
+                let rhs = rhs.try_broadcast_like::<_, Axis<$L>>(&dst)?;
+                lhs.try_mul(rhs)
+            }
+        }
+    };
+}
+
+outer!((), 0, 1);
+outer!((B0), 1, 2);
+outer!((B0, B1), 2, 3);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_outer_1d() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<_, TestDtype, _> = dev.tensor([1.0, 2.0]);
+        let b: Tensor<_, TestDtype, _> = dev.tensor([3.0, 4.0, 5.0]);
+        let r = a.trace().outer(b.trace());
+        assert_eq!(r.array(), [[3.0, 4.0, 5.0], [6.0, 8.0, 10.0]]);
+        let r2 = a.trace().matmul(b.trace());
+        assert_eq!(r.array(), r2.array());
+        let g = r.exp().mean().backward();
+        let g2 = r2.exp().mean().backward();
+        assert_close(&g.get(&a).array(), &g2.get(&a).array());
+        assert_close(&g.get(&b).array(), &g2.get(&b).array());
+    }
+
+    #[test]
+    fn test_outer_batched() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<Rank3<2, 3, 4>, TestDtype, _> = dev.sample_normal();
+        let b: Tensor<Rank3<2, 3, 5>, TestDtype, _> = dev.sample_normal();
+        let r = outer(a.trace(), b.trace());
+        let a_array = a.array();
+        let b_array = b.array();
+        let r_array = r.array();
+        for i in 0..2 {
+            for j in 0..3 {
+                for m in 0..4 {
+                    for n in 0..5 {
+                        assert_eq!(r_array[i][j][m][n], a_array[i][j][m] * b_array[i][j][n]);
+                    }
+                }
+            }
+        }
+        let g = r.sum().backward();
+        let a_expected = b
+            .clone()
+            .sum::<Rank2<2, 3>, Axis<2>>()
+            .broadcast::<Rank3<2, 3, 4>, Axis<2>>();
+        let b_expected = a
+            .clone()
+            .sum::<Rank2<2, 3>, Axis<2>>()
+            .broadcast::<Rank3<2, 3, 5>, Axis<2>>();
+        assert_close(&g.get(&a).array(), &a_expected.array());
+        assert_close(&g.get(&b).array(), &b_expected.array());
+    }
+}
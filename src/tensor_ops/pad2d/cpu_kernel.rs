@@ -0,0 +1,90 @@
+use crate::shapes::*;
+use crate::tensor::cpu::Cpu;
+
+use std::sync::Arc;
+
+use super::{Pad2DKernel, Pad2DOp, PadMode};
+
+fn make_4d<S: Shape>(strides: S::Concrete) -> [usize; 4] {
+    match S::NUM_DIMS {
+        3 => [0, strides[0], strides[1], strides[2]],
+        4 => [strides[0], strides[1], strides[2], strides[3]],
+        _ => panic!("Only implemented for 3d & 4d arrays"),
+    }
+}
+
+/// The input index that output index `o` reads from, or `None` if it is filled
+/// with the constant.
+fn src_idx<E>(o: usize, pad: usize, size: usize, mode: &PadMode<E>) -> Option<usize> {
+    let i = o as isize - pad as isize;
+    let last = size as isize - 1;
+    match mode {
+        PadMode::Constant(_) => (0..size as isize).contains(&i).then_some(i as usize),
+        PadMode::Reflect => Some((last - (last - i.abs()).abs()) as usize),
+        PadMode::Replicate => Some(i.clamp(0, last) as usize),
+    }
+}
+
+impl<E: Unit + std::ops::AddAssign> Pad2DKernel<E> for Cpu {
+    fn forward<I: Shape, O: Shape>(
+        &self,
+        op: Pad2DOp,
+        mode: PadMode<E>,
+        inp: &Self::Storage<I, E>,
+        out: &mut Self::Storage<O, E>,
+    ) -> Result<(), Self::Err> {
+        let istr = make_4d::<I>(inp.strides);
+        let ostr = make_4d::<O>(out.strides);
+
+        let buf = inp.data.as_ref();
+        let out_buf = Arc::make_mut(&mut out.data);
+        for b in 0..op.batch {
+            for c in 0..op.chan {
+                for oh in 0..op.h_out {
+                    let y = src_idx(oh, op.top, op.h_in, &mode);
+                    for ow in 0..op.w_out {
+                        let x = src_idx(ow, op.left, op.w_in, &mode);
+                        out_buf[b * ostr[0] + c * ostr[1] + oh * ostr[2] + ow * ostr[3]] =
+                            match (y, x, mode) {
+                                (Some(y), Some(x), _) => {
+                                    buf[b * istr[0] + c * istr[1] + y * istr[2] + x * istr[3]]
+                                }
+                                (_, _, PadMode::Constant(value)) => value,
+                                _ => unreachable!(),
+                            };
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn backward<I: Shape, O: Shape>(
+        &self,
+        op: Pad2DOp,
+        mode: PadMode<E>,
+        grad_inp: &mut Self::Storage<I, E>,
+        grad_out: &Self::Storage<O, E>,
+    ) -> Result<(), Self::Err> {
+        let istr = make_4d::<I>(grad_inp.strides);
+        let ostr = make_4d::<O>(grad_out.strides);
+
+        let ginp_buf = Arc::make_mut(&mut grad_inp.data);
+        let buf = grad_out.data.as_ref();
+        for b in 0..op.batch {
+            for c in 0..op.chan {
+                for oh in 0..op.h_out {
+                    let y = src_idx(oh, op.top, op.h_in, &mode);
+                    for ow in 0..op.w_out {
+                        let x = src_idx(ow, op.left, op.w_in, &mode);
+                        if let (Some(y), Some(x)) = (y, x) {
+                            ginp_buf[b * istr[0] + c * istr[1] + y * istr[2] + x * istr[3]] +=
+                                buf[b * ostr[0] + c * ostr[1] + oh * ostr[2] + ow * ostr[3]];
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,90 @@
+use crate::{shapes::*, tensor::cuda::Cuda};
+
+use std::sync::Arc;
+
+use cudarc::driver::{AsKernelParam, LaunchAsync, LaunchConfig};
+
+use super::{Pad2DKernel, Pad2DOp, PadMode};
+
+const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/pad2d.ptx"));
+
+unsafe impl AsKernelParam for Pad2DOp {}
+
+fn make_4d<S: Shape>(strides: S::Concrete) -> [usize; 4] {
+    match S::NUM_DIMS {
+        3 => [0, strides[0], strides[1], strides[2]],
+        4 => [strides[0], strides[1], strides[2], strides[3]],
+        _ => panic!("Only implemented for 3d & 4d arrays"),
+    }
+}
+
+/// The `mode` & `value` arguments of the cuda kernels.
+fn mode_params<E: Default>(mode: PadMode<E>) -> (usize, E) {
+    match mode {
+        PadMode::Constant(value) => (0, value),
+        PadMode::Reflect => (1, E::default()),
+        PadMode::Replicate => (2, E::default()),
+    }
+}
+
+macro_rules! pad_impl {
+    ($TypeName:ty, $Fwd:tt, $Bwd:tt) => {
+        impl Pad2DKernel<$TypeName> for Cuda {
+            fn forward<I: Shape, O: Shape>(
+                &self,
+                op: Pad2DOp,
+                mode: PadMode<$TypeName>,
+                inp: &Self::Storage<I, $TypeName>,
+                out: &mut Self::Storage<O, $TypeName>,
+            ) -> Result<(), Self::Err> {
+                if !self.dev.has_func($Fwd, $Fwd) {
+                    self.dev.load_ptx(PTX_SRC.into(), $Fwd, &[$Fwd, $Bwd])?;
+                }
+
+                let (mode, value) = mode_params(mode);
+                let inp_strides = self.dev.take_async(make_4d::<I>(inp.strides).into())?;
+                let out_strides = self.dev.take_async(make_4d::<O>(out.strides).into())?;
+                let fwd_fn = self.dev.get_func($Fwd, $Fwd).unwrap();
+                let cfg = LaunchConfig::for_num_elems(out.shape().num_elements() as u32);
+                let params = (
+                    op,                           // const Pad2dOp op,
+                    mode,                         // const size_t mode,
+                    value,                        // const float value,
+                    &inp_strides,                 // const size_t *inp_strides,
+                    &out_strides,                 // const size_t *out_strides,
+                    inp.data.as_ref(),            // const float *inp,
+                    Arc::make_mut(&mut out.data), // float *out
+                );
+                unsafe { fwd_fn.launch_async(cfg, params) }?;
+                Ok(())
+            }
+
+            fn backward<I: Shape, O: Shape>(
+                &self,
+                op: Pad2DOp,
+                mode: PadMode<$TypeName>,
+                grad_inp: &mut Self::Storage<I, $TypeName>,
+                grad_out: &Self::Storage<O, $TypeName>,
+            ) -> Result<(), Self::Err> {
+                let (mode, _) = mode_params(mode);
+                let inp_strides = self.dev.take_async(make_4d::<I>(grad_inp.strides).into())?;
+                let out_strides = self.dev.take_async(make_4d::<O>(grad_out.strides).into())?;
+                let bwd_fn = self.dev.get_func($Fwd, $Bwd).unwrap();
+                let cfg = LaunchConfig::for_num_elems(grad_out.shape().num_elements() as u32);
+                let params = (
+                    op,                                // const Pad2dOp op,
+                    mode,                              // const size_t mode,
+                    &inp_strides,                      // const size_t *inp_strides,
+                    &out_strides,                      // const size_t *out_strides,
+                    Arc::make_mut(&mut grad_inp.data), // float *grad_inp,
+                    grad_out.data.as_ref(),            // const float *grad_out
+                );
+                unsafe { bwd_fn.launch_async(cfg, params) }?;
+                Ok(())
+            }
+        }
+    };
+}
+
+pad_impl!(f32, "pad2d_fwd_f32", "pad2d_bwd_f32");
+pad_impl!(f64, "pad2d_fwd_f64", "pad2d_bwd_f64");
@@ -0,0 +1,302 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{
+    gradients::Tape,
+    shapes::*,
+    tensor::{DeviceStorage, HasErr, PutTape, SplitTape, Tensor, ZerosTensor},
+};
+
+/// How [TryPad2D] fills in the pixels outside of the input image.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PadMode<E> {
+    /// Fill with a constant value.
+    Constant(E),
+    /// Mirror the image at its border, without repeating the border pixel.
+    /// E.g. padding `[1, 2, 3]` by 2 on each side gives `[3, 2, 1, 2, 3, 2, 1]`.
+    Reflect,
+    /// Repeat the border pixel. E.g. padding `[1, 2, 3]` by 2 on each side gives
+    /// `[1, 1, 1, 2, 3, 3, 3]`.
+    Replicate,
+}
+
+impl<E: Unit> Default for PadMode<E> {
+    fn default() -> Self {
+        Self::Constant(E::default())
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct Pad2DOp {
+    pub batch: usize,
+    pub chan: usize,
+    pub h_in: usize,
+    pub h_out: usize,
+    pub w_in: usize,
+    pub w_out: usize,
+    pub top: usize,
+    pub left: usize,
+}
+
+impl Pad2DOp {
+    fn new<E>(
+        [b, c, h_in, w_in]: [usize; 4],
+        [h_out, w_out]: [usize; 2],
+        [top, left]: [usize; 2],
+        mode: &PadMode<E>,
+    ) -> Self {
+        assert!(top + h_in <= h_out && left + w_in <= w_out);
+        let (bottom, right) = (h_out - h_in - top, w_out - w_in - left);
+        match mode {
+            PadMode::Constant(_) => {}
+            PadMode::Reflect => assert!(
+                top.max(bottom) < h_in && left.max(right) < w_in,
+                "Reflect padding must be smaller than the image"
+            ),
+            PadMode::Replicate => {
+                assert!(h_in > 0 && w_in > 0, "Can't replicate pad an empty image")
+            }
+        }
+        Self {
+            batch: b,
+            chan: c,
+            h_in,
+            h_out,
+            w_in,
+            w_out,
+            top,
+            left,
+        }
+    }
+}
+
+/// The size of a dimension after padding both of its sides by `P`.
+#[cfg(feature = "nightly")]
+pub trait PadAlgebra<const P: usize>: ConstDim {
+    type Padded: ConstDim;
+}
+
+#[cfg(feature = "nightly")]
+impl<const D: usize, const P: usize> PadAlgebra<P> for Const<D>
+where
+    Const<{ D + 2 * P }>: Sized,
+{
+    type Padded = Const<{ D + 2 * P }>;
+}
+
+pub trait Pad2DKernel<E: Unit>: DeviceStorage {
+    fn forward<I: Shape, O: Shape>(
+        &self,
+        op: Pad2DOp,
+        mode: PadMode<E>,
+        inp: &Self::Storage<I, E>,
+        out: &mut Self::Storage<O, E>,
+    ) -> Result<(), Self::Err>;
+
+    fn backward<I: Shape, O: Shape>(
+        &self,
+        op: Pad2DOp,
+        mode: PadMode<E>,
+        grad_inp: &mut Self::Storage<I, E>,
+        grad_out: &Self::Storage<O, E>,
+    ) -> Result<(), Self::Err>;
+}
+
+/// Pads the height & width of images (3d) and batches of images (4d) to any size. `top` &
+/// `left` are how many pixels to add before the image, and the rest of `height` & `width`
+/// is added after.
+pub trait GenericPad2D: HasErr + HasDtype {
+    type Output<OH: Dim, OW: Dim>;
+    fn generic_pad2d_like<OH: Dim, OW: Dim>(
+        self,
+        top: usize,
+        left: usize,
+        height: OH,
+        width: OW,
+        mode: PadMode<Self::Dtype>,
+    ) -> Result<Self::Output<OH, OW>, Self::Err>;
+}
+
+/// Pads images (3d) and batches of images (4d) by `[top, bottom, left, right]` pixels.
+///
+/// **Pytorch equivalent**: `torch.nn.functional.pad(t, (left, right, top, bottom), mode)`
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([[[1.0, 2.0, 3.0]]]);
+/// let r = t.clone().pad2d([0, 0, 2, 1], PadMode::Constant(0.0));
+/// assert_eq!(r.as_vec(), [0.0, 0.0, 1.0, 2.0, 3.0, 0.0]);
+/// let r = t.clone().pad2d([0, 0, 2, 1], PadMode::Reflect);
+/// assert_eq!(r.as_vec(), [3.0, 2.0, 1.0, 2.0, 3.0, 2.0]);
+/// let r = t.pad2d([1, 0, 0, 1], PadMode::Replicate);
+/// assert_eq!(r.shape(), &(Const::<1>, 2, 4));
+/// assert_eq!(r.as_vec(), [1.0, 2.0, 3.0, 3.0, 1.0, 2.0, 3.0, 3.0]);
+/// ```
+pub trait TryPad2D {
+    fn pad2d(
+        self,
+        padding: [usize; 4],
+        mode: PadMode<Self::Dtype>,
+    ) -> <Self as GenericPad2D>::Output<usize, usize>
+    where
+        Self: GenericPad2D + HasShape,
+    {
+        self.try_pad2d(padding, mode).unwrap()
+    }
+    fn try_pad2d(
+        self,
+        [top, bottom, left, right]: [usize; 4],
+        mode: PadMode<Self::Dtype>,
+    ) -> Result<<Self as GenericPad2D>::Output<usize, usize>, Self::Err>
+    where
+        Self: GenericPad2D + HasShape,
+    {
+        let dims = self.shape().concrete();
+        let n = <Self as HasShape>::Shape::NUM_DIMS;
+        let (h, w) = (dims[n - 2], dims[n - 1]);
+        self.generic_pad2d_like(top, left, top + h + bottom, left + w + right, mode)
+    }
+}
+impl<T> TryPad2D for T {}
+
+impl<C: Dim, H: Dim, W: Dim, E: Dtype, D, T> GenericPad2D for Tensor<(C, H, W), E, D, T>
+where
+    D: Pad2DKernel<E> + ZerosTensor<E>,
+    T: 'static + Tape<D>,
+{
+    type Output<OH: Dim, OW: Dim> = Tensor<(C, OH, OW), E, D, T>;
+
+    fn generic_pad2d_like<OH: Dim, OW: Dim>(
+        self,
+        top: usize,
+        left: usize,
+        height: OH,
+        width: OW,
+        mode: PadMode<E>,
+    ) -> Result<Self::Output<OH, OW>, Self::Err> {
+        let &(chan, h, w) = self.shape();
+        let op = Pad2DOp::new(
+            [1, chan.size(), h.size(), w.size()],
+            [height.size(), width.size()],
+            [top, left],
+            &mode,
+        );
+        try_pad2d_op(op, mode, self, (chan, height, width))
+    }
+}
+
+impl<B: Dim, C: Dim, H: Dim, W: Dim, E: Dtype, D, T> GenericPad2D for Tensor<(B, C, H, W), E, D, T>
+where
+    D: Pad2DKernel<E> + ZerosTensor<E>,
+    T: 'static + Tape<D>,
+{
+    type Output<OH: Dim, OW: Dim> = Tensor<(B, C, OH, OW), E, D, T>;
+
+    fn generic_pad2d_like<OH: Dim, OW: Dim>(
+        self,
+        top: usize,
+        left: usize,
+        height: OH,
+        width: OW,
+        mode: PadMode<E>,
+    ) -> Result<Self::Output<OH, OW>, Self::Err> {
+        let &(batch, chan, h, w) = self.shape();
+        let op = Pad2DOp::new(
+            [batch.size(), chan.size(), h.size(), w.size()],
+            [height.size(), width.size()],
+            [top, left],
+            &mode,
+        );
+        try_pad2d_op(op, mode, self, (batch, chan, height, width))
+    }
+}
+
+fn try_pad2d_op<I: Shape, O: Shape, E: Dtype, D, T>(
+    op: Pad2DOp,
+    mode: PadMode<E>,
+    inp: Tensor<I, E, D, T>,
+    out_shape: O,
+) -> Result<Tensor<O, E, D, T>, D::Err>
+where
+    D: Pad2DKernel<E> + ZerosTensor<E>,
+    T: 'static + Tape<D>,
+{
+    let (inp, mut tape) = inp.split_tape();
+    let mut out = inp.device.try_zeros_like(&out_shape)?;
+    inp.device
+        .forward(op, mode, &inp.storage, &mut out.storage)?;
+    let phantom_out = out.clone();
+    tape.try_alloc_grad(&inp)?;
+    tape.try_alloc_grad(&out)?;
+    tape.add_backward_op(move |grads| {
+        let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+        inp.device.backward(op, mode, grad_inp, grad_out)
+    });
+    Ok(out.put_tape(tape))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_pad2d_constant() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([[[1.0, 2.0], [3.0, 4.0]]]);
+        let r = x.trace().pad2d([1, 0, 0, 2], PadMode::Constant(-1.0));
+        assert_eq!(r.shape(), &(Const::<1>, 3, 4));
+        assert_eq!(
+            r.as_vec(),
+            [-1.0, -1.0, -1.0, -1.0, 1.0, 2.0, -1.0, -1.0, 3.0, 4.0, -1.0, -1.0]
+        );
+        let g = r.exp().sum().backward();
+        assert_close(&g.get(&x).array(), &x.exp().array());
+    }
+
+    #[test]
+    fn test_pad2d_reflect() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]]);
+        let r = x.trace().pad2d([1, 1, 2, 1], PadMode::Reflect);
+        assert_eq!(r.shape(), &(Const::<1>, 4, 6));
+        assert_eq!(
+            r.as_vec(),
+            [
+                6.0, 5.0, 4.0, 5.0, 6.0, 5.0, //
+                3.0, 2.0, 1.0, 2.0, 3.0, 2.0, //
+                6.0, 5.0, 4.0, 5.0, 6.0, 5.0, //
+                3.0, 2.0, 1.0, 2.0, 3.0, 2.0,
+            ]
+        );
+        let g = r.sum().backward();
+        assert_eq!(g.get(&x).array(), [[[2.0, 6.0, 4.0], [2.0, 6.0, 4.0]]]);
+    }
+
+    #[test]
+    fn test_pad2d_replicate() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([[[[1.0, 2.0], [3.0, 4.0]]]]);
+        let r = x.trace().pad2d([0, 2, 1, 0], PadMode::Replicate);
+        assert_eq!(r.shape(), &(Const::<1>, Const::<1>, 4, 3));
+        assert_eq!(
+            r.as_vec(),
+            [1.0, 1.0, 2.0, 3.0, 3.0, 4.0, 3.0, 3.0, 4.0, 3.0, 3.0, 4.0]
+        );
+        let g = r.sum().backward();
+        assert_eq!(g.get(&x).array(), [[[[2.0, 1.0], [6.0, 3.0]]]]);
+    }
+
+    #[test]
+    #[should_panic = "Reflect padding must be smaller than the image"]
+    fn test_pad2d_reflect_too_large() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank3<1, 2, 2>, TestDtype, _> = dev.zeros();
+        let _ = x.pad2d([2, 0, 0, 0], PadMode::Reflect);
+    }
+}
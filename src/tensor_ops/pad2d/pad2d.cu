@@ -0,0 +1,128 @@
+#include "cuda_utils.cuh"
+
+struct Pad2dOp {
+    size_t batch;
+    size_t chan;
+    size_t h_in;
+    size_t h_out;
+    size_t w_in;
+    size_t w_out;
+    size_t top;
+    size_t left;
+};
+
+#define PAD_CONSTANT 0
+#define PAD_REFLECT 1
+#define PAD_REPLICATE 2
+
+// The input index that output index `o` reads from, or -1 if it is filled with the constant.
+__device__ long src_idx(size_t o, size_t pad, size_t size, size_t mode) {
+    const long i = static_cast<long>(o) - static_cast<long>(pad);
+    const long last = static_cast<long>(size) - 1;
+    if (mode == PAD_REFLECT) {
+        return last - labs(last - labs(i));
+    } else if (mode == PAD_REPLICATE) {
+        return i < 0 ? 0 : (i > last ? last : i);
+    } else {
+        return (i < 0 || i > last) ? -1 : i;
+    }
+}
+
+template<typename T>
+__device__ void pad2d_fwd(
+    const Pad2dOp op,
+    const size_t mode,
+    const T value,
+    const size_t *inp_strides,
+    const size_t *out_strides,
+    const T *inp, // 4d (Batch, Channels, Height, Width)
+    T *out // 4d (Batch, Channels, HeightOut, WidthOut)
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    const size_t numel = op.batch * op.chan * op.h_out * op.w_out;
+    if (i >= numel) {
+        return;
+    }
+
+    unsigned int idx = i;
+    const size_t ow = idx % op.w_out;
+    idx /= op.w_out;
+    const size_t oh = idx % op.h_out;
+    idx /= op.h_out;
+    const size_t c = idx % op.chan;
+    idx /= op.chan;
+    const size_t b = idx % op.batch;
+    idx /= op.batch;
+
+    const long y = src_idx(oh, op.top, op.h_in, mode);
+    const long x = src_idx(ow, op.left, op.w_in, mode);
+    if (y < 0 || x < 0) {
+        out[i] = value;
+        return;
+    }
+
+    auto inp_i = b * inp_strides[0] + c * inp_strides[1] + y * inp_strides[2] + x * inp_strides[3];
+    out[i] = inp[inp_i];
+}
+
+template<typename T>
+__device__ void pad2d_bwd(
+    const Pad2dOp op,
+    const size_t mode,
+    const size_t *inp_strides,
+    const size_t *out_strides,
+    T *grad_inp, // 4d (Batch, Channels, Height, Width)
+    const T *grad_out // 4d (Batch, Channels, HeightOut, WidthOut)
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    const size_t numel = op.batch * op.chan * op.h_out * op.w_out;
+    if (i >= numel) {
+        return;
+    }
+
+    unsigned int idx = i;
+    const size_t ow = idx % op.w_out;
+    idx /= op.w_out;
+    const size_t oh = idx % op.h_out;
+    idx /= op.h_out;
+    const size_t c = idx % op.chan;
+    idx /= op.chan;
+    const size_t b = idx % op.batch;
+    idx /= op.batch;
+
+    const long y = src_idx(oh, op.top, op.h_in, mode);
+    const long x = src_idx(ow, op.left, op.w_in, mode);
+    if (y < 0 || x < 0) {
+        return;
+    }
+
+    auto inp_i = b * inp_strides[0] + c * inp_strides[1] + y * inp_strides[2] + x * inp_strides[3];
+    auto out_i = b * out_strides[0] + c * out_strides[1] + oh * out_strides[2] + ow * out_strides[3];
+    atomicAdd(grad_inp + inp_i, grad_out[out_i]);
+}
+
+#define PAD_OP(TYPENAME, fwd, bwd) \
+extern "C" __global__ void fwd( \
+    const Pad2dOp op, \
+    const size_t mode, \
+    const TYPENAME value, \
+    const size_t *inp_strides, \
+    const size_t *out_strides, \
+    const TYPENAME *inp, \
+    TYPENAME *out \
+) { \
+    pad2d_fwd(op, mode, value, inp_strides, out_strides, inp, out); \
+} \
+extern "C" __global__ void bwd( \
+    const Pad2dOp op, \
+    const size_t mode, \
+    const size_t *inp_strides, \
+    const size_t *out_strides, \
+    TYPENAME *grad_inp, \
+    const TYPENAME *grad_out \
+) { \
+    pad2d_bwd(op, mode, inp_strides, out_strides, grad_inp, grad_out); \
+}
+
+PAD_OP(float, pad2d_fwd_f32, pad2d_bwd_f32);
+PAD_OP(double, pad2d_fwd_f64, pad2d_bwd_f64);
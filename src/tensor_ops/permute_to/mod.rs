@@ -116,6 +116,18 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_permute_8d() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank8<2, 3, 1, 4, 2, 1, 3, 2>, TestDtype, _> = dev.sample_normal();
+        let r: Tensor<Rank8<3, 2, 4, 2, 1, 2, 3, 1>, _, _> =
+            t.clone().permute::<_, Axes8<6, 0, 3, 7, 2, 4, 1, 5>>();
+        // r[0, 0, 0, 0, 0, 0, 1, 0] is t[0, 1, 0, 0, 0, 0, 0, 0]
+        assert_eq!(r.as_vec()[1], t.as_vec()[4 * 2 * 3 * 2]);
+        let r = r.permute::<_, Axes8<1, 6, 4, 2, 5, 7, 0, 3>>();
+        assert_eq!(r.as_vec(), t.as_vec());
+    }
+
     #[test]
     fn test_permute_2d_backwards() {
         let dev: TestDevice = Default::default();
@@ -0,0 +1,240 @@
+use super::{Device, PermuteTo, ReshapeTo};
+use crate::{gradients::Tape, shapes::*, tensor::*};
+
+/// **Requires Nightly** Rearranges images (3d) and batches of images (4d) from shape
+/// `(C * R * R, H, W)` to `(C, H * R, W * R)`. Each group of `R * R` channels becomes
+/// an `R x R` block of pixels. Also known as depth to space.
+pub trait ConstPixelShuffle<const R: usize>: HasErr {
+    type Output;
+    fn try_shuffle(self) -> Result<Self::Output, Self::Err>;
+}
+
+/// **Requires Nightly** Rearranges images (3d) and batches of images (4d) from shape
+/// `(C, H * R, W * R)` to `(C * R * R, H, W)`. The inverse of [ConstPixelShuffle].
+/// Also known as space to depth.
+pub trait ConstPixelUnshuffle<const R: usize>: HasErr {
+    type Output;
+    fn try_unshuffle(self) -> Result<Self::Output, Self::Err>;
+}
+
+/// **Requires Nightly** Moves data between the channel & spatial dimensions of images.
+///
+/// **Pytorch equivalent**: `torch.nn.functional.pixel_shuffle(t, R)` and
+/// `torch.nn.functional.pixel_unshuffle(t, R)`
+///
+/// Examples:
+/// ```ignore
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t: Tensor<Rank3<8, 3, 5>, f32, _> = dev.zeros();
+/// let r: Tensor<Rank3<2, 6, 10>, f32, _> = t.pixel_shuffle::<2>();
+/// let _: Tensor<Rank3<8, 3, 5>, f32, _> = r.pixel_unshuffle::<2>();
+/// ```
+pub trait TryPixelShuffle {
+    fn pixel_shuffle<const R: usize>(self) -> Self::Output
+    where
+        Self: ConstPixelShuffle<R>,
+    {
+        self.try_shuffle().unwrap()
+    }
+    fn try_pixel_shuffle<const R: usize>(self) -> Result<Self::Output, Self::Err>
+    where
+        Self: ConstPixelShuffle<R>,
+    {
+        self.try_shuffle()
+    }
+    fn pixel_unshuffle<const R: usize>(self) -> Self::Output
+    where
+        Self: ConstPixelUnshuffle<R>,
+    {
+        self.try_unshuffle().unwrap()
+    }
+    fn try_pixel_unshuffle<const R: usize>(self) -> Result<Self::Output, Self::Err>
+    where
+        Self: ConstPixelUnshuffle<R>,
+    {
+        self.try_unshuffle()
+    }
+}
+impl<T> TryPixelShuffle for T {}
+
+/// The `[batch, chan, height, width]` of an image or a batch of images.
+#[cfg_attr(not(feature = "nightly"), allow(dead_code))]
+fn image_dims<S: Shape>(shape: &S) -> [usize; 4] {
+    let dims = shape.concrete();
+    match S::NUM_DIMS {
+        3 => [1, dims[0], dims[1], dims[2]],
+        4 => [dims[0], dims[1], dims[2], dims[3]],
+        _ => panic!("Only implemented for 3d & 4d tensors"),
+    }
+}
+
+#[cfg_attr(not(feature = "nightly"), allow(dead_code))]
+fn try_pixel_shuffle_like<S: Shape, Dst: Shape, E: Dtype, D: Device<E>, T: Tape<D>>(
+    t: Tensor<S, E, D, T>,
+    r: usize,
+    dst: &Dst,
+) -> Result<Tensor<Dst, E, D, T>, D::Err> {
+    let [b, c, h, w] = image_dims(t.shape());
+    assert_eq!(c % (r * r), 0, "Channels must be divisible by R * R");
+    let c = c / (r * r);
+    t.try_reshape_like(&(b, c, r, r, h, w))?
+        .try_permute::<_, Axes6<0, 1, 4, 2, 5, 3>>()?
+        .try_reshape_like(dst)
+}
+
+#[cfg_attr(not(feature = "nightly"), allow(dead_code))]
+fn try_pixel_unshuffle_like<S: Shape, Dst: Shape, E: Dtype, D: Device<E>, T: Tape<D>>(
+    t: Tensor<S, E, D, T>,
+    r: usize,
+    dst: &Dst,
+) -> Result<Tensor<Dst, E, D, T>, D::Err> {
+    let [b, c, h, w] = image_dims(t.shape());
+    assert!(
+        h % r == 0 && w % r == 0,
+        "Height & width must be divisible by R"
+    );
+    let (h, w) = (h / r, w / r);
+    t.try_reshape_like(&(b, c, h, r, w, r))?
+        .try_permute::<_, Axes6<0, 1, 3, 5, 2, 4>>()?
+        .try_reshape_like(dst)
+}
+
+#[cfg(feature = "nightly")]
+impl<const C: usize, const H: usize, const W: usize, const R: usize, E, D, T> ConstPixelShuffle<R>
+    for Tensor<Rank3<C, H, W>, E, D, T>
+where
+    E: Dtype,
+    D: Device<E>,
+    T: Tape<D>,
+    Rank3<{ C / (R * R) }, { H * R }, { W * R }>: Sized,
+{
+    type Output = Tensor<Rank3<{ C / (R * R) }, { H * R }, { W * R }>, E, D, T>;
+    fn try_shuffle(self) -> Result<Self::Output, Self::Err> {
+        try_pixel_shuffle_like(self, R, &Default::default())
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<B: Dim, const C: usize, const H: usize, const W: usize, const R: usize, E, D, T>
+    ConstPixelShuffle<R> for Tensor<(B, Const<C>, Const<H>, Const<W>), E, D, T>
+where
+    E: Dtype,
+    D: Device<E>,
+    T: Tape<D>,
+    Rank3<{ C / (R * R) }, { H * R }, { W * R }>: Sized,
+{
+    type Output = Tensor<
+        (
+            B,
+            Const<{ C / (R * R) }>,
+            Const<{ H * R }>,
+            Const<{ W * R }>,
+        ),
+        E,
+        D,
+        T,
+    >;
+    fn try_shuffle(self) -> Result<Self::Output, Self::Err> {
+        let batch = self.shape().0;
+        try_pixel_shuffle_like(self, R, &(batch, Const, Const, Const))
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<const C: usize, const H: usize, const W: usize, const R: usize, E, D, T> ConstPixelUnshuffle<R>
+    for Tensor<Rank3<C, H, W>, E, D, T>
+where
+    E: Dtype,
+    D: Device<E>,
+    T: Tape<D>,
+    Rank3<{ C * R * R }, { H / R }, { W / R }>: Sized,
+{
+    type Output = Tensor<Rank3<{ C * R * R }, { H / R }, { W / R }>, E, D, T>;
+    fn try_unshuffle(self) -> Result<Self::Output, Self::Err> {
+        try_pixel_unshuffle_like(self, R, &Default::default())
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<B: Dim, const C: usize, const H: usize, const W: usize, const R: usize, E, D, T>
+    ConstPixelUnshuffle<R> for Tensor<(B, Const<C>, Const<H>, Const<W>), E, D, T>
+where
+    E: Dtype,
+    D: Device<E>,
+    T: Tape<D>,
+    Rank3<{ C * R * R }, { H / R }, { W / R }>: Sized,
+{
+    type Output = Tensor<(B, Const<{ C * R * R }>, Const<{ H / R }>, Const<{ W / R }>), E, D, T>;
+    fn try_unshuffle(self) -> Result<Self::Output, Self::Err> {
+        let batch = self.shape().0;
+        try_pixel_unshuffle_like(self, R, &(batch, Const, Const, Const))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_pixel_shuffle_3d() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank3<4, 1, 2>, TestDtype, _> =
+            dev.tensor([[[0.0, 1.0]], [[2.0, 3.0]], [[4.0, 5.0]], [[6.0, 7.0]]]);
+        let r =
+            try_pixel_shuffle_like(x.trace(), 2, &(Const::<1>, Const::<2>, Const::<4>)).unwrap();
+        assert_eq!(r.array(), [[[0.0, 2.0, 1.0, 3.0], [4.0, 6.0, 5.0, 7.0]]]);
+        let g = r.exp().sum().backward();
+        assert_close(&g.get(&x).array(), &x.exp().array());
+    }
+
+    #[test]
+    fn test_pixel_unshuffle_inverts_shuffle() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank4<2, 18, 2, 3>, TestDtype, _> = dev.sample_normal();
+        let r = try_pixel_shuffle_like(
+            x.clone(),
+            3,
+            &(Const::<2>, Const::<2>, Const::<6>, Const::<9>),
+        )
+        .unwrap();
+        let r = try_pixel_unshuffle_like(r, 3, &(Const::<2>, Const::<18>, Const::<2>, Const::<3>))
+            .unwrap();
+        assert_eq!(r.array(), x.array());
+    }
+
+    #[test]
+    fn test_pixel_unshuffle_4d() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank4<1, 1, 2, 4>, TestDtype, _> =
+            dev.tensor([[[[0.0, 1.0, 2.0, 3.0], [4.0, 5.0, 6.0, 7.0]]]]);
+        let r = try_pixel_unshuffle_like(
+            x.trace(),
+            2,
+            &(Const::<1>, Const::<4>, Const::<1>, Const::<2>),
+        )
+        .unwrap();
+        assert_eq!(
+            r.array(),
+            [[[[0.0, 2.0]], [[1.0, 3.0]], [[4.0, 6.0]], [[5.0, 7.0]]]]
+        );
+        let g = (r * dev.tensor([1.0, 2.0]).broadcast()).sum().backward();
+        assert_eq!(
+            g.get(&x).array(),
+            [[[[1.0, 1.0, 2.0, 2.0], [1.0, 1.0, 2.0, 2.0]]]]
+        );
+    }
+
+    #[cfg(feature = "nightly")]
+    #[test]
+    fn test_pixel_shuffle_sizes() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank3<8, 3, 5>, TestDtype, _> = dev.zeros();
+        let r: Tensor<Rank3<2, 6, 10>, _, _> = x.pixel_shuffle::<2>();
+        let _: Tensor<Rank3<8, 3, 5>, _, _> = r.pixel_unshuffle::<2>();
+        let x: Tensor<Rank4<2, 9, 2, 2>, TestDtype, _> = dev.zeros();
+        let r: Tensor<Rank4<2, 1, 6, 6>, _, _> = x.pixel_shuffle::<3>();
+        let _: Tensor<Rank4<2, 9, 2, 2>, _, _> = r.pixel_unshuffle::<3>();
+    }
+}
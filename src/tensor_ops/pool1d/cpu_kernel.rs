@@ -0,0 +1,218 @@
+use crate::shapes::*;
+use crate::tensor::cpu::Cpu;
+
+use std::sync::Arc;
+
+use num_traits::Float;
+
+fn make_3d<S: Shape>(strides: S::Concrete) -> [usize; 3] {
+    match S::NUM_DIMS {
+        2 => [0, strides[0], strides[1]],
+        3 => [strides[0], strides[1], strides[2]],
+        _ => panic!("Only implemented for 2d & 3d arrays"),
+    }
+}
+
+impl<F: Float + Unit + std::ops::AddAssign + std::ops::DivAssign> super::AvgPool1DKernel<F>
+    for Cpu
+{
+    fn forward<I: Shape, O: Shape>(
+        &self,
+        op: super::Pool1DOp,
+        inp: &Self::Storage<I, F>,
+        out: &mut Self::Storage<O, F>,
+    ) -> Result<(), Self::Err> {
+        let istr = make_3d::<I>(inp.strides);
+        let ostr = make_3d::<O>(out.strides);
+
+        let buf = inp.data.as_ref();
+        let out_buf = Arc::make_mut(&mut out.data);
+        for b in 0..op.batch {
+            for c in 0..op.chan {
+                for ol in 0..op.l_out {
+                    let mut tmp = F::zero();
+                    for k in 0..op.kernel {
+                        if let Some(l) = (ol * op.stride + k).checked_sub(op.padding) {
+                            if l < op.l_in {
+                                tmp += buf[b * istr[0] + c * istr[1] + l * istr[2]];
+                            }
+                        }
+                    }
+                    tmp /= F::from(op.kernel).unwrap();
+                    out_buf[b * ostr[0] + c * ostr[1] + ol * ostr[2]] = tmp;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn backward<I: Shape, O: Shape>(
+        &self,
+        op: super::Pool1DOp,
+        inp: &Self::Storage<I, F>,
+        grad_inp: &mut Self::Storage<I, F>,
+        out: &Self::Storage<O, F>,
+        grad_out: &Self::Storage<O, F>,
+    ) -> Result<(), Self::Err> {
+        let istr = make_3d::<I>(inp.strides);
+        let ostr = make_3d::<O>(out.strides);
+
+        let ginp_buf = Arc::make_mut(&mut grad_inp.data);
+        let buf = grad_out.data.as_ref();
+
+        for b in 0..op.batch {
+            for c in 0..op.chan {
+                for ol in 0..op.l_out {
+                    let g =
+                        buf[b * ostr[0] + c * ostr[1] + ol * ostr[2]] / F::from(op.kernel).unwrap();
+                    for k in 0..op.kernel {
+                        if let Some(l) = (ol * op.stride + k).checked_sub(op.padding) {
+                            if l < op.l_in {
+                                ginp_buf[b * istr[0] + c * istr[1] + l * istr[2]] += g;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<F: Float + Unit + std::ops::AddAssign> super::MaxPool1DKernel<F> for Cpu {
+    fn forward<I: Shape, O: Shape>(
+        &self,
+        op: super::Pool1DOp,
+        inp: &Self::Storage<I, F>,
+        out: &mut Self::Storage<O, F>,
+    ) -> Result<(), Self::Err> {
+        let istr = make_3d::<I>(inp.strides);
+        let ostr = make_3d::<O>(out.strides);
+
+        let buf = inp.data.as_ref();
+        let out_buf = Arc::make_mut(&mut out.data);
+        for b in 0..op.batch {
+            for c in 0..op.chan {
+                for ol in 0..op.l_out {
+                    let mut tmp = F::neg_infinity();
+                    for k in 0..op.kernel {
+                        if let Some(l) = (ol * op.stride + k).checked_sub(op.padding) {
+                            if l < op.l_in {
+                                tmp = tmp.max(buf[b * istr[0] + c * istr[1] + l * istr[2]]);
+                            }
+                        }
+                    }
+                    out_buf[b * ostr[0] + c * ostr[1] + ol * ostr[2]] = tmp;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn backward<I: Shape, O: Shape>(
+        &self,
+        op: super::Pool1DOp,
+        inp: &Self::Storage<I, F>,
+        grad_inp: &mut Self::Storage<I, F>,
+        out: &Self::Storage<O, F>,
+        grad_out: &Self::Storage<O, F>,
+    ) -> Result<(), Self::Err> {
+        let istr = make_3d::<I>(inp.strides);
+        let ostr = make_3d::<O>(out.strides);
+
+        let inp_buf = inp.data.as_ref();
+        let ginp_buf = Arc::make_mut(&mut grad_inp.data);
+        let out_buf = out.data.as_ref();
+        let gout_buf = grad_out.data.as_ref();
+
+        for b in 0..op.batch {
+            for c in 0..op.chan {
+                for ol in 0..op.l_out {
+                    let out_idx = b * ostr[0] + c * ostr[1] + ol * ostr[2];
+                    let go = gout_buf[out_idx];
+                    let vo = out_buf[out_idx];
+                    for k in 0..op.kernel {
+                        if let Some(l) = (ol * op.stride + k).checked_sub(op.padding) {
+                            if l < op.l_in {
+                                let inp_idx = b * istr[0] + c * istr[1] + l * istr[2];
+                                if inp_buf[inp_idx] == vo {
+                                    ginp_buf[inp_idx] += go;
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<F: Float + Unit + std::ops::AddAssign> super::MinPool1DKernel<F> for Cpu {
+    fn forward<I: Shape, O: Shape>(
+        &self,
+        op: super::Pool1DOp,
+        inp: &Self::Storage<I, F>,
+        out: &mut Self::Storage<O, F>,
+    ) -> Result<(), Self::Err> {
+        let istr = make_3d::<I>(inp.strides);
+        let ostr = make_3d::<O>(out.strides);
+
+        let buf = inp.data.as_ref();
+        let out_buf = Arc::make_mut(&mut out.data);
+        for b in 0..op.batch {
+            for c in 0..op.chan {
+                for ol in 0..op.l_out {
+                    let mut tmp = F::infinity();
+                    for k in 0..op.kernel {
+                        if let Some(l) = (ol * op.stride + k).checked_sub(op.padding) {
+                            if l < op.l_in {
+                                tmp = tmp.min(buf[b * istr[0] + c * istr[1] + l * istr[2]]);
+                            }
+                        }
+                    }
+                    out_buf[b * ostr[0] + c * ostr[1] + ol * ostr[2]] = tmp;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn backward<I: Shape, O: Shape>(
+        &self,
+        op: super::Pool1DOp,
+        inp: &Self::Storage<I, F>,
+        grad_inp: &mut Self::Storage<I, F>,
+        out: &Self::Storage<O, F>,
+        grad_out: &Self::Storage<O, F>,
+    ) -> Result<(), Self::Err> {
+        let istr = make_3d::<I>(inp.strides);
+        let ostr = make_3d::<O>(out.strides);
+
+        let inp_buf = inp.data.as_ref();
+        let ginp_buf = Arc::make_mut(&mut grad_inp.data);
+        let out_buf = out.data.as_ref();
+        let gout_buf = grad_out.data.as_ref();
+
+        for b in 0..op.batch {
+            for c in 0..op.chan {
+                for ol in 0..op.l_out {
+                    let out_idx = b * ostr[0] + c * ostr[1] + ol * ostr[2];
+                    let go = gout_buf[out_idx];
+                    let vo = out_buf[out_idx];
+                    for k in 0..op.kernel {
+                        if let Some(l) = (ol * op.stride + k).checked_sub(op.padding) {
+                            if l < op.l_in {
+                                let inp_idx = b * istr[0] + c * istr[1] + l * istr[2];
+                                if inp_buf[inp_idx] == vo {
+                                    ginp_buf[inp_idx] += go;
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
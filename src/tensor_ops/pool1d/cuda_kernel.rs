@@ -0,0 +1,104 @@
+use crate::{shapes::*, tensor::cuda::Cuda};
+
+use std::sync::Arc;
+
+use cudarc::driver::{AsKernelParam, LaunchAsync, LaunchConfig};
+
+const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/pool1d.ptx"));
+
+unsafe impl AsKernelParam for super::Pool1DOp {}
+
+fn make_3d<S: Shape>(strides: S::Concrete) -> [usize; 3] {
+    match S::NUM_DIMS {
+        2 => [0, strides[0], strides[1]],
+        3 => [strides[0], strides[1], strides[2]],
+        _ => panic!("Only implemented for 2d & 3d arrays"),
+    }
+}
+
+macro_rules! pool_impl {
+    ($Trait:tt<$TypeName:ty>, $Fwd:tt, $Bwd:tt) => {
+        impl super::$Trait<$TypeName> for Cuda {
+            fn forward<I: Shape, O: Shape>(
+                &self,
+                op: super::Pool1DOp,
+                inp: &Self::Storage<I, $TypeName>,
+                out: &mut Self::Storage<O, $TypeName>,
+            ) -> Result<(), Self::Err> {
+                if !self.dev.has_func($Fwd, $Fwd) {
+                    self.dev.load_ptx(PTX_SRC.into(), $Fwd, &[$Fwd, $Bwd])?;
+                }
+
+                let inp_strides = self.dev.take_async(make_3d::<I>(inp.strides).into())?;
+                let out_strides = self.dev.take_async(make_3d::<O>(out.strides).into())?;
+                let fwd_fn = self.dev.get_func($Fwd, $Fwd).unwrap();
+                let cfg = LaunchConfig::for_num_elems(out.shape().num_elements() as u32);
+                let params = (
+                    op,                           // const Pool1dOp op,
+                    &inp_strides,                 // const size_t *inp_strides,
+                    &out_strides,                 // const size_t *out_strides,
+                    inp.data.as_ref(),            // const float *inp,
+                    Arc::make_mut(&mut out.data), // float *out
+                );
+                unsafe { fwd_fn.launch_async(cfg, params) }?;
+                Ok(())
+            }
+            fn backward<I: Shape, O: Shape>(
+                &self,
+                op: super::Pool1DOp,
+                inp: &Self::Storage<I, $TypeName>,
+                grad_inp: &mut Self::Storage<I, $TypeName>,
+                out: &Self::Storage<O, $TypeName>,
+                grad_out: &Self::Storage<O, $TypeName>,
+            ) -> Result<(), Self::Err> {
+                let inp_strides = self.dev.take_async(make_3d::<I>(inp.strides).into())?;
+                let out_strides = self.dev.take_async(make_3d::<O>(out.strides).into())?;
+                let bwd_fn = self.dev.get_func($Fwd, $Bwd).unwrap();
+                let cfg = LaunchConfig::for_num_elems(grad_inp.shape().num_elements() as u32);
+                let params = (
+                    op,                                // const Pool1dOp op,
+                    &inp_strides,                      // const size_t *inp_strides,
+                    &out_strides,                      // const size_t *out_strides,
+                    inp.data.as_ref(),                 // const float *inp,
+                    Arc::make_mut(&mut grad_inp.data), // float *grad_inp,
+                    out.data.as_ref(),                 // const float *out,
+                    grad_out.data.as_ref(),            // const float *grad_out
+                );
+                unsafe { bwd_fn.launch_async(cfg, params) }?;
+                Ok(())
+            }
+        }
+    };
+}
+
+pool_impl!(
+    AvgPool1DKernel<f32>,
+    "avg_pool1d_fwd_f32",
+    "avg_pool1d_bwd_f32"
+);
+pool_impl!(
+    MaxPool1DKernel<f32>,
+    "max_pool1d_fwd_f32",
+    "max_pool1d_bwd_f32"
+);
+pool_impl!(
+    MinPool1DKernel<f32>,
+    "min_pool1d_fwd_f32",
+    "min_pool1d_bwd_f32"
+);
+
+pool_impl!(
+    AvgPool1DKernel<f64>,
+    "avg_pool1d_fwd_f64",
+    "avg_pool1d_bwd_f64"
+);
+pool_impl!(
+    MaxPool1DKernel<f64>,
+    "max_pool1d_fwd_f64",
+    "max_pool1d_bwd_f64"
+);
+pool_impl!(
+    MinPool1DKernel<f64>,
+    "min_pool1d_fwd_f64",
+    "min_pool1d_bwd_f64"
+);
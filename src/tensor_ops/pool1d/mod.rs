@@ -0,0 +1,233 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{
+    gradients::Tape,
+    shapes::*,
+    tensor::{DeviceStorage, HasErr, PutTape, SplitTape, Tensor, ZerosTensor},
+};
+
+use super::conv2d::ConvAlgebra;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct Pool1DOp {
+    pub kernel: usize,
+    pub stride: usize,
+    pub padding: usize,
+    pub batch: usize,
+    pub chan: usize,
+    pub l_in: usize,
+    pub l_out: usize,
+}
+
+impl Pool1DOp {
+    fn new(k: usize, s: usize, p: usize, [b, c, l_in]: [usize; 3]) -> Self {
+        Self {
+            kernel: k,
+            stride: s,
+            padding: p,
+            batch: b,
+            chan: c,
+            l_in,
+            l_out: (l_in + 2 * p - k) / s + 1,
+        }
+    }
+}
+
+macro_rules! pool1d {
+    (Kernel=$Kernel:ident, ConstTrait=$ConstTrait:ident, TryTrait=$TryTrait:ident, Meth=$Meth:ident, TryMeth=$TryMeth:ident) => {
+        pub trait $Kernel<E: Unit>: DeviceStorage {
+            fn forward<I: Shape, O: Shape>(
+                &self,
+                op: Pool1DOp,
+                inp: &Self::Storage<I, E>,
+                out: &mut Self::Storage<O, E>,
+            ) -> Result<(), Self::Err>;
+
+            fn backward<I: Shape, O: Shape>(
+                &self,
+                op: Pool1DOp,
+                inp: &Self::Storage<I, E>,
+                grad_inp: &mut Self::Storage<I, E>,
+                out: &Self::Storage<O, E>,
+                grad_out: &Self::Storage<O, E>,
+            ) -> Result<(), Self::Err>;
+        }
+
+        pub trait $ConstTrait<const K: usize, const S: usize, const P: usize>: HasErr {
+            type Output;
+            fn try_pool1d(self) -> Result<Self::Output, Self::Err>;
+        }
+
+        pub trait $TryTrait {
+            fn $Meth<const K: usize, const S: usize, const P: usize>(self) -> Self::Output
+            where
+                Self: $ConstTrait<K, S, P>,
+            {
+                self.try_pool1d().unwrap()
+            }
+            fn $TryMeth<const K: usize, const S: usize, const P: usize>(
+                self,
+            ) -> Result<Self::Output, Self::Err>
+            where
+                Self: $ConstTrait<K, S, P>,
+            {
+                self.try_pool1d()
+            }
+        }
+        impl<T> $TryTrait for T {}
+
+        impl<
+                C: Dim,
+                const L: usize,
+                E: Dtype,
+                D: $Kernel<E> + ZerosTensor<E>,
+                T: 'static + Tape<D>,
+                const K: usize,
+                const S: usize,
+                const P: usize,
+            > $ConstTrait<K, S, P> for Tensor<(C, Const<L>), E, D, T>
+        where
+            Const<L>: ConvAlgebra<K, S, P>,
+        {
+            type Output = Tensor<(C, <Const<L> as ConvAlgebra<K, S, P>>::Convolved), E, D, T>;
+
+            fn try_pool1d(self) -> Result<Self::Output, Self::Err> {
+                let &(chan, _) = self.shape();
+                let op = Pool1DOp::new(K, S, P, [1, chan.size(), L]);
+                let (inp, mut tape) = self.split_tape();
+                let mut out = inp.device.try_zeros_like(&(chan, Default::default()))?;
+                inp.device.forward(op, &inp.storage, &mut out.storage)?;
+                let phantom_out = out.clone();
+                tape.try_alloc_grad(&inp)?;
+                tape.try_alloc_grad(&out)?;
+                tape.add_backward_op(move |grads| {
+                    let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+                    inp.device
+                        .backward(op, &inp.storage, grad_inp, &phantom_out.storage, grad_out)
+                });
+                Ok(out.put_tape(tape))
+            }
+        }
+
+        impl<
+                B: Dim,
+                C: Dim,
+                const L: usize,
+                E: Dtype,
+                D: $Kernel<E> + ZerosTensor<E>,
+                T: 'static + Tape<D>,
+                const K: usize,
+                const S: usize,
+                const P: usize,
+            > $ConstTrait<K, S, P> for Tensor<(B, C, Const<L>), E, D, T>
+        where
+            Const<L>: ConvAlgebra<K, S, P>,
+        {
+            type Output = Tensor<(B, C, <Const<L> as ConvAlgebra<K, S, P>>::Convolved), E, D, T>;
+
+            fn try_pool1d(self) -> Result<Self::Output, Self::Err> {
+                let &(batch, chan, _) = self.shape();
+                let op = Pool1DOp::new(K, S, P, [batch.size(), chan.size(), L]);
+                let (inp, mut tape) = self.split_tape();
+                let mut out = inp
+                    .device
+                    .try_zeros_like(&(batch, chan, Default::default()))?;
+                inp.device.forward(op, &inp.storage, &mut out.storage)?;
+                let phantom_out = out.clone();
+                tape.try_alloc_grad(&inp)?;
+                tape.try_alloc_grad(&out)?;
+                tape.add_backward_op(move |grads| {
+                    let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+                    inp.device
+                        .backward(op, &inp.storage, grad_inp, &phantom_out.storage, grad_out)
+                });
+                Ok(out.put_tape(tape))
+            }
+        }
+    };
+}
+
+pool1d!(
+    Kernel = AvgPool1DKernel,
+    ConstTrait = ConstAvgPool1D,
+    TryTrait = TryAvgPool1D,
+    Meth = avg_pool1d,
+    TryMeth = try_avg_pool1d
+);
+
+pool1d!(
+    Kernel = MaxPool1DKernel,
+    ConstTrait = ConstMaxPool1D,
+    TryTrait = TryMaxPool1D,
+    Meth = max_pool1d,
+    TryMeth = try_max_pool1d
+);
+
+pool1d!(
+    Kernel = MinPool1DKernel,
+    ConstTrait = ConstMinPool1D,
+    TryTrait = TryMinPool1D,
+    Meth = min_pool1d,
+    TryMeth = try_min_pool1d
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_pool1d_2d_max1d_eq_grads() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([[1.0, 1., 0.5, 0.2], [0.2, 0.2, 0.5, 1.2]]);
+        let r = x.trace().max_pool1d::<2, 1, 0>();
+        assert_close(&r.array(), &[[1., 1., 0.5], [0.2, 0.5, 1.2]]);
+        let g = r.sum().backward();
+        assert_close(&g.get(&x).array(), &[[1., 2., 1., 0.], [1., 1., 1., 1.]]);
+    }
+
+    #[test]
+    fn test_pool1d_2d_min1d_eq_grads() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([[1., 1., 0.5, 0.2], [0.2, 0.2, 0.5, 1.2]]);
+        let r = x.trace().min_pool1d::<2, 1, 0>();
+        assert_close(&r.array(), &[[1., 0.5, 0.2], [0.2, 0.2, 0.5]]);
+        let g = r.sum().backward();
+        assert_close(&g.get(&x).array(), &[[1., 1., 1., 1.], [1., 2., 1., 0.]]);
+    }
+
+    #[test]
+    fn test_pool1d_2d_avg1d_padding() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([[1., 2., 3., 4., 5.], [-1., 0., 1., 2., 3.]]);
+        let r = x.trace().avg_pool1d::<3, 2, 1>();
+        assert_close(&r.array(), &[[1., 3., 3.], [-1. / 3., 1., 5. / 3.]]);
+        let g = r.sum().backward();
+        let t = 1. / 3.;
+        assert_close(
+            &g.get(&x).array(),
+            &[[t, 2. * t, t, 2. * t, t], [t, 2. * t, t, 2. * t, t]],
+        );
+    }
+
+    #[test]
+    fn test_pool1d_3d_max1d_stride() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> =
+            dev.tensor([[[0.1, -0.3, 0.7, 0.2, -0.5]], [[-1.0, 2.0, 0.0, -0.2, 0.4]]]);
+        let r = x.trace().max_pool1d::<2, 2, 0>();
+        assert_close(&r.array(), &[[[0.1, 0.7]], [[2.0, 0.0]]]);
+        let g = r.exp().sum().backward();
+        assert_close(
+            &g.get(&x).array(),
+            &[
+                [[1.1051709, 0., 2.0137527, 0., 0.]],
+                [[0., 7.389056, 1., 0., 0.]],
+            ],
+        );
+    }
+}
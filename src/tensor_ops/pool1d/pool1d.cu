@@ -0,0 +1,304 @@
+#include "cuda_utils.cuh"
+
+struct Pool1dOp {
+    size_t kernel;
+    size_t stride;
+    size_t padding;
+    size_t batch;
+    size_t chan;
+    size_t l_in;
+    size_t l_out;
+};
+
+template<typename T>
+__device__ void avg_pool1d_fwd(
+    const Pool1dOp op,
+    const size_t *inp_strides,
+    const size_t *out_strides,
+    const T *inp, // 3d (Batch, Channels, Length)
+    T *out // 3d (Batch, Channels, LengthOut)
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    const size_t numel = op.batch * op.chan * op.l_out;
+    if (i >= numel) {
+        return;
+    }
+
+    unsigned int idx = i;
+    const size_t ol = idx % op.l_out;
+    idx /= op.l_out;
+    const size_t c = idx % op.chan;
+    idx /= op.chan;
+    const size_t b = idx % op.batch;
+    idx /= op.batch;
+
+    T tmp = 0.0;
+    for(size_t k = 0; k < op.kernel; k++) {
+        const size_t l_plus_p = ol * op.stride + k;
+        if (l_plus_p < op.padding) { continue; }
+        const size_t l = l_plus_p - op.padding;
+        if (l >= op.l_in) { continue; }
+
+        auto inp_i = b * inp_strides[0] + c * inp_strides[1] + l * inp_strides[2];
+        tmp += inp[inp_i];
+    }
+
+    tmp /= static_cast<T>(op.kernel);
+    out[i] = tmp;
+}
+
+template<typename T>
+__device__ void avg_pool1d_bwd(
+    const Pool1dOp op,
+    const size_t *inp_strides,
+    const size_t *out_strides,
+    const T *inp, // 3d (Batch, Channels, Length)
+    T *grad_inp,
+    const T *out, // 3d (Batch, Channels, LengthOut)
+    const T *grad_out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    const size_t numel = op.batch * op.chan * op.l_in;
+    if (i >= numel) {
+        return;
+    }
+
+    unsigned int idx = i;
+    const size_t l = idx % op.l_in;
+    idx /= op.l_in;
+    const size_t c = idx % op.chan;
+    idx /= op.chan;
+    const size_t b = idx % op.batch;
+    idx /= op.batch;
+
+    T tmp = 0.0;
+    for(size_t k = 0; k < op.kernel; k++) {
+        size_t ol = l + op.padding;
+        if (ol < k) { continue; }
+        ol -= k;
+        if (ol % op.stride != 0) { continue; }
+        ol /= op.stride;
+        if (ol >= op.l_out) { continue; }
+
+        auto out_i = b * out_strides[0] + c * out_strides[1] + ol * out_strides[2];
+        tmp += grad_out[out_i];
+    }
+
+    grad_inp[i] += tmp / static_cast<T>(op.kernel);
+}
+
+template<typename T>
+__device__ void max_pool1d_fwd(
+    const Pool1dOp op,
+    const size_t *inp_strides,
+    const size_t *out_strides,
+    const T *inp, // 3d (Batch, Channels, Length)
+    T *out // 3d (Batch, Channels, LengthOut)
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    const size_t numel = op.batch * op.chan * op.l_out;
+    if (i >= numel) {
+        return;
+    }
+
+    unsigned int idx = i;
+    const size_t ol = idx % op.l_out;
+    idx /= op.l_out;
+    const size_t c = idx % op.chan;
+    idx /= op.chan;
+    const size_t b = idx % op.batch;
+    idx /= op.batch;
+
+    T tmp = -INFINITY;
+    for(size_t k = 0; k < op.kernel; k++) {
+        const size_t l_plus_p = ol * op.stride + k;
+        if (l_plus_p < op.padding) { continue; }
+        const size_t l = l_plus_p - op.padding;
+        if (l >= op.l_in) { continue; }
+
+        auto inp_i = b * inp_strides[0] + c * inp_strides[1] + l * inp_strides[2];
+        tmp = maxg(tmp, inp[inp_i]);
+    }
+
+    out[i] = tmp;
+}
+
+template<typename T>
+__device__ void max_pool1d_bwd(
+    const Pool1dOp op,
+    const size_t *inp_strides,
+    const size_t *out_strides,
+    const T *inp, // 3d (Batch, Channels, Length)
+    T *grad_inp,
+    const T *out, // 3d (Batch, Channels, LengthOut)
+    const T *grad_out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    const size_t numel = op.batch * op.chan * op.l_in;
+    if (i >= numel) {
+        return;
+    }
+
+    unsigned int idx = i;
+    const size_t l = idx % op.l_in;
+    idx /= op.l_in;
+    const size_t c = idx % op.chan;
+    idx /= op.chan;
+    const size_t b = idx % op.batch;
+    idx /= op.batch;
+
+    const T inp_v = inp[i];
+
+    T tmp = 0.0;
+    for(size_t k = 0; k < op.kernel; k++) {
+        size_t ol = l + op.padding;
+        if (ol < k) { continue; }
+        ol -= k;
+        if (ol % op.stride != 0) { continue; }
+        ol /= op.stride;
+        if (ol >= op.l_out) { continue; }
+
+        auto out_i = b * out_strides[0] + c * out_strides[1] + ol * out_strides[2];
+
+        if (out[out_i] == inp_v) {
+            tmp += grad_out[out_i];
+        }
+    }
+
+    grad_inp[i] += tmp;
+}
+
+template<typename T>
+__device__ void min_pool1d_fwd(
+    const Pool1dOp op,
+    const size_t *inp_strides,
+    const size_t *out_strides,
+    const T *inp, // 3d (Batch, Channels, Length)
+    T *out // 3d (Batch, Channels, LengthOut)
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    const size_t numel = op.batch * op.chan * op.l_out;
+    if (i >= numel) {
+        return;
+    }
+
+    unsigned int idx = i;
+    const size_t ol = idx % op.l_out;
+    idx /= op.l_out;
+    const size_t c = idx % op.chan;
+    idx /= op.chan;
+    const size_t b = idx % op.batch;
+    idx /= op.batch;
+
+    T tmp = INFINITY;
+    for(size_t k = 0; k < op.kernel; k++) {
+        const size_t l_plus_p = ol * op.stride + k;
+        if (l_plus_p < op.padding) { continue; }
+        const size_t l = l_plus_p - op.padding;
+        if (l >= op.l_in) { continue; }
+
+        auto inp_i = b * inp_strides[0] + c * inp_strides[1] + l * inp_strides[2];
+        tmp = ming(tmp, inp[inp_i]);
+    }
+
+    out[i] = tmp;
+}
+
+template<typename T>
+__device__ void min_pool1d_bwd(
+    const Pool1dOp op,
+    const size_t *inp_strides,
+    const size_t *out_strides,
+    const T *inp, // 3d (Batch, Channels, Length)
+    T *grad_inp,
+    const T *out, // 3d (Batch, Channels, LengthOut)
+    const T *grad_out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    const size_t numel = op.batch * op.chan * op.l_in;
+    if (i >= numel) {
+        return;
+    }
+
+    unsigned int idx = i;
+    const size_t l = idx % op.l_in;
+    idx /= op.l_in;
+    const size_t c = idx % op.chan;
+    idx /= op.chan;
+    const size_t b = idx % op.batch;
+    idx /= op.batch;
+
+    const T inp_v = inp[i];
+
+    T tmp = 0.0;
+    for(size_t k = 0; k < op.kernel; k++) {
+        size_t ol = l + op.padding;
+        if (ol < k) { continue; }
+        ol -= k;
+        if (ol % op.stride != 0) { continue; }
+        ol /= op.stride;
+        if (ol >= op.l_out) { continue; }
+
+        auto out_i = b * out_strides[0] + c * out_strides[1] + ol * out_strides[2];
+
+        if (out[out_i] == inp_v) {
+            tmp += grad_out[out_i];
+        }
+    }
+
+    grad_inp[i] += tmp;
+}
+
+#define POOL_OP(TYPENAME, fwd, bwd, fwd_FN, bwd_FN) \
+extern "C" __global__ void fwd( \
+    const Pool1dOp op, \
+    const size_t *inp_strides, \
+    const size_t *out_strides, \
+    const TYPENAME *inp, \
+    TYPENAME *out \
+) { \
+    fwd_FN(op, inp_strides, out_strides, inp, out); \
+} \
+extern "C" __global__ void bwd( \
+    const Pool1dOp op, \
+    const size_t *inp_strides, \
+    const size_t *out_strides, \
+    const TYPENAME *inp, \
+    TYPENAME *grad_inp, \
+    const TYPENAME *out, \
+    const TYPENAME *grad_out \
+) { \
+    bwd_FN(op, inp_strides, out_strides, inp, grad_inp, out, grad_out); \
+}
+
+POOL_OP(
+    float,
+    avg_pool1d_fwd_f32, avg_pool1d_bwd_f32,
+    avg_pool1d_fwd, avg_pool1d_bwd
+);
+POOL_OP(
+    float,
+    min_pool1d_fwd_f32, min_pool1d_bwd_f32,
+    min_pool1d_fwd, min_pool1d_bwd
+);
+POOL_OP(
+    float,
+    max_pool1d_fwd_f32, max_pool1d_bwd_f32,
+    max_pool1d_fwd, max_pool1d_bwd
+);
+
+POOL_OP(
+    double,
+    avg_pool1d_fwd_f64, avg_pool1d_bwd_f64,
+    avg_pool1d_fwd, avg_pool1d_bwd
+);
+POOL_OP(
+    double,
+    min_pool1d_fwd_f64, min_pool1d_bwd_f64,
+    min_pool1d_fwd, min_pool1d_bwd
+);
+POOL_OP(
+    double,
+    max_pool1d_fwd_f64, max_pool1d_bwd_f64,
+    max_pool1d_fwd, max_pool1d_bwd
+);
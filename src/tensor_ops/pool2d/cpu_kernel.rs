@@ -268,3 +268,97 @@ impl<F: Float + Unit + std::ops::AddAssign> super::MinPool2DKernel<F> for Cpu {
         Ok(())
     }
 }
+
+impl<F: Float + Unit + std::ops::AddAssign> super::LpPool2DKernel<F> for Cpu {
+    fn forward<I: Shape, O: Shape>(
+        &self,
+        op: super::Pool2DOp,
+        norm: F,
+        inp: &Self::Storage<I, F>,
+        out: &mut Self::Storage<O, F>,
+    ) -> Result<(), Self::Err> {
+        let istr = make_4d::<I>(inp.strides);
+        let ostr = make_4d::<O>(out.strides);
+
+        let buf = inp.data.as_ref();
+        let out_buf = Arc::make_mut(&mut out.data);
+        for b in 0..op.batch {
+            for c in 0..op.chan {
+                for oh in 0..op.h_out {
+                    for ow in 0..op.w_out {
+                        let mut tmp = F::zero();
+                        for k1 in 0..op.kernel {
+                            let y = (oh * op.stride + k1).checked_sub(op.padding);
+                            for k2 in 0..op.kernel {
+                                let x = (ow * op.stride + k2).checked_sub(op.padding);
+                                if let Some((y, x)) = y.zip(x) {
+                                    if y < op.h_in && x < op.w_in {
+                                        let inp_idx =
+                                            b * istr[0] + c * istr[1] + y * istr[2] + x * istr[3];
+                                        tmp += buf[inp_idx].abs().powf(norm);
+                                    }
+                                }
+                            }
+                        }
+                        out_buf[b * ostr[0] + c * ostr[1] + oh * ostr[2] + ow * ostr[3]] =
+                            tmp.powf(norm.recip());
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn backward<I: Shape, O: Shape>(
+        &self,
+        op: super::Pool2DOp,
+        norm: F,
+        inp: &Self::Storage<I, F>,
+        grad_inp: &mut Self::Storage<I, F>,
+        out: &Self::Storage<O, F>,
+        grad_out: &Self::Storage<O, F>,
+    ) -> Result<(), Self::Err> {
+        let istr = make_4d::<I>(inp.strides);
+        let ostr = make_4d::<O>(out.strides);
+
+        let inp_buf = inp.data.as_ref();
+        let ginp_buf = Arc::make_mut(&mut grad_inp.data);
+        let out_buf = out.data.as_ref();
+        let gout_buf = grad_out.data.as_ref();
+
+        let pm1 = norm - F::one();
+        for b in 0..op.batch {
+            for c in 0..op.chan {
+                for oh in 0..op.h_out {
+                    for ow in 0..op.w_out {
+                        let out_idx = b * ostr[0] + c * ostr[1] + oh * ostr[2] + ow * ostr[3];
+                        let vo = out_buf[out_idx];
+                        if vo == F::zero() {
+                            continue;
+                        }
+                        // d/dx (sum |x|^p)^(1/p) = sign(x) * (|x| / out)^(p - 1)
+                        let go = gout_buf[out_idx];
+                        for k1 in 0..op.kernel {
+                            let y = (oh * op.stride + k1).checked_sub(op.padding);
+                            for k2 in 0..op.kernel {
+                                let x = (ow * op.stride + k2).checked_sub(op.padding);
+                                if let Some((y, x)) = y.zip(x) {
+                                    if x < op.w_in && y < op.h_in {
+                                        let inp_idx =
+                                            b * istr[0] + c * istr[1] + y * istr[2] + x * istr[3];
+                                        let vi = inp_buf[inp_idx];
+                                        if vi != F::zero() {
+                                            ginp_buf[inp_idx] +=
+                                                go * vi.signum() * (vi.abs() / vo).powf(pm1);
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
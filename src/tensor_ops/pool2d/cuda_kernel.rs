@@ -102,3 +102,65 @@ pool_impl!(
     "min_pool2d_fwd_f64",
     "min_pool2d_bwd_f64"
 );
+
+macro_rules! lp_pool_impl {
+    ($TypeName:ty, $Fwd:tt, $Bwd:tt) => {
+        impl super::LpPool2DKernel<$TypeName> for Cuda {
+            fn forward<I: Shape, O: Shape>(
+                &self,
+                op: super::Pool2DOp,
+                norm: $TypeName,
+                inp: &Self::Storage<I, $TypeName>,
+                out: &mut Self::Storage<O, $TypeName>,
+            ) -> Result<(), Self::Err> {
+                if !self.dev.has_func($Fwd, $Fwd) {
+                    self.dev.load_ptx(PTX_SRC.into(), $Fwd, &[$Fwd, $Bwd])?;
+                }
+
+                let inp_strides = self.dev.take_async(make_4d::<I>(inp.strides).into())?;
+                let out_strides = self.dev.take_async(make_4d::<O>(out.strides).into())?;
+                let fwd_fn = self.dev.get_func($Fwd, $Fwd).unwrap();
+                let cfg = LaunchConfig::for_num_elems(out.shape().num_elements() as u32);
+                let params = (
+                    op,                           // const Pool2dOp op,
+                    norm,                         // const float norm,
+                    &inp_strides,                 // const size_t *inp_strides,
+                    &out_strides,                 // const size_t *out_strides,
+                    inp.data.as_ref(),            // const float *inp,
+                    Arc::make_mut(&mut out.data), // float *out
+                );
+                unsafe { fwd_fn.launch_async(cfg, params) }?;
+                Ok(())
+            }
+            fn backward<I: Shape, O: Shape>(
+                &self,
+                op: super::Pool2DOp,
+                norm: $TypeName,
+                inp: &Self::Storage<I, $TypeName>,
+                grad_inp: &mut Self::Storage<I, $TypeName>,
+                out: &Self::Storage<O, $TypeName>,
+                grad_out: &Self::Storage<O, $TypeName>,
+            ) -> Result<(), Self::Err> {
+                let inp_strides = self.dev.take_async(make_4d::<I>(inp.strides).into())?;
+                let out_strides = self.dev.take_async(make_4d::<O>(out.strides).into())?;
+                let bwd_fn = self.dev.get_func($Fwd, $Bwd).unwrap();
+                let cfg = LaunchConfig::for_num_elems(grad_inp.shape().num_elements() as u32);
+                let params = (
+                    op,                                // const Pool2dOp op,
+                    norm,                              // const float norm,
+                    &inp_strides,                      // const size_t *inp_strides,
+                    &out_strides,                      // const size_t *out_strides,
+                    inp.data.as_ref(),                 // const float *inp,
+                    Arc::make_mut(&mut grad_inp.data), // float *grad_inp,
+                    out.data.as_ref(),                 // const float *out,
+                    grad_out.data.as_ref(),            // const float *grad_out
+                );
+                unsafe { bwd_fn.launch_async(cfg, params) }?;
+                Ok(())
+            }
+        }
+    };
+}
+
+lp_pool_impl!(f32, "lp_pool2d_fwd_f32", "lp_pool2d_bwd_f32");
+lp_pool_impl!(f64, "lp_pool2d_fwd_f64", "lp_pool2d_bwd_f64");
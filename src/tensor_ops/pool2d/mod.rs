@@ -207,6 +207,176 @@ pool2d!(
     TryMeth = try_min_pool2d
 );
 
+pub trait LpPool2DKernel<E: Unit>: DeviceStorage {
+    fn forward<I: Shape, O: Shape>(
+        &self,
+        op: Pool2DOp,
+        norm: E,
+        inp: &Self::Storage<I, E>,
+        out: &mut Self::Storage<O, E>,
+    ) -> Result<(), Self::Err>;
+
+    fn backward<I: Shape, O: Shape>(
+        &self,
+        op: Pool2DOp,
+        norm: E,
+        inp: &Self::Storage<I, E>,
+        grad_inp: &mut Self::Storage<I, E>,
+        out: &Self::Storage<O, E>,
+        grad_out: &Self::Storage<O, E>,
+    ) -> Result<(), Self::Err>;
+}
+
+pub trait ConstLpPool2D<const K: usize, const S: usize, const P: usize>: HasErr + HasDtype {
+    type Output;
+    fn try_pool2d(self, norm: Self::Dtype) -> Result<Self::Output, Self::Err>;
+}
+
+/// Power-average pooling: each patch reduces to the `norm`-norm of its values,
+/// `(sum |x|^norm)^(1 / norm)`. `norm = 1` is sum pooling of absolute values,
+/// and as `norm` grows this approaches max pooling of absolute values.
+///
+/// **Pytorch equivalent**: `torch.nn.functional.lp_pool2d` (on non-negative inputs)
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t: Tensor<Rank3<1, 2, 2>, f32, _> = dev.tensor([[[3.0, -4.0], [0.0, 0.0]]]);
+/// let r = t.clone().lp_pool2d::<2, 1, 0>(1.0);
+/// assert_eq!(r.array(), [[[7.0]]]);
+/// let r = t.lp_pool2d::<2, 1, 0>(2.0);
+/// ```
+pub trait TryLpPool2D {
+    fn lp_pool2d<const K: usize, const S: usize, const P: usize>(
+        self,
+        norm: Self::Dtype,
+    ) -> Self::Output
+    where
+        Self: ConstLpPool2D<K, S, P>,
+    {
+        self.try_pool2d(norm).unwrap()
+    }
+    fn try_lp_pool2d<const K: usize, const S: usize, const P: usize>(
+        self,
+        norm: Self::Dtype,
+    ) -> Result<Self::Output, Self::Err>
+    where
+        Self: ConstLpPool2D<K, S, P>,
+    {
+        self.try_pool2d(norm)
+    }
+}
+impl<T> TryLpPool2D for T {}
+
+impl<
+        C: Dim,
+        const H: usize,
+        const W: usize,
+        E: Dtype,
+        D: LpPool2DKernel<E> + ZerosTensor<E>,
+        T: 'static + Tape<D>,
+        const K: usize,
+        const S: usize,
+        const P: usize,
+    > ConstLpPool2D<K, S, P> for Tensor<(C, Const<H>, Const<W>), E, D, T>
+where
+    Const<H>: ConvAlgebra<K, S, P>,
+    Const<W>: ConvAlgebra<K, S, P>,
+{
+    type Output = Tensor<
+        (
+            C,
+            <Const<H> as ConvAlgebra<K, S, P>>::Convolved,
+            <Const<W> as ConvAlgebra<K, S, P>>::Convolved,
+        ),
+        E,
+        D,
+        T,
+    >;
+
+    fn try_pool2d(self, norm: E) -> Result<Self::Output, Self::Err> {
+        let &(chan, _, _) = self.shape();
+        let op = Pool2DOp::new(K, S, P, [1, chan.size(), H, W]);
+        let (inp, mut tape) = self.split_tape();
+        let mut out = inp
+            .device
+            .try_zeros_like(&(chan, Default::default(), Default::default()))?;
+        inp.device
+            .forward(op, norm, &inp.storage, &mut out.storage)?;
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&inp)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+            inp.device.backward(
+                op,
+                norm,
+                &inp.storage,
+                grad_inp,
+                &phantom_out.storage,
+                grad_out,
+            )
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+impl<
+        B: Dim,
+        C: Dim,
+        const H: usize,
+        const W: usize,
+        E: Dtype,
+        D: LpPool2DKernel<E> + ZerosTensor<E>,
+        T: 'static + Tape<D>,
+        const K: usize,
+        const S: usize,
+        const P: usize,
+    > ConstLpPool2D<K, S, P> for Tensor<(B, C, Const<H>, Const<W>), E, D, T>
+where
+    Const<H>: ConvAlgebra<K, S, P>,
+    Const<W>: ConvAlgebra<K, S, P>,
+{
+    type Output = Tensor<
+        (
+            B,
+            C,
+            <Const<H> as ConvAlgebra<K, S, P>>::Convolved,
+            <Const<W> as ConvAlgebra<K, S, P>>::Convolved,
+        ),
+        E,
+        D,
+        T,
+    >;
+
+    fn try_pool2d(self, norm: E) -> Result<Self::Output, Self::Err> {
+        let &(batch, chan, _, _) = self.shape();
+        let op = Pool2DOp::new(K, S, P, [batch.size(), chan.size(), H, W]);
+        let (inp, mut tape) = self.split_tape();
+        let mut out =
+            inp.device
+                .try_zeros_like(&(batch, chan, Default::default(), Default::default()))?;
+        inp.device
+            .forward(op, norm, &inp.storage, &mut out.storage)?;
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&inp)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+            inp.device.backward(
+                op,
+                norm,
+                &inp.storage,
+                grad_inp,
+                &phantom_out.storage,
+                grad_out,
+            )
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -314,4 +484,44 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn test_pool2d_3d_lp2d() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([[[3.0, -4.0, 0.0], [0.0, 0.0, 1.0]]]);
+        let r = x.trace().lp_pool2d::<2, 1, 0>(2.0);
+        assert_close(&r.array(), &[[[5.0, 4.1231055]]]);
+        let g = r.sum().backward();
+        assert_close(
+            &g.get(&x).array(),
+            &[[[0.6, -1.7701425, 0.0], [0.0, 0.0, 0.24253562]]],
+        );
+    }
+
+    #[test]
+    fn test_pool2d_4d_lp2d_norm_1() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> =
+            dev.tensor([[[[1.0, -2.0], [0.5, 0.0]]], [[[-1.0, -1.0], [2.0, 3.0]]]]);
+        let r = x.trace().lp_pool2d::<2, 1, 0>(1.0);
+        assert_close(&r.array(), &[[[[3.5]]], [[[7.0]]]]);
+        let g = r.sum().backward();
+        assert_close(
+            &g.get(&x).array(),
+            &[[[[1.0, -1.0], [1.0, 0.0]]], [[[-1.0, -1.0], [1.0, 1.0]]]],
+        );
+    }
+
+    #[test]
+    fn test_pool2d_3d_lp2d_padding() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([[[2.0, -1.0]]]);
+        let r = x.trace().lp_pool2d::<2, 1, 1>(3.0);
+        assert_close(
+            &r.array(),
+            &[[[2.0, 2.0800838, 1.0], [2.0, 2.0800838, 1.0]]],
+        );
+        let g = r.exp().sum().backward();
+        assert_close(&g.get(&x).array(), &[[[29.579323, -9.136866]]]);
+    }
 }
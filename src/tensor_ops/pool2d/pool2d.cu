@@ -308,6 +308,111 @@ __device__ void min_pool2d_bwd(
     grad_inp[i] += tmp;
 }
 
+template<typename T>
+__device__ void lp_pool2d_fwd(
+    const Pool2dOp op,
+    const T norm,
+    const size_t *inp_strides,
+    const size_t *out_strides,
+    const T *inp, // 4d (Batch, Channels, Height, Width)
+    T *out // 4d (Batch, Channels, HeightOut, WidthOut)
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    const size_t numel = op.batch * op.chan * op.h_out * op.w_out;
+    if (i >= numel) {
+        return;
+    }
+
+    unsigned int idx = i;
+    const size_t ow = idx % op.w_out;
+    idx /= op.w_out;
+    const size_t oh = idx % op.h_out;
+    idx /= op.h_out;
+    const size_t c = idx % op.chan;
+    idx /= op.chan;
+    const size_t b = idx % op.batch;
+    idx /= op.batch;
+
+    T tmp = 0.0;
+    for(size_t k1 = 0; k1 < op.kernel; k1++) {
+        for (size_t k2 = 0; k2 < op.kernel; k2++) {
+            const size_t y_plus_p = oh * op.stride + k1;
+            if (y_plus_p < op.padding) { continue; }
+            const size_t y = y_plus_p - op.padding;
+            if (y >= op.h_in) { continue; }
+            const size_t x_plus_p = ow * op.stride + k2;
+            if (x_plus_p < op.padding) { continue; }
+            const size_t x = x_plus_p - op.padding;
+            if (x >= op.w_in) { continue; }
+
+            auto inp_i = b * inp_strides[0] + c * inp_strides[1] + y * inp_strides[2] + x * inp_strides[3];
+            tmp += powg(absg(inp[inp_i]), norm);
+        }
+    }
+
+    out[i] = powg(tmp, static_cast<T>(1.0) / norm);
+}
+
+template<typename T>
+__device__ void lp_pool2d_bwd(
+    const Pool2dOp op,
+    const T norm,
+    const size_t *inp_strides,
+    const size_t *out_strides,
+    const T *inp, // 4d (Batch, Channels, Height, Width)
+    T *grad_inp,
+    const T *out, // 4d (Batch, Channels, HeightOut, WidthOut)
+    const T *grad_out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    const size_t numel = op.batch * op.chan * op.h_in * op.w_in;
+    if (i >= numel) {
+        return;
+    }
+
+    unsigned int idx = i;
+    const size_t x = idx % op.w_in;
+    idx /= op.w_in;
+    const size_t y = idx % op.h_in;
+    idx /= op.h_in;
+    const size_t c = idx % op.chan;
+    idx /= op.chan;
+    const size_t b = idx % op.batch;
+    idx /= op.batch;
+
+    const T inp_v = inp[i];
+    if (inp_v == 0.0) {
+        return;
+    }
+
+    // d/dx (sum |x|^p)^(1/p) = sign(x) * (|x| / out)^(p - 1)
+    T tmp = 0.0;
+    for(size_t k1 = 0; k1 < op.kernel; k1++) {
+        for (size_t k2 = 0; k2 < op.kernel; k2++) {
+            size_t oh = y + op.padding;
+            if (oh < k1) { continue; }
+            oh -= k1;
+            if (oh % op.stride != 0) { continue; }
+            oh /= op.stride;
+            if (oh >= op.h_out) { continue; }
+
+            size_t ow = x + op.padding;
+            if (ow < k2) { continue; }
+            ow -= k2;
+            if (ow % op.stride != 0) { continue; }
+            ow /= op.stride;
+            if (ow >= op.w_out) { continue; }
+
+            auto out_i = b * out_strides[0] + c * out_strides[1] + oh * out_strides[2] + ow * out_strides[3];
+            const T out_v = out[out_i];
+            if (out_v == 0.0) { continue; }
+            tmp += grad_out[out_i] * powg(absg(inp_v) / out_v, norm - static_cast<T>(1.0));
+        }
+    }
+
+    grad_inp[i] += copysigng(tmp, inp_v);
+}
+
 #define POOL_OP(TYPENAME, fwd, bwd, fwd_FN, bwd_FN) \
 extern "C" __global__ void fwd( \
     const Pool2dOp op, \
@@ -361,3 +466,30 @@ POOL_OP(
     max_pool2d_fwd_f64, max_pool2d_bwd_f64,
     max_pool2d_fwd, max_pool2d_bwd
 );
+
+#define LP_POOL_OP(TYPENAME, fwd, bwd) \
+extern "C" __global__ void fwd( \
+    const Pool2dOp op, \
+    const TYPENAME norm, \
+    const size_t *inp_strides, \
+    const size_t *out_strides, \
+    const TYPENAME *inp, \
+    TYPENAME *out \
+) { \
+    lp_pool2d_fwd(op, norm, inp_strides, out_strides, inp, out); \
+} \
+extern "C" __global__ void bwd( \
+    const Pool2dOp op, \
+    const TYPENAME norm, \
+    const size_t *inp_strides, \
+    const size_t *out_strides, \
+    const TYPENAME *inp, \
+    TYPENAME *grad_inp, \
+    const TYPENAME *out, \
+    const TYPENAME *grad_out \
+) { \
+    lp_pool2d_bwd(op, norm, inp_strides, out_strides, inp, grad_inp, out, grad_out); \
+}
+
+LP_POOL_OP(float, lp_pool2d_fwd_f32, lp_pool2d_bwd_f32);
+LP_POOL_OP(double, lp_pool2d_fwd_f64, lp_pool2d_bwd_f64);
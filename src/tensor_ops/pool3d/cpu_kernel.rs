@@ -0,0 +1,336 @@
+use crate::shapes::*;
+use crate::tensor::cpu::Cpu;
+
+use std::sync::Arc;
+
+use num_traits::Float;
+
+fn make_5d<S: Shape>(strides: S::Concrete) -> [usize; 5] {
+    match S::NUM_DIMS {
+        4 => [0, strides[0], strides[1], strides[2], strides[3]],
+        5 => [strides[0], strides[1], strides[2], strides[3], strides[4]],
+        _ => panic!("Only implemented for 4d & 5d arrays"),
+    }
+}
+
+impl<F: Float + Unit + std::ops::AddAssign + std::ops::DivAssign> super::AvgPool3DKernel<F>
+    for Cpu
+{
+    fn forward<I: Shape, O: Shape>(
+        &self,
+        op: super::Pool3DOp,
+        inp: &Self::Storage<I, F>,
+        out: &mut Self::Storage<O, F>,
+    ) -> Result<(), Self::Err> {
+        let istr = make_5d::<I>(inp.strides);
+        let ostr = make_5d::<O>(out.strides);
+
+        let buf = inp.data.as_ref();
+        let out_buf = Arc::make_mut(&mut out.data);
+        for b in 0..op.batch {
+            for c in 0..op.chan {
+                for od in 0..op.d_out {
+                    for oh in 0..op.h_out {
+                        for ow in 0..op.w_out {
+                            let mut tmp = F::zero();
+                            for k0 in 0..op.kernel {
+                                let z = (od * op.stride + k0).checked_sub(op.padding);
+                                for k1 in 0..op.kernel {
+                                    let y = (oh * op.stride + k1).checked_sub(op.padding);
+                                    for k2 in 0..op.kernel {
+                                        let x = (ow * op.stride + k2).checked_sub(op.padding);
+                                        if let Some(((z, y), x)) = z.zip(y).zip(x) {
+                                            if z < op.d_in && y < op.h_in && x < op.w_in {
+                                                let inp_idx = b * istr[0]
+                                                    + c * istr[1]
+                                                    + z * istr[2]
+                                                    + y * istr[3]
+                                                    + x * istr[4];
+                                                tmp += buf[inp_idx];
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                            tmp /= F::from(op.kernel * op.kernel * op.kernel).unwrap();
+                            out_buf[b * ostr[0]
+                                + c * ostr[1]
+                                + od * ostr[2]
+                                + oh * ostr[3]
+                                + ow * ostr[4]] = tmp;
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn backward<I: Shape, O: Shape>(
+        &self,
+        op: super::Pool3DOp,
+        inp: &Self::Storage<I, F>,
+        grad_inp: &mut Self::Storage<I, F>,
+        out: &Self::Storage<O, F>,
+        grad_out: &Self::Storage<O, F>,
+    ) -> Result<(), Self::Err> {
+        let istr = make_5d::<I>(inp.strides);
+        let ostr = make_5d::<O>(out.strides);
+
+        let ginp_buf = Arc::make_mut(&mut grad_inp.data);
+        let buf = grad_out.data.as_ref();
+
+        for b in 0..op.batch {
+            for c in 0..op.chan {
+                for od in 0..op.d_out {
+                    for oh in 0..op.h_out {
+                        for ow in 0..op.w_out {
+                            let g = buf[b * ostr[0]
+                                + c * ostr[1]
+                                + od * ostr[2]
+                                + oh * ostr[3]
+                                + ow * ostr[4]]
+                                / F::from(op.kernel * op.kernel * op.kernel).unwrap();
+                            for k0 in 0..op.kernel {
+                                let z = (od * op.stride + k0).checked_sub(op.padding);
+                                for k1 in 0..op.kernel {
+                                    let y = (oh * op.stride + k1).checked_sub(op.padding);
+                                    for k2 in 0..op.kernel {
+                                        let x = (ow * op.stride + k2).checked_sub(op.padding);
+                                        if let Some(((z, y), x)) = z.zip(y).zip(x) {
+                                            if z < op.d_in && y < op.h_in && x < op.w_in {
+                                                let inp_idx = b * istr[0]
+                                                    + c * istr[1]
+                                                    + z * istr[2]
+                                                    + y * istr[3]
+                                                    + x * istr[4];
+                                                ginp_buf[inp_idx] += g;
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<F: Float + Unit + std::ops::AddAssign> super::MaxPool3DKernel<F> for Cpu {
+    fn forward<I: Shape, O: Shape>(
+        &self,
+        op: super::Pool3DOp,
+        inp: &Self::Storage<I, F>,
+        out: &mut Self::Storage<O, F>,
+    ) -> Result<(), Self::Err> {
+        let istr = make_5d::<I>(inp.strides);
+        let ostr = make_5d::<O>(out.strides);
+
+        let buf = inp.data.as_ref();
+        let out_buf = Arc::make_mut(&mut out.data);
+        for b in 0..op.batch {
+            for c in 0..op.chan {
+                for od in 0..op.d_out {
+                    for oh in 0..op.h_out {
+                        for ow in 0..op.w_out {
+                            let mut tmp = F::neg_infinity();
+                            for k0 in 0..op.kernel {
+                                let z = (od * op.stride + k0).checked_sub(op.padding);
+                                for k1 in 0..op.kernel {
+                                    let y = (oh * op.stride + k1).checked_sub(op.padding);
+                                    for k2 in 0..op.kernel {
+                                        let x = (ow * op.stride + k2).checked_sub(op.padding);
+                                        if let Some(((z, y), x)) = z.zip(y).zip(x) {
+                                            if z < op.d_in && y < op.h_in && x < op.w_in {
+                                                let inp_idx = b * istr[0]
+                                                    + c * istr[1]
+                                                    + z * istr[2]
+                                                    + y * istr[3]
+                                                    + x * istr[4];
+                                                tmp = tmp.max(buf[inp_idx]);
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                            out_buf[b * ostr[0]
+                                + c * ostr[1]
+                                + od * ostr[2]
+                                + oh * ostr[3]
+                                + ow * ostr[4]] = tmp;
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn backward<I: Shape, O: Shape>(
+        &self,
+        op: super::Pool3DOp,
+        inp: &Self::Storage<I, F>,
+        grad_inp: &mut Self::Storage<I, F>,
+        out: &Self::Storage<O, F>,
+        grad_out: &Self::Storage<O, F>,
+    ) -> Result<(), Self::Err> {
+        let istr = make_5d::<I>(inp.strides);
+        let ostr = make_5d::<O>(out.strides);
+
+        let inp_buf = inp.data.as_ref();
+        let ginp_buf = Arc::make_mut(&mut grad_inp.data);
+        let out_buf = out.data.as_ref();
+        let gout_buf = grad_out.data.as_ref();
+
+        for b in 0..op.batch {
+            for c in 0..op.chan {
+                for od in 0..op.d_out {
+                    for oh in 0..op.h_out {
+                        for ow in 0..op.w_out {
+                            let out_idx = b * ostr[0]
+                                + c * ostr[1]
+                                + od * ostr[2]
+                                + oh * ostr[3]
+                                + ow * ostr[4];
+                            let go = gout_buf[out_idx];
+                            let vo = out_buf[out_idx];
+                            for k0 in 0..op.kernel {
+                                let z = (od * op.stride + k0).checked_sub(op.padding);
+                                for k1 in 0..op.kernel {
+                                    let y = (oh * op.stride + k1).checked_sub(op.padding);
+                                    for k2 in 0..op.kernel {
+                                        let x = (ow * op.stride + k2).checked_sub(op.padding);
+                                        if let Some(((z, y), x)) = z.zip(y).zip(x) {
+                                            if z < op.d_in && y < op.h_in && x < op.w_in {
+                                                let inp_idx = b * istr[0]
+                                                    + c * istr[1]
+                                                    + z * istr[2]
+                                                    + y * istr[3]
+                                                    + x * istr[4];
+                                                if inp_buf[inp_idx] == vo {
+                                                    ginp_buf[inp_idx] += go;
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<F: Float + Unit + std::ops::AddAssign> super::MinPool3DKernel<F> for Cpu {
+    fn forward<I: Shape, O: Shape>(
+        &self,
+        op: super::Pool3DOp,
+        inp: &Self::Storage<I, F>,
+        out: &mut Self::Storage<O, F>,
+    ) -> Result<(), Self::Err> {
+        let istr = make_5d::<I>(inp.strides);
+        let ostr = make_5d::<O>(out.strides);
+
+        let buf = inp.data.as_ref();
+        let out_buf = Arc::make_mut(&mut out.data);
+        for b in 0..op.batch {
+            for c in 0..op.chan {
+                for od in 0..op.d_out {
+                    for oh in 0..op.h_out {
+                        for ow in 0..op.w_out {
+                            let mut tmp = F::infinity();
+                            for k0 in 0..op.kernel {
+                                let z = (od * op.stride + k0).checked_sub(op.padding);
+                                for k1 in 0..op.kernel {
+                                    let y = (oh * op.stride + k1).checked_sub(op.padding);
+                                    for k2 in 0..op.kernel {
+                                        let x = (ow * op.stride + k2).checked_sub(op.padding);
+                                        if let Some(((z, y), x)) = z.zip(y).zip(x) {
+                                            if z < op.d_in && y < op.h_in && x < op.w_in {
+                                                let inp_idx = b * istr[0]
+                                                    + c * istr[1]
+                                                    + z * istr[2]
+                                                    + y * istr[3]
+                                                    + x * istr[4];
+                                                tmp = tmp.min(buf[inp_idx]);
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                            out_buf[b * ostr[0]
+                                + c * ostr[1]
+                                + od * ostr[2]
+                                + oh * ostr[3]
+                                + ow * ostr[4]] = tmp;
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn backward<I: Shape, O: Shape>(
+        &self,
+        op: super::Pool3DOp,
+        inp: &Self::Storage<I, F>,
+        grad_inp: &mut Self::Storage<I, F>,
+        out: &Self::Storage<O, F>,
+        grad_out: &Self::Storage<O, F>,
+    ) -> Result<(), Self::Err> {
+        let istr = make_5d::<I>(inp.strides);
+        let ostr = make_5d::<O>(out.strides);
+
+        let inp_buf = inp.data.as_ref();
+        let ginp_buf = Arc::make_mut(&mut grad_inp.data);
+        let out_buf = out.data.as_ref();
+        let gout_buf = grad_out.data.as_ref();
+
+        for b in 0..op.batch {
+            for c in 0..op.chan {
+                for od in 0..op.d_out {
+                    for oh in 0..op.h_out {
+                        for ow in 0..op.w_out {
+                            let out_idx = b * ostr[0]
+                                + c * ostr[1]
+                                + od * ostr[2]
+                                + oh * ostr[3]
+                                + ow * ostr[4];
+                            let go = gout_buf[out_idx];
+                            let vo = out_buf[out_idx];
+                            for k0 in 0..op.kernel {
+                                let z = (od * op.stride + k0).checked_sub(op.padding);
+                                for k1 in 0..op.kernel {
+                                    let y = (oh * op.stride + k1).checked_sub(op.padding);
+                                    for k2 in 0..op.kernel {
+                                        let x = (ow * op.stride + k2).checked_sub(op.padding);
+                                        if let Some(((z, y), x)) = z.zip(y).zip(x) {
+                                            if z < op.d_in && y < op.h_in && x < op.w_in {
+                                                let inp_idx = b * istr[0]
+                                                    + c * istr[1]
+                                                    + z * istr[2]
+                                                    + y * istr[3]
+                                                    + x * istr[4];
+                                                if inp_buf[inp_idx] == vo {
+                                                    ginp_buf[inp_idx] += go;
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,282 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{
+    gradients::Tape,
+    shapes::*,
+    tensor::{DeviceStorage, HasErr, PutTape, SplitTape, Tensor, ZerosTensor},
+};
+
+use super::conv2d::ConvAlgebra;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct Pool3DOp {
+    pub kernel: usize,
+    pub stride: usize,
+    pub padding: usize,
+    pub batch: usize,
+    pub chan: usize,
+    pub d_in: usize,
+    pub d_out: usize,
+    pub h_in: usize,
+    pub h_out: usize,
+    pub w_in: usize,
+    pub w_out: usize,
+}
+
+impl Pool3DOp {
+    fn new(k: usize, s: usize, p: usize, [b, c, d_in, h_in, w_in]: [usize; 5]) -> Self {
+        Self {
+            kernel: k,
+            stride: s,
+            padding: p,
+            batch: b,
+            chan: c,
+            d_in,
+            d_out: (d_in + 2 * p - k) / s + 1,
+            h_in,
+            h_out: (h_in + 2 * p - k) / s + 1,
+            w_in,
+            w_out: (w_in + 2 * p - k) / s + 1,
+        }
+    }
+}
+
+macro_rules! pool3d {
+    (Kernel=$Kernel:ident, ConstTrait=$ConstTrait:ident, TryTrait=$TryTrait:ident, Meth=$Meth:ident, TryMeth=$TryMeth:ident) => {
+        pub trait $Kernel<E: Unit>: DeviceStorage {
+            fn forward<I: Shape, O: Shape>(
+                &self,
+                op: Pool3DOp,
+                inp: &Self::Storage<I, E>,
+                out: &mut Self::Storage<O, E>,
+            ) -> Result<(), Self::Err>;
+
+            fn backward<I: Shape, O: Shape>(
+                &self,
+                op: Pool3DOp,
+                inp: &Self::Storage<I, E>,
+                grad_inp: &mut Self::Storage<I, E>,
+                out: &Self::Storage<O, E>,
+                grad_out: &Self::Storage<O, E>,
+            ) -> Result<(), Self::Err>;
+        }
+
+        pub trait $ConstTrait<const K: usize, const S: usize, const P: usize>: HasErr {
+            type Output;
+            fn try_pool3d(self) -> Result<Self::Output, Self::Err>;
+        }
+
+        pub trait $TryTrait {
+            fn $Meth<const K: usize, const S: usize, const P: usize>(self) -> Self::Output
+            where
+                Self: $ConstTrait<K, S, P>,
+            {
+                self.try_pool3d().unwrap()
+            }
+            fn $TryMeth<const K: usize, const S: usize, const P: usize>(
+                self,
+            ) -> Result<Self::Output, Self::Err>
+            where
+                Self: $ConstTrait<K, S, P>,
+            {
+                self.try_pool3d()
+            }
+        }
+        impl<T> $TryTrait for T {}
+
+        impl<
+                C: Dim,
+                const Z: usize,
+                const H: usize,
+                const W: usize,
+                E: Dtype,
+                D: $Kernel<E> + ZerosTensor<E>,
+                T: 'static + Tape<D>,
+                const K: usize,
+                const S: usize,
+                const P: usize,
+            > $ConstTrait<K, S, P> for Tensor<(C, Const<Z>, Const<H>, Const<W>), E, D, T>
+        where
+            Const<Z>: ConvAlgebra<K, S, P>,
+            Const<H>: ConvAlgebra<K, S, P>,
+            Const<W>: ConvAlgebra<K, S, P>,
+        {
+            type Output = Tensor<
+                (
+                    C,
+                    <Const<Z> as ConvAlgebra<K, S, P>>::Convolved,
+                    <Const<H> as ConvAlgebra<K, S, P>>::Convolved,
+                    <Const<W> as ConvAlgebra<K, S, P>>::Convolved,
+                ),
+                E,
+                D,
+                T,
+            >;
+
+            fn try_pool3d(self) -> Result<Self::Output, Self::Err> {
+                let &(chan, _, _, _) = self.shape();
+                let op = Pool3DOp::new(K, S, P, [1, chan.size(), Z, H, W]);
+                let (inp, mut tape) = self.split_tape();
+                let mut out = inp.device.try_zeros_like(&(
+                    chan,
+                    Default::default(),
+                    Default::default(),
+                    Default::default(),
+                ))?;
+                inp.device.forward(op, &inp.storage, &mut out.storage)?;
+                let phantom_out = out.clone();
+                tape.try_alloc_grad(&inp)?;
+                tape.try_alloc_grad(&out)?;
+                tape.add_backward_op(move |grads| {
+                    let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+                    inp.device
+                        .backward(op, &inp.storage, grad_inp, &phantom_out.storage, grad_out)
+                });
+                Ok(out.put_tape(tape))
+            }
+        }
+
+        impl<
+                B: Dim,
+                C: Dim,
+                const Z: usize,
+                const H: usize,
+                const W: usize,
+                E: Dtype,
+                D: $Kernel<E> + ZerosTensor<E>,
+                T: 'static + Tape<D>,
+                const K: usize,
+                const S: usize,
+                const P: usize,
+            > $ConstTrait<K, S, P> for Tensor<(B, C, Const<Z>, Const<H>, Const<W>), E, D, T>
+        where
+            Const<Z>: ConvAlgebra<K, S, P>,
+            Const<H>: ConvAlgebra<K, S, P>,
+            Const<W>: ConvAlgebra<K, S, P>,
+        {
+            type Output = Tensor<
+                (
+                    B,
+                    C,
+                    <Const<Z> as ConvAlgebra<K, S, P>>::Convolved,
+                    <Const<H> as ConvAlgebra<K, S, P>>::Convolved,
+                    <Const<W> as ConvAlgebra<K, S, P>>::Convolved,
+                ),
+                E,
+                D,
+                T,
+            >;
+
+            fn try_pool3d(self) -> Result<Self::Output, Self::Err> {
+                let &(batch, chan, _, _, _) = self.shape();
+                let op = Pool3DOp::new(K, S, P, [batch.size(), chan.size(), Z, H, W]);
+                let (inp, mut tape) = self.split_tape();
+                let mut out = inp.device.try_zeros_like(&(
+                    batch,
+                    chan,
+                    Default::default(),
+                    Default::default(),
+                    Default::default(),
+                ))?;
+                inp.device.forward(op, &inp.storage, &mut out.storage)?;
+                let phantom_out = out.clone();
+                tape.try_alloc_grad(&inp)?;
+                tape.try_alloc_grad(&out)?;
+                tape.add_backward_op(move |grads| {
+                    let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+                    inp.device
+                        .backward(op, &inp.storage, grad_inp, &phantom_out.storage, grad_out)
+                });
+                Ok(out.put_tape(tape))
+            }
+        }
+    };
+}
+
+pool3d!(
+    Kernel = AvgPool3DKernel,
+    ConstTrait = ConstAvgPool3D,
+    TryTrait = TryAvgPool3D,
+    Meth = avg_pool3d,
+    TryMeth = try_avg_pool3d
+);
+
+pool3d!(
+    Kernel = MaxPool3DKernel,
+    ConstTrait = ConstMaxPool3D,
+    TryTrait = TryMaxPool3D,
+    Meth = max_pool3d,
+    TryMeth = try_max_pool3d
+);
+
+pool3d!(
+    Kernel = MinPool3DKernel,
+    ConstTrait = ConstMinPool3D,
+    TryTrait = TryMinPool3D,
+    Meth = min_pool3d,
+    TryMeth = try_min_pool3d
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_pool3d_4d_max3d_eq_grads() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([[
+            [[1.0, 1.0, 0.5], [0.2, 0.2, 0.5]],
+            [[0.3, 0.1, 1.2], [0.0, 1.0, 0.4]],
+        ]]);
+        let r = x.trace().max_pool3d::<2, 1, 0>();
+        assert_close(&r.array(), &[[[[1.0, 1.2]]]]);
+        let g = r.sum().backward();
+        assert_close(
+            &g.get(&x).array(),
+            &[[[[1., 1., 0.], [0., 0., 0.]], [[0., 0., 1.], [0., 1., 0.]]]],
+        );
+    }
+
+    #[test]
+    fn test_pool3d_4d_min3d() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([[
+            [[1.0, 1.0, 0.5], [0.2, 0.2, 0.5]],
+            [[0.3, 0.1, 1.2], [0.0, 1.0, 0.4]],
+        ]]);
+        let r = x.trace().min_pool3d::<2, 1, 0>();
+        assert_close(&r.array(), &[[[[0.0, 0.1]]]]);
+        let g = r.exp().sum().backward();
+        assert_close(
+            &g.get(&x).array(),
+            &[[
+                [[0., 0., 0.], [0., 0., 0.]],
+                [[0., 1.1051709, 0.], [1., 0., 0.]],
+            ]],
+        );
+    }
+
+    #[test]
+    fn test_pool3d_5d_avg3d_padding() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank5<2, 1, 2, 2, 2>, TestDtype, _> = dev.tensor_from_vec(
+            std::vec![1., 2., 3., 4., 5., 6., 7., 8., -1., -2., -3., -4., -5., -6., -7., -8.,],
+            Default::default(),
+        );
+        let r: Tensor<Rank5<2, 1, 2, 2, 2>, _, _, _> = x.trace().avg_pool3d::<2, 2, 1>();
+        r.as_vec().assert_close(
+            &std::vec![
+                0.125, 0.25, 0.375, 0.5, 0.625, 0.75, 0.875, 1.0, -0.125, -0.25, -0.375, -0.5,
+                -0.625, -0.75, -0.875, -1.0,
+            ],
+            1e-6,
+        );
+        let g = r.sum().backward();
+        g.get(&x).as_vec().assert_close(&std::vec![0.125; 16], 1e-6);
+    }
+}
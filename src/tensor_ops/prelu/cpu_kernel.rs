@@ -0,0 +1,31 @@
+use crate::tensor_ops::cpu_kernels::BinaryDerivative;
+use num_traits::Float;
+
+impl<F: Float> BinaryDerivative<F> for super::PReLUKernelOp {
+    #[inline(always)]
+    fn f(&self, &x: &F, &a: &F) -> F {
+        if x > F::zero() {
+            x
+        } else {
+            a * x
+        }
+    }
+
+    #[inline(always)]
+    fn dfdx(&self, &x: &F, &a: &F) -> F {
+        if x > F::zero() {
+            F::one()
+        } else {
+            a
+        }
+    }
+
+    #[inline(always)]
+    fn dfdy(&self, &x: &F, _: &F) -> F {
+        if x > F::zero() {
+            F::zero()
+        } else {
+            x
+        }
+    }
+}
@@ -0,0 +1,9 @@
+use super::PReLUKernelOp;
+use crate::tensor_ops::cuda_kernels::cuda_binary;
+
+unsafe impl cudarc::driver::AsKernelParam for PReLUKernelOp {}
+
+const PTX: &str = include_str!(concat!(env!("OUT_DIR"), "/prelu.ptx"));
+
+cuda_binary!(PReLUKernelOp, f32, PTX, "prelu_fwd_f32", "prelu_bwd_f32");
+cuda_binary!(PReLUKernelOp, f64, PTX, "prelu_fwd_f64", "prelu_bwd_f64");
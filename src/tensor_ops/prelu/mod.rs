@@ -0,0 +1,98 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use super::ops::{try_binary_op, BinaryKernel};
+use crate::{gradients::*, shapes::*, tensor::*};
+
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct PReLUKernelOp;
+
+/// [Parametric ReLU](https://arxiv.org/abs/1502.01852) `max(0, x) + alpha * min(0, x)`, where
+/// `alpha` is a tensor of learnable slopes.
+///
+/// Gradients flow to both `x` and `alpha`. The gradient wrt `alpha` is `min(0, x)`.
+///
+/// **Pytorch equivalent**: `torch.nn.functional.prelu(x, alpha)`
+///
+/// Example:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let x = dev.tensor([[-2.0, -1.0, 0.5], [1.0, -0.5, -3.0]]);
+/// let alpha = dev.tensor([[0.1, 0.25, 0.5], [0.1, 0.25, 0.5]]);
+/// let r = x.prelu(alpha);
+/// assert_eq!(r.array(), [[-0.2, -0.25, 0.5], [1.0, -0.125, -1.5]]);
+/// ```
+///
+/// `alpha` can be broadcast to the shape of `x`, e.g. along the channels of an image:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let x: Tensor<Rank3<3, 4, 4>, f32, _> = dev.sample_normal();
+/// let alpha = dev.tensor([0.1, 0.25, 0.5]);
+/// let r = x.prelu(alpha.broadcast::<Rank3<3, 4, 4>, _>());
+/// ```
+pub fn prelu<Lhs: TryPReLU<Rhs>, Rhs>(x: Lhs, alpha: Rhs) -> Lhs {
+    x.prelu(alpha)
+}
+
+/// Parametric ReLU with a tensor of slopes. See [prelu].
+pub trait TryPReLU<Rhs = Self>: HasErr {
+    /// See [prelu]
+    fn prelu(self, alpha: Rhs) -> Self {
+        self.try_prelu(alpha).unwrap()
+    }
+
+    /// Fallible version of [TryPReLU::prelu]
+    fn try_prelu(self, alpha: Rhs) -> Result<Self, Self::Err>;
+}
+
+impl<S: Shape, E: Dtype, D, LTape, RTape> TryPReLU<Tensor<S, E, D, RTape>>
+    for Tensor<S, E, D, LTape>
+where
+    D: BinaryKernel<PReLUKernelOp, E>,
+    LTape: Tape<D> + Merge<RTape>,
+    RTape: Tape<D>,
+{
+    fn try_prelu(self, alpha: Tensor<S, E, D, RTape>) -> Result<Self, Self::Err> {
+        try_binary_op(PReLUKernelOp, self, alpha)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_prelu() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([[-2.0, -1.0, 0.5], [1.0, -0.5, -3.0]]);
+        let a: Tensor<_, TestDtype, _> = dev.tensor([[0.1, 0.25, 0.5], [0.1, 0.25, 0.5]]);
+        let r = x.trace().prelu(a.trace());
+        assert_close(&r.array(), &[[-0.2, -0.25, 0.5], [1.0, -0.125, -1.5]]);
+        let g = r.mean().backward();
+        assert_close(
+            &g.get(&x).array(),
+            &[
+                [0.016666668, 0.041666668, 0.16666667],
+                [0.16666667, 0.041666668, 0.083333336],
+            ],
+        );
+        assert_close(
+            &g.get(&a).array(),
+            &[[-0.33333334, -0.16666667, 0.0], [0.0, -0.083333336, -0.5]],
+        );
+    }
+
+    #[test]
+    fn test_prelu_matches_relu() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank2<3, 4>, TestDtype, _> = dev.sample_normal();
+        let zeros: Tensor<Rank2<3, 4>, TestDtype, _> = dev.zeros();
+        assert_eq!(x.clone().prelu(zeros).array(), x.relu().array());
+    }
+}
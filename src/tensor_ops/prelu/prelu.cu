@@ -0,0 +1,13 @@
+#include "binary_op_macros.cuh"
+
+struct PReLUKernelOp {};
+
+BINARY_OP(float, prelu_fwd_f32, prelu_bwd_f32, PReLUKernelOp,
+    x > 0.0 ? x : y * x,
+    x > 0.0 ? 1.0 : y,
+    x > 0.0 ? 0.0 : x)
+
+BINARY_OP(double, prelu_fwd_f64, prelu_bwd_f64, PReLUKernelOp,
+    x > 0.0 ? x : y * x,
+    x > 0.0 ? 1.0 : y,
+    x > 0.0 ? 0.0 : x)
@@ -0,0 +1,21 @@
+use crate::{
+    tensor::cpu::{Cpu, StridedArray},
+    tensor_ops::utilities::rng::StreamRng,
+};
+
+use std::{sync::Arc, vec::Vec};
+
+impl super::RandpermKernel for Cpu {
+    fn forward(&self, seed: u64, n: usize) -> Result<Self::Storage<(usize,), usize>, Self::Err> {
+        let mut out: StridedArray<(usize,), usize> = StridedArray::new((n,))?;
+        let keys: Vec<u64> = (0..n)
+            .map(|i| StreamRng::new(seed, i as u64).next_u64())
+            .collect();
+        let buf = Arc::make_mut(&mut out.data);
+        for (i, x) in buf.iter_mut().enumerate() {
+            *x = i;
+        }
+        buf.sort_unstable_by_key(|&i| (keys[i], i));
+        Ok(out)
+    }
+}
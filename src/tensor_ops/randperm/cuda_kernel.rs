@@ -0,0 +1,68 @@
+use crate::tensor::cuda::{Cuda, CudaArray};
+use cudarc::driver::{LaunchAsync, LaunchConfig};
+use std::sync::Arc;
+
+const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/randperm.ptx"));
+const MOD: &str = "randperm";
+const FNS: &[&str] = &["randperm_keys", "randperm_bitonic_step", "randperm_take"];
+
+impl super::RandpermKernel for Cuda {
+    fn forward(&self, seed: u64, n: usize) -> Result<Self::Storage<(usize,), usize>, Self::Err> {
+        if !self.dev.has_func(MOD, FNS[0]) {
+            self.dev.load_ptx(PTX_SRC.into(), MOD, FNS)?;
+        }
+
+        let shape = (n,);
+        let mut storage = self.dev.alloc_zeros_async::<usize>(n)?;
+
+        if n > 0 {
+            let padded_n = n.next_power_of_two();
+            let mut keys = unsafe { self.dev.alloc_async::<u64>(padded_n) }?;
+            let mut idx = unsafe { self.dev.alloc_async::<usize>(padded_n) }?;
+            let cfg = LaunchConfig::for_num_elems(padded_n as u32);
+
+            let keys_fn = self.dev.get_func(MOD, FNS[0]).unwrap();
+            let params = (
+                n,         // const size_t n,
+                padded_n,  // const size_t padded_n,
+                seed,      // const unsigned long long seed,
+                &mut keys, // unsigned long long *keys,
+                &mut idx,  // size_t *idx
+            );
+            unsafe { keys_fn.launch_async(cfg, params) }?;
+
+            let mut k = 2;
+            while k <= padded_n {
+                let mut j = k / 2;
+                while j > 0 {
+                    let step_fn = self.dev.get_func(MOD, FNS[1]).unwrap();
+                    let params = (
+                        padded_n,  // const size_t padded_n,
+                        j,         // const size_t j,
+                        k,         // const size_t k,
+                        &mut keys, // unsigned long long *keys,
+                        &mut idx,  // size_t *idx
+                    );
+                    unsafe { step_fn.launch_async(cfg, params) }?;
+                    j /= 2;
+                }
+                k *= 2;
+            }
+
+            let take_fn = self.dev.get_func(MOD, FNS[2]).unwrap();
+            let cfg = LaunchConfig::for_num_elems(n as u32);
+            let params = (
+                n,            // const size_t n,
+                &idx,         // const size_t *idx,
+                &mut storage, // size_t *out
+            );
+            unsafe { take_fn.launch_async(cfg, params) }?;
+        }
+
+        Ok(CudaArray {
+            data: Arc::new(storage),
+            shape,
+            strides: [1],
+        })
+    }
+}
@@ -0,0 +1,86 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::tensor::*;
+
+pub trait RandpermKernel: DeviceStorage {
+    /// Sorts `0..n` by random keys, one stream of `seed` per element, so every
+    /// device produces the same permutation for the same seed.
+    fn forward(&self, seed: u64, n: usize) -> Result<Self::Storage<(usize,), usize>, Self::Err>;
+}
+
+/// Constructs random permutations on the device.
+pub trait RandpermTensor: RandpermKernel {
+    /// Returns a random permutation of `0..n`. The permutation is generated on
+    /// the device, so it can be used directly to shuffle or gather tensors.
+    ///
+    /// **Pytorch equivalent**: `torch.randperm(n)`
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let perm = dev.randperm(5);
+    /// let mut sorted = perm.as_vec();
+    /// sorted.sort();
+    /// assert_eq!(sorted, [0, 1, 2, 3, 4]);
+    ///
+    /// // shuffle the rows of a batch
+    /// let batch: Tensor<(usize, Const<3>), f32, _> = dev.zeros_like(&(5, Const));
+    /// let shuffled: Tensor<(usize, Const<3>), f32, _> = batch.gather(perm);
+    /// ```
+    fn randperm(&self, n: usize) -> Tensor<(usize,), usize, Self> {
+        self.try_randperm(n).unwrap()
+    }
+
+    /// Fallible version of [RandpermTensor::randperm]
+    fn try_randperm(&self, n: usize) -> Result<Tensor<(usize,), usize, Self>, Self::Err> {
+        let seed = self.random_u64();
+        let storage = self.forward(seed, n)?;
+        Ok(self.upgrade(storage))
+    }
+}
+
+impl<D: RandpermKernel> RandpermTensor for D {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{shapes::*, tests::*};
+
+    #[test]
+    fn test_randperm_is_permutation() {
+        let dev: TestDevice = Default::default();
+        assert_eq!(dev.randperm(0).shape(), &(0,));
+        for n in [1, 2, 17, 100] {
+            let perm = dev.randperm(n);
+            assert_eq!(perm.shape(), &(n,));
+            let mut sorted = perm.as_vec();
+            sorted.sort_unstable();
+            assert_eq!(sorted, (0..n).collect::<std::vec::Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn test_randperm_shuffles() {
+        let dev: TestDevice = Default::default();
+        let a = dev.randperm(50).as_vec();
+        let b = dev.randperm(50).as_vec();
+        assert_ne!(a, b);
+        assert_ne!(a, (0..50).collect::<std::vec::Vec<_>>());
+    }
+
+    #[test]
+    fn test_randperm_first_element_uniform() {
+        let dev: TestDevice = Default::default();
+        let mut counts = [0; 4];
+        for _ in 0..4000 {
+            counts[dev.randperm(4).as_vec()[0]] += 1;
+        }
+        assert!(
+            counts.iter().all(|&c| (850..1150).contains(&c)),
+            "{counts:?}"
+        );
+    }
+}
@@ -0,0 +1,65 @@
+#include "cuda_utils.cuh"
+
+// Every element gets a random key from its own stream of `seed`, and the
+// permutation is the indices sorted by (key, index), which is the same
+// permutation the cpu produces for the same seed. The keys are padded to a power
+// of two for the bitonic sort, and the padding sorts after every real element.
+extern "C" __global__ void randperm_keys(
+    const size_t n,
+    const size_t padded_n,
+    const unsigned long long seed,
+    unsigned long long *keys,
+    size_t *idx
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= padded_n) {
+        return;
+    }
+
+    unsigned long long state = rng_init(seed, i);
+    keys[i] = i < n ? rng_next_u64(&state) : 0xFFFFFFFFFFFFFFFFull;
+    idx[i] = i;
+}
+
+// One compare and swap step of a bitonic sort, `k` is the size of the
+// sequences being merged and `j` the distance between compared elements.
+extern "C" __global__ void randperm_bitonic_step(
+    const size_t padded_n,
+    const size_t j,
+    const size_t k,
+    unsigned long long *keys,
+    size_t *idx
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= padded_n) {
+        return;
+    }
+
+    const size_t l = i ^ j;
+    if (l <= i) {
+        return;
+    }
+
+    const bool ascending = (i & k) == 0;
+    const bool greater = keys[i] > keys[l] || (keys[i] == keys[l] && idx[i] > idx[l]);
+    if (greater == ascending) {
+        const unsigned long long tmp_key = keys[i];
+        keys[i] = keys[l];
+        keys[l] = tmp_key;
+        const size_t tmp_idx = idx[i];
+        idx[i] = idx[l];
+        idx[l] = tmp_idx;
+    }
+}
+
+extern "C" __global__ void randperm_take(
+    const size_t n,
+    const size_t *idx,
+    size_t *out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= n) {
+        return;
+    }
+    out[i] = idx[i];
+}
@@ -0,0 +1,48 @@
+use crate::{
+    shapes::{Dtype, Shape},
+    tensor::cpu::{Cpu, LendingIterator, StridedArray},
+};
+
+/// Converts an index into the output to the index of the input element it copies.
+fn inp_index<Src: Shape, Dst: Shape>(src: Src, i_out: Dst::Concrete) -> Src::Concrete {
+    let dims = src.concrete();
+    let mut i_inp: Src::Concrete = Default::default();
+    for j in 0..Src::NUM_DIMS {
+        i_inp[j] = i_out[j] % dims[j];
+    }
+    i_inp
+}
+
+impl<E: Dtype> super::RepeatKernel<E> for Cpu {
+    fn forward<Src: Shape, Dst: Shape>(
+        &self,
+        dst: Dst,
+        inp: &Self::Storage<Src, E>,
+    ) -> Result<Self::Storage<Dst, E>, Self::Err> {
+        let mut out: Self::Storage<Dst, E> = StridedArray::new(dst)?;
+        if dst.num_elements() == 0 {
+            return Ok(out);
+        }
+        let mut out_iter = out.iter_mut_with_index();
+        while let Some((x, i_out)) = out_iter.next() {
+            *x = inp[inp_index::<Src, Dst>(inp.shape, i_out)];
+        }
+        Ok(out)
+    }
+
+    fn backward<Src: Shape, Dst: Shape>(
+        &self,
+        grad_inp: &mut Self::Storage<Src, E>,
+        grad_out: &Self::Storage<Dst, E>,
+    ) -> Result<(), Self::Err> {
+        if grad_out.shape.num_elements() == 0 {
+            return Ok(());
+        }
+        let src = grad_inp.shape;
+        let mut out_iter = grad_out.iter_with_index();
+        while let Some((g, i_out)) = out_iter.next() {
+            grad_inp[inp_index::<Src, Dst>(src, i_out)] += *g;
+        }
+        Ok(())
+    }
+}
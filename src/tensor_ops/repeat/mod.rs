@@ -0,0 +1,153 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{gradients::Tape, shapes::*, tensor::*};
+
+pub trait RepeatKernel<E: Dtype>: DeviceStorage {
+    fn forward<Src: Shape, Dst: Shape>(
+        &self,
+        dst: Dst,
+        inp: &Self::Storage<Src, E>,
+    ) -> Result<Self::Storage<Dst, E>, Self::Err>;
+    fn backward<Src: Shape, Dst: Shape>(
+        &self,
+        grad_inp: &mut Self::Storage<Src, E>,
+        grad_out: &Self::Storage<Dst, E>,
+    ) -> Result<(), Self::Err>;
+}
+
+/// Tiles a tensor along its existing axes. Unlike broadcasting, any axis can be
+/// repeated, and the result is materialized.
+///
+/// Each dimension of `Dst` must be a multiple of the same dimension of the input;
+/// the ratio is the number of times the tensor is repeated along that axis. The gradient
+/// of each element is the sum of the gradients of its copies.
+///
+/// **Pytorch equivalent**: `t.repeat(*factors)`
+pub trait TryRepeat: HasErr + HasShape {
+    /// Repeats into the const shape `Dst`:
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t = dev.tensor([[1.0, 2.0], [3.0, 4.0]]);
+    /// let r = t.repeat::<Rank2<4, 4>>();
+    /// assert_eq!(
+    ///     r.array(),
+    ///     [
+    ///         [1.0, 2.0, 1.0, 2.0],
+    ///         [3.0, 4.0, 3.0, 4.0],
+    ///         [1.0, 2.0, 1.0, 2.0],
+    ///         [3.0, 4.0, 3.0, 4.0],
+    ///     ]
+    /// );
+    /// ```
+    fn repeat<Dst: ConstShape>(self) -> Self::WithShape<Dst> {
+        self.try_repeat_like(&Default::default()).unwrap()
+    }
+    /// Fallible version of [TryRepeat::repeat]
+    fn try_repeat<Dst: ConstShape>(self) -> Result<Self::WithShape<Dst>, Self::Err> {
+        self.try_repeat_like(&Default::default())
+    }
+    /// Same as [TryRepeat::repeat], but the target shape is given, so the factors
+    /// can be known only at runtime:
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t = dev.tensor([1.0, 2.0]);
+    /// let factor = 3;
+    /// let r = t.repeat_like(&(2 * factor,));
+    /// assert_eq!(r.as_vec(), [1.0, 2.0, 1.0, 2.0, 1.0, 2.0]);
+    /// ```
+    fn repeat_like<Dst: Shape>(self, dst: &Dst) -> Self::WithShape<Dst> {
+        self.try_repeat_like(dst).unwrap()
+    }
+    /// Fallible version of [TryRepeat::repeat_like]
+    fn try_repeat_like<Dst: Shape>(self, dst: &Dst) -> Result<Self::WithShape<Dst>, Self::Err>;
+}
+
+impl<S: Shape, E: Dtype, D: RepeatKernel<E>, T: Tape<D>> TryRepeat for Tensor<S, E, D, T> {
+    fn try_repeat_like<Dst: Shape>(self, dst: &Dst) -> Result<Self::WithShape<Dst>, Self::Err> {
+        assert_eq!(S::NUM_DIMS, Dst::NUM_DIMS);
+        let src_dims = self.shape().concrete();
+        let dst_dims = dst.concrete();
+        for i in 0..S::NUM_DIMS {
+            // a size of 0 can only be repeated into a size of 0
+            let (s, d) = (src_dims[i], dst_dims[i]);
+            assert!(
+                d.checked_rem(s).map_or(d == 0, |r| r == 0),
+                "dimension {i} is not a multiple of the input's"
+            );
+        }
+
+        let (inp, mut tape) = self.split_tape();
+        let out = inp.device.upgrade(inp.device.forward(*dst, &inp.storage)?);
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&inp)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+            inp.device.backward(grad_inp, grad_out)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_repeat_1d() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([1.0, 2.0]);
+        let r = t.trace().repeat::<Rank1<6>>();
+        assert_eq!(r.array(), [1.0, 2.0, 1.0, 2.0, 1.0, 2.0]);
+        let g = (r * dev.tensor([1.0, 2.0, 3.0, 4.0, 5.0, 6.0]))
+            .sum()
+            .backward();
+        assert_eq!(g.get(&t).array(), [9.0, 12.0]);
+    }
+
+    #[test]
+    fn test_repeat_2d() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([[1.0, 2.0, 3.0]]);
+        let r = t.trace().repeat::<Rank2<2, 6>>();
+        assert_eq!(
+            r.array(),
+            [
+                [1.0, 2.0, 3.0, 1.0, 2.0, 3.0],
+                [1.0, 2.0, 3.0, 1.0, 2.0, 3.0]
+            ]
+        );
+        let g = r.exp().sum().backward();
+        assert_close(
+            &g.get(&t).array(),
+            &[[1.0, 2.0, 3.0].map(|x: TestDtype| 4.0 * x.exp())],
+        );
+    }
+
+    #[test]
+    fn test_repeat_like_runtime() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([[1.0, 2.0], [3.0, 4.0]]);
+        let r = t
+            .trace()
+            .permute::<_, Axes2<1, 0>>()
+            .repeat_like(&(4, Const::<2>));
+        assert_eq!(r.as_vec(), [1.0, 3.0, 2.0, 4.0, 1.0, 3.0, 2.0, 4.0]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&t).array(), [[2.0; 2]; 2]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_repeat_not_a_multiple() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank1<2>, TestDtype, _> = dev.zeros();
+        let _ = t.repeat::<Rank1<5>>();
+    }
+}
@@ -0,0 +1,82 @@
+#include "cuda_utils.cuh"
+
+// Converts the index of an element of the output (in a contiguous layout) into
+// the physical index of the element of the input it copies.
+__device__ unsigned int get_tiled_index(
+    unsigned int out_i,
+    const size_t num_dims,
+    const size_t *out_dims,
+    const size_t *inp_dims,
+    const size_t *inp_strides
+) {
+    unsigned int inp_i = 0;
+    for (int d = num_dims - 1; d >= 0; d--) {
+        inp_i += ((out_i % out_dims[d]) % inp_dims[d]) * inp_strides[d];
+        out_i /= out_dims[d];
+    }
+    return inp_i;
+}
+
+template<typename T>
+__device__ void repeat_fwd(
+    const size_t numel,
+    const size_t num_dims,
+    const size_t *out_dims,
+    const size_t *inp_dims,
+    const T *inp,
+    const size_t *inp_strides,
+    T *out
+) {
+    unsigned int out_i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (out_i >= numel) {
+        return;
+    }
+
+    out[out_i] = inp[get_tiled_index(out_i, num_dims, out_dims, inp_dims, inp_strides)];
+}
+
+template<typename T>
+__device__ void repeat_bwd(
+    const size_t numel,
+    const size_t num_dims,
+    const size_t *out_dims,
+    const size_t *inp_dims,
+    T *grad_inp,
+    const size_t *inp_strides,
+    const T *grad_out
+) {
+    unsigned int out_i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (out_i >= numel) {
+        return;
+    }
+
+    unsigned int inp_i = get_tiled_index(out_i, num_dims, out_dims, inp_dims, inp_strides);
+    atomicAdd(grad_inp + inp_i, grad_out[out_i]);
+}
+
+#define REPEAT_OP(TYPENAME, FWD, BWD) \
+extern "C" __global__ void FWD( \
+    const size_t numel, \
+    const size_t num_dims, \
+    const size_t *out_dims, \
+    const size_t *inp_dims, \
+    const TYPENAME *inp, \
+    const size_t *inp_strides, \
+    TYPENAME *out \
+) { \
+    repeat_fwd(numel, num_dims, out_dims, inp_dims, inp, inp_strides, out); \
+} \
+extern "C" __global__ void BWD( \
+    const size_t numel, \
+    const size_t num_dims, \
+    const size_t *out_dims, \
+    const size_t *inp_dims, \
+    TYPENAME *grad_inp, \
+    const size_t *inp_strides, \
+    const TYPENAME *grad_out \
+) { \
+    repeat_bwd(numel, num_dims, out_dims, inp_dims, grad_inp, inp_strides, grad_out); \
+}
+
+REPEAT_OP(float, repeat_fwd_f32, repeat_bwd_f32);
+REPEAT_OP(double, repeat_fwd_f64, repeat_bwd_f64);
@@ -0,0 +1,155 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{gradients::Tape, shapes::*, tensor::*};
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct RepeatInterleaveOp {
+    /// The axis to repeat elements along
+    pub axis: usize,
+    /// The number of times each element is repeated
+    pub repeats: usize,
+}
+
+pub trait RepeatInterleaveKernel<E: Dtype>: DeviceStorage {
+    fn forward<Src: Shape, Dst: Shape>(
+        &self,
+        op: RepeatInterleaveOp,
+        dst: Dst,
+        inp: &Self::Storage<Src, E>,
+    ) -> Result<Self::Storage<Dst, E>, Self::Err>;
+    fn backward<Src: Shape, Dst: Shape>(
+        &self,
+        op: RepeatInterleaveOp,
+        grad_inp: &mut Self::Storage<Src, E>,
+        grad_out: &Self::Storage<Dst, E>,
+    ) -> Result<(), Self::Err>;
+}
+
+/// Repeats each element `n` times along `Ax`. Unlike broadcasting, which repeats
+/// the whole tensor, the repeats of an element are next to each other.
+///
+/// The size of `Ax` in `Dst` must be `n` times the size of `Ax` in the input, and
+/// all other dimensions must be the same. The gradient of each element is the sum of
+/// the gradients of its repeats.
+///
+/// **Pytorch equivalent**: `t.repeat_interleave(n, dim=Ax)`
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([[1.0, 2.0], [3.0, 4.0]]);
+/// let r: Tensor<Rank2<2, 4>, f32, _> = t.clone().repeat_interleave::<_, Axis<1>>(2);
+/// assert_eq!(r.array(), [[1.0, 1.0, 2.0, 2.0], [3.0, 3.0, 4.0, 4.0]]);
+///
+/// // the repeated axis can also have a runtime size
+/// let r: Tensor<(usize, Const<2>), f32, _> = t.repeat_interleave::<_, Axis<0>>(3);
+/// assert_eq!(r.shape(), &(6, Const));
+/// ```
+pub trait TryRepeatInterleave: HasErr + HasShape {
+    fn repeat_interleave<Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        self,
+        n: usize,
+    ) -> Self::WithShape<Dst>
+    where
+        Self::Shape: HasAxes<Ax>,
+    {
+        self.try_repeat_interleave::<Dst, Ax>(n).unwrap()
+    }
+    fn try_repeat_interleave<Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        self,
+        n: usize,
+    ) -> Result<Self::WithShape<Dst>, Self::Err>
+    where
+        Self::Shape: HasAxes<Ax>;
+}
+
+impl<S: Shape, E: Dtype, D: RepeatInterleaveKernel<E>, T: Tape<D>> TryRepeatInterleave
+    for Tensor<S, E, D, T>
+{
+    fn try_repeat_interleave<Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        self,
+        n: usize,
+    ) -> Result<Self::WithShape<Dst>, Self::Err>
+    where
+        Self::Shape: HasAxes<Ax>,
+    {
+        assert_eq!(S::NUM_DIMS, Dst::NUM_DIMS);
+        let op = RepeatInterleaveOp {
+            axis: Ax::as_array()[0] as usize,
+            repeats: n,
+        };
+        let src_dims = self.shape().concrete();
+        let mut dst_dims: Dst::Concrete = Default::default();
+        for i in 0..S::NUM_DIMS {
+            dst_dims[i] = src_dims[i];
+        }
+        dst_dims[op.axis] *= n;
+        let dst = Dst::from_concrete(&dst_dims).unwrap();
+
+        let (inp, mut tape) = self.split_tape();
+        let out = inp
+            .device
+            .upgrade(inp.device.forward(op, dst, &inp.storage)?);
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&inp)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+            inp.device.backward(op, grad_inp, grad_out)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_repeat_interleave_1d() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([1.0, 2.0, 3.0]);
+        let r: Tensor<Rank1<6>, _, _, _> = t.trace().repeat_interleave::<_, Axis<0>>(2);
+        assert_eq!(r.array(), [1.0, 1.0, 2.0, 2.0, 3.0, 3.0]);
+        let g = (r * dev.tensor([1.0, 2.0, 3.0, 4.0, 5.0, 6.0]))
+            .sum()
+            .backward();
+        assert_eq!(g.get(&t).array(), [3.0, 7.0, 11.0]);
+    }
+
+    #[test]
+    fn test_repeat_interleave_3d() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([[[1.0, 2.0]], [[3.0, 4.0]]]);
+        let r: Tensor<Rank3<2, 3, 2>, _, _, _> = t.trace().repeat_interleave::<_, Axis<1>>(3);
+        assert_eq!(r.array(), [[[1.0, 2.0]; 3], [[3.0, 4.0]; 3]]);
+        let g = r.exp().sum().backward();
+        assert_close(
+            &g.get(&t).array(),
+            &[[[1.0, 2.0]], [[3.0, 4.0]]].map(|a| a.map(|b| b.map(|x: TestDtype| 3.0 * x.exp()))),
+        );
+    }
+
+    #[test]
+    fn test_repeat_interleave_permuted() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let r: Tensor<(Const<3>, usize), _, _, _> = t
+            .trace()
+            .permute::<_, Axes2<1, 0>>()
+            .repeat_interleave::<_, Axis<1>>(2);
+        assert_eq!(r.shape(), &(Const, 4));
+        assert_eq!(
+            r.as_vec(),
+            [1.0, 1.0, 4.0, 4.0, 2.0, 2.0, 5.0, 5.0, 3.0, 3.0, 6.0, 6.0]
+        );
+        let g = r.sum().backward();
+        assert_eq!(g.get(&t).array(), [[2.0; 3]; 2]);
+    }
+}
@@ -0,0 +1,90 @@
+#include "cuda_utils.cuh"
+
+struct RepeatInterleaveOp {
+    size_t axis;
+    size_t repeats;
+};
+
+// Converts the index of an element of the output (in a contiguous layout) into
+// the index of the element of the input (in a contiguous layout) it repeats.
+__device__ unsigned int get_repeated_index(
+    const RepeatInterleaveOp op,
+    const unsigned int out_i,
+    const size_t num_dims,
+    const size_t *inp_dims
+) {
+    size_t inner = 1;
+    for (size_t d = op.axis + 1; d < num_dims; d++) {
+        inner *= inp_dims[d];
+    }
+    size_t out_axis = inp_dims[op.axis] * op.repeats;
+    size_t i_inner = out_i % inner;
+    size_t i_axis = (out_i / inner) % out_axis;
+    size_t i_outer = out_i / (inner * out_axis);
+    return (i_outer * inp_dims[op.axis] + i_axis / op.repeats) * inner + i_inner;
+}
+
+template<typename T>
+__device__ void repeat_interleave_fwd(
+    const RepeatInterleaveOp op,
+    const size_t numel,
+    const size_t num_dims,
+    const size_t *inp_dims,
+    const T *inp,
+    const size_t *inp_strides,
+    T *out
+) {
+    unsigned int out_i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (out_i >= numel) {
+        return;
+    }
+
+    unsigned int inp_i = get_repeated_index(op, out_i, num_dims, inp_dims);
+    out[out_i] = inp[get_strided_index(inp_i, num_dims, inp_dims, inp_strides)];
+}
+
+template<typename T>
+__device__ void repeat_interleave_bwd(
+    const RepeatInterleaveOp op,
+    const size_t numel,
+    const size_t num_dims,
+    const size_t *inp_dims,
+    T *grad_inp,
+    const size_t *inp_strides,
+    const T *grad_out
+) {
+    unsigned int out_i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (out_i >= numel) {
+        return;
+    }
+
+    unsigned int inp_i = get_repeated_index(op, out_i, num_dims, inp_dims);
+    atomicAdd(grad_inp + get_strided_index(inp_i, num_dims, inp_dims, inp_strides), grad_out[out_i]);
+}
+
+#define REPEAT_INTERLEAVE_OP(TYPENAME, FWD, BWD) \
+extern "C" __global__ void FWD( \
+    const RepeatInterleaveOp op, \
+    const size_t numel, \
+    const size_t num_dims, \
+    const size_t *inp_dims, \
+    const TYPENAME *inp, \
+    const size_t *inp_strides, \
+    TYPENAME *out \
+) { \
+    repeat_interleave_fwd(op, numel, num_dims, inp_dims, inp, inp_strides, out); \
+} \
+extern "C" __global__ void BWD( \
+    const RepeatInterleaveOp op, \
+    const size_t numel, \
+    const size_t num_dims, \
+    const size_t *inp_dims, \
+    TYPENAME *grad_inp, \
+    const size_t *inp_strides, \
+    const TYPENAME *grad_out \
+) { \
+    repeat_interleave_bwd(op, numel, num_dims, inp_dims, grad_inp, inp_strides, grad_out); \
+}
+
+REPEAT_INTERLEAVE_OP(float, repeat_interleave_fwd_f32, repeat_interleave_bwd_f32);
+REPEAT_INTERLEAVE_OP(double, repeat_interleave_fwd_f64, repeat_interleave_bwd_f64);
@@ -0,0 +1,36 @@
+use crate::shapes::{Dtype, Shape};
+use crate::tensor::cpu::{Cpu, LendingIterator, StridedArray};
+
+use super::{RollKernel, RollOp};
+
+impl<E: Dtype> RollKernel<E> for Cpu {
+    fn forward<S: Shape>(
+        &self,
+        op: RollOp,
+        inp: &Self::Storage<S, E>,
+    ) -> Result<Self::Storage<S, E>, Self::Err> {
+        let size = inp.shape.concrete()[op.axis];
+        let mut out = StridedArray::new(inp.shape)?;
+        let mut out_iter = out.iter_mut_with_index();
+        while let Some((o, mut i)) = out_iter.next() {
+            i[op.axis] = (i[op.axis] + size - op.shift) % size;
+            *o = inp[i];
+        }
+        Ok(out)
+    }
+
+    fn backward<S: Shape>(
+        &self,
+        op: RollOp,
+        grad_inp: &mut Self::Storage<S, E>,
+        grad_out: &Self::Storage<S, E>,
+    ) -> Result<(), Self::Err> {
+        let size = grad_out.shape.concrete()[op.axis];
+        let mut out_iter = grad_out.iter_with_index();
+        while let Some((o, mut i)) = out_iter.next() {
+            i[op.axis] = (i[op.axis] + size - op.shift) % size;
+            grad_inp[i] += *o;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,152 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{gradients::Tape, shapes::*, tensor::*};
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct RollOp {
+    /// The axis to roll along
+    pub axis: usize,
+    /// How far to move elements towards the end of the axis, always less than the size
+    /// of the axis.
+    pub shift: usize,
+}
+
+pub trait RollKernel<E: Dtype>: DeviceStorage {
+    fn forward<S: Shape>(
+        &self,
+        op: RollOp,
+        inp: &Self::Storage<S, E>,
+    ) -> Result<Self::Storage<S, E>, Self::Err>;
+    fn backward<S: Shape>(
+        &self,
+        op: RollOp,
+        grad_inp: &mut Self::Storage<S, E>,
+        grad_out: &Self::Storage<S, E>,
+    ) -> Result<(), Self::Err>;
+}
+
+/// Circularly shifts the elements of a tensor along `Ax`. Elements that move past the
+/// end of the axis wrap around to the start. Negative shifts move elements towards the
+/// start of the axis instead.
+///
+/// **Pytorch equivalent**: `t.roll(shift, dims=Ax)`
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+/// let r = t.clone().roll::<Axis<1>>(1);
+/// assert_eq!(r.array(), [[3.0, 1.0, 2.0], [6.0, 4.0, 5.0]]);
+/// let r = t.roll::<Axis<1>>(-1);
+/// assert_eq!(r.array(), [[2.0, 3.0, 1.0], [5.0, 6.0, 4.0]]);
+/// ```
+pub trait TryRoll: HasErr + HasShape {
+    fn roll<Ax: Axes<Array = [isize; 1]>>(self, shift: isize) -> Self
+    where
+        Self::Shape: HasAxes<Ax>,
+    {
+        self.try_roll::<Ax>(shift).unwrap()
+    }
+    fn try_roll<Ax: Axes<Array = [isize; 1]>>(self, shift: isize) -> Result<Self, Self::Err>
+    where
+        Self::Shape: HasAxes<Ax>;
+}
+
+impl<S: Shape, E: Dtype, D: RollKernel<E>, T: Tape<D>> TryRoll for Tensor<S, E, D, T> {
+    fn try_roll<Ax: Axes<Array = [isize; 1]>>(self, shift: isize) -> Result<Self, Self::Err>
+    where
+        Self::Shape: HasAxes<Ax>,
+    {
+        let size = HasAxes::<Ax>::size(self.shape());
+        let op = RollOp {
+            axis: Ax::as_array()[0] as usize,
+            shift: if size == 0 {
+                0
+            } else {
+                shift.rem_euclid(size as isize) as usize
+            },
+        };
+        if op.shift == 0 {
+            return Ok(self);
+        }
+        let (inp, mut tape) = self.split_tape();
+        let out = inp.device.upgrade(inp.device.forward(op, &inp.storage)?);
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&inp)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+            inp.device.backward(op, grad_inp, grad_out)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_roll_1d() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([1.0, 2.0, 3.0, 4.0, 5.0]);
+        assert_eq!(
+            t.clone().roll::<Axis<0>>(2).array(),
+            [4.0, 5.0, 1.0, 2.0, 3.0]
+        );
+        assert_eq!(
+            t.clone().roll::<Axis<0>>(-2).array(),
+            [3.0, 4.0, 5.0, 1.0, 2.0]
+        );
+        assert_eq!(
+            t.clone().roll::<Axis<0>>(7).array(),
+            [4.0, 5.0, 1.0, 2.0, 3.0]
+        );
+        assert_eq!(t.clone().roll::<Axis<0>>(5).array(), t.array());
+    }
+
+    #[test]
+    fn test_roll_0d() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor(2.0);
+        let r = t.trace().roll::<Axis<0>>(3);
+        assert_eq!(r.array(), 2.0);
+        let g = r.exp().backward();
+        assert_eq!(g.get(&t).array(), TestDtype::exp(2.0));
+    }
+
+    #[test]
+    fn test_roll_3d_backward() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank3<2, 3, 4>, TestDtype, _> = dev.sample_normal();
+        let r = t.trace().roll::<Axis<1>>(1);
+        let t_arr = t.array();
+        let r_arr = r.array();
+        for i in 0..2 {
+            for j in 0..3 {
+                assert_eq!(r_arr[i][(j + 1) % 3], t_arr[i][j]);
+            }
+        }
+        let w: Tensor<Rank3<2, 3, 4>, TestDtype, _> = dev.sample_normal();
+        let g = (r * w.clone()).sum().backward();
+        assert_eq!(g.get(&t).array(), w.roll::<Axis<1>>(-1).array());
+    }
+
+    #[test]
+    fn test_roll_broadcasted() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([1.0, 2.0, 3.0]);
+        let r = t.trace().broadcast::<Rank2<2, 3>, _>().roll::<Axis<1>>(1);
+        assert_eq!(r.array(), [[3.0, 1.0, 2.0]; 2]);
+        let g = (r * dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]))
+            .sum()
+            .backward();
+        assert_eq!(g.get(&t).array(), [7.0, 9.0, 5.0]);
+    }
+}
@@ -0,0 +1,90 @@
+#include "cuda_utils.cuh"
+
+struct RollOp {
+    size_t axis;
+    size_t shift;
+};
+
+// Computes the index into a strided input for the i'th element of the contiguous
+// output, after undoing the roll along op.axis.
+__device__ unsigned int get_rolled_index(
+    const RollOp op,
+    unsigned int i,
+    const size_t num_dims,
+    const size_t *dims,
+    const size_t *strides
+) {
+    unsigned int inp_i = 0;
+    for (int d = num_dims - 1; d >= 0; d--) {
+        size_t i_dim = i % dims[d];
+        if (d == op.axis) {
+            i_dim = (i_dim + dims[d] - op.shift) % dims[d];
+        }
+        inp_i += i_dim * strides[d];
+        i /= dims[d];
+    }
+    return inp_i;
+}
+
+template<typename T>
+__device__ void roll_fwd(
+    const RollOp op,
+    const size_t numel,
+    const size_t num_dims,
+    const size_t *dims,
+    const size_t *inp_strides,
+    const T *inp,
+    T *out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    out[i] = inp[get_rolled_index(op, i, num_dims, dims, inp_strides)];
+}
+
+template<typename T>
+__device__ void roll_bwd(
+    const RollOp op,
+    const size_t numel,
+    const size_t num_dims,
+    const size_t *dims,
+    const size_t *inp_strides,
+    T *grad_inp,
+    const T *grad_out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    atomicAdd(grad_inp + get_rolled_index(op, i, num_dims, dims, inp_strides), grad_out[i]);
+}
+
+#define ROLL_OP(TYPENAME, FWD, BWD) \
+extern "C" __global__ void FWD( \
+    const RollOp op, \
+    const size_t numel, \
+    const size_t num_dims, \
+    const size_t *dims, \
+    const size_t *inp_strides, \
+    const TYPENAME *inp, \
+    TYPENAME *out \
+) { \
+    roll_fwd(op, numel, num_dims, dims, inp_strides, inp, out); \
+} \
+extern "C" __global__ void BWD( \
+    const RollOp op, \
+    const size_t numel, \
+    const size_t num_dims, \
+    const size_t *dims, \
+    const size_t *inp_strides, \
+    TYPENAME *grad_inp, \
+    const TYPENAME *grad_out \
+) { \
+    roll_bwd(op, numel, num_dims, dims, inp_strides, grad_inp, grad_out); \
+}
+
+ROLL_OP(float, roll_fwd_f32, roll_bwd_f32);
+ROLL_OP(double, roll_fwd_f64, roll_bwd_f64);
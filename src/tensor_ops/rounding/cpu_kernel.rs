@@ -0,0 +1,68 @@
+use crate::tensor_ops::cpu_kernels::UnaryDerivative;
+use num_traits::Float;
+
+impl<F: Float> UnaryDerivative<F> for super::RoundKernelOp {
+    #[inline(always)]
+    fn f(&self, x: &F) -> F {
+        x.round()
+    }
+    #[inline(always)]
+    fn df(&self, _: &F) -> F {
+        F::zero()
+    }
+}
+
+impl<F: Float> UnaryDerivative<F> for super::CeilKernelOp {
+    #[inline(always)]
+    fn f(&self, x: &F) -> F {
+        x.ceil()
+    }
+    #[inline(always)]
+    fn df(&self, _: &F) -> F {
+        F::zero()
+    }
+}
+
+impl<F: Float> UnaryDerivative<F> for super::FloorKernelOp {
+    #[inline(always)]
+    fn f(&self, x: &F) -> F {
+        x.floor()
+    }
+    #[inline(always)]
+    fn df(&self, _: &F) -> F {
+        F::zero()
+    }
+}
+
+impl<F: Float> UnaryDerivative<F> for super::TruncKernelOp {
+    #[inline(always)]
+    fn f(&self, x: &F) -> F {
+        x.trunc()
+    }
+    #[inline(always)]
+    fn df(&self, _: &F) -> F {
+        F::zero()
+    }
+}
+
+impl<F: Float> UnaryDerivative<F> for super::FracKernelOp {
+    #[inline(always)]
+    fn f(&self, x: &F) -> F {
+        x.fract()
+    }
+    #[inline(always)]
+    fn df(&self, _: &F) -> F {
+        F::one()
+    }
+}
+
+impl<F: Float> UnaryDerivative<F> for super::QuantizeSteKernelOp<F> {
+    #[inline(always)]
+    fn f(&self, &x: &F) -> F {
+        (x * self.levels).round() / self.levels
+    }
+    #[inline(always)]
+    fn df(&self, _: &F) -> F {
+        F::one()
+    }
+}
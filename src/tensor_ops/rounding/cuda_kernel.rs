@@ -0,0 +1,40 @@
+use super::{
+    CeilKernelOp, FloorKernelOp, FracKernelOp, QuantizeSteKernelOp as QuantizeSte, RoundKernelOp,
+    TruncKernelOp,
+};
+use crate::tensor_ops::cuda_kernels::cuda_unary;
+
+unsafe impl cudarc::driver::AsKernelParam for RoundKernelOp {}
+unsafe impl cudarc::driver::AsKernelParam for CeilKernelOp {}
+unsafe impl cudarc::driver::AsKernelParam for FloorKernelOp {}
+unsafe impl cudarc::driver::AsKernelParam for TruncKernelOp {}
+unsafe impl cudarc::driver::AsKernelParam for FracKernelOp {}
+unsafe impl cudarc::driver::AsKernelParam for QuantizeSte<f32> {}
+unsafe impl cudarc::driver::AsKernelParam for QuantizeSte<f64> {}
+
+const PTX: &str = include_str!(concat!(env!("OUT_DIR"), "/rounding.ptx"));
+
+cuda_unary!(RoundKernelOp, f32, PTX, "round_fwd_f32", "round_bwd_f32");
+cuda_unary!(RoundKernelOp, f64, PTX, "round_fwd_f64", "round_bwd_f64");
+cuda_unary!(CeilKernelOp, f32, PTX, "ceil_fwd_f32", "ceil_bwd_f32");
+cuda_unary!(CeilKernelOp, f64, PTX, "ceil_fwd_f64", "ceil_bwd_f64");
+cuda_unary!(FloorKernelOp, f32, PTX, "floor_fwd_f32", "floor_bwd_f32");
+cuda_unary!(FloorKernelOp, f64, PTX, "floor_fwd_f64", "floor_bwd_f64");
+cuda_unary!(TruncKernelOp, f32, PTX, "trunc_fwd_f32", "trunc_bwd_f32");
+cuda_unary!(TruncKernelOp, f64, PTX, "trunc_fwd_f64", "trunc_bwd_f64");
+cuda_unary!(FracKernelOp, f32, PTX, "frac_fwd_f32", "frac_bwd_f32");
+cuda_unary!(FracKernelOp, f64, PTX, "frac_fwd_f64", "frac_bwd_f64");
+cuda_unary!(
+    QuantizeSte<f32>,
+    f32,
+    PTX,
+    "quantize_ste_fwd_f32",
+    "quantize_ste_bwd_f32"
+);
+cuda_unary!(
+    QuantizeSte<f64>,
+    f64,
+    PTX,
+    "quantize_ste_fwd_f64",
+    "quantize_ste_bwd_f64"
+);
@@ -0,0 +1,304 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use super::ops::{try_unary_op, UnaryKernel};
+use crate::{gradients::Tape, shapes::*, tensor::Tensor};
+
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct RoundKernelOp;
+
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct CeilKernelOp;
+
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct FloorKernelOp;
+
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct TruncKernelOp;
+
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct FracKernelOp;
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct QuantizeSteKernelOp<E> {
+    pub levels: E,
+}
+
+/// Rounds `t` to the nearest integer, rounding half-way cases away from `0.0`.
+///
+/// Note that pytorch rounds half-way cases to the nearest even integer instead.
+///
+/// The derivative is `0` everywhere, so no gradient flows through this op.
+///
+/// **Pytorch equivalent**: `torch.round(t)`
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([-1.5, -0.5, 0.4, 0.5, 2.7]);
+/// let r = t.round();
+/// assert_eq!(r.array(), [-2.0, -1.0, 0.0, 1.0, 3.0]);
+/// ```
+pub fn round<S: Shape, E: Dtype, D: UnaryKernel<RoundKernelOp, E>, T: Tape<D>>(
+    t: Tensor<S, E, D, T>,
+) -> Tensor<S, E, D, T> {
+    t.round()
+}
+
+/// Rounds `t` up to the smallest integer greater than or equal to it.
+///
+/// The derivative is `0` everywhere, so no gradient flows through this op.
+///
+/// **Pytorch equivalent**: `torch.ceil(t)`
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([-1.5, -0.5, 0.4, 0.5, 2.7]);
+/// let r = t.ceil();
+/// assert_eq!(r.array(), [-1.0, -0.0, 1.0, 1.0, 3.0]);
+/// ```
+pub fn ceil<S: Shape, E: Dtype, D: UnaryKernel<CeilKernelOp, E>, T: Tape<D>>(
+    t: Tensor<S, E, D, T>,
+) -> Tensor<S, E, D, T> {
+    t.ceil()
+}
+
+/// Rounds `t` down to the largest integer less than or equal to it.
+///
+/// The derivative is `0` everywhere, so no gradient flows through this op.
+///
+/// **Pytorch equivalent**: `torch.floor(t)`
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([-1.5, -0.5, 0.4, 0.5, 2.7]);
+/// let r = t.floor();
+/// assert_eq!(r.array(), [-2.0, -1.0, 0.0, 0.0, 2.0]);
+/// ```
+pub fn floor<S: Shape, E: Dtype, D: UnaryKernel<FloorKernelOp, E>, T: Tape<D>>(
+    t: Tensor<S, E, D, T>,
+) -> Tensor<S, E, D, T> {
+    t.floor()
+}
+
+/// Rounds `t` towards `0.0`, dropping its fractional part.
+///
+/// The derivative is `0` everywhere, so no gradient flows through this op.
+///
+/// **Pytorch equivalent**: `torch.trunc(t)`
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([-1.5, -0.5, 0.4, 0.5, 2.7]);
+/// let r = t.trunc();
+/// assert_eq!(r.array(), [-1.0, -0.0, 0.0, 0.0, 2.0]);
+/// ```
+pub fn trunc<S: Shape, E: Dtype, D: UnaryKernel<TruncKernelOp, E>, T: Tape<D>>(
+    t: Tensor<S, E, D, T>,
+) -> Tensor<S, E, D, T> {
+    t.trunc()
+}
+
+/// The fractional part of `t`, which is `t - trunc(t)` and has the same sign as `t`.
+///
+/// The derivative is `1` everywhere.
+///
+/// **Pytorch equivalent**: `torch.frac(t)`
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([-1.5, -0.5, 0.25, 2.75]);
+/// let r = t.frac();
+/// assert_eq!(r.array(), [-0.5, -0.5, 0.25, 0.75]);
+/// ```
+pub fn frac<S: Shape, E: Dtype, D: UnaryKernel<FracKernelOp, E>, T: Tape<D>>(
+    t: Tensor<S, E, D, T>,
+) -> Tensor<S, E, D, T> {
+    t.frac()
+}
+
+/// Rounds `t` to the nearest multiple of `1 / levels`, i.e. `round(t * levels) / levels`,
+/// with a [straight-through estimator](https://arxiv.org/abs/1308.3432) gradient.
+///
+/// The forward pass is discrete, but the derivative is treated as `1` everywhere, so
+/// gradients pass through unchanged. This is what quantization aware training and
+/// VQ-VAE codebooks need, where [round()] would block all gradients.
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([-0.8, 0.1, 0.3, 0.9]);
+/// let r = t.quantize_ste(4.0);
+/// assert_eq!(r.array(), [-0.75, 0.0, 0.25, 1.0]);
+/// ```
+pub fn quantize_ste<S: Shape, E: Dtype, D: UnaryKernel<QuantizeSteKernelOp<E>, E>, T: Tape<D>>(
+    t: Tensor<S, E, D, T>,
+    levels: E,
+) -> Tensor<S, E, D, T> {
+    t.quantize_ste(levels)
+}
+
+impl<S: Shape, E: Dtype, D: UnaryKernel<RoundKernelOp, E>, T: Tape<D>> Tensor<S, E, D, T> {
+    /// See [round]
+    pub fn round(self) -> Self {
+        self.try_round().unwrap()
+    }
+    /// See [round]
+    pub fn try_round(self) -> Result<Self, D::Err> {
+        try_unary_op(RoundKernelOp, self)
+    }
+}
+
+impl<S: Shape, E: Dtype, D: UnaryKernel<CeilKernelOp, E>, T: Tape<D>> Tensor<S, E, D, T> {
+    /// See [ceil]
+    pub fn ceil(self) -> Self {
+        self.try_ceil().unwrap()
+    }
+    /// See [ceil]
+    pub fn try_ceil(self) -> Result<Self, D::Err> {
+        try_unary_op(CeilKernelOp, self)
+    }
+}
+
+impl<S: Shape, E: Dtype, D: UnaryKernel<FloorKernelOp, E>, T: Tape<D>> Tensor<S, E, D, T> {
+    /// See [floor]
+    pub fn floor(self) -> Self {
+        self.try_floor().unwrap()
+    }
+    /// See [floor]
+    pub fn try_floor(self) -> Result<Self, D::Err> {
+        try_unary_op(FloorKernelOp, self)
+    }
+}
+
+impl<S: Shape, E: Dtype, D: UnaryKernel<TruncKernelOp, E>, T: Tape<D>> Tensor<S, E, D, T> {
+    /// See [trunc]
+    pub fn trunc(self) -> Self {
+        self.try_trunc().unwrap()
+    }
+    /// See [trunc]
+    pub fn try_trunc(self) -> Result<Self, D::Err> {
+        try_unary_op(TruncKernelOp, self)
+    }
+}
+
+impl<S: Shape, E: Dtype, D: UnaryKernel<FracKernelOp, E>, T: Tape<D>> Tensor<S, E, D, T> {
+    /// See [frac]
+    pub fn frac(self) -> Self {
+        self.try_frac().unwrap()
+    }
+    /// See [frac]
+    pub fn try_frac(self) -> Result<Self, D::Err> {
+        try_unary_op(FracKernelOp, self)
+    }
+}
+
+impl<S: Shape, E: Dtype, D: UnaryKernel<QuantizeSteKernelOp<E>, E>, T: Tape<D>> Tensor<S, E, D, T> {
+    /// See [quantize_ste]
+    pub fn quantize_ste(self, levels: E) -> Self {
+        self.try_quantize_ste(levels).unwrap()
+    }
+    /// See [quantize_ste]
+    pub fn try_quantize_ste(self, levels: E) -> Result<Self, D::Err> {
+        try_unary_op(QuantizeSteKernelOp { levels }, self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{shapes::*, tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_round() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([-2.5, -1.2, -0.5, 0.0, 0.49, 1.5, 3.7]);
+        let r = x.trace().round();
+        assert_eq!(r.array(), [-3.0, -1.0, -1.0, 0.0, 0.0, 2.0, 4.0]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&x).array(), [0.0; 7]);
+    }
+
+    #[test]
+    fn test_ceil() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([-2.5, -1.2, -0.5, 0.0, 0.49, 1.5, 3.0]);
+        let r = x.trace().ceil();
+        assert_eq!(r.array(), [-2.0, -1.0, 0.0, 0.0, 1.0, 2.0, 3.0]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&x).array(), [0.0; 7]);
+    }
+
+    #[test]
+    fn test_floor() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([-2.5, -1.2, -0.5, 0.0, 0.49, 1.5, 3.0]);
+        let r = x.trace().floor();
+        assert_eq!(r.array(), [-3.0, -2.0, -1.0, 0.0, 0.0, 1.0, 3.0]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&x).array(), [0.0; 7]);
+    }
+
+    #[test]
+    fn test_trunc() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([-2.5, -1.2, -0.5, 0.0, 0.49, 1.5, 3.0]);
+        let r = x.trace().trunc();
+        assert_eq!(r.array(), [-2.0, -1.0, 0.0, 0.0, 0.0, 1.0, 3.0]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&x).array(), [0.0; 7]);
+    }
+
+    #[test]
+    fn test_frac() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([-2.5, -1.25, -0.5, 0.0, 0.75, 1.5, 3.0]);
+        let r = x.trace().frac();
+        assert_eq!(r.array(), [-0.5, -0.25, -0.5, 0.0, 0.75, 0.5, 0.0]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&x).array(), [1.0; 7]);
+    }
+
+    #[test]
+    fn test_trunc_plus_frac_is_identity() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank1<8>, TestDtype, _> = dev.sample_normal();
+        let r = x.clone().trunc() + x.clone().frac();
+        assert_eq!(r.array(), x.array());
+    }
+
+    #[test]
+    fn test_quantize_ste() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([-1.3, -0.6, -0.2, 0.0, 0.2, 0.3, 0.9]);
+        let r = x.trace().quantize_ste(2.0);
+        assert_eq!(r.array(), [-1.5, -0.5, -0.0, 0.0, 0.0, 0.5, 1.0]);
+        let w: Tensor<_, TestDtype, _> = dev.tensor([1.0, -2.0, 3.0, -4.0, 5.0, -6.0, 7.0]);
+        let g = (r * w.clone()).sum().backward();
+        assert_eq!(g.get(&x).array(), w.array());
+    }
+
+    #[test]
+    fn test_quantize_ste_one_level_is_round() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank1<8>, TestDtype, _> = dev.sample_normal();
+        assert_eq!(x.clone().quantize_ste(1.0).array(), x.round().array());
+    }
+}
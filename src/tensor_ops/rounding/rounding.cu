@@ -0,0 +1,60 @@
+#include "unary_op_macros.cuh"
+
+struct RoundKernelOp {};
+struct CeilKernelOp {};
+struct FloorKernelOp {};
+struct TruncKernelOp {};
+struct FracKernelOp {};
+
+template<typename F>
+struct QuantizeSteKernelOp {
+    F levels;
+};
+
+UNARY_OP(float, round_fwd_f32, round_bwd_f32, RoundKernelOp,
+        roundf(x),
+        0.0)
+
+UNARY_OP(double, round_fwd_f64, round_bwd_f64, RoundKernelOp,
+        round(x),
+        0.0)
+
+UNARY_OP(float, ceil_fwd_f32, ceil_bwd_f32, CeilKernelOp,
+        ceilf(x),
+        0.0)
+
+UNARY_OP(double, ceil_fwd_f64, ceil_bwd_f64, CeilKernelOp,
+        ceil(x),
+        0.0)
+
+UNARY_OP(float, floor_fwd_f32, floor_bwd_f32, FloorKernelOp,
+        floorf(x),
+        0.0)
+
+UNARY_OP(double, floor_fwd_f64, floor_bwd_f64, FloorKernelOp,
+        floor(x),
+        0.0)
+
+UNARY_OP(float, trunc_fwd_f32, trunc_bwd_f32, TruncKernelOp,
+        truncf(x),
+        0.0)
+
+UNARY_OP(double, trunc_fwd_f64, trunc_bwd_f64, TruncKernelOp,
+        trunc(x),
+        0.0)
+
+UNARY_OP(float, frac_fwd_f32, frac_bwd_f32, FracKernelOp,
+        x - truncf(x),
+        1.0)
+
+UNARY_OP(double, frac_fwd_f64, frac_bwd_f64, FracKernelOp,
+        x - trunc(x),
+        1.0)
+
+UNARY_OP(float, quantize_ste_fwd_f32, quantize_ste_bwd_f32, QuantizeSteKernelOp<float>,
+        roundf(x * op.levels) / op.levels,
+        1.0)
+
+UNARY_OP(double, quantize_ste_fwd_f64, quantize_ste_bwd_f64, QuantizeSteKernelOp<double>,
+        round(x * op.levels) / op.levels,
+        1.0)
@@ -0,0 +1,9 @@
+use super::RSqrtKernelOp;
+use crate::tensor_ops::cuda_kernels::cuda_unary;
+
+unsafe impl cudarc::driver::AsKernelParam for RSqrtKernelOp {}
+
+const PTX: &str = include_str!(concat!(env!("OUT_DIR"), "/rsqrt.ptx"));
+
+cuda_unary!(RSqrtKernelOp, f32, PTX, "rsqrt_fwd_f32", "rsqrt_bwd_f32");
+cuda_unary!(RSqrtKernelOp, f64, PTX, "rsqrt_fwd_f64", "rsqrt_bwd_f64");
@@ -0,0 +1,73 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use super::ops::{try_unary_op, UnaryKernel};
+use crate::{gradients::Tape, shapes::*, tensor::Tensor};
+
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct RSqrtKernelOp;
+
+/// `1 / √t` or `t^-0.5`, in a single kernel instead of [sqrt()](crate::tensor_ops::sqrt)
+/// followed by a reciprocal.
+///
+/// The derivative is `-0.5 / (t ^ 1.5)`.
+///
+/// **Pytorch equivalent**: `t.rsqrt()`
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([0.25, 1.0, 4.0]);
+/// let r = t.rsqrt();
+/// assert_eq!(r.array(), [2.0, 1.0, 0.5]);
+/// ```
+pub fn rsqrt<S: Shape, E: Dtype, D: UnaryKernel<RSqrtKernelOp, E>, T: Tape<D>>(
+    t: Tensor<S, E, D, T>,
+) -> Tensor<S, E, D, T> {
+    t.rsqrt()
+}
+
+impl<S: Shape, E: Dtype, D: UnaryKernel<RSqrtKernelOp, E>, T: Tape<D>> Tensor<S, E, D, T> {
+    /// See [rsqrt]
+    pub fn rsqrt(self) -> Self {
+        self.try_rsqrt().unwrap()
+    }
+    /// See [rsqrt]
+    pub fn try_rsqrt(self) -> Result<Self, D::Err> {
+        try_unary_op(RSqrtKernelOp, self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{shapes::*, tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_rsqrt() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([-1.0, 0.0, 1.0, 4.0]);
+        let r = x.trace().rsqrt();
+        assert!(r.array()[0].is_nan());
+        assert_eq!(r.array()[1..], [TestDtype::INFINITY, 1.0, 0.5]);
+        let g = r.mean().backward();
+        let g = g.get(&x).array();
+        assert!(g[0].is_nan());
+        assert_eq!(g[1..], [TestDtype::NEG_INFINITY, -0.5 / 4.0, -0.0625 / 4.0]);
+    }
+
+    #[test]
+    fn test_rsqrt_matches_powf() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank2<2, 3>, TestDtype, _> = dev.sample_uniform();
+        let r = x.trace().rsqrt();
+        let r2 = x.trace().powf(-0.5);
+        assert_close(&r.array(), &r2.array());
+        let g = r.sum().backward();
+        let g2 = r2.sum().backward();
+        assert_close(&g.get(&x).array(), &g2.get(&x).array());
+    }
+}
@@ -0,0 +1,11 @@
+#include "unary_op_macros.cuh"
+
+struct RSqrtKernelOp {};
+
+UNARY_OP(float, rsqrt_fwd_f32, rsqrt_bwd_f32, RSqrtKernelOp,
+        rsqrtf(x),
+        -0.5 * rsqrtf(x) / x)
+
+UNARY_OP(double, rsqrt_fwd_f64, rsqrt_bwd_f64, RSqrtKernelOp,
+        rsqrt(x),
+        -0.5 * rsqrt(x) / x)
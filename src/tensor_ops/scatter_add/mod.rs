@@ -0,0 +1,248 @@
+use crate::{
+    gradients::{Merge, Tape},
+    shapes::*,
+    tensor::*,
+};
+
+use super::{
+    add::{BinaryAddKernelOp, TryAdd},
+    ops::BinaryKernel,
+    select_and_gather::{RemoveDimKernel, ReplaceDimKernel},
+};
+
+/// Add values into a tensor at positions along a single axis, where each value
+/// of `src` is added to the position it would be gathered from. This is the inverse
+/// of [super::GatherTo::gather]. Equivalent to `torch.scatter_add` from pytorch.
+pub trait TryScatterAdd<Idx, Src>: HasErr {
+    /// Scatter add values given indices.
+    ///
+    /// The index and `src` shapes are the same shapes that [super::GatherTo::gather]
+    /// would use to gather `src` from `self`. Values scattered to the same position
+    /// are summed.
+    ///
+    /// Gradients flow into both `self` and `src`.
+    ///
+    /// Here is an example of a segment sum, scattering rows into the 0th axis of a 2d tensor:
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let a: Tensor<Rank2<2, 2>, f32, _> = dev.zeros();
+    /// let idx: Tensor<Rank1<3>, usize, _> = dev.tensor([1, 0, 1]);
+    /// let src: Tensor<Rank2<3, 2>, f32, _> = dev.tensor([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+    /// let r = a.clone().scatter_add(idx, src);
+    /// assert_eq!(r.array(), [[3.0, 4.0], [6.0, 8.0]]);
+    ///
+    /// // scatter into the 1st axis
+    /// let a: Tensor<Rank2<2, 3>, f32, _> = dev.zeros();
+    /// let idx: Tensor<Rank2<2, 2>, usize, _> = dev.tensor([[0, 2], [1, 1]]);
+    /// let src: Tensor<Rank2<2, 2>, f32, _> = dev.tensor([[1.0, 2.0], [3.0, 4.0]]);
+    /// let r = a.scatter_add(idx, src);
+    /// assert_eq!(r.array(), [[1.0, 0.0, 2.0], [0.0, 7.0, 0.0]]);
+    ///```
+    fn scatter_add(self, idx: Idx, src: Src) -> Self {
+        self.try_scatter_add(idx, src).unwrap()
+    }
+
+    /// Fallible version of [TryScatterAdd::scatter_add]
+    fn try_scatter_add(self, idx: Idx, src: Src) -> Result<Self, Self::Err>;
+}
+
+/// Add values into a tensor at a single position along an axis, where each value
+/// of `src` is added to the position it would be selected from. This is the inverse
+/// of [super::SelectTo::select].
+pub trait TrySelectScatterAdd<Idx, Src>: HasErr {
+    /// Scatter add values given indices.
+    ///
+    /// The index and `src` shapes are the same shapes that [super::SelectTo::select]
+    /// would use to select `src` from `self`. Values scattered to the same position
+    /// are summed.
+    ///
+    /// Gradients flow into both `self` and `src`.
+    ///
+    /// Here is an example scattering into the 1st axis of a 2d tensor:
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let a: Tensor<Rank2<2, 3>, f32, _> = dev.zeros();
+    /// let idx: Tensor<Rank1<2>, usize, _> = dev.tensor([2, 0]);
+    /// let src: Tensor<Rank1<2>, f32, _> = dev.tensor([1.0, 2.0]);
+    /// let r = a.select_scatter_add(idx, src);
+    /// assert_eq!(r.array(), [[0.0, 0.0, 1.0], [2.0, 0.0, 0.0]]);
+    ///```
+    fn select_scatter_add(self, idx: Idx, src: Src) -> Self {
+        self.try_select_scatter_add(idx, src).unwrap()
+    }
+
+    /// Fallible version of [TrySelectScatterAdd::select_scatter_add]
+    fn try_select_scatter_add(self, idx: Idx, src: Src) -> Result<Self, Self::Err>;
+}
+
+impl<S: Shape, Src: Shape, Idx: Shape, E: Dtype, D, LTape, RTape>
+    TryScatterAdd<Tensor<Idx, usize, D>, Tensor<Src, E, D, RTape>> for Tensor<S, E, D, LTape>
+where
+    S: ReplaceDimTo<Src, Idx>,
+    D: ReplaceDimKernel<E> + BinaryKernel<BinaryAddKernelOp, E> + ZerosTensor<E>,
+    LTape: Tape<D> + Merge<RTape>,
+    RTape: Tape<D>,
+{
+    fn try_scatter_add(
+        self,
+        idx: Tensor<Idx, usize, D>,
+        src: Tensor<Src, E, D, RTape>,
+    ) -> Result<Self, Self::Err> {
+        let shape = *self.shape();
+        shape.check(idx.shape());
+        assert_eq!(
+            shape.replace(*idx.shape()).concrete(),
+            src.shape().concrete()
+        );
+
+        let (src, mut tape) = src.split_tape();
+        let mut storage = src.device.try_zeros_like(&shape)?.storage;
+        ReplaceDimKernel::backward(&src.device, &mut storage, &idx.storage, &src.storage)?;
+        let out = src.device.upgrade(storage);
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&src)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_src, grad_out) = grads.mut_and_ref(&src, &phantom_out);
+            let grad: D::Storage<Src, E> =
+                ReplaceDimKernel::forward(&src.device, grad_out, &idx.storage)?;
+            *grad_src = BinaryKernel::forward(&src.device, BinaryAddKernelOp, grad_src, &grad)?;
+            Ok(())
+        });
+        self.try_add(out.put_tape(tape))
+    }
+}
+
+impl<S: Shape, Src: Shape, Idx: Shape, E: Dtype, D, LTape, RTape>
+    TrySelectScatterAdd<Tensor<Idx, usize, D>, Tensor<Src, E, D, RTape>> for Tensor<S, E, D, LTape>
+where
+    S: RemoveDimTo<Src, Idx>,
+    D: RemoveDimKernel<E> + BinaryKernel<BinaryAddKernelOp, E> + ZerosTensor<E>,
+    LTape: Tape<D> + Merge<RTape>,
+    RTape: Tape<D>,
+{
+    fn try_select_scatter_add(
+        self,
+        idx: Tensor<Idx, usize, D>,
+        src: Tensor<Src, E, D, RTape>,
+    ) -> Result<Self, Self::Err> {
+        let shape = *self.shape();
+        shape.check(idx.shape());
+        assert_eq!(
+            shape.remove(*idx.shape()).concrete(),
+            src.shape().concrete()
+        );
+
+        let (src, mut tape) = src.split_tape();
+        let mut storage = src.device.try_zeros_like(&shape)?.storage;
+        RemoveDimKernel::backward(&src.device, &mut storage, &idx.storage, &src.storage)?;
+        let out = src.device.upgrade(storage);
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&src)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_src, grad_out) = grads.mut_and_ref(&src, &phantom_out);
+            let grad: D::Storage<Src, E> =
+                RemoveDimKernel::forward(&src.device, grad_out, &idx.storage)?;
+            *grad_src = BinaryKernel::forward(&src.device, BinaryAddKernelOp, grad_src, &grad)?;
+            Ok(())
+        });
+        self.try_add(out.put_tape(tape))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor_ops::*;
+    use crate::tests::*;
+
+    #[test]
+    fn test_scatter_add_1d() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<_, TestDtype, _> = dev.tensor([1.0, 2.0, 3.0]);
+        let src: Tensor<_, TestDtype, _> = dev.tensor([0.5, -1.0, 2.0, 4.0]);
+        let r = a.trace().scatter_add(dev.tensor([2, 0, 2, 1]), src.trace());
+        assert_eq!(r.array(), [0.0, 6.0, 5.5]);
+        let g = r.exp().sum().backward();
+        assert_close(
+            &g.get(&a).array(),
+            &[0.0f64.exp(), 6.0f64.exp(), 5.5f64.exp()].map(|x| x as TestDtype),
+        );
+        assert_close(
+            &g.get(&src).array(),
+            &[5.5f64.exp(), 0.0f64.exp(), 5.5f64.exp(), 6.0f64.exp()].map(|x| x as TestDtype),
+        );
+    }
+
+    #[test]
+    fn test_scatter_add_2d_axis_0() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<Rank2<2, 3>, TestDtype, _> = dev.zeros();
+        let src: Tensor<_, TestDtype, _> =
+            dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+        let idx = dev.tensor([1, 1, 0]);
+        let r = a.trace().scatter_add(idx.clone(), src.trace());
+        assert_eq!(r.array(), [[7.0, 8.0, 9.0], [5.0, 7.0, 9.0]]);
+
+        let g = (r * dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]))
+            .sum()
+            .backward();
+        assert_eq!(g.get(&a).array(), [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        assert_eq!(
+            g.get(&src).array(),
+            [[4.0, 5.0, 6.0], [4.0, 5.0, 6.0], [1.0, 2.0, 3.0]]
+        );
+
+        // scatter_add is the transpose of gather
+        let grad_a = dev.tensor(g.get(&a).array());
+        let gathered: Tensor<Rank2<3, 3>, TestDtype, _> = grad_a.gather(idx);
+        assert_eq!(gathered.array(), g.get(&src).array());
+    }
+
+    #[test]
+    fn test_scatter_add_2d_axis_1() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<_, TestDtype, _> = dev.tensor([[1.0, 1.0], [2.0, 2.0]]);
+        let src: Tensor<_, TestDtype, _> = dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let r = a
+            .trace()
+            .scatter_add(dev.tensor([[0, 0, 0], [1, 0, 1]]), src.trace());
+        assert_eq!(r.array(), [[7.0, 1.0], [7.0, 12.0]]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&a).array(), [[1.0; 2]; 2]);
+        assert_eq!(g.get(&src).array(), [[1.0; 3]; 2]);
+    }
+
+    #[test]
+    fn test_select_scatter_add_axis_0() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<Rank2<3, 2>, TestDtype, _> = dev.zeros();
+        let src: Tensor<_, TestDtype, _> = dev.tensor([1.0, 2.0]);
+        let r = a.trace().select_scatter_add(dev.tensor(2), src.trace());
+        assert_eq!(r.array(), [[0.0, 0.0], [0.0, 0.0], [1.0, 2.0]]);
+        let g = (r * dev.tensor([[1.0, -1.0], [2.0, -2.0], [3.0, -3.0]]))
+            .sum()
+            .backward();
+        assert_eq!(g.get(&a).array(), [[1.0, -1.0], [2.0, -2.0], [3.0, -3.0]]);
+        assert_eq!(g.get(&src).array(), [3.0, -3.0]);
+    }
+
+    #[test]
+    fn test_select_scatter_add_last_axis() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<_, TestDtype, _> = dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let src: Tensor<_, TestDtype, _> = dev.tensor([10.0, 20.0]);
+        let r = a
+            .trace()
+            .select_scatter_add(dev.tensor([2, 0]), src.trace());
+        assert_eq!(r.array(), [[1.0, 2.0, 13.0], [24.0, 5.0, 6.0]]);
+        let g = r.exp().sum().backward();
+        assert_close(
+            &g.get(&src).array(),
+            &[13.0f64.exp() as TestDtype, 24.0f64.exp() as TestDtype],
+        );
+    }
+}
@@ -0,0 +1,45 @@
+use crate::{
+    shapes::{Dtype, Shape},
+    tensor::cpu::{Cpu, LendingIterator, StridedArray},
+};
+
+use std::{sync::Arc, vec::Vec};
+
+impl<E: Dtype> super::SearchSortedKernel<E> for Cpu {
+    fn searchsorted<Seq: Shape, V: Shape>(
+        &self,
+        seq: &Self::Storage<Seq, E>,
+        values: &Self::Storage<V, E>,
+        right: bool,
+    ) -> Result<Self::Storage<V, usize>, Self::Err> {
+        let mut out: StridedArray<V, usize> = StridedArray::new(values.shape)?;
+        if values.shape.num_elements() == 0 {
+            return Ok(out);
+        }
+        let row_strides = super::row_strides::<Seq, V>(seq.strides);
+        let seq_len = seq.shape.concrete()[Seq::NUM_DIMS - 1];
+        let seq_stride = seq.strides[Seq::NUM_DIMS - 1];
+        let seq_buf = seq.data.as_ref();
+
+        let out_buf = Arc::make_mut(&mut out.data);
+        let mut values_iter = values.iter_with_index();
+        let mut i = 0;
+        while let Some((&v, idx)) = values_iter.next() {
+            let idx: Vec<usize> = idx.into();
+            let offset: usize = idx.iter().zip(row_strides.iter()).map(|(a, b)| a * b).sum();
+            let (mut lo, mut hi) = (0, seq_len);
+            while lo < hi {
+                let mid = (lo + hi) / 2;
+                let s = seq_buf[offset + mid * seq_stride];
+                if s < v || (right && s == v) {
+                    lo = mid + 1;
+                } else {
+                    hi = mid;
+                }
+            }
+            out_buf[i] = lo;
+            i += 1;
+        }
+        Ok(out)
+    }
+}
@@ -0,0 +1,72 @@
+use crate::{
+    shapes::*,
+    tensor::cuda::{Cuda, CudaArray},
+};
+use cudarc::driver::{AsKernelParam, LaunchAsync, LaunchConfig};
+use std::sync::Arc;
+
+const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/searchsorted.ptx"));
+const MOD: &str = "searchsorted";
+const FNS: &[&str] = &["searchsorted_f32", "searchsorted_f64"];
+
+trait HasCudaKernel<E> {
+    const FN: &'static str;
+}
+
+impl HasCudaKernel<f32> for Cuda {
+    const FN: &'static str = "searchsorted_f32";
+}
+
+impl HasCudaKernel<f64> for Cuda {
+    const FN: &'static str = "searchsorted_f64";
+}
+
+impl<E: Dtype + AsKernelParam> super::SearchSortedKernel<E> for Cuda
+where
+    Self: HasCudaKernel<E>,
+{
+    fn searchsorted<Seq: Shape, V: Shape>(
+        &self,
+        seq: &Self::Storage<Seq, E>,
+        values: &Self::Storage<V, E>,
+        right: bool,
+    ) -> Result<Self::Storage<V, usize>, Self::Err> {
+        if !self.dev.has_func(MOD, Self::FN) {
+            self.dev.load_ptx(PTX_SRC.into(), MOD, FNS)?;
+        }
+
+        let shape = values.shape;
+        let numel = shape.num_elements();
+        let mut storage = self.dev.alloc_zeros_async::<usize>(numel)?;
+
+        if numel > 0 {
+            let dims = self.dev.take_async(shape.concrete().into())?;
+            let values_strides = self.dev.take_async(values.strides.into())?;
+            let seq_strides = self
+                .dev
+                .take_async(super::row_strides::<Seq, V>(seq.strides))?;
+            let fwd_fn = self.dev.get_func(MOD, Self::FN).unwrap();
+            let cfg = LaunchConfig::for_num_elems(numel as u32);
+            let params = (
+                numel,                                   // const size_t numel,
+                V::NUM_DIMS,                             // const size_t num_dims,
+                &dims,                                   // const size_t *dims,
+                &values_strides,                         // const size_t *values_strides,
+                &seq_strides,                            // const size_t *seq_strides,
+                seq.shape.concrete()[Seq::NUM_DIMS - 1], // const size_t seq_len,
+                seq.strides[Seq::NUM_DIMS - 1],          // const size_t seq_stride,
+                right as i32,                            // const int right,
+                seq.data.as_ref(),                       // const T *seq,
+                values.data.as_ref(),                    // const T *values,
+                &mut storage,                            // size_t *out
+            );
+            unsafe { fwd_fn.launch_async(cfg, params) }?;
+        }
+
+        Ok(CudaArray {
+            data: Arc::new(storage),
+            shape,
+            strides: shape.strides(),
+        })
+    }
+}
@@ -0,0 +1,190 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{shapes::*, tensor::*};
+
+use std::vec::Vec;
+
+pub trait SearchSortedKernel<E: Dtype>: DeviceStorage {
+    /// For each value, finds its insertion index into the last axis of `seq`. A 1d `seq`
+    /// is shared by all values, otherwise the leading axes of `seq` select the row for
+    /// the values with the same leading indices.
+    fn searchsorted<Seq: Shape, V: Shape>(
+        &self,
+        seq: &Self::Storage<Seq, E>,
+        values: &Self::Storage<V, E>,
+        right: bool,
+    ) -> Result<Self::Storage<V, usize>, Self::Err>;
+}
+
+/// The strides of the rows of `seq` for each axis of the values, with a
+/// stride of `0` for the last axis.
+fn row_strides<Seq: Shape, V: Shape>(seq_strides: Seq::Concrete) -> Vec<usize> {
+    let seq_strides: Vec<usize> = seq_strides.into();
+    let mut strides = alloc::vec![0; V::NUM_DIMS];
+    if Seq::NUM_DIMS > 1 {
+        strides[..Seq::NUM_DIMS - 1].copy_from_slice(&seq_strides[..Seq::NUM_DIMS - 1]);
+    }
+    strides
+}
+
+/// Finds the indices where `values` would be inserted into the last axis of
+/// `sorted_sequence` to keep it sorted. The result does not track gradients.
+///
+/// If `right` is `false`, this is the index of the first element that is not less than the
+/// value, otherwise it is the index of the first element that is greater than the value.
+///
+/// `sorted_sequence` can either be 1d, in which case it is shared by all values of any shape,
+/// or have the same leading axes as `values`, in which case each row of values is searched
+/// in the matching row of `sorted_sequence`.
+///
+/// **Pytorch equivalent**: `torch.searchsorted(sorted_sequence, values, right=right)`
+///
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let boundaries = dev.tensor([1.0, 3.0, 5.0]);
+/// let values = dev.tensor([[0.0, 1.0, 2.0], [5.0, 6.0, 3.0]]);
+/// let r = searchsorted(boundaries.clone(), values.clone(), false);
+/// assert_eq!(r.array(), [[0, 0, 1], [2, 3, 1]]);
+/// let r = searchsorted(boundaries, values, true);
+/// assert_eq!(r.array(), [[0, 1, 1], [3, 3, 2]]);
+/// ```
+///
+/// Searching each row separately:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let seq = dev.tensor([[1.0, 2.0, 3.0], [10.0, 20.0, 30.0]]);
+/// let values = dev.tensor([[2.5], [2.5]]);
+/// assert_eq!(searchsorted(seq, values, false).array(), [[2], [0]]);
+/// ```
+pub fn searchsorted<Seq: TrySearchSorted<Values>, Values>(
+    sorted_sequence: Seq,
+    values: Values,
+    right: bool,
+) -> Seq::Output {
+    sorted_sequence.searchsorted(values, right)
+}
+
+/// Insertion indices into sorted tensors. See [searchsorted].
+pub trait TrySearchSorted<Values>: HasErr {
+    type Output;
+
+    /// See [searchsorted]
+    fn searchsorted(self, values: Values, right: bool) -> Self::Output {
+        self.try_searchsorted(values, right).unwrap()
+    }
+
+    /// Fallible version of [TrySearchSorted::searchsorted]
+    fn try_searchsorted(self, values: Values, right: bool) -> Result<Self::Output, Self::Err>;
+}
+
+impl<N: Dim, V: Shape, E: Dtype, D: SearchSortedKernel<E>, T, VT>
+    TrySearchSorted<Tensor<V, E, D, VT>> for Tensor<(N,), E, D, T>
+{
+    type Output = Tensor<V, usize, D>;
+
+    fn try_searchsorted(
+        self,
+        values: Tensor<V, E, D, VT>,
+        right: bool,
+    ) -> Result<Self::Output, Self::Err> {
+        let storage = self
+            .device
+            .searchsorted(&self.storage, &values.storage, right)?;
+        Ok(self.device.upgrade(storage))
+    }
+}
+
+macro_rules! searchsorted {
+    ($($B:ident),*) => {
+        impl<$($B: Dim, )* N: Dim, K: Dim, E: Dtype, D: SearchSortedKernel<E>, T, VT>
+            TrySearchSorted<Tensor<($($B, )* K), E, D, VT>> for Tensor<($($B, )* N), E, D, T>
+        {
+            type Output = Tensor<($($B, )* K), usize, D>;
+
+            fn try_searchsorted(
+                self,
+                values: Tensor<($($B, )* K), E, D, VT>,
+                right: bool,
+            ) -> Result<Self::Output, Self::Err> {
+                let seq_shape = self.shape().concrete();
+                let values_shape = values.shape().concrete();
+                assert_eq!(
+                    seq_shape[..seq_shape.len() - 1],
+                    values_shape[..values_shape.len() - 1],
+                    "leading axes of the sequence and values must match"
+                );
+                let storage = self
+                    .device
+                    .searchsorted(&self.storage, &values.storage, right)?;
+                Ok(self.device.upgrade(storage))
+            }
+        }
+    };
+}
+
+searchsorted!(B0);
+searchsorted!(B0, B1);
+searchsorted!(B0, B1, B2);
+
+#[cfg(test)]
+mod tests {
+    use crate::{shapes::*, tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_searchsorted_1d_with_duplicates() {
+        let dev: TestDevice = Default::default();
+        let seq: Tensor<_, TestDtype, _> = dev.tensor([1.0, 2.0, 2.0, 2.0, 4.0]);
+        let values: Tensor<_, TestDtype, _> = dev.tensor([0.0, 2.0, 3.0, 4.0, 5.0]);
+        let r = seq.clone().searchsorted(values.clone(), false);
+        assert_eq!(r.array(), [0, 1, 4, 4, 5]);
+        let r = seq.searchsorted(values, true);
+        assert_eq!(r.array(), [0, 4, 4, 5, 5]);
+    }
+
+    #[test]
+    fn test_searchsorted_3d_rows() {
+        let dev: TestDevice = Default::default();
+        let seq: Tensor<Rank3<2, 3, 4>, TestDtype, _> = dev.sample_normal();
+        let seq = dev.tensor(seq.array().map(|a| {
+            a.map(|mut row| {
+                row.sort_by(|a, b| a.partial_cmp(b).unwrap());
+                row
+            })
+        }));
+        let values: Tensor<Rank3<2, 3, 5>, TestDtype, _> = dev.sample_normal();
+        let r = searchsorted(seq.clone(), values.clone(), false);
+        let (seq, values, r) = (seq.array(), values.array(), r.array());
+        for i in 0..2 {
+            for j in 0..3 {
+                for k in 0..5 {
+                    let expected = seq[i][j].iter().filter(|&&s| s < values[i][j][k]).count();
+                    assert_eq!(r[i][j][k], expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_searchsorted_broadcasted() {
+        let dev: TestDevice = Default::default();
+        let seq: Tensor<_, TestDtype, _> = dev.tensor([0.0, 1.0]);
+        let values: Tensor<_, TestDtype, _> = dev.tensor([-1.0, 0.5, 2.0]);
+        let r = seq
+            .broadcast::<Rank2<2, 2>, Axis<0>>()
+            .searchsorted(values.broadcast::<Rank2<2, 3>, Axis<0>>(), false);
+        assert_eq!(r.array(), [[0, 1, 2]; 2]);
+    }
+
+    #[test]
+    fn test_searchsorted_empty_sequence() {
+        let dev: TestDevice = Default::default();
+        let seq: Tensor<(usize,), TestDtype, _> = dev.zeros_like(&(0,));
+        let values: Tensor<_, TestDtype, _> = dev.tensor([1.0, 2.0]);
+        assert_eq!(seq.searchsorted(values, false).array(), [0, 0]);
+    }
+}
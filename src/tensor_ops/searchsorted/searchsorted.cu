@@ -0,0 +1,58 @@
+#include "cuda_utils.cuh"
+
+// For each value, binary searches the row of seq given by seq_strides, which
+// are the strides of the rows of seq for each axis of values.
+template<typename T>
+__device__ void searchsorted(
+    const size_t numel,
+    const size_t num_dims,
+    const size_t *dims,
+    const size_t *values_strides,
+    const size_t *seq_strides,
+    const size_t seq_len,
+    const size_t seq_stride,
+    const int right,
+    const T *seq,
+    const T *values,
+    size_t *out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    const T v = values[get_strided_index(i, num_dims, dims, values_strides)];
+    const size_t offset = get_strided_index(i, num_dims, dims, seq_strides);
+    size_t lo = 0;
+    size_t hi = seq_len;
+    while (lo < hi) {
+        const size_t mid = (lo + hi) / 2;
+        const T s = seq[offset + mid * seq_stride];
+        if (s < v || (right && s == v)) {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    out[i] = lo;
+}
+
+#define SEARCHSORTED(TYPENAME, FN) \
+extern "C" __global__ void FN( \
+    const size_t numel, \
+    const size_t num_dims, \
+    const size_t *dims, \
+    const size_t *values_strides, \
+    const size_t *seq_strides, \
+    const size_t seq_len, \
+    const size_t seq_stride, \
+    const int right, \
+    const TYPENAME *seq, \
+    const TYPENAME *values, \
+    size_t *out \
+) { \
+    searchsorted(numel, num_dims, dims, values_strides, seq_strides, seq_len, seq_stride, right, seq, values, out); \
+}
+
+SEARCHSORTED(float, searchsorted_f32);
+SEARCHSORTED(double, searchsorted_f64);
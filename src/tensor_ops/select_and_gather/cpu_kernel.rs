@@ -1,6 +1,6 @@
 #![allow(clippy::needless_range_loop)]
 
-use crate::shapes::{Axes, Dtype, RemoveDimTo, ReplaceDimTo, Shape};
+use crate::shapes::{Axes, Dtype, GatherNdShapeTo, RemoveDimTo, ReplaceDimTo, Shape};
 use crate::tensor::cpu::{Cpu, LendingIterator, StridedArray};
 
 impl<E: Dtype> super::ReplaceDimKernel<E> for Cpu {
@@ -144,3 +144,61 @@ impl<E: Dtype> super::RemoveDimKernel<E> for Cpu {
         Ok(())
     }
 }
+
+/// The index into `inp` of the element at `i_out` of the output of gather_nd.
+fn gathered_nd_index<Src, Dst: Shape, Idx: Shape>(
+    inp_shape: &Src,
+    idx: &StridedArray<Idx, usize>,
+    i_out: Dst::Concrete,
+) -> Src::Concrete
+where
+    Src: GatherNdShapeTo<Dst, Idx>,
+{
+    let batch = <Idx as Shape>::NUM_DIMS - 1;
+    let inp_dims = inp_shape.concrete();
+    let mut i_idx: <Idx as Shape>::Concrete = Default::default();
+    for j in 0..batch {
+        i_idx[j] = i_out[j];
+    }
+    let mut i_inp: Src::Concrete = Default::default();
+    for j in 0..Src::NUM_DIMS {
+        i_inp[j] = if j < Src::K {
+            i_idx[batch] = j;
+            let i = idx[i_idx];
+            assert!(i < inp_dims[j], "index {i} is out of bounds for axis {j}");
+            i
+        } else {
+            i_out[j - Src::K + batch]
+        };
+    }
+    i_inp
+}
+
+impl<E: Dtype> super::GatherNdKernel<E> for Cpu {
+    fn forward<Src: GatherNdShapeTo<Dst, Idx>, Dst: Shape, Idx: Shape>(
+        &self,
+        inp: &Self::Storage<Src, E>,
+        idx: &Self::Storage<Idx, usize>,
+    ) -> Result<Self::Storage<Dst, E>, Self::Err> {
+        let mut out = StridedArray::new(inp.shape.gather_nd(idx.shape))?;
+        let mut out_iter = out.iter_mut_with_index();
+        while let Some((x, i_out)) = out_iter.next() {
+            *x = inp[gathered_nd_index(&inp.shape, idx, i_out)];
+        }
+        Ok(out)
+    }
+
+    fn backward<Src: GatherNdShapeTo<Dst, Idx>, Dst: Shape, Idx: Shape>(
+        &self,
+        grad_inp: &mut Self::Storage<Src, E>,
+        idx: &Self::Storage<Idx, usize>,
+        grad_out: &Self::Storage<Dst, E>,
+    ) -> Result<(), Self::Err> {
+        let inp_shape = grad_inp.shape;
+        let mut out_iter = grad_out.iter_with_index();
+        while let Some((x, i_out)) = out_iter.next() {
+            grad_inp[gathered_nd_index(&inp_shape, idx, i_out)] += *x;
+        }
+        Ok(())
+    }
+}
@@ -1,5 +1,5 @@
 use crate::{
-    shapes::{RemoveDimTo, ReplaceDimTo, Shape},
+    shapes::{GatherNdShapeTo, RemoveDimTo, ReplaceDimTo, Shape},
     tensor::cuda::{Cuda, CudaArray},
 };
 use cudarc::driver::{LaunchAsync, LaunchConfig};
@@ -7,6 +7,7 @@ use std::sync::Arc;
 
 const GATHER_PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/gather.ptx"));
 const SELECT_PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/select.ptx"));
+const GATHER_ND_PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/gather_nd.ptx"));
 
 macro_rules! impl_cuda_kernels {
     ($TypeName:ty, $GatherMod:tt, $GatherFwd:tt, $GatherBwd:tt, $SelectMod:tt, $SelectFwd:tt, $SelectBwd:tt) => {
@@ -208,3 +209,135 @@ impl_cuda_kernels!(
     "select_fwd_f64",
     "select_bwd_f64"
 );
+impl_cuda_kernels!(
+    i32,
+    "gather_i32",
+    "gather_fwd_i32",
+    "gather_bwd_i32",
+    "select_i32",
+    "select_fwd_i32",
+    "select_bwd_i32"
+);
+impl_cuda_kernels!(
+    i64,
+    "gather_i64",
+    "gather_fwd_i64",
+    "gather_bwd_i64",
+    "select_i64",
+    "select_fwd_i64",
+    "select_bwd_i64"
+);
+impl_cuda_kernels!(
+    u8,
+    "gather_u8",
+    "gather_fwd_u8",
+    "gather_bwd_u8",
+    "select_u8",
+    "select_fwd_u8",
+    "select_bwd_u8"
+);
+
+macro_rules! impl_gather_nd_cuda_kernel {
+    ($TypeName:ty, $Mod:tt, $Fwd:tt, $Bwd:tt) => {
+        impl super::GatherNdKernel<$TypeName> for Cuda {
+            fn forward<Src: GatherNdShapeTo<Dst, Idx>, Dst: Shape, Idx: Shape>(
+                &self,
+                inp: &Self::Storage<Src, $TypeName>,
+                idx: &Self::Storage<Idx, usize>,
+            ) -> Result<Self::Storage<Dst, $TypeName>, Self::Err> {
+                if !self.dev.has_func($Mod, $Fwd) {
+                    self.dev
+                        .load_ptx(GATHER_ND_PTX_SRC.into(), $Mod, &[$Fwd, $Bwd])?;
+                }
+
+                let dst = inp.shape.gather_nd(idx.shape);
+                let numel = dst.num_elements();
+                let mut storage = self.dev.alloc_zeros_async::<$TypeName>(numel)?;
+
+                let inp_dims = self.dev.take_async(inp.shape.concrete().into())?;
+                let idx_dims = self.dev.take_async(idx.shape.concrete().into())?;
+                let inp_strides = self.dev.take_async(inp.strides.into())?;
+                let idx_strides = self.dev.take_async(idx.strides.into())?;
+
+                let fwd_fn = self.dev.get_func($Mod, $Fwd).unwrap();
+                let cfg = LaunchConfig::for_num_elems(numel as u32);
+                let params = (
+                    numel,             // const size_t numel,
+                    inp.data.as_ref(), // const float *inp,
+                    Src::NUM_DIMS,     // const size_t inp_num_dims,
+                    &inp_dims,         // const size_t *inp_dims,
+                    &inp_strides,      // const size_t *inp_strides,
+                    idx.data.as_ref(), // const size_t *idx,
+                    Idx::NUM_DIMS,     // const size_t idx_num_dims,
+                    &idx_dims,         // const size_t *idx_dims,
+                    &idx_strides,      // const size_t *idx_strides,
+                    &mut storage,      // float *out,
+                );
+                unsafe { fwd_fn.launch_async(cfg, params) }?;
+
+                Ok(CudaArray {
+                    data: Arc::new(storage),
+                    shape: dst,
+                    strides: dst.strides(),
+                })
+            }
+
+            fn backward<Src: GatherNdShapeTo<Dst, Idx>, Dst: Shape, Idx: Shape>(
+                &self,
+                grad_inp: &mut Self::Storage<Src, $TypeName>,
+                idx: &Self::Storage<Idx, usize>,
+                grad_out: &Self::Storage<Dst, $TypeName>,
+            ) -> Result<(), Self::Err> {
+                let bwd_fn = self.dev.get_func($Mod, $Bwd).unwrap();
+                let numel = grad_out.data.len();
+
+                let inp_dims = self.dev.take_async(grad_inp.shape.concrete().into())?;
+                let idx_dims = self.dev.take_async(idx.shape.concrete().into())?;
+                let inp_strides = self.dev.take_async(grad_inp.strides.into())?;
+                let idx_strides = self.dev.take_async(idx.strides.into())?;
+
+                let cfg = LaunchConfig::for_num_elems(numel as u32);
+                let params = (
+                    numel,                             // const size_t numel,
+                    Arc::make_mut(&mut grad_inp.data), // float *grad_inp,
+                    Src::NUM_DIMS,                     // const size_t inp_num_dims,
+                    &inp_dims,                         // const size_t *inp_dims,
+                    &inp_strides,                      // const size_t *inp_strides,
+                    idx.data.as_ref(),                 // const size_t *idx,
+                    Idx::NUM_DIMS,                     // const size_t idx_num_dims,
+                    &idx_dims,                         // const size_t *idx_dims,
+                    &idx_strides,                      // const size_t *idx_strides,
+                    grad_out.data.as_ref(),            // const float *grad_out,
+                );
+                unsafe { bwd_fn.launch_async(cfg, params) }?;
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_gather_nd_cuda_kernel!(
+    f32,
+    "gather_nd_f32",
+    "gather_nd_fwd_f32",
+    "gather_nd_bwd_f32"
+);
+impl_gather_nd_cuda_kernel!(
+    f64,
+    "gather_nd_f64",
+    "gather_nd_fwd_f64",
+    "gather_nd_bwd_f64"
+);
+impl_gather_nd_cuda_kernel!(
+    i32,
+    "gather_nd_i32",
+    "gather_nd_fwd_i32",
+    "gather_nd_bwd_i32"
+);
+impl_gather_nd_cuda_kernel!(
+    i64,
+    "gather_nd_i64",
+    "gather_nd_fwd_i64",
+    "gather_nd_bwd_i64"
+);
+impl_gather_nd_cuda_kernel!(u8, "gather_nd_u8", "gather_nd_fwd_u8", "gather_nd_bwd_u8");
@@ -127,3 +127,6 @@ extern "C" __global__ void BWD( \
 
 GATHER(float, gather_fwd_f32, gather_bwd_f32);
 GATHER(double, gather_fwd_f64, gather_bwd_f64);
+GATHER(int, gather_fwd_i32, gather_bwd_i32);
+GATHER(long long, gather_fwd_i64, gather_bwd_i64);
+GATHER(unsigned char, gather_fwd_u8, gather_bwd_u8);
@@ -0,0 +1,123 @@
+#include "cuda_utils.cuh"
+
+__device__ unsigned int get_gathered_nd_index(
+    const unsigned int index,
+    const size_t inp_num_dims,
+    const size_t *inp_dims,
+    const size_t *inp_strides,
+    const size_t *idx,
+    const size_t idx_num_dims,
+    const size_t *idx_dims,
+    const size_t *idx_strides
+) {
+    // the number of leading axes that are indexed
+    unsigned int k = idx_dims[idx_num_dims - 1];
+
+    unsigned int elem_size = 1; // the size of each indexed element
+    for (unsigned int d = k; d < inp_num_dims; d++) {
+        elem_size *= inp_dims[d];
+    }
+
+    unsigned int i_batch = index / elem_size;
+    unsigned int i_elem = index % elem_size;
+
+    // the indexed axes
+    unsigned int inp_i = 0;
+    for (unsigned int d = 0; d < k; d++) {
+        unsigned int idx_i = get_strided_index(i_batch * k + d, idx_num_dims, idx_dims, idx_strides);
+        unsigned int i = idx[idx_i];
+        assert(i < inp_dims[d]);
+        inp_i += i * inp_strides[d];
+    }
+
+    // the remaining axes
+    for (unsigned int d = inp_num_dims; d > k; d--) {
+        inp_i += (i_elem % inp_dims[d - 1]) * inp_strides[d - 1];
+        i_elem /= inp_dims[d - 1];
+    }
+    return inp_i;
+}
+
+template<typename T>
+__device__ void gather_nd_fwd(
+    const size_t numel,
+    const T *inp,
+    const size_t inp_num_dims,
+    const size_t *inp_dims,
+    const size_t *inp_strides,
+    const size_t *idx,
+    const size_t idx_num_dims,
+    const size_t *idx_dims,
+    const size_t *idx_strides,
+    T *out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    unsigned int inp_i =
+        get_gathered_nd_index(i, inp_num_dims, inp_dims, inp_strides, idx, idx_num_dims, idx_dims, idx_strides);
+
+    out[i] = inp[inp_i];
+}
+
+template<typename T>
+__device__ void gather_nd_bwd(
+    const size_t numel,
+    T *grad_inp,
+    const size_t inp_num_dims,
+    const size_t *inp_dims,
+    const size_t *inp_strides,
+    const size_t *idx,
+    const size_t idx_num_dims,
+    const size_t *idx_dims,
+    const size_t *idx_strides,
+    const T *grad_out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    unsigned int inp_i =
+        get_gathered_nd_index(i, inp_num_dims, inp_dims, inp_strides, idx, idx_num_dims, idx_dims, idx_strides);
+
+    atomicAdd(grad_inp + inp_i, grad_out[i]);
+}
+
+#define GATHER_ND(TYPENAME, FWD, BWD) \
+extern "C" __global__ void FWD( \
+    const size_t numel, \
+    const TYPENAME *inp, \
+    const size_t inp_num_dims, \
+    const size_t *inp_dims, \
+    const size_t *inp_strides, \
+    const size_t *idx, \
+    const size_t idx_num_dims, \
+    const size_t *idx_dims, \
+    const size_t *idx_strides, \
+    TYPENAME *out \
+) { \
+    gather_nd_fwd(numel, inp, inp_num_dims, inp_dims, inp_strides, idx, idx_num_dims, idx_dims, idx_strides, out); \
+} \
+extern "C" __global__ void BWD( \
+    const size_t numel, \
+    TYPENAME *grad_inp, \
+    const size_t inp_num_dims, \
+    const size_t *inp_dims, \
+    const size_t *inp_strides, \
+    const size_t *idx, \
+    const size_t idx_num_dims, \
+    const size_t *idx_dims, \
+    const size_t *idx_strides, \
+    const TYPENAME *grad_out \
+) { \
+    gather_nd_bwd(numel, grad_inp, inp_num_dims, inp_dims, inp_strides, idx, idx_num_dims, idx_dims, idx_strides, grad_out); \
+}
+
+GATHER_ND(float, gather_nd_fwd_f32, gather_nd_bwd_f32);
+GATHER_ND(double, gather_nd_fwd_f64, gather_nd_bwd_f64);
+GATHER_ND(int, gather_nd_fwd_i32, gather_nd_bwd_i32);
+GATHER_ND(long long, gather_nd_fwd_i64, gather_nd_bwd_i64);
+GATHER_ND(unsigned char, gather_nd_fwd_u8, gather_nd_bwd_u8);
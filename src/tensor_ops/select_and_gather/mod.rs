@@ -43,6 +43,20 @@ pub trait RemoveDimKernel<E: Dtype>: DeviceStorage {
         Src: RemoveDimTo<Dst, Idx>;
 }
 
+pub trait GatherNdKernel<E: Dtype>: DeviceStorage {
+    fn forward<Src: GatherNdShapeTo<Dst, Idx>, Dst: Shape, Idx: Shape>(
+        &self,
+        inp: &Self::Storage<Src, E>,
+        idx: &Self::Storage<Idx, usize>,
+    ) -> Result<Self::Storage<Dst, E>, Self::Err>;
+    fn backward<Src: GatherNdShapeTo<Dst, Idx>, Dst: Shape, Idx: Shape>(
+        &self,
+        grad_inp: &mut Self::Storage<Src, E>,
+        idx: &Self::Storage<Idx, usize>,
+        grad_out: &Self::Storage<Dst, E>,
+    ) -> Result<(), Self::Err>;
+}
+
 /// Select a single value from a single dimension, removing that dimension
 /// from the shape. Equivalent to `torch.select` from pytorch.
 pub trait SelectTo<D: DeviceStorage>: HasErr + HasShape {
@@ -180,6 +194,79 @@ impl<Src: Shape, E: Dtype, D: ReplaceDimKernel<E>, T: Tape<D>> GatherTo<D>
     }
 }
 
+/// Select values using indices into multiple axes at once. Equivalent to
+/// `tf.gather_nd` from tensorflow, or indexing with multiple index tensors in pytorch.
+pub trait GatherNdTo<D: DeviceStorage>: HasErr + HasShape {
+    /// Gather values given multi-axis indices.
+    ///
+    /// The last dimension of the index has size `K`, and each row of the index
+    /// holds indices into the first `K` axes of the tensor. The leading dimensions
+    /// of the index replace those `K` dimensions in the result.
+    ///
+    /// For example, given a tensor of shape (M, N, O), here are the resulting
+    /// shapes for index shape (B, K):
+    /// - K = 1: (B, N, O)
+    /// - K = 2: (B, O)
+    /// - K = 3: (B, )
+    ///
+    /// The gradients of values that are gathered multiple times are summed.
+    ///
+    /// Here is an example gathering points from a 3d tensor:
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let a = dev.tensor([[[1.0, 2.0], [3.0, 4.0]], [[5.0, 6.0], [7.0, 8.0]]]);
+    ///
+    /// // gather rows of the last axis
+    /// let idx: Tensor<Rank2<3, 2>, usize, _> = dev.tensor([[0, 1], [1, 0], [1, 1]]);
+    /// let r: Tensor<Rank2<3, 2>, f64, _> = a.clone().gather_nd(idx);
+    /// assert_eq!(r.array(), [[3.0, 4.0], [5.0, 6.0], [7.0, 8.0]]);
+    ///
+    /// // gather single elements
+    /// let idx: Tensor<Rank2<2, 3>, usize, _> = dev.tensor([[0, 0, 1], [1, 1, 0]]);
+    /// let r: Tensor<Rank1<2>, f64, _> = a.gather_nd(idx);
+    /// assert_eq!(r.array(), [2.0, 7.0]);
+    ///```
+    fn gather_nd<Dst: Shape, Idx: Shape>(self, idx: Tensor<Idx, usize, D>) -> Self::WithShape<Dst>
+    where
+        Self::Shape: GatherNdShapeTo<Dst, Idx>,
+    {
+        self.try_gather_nd(idx).unwrap()
+    }
+
+    /// Fallible gather_nd
+    fn try_gather_nd<Dst: Shape, Idx: Shape>(
+        self,
+        idx: Tensor<Idx, usize, D>,
+    ) -> Result<Self::WithShape<Dst>, Self::Err>
+    where
+        Self::Shape: GatherNdShapeTo<Dst, Idx>;
+}
+
+impl<Src: Shape, E: Dtype, D: GatherNdKernel<E>, T: Tape<D>> GatherNdTo<D>
+    for Tensor<Src, E, D, T>
+{
+    fn try_gather_nd<Dst: Shape, Idx: Shape>(
+        self,
+        idx: Tensor<Idx, usize, D>,
+    ) -> Result<Self::WithShape<Dst>, Self::Err>
+    where
+        Self::Shape: GatherNdShapeTo<Dst, Idx>,
+    {
+        let (inp, mut tape) = self.split_tape();
+        let storage = inp.device.forward(&inp.storage, &idx.storage)?;
+        let out = inp.device.upgrade(storage);
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&inp)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+            inp.device.backward(grad_inp, &idx.storage, grad_out)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -426,4 +513,90 @@ mod tests {
         let g = r.sum().backward();
         assert_eq!(g.get(&t).array(), [[3.; 5], [0.; 5], [1.; 5], [2.; 5]]);
     }
+
+    #[test]
+    fn test_gather_nd_rows_backward() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank3<2, 3, 4>, TestDtype, _> = dev.sample_normal();
+        let t_array = t.array();
+        let idx: Tensor<Rank2<3, 2>, usize, _> = dev.tensor([[1, 2], [0, 0], [1, 2]]);
+        let r = t.trace().gather_nd(idx);
+        assert_eq!(r.array(), [t_array[1][2], t_array[0][0], t_array[1][2]]);
+        let g = r.exp().sum().backward();
+        let mut expected = [[[0.0; 4]; 3]; 2];
+        for i in 0..4 {
+            expected[0][0][i] = t_array[0][0][i].exp();
+            expected[1][2][i] = 2.0 * t_array[1][2][i].exp();
+        }
+        assert_close(&g.get(&t).array(), &expected);
+    }
+
+    #[test]
+    fn test_gather_nd_batched_points() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let idx: Tensor<Rank3<2, 2, 2>, usize, _> =
+            dev.tensor([[[0, 0], [1, 2]], [[1, 1], [1, 1]]]);
+        let r = t.trace().gather_nd(idx);
+        assert_eq!(r.array(), [[1.0, 6.0], [5.0, 5.0]]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&t).array(), [[1.0, 0.0, 0.0], [0.0, 2.0, 1.0]]);
+    }
+
+    #[test]
+    fn test_gather_nd_high_rank() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank5<2, 3, 1, 2, 1>, TestDtype, _> = dev.sample_normal();
+        let t_vec = t.as_vec();
+        // the 2 elements at [i][j]
+        let at = |i: usize, j: usize| [t_vec[(i * 3 + j) * 2], t_vec[(i * 3 + j) * 2 + 1]];
+        let idx: Tensor<Rank4<2, 1, 2, 2>, usize, _> =
+            dev.tensor([[[[1, 2], [0, 1]]], [[[0, 0], [1, 2]]]]);
+        let r: Tensor<Rank6<2, 1, 2, 1, 2, 1>, _, _, _> = t.trace().gather_nd(idx);
+        assert_eq!(
+            r.as_vec(),
+            [at(1, 2), at(0, 1), at(0, 0), at(1, 2)].concat()
+        );
+        let g = r.sum().backward();
+        let mut expected = [0.0; 12];
+        for (i, j, n) in [(0, 0, 1.0), (0, 1, 1.0), (1, 2, 2.0)] {
+            expected[(i * 3 + j) * 2] = n;
+            expected[(i * 3 + j) * 2 + 1] = n;
+        }
+        assert_eq!(g.get(&t).as_vec(), expected);
+
+        let t: Tensor<Rank8<2, 1, 2, 1, 1, 1, 1, 3>, TestDtype, _> = dev.sample_normal();
+        let t_vec = t.as_vec();
+        let idx: Tensor<Rank2<2, 7>, usize, _> =
+            dev.tensor([[1, 0, 1, 0, 0, 0, 0], [0, 0, 0, 0, 0, 0, 0]]);
+        let r: Tensor<Rank2<2, 3>, _, _> = t.gather_nd(idx);
+        assert_eq!(r.as_vec(), [&t_vec[9..12], &t_vec[0..3]].concat());
+    }
+
+    #[test]
+    #[should_panic = "index 3 is out of bounds for axis 1"]
+    fn test_gather_nd_out_of_bounds() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank2<2, 3>, TestDtype, _> = dev.zeros();
+        let idx: Tensor<Rank2<1, 2>, usize, _> = dev.tensor([[0, 3]]);
+        let _: Tensor<Rank1<1>, TestDtype, _> = t.gather_nd(idx);
+    }
+
+    #[test]
+    fn test_select_and_gather_ints() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, i32, _> = dev.tensor([[1, -2, 3], [-4, 5, -6]]);
+        assert_eq!(t.clone().select(dev.tensor([2, 0])).array(), [3, -4]);
+        let idx: Tensor<Rank2<2, 2>, usize, _> = dev.tensor([[1, 1], [0, 2]]);
+        let r: Tensor<Rank2<2, 2>, _, _> = t.gather(idx);
+        assert_eq!(r.array(), [[-2, -2], [-4, -6]]);
+
+        let t: Tensor<_, i64, _> = dev.tensor([i64::MAX, 0, i64::MIN]);
+        let r: Tensor<Rank1<2>, _, _> = t.gather(dev.tensor([2, 0]));
+        assert_eq!(r.array(), [i64::MIN, i64::MAX]);
+
+        let t: Tensor<_, u8, _> = dev.tensor([[1, 2], [3, 255]]);
+        let idx: Tensor<Rank2<1, 2>, usize, _> = dev.tensor([[1, 1]]);
+        assert_eq!(t.gather_nd(idx).array(), [255]);
+    }
 }
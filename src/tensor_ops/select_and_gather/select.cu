@@ -118,4 +118,7 @@ extern "C" __global__ void BWD( \
 }
 
 SELECT(float, select_fwd_f32, select_bwd_f32);
-SELECT(double, select_fwd_f64, select_bwd_f64)
+SELECT(double, select_fwd_f64, select_bwd_f64);
+SELECT(int, select_fwd_i32, select_bwd_i32);
+SELECT(long long, select_fwd_i64, select_bwd_i64);
+SELECT(unsigned char, select_fwd_u8, select_bwd_u8);
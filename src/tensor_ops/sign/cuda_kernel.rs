@@ -0,0 +1,9 @@
+use super::SignKernelOp;
+use crate::tensor_ops::cuda_kernels::cuda_unary;
+
+unsafe impl cudarc::driver::AsKernelParam for SignKernelOp {}
+
+const PTX: &str = include_str!(concat!(env!("OUT_DIR"), "/sign.ptx"));
+
+cuda_unary!(SignKernelOp, f32, PTX, "sign_fwd_f32", "sign_bwd_f32");
+cuda_unary!(SignKernelOp, f64, PTX, "sign_fwd_f64", "sign_bwd_f64");
@@ -0,0 +1,77 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use super::ops::{try_unary_op, UnaryKernel};
+use crate::{gradients::Tape, shapes::*, tensor::Tensor};
+
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct SignKernelOp;
+
+/// The sign of `t`, which is `-1` for negative values, `1` for positive values, and
+/// `0` for zeros. NaNs stay NaN.
+///
+/// Unlike [f32::signum], `sign(0.0)` is `0.0`.
+///
+/// The derivative is `0` everywhere.
+///
+/// **Pytorch equivalent**: `torch.sign(t)`
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([-2.5, 0.0, 0.1, 3.0]);
+/// let r = t.sign();
+/// assert_eq!(r.array(), [-1.0, 0.0, 1.0, 1.0]);
+/// ```
+pub fn sign<S: Shape, E: Dtype, D: UnaryKernel<SignKernelOp, E>, T: Tape<D>>(
+    t: Tensor<S, E, D, T>,
+) -> Tensor<S, E, D, T> {
+    t.sign()
+}
+
+impl<S: Shape, E: Dtype, D: UnaryKernel<SignKernelOp, E>, T: Tape<D>> Tensor<S, E, D, T> {
+    /// See [sign]
+    pub fn sign(self) -> Self {
+        self.try_sign().unwrap()
+    }
+    /// See [sign]
+    pub fn try_sign(self) -> Result<Self, D::Err> {
+        try_unary_op(SignKernelOp, self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_sign() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([
+            TestDtype::NEG_INFINITY,
+            -2.0,
+            -1e-10,
+            0.0,
+            1e-10,
+            2.0,
+            TestDtype::INFINITY,
+        ]);
+        let r = x.trace().sign();
+        assert_eq!(r.array(), [-1.0, -1.0, -1.0, 0.0, 1.0, 1.0, 1.0]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&x).array(), [0.0; 7]);
+    }
+
+    #[test]
+    fn test_sign_nan() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([TestDtype::NAN, -0.0]);
+        let r = x.sign().array();
+        assert!(r[0].is_nan());
+        assert_eq!(r[1], 0.0);
+    }
+}
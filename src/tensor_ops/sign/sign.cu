@@ -0,0 +1,11 @@
+#include "unary_op_macros.cuh"
+
+struct SignKernelOp {};
+
+UNARY_OP(float, sign_fwd_f32, sign_bwd_f32, SignKernelOp,
+        x > 0.0 ? 1.0 : (x < 0.0 ? -1.0 : x),
+        0.0)
+
+UNARY_OP(double, sign_fwd_f64, sign_bwd_f64, SignKernelOp,
+        x > 0.0 ? 1.0 : (x < 0.0 ? -1.0 : x),
+        0.0)
@@ -0,0 +1,57 @@
+use crate::{
+    shapes::*,
+    tensor::cpu::{Cpu, LendingIterator, NdIndex, StridedArray},
+};
+
+/// The index into the input buffer of the first element of the slice.
+fn offset<S: Shape>(strides: S::Concrete, starts: S::Concrete) -> usize {
+    (0..S::NUM_DIMS).map(|i| starts[i] * strides[i]).sum()
+}
+
+impl<E: Dtype> super::SliceKernel<E> for Cpu {
+    fn forward<Src: Shape, Dst: Shape>(
+        &self,
+        inp: &Self::Storage<Src, E>,
+        dst: Dst,
+        starts: Src::Concrete,
+    ) -> Result<Self::Storage<Dst, E>, Self::Err> {
+        let mut out: Self::Storage<Dst, E> = StridedArray::new(dst)?;
+        if dst.num_elements() == 0 {
+            return Ok(out);
+        }
+        let offset = offset::<Src>(inp.strides, starts);
+        let mut inp_strides: Dst::Concrete = Default::default();
+        for i in 0..Dst::NUM_DIMS {
+            inp_strides[i] = inp.strides[i];
+        }
+        let mut inp_idx = NdIndex::new(dst, inp_strides);
+        let mut out_iter = out.iter_mut();
+        while let Some(o) = out_iter.next() {
+            *o = inp.data[offset + inp_idx.next().unwrap()];
+        }
+        Ok(out)
+    }
+
+    fn backward<Src: Shape, Dst: Shape>(
+        &self,
+        grad_inp: &mut Self::Storage<Src, E>,
+        grad_out: &Self::Storage<Dst, E>,
+        starts: Src::Concrete,
+    ) -> Result<(), Self::Err> {
+        if grad_out.shape.num_elements() == 0 {
+            return Ok(());
+        }
+        let offset = offset::<Src>(grad_inp.strides, starts);
+        let mut inp_strides: Dst::Concrete = Default::default();
+        for i in 0..Dst::NUM_DIMS {
+            inp_strides[i] = grad_inp.strides[i];
+        }
+        let mut inp_idx = NdIndex::new(grad_out.shape, inp_strides);
+        let grad_inp_buf = std::sync::Arc::make_mut(&mut grad_inp.data);
+        let mut out_iter = grad_out.iter();
+        while let Some(go) = out_iter.next() {
+            grad_inp_buf[offset + inp_idx.next().unwrap()] += *go;
+        }
+        Ok(())
+    }
+}
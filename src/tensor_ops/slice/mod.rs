@@ -0,0 +1,258 @@
+use crate::{gradients::Tape, shapes::*, tensor::*};
+
+use std::ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+
+mod cpu_kernel;
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+/// A range along a single dimension that can be used with [TrySlice::slice].
+pub trait SliceDim<D: Dim> {
+    /// The dimension after slicing. Only [RangeFull] keeps the original dimension,
+    /// all other ranges result in a [usize] dim.
+    type Sliced: Dim;
+
+    /// The start (inclusive) and end (exclusive) of the range, given
+    /// the size of the dimension.
+    fn bounds(&self, size: usize) -> (usize, usize);
+}
+
+impl<D: Dim> SliceDim<D> for RangeFull {
+    type Sliced = D;
+    fn bounds(&self, size: usize) -> (usize, usize) {
+        (0, size)
+    }
+}
+
+impl<D: Dim> SliceDim<D> for Range<usize> {
+    type Sliced = usize;
+    fn bounds(&self, _: usize) -> (usize, usize) {
+        (self.start, self.end)
+    }
+}
+
+impl<D: Dim> SliceDim<D> for RangeFrom<usize> {
+    type Sliced = usize;
+    fn bounds(&self, size: usize) -> (usize, usize) {
+        (self.start, size)
+    }
+}
+
+impl<D: Dim> SliceDim<D> for RangeTo<usize> {
+    type Sliced = usize;
+    fn bounds(&self, _: usize) -> (usize, usize) {
+        (0, self.end)
+    }
+}
+
+impl<D: Dim> SliceDim<D> for RangeInclusive<usize> {
+    type Sliced = usize;
+    fn bounds(&self, _: usize) -> (usize, usize) {
+        (*self.start(), *self.end() + 1)
+    }
+}
+
+impl<D: Dim> SliceDim<D> for RangeToInclusive<usize> {
+    type Sliced = usize;
+    fn bounds(&self, _: usize) -> (usize, usize) {
+        (0, self.end + 1)
+    }
+}
+
+/// Marker for shapes that can be sliced with a tuple of ranges, one per dimension.
+pub trait SliceShape<Slice>: Shape {
+    type Sliced: Shape;
+
+    /// Returns the sliced shape, and the index of the first element of the slice.
+    fn sliced(&self, slice: &Slice) -> (Self::Sliced, Self::Concrete);
+}
+
+macro_rules! slice_shape {
+    ([$($D:ident $R:ident $Idx:tt),*]) => {
+        impl<$($D: Dim, $R: SliceDim<$D>, )*> SliceShape<($($R, )*)> for ($($D, )*) {
+            type Sliced = ($($R::Sliced, )*);
+            fn sliced(&self, slice: &($($R, )*)) -> (Self::Sliced, Self::Concrete) {
+                let dims = self.concrete();
+                let mut starts: Self::Concrete = Default::default();
+                let mut sizes: Self::Concrete = Default::default();
+                $(
+                    let (start, end) = slice.$Idx.bounds(dims[$Idx]);
+                    assert!(
+                        start <= end && end <= dims[$Idx],
+                        "slice {}..{} out of bounds for axis {} with size {}",
+                        start, end, $Idx, dims[$Idx]
+                    );
+                    starts[$Idx] = start;
+                    sizes[$Idx] = end - start;
+                )*
+                (Self::Sliced::from_concrete(&sizes).unwrap(), starts)
+            }
+        }
+    };
+}
+
+slice_shape!([D0 R0 0]);
+slice_shape!([D0 R0 0, D1 R1 1]);
+slice_shape!([D0 R0 0, D1 R1 1, D2 R2 2]);
+slice_shape!([D0 R0 0, D1 R1 1, D2 R2 2, D3 R3 3]);
+slice_shape!([D0 R0 0, D1 R1 1, D2 R2 2, D3 R3 3, D4 R4 4]);
+
+pub trait SliceKernel<E: Dtype>: DeviceStorage {
+    fn forward<Src: Shape, Dst: Shape>(
+        &self,
+        inp: &Self::Storage<Src, E>,
+        dst: Dst,
+        starts: Src::Concrete,
+    ) -> Result<Self::Storage<Dst, E>, Self::Err>;
+    fn backward<Src: Shape, Dst: Shape>(
+        &self,
+        grad_inp: &mut Self::Storage<Src, E>,
+        grad_out: &Self::Storage<Dst, E>,
+        starts: Src::Concrete,
+    ) -> Result<(), Self::Err>;
+}
+
+/// Select a contiguous region of a tensor, using a range for each axis.
+pub trait TrySlice: HasErr + HasShape {
+    /// Slice the tensor with a tuple containing one range per axis. Use `..` to keep
+    /// a whole axis. Every sliced axis becomes a [usize] dim.
+    ///
+    /// **Pytorch equivalent** `t[2:5, :, 0:3]`, or `t.narrow(dim, start, length)`
+    /// for a single axis.
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t = dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+    /// let r: Tensor<(usize, Const<3>), f32, _> = t.clone().slice((1.., ..));
+    /// assert_eq!(r.as_vec(), [4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+    ///
+    /// let r: Tensor<(usize, usize), f32, _> = t.slice((0..2, 1..=2));
+    /// assert_eq!(r.shape(), &(2, 2));
+    /// assert_eq!(r.as_vec(), [2.0, 3.0, 5.0, 6.0]);
+    /// ```
+    fn slice<Slice>(
+        self,
+        slice: Slice,
+    ) -> Self::WithShape<<Self::Shape as SliceShape<Slice>>::Sliced>
+    where
+        Self::Shape: SliceShape<Slice>,
+    {
+        self.try_slice(slice).unwrap()
+    }
+
+    /// Fallible version of [TrySlice::slice]
+    #[allow(clippy::type_complexity)]
+    fn try_slice<Slice>(
+        self,
+        slice: Slice,
+    ) -> Result<Self::WithShape<<Self::Shape as SliceShape<Slice>>::Sliced>, Self::Err>
+    where
+        Self::Shape: SliceShape<Slice>;
+}
+
+impl<S: Shape, E: Dtype, D: SliceKernel<E>, T: Tape<D>> TrySlice for Tensor<S, E, D, T> {
+    fn try_slice<Slice>(self, slice: Slice) -> Result<Self::WithShape<S::Sliced>, Self::Err>
+    where
+        S: SliceShape<Slice>,
+    {
+        let (dst, starts) = self.shape().sliced(&slice);
+        let (inp, mut tape) = self.split_tape();
+        let out = inp
+            .device
+            .upgrade(inp.device.forward(&inp.storage, dst, starts)?);
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&inp)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+            inp.device.backward(grad_inp, grad_out, starts)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_slice_1d() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([1.0, 2.0, 3.0, 4.0, 5.0]);
+        let r = t.trace().slice((1..4,));
+        assert_eq!(r.shape(), &(3,));
+        assert_eq!(r.as_vec(), [2.0, 3.0, 4.0]);
+        let g = r.exp().sum().backward();
+        assert_close(
+            &g.get(&t).array(),
+            &[0.0, 2.0f64.exp(), 3.0f64.exp(), 4.0f64.exp(), 0.0].map(|x| x as TestDtype),
+        );
+    }
+
+    #[test]
+    fn test_slice_range_kinds() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(t.clone().slice((2..,)).as_vec(), [3.0, 4.0]);
+        assert_eq!(t.clone().slice((..1,)).as_vec(), [1.0]);
+        assert_eq!(t.clone().slice((1..=2,)).as_vec(), [2.0, 3.0]);
+        assert_eq!(t.clone().slice((..=0,)).as_vec(), [1.0]);
+        let r: Tensor<Rank1<4>, TestDtype, _> = t.slice((..,));
+        assert_eq!(r.array(), [1.0, 2.0, 3.0, 4.0]);
+    }
+
+    #[test]
+    fn test_slice_3d() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank3<3, 2, 4>, TestDtype, _> = dev.sample_normal();
+        let t_array = t.array();
+        let r = t.trace().slice((1..3, .., 0..3));
+        assert_eq!(r.shape(), &(2, Const, 3));
+        let mut expected = std::vec::Vec::new();
+        for a in t_array.iter().skip(1) {
+            for b in a.iter() {
+                expected.extend_from_slice(&b[0..3]);
+            }
+        }
+        assert_eq!(r.as_vec(), expected);
+
+        let g = r.sum().backward();
+        let mut g_expected = [[[0.0; 4]; 2]; 3];
+        for a in g_expected.iter_mut().skip(1) {
+            for b in a.iter_mut() {
+                b[0..3].copy_from_slice(&[1.0; 3]);
+            }
+        }
+        assert_eq!(g.get(&t).array(), g_expected);
+    }
+
+    #[test]
+    fn test_slice_permuted() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let r = t.trace().permute::<Rank2<3, 2>, _>().slice((1.., 1..));
+        assert_eq!(r.as_vec(), [5.0, 6.0]);
+        let g = (r * dev.tensor_from_vec(std::vec![2.0, 3.0], (2, 1)))
+            .sum()
+            .backward();
+        assert_eq!(g.get(&t).array(), [[0.0; 3], [0.0, 2.0, 3.0]]);
+    }
+
+    #[test]
+    fn test_slice_empty() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank2<2, 3>, TestDtype, _> = dev.ones();
+        let r = t.slice((1..1, ..));
+        assert_eq!(r.shape(), &(0, Const));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_slice_out_of_bounds() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank1<3>, TestDtype, _> = dev.zeros();
+        let _ = t.slice((1..4,));
+    }
+}
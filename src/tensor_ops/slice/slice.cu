@@ -0,0 +1,67 @@
+#include "cuda_utils.cuh"
+
+template<typename T>
+__device__ void slice_fwd(
+    const size_t numel,
+    const size_t num_dims,
+    const size_t *dims,
+    const size_t offset,
+    const T *inp,
+    const size_t *inp_strides,
+    T *out
+) {
+    unsigned int out_i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (out_i >= numel) {
+        return;
+    }
+
+    out[out_i] = inp[offset + get_strided_index(out_i, num_dims, dims, inp_strides)];
+}
+
+template<typename T>
+__device__ void slice_bwd(
+    const size_t numel,
+    const size_t num_dims,
+    const size_t *dims,
+    const size_t offset,
+    T *grad_inp,
+    const size_t *inp_strides,
+    const T *grad_out,
+    const size_t *out_strides
+) {
+    unsigned int out_i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (out_i >= numel) {
+        return;
+    }
+
+    unsigned int inp_i = offset + get_strided_index(out_i, num_dims, dims, inp_strides);
+    atomicAdd(grad_inp + inp_i, grad_out[get_strided_index(out_i, num_dims, dims, out_strides)]);
+}
+
+#define SLICE_OP(TYPENAME, FWD, BWD) \
+extern "C" __global__ void FWD( \
+    const size_t numel, \
+    const size_t num_dims, \
+    const size_t *dims, \
+    const size_t offset, \
+    const TYPENAME *inp, \
+    const size_t *inp_strides, \
+    TYPENAME *out \
+) { \
+    slice_fwd(numel, num_dims, dims, offset, inp, inp_strides, out); \
+} \
+extern "C" __global__ void BWD( \
+    const size_t numel, \
+    const size_t num_dims, \
+    const size_t *dims, \
+    const size_t offset, \
+    TYPENAME *grad_inp, \
+    const size_t *inp_strides, \
+    const TYPENAME *grad_out, \
+    const size_t *out_strides \
+) { \
+    slice_bwd(numel, num_dims, dims, offset, grad_inp, inp_strides, grad_out, out_strides); \
+}
+
+SLICE_OP(float, slice_fwd_f32, slice_bwd_f32);
+SLICE_OP(double, slice_fwd_f64, slice_bwd_f64);
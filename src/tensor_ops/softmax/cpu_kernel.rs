@@ -0,0 +1,89 @@
+use crate::{
+    shapes::{Axes, Dtype, HasAxes, ReduceShape},
+    tensor::cpu::{Cpu, StridedArray},
+    tensor_ops::utilities::reduction_utils::index_for_reductions,
+};
+
+use num_traits::Float;
+use std::{sync::Arc, vec::Vec};
+
+impl<E: Dtype + Float> super::SoftmaxKernel<E> for Cpu {
+    fn forward<S: ReduceShape<Ax>, Ax: Axes>(
+        &self,
+        log: bool,
+        inp: &Self::Storage<S, E>,
+    ) -> Result<Self::Storage<S, E>, Self::Err> {
+        let mut out: StridedArray<S, E> = StridedArray::new(inp.shape)?;
+        let numel = inp.shape.num_elements();
+        if numel == 0 {
+            return Ok(out);
+        }
+        let row_len = <S as HasAxes<Ax>>::size(&inp.shape);
+
+        // both indices visit the elements along Ax one row after the other
+        let mut inp_idx = index_for_reductions::<S, Ax>(inp.shape, inp.strides);
+        let mut out_idx = index_for_reductions::<S, Ax>(out.shape, out.strides);
+        let inp_buf = inp.data.as_ref();
+        let out_buf = Arc::make_mut(&mut out.data);
+
+        let mut row = Vec::with_capacity(row_len);
+        for _ in 0..numel / row_len {
+            row.clear();
+            for _ in 0..row_len {
+                row.push(inp_buf[inp_idx.next().unwrap()]);
+            }
+            let max = row.iter().fold(E::neg_infinity(), |a, &x| a.max(x));
+            let sum = row.iter().fold(E::zero(), |a, &x| a + (x - max).exp());
+            let ln_sum = sum.ln();
+            for &x in row.iter() {
+                out_buf[out_idx.next().unwrap()] = if log {
+                    x - max - ln_sum
+                } else {
+                    (x - max).exp() / sum
+                };
+            }
+        }
+        Ok(out)
+    }
+
+    fn backward<S: ReduceShape<Ax>, Ax: Axes>(
+        &self,
+        log: bool,
+        grad_inp: &mut Self::Storage<S, E>,
+        out: &Self::Storage<S, E>,
+        grad_out: &Self::Storage<S, E>,
+    ) -> Result<(), Self::Err> {
+        let numel = out.shape.num_elements();
+        if numel == 0 {
+            return Ok(());
+        }
+        let row_len = <S as HasAxes<Ax>>::size(&out.shape);
+
+        // out & grad_out are both contiguous, so they share an index
+        let mut inp_idx = index_for_reductions::<S, Ax>(grad_inp.shape, grad_inp.strides);
+        let mut out_idx = index_for_reductions::<S, Ax>(out.shape, out.strides);
+        let (y, dy) = (out.data.as_slice(), grad_out.data.as_slice());
+        let grad_inp_buf = Arc::make_mut(&mut grad_inp.data);
+
+        let mut row = Vec::with_capacity(row_len);
+        for _ in 0..numel / row_len {
+            row.clear();
+            for _ in 0..row_len {
+                row.push(out_idx.next().unwrap());
+            }
+            // softmax: dx = y * (dy - sum(dy * y))
+            // log_softmax: dx = dy - exp(y) * sum(dy)
+            let sum = row.iter().fold(E::zero(), |a, &i| {
+                a + if log { dy[i] } else { dy[i] * y[i] }
+            });
+            for &i in row.iter() {
+                grad_inp_buf[inp_idx.next().unwrap()] += if log {
+                    dy[i] - y[i].exp() * sum
+                } else {
+                    y[i] * (dy[i] - sum)
+                };
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,145 @@
+use crate::{
+    shapes::*,
+    tensor::cuda::{Cuda, CudaArray},
+    tensor_ops::utilities::reduction_utils::index_for_reductions,
+};
+use cudarc::driver::{CudaSlice, DriverError, LaunchAsync, LaunchConfig};
+use std::sync::Arc;
+
+const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/softmax.ptx"));
+
+/// Has to match `BLOCK_SIZE` in softmax.cu
+const BLOCK_SIZE: u32 = 256;
+
+trait HasCudaKernel<E> {
+    const MOD: &'static str;
+    /// The forward & backward kernels of softmax, followed by the ones of log_softmax.
+    const FNS: &'static [&'static str];
+}
+
+impl HasCudaKernel<f32> for Cuda {
+    const MOD: &'static str = "softmax_f32";
+    const FNS: &'static [&'static str] = &[
+        "softmax_fwd_f32",
+        "softmax_bwd_f32",
+        "log_softmax_fwd_f32",
+        "log_softmax_bwd_f32",
+    ];
+}
+
+impl HasCudaKernel<f64> for Cuda {
+    const MOD: &'static str = "softmax_f64";
+    const FNS: &'static [&'static str] = &[
+        "softmax_fwd_f64",
+        "softmax_bwd_f64",
+        "log_softmax_fwd_f64",
+        "log_softmax_bwd_f64",
+    ];
+}
+
+/// One block per row, so that the max & sum of a row can be reduced in shared memory.
+fn launch_cfg(rows: usize) -> LaunchConfig {
+    LaunchConfig {
+        grid_dim: (rows as u32, 1, 1),
+        block_dim: (BLOCK_SIZE, 1, 1),
+        shared_mem_bytes: 0,
+    }
+}
+
+impl Cuda {
+    /// The dims of `shape`, and the strides of the input & output, permuted so that
+    /// the elements along `Ax` are last.
+    #[allow(clippy::type_complexity)]
+    fn softmax_dims<S: Shape, Ax: Axes>(
+        &self,
+        shape: S,
+        inp_strides: S::Concrete,
+        out_strides: S::Concrete,
+    ) -> Result<(CudaSlice<usize>, CudaSlice<usize>, CudaSlice<usize>), DriverError> {
+        let inp_idx = index_for_reductions::<S, Ax>(shape, inp_strides);
+        let out_idx = index_for_reductions::<S, Ax>(shape, out_strides);
+        Ok((
+            self.dev.take_async(inp_idx.shape.into())?,
+            self.dev.take_async(inp_idx.strides.into())?,
+            self.dev.take_async(out_idx.strides.into())?,
+        ))
+    }
+}
+
+impl<E: Dtype> super::SoftmaxKernel<E> for Cuda
+where
+    Self: HasCudaKernel<E>,
+{
+    fn forward<S: ReduceShape<Ax>, Ax: Axes>(
+        &self,
+        log: bool,
+        inp: &Self::Storage<S, E>,
+    ) -> Result<Self::Storage<S, E>, Self::Err> {
+        if !self.dev.has_func(Self::MOD, Self::FNS[0]) {
+            self.dev.load_ptx(PTX_SRC.into(), Self::MOD, Self::FNS)?;
+        }
+
+        let shape = inp.shape;
+        let numel = shape.num_elements();
+        let mut out = CudaArray {
+            data: Arc::new(unsafe { self.dev.alloc_async::<E>(numel) }?),
+            shape,
+            strides: shape.strides(),
+        };
+        if numel == 0 {
+            return Ok(out);
+        }
+
+        let row_len = <S as HasAxes<Ax>>::size(&shape);
+        let (dims, inp_strides, out_strides) =
+            self.softmax_dims::<S, Ax>(shape, inp.strides, out.strides)?;
+        let fwd_fn = self
+            .dev
+            .get_func(Self::MOD, Self::FNS[2 * log as usize])
+            .unwrap();
+        let params = (
+            row_len,                      // const size_t row_len,
+            S::NUM_DIMS,                  // const size_t num_dims,
+            &dims,                        // const size_t *dims,
+            &inp_strides,                 // const size_t *inp_strides,
+            &out_strides,                 // const size_t *out_strides,
+            inp.data.as_ref(),            // const T *inp,
+            Arc::make_mut(&mut out.data), // T *out
+        );
+        unsafe { fwd_fn.launch_async(launch_cfg(numel / row_len), params) }?;
+        Ok(out)
+    }
+
+    fn backward<S: ReduceShape<Ax>, Ax: Axes>(
+        &self,
+        log: bool,
+        grad_inp: &mut Self::Storage<S, E>,
+        out: &Self::Storage<S, E>,
+        grad_out: &Self::Storage<S, E>,
+    ) -> Result<(), Self::Err> {
+        let numel = out.shape.num_elements();
+        if numel == 0 {
+            return Ok(());
+        }
+
+        let row_len = <S as HasAxes<Ax>>::size(&out.shape);
+        let (dims, inp_strides, out_strides) =
+            self.softmax_dims::<S, Ax>(out.shape, grad_inp.strides, out.strides)?;
+        let bwd_fn = self
+            .dev
+            .get_func(Self::MOD, Self::FNS[2 * log as usize + 1])
+            .unwrap();
+        let params = (
+            row_len,                           // const size_t row_len,
+            S::NUM_DIMS,                       // const size_t num_dims,
+            &dims,                             // const size_t *dims,
+            &inp_strides,                      // const size_t *inp_strides,
+            &out_strides,                      // const size_t *out_strides,
+            Arc::make_mut(&mut grad_inp.data), // T *grad_inp,
+            out.data.as_ref(),                 // const T *out,
+            grad_out.data.as_ref(),            // const T *grad_out
+        );
+        unsafe { bwd_fn.launch_async(launch_cfg(numel / row_len), params) }?;
+        Ok(())
+    }
+}
@@ -0,0 +1,268 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use super::Device;
+use crate::{
+    gradients::Tape,
+    shapes::*,
+    tensor::{DeviceStorage, PutTape, SplitTape, Tensor},
+};
+
+pub trait SoftmaxKernel<E: Dtype>: DeviceStorage {
+    /// Computes the softmax of every set of elements along `Ax`, or the log_softmax
+    /// if `log` is true.
+    fn forward<S: ReduceShape<Ax>, Ax: Axes>(
+        &self,
+        log: bool,
+        inp: &Self::Storage<S, E>,
+    ) -> Result<Self::Storage<S, E>, Self::Err>;
+
+    /// Only needs the output of the forward pass, which is what the gradient of
+    /// both functions is expressed in.
+    fn backward<S: ReduceShape<Ax>, Ax: Axes>(
+        &self,
+        log: bool,
+        grad_inp: &mut Self::Storage<S, E>,
+        out: &Self::Storage<S, E>,
+        grad_out: &Self::Storage<S, E>,
+    ) -> Result<(), Self::Err>;
+}
+
+/// Computes the [softmax function](https://en.wikipedia.org/wiki/Softmax_function) across
+/// `Ax`.
+///
+/// Equivalent to `exp(log_softmax(t))`, and computed in a single fused kernel.
+///
+/// **Pytorch equivalent**: `t.softmax(Axes)`
+///
+/// Example:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t: Tensor<Rank3<2, 3, 5>, f32, _> = dev.zeros();
+/// let _ = t.softmax::<Axis<2>>();
+/// ```
+///
+/// Any axis, or multiple axes, can be used:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// # let t: Tensor<Rank3<2, 3, 5>, f32, _> = dev.zeros();
+/// let _ = t.clone().softmax::<Axis<0>>();
+/// let _ = t.softmax::<Axes2<0, 2>>();
+/// ```
+pub fn softmax<Ax: Axes, S: Shape, E: Dtype, D: Device<E>, T: Tape<D>>(
+    t: Tensor<S, E, D, T>,
+) -> Tensor<S, E, D, T>
+where
+    S: ReduceShape<Ax>,
+{
+    t.softmax::<Ax>()
+}
+
+impl<S: Shape, E: Dtype, D: Device<E>, T: Tape<D>> Tensor<S, E, D, T> {
+    /// See [softmax]
+    pub fn softmax<Ax: Axes>(self) -> Self
+    where
+        S: ReduceShape<Ax>,
+    {
+        self.try_softmax::<Ax>().unwrap()
+    }
+    /// See [softmax]
+    pub fn try_softmax<Ax: Axes>(self) -> Result<Self, D::Err>
+    where
+        S: ReduceShape<Ax>,
+    {
+        self.try_softmax_kernel::<Ax>(false)
+    }
+}
+
+impl<S: Shape, E: Dtype, D: SoftmaxKernel<E>, T: Tape<D>> Tensor<S, E, D, T> {
+    /// Runs [SoftmaxKernel], which computes the log_softmax if `log` is true.
+    pub(super) fn try_softmax_kernel<Ax: Axes>(self, log: bool) -> Result<Self, D::Err>
+    where
+        S: ReduceShape<Ax>,
+    {
+        let (inp, mut tape) = self.split_tape();
+        let out = inp
+            .device
+            .upgrade(inp.device.forward::<S, Ax>(log, &inp.storage)?);
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&inp)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+            inp.device
+                .backward::<S, Ax>(log, grad_inp, &phantom_out.storage, grad_out)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{gradients::OwnedTape, shapes::*, tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_softmax_0d() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<_, TestDtype, _> = dev.tensor(3.0);
+        assert_eq!(a.clone().softmax().array(), 1.0);
+        assert_eq!(a.log_softmax().array(), 0.0);
+    }
+
+    #[test]
+    fn test_softmax_1d() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<_, TestDtype, _> = dev.tensor([-2.0, -1.0, 0.0, 1.0, 2.0]);
+        let r = a.trace().softmax();
+        assert_close(
+            &r.array(),
+            &[0.011656232, 0.031684924, 0.086128555, 0.23412168, 0.6364087],
+        );
+        let l = r * dev.tensor([0.0, 0.0, 1.0, 0.0, 0.0]);
+        assert_close(&l.array(), &[0.0, 0.0, 0.086128555, 0.0, 0.0]);
+        let g = l.mean().backward();
+        assert_close(
+            &g.get(&a).array(),
+            &[
+                -0.00020078686,
+                -0.00054579525,
+                0.015742086,
+                -0.0040329117,
+                -0.010962591,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_softmax_2d() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<_, TestDtype, _> = dev.tensor([[-2.0, -1.0, 0.0], [1.0, 4.0, 7.0]]);
+        let r = a.trace().softmax::<Axis<1>>();
+        assert_close(
+            &r.array(),
+            &[
+                [0.09003058, 0.24472849, 0.66524094],
+                [0.002355633, 0.047314156, 0.9503302],
+            ],
+        );
+        let l = r * dev.tensor([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]);
+        assert_close(
+            &l.array(),
+            &[[0.09003058, 0.0, 0.0], [0.0, 0.047314156, 0.0]],
+        );
+        let g = l.mean().backward();
+        assert_close(
+            &g.get(&a).array(),
+            &[
+                [0.01365418, -0.0036721744, -0.009982005],
+                [-1.85758e-5, 0.0075125876, -0.0074940124],
+            ],
+        );
+    }
+
+    #[test]
+    fn test_softmax_2d_0th_axis() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<_, TestDtype, _> = dev.tensor([[-2.0, -1.0, 0.0], [1.0, 4.0, 7.0]]);
+        let r = a.trace().softmax::<Axis<0>>();
+        assert_close(
+            &r.array(),
+            &[
+                [0.047425874, 0.0066928514, 0.0009110514],
+                [0.95257413, 0.9933072, 0.9990892],
+            ],
+        );
+        let l = r * dev.tensor([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]);
+        assert_close(
+            &l.array(),
+            &[[0.047425874, 0.0, 0.0], [0.0, 0.9933072, 0.0]],
+        );
+        let g = l.mean().backward();
+        assert_close(
+            &g.get(&a).array(),
+            &[
+                [0.0075294436, -0.0011080095, 0.0],
+                [-0.0075294436, 0.0011080056, 0.0],
+            ],
+        );
+    }
+
+    #[test]
+    fn test_softmax_3d_to_1d_12() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank3<2, 3, 4>, TestDtype, _> = dev.sample_normal();
+        let r = t.trace().softmax::<Axes2<1, 2>>();
+        #[rustfmt::skip]
+        assert_close(
+            &r.array(),
+            &[
+                [[0.08535644, 0.0987266, 0.00366116, 0.04927256], [0.01169326, 0.1515922, 0.00951258, 0.07721686], [0.0776206, 0.23813945, 0.19471556, 0.00249278]],
+                [[0.01881982, 0.25171953, 0.02559674, 0.03725754], [0.04064152, 0.314442, 0.02427996, 0.04708378], [0.02791536, 0.14462142, 0.02221143, 0.04541067]],
+            ],
+        );
+    }
+
+    #[test]
+    fn test_softmax_from_end_generic_rank() {
+        fn softmax_last<S: Shape + AxisFromEnd<1>, D: Device<TestDtype>>(
+            t: Tensor<S, TestDtype, D>,
+        ) -> Tensor<S, TestDtype, D>
+        where
+            S: ReduceShape<FromEnd<S, 1>>,
+        {
+            t.softmax::<FromEnd<S, 1>>()
+        }
+
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank2<2, 3>, TestDtype, _> = dev.sample_normal();
+        assert_eq!(
+            softmax_last(t.clone()).array(),
+            t.clone().softmax::<Axis<1>>().array()
+        );
+        let t: Tensor<Rank3<4, 2, 3>, TestDtype, _> = dev.sample_normal();
+        assert_eq!(
+            softmax_last(t.clone()).array(),
+            t.softmax::<Axis<2>>().array()
+        );
+    }
+
+    #[test]
+    fn test_softmax_of_broadcast_and_permuted() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank2<3, 4>, TestDtype, _> = dev.sample_normal();
+        let w: Tensor<Rank3<4, 2, 3>, TestDtype, _> = dev.sample_normal();
+        // broadcast along one of the axes, and permute so no axis is contiguous
+        let x = || {
+            t.trace()
+                .broadcast::<Rank3<3, 2, 4>, _>()
+                .permute::<Rank3<4, 2, 3>, _>()
+        };
+
+        let log_softmax = || {
+            let x = x();
+            let lse = x
+                .retaped::<OwnedTape<_>>()
+                .logsumexp::<Rank1<4>, _>()
+                .broadcast();
+            x - lse
+        };
+
+        let r = x().softmax::<Axes2<1, 2>>();
+        let r2 = log_softmax().exp();
+        assert_close(&r.array(), &r2.array());
+        let g = (r * w.clone()).sum().backward();
+        let g2 = (r2 * w.clone()).sum().backward();
+        assert_close(&g.get(&t).array(), &g2.get(&t).array());
+
+        let r = x().log_softmax::<Axes2<1, 2>>();
+        let r2 = log_softmax();
+        assert_close(&r.array(), &r2.array());
+        let g = (r * w.clone()).sum().backward();
+        let g2 = (r2 * w).sum().backward();
+        assert_close(&g.get(&t).array(), &g2.get(&t).array());
+    }
+}
@@ -0,0 +1,101 @@
+#include "cuda_utils.cuh"
+
+// must be a power of two, as it's used for the tree reductions below
+#define BLOCK_SIZE 256
+
+// One block per row, i.e. per set of elements along the reduced axes. `dims` and both
+// strides are permuted so that the elements of a row are next to each other in logical
+// order. `out` is contiguous.
+template<typename T, bool LOG>
+__device__ void softmax_fwd(
+    const size_t row_len,
+    const size_t num_dims,
+    const size_t *dims,
+    const size_t *inp_strides,
+    const size_t *out_strides,
+    const T *inp,
+    T *out
+) {
+    __shared__ T buf[BLOCK_SIZE];
+    size_t start = blockIdx.x * row_len;
+
+    T max = -INFINITY;
+    for (size_t k = threadIdx.x; k < row_len; k += blockDim.x) {
+        max = maxg(max, inp[get_strided_index(start + k, num_dims, dims, inp_strides)]);
+    }
+    max = block_max(max, buf);
+
+    T sum = 0.0;
+    for (size_t k = threadIdx.x; k < row_len; k += blockDim.x) {
+        sum += expg(inp[get_strided_index(start + k, num_dims, dims, inp_strides)] - max);
+    }
+    sum = block_sum(sum, buf);
+    T ln_sum = logg(sum);
+
+    for (size_t k = threadIdx.x; k < row_len; k += blockDim.x) {
+        T x = inp[get_strided_index(start + k, num_dims, dims, inp_strides)] - max;
+        out[get_strided_index(start + k, num_dims, dims, out_strides)] = LOG ? x - ln_sum : expg(x) / sum;
+    }
+}
+
+// One block per row, like `softmax_fwd`. `out` & `grad_out` are contiguous, and `grad_inp`
+// has the strides of `inp`.
+template<typename T, bool LOG>
+__device__ void softmax_bwd(
+    const size_t row_len,
+    const size_t num_dims,
+    const size_t *dims,
+    const size_t *inp_strides,
+    const size_t *out_strides,
+    T *grad_inp,
+    const T *out,
+    const T *grad_out
+) {
+    __shared__ T buf[BLOCK_SIZE];
+    size_t start = blockIdx.x * row_len;
+
+    // softmax: dx = y * (dy - sum(dy * y))
+    // log_softmax: dx = dy - exp(y) * sum(dy)
+    T sum = 0.0;
+    for (size_t k = threadIdx.x; k < row_len; k += blockDim.x) {
+        size_t i = get_strided_index(start + k, num_dims, dims, out_strides);
+        sum += LOG ? grad_out[i] : grad_out[i] * out[i];
+    }
+    sum = block_sum(sum, buf);
+
+    for (size_t k = threadIdx.x; k < row_len; k += blockDim.x) {
+        size_t i = get_strided_index(start + k, num_dims, dims, out_strides);
+        T dx = LOG ? grad_out[i] - expg(out[i]) * sum : out[i] * (grad_out[i] - sum);
+        atomicAdd(grad_inp + get_strided_index(start + k, num_dims, dims, inp_strides), dx);
+    }
+}
+
+#define SOFTMAX_OP(TYPENAME, LOG, FWD, BWD) \
+extern "C" __global__ void FWD( \
+    const size_t row_len, \
+    const size_t num_dims, \
+    const size_t *dims, \
+    const size_t *inp_strides, \
+    const size_t *out_strides, \
+    const TYPENAME *inp, \
+    TYPENAME *out \
+) { \
+    softmax_fwd<TYPENAME, LOG>(row_len, num_dims, dims, inp_strides, out_strides, inp, out); \
+} \
+extern "C" __global__ void BWD( \
+    const size_t row_len, \
+    const size_t num_dims, \
+    const size_t *dims, \
+    const size_t *inp_strides, \
+    const size_t *out_strides, \
+    TYPENAME *grad_inp, \
+    const TYPENAME *out, \
+    const TYPENAME *grad_out \
+) { \
+    softmax_bwd<TYPENAME, LOG>(row_len, num_dims, dims, inp_strides, out_strides, grad_inp, out, grad_out); \
+}
+
+SOFTMAX_OP(float, false, softmax_fwd_f32, softmax_bwd_f32);
+SOFTMAX_OP(float, true, log_softmax_fwd_f32, log_softmax_bwd_f32);
+SOFTMAX_OP(double, false, softmax_fwd_f64, softmax_bwd_f64);
+SOFTMAX_OP(double, true, log_softmax_fwd_f64, log_softmax_bwd_f64);
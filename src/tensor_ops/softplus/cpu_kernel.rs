@@ -0,0 +1,24 @@
+use crate::tensor_ops::cpu_kernels::UnaryDerivative;
+use num_traits::Float;
+
+impl<F: Float> UnaryDerivative<F> for super::SoftplusKernelOp<F> {
+    #[inline(always)]
+    fn f(&self, &x: &F) -> F {
+        let bx = self.beta * x;
+        if bx > self.threshold {
+            x
+        } else {
+            // max(bx, 0) + ln(1 + e^-|bx|) never overflows
+            (bx.max(F::zero()) + (-bx.abs()).exp().ln_1p()) / self.beta
+        }
+    }
+    #[inline(always)]
+    fn df(&self, &x: &F) -> F {
+        let bx = self.beta * x;
+        if bx > self.threshold {
+            F::one()
+        } else {
+            (F::one() + (-bx).exp()).recip()
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use super::SoftplusKernelOp as Softplus;
+use crate::tensor_ops::cuda_kernels::cuda_unary;
+
+unsafe impl cudarc::driver::AsKernelParam for Softplus<f32> {}
+unsafe impl cudarc::driver::AsKernelParam for Softplus<f64> {}
+
+const PTX: &str = include_str!(concat!(env!("OUT_DIR"), "/softplus.ptx"));
+
+cuda_unary!(
+    Softplus<f32>,
+    f32,
+    PTX,
+    "softplus_fwd_f32",
+    "softplus_bwd_f32"
+);
+cuda_unary!(
+    Softplus<f64>,
+    f64,
+    PTX,
+    "softplus_fwd_f64",
+    "softplus_bwd_f64"
+);
@@ -0,0 +1,101 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use super::ops::{try_unary_op, UnaryKernel};
+use crate::{gradients::Tape, shapes::*, tensor::Tensor};
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct SoftplusKernelOp<E> {
+    pub beta: E,
+    pub threshold: E,
+}
+
+/// [Softplus](https://en.wikipedia.org/wiki/Rectifier_(neural_networks)#Softplus)
+/// `ln(1 + e^(beta * t)) / beta`. Reverts to the identity when `beta * t > threshold`.
+///
+/// This is computed as `(max(beta * t, 0) + ln(1 + e^-|beta * t|)) / beta`, so it doesn't
+/// overflow for large inputs like composing [exp()](crate::tensor_ops::exp) and
+/// [ln()](crate::tensor_ops::ln) does.
+///
+/// It's derivative is `sigmoid(beta * t)`, or `1` above the threshold.
+///
+/// **Pytorch equivalent**: `torch.nn.functional.softplus(t, beta, threshold)`
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([-1.0, 0.0, 1.0, 100.0]);
+/// let r = t.softplus(1.0, 20.0);
+/// assert_eq!(r.array()[3], 100.0);
+/// ```
+pub fn softplus<S: Shape, E: Dtype, D: UnaryKernel<SoftplusKernelOp<E>, E>, T: Tape<D>>(
+    t: Tensor<S, E, D, T>,
+    beta: E,
+    threshold: E,
+) -> Tensor<S, E, D, T> {
+    t.softplus(beta, threshold)
+}
+
+impl<S: Shape, E: Dtype, D: UnaryKernel<SoftplusKernelOp<E>, E>, T: Tape<D>> Tensor<S, E, D, T> {
+    /// See [softplus]
+    pub fn softplus(self, beta: E, threshold: E) -> Self {
+        self.try_softplus(beta, threshold).unwrap()
+    }
+    /// See [softplus]
+    pub fn try_softplus(self, beta: E, threshold: E) -> Result<Self, D::Err> {
+        try_unary_op(SoftplusKernelOp { beta, threshold }, self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{tensor::*, tensor_ops::*, tests::*};
+
+    const LN_2: TestDtype = core::f64::consts::LN_2 as TestDtype;
+
+    #[test]
+    fn test_softplus() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([-30.0, -1.0, 0.0, 1.0, 30.0]);
+        let r = x.trace().softplus(1.0, 20.0);
+        assert_close(
+            &r.array(),
+            &[9.357623e-14, 0.3132617, LN_2, 1.3132616, 30.0],
+        );
+        let g = r.mean().backward();
+        assert_close(
+            &g.get(&x).array(),
+            &[1.8715246e-14, 0.053788286, 0.1, 0.14621171, 0.2],
+        );
+    }
+
+    #[test]
+    fn test_softplus_beta_threshold() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([-2.0, -0.5, 0.0, 0.4, 0.6]);
+        let r = x.trace().softplus(2.0, 1.0);
+        assert_close(
+            &r.array(),
+            &[0.009074964, 0.15663084, 0.3465736, 0.5855503, 0.6],
+        );
+        let g = r.mean().backward();
+        assert_close(
+            &g.get(&x).array(),
+            &[0.003597242, 0.053788286, 0.1, 0.1379949, 0.2],
+        );
+    }
+
+    #[test]
+    fn test_softplus_large_inputs() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([-1000.0, 1000.0]);
+        let r = x.trace().softplus(1.0, TestDtype::INFINITY);
+        assert_eq!(r.array(), [0.0, 1000.0]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&x).array(), [0.0, 1.0]);
+    }
+}
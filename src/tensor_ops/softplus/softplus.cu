@@ -0,0 +1,34 @@
+#include "unary_op_macros.cuh"
+#include "cuda_utils.cuh"
+
+template<typename F>
+struct SoftplusKernelOp {
+    F beta;
+    F threshold;
+};
+
+template<typename T>
+__device__ T softplus_fwd(SoftplusKernelOp<T> op, T x) {
+    T bx = op.beta * x;
+    if (bx > op.threshold) {
+        return x;
+    }
+    return (maxg(bx, (T)0.0) + log1pg(expg(-absg(bx)))) / op.beta;
+}
+
+template<typename T>
+__device__ T softplus_bwd(SoftplusKernelOp<T> op, T x) {
+    T bx = op.beta * x;
+    if (bx > op.threshold) {
+        return 1.0;
+    }
+    return 1.0 / (1.0 + expg(-bx));
+}
+
+UNARY_OP(float, softplus_fwd_f32, softplus_bwd_f32, SoftplusKernelOp<float>,
+    softplus_fwd(op, x),
+    softplus_bwd(op, x))
+
+UNARY_OP(double, softplus_fwd_f64, softplus_bwd_f64, SoftplusKernelOp<double>,
+    softplus_fwd(op, x),
+    softplus_bwd(op, x))
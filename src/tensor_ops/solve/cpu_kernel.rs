@@ -0,0 +1,114 @@
+use crate::{
+    shapes::*,
+    tensor::{
+        cpu::{Cpu, LendingIterator, StridedArray},
+        AsVec,
+    },
+    tensor_ops::utilities::linalg::{lu_factor, lu_solve, triangular_solve, MatrixShape},
+};
+
+use super::SolveKind;
+
+use num_traits::Float;
+use std::sync::Arc;
+
+/// Solves `A X = B` in place for the `n x n` matrix `a` and `n x m` matrix `b`.
+fn solve_in_place<E: Float>(kind: SolveKind, a: &[E], n: usize, b: &mut [E], m: usize) {
+    match kind {
+        SolveKind::General => {
+            let mut lu = a.to_vec();
+            let (perm, _) = lu_factor(&mut lu, n);
+            lu_solve(&lu, &perm, n, b, m);
+        }
+        SolveKind::Lower => triangular_solve(a, n, b, m, false),
+        SolveKind::Upper => triangular_solve(a, n, b, m, true),
+    }
+}
+
+impl<E: Dtype + Float> super::SolveKernel<E> for Cpu {
+    fn forward<A: MatrixShape, B: MatrixShape>(
+        &self,
+        kind: SolveKind,
+        a: &Self::Storage<A, E>,
+        b: &Self::Storage<B, E>,
+    ) -> Result<Self::Storage<B, E>, Self::Err> {
+        let (n, m) = (b.shape.rows(), b.shape.cols());
+        let mut out: StridedArray<B, E> = StridedArray::new(b.shape)?;
+        if b.shape.num_elements() == 0 {
+            return Ok(out);
+        }
+        let a_buf = a.as_vec();
+        let out_buf = Arc::make_mut(&mut out.data);
+        out_buf.copy_from_slice(&b.as_vec());
+        for (a, x) in a_buf
+            .chunks_exact(n * n)
+            .zip(out_buf.chunks_exact_mut(n * m))
+        {
+            solve_in_place(kind, a, n, x, m);
+        }
+        Ok(out)
+    }
+
+    fn backward<A: MatrixShape, B: MatrixShape>(
+        &self,
+        kind: SolveKind,
+        a: &Self::Storage<A, E>,
+        grad_a: &mut Self::Storage<A, E>,
+        grad_b: &mut Self::Storage<B, E>,
+        out: &Self::Storage<B, E>,
+        grad_out: &Self::Storage<B, E>,
+    ) -> Result<(), Self::Err> {
+        let (n, m) = (out.shape.rows(), out.shape.cols());
+        if grad_a.shape.num_elements() == 0 {
+            return Ok(());
+        }
+        let kind_t = match kind {
+            SolveKind::General => SolveKind::General,
+            SolveKind::Lower => SolveKind::Upper,
+            SolveKind::Upper => SolveKind::Lower,
+        };
+        let a_buf = a.as_vec();
+        let out_buf = out.as_vec();
+        let mut gb_buf = grad_out.as_vec();
+
+        let mut a_t = alloc::vec![E::zero(); n * n];
+        let mut grad_a_iter = grad_a.iter_mut();
+        for ((a, x), gb) in a_buf
+            .chunks_exact(n * n)
+            .zip(out_buf.chunks_exact(n * m))
+            .zip(gb_buf.chunks_exact_mut(n * m))
+        {
+            // grad_b = A^-T grad_out
+            for i in 0..n {
+                for j in 0..n {
+                    a_t[j * n + i] = a[i * n + j];
+                }
+            }
+            solve_in_place(kind_t, &a_t, n, gb, m);
+
+            // grad_a = -grad_b x^T
+            for i in 0..n {
+                for j in 0..n {
+                    let g = grad_a_iter.next().unwrap();
+                    let read = match kind {
+                        SolveKind::General => true,
+                        SolveKind::Lower => j <= i,
+                        SolveKind::Upper => j >= i,
+                    };
+                    if read {
+                        for k in 0..m {
+                            *g -= gb[i * m + k] * x[j * m + k];
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut gb_iter = gb_buf.iter();
+        let mut grad_b_iter = grad_b.iter_mut();
+        while let Some(g) = grad_b_iter.next() {
+            *g += *gb_iter.next().unwrap();
+        }
+        Ok(())
+    }
+}
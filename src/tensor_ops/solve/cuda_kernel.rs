@@ -0,0 +1,35 @@
+use crate::{shapes::*, tensor::cuda::Cuda, tensor_ops::utilities::linalg::MatrixShape};
+
+use super::SolveKind;
+
+use num_traits::Float;
+
+impl<E: Dtype + Float> super::SolveKernel<E> for Cuda {
+    fn forward<A: MatrixShape, B: MatrixShape>(
+        &self,
+        kind: SolveKind,
+        a: &Self::Storage<A, E>,
+        b: &Self::Storage<B, E>,
+    ) -> Result<Self::Storage<B, E>, Self::Err> {
+        self.on_host((a, b), |cpu, (a, b)| cpu.forward(kind, &a, &b))
+    }
+
+    fn backward<A: MatrixShape, B: MatrixShape>(
+        &self,
+        kind: SolveKind,
+        a: &Self::Storage<A, E>,
+        grad_a: &mut Self::Storage<A, E>,
+        grad_b: &mut Self::Storage<B, E>,
+        out: &Self::Storage<B, E>,
+        grad_out: &Self::Storage<B, E>,
+    ) -> Result<(), Self::Err> {
+        (*grad_a, *grad_b) = self.on_host(
+            ((a, out, grad_out), (&*grad_a, &*grad_b)),
+            |cpu, ((a, out, grad_out), (mut grad_a, mut grad_b))| {
+                cpu.backward(kind, &a, &mut grad_a, &mut grad_b, &out, &grad_out)?;
+                Ok((grad_a, grad_b))
+            },
+        )?;
+        Ok(())
+    }
+}
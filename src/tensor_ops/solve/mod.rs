@@ -0,0 +1,249 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{
+    gradients::{Merge, Tape},
+    shapes::*,
+    tensor::*,
+};
+
+use super::utilities::linalg::MatrixShape;
+
+/// Which part of the coefficient matrix a linear solve reads.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SolveKind {
+    General,
+    Lower,
+    Upper,
+}
+
+pub trait SolveKernel<E: Dtype>: DeviceStorage {
+    fn forward<A: MatrixShape, B: MatrixShape>(
+        &self,
+        kind: SolveKind,
+        a: &Self::Storage<A, E>,
+        b: &Self::Storage<B, E>,
+    ) -> Result<Self::Storage<B, E>, Self::Err>;
+
+    /// With `out = A^-1 B`, adds `A^-T grad_out` to `grad_b` and `-A^-T grad_out out^T`
+    /// to `grad_a`, restricted to the triangle that was read.
+    #[allow(clippy::too_many_arguments)]
+    fn backward<A: MatrixShape, B: MatrixShape>(
+        &self,
+        kind: SolveKind,
+        a: &Self::Storage<A, E>,
+        grad_a: &mut Self::Storage<A, E>,
+        grad_b: &mut Self::Storage<B, E>,
+        out: &Self::Storage<B, E>,
+        grad_out: &Self::Storage<B, E>,
+    ) -> Result<(), Self::Err>;
+}
+
+/// Solves the linear systems `A X = B` for `X`, where `A` is a batch of square matrices
+/// of shape `(.., N, N)` and `B` has shape `(.., N, K)`, with matching batch axes.
+///
+/// Gradients flow into both `A` and `B`. Singular matrices result in non finite values.
+///
+/// **Pytorch equivalent** `torch.linalg.solve(a, b)`
+///
+/// **Cuda**: currently syncs to the host. The systems are solved on the cpu, and
+/// each call and backward pass copies `A` and `B` to the host and back.
+///
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let a = dev.tensor([[2.0, 1.0], [1.0, 3.0]]);
+/// let b = dev.tensor([[4.0], [7.0]]);
+/// let x = solve(a, b);
+/// assert_eq!(x.array(), [[1.0], [2.0]]);
+/// ```
+pub fn solve<A: TrySolve<B>, B>(a: A, b: B) -> A::Output {
+    a.solve(b)
+}
+
+/// Solves the linear systems `A X = B` for `X`, where `A` is a batch of upper or lower
+/// triangular matrices. Only the upper or lower triangle of `A` is read, and only
+/// that triangle receives gradients. See [solve] for shapes.
+///
+/// **Pytorch equivalent** `torch.linalg.solve_triangular(a, b, upper=upper)`
+///
+/// **Cuda**: currently syncs to the host, like [solve].
+///
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let a = dev.tensor([[2.0, 100.0], [1.0, 4.0]]);
+/// let b = dev.tensor([[2.0, 4.0], [5.0, 6.0]]);
+/// let x = triangular_solve(a, b, false);
+/// assert_eq!(x.array(), [[1.0, 2.0], [1.0, 1.0]]);
+/// ```
+pub fn triangular_solve<A: TrySolve<B>, B>(a: A, b: B, upper: bool) -> A::Output {
+    a.triangular_solve(b, upper)
+}
+
+/// Batched linear solves. See [solve] and [triangular_solve].
+pub trait TrySolve<Rhs>: HasErr {
+    type Output;
+
+    /// See [solve]
+    fn solve(self, rhs: Rhs) -> Self::Output {
+        self.try_solve(rhs).unwrap()
+    }
+
+    /// Fallible version of [TrySolve::solve]
+    fn try_solve(self, rhs: Rhs) -> Result<Self::Output, Self::Err>;
+
+    /// See [triangular_solve]
+    fn triangular_solve(self, rhs: Rhs, upper: bool) -> Self::Output {
+        self.try_triangular_solve(rhs, upper).unwrap()
+    }
+
+    /// Fallible version of [TrySolve::triangular_solve]
+    fn try_triangular_solve(self, rhs: Rhs, upper: bool) -> Result<Self::Output, Self::Err>;
+}
+
+fn try_solve<
+    A: MatrixShape,
+    B: MatrixShape<Batch = A::Batch>,
+    E: Dtype,
+    D: SolveKernel<E>,
+    LTape,
+    RTape,
+>(
+    kind: SolveKind,
+    a: Tensor<A, E, D, LTape>,
+    b: Tensor<B, E, D, RTape>,
+) -> Result<Tensor<B, E, D, LTape>, D::Err>
+where
+    LTape: Tape<D> + Merge<RTape>,
+    RTape: Tape<D>,
+{
+    assert_eq!(a.shape().rows(), a.shape().cols(), "A must be square");
+    assert_eq!(a.shape().batch().concrete(), b.shape().batch().concrete());
+    assert_eq!(a.shape().cols(), b.shape().rows());
+    let (a, ltape) = a.split_tape();
+    let (b, rtape) = b.split_tape();
+    let mut tape = ltape.merge(rtape);
+    let out = a
+        .device
+        .upgrade(a.device.forward(kind, &a.storage, &b.storage)?);
+    let phantom_out = out.clone();
+    tape.try_alloc_grad(&a)?;
+    tape.try_alloc_grad(&b)?;
+    tape.try_alloc_grad(&out)?;
+    tape.add_backward_op(move |grads| {
+        let (grad_a, grad_b, grad_out) = grads.muts_and_ref(&a, &b, &phantom_out);
+        a.device.backward(
+            kind,
+            &a.storage,
+            grad_a,
+            grad_b,
+            &phantom_out.storage,
+            grad_out,
+        )
+    });
+    Ok(out.put_tape(tape))
+}
+
+macro_rules! solve {
+    ($($B:ident),*) => {
+        impl<$($B: Dim, )* N: Dim, K: Dim, E: Dtype, D: SolveKernel<E>, LTape, RTape>
+            TrySolve<Tensor<($($B, )* N, K), E, D, RTape>> for Tensor<($($B, )* N, N), E, D, LTape>
+        where
+            LTape: Tape<D> + Merge<RTape>,
+            RTape: Tape<D>,
+        {
+            type Output = Tensor<($($B, )* N, K), E, D, LTape>;
+
+            fn try_solve(
+                self,
+                rhs: Tensor<($($B, )* N, K), E, D, RTape>,
+            ) -> Result<Self::Output, Self::Err> {
+                try_solve(SolveKind::General, self, rhs)
+            }
+
+            fn try_triangular_solve(
+                self,
+                rhs: Tensor<($($B, )* N, K), E, D, RTape>,
+                upper: bool,
+            ) -> Result<Self::Output, Self::Err> {
+                let kind = if upper { SolveKind::Upper } else { SolveKind::Lower };
+                try_solve(kind, self, rhs)
+            }
+        }
+    };
+}
+
+solve!();
+solve!(B0);
+solve!(B0, B1);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_solve_2d() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<_, TestDtype, _> = dev.tensor([[2.0, 1.0], [1.0, 3.0]]);
+        let b: Tensor<_, TestDtype, _> = dev.tensor([[3.0, 1.0], [5.0, 0.0]]);
+        let x = a.trace().solve(b.trace());
+        assert_close(&x.array(), &[[0.8, 0.6], [1.4, -0.2]]);
+        let g = x.sum().backward();
+        // grad_b = A^-T 1, grad_a = -grad_b x^T
+        assert_close(&g.get(&b).array(), &[[0.4, 0.4], [0.2, 0.2]]);
+        assert_close(&g.get(&a).array(), &[[-0.56, -0.48], [-0.28, -0.24]]);
+    }
+
+    #[test]
+    fn test_solve_matches_matmul() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<Rank3<2, 3, 3>, TestDtype, _> = dev.sample_normal();
+        let x: Tensor<Rank3<2, 3, 4>, TestDtype, _> = dev.sample_normal();
+        let b = a.clone().matmul(x.clone());
+        let r = solve(a.trace(), b.trace());
+        assert_close_with_tolerance(&r.array(), &x.array(), 1e-4);
+
+        // grad_b = A^-T w and grad_a = -grad_b x^T
+        let w: Tensor<Rank3<2, 3, 4>, TestDtype, _> = dev.sample_normal();
+        let g = (r * w.clone()).sum().backward();
+        let grad_b = dev.tensor(g.get(&b).array());
+        let a_t = a.clone().permute::<_, Axes3<0, 2, 1>>();
+        assert_close_with_tolerance(&a_t.matmul(grad_b.clone()).array(), &w.array(), 1e-4);
+        let grad_a = -grad_b.matmul(x.permute::<_, Axes3<0, 2, 1>>());
+        assert_close_with_tolerance(&g.get(&a).array(), &grad_a.array(), 1e-4);
+    }
+
+    #[test]
+    fn test_triangular_solve() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<_, TestDtype, _> =
+            dev.tensor([[2.0, 9.0, 0.0], [1.0, 3.0, 9.0], [-1.0, 2.0, 4.0]]);
+        let b: Tensor<_, TestDtype, _> = dev.tensor([[2.0, 4.0], [7.0, 8.0], [11.0, 14.0]]);
+        let x = a.trace().triangular_solve(b.trace(), false);
+        assert_close(&x.array(), &[[1.0, 2.0], [2.0, 2.0], [2.0, 3.0]]);
+        let g = x.sum().backward();
+        let g_a = g.get(&a).array();
+        assert_eq!([g_a[0][1], g_a[0][2], g_a[1][2]], [0.0; 3]);
+
+        // compare against a general solve with the lower triangle
+        let lower: Tensor<_, TestDtype, _> =
+            dev.tensor([[2.0, 0.0, 0.0], [1.0, 3.0, 0.0], [-1.0, 2.0, 4.0]]);
+        let x2 = lower.trace().solve(b.trace());
+        let g2 = x2.sum().backward();
+        let mut expected = g2.get(&lower).array();
+        expected[0][1] = 0.0;
+        expected[0][2] = 0.0;
+        expected[1][2] = 0.0;
+        assert_close(&g_a, &expected);
+        assert_close(&g.get(&b).array(), &g2.get(&b).array());
+
+        let x = a.trace().triangular_solve(b.clone(), true);
+        let upper: Tensor<_, TestDtype, _> =
+            dev.tensor([[2.0, 9.0, 0.0], [0.0, 3.0, 9.0], [0.0, 0.0, 4.0]]);
+        assert_close(&x.array(), &upper.solve(b).array());
+    }
+}
@@ -0,0 +1,70 @@
+use crate::shapes::{Dtype, Shape};
+use crate::tensor::cpu::{Cpu, LendingIterator, StridedArray};
+
+use super::{SortKernel, SortOp};
+
+use std::{cmp::Ordering, sync::Arc, vec::Vec};
+
+/// Total ordering that places NaNs after all other values
+#[inline(always)]
+#[allow(clippy::eq_op)]
+pub(crate) fn nans_last<E: Dtype>(a: &E, b: &E) -> Ordering {
+    a.partial_cmp(b).unwrap_or_else(|| (a != a).cmp(&(b != b)))
+}
+
+impl<E: Dtype> SortKernel<E> for Cpu {
+    fn forward<S: Shape>(
+        &self,
+        op: SortOp,
+        inp: &Self::Storage<S, E>,
+    ) -> Result<(Self::Storage<S, E>, Self::Storage<S, usize>), Self::Err> {
+        let mut values = StridedArray::new(inp.shape)?;
+        let mut indices: StridedArray<S, usize> = StridedArray::new(inp.shape)?;
+        if values.data.is_empty() {
+            return Ok((values, indices));
+        }
+        let mut values_iter = values.iter_mut_with_index();
+        while let Some((v, i)) = values_iter.next() {
+            *v = inp[i];
+        }
+
+        // values is contiguous, so each line along the axis starts at an element whose
+        // position along the axis is 0, and continues every `stride` elements
+        let size = inp.shape.concrete()[op.axis];
+        let stride = values.strides[op.axis];
+        let vals = Arc::make_mut(&mut values.data);
+        let idxs = Arc::make_mut(&mut indices.data);
+        let mut line: Vec<(E, usize)> = Vec::with_capacity(size);
+        for start in 0..vals.len() {
+            if (start / stride) % size != 0 {
+                continue;
+            }
+            line.clear();
+            line.extend((0..size).map(|k| (vals[start + k * stride], k)));
+            line.sort_by(|a, b| nans_last(&a.0, &b.0));
+            for (k, &(v, i)) in line.iter().enumerate() {
+                vals[start + k * stride] = v;
+                idxs[start + k * stride] = i;
+            }
+        }
+        Ok((values, indices))
+    }
+
+    fn backward<S: Shape>(
+        &self,
+        op: SortOp,
+        grad_inp: &mut Self::Storage<S, E>,
+        indices: &Self::Storage<S, usize>,
+        grad_out: &Self::Storage<S, E>,
+    ) -> Result<(), Self::Err> {
+        if grad_out.shape.num_elements() == 0 {
+            return Ok(());
+        }
+        let mut out_iter = grad_out.iter_with_index();
+        while let Some((g, mut i)) = out_iter.next() {
+            i[op.axis] = indices[i];
+            grad_inp[i] += *g;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,114 @@
+use crate::{
+    shapes::*,
+    tensor::cuda::{Cuda, CudaArray},
+};
+use cudarc::driver::{AsKernelParam, LaunchAsync, LaunchConfig};
+use std::sync::Arc;
+
+const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/sort.ptx"));
+
+unsafe impl AsKernelParam for super::SortOp {}
+
+trait HasCudaKernel<E> {
+    const MOD: &'static str;
+    const FNS: &'static [&'static str];
+}
+
+impl HasCudaKernel<f32> for Cuda {
+    const MOD: &'static str = "sort_f32";
+    const FNS: &'static [&'static str] = &["sort_fwd_f32", "sort_bwd_f32"];
+}
+
+impl HasCudaKernel<f64> for Cuda {
+    const MOD: &'static str = "sort_f64";
+    const FNS: &'static [&'static str] = &["sort_fwd_f64", "sort_bwd_f64"];
+}
+
+impl<E: Dtype> super::SortKernel<E> for Cuda
+where
+    Self: HasCudaKernel<E>,
+{
+    fn forward<S: Shape>(
+        &self,
+        op: super::SortOp,
+        inp: &Self::Storage<S, E>,
+    ) -> Result<(Self::Storage<S, E>, Self::Storage<S, usize>), Self::Err> {
+        if !self.dev.has_func(Self::MOD, Self::FNS[0]) {
+            self.dev.load_ptx(PTX_SRC.into(), Self::MOD, Self::FNS)?;
+        }
+
+        let shape = inp.shape;
+        let strides = shape.strides();
+        let numel = shape.num_elements();
+        let mut values = self.dev.alloc_zeros_async::<E>(numel)?;
+        let mut indices = self.dev.alloc_zeros_async::<usize>(numel)?;
+
+        if numel > 0 {
+            let num_lines = numel / shape.concrete()[op.axis];
+            let mut scratch = unsafe { self.dev.alloc_async::<usize>(2 * numel) }?;
+            let dims = self.dev.take_async(shape.concrete().into())?;
+            let inp_strides = self.dev.take_async(inp.strides.into())?;
+            let out_strides = self.dev.take_async(strides.into())?;
+
+            let fwd_fn = self.dev.get_func(Self::MOD, Self::FNS[0]).unwrap();
+            let cfg = LaunchConfig::for_num_elems(num_lines as u32);
+            let params = (
+                op,                // const SortOp op,
+                num_lines,         // const size_t num_lines,
+                S::NUM_DIMS,       // const size_t num_dims,
+                &dims,             // const size_t *dims,
+                &inp_strides,      // const size_t *inp_strides,
+                &out_strides,      // const size_t *out_strides,
+                inp.data.as_ref(), // const T *inp,
+                &mut values,       // T *out,
+                &mut indices,      // size_t *indices,
+                &mut scratch,      // size_t *scratch
+            );
+            unsafe { fwd_fn.launch_async(cfg, params) }?;
+        }
+
+        Ok((
+            CudaArray {
+                data: Arc::new(values),
+                shape,
+                strides,
+            },
+            CudaArray {
+                data: Arc::new(indices),
+                shape,
+                strides,
+            },
+        ))
+    }
+
+    fn backward<S: Shape>(
+        &self,
+        op: super::SortOp,
+        grad_inp: &mut Self::Storage<S, E>,
+        indices: &Self::Storage<S, usize>,
+        grad_out: &Self::Storage<S, E>,
+    ) -> Result<(), Self::Err> {
+        let numel = grad_out.shape.num_elements();
+        if numel == 0 {
+            return Ok(());
+        }
+
+        let dims = self.dev.take_async(grad_out.shape.concrete().into())?;
+        let inp_strides = self.dev.take_async(grad_inp.strides.into())?;
+
+        let bwd_fn = self.dev.get_func(Self::MOD, Self::FNS[1]).unwrap();
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            op,                                // const SortOp op,
+            numel,                             // const size_t numel,
+            S::NUM_DIMS,                       // const size_t num_dims,
+            &dims,                             // const size_t *dims,
+            &inp_strides,                      // const size_t *inp_strides,
+            Arc::make_mut(&mut grad_inp.data), // T *grad_inp,
+            indices.data.as_ref(),             // const size_t *indices,
+            grad_out.data.as_ref(),            // const T *grad_out
+        );
+        unsafe { bwd_fn.launch_async(cfg, params) }?;
+        Ok(())
+    }
+}
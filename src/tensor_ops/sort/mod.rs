@@ -0,0 +1,236 @@
+mod cpu_kernel;
+
+pub(crate) use cpu_kernel::nans_last;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{gradients::Tape, shapes::*, tensor::*};
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct SortOp {
+    /// The axis to sort along
+    pub axis: usize,
+}
+
+pub trait SortKernel<E: Dtype>: DeviceStorage {
+    /// Sorts every line of `inp` along `op.axis` in ascending order, with NaNs
+    /// placed last. Equal values keep their original order.
+    ///
+    /// Returns the sorted values, and the position along `op.axis` in `inp` that
+    /// each sorted value came from.
+    #[allow(clippy::type_complexity)]
+    fn forward<S: Shape>(
+        &self,
+        op: SortOp,
+        inp: &Self::Storage<S, E>,
+    ) -> Result<(Self::Storage<S, E>, Self::Storage<S, usize>), Self::Err>;
+
+    fn backward<S: Shape>(
+        &self,
+        op: SortOp,
+        grad_inp: &mut Self::Storage<S, E>,
+        indices: &Self::Storage<S, usize>,
+        grad_out: &Self::Storage<S, E>,
+    ) -> Result<(), Self::Err>;
+}
+
+/// Sorts the elements along `Ax` in ascending order. NaNs are placed after all other
+/// values, and equal values keep their original order.
+///
+/// The gradient of each sorted value is routed back to the position it came from.
+///
+/// **Pytorch equivalent**: `t.sort(dim=Ax, stable=True).values`
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([[3.0, 1.0, 2.0], [-1.0, 5.0, 0.0]]);
+/// let r = t.clone().sort::<Axis<1>>();
+/// assert_eq!(r.array(), [[1.0, 2.0, 3.0], [-1.0, 0.0, 5.0]]);
+/// let r = t.sort::<Axis<0>>();
+/// assert_eq!(r.array(), [[-1.0, 1.0, 0.0], [3.0, 5.0, 2.0]]);
+/// ```
+pub trait TrySort: HasErr + HasShape {
+    fn sort<Ax: Axes<Array = [isize; 1]>>(self) -> Self
+    where
+        Self::Shape: HasAxes<Ax>,
+    {
+        self.try_sort::<Ax>().unwrap()
+    }
+    fn try_sort<Ax: Axes<Array = [isize; 1]>>(self) -> Result<Self, Self::Err>
+    where
+        Self::Shape: HasAxes<Ax>;
+}
+
+impl<S: Shape, E: Dtype, D: SortKernel<E>, T: Tape<D>> TrySort for Tensor<S, E, D, T> {
+    fn try_sort<Ax: Axes<Array = [isize; 1]>>(self) -> Result<Self, Self::Err>
+    where
+        Self::Shape: HasAxes<Ax>,
+    {
+        if S::NUM_DIMS == 0 {
+            return Ok(self);
+        }
+        let op = SortOp {
+            axis: Ax::as_array()[0] as usize,
+        };
+        let (inp, mut tape) = self.split_tape();
+        let (values, indices) = inp.device.forward(op, &inp.storage)?;
+        let out = inp.device.upgrade(values);
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&inp)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+            inp.device.backward(op, grad_inp, &indices, grad_out)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+/// Returns the indices along `Ax` that would sort the tensor in ascending order, using
+/// the same ordering as [TrySort::sort()]. No gradients are tracked through the result.
+///
+/// Sorting along the last axis produces indices that can be passed directly to
+/// [crate::tensor_ops::GatherTo::gather()] to reorder this or any other tensor of the same shape.
+///
+/// **Pytorch equivalent**: `t.argsort(dim=Ax, stable=True)`
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([[3.0, 1.0, 2.0], [-1.0, 5.0, 0.0]]);
+/// let idx = t.clone().argsort::<Axis<1>>();
+/// assert_eq!(idx.array(), [[1, 2, 0], [0, 2, 1]]);
+/// let r: Tensor<Rank2<2, 3>, f32, _> = t.gather(idx);
+/// assert_eq!(r.array(), [[1.0, 2.0, 3.0], [-1.0, 0.0, 5.0]]);
+/// ```
+pub trait TryArgSort<D: DeviceStorage>: HasErr + HasShape {
+    fn argsort<Ax: Axes<Array = [isize; 1]>>(self) -> Tensor<Self::Shape, usize, D>
+    where
+        Self::Shape: HasAxes<Ax>,
+    {
+        self.try_argsort::<Ax>().unwrap()
+    }
+    fn try_argsort<Ax: Axes<Array = [isize; 1]>>(
+        self,
+    ) -> Result<Tensor<Self::Shape, usize, D>, Self::Err>
+    where
+        Self::Shape: HasAxes<Ax>;
+}
+
+impl<S: Shape, E: Dtype, D: SortKernel<E> + ZerosTensor<usize>, T> TryArgSort<D>
+    for Tensor<S, E, D, T>
+{
+    fn try_argsort<Ax: Axes<Array = [isize; 1]>>(self) -> Result<Tensor<S, usize, D>, Self::Err>
+    where
+        Self::Shape: HasAxes<Ax>,
+    {
+        if S::NUM_DIMS == 0 {
+            return self.device.try_zeros_like(self.shape());
+        }
+        let op = SortOp {
+            axis: Ax::as_array()[0] as usize,
+        };
+        let (_, indices) = self.device.forward(op, &self.storage)?;
+        Ok(self.device.upgrade(indices))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_sort_1d() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([3.0, -1.0, 2.0, 0.5, 2.0]);
+        let r = t.trace().sort::<Axis<0>>();
+        assert_eq!(r.array(), [-1.0, 0.5, 2.0, 2.0, 3.0]);
+        let g = (r * dev.tensor([1.0, 2.0, 3.0, 4.0, 5.0])).sum().backward();
+        assert_eq!(g.get(&t).array(), [5.0, 1.0, 3.0, 2.0, 4.0]);
+    }
+
+    #[test]
+    fn test_sort_nans_last() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([TestDtype::NAN, 1.0, -2.0]);
+        let r = t.sort::<Axis<0>>().array();
+        assert_eq!(r[..2], [-2.0, 1.0]);
+        assert!(r[2].is_nan());
+    }
+
+    #[test]
+    fn test_sort_3d_middle_axis() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank3<2, 5, 3>, TestDtype, _> = dev.sample_normal();
+        let r = t.trace().sort::<Axis<1>>();
+        let t_arr = t.array();
+        let r_arr = r.array();
+        for i in 0..2 {
+            for k in 0..3 {
+                let mut line: std::vec::Vec<TestDtype> = (0..5).map(|j| t_arr[i][j][k]).collect();
+                line.sort_by(|a, b| a.partial_cmp(b).unwrap());
+                for j in 0..5 {
+                    assert_eq!(r_arr[i][j][k], line[j]);
+                }
+            }
+        }
+        let g = r.exp().sum().backward();
+        assert_close(&g.get(&t).array(), &t.exp().array());
+    }
+
+    #[test]
+    fn test_argsort_1d() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([3.0, -1.0, 2.0, 0.5, 2.0]);
+        let idx = t.clone().argsort::<Axis<0>>();
+        assert_eq!(idx.array(), [1, 3, 2, 4, 0]);
+        let r: Tensor<Rank1<5>, TestDtype, _> = t.clone().gather(idx);
+        assert_eq!(r.array(), t.sort::<Axis<0>>().array());
+    }
+
+    #[test]
+    fn test_argsort_reorders_other_tensor() {
+        let dev: TestDevice = Default::default();
+        let keys: Tensor<Rank2<2, 4>, TestDtype, _> = dev.sample_normal();
+        let vals: Tensor<Rank2<2, 4>, TestDtype, _> = dev.sample_normal();
+        let idx = keys.clone().argsort::<Axis<1>>();
+        let r: Tensor<Rank2<2, 4>, _, _, _> = vals.trace().gather(idx.clone());
+        let (k, v, i, r_arr) = (keys.array(), vals.array(), idx.array(), r.array());
+        for row in 0..2 {
+            for j in 0..4 {
+                assert_eq!(r_arr[row][j], v[row][i[row][j]]);
+                if j > 0 {
+                    assert!(k[row][i[row][j - 1]] <= k[row][i[row][j]]);
+                }
+            }
+        }
+        let g = r.sum().backward();
+        assert_eq!(g.get(&vals).array(), [[1.0; 4]; 2]);
+    }
+
+    #[test]
+    fn test_argsort_middle_axis() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([[[3.0, 0.0]], [[1.0, 2.0]], [[2.0, 1.0]]]);
+        let idx = t.argsort::<Axis<0>>();
+        assert_eq!(idx.array(), [[[1, 0]], [[2, 2]], [[0, 1]]]);
+    }
+
+    #[test]
+    fn test_sort_broadcasted() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([2.0, 1.0, 3.0]);
+        let r = t.trace().broadcast::<Rank2<2, 3>, _>().sort::<Axis<1>>();
+        assert_eq!(r.array(), [[1.0, 2.0, 3.0]; 2]);
+        let g = (r * dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]))
+            .sum()
+            .backward();
+        assert_eq!(g.get(&t).array(), [7.0, 5.0, 9.0]);
+    }
+}
@@ -0,0 +1,158 @@
+#include "cuda_utils.cuh"
+
+struct SortOp {
+    size_t axis;
+};
+
+// Computes the strided offsets of the first element of the `line`th line along op.axis
+__device__ void get_line_offsets(
+    const SortOp op,
+    size_t line,
+    const size_t num_dims,
+    const size_t *dims,
+    const size_t *inp_strides,
+    const size_t *out_strides,
+    size_t *inp_i,
+    size_t *out_i
+) {
+    *inp_i = 0;
+    *out_i = 0;
+    for (int d = num_dims - 1; d >= 0; d--) {
+        if (d == op.axis) {
+            continue;
+        }
+        const size_t i_dim = line % dims[d];
+        *inp_i += i_dim * inp_strides[d];
+        *out_i += i_dim * out_strides[d];
+        line /= dims[d];
+    }
+}
+
+// Strict ordering that places NaNs after all other values
+template<typename T>
+__device__ bool nans_last_lt(T a, T b) {
+    if (isnan(a)) {
+        return false;
+    }
+    if (isnan(b)) {
+        return true;
+    }
+    return a < b;
+}
+
+// Each thread sorts one line with a stable bottom-up merge sort. The permutation is
+// built in `scratch`, which holds 2 * numel elements.
+template<typename T>
+__device__ void sort_fwd(
+    const SortOp op,
+    const size_t num_lines,
+    const size_t num_dims,
+    const size_t *dims,
+    const size_t *inp_strides,
+    const size_t *out_strides,
+    const T *inp,
+    T *out,
+    size_t *indices,
+    size_t *scratch
+) {
+    unsigned int line = blockIdx.x * blockDim.x + threadIdx.x;
+    if (line >= num_lines) {
+        return;
+    }
+
+    size_t inp_i, out_i;
+    get_line_offsets(op, line, num_dims, dims, inp_strides, out_strides, &inp_i, &out_i);
+
+    const size_t n = dims[op.axis];
+    const size_t inp_stride = inp_strides[op.axis];
+    const size_t out_stride = out_strides[op.axis];
+    size_t *src = scratch + line * n;
+    size_t *dst = scratch + (num_lines + line) * n;
+
+    for (size_t k = 0; k < n; k++) {
+        src[k] = k;
+    }
+
+    for (size_t width = 1; width < n; width *= 2) {
+        for (size_t lo = 0; lo < n; lo += 2 * width) {
+            const size_t mid = min(lo + width, n);
+            const size_t hi = min(lo + 2 * width, n);
+            size_t l = lo;
+            size_t r = mid;
+            for (size_t k = lo; k < hi; k++) {
+                if (l < mid && (r >= hi || !nans_last_lt(inp[inp_i + src[r] * inp_stride], inp[inp_i + src[l] * inp_stride]))) {
+                    dst[k] = src[l++];
+                } else {
+                    dst[k] = src[r++];
+                }
+            }
+        }
+        size_t *tmp = src;
+        src = dst;
+        dst = tmp;
+    }
+
+    for (size_t k = 0; k < n; k++) {
+        out[out_i + k * out_stride] = inp[inp_i + src[k] * inp_stride];
+        indices[out_i + k * out_stride] = src[k];
+    }
+}
+
+template<typename T>
+__device__ void sort_bwd(
+    const SortOp op,
+    const size_t numel,
+    const size_t num_dims,
+    const size_t *dims,
+    const size_t *inp_strides,
+    T *grad_inp,
+    const size_t *indices,
+    const T *grad_out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    // grad_out & indices are contiguous
+    unsigned int inp_i = 0;
+    unsigned int idx = i;
+    for (int d = num_dims - 1; d >= 0; d--) {
+        const size_t i_dim = d == op.axis ? indices[i] : idx % dims[d];
+        inp_i += i_dim * inp_strides[d];
+        idx /= dims[d];
+    }
+
+    atomicAdd(grad_inp + inp_i, grad_out[i]);
+}
+
+#define SORT_OP(TYPENAME, FWD, BWD) \
+extern "C" __global__ void FWD( \
+    const SortOp op, \
+    const size_t num_lines, \
+    const size_t num_dims, \
+    const size_t *dims, \
+    const size_t *inp_strides, \
+    const size_t *out_strides, \
+    const TYPENAME *inp, \
+    TYPENAME *out, \
+    size_t *indices, \
+    size_t *scratch \
+) { \
+    sort_fwd(op, num_lines, num_dims, dims, inp_strides, out_strides, inp, out, indices, scratch); \
+} \
+extern "C" __global__ void BWD( \
+    const SortOp op, \
+    const size_t numel, \
+    const size_t num_dims, \
+    const size_t *dims, \
+    const size_t *inp_strides, \
+    TYPENAME *grad_inp, \
+    const size_t *indices, \
+    const TYPENAME *grad_out \
+) { \
+    sort_bwd(op, numel, num_dims, dims, inp_strides, grad_inp, indices, grad_out); \
+}
+
+SORT_OP(float, sort_fwd_f32, sort_bwd_f32);
+SORT_OP(double, sort_fwd_f64, sort_bwd_f64);
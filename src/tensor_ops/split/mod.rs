@@ -0,0 +1,230 @@
+use crate::{gradients::Tape, shapes::*, tensor::*};
+
+use super::{
+    add::BinaryAddKernelOp,
+    concat_along::{ConcatAlongKernel, ConcatShape},
+    ops::BinaryKernel,
+};
+
+use std::vec::Vec;
+
+/// Split a tensor into multiple tensors along an existing axis. This is the inverse
+/// of [super::TryConcatAlong].
+///
+/// All of the resulting tensors participate in the autodiff graph, and the gradient
+/// of the input is the concatenation of their gradients. The tape is kept by the first
+/// tensor, and the other tensors start with an empty tape, so gradients only flow if the
+/// first tensor is used in the final computation.
+pub trait TrySplit: HasErr + HasShape {
+    /// Split along `Ax` into tensors with the given sizes along `Ax`. The sizes
+    /// must sum to the size of `Ax`.
+    ///
+    /// **Pytorch equivalent** `t.split(sizes, dim=Ax)`.
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t = dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    /// let r: Vec<Tensor<(Const<2>, usize), f32, _>> = t.split::<Axis<1>>(&[2, 1]);
+    /// assert_eq!(r[0].as_vec(), [1.0, 2.0, 4.0, 5.0]);
+    /// assert_eq!(r[1].as_vec(), [3.0, 6.0]);
+    /// ```
+    #[allow(clippy::type_complexity)]
+    fn split<Ax>(
+        self,
+        sizes: &[usize],
+    ) -> Vec<Self::WithShape<<Self::Shape as ConcatShape<Ax>>::Catted>>
+    where
+        Self::Shape: ConcatShape<Ax>,
+    {
+        self.try_split::<Ax>(sizes).unwrap()
+    }
+
+    /// Fallible version of [TrySplit::split]
+    #[allow(clippy::type_complexity)]
+    fn try_split<Ax>(
+        self,
+        sizes: &[usize],
+    ) -> Result<Vec<Self::WithShape<<Self::Shape as ConcatShape<Ax>>::Catted>>, Self::Err>
+    where
+        Self::Shape: ConcatShape<Ax>;
+
+    /// Split along `Ax` into `N` tensors with sizes as equal as possible. If the size of
+    /// `Ax` isn't divisible by `N`, the first tensors are larger by 1.
+    ///
+    /// **Pytorch equivalent** `t.tensor_split(N, dim=Ax)`.
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t = dev.tensor([1.0, 2.0, 3.0, 4.0, 5.0]);
+    /// let [a, b] = t.chunk::<Axis<0>, 2>();
+    /// assert_eq!(a.as_vec(), [1.0, 2.0, 3.0]);
+    /// assert_eq!(b.as_vec(), [4.0, 5.0]);
+    /// ```
+    #[allow(clippy::type_complexity)]
+    fn chunk<Ax, const N: usize>(
+        self,
+    ) -> [Self::WithShape<<Self::Shape as ConcatShape<Ax>>::Catted>; N]
+    where
+        Self::Shape: ConcatShape<Ax>,
+    {
+        self.try_chunk::<Ax, N>().unwrap()
+    }
+
+    /// Fallible version of [TrySplit::chunk]
+    #[allow(clippy::type_complexity)]
+    fn try_chunk<Ax, const N: usize>(
+        self,
+    ) -> Result<[Self::WithShape<<Self::Shape as ConcatShape<Ax>>::Catted>; N], Self::Err>
+    where
+        Self::Shape: ConcatShape<Ax>;
+}
+
+impl<S: Shape, E: Dtype, D, T: Tape<D>> TrySplit for Tensor<S, E, D, T>
+where
+    D: ConcatAlongKernel<E> + BinaryKernel<BinaryAddKernelOp, E> + ZerosTensor<E>,
+{
+    fn try_split<Ax>(self, sizes: &[usize]) -> Result<Vec<Tensor<S::Catted, E, D, T>>, Self::Err>
+    where
+        S: ConcatShape<Ax>,
+    {
+        let shape = *self.shape();
+
+        // the axis is the only dimension that changes
+        let mut axis = 0;
+        let dims = shape.concrete();
+        let catted = shape.catted(usize::MAX).concrete();
+        while catted[axis] != usize::MAX {
+            axis += 1;
+        }
+        assert_eq!(
+            sizes.iter().sum::<usize>(),
+            dims[axis],
+            "sizes must sum to the size of the axis"
+        );
+
+        let (inp, mut tape) = self.split_tape();
+        let mut storages = Vec::with_capacity(sizes.len());
+        for &size in sizes {
+            storages.push(inp.device.try_zeros_like(&shape.catted(size))?.storage);
+        }
+        // concatenating is the inverse of splitting, so the gradient of concatenation
+        // splits `inp` into the zeroed storages
+        ConcatAlongKernel::backward(
+            &inp.device,
+            axis,
+            storages.iter_mut().collect(),
+            &inp.storage,
+        )?;
+        let outs: Vec<Tensor<S::Catted, E, D>> = storages
+            .into_iter()
+            .map(|s| inp.device.upgrade(s))
+            .collect();
+
+        let phantom_outs = outs.clone();
+        tape.try_alloc_grad(&inp)?;
+        for out in outs.iter() {
+            tape.try_alloc_grad(out)?;
+        }
+        tape.add_backward_op(move |grads| {
+            let grad_outs = phantom_outs.iter().map(|out| grads.get(out)).collect();
+            let grad: D::Storage<S, E> =
+                ConcatAlongKernel::forward(&inp.device, axis, shape, grad_outs)?;
+            let grad_inp = grads.get_mut(&inp);
+            *grad_inp = BinaryKernel::forward(&inp.device, BinaryAddKernelOp, grad_inp, &grad)?;
+            Ok(())
+        });
+
+        let mut tapes = std::iter::once(tape).chain(std::iter::repeat_with(Default::default));
+        Ok(outs
+            .into_iter()
+            .map(|out| out.put_tape(tapes.next().unwrap()))
+            .collect())
+    }
+
+    fn try_chunk<Ax, const N: usize>(self) -> Result<[Tensor<S::Catted, E, D, T>; N], Self::Err>
+    where
+        S: ConcatShape<Ax>,
+    {
+        assert!(N > 0);
+        let dims = self.shape().concrete();
+        let catted = self.shape().catted(usize::MAX).concrete();
+        let size = (0..S::NUM_DIMS)
+            .find(|&i| catted[i] == usize::MAX)
+            .map(|i| dims[i])
+            .unwrap();
+        let sizes: Vec<usize> = (0..N)
+            .map(|i| size / N + if i < size % N { 1 } else { 0 })
+            .collect();
+        let mut outs = self.try_split::<Ax>(&sizes)?.into_iter();
+        Ok(std::array::from_fn(|_| outs.next().unwrap()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_split_axis_0() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank2<4, 2>, TestDtype, _> = dev.sample_normal();
+        let t_array = t.array();
+        let r = t.trace().split::<Axis<0>>(&[1, 3]);
+        assert_eq!(r.len(), 2);
+        assert_eq!(r[0].shape(), &(1, Const));
+        assert_eq!(r[1].shape(), &(3, Const));
+        assert_eq!(r[0].as_vec(), t_array[0]);
+        assert_eq!(r[1].as_vec(), [t_array[1], t_array[2], t_array[3]].concat());
+    }
+
+    #[test]
+    fn test_split_backward() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let mut r = t.trace().split::<Axis<1>>(&[1, 0, 2]).into_iter();
+        let a = r.next().unwrap();
+        let b = r.next().unwrap();
+        let c = r.next().unwrap();
+        assert_eq!(b.shape(), &(Const, 0));
+        let loss = a.sum() + c.square().sum();
+        let g = loss.backward();
+        assert_eq!(g.get(&t).array(), [[1.0, 4.0, 6.0], [1.0, 10.0, 12.0]]);
+    }
+
+    #[test]
+    fn test_split_unused_tensors() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([1.0, 2.0, 3.0]);
+        let [a, _] = t.trace().chunk::<Axis<0>, 2>();
+        let g = a.exp().sum().backward();
+        assert_close(
+            &g.get(&t).array(),
+            &[TestDtype::exp(1.0), TestDtype::exp(2.0), 0.0],
+        );
+    }
+
+    #[test]
+    fn test_chunk_3d() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank3<2, 6, 2>, TestDtype, _> = dev.sample_normal();
+        let chunks = t.trace().chunk::<Axis<1>, 3>();
+        for chunk in chunks.iter() {
+            assert_eq!(chunk.shape(), &(Const, 2, Const));
+        }
+        let [a, b, c] = chunks;
+        let r = std::vec![c, b, a].concat_along(Axis::<1>);
+        let g = r.exp().sum().backward();
+        assert_close(&g.get(&t).array(), &t.exp().array());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_split_wrong_sizes() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank1<3>, TestDtype, _> = dev.zeros();
+        let _ = t.split::<Axis<0>>(&[1, 1]);
+    }
+}
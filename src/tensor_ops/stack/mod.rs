@@ -239,4 +239,26 @@ mod tests {
         assert_eq!(r_grad[1], g.get(&y).array());
         assert_eq!(r_grad[2], g.get(&z).array());
     }
+
+    #[test]
+    fn test_stack_vec_backwards() {
+        let dev: TestDevice = Default::default();
+
+        let items: std::vec::Vec<Tensor<Rank1<3>, TestDtype, _>> =
+            (0..4).map(|_| dev.sample_normal()).collect();
+        let r = dev.stack(
+            items
+                .iter()
+                .map(|t| t.trace())
+                .collect::<std::vec::Vec<_>>(),
+        );
+        assert_eq!(r.shape(), &(4, Const));
+        let r1 = r.retaped::<NoneTape>();
+        let g1 = r1.trace().exp().mean().backward();
+        let g = r.exp().mean().backward();
+        let r_grad = g1.get(&r1).as_vec();
+        for (i, item) in items.iter().enumerate() {
+            assert_eq!(&r_grad[3 * i..3 * (i + 1)], &g.get(item).as_vec());
+        }
+    }
 }
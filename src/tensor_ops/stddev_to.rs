@@ -28,6 +28,33 @@ pub trait StddevTo<E: Dtype>: HasErr + HasShape {
     ) -> Result<Self::WithShape<Dst>, Self::Err>
     where
         Self::Shape: HasAxes<Ax> + ReduceShapeTo<Dst, Ax>;
+
+    /// Square root of [VarTo::var_along], i.e. the standard deviation that divides
+    /// by `N - correction`. `correction = 1` is the Bessel corrected standard deviation.
+    ///
+    /// **Pytorch equivalent**: `t.std(Axes, correction=correction)`
+    ///
+    /// Examples:
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t = dev.tensor([[2.0f32, 3.0, 4.0], [3.0, 6.0, 9.0]]);
+    /// let r = t.stddev_along::<Rank1<2>, _>(1); // or `stddev_along::<_, Axis<1>>(1)`
+    /// assert_eq!(r.array(), [1.0, 3.0]);
+    /// ```
+    fn stddev_along<Dst: Shape, Ax: Axes>(self, correction: usize) -> Self::WithShape<Dst>
+    where
+        Self::Shape: HasAxes<Ax> + ReduceShapeTo<Dst, Ax>,
+    {
+        self.try_stddev_along(correction).unwrap()
+    }
+    /// Fallible version of [StddevTo::stddev_along]
+    fn try_stddev_along<Dst: Shape, Ax: Axes>(
+        self,
+        correction: usize,
+    ) -> Result<Self::WithShape<Dst>, Self::Err>
+    where
+        Self::Shape: HasAxes<Ax> + ReduceShapeTo<Dst, Ax>;
 }
 
 impl<S: Shape, E: Dtype, D: Device<E>, T: Tape<D>> StddevTo<E> for Tensor<S, E, D, T> {
@@ -37,6 +64,16 @@ impl<S: Shape, E: Dtype, D: Device<E>, T: Tape<D>> StddevTo<E> for Tensor<S, E,
     {
         self.try_var()?.try_add(epsilon)?.try_sqrt()
     }
+
+    fn try_stddev_along<Dst: Shape, Ax: Axes>(
+        self,
+        correction: usize,
+    ) -> Result<Self::WithShape<Dst>, Self::Err>
+    where
+        Self::Shape: HasAxes<Ax> + ReduceShapeTo<Dst, Ax>,
+    {
+        self.try_var_along(correction)?.try_sqrt()
+    }
 }
 
 #[cfg(test)]
@@ -72,4 +109,20 @@ mod tests {
             ],
         );
     }
+
+    #[test]
+    fn test_stddev_along_correction() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([[1.0, 2.0, 3.0, 4.0], [0.0, 2.0, 5.0, 10.0]]);
+        let r = t.trace().stddev_along::<Rank1<2>, _>(1);
+        assert_close(&r.array(), &[1.2909944, 4.3493295]);
+        let g = r.mean().backward();
+        assert_close(
+            &g.get(&t).array(),
+            &[
+                [-0.19364917, -0.06454972, 0.06454972, 0.19364917],
+                [-0.16286035, -0.08622019, 0.028740063, 0.22034048],
+            ],
+        );
+    }
 }
@@ -0,0 +1,326 @@
+use crate::{
+    gradients::Tape,
+    shapes::*,
+    tensor::{DeviceStorage, HasErr, Tensor, TensorFromVec},
+};
+
+use super::{fft::FftKernel, BroadcastTo, Device, GatherTo, PermuteTo, ReshapeTo, SumTo, TryMul};
+
+/// A periodic hann window of length `n`, i.e. `0.5 - 0.5 * cos(2 pi i / n)`, to use with
+/// [TryStft::stft].
+///
+/// **Pytorch equivalent**: `torch.hann_window(n)`
+///
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let w: Tensor<(usize,), f32, _> = hann_window(&dev, 4);
+/// assert_eq!(w.as_vec(), [0.0, 0.5, 1.0, 0.5]);
+/// ```
+pub fn hann_window<E: Dtype, D: TensorFromVec<E>>(dev: &D, n: usize) -> Tensor<(usize,), E, D> {
+    let w = (0..n)
+        .map(|i| {
+            let c = (2.0 * std::f64::consts::PI * i as f64 / n as f64).cos();
+            E::from_f64(0.5 - 0.5 * c).unwrap()
+        })
+        .collect();
+    dev.tensor_from_vec(w, (n,))
+}
+
+/// Triangular mel filters of shape `(n_freqs, n_mels)`, mapping the `n_freqs` frequency
+/// bins of a spectrum to `n_mels` mel bins between `f_min` and `f_max`. Uses the HTK mel
+/// scale, `2595 * log10(1 + f / 700)`, without normalizing the filters.
+///
+/// `n_freqs` is the number of frequencies of [TryStft::stft], i.e. `n_fft / 2 + 1`.
+///
+/// **Pytorch equivalent**: `torchaudio.functional.melscale_fbanks(n_freqs, f_min, f_max, n_mels, sample_rate)`
+pub fn mel_filterbank<E: Dtype, D: TensorFromVec<E>>(
+    dev: &D,
+    n_freqs: usize,
+    n_mels: usize,
+    sample_rate: f64,
+    f_min: f64,
+    f_max: f64,
+) -> Tensor<(usize, usize), E, D> {
+    let to_mel = |f: f64| 2595.0 * (1.0 + f / 700.0).log10();
+    let to_hz = |m: f64| 700.0 * (10f64.powf(m / 2595.0) - 1.0);
+    let linspace = |a: f64, b: f64, n: usize, i: usize| {
+        if n > 1 {
+            a + (b - a) * i as f64 / (n - 1) as f64
+        } else {
+            a
+        }
+    };
+
+    // the edges of the filters, evenly spaced on the mel scale
+    let (m_min, m_max) = (to_mel(f_min), to_mel(f_max));
+    let f_pts: std::vec::Vec<f64> = (0..n_mels + 2)
+        .map(|i| to_hz(linspace(m_min, m_max, n_mels + 2, i)))
+        .collect();
+
+    let mut fb = std::vec::Vec::with_capacity(n_freqs * n_mels);
+    for i in 0..n_freqs {
+        let f = linspace(0.0, sample_rate / 2.0, n_freqs, i);
+        for m in 0..n_mels {
+            let down = (f - f_pts[m]) / (f_pts[m + 1] - f_pts[m]);
+            let up = (f_pts[m + 2] - f) / (f_pts[m + 2] - f_pts[m + 1]);
+            fb.push(E::from_f64(down.min(up).max(0.0)).unwrap());
+        }
+    }
+    dev.tensor_from_vec(fb, (n_freqs, n_mels))
+}
+
+/// The number of frames, and the indices into the signal of each frame's samples.
+fn frame_indices(len: usize, n: usize, hop: usize) -> (usize, std::vec::Vec<usize>) {
+    assert!(hop > 0, "hop must be positive");
+    assert!(n > 0 && len >= n, "the signal is shorter than the window");
+    let frames = (len - n) / hop + 1;
+    let idx = (0..frames)
+        .flat_map(|f| (0..n).map(move |j| f * hop + j))
+        .collect();
+    (frames, idx)
+}
+
+/// Short time fourier transforms & mel spectrograms of `(Len,)` signals, or `(Batch, Len)`
+/// batches of signals.
+///
+/// The window is treated as a constant, so it has no gradient.
+pub trait TryStft<E: Dtype, D: DeviceStorage>: HasErr {
+    type Spectrum;
+    type Spectrogram;
+
+    /// Slides `window` over the signal in steps of `hop`, and returns the [rfft()](super::rfft())
+    /// of each windowed frame. The signal isn't padded, so there are
+    /// `(Len - window.len()) / hop + 1` frames, each with `window.len() / 2 + 1` frequencies.
+    ///
+    /// Unlike pytorch, the frames are before the frequencies: `(Len,)` becomes
+    /// `(Frames, Freqs, 2)`, where the last axis holds the real & imaginary parts.
+    ///
+    /// **Pytorch equivalent**: `torch.view_as_real(torch.stft(t, n_fft, hop, window=window, center=False, return_complex=True)).transpose(-3, -2)`
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t = dev.tensor([1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    /// let r: Tensor<(usize, usize, Const<2>), f32, _> = t.stft(dev.ones_like(&(4,)), 2);
+    /// assert_eq!(r.shape(), &(2, 3, Const));
+    /// assert_eq!(
+    ///     r.as_vec(),
+    ///     [10.0, 0.0, -2.0, 2.0, -2.0, 0.0, 18.0, 0.0, -2.0, 2.0, -2.0, 0.0]
+    /// );
+    /// ```
+    fn stft(self, window: Tensor<(usize,), E, D>, hop: usize) -> Self::Spectrum {
+        self.try_stft(window, hop).unwrap()
+    }
+
+    /// Fallible version of [TryStft::stft]
+    fn try_stft(
+        self,
+        window: Tensor<(usize,), E, D>,
+        hop: usize,
+    ) -> Result<Self::Spectrum, Self::Err>;
+
+    /// The power of [TryStft::stft] (squared magnitudes), projected onto the mel scale
+    /// with a `(Freqs, Mels)` `filterbank` from [mel_filterbank()]. `(Len,)` becomes
+    /// `(Frames, Mels)`.
+    ///
+    /// **Pytorch equivalent**: `torchaudio.transforms.MelSpectrogram(center=False, ...)(t).transpose(-1, -2)`
+    fn mel_spectrogram(
+        self,
+        window: Tensor<(usize,), E, D>,
+        hop: usize,
+        filterbank: Tensor<(usize, usize), E, D>,
+    ) -> Self::Spectrogram {
+        self.try_mel_spectrogram(window, hop, filterbank).unwrap()
+    }
+
+    /// Fallible version of [TryStft::mel_spectrogram]
+    fn try_mel_spectrogram(
+        self,
+        window: Tensor<(usize,), E, D>,
+        hop: usize,
+        filterbank: Tensor<(usize, usize), E, D>,
+    ) -> Result<Self::Spectrogram, Self::Err>;
+}
+
+impl<L: Dim, E: Dtype, D, T: Tape<D>> TryStft<E, D> for Tensor<(L,), E, D, T>
+where
+    D: Device<E> + FftKernel<E> + TensorFromVec<usize>,
+{
+    type Spectrum = Tensor<(usize, usize, Const<2>), E, D, T>;
+    type Spectrogram = Tensor<(usize, usize), E, D, T>;
+
+    fn try_stft(
+        self,
+        window: Tensor<(usize,), E, D>,
+        hop: usize,
+    ) -> Result<Self::Spectrum, Self::Err> {
+        let n = window.shape().0;
+        let (frames, idx) = frame_indices(self.shape().0.size(), n, hop);
+        let idx = self.device.try_tensor_from_vec(idx, (frames * n,))?;
+        let shape = (frames, n);
+        let window = window.try_broadcast_like::<_, Axis<0>>(&shape)?;
+        self.try_gather(idx)?
+            .try_reshape_like(&shape)?
+            .try_mul(window)?
+            .try_rfft()
+    }
+
+    fn try_mel_spectrogram(
+        self,
+        window: Tensor<(usize,), E, D>,
+        hop: usize,
+        filterbank: Tensor<(usize, usize), E, D>,
+    ) -> Result<Self::Spectrogram, Self::Err> {
+        let power = self
+            .try_stft(window, hop)?
+            .try_square()?
+            .try_sum::<_, Axis<2>>()?;
+        let (frames, freqs) = *power.shape();
+        let mels = filterbank.shape().1;
+        let shape = (frames, mels, freqs);
+        let filterbank = filterbank
+            .try_permute::<_, Axes2<1, 0>>()?
+            .try_broadcast_like::<_, Axis<0>>(&shape)?;
+        power
+            .try_broadcast_like::<_, Axis<1>>(&shape)?
+            .try_mul(filterbank)?
+            .try_sum::<_, Axis<2>>()
+    }
+}
+
+impl<B: Dim, L: Dim, E: Dtype, D, T: Tape<D>> TryStft<E, D> for Tensor<(B, L), E, D, T>
+where
+    D: Device<E> + FftKernel<E> + TensorFromVec<usize>,
+{
+    type Spectrum = Tensor<(B, usize, usize, Const<2>), E, D, T>;
+    type Spectrogram = Tensor<(B, usize, usize), E, D, T>;
+
+    fn try_stft(
+        self,
+        window: Tensor<(usize,), E, D>,
+        hop: usize,
+    ) -> Result<Self::Spectrum, Self::Err> {
+        let (batch, len) = *self.shape();
+        let n = window.shape().0;
+        let (frames, idx) = frame_indices(len.size(), n, hop);
+        let idx = self
+            .device
+            .try_tensor_from_vec(idx.repeat(batch.size()), (batch, frames * n))?;
+        let shape = (batch, frames, n);
+        let window = window.try_broadcast_like::<_, Axes2<0, 1>>(&shape)?;
+        self.try_gather::<(B, usize), _>(idx)?
+            .try_reshape_like(&shape)?
+            .try_mul(window)?
+            .try_rfft()
+    }
+
+    fn try_mel_spectrogram(
+        self,
+        window: Tensor<(usize,), E, D>,
+        hop: usize,
+        filterbank: Tensor<(usize, usize), E, D>,
+    ) -> Result<Self::Spectrogram, Self::Err> {
+        let power = self
+            .try_stft(window, hop)?
+            .try_square()?
+            .try_sum::<_, Axis<3>>()?;
+        let (batch, frames, freqs) = *power.shape();
+        let mels = filterbank.shape().1;
+        let shape = (batch, frames, mels, freqs);
+        let filterbank = filterbank
+            .try_permute::<_, Axes2<1, 0>>()?
+            .try_broadcast_like::<_, Axes2<0, 1>>(&shape)?;
+        power
+            .try_broadcast_like::<_, Axis<2>>(&shape)?
+            .try_mul(filterbank)?
+            .try_sum::<_, Axis<3>>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_hann_window() {
+        let dev: TestDevice = Default::default();
+        let w: Tensor<_, TestDtype, _> = hann_window(&dev, 4);
+        assert_close(&w.as_vec(), &std::vec![0.0, 0.5, 1.0, 0.5]);
+        let w: Tensor<_, TestDtype, _> = hann_window(&dev, 3);
+        assert_close(&w.as_vec(), &std::vec![0.0, 0.75, 0.75]);
+    }
+
+    #[test]
+    fn test_mel_filterbank() {
+        let dev: TestDevice = Default::default();
+        let fb: Tensor<_, TestDtype, _> = mel_filterbank(&dev, 5, 2, 16000.0, 0.0, 8000.0);
+        assert_eq!(fb.shape(), &(5, 2));
+        assert_close_with_tolerance(
+            &fb.as_vec(),
+            &std::vec![
+                0.0, 0.0, 0.49469176, 0.5053082, 0.0, 0.8090425, 0.0, 0.40452126, 0.0, 0.0
+            ],
+            1e-5,
+        );
+    }
+
+    #[test]
+    fn test_stft_rectangular_window() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let r = t.trace().stft(dev.ones_like(&(4,)), 2);
+        assert_close(
+            &r.as_vec(),
+            &std::vec![10.0, 0.0, -2.0, 2.0, -2.0, 0.0, 18.0, 0.0, -2.0, 2.0, -2.0, 0.0],
+        );
+        // the middle samples are in both frames
+        let g = r.sum().backward();
+        assert_close(&g.get(&t).array(), &[3.0, -1.0, 4.0, 0.0, 1.0, 1.0]);
+    }
+
+    #[test]
+    fn test_stft_drops_partial_frames() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank1<10>, TestDtype, _> = dev.sample_normal();
+        let r = t.stft(hann_window(&dev, 4), 3);
+        assert_eq!(r.shape(), &(3, 3, Const));
+    }
+
+    #[test]
+    fn test_batched_stft_matches_unbatched() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank2<2, 20>, TestDtype, _> = dev.sample_normal();
+        let fb = mel_filterbank(&dev, 5, 3, 100.0, 0.0, 50.0);
+        let r = t
+            .trace()
+            .mel_spectrogram(hann_window(&dev, 8), 3, fb.clone());
+        assert_eq!(r.shape(), &(Const, 5, 3));
+        let r_vec = r.as_vec();
+        let g = r.exp().mean().backward();
+        let g_t = g.get(&t).array();
+        for i in 0..2 {
+            let t_i = dev.tensor(t.array()[i]);
+            let r_i = t_i
+                .trace()
+                .mel_spectrogram(hann_window(&dev, 8), 3, fb.clone());
+            assert_close(&r_i.as_vec(), &r_vec[i * 15..(i + 1) * 15].to_vec());
+            let g_i = (r_i.exp().sum() / 30.0).backward();
+            assert_close(&g_i.get(&t_i).array(), &g_t[i]);
+        }
+    }
+
+    #[test]
+    fn test_mel_spectrogram_is_projected_power() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let fb = dev.tensor_from_vec(std::vec![1.0, 0.0, 0.5, 0.5, 0.0, 1.0], (3, 2));
+        let r = t.trace().mel_spectrogram(dev.ones_like(&(4,)), 2, fb);
+        // powers are [100, 8, 4] and [324, 8, 4]
+        assert_close(&r.as_vec(), &std::vec![104.0, 8.0, 328.0, 8.0]);
+        // the filters sum to 1 for each frequency, so this is the total power
+        let g = r.sum().backward();
+        assert_close(&g.get(&t).array(), &[12.0, 20.0, 48.0, 64.0, 36.0, 44.0]);
+    }
+}
@@ -11,4 +11,24 @@ BINARY_OP(double, bsub_fwd_f64, bsub_bwd_f64, BinarySubKernelOp,
     x - y,
     1.0,
     -1.0)
-   
\ No newline at end of file
+
+// integers don't have gradients
+BINARY_OP(size_t, bsub_fwd_usize, bsub_bwd_usize, BinarySubKernelOp,
+    wrapping_sub(x, y),
+    0,
+    0)
+
+BINARY_OP(int, bsub_fwd_i32, bsub_bwd_i32, BinarySubKernelOp,
+    wrapping_sub(x, y),
+    0,
+    0)
+
+BINARY_OP(long long, bsub_fwd_i64, bsub_bwd_i64, BinarySubKernelOp,
+    wrapping_sub(x, y),
+    0,
+    0)
+
+BINARY_OP(unsigned char, bsub_fwd_u8, bsub_bwd_u8, BinarySubKernelOp,
+    wrapping_sub(x, y),
+    0,
+    0)
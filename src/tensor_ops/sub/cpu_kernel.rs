@@ -1,6 +1,9 @@
-use crate::tensor_ops::cpu_kernels::{BinaryDerivative, UnaryDerivative};
+use super::ScalarSubKernelOp;
+use crate::tensor_ops::cpu_kernels::{
+    int_binary_derivative, int_scalar_derivative, BinaryDerivative, Float, UnaryDerivative,
+};
 
-impl<F: num_traits::Float> UnaryDerivative<F> for super::ScalarSubKernelOp<F> {
+impl<F: Float> UnaryDerivative<F> for super::ScalarSubKernelOp<F> {
     fn f(&self, &x: &F) -> F {
         x - self.scalar
     }
@@ -9,7 +12,7 @@ impl<F: num_traits::Float> UnaryDerivative<F> for super::ScalarSubKernelOp<F> {
     }
 }
 
-impl<F: num_traits::Float> BinaryDerivative<F> for super::BinarySubKernelOp {
+impl<F: Float> BinaryDerivative<F> for super::BinarySubKernelOp {
     #[inline(always)]
     fn f(&self, &x: &F, &y: &F) -> F {
         x - y
@@ -23,3 +26,6 @@ impl<F: num_traits::Float> BinaryDerivative<F> for super::BinarySubKernelOp {
         -F::one()
     }
 }
+
+int_binary_derivative!(super::BinarySubKernelOp, |x, y| x.wrapping_sub(y));
+int_scalar_derivative!(ScalarSubKernelOp, |x, scalar| x.wrapping_sub(scalar));
@@ -36,6 +36,15 @@ pub struct ScalarSubKernelOp<E> {
 /// let r = a - 1.0;
 /// assert_eq!(r.array(), [[0.0, 1.0, 2.0], [-2.0, -3.0, -4.0]]);
 /// ```
+///
+/// Integer subtraction wraps around instead of panicking on overflow:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let a = dev.tensor([1usize, 2, 3]);
+/// let r = a - 2;
+/// assert_eq!(r.array(), [usize::MAX, 0, 1]);
+/// ```
 pub fn sub<S: Shape, E: Dtype, D: Device<E>, T: Tape<D> + Merge<RhsTape>, RhsTape: Tape<D>>(
     lhs: Tensor<S, E, D, T>,
     rhs: Tensor<S, E, D, RhsTape>,
@@ -48,9 +57,10 @@ pub trait TrySub<Rhs = Self>: HasErr {
     fn try_sub(self, rhs: Rhs) -> Result<Self, Self::Err>;
 }
 
-impl<S: Shape, E: Dtype, D: Device<E>, LTape: Tape<D>, RTape: Tape<D>>
-    TrySub<Tensor<S, E, D, RTape>> for Tensor<S, E, D, LTape>
+impl<S: Shape, E: Dtype, D, LTape: Tape<D>, RTape: Tape<D>> TrySub<Tensor<S, E, D, RTape>>
+    for Tensor<S, E, D, LTape>
 where
+    D: BinaryKernel<BinarySubKernelOp, E>,
     LTape: Merge<RTape>,
 {
     fn try_sub(self, rhs: Tensor<S, E, D, RTape>) -> Result<Self, Self::Err> {
@@ -58,13 +68,15 @@ where
     }
 }
 
-impl<S: Shape, E: Dtype, D: Device<E>, T: Tape<D>> TrySub<E> for Tensor<S, E, D, T> {
+impl<S: Shape, E: Dtype, D: UnaryKernel<ScalarSubKernelOp<E>, E>, T: Tape<D>> TrySub<E>
+    for Tensor<S, E, D, T>
+{
     fn try_sub(self, rhs: E) -> Result<Self, Self::Err> {
         try_unary_op(ScalarSubKernelOp { scalar: rhs }, self)
     }
 }
 
-impl<S: Shape, E: Dtype, D: Device<E>, LTape: Tape<D>, Rhs> std::ops::Sub<Rhs>
+impl<S: Shape, E: Dtype, D: DeviceStorage, LTape: Tape<D>, Rhs> std::ops::Sub<Rhs>
     for Tensor<S, E, D, LTape>
 where
     Self: TrySub<Rhs>,
@@ -158,4 +170,32 @@ mod tests {
         let g = r.exp().sum().backward();
         assert_close(&g.get(&x).array(), &[[0.36787945; 2]; 3]);
     }
+
+    #[test]
+    fn test_sub_usize() {
+        let dev: TestDevice = Default::default();
+        let a = dev.tensor([[3usize, 4, 5], [6, 7, 8]]);
+        let b = dev.tensor([[1usize, 4, 0], [2, 5, 8]]);
+        assert_eq!((a.clone() - b).array(), [[2, 0, 5], [4, 2, 0]]);
+        assert_eq!((a - 3).array(), [[0, 1, 2], [3, 4, 5]]);
+    }
+
+    #[test]
+    fn test_sub_int_wraps() {
+        let dev: TestDevice = Default::default();
+        let a = dev.tensor([0usize, 1, 2]);
+        let b = dev.tensor([1usize, 3, 2]);
+        assert_eq!((a.clone() - b).array(), [usize::MAX, usize::MAX - 1, 0]);
+        assert_eq!((a - 1).array(), [usize::MAX, 0, 1]);
+
+        let a = dev.tensor([0u8, 5, 255]);
+        assert_eq!((a - 6).array(), [250, 255, 249]);
+
+        let a = dev.tensor([i32::MIN, -1, i32::MAX]);
+        let b = dev.tensor([1i32, i32::MAX, -1]);
+        assert_eq!((a - b).array(), [i32::MAX, i32::MIN, i32::MIN]);
+
+        let a = dev.tensor([i64::MIN, 3]);
+        assert_eq!((a - 4).array(), [i64::MAX - 3, -1]);
+    }
 }
@@ -1,3 +1,4 @@
+#include "cuda_utils.cuh"
 #include "unary_op_macros.cuh"
 
 template<typename F>
@@ -12,4 +13,20 @@ UNARY_OP(float, ssub_fwd_f32, ssub_bwd_f32, ScalarSubKernelOp<float>,
 UNARY_OP(double, ssub_fwd_f64, ssub_bwd_f64, ScalarSubKernelOp<double>,
     x - op.scalar,
     1.0);
-    
\ No newline at end of file
+
+// integers don't have gradients
+UNARY_OP(size_t, ssub_fwd_usize, ssub_bwd_usize, ScalarSubKernelOp<size_t>,
+    wrapping_sub(x, op.scalar),
+    0);
+
+UNARY_OP(int, ssub_fwd_i32, ssub_bwd_i32, ScalarSubKernelOp<int>,
+    wrapping_sub(x, op.scalar),
+    0);
+
+UNARY_OP(long long, ssub_fwd_i64, ssub_bwd_i64, ScalarSubKernelOp<long long>,
+    wrapping_sub(x, op.scalar),
+    0);
+
+UNARY_OP(unsigned char, ssub_fwd_u8, ssub_bwd_u8, ScalarSubKernelOp<unsigned char>,
+    wrapping_sub(x, op.scalar),
+    0);
@@ -129,6 +129,22 @@ mod tests {
         assert_close(&g.get(&t).array(), &g2.get(&t).array());
     }
 
+    #[test]
+    fn test_sum_axes_8d_to_2d() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank8<2, 1, 2, 3, 1, 2, 4, 2>, TestDtype, _> = dev.sample_normal();
+        let r = t.trace().sum::<Rank2<3, 4>, Axes6<0, 1, 2, 4, 5, 7>>();
+        let r2 = t
+            .trace()
+            .sum::<_, Axis<7>>()
+            .sum::<_, Axes2<4, 5>>()
+            .sum::<_, Axes3<0, 1, 2>>();
+        assert_close(&r.array(), &r2.array());
+        let g = r.square().mean().backward();
+        let g2 = r2.square().mean().backward();
+        assert_close(&g.get(&t).as_vec(), &g2.get(&t).as_vec());
+    }
+
     #[test]
     fn test_sum_broadcasted() {
         let dev: TestDevice = Default::default();
@@ -0,0 +1,180 @@
+use crate::{
+    shapes::*,
+    tensor::{
+        cpu::{Cpu, LendingIterator, StridedArray},
+        AsVec,
+    },
+    tensor_ops::utilities::linalg::{svd, SvdShape},
+};
+
+use super::SvdStorage;
+
+use num_traits::Float;
+use std::{sync::Arc, vec::Vec};
+
+impl<E: Dtype + Float> super::SvdKernel<E> for Cpu {
+    fn forward<S: SvdShape, K: Dim>(
+        &self,
+        inp: &Self::Storage<S, E>,
+        k: K,
+    ) -> Result<(SvdStorage<Self, S, usize, E>, SvdStorage<Self, S, K, E>), Self::Err> {
+        let (m, n) = (inp.shape.rows(), inp.shape.cols());
+        let r = m.min(n);
+        let kk = k.size();
+        let (u_shape, s_shape, v_shape) = inp.shape.svd_shapes(r);
+        let mut u: StridedArray<_, E> = StridedArray::new(u_shape)?;
+        let mut s: StridedArray<_, E> = StridedArray::new(s_shape)?;
+        let mut v: StridedArray<_, E> = StridedArray::new(v_shape)?;
+        let (u_k_shape, s_k_shape, v_k_shape) = inp.shape.svd_shapes(k);
+        let mut u_k: StridedArray<_, E> = StridedArray::new(u_k_shape)?;
+        let mut s_k: StridedArray<_, E> = StridedArray::new(s_k_shape)?;
+        let mut v_k: StridedArray<_, E> = StridedArray::new(v_k_shape)?;
+
+        if inp.shape.num_elements() > 0 {
+            let buf = inp.as_vec();
+            let u_buf = Arc::make_mut(&mut u.data);
+            let s_buf = Arc::make_mut(&mut s.data);
+            let v_buf = Arc::make_mut(&mut v.data);
+            let u_k_buf = Arc::make_mut(&mut u_k.data);
+            let s_k_buf = Arc::make_mut(&mut s_k.data);
+            let v_k_buf = Arc::make_mut(&mut v_k.data);
+            for (b, a) in buf.chunks_exact(m * n).enumerate() {
+                let (u_b, s_b, v_b) = svd(a, m, n);
+                u_buf[b * m * r..(b + 1) * m * r].copy_from_slice(&u_b);
+                s_buf[b * r..(b + 1) * r].copy_from_slice(&s_b);
+                v_buf[b * n * r..(b + 1) * n * r].copy_from_slice(&v_b);
+                s_k_buf[b * kk..(b + 1) * kk].copy_from_slice(&s_b[..kk]);
+                for i in 0..m {
+                    let dst = (b * m + i) * kk;
+                    u_k_buf[dst..dst + kk].copy_from_slice(&u_b[i * r..i * r + kk]);
+                }
+                for i in 0..n {
+                    let dst = (b * n + i) * kk;
+                    v_k_buf[dst..dst + kk].copy_from_slice(&v_b[i * r..i * r + kk]);
+                }
+            }
+        }
+        Ok(((u, s, v), (u_k, s_k, v_k)))
+    }
+
+    fn backward<S: SvdShape, K: Dim>(
+        &self,
+        factors: &SvdStorage<Self, S, usize, E>,
+        grad_inp: &mut Self::Storage<S, E>,
+        grad_out: &SvdStorage<Self, S, K, E>,
+    ) -> Result<(), Self::Err> {
+        if grad_inp.shape.num_elements() == 0 {
+            return Ok(());
+        }
+        let (m, n) = (grad_inp.shape.rows(), grad_inp.shape.cols());
+        let r = m.min(n);
+        let num_batch = grad_inp.shape.batch().num_elements();
+        let kk = grad_out.1.shape.num_elements() / num_batch;
+        let (u, s, v) = (factors.0.as_vec(), factors.1.as_vec(), factors.2.as_vec());
+        let (gu_k, gs_k, gv_k) = (
+            grad_out.0.as_vec(),
+            grad_out.1.as_vec(),
+            grad_out.2.as_vec(),
+        );
+
+        // a zero numerator means the factor received no gradient, so it's skipped even
+        // if the denominator is zero
+        let div = |x: E, d: E| if x == E::zero() { x } else { x / d };
+
+        let mut grad_inp_iter = grad_inp.iter_mut();
+        let mut gu = alloc::vec![E::zero(); m * r];
+        let mut gv = alloc::vec![E::zero(); n * r];
+        let mut inner = alloc::vec![E::zero(); r * r];
+        let mut ga = alloc::vec![E::zero(); m * n];
+        for b in 0..num_batch {
+            let u = &u[b * m * r..(b + 1) * m * r];
+            let s = &s[b * r..(b + 1) * r];
+            let v = &v[b * n * r..(b + 1) * n * r];
+            for i in 0..m {
+                for j in 0..r {
+                    gu[i * r + j] = if j < kk {
+                        gu_k[(b * m + i) * kk + j]
+                    } else {
+                        E::zero()
+                    };
+                }
+            }
+            for i in 0..n {
+                for j in 0..r {
+                    gv[i * r + j] = if j < kk {
+                        gv_k[(b * n + i) * kk + j]
+                    } else {
+                        E::zero()
+                    };
+                }
+            }
+            let ut_gu = transposed_matmul(u, &gu, m, r);
+            let vt_gv = transposed_matmul(v, &gv, n, r);
+
+            // inner = F∘(U^T gU - gU^T U) S + diag(gS) + S F∘(V^T gV - gV^T V),
+            // where F_ij = 1 / (s_j^2 - s_i^2)
+            for i in 0..r {
+                for j in 0..r {
+                    inner[i * r + j] = if i == j {
+                        if j < kk {
+                            gs_k[b * kk + j]
+                        } else {
+                            E::zero()
+                        }
+                    } else {
+                        let f = s[j] * s[j] - s[i] * s[i];
+                        div(ut_gu[i * r + j] - ut_gu[j * r + i], f) * s[j]
+                            + s[i] * div(vt_gv[i * r + j] - vt_gv[j * r + i], f)
+                    };
+                }
+            }
+
+            // (I - U U^T) gU and (I - V V^T) gV
+            for i in 0..m {
+                for j in 0..r {
+                    let p = (0..r).fold(E::zero(), |acc, k| acc + u[i * r + k] * ut_gu[k * r + j]);
+                    gu[i * r + j] -= p;
+                }
+            }
+            for i in 0..n {
+                for j in 0..r {
+                    let p = (0..r).fold(E::zero(), |acc, k| acc + v[i * r + k] * vt_gv[k * r + j]);
+                    gv[i * r + j] -= p;
+                }
+            }
+
+            // grad = U inner V^T + (I - U U^T) gU S^-1 V^T + U S^-1 gV^T (I - V V^T)
+            for i in 0..m {
+                for j in 0..n {
+                    let mut g = E::zero();
+                    for k in 0..r {
+                        let mut ui = E::zero();
+                        for l in 0..r {
+                            ui += u[i * r + l] * inner[l * r + k];
+                        }
+                        g += ui * v[j * r + k];
+                        g += div(gu[i * r + k], s[k]) * v[j * r + k];
+                        g += div(gv[j * r + k], s[k]) * u[i * r + k];
+                    }
+                    ga[i * n + j] = g;
+                }
+            }
+            for g in ga.iter() {
+                *grad_inp_iter.next().unwrap() += *g;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// `x^T y` for `rows x cols` matrices `x` and `y`.
+fn transposed_matmul<E: Float>(x: &[E], y: &[E], rows: usize, cols: usize) -> Vec<E> {
+    let mut out = alloc::vec![E::zero(); cols * cols];
+    for i in 0..cols {
+        for j in 0..cols {
+            out[i * cols + j] =
+                (0..rows).fold(E::zero(), |acc, k| acc + x[k * cols + i] * y[k * cols + j]);
+        }
+    }
+    out
+}
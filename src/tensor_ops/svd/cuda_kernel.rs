@@ -0,0 +1,33 @@
+use crate::{shapes::*, tensor::cuda::Cuda, tensor_ops::utilities::linalg::SvdShape};
+
+use super::SvdStorage;
+
+use num_traits::Float;
+
+impl<E: Dtype + Float> super::SvdKernel<E> for Cuda {
+    fn forward<S: SvdShape, K: Dim>(
+        &self,
+        inp: &Self::Storage<S, E>,
+        k: K,
+    ) -> Result<(SvdStorage<Self, S, usize, E>, SvdStorage<Self, S, K, E>), Self::Err> {
+        self.on_host(inp, |cpu, inp| cpu.forward(&inp, k))
+    }
+
+    fn backward<S: SvdShape, K: Dim>(
+        &self,
+        factors: &SvdStorage<Self, S, usize, E>,
+        grad_inp: &mut Self::Storage<S, E>,
+        grad_out: &SvdStorage<Self, S, K, E>,
+    ) -> Result<(), Self::Err> {
+        let (u, s, v) = factors;
+        let (grad_u, grad_s, grad_v) = grad_out;
+        *grad_inp = self.on_host(
+            ((u, s, v), &*grad_inp, (grad_u, grad_s, grad_v)),
+            |cpu, (factors, mut grad_inp, grad_out)| {
+                cpu.backward(&factors, &mut grad_inp, &grad_out)?;
+                Ok(grad_inp)
+            },
+        )?;
+        Ok(())
+    }
+}
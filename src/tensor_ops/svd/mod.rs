@@ -0,0 +1,200 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{gradients::Tape, shapes::*, tensor::*};
+
+use super::utilities::linalg::SvdShape;
+
+/// The `U`, `S` and `V` factors of a batch of matrices of shape `S`, keeping `K` singular values.
+type SvdStorage<D, S, K, E> = (
+    <D as DeviceStorage>::Storage<<S as SvdShape>::U<K>, E>,
+    <D as DeviceStorage>::Storage<<S as SvdShape>::S<K>, E>,
+    <D as DeviceStorage>::Storage<<S as SvdShape>::V<K>, E>,
+);
+
+pub trait SvdKernel<E: Dtype>: DeviceStorage {
+    /// Returns the factors of the reduced decomposition with `min(M, N)` singular values,
+    /// and the factors truncated to the first `k` singular values.
+    #[allow(clippy::type_complexity)]
+    fn forward<S: SvdShape, K: Dim>(
+        &self,
+        inp: &Self::Storage<S, E>,
+        k: K,
+    ) -> Result<(SvdStorage<Self, S, usize, E>, SvdStorage<Self, S, K, E>), Self::Err>;
+
+    /// Adds the gradient of the truncated factors to `grad_inp`, using the factors of the
+    /// reduced decomposition.
+    fn backward<S: SvdShape, K: Dim>(
+        &self,
+        factors: &SvdStorage<Self, S, usize, E>,
+        grad_inp: &mut Self::Storage<S, E>,
+        grad_out: &SvdStorage<Self, S, K, E>,
+    ) -> Result<(), Self::Err>;
+}
+
+/// The `U`, `S` and `V` tensors returned by [Tensor::svd_truncated].
+type Svd<S, K, E, D, T> = (
+    Tensor<<S as SvdShape>::U<K>, E, D, T>,
+    Tensor<<S as SvdShape>::S<K>, E, D, T>,
+    Tensor<<S as SvdShape>::V<K>, E, D, T>,
+);
+
+impl<S: SvdShape, E: Dtype, D: SvdKernel<E>, T: Tape<D>> Tensor<S, E, D, T> {
+    /// Reduced singular value decomposition `A = U diag(S) V^T` of a matrix, or a batch of
+    /// matrices in the last two axes. For `(.., M, N)` matrices, with `K = min(M, N)`, this
+    /// returns `U: (.., M, K)`, `S: (.., K)` in descending order, and `V: (.., N, K)`.
+    ///
+    /// See [Tensor::svd_truncated] for details on gradients.
+    ///
+    /// **Pytorch equivalent**: `torch.linalg.svd(t, full_matrices=False)`, except that `V`
+    /// is returned instead of `V^T`.
+    ///
+    /// **Cuda**: currently syncs to the host, like [Tensor::svd_truncated].
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t: Tensor<Rank2<3, 2>, f32, _> = dev.tensor([[0.0, 2.0], [-3.0, 0.0], [0.0, 0.0]]);
+    /// let (u, s, v) = t.svd();
+    /// assert_eq!(s.as_vec(), [3.0, 2.0]);
+    /// assert_eq!(u.shape(), &(Const::<3>, 2));
+    /// assert_eq!(v.shape(), &(Const::<2>, 2));
+    /// ```
+    pub fn svd(self) -> Svd<S, usize, E, D, T> {
+        self.try_svd().unwrap()
+    }
+
+    /// Fallible version of [Tensor::svd]
+    pub fn try_svd(self) -> Result<Svd<S, usize, E, D, T>, D::Err> {
+        let k = self.shape().rows().min(self.shape().cols());
+        self.try_svd_truncated(k)
+    }
+
+    /// Singular value decomposition `A = U diag(S) V^T`, keeping only the `k` largest
+    /// singular values, so `U: (.., M, K)`, `S: (.., K)` and `V: (.., N, K)`.
+    /// `k` must be at most `min(M, N)`.
+    ///
+    /// Gradients flow through all of `U`, `S` and `V`. The tape is kept by `S`, and `U`
+    /// and `V` start with an empty tape, so gradients only flow if `S` is used in the final
+    /// computation. Gradients of `U` and `V` are not finite for repeated or zero singular
+    /// values, and the signs of the singular vectors are arbitrary, so losses should only
+    /// depend on `U` and `V` in sign invariant ways.
+    ///
+    /// **Cuda**: currently syncs to the host. The decomposition runs on the cpu, and
+    /// each call and backward pass copies the matrices to the host and back.
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t: Tensor<Rank2<3, 2>, f32, _> = dev.tensor([[0.0, 2.0], [-3.0, 0.0], [0.0, 0.0]]);
+    /// let (u, s, v) = t.svd_truncated(Const::<1>);
+    /// assert_eq!(s.array(), [3.0]);
+    /// assert_eq!(u.array().map(|r| r[0].abs()), [0.0, 1.0, 0.0]);
+    /// assert_eq!(v.array().map(|r| r[0].abs()), [1.0, 0.0]);
+    /// ```
+    pub fn svd_truncated<K: Dim>(self, k: K) -> Svd<S, K, E, D, T> {
+        self.try_svd_truncated(k).unwrap()
+    }
+
+    /// Fallible version of [Tensor::svd_truncated]
+    pub fn try_svd_truncated<K: Dim>(self, k: K) -> Result<Svd<S, K, E, D, T>, D::Err> {
+        let shape = *self.shape();
+        assert!(
+            k.size() <= shape.rows().min(shape.cols()),
+            "k must be at most the number of singular values"
+        );
+        let (inp, mut tape) = self.split_tape();
+        let (factors, (u, s, v)) = inp.device.forward(&inp.storage, k)?;
+        let u = inp.device.upgrade(u);
+        let s = inp.device.upgrade(s);
+        let v = inp.device.upgrade(v);
+        let (phantom_u, phantom_s, phantom_v) = (u.clone(), s.clone(), v.clone());
+        tape.try_alloc_grad(&inp)?;
+        tape.try_alloc_grad(&u)?;
+        tape.try_alloc_grad(&s)?;
+        tape.try_alloc_grad(&v)?;
+        tape.add_backward_op(move |grads| {
+            let grad_out = (
+                grads.get(&phantom_u).clone(),
+                grads.get(&phantom_s).clone(),
+                grads.get(&phantom_v).clone(),
+            );
+            let grad_inp = grads.get_mut(&inp);
+            inp.device.backward(&factors, grad_inp, &grad_out)
+        });
+        Ok((
+            u.put_tape(Default::default()),
+            s.put_tape(tape),
+            v.put_tape(Default::default()),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{shapes::*, tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_svd_reconstructs() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank3<2, 4, 3>, TestDtype, _> = dev.sample_normal();
+        let (u, s, v) = t.clone().svd_truncated(Const::<3>);
+        assert!(s
+            .array()
+            .iter()
+            .all(|s| s[0] >= s[1] && s[1] >= s[2] && s[2] >= 0.0));
+
+        let us = u.clone() * s.broadcast::<Rank3<2, 4, 3>, Axis<1>>();
+        let r = us.matmul(v.clone().permute::<_, Axes3<0, 2, 1>>());
+        assert_close_with_tolerance(&r.array(), &t.array(), 1e-5);
+
+        let eye = [[[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]; 2];
+        let utu = u.clone().permute::<_, Axes3<0, 2, 1>>().matmul(u);
+        assert_close_with_tolerance(&utu.array(), &eye, 1e-5);
+        let vtv = v.clone().permute::<_, Axes3<0, 2, 1>>().matmul(v);
+        assert_close_with_tolerance(&vtv.array(), &eye, 1e-5);
+    }
+
+    #[test]
+    fn test_svd_wide_singular_values() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([[3.0, 0.0, 0.0], [0.0, 0.0, -4.0]]);
+        let (_, s, _) = t.trace().svd();
+        assert_close(&s.as_vec(), &std::vec![4.0, 3.0]);
+        // the gradient of each singular value is u v^T
+        let g = s.sum().backward();
+        assert_close(&g.get(&t).array(), &[[1.0, 0.0, 0.0], [0.0, 0.0, -1.0]]);
+    }
+
+    #[test]
+    fn test_svd_backward_through_all_factors() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank2<4, 3>, TestDtype, _> = dev.sample_normal();
+        let (u, s, v) = t.trace().svd_truncated(Const::<3>);
+        // U diag(S) V^T is the identity function
+        let us = s.broadcast::<Rank2<4, 3>, Axis<0>>() * u;
+        let r = us.matmul(v.permute());
+        let w: Tensor<Rank2<4, 3>, TestDtype, _> = dev.sample_normal();
+        let g = (r * w.clone()).sum().backward();
+        assert_close_with_tolerance(&g.get(&t).array(), &w.array(), 1e-4);
+    }
+
+    #[test]
+    fn test_svd_truncated_backward() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank3<2, 3, 4>, TestDtype, _> = dev.sample_normal();
+
+        // the squared norm of the rank 1 approximation is s_1^2, whose gradient is
+        // 2 s_1 u_1 v_1^T
+        let (u, s, v) = t.trace().svd_truncated(Const::<1>);
+        let us = s.broadcast::<Rank3<2, 3, 1>, Axis<1>>() * u;
+        let r = us.matmul(v.permute::<_, Axes3<0, 2, 1>>());
+        let g = r.square().sum().backward();
+
+        let (_, s, _) = t.trace().svd_truncated(Const::<1>);
+        let g2 = s.square().sum().backward();
+        assert_close_with_tolerance(&g.get(&t).array(), &g2.get(&t).array(), 1e-4);
+    }
+}
@@ -0,0 +1,32 @@
+use crate::shapes::{Dtype, Shape};
+use crate::tensor::cpu::{Cpu, LendingIterator, StridedArray};
+
+use num_traits::AsPrimitive;
+
+impl<E1: Dtype + AsPrimitive<E2>, E2: Dtype + AsPrimitive<E1>> super::ToDtypeKernel<E1, E2>
+    for Cpu
+{
+    fn forward<S: Shape>(
+        &self,
+        inp: &Self::Storage<S, E1>,
+    ) -> Result<Self::Storage<S, E2>, Self::Err> {
+        let mut out: Self::Storage<S, E2> = StridedArray::new(inp.shape)?;
+        let mut out_iter = out.iter_mut_with_index();
+        while let Some((o, i)) = out_iter.next() {
+            *o = inp[i].as_();
+        }
+        Ok(out)
+    }
+
+    fn backward<S: Shape>(
+        &self,
+        grad_inp: &mut Self::Storage<S, E1>,
+        grad_out: &Self::Storage<S, E2>,
+    ) -> Result<(), Self::Err> {
+        let mut grad_out_iter = grad_out.iter_with_index();
+        while let Some((g, i)) = grad_out_iter.next() {
+            grad_inp[i] += g.as_();
+        }
+        Ok(())
+    }
+}
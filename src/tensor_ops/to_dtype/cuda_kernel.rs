@@ -0,0 +1,109 @@
+use crate::{
+    shapes::*,
+    tensor::cuda::{Cuda, CudaArray},
+};
+use cudarc::driver::{LaunchAsync, LaunchConfig};
+use std::sync::Arc;
+
+const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/to_dtype.ptx"));
+
+trait HasCudaKernel<E1, E2> {
+    const MOD: &'static str;
+    const FNS: &'static [&'static str];
+}
+
+macro_rules! cuda_to_dtype {
+    ($Src:ty, $Dst:ty, $src:literal, $dst:literal) => {
+        impl HasCudaKernel<$Src, $Dst> for Cuda {
+            const MOD: &'static str = concat!("to_dtype_", $src, "_", $dst);
+            const FNS: &'static [&'static str] = &[
+                concat!("to_dtype_fwd_", $src, "_", $dst),
+                concat!("to_dtype_bwd_", $src, "_", $dst),
+            ];
+        }
+    };
+}
+
+macro_rules! cuda_to_dtypes {
+    ($Src:ty, $src:literal) => {
+        cuda_to_dtype!($Src, f32, $src, "f32");
+        cuda_to_dtype!($Src, f64, $src, "f64");
+        cuda_to_dtype!($Src, i32, $src, "i32");
+        cuda_to_dtype!($Src, i64, $src, "i64");
+        cuda_to_dtype!($Src, u8, $src, "u8");
+        cuda_to_dtype!($Src, usize, $src, "usize");
+    };
+}
+
+cuda_to_dtypes!(f32, "f32");
+cuda_to_dtypes!(f64, "f64");
+cuda_to_dtypes!(i32, "i32");
+cuda_to_dtypes!(i64, "i64");
+cuda_to_dtypes!(u8, "u8");
+cuda_to_dtypes!(usize, "usize");
+
+impl<E1: Dtype, E2: Dtype> super::ToDtypeKernel<E1, E2> for Cuda
+where
+    Self: HasCudaKernel<E1, E2>,
+{
+    fn forward<S: Shape>(
+        &self,
+        inp: &Self::Storage<S, E1>,
+    ) -> Result<Self::Storage<S, E2>, Self::Err> {
+        if !self.dev.has_func(Self::MOD, Self::FNS[0]) {
+            self.dev.load_ptx(PTX_SRC.into(), Self::MOD, Self::FNS)?;
+        }
+
+        let shape = inp.shape;
+        let strides = shape.strides();
+        let numel = shape.num_elements();
+        let mut storage = unsafe { self.dev.alloc_async::<E2>(numel) }?;
+
+        let dims = self.dev.take_async(shape.concrete().into())?;
+        let inp_strides = self.dev.take_async(inp.strides.into())?;
+
+        let fwd_fn = self.dev.get_func(Self::MOD, Self::FNS[0]).unwrap();
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            numel,             // const size_t numel,
+            S::NUM_DIMS,       // const size_t num_dims,
+            &dims,             // const size_t *dims,
+            &inp_strides,      // const size_t *inp_strides,
+            inp.data.as_ref(), // const SRC *inp,
+            &mut storage,      // DST *out
+        );
+        unsafe { fwd_fn.launch_async(cfg, params) }?;
+
+        Ok(CudaArray {
+            data: Arc::new(storage),
+            shape,
+            strides,
+        })
+    }
+
+    fn backward<S: Shape>(
+        &self,
+        grad_inp: &mut Self::Storage<S, E1>,
+        grad_out: &Self::Storage<S, E2>,
+    ) -> Result<(), Self::Err> {
+        let numel = grad_out.shape.num_elements();
+
+        let dims = self.dev.take_async(grad_out.shape.concrete().into())?;
+        let inp_strides = self.dev.take_async(grad_inp.strides.into())?;
+        let out_strides = self.dev.take_async(grad_out.strides.into())?;
+
+        let bwd_fn = self.dev.get_func(Self::MOD, Self::FNS[1]).unwrap();
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            numel,                             // const size_t numel,
+            S::NUM_DIMS,                       // const size_t num_dims,
+            &dims,                             // const size_t *dims,
+            &inp_strides,                      // const size_t *inp_strides,
+            &out_strides,                      // const size_t *out_strides,
+            Arc::make_mut(&mut grad_inp.data), // SRC *grad_inp,
+            grad_out.data.as_ref(),            // const DST *grad_out
+        );
+        unsafe { bwd_fn.launch_async(cfg, params) }?;
+        Ok(())
+    }
+}
@@ -0,0 +1,124 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{gradients::Tape, shapes::*, tensor::*};
+
+pub trait ToDtypeKernel<E1: Dtype, E2: Dtype>: DeviceStorage {
+    fn forward<S: Shape>(
+        &self,
+        inp: &Self::Storage<S, E1>,
+    ) -> Result<Self::Storage<S, E2>, Self::Err>;
+    fn backward<S: Shape>(
+        &self,
+        grad_inp: &mut Self::Storage<S, E1>,
+        grad_out: &Self::Storage<S, E2>,
+    ) -> Result<(), Self::Err>;
+}
+
+/// Casts `t` to the dtype `E2`, with the same semantics as an `as` cast in rust. Float to
+/// integer casts round towards zero and saturate, and NaN becomes `0`.
+///
+/// The cast is part of the autodiff graph, and the gradient is cast back to the original
+/// dtype. This is only meaningful between float dtypes, since the gradient of an integer
+/// is truncated like any other integer.
+///
+/// **Pytorch equivalent**: `t.to(dtype)`
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([1.5f32, -2.5, 3.0]);
+/// let r: Tensor<Rank1<3>, f64, _> = t.clone().to_dtype::<f64>();
+/// assert_eq!(r.array(), [1.5, -2.5, 3.0]);
+/// let r = t.to_dtype::<i32>();
+/// assert_eq!(r.array(), [1, -2, 3]);
+/// ```
+pub fn to_dtype<E2: Dtype, S: Shape, E: Dtype, D: ToDtypeKernel<E, E2>, T: Tape<D>>(
+    t: Tensor<S, E, D, T>,
+) -> Tensor<S, E2, D, T> {
+    t.to_dtype()
+}
+
+impl<S: Shape, E: Dtype, D: DeviceStorage, T: Tape<D>> Tensor<S, E, D, T> {
+    /// See [to_dtype]
+    pub fn to_dtype<E2: Dtype>(self) -> Tensor<S, E2, D, T>
+    where
+        D: ToDtypeKernel<E, E2>,
+    {
+        self.try_to_dtype().unwrap()
+    }
+
+    /// See [to_dtype]
+    pub fn try_to_dtype<E2: Dtype>(self) -> Result<Tensor<S, E2, D, T>, D::Err>
+    where
+        D: ToDtypeKernel<E, E2>,
+    {
+        let (inp, mut tape) = self.split_tape();
+        let out = inp.device.upgrade(inp.device.forward(&inp.storage)?);
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&inp)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+            inp.device.backward(grad_inp, grad_out)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{shapes::*, tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_f32_to_f64() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, f32, _> = dev.tensor([0.1, -2.0, 3.5]);
+        let r = t.trace().to_dtype::<f64>();
+        assert_eq!(r.array(), [0.1f32 as f64, -2.0, 3.5]);
+        let g = (r * 2.0).sum().backward();
+        assert_eq!(g.get(&t).array(), [2.0; 3]);
+    }
+
+    #[test]
+    fn test_f64_to_f32() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, f64, _> = dev.tensor([1e-50, 1e50, 0.5]);
+        let r = t.trace().to_dtype::<f32>();
+        assert_eq!(r.array(), [0.0, f32::INFINITY, 0.5]);
+        let g = r.square().sum().backward();
+        assert_eq!(g.get(&t).array()[2], 1.0);
+    }
+
+    #[test]
+    fn test_float_to_int() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([-1.7, -0.5, 0.9, 300.0, TestDtype::NAN]);
+        assert_eq!(t.clone().to_dtype::<i32>().array(), [-1, 0, 0, 300, 0]);
+        assert_eq!(t.clone().to_dtype::<i64>().array(), [-1, 0, 0, 300, 0]);
+        assert_eq!(t.to_dtype::<u8>().array(), [0, 0, 0, 255, 0]);
+    }
+
+    #[test]
+    fn test_int_to_float() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, usize, _> = dev.tensor([0, 1, 2, 3]);
+        let r = t.to_dtype::<TestDtype>() / 2.0;
+        assert_eq!(r.array(), [0.0, 0.5, 1.0, 1.5]);
+        let t: Tensor<_, u8, _> = dev.tensor([0, 255]);
+        assert_eq!(t.to_dtype::<i32>().array(), [0, 255]);
+    }
+
+    #[test]
+    fn test_to_dtype_broadcasted_backward() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, f32, _> = dev.tensor([1.0, 2.0]);
+        let r = t.trace().broadcast::<Rank2<3, 2>, _>().to_dtype::<f64>();
+        assert_eq!(r.array(), [[1.0, 2.0]; 3]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&t).array(), [3.0; 2]);
+    }
+}
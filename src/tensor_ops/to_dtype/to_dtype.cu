@@ -0,0 +1,80 @@
+#include <limits.h>
+#include <stdint.h>
+#include "cuda_utils.cuh"
+
+// Float to integer casts in C++ are undefined when the value is out of range, so
+// this saturates and maps NaN to 0 like an `as` cast in rust.
+template<typename DST, typename SRC>
+__device__ DST cast(SRC x) {
+    return (DST)x;
+}
+
+#define SATURATING_CAST(DST, SRC, LO, HI) \
+template<> \
+__device__ DST cast<DST, SRC>(SRC x) { \
+    if (x != x) { \
+        return 0; \
+    } else if (x <= (SRC)LO) { \
+        return LO; \
+    } else if (x >= (SRC)HI) { \
+        return HI; \
+    } else { \
+        return (DST)x; \
+    } \
+}
+
+SATURATING_CAST(int, float, INT_MIN, INT_MAX)
+SATURATING_CAST(int, double, INT_MIN, INT_MAX)
+SATURATING_CAST(long long, float, LLONG_MIN, LLONG_MAX)
+SATURATING_CAST(long long, double, LLONG_MIN, LLONG_MAX)
+SATURATING_CAST(unsigned char, float, 0, UCHAR_MAX)
+SATURATING_CAST(unsigned char, double, 0, UCHAR_MAX)
+SATURATING_CAST(size_t, float, 0, SIZE_MAX)
+SATURATING_CAST(size_t, double, 0, SIZE_MAX)
+
+#define TO_DTYPE(SRC, DST, SRC_NAME, DST_NAME) \
+extern "C" __global__ void to_dtype_fwd_##SRC_NAME##_##DST_NAME( \
+    const size_t numel, \
+    const size_t num_dims, \
+    const size_t *dims, \
+    const size_t *inp_strides, \
+    const SRC *inp, \
+    DST *out \
+) { \
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x; \
+    if (i >= numel) { \
+        return; \
+    } \
+    out[i] = cast<DST, SRC>(inp[get_strided_index(i, num_dims, dims, inp_strides)]); \
+} \
+extern "C" __global__ void to_dtype_bwd_##SRC_NAME##_##DST_NAME( \
+    const size_t numel, \
+    const size_t num_dims, \
+    const size_t *dims, \
+    const size_t *inp_strides, \
+    const size_t *out_strides, \
+    SRC *grad_inp, \
+    const DST *grad_out \
+) { \
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x; \
+    if (i >= numel) { \
+        return; \
+    } \
+    DST g = grad_out[get_strided_index(i, num_dims, dims, out_strides)]; \
+    atomicAdd(grad_inp + get_strided_index(i, num_dims, dims, inp_strides), cast<SRC, DST>(g)); \
+}
+
+#define TO_DTYPES(SRC, SRC_NAME) \
+TO_DTYPE(SRC, float, SRC_NAME, f32) \
+TO_DTYPE(SRC, double, SRC_NAME, f64) \
+TO_DTYPE(SRC, int, SRC_NAME, i32) \
+TO_DTYPE(SRC, long long, SRC_NAME, i64) \
+TO_DTYPE(SRC, unsigned char, SRC_NAME, u8) \
+TO_DTYPE(SRC, size_t, SRC_NAME, usize)
+
+TO_DTYPES(float, f32)
+TO_DTYPES(double, f64)
+TO_DTYPES(int, i32)
+TO_DTYPES(long long, i64)
+TO_DTYPES(unsigned char, u8)
+TO_DTYPES(size_t, usize)
@@ -0,0 +1,89 @@
+use crate::{
+    shapes::{Axes, Dtype, HasAxes, ReduceShapeTo, Shape},
+    tensor::cpu::{Cpu, StridedArray},
+    tensor_ops::utilities::reduction_utils::index_for_reductions,
+};
+
+use std::vec::Vec;
+
+impl<E: Dtype> super::TrapzKernel<E> for Cpu {
+    fn forward<Src: Shape + ReduceShapeTo<Dst, Ax>, Dst: Shape, Ax: Axes>(
+        &self,
+        dst: Dst,
+        y: &Self::Storage<Src, E>,
+        x: Option<&Self::Storage<Src, E>>,
+        dx: E,
+    ) -> Result<Self::Storage<Dst, E>, Self::Err> {
+        let mut out: StridedArray<Dst, E> = StridedArray::new(dst)?;
+        let n = <Src as HasAxes<Ax>>::size(&y.shape);
+        let two = E::ONE + E::ONE;
+        let mut y_idx = index_for_reductions::<Src, Ax>(y.shape, y.strides);
+        let mut x_idx = x.map(|x| index_for_reductions::<Src, Ax>(x.shape, x.strides));
+        for o in out.buf_iter_mut() {
+            let mut tmp = E::default();
+            let mut prev: Option<(E, E)> = None;
+            for _ in 0..n {
+                let y_k = y.data[y_idx.next().unwrap()];
+                let x_k = match (x, x_idx.as_mut()) {
+                    (Some(x), Some(idx)) => x.data[idx.next().unwrap()],
+                    _ => Default::default(),
+                };
+                if let Some((y_prev, x_prev)) = prev {
+                    let h = if x.is_some() { x_k - x_prev } else { dx };
+                    tmp += h * (y_k + y_prev);
+                }
+                prev = Some((y_k, x_k));
+            }
+            *o = tmp / two;
+        }
+        Ok(out)
+    }
+
+    fn backward<Src: Shape + ReduceShapeTo<Dst, Ax>, Dst: Shape, Ax: Axes>(
+        &self,
+        y: &Self::Storage<Src, E>,
+        grad_y: &mut Self::Storage<Src, E>,
+        mut x: Option<(&Self::Storage<Src, E>, &mut Self::Storage<Src, E>)>,
+        dx: E,
+        grad_out: &Self::Storage<Dst, E>,
+    ) -> Result<(), Self::Err> {
+        let n = <Src as HasAxes<Ax>>::size(&y.shape);
+        let two = E::ONE + E::ONE;
+        let mut y_idx = index_for_reductions::<Src, Ax>(y.shape, y.strides);
+        let mut gy_idx = index_for_reductions::<Src, Ax>(grad_y.shape, grad_y.strides);
+        let mut x_idx = x.as_ref().map(|(x, gx)| {
+            (
+                index_for_reductions::<Src, Ax>(x.shape, x.strides),
+                index_for_reductions::<Src, Ax>(gx.shape, gx.strides),
+            )
+        });
+        let grad_y_buf = std::sync::Arc::make_mut(&mut grad_y.data);
+        for &g in grad_out.buf_iter() {
+            let g = g / two;
+            let i_y: Vec<usize> = (0..n).map(|_| y_idx.next().unwrap()).collect();
+            let i_gy: Vec<usize> = (0..n).map(|_| gy_idx.next().unwrap()).collect();
+            match (x.as_mut(), x_idx.as_mut()) {
+                (Some((x, grad_x)), Some((x_idx, gx_idx))) => {
+                    let i_x: Vec<usize> = (0..n).map(|_| x_idx.next().unwrap()).collect();
+                    let i_gx: Vec<usize> = (0..n).map(|_| gx_idx.next().unwrap()).collect();
+                    let grad_x_buf = std::sync::Arc::make_mut(&mut grad_x.data);
+                    for k in 1..n {
+                        let h = x.data[i_x[k]] - x.data[i_x[k - 1]];
+                        let s = y.data[i_y[k]] + y.data[i_y[k - 1]];
+                        grad_y_buf[i_gy[k]] += g * h;
+                        grad_y_buf[i_gy[k - 1]] += g * h;
+                        grad_x_buf[i_gx[k]] += g * s;
+                        grad_x_buf[i_gx[k - 1]] -= g * s;
+                    }
+                }
+                _ => {
+                    for k in 1..n {
+                        grad_y_buf[i_gy[k]] += g * dx;
+                        grad_y_buf[i_gy[k - 1]] += g * dx;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
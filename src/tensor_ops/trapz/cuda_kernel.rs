@@ -0,0 +1,168 @@
+use crate::{
+    shapes::*,
+    tensor::cuda::{Cuda, CudaArray},
+};
+use cudarc::driver::{AsKernelParam, CudaSlice, LaunchAsync, LaunchConfig};
+use std::{sync::Arc, vec::Vec};
+
+const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/trapz.ptx"));
+
+trait HasCudaKernel<E> {
+    const MOD: &'static str;
+    const FNS: &'static [&'static str];
+}
+
+impl HasCudaKernel<f32> for Cuda {
+    const MOD: &'static str = "trapz_f32";
+    const FNS: &'static [&'static str] = &[
+        "trapz_dx_fwd_f32",
+        "trapz_dx_bwd_f32",
+        "trapz_x_fwd_f32",
+        "trapz_x_bwd_f32",
+    ];
+}
+
+impl HasCudaKernel<f64> for Cuda {
+    const MOD: &'static str = "trapz_f64";
+    const FNS: &'static [&'static str] = &[
+        "trapz_dx_fwd_f64",
+        "trapz_dx_bwd_f64",
+        "trapz_x_fwd_f64",
+        "trapz_x_bwd_f64",
+    ];
+}
+
+/// Moves the reduced axis to the end, so each output element integrates a
+/// contiguous chunk of logical indices
+fn reduced_last<S: Shape, Ax: Axes>(shape: &S, strides: S::Concrete) -> (Vec<usize>, Vec<usize>) {
+    let mut axes: Vec<(bool, (usize, usize))> = shape
+        .concrete()
+        .into_iter()
+        .zip(strides.into_iter())
+        .map(|x| (false, x))
+        .collect();
+    for i in Ax::as_array().into_iter() {
+        axes[i as usize].0 = true;
+    }
+    axes.sort_by_key(|x| x.0);
+    axes.into_iter().map(|(_, x)| x).unzip()
+}
+
+impl<E: Dtype + AsKernelParam> super::TrapzKernel<E> for Cuda
+where
+    Self: HasCudaKernel<E>,
+{
+    fn forward<Src: Shape + ReduceShapeTo<Dst, Ax>, Dst: Shape, Ax: Axes>(
+        &self,
+        dst: Dst,
+        y: &Self::Storage<Src, E>,
+        x: Option<&Self::Storage<Src, E>>,
+        dx: E,
+    ) -> Result<Self::Storage<Dst, E>, Self::Err> {
+        if !self.dev.has_func(Self::MOD, Self::FNS[0]) {
+            self.dev.load_ptx(PTX_SRC.into(), Self::MOD, Self::FNS)?;
+        }
+
+        let (dims, y_strides) = reduced_last::<Src, Ax>(&y.shape, y.strides);
+        let numel = dst.num_elements();
+        let chunk_len = <Src as HasAxes<Ax>>::size(&y.shape);
+        let dims: CudaSlice<usize> = self.dev.take_async(dims)?;
+        let y_strides: CudaSlice<usize> = self.dev.take_async(y_strides)?;
+        let mut storage = unsafe { self.dev.alloc_async::<E>(numel) }?;
+
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        match x {
+            Some(x) => {
+                let (_, x_strides) = reduced_last::<Src, Ax>(&x.shape, x.strides);
+                let x_strides: CudaSlice<usize> = self.dev.take_async(x_strides)?;
+                let fwd_fn = self.dev.get_func(Self::MOD, Self::FNS[2]).unwrap();
+                let params = (
+                    numel,           // const size_t numel,
+                    chunk_len,       // const size_t chunk_len,
+                    Src::NUM_DIMS,   // const size_t num_dims,
+                    &dims,           // const size_t *dims,
+                    &y_strides,      // const size_t *y_strides,
+                    &x_strides,      // const size_t *x_strides,
+                    y.data.as_ref(), // const T *y,
+                    x.data.as_ref(), // const T *x,
+                    &mut storage,    // T *out
+                );
+                unsafe { fwd_fn.launch_async(cfg, params) }?;
+            }
+            None => {
+                let fwd_fn = self.dev.get_func(Self::MOD, Self::FNS[0]).unwrap();
+                let params = (
+                    numel,           // const size_t numel,
+                    chunk_len,       // const size_t chunk_len,
+                    Src::NUM_DIMS,   // const size_t num_dims,
+                    &dims,           // const size_t *dims,
+                    &y_strides,      // const size_t *y_strides,
+                    dx,              // const T dx,
+                    y.data.as_ref(), // const T *y,
+                    &mut storage,    // T *out
+                );
+                unsafe { fwd_fn.launch_async(cfg, params) }?;
+            }
+        }
+        Ok(CudaArray {
+            data: Arc::new(storage),
+            shape: dst,
+            strides: dst.strides(),
+        })
+    }
+
+    fn backward<Src: Shape + ReduceShapeTo<Dst, Ax>, Dst: Shape, Ax: Axes>(
+        &self,
+        y: &Self::Storage<Src, E>,
+        grad_y: &mut Self::Storage<Src, E>,
+        x: Option<(&Self::Storage<Src, E>, &mut Self::Storage<Src, E>)>,
+        dx: E,
+        grad_out: &Self::Storage<Dst, E>,
+    ) -> Result<(), Self::Err> {
+        let (dims, y_strides) = reduced_last::<Src, Ax>(&y.shape, y.strides);
+        let (_, gy_strides) = reduced_last::<Src, Ax>(&grad_y.shape, grad_y.strides);
+        let numel = grad_out.shape.num_elements();
+        let chunk_len = <Src as HasAxes<Ax>>::size(&y.shape);
+        let dims: CudaSlice<usize> = self.dev.take_async(dims)?;
+
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        match x {
+            Some((x, grad_x)) => {
+                let (_, x_strides) = reduced_last::<Src, Ax>(&x.shape, x.strides);
+                let (_, gx_strides) = reduced_last::<Src, Ax>(&grad_x.shape, grad_x.strides);
+                let strides = [y_strides, gy_strides, x_strides, gx_strides].concat();
+                let strides: CudaSlice<usize> = self.dev.take_async(strides)?;
+                let bwd_fn = self.dev.get_func(Self::MOD, Self::FNS[3]).unwrap();
+                let params = (
+                    numel,                           // const size_t numel,
+                    chunk_len,                       // const size_t chunk_len,
+                    Src::NUM_DIMS,                   // const size_t num_dims,
+                    &dims,                           // const size_t *dims,
+                    &strides,                        // const size_t *strides,
+                    y.data.as_ref(),                 // const T *y,
+                    Arc::make_mut(&mut grad_y.data), // T *grad_y,
+                    x.data.as_ref(),                 // const T *x,
+                    Arc::make_mut(&mut grad_x.data), // T *grad_x,
+                    grad_out.data.as_ref(),          // const T *grad_out
+                );
+                unsafe { bwd_fn.launch_async(cfg, params) }?;
+            }
+            None => {
+                let gy_strides: CudaSlice<usize> = self.dev.take_async(gy_strides)?;
+                let bwd_fn = self.dev.get_func(Self::MOD, Self::FNS[1]).unwrap();
+                let params = (
+                    numel,                           // const size_t numel,
+                    chunk_len,                       // const size_t chunk_len,
+                    Src::NUM_DIMS,                   // const size_t num_dims,
+                    &dims,                           // const size_t *dims,
+                    &gy_strides,                     // const size_t *grad_y_strides,
+                    dx,                              // const T dx,
+                    Arc::make_mut(&mut grad_y.data), // T *grad_y,
+                    grad_out.data.as_ref(),          // const T *grad_out
+                );
+                unsafe { bwd_fn.launch_async(cfg, params) }?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,193 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{gradients::*, shapes::*, tensor::*};
+
+pub trait TrapzKernel<E: Dtype>: DeviceStorage {
+    /// `x` are the sample points. If `x` is `None` the samples are spaced `dx` apart.
+    fn forward<Src: Shape + ReduceShapeTo<Dst, Ax>, Dst: Shape, Ax: Axes>(
+        &self,
+        dst: Dst,
+        y: &Self::Storage<Src, E>,
+        x: Option<&Self::Storage<Src, E>>,
+        dx: E,
+    ) -> Result<Self::Storage<Dst, E>, Self::Err>;
+
+    #[allow(clippy::type_complexity)]
+    fn backward<Src: Shape + ReduceShapeTo<Dst, Ax>, Dst: Shape, Ax: Axes>(
+        &self,
+        y: &Self::Storage<Src, E>,
+        grad_y: &mut Self::Storage<Src, E>,
+        x: Option<(&Self::Storage<Src, E>, &mut Self::Storage<Src, E>)>,
+        dx: E,
+        grad_out: &Self::Storage<Dst, E>,
+    ) -> Result<(), Self::Err>;
+}
+
+/// Integrates `y` along `Ax` with the trapezoidal rule. The sample points are either
+/// spaced a scalar `dx` apart, or are given by a tensor `x` of the same shape as `y`.
+/// Gradients flow into both `y` and `x`.
+///
+/// Like the other reductions, the output shape and/or the axis can be specified, e.g.
+/// `trapz::<Rank1<2>, _>(dx)` or `trapz::<_, Axis<1>>(dx)`.
+///
+/// **Pytorch equivalent**: `torch.trapezoid(y, dx=dx, dim=Ax)` or `torch.trapezoid(y, x, dim=Ax)`
+///
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let y = dev.tensor([[1.0, 2.0, 3.0], [0.0, 4.0, 0.0]]);
+/// let r = y.clone().trapz::<_, Axis<1>>(0.5);
+/// assert_eq!(r.array(), [2.0, 2.0]);
+///
+/// let x = dev.tensor([[0.0, 1.0, 3.0], [0.0, 1.0, 2.0]]);
+/// let r = y.trapz::<_, Axis<1>>(x);
+/// assert_eq!(r.array(), [6.5, 4.0]);
+/// ```
+pub fn trapz<Dst: Shape, Ax: Axes<Array = [isize; 1]>, Y: TryTrapz<X>, X>(
+    y: Y,
+    x: X,
+) -> Y::WithShape<Dst>
+where
+    Y::Shape: ReduceShapeTo<Dst, Ax>,
+{
+    y.trapz::<Dst, Ax>(x)
+}
+
+/// Trapezoidal integration. See [trapz].
+pub trait TryTrapz<X>: HasErr + HasShape {
+    /// See [trapz]
+    fn trapz<Dst: Shape, Ax: Axes<Array = [isize; 1]>>(self, x: X) -> Self::WithShape<Dst>
+    where
+        Self::Shape: ReduceShapeTo<Dst, Ax>,
+    {
+        self.try_trapz(x).unwrap()
+    }
+
+    /// Fallible version of [TryTrapz::trapz]
+    fn try_trapz<Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        self,
+        x: X,
+    ) -> Result<Self::WithShape<Dst>, Self::Err>
+    where
+        Self::Shape: ReduceShapeTo<Dst, Ax>;
+}
+
+impl<S: Shape, E: Dtype, D: TrapzKernel<E>, T: Tape<D>> TryTrapz<E> for Tensor<S, E, D, T> {
+    fn try_trapz<Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        self,
+        dx: E,
+    ) -> Result<Self::WithShape<Dst>, Self::Err>
+    where
+        Self::Shape: ReduceShapeTo<Dst, Ax>,
+    {
+        let dst: Dst = self.shape().reduced();
+        let (y, mut tape) = self.split_tape();
+        let out = y
+            .device
+            .upgrade(y.device.forward(dst, &y.storage, None, dx)?);
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&y)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_y, grad_out) = grads.mut_and_ref(&y, &phantom_out);
+            y.device.backward(&y.storage, grad_y, None, dx, grad_out)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+impl<S: Shape, E: Dtype, D: TrapzKernel<E>, T, R> TryTrapz<Tensor<S, E, D, R>>
+    for Tensor<S, E, D, T>
+where
+    T: Tape<D> + Merge<R>,
+    R: Tape<D>,
+{
+    fn try_trapz<Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        self,
+        x: Tensor<S, E, D, R>,
+    ) -> Result<Self::WithShape<Dst>, Self::Err>
+    where
+        Self::Shape: ReduceShapeTo<Dst, Ax>,
+    {
+        assert_eq!(self.shape(), x.shape());
+        let dst: Dst = self.shape().reduced();
+        let (y, tape) = self.split_tape();
+        let (x, x_tape) = x.split_tape();
+        let mut tape = tape.merge(x_tape);
+        let storage = y
+            .device
+            .forward(dst, &y.storage, Some(&x.storage), Default::default())?;
+        let out = y.device.upgrade(storage);
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&y)?;
+        tape.try_alloc_grad(&x)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_y, grad_x, grad_out) = grads.muts_and_ref(&y, &x, &phantom_out);
+            y.device.backward(
+                &y.storage,
+                grad_y,
+                Some((&x.storage, grad_x)),
+                Default::default(),
+                grad_out,
+            )
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_trapz_dx() {
+        let dev: TestDevice = Default::default();
+        let y: Tensor<_, TestDtype, _> = dev.tensor([[1.0, 2.0, 3.0], [0.0, 4.0, 0.0]]);
+        let r = y.trace().trapz::<_, Axis<1>>(0.5);
+        assert_eq!(r.array(), [2.0, 2.0]);
+        let g = r.square().sum().backward();
+        assert_eq!(g.get(&y).array(), [[1.0, 2.0, 1.0], [1.0, 2.0, 1.0]]);
+
+        let r = y.trace().trapz::<_, Axis<0>>(2.0);
+        assert_eq!(r.array(), [1.0, 6.0, 3.0]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&y).array(), [[1.0; 3]; 2]);
+    }
+
+    #[test]
+    fn test_trapz_x() {
+        let dev: TestDevice = Default::default();
+        let y: Tensor<_, TestDtype, _> = dev.tensor([[1.0, 2.0, 3.0], [0.0, 4.0, 0.0]]);
+        let x: Tensor<_, TestDtype, _> = dev.tensor([[0.0, 1.0, 3.0], [0.0, 1.0, 2.0]]);
+        let r = y.trace().trapz::<_, Axis<1>>(x.trace());
+        assert_eq!(r.array(), [6.5, 4.0]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&y).array(), [[0.5, 1.5, 1.0], [0.5, 1.0, 0.5]]);
+        assert_eq!(g.get(&x).array(), [[-1.5, -1.0, 2.5], [-2.0, 0.0, 2.0]]);
+    }
+
+    #[test]
+    fn test_trapz_matches_dx_and_x() {
+        let dev: TestDevice = Default::default();
+        let y: Tensor<Rank3<2, 5, 3>, TestDtype, _> = dev.sample_normal();
+        let x: Tensor<Rank1<5>, TestDtype, _> = dev.tensor([0.0, 0.25, 0.5, 0.75, 1.0]);
+        let r = y.trace().trapz::<_, Axis<1>>(0.25);
+        let r2 = y.trace().trapz::<_, Axis<1>>(x.broadcast());
+        assert_close(&r.array(), &r2.array());
+        let g = r.exp().sum().backward();
+        let g2 = r2.exp().sum().backward();
+        assert_close(&g.get(&y).array(), &g2.get(&y).array());
+    }
+
+    #[test]
+    fn test_trapz_single_sample() {
+        let dev: TestDevice = Default::default();
+        let y: Tensor<Rank2<1, 2>, TestDtype, _> = dev.tensor([[1.0, 2.0]]);
+        assert_eq!(y.trapz::<_, Axis<0>>(1.0).array(), [0.0, 0.0]);
+    }
+}
@@ -0,0 +1,177 @@
+#include "cuda_utils.cuh"
+
+// Each thread integrates the `chunk_len` elements of one output element. The
+// integrated axis is the last dim, so they are the logical indices
+// [i * chunk_len, (i + 1) * chunk_len).
+template<typename T>
+__device__ void trapz_dx_fwd(
+    const size_t numel,
+    const size_t chunk_len,
+    const size_t num_dims,
+    const size_t *dims,
+    const size_t *y_strides,
+    const T dx,
+    const T *y,
+    T *out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    T tmp = 0.0;
+    for (size_t k = 1; k < chunk_len; k++) {
+        size_t j = i * chunk_len + k;
+        tmp += y[get_strided_index(j, num_dims, dims, y_strides)]
+            + y[get_strided_index(j - 1, num_dims, dims, y_strides)];
+    }
+    out[i] = tmp * dx / 2.0;
+}
+
+template<typename T>
+__device__ void trapz_dx_bwd(
+    const size_t numel,
+    const size_t chunk_len,
+    const size_t num_dims,
+    const size_t *dims,
+    const size_t *grad_y_strides,
+    const T dx,
+    T *grad_y,
+    const T *grad_out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    T g = grad_out[i] * dx / 2.0;
+    for (size_t k = 1; k < chunk_len; k++) {
+        size_t j = i * chunk_len + k;
+        // broadcasted inputs can share a gradient with other output elements
+        atomicAdd(grad_y + get_strided_index(j, num_dims, dims, grad_y_strides), g);
+        atomicAdd(grad_y + get_strided_index(j - 1, num_dims, dims, grad_y_strides), g);
+    }
+}
+
+template<typename T>
+__device__ void trapz_x_fwd(
+    const size_t numel,
+    const size_t chunk_len,
+    const size_t num_dims,
+    const size_t *dims,
+    const size_t *y_strides,
+    const size_t *x_strides,
+    const T *y,
+    const T *x,
+    T *out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    T tmp = 0.0;
+    for (size_t k = 1; k < chunk_len; k++) {
+        size_t j = i * chunk_len + k;
+        T h = x[get_strided_index(j, num_dims, dims, x_strides)]
+            - x[get_strided_index(j - 1, num_dims, dims, x_strides)];
+        T s = y[get_strided_index(j, num_dims, dims, y_strides)]
+            + y[get_strided_index(j - 1, num_dims, dims, y_strides)];
+        tmp += h * s;
+    }
+    out[i] = tmp / 2.0;
+}
+
+template<typename T>
+__device__ void trapz_x_bwd(
+    const size_t numel,
+    const size_t chunk_len,
+    const size_t num_dims,
+    const size_t *dims,
+    const size_t *strides,
+    const T *y,
+    T *grad_y,
+    const T *x,
+    T *grad_x,
+    const T *grad_out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    // the strides of y, grad_y, x and grad_x, one after another
+    const size_t *y_strides = strides;
+    const size_t *grad_y_strides = strides + num_dims;
+    const size_t *x_strides = strides + 2 * num_dims;
+    const size_t *grad_x_strides = strides + 3 * num_dims;
+
+    T g = grad_out[i] / 2.0;
+    for (size_t k = 1; k < chunk_len; k++) {
+        size_t j = i * chunk_len + k;
+        T h = x[get_strided_index(j, num_dims, dims, x_strides)]
+            - x[get_strided_index(j - 1, num_dims, dims, x_strides)];
+        T s = y[get_strided_index(j, num_dims, dims, y_strides)]
+            + y[get_strided_index(j - 1, num_dims, dims, y_strides)];
+        atomicAdd(grad_y + get_strided_index(j, num_dims, dims, grad_y_strides), g * h);
+        atomicAdd(grad_y + get_strided_index(j - 1, num_dims, dims, grad_y_strides), g * h);
+        atomicAdd(grad_x + get_strided_index(j, num_dims, dims, grad_x_strides), g * s);
+        atomicAdd(grad_x + get_strided_index(j - 1, num_dims, dims, grad_x_strides), -g * s);
+    }
+}
+
+#define TRAPZ(TYPENAME, DX_FWD, DX_BWD, X_FWD, X_BWD) \
+extern "C" __global__ void DX_FWD( \
+    const size_t numel, \
+    const size_t chunk_len, \
+    const size_t num_dims, \
+    const size_t *dims, \
+    const size_t *y_strides, \
+    const TYPENAME dx, \
+    const TYPENAME *y, \
+    TYPENAME *out \
+) { \
+    trapz_dx_fwd(numel, chunk_len, num_dims, dims, y_strides, dx, y, out); \
+} \
+extern "C" __global__ void DX_BWD( \
+    const size_t numel, \
+    const size_t chunk_len, \
+    const size_t num_dims, \
+    const size_t *dims, \
+    const size_t *grad_y_strides, \
+    const TYPENAME dx, \
+    TYPENAME *grad_y, \
+    const TYPENAME *grad_out \
+) { \
+    trapz_dx_bwd(numel, chunk_len, num_dims, dims, grad_y_strides, dx, grad_y, grad_out); \
+} \
+extern "C" __global__ void X_FWD( \
+    const size_t numel, \
+    const size_t chunk_len, \
+    const size_t num_dims, \
+    const size_t *dims, \
+    const size_t *y_strides, \
+    const size_t *x_strides, \
+    const TYPENAME *y, \
+    const TYPENAME *x, \
+    TYPENAME *out \
+) { \
+    trapz_x_fwd(numel, chunk_len, num_dims, dims, y_strides, x_strides, y, x, out); \
+} \
+extern "C" __global__ void X_BWD( \
+    const size_t numel, \
+    const size_t chunk_len, \
+    const size_t num_dims, \
+    const size_t *dims, \
+    const size_t *strides, \
+    const TYPENAME *y, \
+    TYPENAME *grad_y, \
+    const TYPENAME *x, \
+    TYPENAME *grad_x, \
+    const TYPENAME *grad_out \
+) { \
+    trapz_x_bwd(numel, chunk_len, num_dims, dims, strides, y, grad_y, x, grad_x, grad_out); \
+}
+
+TRAPZ(float, trapz_dx_fwd_f32, trapz_dx_bwd_f32, trapz_x_fwd_f32, trapz_x_bwd_f32);
+TRAPZ(double, trapz_dx_fwd_f64, trapz_dx_bwd_f64, trapz_x_fwd_f64, trapz_x_bwd_f64);
@@ -0,0 +1,36 @@
+use crate::shapes::{Dtype, Shape};
+use crate::tensor::cpu::{Cpu, LendingIterator, StridedArray};
+
+use super::{TriangleKernel, TriangleOp};
+
+impl<E: Dtype> TriangleKernel<E> for Cpu {
+    fn forward<S: Shape>(
+        &self,
+        op: TriangleOp,
+        inp: &Self::Storage<S, E>,
+    ) -> Result<Self::Storage<S, E>, Self::Err> {
+        let mut out = StridedArray::new(inp.shape)?;
+        let mut out_iter = out.iter_mut_with_index();
+        while let Some((o, i)) = out_iter.next() {
+            if op.keep(i[S::NUM_DIMS - 2], i[S::NUM_DIMS - 1]) {
+                *o = inp[i];
+            }
+        }
+        Ok(out)
+    }
+
+    fn backward<S: Shape>(
+        &self,
+        op: TriangleOp,
+        grad_inp: &mut Self::Storage<S, E>,
+        grad_out: &Self::Storage<S, E>,
+    ) -> Result<(), Self::Err> {
+        let mut out_iter = grad_out.iter_with_index();
+        while let Some((o, i)) = out_iter.next() {
+            if op.keep(i[S::NUM_DIMS - 2], i[S::NUM_DIMS - 1]) {
+                grad_inp[i] += *o;
+            }
+        }
+        Ok(())
+    }
+}
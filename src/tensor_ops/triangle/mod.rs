@@ -0,0 +1,220 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{gradients::Tape, shapes::*, tensor::*};
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct TriangleOp {
+    /// The diagonal to keep elements relative to. `0` is the main diagonal, positive values are
+    /// above it, and negative values below it.
+    pub offset: isize,
+    /// Whether to keep the upper triangle (`triu`) or the lower triangle (`tril`)
+    pub upper: bool,
+}
+
+impl TriangleOp {
+    /// Whether the element at `row` and `col` of the last two dims is kept
+    #[inline(always)]
+    pub(crate) fn keep(&self, row: usize, col: usize) -> bool {
+        let diff = col as isize - row as isize;
+        if self.upper {
+            diff >= self.offset
+        } else {
+            diff <= self.offset
+        }
+    }
+}
+
+pub trait TriangleKernel<E: Dtype>: DeviceStorage {
+    fn forward<S: Shape>(
+        &self,
+        op: TriangleOp,
+        inp: &Self::Storage<S, E>,
+    ) -> Result<Self::Storage<S, E>, Self::Err>;
+    fn backward<S: Shape>(
+        &self,
+        op: TriangleOp,
+        grad_inp: &mut Self::Storage<S, E>,
+        grad_out: &Self::Storage<S, E>,
+    ) -> Result<(), Self::Err>;
+}
+
+/// Zeroes out all elements above the `offset`th diagonal of the last two dims.
+///
+/// `offset` of `0` is the main diagonal, positive values move the diagonal to the right,
+/// and negative values to the left.
+///
+/// **Pytorch equivalent**: `t.tril(offset)`
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+/// let r = t.clone().tril(0);
+/// assert_eq!(r.array(), [[1.0, 0.0, 0.0], [4.0, 5.0, 0.0], [7.0, 8.0, 9.0]]);
+/// let r = t.tril(-1);
+/// assert_eq!(r.array(), [[0.0, 0.0, 0.0], [4.0, 0.0, 0.0], [7.0, 8.0, 0.0]]);
+/// ```
+pub fn tril<S: Shape, E: Dtype, D: TriangleKernel<E>, T: Tape<D>>(
+    t: Tensor<S, E, D, T>,
+    offset: isize,
+) -> Tensor<S, E, D, T> {
+    t.tril(offset)
+}
+
+/// Zeroes out all elements below the `offset`th diagonal of the last two dims.
+///
+/// `offset` of `0` is the main diagonal, positive values move the diagonal to the right,
+/// and negative values to the left.
+///
+/// **Pytorch equivalent**: `t.triu(offset)`
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+/// let r = t.clone().triu(0);
+/// assert_eq!(r.array(), [[1.0, 2.0, 3.0], [0.0, 5.0, 6.0], [0.0, 0.0, 9.0]]);
+/// let r = t.triu(1);
+/// assert_eq!(r.array(), [[0.0, 2.0, 3.0], [0.0, 0.0, 6.0], [0.0, 0.0, 0.0]]);
+/// ```
+pub fn triu<S: Shape, E: Dtype, D: TriangleKernel<E>, T: Tape<D>>(
+    t: Tensor<S, E, D, T>,
+    offset: isize,
+) -> Tensor<S, E, D, T> {
+    t.triu(offset)
+}
+
+impl<S: Shape, E: Dtype, D: TriangleKernel<E>, T: Tape<D>> Tensor<S, E, D, T> {
+    /// See [tril]
+    pub fn tril(self, offset: isize) -> Self {
+        self.try_tril(offset).unwrap()
+    }
+    /// See [tril]
+    pub fn try_tril(self, offset: isize) -> Result<Self, D::Err> {
+        try_triangle_op(
+            TriangleOp {
+                offset,
+                upper: false,
+            },
+            self,
+        )
+    }
+    /// See [triu]
+    pub fn triu(self, offset: isize) -> Self {
+        self.try_triu(offset).unwrap()
+    }
+    /// See [triu]
+    pub fn try_triu(self, offset: isize) -> Result<Self, D::Err> {
+        try_triangle_op(
+            TriangleOp {
+                offset,
+                upper: true,
+            },
+            self,
+        )
+    }
+}
+
+fn try_triangle_op<S: Shape, E: Dtype, D: TriangleKernel<E>, T: Tape<D>>(
+    op: TriangleOp,
+    t: Tensor<S, E, D, T>,
+) -> Result<Tensor<S, E, D, T>, D::Err> {
+    assert!(
+        S::NUM_DIMS >= 2,
+        "tril/triu require a tensor with at least 2 dimensions"
+    );
+    let (inp, mut tape) = t.split_tape();
+    let out = inp.device.upgrade(inp.device.forward(op, &inp.storage)?);
+    let phantom_out = out.clone();
+    tape.try_alloc_grad(&inp)?;
+    tape.try_alloc_grad(&out)?;
+    tape.add_backward_op(move |grads| {
+        let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+        inp.device.backward(op, grad_inp, grad_out)
+    });
+    Ok(out.put_tape(tape))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{shapes::*, tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_tril_offsets() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([[1.0, 2.0, 3.0, 4.0], [5.0, 6.0, 7.0, 8.0]]);
+        assert_eq!(
+            t.clone().tril(0).array(),
+            [[1.0, 0.0, 0.0, 0.0], [5.0, 6.0, 0.0, 0.0]]
+        );
+        assert_eq!(
+            t.clone().tril(2).array(),
+            [[1.0, 2.0, 3.0, 0.0], [5.0, 6.0, 7.0, 8.0]]
+        );
+        assert_eq!(
+            t.clone().tril(-1).array(),
+            [[0.0, 0.0, 0.0, 0.0], [5.0, 0.0, 0.0, 0.0]]
+        );
+        assert_eq!(t.clone().tril(-2).array(), [[0.0; 4]; 2]);
+    }
+
+    #[test]
+    fn test_triu_offsets() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([[1.0, 2.0, 3.0, 4.0], [5.0, 6.0, 7.0, 8.0]]);
+        assert_eq!(
+            t.clone().triu(0).array(),
+            [[1.0, 2.0, 3.0, 4.0], [0.0, 6.0, 7.0, 8.0]]
+        );
+        assert_eq!(
+            t.clone().triu(2).array(),
+            [[0.0, 0.0, 3.0, 4.0], [0.0, 0.0, 0.0, 8.0]]
+        );
+        assert_eq!(
+            t.clone().triu(-1).array(),
+            [[1.0, 2.0, 3.0, 4.0], [5.0, 6.0, 7.0, 8.0]]
+        );
+        assert_eq!(t.clone().triu(4).array(), [[0.0; 4]; 2]);
+    }
+
+    #[test]
+    fn test_tril_3d_backward() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank3<2, 3, 3>, TestDtype, _> = dev.sample_normal();
+        let r = t.trace().tril(0);
+        let t_arr = t.array();
+        let r_arr = r.array();
+        for b in 0..2 {
+            for i in 0..3 {
+                for j in 0..3 {
+                    let expected = if j <= i { t_arr[b][i][j] } else { 0.0 };
+                    assert_eq!(r_arr[b][i][j], expected);
+                }
+            }
+        }
+        let g = r.sum().backward();
+        assert_eq!(
+            g.get(&t).array(),
+            [[[1.0, 0.0, 0.0], [1.0, 1.0, 0.0], [1.0, 1.0, 1.0]]; 2]
+        );
+    }
+
+    #[test]
+    fn test_triu_broadcasted_backward() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([1.0, 2.0, 3.0]);
+        let r = t.trace().broadcast::<Rank2<3, 3>, Axis<0>>().triu(0);
+        assert_eq!(
+            r.array(),
+            [[1.0, 2.0, 3.0], [0.0, 2.0, 3.0], [0.0, 0.0, 3.0]]
+        );
+        let g = r.sum().backward();
+        assert_eq!(g.get(&t).array(), [1.0, 2.0, 3.0]);
+    }
+}
@@ -0,0 +1,84 @@
+#include "cuda_utils.cuh"
+
+struct TriangleOp {
+    ptrdiff_t offset;
+    bool upper;
+};
+
+__device__ bool keep(const TriangleOp op, const size_t row, const size_t col) {
+    const ptrdiff_t diff = static_cast<ptrdiff_t>(col) - static_cast<ptrdiff_t>(row);
+    return op.upper ? diff >= op.offset : diff <= op.offset;
+}
+
+template<typename T>
+__device__ void triangle_fwd(
+    const TriangleOp op,
+    const size_t numel,
+    const size_t num_dims,
+    const size_t *dims,
+    const size_t *inp_strides,
+    const T *inp,
+    T *out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    const size_t col = i % dims[num_dims - 1];
+    const size_t row = (i / dims[num_dims - 1]) % dims[num_dims - 2];
+    if (keep(op, row, col)) {
+        out[i] = inp[get_strided_index(i, num_dims, dims, inp_strides)];
+    } else {
+        out[i] = 0.0;
+    }
+}
+
+template<typename T>
+__device__ void triangle_bwd(
+    const TriangleOp op,
+    const size_t numel,
+    const size_t num_dims,
+    const size_t *dims,
+    const size_t *inp_strides,
+    T *grad_inp,
+    const T *grad_out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    const size_t col = i % dims[num_dims - 1];
+    const size_t row = (i / dims[num_dims - 1]) % dims[num_dims - 2];
+    if (keep(op, row, col)) {
+        atomicAdd(grad_inp + get_strided_index(i, num_dims, dims, inp_strides), grad_out[i]);
+    }
+}
+
+#define TRIANGLE_OP(TYPENAME, FWD, BWD) \
+extern "C" __global__ void FWD( \
+    const TriangleOp op, \
+    const size_t numel, \
+    const size_t num_dims, \
+    const size_t *dims, \
+    const size_t *inp_strides, \
+    const TYPENAME *inp, \
+    TYPENAME *out \
+) { \
+    triangle_fwd(op, numel, num_dims, dims, inp_strides, inp, out); \
+} \
+extern "C" __global__ void BWD( \
+    const TriangleOp op, \
+    const size_t numel, \
+    const size_t num_dims, \
+    const size_t *dims, \
+    const size_t *inp_strides, \
+    TYPENAME *grad_inp, \
+    const TYPENAME *grad_out \
+) { \
+    triangle_bwd(op, numel, num_dims, dims, inp_strides, grad_inp, grad_out); \
+}
+
+TRIANGLE_OP(float, triangle_fwd_f32, triangle_bwd_f32);
+TRIANGLE_OP(double, triangle_fwd_f64, triangle_bwd_f64);
@@ -0,0 +1,78 @@
+use crate::{shapes::*, tensor::cpu::Cpu};
+
+use super::UnfoldOp;
+
+use std::sync::Arc;
+
+fn make_4d<S: Shape>(strides: S::Concrete) -> [usize; 4] {
+    match S::NUM_DIMS {
+        3 => [0, strides[0], strides[1], strides[2]],
+        4 => [strides[0], strides[1], strides[2], strides[3]],
+        _ => unreachable!("Only implemented for 3d & 4d arrays"),
+    }
+}
+
+impl UnfoldOp {
+    /// Calls `f` with the index of every patch element in a contiguous patches buffer,
+    /// and the strided index of the image element it comes from. Padded elements
+    /// are skipped.
+    #[inline]
+    fn for_each_patch(&self, strides: [usize; 4], mut f: impl FnMut(usize, usize)) {
+        let mut i = 0;
+        for b in 0..self.batch {
+            for c in 0..self.chan {
+                for k1 in 0..self.kernel {
+                    for k2 in 0..self.kernel {
+                        for oh in 0..self.h_out {
+                            for ow in 0..self.w_out {
+                                let y = (oh * self.stride + k1).wrapping_sub(self.padding);
+                                let x = (ow * self.stride + k2).wrapping_sub(self.padding);
+                                if y < self.h_in && x < self.w_in {
+                                    f(
+                                        i,
+                                        b * strides[0]
+                                            + c * strides[1]
+                                            + y * strides[2]
+                                            + x * strides[3],
+                                    );
+                                }
+                                i += 1;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<E: Dtype> super::UnfoldKernel<E> for Cpu {
+    fn forward<I: Shape, P: Shape>(
+        &self,
+        op: UnfoldOp,
+        img: &Self::Storage<I, E>,
+        patches: &mut Self::Storage<P, E>,
+    ) -> Result<(), Self::Err> {
+        let img_buf = img.data.as_ref();
+        let patches_buf = Arc::make_mut(&mut patches.data);
+        op.for_each_patch(make_4d::<I>(img.strides), |i_patch, i_img| {
+            patches_buf[i_patch] = img_buf[i_img];
+        });
+        Ok(())
+    }
+
+    fn backward<I: Shape, P: Shape>(
+        &self,
+        op: UnfoldOp,
+        grad_img: &mut Self::Storage<I, E>,
+        grad_patches: &Self::Storage<P, E>,
+    ) -> Result<(), Self::Err> {
+        let strides = make_4d::<I>(grad_img.strides);
+        let grad_img_buf = Arc::make_mut(&mut grad_img.data);
+        let grad_patches_buf = grad_patches.data.as_ref();
+        op.for_each_patch(strides, |i_patch, i_img| {
+            grad_img_buf[i_img] += grad_patches_buf[i_patch];
+        });
+        Ok(())
+    }
+}
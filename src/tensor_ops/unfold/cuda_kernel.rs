@@ -0,0 +1,83 @@
+use crate::{shapes::*, tensor::cuda::Cuda};
+
+use cudarc::driver::{AsKernelParam, LaunchAsync, LaunchConfig};
+
+use std::sync::Arc;
+
+unsafe impl AsKernelParam for super::UnfoldOp {}
+
+const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/unfold.ptx"));
+
+trait HasCudaKernel<E> {
+    const MOD: &'static str;
+    const FNS: &'static [&'static str];
+}
+
+impl HasCudaKernel<f32> for Cuda {
+    const MOD: &'static str = "unfold_f32";
+    const FNS: &'static [&'static str] = &["unfold_fwd_f32", "unfold_bwd_f32"];
+}
+
+impl HasCudaKernel<f64> for Cuda {
+    const MOD: &'static str = "unfold_f64";
+    const FNS: &'static [&'static str] = &["unfold_fwd_f64", "unfold_bwd_f64"];
+}
+
+fn make_4d<S: Shape>(strides: S::Concrete) -> [usize; 4] {
+    match S::NUM_DIMS {
+        3 => [0, strides[0], strides[1], strides[2]],
+        4 => [strides[0], strides[1], strides[2], strides[3]],
+        _ => unreachable!("Only implemented for 3d & 4d arrays"),
+    }
+}
+
+impl<E: Dtype + AsKernelParam> super::UnfoldKernel<E> for Cuda
+where
+    Self: HasCudaKernel<E>,
+{
+    fn forward<I: Shape, P: Shape>(
+        &self,
+        op: super::UnfoldOp,
+        img: &Self::Storage<I, E>,
+        patches: &mut Self::Storage<P, E>,
+    ) -> Result<(), Self::Err> {
+        if !self.dev.has_func(Self::MOD, Self::FNS[0]) {
+            self.dev.load_ptx(PTX_SRC.into(), Self::MOD, Self::FNS)?;
+        }
+
+        let img_strides = self.dev.take_async(make_4d::<I>(img.strides).into())?;
+        let fwd_fn = self.dev.get_func(Self::MOD, Self::FNS[0]).unwrap();
+        let cfg = LaunchConfig::for_num_elems(patches.data.len() as u32);
+        let params = (
+            op,                               // const UnfoldOp op,
+            img.data.as_ref(),                // const T *image,
+            &img_strides,                     // const size_t *strides,
+            Arc::make_mut(&mut patches.data), // T *patches
+        );
+        unsafe { fwd_fn.launch_async(cfg, params) }?;
+        Ok(())
+    }
+
+    fn backward<I: Shape, P: Shape>(
+        &self,
+        op: super::UnfoldOp,
+        grad_img: &mut Self::Storage<I, E>,
+        grad_patches: &Self::Storage<P, E>,
+    ) -> Result<(), Self::Err> {
+        if !self.dev.has_func(Self::MOD, Self::FNS[0]) {
+            self.dev.load_ptx(PTX_SRC.into(), Self::MOD, Self::FNS)?;
+        }
+
+        let img_strides = self.dev.take_async(make_4d::<I>(grad_img.strides).into())?;
+        let bwd_fn = self.dev.get_func(Self::MOD, Self::FNS[1]).unwrap();
+        let cfg = LaunchConfig::for_num_elems(grad_patches.data.len() as u32);
+        let params = (
+            op,                                // const UnfoldOp op,
+            Arc::make_mut(&mut grad_img.data), // T *grad_image,
+            &img_strides,                      // const size_t *strides,
+            grad_patches.data.as_ref(),        // const T *grad_patches
+        );
+        unsafe { bwd_fn.launch_async(cfg, params) }?;
+        Ok(())
+    }
+}
@@ -0,0 +1,256 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{gradients::Tape, shapes::*, tensor::*};
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub(super) struct UnfoldOp {
+    pub kernel: usize,
+    pub stride: usize,
+    pub padding: usize,
+    pub batch: usize,
+    pub chan: usize,
+    pub h_in: usize,
+    pub h_out: usize,
+    pub w_in: usize,
+    pub w_out: usize,
+}
+
+impl UnfoldOp {
+    pub(super) fn new(k: usize, s: usize, p: usize, [b, c, h_in, w_in]: [usize; 4]) -> Self {
+        assert!(k > 0, "kernel size must be positive");
+        assert!(s > 0, "stride must be positive");
+        assert!(
+            h_in + 2 * p >= k && w_in + 2 * p >= k,
+            "kernel is larger than the padded image"
+        );
+        Self {
+            kernel: k,
+            stride: s,
+            padding: p,
+            batch: b,
+            chan: c,
+            h_in,
+            h_out: (h_in + 2 * p - k) / s + 1,
+            w_in,
+            w_out: (w_in + 2 * p - k) / s + 1,
+        }
+    }
+
+    /// The shape of the patches of a single image, `(Chan * K * K, HeightOut * WidthOut)`.
+    pub(super) fn patches_shape(&self) -> (usize, usize) {
+        (
+            self.chan * self.kernel * self.kernel,
+            self.h_out * self.w_out,
+        )
+    }
+}
+
+pub(super) trait UnfoldKernel<E: Dtype>: DeviceStorage {
+    /// Copies the patches of `img` into `patches`.
+    fn forward<I: Shape, P: Shape>(
+        &self,
+        op: UnfoldOp,
+        img: &Self::Storage<I, E>,
+        patches: &mut Self::Storage<P, E>,
+    ) -> Result<(), Self::Err>;
+
+    /// Sums the patches in `grad_patches` back into `grad_img`.
+    fn backward<I: Shape, P: Shape>(
+        &self,
+        op: UnfoldOp,
+        grad_img: &mut Self::Storage<I, E>,
+        grad_patches: &Self::Storage<P, E>,
+    ) -> Result<(), Self::Err>;
+}
+
+/// Extract sliding local blocks (patches) from `(Chan, Height, Width)` or
+/// `(Batch, Chan, Height, Width)` images. This is also known as `im2col`.
+pub trait TryUnfold: HasErr {
+    type Output;
+
+    /// Unfolds `K x K` patches with stride `stride` and zero padding `padding`
+    /// into a `(Chan * K * K, L)` tensor, where `L` is the number of patches.
+    /// The rows are ordered by channel, then kernel row, then kernel column,
+    /// and the columns are ordered by patch row, then patch column.
+    ///
+    /// A convolution is a matmul of the flattened filters with the patches.
+    ///
+    /// **Pytorch equivalent** `torch.nn.functional.unfold(t, kernel, padding=padding, stride=stride)`.
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let img = dev.tensor([[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]]);
+    /// let patches: Tensor<(usize, usize), f32, _> = img.unfold(2, 1, 0);
+    /// assert_eq!(patches.shape(), &(4, 4));
+    /// assert_eq!(
+    ///     patches.as_vec(),
+    ///     [1.0, 2.0, 4.0, 5.0, 2.0, 3.0, 5.0, 6.0, 4.0, 5.0, 7.0, 8.0, 5.0, 6.0, 8.0, 9.0]
+    /// );
+    /// ```
+    fn unfold(self, kernel: usize, stride: usize, padding: usize) -> Self::Output {
+        self.try_unfold(kernel, stride, padding).unwrap()
+    }
+
+    /// Fallible version of [TryUnfold::unfold]
+    fn try_unfold(
+        self,
+        kernel: usize,
+        stride: usize,
+        padding: usize,
+    ) -> Result<Self::Output, Self::Err>;
+}
+
+impl<C: Dim, H: Dim, W: Dim, E: Dtype, D, T: Tape<D>> TryUnfold for Tensor<(C, H, W), E, D, T>
+where
+    D: UnfoldKernel<E> + ZerosTensor<E>,
+{
+    type Output = Tensor<(usize, usize), E, D, T>;
+
+    fn try_unfold(
+        self,
+        kernel: usize,
+        stride: usize,
+        padding: usize,
+    ) -> Result<Self::Output, Self::Err> {
+        let (c, h, w) = *self.shape();
+        let op = UnfoldOp::new(kernel, stride, padding, [1, c.size(), h.size(), w.size()]);
+        let (img, mut tape) = self.split_tape();
+        let mut out = img.device.try_zeros_like(&op.patches_shape())?;
+        img.device.forward(op, &img.storage, &mut out.storage)?;
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&img)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_img, grad_out) = grads.mut_and_ref(&img, &phantom_out);
+            img.device.backward(op, grad_img, grad_out)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+impl<B: Dim, C: Dim, H: Dim, W: Dim, E: Dtype, D, T: Tape<D>> TryUnfold
+    for Tensor<(B, C, H, W), E, D, T>
+where
+    D: UnfoldKernel<E> + ZerosTensor<E>,
+{
+    type Output = Tensor<(B, usize, usize), E, D, T>;
+
+    fn try_unfold(
+        self,
+        kernel: usize,
+        stride: usize,
+        padding: usize,
+    ) -> Result<Self::Output, Self::Err> {
+        let (b, c, h, w) = *self.shape();
+        let dims = [b.size(), c.size(), h.size(), w.size()];
+        let op = UnfoldOp::new(kernel, stride, padding, dims);
+        let (rows, cols) = op.patches_shape();
+        let (img, mut tape) = self.split_tape();
+        let mut out = img.device.try_zeros_like(&(b, rows, cols))?;
+        img.device.forward(op, &img.storage, &mut out.storage)?;
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&img)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_img, grad_out) = grads.mut_and_ref(&img, &phantom_out);
+            img.device.backward(op, grad_img, grad_out)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_unfold_3d() {
+        let dev: TestDevice = Default::default();
+        let img: Tensor<_, TestDtype, _> =
+            dev.tensor([[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]]);
+        let r = img.trace().unfold(2, 1, 0);
+        assert_eq!(r.shape(), &(4, 4));
+        #[rustfmt::skip]
+        assert_eq!(
+            r.as_vec(),
+            [
+                1.0, 2.0, 4.0, 5.0,
+                2.0, 3.0, 5.0, 6.0,
+                4.0, 5.0, 7.0, 8.0,
+                5.0, 6.0, 8.0, 9.0,
+            ]
+        );
+        let g = r.sum().backward();
+        assert_eq!(
+            g.get(&img).array(),
+            [[[1.0, 2.0, 1.0], [2.0, 4.0, 2.0], [1.0, 2.0, 1.0]]]
+        );
+    }
+
+    #[test]
+    fn test_unfold_stride_and_padding() {
+        let dev: TestDevice = Default::default();
+        let img: Tensor<_, TestDtype, _> =
+            dev.tensor([[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]]);
+        let r = img.trace().unfold(3, 2, 1);
+        assert_eq!(r.shape(), &(9, 4));
+        let v = r.as_vec();
+        assert_eq!(v[0..4], [0.0, 0.0, 0.0, 5.0]);
+        assert_eq!(v[16..20], [1.0, 3.0, 7.0, 9.0]);
+        assert_eq!(v[32..36], [5.0, 0.0, 0.0, 0.0]);
+        let g = (r * 2.0).sum().backward();
+        assert_eq!(
+            g.get(&img).array(),
+            [[[2.0, 4.0, 2.0], [4.0, 8.0, 4.0], [2.0, 4.0, 2.0]]]
+        );
+    }
+
+    #[test]
+    fn test_unfold_channels() {
+        let dev: TestDevice = Default::default();
+        let img: Tensor<_, TestDtype, _> = dev.tensor([[[1.0, 2.0]], [[3.0, 4.0]]]);
+        let r = img.unfold(1, 1, 0);
+        assert_eq!(r.shape(), &(2, 2));
+        assert_eq!(r.as_vec(), [1.0, 2.0, 3.0, 4.0]);
+    }
+
+    #[test]
+    fn test_unfold_4d_same_as_3d() {
+        let dev: TestDevice = Default::default();
+        let img: Tensor<Rank4<2, 3, 5, 4>, TestDtype, _> = dev.sample_normal();
+        let r = img.trace().unfold(3, 2, 1);
+        assert_eq!(r.shape(), &(Const, 27, 6));
+        let r0 = img.clone().select(dev.tensor(0)).unfold(3, 2, 1);
+        let r1 = img.clone().select(dev.tensor(1)).unfold(3, 2, 1);
+        assert_eq!(r.as_vec(), [r0.as_vec(), r1.as_vec()].concat());
+
+        let g = r.exp().sum().backward();
+        let g0 = img
+            .trace()
+            .select(dev.tensor(0))
+            .unfold(3, 2, 1)
+            .exp()
+            .sum()
+            .backward();
+        let g0 = g0.get(&img).array();
+        assert_close(&g.get(&img).array()[0], &g0[0]);
+    }
+
+    #[test]
+    fn test_unfold_permuted() {
+        let dev: TestDevice = Default::default();
+        let img: Tensor<_, TestDtype, _> = dev.tensor([[[1.0, 2.0], [3.0, 4.0]]]);
+        let r = img.trace().permute::<_, Axes3<0, 2, 1>>().unfold(1, 1, 0);
+        assert_eq!(r.as_vec(), [1.0, 3.0, 2.0, 4.0]);
+        let g = (r * dev.tensor_from_vec(std::vec![1.0, 2.0, 3.0, 4.0], (1, 4)))
+            .sum()
+            .backward();
+        assert_eq!(g.get(&img).array(), [[[1.0, 3.0], [2.0, 4.0]]]);
+    }
+}
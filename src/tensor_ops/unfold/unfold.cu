@@ -0,0 +1,115 @@
+#include "cuda_utils.cuh"
+
+struct UnfoldOp {
+    size_t kernel;
+    size_t stride;
+    size_t padding;
+    size_t batch;
+    size_t chan;
+    size_t h_in;
+    size_t h_out;
+    size_t w_in;
+    size_t w_out;
+};
+
+// Converts the index of a patch element into the strided index of the image
+// element it comes from, returning false if the patch element is padding.
+__device__ bool get_image_index(
+    const UnfoldOp op,
+    unsigned int idx,
+    const size_t *strides,
+    size_t *i_image
+) {
+    // patches shape is (B, C, K, K, h_out, w_out)
+    const size_t ow = idx % op.w_out;
+    idx /= op.w_out;
+    const size_t oh = idx % op.h_out;
+    idx /= op.h_out;
+    const size_t k2 = idx % op.kernel;
+    idx /= op.kernel;
+    const size_t k1 = idx % op.kernel;
+    idx /= op.kernel;
+    const size_t c = idx % op.chan;
+    idx /= op.chan;
+    const size_t b = idx % op.batch;
+
+    const size_t y_plus_p = oh * op.stride + k1;
+    if (y_plus_p < op.padding) {
+        return false;
+    }
+    const size_t y = y_plus_p - op.padding;
+    if (y >= op.h_in) {
+        return false;
+    }
+
+    const size_t x_plus_p = ow * op.stride + k2;
+    if (x_plus_p < op.padding) {
+        return false;
+    }
+    const size_t x = x_plus_p - op.padding;
+    if (x >= op.w_in) {
+        return false;
+    }
+
+    *i_image = b * strides[0] + c * strides[1] + y * strides[2] + x * strides[3];
+    return true;
+}
+
+template<typename T>
+__device__ void unfold_fwd(
+    const UnfoldOp op,
+    const T *image, // 4d (Batch, Channels, Height, Width)
+    const size_t *strides, // 4d image strides
+    T *patches // 6d (Batch, Channels, KernelSize, KernelSize, HeightOut, WidthOut)
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    const auto patches_numel = op.batch * op.chan * op.kernel * op.kernel * op.h_out * op.w_out;
+    if (i >= patches_numel) {
+        return;
+    }
+
+    size_t i_image;
+    if (get_image_index(op, i, strides, &i_image)) {
+        patches[i] = image[i_image];
+    }
+}
+
+template<typename T>
+__device__ void unfold_bwd(
+    const UnfoldOp op,
+    T *grad_image, // 4d (Batch, Channels, Height, Width)
+    const size_t *strides, // 4d image strides
+    const T *grad_patches // 6d (Batch, Channels, KernelSize, KernelSize, HeightOut, WidthOut)
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    const auto patches_numel = op.batch * op.chan * op.kernel * op.kernel * op.h_out * op.w_out;
+    if (i >= patches_numel) {
+        return;
+    }
+
+    size_t i_image;
+    if (get_image_index(op, i, strides, &i_image)) {
+        atomicAdd(grad_image + i_image, grad_patches[i]);
+    }
+}
+
+#define UNFOLD_OP(TYPENAME, FWD, BWD) \
+extern "C" __global__ void FWD( \
+    const UnfoldOp op, \
+    const TYPENAME *image, \
+    const size_t *strides, \
+    TYPENAME *patches \
+) { \
+    unfold_fwd(op, image, strides, patches); \
+} \
+extern "C" __global__ void BWD( \
+    const UnfoldOp op, \
+    TYPENAME *grad_image, \
+    const size_t *strides, \
+    const TYPENAME *grad_patches \
+) { \
+    unfold_bwd(op, grad_image, strides, grad_patches); \
+}
+
+UNFOLD_OP(float, unfold_fwd_f32, unfold_bwd_f32);
+UNFOLD_OP(double, unfold_fwd_f64, unfold_bwd_f64);
@@ -0,0 +1,168 @@
+use crate::shapes::*;
+use crate::tensor::cpu::Cpu;
+
+use std::sync::Arc;
+
+use num_traits::Float;
+
+use super::{Bilinear, NearestNeighbor, Upscale2DKernel, Upscale2DOp};
+
+fn make_4d<S: Shape>(strides: S::Concrete) -> [usize; 4] {
+    match S::NUM_DIMS {
+        3 => [0, strides[0], strides[1], strides[2]],
+        4 => [strides[0], strides[1], strides[2], strides[3]],
+        _ => panic!("Only implemented for 3d & 4d arrays"),
+    }
+}
+
+impl<E: Unit + std::ops::AddAssign> Upscale2DKernel<E, NearestNeighbor> for Cpu {
+    fn forward<I: Shape, O: Shape>(
+        &self,
+        op: Upscale2DOp,
+        _method: NearestNeighbor,
+        inp: &Self::Storage<I, E>,
+        out: &mut Self::Storage<O, E>,
+    ) -> Result<(), Self::Err> {
+        let istr = make_4d::<I>(inp.strides);
+        let ostr = make_4d::<O>(out.strides);
+
+        let buf = inp.data.as_ref();
+        let out_buf = Arc::make_mut(&mut out.data);
+        for b in 0..op.batch {
+            for c in 0..op.chan {
+                for oh in 0..op.h_out {
+                    let y = oh * op.h_in / op.h_out;
+                    for ow in 0..op.w_out {
+                        let x = ow * op.w_in / op.w_out;
+                        out_buf[b * ostr[0] + c * ostr[1] + oh * ostr[2] + ow * ostr[3]] =
+                            buf[b * istr[0] + c * istr[1] + y * istr[2] + x * istr[3]];
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn backward<I: Shape, O: Shape>(
+        &self,
+        op: Upscale2DOp,
+        _method: NearestNeighbor,
+        grad_inp: &mut Self::Storage<I, E>,
+        grad_out: &Self::Storage<O, E>,
+    ) -> Result<(), Self::Err> {
+        let istr = make_4d::<I>(grad_inp.strides);
+        let ostr = make_4d::<O>(grad_out.strides);
+
+        let ginp_buf = Arc::make_mut(&mut grad_inp.data);
+        let buf = grad_out.data.as_ref();
+        for b in 0..op.batch {
+            for c in 0..op.chan {
+                for oh in 0..op.h_out {
+                    let y = oh * op.h_in / op.h_out;
+                    for ow in 0..op.w_out {
+                        let x = ow * op.w_in / op.w_out;
+                        ginp_buf[b * istr[0] + c * istr[1] + y * istr[2] + x * istr[3]] +=
+                            buf[b * ostr[0] + c * ostr[1] + oh * ostr[2] + ow * ostr[3]];
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The two input pixels that output pixel `o` interpolates between, and the
+/// weight of the second one.
+fn bilinear_src<F: Float>(
+    o: usize,
+    inp: usize,
+    out: usize,
+    align_corners: bool,
+) -> (usize, usize, F) {
+    let src = if align_corners {
+        if out > 1 {
+            F::from(o * (inp - 1)).unwrap() / F::from(out - 1).unwrap()
+        } else {
+            F::zero()
+        }
+    } else {
+        let half = F::from(0.5).unwrap();
+        let src =
+            (F::from(o).unwrap() + half) * F::from(inp).unwrap() / F::from(out).unwrap() - half;
+        src.max(F::zero())
+    };
+    let i0 = src.floor().to_usize().unwrap().min(inp - 1);
+    let i1 = (i0 + 1).min(inp - 1);
+    (i0, i1, src - F::from(i0).unwrap())
+}
+
+impl<F: Float + Unit + std::ops::AddAssign> Upscale2DKernel<F, Bilinear> for Cpu {
+    fn forward<I: Shape, O: Shape>(
+        &self,
+        op: Upscale2DOp,
+        method: Bilinear,
+        inp: &Self::Storage<I, F>,
+        out: &mut Self::Storage<O, F>,
+    ) -> Result<(), Self::Err> {
+        let istr = make_4d::<I>(inp.strides);
+        let ostr = make_4d::<O>(out.strides);
+
+        let buf = inp.data.as_ref();
+        let out_buf = Arc::make_mut(&mut out.data);
+        for b in 0..op.batch {
+            for c in 0..op.chan {
+                let base = b * istr[0] + c * istr[1];
+                for oh in 0..op.h_out {
+                    let (y0, y1, ly) =
+                        bilinear_src::<F>(oh, op.h_in, op.h_out, method.align_corners);
+                    for ow in 0..op.w_out {
+                        let (x0, x1, lx) =
+                            bilinear_src::<F>(ow, op.w_in, op.w_out, method.align_corners);
+                        let top = buf[base + y0 * istr[2] + x0 * istr[3]] * (F::one() - lx)
+                            + buf[base + y0 * istr[2] + x1 * istr[3]] * lx;
+                        let bot = buf[base + y1 * istr[2] + x0 * istr[3]] * (F::one() - lx)
+                            + buf[base + y1 * istr[2] + x1 * istr[3]] * lx;
+                        out_buf[b * ostr[0] + c * ostr[1] + oh * ostr[2] + ow * ostr[3]] =
+                            top * (F::one() - ly) + bot * ly;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn backward<I: Shape, O: Shape>(
+        &self,
+        op: Upscale2DOp,
+        method: Bilinear,
+        grad_inp: &mut Self::Storage<I, F>,
+        grad_out: &Self::Storage<O, F>,
+    ) -> Result<(), Self::Err> {
+        let istr = make_4d::<I>(grad_inp.strides);
+        let ostr = make_4d::<O>(grad_out.strides);
+
+        let ginp_buf = Arc::make_mut(&mut grad_inp.data);
+        let buf = grad_out.data.as_ref();
+        for b in 0..op.batch {
+            for c in 0..op.chan {
+                let base = b * istr[0] + c * istr[1];
+                for oh in 0..op.h_out {
+                    let (y0, y1, ly) =
+                        bilinear_src::<F>(oh, op.h_in, op.h_out, method.align_corners);
+                    for ow in 0..op.w_out {
+                        let (x0, x1, lx) =
+                            bilinear_src::<F>(ow, op.w_in, op.w_out, method.align_corners);
+                        let go = buf[b * ostr[0] + c * ostr[1] + oh * ostr[2] + ow * ostr[3]];
+                        let top = go * (F::one() - ly);
+                        let bot = go * ly;
+                        ginp_buf[base + y0 * istr[2] + x0 * istr[3]] += top * (F::one() - lx);
+                        ginp_buf[base + y0 * istr[2] + x1 * istr[3]] += top * lx;
+                        ginp_buf[base + y1 * istr[2] + x0 * istr[3]] += bot * (F::one() - lx);
+                        ginp_buf[base + y1 * istr[2] + x1 * istr[3]] += bot * lx;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,105 @@
+use crate::{shapes::*, tensor::cuda::Cuda};
+
+use std::sync::Arc;
+
+use cudarc::driver::{AsKernelParam, LaunchAsync, LaunchConfig};
+
+use super::{Bilinear, NearestNeighbor, Upscale2DKernel, Upscale2DOp};
+
+const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/upscale2d.ptx"));
+
+unsafe impl AsKernelParam for Upscale2DOp {}
+
+fn make_4d<S: Shape>(strides: S::Concrete) -> [usize; 4] {
+    match S::NUM_DIMS {
+        3 => [0, strides[0], strides[1], strides[2]],
+        4 => [strides[0], strides[1], strides[2], strides[3]],
+        _ => panic!("Only implemented for 3d & 4d arrays"),
+    }
+}
+
+macro_rules! upscale_impl {
+    ($Method:ty, |$m:ident| $AlignCorners:expr, $TypeName:ty, $Fwd:tt, $Bwd:tt) => {
+        impl Upscale2DKernel<$TypeName, $Method> for Cuda {
+            fn forward<I: Shape, O: Shape>(
+                &self,
+                op: Upscale2DOp,
+                $m: $Method,
+                inp: &Self::Storage<I, $TypeName>,
+                out: &mut Self::Storage<O, $TypeName>,
+            ) -> Result<(), Self::Err> {
+                if !self.dev.has_func($Fwd, $Fwd) {
+                    self.dev.load_ptx(PTX_SRC.into(), $Fwd, &[$Fwd, $Bwd])?;
+                }
+
+                let align_corners: usize = $AlignCorners;
+                let inp_strides = self.dev.take_async(make_4d::<I>(inp.strides).into())?;
+                let out_strides = self.dev.take_async(make_4d::<O>(out.strides).into())?;
+                let fwd_fn = self.dev.get_func($Fwd, $Fwd).unwrap();
+                let cfg = LaunchConfig::for_num_elems(out.shape().num_elements() as u32);
+                let params = (
+                    op,                           // const Upscale2dOp op,
+                    align_corners,                // const size_t align_corners,
+                    &inp_strides,                 // const size_t *inp_strides,
+                    &out_strides,                 // const size_t *out_strides,
+                    inp.data.as_ref(),            // const float *inp,
+                    Arc::make_mut(&mut out.data), // float *out
+                );
+                unsafe { fwd_fn.launch_async(cfg, params) }?;
+                Ok(())
+            }
+            fn backward<I: Shape, O: Shape>(
+                &self,
+                op: Upscale2DOp,
+                $m: $Method,
+                grad_inp: &mut Self::Storage<I, $TypeName>,
+                grad_out: &Self::Storage<O, $TypeName>,
+            ) -> Result<(), Self::Err> {
+                let align_corners: usize = $AlignCorners;
+                let inp_strides = self.dev.take_async(make_4d::<I>(grad_inp.strides).into())?;
+                let out_strides = self.dev.take_async(make_4d::<O>(grad_out.strides).into())?;
+                let bwd_fn = self.dev.get_func($Fwd, $Bwd).unwrap();
+                let cfg = LaunchConfig::for_num_elems(grad_out.shape().num_elements() as u32);
+                let params = (
+                    op,                                // const Upscale2dOp op,
+                    align_corners,                     // const size_t align_corners,
+                    &inp_strides,                      // const size_t *inp_strides,
+                    &out_strides,                      // const size_t *out_strides,
+                    Arc::make_mut(&mut grad_inp.data), // float *grad_inp,
+                    grad_out.data.as_ref(),            // const float *grad_out
+                );
+                unsafe { bwd_fn.launch_async(cfg, params) }?;
+                Ok(())
+            }
+        }
+    };
+}
+
+upscale_impl!(
+    NearestNeighbor,
+    |_method| 0,
+    f32,
+    "nearest_upscale2d_fwd_f32",
+    "nearest_upscale2d_bwd_f32"
+);
+upscale_impl!(
+    NearestNeighbor,
+    |_method| 0,
+    f64,
+    "nearest_upscale2d_fwd_f64",
+    "nearest_upscale2d_bwd_f64"
+);
+upscale_impl!(
+    Bilinear,
+    |method| method.align_corners as usize,
+    f32,
+    "bilinear_upscale2d_fwd_f32",
+    "bilinear_upscale2d_bwd_f32"
+);
+upscale_impl!(
+    Bilinear,
+    |method| method.align_corners as usize,
+    f64,
+    "bilinear_upscale2d_fwd_f64",
+    "bilinear_upscale2d_bwd_f64"
+);
@@ -0,0 +1,459 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{
+    gradients::Tape,
+    shapes::*,
+    tensor::{DeviceStorage, HasErr, PutTape, SplitTape, Tensor, ZerosTensor},
+};
+
+/// How [TryUpscale2D] computes each output pixel from the input image.
+pub trait UpscaleMethod: 'static + Default + Clone + Copy + std::fmt::Debug {}
+
+/// Each output pixel copies the input pixel it lands in. Output pixel `i`
+/// reads input pixel `floor(i * in / out)`.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NearestNeighbor;
+impl UpscaleMethod for NearestNeighbor {}
+
+/// Each output pixel is a weighted average of the 4 input pixels closest to it.
+///
+/// With `align_corners` the corner pixels of the input and output line up exactly,
+/// so output pixel `i` samples the input at `i * (in - 1) / (out - 1)`. Otherwise
+/// pixels are treated as squares, and output pixel `i` samples the input
+/// at `(i + 0.5) * in / out - 0.5`.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Bilinear {
+    pub align_corners: bool,
+}
+impl UpscaleMethod for Bilinear {}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct Upscale2DOp {
+    pub batch: usize,
+    pub chan: usize,
+    pub h_in: usize,
+    pub h_out: usize,
+    pub w_in: usize,
+    pub w_out: usize,
+}
+
+impl Upscale2DOp {
+    fn new([b, c, h_in, w_in]: [usize; 4], [h_out, w_out]: [usize; 2]) -> Self {
+        assert!(h_in > 0 && w_in > 0, "Can't upscale an empty image");
+        Self {
+            batch: b,
+            chan: c,
+            h_in,
+            h_out,
+            w_in,
+            w_out,
+        }
+    }
+}
+
+pub trait Upscale2DKernel<E: Unit, M: UpscaleMethod>: DeviceStorage {
+    fn forward<I: Shape, O: Shape>(
+        &self,
+        op: Upscale2DOp,
+        method: M,
+        inp: &Self::Storage<I, E>,
+        out: &mut Self::Storage<O, E>,
+    ) -> Result<(), Self::Err>;
+
+    fn backward<I: Shape, O: Shape>(
+        &self,
+        op: Upscale2DOp,
+        method: M,
+        grad_inp: &mut Self::Storage<I, E>,
+        grad_out: &Self::Storage<O, E>,
+    ) -> Result<(), Self::Err>;
+}
+
+/// Resizes the height & width of images (3d) and batches of images (4d) to any size
+/// using an [UpscaleMethod].
+pub trait GenericUpscale2D<M: UpscaleMethod>: HasErr {
+    type Output<OH: Dim, OW: Dim>;
+    fn generic_upscale2d_like<OH: Dim, OW: Dim>(
+        self,
+        method: M,
+        height: OH,
+        width: OW,
+    ) -> Result<Self::Output<OH, OW>, Self::Err>;
+}
+
+/// Upscales images (3d) and batches of images (4d) to a larger height & width.
+///
+/// The target size can be given at compile time, at runtime, or as a scale factor:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t: Tensor<Rank3<3, 2, 4>, f32, _> = dev.zeros();
+/// let _: Tensor<Rank3<3, 4, 8>, f32, _> = t.clone().upsample2d_nearest();
+/// let _: Tensor<(Const<3>, usize, usize), f32, _> = t.clone().upsample2d_nearest_like(5, 7);
+/// let r: Tensor<(Const<3>, usize, usize), f32, _> = t.upsample2d_nearest_by(3);
+/// assert_eq!(r.shape(), &(Const, 6, 12));
+/// ```
+///
+/// Bilinear upscaling takes whether to align the corners of the input & output:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([[[1.0, 2.0], [3.0, 4.0]]]);
+/// let r: Tensor<Rank3<1, 3, 3>, f32, _> = t.clone().upsample2d_bilinear(true);
+/// assert_eq!(r.array(), [[[1.0, 1.5, 2.0], [2.0, 2.5, 3.0], [3.0, 3.5, 4.0]]]);
+/// let r: Tensor<Rank3<1, 1, 4>, f32, _> = t.upsample2d_bilinear(false);
+/// assert_eq!(r.array(), [[[2.0, 2.25, 2.75, 3.0]]]);
+/// ```
+pub trait TryUpscale2D {
+    /// Nearest neighbor upscale to a compile time known height & width.
+    fn upsample2d_nearest<const OH: usize, const OW: usize>(
+        self,
+    ) -> <Self as GenericUpscale2D<NearestNeighbor>>::Output<Const<OH>, Const<OW>>
+    where
+        Self: GenericUpscale2D<NearestNeighbor>,
+    {
+        self.generic_upscale2d_like(NearestNeighbor, Const, Const)
+            .unwrap()
+    }
+    /// Fallible version of [TryUpscale2D::upsample2d_nearest]
+    fn try_upsample2d_nearest<const OH: usize, const OW: usize>(
+        self,
+    ) -> Result<<Self as GenericUpscale2D<NearestNeighbor>>::Output<Const<OH>, Const<OW>>, Self::Err>
+    where
+        Self: GenericUpscale2D<NearestNeighbor>,
+    {
+        self.generic_upscale2d_like(NearestNeighbor, Const, Const)
+    }
+    /// Nearest neighbor upscale to a runtime height & width.
+    fn upsample2d_nearest_like<OH: Dim, OW: Dim>(
+        self,
+        height: OH,
+        width: OW,
+    ) -> <Self as GenericUpscale2D<NearestNeighbor>>::Output<OH, OW>
+    where
+        Self: GenericUpscale2D<NearestNeighbor>,
+    {
+        self.generic_upscale2d_like(NearestNeighbor, height, width)
+            .unwrap()
+    }
+    /// Fallible version of [TryUpscale2D::upsample2d_nearest_like]
+    fn try_upsample2d_nearest_like<OH: Dim, OW: Dim>(
+        self,
+        height: OH,
+        width: OW,
+    ) -> Result<<Self as GenericUpscale2D<NearestNeighbor>>::Output<OH, OW>, Self::Err>
+    where
+        Self: GenericUpscale2D<NearestNeighbor>,
+    {
+        self.generic_upscale2d_like(NearestNeighbor, height, width)
+    }
+    /// Nearest neighbor upscale that multiplies the height & width by `scale`.
+    fn upsample2d_nearest_by(
+        self,
+        scale: usize,
+    ) -> <Self as GenericUpscale2D<NearestNeighbor>>::Output<usize, usize>
+    where
+        Self: GenericUpscale2D<NearestNeighbor> + HasShape,
+    {
+        self.try_upsample2d_nearest_by(scale).unwrap()
+    }
+    /// Fallible version of [TryUpscale2D::upsample2d_nearest_by]
+    fn try_upsample2d_nearest_by(
+        self,
+        scale: usize,
+    ) -> Result<<Self as GenericUpscale2D<NearestNeighbor>>::Output<usize, usize>, Self::Err>
+    where
+        Self: GenericUpscale2D<NearestNeighbor> + HasShape,
+    {
+        let [h, w] = last_two_dims(self.shape());
+        self.generic_upscale2d_like(NearestNeighbor, h * scale, w * scale)
+    }
+    /// Bilinear upscale to a compile time known height & width.
+    fn upsample2d_bilinear<const OH: usize, const OW: usize>(
+        self,
+        align_corners: bool,
+    ) -> <Self as GenericUpscale2D<Bilinear>>::Output<Const<OH>, Const<OW>>
+    where
+        Self: GenericUpscale2D<Bilinear>,
+    {
+        self.try_upsample2d_bilinear(align_corners).unwrap()
+    }
+    /// Fallible version of [TryUpscale2D::upsample2d_bilinear]
+    fn try_upsample2d_bilinear<const OH: usize, const OW: usize>(
+        self,
+        align_corners: bool,
+    ) -> Result<<Self as GenericUpscale2D<Bilinear>>::Output<Const<OH>, Const<OW>>, Self::Err>
+    where
+        Self: GenericUpscale2D<Bilinear>,
+    {
+        self.generic_upscale2d_like(Bilinear { align_corners }, Const, Const)
+    }
+    /// Bilinear upscale to a runtime height & width.
+    fn upsample2d_bilinear_like<OH: Dim, OW: Dim>(
+        self,
+        height: OH,
+        width: OW,
+        align_corners: bool,
+    ) -> <Self as GenericUpscale2D<Bilinear>>::Output<OH, OW>
+    where
+        Self: GenericUpscale2D<Bilinear>,
+    {
+        self.try_upsample2d_bilinear_like(height, width, align_corners)
+            .unwrap()
+    }
+    /// Fallible version of [TryUpscale2D::upsample2d_bilinear_like]
+    fn try_upsample2d_bilinear_like<OH: Dim, OW: Dim>(
+        self,
+        height: OH,
+        width: OW,
+        align_corners: bool,
+    ) -> Result<<Self as GenericUpscale2D<Bilinear>>::Output<OH, OW>, Self::Err>
+    where
+        Self: GenericUpscale2D<Bilinear>,
+    {
+        self.generic_upscale2d_like(Bilinear { align_corners }, height, width)
+    }
+    /// Bilinear upscale that multiplies the height & width by `scale`.
+    fn upsample2d_bilinear_by(
+        self,
+        scale: usize,
+        align_corners: bool,
+    ) -> <Self as GenericUpscale2D<Bilinear>>::Output<usize, usize>
+    where
+        Self: GenericUpscale2D<Bilinear> + HasShape,
+    {
+        self.try_upsample2d_bilinear_by(scale, align_corners)
+            .unwrap()
+    }
+    /// Fallible version of [TryUpscale2D::upsample2d_bilinear_by]
+    fn try_upsample2d_bilinear_by(
+        self,
+        scale: usize,
+        align_corners: bool,
+    ) -> Result<<Self as GenericUpscale2D<Bilinear>>::Output<usize, usize>, Self::Err>
+    where
+        Self: GenericUpscale2D<Bilinear> + HasShape,
+    {
+        let [h, w] = last_two_dims(self.shape());
+        self.generic_upscale2d_like(Bilinear { align_corners }, h * scale, w * scale)
+    }
+}
+impl<T> TryUpscale2D for T {}
+
+fn last_two_dims<S: Shape>(shape: &S) -> [usize; 2] {
+    let dims = shape.concrete();
+    [dims[S::NUM_DIMS - 2], dims[S::NUM_DIMS - 1]]
+}
+
+impl<
+        C: Dim,
+        H: Dim,
+        W: Dim,
+        E: Dtype,
+        M: UpscaleMethod,
+        D: Upscale2DKernel<E, M> + ZerosTensor<E>,
+        T: 'static + Tape<D>,
+    > GenericUpscale2D<M> for Tensor<(C, H, W), E, D, T>
+{
+    type Output<OH: Dim, OW: Dim> = Tensor<(C, OH, OW), E, D, T>;
+
+    fn generic_upscale2d_like<OH: Dim, OW: Dim>(
+        self,
+        method: M,
+        height: OH,
+        width: OW,
+    ) -> Result<Self::Output<OH, OW>, Self::Err> {
+        let &(chan, h, w) = self.shape();
+        let op = Upscale2DOp::new(
+            [1, chan.size(), h.size(), w.size()],
+            [height.size(), width.size()],
+        );
+        let (inp, mut tape) = self.split_tape();
+        let mut out = inp.device.try_zeros_like(&(chan, height, width))?;
+        Upscale2DKernel::<E, M>::forward(&inp.device, op, method, &inp.storage, &mut out.storage)?;
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&inp)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+            Upscale2DKernel::<E, M>::backward(&inp.device, op, method, grad_inp, grad_out)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+impl<
+        B: Dim,
+        C: Dim,
+        H: Dim,
+        W: Dim,
+        E: Dtype,
+        M: UpscaleMethod,
+        D: Upscale2DKernel<E, M> + ZerosTensor<E>,
+        T: 'static + Tape<D>,
+    > GenericUpscale2D<M> for Tensor<(B, C, H, W), E, D, T>
+{
+    type Output<OH: Dim, OW: Dim> = Tensor<(B, C, OH, OW), E, D, T>;
+
+    fn generic_upscale2d_like<OH: Dim, OW: Dim>(
+        self,
+        method: M,
+        height: OH,
+        width: OW,
+    ) -> Result<Self::Output<OH, OW>, Self::Err> {
+        let &(batch, chan, h, w) = self.shape();
+        let op = Upscale2DOp::new(
+            [batch.size(), chan.size(), h.size(), w.size()],
+            [height.size(), width.size()],
+        );
+        let (inp, mut tape) = self.split_tape();
+        let mut out = inp.device.try_zeros_like(&(batch, chan, height, width))?;
+        Upscale2DKernel::<E, M>::forward(&inp.device, op, method, &inp.storage, &mut out.storage)?;
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&inp)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+            Upscale2DKernel::<E, M>::backward(&inp.device, op, method, grad_inp, grad_out)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_upsample2d_nearest_3d_by_2() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([[[1.0, 2.0], [3.0, 4.0]]]);
+        let r: Tensor<Rank3<1, 4, 4>, _, _, _> = x.trace().upsample2d_nearest();
+        assert_close(
+            &r.array(),
+            &[[
+                [1.0, 1.0, 2.0, 2.0],
+                [1.0, 1.0, 2.0, 2.0],
+                [3.0, 3.0, 4.0, 4.0],
+                [3.0, 3.0, 4.0, 4.0],
+            ]],
+        );
+        let g = r.exp().sum().backward();
+        assert_close(
+            &g.get(&x).array(),
+            &[[[10.873127, 29.556224], [80.34215, 218.3926]]],
+        );
+    }
+
+    #[test]
+    fn test_upsample2d_nearest_3d_uneven() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]]);
+        let r: Tensor<Rank3<1, 3, 5>, _, _, _> = x.trace().upsample2d_nearest();
+        assert_close(
+            &r.array(),
+            &[[
+                [1.0, 1.0, 2.0, 2.0, 3.0],
+                [1.0, 1.0, 2.0, 2.0, 3.0],
+                [4.0, 4.0, 5.0, 5.0, 6.0],
+            ]],
+        );
+        let g = r.sum().backward();
+        assert_close(&g.get(&x).array(), &[[[4.0, 4.0, 2.0], [2.0, 2.0, 1.0]]]);
+    }
+
+    #[test]
+    fn test_upsample2d_nearest_4d_by() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank4<2, 3, 2, 3>, TestDtype, _> = dev.sample_normal();
+        let r = x.trace().upsample2d_nearest_by(3);
+        assert_eq!(r.shape(), &(Const::<2>, Const::<3>, 6, 9));
+        let r_vec = r.as_vec();
+        let x_arr = x.array();
+        for b in 0..2 {
+            for c in 0..3 {
+                for oh in 0..6 {
+                    for ow in 0..9 {
+                        assert_eq!(
+                            r_vec[((b * 3 + c) * 6 + oh) * 9 + ow],
+                            x_arr[b][c][oh / 3][ow / 3]
+                        );
+                    }
+                }
+            }
+        }
+        let g = r.sum().backward();
+        assert_close(&g.get(&x).array(), &[[[[9.0; 3]; 2]; 3]; 2]);
+    }
+
+    #[test]
+    fn test_upsample2d_nearest_downscale() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([[[1.0, 2.0, 3.0, 4.0], [5.0, 6.0, 7.0, 8.0]]]);
+        let r = x.trace().upsample2d_nearest_like(1, 2);
+        assert_eq!(r.as_vec(), [1.0, 3.0]);
+        let g = r.sum().backward();
+        assert_close(&g.get(&x).array(), &[[[1.0, 0.0, 1.0, 0.0], [0.0; 4]]]);
+    }
+
+    #[test]
+    fn test_upsample2d_bilinear_3d() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]]);
+        let r: Tensor<Rank3<1, 3, 5>, _, _, _> = x.trace().upsample2d_bilinear(false);
+        assert_close(
+            &r.array(),
+            &[[
+                [1.0, 1.4, 2.0, 2.6, 3.0],
+                [2.5, 2.9, 3.5, 4.1, 4.5],
+                [4.0, 4.4, 5.0, 5.6, 6.0],
+            ]],
+        );
+        let g = r.exp().sum().backward();
+        assert_close_with_tolerance(
+            &g.get(&x).array(),
+            &[[
+                [16.694892, 46.657244, 91.27443],
+                [115.01216, 321.42468, 628.7953],
+            ]],
+            1e-4,
+        );
+    }
+
+    #[test]
+    fn test_upsample2d_bilinear_align_corners() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<_, TestDtype, _> = dev.tensor([[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]]);
+        let r = x.trace().upsample2d_bilinear_like(3, 5, true);
+        assert_close(
+            &r.as_vec(),
+            &std::vec![1.0, 1.5, 2.0, 2.5, 3.0, 2.5, 3.0, 3.5, 4.0, 4.5, 4.0, 4.5, 5.0, 5.5, 6.0],
+        );
+        let g = r.exp().sum().backward();
+        assert_close_with_tolerance(
+            &g.get(&x).array(),
+            &[[
+                [16.071758, 50.949795, 84.834887],
+                [110.719347, 350.99634, 584.43286],
+            ]],
+            1e-4,
+        );
+    }
+
+    #[test]
+    fn test_upsample2d_bilinear_4d_same_size() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank4<2, 3, 4, 5>, TestDtype, _> = dev.sample_normal();
+        for align_corners in [false, true] {
+            let r = x.trace().upsample2d_bilinear_by(1, align_corners);
+            assert_close(&r.as_vec(), &x.as_vec());
+            let g = r.sum().backward();
+            assert_close(&g.get(&x).array(), &[[[[1.0; 5]; 4]; 3]; 2]);
+        }
+    }
+}
@@ -0,0 +1,224 @@
+#include "cuda_utils.cuh"
+
+struct Upscale2dOp {
+    size_t batch;
+    size_t chan;
+    size_t h_in;
+    size_t h_out;
+    size_t w_in;
+    size_t w_out;
+};
+
+template<typename T>
+__device__ void nearest_upscale2d_fwd(
+    const Upscale2dOp op,
+    const size_t align_corners,
+    const size_t *inp_strides,
+    const size_t *out_strides,
+    const T *inp, // 4d (Batch, Channels, Height, Width)
+    T *out // 4d (Batch, Channels, HeightOut, WidthOut)
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    const size_t numel = op.batch * op.chan * op.h_out * op.w_out;
+    if (i >= numel) {
+        return;
+    }
+
+    unsigned int idx = i;
+    const size_t ow = idx % op.w_out;
+    idx /= op.w_out;
+    const size_t oh = idx % op.h_out;
+    idx /= op.h_out;
+    const size_t c = idx % op.chan;
+    idx /= op.chan;
+    const size_t b = idx % op.batch;
+    idx /= op.batch;
+
+    const size_t y = oh * op.h_in / op.h_out;
+    const size_t x = ow * op.w_in / op.w_out;
+
+    auto inp_i = b * inp_strides[0] + c * inp_strides[1] + y * inp_strides[2] + x * inp_strides[3];
+    out[i] = inp[inp_i];
+}
+
+template<typename T>
+__device__ void nearest_upscale2d_bwd(
+    const Upscale2dOp op,
+    const size_t align_corners,
+    const size_t *inp_strides,
+    const size_t *out_strides,
+    T *grad_inp, // 4d (Batch, Channels, Height, Width)
+    const T *grad_out // 4d (Batch, Channels, HeightOut, WidthOut)
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    const size_t numel = op.batch * op.chan * op.h_out * op.w_out;
+    if (i >= numel) {
+        return;
+    }
+
+    unsigned int idx = i;
+    const size_t ow = idx % op.w_out;
+    idx /= op.w_out;
+    const size_t oh = idx % op.h_out;
+    idx /= op.h_out;
+    const size_t c = idx % op.chan;
+    idx /= op.chan;
+    const size_t b = idx % op.batch;
+    idx /= op.batch;
+
+    const size_t y = oh * op.h_in / op.h_out;
+    const size_t x = ow * op.w_in / op.w_out;
+
+    auto inp_i = b * inp_strides[0] + c * inp_strides[1] + y * inp_strides[2] + x * inp_strides[3];
+    auto out_i = b * out_strides[0] + c * out_strides[1] + oh * out_strides[2] + ow * out_strides[3];
+    atomicAdd(grad_inp + inp_i, grad_out[out_i]);
+}
+
+template<typename T>
+__device__ void bilinear_src(
+    const size_t o,
+    const size_t inp,
+    const size_t out,
+    const size_t align_corners,
+    size_t *i0,
+    size_t *i1,
+    T *lambda
+) {
+    T src;
+    if (align_corners) {
+        src = out > 1 ? static_cast<T>(o * (inp - 1)) / static_cast<T>(out - 1) : 0.0;
+    } else {
+        src = (static_cast<T>(o) + 0.5) * static_cast<T>(inp) / static_cast<T>(out) - 0.5;
+        src = maxg(src, static_cast<T>(0.0));
+    }
+    *i0 = static_cast<size_t>(src);
+    if (*i0 > inp - 1) { *i0 = inp - 1; }
+    *i1 = *i0 + 1 < inp ? *i0 + 1 : inp - 1;
+    *lambda = src - static_cast<T>(*i0);
+}
+
+template<typename T>
+__device__ void bilinear_upscale2d_fwd(
+    const Upscale2dOp op,
+    const size_t align_corners,
+    const size_t *inp_strides,
+    const size_t *out_strides,
+    const T *inp, // 4d (Batch, Channels, Height, Width)
+    T *out // 4d (Batch, Channels, HeightOut, WidthOut)
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    const size_t numel = op.batch * op.chan * op.h_out * op.w_out;
+    if (i >= numel) {
+        return;
+    }
+
+    unsigned int idx = i;
+    const size_t ow = idx % op.w_out;
+    idx /= op.w_out;
+    const size_t oh = idx % op.h_out;
+    idx /= op.h_out;
+    const size_t c = idx % op.chan;
+    idx /= op.chan;
+    const size_t b = idx % op.batch;
+    idx /= op.batch;
+
+    size_t y0, y1, x0, x1;
+    T ly, lx;
+    bilinear_src(oh, op.h_in, op.h_out, align_corners, &y0, &y1, &ly);
+    bilinear_src(ow, op.w_in, op.w_out, align_corners, &x0, &x1, &lx);
+
+    const T *base = inp + b * inp_strides[0] + c * inp_strides[1];
+    const T top = base[y0 * inp_strides[2] + x0 * inp_strides[3]] * (1.0 - lx)
+        + base[y0 * inp_strides[2] + x1 * inp_strides[3]] * lx;
+    const T bot = base[y1 * inp_strides[2] + x0 * inp_strides[3]] * (1.0 - lx)
+        + base[y1 * inp_strides[2] + x1 * inp_strides[3]] * lx;
+    out[i] = top * (1.0 - ly) + bot * ly;
+}
+
+template<typename T>
+__device__ void bilinear_upscale2d_bwd(
+    const Upscale2dOp op,
+    const size_t align_corners,
+    const size_t *inp_strides,
+    const size_t *out_strides,
+    T *grad_inp, // 4d (Batch, Channels, Height, Width)
+    const T *grad_out // 4d (Batch, Channels, HeightOut, WidthOut)
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    const size_t numel = op.batch * op.chan * op.h_out * op.w_out;
+    if (i >= numel) {
+        return;
+    }
+
+    unsigned int idx = i;
+    const size_t ow = idx % op.w_out;
+    idx /= op.w_out;
+    const size_t oh = idx % op.h_out;
+    idx /= op.h_out;
+    const size_t c = idx % op.chan;
+    idx /= op.chan;
+    const size_t b = idx % op.batch;
+    idx /= op.batch;
+
+    size_t y0, y1, x0, x1;
+    T ly, lx;
+    bilinear_src(oh, op.h_in, op.h_out, align_corners, &y0, &y1, &ly);
+    bilinear_src(ow, op.w_in, op.w_out, align_corners, &x0, &x1, &lx);
+
+    auto out_i = b * out_strides[0] + c * out_strides[1] + oh * out_strides[2] + ow * out_strides[3];
+    const T go = grad_out[out_i];
+    const T top = go * (1.0 - ly);
+    const T bot = go * ly;
+
+    T *base = grad_inp + b * inp_strides[0] + c * inp_strides[1];
+    atomicAdd(base + y0 * inp_strides[2] + x0 * inp_strides[3], top * (1.0 - lx));
+    atomicAdd(base + y0 * inp_strides[2] + x1 * inp_strides[3], top * lx);
+    atomicAdd(base + y1 * inp_strides[2] + x0 * inp_strides[3], bot * (1.0 - lx));
+    atomicAdd(base + y1 * inp_strides[2] + x1 * inp_strides[3], bot * lx);
+}
+
+#define UPSCALE_OP(TYPENAME, fwd, bwd, fwd_FN, bwd_FN) \
+extern "C" __global__ void fwd( \
+    const Upscale2dOp op, \
+    const size_t align_corners, \
+    const size_t *inp_strides, \
+    const size_t *out_strides, \
+    const TYPENAME *inp, \
+    TYPENAME *out \
+) { \
+    fwd_FN(op, align_corners, inp_strides, out_strides, inp, out); \
+} \
+extern "C" __global__ void bwd( \
+    const Upscale2dOp op, \
+    const size_t align_corners, \
+    const size_t *inp_strides, \
+    const size_t *out_strides, \
+    TYPENAME *grad_inp, \
+    const TYPENAME *grad_out \
+) { \
+    bwd_FN(op, align_corners, inp_strides, out_strides, grad_inp, grad_out); \
+}
+
+UPSCALE_OP(
+    float,
+    nearest_upscale2d_fwd_f32, nearest_upscale2d_bwd_f32,
+    nearest_upscale2d_fwd, nearest_upscale2d_bwd
+);
+
+UPSCALE_OP(
+    double,
+    nearest_upscale2d_fwd_f64, nearest_upscale2d_bwd_f64,
+    nearest_upscale2d_fwd, nearest_upscale2d_bwd
+);
+
+UPSCALE_OP(
+    float,
+    bilinear_upscale2d_fwd_f32, bilinear_upscale2d_bwd_f32,
+    bilinear_upscale2d_fwd, bilinear_upscale2d_bwd
+);
+
+UPSCALE_OP(
+    double,
+    bilinear_upscale2d_fwd_f64, bilinear_upscale2d_bwd_f64,
+    bilinear_upscale2d_fwd, bilinear_upscale2d_bwd
+);
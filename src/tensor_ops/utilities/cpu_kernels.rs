@@ -15,6 +15,73 @@ pub trait BinaryDerivative<E> {
     fn dfdy(&self, x: &E, y: &E) -> E;
 }
 
+/// The float dtypes. Ops that also support integer dtypes bound their float derivatives
+/// by this instead of [num_traits::Float], so they don't overlap with the integer impls.
+pub trait Float: num_traits::Float {}
+impl Float for f32 {}
+impl Float for f64 {}
+
+/// Integer division the same way the cuda kernels do it: dividing by zero saturates to
+/// the largest value (or the smallest, for negative `x`), and `MIN / -1` wraps to `MIN`.
+#[inline(always)]
+pub(crate) fn int_div<I: num_traits::PrimInt>(x: I, y: I) -> I {
+    if y.is_zero() {
+        if x < I::zero() {
+            I::min_value()
+        } else {
+            I::max_value()
+        }
+    } else {
+        x.checked_div(&y).unwrap_or(x)
+    }
+}
+
+/// Implements [BinaryDerivative] of `$Op` for the integer dtypes, which have no gradients.
+macro_rules! int_binary_derivative {
+    ($Op:ty, |$x:ident, $y:ident| $f:expr) => {
+        int_binary_derivative!($Op, |$x, $y| $f, usize, i32, i64, u8);
+    };
+    ($Op:ty, |$x:ident, $y:ident| $f:expr, $($I:ty),+) => {$(
+        impl crate::tensor_ops::cpu_kernels::BinaryDerivative<$I> for $Op {
+            #[inline(always)]
+            fn f(&self, &$x: &$I, &$y: &$I) -> $I {
+                $f
+            }
+            #[inline(always)]
+            fn dfdx(&self, _: &$I, _: &$I) -> $I {
+                0
+            }
+            #[inline(always)]
+            fn dfdy(&self, _: &$I, _: &$I) -> $I {
+                0
+            }
+        }
+    )+};
+}
+
+pub(crate) use int_binary_derivative;
+
+/// Implements [UnaryDerivative] of the scalar op `$Op<I>` for the integer dtypes `I`,
+/// which have no gradients.
+macro_rules! int_scalar_derivative {
+    ($Op:ident, |$x:ident, $scalar:ident| $f:expr) => {
+        int_scalar_derivative!($Op, |$x, $scalar| $f, usize, i32, i64, u8);
+    };
+    ($Op:ident, |$x:ident, $scalar:ident| $f:expr, $($I:ty),+) => {$(
+        impl crate::tensor_ops::cpu_kernels::UnaryDerivative<$I> for $Op<$I> {
+            fn f(&self, &$x: &$I) -> $I {
+                let $scalar = self.scalar;
+                $f
+            }
+            fn df(&self, _: &$I) -> $I {
+                0
+            }
+        }
+    )+};
+}
+
+pub(crate) use int_scalar_derivative;
+
 impl<E: Dtype, Op: UnaryDerivative<E>> UnaryKernel<Op, E> for Cpu {
     fn forward<S: Shape>(
         &self,
@@ -4,7 +4,17 @@ use crate::{
     tensor_ops::ops::{BinaryKernel, UnaryKernel},
 };
 use cudarc::driver::{AsKernelParam, CudaSlice, LaunchAsync, LaunchConfig};
-use std::sync::Arc;
+use std::{sync::Arc, vec::Vec};
+
+/// The dims of `arr` followed by its strides, so a kernel can take both as one
+/// `const size_t *info` param and stay under cudarc's limit on launch params.
+pub(crate) fn packed_info<S: Shape, E>(arr: &CudaArray<S, E>) -> Vec<usize> {
+    arr.shape
+        .concrete()
+        .into_iter()
+        .chain(arr.strides)
+        .collect()
+}
 
 pub trait UnaryOpCudaKernel<E> {
     /// Compiled by build.rs
@@ -75,3 +75,129 @@ __device__ __forceinline__ float absg(float a) { return fabsf(a); }
 __device__ __forceinline__ double absg(double a) { return fabs(a); }
 __device__ __forceinline__ float copysigng(float a, float b) { return copysignf(a, b); }
 __device__ __forceinline__ double copysigng(double a, double b) { return copysign(a, b); }
+__device__ __forceinline__ float fmodg(float a, float b) { return fmodf(a, b); }
+__device__ __forceinline__ double fmodg(double a, double b) { return fmod(a, b); }
+__device__ __forceinline__ float log1pg(float a) { return log1pf(a); }
+__device__ __forceinline__ double log1pg(double a) { return log1p(a); }
+__device__ __forceinline__ float expm1g(float a) { return expm1f(a); }
+__device__ __forceinline__ double expm1g(double a) { return expm1(a); }
+__device__ __forceinline__ float erfg(float a) { return erff(a); }
+__device__ __forceinline__ double erfg(double a) { return erf(a); }
+
+// Sums `value` over all the threads of the block. `buf` must have `blockDim.x` elements,
+// and `blockDim.x` must be a power of two.
+template<typename T>
+__device__ T block_sum(T value, T *buf) {
+    buf[threadIdx.x] = value;
+    __syncthreads();
+    for (unsigned int incr = blockDim.x / 2; incr > 0; incr >>= 1) {
+        if (threadIdx.x < incr) {
+            buf[threadIdx.x] += buf[threadIdx.x + incr];
+        }
+        __syncthreads();
+    }
+    T sum = buf[0];
+    // make sure every thread has read the sum before buf is reused
+    __syncthreads();
+    return sum;
+}
+
+// The maximum of `value` over all the threads of the block, with the same requirements
+// as `block_sum`.
+template<typename T>
+__device__ T block_max(T value, T *buf) {
+    buf[threadIdx.x] = value;
+    __syncthreads();
+    for (unsigned int incr = blockDim.x / 2; incr > 0; incr >>= 1) {
+        if (threadIdx.x < incr) {
+            buf[threadIdx.x] = maxg(buf[threadIdx.x], buf[threadIdx.x + incr]);
+        }
+        __syncthreads();
+    }
+    T result = buf[0];
+    __syncthreads();
+    return result;
+}
+
+// cuda only has an atomicAdd for unsigned long long, which size_t isn't on all platforms
+__device__ __forceinline__ size_t atomicAdd(size_t *address, size_t val) {
+    return atomicAdd((unsigned long long *)address, (unsigned long long)val);
+}
+
+// two's complement addition is the same for signed and unsigned integers
+__device__ __forceinline__ long long atomicAdd(long long *address, long long val) {
+    return (long long)atomicAdd((unsigned long long *)address, (unsigned long long)val);
+}
+
+// cuda has no atomicAdd for bytes, so this does a compare and swap on the aligned
+// 32 bit word containing `address`
+__device__ __forceinline__ unsigned char atomicAdd(unsigned char *address, unsigned char val) {
+    size_t offset = (size_t)address & 3;
+    unsigned int *word = (unsigned int *)(address - offset);
+    unsigned int shift = offset * 8;
+    unsigned int old = *word;
+    unsigned int assumed;
+    do {
+        assumed = old;
+        unsigned int sum = ((assumed >> shift) + val) & 0xff;
+        old = atomicCAS(word, assumed, (assumed & ~(0xffu << shift)) | (sum << shift));
+    } while (assumed != old);
+    return (old >> shift) & 0xff;
+}
+
+// `a % b` with the sign of `b`, like python's `%`
+template<typename T>
+__device__ __forceinline__ T remainderg(T a, T b) {
+    T r = fmodg(a, b);
+    return (r != 0.0 && (r < 0.0) != (b < 0.0)) ? r + b : r;
+}
+
+// Integer arithmetic that matches the cpu kernels. Overflow wraps around, like rust's
+// `wrapping_*` methods. Signed overflow is undefined in C++, so it's done on the
+// unsigned type of the same width.
+#define WRAPPING_OPS(T, U) \
+__device__ __forceinline__ T wrapping_add(T a, T b) { return (T)((U)a + (U)b); } \
+__device__ __forceinline__ T wrapping_sub(T a, T b) { return (T)((U)a - (U)b); } \
+__device__ __forceinline__ T wrapping_mul(T a, T b) { return (T)((U)a * (U)b); }
+
+WRAPPING_OPS(size_t, size_t)
+WRAPPING_OPS(int, unsigned int)
+WRAPPING_OPS(long long, unsigned long long)
+WRAPPING_OPS(unsigned char, unsigned int)
+
+// Integer division that matches `int_div` in `utilities/cpu_kernels.rs`: dividing by
+// zero saturates to MAX (or MIN for negative `a`), and `MIN / -1` wraps to MIN.
+#define INT_DIV(T, MIN, MAX) \
+__device__ __forceinline__ T int_div(T a, T b) { \
+    if (b == 0) { \
+        return a < (T)0 ? (T)(MIN) : (T)(MAX); \
+    } \
+    if ((T)(MIN) != (T)0 && a == (T)(MIN) && b == (T)-1) { \
+        return a; \
+    } \
+    return a / b; \
+}
+
+INT_DIV(size_t, 0, ~(size_t)0)
+INT_DIV(int, -2147483647 - 1, 2147483647)
+INT_DIV(long long, -9223372036854775807ll - 1, 9223372036854775807ll)
+INT_DIV(unsigned char, 0, 255)
+
+// A splitmix64 generator for a single stream of a seed, which mirrors
+// `StreamRng` in `utilities/rng.rs` so sampling matches the cpu.
+__device__ __forceinline__ unsigned long long rng_init(unsigned long long seed, unsigned long long stream) {
+    return seed ^ (stream * 0xD2B74407B1CE6E93ull);
+}
+
+__device__ __forceinline__ unsigned long long rng_next_u64(unsigned long long *state) {
+    *state += 0x9E3779B97F4A7C15ull;
+    unsigned long long z = *state;
+    z = (z ^ (z >> 30)) * 0xBF58476D1CE4E5B9ull;
+    z = (z ^ (z >> 27)) * 0x94D049BB133111EBull;
+    return z ^ (z >> 31);
+}
+
+// A uniform value in [0, 1).
+__device__ __forceinline__ double rng_next_f64(unsigned long long *state) {
+    return (double)(rng_next_u64(state) >> 11) * (1.0 / 9007199254740992.0);
+}
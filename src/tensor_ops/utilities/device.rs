@@ -22,6 +22,10 @@ pub trait Device<E: Dtype>:
     + super::super::sum_to::SumKernel<E>
     + super::super::max_to::MaxReduceKernel<E>
     + super::super::min_to::MinReduceKernel<E>
+    + super::super::var_to::VarKernel<E>
+    + super::super::softmax::SoftmaxKernel<E>
+    + super::super::group_norm::GroupNormKernel<E>
+    + super::super::local_response_norm::LocalResponseNormKernel<E>
     + super::super::permute_to::PermuteKernel<E>
     + super::super::reshape_to::ReshapeKernel<E>
 
@@ -29,6 +33,8 @@ pub trait Device<E: Dtype>:
     + super::super::select_and_gather::ReplaceDimKernel<E>
     + super::super::select_and_gather::RemoveDimKernel<E>
     + super::super::choose::ChooseKernel<E>
+    + super::super::embedding::EmbeddingKernel<E>
+    + super::super::embedding_bag::EmbeddingBagKernel<E>
 
     // matmuls
     + super::super::matmul::VecMatKernel<E>
@@ -57,6 +63,10 @@ pub trait Device<E: Dtype>:
     + UnaryKernel<super::super::abs::AbsKernelOp, E>
     + UnaryKernel<super::super::clamp::ClampKernelOp<E>, E>
     + UnaryKernel<super::super::cos::CosKernelOp, E>
+    + UnaryKernel<super::super::elu::EluKernelOp<E>, E>
+    + UnaryKernel<super::super::elu::SeluKernelOp, E>
+    + UnaryKernel<super::super::elu::CeluKernelOp<E>, E>
+    + super::super::bernoulli::BernoulliKernel<E>
     + super::super::dropout::DropoutKernel<E>
     + UnaryKernel<super::super::exp::ExpKernelOp, E>
     + UnaryKernel<super::super::ln::LnKernelOp, E>
@@ -64,6 +74,11 @@ pub trait Device<E: Dtype>:
     + UnaryKernel<super::super::negate::NegateKernelOp, E>
     + UnaryKernel<super::super::relu::ReLUKernelOp, E>
     + UnaryKernel<super::super::gelu::GeLUKernelOp, E>
+    + UnaryKernel<super::super::gelu::GeLUExactKernelOp, E>
+    + UnaryKernel<super::super::mish::MishKernelOp, E>
+    + UnaryKernel<super::super::hard_activations::HardSwishKernelOp, E>
+    + UnaryKernel<super::super::hard_activations::HardSigmoidKernelOp, E>
+    + UnaryKernel<super::super::hard_activations::HardTanhKernelOp, E>
     + UnaryKernel<super::super::sigmoid::SigmoidKernelOp, E>
     + UnaryKernel<super::super::sin::SinKernelOp, E>
     + UnaryKernel<super::super::sqrt::SqrtKernelOp, E>
@@ -77,6 +92,7 @@ pub trait Device<E: Dtype>:
     + BinaryKernel<super::super::huber_error::HuberErrorKernelOp<E>, E>
     + BinaryKernel<super::super::maximum::MaximumKernelOp, E>
     + BinaryKernel<super::super::minimum::MinimumKernelOp, E>
+    + BinaryKernel<super::super::prelu::PReLUKernelOp, E>
 {
 }
 
@@ -0,0 +1,411 @@
+//! Host implementations of dense linear algebra routines on row-major matrices,
+//! used by the linear algebra ops.
+
+use crate::shapes::{Dim, Shape};
+use core::cmp::Ordering;
+use num_traits::Float;
+use std::vec::Vec;
+
+/// Shapes whose last two dims are a matrix, with any leading dims being a batch of matrices.
+pub trait MatrixShape: Shape {
+    /// The shape of the batch of matrices.
+    type Batch: Shape;
+
+    fn batch(&self) -> Self::Batch;
+    fn rows(&self) -> usize;
+    fn cols(&self) -> usize;
+}
+
+impl<M: Dim, N: Dim> MatrixShape for (M, N) {
+    type Batch = ();
+    fn batch(&self) -> Self::Batch {}
+    fn rows(&self) -> usize {
+        self.0.size()
+    }
+    fn cols(&self) -> usize {
+        self.1.size()
+    }
+}
+
+impl<B: Dim, M: Dim, N: Dim> MatrixShape for (B, M, N) {
+    type Batch = (B,);
+    fn batch(&self) -> Self::Batch {
+        (self.0,)
+    }
+    fn rows(&self) -> usize {
+        self.1.size()
+    }
+    fn cols(&self) -> usize {
+        self.2.size()
+    }
+}
+
+impl<B0: Dim, B1: Dim, M: Dim, N: Dim> MatrixShape for (B0, B1, M, N) {
+    type Batch = (B0, B1);
+    fn batch(&self) -> Self::Batch {
+        (self.0, self.1)
+    }
+    fn rows(&self) -> usize {
+        self.2.size()
+    }
+    fn cols(&self) -> usize {
+        self.3.size()
+    }
+}
+
+/// Matrix shapes `(.., M, N)` that can be decomposed into `U: (.., M, K)`, `S: (.., K)`
+/// and `V: (.., N, K)` by a singular value decomposition.
+pub trait SvdShape: MatrixShape {
+    type U<K: Dim>: Shape;
+    type S<K: Dim>: Shape;
+    type V<K: Dim>: Shape;
+
+    #[allow(clippy::type_complexity)]
+    fn svd_shapes<K: Dim>(&self, k: K) -> (Self::U<K>, Self::S<K>, Self::V<K>);
+}
+
+impl<M: Dim, N: Dim> SvdShape for (M, N) {
+    type U<K: Dim> = (M, K);
+    type S<K: Dim> = (K,);
+    type V<K: Dim> = (N, K);
+    fn svd_shapes<K: Dim>(&self, k: K) -> (Self::U<K>, Self::S<K>, Self::V<K>) {
+        ((self.0, k), (k,), (self.1, k))
+    }
+}
+
+impl<B: Dim, M: Dim, N: Dim> SvdShape for (B, M, N) {
+    type U<K: Dim> = (B, M, K);
+    type S<K: Dim> = (B, K);
+    type V<K: Dim> = (B, N, K);
+    fn svd_shapes<K: Dim>(&self, k: K) -> (Self::U<K>, Self::S<K>, Self::V<K>) {
+        ((self.0, self.1, k), (self.0, k), (self.0, self.2, k))
+    }
+}
+
+impl<B0: Dim, B1: Dim, M: Dim, N: Dim> SvdShape for (B0, B1, M, N) {
+    type U<K: Dim> = (B0, B1, M, K);
+    type S<K: Dim> = (B0, B1, K);
+    type V<K: Dim> = (B0, B1, N, K);
+    fn svd_shapes<K: Dim>(&self, k: K) -> (Self::U<K>, Self::S<K>, Self::V<K>) {
+        (
+            (self.0, self.1, self.2, k),
+            (self.0, self.1, k),
+            (self.0, self.1, self.3, k),
+        )
+    }
+}
+
+/// LU decomposition with partial pivoting of the `n x n` matrix `a`, in place.
+///
+/// Afterwards `a` holds `U` in its upper triangle and the unit lower triangular `L`
+/// below the diagonal, such that `P A = L U` where row `i` of `P A` is row `perm[i]` of `A`.
+/// Returns `perm` and the sign of the permutation. Singular matrices leave zeros on
+/// the diagonal of `U`.
+pub(crate) fn lu_factor<E: Float>(a: &mut [E], n: usize) -> (Vec<usize>, E) {
+    let mut perm: Vec<usize> = (0..n).collect();
+    let mut sign = E::one();
+    for k in 0..n {
+        let mut pivot = k;
+        for i in k + 1..n {
+            if a[i * n + k].abs() > a[pivot * n + k].abs() {
+                pivot = i;
+            }
+        }
+        if pivot != k {
+            for j in 0..n {
+                a.swap(k * n + j, pivot * n + j);
+            }
+            perm.swap(k, pivot);
+            sign = -sign;
+        }
+        let diag = a[k * n + k];
+        if diag == E::zero() {
+            continue;
+        }
+        for i in k + 1..n {
+            let f = a[i * n + k] / diag;
+            a[i * n + k] = f;
+            for j in k + 1..n {
+                a[i * n + j] = a[i * n + j] - f * a[k * n + j];
+            }
+        }
+    }
+    (perm, sign)
+}
+
+/// Solves `A X = B` in place for the `n x m` matrix `b`, given the output of [lu_factor] of `A`.
+pub(crate) fn lu_solve<E: Float>(lu: &[E], perm: &[usize], n: usize, b: &mut [E], m: usize) {
+    let mut x: Vec<E> = Vec::with_capacity(n * m);
+    for &p in perm.iter() {
+        x.extend_from_slice(&b[p * m..(p + 1) * m]);
+    }
+    // forward substitution with unit lower triangular L
+    for i in 0..n {
+        for k in 0..i {
+            let l = lu[i * n + k];
+            for j in 0..m {
+                x[i * m + j] = x[i * m + j] - l * x[k * m + j];
+            }
+        }
+    }
+    // back substitution with upper triangular U
+    for i in (0..n).rev() {
+        for k in i + 1..n {
+            let u = lu[i * n + k];
+            for j in 0..m {
+                x[i * m + j] = x[i * m + j] - u * x[k * m + j];
+            }
+        }
+        let diag = lu[i * n + i];
+        for j in 0..m {
+            x[i * m + j] = x[i * m + j] / diag;
+        }
+    }
+    b.copy_from_slice(&x);
+}
+
+/// Solves `A X = B` in place for the `n x n` triangular matrix `a` and the `n x m`
+/// matrix `b`. Only the upper or lower triangle of `a` is read.
+pub(crate) fn triangular_solve<E: Float>(a: &[E], n: usize, b: &mut [E], m: usize, upper: bool) {
+    for step in 0..n {
+        let i = if upper { n - 1 - step } else { step };
+        let ks = if upper { i + 1..n } else { 0..i };
+        for k in ks {
+            let l = a[i * n + k];
+            for j in 0..m {
+                b[i * m + j] = b[i * m + j] - l * b[k * m + j];
+            }
+        }
+        let diag = a[i * n + i];
+        for j in 0..m {
+            b[i * m + j] = b[i * m + j] / diag;
+        }
+    }
+}
+
+/// Cholesky decomposition of the symmetric positive definite `n x n` matrix `a`, in place.
+///
+/// Only the lower triangle of `a` is read. Afterwards `a` holds the lower triangular `L`
+/// such that `A = L L^T`, with zeros above the diagonal. Matrices that aren't positive
+/// definite result in NaNs.
+pub(crate) fn cholesky_factor<E: Float>(a: &mut [E], n: usize) {
+    for j in 0..n {
+        let mut d = a[j * n + j];
+        for k in 0..j {
+            d = d - a[j * n + k] * a[j * n + k];
+        }
+        let d = d.sqrt();
+        a[j * n + j] = d;
+        for i in j + 1..n {
+            let mut v = a[i * n + j];
+            for k in 0..j {
+                v = v - a[i * n + k] * a[j * n + k];
+            }
+            a[i * n + j] = v / d;
+        }
+        for k in j + 1..n {
+            a[j * n + k] = E::zero();
+        }
+    }
+}
+
+/// Inverse of the `n x n` lower triangular matrix `l`, which is also lower triangular.
+pub(crate) fn lower_triangular_inverse<E: Float>(l: &[E], n: usize) -> Vec<E> {
+    let mut inv = alloc::vec![E::zero(); n * n];
+    for j in 0..n {
+        inv[j * n + j] = E::one() / l[j * n + j];
+        for i in j + 1..n {
+            let mut v = E::zero();
+            for k in j..i {
+                v = v - l[i * n + k] * inv[k * n + j];
+            }
+            inv[i * n + j] = v / l[i * n + i];
+        }
+    }
+    inv
+}
+
+/// Reduced singular value decomposition `A = U diag(s) V^T` of the `m x n` matrix `a`,
+/// using one sided Jacobi rotations. With `r = min(m, n)`, returns the `m x r` matrix `U`,
+/// the `r` singular values in descending order, and the `n x r` matrix `V`.
+///
+/// The columns of `U` and `V` are orthonormal, even for rank deficient matrices.
+pub(crate) fn svd<E: Float>(a: &[E], m: usize, n: usize) -> (Vec<E>, Vec<E>, Vec<E>) {
+    if m < n {
+        let mut a_t = alloc::vec![E::zero(); m * n];
+        for i in 0..m {
+            for j in 0..n {
+                a_t[j * m + i] = a[i * n + j];
+            }
+        }
+        let (v, s, u) = svd(&a_t, n, m);
+        return (u, s, v);
+    }
+    if n == 0 {
+        return (Vec::new(), Vec::new(), Vec::new());
+    }
+
+    // orthogonalize the columns of `w`, applying the same rotations to `v`
+    let mut w = a.to_vec();
+    let mut v = alloc::vec![E::zero(); n * n];
+    for i in 0..n {
+        v[i * n + i] = E::one();
+    }
+    for _ in 0..64 {
+        let mut rotated = false;
+        for p in 0..n {
+            for q in p + 1..n {
+                let (mut alpha, mut beta, mut gamma) = (E::zero(), E::zero(), E::zero());
+                for i in 0..m {
+                    let (x, y) = (w[i * n + p], w[i * n + q]);
+                    alpha = alpha + x * x;
+                    beta = beta + y * y;
+                    gamma = gamma + x * y;
+                }
+                if gamma == E::zero() || gamma.abs() <= E::epsilon() * (alpha * beta).sqrt() {
+                    continue;
+                }
+                rotated = true;
+                let zeta = (beta - alpha) / (gamma + gamma);
+                let t = zeta.signum() / (zeta.abs() + (E::one() + zeta * zeta).sqrt());
+                let c = E::one() / (E::one() + t * t).sqrt();
+                let s = c * t;
+                for (x, k) in [(&mut w, m), (&mut v, n)] {
+                    for i in 0..k {
+                        let (xp, xq) = (x[i * n + p], x[i * n + q]);
+                        x[i * n + p] = c * xp - s * xq;
+                        x[i * n + q] = s * xp + c * xq;
+                    }
+                }
+            }
+        }
+        if !rotated {
+            break;
+        }
+    }
+
+    let norms: Vec<E> = (0..n)
+        .map(|j| {
+            (0..m)
+                .fold(E::zero(), |acc, i| acc + w[i * n + j] * w[i * n + j])
+                .sqrt()
+        })
+        .collect();
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|&i, &j| norms[j].partial_cmp(&norms[i]).unwrap_or(Ordering::Equal));
+    let tol = norms[order[0]] * E::epsilon() * E::from(m).unwrap();
+
+    let mut u = alloc::vec![E::zero(); m * n];
+    let mut s = alloc::vec![E::zero(); n];
+    let mut v_sorted = alloc::vec![E::zero(); n * n];
+    for (k, &j) in order.iter().enumerate() {
+        s[k] = norms[j];
+        for i in 0..n {
+            v_sorted[i * n + k] = v[i * n + j];
+        }
+        if norms[j] > tol {
+            for i in 0..m {
+                u[i * n + k] = w[i * n + j] / norms[j];
+            }
+        } else {
+            // complete `u` with a unit vector orthogonal to the previous columns
+            for e in 0..m {
+                let mut col = alloc::vec![E::zero(); m];
+                col[e] = E::one();
+                for prev in 0..k {
+                    let dot = (0..m).fold(E::zero(), |acc, i| acc + u[i * n + prev] * col[i]);
+                    for (i, c) in col.iter_mut().enumerate() {
+                        *c = *c - dot * u[i * n + prev];
+                    }
+                }
+                let norm = col.iter().fold(E::zero(), |acc, &c| acc + c * c).sqrt();
+                if norm > E::from(0.5).unwrap() {
+                    for (i, c) in col.iter().enumerate() {
+                        u[i * n + k] = *c / norm;
+                    }
+                    break;
+                }
+            }
+        }
+    }
+    (u, s, v_sorted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lu_solve() {
+        let a = [2.0, 1.0, 1.0, 4.0, -6.0, 0.0, -2.0, 7.0, 2.0];
+        let mut lu = a;
+        let (perm, sign) = lu_factor(&mut lu, 3);
+        assert_eq!(perm, [1, 0, 2]);
+        assert_eq!(sign, -1.0);
+        let mut b = [5.0, -2.0, 9.0];
+        lu_solve(&lu, &perm, 3, &mut b, 1);
+        for (x, e) in b.iter().zip([1.0, 1.0, 2.0]) {
+            assert!((x - e).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_cholesky_factor() {
+        let mut a = [4.0, 12.0, -16.0, 12.0, 37.0, -43.0, -16.0, -43.0, 98.0];
+        cholesky_factor(&mut a, 3);
+        assert_eq!(a, [2.0, 0.0, 0.0, 6.0, 1.0, 0.0, -8.0, 5.0, 3.0]);
+        let inv = lower_triangular_inverse(&a, 3);
+        for i in 0..3 {
+            for j in 0..3 {
+                let v: f64 = (0..3).map(|k| a[i * 3 + k] * inv[k * 3 + j]).sum();
+                assert!((v - if i == j { 1.0 } else { 0.0 }).abs() < 1e-12);
+            }
+        }
+    }
+
+    fn check_svd(a: &[f64], m: usize, n: usize, expected_s: &[f64]) {
+        let r = m.min(n);
+        let (u, s, v) = svd(a, m, n);
+        for (x, e) in s.iter().zip(expected_s) {
+            assert!((x - e).abs() < 1e-10, "{s:?}");
+        }
+        for i in 0..m {
+            for j in 0..n {
+                let x: f64 = (0..r).map(|k| u[i * r + k] * s[k] * v[j * r + k]).sum();
+                assert!((x - a[i * n + j]).abs() < 1e-10);
+            }
+        }
+        for (x, rows) in [(&u, m), (&v, n)] {
+            for i in 0..r {
+                for j in 0..r {
+                    let d: f64 = (0..rows).map(|k| x[k * r + i] * x[k * r + j]).sum();
+                    assert!((d - if i == j { 1.0 } else { 0.0 }).abs() < 1e-10);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_svd() {
+        check_svd(&[3.0, 0.0, 0.0, -4.0, 0.0, 0.0], 3, 2, &[4.0, 3.0]);
+        check_svd(&[3.0, 0.0, 0.0, 0.0, -4.0, 0.0], 2, 3, &[4.0, 3.0]);
+        check_svd(
+            &[1.0, 2.0, 2.0, 4.0, 3.0, 6.0],
+            3,
+            2,
+            &[70.0f64.sqrt(), 0.0],
+        );
+        check_svd(&[2.0, 0.0, 1.0, 1.0, 3.0, -1.0, 0.5, 0.0, 2.0], 3, 3, &[]);
+    }
+
+    #[test]
+    fn test_triangular_solve() {
+        let a = [2.0, 9.0, 0.0, 1.0, 3.0, 9.0, -1.0, 2.0, 4.0];
+        let mut b = [2.0, 4.0, 7.0, 8.0, 11.0, 14.0];
+        triangular_solve(&a, 3, &mut b, 2, false);
+        assert_eq!(b, [1.0, 2.0, 2.0, 2.0, 2.0, 3.0]);
+        let mut b = [20.0, 47.0, 24.0, 42.0, 8.0, 12.0];
+        triangular_solve(&a, 3, &mut b, 2, true);
+        assert_eq!(b, [1.0, 1.0, 2.0, 5.0, 2.0, 3.0]);
+    }
+}
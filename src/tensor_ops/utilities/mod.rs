@@ -3,8 +3,11 @@ pub(crate) mod cpu_kernels;
 #[cfg(feature = "cuda")]
 pub(crate) mod cuda_kernels;
 mod device;
+pub(crate) mod linalg;
 pub(crate) mod ops;
 pub(crate) mod reduction_utils;
+pub(crate) mod rng;
+pub(crate) mod special;
 
 pub use backward::Backward;
 pub use device::Device;
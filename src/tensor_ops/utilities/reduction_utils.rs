@@ -16,7 +16,8 @@ pub(crate) fn index_for_reductions<S: Shape, Ax: Axes>(
     let dims = shape.concrete();
     let mut new_shape: S::Concrete = Default::default();
     let mut new_strides: S::Concrete = Default::default();
-    let num_non_reduced_dims = S::NUM_DIMS - Ax::as_array().into_iter().count();
+    // saturating, as `()` is reduced along `Axis<0>` even though it has no dims
+    let num_non_reduced_dims = S::NUM_DIMS.saturating_sub(Ax::as_array().into_iter().count());
 
     let mut i_reduced = 0;
     let mut i_non_reduced = 0;
@@ -0,0 +1,54 @@
+//! A small counter based random number generator, mirrored by the `rng_*` functions in
+//! `cuda_utils.cuh`. Every output element (or row) gets its own stream derived from a
+//! single seed, so the sampling ops produce the same values on every device.
+
+/// A splitmix64 generator for a single stream of a seed.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct StreamRng {
+    state: u64,
+}
+
+impl StreamRng {
+    pub(crate) fn new(seed: u64, stream: u64) -> Self {
+        Self {
+            state: seed ^ stream.wrapping_mul(0xD2B74407B1CE6E93),
+        }
+    }
+
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+
+    /// A uniform value in `[0, 1)`.
+    pub(crate) fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_streams_are_independent() {
+        let mut a = StreamRng::new(0, 0);
+        let mut b = StreamRng::new(0, 1);
+        let mut c = StreamRng::new(0, 0);
+        for _ in 0..10 {
+            let x = a.next_u64();
+            assert_ne!(x, b.next_u64());
+            assert_eq!(x, c.next_u64());
+        }
+        let mut total = 0.0;
+        for _ in 0..1000 {
+            let u = a.next_f64();
+            assert!((0.0..1.0).contains(&u));
+            total += u;
+        }
+        assert!((total / 1000.0 - 0.5).abs() < 0.05);
+    }
+}
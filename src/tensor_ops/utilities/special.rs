@@ -0,0 +1,262 @@
+//! Host implementations of special functions that aren't provided by [num_traits::Float].
+//! These are evaluated in `f64`, and mirror the builtins that the cuda kernels call.
+
+use core::f64::consts::FRAC_2_SQRT_PI;
+#[allow(unused_imports)]
+use num_traits::Float;
+
+/// The [error function](https://en.wikipedia.org/wiki/Error_function).
+///
+/// Uses the everywhere positive series `erf(x) = 2/√π exp(-x²) Σ 2ⁿ x²ⁿ⁺¹ / (2n+1)!!`
+/// for `|x| < 3`, and the continued fraction of `erfc(x)` otherwise.
+pub(crate) fn erf(x: f64) -> f64 {
+    if x.is_nan() {
+        return x;
+    }
+    let ax = x.abs();
+    let y = if ax < 3.0 {
+        let x_sq = ax * ax;
+        let mut term = ax;
+        let mut sum = ax;
+        let mut n = 0.0;
+        while term > sum * f64::EPSILON {
+            n += 1.0;
+            term *= 2.0 * x_sq / (2.0 * n + 1.0);
+            sum += term;
+        }
+        FRAC_2_SQRT_PI * (-x_sq).exp() * sum
+    } else {
+        1.0 - erfc_cf(ax)
+    };
+    y.copysign(x)
+}
+
+/// `erfc(x)` for `x >= 3` through its continued fraction
+/// `exp(-x²)/√π · 1/(x + (1/2)/(x + 1/(x + (3/2)/(x + ...))))`.
+fn erfc_cf(x: f64) -> f64 {
+    let mut f = x;
+    for k in (1..=64).rev() {
+        f = x + (0.5 * k as f64) / f;
+    }
+    0.5 * FRAC_2_SQRT_PI * (-x * x).exp() / f
+}
+
+/// The inverse of [erf], defined on `[-1, 1]`.
+///
+/// Starts from Winitzki's closed form approximation, and refines it with newton steps.
+pub(crate) fn erfinv(y: f64) -> f64 {
+    if y.is_nan() || y.abs() > 1.0 {
+        return f64::NAN;
+    }
+    if y.abs() == 1.0 {
+        return f64::INFINITY.copysign(y);
+    }
+    if y == 0.0 {
+        return y;
+    }
+
+    const A: f64 = 0.147;
+    let ln = (1.0 - y * y).ln();
+    let b = 2.0 / (core::f64::consts::PI * A) + 0.5 * ln;
+    let mut x = ((b * b - ln / A).sqrt() - b).sqrt().copysign(y);
+
+    for _ in 0..8 {
+        let dx = (erf(x) - y) / (FRAC_2_SQRT_PI * (-x * x).exp());
+        x -= dx;
+        if dx.abs() <= f64::EPSILON * x.abs() {
+            break;
+        }
+    }
+    x
+}
+
+/// The natural log of the absolute value of the [gamma function](https://en.wikipedia.org/wiki/Gamma_function).
+///
+/// Uses the Lanczos approximation with `g = 7`, and the reflection formula for `x < 0.5`.
+/// The non positive integers are poles, where this returns `inf`.
+pub(crate) fn lgamma(x: f64) -> f64 {
+    const G: f64 = 7.0;
+    const P: [f64; 9] = [
+        0.999_999_999_999_809_9,
+        676.520_368_121_885_1,
+        -1_259.139_216_722_402_8,
+        771.323_428_777_653_1,
+        -176.615_029_162_140_6,
+        12.507_343_278_686_905,
+        -0.138_571_095_265_720_12,
+        9.984_369_578_019_572e-6,
+        1.505_632_735_149_311_6e-7,
+    ];
+
+    if x.is_nan() {
+        return x;
+    }
+    if x <= 0.0 && x == x.floor() {
+        return f64::INFINITY;
+    }
+    if x < 0.5 {
+        let s = (core::f64::consts::PI * x).sin().abs();
+        return (core::f64::consts::PI / s).ln() - lgamma(1.0 - x);
+    }
+
+    let x = x - 1.0;
+    let mut a = P[0];
+    for (i, p) in P.iter().enumerate().skip(1) {
+        a += p / (x + i as f64);
+    }
+    let t = x + G + 0.5;
+    0.5 * (2.0 * core::f64::consts::PI).ln() + (x + 0.5) * t.ln() - t + a.ln()
+}
+
+/// The [digamma function](https://en.wikipedia.org/wiki/Digamma_function), which is the
+/// derivative of [lgamma].
+///
+/// Shifts `x` above 10 with the recurrence `ψ(x) = ψ(x + 1) - 1/x` and uses the
+/// asymptotic series there, with the reflection formula for negative `x`.
+/// The non positive integers are poles, where this returns `nan`.
+pub(crate) fn digamma(x: f64) -> f64 {
+    if x.is_nan() || (x <= 0.0 && x == x.floor()) {
+        return f64::NAN;
+    }
+    if x < 0.0 {
+        let pi = core::f64::consts::PI;
+        return digamma(1.0 - x) - pi / (pi * x).tan();
+    }
+
+    let mut x = x;
+    let mut acc = 0.0;
+    while x < 10.0 {
+        acc -= 1.0 / x;
+        x += 1.0;
+    }
+    let inv_sq = 1.0 / (x * x);
+    let series = inv_sq
+        * (1.0 / 12.0
+            - inv_sq
+                * (1.0 / 120.0 - inv_sq * (1.0 / 252.0 - inv_sq * (1.0 / 240.0 - inv_sq / 132.0))));
+    acc + x.ln() - 0.5 / x - series
+}
+
+/// The [trigamma function](https://en.wikipedia.org/wiki/Trigamma_function), which is the
+/// derivative of [digamma].
+///
+/// Uses the same recurrence, asymptotic series and reflection as [digamma].
+pub(crate) fn trigamma(x: f64) -> f64 {
+    if x.is_nan() || (x <= 0.0 && x == x.floor()) {
+        return f64::NAN;
+    }
+    if x < 0.0 {
+        let pi = core::f64::consts::PI;
+        let s = (pi * x).sin();
+        return pi * pi / (s * s) - trigamma(1.0 - x);
+    }
+
+    let mut x = x;
+    let mut acc = 0.0;
+    while x < 10.0 {
+        acc += 1.0 / (x * x);
+        x += 1.0;
+    }
+    let inv = 1.0 / x;
+    let inv_sq = inv * inv;
+    let series = inv
+        + 0.5 * inv_sq
+        + inv
+            * inv_sq
+            * (1.0 / 6.0
+                - inv_sq
+                    * (1.0 / 30.0
+                        - inv_sq * (1.0 / 42.0 - inv_sq * (1.0 / 30.0 - inv_sq * (5.0 / 66.0)))));
+    acc + series
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_erf() {
+        let expected = [
+            (0.0, 0.0),
+            (1e-3, 1.1283787909692363e-3),
+            (0.5, 0.5204998778130465),
+            (1.0, 0.8427007929497149),
+            (2.5, 0.999593047982555),
+            (3.0, 0.9999779095030014),
+            (4.0, 0.9999999845827421),
+            (-2.0, -0.9953222650189527),
+        ];
+        for (x, y) in expected {
+            assert!((erf(x) - y).abs() < 1e-14, "erf({x}) = {}", erf(x));
+        }
+        assert_eq!(erf(10.0), 1.0);
+        assert_eq!(erf(f64::NEG_INFINITY), -1.0);
+    }
+
+    #[test]
+    fn test_erfinv() {
+        for y in [-0.999999, -0.9, -0.5, -1e-4, 1e-4, 0.3, 0.7, 0.99, 0.999999] {
+            assert!((erf(erfinv(y)) - y).abs() < 1e-14, "erfinv({y})");
+        }
+        assert!((erfinv(0.5) - 0.4769362762044699).abs() < 1e-14);
+        assert_eq!(erfinv(1.0), f64::INFINITY);
+        assert_eq!(erfinv(-1.0), f64::NEG_INFINITY);
+        assert!(erfinv(1.5).is_nan());
+    }
+
+    #[test]
+    fn test_lgamma() {
+        let expected = [
+            (1.0, 0.0),
+            (2.0, 0.0),
+            (0.5, 0.5723649429247001),
+            (3.7, 1.428072326665388),
+            (100.0, 359.1342053695754),
+            (1e-3, 6.907178885383854),
+            (-0.5, 1.2655121234846454),
+            (-2.3, 0.369566663455008),
+        ];
+        for (x, y) in expected {
+            assert!((lgamma(x) - y).abs() < 1e-12, "lgamma({x}) = {}", lgamma(x));
+        }
+        assert_eq!(lgamma(0.0), f64::INFINITY);
+        assert_eq!(lgamma(-3.0), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_digamma() {
+        let expected = [
+            (1.0, -0.5772156649015329),
+            (0.5, -1.9635100260214235),
+            (3.7, 1.1671535393615114),
+            (20.0, 2.970523992242149),
+            (-0.5, 0.03648997397857652),
+            (-2.3, 3.3173231575618227),
+        ];
+        for (x, y) in expected {
+            assert!(
+                (digamma(x) - y).abs() < 1e-12,
+                "digamma({x}) = {}",
+                digamma(x)
+            );
+        }
+        assert!(digamma(-1.0).is_nan());
+    }
+
+    #[test]
+    fn test_trigamma() {
+        let expected = [
+            (1.0, 1.6449340668482264),
+            (0.5, 4.934802200544679),
+            (3.7, 0.3100378576700383),
+            (-0.5, 8.934802200544679),
+        ];
+        for (x, y) in expected {
+            assert!(
+                (trigamma(x) - y).abs() < 1e-12,
+                "trigamma({x}) = {}",
+                trigamma(x)
+            );
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use crate::{
+    shapes::{Axes, Dtype, HasAxes, ReduceShapeTo, Shape},
+    tensor::cpu::{Cpu, StridedArray},
+    tensor_ops::utilities::reduction_utils::index_for_reductions,
+};
+
+impl<E: Dtype> super::VarKernel<E> for Cpu {
+    fn forward<Src: Shape + ReduceShapeTo<Dst, Ax>, Dst: Shape, Ax: Axes>(
+        &self,
+        dst: Dst,
+        inp: &Self::Storage<Src, E>,
+        denom: E,
+    ) -> Result<Self::Storage<Dst, E>, Self::Err> {
+        let mut out: StridedArray<Dst, E> = StridedArray::new(dst)?;
+        let num_elems_reduced = <Src as HasAxes<Ax>>::size(&inp.shape);
+        let inp_buf = inp.data.as_ref();
+        let mut idx = index_for_reductions::<Src, Ax>(inp.shape, inp.strides);
+        for o in out.buf_iter_mut() {
+            // Welford's online algorithm, which doesn't lose precision when the
+            // mean is large compared to the variance.
+            let mut count = E::default();
+            let mut mean = E::default();
+            let mut m2 = E::default();
+            for _ in 0..num_elems_reduced {
+                let x = inp_buf[idx.next().unwrap()];
+                count += E::ONE;
+                let delta = x - mean;
+                mean += delta / count;
+                m2 += delta * (x - mean);
+            }
+            *o = m2 / denom;
+        }
+        Ok(out)
+    }
+
+    fn backward<Src: Shape + ReduceShapeTo<Dst, Ax>, Dst: Shape, Ax: Axes>(
+        &self,
+        inp: &Self::Storage<Src, E>,
+        grad_inp: &mut Self::Storage<Src, E>,
+        grad_out: &Self::Storage<Dst, E>,
+        denom: E,
+    ) -> Result<(), Self::Err> {
+        let num_elems_reduced = <Src as HasAxes<Ax>>::size(&inp.shape);
+        let n = E::from_usize(num_elems_reduced).unwrap();
+        let two = E::ONE + E::ONE;
+        let inp_buf = inp.data.as_ref();
+        let grad_inp_buf = std::sync::Arc::make_mut(&mut grad_inp.data);
+        let mut mean_idx = index_for_reductions::<Src, Ax>(inp.shape, inp.strides);
+        let mut inp_idx = index_for_reductions::<Src, Ax>(inp.shape, inp.strides);
+        let mut grad_idx = index_for_reductions::<Src, Ax>(grad_inp.shape, grad_inp.strides);
+        for &g in grad_out.buf_iter() {
+            let mut mean = E::default();
+            for _ in 0..num_elems_reduced {
+                mean += inp_buf[mean_idx.next().unwrap()];
+            }
+            mean /= n;
+            let scale = two * g / denom;
+            for _ in 0..num_elems_reduced {
+                let x = inp_buf[inp_idx.next().unwrap()];
+                grad_inp_buf[grad_idx.next().unwrap()] += scale * (x - mean);
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,118 @@
+use crate::{
+    shapes::*,
+    tensor::cuda::{Cuda, CudaArray},
+    tensor_ops::cuda_kernels::packed_info,
+};
+use cudarc::driver::{AsKernelParam, CudaSlice, LaunchAsync, LaunchConfig};
+use std::{sync::Arc, vec::Vec};
+
+const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/var_to.ptx"));
+
+trait HasCudaKernel<E> {
+    const MOD: &'static str;
+    const FNS: &'static [&'static str];
+}
+
+impl HasCudaKernel<f32> for Cuda {
+    const MOD: &'static str = "var_to_f32";
+    const FNS: &'static [&'static str] = &["var_to_fwd_f32", "var_to_bwd_f32"];
+}
+
+impl HasCudaKernel<f64> for Cuda {
+    const MOD: &'static str = "var_to_f64";
+    const FNS: &'static [&'static str] = &["var_to_fwd_f64", "var_to_bwd_f64"];
+}
+
+/// Moves the reduced axes to the end, so each output element reduces a
+/// contiguous chunk of logical indices
+fn reduced_last<S: Shape, Ax: Axes>(shape: &S, strides: S::Concrete) -> (Vec<usize>, Vec<usize>) {
+    let mut axes: Vec<(bool, (usize, usize))> = shape
+        .concrete()
+        .into_iter()
+        .zip(strides.into_iter())
+        .map(|x| (false, x))
+        .collect();
+    for i in Ax::as_array().into_iter() {
+        axes[i as usize].0 = true;
+    }
+    axes.sort_by_key(|x| x.0);
+    axes.into_iter().map(|(_, x)| x).unzip()
+}
+
+impl<E: Dtype + AsKernelParam> super::VarKernel<E> for Cuda
+where
+    Self: HasCudaKernel<E>,
+{
+    fn forward<Src: Shape + ReduceShapeTo<Dst, Ax>, Dst: Shape, Ax: Axes>(
+        &self,
+        dst: Dst,
+        inp: &Self::Storage<Src, E>,
+        denom: E,
+    ) -> Result<Self::Storage<Dst, E>, Self::Err> {
+        if !self.dev.has_func(Self::MOD, Self::FNS[0]) {
+            self.dev.load_ptx(PTX_SRC.into(), Self::MOD, Self::FNS)?;
+        }
+
+        let (dims, strides) = reduced_last::<Src, Ax>(&inp.shape, inp.strides);
+        let numel = dst.num_elements();
+        let chunk_len = <Src as HasAxes<Ax>>::size(&inp.shape);
+        let dims: CudaSlice<usize> = self.dev.take_async(dims)?;
+        let strides: CudaSlice<usize> = self.dev.take_async(strides)?;
+        let mut storage = unsafe { self.dev.alloc_async::<E>(numel) }?;
+
+        let fwd_fn = self.dev.get_func(Self::MOD, Self::FNS[0]).unwrap();
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            numel,             // const size_t numel,
+            chunk_len,         // const size_t chunk_len,
+            Src::NUM_DIMS,     // const size_t num_dims,
+            &dims,             // const size_t *dims,
+            &strides,          // const size_t *strides,
+            denom,             // const T denom,
+            inp.data.as_ref(), // const T *inp,
+            &mut storage,      // T *out
+        );
+        unsafe { fwd_fn.launch_async(cfg, params) }?;
+        Ok(CudaArray {
+            data: Arc::new(storage),
+            shape: dst,
+            strides: dst.strides(),
+        })
+    }
+
+    fn backward<Src: Shape + ReduceShapeTo<Dst, Ax>, Dst: Shape, Ax: Axes>(
+        &self,
+        inp: &Self::Storage<Src, E>,
+        grad_inp: &mut Self::Storage<Src, E>,
+        grad_out: &Self::Storage<Dst, E>,
+        denom: E,
+    ) -> Result<(), Self::Err> {
+        let (dims, inp_strides) = reduced_last::<Src, Ax>(&inp.shape, inp.strides);
+        let (_, grad_inp_strides) = reduced_last::<Src, Ax>(&grad_inp.shape, grad_inp.strides);
+        let numel = grad_out.shape.num_elements();
+        let chunk_len = <Src as HasAxes<Ax>>::size(&inp.shape);
+        let dims: CudaSlice<usize> = self.dev.take_async(dims)?;
+        let inp_strides: CudaSlice<usize> = self.dev.take_async(inp_strides)?;
+        let grad_inp_strides: CudaSlice<usize> = self.dev.take_async(grad_inp_strides)?;
+        let out_info: CudaSlice<usize> = self.dev.take_async(packed_info(grad_out))?;
+
+        let bwd_fn = self.dev.get_func(Self::MOD, Self::FNS[1]).unwrap();
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            numel,                             // const size_t numel,
+            chunk_len,                         // const size_t chunk_len,
+            Src::NUM_DIMS,                     // const size_t num_dims,
+            &dims,                             // const size_t *dims,
+            &inp_strides,                      // const size_t *inp_strides,
+            &grad_inp_strides,                 // const size_t *grad_inp_strides,
+            Dst::NUM_DIMS,                     // const size_t out_num_dims,
+            &out_info,                         // const size_t *out_info,
+            denom,                             // const T denom,
+            inp.data.as_ref(),                 // const T *inp,
+            Arc::make_mut(&mut grad_inp.data), // T *grad_inp,
+            grad_out.data.as_ref(),            // const T *grad_out
+        );
+        unsafe { bwd_fn.launch_async(cfg, params) }?;
+        Ok(())
+    }
+}
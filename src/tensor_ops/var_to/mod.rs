@@ -0,0 +1,198 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{gradients::Tape, shapes::*, tensor::*};
+
+pub trait VarKernel<E: Dtype>: DeviceStorage {
+    /// `denom` is the number of reduced elements minus the correction.
+    fn forward<Src: Shape + ReduceShapeTo<Dst, Ax>, Dst: Shape, Ax: Axes>(
+        &self,
+        dst: Dst,
+        inp: &Self::Storage<Src, E>,
+        denom: E,
+    ) -> Result<Self::Storage<Dst, E>, Self::Err>;
+    fn backward<Src: Shape + ReduceShapeTo<Dst, Ax>, Dst: Shape, Ax: Axes>(
+        &self,
+        inp: &Self::Storage<Src, E>,
+        grad_inp: &mut Self::Storage<Src, E>,
+        grad_out: &Self::Storage<Dst, E>,
+        denom: E,
+    ) -> Result<(), Self::Err>;
+}
+
+/// Reduction alogn multiple axes using variance
+pub trait VarTo: HasErr + HasShape {
+    /// Result [Tensor] has smaller number of dimensions.
+    ///
+    /// **Pytorch equivalent**: `t.var(Axes, unbiased=False)`
+    ///
+    /// Examples:
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t = dev.tensor([[2.0f32, 3.0, 4.0], [3.0, 6.0, 9.0]]);
+    /// let r = t.var::<Rank1<2>, _>(); // or `var::<_, Axis<1>>()`
+    /// assert_eq!(r.array(), [0.6666667, 6.0]);
+    /// ```
+    fn var<Dst: Shape, Ax: Axes>(self) -> Self::WithShape<Dst>
+    where
+        Self::Shape: HasAxes<Ax> + ReduceShapeTo<Dst, Ax>,
+    {
+        self.try_var().unwrap()
+    }
+    /// Fallible version of [VarTo::var]
+    fn try_var<Dst: Shape, Ax: Axes>(self) -> Result<Self::WithShape<Dst>, Self::Err>
+    where
+        Self::Shape: HasAxes<Ax> + ReduceShapeTo<Dst, Ax>,
+    {
+        self.try_var_along(0)
+    }
+
+    /// Variance that divides by `N - correction`, where `N` is the number of
+    /// elements reduced. `correction = 1` is the unbiased (Bessel corrected) estimate,
+    /// and `correction = 0` is the same as [VarTo::var].
+    ///
+    /// If `N <= correction` the result is `inf` (or `NaN` if all elements are equal).
+    ///
+    /// **Pytorch equivalent**: `t.var(Axes, correction=correction)`
+    ///
+    /// Examples:
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t = dev.tensor([[2.0f32, 3.0, 4.0], [3.0, 6.0, 9.0]]);
+    /// let r = t.var_along::<Rank1<2>, _>(1); // or `var_along::<_, Axis<1>>(1)`
+    /// assert_eq!(r.array(), [1.0, 9.0]);
+    /// ```
+    fn var_along<Dst: Shape, Ax: Axes>(self, correction: usize) -> Self::WithShape<Dst>
+    where
+        Self::Shape: HasAxes<Ax> + ReduceShapeTo<Dst, Ax>,
+    {
+        self.try_var_along(correction).unwrap()
+    }
+    /// Fallible version of [VarTo::var_along]
+    fn try_var_along<Dst: Shape, Ax: Axes>(
+        self,
+        correction: usize,
+    ) -> Result<Self::WithShape<Dst>, Self::Err>
+    where
+        Self::Shape: HasAxes<Ax> + ReduceShapeTo<Dst, Ax>;
+}
+
+impl<S: Shape, E: Dtype, D: VarKernel<E>, T: Tape<D>> VarTo for Tensor<S, E, D, T> {
+    fn try_var_along<Dst: Shape, Ax: Axes>(
+        self,
+        correction: usize,
+    ) -> Result<Self::WithShape<Dst>, Self::Err>
+    where
+        Self::Shape: HasAxes<Ax> + ReduceShapeTo<Dst, Ax>,
+    {
+        let num_elems_reduced = <S as HasAxes<Ax>>::size(self.shape());
+        let denom = E::from_usize(num_elems_reduced.saturating_sub(correction)).unwrap();
+        let dst: Dst = self.shape().reduced();
+        let (inp, mut tape) = self.split_tape();
+        let out = inp
+            .device
+            .upgrade(inp.device.forward(dst, &inp.storage, denom)?);
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&inp)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+            inp.device.backward(&inp.storage, grad_inp, grad_out, denom)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{gradients::OwnedTape, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_var_axis_0_2d() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([[1.0, 2.0, 3.0, 4.0], [0.0, 2.0, 5.0, 10.0]]);
+        let r = t.trace().var::<Rank1<4>, _>();
+        assert_eq!(r.array(), [0.25, 0.0, 1.0, 9.0]);
+        let g = r.mean().backward();
+        assert_eq!(
+            g.get(&t).array(),
+            [[0.125, 0.0, -0.25, -0.75], [-0.125, 0.0, 0.25, 0.75]]
+        );
+    }
+
+    #[test]
+    fn test_var_axis_1_2d() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([[1.0, 2.0, 3.0, 4.0], [0.0, 2.0, 5.0, 10.0]]);
+        let r = t.trace().var::<Rank1<2>, _>();
+        assert_eq!(r.array(), [1.25, 14.1875]);
+        let g = r.mean().backward();
+        assert_eq!(
+            g.get(&t).array(),
+            [
+                [-0.375, -0.125, 0.125, 0.375],
+                [-1.0625, -0.5625, 0.1875, 1.4375]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_var_along_correction() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([[1.0, 2.0, 3.0, 4.0], [0.0, 2.0, 5.0, 10.0]]);
+        let r = t.trace().var_along::<Rank1<2>, _>(1);
+        assert_close(&r.array(), &[1.6666666, 18.916666]);
+        let g = r.mean().backward();
+        assert_close(
+            &g.get(&t).array(),
+            &[
+                [-0.5, -0.16666667, 0.16666667, 0.5],
+                [-1.4166666, -0.75, 0.25, 1.9166666],
+            ],
+        );
+    }
+
+    #[test]
+    fn test_var_along_multiple_axes() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank3<2, 3, 4>, TestDtype, _> = dev.sample_normal();
+        let r = t.trace().var_along::<Rank1<3>, Axes2<0, 2>>(1);
+        let x = t.trace();
+        let mean = x
+            .retaped::<OwnedTape<_>>()
+            .mean::<Rank1<3>, Axes2<0, 2>>()
+            .broadcast::<Rank3<2, 3, 4>, _>();
+        let r2 = (x - mean).square().sum::<Rank1<3>, Axes2<0, 2>>() / 7.0;
+        assert_close(&r.array(), &r2.array());
+
+        let w: Tensor<Rank1<3>, TestDtype, _> = dev.sample_normal();
+        let g = (r * w.clone()).sum().backward();
+        let g2 = (r2 * w).sum().backward();
+        assert_close(&g.get(&t).array(), &g2.get(&t).array());
+    }
+
+    #[test]
+    fn test_var_along_broadcasted() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([1.0, 2.0, 6.0]);
+        let r = t
+            .trace()
+            .broadcast::<Rank2<2, 3>, _>()
+            .var_along::<Rank0, _>(1);
+        assert_close(&r.array(), &5.6);
+        let g = r.backward();
+        assert_close(&g.get(&t).array(), &[-1.6, -0.8, 2.4]);
+    }
+
+    #[test]
+    fn test_var_along_large_offset() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([1e4 + 4.0, 1e4 + 7.0, 1e4 + 13.0, 1e4 + 16.0]);
+        assert_close(&t.var_along::<Rank0, _>(1).array(), &30.0);
+    }
+}
@@ -0,0 +1,108 @@
+#include "cuda_utils.cuh"
+
+// Each thread reduces the `chunk_len` elements of one output element. The reduced
+// axes are the last dims, so they are the logical indices
+// [i * chunk_len, (i + 1) * chunk_len).
+template<typename T>
+__device__ T chunk_mean(
+    const size_t i,
+    const size_t chunk_len,
+    const size_t num_dims,
+    const size_t *dims,
+    const size_t *strides,
+    const T *inp
+) {
+    T sum = 0.0;
+    for (size_t j = i * chunk_len; j < (i + 1) * chunk_len; j++) {
+        sum += inp[get_strided_index(j, num_dims, dims, strides)];
+    }
+    return sum / chunk_len;
+}
+
+template<typename T>
+__device__ void var_to_fwd(
+    const size_t numel,
+    const size_t chunk_len,
+    const size_t num_dims,
+    const size_t *dims,
+    const size_t *strides,
+    const T denom,
+    const T *inp,
+    T *out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    // two passes: the mean first, then the squared deviations from it
+    T mean = chunk_mean(i, chunk_len, num_dims, dims, strides, inp);
+    T m2 = 0.0;
+    for (size_t j = i * chunk_len; j < (i + 1) * chunk_len; j++) {
+        T delta = inp[get_strided_index(j, num_dims, dims, strides)] - mean;
+        m2 += delta * delta;
+    }
+    out[i] = m2 / denom;
+}
+
+template<typename T>
+__device__ void var_to_bwd(
+    const size_t numel,
+    const size_t chunk_len,
+    const size_t num_dims,
+    const size_t *dims,
+    const size_t *inp_strides,
+    const size_t *grad_inp_strides,
+    const size_t out_num_dims,
+    const size_t *out_info,
+    const T denom,
+    const T *inp,
+    T *grad_inp,
+    const T *grad_out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    T mean = chunk_mean(i, chunk_len, num_dims, dims, inp_strides, inp);
+    T scale = 2.0 * grad_out[get_strided_index(i, out_num_dims, out_info, out_info + out_num_dims)] / denom;
+    for (size_t j = i * chunk_len; j < (i + 1) * chunk_len; j++) {
+        T x = inp[get_strided_index(j, num_dims, dims, inp_strides)];
+        // broadcasted inputs can share a gradient with other output elements
+        atomicAdd(grad_inp + get_strided_index(j, num_dims, dims, grad_inp_strides), scale * (x - mean));
+    }
+}
+
+#define VAR_TO(TYPENAME, FWD, BWD) \
+extern "C" __global__ void FWD( \
+    const size_t numel, \
+    const size_t chunk_len, \
+    const size_t num_dims, \
+    const size_t *dims, \
+    const size_t *strides, \
+    const TYPENAME denom, \
+    const TYPENAME *inp, \
+    TYPENAME *out \
+) { \
+    var_to_fwd(numel, chunk_len, num_dims, dims, strides, denom, inp, out); \
+} \
+extern "C" __global__ void BWD( \
+    const size_t numel, \
+    const size_t chunk_len, \
+    const size_t num_dims, \
+    const size_t *dims, \
+    const size_t *inp_strides, \
+    const size_t *grad_inp_strides, \
+    const size_t out_num_dims, \
+    const size_t *out_info, \
+    const TYPENAME denom, \
+    const TYPENAME *inp, \
+    TYPENAME *grad_inp, \
+    const TYPENAME *grad_out \
+) { \
+    var_to_bwd(numel, chunk_len, num_dims, dims, inp_strides, grad_inp_strides, out_num_dims, out_info, denom, inp, grad_inp, grad_out); \
+}
+
+VAR_TO(float, var_to_fwd_f32, var_to_bwd_f32);
+VAR_TO(double, var_to_fwd_f64, var_to_bwd_f64);
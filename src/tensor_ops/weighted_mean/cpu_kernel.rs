@@ -0,0 +1,64 @@
+use crate::{
+    shapes::{Axes, Dtype, HasAxes, ReduceShapeTo, Shape},
+    tensor::cpu::{Cpu, StridedArray},
+    tensor_ops::utilities::reduction_utils::index_for_reductions,
+};
+
+impl<E: Dtype> super::WeightedMeanKernel<E> for Cpu {
+    fn forward<Src: Shape + ReduceShapeTo<Dst, Ax>, Dst: Shape, Ax: Axes>(
+        &self,
+        dst: Dst,
+        inp: &Self::Storage<Src, E>,
+        weights: &Self::Storage<Src, E>,
+    ) -> Result<Self::Storage<Dst, E>, Self::Err> {
+        let mut out: StridedArray<Dst, E> = StridedArray::new(dst)?;
+        let num_elems_reduced = <Src as HasAxes<Ax>>::size(&inp.shape);
+        let mut inp_idx = index_for_reductions::<Src, Ax>(inp.shape, inp.strides);
+        let mut w_idx = index_for_reductions::<Src, Ax>(weights.shape, weights.strides);
+        for o in out.buf_iter_mut() {
+            let mut num = E::default();
+            let mut den = E::default();
+            for _ in 0..num_elems_reduced {
+                let x = inp.data[inp_idx.next().unwrap()];
+                let w = weights.data[w_idx.next().unwrap()];
+                num += w * x;
+                den += w;
+            }
+            *o = num / den;
+        }
+        Ok(out)
+    }
+
+    fn backward<Src: Shape + ReduceShapeTo<Dst, Ax>, Dst: Shape, Ax: Axes>(
+        &self,
+        inp: &Self::Storage<Src, E>,
+        grad_inp: &mut Self::Storage<Src, E>,
+        weights: &Self::Storage<Src, E>,
+        grad_weights: &mut Self::Storage<Src, E>,
+        out: &Self::Storage<Dst, E>,
+        grad_out: &Self::Storage<Dst, E>,
+    ) -> Result<(), Self::Err> {
+        let num_elems_reduced = <Src as HasAxes<Ax>>::size(&inp.shape);
+        let grad_inp_buf = std::sync::Arc::make_mut(&mut grad_inp.data);
+        let grad_w_buf = std::sync::Arc::make_mut(&mut grad_weights.data);
+        let mut den_idx = index_for_reductions::<Src, Ax>(weights.shape, weights.strides);
+        let mut inp_idx = index_for_reductions::<Src, Ax>(inp.shape, inp.strides);
+        let mut w_idx = index_for_reductions::<Src, Ax>(weights.shape, weights.strides);
+        let mut gi_idx = index_for_reductions::<Src, Ax>(grad_inp.shape, grad_inp.strides);
+        let mut gw_idx = index_for_reductions::<Src, Ax>(grad_weights.shape, grad_weights.strides);
+        for (&o, &g) in out.buf_iter().zip(grad_out.buf_iter()) {
+            let mut den = E::default();
+            for _ in 0..num_elems_reduced {
+                den += weights.data[den_idx.next().unwrap()];
+            }
+            let g = g / den;
+            for _ in 0..num_elems_reduced {
+                let x = inp.data[inp_idx.next().unwrap()];
+                let w = weights.data[w_idx.next().unwrap()];
+                grad_inp_buf[gi_idx.next().unwrap()] += g * w;
+                grad_w_buf[gw_idx.next().unwrap()] += g * (x - o);
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,122 @@
+use crate::{
+    shapes::*,
+    tensor::cuda::{Cuda, CudaArray},
+};
+use cudarc::driver::{CudaSlice, LaunchAsync, LaunchConfig};
+use std::{sync::Arc, vec::Vec};
+
+const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/weighted_mean.ptx"));
+
+trait HasCudaKernel<E> {
+    const MOD: &'static str;
+    const FNS: &'static [&'static str];
+}
+
+impl HasCudaKernel<f32> for Cuda {
+    const MOD: &'static str = "weighted_mean_f32";
+    const FNS: &'static [&'static str] = &["weighted_mean_fwd_f32", "weighted_mean_bwd_f32"];
+}
+
+impl HasCudaKernel<f64> for Cuda {
+    const MOD: &'static str = "weighted_mean_f64";
+    const FNS: &'static [&'static str] = &["weighted_mean_fwd_f64", "weighted_mean_bwd_f64"];
+}
+
+/// Moves the reduced axes to the end, so each output element reduces a
+/// contiguous chunk of logical indices
+fn reduced_last<S: Shape, Ax: Axes>(shape: &S, strides: S::Concrete) -> (Vec<usize>, Vec<usize>) {
+    let mut axes: Vec<(bool, (usize, usize))> = shape
+        .concrete()
+        .into_iter()
+        .zip(strides.into_iter())
+        .map(|x| (false, x))
+        .collect();
+    for i in Ax::as_array().into_iter() {
+        axes[i as usize].0 = true;
+    }
+    axes.sort_by_key(|x| x.0);
+    axes.into_iter().map(|(_, x)| x).unzip()
+}
+
+impl<E: Dtype> super::WeightedMeanKernel<E> for Cuda
+where
+    Self: HasCudaKernel<E>,
+{
+    fn forward<Src: Shape + ReduceShapeTo<Dst, Ax>, Dst: Shape, Ax: Axes>(
+        &self,
+        dst: Dst,
+        inp: &Self::Storage<Src, E>,
+        weights: &Self::Storage<Src, E>,
+    ) -> Result<Self::Storage<Dst, E>, Self::Err> {
+        if !self.dev.has_func(Self::MOD, Self::FNS[0]) {
+            self.dev.load_ptx(PTX_SRC.into(), Self::MOD, Self::FNS)?;
+        }
+
+        let (dims, inp_strides) = reduced_last::<Src, Ax>(&inp.shape, inp.strides);
+        let (_, w_strides) = reduced_last::<Src, Ax>(&weights.shape, weights.strides);
+        let numel = dst.num_elements();
+        let chunk_len = <Src as HasAxes<Ax>>::size(&inp.shape);
+        let dims: CudaSlice<usize> = self.dev.take_async(dims)?;
+        let inp_strides: CudaSlice<usize> = self.dev.take_async(inp_strides)?;
+        let w_strides: CudaSlice<usize> = self.dev.take_async(w_strides)?;
+        let mut storage = unsafe { self.dev.alloc_async::<E>(numel) }?;
+
+        let fwd_fn = self.dev.get_func(Self::MOD, Self::FNS[0]).unwrap();
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            numel,                 // const size_t numel,
+            chunk_len,             // const size_t chunk_len,
+            Src::NUM_DIMS,         // const size_t num_dims,
+            &dims,                 // const size_t *dims,
+            &inp_strides,          // const size_t *inp_strides,
+            &w_strides,            // const size_t *w_strides,
+            inp.data.as_ref(),     // const T *inp,
+            weights.data.as_ref(), // const T *weights,
+            &mut storage,          // T *out
+        );
+        unsafe { fwd_fn.launch_async(cfg, params) }?;
+        Ok(CudaArray {
+            data: Arc::new(storage),
+            shape: dst,
+            strides: dst.strides(),
+        })
+    }
+
+    fn backward<Src: Shape + ReduceShapeTo<Dst, Ax>, Dst: Shape, Ax: Axes>(
+        &self,
+        inp: &Self::Storage<Src, E>,
+        grad_inp: &mut Self::Storage<Src, E>,
+        weights: &Self::Storage<Src, E>,
+        grad_weights: &mut Self::Storage<Src, E>,
+        out: &Self::Storage<Dst, E>,
+        grad_out: &Self::Storage<Dst, E>,
+    ) -> Result<(), Self::Err> {
+        let (dims, inp_strides) = reduced_last::<Src, Ax>(&inp.shape, inp.strides);
+        let (_, w_strides) = reduced_last::<Src, Ax>(&weights.shape, weights.strides);
+        let (_, gi_strides) = reduced_last::<Src, Ax>(&grad_inp.shape, grad_inp.strides);
+        let (_, gw_strides) = reduced_last::<Src, Ax>(&grad_weights.shape, grad_weights.strides);
+        let numel = grad_out.shape.num_elements();
+        let chunk_len = <Src as HasAxes<Ax>>::size(&inp.shape);
+        let dims: CudaSlice<usize> = self.dev.take_async(dims)?;
+        let strides = [inp_strides, w_strides, gi_strides, gw_strides].concat();
+        let strides: CudaSlice<usize> = self.dev.take_async(strides)?;
+
+        let bwd_fn = self.dev.get_func(Self::MOD, Self::FNS[1]).unwrap();
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            numel,                                 // const size_t numel,
+            chunk_len,                             // const size_t chunk_len,
+            Src::NUM_DIMS,                         // const size_t num_dims,
+            &dims,                                 // const size_t *dims,
+            &strides,                              // const size_t *strides,
+            inp.data.as_ref(),                     // const T *inp,
+            Arc::make_mut(&mut grad_inp.data),     // T *grad_inp,
+            weights.data.as_ref(),                 // const T *weights,
+            Arc::make_mut(&mut grad_weights.data), // T *grad_weights,
+            out.data.as_ref(),                     // const T *out,
+            grad_out.data.as_ref(),                // const T *grad_out
+        );
+        unsafe { bwd_fn.launch_async(cfg, params) }?;
+        Ok(())
+    }
+}
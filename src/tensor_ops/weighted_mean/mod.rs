@@ -0,0 +1,152 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{gradients::*, shapes::*, tensor::*};
+
+pub trait WeightedMeanKernel<E: Dtype>: DeviceStorage {
+    fn forward<Src: Shape + ReduceShapeTo<Dst, Ax>, Dst: Shape, Ax: Axes>(
+        &self,
+        dst: Dst,
+        inp: &Self::Storage<Src, E>,
+        weights: &Self::Storage<Src, E>,
+    ) -> Result<Self::Storage<Dst, E>, Self::Err>;
+
+    #[allow(clippy::too_many_arguments)]
+    fn backward<Src: Shape + ReduceShapeTo<Dst, Ax>, Dst: Shape, Ax: Axes>(
+        &self,
+        inp: &Self::Storage<Src, E>,
+        grad_inp: &mut Self::Storage<Src, E>,
+        weights: &Self::Storage<Src, E>,
+        grad_weights: &mut Self::Storage<Src, E>,
+        out: &Self::Storage<Dst, E>,
+        grad_out: &Self::Storage<Dst, E>,
+    ) -> Result<(), Self::Err>;
+}
+
+/// Reduction along multiple axes using a weighted mean, `sum(w * x) / sum(w)`.
+pub trait WeightedMeanTo<W>: HasErr + HasShape {
+    /// Weighted mean reduction in a single kernel. `weights` must have the same shape
+    /// as `self`, and gradients flow into both.
+    ///
+    /// Using a 0/1 mask as the weights gives the mean of the unmasked elements, e.g.
+    /// for a loss over padded sequences. If all the weights of a slice are zero
+    /// the result is `NaN`.
+    ///
+    /// **Pytorch equivalent**: `(t * w).sum(Ax) / w.sum(Ax)`
+    ///
+    /// Example:
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t = dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    /// let w = dev.tensor([[1.0, 1.0, 0.0], [1.0, 0.0, 3.0]]);
+    /// let r = t.weighted_mean::<Rank1<2>, _>(w); // or `weighted_mean::<_, Axis<1>>(w)`
+    /// assert_eq!(r.array(), [1.5, 5.5]);
+    /// ```
+    fn weighted_mean<Dst: Shape, Ax: Axes>(self, weights: W) -> Self::WithShape<Dst>
+    where
+        Self::Shape: ReduceShapeTo<Dst, Ax>,
+    {
+        self.try_weighted_mean(weights).unwrap()
+    }
+    /// Fallible version of [WeightedMeanTo::weighted_mean]
+    fn try_weighted_mean<Dst: Shape, Ax: Axes>(
+        self,
+        weights: W,
+    ) -> Result<Self::WithShape<Dst>, Self::Err>
+    where
+        Self::Shape: ReduceShapeTo<Dst, Ax>;
+}
+
+impl<S: Shape, E: Dtype, D: WeightedMeanKernel<E>, T, R> WeightedMeanTo<Tensor<S, E, D, R>>
+    for Tensor<S, E, D, T>
+where
+    T: Tape<D> + Merge<R>,
+    R: Tape<D>,
+{
+    fn try_weighted_mean<Dst: Shape, Ax: Axes>(
+        self,
+        weights: Tensor<S, E, D, R>,
+    ) -> Result<Self::WithShape<Dst>, Self::Err>
+    where
+        S: ReduceShapeTo<Dst, Ax>,
+    {
+        assert_eq!(self.shape(), weights.shape());
+        let dst: Dst = self.shape().reduced();
+        let (inp, tape) = self.split_tape();
+        let (weights, weights_tape) = weights.split_tape();
+        let mut tape = tape.merge(weights_tape);
+        let storage = inp.device.forward(dst, &inp.storage, &weights.storage)?;
+        let out = inp.device.upgrade(storage);
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&inp)?;
+        tape.try_alloc_grad(&weights)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_inp, grad_weights, grad_out) =
+                grads.muts_and_ref(&inp, &weights, &phantom_out);
+            inp.device.backward(
+                &inp.storage,
+                grad_inp,
+                &weights.storage,
+                grad_weights,
+                &phantom_out.storage,
+                grad_out,
+            )
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_weighted_mean_axis_1() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let w: Tensor<_, TestDtype, _> = dev.tensor([[1.0, 1.0, 0.0], [1.0, 0.0, 3.0]]);
+        let r = t.trace().weighted_mean::<Rank1<2>, _>(w.trace());
+        assert_eq!(r.array(), [1.5, 5.5]);
+        let g = r.square().sum().backward();
+        assert_eq!(g.get(&t).array(), [[1.5, 1.5, 0.0], [2.75, 0.0, 8.25]]);
+        assert_eq!(
+            g.get(&w).array(),
+            [[-0.75, 0.75, 2.25], [-4.125, -1.375, 1.375]]
+        );
+    }
+
+    #[test]
+    fn test_weighted_mean_matches_composition() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank3<2, 3, 4>, TestDtype, _> = dev.sample_normal();
+        let w: Tensor<Rank3<2, 3, 4>, TestDtype, _> = dev.sample_uniform();
+        let r = t.trace().weighted_mean::<Rank1<3>, Axes2<0, 2>>(w.trace());
+        let r2 = (t.trace() * w.trace()).sum::<Rank1<3>, Axes2<0, 2>>()
+            / w.trace().sum::<Rank1<3>, Axes2<0, 2>>();
+        assert_close(&r.array(), &r2.array());
+
+        let g = r.exp().sum().backward();
+        let g2 = r2.exp().sum().backward();
+        assert_close(&g.get(&t).array(), &g2.get(&t).array());
+        assert_close(&g.get(&w).array(), &g2.get(&w).array());
+    }
+
+    #[test]
+    fn test_weighted_mean_broadcasted_mask() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, TestDtype, _> = dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let mask: Tensor<_, TestDtype, _> = dev.tensor([1.0, 1.0, 0.0]);
+        let r = t
+            .trace()
+            .weighted_mean::<Rank0, _>(mask.trace().broadcast());
+        assert_eq!(r.array(), 3.0);
+        let g = r.backward();
+        assert_eq!(g.get(&t).array(), [[0.25, 0.25, 0.0], [0.25, 0.25, 0.0]]);
+        assert_eq!(g.get(&mask).array(), [-0.25, 0.25, 0.75]);
+    }
+}
@@ -0,0 +1,104 @@
+#include "cuda_utils.cuh"
+
+// Each thread reduces the `chunk_len` elements of one output element. The reduced
+// axes are the last dims, so they are the logical indices
+// [i * chunk_len, (i + 1) * chunk_len).
+template<typename T>
+__device__ void weighted_mean_fwd(
+    const size_t numel,
+    const size_t chunk_len,
+    const size_t num_dims,
+    const size_t *dims,
+    const size_t *inp_strides,
+    const size_t *w_strides,
+    const T *inp,
+    const T *weights,
+    T *out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    T num = 0.0;
+    T den = 0.0;
+    for (size_t j = i * chunk_len; j < (i + 1) * chunk_len; j++) {
+        T w = weights[get_strided_index(j, num_dims, dims, w_strides)];
+        num += w * inp[get_strided_index(j, num_dims, dims, inp_strides)];
+        den += w;
+    }
+    out[i] = num / den;
+}
+
+template<typename T>
+__device__ void weighted_mean_bwd(
+    const size_t numel,
+    const size_t chunk_len,
+    const size_t num_dims,
+    const size_t *dims,
+    const size_t *strides,
+    const T *inp,
+    T *grad_inp,
+    const T *weights,
+    T *grad_weights,
+    const T *out,
+    const T *grad_out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    // the strides of inp, weights, grad_inp and grad_weights, one after another
+    const size_t *inp_strides = strides;
+    const size_t *w_strides = strides + num_dims;
+    const size_t *grad_inp_strides = strides + 2 * num_dims;
+    const size_t *grad_w_strides = strides + 3 * num_dims;
+
+    T den = 0.0;
+    for (size_t j = i * chunk_len; j < (i + 1) * chunk_len; j++) {
+        den += weights[get_strided_index(j, num_dims, dims, w_strides)];
+    }
+    T g = grad_out[i] / den;
+    T o = out[i];
+    for (size_t j = i * chunk_len; j < (i + 1) * chunk_len; j++) {
+        T x = inp[get_strided_index(j, num_dims, dims, inp_strides)];
+        T w = weights[get_strided_index(j, num_dims, dims, w_strides)];
+        // broadcasted inputs can share a gradient with other output elements
+        atomicAdd(grad_inp + get_strided_index(j, num_dims, dims, grad_inp_strides), g * w);
+        atomicAdd(grad_weights + get_strided_index(j, num_dims, dims, grad_w_strides), g * (x - o));
+    }
+}
+
+#define WEIGHTED_MEAN(TYPENAME, FWD, BWD) \
+extern "C" __global__ void FWD( \
+    const size_t numel, \
+    const size_t chunk_len, \
+    const size_t num_dims, \
+    const size_t *dims, \
+    const size_t *inp_strides, \
+    const size_t *w_strides, \
+    const TYPENAME *inp, \
+    const TYPENAME *weights, \
+    TYPENAME *out \
+) { \
+    weighted_mean_fwd(numel, chunk_len, num_dims, dims, inp_strides, w_strides, inp, weights, out); \
+} \
+extern "C" __global__ void BWD( \
+    const size_t numel, \
+    const size_t chunk_len, \
+    const size_t num_dims, \
+    const size_t *dims, \
+    const size_t *strides, \
+    const TYPENAME *inp, \
+    TYPENAME *grad_inp, \
+    const TYPENAME *weights, \
+    TYPENAME *grad_weights, \
+    const TYPENAME *out, \
+    const TYPENAME *grad_out \
+) { \
+    weighted_mean_bwd(numel, chunk_len, num_dims, dims, strides, inp, grad_inp, weights, grad_weights, out, grad_out); \
+}
+
+WEIGHTED_MEAN(float, weighted_mean_fwd_f32, weighted_mean_bwd_f32);
+WEIGHTED_MEAN(double, weighted_mean_fwd_f64, weighted_mean_bwd_f64);
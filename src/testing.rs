@@ -0,0 +1,231 @@
+//! Utilities for asserting that arrays & tensors are approximately equal.
+//!
+//! These are the same helpers that dfdx uses for its own tests, and they work with
+//! rust arrays, as well as [Tensor]s on any device.
+//!
+//! Two elements `l` and `r` are considered close if `|l - r| <= atol + rtol * |r|`.
+//! [assert_close] and [assert_close_with_tolerance] only use an absolute tolerance,
+//! while [assert_close_with_tolerances] allows setting a relative tolerance too.
+//!
+//! ```rust
+//! # use dfdx::{prelude::*, testing::*};
+//! # let dev: Cpu = Default::default();
+//! let t = dev.tensor([1.0, 2.0, 3.0]).exp();
+//! assert_close(&t.array(), &[2.7182817, 7.389056, 20.085537]);
+//! assert_close_with_tolerances(&t, &dev.tensor([2.72, 7.39, 20.09]), 0.0, 1e-3);
+//! ```
+
+use crate::{
+    shapes::{HasShape, Shape, Unit},
+    tensor::{AsVec, DeviceStorage, Tensor},
+};
+use std::vec::Vec;
+
+/// Something that can be compared element wise with an absolute & relative tolerance.
+pub trait AssertClose {
+    type Elem: std::fmt::Display + std::fmt::Debug + Copy + Default;
+    const DEFAULT_TOLERANCE: Self::Elem;
+
+    /// Returns the index & values of the first pair of elements
+    /// that are not within the tolerances, or `None` if all elements are close.
+    fn get_far_pair(
+        &self,
+        rhs: &Self,
+        atol: Self::Elem,
+        rtol: Self::Elem,
+    ) -> Option<(Vec<usize>, Self::Elem, Self::Elem)>;
+
+    /// Panics if any pair of elements are more than `tolerance` apart.
+    #[track_caller]
+    fn assert_close(&self, rhs: &Self, tolerance: Self::Elem)
+    where
+        Self: std::fmt::Debug,
+    {
+        self.assert_close_with_tolerances(rhs, tolerance, Default::default())
+    }
+
+    /// Panics if any pair of elements `l`, `r` have `|l - r| > atol + rtol * |r|`.
+    #[track_caller]
+    fn assert_close_with_tolerances(&self, rhs: &Self, atol: Self::Elem, rtol: Self::Elem)
+    where
+        Self: std::fmt::Debug,
+    {
+        if let Some((idx, l, r)) = self.get_far_pair(rhs, atol, rtol) {
+            panic!(
+                "lhs != rhs at index {idx:?} | {l} != {r} (atol={atol}, rtol={rtol})\n\n{self:?}\n\n{rhs:?}"
+            );
+        }
+    }
+}
+
+macro_rules! float_assert_close {
+    ($Float:ty) => {
+        impl AssertClose for $Float {
+            type Elem = $Float;
+            const DEFAULT_TOLERANCE: Self::Elem = 1e-6;
+            fn get_far_pair(
+                &self,
+                rhs: &Self,
+                atol: $Float,
+                rtol: $Float,
+            ) -> Option<(Vec<usize>, $Float, $Float)> {
+                let abs = |x: $Float| if x < 0.0 { -x } else { x };
+                let close = abs(self - rhs) <= atol + rtol * abs(*rhs);
+                // equal infinities & nans are close to each other
+                if close || self == rhs || (self.is_nan() && rhs.is_nan()) {
+                    None
+                } else {
+                    Some((Vec::new(), *self, *rhs))
+                }
+            }
+        }
+    };
+}
+
+float_assert_close!(f32);
+float_assert_close!(f64);
+
+impl<T: AssertClose, const M: usize> AssertClose for [T; M] {
+    type Elem = T::Elem;
+    const DEFAULT_TOLERANCE: Self::Elem = T::DEFAULT_TOLERANCE;
+    fn get_far_pair(
+        &self,
+        rhs: &Self,
+        atol: Self::Elem,
+        rtol: Self::Elem,
+    ) -> Option<(Vec<usize>, Self::Elem, Self::Elem)> {
+        for (i, (l, r)) in self.iter().zip(rhs.iter()).enumerate() {
+            if let Some((mut idx, l, r)) = l.get_far_pair(r, atol, rtol) {
+                idx.insert(0, i);
+                return Some((idx, l, r));
+            }
+        }
+        None
+    }
+}
+
+/// **Panics** if the vecs have different lengths.
+impl<T: AssertClose> AssertClose for Vec<T> {
+    type Elem = T::Elem;
+    const DEFAULT_TOLERANCE: Self::Elem = T::DEFAULT_TOLERANCE;
+    fn get_far_pair(
+        &self,
+        rhs: &Self,
+        atol: Self::Elem,
+        rtol: Self::Elem,
+    ) -> Option<(Vec<usize>, Self::Elem, Self::Elem)> {
+        assert_eq!(self.len(), rhs.len(), "lhs & rhs have different lengths");
+        for (i, (l, r)) in self.iter().zip(rhs.iter()).enumerate() {
+            if let Some((mut idx, l, r)) = l.get_far_pair(r, atol, rtol) {
+                idx.insert(0, i);
+                return Some((idx, l, r));
+            }
+        }
+        None
+    }
+}
+
+/// Copies the data of both tensors to the host, so this works with any device.
+///
+/// **Panics** if the tensors have different shapes.
+impl<S: Shape, E: Unit + std::fmt::Display + AssertClose<Elem = E>, D: DeviceStorage, T> AssertClose
+    for Tensor<S, E, D, T>
+{
+    type Elem = E;
+    const DEFAULT_TOLERANCE: Self::Elem = E::DEFAULT_TOLERANCE;
+    fn get_far_pair(
+        &self,
+        rhs: &Self,
+        atol: Self::Elem,
+        rtol: Self::Elem,
+    ) -> Option<(Vec<usize>, Self::Elem, Self::Elem)> {
+        let shape = self.shape().concrete();
+        assert_eq!(
+            shape,
+            rhs.shape().concrete(),
+            "lhs & rhs have different shapes"
+        );
+        let (lhs, rhs) = (self.as_vec(), rhs.as_vec());
+        for (i, (l, r)) in lhs.iter().zip(rhs.iter()).enumerate() {
+            if let Some((_, l, r)) = l.get_far_pair(r, atol, rtol) {
+                let mut idx = alloc::vec![0; S::NUM_DIMS];
+                let mut rem = i;
+                for d in (0..S::NUM_DIMS).rev() {
+                    idx[d] = rem % shape[d];
+                    rem /= shape[d];
+                }
+                return Some((idx, l, r));
+            }
+        }
+        None
+    }
+}
+
+/// Asserts that all elements of `a` & `b` are within [AssertClose::DEFAULT_TOLERANCE].
+#[track_caller]
+pub fn assert_close<T: AssertClose + std::fmt::Debug>(a: &T, b: &T) {
+    a.assert_close(b, T::DEFAULT_TOLERANCE);
+}
+
+/// Asserts that all elements of `a` & `b` are within an absolute `tolerance`.
+#[track_caller]
+pub fn assert_close_with_tolerance<T: AssertClose + std::fmt::Debug>(
+    a: &T,
+    b: &T,
+    tolerance: T::Elem,
+) {
+    a.assert_close(b, tolerance);
+}
+
+/// Asserts that all elements `l` of `a` and `r` of `b` satisfy `|l - r| <= atol + rtol * |r|`.
+#[track_caller]
+pub fn assert_close_with_tolerances<T: AssertClose + std::fmt::Debug>(
+    a: &T,
+    b: &T,
+    atol: T::Elem,
+    rtol: T::Elem,
+) {
+    a.assert_close_with_tolerances(b, atol, rtol);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tensor::*, tests::TestDevice};
+
+    #[test]
+    fn test_relative_tolerance() {
+        assert_close_with_tolerances(&[1000.0f32, 1.0], &[1000.5, 1.0], 0.0, 1e-3);
+        assert!([1000.0f32, 1.0]
+            .get_far_pair(&[1000.0, 1.1], 0.0, 1e-3)
+            .is_some());
+    }
+
+    #[test]
+    fn test_far_pair_index() {
+        let a = [[1.0f32, 2.0], [3.0, 4.0]];
+        let b = [[1.0f32, 2.0], [3.0, 5.0]];
+        assert_eq!(
+            a.get_far_pair(&b, 1e-6, 0.0),
+            Some((std::vec![1, 1], 4.0, 5.0))
+        );
+    }
+
+    #[test]
+    fn test_tensor_far_pair_index() {
+        let dev: TestDevice = Default::default();
+        let a = dev.tensor([[1.0f32, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let b = dev.tensor([[1.0f32, 2.0, 3.0], [4.0, 5.5, 6.0]]);
+        assert_eq!(
+            a.get_far_pair(&b, 1e-6, 0.0),
+            Some((std::vec![1, 1], 5.0, 5.5))
+        );
+        assert_close(&a, &a.clone());
+    }
+
+    #[test]
+    #[should_panic = "lhs != rhs at index [2]"]
+    fn test_assert_close_panics() {
+        assert_close(&[0.0f64, 1.0, 2.0], &[0.0, 1.0, 2.1]);
+    }
+}